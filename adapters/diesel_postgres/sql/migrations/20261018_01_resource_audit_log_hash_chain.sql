@@ -0,0 +1,59 @@
+-- Tamper-evident hash chain over resource_audit_log.
+--
+-- Every row appended from now on is linked to the previous row of its tenant
+-- chain (rows with a NULL tenant_id share the system chain):
+--
+--   row_hash = sha256(prev_hash || '\n' || canonical encoding of the row)
+--
+-- chain_seq numbers rows inside a chain starting at 1; the first row's
+-- prev_hash is 64 zeros. The dispatcher serialises appends per chain with a
+-- transaction-scoped advisory lock, and the unique index below rejects two
+-- rows claiming the same position if that is ever bypassed.
+--
+-- Rows written before this migration keep NULL chain columns and stay outside
+-- every chain -- they cannot be hashed retroactively without trusting their
+-- current content, which is exactly what the chain is meant to avoid.
+--
+-- resource_audit_checkpoint stores an HMAC of the chain head every 100 rows,
+-- keyed with the chain tenant's DEK (see 20260421_01_envelope_encryption), so
+-- truncating a chain or rebuilding it from scratch is detectable by
+-- `myc-cli audit verify`. chain_tenant_id is the nil UUID for the system
+-- chain. Checkpoints are as immutable as the rows they cover.
+--
+-- Requires -v db_role, same as 20260722_01.
+
+ALTER TABLE resource_audit_log
+    ADD COLUMN IF NOT EXISTS chain_seq BIGINT,
+    ADD COLUMN IF NOT EXISTS prev_hash TEXT,
+    ADD COLUMN IF NOT EXISTS row_hash  TEXT;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_resource_audit_log_chain
+    ON resource_audit_log (
+        COALESCE(tenant_id, '00000000-0000-0000-0000-000000000000'::uuid),
+        chain_seq
+    )
+    WHERE chain_seq IS NOT NULL;
+
+CREATE TABLE IF NOT EXISTS resource_audit_checkpoint (
+    id              UUID        PRIMARY KEY,
+    chain_tenant_id UUID        NOT NULL,
+    chain_seq       BIGINT      NOT NULL,
+    root_hash       TEXT        NOT NULL,
+    signature       TEXT        NOT NULL,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT now(),
+    UNIQUE (chain_tenant_id, chain_seq)
+);
+
+CREATE OR REPLACE FUNCTION prevent_resource_audit_checkpoint_mutation() RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'resource_audit_checkpoint is immutable: % not allowed', TG_OP;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS trg_resource_audit_checkpoint_immutable ON resource_audit_checkpoint;
+
+CREATE TRIGGER trg_resource_audit_checkpoint_immutable
+BEFORE UPDATE OR DELETE ON resource_audit_checkpoint
+FOR EACH ROW EXECUTE FUNCTION prevent_resource_audit_checkpoint_mutation();
+
+GRANT ALL ON resource_audit_checkpoint TO :"db_role";
@@ -0,0 +1,21 @@
+-- Widen resource_audit_log to cover security-relevant events that do not fit
+-- the original create/update/delete shape:
+--
+--   resource_type: guest_user        (guest grants and revocations)
+--                  connection_string (issuance and revocation)
+--                  error_code        (keyed by a UUIDv5 of prefix + code)
+--   event:         accessed          (sensitive reads and login attempts)
+--
+-- Only the CHECK constraints change. Dropping and re-adding a constraint does
+-- not touch existing rows, so the immutability trigger is not involved.
+
+ALTER TABLE resource_audit_log
+    DROP CONSTRAINT IF EXISTS resource_audit_log_resource_type_check,
+    ADD CONSTRAINT resource_audit_log_resource_type_check CHECK (resource_type IN
+        ('account', 'account_meta', 'user', 'tenant', 'tenant_meta', 'guest_role', 'webhook',
+         'guest_user', 'connection_string', 'error_code'));
+
+ALTER TABLE resource_audit_log
+    DROP CONSTRAINT IF EXISTS resource_audit_log_event_check,
+    ADD CONSTRAINT resource_audit_log_event_check CHECK (event IN
+        ('created', 'updated', 'deleted', 'accessed'));
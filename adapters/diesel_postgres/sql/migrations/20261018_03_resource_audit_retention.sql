@@ -0,0 +1,86 @@
+-- Retention, archival and legal hold for resource_audit_log.
+--
+-- resource_audit_retention_policy holds one optional row per chain
+-- (chain_tenant_id is the nil UUID for the system chain). retention_days
+-- overrides `core.audit.retentionDays` for that tenant; NULL falls back to it.
+-- legal_hold blocks every deletion from the chain, whatever its retention.
+--
+-- resource_audit_archive records every range moved out of the log: the seq
+-- prefix it covers (first_seq..last_seq, NULL when only pre-chain rows were
+-- archived), the hashes needed to resume chain verification after it, the
+-- cutoff (pre-chain rows created before it are covered too), and where the
+-- signed, compressed JSONL file lives. Archive records are immutable.
+--
+-- The resource_audit_log trigger keeps rejecting every UPDATE. A DELETE is
+-- now let through only for rows an archive record covers, and only while the
+-- chain is not on legal hold -- so rows can leave the log solely through an
+-- archive, never silently.
+--
+-- Requires -v db_role, same as 20260722_01.
+
+CREATE TABLE IF NOT EXISTS resource_audit_retention_policy (
+    chain_tenant_id UUID        PRIMARY KEY,
+    retention_days  INTEGER     CHECK (retention_days IS NULL OR retention_days > 0),
+    legal_hold      BOOLEAN     NOT NULL DEFAULT FALSE,
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE TABLE IF NOT EXISTS resource_audit_archive (
+    id              UUID        PRIMARY KEY,
+    chain_tenant_id UUID        NOT NULL,
+    first_seq       BIGINT,
+    last_seq        BIGINT,
+    first_prev_hash TEXT,
+    last_hash       TEXT,
+    cutoff          TIMESTAMPTZ NOT NULL,
+    row_count       BIGINT      NOT NULL,
+    location        TEXT        NOT NULL,
+    sha256          TEXT        NOT NULL,
+    signature       TEXT        NOT NULL,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_resource_audit_archive_chain
+    ON resource_audit_archive (chain_tenant_id, last_seq)
+    WHERE last_seq IS NOT NULL;
+
+CREATE OR REPLACE FUNCTION prevent_resource_audit_archive_mutation() RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'resource_audit_archive is immutable: % not allowed', TG_OP;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS trg_resource_audit_archive_immutable ON resource_audit_archive;
+
+CREATE TRIGGER trg_resource_audit_archive_immutable
+BEFORE UPDATE OR DELETE ON resource_audit_archive
+FOR EACH ROW EXECUTE FUNCTION prevent_resource_audit_archive_mutation();
+
+CREATE OR REPLACE FUNCTION prevent_resource_audit_log_mutation() RETURNS TRIGGER AS $$
+DECLARE
+    chain UUID;
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        chain := COALESCE(OLD.tenant_id, '00000000-0000-0000-0000-000000000000'::uuid);
+
+        IF NOT EXISTS (
+            SELECT 1 FROM resource_audit_retention_policy p
+            WHERE p.chain_tenant_id = chain AND p.legal_hold
+        ) AND EXISTS (
+            SELECT 1 FROM resource_audit_archive a
+            WHERE a.chain_tenant_id = chain
+              AND (
+                  (OLD.chain_seq IS NOT NULL AND OLD.chain_seq <= a.last_seq)
+                  OR (OLD.chain_seq IS NULL AND OLD.created_at < a.cutoff)
+              )
+        ) THEN
+            RETURN OLD;
+        END IF;
+    END IF;
+
+    RAISE EXCEPTION 'resource_audit_log is immutable: % not allowed', TG_OP;
+END;
+$$ LANGUAGE plpgsql;
+
+GRANT ALL ON resource_audit_retention_policy TO :"db_role";
+GRANT ALL ON resource_audit_archive          TO :"db_role";
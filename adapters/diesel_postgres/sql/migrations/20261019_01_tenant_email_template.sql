@@ -0,0 +1,23 @@
+-- Tenant overrides of the notification emails.
+--
+-- One row per tenant, kind and locale. `kind` names the file template the row
+-- overrides (e.g. `magic-link-request`); when no row matches the notification
+-- locale, the file templates under `templates/<locale>/email/` are used.
+-- Templates are validated and rendered in a sandbox by the application layer,
+-- so this table only stores their sources.
+--
+-- Requires -v db_role, same as 20260722_01.
+
+CREATE TABLE IF NOT EXISTS tenant_email_template (
+    id        UUID         DEFAULT gen_random_uuid() PRIMARY KEY,
+    tenant_id UUID         NOT NULL REFERENCES tenant(id) ON DELETE CASCADE,
+    kind      VARCHAR(64)  NOT NULL,
+    locale    VARCHAR(16)  NOT NULL,
+    subject   VARCHAR(255) NOT NULL,
+    body      TEXT         NOT NULL,
+    created   TIMESTAMPTZ  NOT NULL DEFAULT now(),
+    updated   TIMESTAMPTZ  DEFAULT NULL,
+    CONSTRAINT unique_tenant_email_template UNIQUE (tenant_id, kind, locale)
+);
+
+GRANT ALL ON tenant_email_template TO :"db_role";
@@ -0,0 +1,41 @@
+-- Delivery status of sent emails and the email suppression list.
+--
+-- `message_delivery` keeps the envelope of every message leaving
+-- `message_queue` (sent or given up), keyed by the queue message id, which is
+-- also sent as the `X-Mycelium-Message-Id` header. Provider webhooks update
+-- its status to delivered, bounced or complained.
+--
+-- `email_suppression` lists the lowercase addresses which hard-bounced or
+-- complained. Notifications are not queued for them until a users manager
+-- lifts the suppression.
+--
+-- Requires -v db_role, same as 20260722_01.
+
+CREATE TABLE IF NOT EXISTS message_delivery (
+    id        UUID         PRIMARY KEY,
+    recipient VARCHAR(255) NOT NULL,
+    subject   TEXT         NOT NULL,
+    status    VARCHAR(16)  NOT NULL,
+    detail    TEXT         DEFAULT NULL,
+    created   TIMESTAMPTZ  NOT NULL DEFAULT now(),
+    updated   TIMESTAMPTZ  DEFAULT NULL,
+    CONSTRAINT message_delivery_status_check CHECK (
+        status IN ('sent', 'failed', 'delivered', 'bounced', 'complained')
+    )
+);
+
+CREATE INDEX IF NOT EXISTS idx_message_delivery_recipient
+    ON message_delivery (recipient, created DESC);
+
+CREATE TABLE IF NOT EXISTS email_suppression (
+    email   VARCHAR(255) PRIMARY KEY,
+    reason  VARCHAR(32)  NOT NULL,
+    detail  TEXT         DEFAULT NULL,
+    created TIMESTAMPTZ  NOT NULL DEFAULT now(),
+    CONSTRAINT email_suppression_reason_check CHECK (
+        reason IN ('hardBounce', 'complaint')
+    )
+);
+
+GRANT ALL ON message_delivery TO :"db_role";
+GRANT ALL ON email_suppression TO :"db_role";
@@ -0,0 +1,21 @@
+-- Translations of the error code messages.
+--
+-- One row per error code and locale (lowercase, e.g. `pt-br`). The message in
+-- `error_code` stays the default; API error responses use a translation when
+-- the caller prefers its locale. `myc-cli native-errors init` seeds the
+-- translations of the native codes without replacing customized rows.
+--
+-- Requires -v db_role, same as 20260722_01.
+
+CREATE TABLE IF NOT EXISTS error_code_translation (
+    prefix  VARCHAR      NOT NULL,
+    code    INTEGER      NOT NULL,
+    locale  VARCHAR(16)  NOT NULL,
+    message VARCHAR(255) NOT NULL,
+    details VARCHAR(255) DEFAULT NULL,
+    CONSTRAINT error_code_translation_pk PRIMARY KEY (prefix, code, locale),
+    CONSTRAINT fk_error_code_translation FOREIGN KEY (prefix, code)
+        REFERENCES error_code(prefix, code) ON DELETE CASCADE
+);
+
+GRANT ALL ON error_code_translation TO :"db_role";
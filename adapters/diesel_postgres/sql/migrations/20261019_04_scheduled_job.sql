@@ -0,0 +1,18 @@
+-- Runs of the notification scheduler jobs.
+--
+-- One row per job. Every API replica tries to claim the due run of a job by
+-- moving `next_run_at` forward with a conditional update; the replica whose
+-- update matches the row runs the job, the others skip it. `last_run_at` is
+-- the start of the period covered by the next run.
+--
+-- Requires -v db_role, same as 20260722_01.
+
+CREATE TABLE IF NOT EXISTS scheduled_job (
+    name        VARCHAR(64)  NOT NULL,
+    last_run_at TIMESTAMPTZ  DEFAULT NULL,
+    next_run_at TIMESTAMPTZ  NOT NULL DEFAULT now(),
+    claimed_by  VARCHAR(255) DEFAULT NULL,
+    CONSTRAINT scheduled_job_pk PRIMARY KEY (name)
+);
+
+GRANT ALL ON scheduled_job TO :"db_role";
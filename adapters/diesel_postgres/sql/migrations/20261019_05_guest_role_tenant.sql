@@ -0,0 +1,54 @@
+-- Tenant-scoped guest roles.
+--
+-- Roles with a `tenant_id` belong to the tenant catalogue: they are managed by
+-- the tenant owners and managers, and only grant access inside the tenant.
+-- Roles without a tenant stay instance-wide. Names and slugs are unique per
+-- tenant (and among the instance-wide roles), so tenants may register roles
+-- with the same name.
+
+ALTER TABLE guest_role ADD COLUMN IF NOT EXISTS tenant_id UUID DEFAULT NULL;
+
+ALTER TABLE guest_role DROP CONSTRAINT IF EXISTS fk_guest_role_tenant;
+ALTER TABLE guest_role ADD CONSTRAINT fk_guest_role_tenant FOREIGN KEY (tenant_id) REFERENCES tenant(id) ON DELETE CASCADE;
+
+ALTER TABLE guest_role DROP CONSTRAINT IF EXISTS unique_guest_role_name;
+ALTER TABLE guest_role DROP CONSTRAINT IF EXISTS unique_guest_role_slug;
+
+CREATE UNIQUE INDEX IF NOT EXISTS unique_guest_role_name ON guest_role (name, permission) WHERE tenant_id IS NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS unique_guest_role_slug ON guest_role (slug, permission) WHERE tenant_id IS NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS unique_tenant_guest_role_name ON guest_role (tenant_id, name, permission) WHERE tenant_id IS NOT NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS unique_tenant_guest_role_slug ON guest_role (tenant_id, slug, permission) WHERE tenant_id IS NOT NULL;
+
+-- Tenant roles only license the accounts of their own tenant.
+CREATE OR REPLACE VIEW licensed_resources AS
+SELECT DISTINCT
+	ac.id AS acc_id,
+	ac.name AS acc_name,
+	ac.is_default AS is_acc_std,
+	gr.id AS gr_id,
+	gr.slug AS gr_slug,
+	gr.permission AS gr_perm,
+	gu.email AS gu_email,
+	gu.was_verified AS gu_verified,
+	ac.tenant_id AS tenant_id,
+    ga.permit_flags AS permit_flags,
+    ga.deny_flags AS deny_flags
+FROM
+	guest_user_on_account AS ga
+JOIN
+	guest_user AS gu
+ON
+	ga.guest_user_id = gu.id
+JOIN
+	guest_role AS gr
+ON
+	gr.id = gu.guest_role_id
+JOIN
+	account AS ac
+ON
+	ac.id = ga.account_id
+WHERE
+	ac.is_deleted = FALSE
+	AND (gr.tenant_id IS NULL OR gr.tenant_id = ac.tenant_id)
+ORDER BY
+    gu_email, gr_slug, acc_id, gr_id;
@@ -0,0 +1,48 @@
+-- Time-bound guest grants.
+--
+-- Grants may license their account inside a validity window only. Grants
+-- outside the window are hidden from the licensed resources view, so profiles
+-- drop them as soon as they expire, before the scheduler revokes them.
+
+ALTER TABLE guest_user_on_account ADD COLUMN IF NOT EXISTS valid_from TIMESTAMPTZ DEFAULT NULL;
+ALTER TABLE guest_user_on_account ADD COLUMN IF NOT EXISTS valid_until TIMESTAMPTZ DEFAULT NULL;
+
+CREATE INDEX IF NOT EXISTS idx_guest_user_on_account_valid_until
+    ON guest_user_on_account (valid_until)
+    WHERE valid_until IS NOT NULL;
+
+CREATE OR REPLACE VIEW licensed_resources AS
+SELECT DISTINCT
+	ac.id AS acc_id,
+	ac.name AS acc_name,
+	ac.is_default AS is_acc_std,
+	gr.id AS gr_id,
+	gr.slug AS gr_slug,
+	gr.permission AS gr_perm,
+	gu.email AS gu_email,
+	gu.was_verified AS gu_verified,
+	ac.tenant_id AS tenant_id,
+    ga.permit_flags AS permit_flags,
+    ga.deny_flags AS deny_flags,
+    gr.permissions AS gr_permissions
+FROM
+	guest_user_on_account AS ga
+JOIN
+	guest_user AS gu
+ON
+	ga.guest_user_id = gu.id
+JOIN
+	guest_role AS gr
+ON
+	gr.id = gu.guest_role_id
+JOIN
+	account AS ac
+ON
+	ac.id = ga.account_id
+WHERE
+	ac.is_deleted = FALSE
+	AND (gr.tenant_id IS NULL OR gr.tenant_id = ac.tenant_id)
+	AND (ga.valid_from IS NULL OR ga.valid_from <= now())
+	AND (ga.valid_until IS NULL OR ga.valid_until > now())
+ORDER BY
+    gu_email, gr_slug, acc_id, gr_id;
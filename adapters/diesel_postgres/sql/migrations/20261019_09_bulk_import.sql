@@ -0,0 +1,34 @@
+-- Bulk import jobs of guests and subscription accounts.
+--
+-- Jobs are queued by the subscription managers and applied in background by
+-- the API, which claims a pending job by moving it to `running` with a
+-- conditional update. A job is identified by the checksum of its tenant and
+-- normalized rows, so uploading the same rows again returns the existing job.
+--
+-- Requires -v db_role, same as 20260722_01.
+
+CREATE TABLE IF NOT EXISTS bulk_import (
+    id          UUID         NOT NULL,
+    tenant_id   UUID         NOT NULL,
+    checksum    VARCHAR(64)  NOT NULL,
+    status      VARCHAR(16)  NOT NULL DEFAULT 'pending',
+    import_rows JSONB        NOT NULL,
+    row_results JSONB        NOT NULL DEFAULT '[]'::jsonb,
+    created_by  JSONB        NOT NULL,
+    created     TIMESTAMPTZ  NOT NULL DEFAULT now(),
+    claimed_by  VARCHAR(255) DEFAULT NULL,
+    started_at  TIMESTAMPTZ  DEFAULT NULL,
+    finished_at TIMESTAMPTZ  DEFAULT NULL,
+    CONSTRAINT bulk_import_pk PRIMARY KEY (id),
+    CONSTRAINT unique_bulk_import_checksum UNIQUE (tenant_id, checksum),
+    CONSTRAINT fk_bulk_import_tenant FOREIGN KEY (tenant_id) REFERENCES tenant(id) ON DELETE CASCADE,
+    CONSTRAINT bulk_import_status_check CHECK (
+        status IN ('pending', 'running', 'done', 'failed')
+    )
+);
+
+CREATE INDEX IF NOT EXISTS idx_bulk_import_queue
+    ON bulk_import (created)
+    WHERE status IN ('pending', 'running');
+
+GRANT ALL ON bulk_import TO :"db_role";
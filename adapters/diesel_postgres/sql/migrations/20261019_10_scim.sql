@@ -0,0 +1,44 @@
+-- SCIM 2.0 provisioning of users and groups.
+--
+-- Tenant owners issue bearer credentials to the SCIM clients of their tenants;
+-- only the SHA-256 hash of the secrets is stored. The users and groups the
+-- clients provision are stored as SCIM resources, mapped onto the personal
+-- account of the users and onto a guest role of a subscription account for
+-- the groups. Names are unique per tenant and kind, without regard to case.
+--
+-- Requires -v db_role, same as 20260722_01.
+
+CREATE TABLE IF NOT EXISTS scim_credential (
+    id          UUID         NOT NULL,
+    tenant_id   UUID         NOT NULL,
+    name        VARCHAR(128) NOT NULL,
+    secret_hash VARCHAR(64)  NOT NULL,
+    created_by  JSONB        NOT NULL,
+    created     TIMESTAMPTZ  NOT NULL DEFAULT now(),
+    CONSTRAINT scim_credential_pk PRIMARY KEY (id),
+    CONSTRAINT unique_scim_credential_secret_hash UNIQUE (secret_hash),
+    CONSTRAINT fk_scim_credential_tenant FOREIGN KEY (tenant_id) REFERENCES tenant(id) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS scim_resource (
+    id            UUID         NOT NULL,
+    tenant_id     UUID         NOT NULL,
+    kind          VARCHAR(16)  NOT NULL,
+    external_id   VARCHAR(255) DEFAULT NULL,
+    display_name  VARCHAR(255) NOT NULL,
+    account_id    UUID         DEFAULT NULL,
+    guest_role_id UUID         DEFAULT NULL,
+    provisioned   BOOLEAN      NOT NULL DEFAULT false,
+    attributes    JSONB        NOT NULL DEFAULT '{}'::jsonb,
+    created       TIMESTAMPTZ  NOT NULL DEFAULT now(),
+    updated       TIMESTAMPTZ  DEFAULT NULL,
+    CONSTRAINT scim_resource_pk PRIMARY KEY (id),
+    CONSTRAINT fk_scim_resource_tenant FOREIGN KEY (tenant_id) REFERENCES tenant(id) ON DELETE CASCADE,
+    CONSTRAINT scim_resource_kind_check CHECK (kind IN ('User', 'Group'))
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS unique_scim_resource_display_name
+    ON scim_resource (tenant_id, kind, lower(display_name));
+
+GRANT ALL ON scim_credential TO :"db_role";
+GRANT ALL ON scim_resource TO :"db_role";
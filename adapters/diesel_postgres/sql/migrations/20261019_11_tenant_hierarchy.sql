@@ -0,0 +1,14 @@
+-- Hierarchical tenants.
+--
+-- Tenants may have a parent tenant. Owners of a tenant own its descendants,
+-- and child tenants inherit the meta they do not override. Removing a parent
+-- detaches its children.
+
+ALTER TABLE tenant ADD COLUMN IF NOT EXISTS parent_id UUID DEFAULT NULL;
+
+ALTER TABLE tenant DROP CONSTRAINT IF EXISTS fk_tenant_parent;
+ALTER TABLE tenant ADD CONSTRAINT fk_tenant_parent FOREIGN KEY (parent_id) REFERENCES tenant(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_tenant_parent_id
+    ON tenant (parent_id)
+    WHERE parent_id IS NOT NULL;
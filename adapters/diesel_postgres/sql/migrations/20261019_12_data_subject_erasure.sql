@@ -0,0 +1,56 @@
+-- Data subject erasure.
+--
+-- Erasing a person pseudonymises the audit rows that name them. redacted_at
+-- marks such rows: their metadata and performed_by no longer match the
+-- content their row_hash was computed from, so chain verification checks
+-- only their links.
+--
+-- The resource_audit_log trigger now lets an UPDATE through when it changes
+-- nothing but metadata and performed_by, stamps redacted_at, and the chain
+-- is not on legal hold. Every other UPDATE is still rejected.
+
+ALTER TABLE resource_audit_log ADD COLUMN IF NOT EXISTS redacted_at TIMESTAMPTZ DEFAULT NULL;
+
+CREATE OR REPLACE FUNCTION prevent_resource_audit_log_mutation() RETURNS TRIGGER AS $$
+DECLARE
+    chain UUID;
+BEGIN
+    chain := COALESCE(OLD.tenant_id, '00000000-0000-0000-0000-000000000000'::uuid);
+
+    IF EXISTS (
+        SELECT 1 FROM resource_audit_retention_policy p
+        WHERE p.chain_tenant_id = chain AND p.legal_hold
+    ) THEN
+        RAISE EXCEPTION 'resource_audit_log chain is on legal hold: % not allowed', TG_OP;
+    END IF;
+
+    IF TG_OP = 'UPDATE' THEN
+        IF NEW.redacted_at IS NOT NULL
+           AND NEW.id = OLD.id
+           AND NEW.resource_type = OLD.resource_type
+           AND NEW.resource_id = OLD.resource_id
+           AND NEW.tenant_id IS NOT DISTINCT FROM OLD.tenant_id
+           AND NEW.event = OLD.event
+           AND NEW.created_at = OLD.created_at
+           AND NEW.chain_seq IS NOT DISTINCT FROM OLD.chain_seq
+           AND NEW.prev_hash IS NOT DISTINCT FROM OLD.prev_hash
+           AND NEW.row_hash IS NOT DISTINCT FROM OLD.row_hash
+        THEN
+            RETURN NEW;
+        END IF;
+    END IF;
+
+    IF TG_OP = 'DELETE' AND EXISTS (
+        SELECT 1 FROM resource_audit_archive a
+        WHERE a.chain_tenant_id = chain
+          AND (
+              (OLD.chain_seq IS NOT NULL AND OLD.chain_seq <= a.last_seq)
+              OR (OLD.chain_seq IS NULL AND OLD.created_at < a.cutoff)
+          )
+    ) THEN
+        RETURN OLD;
+    END IF;
+
+    RAISE EXCEPTION 'resource_audit_log is immutable: % not allowed', TG_OP;
+END;
+$$ LANGUAGE plpgsql;
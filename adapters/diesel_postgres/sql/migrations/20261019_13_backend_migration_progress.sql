@@ -0,0 +1,21 @@
+-- Progress of `myc-cli migrate-backend` (standalone SQLite to Postgres).
+--
+-- One row per copied table. `copied_rows` is both the number of rows already
+-- written and the offset the copy resumes from: it is updated in the same
+-- transaction as each batch, so an interrupted run never writes a batch twice.
+-- `checksum` is the checksum of the source rows, recorded once the rows read
+-- back from this database are found to match it.
+--
+-- Requires -v db_role, same as 20260722_01.
+
+CREATE TABLE IF NOT EXISTS backend_migration_progress (
+    table_name   VARCHAR(64) NOT NULL,
+    source_rows  BIGINT      NOT NULL,
+    copied_rows  BIGINT      NOT NULL DEFAULT 0,
+    checksum     TEXT        NOT NULL DEFAULT '0',
+    completed_at TIMESTAMPTZ DEFAULT NULL,
+    updated_at   TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT backend_migration_progress_pk PRIMARY KEY (table_name)
+);
+
+GRANT ALL ON backend_migration_progress TO :"db_role";
@@ -0,0 +1,43 @@
+-- Expose the end of the grant validity window in the licensed resources view.
+--
+-- Gateways cap the time a resolved profile stays cached at the earliest
+-- `valid_until` of its licenses, so an expiring grant is not served from the
+-- profile cache past its window.
+
+CREATE OR REPLACE VIEW licensed_resources AS
+SELECT DISTINCT
+	ac.id AS acc_id,
+	ac.name AS acc_name,
+	ac.is_default AS is_acc_std,
+	gr.id AS gr_id,
+	gr.slug AS gr_slug,
+	gr.permission AS gr_perm,
+	gu.email AS gu_email,
+	gu.was_verified AS gu_verified,
+	ac.tenant_id AS tenant_id,
+    ga.permit_flags AS permit_flags,
+    ga.deny_flags AS deny_flags,
+    gr.permissions AS gr_permissions,
+    ga.valid_until AS valid_until
+FROM
+	guest_user_on_account AS ga
+JOIN
+	guest_user AS gu
+ON
+	ga.guest_user_id = gu.id
+JOIN
+	guest_role AS gr
+ON
+	gr.id = gu.guest_role_id
+JOIN
+	account AS ac
+ON
+	ac.id = ga.account_id
+WHERE
+	ac.is_deleted = FALSE
+	AND (gr.tenant_id IS NULL OR gr.tenant_id = ac.tenant_id)
+	AND (ga.valid_from IS NULL OR ga.valid_from <= now())
+	AND (ga.valid_until IS NULL OR ga.valid_until > now())
+	AND (gu.was_verified OR gu.invitation_expires_at IS NULL OR gu.invitation_expires_at > now())
+ORDER BY
+    gu_email, gr_slug, acc_id, gr_id;
@@ -175,7 +175,8 @@ CREATE TABLE tenant (
     created TIMESTAMPTZ DEFAULT now(),
     updated TIMESTAMPTZ DEFAULT NULL,
     encrypted_dek TEXT,
-    kek_version INTEGER NOT NULL DEFAULT 1
+    kek_version INTEGER NOT NULL DEFAULT 1,
+    parent_id UUID DEFAULT NULL
 );
 
 -- Account table
@@ -262,8 +263,10 @@ CREATE TABLE guest_role (
     description VARCHAR(255),
     permission INT DEFAULT 0,
     system BOOLEAN DEFAULT FALSE NOT NULL,
+    tenant_id UUID DEFAULT NULL,
     created TIMESTAMPTZ DEFAULT now(),
-    updated TIMESTAMPTZ DEFAULT NULL
+    updated TIMESTAMPTZ DEFAULT NULL,
+    permissions TEXT[] DEFAULT NULL
 );
 
 -- Guest role children table
@@ -282,7 +285,10 @@ CREATE TABLE guest_user (
     guest_role_id UUID NOT NULL,
     created TIMESTAMPTZ DEFAULT now(),
     updated TIMESTAMPTZ DEFAULT NULL,
-    was_verified BOOLEAN DEFAULT FALSE
+    was_verified BOOLEAN DEFAULT FALSE,
+    invited_by JSONB DEFAULT NULL,
+    invitation_sent_at TIMESTAMPTZ DEFAULT NULL,
+    invitation_expires_at TIMESTAMPTZ DEFAULT NULL
 );
 
 -- Guest user on account table
@@ -291,9 +297,23 @@ CREATE TABLE guest_user_on_account (
     account_id UUID NOT NULL,
     created TIMESTAMPTZ DEFAULT now(),
     permit_flags JSONB[],
-    deny_flags JSONB[]
+    deny_flags JSONB[],
+    valid_from TIMESTAMPTZ DEFAULT NULL,
+    valid_until TIMESTAMPTZ DEFAULT NULL
 );
 
+-- Backs the revocation of expired guest grants by the scheduler. See
+-- migration 20261019_07.
+CREATE INDEX IF NOT EXISTS idx_guest_user_on_account_valid_until
+    ON guest_user_on_account (valid_until)
+    WHERE valid_until IS NOT NULL;
+
+-- Backs the removal of expired guest invitations by the scheduler. See
+-- migration 20261019_08.
+CREATE INDEX IF NOT EXISTS idx_guest_user_invitation_expires_at
+    ON guest_user (invitation_expires_at)
+    WHERE was_verified = FALSE;
+
 -- Error code table
 CREATE TABLE error_code (
     code SERIAL NOT NULL,
@@ -304,6 +324,15 @@ CREATE TABLE error_code (
     is_native BOOLEAN DEFAULT FALSE
 );
 
+-- Error code translations table, keyed by locale. See migration 20261019_03.
+CREATE TABLE error_code_translation (
+    prefix VARCHAR NOT NULL,
+    code INTEGER NOT NULL,
+    locale VARCHAR(16) NOT NULL,
+    message VARCHAR(255) NOT NULL,
+    details VARCHAR(255) DEFAULT NULL
+);
+
 -- Webhook table
 CREATE TABLE webhook (
     id UUID DEFAULT gen_random_uuid(),
@@ -384,6 +413,110 @@ CREATE TABLE instance_settings (
     updated TIMESTAMPTZ DEFAULT NULL
 );
 
+-- Tenant overrides of the notification emails, unique by tenant, kind and
+-- locale. See migration 20261019_01.
+CREATE TABLE tenant_email_template (
+    id UUID DEFAULT gen_random_uuid(),
+    tenant_id UUID NOT NULL,
+    kind VARCHAR(64) NOT NULL,
+    locale VARCHAR(16) NOT NULL,
+    subject VARCHAR(255) NOT NULL,
+    body TEXT NOT NULL,
+    created TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated TIMESTAMPTZ DEFAULT NULL
+);
+
+-- Delivery status of the messages which left the message queue, keyed by the
+-- queue message id. See migration 20261019_02.
+CREATE TABLE message_delivery (
+    id UUID NOT NULL,
+    recipient VARCHAR(255) NOT NULL,
+    subject TEXT NOT NULL,
+    status VARCHAR(16) NOT NULL,
+    detail TEXT DEFAULT NULL,
+    created TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated TIMESTAMPTZ DEFAULT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_message_delivery_recipient
+    ON message_delivery (recipient, created DESC);
+
+-- Addresses no email is sent to (hard bounces and complaints). See migration
+-- 20261019_02.
+CREATE TABLE email_suppression (
+    email VARCHAR(255) NOT NULL,
+    reason VARCHAR(32) NOT NULL,
+    detail TEXT DEFAULT NULL,
+    created TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+-- Runs of the notification scheduler jobs, claimed by a single replica. See
+-- migration 20261019_04.
+CREATE TABLE scheduled_job (
+    name VARCHAR(64) NOT NULL,
+    last_run_at TIMESTAMPTZ DEFAULT NULL,
+    next_run_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    claimed_by VARCHAR(255) DEFAULT NULL
+);
+
+-- Bulk import jobs of guests and subscription accounts, applied in background.
+-- See migration 20261019_09.
+CREATE TABLE bulk_import (
+    id UUID NOT NULL,
+    tenant_id UUID NOT NULL,
+    checksum VARCHAR(64) NOT NULL,
+    status VARCHAR(16) NOT NULL DEFAULT 'pending',
+    import_rows JSONB NOT NULL,
+    row_results JSONB NOT NULL DEFAULT '[]'::jsonb,
+    created_by JSONB NOT NULL,
+    created TIMESTAMPTZ NOT NULL DEFAULT now(),
+    claimed_by VARCHAR(255) DEFAULT NULL,
+    started_at TIMESTAMPTZ DEFAULT NULL,
+    finished_at TIMESTAMPTZ DEFAULT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_bulk_import_queue
+    ON bulk_import (created)
+    WHERE status IN ('pending', 'running');
+
+-- SCIM credentials and provisioned resources. See migration 20261019_10.
+CREATE TABLE scim_credential (
+    id UUID NOT NULL,
+    tenant_id UUID NOT NULL,
+    name VARCHAR(128) NOT NULL,
+    secret_hash VARCHAR(64) NOT NULL,
+    created_by JSONB NOT NULL,
+    created TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE TABLE scim_resource (
+    id UUID NOT NULL,
+    tenant_id UUID NOT NULL,
+    kind VARCHAR(16) NOT NULL,
+    external_id VARCHAR(255) DEFAULT NULL,
+    display_name VARCHAR(255) NOT NULL,
+    account_id UUID DEFAULT NULL,
+    guest_role_id UUID DEFAULT NULL,
+    provisioned BOOLEAN NOT NULL DEFAULT false,
+    attributes JSONB NOT NULL DEFAULT '{}'::jsonb,
+    created TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated TIMESTAMPTZ DEFAULT NULL
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS unique_scim_resource_display_name
+    ON scim_resource (tenant_id, kind, lower(display_name));
+
+-- Progress of `myc-cli migrate-backend`, one row per copied table. See
+-- migration 20261019_13.
+CREATE TABLE backend_migration_progress (
+    table_name VARCHAR(64) NOT NULL,
+    source_rows BIGINT NOT NULL,
+    copied_rows BIGINT NOT NULL DEFAULT 0,
+    checksum TEXT NOT NULL DEFAULT '0',
+    completed_at TIMESTAMPTZ DEFAULT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
 --------------------------------------------------------------------------------
 -- CONSTRAINTS
 --------------------------------------------------------------------------------
@@ -391,6 +524,8 @@ CREATE TABLE instance_settings (
 -- Tenant table constraints
 ALTER TABLE tenant ADD CONSTRAINT tenant_pk PRIMARY KEY (id);
 ALTER TABLE tenant ADD CONSTRAINT tenant_name_unique UNIQUE (name);
+ALTER TABLE tenant ADD CONSTRAINT fk_tenant_parent FOREIGN KEY (parent_id) REFERENCES tenant(id) ON DELETE SET NULL;
+CREATE INDEX IF NOT EXISTS idx_tenant_parent_id ON tenant (parent_id) WHERE parent_id IS NOT NULL;
 
 -- Account table constraints
 ALTER TABLE account ADD CONSTRAINT account_pk PRIMARY KEY (id);
@@ -433,10 +568,18 @@ ALTER TABLE tenant_tag ADD CONSTRAINT tenant_tag_pk PRIMARY KEY (id);
 ALTER TABLE tenant_tag ADD CONSTRAINT unique_tenant_tag UNIQUE (value, tenant_id);
 ALTER TABLE tenant_tag ADD CONSTRAINT fk_tenant_tag FOREIGN KEY (tenant_id) REFERENCES tenant(id) ON DELETE CASCADE;
 
+-- Tenant email template table constraints
+ALTER TABLE tenant_email_template ADD CONSTRAINT tenant_email_template_pk PRIMARY KEY (id);
+ALTER TABLE tenant_email_template ADD CONSTRAINT unique_tenant_email_template UNIQUE (tenant_id, kind, locale);
+ALTER TABLE tenant_email_template ADD CONSTRAINT fk_tenant_email_template FOREIGN KEY (tenant_id) REFERENCES tenant(id) ON DELETE CASCADE;
+
 -- Guest role table constraints
 ALTER TABLE guest_role ADD CONSTRAINT guest_role_pk PRIMARY KEY (id);
-ALTER TABLE guest_role ADD CONSTRAINT unique_guest_role_name UNIQUE (name, permission);
-ALTER TABLE guest_role ADD CONSTRAINT unique_guest_role_slug UNIQUE (slug, permission);
+ALTER TABLE guest_role ADD CONSTRAINT fk_guest_role_tenant FOREIGN KEY (tenant_id) REFERENCES tenant(id) ON DELETE CASCADE;
+CREATE UNIQUE INDEX unique_guest_role_name ON guest_role (name, permission) WHERE tenant_id IS NULL;
+CREATE UNIQUE INDEX unique_guest_role_slug ON guest_role (slug, permission) WHERE tenant_id IS NULL;
+CREATE UNIQUE INDEX unique_tenant_guest_role_name ON guest_role (tenant_id, name, permission) WHERE tenant_id IS NOT NULL;
+CREATE UNIQUE INDEX unique_tenant_guest_role_slug ON guest_role (tenant_id, slug, permission) WHERE tenant_id IS NOT NULL;
 
 -- Guest role children table constraints
 ALTER TABLE guest_role_children ADD CONSTRAINT guest_role_children_pk PRIMARY KEY (parent_id, child_role_id);
@@ -456,6 +599,10 @@ ALTER TABLE guest_user_on_account ADD CONSTRAINT fk_guest_account FOREIGN KEY (a
 -- Error code table constraints
 ALTER TABLE error_code ADD CONSTRAINT error_code_pk PRIMARY KEY (prefix, code);
 
+-- Error code translations table constraints
+ALTER TABLE error_code_translation ADD CONSTRAINT error_code_translation_pk PRIMARY KEY (prefix, code, locale);
+ALTER TABLE error_code_translation ADD CONSTRAINT fk_error_code_translation FOREIGN KEY (prefix, code) REFERENCES error_code(prefix, code) ON DELETE CASCADE;
+
 -- Webhook table constraints
 ALTER TABLE webhook ADD CONSTRAINT webhook_pk PRIMARY KEY (id);
 ALTER TABLE webhook ADD CONSTRAINT unique_webhook UNIQUE (name, url, trigger);
@@ -466,6 +613,34 @@ ALTER TABLE webhook_execution ADD CONSTRAINT webhook_execution_pk PRIMARY KEY (i
 -- Message queue table constraints
 ALTER TABLE message_queue ADD CONSTRAINT message_queue_pk PRIMARY KEY (id);
 
+-- Message delivery table constraints
+ALTER TABLE message_delivery ADD CONSTRAINT message_delivery_pk PRIMARY KEY (id);
+ALTER TABLE message_delivery ADD CONSTRAINT message_delivery_status_check CHECK (status IN ('sent', 'failed', 'delivered', 'bounced', 'complained'));
+
+-- Email suppression table constraints
+ALTER TABLE email_suppression ADD CONSTRAINT email_suppression_pk PRIMARY KEY (email);
+ALTER TABLE email_suppression ADD CONSTRAINT email_suppression_reason_check CHECK (reason IN ('hardBounce', 'complaint'));
+
+-- Scheduled job table constraints
+ALTER TABLE scheduled_job ADD CONSTRAINT scheduled_job_pk PRIMARY KEY (name);
+
+-- Bulk import table constraints
+ALTER TABLE bulk_import ADD CONSTRAINT bulk_import_pk PRIMARY KEY (id);
+ALTER TABLE bulk_import ADD CONSTRAINT unique_bulk_import_checksum UNIQUE (tenant_id, checksum);
+ALTER TABLE bulk_import ADD CONSTRAINT fk_bulk_import_tenant FOREIGN KEY (tenant_id) REFERENCES tenant(id) ON DELETE CASCADE;
+ALTER TABLE bulk_import ADD CONSTRAINT bulk_import_status_check CHECK (status IN ('pending', 'running', 'done', 'failed'));
+
+-- SCIM tables constraints
+ALTER TABLE scim_credential ADD CONSTRAINT scim_credential_pk PRIMARY KEY (id);
+ALTER TABLE scim_credential ADD CONSTRAINT unique_scim_credential_secret_hash UNIQUE (secret_hash);
+ALTER TABLE scim_credential ADD CONSTRAINT fk_scim_credential_tenant FOREIGN KEY (tenant_id) REFERENCES tenant(id) ON DELETE CASCADE;
+ALTER TABLE scim_resource ADD CONSTRAINT scim_resource_pk PRIMARY KEY (id);
+ALTER TABLE scim_resource ADD CONSTRAINT fk_scim_resource_tenant FOREIGN KEY (tenant_id) REFERENCES tenant(id) ON DELETE CASCADE;
+ALTER TABLE scim_resource ADD CONSTRAINT scim_resource_kind_check CHECK (kind IN ('User', 'Group'));
+
+-- Backend migration progress constraints
+ALTER TABLE backend_migration_progress ADD CONSTRAINT backend_migration_progress_pk PRIMARY KEY (table_name);
+
 --------------------------------------------------------------------------------
 -- VIEWS
 --------------------------------------------------------------------------------
@@ -483,7 +658,9 @@ SELECT DISTINCT
 	gu.was_verified AS gu_verified,
 	ac.tenant_id AS tenant_id,
     ga.permit_flags AS permit_flags,
-    ga.deny_flags AS deny_flags
+    ga.deny_flags AS deny_flags,
+    gr.permissions AS gr_permissions,
+    ga.valid_until AS valid_until
 FROM
 	guest_user_on_account AS ga
 JOIN
@@ -500,6 +677,10 @@ ON
 	ac.id = ga.account_id
 WHERE
 	ac.is_deleted = FALSE
+	AND (gr.tenant_id IS NULL OR gr.tenant_id = ac.tenant_id)
+	AND (ga.valid_from IS NULL OR ga.valid_from <= now())
+	AND (ga.valid_until IS NULL OR ga.valid_until > now())
+	AND (gu.was_verified OR gu.invitation_expires_at IS NULL OR gu.invitation_expires_at > now())
 ORDER BY
     gu_email, gr_slug, acc_id, gr_id;
 
@@ -591,21 +772,68 @@ CREATE INDEX IF NOT EXISTS idx_telegram_audit_created_at
 CREATE TABLE IF NOT EXISTS resource_audit_log (
     id            UUID        PRIMARY KEY DEFAULT gen_random_uuid(),
     resource_type TEXT        NOT NULL CHECK (resource_type IN
-                       ('account', 'account_meta', 'user', 'tenant', 'tenant_meta', 'guest_role', 'webhook')),
+                       ('account', 'account_meta', 'user', 'tenant', 'tenant_meta', 'guest_role', 'webhook',
+                        'guest_user', 'connection_string', 'error_code')),
     resource_id   UUID        NOT NULL,
     tenant_id     UUID,
-    event         TEXT        NOT NULL CHECK (event IN ('created', 'updated', 'deleted')),
+    event         TEXT        NOT NULL CHECK (event IN ('created', 'updated', 'deleted', 'accessed')),
     performed_by  JSONB       NOT NULL,
     metadata      JSONB       NOT NULL DEFAULT '{}'::jsonb,
-    created_at    TIMESTAMPTZ NOT NULL
+    created_at    TIMESTAMPTZ NOT NULL,
+    chain_seq     BIGINT,
+    prev_hash     TEXT,
+    row_hash      TEXT,
+    redacted_at   TIMESTAMPTZ DEFAULT NULL
 );
 
 CREATE INDEX idx_resource_audit_log_resource ON resource_audit_log (resource_id, created_at DESC);
 CREATE INDEX idx_resource_audit_log_tenant   ON resource_audit_log (tenant_id, created_at DESC)
     WHERE tenant_id IS NOT NULL;
 
+-- DELETE is only let through for archived rows of chains not on legal hold:
+-- see migrations/20261018_03_resource_audit_retention.sql. UPDATE is only let
+-- through for the pseudonymisation of a row (metadata and performed_by, with
+-- redacted_at stamped): see migrations/20261019_12_data_subject_erasure.sql.
 CREATE OR REPLACE FUNCTION prevent_resource_audit_log_mutation() RETURNS TRIGGER AS $$
+DECLARE
+    chain UUID;
 BEGIN
+    chain := COALESCE(OLD.tenant_id, '00000000-0000-0000-0000-000000000000'::uuid);
+
+    IF EXISTS (
+        SELECT 1 FROM resource_audit_retention_policy p
+        WHERE p.chain_tenant_id = chain AND p.legal_hold
+    ) THEN
+        RAISE EXCEPTION 'resource_audit_log chain is on legal hold: % not allowed', TG_OP;
+    END IF;
+
+    IF TG_OP = 'UPDATE' THEN
+        IF NEW.redacted_at IS NOT NULL
+           AND NEW.id = OLD.id
+           AND NEW.resource_type = OLD.resource_type
+           AND NEW.resource_id = OLD.resource_id
+           AND NEW.tenant_id IS NOT DISTINCT FROM OLD.tenant_id
+           AND NEW.event = OLD.event
+           AND NEW.created_at = OLD.created_at
+           AND NEW.chain_seq IS NOT DISTINCT FROM OLD.chain_seq
+           AND NEW.prev_hash IS NOT DISTINCT FROM OLD.prev_hash
+           AND NEW.row_hash IS NOT DISTINCT FROM OLD.row_hash
+        THEN
+            RETURN NEW;
+        END IF;
+    END IF;
+
+    IF TG_OP = 'DELETE' AND EXISTS (
+        SELECT 1 FROM resource_audit_archive a
+        WHERE a.chain_tenant_id = chain
+          AND (
+              (OLD.chain_seq IS NOT NULL AND OLD.chain_seq <= a.last_seq)
+              OR (OLD.chain_seq IS NULL AND OLD.created_at < a.cutoff)
+          )
+    ) THEN
+        RETURN OLD;
+    END IF;
+
     RAISE EXCEPTION 'resource_audit_log is immutable: % not allowed', TG_OP;
 END;
 $$ LANGUAGE plpgsql;
@@ -614,6 +842,73 @@ CREATE TRIGGER trg_resource_audit_log_immutable
 BEFORE UPDATE OR DELETE ON resource_audit_log
 FOR EACH ROW EXECUTE FUNCTION prevent_resource_audit_log_mutation();
 
+-- Hash chain: see migrations/20261018_01_resource_audit_log_hash_chain.sql.
+
+CREATE UNIQUE INDEX idx_resource_audit_log_chain
+    ON resource_audit_log (
+        COALESCE(tenant_id, '00000000-0000-0000-0000-000000000000'::uuid),
+        chain_seq
+    )
+    WHERE chain_seq IS NOT NULL;
+
+CREATE TABLE IF NOT EXISTS resource_audit_checkpoint (
+    id              UUID        PRIMARY KEY,
+    chain_tenant_id UUID        NOT NULL,
+    chain_seq       BIGINT      NOT NULL,
+    root_hash       TEXT        NOT NULL,
+    signature       TEXT        NOT NULL,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT now(),
+    UNIQUE (chain_tenant_id, chain_seq)
+);
+
+CREATE OR REPLACE FUNCTION prevent_resource_audit_checkpoint_mutation() RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'resource_audit_checkpoint is immutable: % not allowed', TG_OP;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_resource_audit_checkpoint_immutable
+BEFORE UPDATE OR DELETE ON resource_audit_checkpoint
+FOR EACH ROW EXECUTE FUNCTION prevent_resource_audit_checkpoint_mutation();
+
+-- Retention and archival: see migrations/20261018_03_resource_audit_retention.sql.
+
+CREATE TABLE IF NOT EXISTS resource_audit_retention_policy (
+    chain_tenant_id UUID        PRIMARY KEY,
+    retention_days  INTEGER     CHECK (retention_days IS NULL OR retention_days > 0),
+    legal_hold      BOOLEAN     NOT NULL DEFAULT FALSE,
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE TABLE IF NOT EXISTS resource_audit_archive (
+    id              UUID        PRIMARY KEY,
+    chain_tenant_id UUID        NOT NULL,
+    first_seq       BIGINT,
+    last_seq        BIGINT,
+    first_prev_hash TEXT,
+    last_hash       TEXT,
+    cutoff          TIMESTAMPTZ NOT NULL,
+    row_count       BIGINT      NOT NULL,
+    location        TEXT        NOT NULL,
+    sha256          TEXT        NOT NULL,
+    signature       TEXT        NOT NULL,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX idx_resource_audit_archive_chain
+    ON resource_audit_archive (chain_tenant_id, last_seq)
+    WHERE last_seq IS NOT NULL;
+
+CREATE OR REPLACE FUNCTION prevent_resource_audit_archive_mutation() RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'resource_audit_archive is immutable: % not allowed', TG_OP;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_resource_audit_archive_immutable
+BEFORE UPDATE OR DELETE ON resource_audit_archive
+FOR EACH ROW EXECUTE FUNCTION prevent_resource_audit_archive_mutation();
+
 --------------------------------------------------------------------------------
 -- PERMISSIONS
 --
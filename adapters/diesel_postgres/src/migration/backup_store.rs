@@ -0,0 +1,413 @@
+use super::{
+    migrate_backend::{
+        convert_row, load_target_columns, quote_identifier, reset_sequences,
+    },
+    tenant_iteration::acquire_conn,
+};
+use crate::models::config::DbPool;
+
+use diesel::{
+    connection::{AnsiTransactionManager, TransactionManager},
+    prelude::*,
+    r2d2::{ConnectionManager, PooledConnection},
+    result::{DatabaseErrorKind, Error as DieselError},
+    sql_types::{BigInt, Jsonb, Text},
+};
+use myc_core::domain::{
+    dtos::backup::BackupBackend,
+    utils::{BackupRowWrite, BackupStore, WrittenRows, BACKUP_TABLES},
+};
+use mycelium_base::utils::errors::{execution_err, fetching_err, MappedErrors};
+use serde_json::{Map, Value};
+
+/// Tables whose rows reference other rows of the same table, with the
+/// referencing column. Their rows are read parents first.
+const SELF_REFERENCING_TABLES: &[(&str, &str)] = &[("tenant", "parent_id")];
+
+#[derive(QueryableByName)]
+struct TableName {
+    #[diesel(sql_type = Text)]
+    name: String,
+}
+
+#[derive(QueryableByName)]
+struct RowCount {
+    #[diesel(sql_type = BigInt)]
+    count: i64,
+}
+
+#[derive(QueryableByName)]
+struct JsonRow {
+    #[diesel(sql_type = Jsonb)]
+    row: Value,
+}
+
+/// Raw row access to the Postgres database, for `myc-cli backup` and
+/// `myc-cli restore`
+///
+/// Rows are read with `to_jsonb`, so every value is rendered with its
+/// Postgres type, and written back with `jsonb_populate_recordset`.
+pub struct PostgresBackupStore {
+    conn: PooledConnection<ConnectionManager<PgConnection>>,
+}
+
+impl PostgresBackupStore {
+    pub fn new(pool: &DbPool) -> Result<Self, MappedErrors> {
+        let mut conn = acquire_conn(pool)?;
+
+        //
+        // Timestamps are archived as rendered in the session time zone.
+        //
+        diesel::sql_query("SET TIME ZONE 'UTC'")
+            .execute(&mut conn)
+            .map_err(|err| {
+                execution_err(format!("Failed to set the time zone: {err}"))
+            })?;
+
+        Ok(Self { conn })
+    }
+
+    fn primary_key(
+        &mut self,
+        table: &str,
+    ) -> Result<Vec<String>, MappedErrors> {
+        let columns: Vec<TableName> = diesel::sql_query(
+            "SELECT a.attname::text AS name \
+             FROM pg_index i \
+             JOIN pg_attribute a \
+               ON a.attrelid = i.indrelid AND a.attnum = ANY(i.indkey) \
+             WHERE i.indrelid = $1::regclass AND i.indisprimary \
+             ORDER BY array_position(i.indkey::int2[], a.attnum)",
+        )
+        .bind::<Text, _>(quote_identifier(table))
+        .load(&mut *self.conn)
+        .map_err(|err| {
+            fetching_err(format!(
+                "Failed to read the primary key of {table}: {err}"
+            ))
+        })?;
+
+        Ok(columns.into_iter().map(|column| column.name).collect())
+    }
+
+    /// Insert `rows` in a single statement, returning the inserted count
+    fn insert(
+        &mut self,
+        table: &str,
+        rows: &[Map<String, Value>],
+        conflict: &str,
+    ) -> Result<u64, DieselError> {
+        let columns = rows
+            .first()
+            .map(|row| {
+                row.keys()
+                    .map(|name| quote_identifier(name))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .unwrap_or_default();
+
+        let quoted_table = quote_identifier(table);
+
+        let rows =
+            Value::Array(rows.iter().cloned().map(Value::Object).collect());
+
+        diesel::sql_query(format!(
+            "INSERT INTO {quoted_table} ({columns}) \
+             SELECT {columns} \
+             FROM jsonb_populate_recordset(NULL::{quoted_table}, $1) \
+             {conflict}"
+        ))
+        .bind::<Jsonb, _>(&rows)
+        .execute(&mut *self.conn)
+        .map(|count| count as u64)
+    }
+}
+
+impl BackupStore for PostgresBackupStore {
+    fn backend(&self) -> BackupBackend {
+        BackupBackend::Postgres
+    }
+
+    fn tables(&mut self) -> Result<Vec<String>, MappedErrors> {
+        let tables: Vec<TableName> = diesel::sql_query(
+            "SELECT table_name::text AS name \
+             FROM information_schema.tables \
+             WHERE table_schema = 'public' \
+               AND table_type = 'BASE TABLE' \
+               AND table_name != '__diesel_schema_migrations' \
+             ORDER BY table_name",
+        )
+        .load(&mut *self.conn)
+        .map_err(|err| {
+            fetching_err(format!("Failed to list the tables: {err}"))
+        })?;
+
+        Ok(tables.into_iter().map(|table| table.name).collect())
+    }
+
+    fn count_rows(
+        &mut self,
+        table: &str,
+        filter: Option<&str>,
+    ) -> Result<u64, MappedErrors> {
+        let count: RowCount = diesel::sql_query(format!(
+            "SELECT COUNT(*) AS count FROM {} WHERE {}",
+            quote_identifier(table),
+            filter.unwrap_or("true")
+        ))
+        .get_result(&mut *self.conn)
+        .map_err(|err| {
+            fetching_err(format!("Failed to count the rows of {table}: {err}"))
+        })?;
+
+        Ok(count.count as u64)
+    }
+
+    fn read_rows(
+        &mut self,
+        table: &str,
+        filter: Option<&str>,
+        offset: u64,
+        limit: u64,
+    ) -> Result<Vec<Map<String, Value>>, MappedErrors> {
+        let quoted_table = quote_identifier(table);
+        let filter = filter.unwrap_or("true");
+
+        let order = match self.primary_key(table)? {
+            keys if keys.is_empty() => "copied.ctid".to_string(),
+            keys => keys
+                .iter()
+                .map(|key| format!("copied.{}", quote_identifier(key)))
+                .collect::<Vec<_>>()
+                .join(", "),
+        };
+
+        let query = match SELF_REFERENCING_TABLES
+            .iter()
+            .find(|(name, _)| *name == table)
+        {
+            //
+            // Depth of each row in its hierarchy, roots first. Rows whose
+            // parent is missing are roots too, so none is left out.
+            //
+            Some((_, parent)) => {
+                let parent = quote_identifier(parent);
+
+                format!(
+                    "WITH RECURSIVE depth(node, level) AS ( \
+                         SELECT child.id, 0 FROM {quoted_table} child \
+                         WHERE child.{parent} IS NULL OR NOT EXISTS ( \
+                             SELECT 1 FROM {quoted_table} up \
+                             WHERE up.id = child.{parent}) \
+                         UNION ALL \
+                         SELECT child.id, depth.level + 1 \
+                         FROM {quoted_table} child \
+                         JOIN depth ON child.{parent} = depth.node \
+                     ) \
+                     SELECT to_jsonb(copied) AS row \
+                     FROM {quoted_table} copied \
+                     JOIN depth ON depth.node = copied.id \
+                     WHERE {filter} \
+                     ORDER BY depth.level, {order} \
+                     LIMIT $1 OFFSET $2"
+                )
+            }
+            None => format!(
+                "SELECT to_jsonb(copied) AS row FROM {quoted_table} copied \
+                 WHERE {filter} ORDER BY {order} LIMIT $1 OFFSET $2"
+            ),
+        };
+
+        let rows: Vec<JsonRow> = diesel::sql_query(query)
+            .bind::<BigInt, _>(limit as i64)
+            .bind::<BigInt, _>(offset as i64)
+            .load(&mut *self.conn)
+            .map_err(|err| {
+                fetching_err(format!(
+                    "Failed to read the rows of {table}: {err}"
+                ))
+            })?;
+
+        rows.into_iter()
+            .map(|row| match row.row {
+                Value::Object(row) => Ok(row),
+                _ => fetching_err(format!(
+                    "A row of {table} was not read as an object"
+                ))
+                .as_error(),
+            })
+            .collect()
+    }
+
+    fn delete_rows(
+        &mut self,
+        table: &str,
+        filter: &str,
+    ) -> Result<u64, MappedErrors> {
+        diesel::sql_query(format!(
+            "DELETE FROM {} WHERE {filter}",
+            quote_identifier(table)
+        ))
+        .execute(&mut *self.conn)
+        .map(|count| count as u64)
+        .map_err(|err| {
+            execution_err(format!(
+                "Failed to delete the rows of {table}: {err}"
+            ))
+        })
+    }
+
+    fn write_rows(
+        &mut self,
+        table: &str,
+        mut rows: Vec<Map<String, Value>>,
+        source: BackupBackend,
+        write: BackupRowWrite,
+        skip_dangling: bool,
+    ) -> Result<WrittenRows, MappedErrors> {
+        let columns = load_target_columns(&mut self.conn, table)?;
+
+        for row in rows.iter_mut() {
+            match source {
+                //
+                // Rows of another backend are converted to the Postgres
+                // column types.
+                //
+                BackupBackend::Sqlite => convert_row(table, row, &columns)?,
+                BackupBackend::Postgres => {
+                    if let Some(name) =
+                        row.keys().find(|name| !columns.contains_key(*name))
+                    {
+                        return execution_err(format!(
+                            "Column {table}.{name} does not exist in the \
+                             target database"
+                        ))
+                        .as_error();
+                    }
+                }
+            }
+        }
+
+        let conflict = match write {
+            BackupRowWrite::Insert => String::new(),
+            BackupRowWrite::InsertMissing => {
+                "ON CONFLICT DO NOTHING".to_string()
+            }
+            BackupRowWrite::Upsert => {
+                let keys = self.primary_key(table)?;
+
+                let updates = rows
+                    .first()
+                    .map(|row| {
+                        row.keys()
+                            .filter(|name| !keys.contains(*name))
+                            .map(|name| {
+                                let name = quote_identifier(name);
+                                format!("{name} = EXCLUDED.{name}")
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                    .unwrap_or_default();
+
+                match updates.is_empty() {
+                    true => "ON CONFLICT DO NOTHING".to_string(),
+                    false => format!(
+                        "ON CONFLICT ({}) DO UPDATE SET {}",
+                        keys.iter()
+                            .map(|key| quote_identifier(key))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                        updates.join(", ")
+                    ),
+                }
+            }
+        };
+
+        let failed = |err: DieselError| {
+            execution_err(format!("Failed to write the rows of {table}: {err}"))
+        };
+
+        if !skip_dangling {
+            return Ok(WrittenRows {
+                written: self
+                    .insert(table, &rows, &conflict)
+                    .map_err(failed)?,
+                skipped: 0,
+            });
+        }
+
+        //
+        // Row by row, each in its own savepoint, so a row referencing a
+        // missing row is dropped without aborting the transaction.
+        //
+        let mut written = WrittenRows::default();
+
+        for row in rows {
+            let inserted =
+                AnsiTransactionManager::begin_transaction(&mut *self.conn)
+                    .and_then(|_| self.insert(table, &[row], &conflict));
+
+            match inserted {
+                Ok(count) => {
+                    AnsiTransactionManager::commit_transaction(&mut *self.conn)
+                        .map_err(failed)?;
+
+                    written.written += count;
+                }
+                Err(DieselError::DatabaseError(
+                    DatabaseErrorKind::ForeignKeyViolation,
+                    _,
+                )) => {
+                    AnsiTransactionManager::rollback_transaction(
+                        &mut *self.conn,
+                    )
+                    .map_err(failed)?;
+
+                    written.skipped += 1;
+                }
+                Err(err) => {
+                    let _ = AnsiTransactionManager::rollback_transaction(
+                        &mut *self.conn,
+                    );
+
+                    return Err(failed(err));
+                }
+            }
+        }
+
+        Ok(written)
+    }
+
+    fn begin(&mut self) -> Result<(), MappedErrors> {
+        AnsiTransactionManager::begin_transaction_sql(
+            &mut *self.conn,
+            "BEGIN ISOLATION LEVEL REPEATABLE READ",
+        )
+        .map_err(|err| {
+            execution_err(format!("Failed to start a transaction: {err}"))
+        })
+    }
+
+    /// Move the sequences past the restored values, then commit
+    fn commit(&mut self) -> Result<(), MappedErrors> {
+        let tables = BACKUP_TABLES
+            .iter()
+            .map(|table| table.name)
+            .collect::<Vec<_>>();
+
+        reset_sequences(&mut self.conn, &tables)?;
+
+        AnsiTransactionManager::commit_transaction(&mut *self.conn).map_err(
+            |err| execution_err(format!("Failed to commit the restore: {err}")),
+        )
+    }
+
+    fn rollback(&mut self) -> Result<(), MappedErrors> {
+        AnsiTransactionManager::rollback_transaction(&mut *self.conn).map_err(
+            |err| {
+                execution_err(format!("Failed to end the transaction: {err}"))
+            },
+        )
+    }
+}
@@ -0,0 +1,645 @@
+use super::tenant_iteration::acquire_conn;
+use crate::models::config::DbPool;
+
+use diesel::{
+    prelude::*,
+    sql_types::{Array, BigInt, Bool, Jsonb, Text},
+};
+use myc_core::{
+    domain::utils::{rewrap_tenant_dek, TableChecksum},
+    models::AccountLifeCycle,
+};
+use mycelium_base::utils::errors::{execution_err, MappedErrors};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// Tables copied by `migrate_backend`, in the order they are copied
+///
+/// Referenced tables come before the tables referencing them, so foreign keys
+/// hold after every batch. A source table missing from this list stops the
+/// migration instead of being left behind.
+pub const MIGRATED_TABLES: &[&str] = &[
+    "tenant",
+    "tenant_tag",
+    "tenant_email_template",
+    "account",
+    "account_tag",
+    "user",
+    "identity_provider",
+    "owner_on_tenant",
+    "manager_account_on_tenant",
+    "guest_role",
+    "guest_role_children",
+    "guest_user",
+    "guest_user_on_account",
+    "token",
+    "webhook",
+    "webhook_execution",
+    "error_code",
+    "error_code_translation",
+    "instance_settings",
+    "message_queue",
+    "message_delivery",
+    "email_suppression",
+    "scheduled_job",
+    "bulk_import",
+    "scim_credential",
+    "scim_resource",
+    "resource_audit_retention_policy",
+    "resource_audit_log",
+    "resource_audit_checkpoint",
+    "resource_audit_archive",
+];
+
+/// Source tables left behind by `migrate_backend`
+///
+/// The health check history is transient and has no table in the Postgres
+/// schema: the gateway starts a new one.
+pub const SKIPPED_TABLES: &[&str] = &["healthcheck_logs"];
+
+/// Columns rewritten while copied, left out of the checksums
+///
+/// Tenant DEKs are rewrapped with the target KEK, so their ciphertext differs
+/// between the source and the target by design.
+const REWRITTEN_COLUMNS: &[(&str, &str)] = &[("tenant", "encrypted_dek")];
+
+/// Rows read from the backend being migrated
+///
+/// Rows are JSON objects keyed by column name. Values may use the source
+/// encoding of their type (UUIDs and timestamps as strings, JSON and arrays as
+/// JSON text, booleans as integers): they are converted to the Postgres column
+/// types while copied.
+pub trait BackendMigrationSource {
+    /// The data tables of the source
+    fn tables(&mut self) -> Result<Vec<String>, MappedErrors>;
+
+    /// The number of rows of `table`
+    fn count_rows(&mut self, table: &str) -> Result<u64, MappedErrors>;
+
+    /// `limit` rows of `table` after the first `offset` ones, in a stable
+    /// order
+    fn read_rows(
+        &mut self,
+        table: &str,
+        offset: u64,
+        limit: u64,
+    ) -> Result<Vec<Map<String, Value>>, MappedErrors>;
+
+    /// The checksum of the rows of `table` as stored in the source, over
+    /// every column but `excluded_columns`
+    fn checksum_rows(
+        &mut self,
+        table: &str,
+        excluded_columns: &[&str],
+    ) -> Result<TableChecksum, MappedErrors>;
+}
+
+/// Outcome of the copy of a single table.
+pub struct TableMigrationReport {
+    pub table: String,
+    pub rows: u64,
+    pub checksum: String,
+
+    /// Rows already copied by a previous run
+    pub resumed_from: u64,
+}
+
+/// Summary of a `migrate-backend` run.
+pub struct MigrateBackendReport {
+    pub tables: Vec<TableMigrationReport>,
+
+    /// Tenant DEKs rewrapped from the source KEK to the target KEK
+    pub rewrapped_deks: usize,
+}
+
+#[derive(QueryableByName)]
+struct Progress {
+    #[diesel(sql_type = Text)]
+    table_name: String,
+
+    #[diesel(sql_type = BigInt)]
+    source_rows: i64,
+
+    #[diesel(sql_type = BigInt)]
+    copied_rows: i64,
+}
+
+#[derive(QueryableByName)]
+struct TargetColumn {
+    #[diesel(sql_type = Text)]
+    name: String,
+
+    #[diesel(sql_type = Text)]
+    data_type: String,
+}
+
+#[derive(QueryableByName)]
+struct SerialColumn {
+    #[diesel(sql_type = Text)]
+    table_name: String,
+
+    #[diesel(sql_type = Text)]
+    column_name: String,
+}
+
+#[derive(QueryableByName)]
+struct Found {
+    #[diesel(sql_type = Bool)]
+    found: bool,
+}
+
+#[derive(QueryableByName)]
+struct TargetRow {
+    #[diesel(sql_type = Text)]
+    row: String,
+}
+
+/// Copy every table of `source` to the Postgres database behind `pool`
+///
+/// Tables are copied in batches of `batch_size` rows, in `MIGRATED_TABLES`
+/// order. Each batch is written in the same transaction as the progress of
+/// its table (`backend_migration_progress`), so an interrupted run resumes
+/// from the last written batch when started again.
+///
+/// Once a table is copied, on every run, `source` checksums the table as it
+/// stores it and the rows are read back from Postgres to be checksummed the
+/// same way (see `TableChecksum`): the row counts and checksums must match.
+/// The checksums leave out the `REWRITTEN_COLUMNS`, which are checked by
+/// unwrapping them instead.
+///
+/// Tenant DEKs are unwrapped with the KEK of `source_config` and wrapped with
+/// the KEK of `target_config`. No other ciphertext is touched: user data is
+/// encrypted with the DEKs, which do not change.
+///
+/// A first run requires every copied table to be empty in Postgres.
+#[tracing::instrument(name = "migrate_backend", skip_all)]
+pub async fn migrate_backend(
+    pool: &DbPool,
+    source: &mut dyn BackendMigrationSource,
+    source_config: &AccountLifeCycle,
+    target_config: &AccountLifeCycle,
+    batch_size: u64,
+) -> Result<MigrateBackendReport, MappedErrors> {
+    if batch_size == 0 {
+        return execution_err("migrate_backend requires a batch size above 0")
+            .as_error();
+    }
+
+    let source_kek = source_config.derive_kek_bytes().await?;
+    let target_kek = target_config.derive_kek_bytes().await?;
+
+    let conn = &mut acquire_conn(pool)?;
+
+    //
+    // Timestamps without offset are UTC in the source, and the rows read
+    // back render timestamps in the session time zone: both need UTC.
+    //
+    diesel::sql_query("SET TIME ZONE 'UTC'")
+        .execute(conn)
+        .map_err(|err| {
+            execution_err(format!("Failed to set the time zone: {err}"))
+        })?;
+
+    let source_tables = source.tables()?;
+
+    let unknown_tables = source_tables
+        .iter()
+        .filter(|table| {
+            !MIGRATED_TABLES.contains(&table.as_str())
+                && !SKIPPED_TABLES.contains(&table.as_str())
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+
+    if !unknown_tables.is_empty() {
+        return execution_err(format!(
+            "The source has tables this release does not migrate: {}",
+            unknown_tables.join(", ")
+        ))
+        .as_error();
+    }
+
+    let mut progress = load_progress(conn)?;
+
+    if progress.is_empty() {
+        for table in MIGRATED_TABLES {
+            ensure_empty(conn, table)?;
+        }
+    }
+
+    let mut report = MigrateBackendReport {
+        tables: vec![],
+        rewrapped_deks: 0,
+    };
+
+    for table in MIGRATED_TABLES
+        .iter()
+        .filter(|table| source_tables.iter().any(|t| t == *table))
+    {
+        let source_rows = source.count_rows(table)?;
+
+        let mut copied_rows = match progress.remove(*table) {
+            Some(done) => {
+                if done.source_rows as u64 != source_rows {
+                    return execution_err(format!(
+                        "Table {table} changed in the source since the \
+                             migration started ({} rows, now {source_rows})",
+                        done.source_rows
+                    ))
+                    .as_error();
+                }
+
+                done.copied_rows as u64
+            }
+            None => 0,
+        };
+
+        let resumed_from = copied_rows;
+
+        let columns = load_target_columns(conn, table)?;
+
+        while copied_rows < source_rows {
+            let mut rows = source.read_rows(table, copied_rows, batch_size)?;
+
+            if rows.is_empty() {
+                break;
+            }
+
+            for row in rows.iter_mut() {
+                convert_row(table, row, &columns)?;
+
+                if *table == "tenant"
+                    && rewrap_tenant_dek(row, &source_kek, &target_kek)?
+                {
+                    report.rewrapped_deks += 1;
+                }
+            }
+
+            copied_rows +=
+                copy_batch(conn, table, rows, source_rows, copied_rows)?;
+        }
+
+        let excluded_columns = REWRITTEN_COLUMNS
+            .iter()
+            .filter(|(rewritten_table, _)| rewritten_table == table)
+            .map(|(_, column)| *column)
+            .collect::<Vec<_>>();
+
+        let source_checksum = source.checksum_rows(table, &excluded_columns)?;
+
+        if source_checksum.rows != source_rows {
+            return execution_err(format!(
+                "Table {table} changed in the source during the migration \
+                 ({source_rows} rows, now {})",
+                source_checksum.rows
+            ))
+            .as_error();
+        }
+
+        verify_table(conn, table, &source_checksum, batch_size)?;
+
+        report.tables.push(TableMigrationReport {
+            table: table.to_string(),
+            rows: source_rows,
+            checksum: source_checksum.to_hex(),
+            resumed_from,
+        });
+    }
+
+    reset_sequences(conn, MIGRATED_TABLES)?;
+
+    Ok(report)
+}
+
+pub(crate) fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+fn load_progress(
+    conn: &mut PgConnection,
+) -> Result<HashMap<String, Progress>, MappedErrors> {
+    let rows: Vec<Progress> = diesel::sql_query(
+        "SELECT table_name::text AS table_name, source_rows, copied_rows \
+         FROM backend_migration_progress",
+    )
+    .load(conn)
+    .map_err(|err| {
+        execution_err(format!("Failed to load the migration progress: {err}"))
+    })?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| (row.table_name.to_owned(), row))
+        .collect())
+}
+
+fn ensure_empty(
+    conn: &mut PgConnection,
+    table: &str,
+) -> Result<(), MappedErrors> {
+    let found: Found = diesel::sql_query(format!(
+        "SELECT EXISTS (SELECT 1 FROM {}) AS found",
+        quote_identifier(table)
+    ))
+    .get_result(conn)
+    .map_err(|err| {
+        execution_err(format!("Failed to inspect table {table}: {err}"))
+    })?;
+
+    if found.found {
+        return execution_err(format!(
+            "Table {table} already has rows in the target database: \
+             migrate to a freshly installed database"
+        ))
+        .as_error();
+    }
+
+    Ok(())
+}
+
+pub(crate) fn load_target_columns(
+    conn: &mut PgConnection,
+    table: &str,
+) -> Result<HashMap<String, String>, MappedErrors> {
+    let columns: Vec<TargetColumn> = diesel::sql_query(
+        "SELECT column_name::text AS name, data_type::text AS data_type \
+         FROM information_schema.columns \
+         WHERE table_schema = 'public' AND table_name = $1",
+    )
+    .bind::<Text, _>(table)
+    .load(conn)
+    .map_err(|err| {
+        execution_err(format!("Failed to read the columns of {table}: {err}"))
+    })?;
+
+    if columns.is_empty() {
+        return execution_err(format!(
+            "Table {table} does not exist in the target database"
+        ))
+        .as_error();
+    }
+
+    Ok(columns
+        .into_iter()
+        .map(|column| (column.name, column.data_type))
+        .collect())
+}
+
+/// Convert the values of a source row to the types of the target columns
+///
+/// JSON and array columns are stored as JSON text by the source, and are
+/// parsed so Postgres receives the structured value. Booleans stored as
+/// integers become booleans. Other values are left to the input functions of
+/// their Postgres types.
+pub(crate) fn convert_row(
+    table: &str,
+    row: &mut Map<String, Value>,
+    columns: &HashMap<String, String>,
+) -> Result<(), MappedErrors> {
+    for (name, value) in row.iter_mut() {
+        let Some(data_type) = columns.get(name) else {
+            return execution_err(format!(
+                "Column {table}.{name} does not exist in the target database"
+            ))
+            .as_error();
+        };
+
+        match (data_type.as_str(), &value) {
+            ("json" | "jsonb" | "ARRAY", Value::String(text)) => {
+                if let Ok(parsed) = serde_json::from_str::<Value>(text) {
+                    *value = parsed;
+                }
+            }
+            ("boolean", Value::Number(number)) => {
+                *value = Value::Bool(number.as_i64() != Some(0));
+            }
+            _ => (),
+        }
+    }
+
+    Ok(())
+}
+
+/// Write a batch of rows together with the progress of its table, returning
+/// the number of rows written
+fn copy_batch(
+    conn: &mut PgConnection,
+    table: &str,
+    rows: Vec<Map<String, Value>>,
+    source_rows: u64,
+    copied_rows: u64,
+) -> Result<u64, MappedErrors> {
+    let columns = rows
+        .first()
+        .map(|row| {
+            row.keys()
+                .map(|name| quote_identifier(name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+        .unwrap_or_default();
+
+    let quoted_table = quote_identifier(table);
+
+    let insert = format!(
+        "INSERT INTO {quoted_table} ({columns}) \
+         SELECT {columns} \
+         FROM jsonb_populate_recordset(NULL::{quoted_table}, $1)"
+    );
+
+    let rows = Value::Array(rows.into_iter().map(Value::Object).collect());
+
+    conn.transaction(|conn| {
+        let count = diesel::sql_query(&insert)
+            .bind::<Jsonb, _>(&rows)
+            .execute(conn)? as u64;
+
+        diesel::sql_query(
+            "INSERT INTO backend_migration_progress \
+                 (table_name, source_rows, copied_rows) \
+             VALUES ($1, $2, $3) \
+             ON CONFLICT (table_name) DO UPDATE SET \
+                 copied_rows = EXCLUDED.copied_rows, \
+                 updated_at = now()",
+        )
+        .bind::<Text, _>(table)
+        .bind::<BigInt, _>(source_rows as i64)
+        .bind::<BigInt, _>((copied_rows + count) as i64)
+        .execute(conn)?;
+
+        Ok(count)
+    })
+    .map_err(|err: diesel::result::Error| {
+        execution_err(format!(
+            "Failed to copy rows {copied_rows}.. of {table}: {err}"
+        ))
+    })
+}
+
+/// Read the rows of `table` back from Postgres, check them against the
+/// checksum of the source rows, and record the table as completed
+fn verify_table(
+    conn: &mut PgConnection,
+    table: &str,
+    source_checksum: &TableChecksum,
+    batch_size: u64,
+) -> Result<(), MappedErrors> {
+    let mut target_checksum =
+        TableChecksum::new(source_checksum.columns.to_owned());
+
+    let select = format!(
+        "SELECT to_jsonb(copied)::text AS row FROM {} AS copied \
+         ORDER BY ctid LIMIT $1 OFFSET $2",
+        quote_identifier(table)
+    );
+
+    loop {
+        let rows: Vec<TargetRow> = diesel::sql_query(&select)
+            .bind::<BigInt, _>(batch_size as i64)
+            .bind::<BigInt, _>(target_checksum.rows as i64)
+            .load(conn)
+            .map_err(|err| {
+                execution_err(format!("Failed to read back {table}: {err}"))
+            })?;
+
+        for row in rows.iter() {
+            let row = serde_json::from_str::<Map<String, Value>>(&row.row)
+                .map_err(|err| {
+                    execution_err(format!(
+                        "Failed to parse a row of {table}: {err}"
+                    ))
+                })?;
+
+            target_checksum.add_row(&row);
+        }
+
+        if (rows.len() as u64) < batch_size {
+            break;
+        }
+    }
+
+    if target_checksum.rows != source_checksum.rows {
+        return execution_err(format!(
+            "Table {table} has {} rows in the target database, the source \
+             has {}",
+            target_checksum.rows, source_checksum.rows
+        ))
+        .as_error();
+    }
+
+    if target_checksum.checksum != source_checksum.checksum {
+        return execution_err(format!(
+            "Table {table} in the target database does not match its \
+             checksum in the source"
+        ))
+        .as_error();
+    }
+
+    diesel::sql_query(
+        "INSERT INTO backend_migration_progress \
+             (table_name, source_rows, copied_rows, checksum, completed_at) \
+         VALUES ($1, $2, $2, $3, now()) \
+         ON CONFLICT (table_name) DO UPDATE SET \
+             checksum = EXCLUDED.checksum, \
+             completed_at = now(), \
+             updated_at = now()",
+    )
+    .bind::<Text, _>(table)
+    .bind::<BigInt, _>(source_checksum.rows as i64)
+    .bind::<Text, _>(source_checksum.to_hex())
+    .execute(conn)
+    .map_err(|err| {
+        execution_err(format!("Failed to record table {table}: {err}"))
+    })?;
+
+    Ok(())
+}
+
+/// Move the sequences of the copied serial columns past the copied values
+pub(crate) fn reset_sequences(
+    conn: &mut PgConnection,
+    tables: &[&str],
+) -> Result<(), MappedErrors> {
+    let tables = tables
+        .iter()
+        .map(|table| table.to_string())
+        .collect::<Vec<_>>();
+
+    let serials: Vec<SerialColumn> = diesel::sql_query(
+        "SELECT table_name::text AS table_name, \
+                column_name::text AS column_name \
+         FROM information_schema.columns \
+         WHERE table_schema = 'public' \
+           AND table_name = ANY($1) \
+           AND column_default LIKE 'nextval(%'",
+    )
+    .bind::<Array<Text>, _>(&tables)
+    .load(conn)
+    .map_err(|err| {
+        execution_err(format!("Failed to list serial columns: {err}"))
+    })?;
+
+    for serial in serials {
+        let table = quote_identifier(&serial.table_name);
+        let column = quote_identifier(&serial.column_name);
+
+        diesel::sql_query(format!(
+            "SELECT setval(pg_get_serial_sequence($1, $2), \
+                 COALESCE((SELECT MAX({column}) FROM {table}), 0) + 1, false)"
+        ))
+        .bind::<Text, _>(&table)
+        .bind::<Text, _>(&serial.column_name)
+        .execute(conn)
+        .map_err(|err| {
+            execution_err(format!(
+                "Failed to reset the sequence of {}.{}: {err}",
+                serial.table_name, serial.column_name
+            ))
+        })?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn source_values_are_converted_to_the_target_types() {
+        let columns = HashMap::from([
+            ("meta".to_string(), "jsonb".to_string()),
+            ("status".to_string(), "ARRAY".to_string()),
+            ("is_active".to_string(), "boolean".to_string()),
+            ("name".to_string(), "character varying".to_string()),
+        ]);
+
+        let mut row = json!({
+            "meta": "{\"key\":\"value\"}",
+            "status": "[{\"active\":true}]",
+            "is_active": 0,
+            "name": "{\"not\":\"json\"}",
+        })
+        .as_object()
+        .unwrap()
+        .to_owned();
+
+        convert_row("tenant", &mut row, &columns).unwrap();
+
+        assert_eq!(
+            Value::Object(row),
+            json!({
+                "meta": { "key": "value" },
+                "status": [{ "active": true }],
+                "is_active": false,
+                "name": "{\"not\":\"json\"}",
+            })
+        );
+
+        let mut unknown =
+            json!({ "dropped": 1 }).as_object().unwrap().to_owned();
+
+        assert!(convert_row("tenant", &mut unknown, &columns).is_err());
+    }
+}
@@ -1,7 +1,14 @@
+mod backup_store;
+mod migrate_backend;
 mod migrate_dek;
 mod rotate_kek;
 mod tenant_iteration;
 
+pub use backup_store::PostgresBackupStore;
+pub use migrate_backend::{
+    migrate_backend, BackendMigrationSource, MigrateBackendReport,
+    TableMigrationReport, MIGRATED_TABLES, SKIPPED_TABLES,
+};
 pub use migrate_dek::{migrate_dek, MigrateDekReport};
 pub use rotate_kek::{rotate_kek, RotateKekReport};
 pub use tenant_iteration::{RowOutcome, Summary};
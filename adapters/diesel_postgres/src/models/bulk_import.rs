@@ -0,0 +1,21 @@
+use chrono::{DateTime, Local};
+use diesel::prelude::*;
+use serde_json::Value as JsonValue;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Queryable, Insertable, Selectable)]
+#[diesel(table_name = crate::schema::bulk_import)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub(crate) struct BulkImport {
+    pub id: Uuid,
+    pub tenant_id: Uuid,
+    pub checksum: String,
+    pub status: String,
+    pub import_rows: JsonValue,
+    pub row_results: JsonValue,
+    pub created_by: JsonValue,
+    pub created: DateTime<Local>,
+    pub claimed_by: Option<String>,
+    pub started_at: Option<DateTime<Local>>,
+    pub finished_at: Option<DateTime<Local>>,
+}
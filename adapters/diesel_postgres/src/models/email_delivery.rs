@@ -0,0 +1,26 @@
+use chrono::{DateTime, Local};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Queryable, Insertable, Selectable)]
+#[diesel(table_name = crate::schema::message_delivery)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub(crate) struct MessageDelivery {
+    pub id: Uuid,
+    pub recipient: String,
+    pub subject: String,
+    pub status: String,
+    pub detail: Option<String>,
+    pub created: DateTime<Local>,
+    pub updated: Option<DateTime<Local>>,
+}
+
+#[derive(Clone, Debug, Queryable, Insertable, Selectable)]
+#[diesel(table_name = crate::schema::email_suppression)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub(crate) struct EmailSuppression {
+    pub email: String,
+    pub reason: String,
+    pub detail: Option<String>,
+    pub created: DateTime<Local>,
+}
@@ -13,6 +13,8 @@ pub struct GuestRole {
     pub permission: i32,
     pub slug: String,
     pub system: bool,
+    pub tenant_id: Option<Uuid>,
     pub created: NaiveDateTime,
     pub updated: Option<NaiveDateTime>,
+    pub permissions: Option<Vec<String>>,
 }
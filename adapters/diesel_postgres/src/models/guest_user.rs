@@ -1,6 +1,7 @@
 use super::guest_role::GuestRole;
 use chrono::{DateTime, Local};
 use diesel::prelude::*;
+use serde_json::Value as JsonValue;
 use uuid::Uuid;
 
 #[derive(
@@ -18,4 +19,7 @@ pub(crate) struct GuestUser {
     pub created: DateTime<Local>,
     pub updated: Option<DateTime<Local>>,
     pub was_verified: bool,
+    pub invited_by: Option<JsonValue>,
+    pub invitation_sent_at: Option<DateTime<Local>>,
+    pub invitation_expires_at: Option<DateTime<Local>>,
 }
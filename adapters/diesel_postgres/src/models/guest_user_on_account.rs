@@ -1,7 +1,7 @@
 use super::account::Account;
 use super::guest_user::GuestUser;
 
-use chrono::NaiveDateTime;
+use chrono::{DateTime, Local, NaiveDateTime};
 use diesel::prelude::*;
 use uuid::Uuid;
 
@@ -22,4 +22,8 @@ pub struct GuestUserOnAccount {
     pub permit_flags: Option<Vec<String>>,
     #[diesel(sql_type = Nullable<Array<Text>>)]
     pub deny_flags: Option<Vec<String>>,
+    #[diesel(sql_type = Nullable<diesel::sql_types::Timestamptz>)]
+    pub valid_from: Option<DateTime<Local>>,
+    #[diesel(sql_type = Nullable<diesel::sql_types::Timestamptz>)]
+    pub valid_until: Option<DateTime<Local>>,
 }
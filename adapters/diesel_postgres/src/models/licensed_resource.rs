@@ -1,6 +1,7 @@
+use chrono::{DateTime, Utc};
 use diesel::{
     prelude::*,
-    sql_types::{Array, Bool, Integer, Nullable, Text},
+    sql_types::{Array, Bool, Integer, Nullable, Text, Timestamptz},
 };
 use uuid::Uuid;
 
@@ -26,4 +27,8 @@ pub(crate) struct LicensedResourceRow {
     pub permit_flags: Option<Vec<String>>,
     #[diesel(sql_type = Nullable<Array<Text>>)]
     pub deny_flags: Option<Vec<String>>,
+    #[diesel(sql_type = Nullable<Array<Text>>)]
+    pub gr_permissions: Option<Vec<String>>,
+    #[diesel(sql_type = Nullable<Timestamptz>)]
+    pub valid_until: Option<DateTime<Utc>>,
 }
@@ -1,5 +1,7 @@
 pub(crate) mod account;
 pub(crate) mod account_tag;
+pub(crate) mod bulk_import;
+pub(crate) mod email_delivery;
 pub(crate) mod error_code;
 pub(crate) mod guest_role;
 pub(crate) mod guest_role_children;
@@ -13,7 +15,11 @@ pub(crate) mod message;
 pub(crate) mod owner_on_tenant;
 pub(crate) mod public_connection_string_info;
 pub(crate) mod resource_audit_log;
+pub(crate) mod scheduled_job;
+pub(crate) mod scim_credential;
+pub(crate) mod scim_resource;
 pub(crate) mod tenant;
+pub(crate) mod tenant_email_template;
 pub(crate) mod tenant_tag;
 pub(crate) mod token;
 pub(crate) mod user;
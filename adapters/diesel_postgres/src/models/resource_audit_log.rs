@@ -3,7 +3,7 @@ use diesel::prelude::*;
 use serde_json::Value as JsonValue;
 use uuid::Uuid;
 
-#[derive(Queryable, Selectable)]
+#[derive(Queryable, Selectable, Insertable)]
 #[diesel(table_name = crate::schema::resource_audit_log)]
 #[diesel(check_for_backend(diesel::pg::Pg))]
 pub(crate) struct ResourceAuditLog {
@@ -15,6 +15,10 @@ pub(crate) struct ResourceAuditLog {
     pub performed_by: JsonValue,
     pub metadata: JsonValue,
     pub created_at: NaiveDateTime,
+    pub chain_seq: Option<i64>,
+    pub prev_hash: Option<String>,
+    pub row_hash: Option<String>,
+    pub redacted_at: Option<NaiveDateTime>,
 }
 
 #[derive(Insertable)]
@@ -27,4 +31,48 @@ pub(crate) struct NewResourceAuditLog {
     pub performed_by: JsonValue,
     pub metadata: JsonValue,
     pub created_at: NaiveDateTime,
+    pub chain_seq: Option<i64>,
+    pub prev_hash: Option<String>,
+    pub row_hash: Option<String>,
+}
+
+#[derive(Queryable, Selectable, Insertable)]
+#[diesel(table_name = crate::schema::resource_audit_checkpoint)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub(crate) struct ResourceAuditCheckpoint {
+    pub id: Uuid,
+    pub chain_tenant_id: Uuid,
+    pub chain_seq: i64,
+    pub root_hash: String,
+    pub signature: String,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Queryable, Selectable, Insertable)]
+#[diesel(table_name = crate::schema::resource_audit_archive)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub(crate) struct ResourceAuditArchive {
+    pub id: Uuid,
+    pub chain_tenant_id: Uuid,
+    pub first_seq: Option<i64>,
+    pub last_seq: Option<i64>,
+    pub first_prev_hash: Option<String>,
+    pub last_hash: Option<String>,
+    pub cutoff: NaiveDateTime,
+    pub row_count: i64,
+    pub location: String,
+    pub sha256: String,
+    pub signature: String,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Queryable, Selectable, Insertable, AsChangeset)]
+#[diesel(table_name = crate::schema::resource_audit_retention_policy)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(treat_none_as_null = true)]
+pub(crate) struct ResourceAuditRetentionPolicy {
+    pub chain_tenant_id: Uuid,
+    pub retention_days: Option<i32>,
+    pub legal_hold: bool,
+    pub updated_at: NaiveDateTime,
 }
@@ -0,0 +1,12 @@
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+
+#[derive(Clone, Debug, Queryable, Insertable, Selectable)]
+#[diesel(table_name = crate::schema::scheduled_job)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub(crate) struct ScheduledJob {
+    pub name: String,
+    pub last_run_at: Option<DateTime<Utc>>,
+    pub next_run_at: DateTime<Utc>,
+    pub claimed_by: Option<String>,
+}
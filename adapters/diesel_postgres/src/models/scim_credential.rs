@@ -0,0 +1,16 @@
+use chrono::{DateTime, Local};
+use diesel::prelude::*;
+use serde_json::Value as JsonValue;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Queryable, Insertable, Selectable)]
+#[diesel(table_name = crate::schema::scim_credential)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub(crate) struct ScimCredential {
+    pub id: Uuid,
+    pub tenant_id: Uuid,
+    pub name: String,
+    pub secret_hash: String,
+    pub created_by: JsonValue,
+    pub created: DateTime<Local>,
+}
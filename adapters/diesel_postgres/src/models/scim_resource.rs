@@ -0,0 +1,21 @@
+use chrono::{DateTime, Local};
+use diesel::prelude::*;
+use serde_json::Value as JsonValue;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Queryable, Insertable, Selectable)]
+#[diesel(table_name = crate::schema::scim_resource)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub(crate) struct ScimResource {
+    pub id: Uuid,
+    pub tenant_id: Uuid,
+    pub kind: String,
+    pub external_id: Option<String>,
+    pub display_name: String,
+    pub account_id: Option<Uuid>,
+    pub guest_role_id: Option<Uuid>,
+    pub provisioned: bool,
+    pub attributes: JsonValue,
+    pub created: DateTime<Local>,
+    pub updated: Option<DateTime<Local>>,
+}
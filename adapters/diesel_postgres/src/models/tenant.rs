@@ -3,7 +3,15 @@ use diesel::prelude::*;
 use serde_json::Value as JsonValue;
 use uuid::Uuid;
 
-#[derive(Identifiable, Clone, Debug, Queryable, Insertable, Selectable)]
+#[derive(
+    Identifiable,
+    Clone,
+    Debug,
+    Queryable,
+    QueryableByName,
+    Insertable,
+    Selectable,
+)]
 #[diesel(table_name = crate::schema::tenant)]
 #[diesel(check_for_backend(diesel::pg::Pg))]
 pub(crate) struct Tenant {
@@ -17,4 +25,5 @@ pub(crate) struct Tenant {
     pub updated: Option<NaiveDateTime>,
     pub encrypted_dek: Option<String>,
     pub kek_version: i32,
+    pub parent_id: Option<Uuid>,
 }
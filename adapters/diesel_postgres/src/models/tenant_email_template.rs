@@ -0,0 +1,24 @@
+use super::tenant::Tenant;
+
+use chrono::{DateTime, Local};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+#[derive(
+    Identifiable, Associations, Clone, Debug, Queryable, Insertable, Selectable,
+)]
+#[diesel(table_name = crate::schema::tenant_email_template)]
+#[diesel(belongs_to(Tenant))]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub(crate) struct TenantEmailTemplate {
+    #[diesel(sql_type = diesel::sql_types::Uuid)]
+    pub id: Uuid,
+    #[diesel(sql_type = diesel::sql_types::Uuid)]
+    pub tenant_id: Uuid,
+    pub kind: String,
+    pub locale: String,
+    pub subject: String,
+    pub body: String,
+    pub created: DateTime<Local>,
+    pub updated: Option<DateTime<Local>>,
+}
@@ -0,0 +1,96 @@
+use super::shared::map_model_to_dto;
+use crate::{
+    models::{
+        bulk_import::BulkImport as BulkImportModel, config::DbPoolProvider,
+    },
+    schema::bulk_import as bulk_import_model,
+};
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use myc_core::domain::{
+    dtos::{bulk_import::BulkImport, native_error_codes::NativeErrorCodes},
+    entities::BulkImportRegistration,
+};
+use mycelium_base::{
+    entities::GetOrCreateResponseKind,
+    utils::errors::{creation_err, MappedErrors},
+};
+use serde_json::to_value;
+use shaku::Component;
+use std::sync::Arc;
+
+#[derive(Component)]
+#[shaku(interface = BulkImportRegistration)]
+pub struct BulkImportRegistrationSqlDbRepository {
+    #[shaku(inject)]
+    pub db_config: Arc<dyn DbPoolProvider>,
+}
+
+#[async_trait]
+impl BulkImportRegistration for BulkImportRegistrationSqlDbRepository {
+    #[tracing::instrument(name = "get_or_create_bulk_import", skip_all)]
+    async fn get_or_create(
+        &self,
+        bulk_import: BulkImport,
+    ) -> Result<GetOrCreateResponseKind<BulkImport>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            creation_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let to_json = |value: serde_json::Result<serde_json::Value>| {
+            value.map_err(|e| {
+                creation_err(format!("Failed to serialize bulk import: {e}"))
+            })
+        };
+
+        let model = BulkImportModel {
+            id: bulk_import.id,
+            tenant_id: bulk_import.tenant_id,
+            checksum: bulk_import.checksum.to_owned(),
+            status: bulk_import.status.to_string(),
+            import_rows: to_json(to_value(&bulk_import.rows))?,
+            row_results: to_json(to_value(&bulk_import.results))?,
+            created_by: to_json(to_value(&bulk_import.created_by))?,
+            created: bulk_import.created_at,
+            claimed_by: bulk_import.claimed_by.to_owned(),
+            started_at: bulk_import.started_at,
+            finished_at: bulk_import.finished_at,
+        };
+
+        //
+        // The checksum is unique per tenant, so a job already registered
+        // with the same rows is not registered again.
+        //
+        let inserted = diesel::insert_into(bulk_import_model::table)
+            .values(&model)
+            .on_conflict((
+                bulk_import_model::tenant_id,
+                bulk_import_model::checksum,
+            ))
+            .do_nothing()
+            .execute(conn)
+            .map_err(|e| {
+                creation_err(format!("Failed to register bulk import: {e}"))
+            })?;
+
+        if inserted > 0 {
+            return Ok(GetOrCreateResponseKind::Created(bulk_import));
+        }
+
+        let existing = bulk_import_model::table
+            .filter(bulk_import_model::tenant_id.eq(bulk_import.tenant_id))
+            .filter(bulk_import_model::checksum.eq(bulk_import.checksum))
+            .select(BulkImportModel::as_select())
+            .first::<BulkImportModel>(conn)
+            .map_err(|e| {
+                creation_err(format!("Failed to fetch bulk import: {e}"))
+            })?;
+
+        Ok(GetOrCreateResponseKind::NotCreated(
+            map_model_to_dto(existing)?,
+            "The rows were already imported".to_string(),
+        ))
+    }
+}
@@ -0,0 +1,134 @@
+use super::shared::map_model_to_dto;
+use crate::{
+    models::{
+        bulk_import::BulkImport as BulkImportModel, config::DbPoolProvider,
+    },
+    schema::bulk_import as bulk_import_model,
+};
+
+use async_trait::async_trait;
+use chrono::{Duration, Local};
+use diesel::prelude::*;
+use myc_core::domain::{
+    dtos::{
+        bulk_import::{BulkImport, BulkImportRowResult, BulkImportStatus},
+        native_error_codes::NativeErrorCodes,
+    },
+    entities::BulkImportUpdating,
+};
+use mycelium_base::{
+    entities::UpdatingResponseKind,
+    utils::errors::{updating_err, MappedErrors},
+};
+use serde_json::to_value;
+use shaku::Component;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Component)]
+#[shaku(interface = BulkImportUpdating)]
+pub struct BulkImportUpdatingSqlDbRepository {
+    #[shaku(inject)]
+    pub db_config: Arc<dyn DbPoolProvider>,
+}
+
+#[async_trait]
+impl BulkImportUpdating for BulkImportUpdatingSqlDbRepository {
+    #[tracing::instrument(name = "claim_next_bulk_import", skip_all)]
+    async fn claim_next(
+        &self,
+        claimed_by: String,
+        stale_after: Duration,
+    ) -> Result<Option<BulkImport>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            updating_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let now = Local::now();
+
+        let claimable = bulk_import_model::status
+            .eq(BulkImportStatus::Pending.to_string())
+            .or(bulk_import_model::status
+                .eq(BulkImportStatus::Running.to_string())
+                .and(bulk_import_model::started_at.lt(now - stale_after)));
+
+        //
+        // `SKIP LOCKED` lets concurrent dispatchers claim different jobs
+        // instead of waiting for each other.
+        //
+        let claimed = conn
+            .transaction::<Option<BulkImportModel>, diesel::result::Error, _>(
+                |conn| {
+                    let candidate = bulk_import_model::table
+                        .filter(claimable)
+                        .order(bulk_import_model::created.asc())
+                        .select(bulk_import_model::id)
+                        .for_update()
+                        .skip_locked()
+                        .first::<Uuid>(conn)
+                        .optional()?;
+
+                    let id = match candidate {
+                        Some(id) => id,
+                        None => return Ok(None),
+                    };
+
+                    diesel::update(bulk_import_model::table.find(id))
+                        .set((
+                            bulk_import_model::status
+                                .eq(BulkImportStatus::Running.to_string()),
+                            bulk_import_model::claimed_by.eq(claimed_by),
+                            bulk_import_model::started_at.eq(now),
+                        ))
+                        .get_result::<BulkImportModel>(conn)
+                        .map(Some)
+                },
+            )
+            .map_err(|e| {
+                updating_err(format!("Failed to claim bulk import: {e}"))
+            })?;
+
+        claimed.map(map_model_to_dto).transpose()
+    }
+
+    #[tracing::instrument(name = "finish_bulk_import", skip_all)]
+    async fn finish(
+        &self,
+        id: Uuid,
+        status: BulkImportStatus,
+        results: Vec<BulkImportRowResult>,
+    ) -> Result<UpdatingResponseKind<BulkImport>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            updating_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let results = to_value(results).map_err(|e| {
+            updating_err(format!(
+                "Failed to serialize bulk import results: {e}"
+            ))
+        })?;
+
+        let record = diesel::update(bulk_import_model::table.find(id))
+            .set((
+                bulk_import_model::status.eq(status.to_string()),
+                bulk_import_model::row_results.eq(results),
+                bulk_import_model::finished_at.eq(Some(Local::now())),
+            ))
+            .get_result::<BulkImportModel>(conn)
+            .optional()
+            .map_err(|e| {
+                updating_err(format!("Failed to update bulk import: {e}"))
+            })?;
+
+        match record {
+            Some(record) => {
+                Ok(UpdatingResponseKind::Updated(map_model_to_dto(record)?))
+            }
+            None => updating_err(format!("Bulk import not found: {id}"))
+                .with_code(NativeErrorCodes::MYC00013)
+                .as_error(),
+        }
+    }
+}
@@ -0,0 +1,9 @@
+mod shared;
+
+mod bulk_import_fetching;
+mod bulk_import_registration;
+mod bulk_import_updating;
+
+pub(super) use bulk_import_fetching::*;
+pub(super) use bulk_import_registration::*;
+pub(super) use bulk_import_updating::*;
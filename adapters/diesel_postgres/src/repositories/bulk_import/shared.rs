@@ -0,0 +1,30 @@
+use crate::models::bulk_import::BulkImport as BulkImportModel;
+
+use myc_core::domain::dtos::bulk_import::{BulkImport, BulkImportStatus};
+use mycelium_base::utils::errors::{fetching_err, MappedErrors};
+use serde_json::from_value;
+use std::str::FromStr;
+
+pub(super) fn map_model_to_dto(
+    model: BulkImportModel,
+) -> Result<BulkImport, MappedErrors> {
+    Ok(BulkImport {
+        id: model.id,
+        tenant_id: model.tenant_id,
+        checksum: model.checksum,
+        status: BulkImportStatus::from_str(&model.status)
+            .map_err(fetching_err)?,
+        dry_run: false,
+        rows: from_value(model.import_rows).map_err(|e| {
+            fetching_err(format!("Failed to parse bulk import rows: {e}"))
+        })?,
+        results: from_value(model.row_results).map_err(|e| {
+            fetching_err(format!("Failed to parse bulk import results: {e}"))
+        })?,
+        created_by: from_value(model.created_by).unwrap_or_default(),
+        created_at: model.created,
+        claimed_by: model.claimed_by,
+        started_at: model.started_at,
+        finished_at: model.finished_at,
+    })
+}
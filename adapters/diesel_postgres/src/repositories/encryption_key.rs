@@ -59,6 +59,36 @@ impl EncryptionKeyFetching for EncryptionKeyFetchingSqlDbRepository {
 
         provision_and_persist_dek(conn, tid, kek)
     }
+
+    #[tracing::instrument(name = "fetch_dek", skip(self, kek))]
+    async fn fetch_dek(
+        &self,
+        tenant_id: Option<Uuid>,
+        kek: &[u8; 32],
+    ) -> Result<Option<[u8; 32]>, MappedErrors> {
+        let tid = tenant_id.unwrap_or(SYSTEM_TENANT_ID);
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {e}"))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let wrapped = tenant_dsl::tenant
+            .filter(tenant_model::id.eq(tid))
+            .select(tenant_model::encrypted_dek)
+            .first::<Option<String>>(conn)
+            .optional()
+            .map_err(|e| {
+                fetching_err(format!("Failed to fetch tenant DEK row: {e}"))
+            })?
+            .flatten();
+
+        match wrapped {
+            Some(wrapped) => {
+                unwrap_dek(&wrapped, kek, tid.as_bytes()).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
 }
 
 /// Provision a DEK for a tenant row that was not found.
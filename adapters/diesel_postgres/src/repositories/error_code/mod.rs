@@ -3,9 +3,13 @@ mod shared;
 mod error_code_deletion;
 mod error_code_fetching;
 mod error_code_registration;
+mod error_code_translation_fetching;
+mod error_code_translation_registration;
 mod error_code_updating;
 
 pub(super) use error_code_deletion::*;
 pub(super) use error_code_fetching::*;
 pub(super) use error_code_registration::*;
+pub(super) use error_code_translation_fetching::*;
+pub(super) use error_code_translation_registration::*;
 pub(super) use error_code_updating::*;
@@ -1,5 +1,8 @@
-use crate::models::error_code::ErrorCode as ErrorCodeModel;
-use myc_core::domain::dtos::error_code::ErrorCode;
+use crate::models::error_code::{
+    ErrorCode as ErrorCodeModel,
+    ErrorCodeTranslation as ErrorCodeTranslationModel,
+};
+use myc_core::domain::dtos::error_code::{ErrorCode, ErrorCodeTranslation};
 
 pub(super) fn map_model_to_dto(model: ErrorCodeModel) -> ErrorCode {
     ErrorCode {
@@ -12,3 +15,15 @@ pub(super) fn map_model_to_dto(model: ErrorCodeModel) -> ErrorCode {
         is_native: model.is_native,
     }
 }
+
+pub(super) fn map_translation_model_to_dto(
+    model: ErrorCodeTranslationModel,
+) -> ErrorCodeTranslation {
+    ErrorCodeTranslation {
+        prefix: model.prefix,
+        error_number: model.code,
+        locale: model.locale,
+        message: model.message,
+        details: model.details,
+    }
+}
@@ -123,6 +123,7 @@ impl GuestRoleFetching for GuestRoleFetchingSqlDbRepository {
     #[tracing::instrument(name = "list_guest_roles", skip_all)]
     async fn list(
         &self,
+        tenant_id: Option<Uuid>,
         name: Option<String>,
         slug: Option<String>,
         system: Option<bool>,
@@ -140,6 +141,23 @@ impl GuestRoleFetching for GuestRoleFetchingSqlDbRepository {
         let page_size = page_size.unwrap_or(10) as i64;
         let skip = skip.unwrap_or(0) as i64;
 
+        // Instance-wide roles are always listed, tenant roles only to their
+        // tenant
+        match tenant_id {
+            Some(tenant_id) => {
+                let stm = guest_role_model::tenant_id
+                    .is_null()
+                    .or(guest_role_model::tenant_id.eq(tenant_id));
+                query_records = query_records.filter(stm.to_owned());
+                query_count = query_count.filter(stm);
+            }
+            None => {
+                let stm = guest_role_model::tenant_id.is_null();
+                query_records = query_records.filter(stm);
+                query_count = query_count.filter(stm);
+            }
+        }
+
         // Apply name filter if provided
         if let Some(name) = name {
             let stm = guest_role_model::name.ilike(format!("%{}%", name));
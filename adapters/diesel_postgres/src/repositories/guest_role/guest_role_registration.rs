@@ -37,14 +37,24 @@ impl GuestRoleRegistration for GuestRoleRegistrationSqlDbRepository {
                 .with_code(NativeErrorCodes::MYC00001)
         })?;
 
-        // Check if role already exists
-        let existing = guest_role_model::table
-            .filter(
-                guest_role_model::slug.eq(&guest_role.slug).and(
-                    guest_role_model::permission
-                        .eq(&guest_role.permission.to_i32()),
-                ),
-            )
+        // Check if role already exists in the role scope: the tenant of the
+        // role, or the instance-wide roles
+        let mut existing_query = guest_role_model::table
+            .filter(guest_role_model::slug.eq(&guest_role.slug).and(
+                guest_role_model::permission.eq(guest_role.permission.to_i32()),
+            ))
+            .into_boxed();
+
+        existing_query = match guest_role.tenant_id {
+            Some(tenant_id) => {
+                existing_query.filter(guest_role_model::tenant_id.eq(tenant_id))
+            }
+            None => {
+                existing_query.filter(guest_role_model::tenant_id.is_null())
+            }
+        };
+
+        let existing = existing_query
             .select(GuestRoleModel::as_select())
             .first::<GuestRoleModel>(conn)
             .optional()
@@ -67,8 +77,10 @@ impl GuestRoleRegistration for GuestRoleRegistrationSqlDbRepository {
             description: guest_role.description,
             permission: guest_role.permission.to_i32(),
             system: guest_role.system,
+            tenant_id: guest_role.tenant_id,
             created: chrono::Utc::now().naive_utc(),
             updated: None,
+            permissions: Some(guest_role.permissions),
         };
 
         let created = diesel::insert_into(guest_role_model::table)
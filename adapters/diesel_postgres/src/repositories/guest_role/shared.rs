@@ -12,6 +12,8 @@ pub(super) fn map_model_to_dto(model: GuestRoleModel) -> GuestRole {
         permission: Permission::from_i32(model.permission),
         children: None,
         system: model.system,
+        tenant_id: model.tenant_id,
+        permissions: model.permissions.unwrap_or_default(),
         created: model.created.and_local_timezone(Local).unwrap(),
         updated: model
             .updated
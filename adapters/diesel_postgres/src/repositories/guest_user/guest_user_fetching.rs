@@ -4,21 +4,30 @@ use crate::{
         config::DbPoolProvider, guest_role::GuestRole as GuestRoleModel,
         guest_user::GuestUser as GuestUserModel,
     },
+    repositories::parse_optional_written_by,
+    schema::account as account_model,
     schema::guest_role as guest_role_model,
     schema::guest_user as guest_user_model,
     schema::guest_user_on_account as guest_user_on_account_model,
 };
 
 use async_trait::async_trait;
+use chrono::{DateTime, Local, Utc};
 use diesel::prelude::*;
 use myc_core::domain::{
-    dtos::{guest_user::GuestUser, native_error_codes::NativeErrorCodes},
+    dtos::{
+        email::Email,
+        guest_role::Permission,
+        guest_user::{ExpiringGuestGrant, GuestUser, PendingGuestInvitation},
+        native_error_codes::NativeErrorCodes,
+    },
     entities::GuestUserFetching,
 };
 use mycelium_base::{
     entities::FetchManyResponseKind,
     utils::errors::{fetching_err, MappedErrors},
 };
+use serde_json::Value as JsonValue;
 use shaku::Component;
 use std::sync::Arc;
 use uuid::Uuid;
@@ -80,4 +89,289 @@ impl GuestUserFetching for GuestUserFetchingSqlDbRepository {
                 .collect(),
         })
     }
+
+    #[tracing::instrument(name = "list_pending_invitations", skip_all)]
+    async fn list_pending_invitations(
+        &self,
+        created_from: DateTime<Utc>,
+        created_before: DateTime<Utc>,
+    ) -> Result<FetchManyResponseKind<PendingGuestInvitation>, MappedErrors>
+    {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let records = guest_user_on_account_model::table
+            .inner_join(
+                guest_user_model::table.inner_join(guest_role_model::table),
+            )
+            .inner_join(account_model::table)
+            .filter(guest_user_model::was_verified.eq(false))
+            .filter(guest_user_model::created.ge(created_from))
+            .filter(guest_user_model::created.lt(created_before))
+            .filter(
+                guest_user_model::invitation_expires_at
+                    .is_null()
+                    .or(guest_user_model::invitation_expires_at.gt(Utc::now())),
+            )
+            .filter(account_model::is_deleted.eq(false))
+            .select(INVITATION_COLUMNS)
+            .order(guest_user_model::created.asc())
+            .load::<InvitationRow>(conn)
+            .map_err(|e| {
+                fetching_err(format!(
+                    "Failed to fetch pending invitations: {}",
+                    e
+                ))
+            })?;
+
+        map_invitation_rows(records)
+    }
+
+    #[tracing::instrument(name = "list_account_invitations", skip_all)]
+    async fn list_account_invitations(
+        &self,
+        account_id: Uuid,
+    ) -> Result<FetchManyResponseKind<PendingGuestInvitation>, MappedErrors>
+    {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let records = guest_user_on_account_model::table
+            .inner_join(
+                guest_user_model::table.inner_join(guest_role_model::table),
+            )
+            .inner_join(account_model::table)
+            .filter(guest_user_on_account_model::account_id.eq(account_id))
+            .filter(guest_user_model::was_verified.eq(false))
+            .filter(account_model::is_deleted.eq(false))
+            .select(INVITATION_COLUMNS)
+            .order(guest_user_model::created.asc())
+            .load::<InvitationRow>(conn)
+            .map_err(|e| {
+                fetching_err(format!(
+                    "Failed to fetch account invitations: {}",
+                    e
+                ))
+            })?;
+
+        map_invitation_rows(records)
+    }
+
+    #[tracing::instrument(name = "list_expired_invitations", skip_all)]
+    async fn list_expired_invitations(
+        &self,
+        expired_before: DateTime<Utc>,
+    ) -> Result<FetchManyResponseKind<PendingGuestInvitation>, MappedErrors>
+    {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let records = guest_user_on_account_model::table
+            .inner_join(
+                guest_user_model::table.inner_join(guest_role_model::table),
+            )
+            .inner_join(account_model::table)
+            .filter(guest_user_model::was_verified.eq(false))
+            .filter(guest_user_model::invitation_expires_at.lt(expired_before))
+            .filter(account_model::is_deleted.eq(false))
+            .select(INVITATION_COLUMNS)
+            .order(guest_user_model::invitation_expires_at.asc())
+            .load::<InvitationRow>(conn)
+            .map_err(|e| {
+                fetching_err(format!(
+                    "Failed to fetch expired invitations: {}",
+                    e
+                ))
+            })?;
+
+        map_invitation_rows(records)
+    }
+
+    #[tracing::instrument(name = "list_expiring_grants", skip_all)]
+    async fn list_expiring_grants(
+        &self,
+        valid_until_from: Option<DateTime<Utc>>,
+        valid_until_before: DateTime<Utc>,
+    ) -> Result<FetchManyResponseKind<ExpiringGuestGrant>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let mut query = guest_user_on_account_model::table
+            .inner_join(
+                guest_user_model::table.inner_join(guest_role_model::table),
+            )
+            .inner_join(account_model::table)
+            .filter(
+                guest_user_on_account_model::valid_until.lt(valid_until_before),
+            )
+            .filter(account_model::is_deleted.eq(false))
+            .into_boxed();
+
+        if let Some(valid_until_from) = valid_until_from {
+            query = query.filter(
+                guest_user_on_account_model::valid_until.ge(valid_until_from),
+            );
+        }
+
+        let records = query
+            .select((
+                guest_user_model::id,
+                guest_user_model::email,
+                guest_role_model::id,
+                guest_role_model::name,
+                account_model::id,
+                account_model::name,
+                account_model::tenant_id,
+                guest_user_on_account_model::valid_until,
+            ))
+            .order(guest_user_on_account_model::valid_until.asc())
+            .load::<(
+                Uuid,
+                String,
+                Uuid,
+                String,
+                Uuid,
+                String,
+                Option<Uuid>,
+                Option<DateTime<Local>>,
+            )>(conn)
+            .map_err(|e| {
+                fetching_err(format!("Failed to fetch expiring grants: {}", e))
+            })?;
+
+        let grants = records
+            .into_iter()
+            .filter_map(
+                |(
+                    guest_user_id,
+                    email,
+                    guest_role_id,
+                    role_name,
+                    account_id,
+                    account_name,
+                    tenant_id,
+                    valid_until,
+                )| {
+                    valid_until.map(|valid_until| {
+                        Ok(ExpiringGuestGrant {
+                            guest_user_id,
+                            email: Email::from_string(email)?,
+                            guest_role_id,
+                            role_name,
+                            account_id,
+                            account_name,
+                            tenant_id,
+                            valid_until,
+                        })
+                    })
+                },
+            )
+            .collect::<Result<Vec<_>, MappedErrors>>()?;
+
+        if grants.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        Ok(FetchManyResponseKind::Found(grants))
+    }
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Invitation rows
+// ? ---------------------------------------------------------------------------
+
+const INVITATION_COLUMNS: (
+    guest_user_model::id,
+    guest_user_model::email,
+    guest_user_model::created,
+    guest_user_model::guest_role_id,
+    guest_user_model::invited_by,
+    guest_user_model::invitation_sent_at,
+    guest_user_model::invitation_expires_at,
+    account_model::id,
+    account_model::name,
+    account_model::tenant_id,
+    guest_role_model::name,
+    guest_role_model::permission,
+) = (
+    guest_user_model::id,
+    guest_user_model::email,
+    guest_user_model::created,
+    guest_user_model::guest_role_id,
+    guest_user_model::invited_by,
+    guest_user_model::invitation_sent_at,
+    guest_user_model::invitation_expires_at,
+    account_model::id,
+    account_model::name,
+    account_model::tenant_id,
+    guest_role_model::name,
+    guest_role_model::permission,
+);
+
+type InvitationRow = (
+    Uuid,
+    String,
+    DateTime<Local>,
+    Uuid,
+    Option<JsonValue>,
+    Option<DateTime<Local>>,
+    Option<DateTime<Local>>,
+    Uuid,
+    String,
+    Option<Uuid>,
+    String,
+    i32,
+);
+
+fn map_invitation_rows(
+    records: Vec<InvitationRow>,
+) -> Result<FetchManyResponseKind<PendingGuestInvitation>, MappedErrors> {
+    if records.is_empty() {
+        return Ok(FetchManyResponseKind::NotFound);
+    }
+
+    let invitations = records
+        .into_iter()
+        .map(
+            |(
+                guest_user_id,
+                email,
+                invited_at,
+                guest_role_id,
+                invited_by,
+                last_sent_at,
+                expires_at,
+                account_id,
+                account_name,
+                tenant_id,
+                role_name,
+                permission,
+            )| {
+                Ok(PendingGuestInvitation {
+                    guest_user_id,
+                    email: Email::from_string(email)?,
+                    invited_at,
+                    account_id,
+                    account_name,
+                    tenant_id,
+                    role_name,
+                    role_permission: Permission::from_i32(permission),
+                    guest_role_id,
+                    invited_by: parse_optional_written_by(invited_by),
+                    last_sent_at,
+                    expires_at,
+                })
+            },
+        )
+        .collect::<Result<Vec<_>, MappedErrors>>()?;
+
+    Ok(FetchManyResponseKind::Found(invitations))
 }
@@ -79,6 +79,8 @@ impl GuestUserOnAccountFetching for GuestUserOnAccountFetchingSqlDbRepository {
                     .with_timezone(&Local),
                 permit_flags: model.permit_flags.unwrap_or_default(),
                 deny_flags: model.deny_flags.unwrap_or_default(),
+                valid_from: model.valid_from,
+                valid_until: model.valid_until,
             })
             .collect();
 
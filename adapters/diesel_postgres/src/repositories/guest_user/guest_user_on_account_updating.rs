@@ -1,3 +1,4 @@
+use super::shared::map_model_to_dto;
 use crate::{
     models::{
         config::DbPoolProvider, guest_user::GuestUser as GuestUserModel,
@@ -10,10 +11,12 @@ use crate::{
 };
 
 use async_trait::async_trait;
+use chrono::{DateTime, Local};
 use diesel::prelude::*;
 use myc_core::domain::{
     dtos::{
-        guest_role::Permission, guest_user_on_account::GuestUserOnAccount,
+        guest_role::Permission, guest_user::GuestUser,
+        guest_user_on_account::GuestUserOnAccount,
         native_error_codes::NativeErrorCodes,
     },
     entities::GuestUserOnAccountUpdating,
@@ -72,17 +75,21 @@ impl GuestUserOnAccountUpdating for GuestUserOnAccountUpdatingSqlDbRepository {
             }
         };
 
-        // Find guest user by account
-        let guest_user = guest_user_model::table
-            .inner_join(guest_user_on_account::table)
-            .filter(guest_user_on_account::account_id.eq(account_id))
-            .filter(guest_user_model::was_verified.eq(false))
-            .select(GuestUserModel::as_select())
-            .first::<GuestUserModel>(conn)
-            .optional()
-            .map_err(|e| {
-                updating_err(format!("Failed to fetch guest user: {}", e))
-            })?;
+        // Find guest user by account. Expired invitations are not accepted.
+        let guest_user =
+            guest_user_model::table
+                .inner_join(guest_user_on_account::table)
+                .filter(guest_user_on_account::account_id.eq(account_id))
+                .filter(guest_user_model::was_verified.eq(false))
+                .filter(guest_user_model::invitation_expires_at.is_null().or(
+                    guest_user_model::invitation_expires_at.gt(Local::now()),
+                ))
+                .select(GuestUserModel::as_select())
+                .first::<GuestUserModel>(conn)
+                .optional()
+                .map_err(|e| {
+                    updating_err(format!("Failed to fetch guest user: {}", e))
+                })?;
 
         match guest_user {
             Some(user) => {
@@ -118,8 +125,6 @@ impl GuestUserOnAccountUpdating for GuestUserOnAccountUpdatingSqlDbRepository {
         &self,
         guest_user_on_account: GuestUserOnAccount,
     ) -> Result<UpdatingResponseKind<GuestUserOnAccount>, MappedErrors> {
-        use chrono::Local;
-
         let conn = &mut self.db_config.get_pool().get().map_err(|e| {
             updating_err(format!("Failed to get DB connection: {}", e))
                 .with_code(NativeErrorCodes::MYC00001)
@@ -177,8 +182,138 @@ impl GuestUserOnAccountUpdating for GuestUserOnAccountUpdatingSqlDbRepository {
                 .with_timezone(&Local),
             permit_flags: updated.permit_flags.unwrap_or_default(),
             deny_flags: updated.deny_flags.unwrap_or_default(),
+            valid_from: updated.valid_from,
+            valid_until: updated.valid_until,
         };
 
         Ok(UpdatingResponseKind::Updated(dto))
     }
+
+    #[tracing::instrument(name = "update_validity", skip_all)]
+    async fn update_validity(
+        &self,
+        guest_user_id: Uuid,
+        account_id: Uuid,
+        valid_from: Option<DateTime<Local>>,
+        valid_until: Option<DateTime<Local>>,
+    ) -> Result<UpdatingResponseKind<GuestUserOnAccount>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            updating_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let updated = diesel::update(
+            guest_user_on_account::table.filter(
+                guest_user_on_account::guest_user_id
+                    .eq(guest_user_id)
+                    .and(guest_user_on_account::account_id.eq(account_id)),
+            ),
+        )
+        .set((
+            guest_user_on_account::valid_from.eq(valid_from),
+            guest_user_on_account::valid_until.eq(valid_until),
+        ))
+        .get_result::<GuestUserOnAccountModel>(conn)
+        .optional()
+        .map_err(|e| {
+            updating_err(format!(
+                "Failed to update guest grant validity: {}",
+                e
+            ))
+        })?;
+
+        let updated = match updated {
+            Some(record) => record,
+            None => {
+                return Ok(UpdatingResponseKind::NotUpdated(
+                    GuestUserOnAccount {
+                        guest_user_id,
+                        account_id,
+                        created: Local::now(),
+                        permit_flags: vec![],
+                        deny_flags: vec![],
+                        valid_from,
+                        valid_until,
+                    },
+                    "Guest user on account not found".to_string(),
+                ))
+            }
+        };
+
+        Ok(UpdatingResponseKind::Updated(GuestUserOnAccount {
+            guest_user_id: updated.guest_user_id,
+            account_id: updated.account_id,
+            created: updated
+                .created
+                .and_local_timezone(Local)
+                .unwrap()
+                .with_timezone(&Local),
+            permit_flags: updated.permit_flags.unwrap_or_default(),
+            deny_flags: updated.deny_flags.unwrap_or_default(),
+            valid_from: updated.valid_from,
+            valid_until: updated.valid_until,
+        }))
+    }
+
+    #[tracing::instrument(name = "renew_invitation", skip_all)]
+    async fn renew_invitation(
+        &self,
+        guest_user_id: Uuid,
+        expires_at: DateTime<Local>,
+    ) -> Result<UpdatingResponseKind<GuestUser>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            updating_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let guest_user = guest_user_model::table
+            .find(guest_user_id)
+            .select(GuestUserModel::as_select())
+            .first::<GuestUserModel>(conn)
+            .optional()
+            .map_err(|e| {
+                updating_err(format!("Failed to fetch guest user: {}", e))
+            })?;
+
+        let guest_user = match guest_user {
+            Some(record) => record,
+            None => {
+                return updating_err(format!(
+                    "Guest user not found: {guest_user_id}"
+                ))
+                .with_code(NativeErrorCodes::MYC00013)
+                .with_exp_true()
+                .as_error()
+            }
+        };
+
+        if guest_user.was_verified {
+            return Ok(UpdatingResponseKind::NotUpdated(
+                map_model_to_dto(guest_user, None),
+                "Invitation already accepted".to_string(),
+            ));
+        }
+
+        let now = Local::now();
+
+        let updated =
+            diesel::update(guest_user_model::table.find(guest_user_id))
+                .set((
+                    guest_user_model::invitation_sent_at.eq(Some(now)),
+                    guest_user_model::invitation_expires_at
+                        .eq(Some(expires_at)),
+                    guest_user_model::updated.eq(Some(now)),
+                ))
+                .get_result::<GuestUserModel>(conn)
+                .map_err(|e| {
+                    updating_err(format!(
+                        "Failed to renew guest invitation: {}",
+                        e
+                    ))
+                })?;
+
+        Ok(UpdatingResponseKind::Updated(map_model_to_dto(
+            updated, None,
+        )))
+    }
 }
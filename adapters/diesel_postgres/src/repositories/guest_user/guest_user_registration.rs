@@ -19,6 +19,7 @@ use mycelium_base::{
     entities::GetOrCreateResponseKind,
     utils::errors::{creation_err, MappedErrors},
 };
+use serde_json::to_value;
 use shaku::Component;
 use std::sync::Arc;
 use uuid::Uuid;
@@ -58,8 +59,37 @@ impl GuestUserRegistration for GuestUserRegistrationSqlDbRepository {
                 ))
             })?;
 
+        let invited_by = guest_user
+            .invited_by
+            .to_owned()
+            .map(|m| to_value(m).unwrap());
+
         let guest_user = if let Some(record) = existing {
-            record
+            //
+            // Invitations not accepted yet are renewed when the user is
+            // invited again.
+            //
+            if record.was_verified || guest_user.invitation_expires_at.is_none()
+            {
+                record
+            } else {
+                diesel::update(guest_user_model::table.find(record.id))
+                    .set((
+                        guest_user_model::invited_by.eq(invited_by),
+                        guest_user_model::invitation_sent_at
+                            .eq(guest_user.invitation_sent_at),
+                        guest_user_model::invitation_expires_at
+                            .eq(guest_user.invitation_expires_at),
+                        guest_user_model::updated.eq(Some(Local::now())),
+                    ))
+                    .get_result::<GuestUserModel>(conn)
+                    .map_err(|e| {
+                        creation_err(format!(
+                            "Failed to renew guest invitation: {}",
+                            e
+                        ))
+                    })?
+            }
         } else {
             // Create new guest user
             let new_user = GuestUserModel {
@@ -77,6 +107,9 @@ impl GuestUserRegistration for GuestUserRegistrationSqlDbRepository {
                 created: Local::now(),
                 updated: None,
                 was_verified: false,
+                invited_by,
+                invitation_sent_at: guest_user.invitation_sent_at,
+                invitation_expires_at: guest_user.invitation_expires_at,
             };
 
             diesel::insert_into(guest_user_model::table)
@@ -1,6 +1,9 @@
-use crate::models::{
-    guest_role::GuestRole as GuestRoleModel,
-    guest_user::GuestUser as GuestUserModel,
+use crate::{
+    models::{
+        guest_role::GuestRole as GuestRoleModel,
+        guest_user::GuestUser as GuestUserModel,
+    },
+    repositories::parse_optional_written_by,
 };
 
 use chrono::Local;
@@ -27,6 +30,8 @@ pub(super) fn map_model_to_dto(
                 permission: Permission::from_i32(role.permission),
                 children: None,
                 system: role.system,
+                tenant_id: role.tenant_id,
+                permissions: role.permissions.unwrap_or_default(),
                 created: role.created.and_local_timezone(Local).unwrap(),
                 updated: role
                     .updated
@@ -38,5 +43,8 @@ pub(super) fn map_model_to_dto(
         created: model.created,
         updated: model.updated,
         was_verified: model.was_verified,
+        invited_by: parse_optional_written_by(model.invited_by),
+        invitation_sent_at: model.invitation_sent_at,
+        invitation_expires_at: model.invitation_expires_at,
     }
 }
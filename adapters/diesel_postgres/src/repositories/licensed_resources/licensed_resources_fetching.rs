@@ -1,5 +1,6 @@
 use crate::{
     models::{config::DbPoolProvider, licensed_resource::LicensedResourceRow},
+    repositories::tenant::load_descendants,
     schema::{
         owner_on_tenant::dsl as owner_dsl, tenant::dsl as tenant_dsl,
         user::dsl as user_dsl,
@@ -25,7 +26,10 @@ use mycelium_base::{
     utils::errors::{fetching_err, MappedErrors},
 };
 use shaku::Component;
-use std::sync::Arc;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 use tracing::trace;
 use uuid::Uuid;
 
@@ -149,6 +153,12 @@ impl LicensedResourcesFetching for LicensedResourcesFetchingSqlDbRepository {
                 verified: record.gu_verified,
                 permit_flags: record.permit_flags,
                 deny_flags: record.deny_flags,
+                permissions: record
+                    .gr_permissions
+                    .filter(|permissions| !permissions.is_empty()),
+                valid_until: record
+                    .valid_until
+                    .map(|valid_until| valid_until.with_timezone(&Local)),
             })
             .collect::<Vec<LicensedResource>>();
 
@@ -166,8 +176,7 @@ impl LicensedResourcesFetching for LicensedResourcesFetchingSqlDbRepository {
                 .with_code(NativeErrorCodes::MYC00001)
         })?;
 
-        let mut query = user_dsl::user
-            .into_boxed()
+        let direct_ownerships = user_dsl::user
             .inner_join(owner_dsl::owner_on_tenant)
             .inner_join(
                 tenant_dsl::tenant.on(owner_dsl::tenant_id.eq(tenant_dsl::id)),
@@ -177,33 +186,59 @@ impl LicensedResourcesFetching for LicensedResourcesFetchingSqlDbRepository {
                 owner_dsl::tenant_id,
                 owner_dsl::created,
                 tenant_dsl::name,
-            ));
+                tenant_dsl::parent_id,
+            ))
+            .load::<(Uuid, NaiveDateTime, String, Option<Uuid>)>(conn)
+            .map_err(|e| {
+                fetching_err(format!("Failed to fetch tenant ownerships: {e}"))
+            })?
+            .into_iter()
+            .map(|(tenant_id, created, tenant_name, parent_id)| {
+                TenantOwnership {
+                    id: tenant_id,
+                    since: created.and_local_timezone(Local).unwrap(),
+                    name: tenant_name,
+                    parent_id,
+                    inherited_from: None,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        //
+        // Owners of a tenant own its descendants too. Direct ownerships take
+        // precedence over the inherited ones.
+        //
+        let mut seen: HashSet<Uuid> =
+            direct_ownerships.iter().map(|o| o.id).collect();
+        let mut ownerships = direct_ownerships.to_owned();
+
+        for owned in direct_ownerships {
+            let mut lineage = HashMap::from([(owned.id, owned.to_owned())]);
+
+            for child in load_descendants(conn, owned.id)? {
+                let Some(parent) =
+                    child.parent_id.and_then(|id| lineage.get(&id))
+                else {
+                    continue;
+                };
+
+                let inherited = parent.inherit_to(child.id, child.name);
+                lineage.insert(child.id, inherited.to_owned());
+
+                if seen.insert(child.id) {
+                    ownerships.push(inherited);
+                }
+            }
+        }
 
         if let Some(tenant_id) = tenant {
-            query = query.filter(owner_dsl::tenant_id.eq(tenant_id));
+            ownerships.retain(|o| o.id == tenant_id);
         }
 
-        let rows =
-            query
-                .load::<(Uuid, NaiveDateTime, String)>(conn)
-                .map_err(|e| {
-                    fetching_err(format!(
-                        "Failed to fetch tenant ownerships: {e}"
-                    ))
-                })?;
-
-        if rows.is_empty() {
+        if ownerships.is_empty() {
             return Ok(FetchManyResponseKind::NotFound);
         }
 
-        Ok(FetchManyResponseKind::Found(
-            rows.into_iter()
-                .map(|(tenant_id, created, tenant_name)| TenantOwnership {
-                    id: tenant_id,
-                    since: created.and_local_timezone(Local).unwrap(),
-                    name: tenant_name,
-                })
-                .collect(),
-        ))
+        Ok(FetchManyResponseKind::Found(ownerships))
     }
 }
@@ -0,0 +1,93 @@
+use crate::{
+    models::{
+        config::DbPoolProvider,
+        email_delivery::EmailSuppression as EmailSuppressionModel,
+    },
+    schema::email_suppression as email_suppression_model,
+};
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use myc_core::domain::{
+    dtos::{
+        email::Email, email_delivery::EmailSuppression,
+        native_error_codes::NativeErrorCodes,
+    },
+    entities::EmailSuppressionRegistration,
+};
+use mycelium_base::{
+    entities::DeletionResponseKind,
+    utils::errors::{creation_err, deletion_err, MappedErrors},
+};
+use shaku::Component;
+use std::sync::Arc;
+
+#[derive(Component)]
+#[shaku(interface = EmailSuppressionRegistration)]
+pub struct EmailSuppressionRegistrationSqlDbRepository {
+    #[shaku(inject)]
+    pub db_config: Arc<dyn DbPoolProvider>,
+}
+
+#[async_trait]
+impl EmailSuppressionRegistration
+    for EmailSuppressionRegistrationSqlDbRepository
+{
+    #[tracing::instrument(name = "register_email_suppression", skip_all)]
+    async fn register(
+        &self,
+        suppression: EmailSuppression,
+    ) -> Result<(), MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            creation_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        diesel::insert_into(email_suppression_model::table)
+            .values(&EmailSuppressionModel {
+                email: suppression.email.to_lowercase(),
+                reason: suppression.reason.to_string(),
+                detail: suppression.detail,
+                created: suppression.created,
+            })
+            .on_conflict(email_suppression_model::email)
+            .do_nothing()
+            .execute(conn)
+            .map_err(|e| {
+                creation_err(format!(
+                    "Failed to register email suppression: {e}"
+                ))
+            })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "delete_email_suppression", skip_all)]
+    async fn delete(
+        &self,
+        email: Email,
+    ) -> Result<DeletionResponseKind<String>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            deletion_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let deleted =
+            diesel::delete(email_suppression_model::table.find(email.email()))
+                .execute(conn)
+                .map_err(|e| {
+                    deletion_err(format!(
+                        "Failed to delete email suppression: {e}"
+                    ))
+                })?;
+
+        if deleted == 0 {
+            return Ok(DeletionResponseKind::NotDeleted(
+                email.email(),
+                "Email is not suppressed".to_string(),
+            ));
+        }
+
+        Ok(DeletionResponseKind::Deleted)
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{
+    models::{
+        config::DbPoolProvider,
+        email_delivery::MessageDelivery as MessageDeliveryModel,
+    },
+    schema::message_delivery as message_delivery_model,
+};
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use myc_core::domain::{
+    dtos::{
+        email_delivery::{MessageDelivery, MessageDeliveryStatus},
+        native_error_codes::NativeErrorCodes,
+    },
+    entities::MessageDeliveryFetching,
+};
+use mycelium_base::{
+    entities::FetchManyResponseKind,
+    utils::errors::{fetching_err, MappedErrors},
+};
+use shaku::Component;
+use std::{str::FromStr, sync::Arc};
+
+#[derive(Component)]
+#[shaku(interface = MessageDeliveryFetching)]
+pub struct MessageDeliveryFetchingSqlDbRepository {
+    #[shaku(inject)]
+    pub db_config: Arc<dyn DbPoolProvider>,
+}
+
+#[async_trait]
+impl MessageDeliveryFetching for MessageDeliveryFetchingSqlDbRepository {
+    #[tracing::instrument(name = "list_message_deliveries", skip_all)]
+    async fn list(
+        &self,
+        recipient: Option<String>,
+        status: Option<MessageDeliveryStatus>,
+        page_size: Option<i32>,
+        skip: Option<i32>,
+    ) -> Result<FetchManyResponseKind<MessageDelivery>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let mut records_query = message_delivery_model::table.into_boxed();
+        let mut total_query = message_delivery_model::table.into_boxed();
+
+        if let Some(recipient) = recipient {
+            let stm = message_delivery_model::recipient.eq(recipient);
+            records_query = records_query.filter(stm.to_owned());
+            total_query = total_query.filter(stm);
+        }
+
+        if let Some(status) = status {
+            let stm = message_delivery_model::status.eq(status.to_string());
+            records_query = records_query.filter(stm.to_owned());
+            total_query = total_query.filter(stm);
+        }
+
+        let total =
+            total_query.count().get_result::<i64>(conn).map_err(|e| {
+                fetching_err(format!("Failed to get total count: {}", e))
+            })?;
+
+        let page_size = i64::from(page_size.unwrap_or(10));
+        let skip = i64::from(skip.unwrap_or(0));
+
+        let records = records_query
+            .order(message_delivery_model::created.desc())
+            .offset(skip)
+            .limit(page_size)
+            .select(MessageDeliveryModel::as_select())
+            .load::<MessageDeliveryModel>(conn)
+            .map_err(|e| {
+                fetching_err(format!("Failed to fetch message deliveries: {e}"))
+            })?;
+
+        Ok(FetchManyResponseKind::FoundPaginated {
+            count: total,
+            skip: Some(skip),
+            size: Some(page_size),
+            records: records
+                .into_iter()
+                .map(map_delivery_model_to_dto)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+fn map_delivery_model_to_dto(
+    record: MessageDeliveryModel,
+) -> Result<MessageDelivery, MappedErrors> {
+    Ok(MessageDelivery {
+        id: record.id,
+        recipient: record.recipient,
+        subject: record.subject,
+        status: MessageDeliveryStatus::from_str(&record.status)
+            .map_err(fetching_err)?,
+        detail: record.detail,
+        created: record.created,
+        updated: record.updated,
+    })
+}
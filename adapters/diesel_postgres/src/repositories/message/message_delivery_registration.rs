@@ -0,0 +1,102 @@
+use crate::{
+    models::{
+        config::DbPoolProvider,
+        email_delivery::MessageDelivery as MessageDeliveryModel,
+    },
+    schema::message_delivery as message_delivery_model,
+};
+
+use async_trait::async_trait;
+use chrono::Local;
+use diesel::{prelude::*, upsert::excluded};
+use myc_core::domain::{
+    dtos::{
+        email_delivery::{MessageDelivery, MessageDeliveryStatus},
+        native_error_codes::NativeErrorCodes,
+    },
+    entities::MessageDeliveryRegistration,
+};
+use mycelium_base::utils::errors::{creation_err, updating_err, MappedErrors};
+use shaku::Component;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Component)]
+#[shaku(interface = MessageDeliveryRegistration)]
+pub struct MessageDeliveryRegistrationSqlDbRepository {
+    #[shaku(inject)]
+    pub db_config: Arc<dyn DbPoolProvider>,
+}
+
+#[async_trait]
+impl MessageDeliveryRegistration
+    for MessageDeliveryRegistrationSqlDbRepository
+{
+    #[tracing::instrument(name = "register_message_delivery", skip_all)]
+    async fn register(
+        &self,
+        delivery: MessageDelivery,
+    ) -> Result<(), MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            creation_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        diesel::insert_into(message_delivery_model::table)
+            .values(&MessageDeliveryModel {
+                id: delivery.id,
+                recipient: delivery.recipient,
+                subject: delivery.subject,
+                status: delivery.status.to_string(),
+                detail: delivery.detail,
+                created: delivery.created,
+                updated: delivery.updated,
+            })
+            .on_conflict(message_delivery_model::id)
+            .do_update()
+            .set((
+                message_delivery_model::status
+                    .eq(excluded(message_delivery_model::status)),
+                message_delivery_model::detail
+                    .eq(excluded(message_delivery_model::detail)),
+                message_delivery_model::updated
+                    .eq(excluded(message_delivery_model::updated)),
+            ))
+            .execute(conn)
+            .map_err(|e| {
+                creation_err(format!(
+                    "Failed to register message delivery: {e}"
+                ))
+            })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "update_message_delivery_status", skip_all)]
+    async fn update_status(
+        &self,
+        id: Uuid,
+        status: MessageDeliveryStatus,
+        detail: Option<String>,
+    ) -> Result<bool, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            updating_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let updated = diesel::update(message_delivery_model::table.find(id))
+            .set((
+                message_delivery_model::status.eq(status.to_string()),
+                message_delivery_model::detail.eq(detail),
+                message_delivery_model::updated.eq(Some(Local::now())),
+            ))
+            .execute(conn)
+            .map_err(|e| {
+                updating_err(format!(
+                    "Failed to update message delivery status: {e}"
+                ))
+            })?;
+
+        Ok(updated > 0)
+    }
+}
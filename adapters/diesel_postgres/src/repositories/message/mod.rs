@@ -1,7 +1,15 @@
+mod email_suppression_fetching;
+mod email_suppression_registration;
 mod local_message_read;
 mod local_message_write;
+mod message_delivery_fetching;
+mod message_delivery_registration;
 
+pub(crate) use email_suppression_fetching::*;
+pub(crate) use email_suppression_registration::*;
 // Fully public (not just `pub(crate)`) so `ports/api` can seed the claim
 // visibility timeout via `with_component_parameters` on the read repo.
 pub use local_message_read::*;
 pub(crate) use local_message_write::*;
+pub(crate) use message_delivery_fetching::*;
+pub(crate) use message_delivery_registration::*;
@@ -2,6 +2,7 @@ use shaku::module;
 
 mod account;
 mod account_tag;
+mod bulk_import;
 mod config;
 mod encryption_key;
 mod error_code;
@@ -10,9 +11,12 @@ mod guest_user;
 mod instance_settings;
 mod licensed_resources;
 mod message;
+mod notification;
 mod optional_written_by_parser;
 mod profile;
 mod resource_audit_log;
+mod scheduled_job;
+mod scim;
 mod tenant;
 mod tenant_tag;
 mod token;
@@ -21,6 +25,7 @@ mod webhook;
 
 use account::*;
 use account_tag::*;
+use bulk_import::*;
 use encryption_key::*;
 use error_code::*;
 use guest_role::*;
@@ -28,8 +33,11 @@ use guest_user::*;
 use instance_settings::*;
 use licensed_resources::*;
 pub use message::*;
+use notification::*;
 use optional_written_by_parser::*;
 use profile::*;
+use scheduled_job::*;
+use scim::*;
 use tenant::*;
 use tenant_tag::*;
 use token::*;
@@ -56,10 +64,17 @@ module! {
             AccountTagDeletionSqlDbRepository,
             AccountTagRegistrationSqlDbRepository,
             AccountTagUpdatingSqlDbRepository,
+            BulkImportFetchingSqlDbRepository,
+            BulkImportRegistrationSqlDbRepository,
+            BulkImportUpdatingSqlDbRepository,
+            EmailSuppressionFetchingSqlDbRepository,
+            EmailSuppressionRegistrationSqlDbRepository,
             EncryptionKeyFetchingSqlDbRepository,
             ErrorCodeDeletionSqlDbRepository,
             ErrorCodeFetchingSqlDbRepository,
             ErrorCodeRegistrationSqlDbRepository,
+            ErrorCodeTranslationFetchingSqlDbRepository,
+            ErrorCodeTranslationRegistrationSqlDbRepository,
             ErrorCodeUpdatingSqlDbRepository,
             GuestRoleDeletionSqlDbRepository,
             GuestRoleFetchingSqlDbRepository,
@@ -76,8 +91,20 @@ module! {
             LicensedResourcesFetchingSqlDbRepository,
             LocalMessageReadSqlDbRepository,
             LocalMessageWriteSqlDbRepository,
+            MessageDeliveryFetchingSqlDbRepository,
+            MessageDeliveryRegistrationSqlDbRepository,
+            NotificationRecipientFetchingSqlDbRepository,
+            ResourceAuditLogArchivalSqlDbRepository,
             ResourceAuditLogFetchingSqlDbRepository,
             ResourceAuditLogRegistrationSqlDbRepository,
+            ScheduledJobClaimingSqlDbRepository,
+            ScimCredentialDeletionSqlDbRepository,
+            ScimCredentialFetchingSqlDbRepository,
+            ScimCredentialRegistrationSqlDbRepository,
+            ScimResourceDeletionSqlDbRepository,
+            ScimResourceFetchingSqlDbRepository,
+            ScimResourceRegistrationSqlDbRepository,
+            ScimResourceUpdatingSqlDbRepository,
             TenantDeletionSqlDbRepository,
             TenantFetchingSqlDbRepository,
             TenantRegistrationSqlDbRepository,
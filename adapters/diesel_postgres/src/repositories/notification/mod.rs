@@ -0,0 +1,3 @@
+mod notification_recipient_fetching;
+
+pub(super) use notification_recipient_fetching::*;
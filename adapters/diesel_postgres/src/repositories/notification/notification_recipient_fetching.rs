@@ -0,0 +1,85 @@
+use crate::{
+    models::config::DbPoolProvider,
+    schema::{account as account_model, user as user_model},
+};
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use myc_core::domain::{
+    dtos::{
+        account::AccountMetaKey, email::Email,
+        native_error_codes::NativeErrorCodes,
+        notification::NotificationRecipient,
+    },
+    entities::NotificationRecipientFetching,
+};
+use mycelium_base::{
+    entities::FetchResponseKind,
+    utils::errors::{fetching_err, MappedErrors},
+};
+use serde_json::Value as JsonValue;
+use shaku::Component;
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+use uuid::Uuid;
+
+#[derive(Component)]
+#[shaku(interface = NotificationRecipientFetching)]
+pub struct NotificationRecipientFetchingSqlDbRepository {
+    #[shaku(inject)]
+    pub db_config: Arc<dyn DbPoolProvider>,
+}
+
+#[async_trait]
+impl NotificationRecipientFetching
+    for NotificationRecipientFetchingSqlDbRepository
+{
+    #[tracing::instrument(name = "get_notification_recipient", skip_all)]
+    async fn get_by_email(
+        &self,
+        email: Email,
+    ) -> Result<FetchResponseKind<NotificationRecipient, String>, MappedErrors>
+    {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let result = user_model::table
+            .inner_join(account_model::table)
+            .filter(user_model::email.eq(email.email()))
+            .select((account_model::id, account_model::meta))
+            .first::<(Uuid, Option<JsonValue>)>(conn)
+            .optional()
+            .map_err(|e| {
+                fetching_err(format!(
+                    "Failed to fetch notification recipient: {}",
+                    e
+                ))
+            })?;
+
+        match result {
+            None => Ok(FetchResponseKind::NotFound(None)),
+            Some((account_id, meta)) => {
+                let meta: HashMap<String, String> = match meta {
+                    Some(meta) => {
+                        serde_json::from_value(meta).unwrap_or_default()
+                    }
+                    None => HashMap::new(),
+                };
+
+                let meta = meta
+                    .into_iter()
+                    .filter_map(|(key, value)| {
+                        AccountMetaKey::from_str(&key)
+                            .ok()
+                            .map(|key| (key, value))
+                    })
+                    .collect();
+
+                Ok(FetchResponseKind::Found(
+                    NotificationRecipient::from_account_meta(account_id, &meta),
+                ))
+            }
+        }
+    }
+}
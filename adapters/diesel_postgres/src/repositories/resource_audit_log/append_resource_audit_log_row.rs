@@ -8,30 +8,128 @@
 // pooled connection (via `DbPoolProvider`) and this one function; it never
 // touches Diesel directly, matching every other cross-boundary call in this
 // codebase.
+//
+// Every row is linked into its tenant's hash chain. Reading the chain head
+// and inserting the new row happen in one transaction holding a
+// transaction-scoped advisory lock on the chain, so concurrent writers (e.g.
+// several API replicas) never fork a chain. Archiving takes the same lock.
 // ? ---------------------------------------------------------------------------
 
 use super::resource_audit_log_db_encoding::{
     event_kind_to_db_str, resource_type_to_db_str,
 };
 use crate::{
-    models::resource_audit_log::NewResourceAuditLog,
-    schema::resource_audit_log as resource_audit_log_model,
+    models::resource_audit_log::{
+        NewResourceAuditLog,
+        ResourceAuditCheckpoint as ResourceAuditCheckpointModel,
+    },
+    schema::{
+        resource_audit_archive as resource_audit_archive_model,
+        resource_audit_checkpoint as resource_audit_checkpoint_model,
+        resource_audit_log as resource_audit_log_model,
+    },
 };
 
+use chrono::SubsecRound;
 use diesel::prelude::*;
+use diesel::sql_types::Text;
 use diesel::PgConnection;
-use myc_core::domain::dtos::resource_audit_log::NewResourceAuditLogEvent;
+use myc_core::domain::{
+    dtos::resource_audit_log::{
+        NewResourceAuditLogEvent, ResourceAuditChainLink,
+        ResourceAuditCheckpoint, RESOURCE_AUDIT_CHAIN_GENESIS_HASH,
+    },
+    utils::SYSTEM_TENANT_ID,
+};
 use mycelium_base::utils::errors::{creation_err, MappedErrors};
 
 pub fn append_resource_audit_log_row(
     conn: &mut PgConnection,
     event: &NewResourceAuditLogEvent,
-) -> Result<(), MappedErrors> {
+) -> Result<ResourceAuditChainLink, MappedErrors> {
     let performed_by =
         serde_json::to_value(&event.performed_by).map_err(|e| {
             creation_err(format!("Failed to encode performed_by: {e}"))
         })?;
 
+    conn.transaction(|conn| insert_chained_row(conn, event, performed_by))
+        .map_err(|e: diesel::result::Error| {
+            creation_err(format!("Failed to insert resource audit log: {e}"))
+        })
+}
+
+/// Link `event` into its chain and insert it, inside the transaction the
+/// caller holds (the advisory lock lasts until that transaction ends).
+pub(super) fn insert_chained_row(
+    conn: &mut PgConnection,
+    event: &NewResourceAuditLogEvent,
+    performed_by: serde_json::Value,
+) -> QueryResult<ResourceAuditChainLink> {
+    //
+    // Postgres keeps microseconds. Truncating up front guarantees the hash is
+    // computed over exactly the timestamp that ends up stored.
+    //
+    let mut event = event.to_owned();
+    event.created_at = event.created_at.trunc_subsecs(6);
+
+    diesel::sql_query("SELECT pg_advisory_xact_lock(hashtextextended($1, 0))")
+        .bind::<Text, _>(format!(
+            "resource_audit_log:{}",
+            event.tenant_id.unwrap_or(SYSTEM_TENANT_ID)
+        ))
+        .execute(conn)?;
+
+    let mut head_query = resource_audit_log_model::table
+        .filter(resource_audit_log_model::chain_seq.is_not_null())
+        .into_boxed();
+
+    head_query = match event.tenant_id {
+        Some(tenant_id) => {
+            head_query.filter(resource_audit_log_model::tenant_id.eq(tenant_id))
+        }
+        None => {
+            head_query.filter(resource_audit_log_model::tenant_id.is_null())
+        }
+    };
+
+    let head = head_query
+        .order_by(resource_audit_log_model::chain_seq.desc())
+        .select((
+            resource_audit_log_model::chain_seq,
+            resource_audit_log_model::row_hash,
+        ))
+        .first::<(Option<i64>, Option<String>)>(conn)
+        .optional()?;
+
+    //
+    // A chain whose rows were all archived resumes after its latest
+    // archive rather than restarting from the genesis.
+    //
+    let head = match head {
+        Some((Some(seq), Some(hash))) => Some((seq, hash)),
+        _ => resource_audit_archive_model::table
+            .filter(
+                resource_audit_archive_model::chain_tenant_id
+                    .eq(event.tenant_id.unwrap_or(SYSTEM_TENANT_ID)),
+            )
+            .filter(resource_audit_archive_model::last_seq.is_not_null())
+            .order_by(resource_audit_archive_model::last_seq.desc())
+            .select((
+                resource_audit_archive_model::last_seq,
+                resource_audit_archive_model::last_hash,
+            ))
+            .first::<(Option<i64>, Option<String>)>(conn)
+            .optional()?
+            .and_then(|(seq, hash)| seq.zip(hash)),
+    };
+
+    let (seq, prev_hash) = match head {
+        Some((seq, hash)) => (seq + 1, hash),
+        None => (1, RESOURCE_AUDIT_CHAIN_GENESIS_HASH.to_string()),
+    };
+
+    let link = event.chain_link(seq, &prev_hash);
+
     let new_row = NewResourceAuditLog {
         resource_type: resource_type_to_db_str(&event.resource_type).to_owned(),
         resource_id: event.resource_id,
@@ -40,13 +138,38 @@ pub fn append_resource_audit_log_row(
         performed_by,
         metadata: event.metadata.to_owned(),
         created_at: event.created_at.naive_utc(),
+        chain_seq: Some(link.seq),
+        prev_hash: Some(link.prev_hash.to_owned()),
+        row_hash: Some(link.hash.to_owned()),
     };
 
     diesel::insert_into(resource_audit_log_model::table)
+        .values(&new_row)
+        .execute(conn)?;
+
+    Ok(link)
+}
+
+pub fn append_resource_audit_checkpoint_row(
+    conn: &mut PgConnection,
+    checkpoint: &ResourceAuditCheckpoint,
+) -> Result<(), MappedErrors> {
+    let new_row = ResourceAuditCheckpointModel {
+        id: checkpoint.id,
+        chain_tenant_id: checkpoint.chain_tenant_id.unwrap_or(SYSTEM_TENANT_ID),
+        chain_seq: checkpoint.seq,
+        root_hash: checkpoint.root_hash.to_owned(),
+        signature: checkpoint.signature.to_owned(),
+        created_at: checkpoint.created_at.naive_utc(),
+    };
+
+    diesel::insert_into(resource_audit_checkpoint_model::table)
         .values(&new_row)
         .execute(conn)
         .map_err(|e| {
-            creation_err(format!("Failed to insert resource audit log: {e}"))
+            creation_err(format!(
+                "Failed to insert resource audit checkpoint: {e}"
+            ))
         })?;
 
     Ok(())
@@ -1,8 +1,10 @@
 mod append_resource_audit_log_row;
+mod resource_audit_log_archival;
 mod resource_audit_log_db_encoding;
 mod resource_audit_log_fetching;
 mod resource_audit_log_registration;
 
 pub use append_resource_audit_log_row::*;
+pub use resource_audit_log_archival::*;
 pub use resource_audit_log_fetching::*;
 pub use resource_audit_log_registration::*;
@@ -0,0 +1,432 @@
+use crate::{
+    models::{
+        config::DbPoolProvider,
+        resource_audit_log::{
+            ResourceAuditArchive as ResourceAuditArchiveModel,
+            ResourceAuditLog as ResourceAuditLogModel,
+            ResourceAuditRetentionPolicy as ResourceAuditRetentionPolicyModel,
+        },
+    },
+    schema::{
+        resource_audit_archive as resource_audit_archive_model,
+        resource_audit_log as resource_audit_log_model,
+        resource_audit_retention_policy as resource_audit_retention_policy_model,
+    },
+};
+
+use super::{
+    append_resource_audit_log_row::insert_chained_row,
+    resource_audit_log_db_encoding::{
+        event_kind_to_db_str, resource_type_to_db_str,
+    },
+};
+
+use async_trait::async_trait;
+use diesel::{
+    prelude::*,
+    sql_types::{BigInt, Nullable, Text, Timestamptz, Uuid as SqlUuid},
+};
+use myc_core::domain::{
+    dtos::resource_audit_log::{
+        NewResourceAuditLogEvent, ResourceAuditArchive, ResourceAuditLog,
+        ResourceAuditRetentionPolicy,
+    },
+    entities::ResourceAuditLogArchival,
+    utils::SYSTEM_TENANT_ID,
+};
+use mycelium_base::utils::errors::{
+    creation_err, deletion_err, updating_err, MappedErrors,
+};
+use shaku::Component;
+use std::sync::Arc;
+use uuid::Uuid;
+
+const RESTORE_BATCH_SIZE: usize = 500;
+
+#[derive(Component)]
+#[shaku(interface = ResourceAuditLogArchival)]
+pub struct ResourceAuditLogArchivalSqlDbRepository {
+    #[shaku(inject)]
+    pub db_config: Arc<dyn DbPoolProvider>,
+}
+
+#[async_trait]
+impl ResourceAuditLogArchival for ResourceAuditLogArchivalSqlDbRepository {
+    #[tracing::instrument(
+        name = "upsert_resource_audit_retention_policy",
+        skip_all
+    )]
+    async fn upsert_retention_policy(
+        &self,
+        policy: ResourceAuditRetentionPolicy,
+    ) -> Result<ResourceAuditRetentionPolicy, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            creation_err(format!("Failed to get DB connection: {}", e))
+        })?;
+
+        let model = ResourceAuditRetentionPolicyModel {
+            chain_tenant_id: policy.chain_tenant_id.unwrap_or(SYSTEM_TENANT_ID),
+            retention_days: policy.retention_days,
+            legal_hold: policy.legal_hold,
+            updated_at: policy.updated_at.naive_utc(),
+        };
+
+        let record =
+            diesel::insert_into(resource_audit_retention_policy_model::table)
+                .values(&model)
+                .on_conflict(
+                    resource_audit_retention_policy_model::chain_tenant_id,
+                )
+                .do_update()
+                .set(&model)
+                .returning(ResourceAuditRetentionPolicyModel::as_returning())
+                .get_result::<ResourceAuditRetentionPolicyModel>(conn)
+                .map_err(|e| {
+                    creation_err(format!(
+                        "Failed to store resource audit retention policy: {e}"
+                    ))
+                })?;
+
+        Ok(retention_policy_from_model(record))
+    }
+
+    #[tracing::instrument(
+        name = "register_resource_audit_archive_and_purge",
+        skip_all
+    )]
+    async fn register_archive_and_purge(
+        &self,
+        archive: ResourceAuditArchive,
+    ) -> Result<u64, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            creation_err(format!("Failed to get DB connection: {}", e))
+        })?;
+
+        let chain_tenant_id =
+            archive.chain_tenant_id.unwrap_or(SYSTEM_TENANT_ID);
+
+        let model = archive_to_model(&archive);
+
+        //
+        // Same advisory lock as `append_resource_audit_log_row`: no row is
+        // appended to the chain while its archived prefix is being removed.
+        //
+        let outcome = conn
+            .transaction(|conn| {
+                diesel::sql_query(
+                    "SELECT pg_advisory_xact_lock(hashtextextended($1, 0))",
+                )
+                .bind::<Text, _>(format!(
+                    "resource_audit_log:{chain_tenant_id}"
+                ))
+                .execute(conn)?;
+
+                let legal_hold = resource_audit_retention_policy_model::table
+                    .filter(
+                        resource_audit_retention_policy_model::chain_tenant_id
+                            .eq(chain_tenant_id),
+                    )
+                    .select(resource_audit_retention_policy_model::legal_hold)
+                    .first::<bool>(conn)
+                    .optional()?
+                    .unwrap_or(false);
+
+                if legal_hold {
+                    return Ok(Err("the chain is on legal hold".to_string()));
+                }
+
+                let archived_through = resource_audit_archive_model::table
+                    .filter(
+                        resource_audit_archive_model::chain_tenant_id
+                            .eq(chain_tenant_id),
+                    )
+                    .select(diesel::dsl::max(
+                        resource_audit_archive_model::last_seq,
+                    ))
+                    .first::<Option<i64>>(conn)?
+                    .unwrap_or(0);
+
+                if let Some(first_seq) = archive.first_seq {
+                    if first_seq != archived_through + 1 {
+                        return Ok(Err(format!(
+                            "the chain is archived through seq \
+                             {archived_through}, not {}",
+                            first_seq - 1
+                        )));
+                    }
+                }
+
+                diesel::insert_into(resource_audit_archive_model::table)
+                    .values(&model)
+                    .execute(conn)?;
+
+                let purged = delete_archived_rows(conn, chain_tenant_id)?;
+
+                Ok(Ok(purged))
+            })
+            .map_err(|e: diesel::result::Error| {
+                deletion_err(format!(
+                    "Failed to archive resource audit log rows: {e}"
+                ))
+            })?;
+
+        outcome.map_err(|reason| {
+            deletion_err(format!("Resource audit archive refused: {reason}"))
+                .with_exp_true()
+        })
+    }
+
+    #[tracing::instrument(
+        name = "purge_archived_resource_audit_rows",
+        skip_all
+    )]
+    async fn purge_archived_rows(
+        &self,
+        chain_tenant_id: Option<Uuid>,
+    ) -> Result<u64, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            deletion_err(format!("Failed to get DB connection: {}", e))
+        })?;
+
+        let chain_tenant_id = chain_tenant_id.unwrap_or(SYSTEM_TENANT_ID);
+
+        let legal_hold = resource_audit_retention_policy_model::table
+            .filter(
+                resource_audit_retention_policy_model::chain_tenant_id
+                    .eq(chain_tenant_id),
+            )
+            .select(resource_audit_retention_policy_model::legal_hold)
+            .first::<bool>(conn)
+            .optional()
+            .map_err(|e| {
+                deletion_err(format!(
+                    "Failed to fetch resource audit retention policy: {e}"
+                ))
+            })?
+            .unwrap_or(false);
+
+        if legal_hold {
+            return Ok(0);
+        }
+
+        delete_archived_rows(conn, chain_tenant_id).map_err(|e| {
+            deletion_err(format!(
+                "Failed to purge archived resource audit log rows: {e}"
+            ))
+        })
+    }
+
+    #[tracing::instrument(name = "restore_resource_audit_rows", skip_all)]
+    async fn restore_rows(
+        &self,
+        rows: Vec<ResourceAuditLog>,
+    ) -> Result<u64, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            creation_err(format!("Failed to get DB connection: {}", e))
+        })?;
+
+        let models = rows
+            .into_iter()
+            .map(|row| {
+                Ok(ResourceAuditLogModel {
+                    id: row.id,
+                    resource_type: resource_type_to_db_str(&row.resource_type)
+                        .to_owned(),
+                    resource_id: row.resource_id,
+                    tenant_id: row.tenant_id,
+                    event: event_kind_to_db_str(&row.event).to_owned(),
+                    performed_by: serde_json::to_value(&row.performed_by)
+                        .map_err(|e| {
+                            creation_err(format!(
+                                "Failed to encode performed_by: {e}"
+                            ))
+                        })?,
+                    metadata: row.metadata,
+                    created_at: row.created_at.naive_utc(),
+                    chain_seq: row.chain.as_ref().map(|link| link.seq),
+                    prev_hash: row
+                        .chain
+                        .as_ref()
+                        .map(|link| link.prev_hash.to_owned()),
+                    row_hash: row.chain.map(|link| link.hash),
+                    redacted_at: row.redacted_at.map(|at| at.naive_utc()),
+                })
+            })
+            .collect::<Result<Vec<_>, MappedErrors>>()?;
+
+        conn.transaction(|conn| {
+            let mut restored = 0;
+
+            for batch in models.chunks(RESTORE_BATCH_SIZE) {
+                restored += diesel::insert_into(resource_audit_log_model::table)
+                    .values(batch)
+                    .on_conflict_do_nothing()
+                    .execute(conn)? as u64;
+            }
+
+            Ok(restored)
+        })
+        .map_err(|e: diesel::result::Error| {
+            creation_err(format!(
+                "Failed to restore resource audit log rows: {e}"
+            ))
+        })
+    }
+
+    #[tracing::instrument(name = "redact_resource_audit_rows", skip_all)]
+    async fn redact_rows(
+        &self,
+        rows: Vec<ResourceAuditLog>,
+        erasure: NewResourceAuditLogEvent,
+    ) -> Result<u64, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            updating_err(format!("Failed to get DB connection: {}", e))
+        })?;
+
+        let erasure_performed_by = serde_json::to_value(&erasure.performed_by)
+            .map_err(|e| {
+                updating_err(format!("Failed to encode performed_by: {e}"))
+            })?;
+
+        let held_chains = resource_audit_retention_policy_model::table
+            .filter(resource_audit_retention_policy_model::legal_hold.eq(true))
+            .select(resource_audit_retention_policy_model::chain_tenant_id)
+            .load::<Uuid>(conn)
+            .map_err(|e| {
+                updating_err(format!(
+                    "Failed to fetch resource audit retention policies: {e}"
+                ))
+            })?;
+
+        let updates = rows
+            .into_iter()
+            .filter(|row| {
+                !held_chains
+                    .contains(&row.tenant_id.unwrap_or(SYSTEM_TENANT_ID))
+            })
+            .map(|row| {
+                Ok((
+                    row.id,
+                    serde_json::to_value(&row.performed_by).map_err(|e| {
+                        updating_err(format!(
+                            "Failed to encode performed_by: {e}"
+                        ))
+                    })?,
+                    row.metadata,
+                    row.redacted_at.unwrap_or_else(chrono::Utc::now),
+                ))
+            })
+            .collect::<Result<Vec<_>, MappedErrors>>()?;
+
+        conn.transaction(|conn| {
+            let mut redacted = 0;
+
+            for (id, performed_by, metadata, redacted_at) in updates {
+                redacted +=
+                    diesel::update(resource_audit_log_model::table.find(id))
+                        .set((
+                            resource_audit_log_model::performed_by
+                                .eq(performed_by),
+                            resource_audit_log_model::metadata.eq(metadata),
+                            resource_audit_log_model::redacted_at
+                                .eq(Some(redacted_at.naive_utc())),
+                        ))
+                        .execute(conn)? as u64;
+            }
+
+            insert_chained_row(conn, &erasure, erasure_performed_by)?;
+
+            Ok(redacted)
+        })
+        .map_err(|e: diesel::result::Error| {
+            updating_err(format!(
+                "Failed to redact resource audit log rows: {e}"
+            ))
+        })
+    }
+}
+
+/// Delete every row of the chain covered by one of its archives. Mirrors the
+/// condition the `prevent_resource_audit_log_mutation` trigger enforces.
+fn delete_archived_rows(
+    conn: &mut PgConnection,
+    chain_tenant_id: Uuid,
+) -> Result<u64, diesel::result::Error> {
+    let (archived_through, cutoff) = resource_audit_archive_model::table
+        .filter(
+            resource_audit_archive_model::chain_tenant_id.eq(chain_tenant_id),
+        )
+        .select((
+            diesel::dsl::max(resource_audit_archive_model::last_seq),
+            diesel::dsl::max(resource_audit_archive_model::cutoff),
+        ))
+        .first::<(Option<i64>, Option<chrono::NaiveDateTime>)>(conn)?;
+
+    let Some(cutoff) = cutoff else {
+        return Ok(0);
+    };
+
+    diesel::sql_query(
+        "DELETE FROM resource_audit_log \
+         WHERE COALESCE(tenant_id, $1) = $2 \
+           AND ((chain_seq IS NOT NULL AND chain_seq <= $3) \
+             OR (chain_seq IS NULL AND created_at < $4))",
+    )
+    .bind::<SqlUuid, _>(SYSTEM_TENANT_ID)
+    .bind::<SqlUuid, _>(chain_tenant_id)
+    .bind::<Nullable<BigInt>, _>(archived_through)
+    .bind::<Timestamptz, _>(cutoff)
+    .execute(conn)
+    .map(|deleted| deleted as u64)
+}
+
+pub(super) fn archive_to_model(
+    archive: &ResourceAuditArchive,
+) -> ResourceAuditArchiveModel {
+    ResourceAuditArchiveModel {
+        id: archive.id,
+        chain_tenant_id: archive.chain_tenant_id.unwrap_or(SYSTEM_TENANT_ID),
+        first_seq: archive.first_seq,
+        last_seq: archive.last_seq,
+        first_prev_hash: archive.first_prev_hash.to_owned(),
+        last_hash: archive.last_hash.to_owned(),
+        cutoff: archive.cutoff.naive_utc(),
+        row_count: archive.row_count,
+        location: archive.location.to_owned(),
+        sha256: archive.sha256.to_owned(),
+        signature: archive.signature.to_owned(),
+        created_at: archive.created_at.naive_utc(),
+    }
+}
+
+pub(super) fn archive_from_model(
+    record: ResourceAuditArchiveModel,
+) -> ResourceAuditArchive {
+    ResourceAuditArchive {
+        id: record.id,
+        chain_tenant_id: Some(record.chain_tenant_id)
+            .filter(|id| *id != SYSTEM_TENANT_ID),
+        first_seq: record.first_seq,
+        last_seq: record.last_seq,
+        first_prev_hash: record.first_prev_hash,
+        last_hash: record.last_hash,
+        cutoff: record.cutoff.and_utc(),
+        row_count: record.row_count,
+        location: record.location,
+        sha256: record.sha256,
+        signature: record.signature,
+        created_at: record.created_at.and_utc(),
+    }
+}
+
+pub(super) fn retention_policy_from_model(
+    record: ResourceAuditRetentionPolicyModel,
+) -> ResourceAuditRetentionPolicy {
+    ResourceAuditRetentionPolicy {
+        chain_tenant_id: Some(record.chain_tenant_id)
+            .filter(|id| *id != SYSTEM_TENANT_ID),
+        retention_days: record.retention_days,
+        legal_hold: record.legal_hold,
+        updated_at: record.updated_at.and_utc(),
+    }
+}
@@ -27,6 +27,9 @@ pub(super) fn resource_type_to_db_str(
         ResourceAuditResourceType::TenantMeta => "tenant_meta",
         ResourceAuditResourceType::GuestRole => "guest_role",
         ResourceAuditResourceType::Webhook => "webhook",
+        ResourceAuditResourceType::GuestUser => "guest_user",
+        ResourceAuditResourceType::ConnectionString => "connection_string",
+        ResourceAuditResourceType::ErrorCode => "error_code",
     }
 }
 
@@ -41,6 +44,9 @@ pub(super) fn resource_type_from_db_str(
         "tenant_meta" => Ok(ResourceAuditResourceType::TenantMeta),
         "guest_role" => Ok(ResourceAuditResourceType::GuestRole),
         "webhook" => Ok(ResourceAuditResourceType::Webhook),
+        "guest_user" => Ok(ResourceAuditResourceType::GuestUser),
+        "connection_string" => Ok(ResourceAuditResourceType::ConnectionString),
+        "error_code" => Ok(ResourceAuditResourceType::ErrorCode),
         other => {
             Err(format!("Unknown resource_audit_log resource_type: {other}"))
         }
@@ -56,6 +62,7 @@ pub(super) fn event_kind_to_db_str(
         ResourceAuditEventKind::Created => "created",
         ResourceAuditEventKind::Updated => "updated",
         ResourceAuditEventKind::Deleted => "deleted",
+        ResourceAuditEventKind::Accessed => "accessed",
     }
 }
 
@@ -66,6 +73,7 @@ pub(super) fn event_kind_from_db_str(
         "created" => Ok(ResourceAuditEventKind::Created),
         "updated" => Ok(ResourceAuditEventKind::Updated),
         "deleted" => Ok(ResourceAuditEventKind::Deleted),
+        "accessed" => Ok(ResourceAuditEventKind::Accessed),
         other => Err(format!("Unknown resource_audit_log event: {other}")),
     }
 }
@@ -84,6 +92,9 @@ mod tests {
             ResourceAuditResourceType::TenantMeta,
             ResourceAuditResourceType::GuestRole,
             ResourceAuditResourceType::Webhook,
+            ResourceAuditResourceType::GuestUser,
+            ResourceAuditResourceType::ConnectionString,
+            ResourceAuditResourceType::ErrorCode,
         ];
 
         for variant in variants {
@@ -107,6 +118,7 @@ mod tests {
             ResourceAuditEventKind::Created,
             ResourceAuditEventKind::Updated,
             ResourceAuditEventKind::Deleted,
+            ResourceAuditEventKind::Accessed,
         ];
 
         for variant in variants {
@@ -1,19 +1,45 @@
-use super::resource_audit_log_db_encoding::{
-    event_kind_from_db_str, resource_type_from_db_str,
+use super::{
+    resource_audit_log_archival::{
+        archive_from_model, retention_policy_from_model,
+    },
+    resource_audit_log_db_encoding::{
+        event_kind_from_db_str, event_kind_to_db_str,
+        resource_type_from_db_str, resource_type_to_db_str,
+    },
 };
 use crate::{
     models::{
         config::DbPoolProvider,
-        resource_audit_log::ResourceAuditLog as ResourceAuditLogModel,
+        resource_audit_log::{
+            ResourceAuditArchive as ResourceAuditArchiveModel,
+            ResourceAuditCheckpoint as ResourceAuditCheckpointModel,
+            ResourceAuditLog as ResourceAuditLogModel,
+            ResourceAuditRetentionPolicy as ResourceAuditRetentionPolicyModel,
+        },
+    },
+    schema::{
+        resource_audit_archive as resource_audit_archive_model,
+        resource_audit_checkpoint as resource_audit_checkpoint_model,
+        resource_audit_log as resource_audit_log_model,
+        resource_audit_retention_policy as resource_audit_retention_policy_model,
     },
-    schema::resource_audit_log as resource_audit_log_model,
 };
 
 use async_trait::async_trait;
-use diesel::prelude::*;
+use chrono::{DateTime, Utc};
+use diesel::{
+    dsl::sql,
+    prelude::*,
+    sql_types::{Bool, Text},
+};
 use myc_core::domain::{
-    dtos::resource_audit_log::{ResourceAuditLog, ResourceAuditResourceType},
+    dtos::resource_audit_log::{
+        ResourceAuditArchive, ResourceAuditChainLink, ResourceAuditCheckpoint,
+        ResourceAuditLog, ResourceAuditLogCursor, ResourceAuditLogFilter,
+        ResourceAuditResourceType, ResourceAuditRetentionPolicy,
+    },
     entities::ResourceAuditLogFetching,
+    utils::SYSTEM_TENANT_ID,
 };
 use mycelium_base::{
     entities::FetchManyResponseKind,
@@ -129,6 +155,380 @@ impl ResourceAuditLogFetching for ResourceAuditLogFetchingSqlDbRepository {
             records: logs,
         })
     }
+
+    #[tracing::instrument(name = "list_resource_audit_log_filtered", skip_all)]
+    async fn list_filtered(
+        &self,
+        filter: ResourceAuditLogFilter,
+        cursor: Option<ResourceAuditLogCursor>,
+        page_size: i64,
+    ) -> Result<FetchManyResponseKind<ResourceAuditLog>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+        })?;
+
+        let mut query = resource_audit_log_model::table.into_boxed();
+
+        if let Some(resource_type) = filter.resource_type {
+            query = query.filter(
+                resource_audit_log_model::resource_type
+                    .eq(resource_type_to_db_str(&resource_type)),
+            );
+        }
+
+        if let Some(resource_id) = filter.resource_id {
+            query = query
+                .filter(resource_audit_log_model::resource_id.eq(resource_id));
+        }
+
+        if let Some(tenant_id) = filter.tenant_id {
+            query =
+                query.filter(resource_audit_log_model::tenant_id.eq(tenant_id));
+        }
+
+        if let Some(event) = filter.event {
+            query = query.filter(
+                resource_audit_log_model::event
+                    .eq(event_kind_to_db_str(&event)),
+            );
+        }
+
+        if let Some(performed_by) = filter.performed_by {
+            query = query.filter(
+                sql::<Bool>("performed_by ->> 'id' = ")
+                    .bind::<Text, _>(performed_by.to_string()),
+            );
+        }
+
+        if let Some(created_after) = filter.created_after {
+            query = query.filter(
+                resource_audit_log_model::created_at
+                    .ge(created_after.naive_utc()),
+            );
+        }
+
+        if let Some(created_before) = filter.created_before {
+            query = query.filter(
+                resource_audit_log_model::created_at
+                    .lt(created_before.naive_utc()),
+            );
+        }
+
+        if let Some(text) = filter.metadata_contains {
+            query = query.filter(
+                sql::<Bool>("metadata::text ILIKE ")
+                    .bind::<Text, _>(like_pattern(&text))
+                    .sql(" ESCAPE '\\'"),
+            );
+        }
+
+        if let Some(cursor) = cursor {
+            let created_at = cursor.created_at.naive_utc();
+
+            query = query.filter(
+                resource_audit_log_model::created_at.lt(created_at).or(
+                    resource_audit_log_model::created_at
+                        .eq(created_at)
+                        .and(resource_audit_log_model::id.lt(cursor.id)),
+                ),
+            );
+        }
+
+        let records = query
+            .order_by((
+                resource_audit_log_model::created_at.desc(),
+                resource_audit_log_model::id.desc(),
+            ))
+            .limit(page_size)
+            .select(ResourceAuditLogModel::as_select())
+            .load::<ResourceAuditLogModel>(conn)
+            .map_err(|e| {
+                fetching_err(format!(
+                    "Failed to fetch resource audit log: {}",
+                    e
+                ))
+            })?;
+
+        if records.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        let logs = records
+            .into_iter()
+            .map(parse_resource_audit_log_model)
+            .collect::<Result<Vec<_>, String>>()
+            .map_err(fetching_err)?;
+
+        Ok(FetchManyResponseKind::Found(logs))
+    }
+
+    #[tracing::instrument(name = "list_resource_audit_chain_tenants", skip_all)]
+    async fn list_chain_tenants(
+        &self,
+    ) -> Result<FetchManyResponseKind<Option<Uuid>>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+        })?;
+
+        let tenants = resource_audit_log_model::table
+            .filter(resource_audit_log_model::chain_seq.is_not_null())
+            .select(resource_audit_log_model::tenant_id)
+            .distinct()
+            .load::<Option<Uuid>>(conn)
+            .map_err(|e| {
+                fetching_err(format!(
+                    "Failed to fetch resource audit chains: {}",
+                    e
+                ))
+            })?;
+
+        if tenants.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        Ok(FetchManyResponseKind::Found(tenants))
+    }
+
+    #[tracing::instrument(name = "list_resource_audit_chain_page", skip_all)]
+    async fn list_chain_page(
+        &self,
+        chain_tenant_id: Option<Uuid>,
+        after_seq: i64,
+        page_size: i64,
+    ) -> Result<FetchManyResponseKind<ResourceAuditLog>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+        })?;
+
+        let mut query = resource_audit_log_model::table
+            .filter(resource_audit_log_model::chain_seq.gt(after_seq))
+            .into_boxed();
+
+        query = match chain_tenant_id {
+            Some(tenant_id) => {
+                query.filter(resource_audit_log_model::tenant_id.eq(tenant_id))
+            }
+            None => query.filter(resource_audit_log_model::tenant_id.is_null()),
+        };
+
+        let records = query
+            .order_by(resource_audit_log_model::chain_seq.asc())
+            .limit(page_size)
+            .select(ResourceAuditLogModel::as_select())
+            .load::<ResourceAuditLogModel>(conn)
+            .map_err(|e| {
+                fetching_err(format!(
+                    "Failed to fetch resource audit log: {}",
+                    e
+                ))
+            })?;
+
+        if records.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        let logs = records
+            .into_iter()
+            .map(parse_resource_audit_log_model)
+            .collect::<Result<Vec<_>, String>>()
+            .map_err(fetching_err)?;
+
+        Ok(FetchManyResponseKind::Found(logs))
+    }
+
+    #[tracing::instrument(name = "list_resource_audit_checkpoints", skip_all)]
+    async fn list_checkpoints(
+        &self,
+        chain_tenant_id: Option<Uuid>,
+    ) -> Result<FetchManyResponseKind<ResourceAuditCheckpoint>, MappedErrors>
+    {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+        })?;
+
+        let records = resource_audit_checkpoint_model::table
+            .filter(
+                resource_audit_checkpoint_model::chain_tenant_id
+                    .eq(chain_tenant_id.unwrap_or(SYSTEM_TENANT_ID)),
+            )
+            .order_by(resource_audit_checkpoint_model::chain_seq.asc())
+            .select(ResourceAuditCheckpointModel::as_select())
+            .load::<ResourceAuditCheckpointModel>(conn)
+            .map_err(|e| {
+                fetching_err(format!(
+                    "Failed to fetch resource audit checkpoints: {}",
+                    e
+                ))
+            })?;
+
+        if records.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        Ok(FetchManyResponseKind::Found(
+            records
+                .into_iter()
+                .map(|record| ResourceAuditCheckpoint {
+                    id: record.id,
+                    chain_tenant_id: Some(record.chain_tenant_id)
+                        .filter(|id| *id != SYSTEM_TENANT_ID),
+                    seq: record.chain_seq,
+                    root_hash: record.root_hash,
+                    signature: record.signature,
+                    created_at: record.created_at.and_utc(),
+                })
+                .collect(),
+        ))
+    }
+
+    #[tracing::instrument(
+        name = "list_resource_audit_unchained_page",
+        skip_all
+    )]
+    async fn list_unchained_page(
+        &self,
+        chain_tenant_id: Option<Uuid>,
+        created_from: Option<DateTime<Utc>>,
+        created_before: DateTime<Utc>,
+        page_size: i64,
+        skip: i64,
+    ) -> Result<FetchManyResponseKind<ResourceAuditLog>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+        })?;
+
+        let mut query = resource_audit_log_model::table
+            .filter(resource_audit_log_model::chain_seq.is_null())
+            .filter(
+                resource_audit_log_model::created_at
+                    .lt(created_before.naive_utc()),
+            )
+            .into_boxed();
+
+        query = match chain_tenant_id {
+            Some(tenant_id) => {
+                query.filter(resource_audit_log_model::tenant_id.eq(tenant_id))
+            }
+            None => query.filter(resource_audit_log_model::tenant_id.is_null()),
+        };
+
+        if let Some(created_from) = created_from {
+            query = query.filter(
+                resource_audit_log_model::created_at
+                    .ge(created_from.naive_utc()),
+            );
+        }
+
+        let records = query
+            .order_by((
+                resource_audit_log_model::created_at.asc(),
+                resource_audit_log_model::id.asc(),
+            ))
+            .limit(page_size)
+            .offset(skip)
+            .select(ResourceAuditLogModel::as_select())
+            .load::<ResourceAuditLogModel>(conn)
+            .map_err(|e| {
+                fetching_err(format!(
+                    "Failed to fetch resource audit log: {}",
+                    e
+                ))
+            })?;
+
+        if records.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        let logs = records
+            .into_iter()
+            .map(parse_resource_audit_log_model)
+            .collect::<Result<Vec<_>, String>>()
+            .map_err(fetching_err)?;
+
+        Ok(FetchManyResponseKind::Found(logs))
+    }
+
+    #[tracing::instrument(name = "list_resource_audit_archives", skip_all)]
+    async fn list_archives(
+        &self,
+        chain_tenant_id: Option<Uuid>,
+    ) -> Result<FetchManyResponseKind<ResourceAuditArchive>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+        })?;
+
+        let records = resource_audit_archive_model::table
+            .filter(
+                resource_audit_archive_model::chain_tenant_id
+                    .eq(chain_tenant_id.unwrap_or(SYSTEM_TENANT_ID)),
+            )
+            .order_by((
+                resource_audit_archive_model::created_at.asc(),
+                resource_audit_archive_model::id.asc(),
+            ))
+            .select(ResourceAuditArchiveModel::as_select())
+            .load::<ResourceAuditArchiveModel>(conn)
+            .map_err(|e| {
+                fetching_err(format!(
+                    "Failed to fetch resource audit archives: {}",
+                    e
+                ))
+            })?;
+
+        if records.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        Ok(FetchManyResponseKind::Found(
+            records.into_iter().map(archive_from_model).collect(),
+        ))
+    }
+
+    #[tracing::instrument(
+        name = "list_resource_audit_retention_policies",
+        skip_all
+    )]
+    async fn list_retention_policies(
+        &self,
+    ) -> Result<FetchManyResponseKind<ResourceAuditRetentionPolicy>, MappedErrors>
+    {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+        })?;
+
+        let records = resource_audit_retention_policy_model::table
+            .order_by(resource_audit_retention_policy_model::chain_tenant_id)
+            .select(ResourceAuditRetentionPolicyModel::as_select())
+            .load::<ResourceAuditRetentionPolicyModel>(conn)
+            .map_err(|e| {
+                fetching_err(format!(
+                    "Failed to fetch resource audit retention policies: {}",
+                    e
+                ))
+            })?;
+
+        if records.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        Ok(FetchManyResponseKind::Found(
+            records
+                .into_iter()
+                .map(retention_policy_from_model)
+                .collect(),
+        ))
+    }
+}
+
+/// `%text%` with the `LIKE` wildcards of `text` escaped, so the search is a
+/// plain substring match.
+fn like_pattern(text: &str) -> String {
+    format!(
+        "%{}%",
+        text.replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_")
+    )
 }
 
 fn parse_resource_audit_log_model(
@@ -144,5 +544,16 @@ fn parse_resource_audit_log_model(
             .map_err(|e| format!("Failed to parse performed_by: {e}"))?,
         metadata: record.metadata,
         created_at: record.created_at.and_utc(),
+        chain: match (record.chain_seq, record.prev_hash, record.row_hash) {
+            (Some(seq), Some(prev_hash), Some(hash)) => {
+                Some(ResourceAuditChainLink {
+                    seq,
+                    prev_hash,
+                    hash,
+                })
+            }
+            _ => None,
+        },
+        redacted_at: record.redacted_at.map(|at| at.and_utc()),
     })
 }
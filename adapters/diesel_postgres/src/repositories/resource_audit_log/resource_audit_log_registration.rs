@@ -1,25 +1,28 @@
 use myc_core::domain::{
     dtos::resource_audit_log::NewResourceAuditLogEvent,
-    entities::ResourceAuditLogRegistration,
+    entities::ResourceAuditLogRegistration, utils::ResourceAuditSpill,
 };
 use mycelium_base::utils::errors::MappedErrors;
 
 use async_trait::async_trait;
 use shaku::Component;
-use tokio::sync::mpsc;
+use std::sync::Arc;
 
-/// Non-blocking enqueue into the audit log channel. Does no I/O itself --
-/// the single background dispatcher (wired in `ports/api`) drains this
-/// channel and performs the actual insert.
+/// Write-ahead enqueue of audit log events. Every event is appended to the
+/// `spill` file and flushed to disk on the blocking pool -- the only I/O this
+/// repository ever does -- before the single background dispatcher (wired in
+/// `ports/api`) is woken up to perform the actual insert. Events still in the
+/// file when the process dies are persisted by the next start.
 ///
-/// The `sender` field is deliberately NOT `#[shaku(inject)]`-resolved: it is
-/// supplied via `.with_component_parameters::<ResourceAuditLogRegistrationSqlDbRepository>(...)`
+/// The `spill` field is deliberately NOT `#[shaku(inject)]`-resolved: it is
+/// supplied via
+/// `.with_component_parameters::<ResourceAuditLogRegistrationSqlDbRepository>(...)`
 /// at `SqlAppModule::builder()` time, exactly like `DieselDbPoolProvider`'s
 /// `pool` field.
 #[derive(Component)]
 #[shaku(interface = ResourceAuditLogRegistration)]
 pub struct ResourceAuditLogRegistrationSqlDbRepository {
-    sender: mpsc::Sender<NewResourceAuditLogEvent>,
+    spill: Arc<ResourceAuditSpill>,
 }
 
 #[async_trait]
@@ -31,21 +34,19 @@ impl ResourceAuditLogRegistration
         &self,
         event: NewResourceAuditLogEvent,
     ) -> Result<(), MappedErrors> {
-        match self.sender.try_send(event) {
-            Ok(_) => {}
-            Err(mpsc::error::TrySendError::Full(e)) => {
-                tracing::warn!(
-                    resource_type = ?e.resource_type,
-                    resource_id = %e.resource_id,
-                    "resource audit log channel full, dropping event"
-                );
-            }
-            Err(mpsc::error::TrySendError::Closed(_)) => {
-                tracing::error!(
-                    "resource audit log channel closed, dropping event"
-                );
-            }
-        };
+        let spill = self.spill.clone();
+
+        //
+        // `spill` never fails: an event it cannot write is logged in full.
+        //
+        if let Err(err) =
+            tokio::task::spawn_blocking(move || spill.spill(&event)).await
+        {
+            tracing::error!(
+                error = %err,
+                "unable to write resource audit event ahead"
+            );
+        }
 
         Ok(())
     }
@@ -75,26 +76,31 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn create_never_blocks_and_always_returns_ok() {
-        let (sender, mut receiver) =
-            mpsc::channel::<NewResourceAuditLogEvent>(1);
-        let repo = ResourceAuditLogRegistrationSqlDbRepository { sender };
+    async fn create_writes_every_event_ahead_and_always_returns_ok() {
+        let spill = Arc::new(
+            ResourceAuditSpill::open(std::env::temp_dir().join(format!(
+                "myc_audit_registration_test_{}.jsonl",
+                Uuid::new_v4()
+            )))
+            .unwrap(),
+        );
+        let repo = ResourceAuditLogRegistrationSqlDbRepository {
+            spill: spill.clone(),
+        };
 
         let first_event = sample_event();
         let first_event_id = first_event.resource_id;
+        let second_event = sample_event();
+        let second_event_id = second_event.resource_id;
 
-        let first_result = repo.create(first_event).await;
-        assert!(first_result.is_ok());
-
-        // Capacity is 1 and nothing has drained the channel yet, so this
-        // second call must hit `Full` -- and still return `Ok(())`.
-        let second_result = repo.create(sample_event()).await;
-        assert!(second_result.is_ok());
-
-        let received = receiver.try_recv().expect("expected the first event");
-        assert_eq!(received.resource_id, first_event_id);
+        assert!(repo.create(first_event).await.is_ok());
+        assert!(repo.create(second_event).await.is_ok());
 
-        // Only the first event made it onto the channel.
-        assert!(receiver.try_recv().is_err());
+        // Both events are on disk before `create` returns, in order, for the
+        // dispatcher to persist.
+        let written = spill.take_pending().unwrap();
+        assert_eq!(written.len(), 2);
+        assert_eq!(written[0].resource_id, first_event_id);
+        assert_eq!(written[1].resource_id, second_event_id);
     }
 }
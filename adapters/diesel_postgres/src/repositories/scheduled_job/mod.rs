@@ -0,0 +1,3 @@
+mod scheduled_job_claiming;
+
+pub(super) use scheduled_job_claiming::*;
@@ -0,0 +1,104 @@
+use crate::{
+    models::{
+        config::DbPoolProvider,
+        scheduled_job::ScheduledJob as ScheduledJobModel,
+    },
+    schema::scheduled_job as scheduled_job_model,
+};
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use diesel::prelude::*;
+use myc_core::domain::{
+    dtos::{
+        native_error_codes::NativeErrorCodes,
+        scheduled_job::{ScheduledJob, ScheduledJobRun},
+    },
+    entities::ScheduledJobClaiming,
+};
+use mycelium_base::utils::errors::{updating_err, MappedErrors};
+use shaku::Component;
+use std::sync::Arc;
+
+#[derive(Component)]
+#[shaku(interface = ScheduledJobClaiming)]
+pub struct ScheduledJobClaimingSqlDbRepository {
+    #[shaku(inject)]
+    pub db_config: Arc<dyn DbPoolProvider>,
+}
+
+#[async_trait]
+impl ScheduledJobClaiming for ScheduledJobClaimingSqlDbRepository {
+    #[tracing::instrument(name = "claim_due_run", skip_all)]
+    async fn claim_due_run(
+        &self,
+        job: ScheduledJob,
+        claimed_by: String,
+        interval: Duration,
+    ) -> Result<Option<ScheduledJobRun>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            updating_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let now = Utc::now();
+
+        //
+        // Jobs never run before are due right away
+        //
+        diesel::insert_into(scheduled_job_model::table)
+            .values((
+                scheduled_job_model::name.eq(job.to_string()),
+                scheduled_job_model::next_run_at.eq(now),
+            ))
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .map_err(|e| {
+                updating_err(format!("Failed to register scheduled job: {e}"))
+            })?;
+
+        let record = scheduled_job_model::table
+            .find(job.to_string())
+            .select(ScheduledJobModel::as_select())
+            .first::<ScheduledJobModel>(conn)
+            .map_err(|e| {
+                updating_err(format!("Failed to fetch scheduled job: {e}"))
+            })?;
+
+        if record.next_run_at > now {
+            return Ok(None);
+        }
+
+        //
+        // The update only matches while no other replica moved the next run
+        // forward, so a single replica claims the run.
+        //
+        let claimed = diesel::update(
+            scheduled_job_model::table
+                .filter(scheduled_job_model::name.eq(job.to_string()))
+                .filter(
+                    scheduled_job_model::next_run_at.eq(record.next_run_at),
+                ),
+        )
+        .set((
+            scheduled_job_model::last_run_at.eq(now),
+            scheduled_job_model::next_run_at.eq(now + interval),
+            scheduled_job_model::claimed_by.eq(claimed_by.to_owned()),
+        ))
+        .execute(conn)
+        .map_err(|e| {
+            updating_err(format!("Failed to claim scheduled job: {e}"))
+        })?;
+
+        if claimed == 0 {
+            return Ok(None);
+        }
+
+        Ok(Some(ScheduledJobRun {
+            job,
+            started_at: now,
+            previous_run_at: record.last_run_at,
+            claimed_by,
+        }))
+    }
+}
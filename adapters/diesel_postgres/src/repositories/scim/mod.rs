@@ -0,0 +1,17 @@
+mod shared;
+
+mod scim_credential_deletion;
+mod scim_credential_fetching;
+mod scim_credential_registration;
+mod scim_resource_deletion;
+mod scim_resource_fetching;
+mod scim_resource_registration;
+mod scim_resource_updating;
+
+pub(super) use scim_credential_deletion::*;
+pub(super) use scim_credential_fetching::*;
+pub(super) use scim_credential_registration::*;
+pub(super) use scim_resource_deletion::*;
+pub(super) use scim_resource_fetching::*;
+pub(super) use scim_resource_registration::*;
+pub(super) use scim_resource_updating::*;
@@ -0,0 +1,58 @@
+use crate::{
+    models::config::DbPoolProvider,
+    schema::scim_credential as scim_credential_model,
+};
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use myc_core::domain::{
+    dtos::native_error_codes::NativeErrorCodes,
+    entities::ScimCredentialDeletion,
+};
+use mycelium_base::{
+    entities::DeletionResponseKind,
+    utils::errors::{deletion_err, MappedErrors},
+};
+use shaku::Component;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Component)]
+#[shaku(interface = ScimCredentialDeletion)]
+pub struct ScimCredentialDeletionSqlDbRepository {
+    #[shaku(inject)]
+    pub db_config: Arc<dyn DbPoolProvider>,
+}
+
+#[async_trait]
+impl ScimCredentialDeletion for ScimCredentialDeletionSqlDbRepository {
+    #[tracing::instrument(name = "delete_scim_credential", skip_all)]
+    async fn delete(
+        &self,
+        tenant_id: Uuid,
+        credential_id: Uuid,
+    ) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            deletion_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let deleted = diesel::delete(
+            scim_credential_model::table
+                .filter(scim_credential_model::id.eq(credential_id))
+                .filter(scim_credential_model::tenant_id.eq(tenant_id)),
+        )
+        .execute(conn)
+        .map_err(|e| {
+            deletion_err(format!("Failed to delete SCIM credential: {e}"))
+        })?;
+
+        match deleted {
+            0 => Ok(DeletionResponseKind::NotDeleted(
+                credential_id,
+                "SCIM credential not found".to_string(),
+            )),
+            _ => Ok(DeletionResponseKind::Deleted),
+        }
+    }
+}
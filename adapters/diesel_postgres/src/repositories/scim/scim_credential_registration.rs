@@ -0,0 +1,48 @@
+use super::shared::map_credential_dto_to_model;
+use crate::{
+    models::config::DbPoolProvider,
+    schema::scim_credential as scim_credential_model,
+};
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use myc_core::domain::{
+    dtos::{native_error_codes::NativeErrorCodes, scim::ScimCredential},
+    entities::ScimCredentialRegistration,
+};
+use mycelium_base::{
+    entities::CreateResponseKind,
+    utils::errors::{creation_err, MappedErrors},
+};
+use shaku::Component;
+use std::sync::Arc;
+
+#[derive(Component)]
+#[shaku(interface = ScimCredentialRegistration)]
+pub struct ScimCredentialRegistrationSqlDbRepository {
+    #[shaku(inject)]
+    pub db_config: Arc<dyn DbPoolProvider>,
+}
+
+#[async_trait]
+impl ScimCredentialRegistration for ScimCredentialRegistrationSqlDbRepository {
+    #[tracing::instrument(name = "create_scim_credential", skip_all)]
+    async fn create(
+        &self,
+        credential: ScimCredential,
+    ) -> Result<CreateResponseKind<ScimCredential>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            creation_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        diesel::insert_into(scim_credential_model::table)
+            .values(&map_credential_dto_to_model(&credential)?)
+            .execute(conn)
+            .map_err(|e| {
+                creation_err(format!("Failed to register SCIM credential: {e}"))
+            })?;
+
+        Ok(CreateResponseKind::Created(credential))
+    }
+}
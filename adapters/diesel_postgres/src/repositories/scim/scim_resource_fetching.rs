@@ -0,0 +1,99 @@
+use super::shared::map_resource_model_to_dto;
+use crate::{
+    models::{
+        config::DbPoolProvider,
+        scim_resource::ScimResource as ScimResourceModel,
+    },
+    schema::scim_resource as scim_resource_model,
+};
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use myc_core::domain::{
+    dtos::{
+        native_error_codes::NativeErrorCodes,
+        scim::{ScimResource, ScimResourceKind},
+    },
+    entities::ScimResourceFetching,
+};
+use mycelium_base::{
+    entities::{FetchManyResponseKind, FetchResponseKind},
+    utils::errors::{fetching_err, MappedErrors},
+};
+use shaku::Component;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Component)]
+#[shaku(interface = ScimResourceFetching)]
+pub struct ScimResourceFetchingSqlDbRepository {
+    #[shaku(inject)]
+    pub db_config: Arc<dyn DbPoolProvider>,
+}
+
+#[async_trait]
+impl ScimResourceFetching for ScimResourceFetchingSqlDbRepository {
+    #[tracing::instrument(name = "get_scim_resource", skip_all)]
+    async fn get(
+        &self,
+        tenant_id: Uuid,
+        kind: ScimResourceKind,
+        id: Uuid,
+    ) -> Result<FetchResponseKind<ScimResource, Uuid>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let record = scim_resource_model::table
+            .filter(scim_resource_model::id.eq(id))
+            .filter(scim_resource_model::tenant_id.eq(tenant_id))
+            .filter(scim_resource_model::kind.eq(kind.to_string()))
+            .select(ScimResourceModel::as_select())
+            .first::<ScimResourceModel>(conn)
+            .optional()
+            .map_err(|e| {
+                fetching_err(format!("Failed to fetch SCIM resource: {e}"))
+            })?;
+
+        match record {
+            Some(record) => {
+                Ok(FetchResponseKind::Found(map_resource_model_to_dto(record)?))
+            }
+            None => Ok(FetchResponseKind::NotFound(Some(id))),
+        }
+    }
+
+    #[tracing::instrument(name = "list_scim_resources", skip_all)]
+    async fn list(
+        &self,
+        tenant_id: Uuid,
+        kind: ScimResourceKind,
+    ) -> Result<FetchManyResponseKind<ScimResource>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let records = scim_resource_model::table
+            .filter(scim_resource_model::tenant_id.eq(tenant_id))
+            .filter(scim_resource_model::kind.eq(kind.to_string()))
+            .order(scim_resource_model::created.asc())
+            .select(ScimResourceModel::as_select())
+            .load::<ScimResourceModel>(conn)
+            .map_err(|e| {
+                fetching_err(format!("Failed to list SCIM resources: {e}"))
+            })?;
+
+        if records.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        Ok(FetchManyResponseKind::Found(
+            records
+                .into_iter()
+                .map(map_resource_model_to_dto)
+                .collect::<Result<Vec<_>, _>>()?,
+        ))
+    }
+}
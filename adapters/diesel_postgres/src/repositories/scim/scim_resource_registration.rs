@@ -0,0 +1,63 @@
+use super::shared::map_resource_dto_to_model;
+use crate::{
+    models::config::DbPoolProvider,
+    schema::scim_resource as scim_resource_model,
+};
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use myc_core::domain::{
+    dtos::{native_error_codes::NativeErrorCodes, scim::ScimResource},
+    entities::ScimResourceRegistration,
+};
+use mycelium_base::{
+    entities::CreateResponseKind,
+    utils::errors::{creation_err, MappedErrors},
+};
+use shaku::Component;
+use std::sync::Arc;
+
+#[derive(Component)]
+#[shaku(interface = ScimResourceRegistration)]
+pub struct ScimResourceRegistrationSqlDbRepository {
+    #[shaku(inject)]
+    pub db_config: Arc<dyn DbPoolProvider>,
+}
+
+#[async_trait]
+impl ScimResourceRegistration for ScimResourceRegistrationSqlDbRepository {
+    #[tracing::instrument(name = "create_scim_resource", skip_all)]
+    async fn create(
+        &self,
+        resource: ScimResource,
+    ) -> Result<CreateResponseKind<ScimResource>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            creation_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        //
+        // The display names are unique per tenant and kind without regard to
+        // case, by an expression index the conflict target can not name.
+        //
+        let inserted = diesel::insert_into(scim_resource_model::table)
+            .values(&map_resource_dto_to_model(&resource))
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .map_err(|e| {
+                creation_err(format!("Failed to register SCIM resource: {e}"))
+            })?;
+
+        if inserted == 0 {
+            return Ok(CreateResponseKind::NotCreated(
+                resource.to_owned(),
+                format!(
+                    "A resource named {} already exists",
+                    resource.display_name
+                ),
+            ));
+        }
+
+        Ok(CreateResponseKind::Created(resource))
+    }
+}
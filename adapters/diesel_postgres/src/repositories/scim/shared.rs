@@ -0,0 +1,79 @@
+use crate::models::{
+    scim_credential::ScimCredential as ScimCredentialModel,
+    scim_resource::ScimResource as ScimResourceModel,
+};
+
+use myc_core::domain::dtos::scim::{
+    ScimCredential, ScimResource, ScimResourceKind,
+};
+use mycelium_base::utils::errors::{fetching_err, MappedErrors};
+use serde_json::{from_value, to_value};
+use std::str::FromStr;
+
+pub(super) fn map_credential_model_to_dto(
+    model: ScimCredentialModel,
+) -> Result<ScimCredential, MappedErrors> {
+    Ok(ScimCredential {
+        id: model.id,
+        tenant_id: model.tenant_id,
+        name: model.name,
+        secret_hash: model.secret_hash,
+        created_by: from_value(model.created_by).map_err(|e| {
+            fetching_err(format!("Failed to parse the credential author: {e}"))
+        })?,
+        created: model.created,
+    })
+}
+
+pub(super) fn map_credential_dto_to_model(
+    credential: &ScimCredential,
+) -> Result<ScimCredentialModel, MappedErrors> {
+    Ok(ScimCredentialModel {
+        id: credential.id,
+        tenant_id: credential.tenant_id,
+        name: credential.name.to_owned(),
+        secret_hash: credential.secret_hash.to_owned(),
+        created_by: to_value(&credential.created_by).map_err(|e| {
+            fetching_err(format!(
+                "Failed to serialize the credential author: {e}"
+            ))
+        })?,
+        created: credential.created,
+    })
+}
+
+pub(super) fn map_resource_model_to_dto(
+    model: ScimResourceModel,
+) -> Result<ScimResource, MappedErrors> {
+    Ok(ScimResource {
+        id: model.id,
+        tenant_id: model.tenant_id,
+        kind: ScimResourceKind::from_str(&model.kind).map_err(fetching_err)?,
+        external_id: model.external_id,
+        display_name: model.display_name,
+        account_id: model.account_id,
+        guest_role_id: model.guest_role_id,
+        provisioned: model.provisioned,
+        attributes: model.attributes,
+        created: model.created,
+        updated: model.updated,
+    })
+}
+
+pub(super) fn map_resource_dto_to_model(
+    resource: &ScimResource,
+) -> ScimResourceModel {
+    ScimResourceModel {
+        id: resource.id,
+        tenant_id: resource.tenant_id,
+        kind: resource.kind.to_string(),
+        external_id: resource.external_id.to_owned(),
+        display_name: resource.display_name.to_owned(),
+        account_id: resource.account_id,
+        guest_role_id: resource.guest_role_id,
+        provisioned: resource.provisioned,
+        attributes: resource.attributes.to_owned(),
+        created: resource.created,
+        updated: resource.updated,
+    }
+}
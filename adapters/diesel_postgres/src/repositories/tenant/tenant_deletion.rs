@@ -1,17 +1,25 @@
+use super::tenant_fetching::map_tenant_model_to_dto;
 use crate::{
     models::{config::DbPoolProvider, tenant::Tenant as TenantModel},
     schema::{
+        account as account_model, guest_role as guest_role_model,
+        guest_role_children as guest_role_children_model,
+        guest_user as guest_user_model,
+        guest_user_on_account as guest_user_on_account_model,
         owner_on_tenant as owner_on_tenant_model, tenant as tenant_model,
+        tenant_email_template as tenant_email_template_model,
         user as users_model,
     },
 };
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use diesel::{prelude::*, QueryDsl};
 use myc_core::domain::{
     dtos::{
-        email::Email, native_error_codes::NativeErrorCodes,
-        tenant::TenantMetaKey,
+        email::Email,
+        native_error_codes::NativeErrorCodes,
+        tenant::{EmailTemplateKind, TenantMetaKey},
     },
     entities::TenantDeletion,
 };
@@ -71,6 +79,130 @@ impl TenantDeletion for TenantDeletionSqlDbRepository {
         }
     }
 
+    #[tracing::instrument(name = "purge_tenant", skip_all)]
+    async fn purge(
+        &self,
+        id: Uuid,
+        trashed_before: DateTime<Utc>,
+    ) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            deletion_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        //
+        // Accounts, guest users and guest role links carry no cascading
+        // foreign key, so they are deleted before the tenant. Tags, meta,
+        // owners, email templates and the encrypted DEK go with the tenant
+        // row itself.
+        //
+        let not_purged = conn
+            .transaction::<Option<&str>, diesel::result::Error, _>(|conn| {
+                //
+                // The tenant may have been restored since it was listed for
+                // the purge, so its status is checked again under a lock
+                // held until the purge commits.
+                //
+                let tenant = tenant_model::table
+                    .find(id)
+                    .select(TenantModel::as_select())
+                    .for_update()
+                    .first::<TenantModel>(conn)
+                    .optional()?;
+
+                let Some(tenant) = tenant else {
+                    return Ok(Some("Tenant not found"));
+                };
+
+                if map_tenant_model_to_dto(tenant)
+                    .trashed_at()
+                    .is_none_or(|at| at > trashed_before)
+                {
+                    return Ok(Some("Tenant is no longer trashed"));
+                }
+
+                let account_ids = account_model::table
+                    .filter(account_model::tenant_id.eq(id))
+                    .select(account_model::id)
+                    .load::<Uuid>(conn)?;
+
+                let role_ids = guest_role_model::table
+                    .filter(guest_role_model::tenant_id.eq(id))
+                    .select(guest_role_model::id)
+                    .load::<Uuid>(conn)?;
+
+                let guest_user_ids = guest_user_on_account_model::table
+                    .filter(
+                        guest_user_on_account_model::account_id
+                            .eq_any(&account_ids),
+                    )
+                    .select(guest_user_on_account_model::guest_user_id)
+                    .load::<Uuid>(conn)?;
+
+                diesel::delete(
+                    guest_user_on_account_model::table.filter(
+                        guest_user_on_account_model::account_id
+                            .eq_any(&account_ids),
+                    ),
+                )
+                .execute(conn)?;
+
+                //
+                // Guest users left without accounts were only guests of the
+                // purged tenant
+                //
+                let still_guests = guest_user_on_account_model::table
+                    .filter(
+                        guest_user_on_account_model::guest_user_id
+                            .eq_any(&guest_user_ids),
+                    )
+                    .select(guest_user_on_account_model::guest_user_id)
+                    .load::<Uuid>(conn)?;
+
+                let orphan_guest_user_ids = guest_user_ids
+                    .into_iter()
+                    .filter(|id| !still_guests.contains(id))
+                    .collect::<Vec<_>>();
+
+                diesel::delete(guest_user_model::table.filter(
+                    guest_user_model::guest_role_id.eq_any(&role_ids).or(
+                        guest_user_model::id.eq_any(&orphan_guest_user_ids),
+                    ),
+                ))
+                .execute(conn)?;
+
+                diesel::delete(
+                    guest_role_children_model::table.filter(
+                        guest_role_children_model::parent_id
+                            .eq_any(&role_ids)
+                            .or(guest_role_children_model::child_role_id
+                                .eq_any(&role_ids)),
+                    ),
+                )
+                .execute(conn)?;
+
+                diesel::delete(
+                    account_model::table
+                        .filter(account_model::id.eq_any(&account_ids)),
+                )
+                .execute(conn)?;
+
+                diesel::delete(tenant_model::table.find(id)).execute(conn)?;
+
+                Ok(None)
+            })
+            .map_err(|e| {
+                deletion_err(format!("Failed to purge tenant: {}", e))
+            })?;
+
+        match not_purged {
+            None => Ok(DeletionResponseKind::Deleted),
+            Some(reason) => {
+                Ok(DeletionResponseKind::NotDeleted(id, reason.to_string()))
+            }
+        }
+    }
+
     #[tracing::instrument(name = "delete_tenant_owner", skip_all)]
     async fn delete_owner(
         &self,
@@ -197,4 +329,37 @@ impl TenantDeletion for TenantDeletionSqlDbRepository {
             )),
         }
     }
+
+    #[tracing::instrument(name = "delete_email_template", skip_all)]
+    async fn delete_email_template(
+        &self,
+        tenant_id: Uuid,
+        kind: EmailTemplateKind,
+        locale: String,
+    ) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            deletion_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let deleted = diesel::delete(
+            tenant_email_template_model::table
+                .filter(tenant_email_template_model::tenant_id.eq(tenant_id))
+                .filter(tenant_email_template_model::kind.eq(kind.to_string()))
+                .filter(tenant_email_template_model::locale.eq(&locale)),
+        )
+        .execute(conn)
+        .map_err(|e| {
+            deletion_err(format!("Failed to delete email template: {e}"))
+        })?;
+
+        if deleted == 0 {
+            return Ok(DeletionResponseKind::NotDeleted(
+                tenant_id,
+                format!("Email template {kind}/{locale} not found"),
+            ));
+        }
+
+        Ok(DeletionResponseKind::Deleted)
+    }
 }
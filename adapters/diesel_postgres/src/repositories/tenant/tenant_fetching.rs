@@ -2,8 +2,9 @@ use crate::{
     models::{
         account::Account as AccountModel, config::DbPoolProvider,
         owner_on_tenant::OwnerOnTenant as OwnerOnTenantModel,
-        tenant::Tenant as TenantModel, tenant_tag::TenantTag as TenantTagModel,
-        user::User as UserModel,
+        tenant::Tenant as TenantModel,
+        tenant_email_template::TenantEmailTemplate as TenantEmailTemplateModel,
+        tenant_tag::TenantTag as TenantTagModel, user::User as UserModel,
     },
     repositories::account::map_account_model_to_dto,
     schema::{
@@ -13,20 +14,30 @@ use crate::{
             self as owner_on_tenant_model, dsl as owner_on_tenant_dsl,
         },
         tenant::{self as tenant_model, dsl as tenant_dsl},
+        tenant_email_template as tenant_email_template_model,
         tenant_tag::dsl as tenant_tag_dsl,
         user::{self as user_model},
     },
 };
 
 use async_trait::async_trait;
-use chrono::Local;
-use diesel::{dsl::sql, prelude::*, BelongingToDsl, QueryDsl};
+use chrono::{DateTime, Local, Utc};
+use diesel::{
+    dsl::sql,
+    prelude::*,
+    sql_query,
+    sql_types::{Array, Bool, Uuid as SqlUuid},
+    BelongingToDsl, PgConnection, QueryDsl,
+};
 use myc_core::domain::{
     dtos::{
         native_error_codes::NativeErrorCodes,
         profile::Owner,
         tag::Tag,
-        tenant::{Tenant, TenantMetaKey},
+        tenant::{
+            EmailTemplateKind, Tenant, TenantEmailTemplate, TenantMeta,
+            TenantMetaKey,
+        },
     },
     entities::TenantFetching,
 };
@@ -61,12 +72,7 @@ impl TenantFetching for TenantFetchingSqlDbRepository {
         })?;
 
         let tenant = tenant_model::table
-            .inner_join(owner_on_tenant_model::table)
             .filter(tenant_model::id.eq(id))
-            .filter(
-                owner_on_tenant_model::owner_id
-                    .eq_any(owners_ids.iter().map(|id| id).collect::<Vec<_>>()),
-            )
             .select(TenantModel::as_select())
             .first::<TenantModel>(conn)
             .optional()
@@ -76,6 +82,17 @@ impl TenantFetching for TenantFetchingSqlDbRepository {
 
         match tenant {
             Some(record) => {
+                //
+                // Owners of an ancestor own the tenant too
+                //
+                if !is_owned_through_lineage(conn, record.id, owners_ids)? {
+                    return Ok(FetchResponseKind::NotFound(Some(
+                        id.to_string(),
+                    )));
+                }
+
+                let ancestors = load_ancestors(conn, &record)?;
+
                 let owners = OwnerOnTenantModel::belonging_to(&record)
                     .inner_join(user_model::table)
                     .select(UserModel::as_select())
@@ -120,10 +137,11 @@ impl TenantFetching for TenantFetchingSqlDbRepository {
                     })
                     .collect::<Vec<Tag>>();
 
-                Ok(FetchResponseKind::Found(Tenant {
+                let tenant = Tenant {
                     id: Some(record.id),
                     name: record.name,
                     description: record.description,
+                    parent_id: record.parent_id,
                     meta: record.meta.map(|m| {
                         serde_json::from_value::<HashMap<String, String>>(m)
                             .unwrap()
@@ -160,7 +178,12 @@ impl TenantFetching for TenantFetchingSqlDbRepository {
                         0 => None,
                         _ => Some(tags),
                     },
-                }))
+                    inherited_meta: None,
+                };
+
+                Ok(FetchResponseKind::Found(
+                    tenant.with_inherited_meta(map_ancestors_meta(ancestors)),
+                ))
             }
             None => Ok(FetchResponseKind::NotFound(Some(id.to_string()))),
         }
@@ -194,7 +217,9 @@ impl TenantFetching for TenantFetchingSqlDbRepository {
                         fetching_err(format!("Failed to fetch tags: {}", e))
                     })?;
 
-                let mut tenant = map_tenant_model_to_dto(record);
+                let ancestors = load_ancestors(conn, &record)?;
+                let mut tenant = map_tenant_model_to_dto(record)
+                    .with_inherited_meta(map_ancestors_meta(ancestors));
 
                 let tags = tags
                     .into_iter()
@@ -245,6 +270,8 @@ impl TenantFetching for TenantFetchingSqlDbRepository {
 
         match record {
             Some(record) => {
+                let ancestors = load_ancestors(conn, &record)?;
+
                 let owners = OwnerOnTenantModel::belonging_to(&record)
                     .inner_join(user_model::table)
                     .select(UserModel::as_select())
@@ -289,10 +316,11 @@ impl TenantFetching for TenantFetchingSqlDbRepository {
                     })
                     .collect::<Vec<Tag>>();
 
-                Ok(FetchResponseKind::Found(Tenant {
+                let tenant = Tenant {
                     id: Some(record.id),
                     name: record.name,
                     description: record.description,
+                    parent_id: record.parent_id,
                     meta: record.meta.map(|m| {
                         serde_json::from_value::<HashMap<String, String>>(m)
                             .unwrap()
@@ -329,7 +357,12 @@ impl TenantFetching for TenantFetchingSqlDbRepository {
                         0 => None,
                         _ => Some(tags),
                     },
-                }))
+                    inherited_meta: None,
+                };
+
+                Ok(FetchResponseKind::Found(
+                    tenant.with_inherited_meta(map_ancestors_meta(ancestors)),
+                ))
             }
             None => Ok(FetchResponseKind::NotFound(Some(id.to_string()))),
         }
@@ -471,13 +504,197 @@ impl TenantFetching for TenantFetchingSqlDbRepository {
             records: tenants,
         })
     }
+
+    #[tracing::instrument(name = "get_email_template", skip_all)]
+    async fn get_email_template(
+        &self,
+        tenant_id: Uuid,
+        kind: EmailTemplateKind,
+        locale: String,
+    ) -> Result<FetchResponseKind<TenantEmailTemplate, String>, MappedErrors>
+    {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let template = tenant_email_template_model::table
+            .filter(tenant_email_template_model::tenant_id.eq(tenant_id))
+            .filter(tenant_email_template_model::kind.eq(kind.to_string()))
+            .filter(tenant_email_template_model::locale.eq(&locale))
+            .select(TenantEmailTemplateModel::as_select())
+            .first::<TenantEmailTemplateModel>(conn)
+            .optional()
+            .map_err(|e| {
+                fetching_err(format!("Failed to fetch email template: {e}"))
+            })?;
+
+        match template {
+            Some(record) => Ok(FetchResponseKind::Found(
+                map_email_template_model_to_dto(record)?,
+            )),
+            None => Ok(FetchResponseKind::NotFound(Some(format!(
+                "{kind}/{locale}"
+            )))),
+        }
+    }
+
+    #[tracing::instrument(name = "list_email_templates", skip_all)]
+    async fn list_email_templates(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<FetchManyResponseKind<TenantEmailTemplate>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let templates = tenant_email_template_model::table
+            .filter(tenant_email_template_model::tenant_id.eq(tenant_id))
+            .order((
+                tenant_email_template_model::kind.asc(),
+                tenant_email_template_model::locale.asc(),
+            ))
+            .select(TenantEmailTemplateModel::as_select())
+            .load::<TenantEmailTemplateModel>(conn)
+            .map_err(|e| {
+                fetching_err(format!("Failed to fetch email templates: {e}"))
+            })?
+            .into_iter()
+            .map(map_email_template_model_to_dto)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if templates.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        Ok(FetchManyResponseKind::Found(templates))
+    }
+
+    #[tracing::instrument(name = "list_descendants", skip_all)]
+    async fn list_descendants(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<FetchManyResponseKind<Tenant>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let descendants = load_descendants(conn, tenant_id)?
+            .into_iter()
+            .map(map_tenant_model_to_dto)
+            .collect::<Vec<Tenant>>();
+
+        if descendants.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        Ok(FetchManyResponseKind::Found(descendants))
+    }
+
+    #[tracing::instrument(name = "list_trashed", skip_all)]
+    async fn list_trashed(
+        &self,
+        trashed_after: Option<DateTime<Utc>>,
+        trashed_before: DateTime<Utc>,
+    ) -> Result<FetchManyResponseKind<Tenant>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        //
+        // The current status is resolved once mapped, so the query only
+        // narrows the tenants down to those ever trashed
+        //
+        let tenants = tenant_model::table
+            .filter(sql::<diesel::sql_types::Bool>(
+                "array_to_string(status, ',') LIKE '%\"trashed\"%'",
+            ))
+            .select(TenantModel::as_select())
+            .load::<TenantModel>(conn)
+            .map_err(|e| {
+                fetching_err(format!("Failed to fetch trashed tenants: {}", e))
+            })?
+            .into_iter()
+            .map(map_tenant_model_to_dto)
+            .filter(|tenant| {
+                tenant.trashed_at().is_some_and(|at| {
+                    at <= trashed_before
+                        && trashed_after.is_none_or(|after| at > after)
+                })
+            })
+            .collect::<Vec<Tenant>>();
+
+        if tenants.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        Ok(FetchManyResponseKind::Found(tenants))
+    }
+
+    #[tracing::instrument(name = "list_owners", skip_all)]
+    async fn list_owners(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<FetchManyResponseKind<Owner>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let owners = owner_on_tenant_model::table
+            .inner_join(user_model::table)
+            .filter(owner_on_tenant_model::tenant_id.eq(tenant_id))
+            .select(UserModel::as_select())
+            .load::<UserModel>(conn)
+            .map_err(|e| {
+                fetching_err(format!("Failed to fetch owners: {}", e))
+            })?
+            .into_iter()
+            .map(|u| Owner {
+                id: u.id,
+                email: u.email,
+                first_name: Some(u.first_name),
+                last_name: Some(u.last_name),
+                username: Some(u.username),
+                is_principal: u.is_principal,
+            })
+            .collect::<Vec<Owner>>();
+
+        if owners.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        Ok(FetchManyResponseKind::Found(owners))
+    }
 }
 
-fn map_tenant_model_to_dto(record: TenantModel) -> Tenant {
+pub(super) fn map_email_template_model_to_dto(
+    record: TenantEmailTemplateModel,
+) -> Result<TenantEmailTemplate, MappedErrors> {
+    let kind =
+        EmailTemplateKind::from_str(&record.kind).map_err(fetching_err)?;
+
+    Ok(TenantEmailTemplate {
+        id: Some(record.id),
+        tenant_id: record.tenant_id,
+        kind,
+        locale: record.locale,
+        subject: record.subject,
+        body: record.body,
+        created: record.created,
+        updated: record.updated,
+    })
+}
+
+pub(super) fn map_tenant_model_to_dto(record: TenantModel) -> Tenant {
     Tenant {
         id: Some(record.id),
         name: record.name,
         description: record.description,
+        parent_id: record.parent_id,
         meta: record.meta.map(|m| {
             serde_json::from_value::<HashMap<String, String>>(m)
                 .unwrap()
@@ -500,5 +717,109 @@ fn map_tenant_model_to_dto(record: TenantModel) -> Tenant {
         owners: Children::Records(vec![]),
         manager: None,
         tags: None,
+        inherited_meta: None,
     }
 }
+
+/// The meta of the ancestors of a tenant, nearest first
+fn map_ancestors_meta(ancestors: Vec<TenantModel>) -> Vec<TenantMeta> {
+    ancestors
+        .into_iter()
+        .filter_map(|ancestor| map_tenant_model_to_dto(ancestor).meta)
+        .collect()
+}
+
+/// The lineage of the tenant bound to `$1`: the tenant itself at depth 0,
+/// then its ancestors, nearest first
+///
+/// The walk stops on missing parents and on cycles.
+pub(crate) const TENANT_LINEAGE_CTE: &str = "
+    WITH RECURSIVE lineage AS (
+        SELECT id, parent_id, 0 AS depth, ARRAY[id] AS path
+        FROM tenant
+        WHERE id = $1
+        UNION ALL
+        SELECT t.id, t.parent_id, l.depth + 1, l.path || t.id
+        FROM tenant t
+        JOIN lineage l ON t.id = l.parent_id
+        WHERE NOT t.id = ANY(l.path)
+    )";
+
+#[derive(QueryableByName)]
+struct LineageOwnership {
+    #[diesel(sql_type = Bool)]
+    owned: bool,
+}
+
+/// Load the ancestors of a tenant, nearest first
+///
+/// The walk stops on missing parents and on cycles.
+pub(crate) fn load_ancestors(
+    conn: &mut PgConnection,
+    record: &TenantModel,
+) -> Result<Vec<TenantModel>, MappedErrors> {
+    if record.parent_id.is_none() {
+        return Ok(vec![]);
+    }
+
+    sql_query(format!(
+        "{TENANT_LINEAGE_CTE}
+        SELECT t.*
+        FROM tenant t
+        JOIN lineage l ON t.id = l.id
+        WHERE l.depth > 0
+        ORDER BY l.depth"
+    ))
+    .bind::<SqlUuid, _>(record.id)
+    .load::<TenantModel>(conn)
+    .map_err(|e| fetching_err(format!("Failed to fetch parent tenants: {e}")))
+}
+
+/// Whether the owners own the tenant, directly or through one of its
+/// ancestors
+pub(crate) fn is_owned_through_lineage(
+    conn: &mut PgConnection,
+    tenant_id: Uuid,
+    owners_ids: Vec<Uuid>,
+) -> Result<bool, MappedErrors> {
+    sql_query(format!(
+        "{TENANT_LINEAGE_CTE}
+        SELECT EXISTS (
+            SELECT 1
+            FROM owner_on_tenant o
+            JOIN lineage l ON o.tenant_id = l.id
+            WHERE o.owner_id = ANY($2)
+        ) AS owned"
+    ))
+    .bind::<SqlUuid, _>(tenant_id)
+    .bind::<Array<SqlUuid>, _>(owners_ids)
+    .get_result::<LineageOwnership>(conn)
+    .map(|ownership| ownership.owned)
+    .map_err(|e| fetching_err(format!("Failed to fetch ownership: {e}")))
+}
+
+/// Load the descendants of a tenant, children before their own children
+pub(crate) fn load_descendants(
+    conn: &mut PgConnection,
+    tenant_id: Uuid,
+) -> Result<Vec<TenantModel>, MappedErrors> {
+    sql_query(
+        "WITH RECURSIVE descendants AS (
+            SELECT id, 1 AS depth, ARRAY[$1, id] AS path
+            FROM tenant
+            WHERE parent_id = $1 AND id <> $1
+            UNION ALL
+            SELECT t.id, d.depth + 1, d.path || t.id
+            FROM tenant t
+            JOIN descendants d ON t.parent_id = d.id
+            WHERE NOT t.id = ANY(d.path)
+        )
+        SELECT t.*
+        FROM tenant t
+        JOIN descendants d ON t.id = d.id
+        ORDER BY d.depth, t.name",
+    )
+    .bind::<SqlUuid, _>(tenant_id)
+    .load::<TenantModel>(conn)
+    .map_err(|e| fetching_err(format!("Failed to fetch child tenants: {e}")))
+}
@@ -3,19 +3,24 @@ use crate::{
         config::DbPoolProvider,
         owner_on_tenant::OwnerOnTenant as OwnerOnTenantModel,
         tenant::Tenant as TenantModel,
+        tenant_email_template::TenantEmailTemplate as TenantEmailTemplateModel,
+    },
+    repositories::tenant::{
+        is_owned_through_lineage, map_email_template_model_to_dto,
     },
     schema::{
         owner_on_tenant as owner_on_tenant_model, tenant as tenant_model,
+        tenant_email_template as tenant_email_template_model,
     },
 };
 
 use async_trait::async_trait;
 use chrono::Local;
-use diesel::prelude::*;
+use diesel::{prelude::*, upsert::excluded};
 use myc_core::domain::{
     dtos::{
         native_error_codes::NativeErrorCodes,
-        tenant::{Tenant, TenantMetaKey},
+        tenant::{Tenant, TenantEmailTemplate, TenantMetaKey},
     },
     entities::TenantRegistration,
 };
@@ -65,6 +70,7 @@ impl TenantRegistration for TenantRegistrationSqlDbRepository {
                     id: Some(record.id),
                     name: record.name,
                     description: record.description,
+                    parent_id: record.parent_id,
                     meta: record
                         .meta
                         .map(|m| serde_json::from_value(m).unwrap()),
@@ -81,6 +87,7 @@ impl TenantRegistration for TenantRegistrationSqlDbRepository {
                     owners: Children::Records(vec![]),
                     manager: None,
                     tags: None,
+                    inherited_meta: None,
                 },
                 "Tenant already exists".to_string(),
             ));
@@ -104,6 +111,7 @@ impl TenantRegistration for TenantRegistrationSqlDbRepository {
             updated: None,
             encrypted_dek: None,
             kek_version: 1,
+            parent_id: tenant.parent_id,
         };
 
         let created = conn
@@ -154,6 +162,7 @@ impl TenantRegistration for TenantRegistrationSqlDbRepository {
             id: Some(created.id),
             name: created.name,
             description: created.description,
+            parent_id: created.parent_id,
             meta: created.meta.map(|m| serde_json::from_value(m).unwrap()),
             status: created
                 .status
@@ -168,6 +177,7 @@ impl TenantRegistration for TenantRegistrationSqlDbRepository {
             owners: Children::Records(vec![]),
             manager: None,
             tags: None,
+            inherited_meta: None,
         }))
     }
 
@@ -184,18 +194,10 @@ impl TenantRegistration for TenantRegistrationSqlDbRepository {
                 .with_code(NativeErrorCodes::MYC00001)
         })?;
 
-        // Verify if the tenant exists and if the user has permission
+        // Verify if the tenant exists and if the user has permission, directly
+        // or through an ancestor tenant
         let tenant = tenant_model::table
-            .inner_join(owner_on_tenant_model::table)
             .filter(tenant_model::id.eq(tenant_id))
-            .filter(
-                owner_on_tenant_model::owner_id.eq_any(
-                    owners_ids
-                        .iter()
-                        .map(|id| id.clone())
-                        .collect::<Vec<Uuid>>(),
-                ),
-            )
             .select(TenantModel::as_select())
             .first::<TenantModel>(conn)
             .optional()
@@ -203,6 +205,13 @@ impl TenantRegistration for TenantRegistrationSqlDbRepository {
                 creation_err(format!("Failed to check tenant: {}", e))
             })?;
 
+        let tenant = match tenant {
+            Some(t) => {
+                is_owned_through_lineage(conn, t.id, owners_ids)?.then_some(t)
+            }
+            None => None,
+        };
+
         let tenant = match tenant {
             Some(t) => t,
             None => {
@@ -230,4 +239,47 @@ impl TenantRegistration for TenantRegistrationSqlDbRepository {
 
         Ok(CreateResponseKind::Created(meta_map))
     }
+
+    #[tracing::instrument(name = "register_email_template", skip_all)]
+    async fn register_email_template(
+        &self,
+        template: TenantEmailTemplate,
+    ) -> Result<TenantEmailTemplate, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            creation_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let record = diesel::insert_into(tenant_email_template_model::table)
+            .values(&TenantEmailTemplateModel {
+                id: Uuid::new_v4(),
+                tenant_id: template.tenant_id,
+                kind: template.kind.to_string(),
+                locale: template.locale,
+                subject: template.subject,
+                body: template.body,
+                created: Local::now(),
+                updated: None,
+            })
+            .on_conflict((
+                tenant_email_template_model::tenant_id,
+                tenant_email_template_model::kind,
+                tenant_email_template_model::locale,
+            ))
+            .do_update()
+            .set((
+                tenant_email_template_model::subject
+                    .eq(excluded(tenant_email_template_model::subject)),
+                tenant_email_template_model::body
+                    .eq(excluded(tenant_email_template_model::body)),
+                tenant_email_template_model::updated.eq(Some(Local::now())),
+            ))
+            .returning(TenantEmailTemplateModel::as_returning())
+            .get_result::<TenantEmailTemplateModel>(conn)
+            .map_err(|e| {
+                creation_err(format!("Failed to register email template: {e}"))
+            })?;
+
+        map_email_template_model_to_dto(record)
+    }
 }
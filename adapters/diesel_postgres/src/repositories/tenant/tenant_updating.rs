@@ -4,6 +4,7 @@ use crate::{
         owner_on_tenant::OwnerOnTenant as OwnerOnTenantModel,
         tenant::Tenant as TenantModel,
     },
+    repositories::tenant::TENANT_LINEAGE_CTE,
     schema::{
         owner_on_tenant as owner_on_tenant_model, tenant as tenant_model,
     },
@@ -11,7 +12,7 @@ use crate::{
 
 use async_trait::async_trait;
 use chrono::Local;
-use diesel::prelude::*;
+use diesel::{prelude::*, sql_query, sql_types::Uuid as SqlUuid};
 use myc_core::domain::{
     dtos::{
         native_error_codes::NativeErrorCodes,
@@ -29,6 +30,12 @@ use shaku::Component;
 use std::{collections::HashMap, str::FromStr, sync::Arc};
 use uuid::Uuid;
 
+#[derive(QueryableByName)]
+struct LineageTenant {
+    #[diesel(sql_type = SqlUuid)]
+    id: Uuid,
+}
+
 #[derive(Component)]
 #[shaku(interface = TenantUpdating)]
 pub struct TenantUpdatingSqlDbRepository {
@@ -65,6 +72,74 @@ impl TenantUpdating for TenantUpdatingSqlDbRepository {
         ))
     }
 
+    #[tracing::instrument(name = "update_tenant_parent", skip_all)]
+    async fn update_tenant_parent(
+        &self,
+        tenant_id: Uuid,
+        parent_id: Option<Uuid>,
+    ) -> Result<UpdatingResponseKind<Tenant>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            updating_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        //
+        // The tenant row and the lineage of the next parent stay locked until
+        // the parent is set, so concurrent moves could not close a cycle
+        // between the check and the update.
+        //
+        let result = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            let tenant = tenant_model::table
+                .find(tenant_id)
+                .select(TenantModel::as_select())
+                .for_update()
+                .first::<TenantModel>(conn)?;
+
+            if let Some(parent_id) = parent_id {
+                let lineage = sql_query(format!(
+                    "{TENANT_LINEAGE_CTE}
+                    SELECT t.id
+                    FROM tenant t
+                    JOIN lineage l ON t.id = l.id
+                    FOR UPDATE OF t"
+                ))
+                .bind::<SqlUuid, _>(parent_id)
+                .load::<LineageTenant>(conn)?;
+
+                if lineage.is_empty() {
+                    return Ok(Err((tenant, "Parent tenant not found")));
+                }
+
+                if lineage.iter().any(|t| t.id == tenant_id) {
+                    return Ok(Err((
+                        tenant,
+                        "A descendant of the tenant could not be its parent",
+                    )));
+                }
+            }
+
+            diesel::update(tenant_model::table.find(tenant_id))
+                .set((
+                    tenant_model::parent_id.eq(parent_id),
+                    tenant_model::updated.eq(Some(Local::now().naive_utc())),
+                ))
+                .get_result::<TenantModel>(conn)
+                .map(Ok)
+        });
+
+        match result.map_err(|e| {
+            updating_err(format!("Failed to update tenant: {}", e))
+        })? {
+            Ok(updated) => Ok(UpdatingResponseKind::Updated(
+                self.map_tenant_model_to_dto(updated),
+            )),
+            Err((tenant, msg)) => Ok(UpdatingResponseKind::NotUpdated(
+                self.map_tenant_model_to_dto(tenant),
+                msg.to_string(),
+            )),
+        }
+    }
+
     #[tracing::instrument(name = "update_tenant_status", skip_all)]
     async fn update_tenant_status(
         &self,
@@ -235,6 +310,7 @@ impl TenantUpdatingSqlDbRepository {
             id: Some(model.id),
             name: model.name,
             description: model.description,
+            parent_id: model.parent_id,
             meta: model.meta.map(|m| {
                 serde_json::from_value::<HashMap<String, String>>(m)
                     .unwrap()
@@ -257,6 +333,7 @@ impl TenantUpdatingSqlDbRepository {
             owners: Children::Records(vec![]),
             manager: None,
             tags: None,
+            inherited_meta: None,
         }
     }
 }
@@ -8,8 +8,8 @@ use crate::{
 };
 
 use async_trait::async_trait;
-use chrono::Local;
-use diesel::RunQueryDsl;
+use chrono::{DateTime, Local, Utc};
+use diesel::{sql_types::Timestamptz, RunQueryDsl};
 use myc_core::domain::{
     dtos::{
         native_error_codes::NativeErrorCodes,
@@ -196,4 +196,49 @@ impl TokenFetching for TokenFetchingSqlDbRepository {
             Err(e) => Err(e),
         }
     }
+
+    #[tracing::instrument(
+        name = "list_connection_strings_expiring_between",
+        skip_all
+    )]
+    async fn list_connection_strings_expiring_between(
+        &self,
+        expiring_from: DateTime<Utc>,
+        expiring_before: DateTime<Utc>,
+    ) -> Result<FetchManyResponseKind<PublicConnectionStringInfo>, MappedErrors>
+    {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let sql = r#"
+            SELECT id, innerid, accountid, email, name, expiration, createdat, scope
+            FROM public_connection_string_info
+            WHERE expiration >= $1 AND expiration < $2
+            ORDER BY expiration ASC
+        "#;
+
+        let rows = diesel::sql_query(sql)
+            .bind::<Timestamptz, _>(expiring_from)
+            .bind::<Timestamptz, _>(expiring_before)
+            .load::<PublicConnectionStringInfoModel>(conn)
+            .map_err(|e| {
+                fetching_err(format!(
+                    "Failed to fetch connection strings: {}",
+                    e
+                ))
+            })?;
+
+        if rows.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        let connection_strings = rows
+            .into_iter()
+            .map(map_public_connection_string_info_model_to_dto)
+            .collect::<Result<Vec<_>, MappedErrors>>()?;
+
+        Ok(FetchManyResponseKind::Found(connection_strings))
+    }
 }
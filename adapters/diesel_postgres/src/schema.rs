@@ -23,6 +23,25 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    bulk_import (id) {
+        id -> Uuid,
+        tenant_id -> Uuid,
+        #[max_length = 64]
+        checksum -> Varchar,
+        #[max_length = 16]
+        status -> Varchar,
+        import_rows -> Jsonb,
+        row_results -> Jsonb,
+        created_by -> Jsonb,
+        created -> Timestamptz,
+        #[max_length = 255]
+        claimed_by -> Nullable<Varchar>,
+        started_at -> Nullable<Timestamptz>,
+        finished_at -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::table! {
     account_tag (id) {
         id -> Uuid,
@@ -46,6 +65,19 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    error_code_translation (prefix, code, locale) {
+        prefix -> Varchar,
+        code -> Int4,
+        #[max_length = 16]
+        locale -> Varchar,
+        #[max_length = 255]
+        message -> Varchar,
+        #[max_length = 255]
+        details -> Nullable<Varchar>,
+    }
+}
+
 diesel::table! {
     guest_role (id) {
         id -> Uuid,
@@ -57,8 +89,10 @@ diesel::table! {
         #[max_length = 140]
         slug -> Varchar,
         system -> Bool,
+        tenant_id -> Nullable<Uuid>,
         created -> Timestamptz,
         updated -> Nullable<Timestamptz>,
+        permissions -> Nullable<Array<Text>>,
     }
 }
 
@@ -81,6 +115,9 @@ diesel::table! {
         created -> Timestamptz,
         updated -> Nullable<Timestamptz>,
         was_verified -> Bool,
+        invited_by -> Nullable<Jsonb>,
+        invitation_sent_at -> Nullable<Timestamptz>,
+        invitation_expires_at -> Nullable<Timestamptz>,
     }
 }
 
@@ -91,6 +128,8 @@ diesel::table! {
         created -> Timestamptz,
         permit_flags -> Nullable<Array<Text>>,
         deny_flags -> Nullable<Array<Text>>,
+        valid_from -> Nullable<Timestamptz>,
+        valid_until -> Nullable<Timestamptz>,
     }
 }
 
@@ -125,6 +164,58 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    resource_audit_retention_policy (chain_tenant_id) {
+        chain_tenant_id -> Uuid,
+        retention_days -> Nullable<Int4>,
+        legal_hold -> Bool,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    scheduled_job (name) {
+        #[max_length = 64]
+        name -> Varchar,
+        last_run_at -> Nullable<Timestamptz>,
+        next_run_at -> Timestamptz,
+        #[max_length = 255]
+        claimed_by -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    scim_credential (id) {
+        id -> Uuid,
+        tenant_id -> Uuid,
+        #[max_length = 128]
+        name -> Varchar,
+        #[max_length = 64]
+        secret_hash -> Varchar,
+        created_by -> Jsonb,
+        created -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    scim_resource (id) {
+        id -> Uuid,
+        tenant_id -> Uuid,
+        #[max_length = 16]
+        kind -> Varchar,
+        #[max_length = 255]
+        external_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        display_name -> Varchar,
+        account_id -> Nullable<Uuid>,
+        guest_role_id -> Nullable<Uuid>,
+        provisioned -> Bool,
+        attributes -> Jsonb,
+        created -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::table! {
     tenant (id) {
         id -> Uuid,
@@ -137,6 +228,7 @@ diesel::table! {
         updated -> Nullable<Timestamptz>,
         encrypted_dek -> Nullable<Text>,
         kek_version -> Integer,
+        parent_id -> Nullable<Uuid>,
     }
 }
 
@@ -150,6 +242,22 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    tenant_email_template (id) {
+        id -> Uuid,
+        tenant_id -> Uuid,
+        #[max_length = 64]
+        kind -> Varchar,
+        #[max_length = 16]
+        locale -> Varchar,
+        #[max_length = 255]
+        subject -> Varchar,
+        body -> Text,
+        created -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::table! {
     token (id) {
         id -> Int4,
@@ -228,6 +336,31 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    message_delivery (id) {
+        id -> Uuid,
+        #[max_length = 255]
+        recipient -> Varchar,
+        subject -> Text,
+        #[max_length = 16]
+        status -> Varchar,
+        detail -> Nullable<Text>,
+        created -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    email_suppression (email) {
+        #[max_length = 255]
+        email -> Varchar,
+        #[max_length = 32]
+        reason -> Varchar,
+        detail -> Nullable<Text>,
+        created -> Timestamptz,
+    }
+}
+
 diesel::table! {
     healthcheck_logs (service_id, checked_at) {
         service_id -> Uuid,
@@ -269,27 +402,66 @@ diesel::table! {
         performed_by -> Jsonb,
         metadata -> Jsonb,
         created_at -> Timestamptz,
+        chain_seq -> Nullable<Int8>,
+        prev_hash -> Nullable<Text>,
+        row_hash -> Nullable<Text>,
+        redacted_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    resource_audit_archive (id) {
+        id -> Uuid,
+        chain_tenant_id -> Uuid,
+        first_seq -> Nullable<Int8>,
+        last_seq -> Nullable<Int8>,
+        first_prev_hash -> Nullable<Text>,
+        last_hash -> Nullable<Text>,
+        cutoff -> Timestamptz,
+        row_count -> Int8,
+        location -> Text,
+        sha256 -> Text,
+        signature -> Text,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    resource_audit_checkpoint (id) {
+        id -> Uuid,
+        chain_tenant_id -> Uuid,
+        chain_seq -> Int8,
+        root_hash -> Text,
+        signature -> Text,
+        created_at -> Timestamptz,
     }
 }
 
 diesel::joinable!(account -> tenant (tenant_id));
 diesel::joinable!(account_tag -> account (account_id));
+diesel::joinable!(bulk_import -> tenant (tenant_id));
 diesel::joinable!(guest_user -> guest_role (guest_role_id));
 diesel::joinable!(guest_user_on_account -> account (account_id));
 diesel::joinable!(guest_user_on_account -> guest_user (guest_user_id));
+diesel::joinable!(guest_role -> tenant (tenant_id));
 diesel::joinable!(guest_role_children -> guest_role (child_role_id));
 diesel::joinable!(identity_provider -> user (user_id));
 diesel::joinable!(manager_account_on_tenant -> account (account_id));
 diesel::joinable!(manager_account_on_tenant -> tenant (tenant_id));
 diesel::joinable!(owner_on_tenant -> tenant (tenant_id));
 diesel::joinable!(owner_on_tenant -> user (owner_id));
+diesel::joinable!(scim_credential -> tenant (tenant_id));
+diesel::joinable!(scim_resource -> tenant (tenant_id));
+diesel::joinable!(tenant_email_template -> tenant (tenant_id));
 diesel::joinable!(tenant_tag -> tenant (tenant_id));
 diesel::joinable!(user -> account (account_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     account,
     account_tag,
+    bulk_import,
     error_code,
+    error_code_translation,
     guest_role,
     guest_role_children,
     guest_user,
@@ -298,7 +470,10 @@ diesel::allow_tables_to_appear_in_same_query!(
     identity_provider,
     manager_account_on_tenant,
     owner_on_tenant,
+    scim_credential,
+    scim_resource,
     tenant,
+    tenant_email_template,
     tenant_tag,
     token,
     user,
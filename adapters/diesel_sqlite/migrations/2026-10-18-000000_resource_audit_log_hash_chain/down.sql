@@ -0,0 +1,7 @@
+DROP TRIGGER IF EXISTS trg_resource_audit_checkpoint_no_delete;
+DROP TRIGGER IF EXISTS trg_resource_audit_checkpoint_no_update;
+DROP TABLE IF EXISTS resource_audit_checkpoint;
+DROP INDEX IF EXISTS idx_resource_audit_log_chain;
+ALTER TABLE resource_audit_log DROP COLUMN row_hash;
+ALTER TABLE resource_audit_log DROP COLUMN prev_hash;
+ALTER TABLE resource_audit_log DROP COLUMN chain_seq;
@@ -0,0 +1,43 @@
+-- Tamper-evident hash chain over resource_audit_log. Mirrors the Postgres
+-- migration 20261018_01_resource_audit_log_hash_chain with this adapter's
+-- SQLite type mapping (BIGINT -> INTEGER, UUID/TIMESTAMPTZ -> TEXT).
+--
+-- Rows written before this migration keep NULL chain columns and stay outside
+-- every chain. Rows with a NULL tenant_id share the system chain, keyed by
+-- the nil UUID in both the unique index and resource_audit_checkpoint.
+--
+-- ALTER TABLE ... ADD COLUMN does not fire the immutability triggers, which
+-- are row-level.
+
+ALTER TABLE resource_audit_log ADD COLUMN chain_seq INTEGER;
+ALTER TABLE resource_audit_log ADD COLUMN prev_hash TEXT;
+ALTER TABLE resource_audit_log ADD COLUMN row_hash TEXT;
+
+CREATE UNIQUE INDEX idx_resource_audit_log_chain
+    ON resource_audit_log (
+        COALESCE(tenant_id, '00000000-0000-0000-0000-000000000000'),
+        chain_seq
+    )
+    WHERE chain_seq IS NOT NULL;
+
+CREATE TABLE resource_audit_checkpoint (
+    id TEXT NOT NULL PRIMARY KEY,
+    chain_tenant_id TEXT NOT NULL,
+    chain_seq INTEGER NOT NULL,
+    root_hash TEXT NOT NULL,
+    signature TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    UNIQUE (chain_tenant_id, chain_seq)
+);
+
+CREATE TRIGGER trg_resource_audit_checkpoint_no_update
+BEFORE UPDATE ON resource_audit_checkpoint
+BEGIN
+    SELECT RAISE(ABORT, 'resource_audit_checkpoint is immutable');
+END;
+
+CREATE TRIGGER trg_resource_audit_checkpoint_no_delete
+BEFORE DELETE ON resource_audit_checkpoint
+BEGIN
+    SELECT RAISE(ABORT, 'resource_audit_checkpoint is immutable');
+END;
@@ -0,0 +1,56 @@
+-- Rows using the widened values cannot satisfy the original constraints, so
+-- rolling back drops them and breaks the chains they belong to. Export the
+-- trail first (see the audit export endpoint) if those rows matter.
+
+CREATE TABLE resource_audit_log_new (
+    id TEXT NOT NULL PRIMARY KEY,
+    resource_type TEXT NOT NULL CHECK (resource_type IN
+        ('account', 'account_meta', 'user', 'tenant', 'tenant_meta', 'guest_role', 'webhook')),
+    resource_id TEXT NOT NULL,
+    tenant_id TEXT,
+    event TEXT NOT NULL CHECK (event IN ('created', 'updated', 'deleted')),
+    performed_by TEXT NOT NULL,
+    metadata TEXT NOT NULL DEFAULT '{}',
+    created_at TEXT NOT NULL,
+    chain_seq INTEGER,
+    prev_hash TEXT,
+    row_hash TEXT
+);
+
+INSERT INTO resource_audit_log_new
+SELECT id, resource_type, resource_id, tenant_id, event, performed_by,
+       metadata, created_at, chain_seq, prev_hash, row_hash
+FROM resource_audit_log
+WHERE resource_type IN
+        ('account', 'account_meta', 'user', 'tenant', 'tenant_meta', 'guest_role', 'webhook')
+    AND event IN ('created', 'updated', 'deleted');
+
+DROP TABLE resource_audit_log;
+
+ALTER TABLE resource_audit_log_new RENAME TO resource_audit_log;
+
+CREATE INDEX idx_resource_audit_log_resource
+    ON resource_audit_log (resource_id, created_at DESC);
+
+CREATE INDEX idx_resource_audit_log_tenant
+    ON resource_audit_log (tenant_id, created_at DESC)
+    WHERE tenant_id IS NOT NULL;
+
+CREATE UNIQUE INDEX idx_resource_audit_log_chain
+    ON resource_audit_log (
+        COALESCE(tenant_id, '00000000-0000-0000-0000-000000000000'),
+        chain_seq
+    )
+    WHERE chain_seq IS NOT NULL;
+
+CREATE TRIGGER trg_resource_audit_log_no_update
+BEFORE UPDATE ON resource_audit_log
+BEGIN
+    SELECT RAISE(ABORT, 'resource_audit_log is immutable');
+END;
+
+CREATE TRIGGER trg_resource_audit_log_no_delete
+BEFORE DELETE ON resource_audit_log
+BEGIN
+    SELECT RAISE(ABORT, 'resource_audit_log is immutable');
+END;
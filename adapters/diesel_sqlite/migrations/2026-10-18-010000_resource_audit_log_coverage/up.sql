@@ -0,0 +1,62 @@
+-- Widen resource_audit_log to cover security-relevant events that do not fit
+-- the original create/update/delete shape. Mirrors the Postgres migration
+-- 20261018_02_resource_audit_log_coverage:
+--
+--   resource_type: guest_user, connection_string, error_code
+--   event:         accessed
+--
+-- SQLite cannot alter a CHECK constraint in place, so the table is rebuilt
+-- and its indexes and immutability triggers recreated. DROP TABLE does not
+-- fire row-level DELETE triggers, and the copy preserves every column
+-- verbatim, so existing hash chains still verify.
+
+CREATE TABLE resource_audit_log_new (
+    id TEXT NOT NULL PRIMARY KEY,
+    resource_type TEXT NOT NULL CHECK (resource_type IN
+        ('account', 'account_meta', 'user', 'tenant', 'tenant_meta', 'guest_role', 'webhook',
+         'guest_user', 'connection_string', 'error_code')),
+    resource_id TEXT NOT NULL,
+    tenant_id TEXT,
+    event TEXT NOT NULL CHECK (event IN ('created', 'updated', 'deleted', 'accessed')),
+    performed_by TEXT NOT NULL,
+    metadata TEXT NOT NULL DEFAULT '{}',
+    created_at TEXT NOT NULL,
+    chain_seq INTEGER,
+    prev_hash TEXT,
+    row_hash TEXT
+);
+
+INSERT INTO resource_audit_log_new
+SELECT id, resource_type, resource_id, tenant_id, event, performed_by,
+       metadata, created_at, chain_seq, prev_hash, row_hash
+FROM resource_audit_log;
+
+DROP TABLE resource_audit_log;
+
+ALTER TABLE resource_audit_log_new RENAME TO resource_audit_log;
+
+CREATE INDEX idx_resource_audit_log_resource
+    ON resource_audit_log (resource_id, created_at DESC);
+
+CREATE INDEX idx_resource_audit_log_tenant
+    ON resource_audit_log (tenant_id, created_at DESC)
+    WHERE tenant_id IS NOT NULL;
+
+CREATE UNIQUE INDEX idx_resource_audit_log_chain
+    ON resource_audit_log (
+        COALESCE(tenant_id, '00000000-0000-0000-0000-000000000000'),
+        chain_seq
+    )
+    WHERE chain_seq IS NOT NULL;
+
+CREATE TRIGGER trg_resource_audit_log_no_update
+BEFORE UPDATE ON resource_audit_log
+BEGIN
+    SELECT RAISE(ABORT, 'resource_audit_log is immutable');
+END;
+
+CREATE TRIGGER trg_resource_audit_log_no_delete
+BEFORE DELETE ON resource_audit_log
+BEGIN
+    SELECT RAISE(ABORT, 'resource_audit_log is immutable');
+END;
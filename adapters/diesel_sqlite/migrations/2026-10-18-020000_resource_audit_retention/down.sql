@@ -0,0 +1,12 @@
+DROP TRIGGER IF EXISTS trg_resource_audit_log_no_delete;
+
+CREATE TRIGGER trg_resource_audit_log_no_delete
+BEFORE DELETE ON resource_audit_log
+BEGIN
+    SELECT RAISE(ABORT, 'resource_audit_log is immutable');
+END;
+
+DROP TRIGGER IF EXISTS trg_resource_audit_archive_no_delete;
+DROP TRIGGER IF EXISTS trg_resource_audit_archive_no_update;
+DROP TABLE IF EXISTS resource_audit_archive;
+DROP TABLE IF EXISTS resource_audit_retention_policy;
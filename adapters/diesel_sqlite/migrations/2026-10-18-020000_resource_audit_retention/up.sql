@@ -0,0 +1,69 @@
+-- Retention, archival and legal hold for resource_audit_log. Mirrors the
+-- Postgres migration 20261018_03_resource_audit_retention with this adapter's
+-- SQLite type mapping (BIGINT -> INTEGER, BOOLEAN -> INTEGER (0/1),
+-- UUID/TIMESTAMPTZ -> TEXT). chain_tenant_id is the nil UUID for the system
+-- chain.
+--
+-- The no-delete trigger is recreated with a WHEN clause: a DELETE now goes
+-- through only for rows an archive record covers, and only while the chain
+-- is not on legal hold. UPDATE stays rejected unconditionally.
+
+CREATE TABLE resource_audit_retention_policy (
+    chain_tenant_id TEXT NOT NULL PRIMARY KEY,
+    retention_days INTEGER CHECK (retention_days IS NULL OR retention_days > 0),
+    legal_hold INTEGER NOT NULL DEFAULT 0,
+    updated_at TEXT NOT NULL
+);
+
+CREATE TABLE resource_audit_archive (
+    id TEXT NOT NULL PRIMARY KEY,
+    chain_tenant_id TEXT NOT NULL,
+    first_seq INTEGER,
+    last_seq INTEGER,
+    first_prev_hash TEXT,
+    last_hash TEXT,
+    cutoff TEXT NOT NULL,
+    row_count INTEGER NOT NULL,
+    location TEXT NOT NULL,
+    sha256 TEXT NOT NULL,
+    signature TEXT NOT NULL,
+    created_at TEXT NOT NULL
+);
+
+CREATE UNIQUE INDEX idx_resource_audit_archive_chain
+    ON resource_audit_archive (chain_tenant_id, last_seq)
+    WHERE last_seq IS NOT NULL;
+
+CREATE TRIGGER trg_resource_audit_archive_no_update
+BEFORE UPDATE ON resource_audit_archive
+BEGIN
+    SELECT RAISE(ABORT, 'resource_audit_archive is immutable');
+END;
+
+CREATE TRIGGER trg_resource_audit_archive_no_delete
+BEFORE DELETE ON resource_audit_archive
+BEGIN
+    SELECT RAISE(ABORT, 'resource_audit_archive is immutable');
+END;
+
+DROP TRIGGER trg_resource_audit_log_no_delete;
+
+CREATE TRIGGER trg_resource_audit_log_no_delete
+BEFORE DELETE ON resource_audit_log
+WHEN EXISTS (
+    SELECT 1 FROM resource_audit_retention_policy p
+    WHERE p.chain_tenant_id =
+              COALESCE(OLD.tenant_id, '00000000-0000-0000-0000-000000000000')
+      AND p.legal_hold
+) OR NOT EXISTS (
+    SELECT 1 FROM resource_audit_archive a
+    WHERE a.chain_tenant_id =
+              COALESCE(OLD.tenant_id, '00000000-0000-0000-0000-000000000000')
+      AND (
+          (OLD.chain_seq IS NOT NULL AND OLD.chain_seq <= a.last_seq)
+          OR (OLD.chain_seq IS NULL AND OLD.created_at < a.cutoff)
+      )
+)
+BEGIN
+    SELECT RAISE(ABORT, 'resource_audit_log is immutable');
+END;
@@ -0,0 +1,16 @@
+-- Tenant overrides of the notification emails. Mirrors the Postgres migration
+-- 20261019_01_tenant_email_template with this adapter's SQLite type mapping
+-- (UUID/TIMESTAMPTZ -> TEXT).
+
+CREATE TABLE tenant_email_template (
+    id TEXT NOT NULL PRIMARY KEY,
+    tenant_id TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    locale TEXT NOT NULL,
+    subject TEXT NOT NULL,
+    body TEXT NOT NULL,
+    created TEXT NOT NULL,
+    updated TEXT,
+    CONSTRAINT unique_tenant_email_template UNIQUE (tenant_id, kind, locale),
+    FOREIGN KEY (tenant_id) REFERENCES tenant(id) ON DELETE CASCADE
+);
@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS email_suppression;
+DROP INDEX IF EXISTS idx_message_delivery_recipient;
+DROP TABLE IF EXISTS message_delivery;
@@ -0,0 +1,25 @@
+-- Delivery status of sent emails and the email suppression list. Mirrors the
+-- Postgres migration 20261019_02_email_delivery with this adapter's SQLite
+-- type mapping (UUID/TIMESTAMPTZ -> TEXT).
+
+CREATE TABLE message_delivery (
+    id TEXT NOT NULL PRIMARY KEY,
+    recipient TEXT NOT NULL,
+    subject TEXT NOT NULL,
+    status TEXT NOT NULL CHECK (
+        status IN ('sent', 'failed', 'delivered', 'bounced', 'complained')
+    ),
+    detail TEXT,
+    created TEXT NOT NULL,
+    updated TEXT
+);
+
+CREATE INDEX idx_message_delivery_recipient
+    ON message_delivery (recipient, created DESC);
+
+CREATE TABLE email_suppression (
+    email TEXT NOT NULL PRIMARY KEY,
+    reason TEXT NOT NULL CHECK (reason IN ('hardBounce', 'complaint')),
+    detail TEXT,
+    created TEXT NOT NULL
+);
@@ -0,0 +1,13 @@
+-- Translations of the error code messages. Mirrors the Postgres migration
+-- 20261019_03_error_code_translation with this adapter's SQLite type mapping
+-- (VARCHAR -> TEXT).
+
+CREATE TABLE error_code_translation (
+    prefix TEXT NOT NULL,
+    code INTEGER NOT NULL,
+    locale TEXT NOT NULL,
+    message TEXT NOT NULL,
+    details TEXT,
+    PRIMARY KEY (prefix, code, locale),
+    FOREIGN KEY (prefix, code) REFERENCES error_code(prefix, code) ON DELETE CASCADE
+);
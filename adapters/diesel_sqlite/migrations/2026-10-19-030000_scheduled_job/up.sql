@@ -0,0 +1,10 @@
+-- Runs of the notification scheduler jobs. Mirrors the Postgres migration
+-- 20261019_04_scheduled_job with this adapter's SQLite type mapping
+-- (VARCHAR -> TEXT, TIMESTAMPTZ -> TEXT).
+
+CREATE TABLE scheduled_job (
+    name TEXT PRIMARY KEY NOT NULL,
+    last_run_at TEXT,
+    next_run_at TEXT NOT NULL,
+    claimed_by TEXT
+);
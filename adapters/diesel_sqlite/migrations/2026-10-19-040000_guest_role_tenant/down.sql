@@ -0,0 +1,58 @@
+-- Tenant roles cannot satisfy the instance-wide unique constraints, so rolling
+-- back drops them, with the guests they were granted to.
+
+DROP VIEW licensed_resources;
+
+DELETE FROM guest_user_on_account WHERE guest_user_id IN (
+    SELECT gu.id FROM guest_user AS gu
+    JOIN guest_role AS gr ON gr.id = gu.guest_role_id
+    WHERE gr.tenant_id IS NOT NULL
+);
+DELETE FROM guest_user WHERE guest_role_id IN (
+    SELECT id FROM guest_role WHERE tenant_id IS NOT NULL
+);
+DELETE FROM guest_role_children
+    WHERE parent_id IN (SELECT id FROM guest_role WHERE tenant_id IS NOT NULL)
+    OR child_role_id IN (SELECT id FROM guest_role WHERE tenant_id IS NOT NULL);
+
+CREATE TABLE guest_role_old (
+    id TEXT NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL,
+    description TEXT,
+    permission INTEGER NOT NULL DEFAULT 0,
+    slug TEXT NOT NULL,
+    system INTEGER NOT NULL DEFAULT 0,
+    created TEXT NOT NULL,
+    updated TEXT,
+    CONSTRAINT unique_guest_role_name UNIQUE (name, permission),
+    CONSTRAINT unique_guest_role_slug UNIQUE (slug, permission)
+);
+
+INSERT INTO guest_role_old
+SELECT id, name, description, permission, slug, system, created, updated
+FROM guest_role
+WHERE tenant_id IS NULL;
+
+DROP TABLE guest_role;
+
+ALTER TABLE guest_role_old RENAME TO guest_role;
+
+CREATE VIEW licensed_resources AS
+SELECT DISTINCT
+    ac.id AS acc_id,
+    ac.name AS acc_name,
+    ac.is_default AS is_acc_std,
+    gr.id AS gr_id,
+    gr.slug AS gr_slug,
+    gr.permission AS gr_perm,
+    gu.email AS gu_email,
+    gu.was_verified AS gu_verified,
+    ac.tenant_id AS tenant_id,
+    ga.permit_flags AS permit_flags,
+    ga.deny_flags AS deny_flags
+FROM guest_user_on_account AS ga
+JOIN guest_user AS gu ON ga.guest_user_id = gu.id
+JOIN guest_role AS gr ON gr.id = gu.guest_role_id
+JOIN account AS ac ON ac.id = ga.account_id
+WHERE ac.is_deleted = 0
+ORDER BY gu_email, gr_slug, acc_id, gr_id;
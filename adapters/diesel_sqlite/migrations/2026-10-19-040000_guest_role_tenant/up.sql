@@ -0,0 +1,64 @@
+-- Tenant-scoped guest roles. Mirrors the Postgres migration
+-- 20261019_05_guest_role_tenant: roles with a `tenant_id` belong to the tenant
+-- catalogue and only license accounts of their own tenant; names and slugs are
+-- unique per tenant (and among the instance-wide roles).
+--
+-- SQLite cannot drop the inline UNIQUE constraints, so the table is rebuilt.
+-- The licensed_resources view is dropped first, since renaming a table fails
+-- while a view references a missing one. Migrations run without
+-- `foreign_keys`, so the guest_role_children and guest_user references keep
+-- pointing to `guest_role` by name.
+
+DROP VIEW licensed_resources;
+
+CREATE TABLE guest_role_new (
+    id TEXT NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL,
+    description TEXT,
+    permission INTEGER NOT NULL DEFAULT 0,
+    slug TEXT NOT NULL,
+    system INTEGER NOT NULL DEFAULT 0,
+    tenant_id TEXT,
+    created TEXT NOT NULL,
+    updated TEXT,
+    FOREIGN KEY (tenant_id) REFERENCES tenant(id) ON DELETE CASCADE
+);
+
+INSERT INTO guest_role_new
+    (id, name, description, permission, slug, system, tenant_id, created, updated)
+SELECT id, name, description, permission, slug, system, NULL, created, updated
+FROM guest_role;
+
+DROP TABLE guest_role;
+
+ALTER TABLE guest_role_new RENAME TO guest_role;
+
+CREATE UNIQUE INDEX unique_guest_role_name
+    ON guest_role (name, permission) WHERE tenant_id IS NULL;
+CREATE UNIQUE INDEX unique_guest_role_slug
+    ON guest_role (slug, permission) WHERE tenant_id IS NULL;
+CREATE UNIQUE INDEX unique_tenant_guest_role_name
+    ON guest_role (tenant_id, name, permission) WHERE tenant_id IS NOT NULL;
+CREATE UNIQUE INDEX unique_tenant_guest_role_slug
+    ON guest_role (tenant_id, slug, permission) WHERE tenant_id IS NOT NULL;
+
+CREATE VIEW licensed_resources AS
+SELECT DISTINCT
+    ac.id AS acc_id,
+    ac.name AS acc_name,
+    ac.is_default AS is_acc_std,
+    gr.id AS gr_id,
+    gr.slug AS gr_slug,
+    gr.permission AS gr_perm,
+    gu.email AS gu_email,
+    gu.was_verified AS gu_verified,
+    ac.tenant_id AS tenant_id,
+    ga.permit_flags AS permit_flags,
+    ga.deny_flags AS deny_flags
+FROM guest_user_on_account AS ga
+JOIN guest_user AS gu ON ga.guest_user_id = gu.id
+JOIN guest_role AS gr ON gr.id = gu.guest_role_id
+JOIN account AS ac ON ac.id = ga.account_id
+WHERE ac.is_deleted = 0
+  AND (gr.tenant_id IS NULL OR gr.tenant_id = ac.tenant_id)
+ORDER BY gu_email, gr_slug, acc_id, gr_id;
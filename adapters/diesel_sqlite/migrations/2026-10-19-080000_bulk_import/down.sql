@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_bulk_import_queue;
+DROP TABLE IF EXISTS bulk_import;
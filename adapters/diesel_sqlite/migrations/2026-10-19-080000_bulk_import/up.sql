@@ -0,0 +1,23 @@
+-- Bulk import jobs. Mirrors the Postgres migration 20261019_09_bulk_import
+-- with this adapter's SQLite type mapping (UUID -> TEXT, JSONB -> TEXT,
+-- TIMESTAMPTZ -> TEXT).
+
+CREATE TABLE bulk_import (
+    id TEXT PRIMARY KEY NOT NULL,
+    tenant_id TEXT NOT NULL REFERENCES tenant (id) ON DELETE CASCADE,
+    checksum TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('pending', 'running', 'done', 'failed')),
+    import_rows TEXT NOT NULL,
+    row_results TEXT NOT NULL DEFAULT '[]',
+    created_by TEXT NOT NULL,
+    created TEXT NOT NULL,
+    claimed_by TEXT,
+    started_at TEXT,
+    finished_at TEXT,
+    UNIQUE (tenant_id, checksum)
+);
+
+CREATE INDEX idx_bulk_import_queue
+    ON bulk_import (created)
+    WHERE status IN ('pending', 'running');
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS unique_scim_resource_display_name;
+DROP TABLE IF EXISTS scim_resource;
+DROP TABLE IF EXISTS scim_credential;
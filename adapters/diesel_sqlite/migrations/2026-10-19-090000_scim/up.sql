@@ -0,0 +1,29 @@
+-- SCIM credentials and provisioned resources. Mirrors the Postgres migration
+-- 20261019_10_scim with this adapter's SQLite type mapping (UUID -> TEXT,
+-- JSONB -> TEXT, TIMESTAMPTZ -> TEXT).
+
+CREATE TABLE scim_credential (
+    id TEXT PRIMARY KEY NOT NULL,
+    tenant_id TEXT NOT NULL REFERENCES tenant (id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    secret_hash TEXT NOT NULL UNIQUE,
+    created_by TEXT NOT NULL,
+    created TEXT NOT NULL
+);
+
+CREATE TABLE scim_resource (
+    id TEXT PRIMARY KEY NOT NULL,
+    tenant_id TEXT NOT NULL REFERENCES tenant (id) ON DELETE CASCADE,
+    kind TEXT NOT NULL CHECK (kind IN ('User', 'Group')),
+    external_id TEXT,
+    display_name TEXT NOT NULL,
+    account_id TEXT,
+    guest_role_id TEXT,
+    provisioned BOOLEAN NOT NULL DEFAULT 0,
+    attributes TEXT NOT NULL DEFAULT '{}',
+    created TEXT NOT NULL,
+    updated TEXT
+);
+
+CREATE UNIQUE INDEX unique_scim_resource_display_name
+    ON scim_resource (tenant_id, kind, lower(display_name));
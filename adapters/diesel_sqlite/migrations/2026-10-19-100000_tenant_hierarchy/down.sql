@@ -0,0 +1,3 @@
+DROP INDEX idx_tenant_parent_id;
+
+ALTER TABLE tenant DROP COLUMN parent_id;
@@ -0,0 +1,11 @@
+-- Hierarchical tenants. Mirrors the Postgres migration
+-- 20261019_11_tenant_hierarchy: tenants may have a parent tenant.
+--
+-- SQLite cannot drop columns used by foreign keys, so the column carries no
+-- reference and the tenant deletion detaches the children instead.
+
+ALTER TABLE tenant ADD COLUMN parent_id TEXT;
+
+CREATE INDEX idx_tenant_parent_id
+    ON tenant (parent_id)
+    WHERE parent_id IS NOT NULL;
@@ -0,0 +1,9 @@
+DROP TRIGGER IF EXISTS trg_resource_audit_log_no_update;
+
+CREATE TRIGGER trg_resource_audit_log_no_update
+BEFORE UPDATE ON resource_audit_log
+BEGIN
+    SELECT RAISE(ABORT, 'resource_audit_log is immutable');
+END;
+
+ALTER TABLE resource_audit_log DROP COLUMN redacted_at;
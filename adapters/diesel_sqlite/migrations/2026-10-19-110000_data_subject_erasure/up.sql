@@ -0,0 +1,33 @@
+-- Data subject erasure. Mirrors the Postgres migration
+-- 20261019_12_data_subject_erasure with this adapter's SQLite type mapping
+-- (TIMESTAMPTZ -> TEXT).
+--
+-- The no-update trigger is recreated with a WHEN clause: an UPDATE now goes
+-- through only when it changes nothing but metadata and performed_by, stamps
+-- redacted_at, and the chain is not on legal hold.
+
+ALTER TABLE resource_audit_log ADD COLUMN redacted_at TEXT DEFAULT NULL;
+
+DROP TRIGGER trg_resource_audit_log_no_update;
+
+CREATE TRIGGER trg_resource_audit_log_no_update
+BEFORE UPDATE ON resource_audit_log
+WHEN NEW.redacted_at IS NULL
+  OR NEW.id IS NOT OLD.id
+  OR NEW.resource_type IS NOT OLD.resource_type
+  OR NEW.resource_id IS NOT OLD.resource_id
+  OR NEW.tenant_id IS NOT OLD.tenant_id
+  OR NEW.event IS NOT OLD.event
+  OR NEW.created_at IS NOT OLD.created_at
+  OR NEW.chain_seq IS NOT OLD.chain_seq
+  OR NEW.prev_hash IS NOT OLD.prev_hash
+  OR NEW.row_hash IS NOT OLD.row_hash
+  OR EXISTS (
+      SELECT 1 FROM resource_audit_retention_policy p
+      WHERE p.chain_tenant_id =
+                COALESCE(OLD.tenant_id, '00000000-0000-0000-0000-000000000000')
+        AND p.legal_hold
+  )
+BEGIN
+    SELECT RAISE(ABORT, 'resource_audit_log is immutable');
+END;
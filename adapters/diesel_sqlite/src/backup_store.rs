@@ -0,0 +1,467 @@
+use super::{
+    migration::{provision_database, MIGRATIONS},
+    table_reader::{
+        count_table_rows, list_tables, quote_identifier, read_table_rows,
+        table_columns,
+    },
+};
+
+use diesel::{
+    connection::{
+        AnsiTransactionManager, SimpleConnection, TransactionManager,
+    },
+    result::{DatabaseErrorKind, Error as DieselError},
+    sql_types::Text,
+    Connection, RunQueryDsl, SqliteConnection,
+};
+use diesel_migrations::MigrationHarness;
+use myc_core::domain::{
+    dtos::backup::BackupBackend,
+    utils::{BackupRowWrite, BackupStore, WrittenRows},
+};
+use mycelium_base::utils::errors::{execution_err, fetching_err, MappedErrors};
+use serde_json::{Map, Value};
+
+/// Raw row access to a standalone SQLite database, for `myc-cli backup` and
+/// `myc-cli restore`
+///
+/// Rows are read with `json_object`, so values come as SQLite stores them,
+/// and written back with `json_each`.
+pub struct SqliteBackupStore {
+    conn: SqliteConnection,
+}
+
+impl SqliteBackupStore {
+    /// Open the existing database at `url`
+    ///
+    /// A database with pending migrations was written by an older release
+    /// and is rejected: start the standalone API of this release once first.
+    pub fn open(url: &str) -> Result<Self, MappedErrors> {
+        let mut conn = SqliteConnection::establish(url).map_err(|err| {
+            fetching_err(format!(
+                "Failed to establish SQLite connection at {url}: {err}"
+            ))
+        })?;
+
+        conn.batch_execute(
+            "PRAGMA foreign_keys = ON; PRAGMA busy_timeout = 5000;",
+        )
+        .map_err(|err| {
+            execution_err(format!("Failed to set SQLite pragmas: {err}"))
+        })?;
+
+        let pending =
+            conn.has_pending_migration(MIGRATIONS).map_err(|err| {
+                fetching_err(format!(
+                    "Failed to check SQLite migrations: {err}"
+                ))
+            })?;
+
+        if pending {
+            return execution_err(
+                "The SQLite database has pending migrations: start the \
+                 standalone API of this release once first",
+            )
+            .as_error();
+        }
+
+        Ok(Self { conn })
+    }
+
+    /// Open the database at `url`, creating and migrating it first if needed
+    pub fn provision(url: &str) -> Result<Self, MappedErrors> {
+        provision_database(url)?;
+        Self::open(url)
+    }
+
+    /// Insert `rows` in a single statement, returning the inserted count
+    fn insert(
+        &mut self,
+        table: &str,
+        rows: &[Map<String, Value>],
+        conflict: &str,
+    ) -> Result<u64, DieselError> {
+        let names = rows
+            .first()
+            .map(|row| row.keys().cloned().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        let columns = names
+            .iter()
+            .map(|name| quote_identifier(name))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let values = names
+            .iter()
+            .map(|name| {
+                format!(
+                    "json_extract(value, '$.\"{}\"')",
+                    name.replace('\'', "''").replace('"', "\\\"")
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let rows =
+            Value::Array(rows.iter().cloned().map(Value::Object).collect())
+                .to_string();
+
+        //
+        // `WHERE true` keeps the `ON CONFLICT` clause from being parsed as a
+        // join constraint of the select.
+        //
+        diesel::sql_query(format!(
+            "INSERT INTO {} ({columns}) \
+             SELECT {values} FROM json_each(?) WHERE true {conflict}",
+            quote_identifier(table)
+        ))
+        .bind::<Text, _>(rows)
+        .execute(&mut self.conn)
+        .map(|count| count as u64)
+    }
+}
+
+impl BackupStore for SqliteBackupStore {
+    fn backend(&self) -> BackupBackend {
+        BackupBackend::Sqlite
+    }
+
+    fn tables(&mut self) -> Result<Vec<String>, MappedErrors> {
+        list_tables(&mut self.conn)
+    }
+
+    fn count_rows(
+        &mut self,
+        table: &str,
+        filter: Option<&str>,
+    ) -> Result<u64, MappedErrors> {
+        count_table_rows(&mut self.conn, table, filter)
+    }
+
+    fn read_rows(
+        &mut self,
+        table: &str,
+        filter: Option<&str>,
+        offset: u64,
+        limit: u64,
+    ) -> Result<Vec<Map<String, Value>>, MappedErrors> {
+        read_table_rows(&mut self.conn, table, filter, offset, limit)
+    }
+
+    fn delete_rows(
+        &mut self,
+        table: &str,
+        filter: &str,
+    ) -> Result<u64, MappedErrors> {
+        diesel::sql_query(format!(
+            "DELETE FROM {} WHERE {filter}",
+            quote_identifier(table)
+        ))
+        .execute(&mut self.conn)
+        .map(|count| count as u64)
+        .map_err(|err| {
+            execution_err(format!(
+                "Failed to delete the rows of {table}: {err}"
+            ))
+        })
+    }
+
+    fn write_rows(
+        &mut self,
+        table: &str,
+        rows: Vec<Map<String, Value>>,
+        source: BackupBackend,
+        write: BackupRowWrite,
+        skip_dangling: bool,
+    ) -> Result<WrittenRows, MappedErrors> {
+        if source != BackupBackend::Sqlite {
+            return execution_err(format!(
+                "Rows of a {source} backup cannot be written to SQLite"
+            ))
+            .as_error();
+        }
+
+        let columns = table_columns(&mut self.conn, table)?;
+
+        for row in rows.iter() {
+            if let Some(name) = row
+                .keys()
+                .find(|name| !columns.iter().any(|(column, _)| column == *name))
+            {
+                return execution_err(format!(
+                    "Column {table}.{name} does not exist in the target \
+                     database"
+                ))
+                .as_error();
+            }
+        }
+
+        let conflict = match write {
+            BackupRowWrite::Insert => String::new(),
+            BackupRowWrite::InsertMissing => {
+                "ON CONFLICT DO NOTHING".to_string()
+            }
+            BackupRowWrite::Upsert => {
+                let mut keys = columns
+                    .iter()
+                    .filter(|(_, pk)| *pk > 0)
+                    .collect::<Vec<_>>();
+
+                keys.sort_by_key(|(_, pk)| *pk);
+
+                let updates = rows
+                    .first()
+                    .map(|row| {
+                        row.keys()
+                            .filter(|name| {
+                                !keys.iter().any(|(key, _)| key == *name)
+                            })
+                            .map(|name| {
+                                let name = quote_identifier(name);
+                                format!("{name} = excluded.{name}")
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                    .unwrap_or_default();
+
+                match keys.is_empty() || updates.is_empty() {
+                    true => "ON CONFLICT DO NOTHING".to_string(),
+                    false => format!(
+                        "ON CONFLICT ({}) DO UPDATE SET {}",
+                        keys.iter()
+                            .map(|(key, _)| quote_identifier(key))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                        updates.join(", ")
+                    ),
+                }
+            }
+        };
+
+        let failed = |err: DieselError| {
+            execution_err(format!("Failed to write the rows of {table}: {err}"))
+        };
+
+        if !skip_dangling {
+            return Ok(WrittenRows {
+                written: self
+                    .insert(table, &rows, &conflict)
+                    .map_err(failed)?,
+                skipped: 0,
+            });
+        }
+
+        //
+        // Row by row, each in its own savepoint, so a row referencing a
+        // missing row is dropped without aborting the transaction.
+        //
+        let mut written = WrittenRows::default();
+
+        for row in rows {
+            let inserted =
+                AnsiTransactionManager::begin_transaction(&mut self.conn)
+                    .and_then(|_| self.insert(table, &[row], &conflict));
+
+            match inserted {
+                Ok(count) => {
+                    AnsiTransactionManager::commit_transaction(&mut self.conn)
+                        .map_err(failed)?;
+
+                    written.written += count;
+                }
+                Err(DieselError::DatabaseError(
+                    DatabaseErrorKind::ForeignKeyViolation,
+                    _,
+                )) => {
+                    AnsiTransactionManager::rollback_transaction(
+                        &mut self.conn,
+                    )
+                    .map_err(failed)?;
+
+                    written.skipped += 1;
+                }
+                Err(err) => {
+                    let _ = AnsiTransactionManager::rollback_transaction(
+                        &mut self.conn,
+                    );
+
+                    return Err(failed(err));
+                }
+            }
+        }
+
+        Ok(written)
+    }
+
+    fn begin(&mut self) -> Result<(), MappedErrors> {
+        AnsiTransactionManager::begin_transaction(&mut self.conn).map_err(
+            |err| {
+                execution_err(format!("Failed to start a transaction: {err}"))
+            },
+        )
+    }
+
+    fn commit(&mut self) -> Result<(), MappedErrors> {
+        AnsiTransactionManager::commit_transaction(&mut self.conn).map_err(
+            |err| execution_err(format!("Failed to commit the restore: {err}")),
+        )
+    }
+
+    fn rollback(&mut self) -> Result<(), MappedErrors> {
+        AnsiTransactionManager::rollback_transaction(&mut self.conn).map_err(
+            |err| {
+                execution_err(format!("Failed to end the transaction: {err}"))
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::setup_temp_db;
+
+    use myc_core::domain::{
+        dtos::backup::BackupScope,
+        utils::{backup, restore},
+    };
+    use uuid::Uuid;
+
+    const TENANT: &str = "00000000-0000-0000-0000-0000000000a1";
+    const OTHER_TENANT: &str = "00000000-0000-0000-0000-0000000000b1";
+
+    fn seed(conn: &mut SqliteConnection) {
+        conn.batch_execute(&format!(
+            "INSERT INTO tenant (id, name, meta, created, kek_version) VALUES \
+                 ('{TENANT}', 'tenant', '{{\"k\":\"v\"}}', \
+                  '2026-10-19T00:00:00', 1), \
+                 ('{OTHER_TENANT}', 'other', NULL, \
+                  '2026-10-19T00:00:00', 1); \
+             INSERT INTO tenant_tag (id, value, tenant_id) VALUES \
+                 ('00000000-0000-0000-0000-0000000000a2', 'tag', '{TENANT}'); \
+             INSERT INTO account (id, name, slug, created, tenant_id) VALUES \
+                 ('00000000-0000-0000-0000-0000000000a3', 'account', \
+                  'account', '2026-10-19T00:00:00', '{TENANT}'), \
+                 ('00000000-0000-0000-0000-0000000000b3', 'other', 'other', \
+                  '2026-10-19T00:00:00', '{OTHER_TENANT}');"
+        ))
+        .unwrap();
+    }
+
+    fn names(store: &mut SqliteBackupStore, table: &str) -> Vec<String> {
+        store
+            .read_rows(table, None, 0, 100)
+            .unwrap()
+            .into_iter()
+            .map(|row| row["name"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn tenant_backups_roll_the_tenant_back() {
+        let db = setup_temp_db();
+        seed(&mut db.provider.get_pool().get().unwrap());
+
+        let url = db.path().to_str().unwrap();
+        let archive = std::env::temp_dir()
+            .join(format!("myc_sqlite_backup_{}.mycbak", Uuid::new_v4()));
+
+        let tenant_id = Uuid::parse_str(TENANT).unwrap();
+        let mut store = SqliteBackupStore::open(url).unwrap();
+
+        let summary = backup(
+            &mut store,
+            BackupScope::Tenant(tenant_id),
+            &archive,
+            Some("passphrase"),
+            10,
+        )
+        .unwrap();
+
+        assert_eq!(
+            summary
+                .iter()
+                .map(|table| (table.table.as_str(), table.rows))
+                .collect::<Vec<_>>(),
+            [("tenant", 1), ("tenant_tag", 1), ("account", 1)]
+        );
+
+        db.provider
+            .get_pool()
+            .get()
+            .unwrap()
+            .batch_execute(&format!(
+                "UPDATE tenant SET name = 'renamed' WHERE id = '{TENANT}'; \
+                 DELETE FROM tenant_tag; \
+                 UPDATE account SET name = 'renamed'; \
+                 INSERT INTO account (id, name, slug, created, tenant_id) \
+                 VALUES ('00000000-0000-0000-0000-0000000000a4', 'added', \
+                         'added', '2026-10-19T00:00:00', '{TENANT}');"
+            ))
+            .unwrap();
+
+        let report = restore(
+            &mut store,
+            &archive,
+            Some("passphrase"),
+            &[0u8; 32],
+            None,
+            10,
+        )
+        .unwrap();
+
+        assert!(!report.detached_parent);
+        assert_eq!(names(&mut store, "tenant"), ["tenant", "other"]);
+        assert_eq!(store.count_rows("tenant_tag", None).unwrap(), 1);
+
+        //
+        // The other tenant is left untouched.
+        //
+        assert_eq!(names(&mut store, "account"), ["account", "renamed"]);
+
+        let meta = store
+            .read_rows("tenant", Some(&format!("id = '{TENANT}'")), 0, 1)
+            .unwrap()
+            .remove(0)["meta"]
+            .to_owned();
+
+        assert_eq!(meta, Value::from("{\"k\":\"v\"}"));
+
+        let _ = std::fs::remove_file(&archive);
+    }
+
+    #[test]
+    fn instance_backups_restore_into_a_new_database() {
+        let db = setup_temp_db();
+        seed(&mut db.provider.get_pool().get().unwrap());
+
+        let archive = std::env::temp_dir()
+            .join(format!("myc_sqlite_backup_{}.mycbak", Uuid::new_v4()));
+
+        let mut store =
+            SqliteBackupStore::open(db.path().to_str().unwrap()).unwrap();
+
+        backup(&mut store, BackupScope::Instance, &archive, None, 1).unwrap();
+
+        let target_db = setup_temp_db();
+        let target_url = target_db.path().to_str().unwrap();
+
+        let mut target = SqliteBackupStore::provision(target_url).unwrap();
+
+        restore(&mut target, &archive, None, &[0u8; 32], None, 1).unwrap();
+
+        assert_eq!(names(&mut target, "tenant"), ["tenant", "other"]);
+        assert_eq!(names(&mut target, "account"), ["account", "other"]);
+
+        //
+        // The target is no longer empty.
+        //
+        assert!(
+            restore(&mut target, &archive, None, &[0u8; 32], None, 1).is_err()
+        );
+        assert_eq!(target.count_rows("account", None).unwrap(), 2);
+
+        let _ = std::fs::remove_file(&archive);
+    }
+}
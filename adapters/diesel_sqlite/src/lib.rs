@@ -1,7 +1,9 @@
+pub mod backup_store;
 pub mod config;
 pub mod migration;
 pub(crate) mod models;
 pub mod repositories;
 pub mod schema;
+pub mod table_reader;
 mod test_support;
 pub mod types;
@@ -0,0 +1,18 @@
+use diesel::prelude::*;
+
+#[derive(Clone, Debug, Queryable, Insertable, Selectable)]
+#[diesel(table_name = crate::schema::bulk_import)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub(crate) struct BulkImport {
+    pub id: String,
+    pub tenant_id: String,
+    pub checksum: String,
+    pub status: String,
+    pub import_rows: String,
+    pub row_results: String,
+    pub created_by: String,
+    pub created: String,
+    pub claimed_by: Option<String>,
+    pub started_at: Option<String>,
+    pub finished_at: Option<String>,
+}
@@ -0,0 +1,24 @@
+use diesel::prelude::*;
+
+#[derive(Clone, Debug, Queryable, Insertable, Selectable)]
+#[diesel(table_name = crate::schema::message_delivery)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub(crate) struct MessageDelivery {
+    pub id: String,
+    pub recipient: String,
+    pub subject: String,
+    pub status: String,
+    pub detail: Option<String>,
+    pub created: String,
+    pub updated: Option<String>,
+}
+
+#[derive(Clone, Debug, Queryable, Insertable, Selectable)]
+#[diesel(table_name = crate::schema::email_suppression)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub(crate) struct EmailSuppression {
+    pub email: String,
+    pub reason: String,
+    pub detail: Option<String>,
+    pub created: String,
+}
@@ -11,3 +11,14 @@ pub(crate) struct ErrorCode {
     pub is_internal: bool,
     pub is_native: bool,
 }
+
+#[derive(Queryable, Insertable, Selectable)]
+#[diesel(table_name = crate::schema::error_code_translation)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub(crate) struct ErrorCodeTranslation {
+    pub prefix: String,
+    pub code: i32,
+    pub locale: String,
+    pub message: String,
+    pub details: Option<String>,
+}
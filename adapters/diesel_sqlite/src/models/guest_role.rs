@@ -10,6 +10,8 @@ pub struct GuestRole {
     pub permission: i32,
     pub slug: String,
     pub system: bool,
+    pub tenant_id: Option<String>,
     pub created: String,
     pub updated: Option<String>,
+    pub permissions: Option<String>,
 }
@@ -19,4 +19,7 @@ pub(crate) struct GuestUser {
     pub created: String,
     pub updated: Option<String>,
     pub was_verified: bool,
+    pub invited_by: Option<String>,
+    pub invitation_sent_at: Option<String>,
+    pub invitation_expires_at: Option<String>,
 }
@@ -17,4 +17,6 @@ pub struct GuestUserOnAccount {
     pub created: String,
     pub permit_flags: Option<String>,
     pub deny_flags: Option<String>,
+    pub valid_from: Option<String>,
+    pub valid_until: Option<String>,
 }
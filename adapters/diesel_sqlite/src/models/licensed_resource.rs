@@ -26,4 +26,8 @@ pub(crate) struct LicensedResourceRow {
     pub permit_flags: Option<String>,
     #[diesel(sql_type = Nullable<Text>)]
     pub deny_flags: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    pub gr_permissions: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    pub valid_until: Option<String>,
 }
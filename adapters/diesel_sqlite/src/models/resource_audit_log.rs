@@ -12,4 +12,49 @@ pub(crate) struct ResourceAuditLog {
     pub performed_by: String,
     pub metadata: String,
     pub created_at: String,
+    pub chain_seq: Option<i64>,
+    pub prev_hash: Option<String>,
+    pub row_hash: Option<String>,
+    pub redacted_at: Option<String>,
+}
+
+#[derive(Queryable, Insertable, Selectable)]
+#[diesel(table_name = crate::schema::resource_audit_checkpoint)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub(crate) struct ResourceAuditCheckpoint {
+    pub id: String,
+    pub chain_tenant_id: String,
+    pub chain_seq: i64,
+    pub root_hash: String,
+    pub signature: String,
+    pub created_at: String,
+}
+
+#[derive(Queryable, Insertable, Selectable)]
+#[diesel(table_name = crate::schema::resource_audit_archive)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub(crate) struct ResourceAuditArchive {
+    pub id: String,
+    pub chain_tenant_id: String,
+    pub first_seq: Option<i64>,
+    pub last_seq: Option<i64>,
+    pub first_prev_hash: Option<String>,
+    pub last_hash: Option<String>,
+    pub cutoff: String,
+    pub row_count: i64,
+    pub location: String,
+    pub sha256: String,
+    pub signature: String,
+    pub created_at: String,
+}
+
+#[derive(Queryable, Insertable, Selectable, AsChangeset)]
+#[diesel(table_name = crate::schema::resource_audit_retention_policy)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+#[diesel(treat_none_as_null = true)]
+pub(crate) struct ResourceAuditRetentionPolicy {
+    pub chain_tenant_id: String,
+    pub retention_days: Option<i32>,
+    pub legal_hold: bool,
+    pub updated_at: String,
 }
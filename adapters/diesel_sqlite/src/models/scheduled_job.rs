@@ -0,0 +1,11 @@
+use diesel::prelude::*;
+
+#[derive(Clone, Debug, Queryable, Insertable, Selectable)]
+#[diesel(table_name = crate::schema::scheduled_job)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub(crate) struct ScheduledJob {
+    pub name: String,
+    pub last_run_at: Option<String>,
+    pub next_run_at: String,
+    pub claimed_by: Option<String>,
+}
@@ -0,0 +1,13 @@
+use diesel::prelude::*;
+
+#[derive(Clone, Debug, Queryable, Insertable, Selectable)]
+#[diesel(table_name = crate::schema::scim_credential)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub(crate) struct ScimCredential {
+    pub id: String,
+    pub tenant_id: String,
+    pub name: String,
+    pub secret_hash: String,
+    pub created_by: String,
+    pub created: String,
+}
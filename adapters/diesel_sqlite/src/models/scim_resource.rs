@@ -0,0 +1,18 @@
+use diesel::prelude::*;
+
+#[derive(Clone, Debug, Queryable, Insertable, Selectable)]
+#[diesel(table_name = crate::schema::scim_resource)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub(crate) struct ScimResource {
+    pub id: String,
+    pub tenant_id: String,
+    pub kind: String,
+    pub external_id: Option<String>,
+    pub display_name: String,
+    pub account_id: Option<String>,
+    pub guest_role_id: Option<String>,
+    pub provisioned: bool,
+    pub attributes: String,
+    pub created: String,
+    pub updated: Option<String>,
+}
@@ -1,6 +1,14 @@
 use diesel::prelude::*;
 
-#[derive(Identifiable, Clone, Debug, Queryable, Insertable, Selectable)]
+#[derive(
+    Identifiable,
+    Clone,
+    Debug,
+    Queryable,
+    QueryableByName,
+    Insertable,
+    Selectable,
+)]
 #[diesel(table_name = crate::schema::tenant)]
 #[diesel(check_for_backend(diesel::sqlite::Sqlite))]
 pub(crate) struct Tenant {
@@ -16,4 +24,5 @@ pub(crate) struct Tenant {
     pub updated: Option<String>,
     pub encrypted_dek: Option<String>,
     pub kek_version: i32,
+    pub parent_id: Option<String>,
 }
@@ -0,0 +1,20 @@
+use super::tenant::Tenant;
+
+use diesel::prelude::*;
+
+#[derive(
+    Identifiable, Associations, Clone, Debug, Queryable, Insertable, Selectable,
+)]
+#[diesel(table_name = crate::schema::tenant_email_template)]
+#[diesel(belongs_to(Tenant))]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub(crate) struct TenantEmailTemplate {
+    pub id: String,
+    pub tenant_id: String,
+    pub kind: String,
+    pub locale: String,
+    pub subject: String,
+    pub body: String,
+    pub created: String,
+    pub updated: Option<String>,
+}
@@ -0,0 +1,108 @@
+use super::shared::map_model_to_dto;
+use crate::{
+    config::SqliteDbPoolProvider,
+    models::bulk_import::BulkImport as BulkImportModel,
+    schema::bulk_import as bulk_import_model, types::uuid_to_text,
+};
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use myc_core::domain::{
+    dtos::{bulk_import::BulkImport, native_error_codes::NativeErrorCodes},
+    entities::BulkImportFetching,
+};
+use mycelium_base::{
+    entities::{FetchManyResponseKind, FetchResponseKind},
+    utils::errors::{fetching_err, MappedErrors},
+};
+use shaku::Component;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Component)]
+#[shaku(interface = BulkImportFetching)]
+pub struct BulkImportFetchingSqlDbRepository {
+    #[shaku(inject)]
+    pub db_config: Arc<dyn SqliteDbPoolProvider>,
+}
+
+#[async_trait]
+impl BulkImportFetching for BulkImportFetchingSqlDbRepository {
+    #[tracing::instrument(name = "get_bulk_import", skip_all)]
+    async fn get(
+        &self,
+        tenant_id: Uuid,
+        id: Uuid,
+    ) -> Result<FetchResponseKind<BulkImport, Uuid>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let record = bulk_import_model::table
+            .filter(bulk_import_model::id.eq(uuid_to_text(&id)))
+            .filter(bulk_import_model::tenant_id.eq(uuid_to_text(&tenant_id)))
+            .select(BulkImportModel::as_select())
+            .first::<BulkImportModel>(conn)
+            .optional()
+            .map_err(|e| {
+                fetching_err(format!("Failed to fetch bulk import: {e}"))
+            })?;
+
+        match record {
+            Some(record) => {
+                Ok(FetchResponseKind::Found(map_model_to_dto(record)?))
+            }
+            None => Ok(FetchResponseKind::NotFound(Some(id))),
+        }
+    }
+
+    #[tracing::instrument(name = "list_bulk_imports", skip_all)]
+    async fn list(
+        &self,
+        tenant_id: Uuid,
+        page_size: Option<i32>,
+        skip: Option<i32>,
+    ) -> Result<FetchManyResponseKind<BulkImport>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let page_size = page_size.unwrap_or(10) as i64;
+        let skip = skip.unwrap_or(0) as i64;
+
+        let count = bulk_import_model::table
+            .filter(bulk_import_model::tenant_id.eq(uuid_to_text(&tenant_id)))
+            .count()
+            .get_result::<i64>(conn)
+            .map_err(|e| {
+                fetching_err(format!("Failed to count bulk imports: {e}"))
+            })?;
+
+        if count == 0 {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        let records = bulk_import_model::table
+            .filter(bulk_import_model::tenant_id.eq(uuid_to_text(&tenant_id)))
+            .order(bulk_import_model::created.desc())
+            .limit(page_size)
+            .offset(skip)
+            .select(BulkImportModel::as_select())
+            .load::<BulkImportModel>(conn)
+            .map_err(|e| {
+                fetching_err(format!("Failed to fetch bulk imports: {e}"))
+            })?;
+
+        Ok(FetchManyResponseKind::FoundPaginated {
+            count,
+            skip: Some(skip),
+            size: Some(page_size),
+            records: records
+                .into_iter()
+                .map(map_model_to_dto)
+                .collect::<Result<Vec<BulkImport>, MappedErrors>>()?,
+        })
+    }
+}
@@ -0,0 +1,77 @@
+use super::shared::{map_dto_to_model, map_model_to_dto};
+use crate::{
+    config::SqliteDbPoolProvider,
+    models::bulk_import::BulkImport as BulkImportModel,
+    schema::bulk_import as bulk_import_model, types::uuid_to_text,
+};
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use myc_core::domain::{
+    dtos::{bulk_import::BulkImport, native_error_codes::NativeErrorCodes},
+    entities::BulkImportRegistration,
+};
+use mycelium_base::{
+    entities::GetOrCreateResponseKind,
+    utils::errors::{creation_err, MappedErrors},
+};
+use shaku::Component;
+use std::sync::Arc;
+
+#[derive(Component)]
+#[shaku(interface = BulkImportRegistration)]
+pub struct BulkImportRegistrationSqlDbRepository {
+    #[shaku(inject)]
+    pub db_config: Arc<dyn SqliteDbPoolProvider>,
+}
+
+#[async_trait]
+impl BulkImportRegistration for BulkImportRegistrationSqlDbRepository {
+    #[tracing::instrument(name = "get_or_create_bulk_import", skip_all)]
+    async fn get_or_create(
+        &self,
+        bulk_import: BulkImport,
+    ) -> Result<GetOrCreateResponseKind<BulkImport>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            creation_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        //
+        // The checksum is unique per tenant, so a job already registered
+        // with the same rows is not registered again.
+        //
+        let inserted = diesel::insert_into(bulk_import_model::table)
+            .values(map_dto_to_model(&bulk_import)?)
+            .on_conflict((
+                bulk_import_model::tenant_id,
+                bulk_import_model::checksum,
+            ))
+            .do_nothing()
+            .execute(conn)
+            .map_err(|e| {
+                creation_err(format!("Failed to register bulk import: {e}"))
+            })?;
+
+        if inserted > 0 {
+            return Ok(GetOrCreateResponseKind::Created(bulk_import));
+        }
+
+        let existing = bulk_import_model::table
+            .filter(
+                bulk_import_model::tenant_id
+                    .eq(uuid_to_text(&bulk_import.tenant_id)),
+            )
+            .filter(bulk_import_model::checksum.eq(bulk_import.checksum))
+            .select(BulkImportModel::as_select())
+            .first::<BulkImportModel>(conn)
+            .map_err(|e| {
+                creation_err(format!("Failed to fetch bulk import: {e}"))
+            })?;
+
+        Ok(GetOrCreateResponseKind::NotCreated(
+            map_model_to_dto(existing)?,
+            "The rows were already imported".to_string(),
+        ))
+    }
+}
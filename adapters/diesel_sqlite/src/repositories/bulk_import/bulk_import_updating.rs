@@ -0,0 +1,267 @@
+use super::shared::{encode_json, encode_timestamp, map_model_to_dto};
+use crate::{
+    config::SqliteDbPoolProvider,
+    models::bulk_import::BulkImport as BulkImportModel,
+    schema::bulk_import as bulk_import_model,
+    types::{timestamp_from_text, uuid_to_text},
+};
+
+use async_trait::async_trait;
+use chrono::{Duration, Local, Utc};
+use diesel::prelude::*;
+use myc_core::domain::{
+    dtos::{
+        bulk_import::{BulkImport, BulkImportRowResult, BulkImportStatus},
+        native_error_codes::NativeErrorCodes,
+    },
+    entities::BulkImportUpdating,
+};
+use mycelium_base::{
+    entities::UpdatingResponseKind,
+    utils::errors::{updating_err, MappedErrors},
+};
+use shaku::Component;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Component)]
+#[shaku(interface = BulkImportUpdating)]
+pub struct BulkImportUpdatingSqlDbRepository {
+    #[shaku(inject)]
+    pub db_config: Arc<dyn SqliteDbPoolProvider>,
+}
+
+#[async_trait]
+impl BulkImportUpdating for BulkImportUpdatingSqlDbRepository {
+    #[tracing::instrument(name = "claim_next_bulk_import", skip_all)]
+    async fn claim_next(
+        &self,
+        claimed_by: String,
+        stale_after: Duration,
+    ) -> Result<Option<BulkImport>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            updating_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let now = Local::now();
+        let stale_before = Utc::now() - stale_after;
+
+        let candidates = bulk_import_model::table
+            .filter(bulk_import_model::status.eq_any([
+                BulkImportStatus::Pending.to_string(),
+                BulkImportStatus::Running.to_string(),
+            ]))
+            .order(bulk_import_model::created.asc())
+            .select(BulkImportModel::as_select())
+            .load::<BulkImportModel>(conn)
+            .map_err(|e| {
+                updating_err(format!("Failed to fetch bulk imports: {e}"))
+            })?;
+
+        for candidate in candidates {
+            if candidate.status == BulkImportStatus::Running.to_string() {
+                let is_stale = match candidate.started_at.as_deref() {
+                    Some(started_at) => {
+                        timestamp_from_text(started_at)? < stale_before
+                    }
+                    None => true,
+                };
+
+                if !is_stale {
+                    continue;
+                }
+            }
+
+            //
+            // The update only matches while no other replica claimed the job
+            // since it was read, so a single replica runs it.
+            //
+            let mut query = diesel::update(bulk_import_model::table)
+                .filter(bulk_import_model::id.eq(candidate.id.to_owned()))
+                .filter(bulk_import_model::status.eq(candidate.status))
+                .into_boxed();
+
+            query = match candidate.started_at {
+                Some(started_at) => {
+                    query.filter(bulk_import_model::started_at.eq(started_at))
+                }
+                None => query.filter(bulk_import_model::started_at.is_null()),
+            };
+
+            let claimed = query
+                .set((
+                    bulk_import_model::status
+                        .eq(BulkImportStatus::Running.to_string()),
+                    bulk_import_model::claimed_by.eq(claimed_by.to_owned()),
+                    bulk_import_model::started_at.eq(encode_timestamp(&now)),
+                ))
+                .execute(conn)
+                .map_err(|e| {
+                    updating_err(format!("Failed to claim bulk import: {e}"))
+                })?;
+
+            if claimed == 0 {
+                continue;
+            }
+
+            let record = bulk_import_model::table
+                .find(candidate.id)
+                .select(BulkImportModel::as_select())
+                .first::<BulkImportModel>(conn)
+                .map_err(|e| {
+                    updating_err(format!("Failed to fetch bulk import: {e}"))
+                })?;
+
+            return Ok(Some(map_model_to_dto(record)?));
+        }
+
+        Ok(None)
+    }
+
+    #[tracing::instrument(name = "finish_bulk_import", skip_all)]
+    async fn finish(
+        &self,
+        id: Uuid,
+        status: BulkImportStatus,
+        results: Vec<BulkImportRowResult>,
+    ) -> Result<UpdatingResponseKind<BulkImport>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            updating_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let updated =
+            diesel::update(bulk_import_model::table.find(uuid_to_text(&id)))
+                .set((
+                    bulk_import_model::status.eq(status.to_string()),
+                    bulk_import_model::row_results.eq(encode_json(&results)?),
+                    bulk_import_model::finished_at
+                        .eq(Some(encode_timestamp(&Local::now()))),
+                ))
+                .execute(conn)
+                .map_err(|e| {
+                    updating_err(format!("Failed to update bulk import: {e}"))
+                })?;
+
+        if updated == 0 {
+            return updating_err(format!("Bulk import not found: {id}"))
+                .with_code(NativeErrorCodes::MYC00013)
+                .as_error();
+        }
+
+        let record = bulk_import_model::table
+            .find(uuid_to_text(&id))
+            .select(BulkImportModel::as_select())
+            .first::<BulkImportModel>(conn)
+            .map_err(|e| {
+                updating_err(format!("Failed to fetch bulk import: {e}"))
+            })?;
+
+        Ok(UpdatingResponseKind::Updated(map_model_to_dto(record)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        repositories::bulk_import::BulkImportRegistrationSqlDbRepository,
+        schema::tenant, test_support::setup_temp_db,
+        types::naive_timestamp_to_text,
+    };
+
+    use myc_core::domain::{
+        dtos::{
+            bulk_import::{BulkImportFormat, BulkImportRow},
+            written_by::WrittenBy,
+        },
+        entities::BulkImportRegistration,
+    };
+    use mycelium_base::entities::GetOrCreateResponseKind;
+
+    #[tokio::test]
+    async fn jobs_are_registered_once_and_claimed_once() {
+        let db = setup_temp_db();
+        let tenant_id = Uuid::new_v4();
+
+        {
+            let conn = &mut db.provider.get_pool().get().unwrap();
+            diesel::insert_into(tenant::table)
+                .values((
+                    tenant::id.eq(uuid_to_text(&tenant_id)),
+                    tenant::name.eq("Lab"),
+                    tenant::created
+                        .eq(naive_timestamp_to_text(&Utc::now().naive_utc())),
+                    tenant::kek_version.eq(1),
+                ))
+                .execute(conn)
+                .unwrap();
+        }
+
+        let registration = BulkImportRegistrationSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let updating = BulkImportUpdatingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+
+        let rows = BulkImportRow::parse_many(
+            BulkImportFormat::Csv,
+            "email,role,accountName\nana@example.com,viewer,Lab A\n",
+        )
+        .unwrap();
+
+        let created_by = WrittenBy::new_from_user(Uuid::new_v4());
+        let first = match registration
+            .get_or_create(BulkImport::new(
+                tenant_id,
+                rows.to_owned(),
+                created_by.to_owned(),
+            ))
+            .await
+            .unwrap()
+        {
+            GetOrCreateResponseKind::Created(job) => job,
+            _ => panic!("the first upload registers the job"),
+        };
+
+        match registration
+            .get_or_create(BulkImport::new(tenant_id, rows, created_by))
+            .await
+            .unwrap()
+        {
+            GetOrCreateResponseKind::NotCreated(job, _) => {
+                assert_eq!(job.id, first.id)
+            }
+            _ => panic!("the re-upload returns the registered job"),
+        };
+
+        let claimed = updating
+            .claim_next("pod-a".to_string(), Duration::hours(1))
+            .await
+            .unwrap()
+            .expect("the pending job is claimed");
+
+        assert_eq!(claimed.id, first.id);
+        assert_eq!(claimed.status, BulkImportStatus::Running);
+        assert_eq!(claimed.claimed_by, Some("pod-a".to_string()));
+
+        assert!(updating
+            .claim_next("pod-b".to_string(), Duration::hours(1))
+            .await
+            .unwrap()
+            .is_none());
+
+        updating
+            .finish(first.id, BulkImportStatus::Done, vec![])
+            .await
+            .unwrap();
+
+        assert!(updating
+            .claim_next("pod-b".to_string(), Duration::zero())
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
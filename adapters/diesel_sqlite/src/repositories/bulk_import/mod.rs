@@ -0,0 +1,8 @@
+mod bulk_import_fetching;
+mod bulk_import_registration;
+mod bulk_import_updating;
+mod shared;
+
+pub use bulk_import_fetching::*;
+pub use bulk_import_registration::*;
+pub use bulk_import_updating::*;
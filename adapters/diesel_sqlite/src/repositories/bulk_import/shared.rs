@@ -0,0 +1,84 @@
+use crate::{
+    models::bulk_import::BulkImport as BulkImportModel,
+    types::{
+        json_from_text, json_to_text, timestamp_from_text, timestamp_to_text,
+        uuid_from_text, uuid_to_text,
+    },
+};
+
+use chrono::{DateTime, Local, Utc};
+use myc_core::domain::dtos::bulk_import::{BulkImport, BulkImportStatus};
+use mycelium_base::utils::errors::{dto_err, fetching_err, MappedErrors};
+use serde_json::{from_value, to_value};
+use std::str::FromStr;
+
+pub(super) fn encode_timestamp(moment: &DateTime<Local>) -> String {
+    timestamp_to_text(&moment.with_timezone(&Utc))
+}
+
+fn decode_timestamp(value: &str) -> Result<DateTime<Local>, MappedErrors> {
+    Ok(timestamp_from_text(value)?.with_timezone(&Local))
+}
+
+pub(super) fn encode_json<T: serde::Serialize>(
+    value: &T,
+) -> Result<String, MappedErrors> {
+    json_to_text(&to_value(value).map_err(|e| {
+        dto_err(format!("Failed to serialize bulk import: {e}"))
+    })?)
+}
+
+pub(super) fn map_dto_to_model(
+    bulk_import: &BulkImport,
+) -> Result<BulkImportModel, MappedErrors> {
+    Ok(BulkImportModel {
+        id: uuid_to_text(&bulk_import.id),
+        tenant_id: uuid_to_text(&bulk_import.tenant_id),
+        checksum: bulk_import.checksum.to_owned(),
+        status: bulk_import.status.to_string(),
+        import_rows: encode_json(&bulk_import.rows)?,
+        row_results: encode_json(&bulk_import.results)?,
+        created_by: encode_json(&bulk_import.created_by)?,
+        created: encode_timestamp(&bulk_import.created_at),
+        claimed_by: bulk_import.claimed_by.to_owned(),
+        started_at: bulk_import.started_at.as_ref().map(encode_timestamp),
+        finished_at: bulk_import.finished_at.as_ref().map(encode_timestamp),
+    })
+}
+
+pub(super) fn map_model_to_dto(
+    model: BulkImportModel,
+) -> Result<BulkImport, MappedErrors> {
+    Ok(BulkImport {
+        id: uuid_from_text(&model.id)?,
+        tenant_id: uuid_from_text(&model.tenant_id)?,
+        checksum: model.checksum,
+        status: BulkImportStatus::from_str(&model.status)
+            .map_err(fetching_err)?,
+        dry_run: false,
+        rows: from_value(json_from_text(&model.import_rows)?).map_err(|e| {
+            fetching_err(format!("Failed to parse bulk import rows: {e}"))
+        })?,
+        results: from_value(json_from_text(&model.row_results)?).map_err(
+            |e| {
+                fetching_err(format!(
+                    "Failed to parse bulk import results: {e}"
+                ))
+            },
+        )?,
+        created_by: from_value(json_from_text(&model.created_by)?)
+            .unwrap_or_default(),
+        created_at: decode_timestamp(&model.created)?,
+        claimed_by: model.claimed_by,
+        started_at: model
+            .started_at
+            .as_deref()
+            .map(decode_timestamp)
+            .transpose()?,
+        finished_at: model
+            .finished_at
+            .as_deref()
+            .map(decode_timestamp)
+            .transpose()?,
+    })
+}
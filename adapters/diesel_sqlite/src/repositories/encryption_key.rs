@@ -63,6 +63,38 @@ impl EncryptionKeyFetching for EncryptionKeyFetchingSqlDbRepository {
 
         provision_and_persist_dek(conn, &tid_text, tid, kek)
     }
+
+    #[tracing::instrument(name = "fetch_dek", skip(self, kek))]
+    async fn fetch_dek(
+        &self,
+        tenant_id: Option<Uuid>,
+        kek: &[u8; 32],
+    ) -> Result<Option<[u8; 32]>, MappedErrors> {
+        let tid = tenant_id.unwrap_or(SYSTEM_TENANT_ID);
+        let tid_text = uuid_to_text(&tid);
+
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {e}"))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let wrapped = tenant_dsl::tenant
+            .filter(tenant::id.eq(&tid_text))
+            .select(tenant::encrypted_dek)
+            .first::<Option<String>>(conn)
+            .optional()
+            .map_err(|e| {
+                fetching_err(format!("Failed to fetch tenant DEK row: {e}"))
+            })?
+            .flatten();
+
+        match wrapped {
+            Some(wrapped) => {
+                unwrap_dek(&wrapped, kek, tid.as_bytes()).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
 }
 
 /// Provision a DEK for a tenant row that was not found.
@@ -279,4 +311,31 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn fetch_dek_never_provisions() -> Result<(), MappedErrors> {
+        // Read-only callers see a missing DEK as `None`, and nothing is
+        // written: the system sentinel row is not created either.
+        let db = setup_temp_db();
+        let fetching = EncryptionKeyFetchingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let kek = [4u8; 32];
+
+        assert_eq!(fetching.fetch_dek(None, &kek).await?, None);
+
+        let sentinel = tenant_dsl::tenant
+            .filter(tenant::id.eq(uuid_to_text(&SYSTEM_TENANT_ID)))
+            .select(tenant::id)
+            .first::<String>(&mut db.provider.get_pool().get().unwrap())
+            .optional()
+            .unwrap();
+        assert!(sentinel.is_none());
+
+        // Once provisioned, the fetch returns the same key.
+        let dek = fetching.get_or_provision_dek(None, &kek).await?;
+        assert_eq!(fetching.fetch_dek(None, &kek).await?, Some(dek));
+
+        Ok(())
+    }
 }
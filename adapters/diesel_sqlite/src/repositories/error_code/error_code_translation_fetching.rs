@@ -0,0 +1,72 @@
+use super::shared::map_translation_model_to_dto;
+use crate::{
+    config::SqliteDbPoolProvider,
+    models::error_code::ErrorCodeTranslation as ErrorCodeTranslationModel,
+    schema::error_code_translation as error_code_translation_model,
+};
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use myc_core::domain::{
+    dtos::{
+        error_code::ErrorCodeTranslation, native_error_codes::NativeErrorCodes,
+    },
+    entities::ErrorCodeTranslationFetching,
+};
+use mycelium_base::{
+    entities::FetchManyResponseKind,
+    utils::errors::{fetching_err, MappedErrors},
+};
+use shaku::Component;
+use std::sync::Arc;
+
+#[derive(Component)]
+#[shaku(interface = ErrorCodeTranslationFetching)]
+pub struct ErrorCodeTranslationFetchingSqlDbRepository {
+    #[shaku(inject)]
+    pub db_config: Arc<dyn SqliteDbPoolProvider>,
+}
+
+#[async_trait]
+impl ErrorCodeTranslationFetching
+    for ErrorCodeTranslationFetchingSqlDbRepository
+{
+    #[tracing::instrument(name = "list_error_code_translations", skip_all)]
+    async fn list(
+        &self,
+        prefix: String,
+        code: i32,
+    ) -> Result<FetchManyResponseKind<ErrorCodeTranslation>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let records = error_code_translation_model::table
+            .filter(
+                error_code_translation_model::prefix
+                    .eq(&prefix)
+                    .and(error_code_translation_model::code.eq(code)),
+            )
+            .order(error_code_translation_model::locale.asc())
+            .select(ErrorCodeTranslationModel::as_select())
+            .load::<ErrorCodeTranslationModel>(conn)
+            .map_err(|e| {
+                fetching_err(format!(
+                    "Failed to fetch error code translations: {}",
+                    e
+                ))
+            })?;
+
+        if records.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        Ok(FetchManyResponseKind::Found(
+            records
+                .into_iter()
+                .map(map_translation_model_to_dto)
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,226 @@
+use crate::{
+    config::SqliteDbPoolProvider,
+    models::error_code::ErrorCodeTranslation as ErrorCodeTranslationModel,
+    schema::error_code_translation as error_code_translation_model,
+};
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use myc_core::domain::{
+    dtos::{
+        error_code::ErrorCodeTranslation, native_error_codes::NativeErrorCodes,
+    },
+    entities::ErrorCodeTranslationRegistration,
+};
+use mycelium_base::{
+    entities::{CreateResponseKind, DeletionResponseKind},
+    utils::errors::{creation_err, deletion_err, MappedErrors},
+};
+use shaku::Component;
+use std::sync::Arc;
+
+#[derive(Component)]
+#[shaku(interface = ErrorCodeTranslationRegistration)]
+pub struct ErrorCodeTranslationRegistrationSqlDbRepository {
+    #[shaku(inject)]
+    pub db_config: Arc<dyn SqliteDbPoolProvider>,
+}
+
+#[async_trait]
+impl ErrorCodeTranslationRegistration
+    for ErrorCodeTranslationRegistrationSqlDbRepository
+{
+    #[tracing::instrument(name = "create_error_code_translation", skip_all)]
+    async fn create(
+        &self,
+        translation: ErrorCodeTranslation,
+    ) -> Result<CreateResponseKind<ErrorCodeTranslation>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            creation_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let created = diesel::insert_into(error_code_translation_model::table)
+            .values(map_dto_to_model(translation.to_owned()))
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .map_err(|e| {
+                creation_err(format!(
+                    "Failed to create error code translation: {}",
+                    e
+                ))
+            })?;
+
+        if created == 0 {
+            return Ok(CreateResponseKind::NotCreated(
+                translation,
+                "Error code translation already exists".to_string(),
+            ));
+        }
+
+        Ok(CreateResponseKind::Created(translation))
+    }
+
+    #[tracing::instrument(name = "upsert_error_code_translation", skip_all)]
+    async fn upsert(
+        &self,
+        translation: ErrorCodeTranslation,
+    ) -> Result<ErrorCodeTranslation, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            creation_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        diesel::insert_into(error_code_translation_model::table)
+            .values(map_dto_to_model(translation.to_owned()))
+            .on_conflict((
+                error_code_translation_model::prefix,
+                error_code_translation_model::code,
+                error_code_translation_model::locale,
+            ))
+            .do_update()
+            .set((
+                error_code_translation_model::message
+                    .eq(translation.message.to_owned()),
+                error_code_translation_model::details
+                    .eq(translation.details.to_owned()),
+            ))
+            .execute(conn)
+            .map_err(|e| {
+                creation_err(format!(
+                    "Failed to register error code translation: {}",
+                    e
+                ))
+            })?;
+
+        Ok(translation)
+    }
+
+    #[tracing::instrument(name = "delete_error_code_translation", skip_all)]
+    async fn delete(
+        &self,
+        prefix: String,
+        code: i32,
+        locale: String,
+    ) -> Result<DeletionResponseKind<(String, i32, String)>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            deletion_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let deleted = diesel::delete(
+            error_code_translation_model::table.filter(
+                error_code_translation_model::prefix
+                    .eq(&prefix)
+                    .and(error_code_translation_model::code.eq(code))
+                    .and(error_code_translation_model::locale.eq(&locale)),
+            ),
+        )
+        .execute(conn)
+        .map_err(|e| {
+            deletion_err(format!(
+                "Failed to delete error code translation: {}",
+                e
+            ))
+        })?;
+
+        if deleted == 0 {
+            return Ok(DeletionResponseKind::NotDeleted(
+                (prefix, code, locale),
+                "Error code translation not found".to_string(),
+            ));
+        }
+
+        Ok(DeletionResponseKind::Deleted)
+    }
+}
+
+fn map_dto_to_model(
+    translation: ErrorCodeTranslation,
+) -> ErrorCodeTranslationModel {
+    ErrorCodeTranslationModel {
+        prefix: translation.prefix,
+        code: translation.error_number,
+        locale: translation.locale,
+        message: translation.message,
+        details: translation.details,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        repositories::error_code::{
+            ErrorCodeRegistrationSqlDbRepository,
+            ErrorCodeTranslationFetchingSqlDbRepository,
+        },
+        test_support::setup_temp_db,
+    };
+    use myc_core::domain::{
+        dtos::error_code::ErrorCode,
+        entities::{ErrorCodeRegistration, ErrorCodeTranslationFetching},
+    };
+    use mycelium_base::entities::FetchManyResponseKind;
+
+    #[tokio::test]
+    async fn translations_are_seeded_replaced_and_deleted(
+    ) -> Result<(), MappedErrors> {
+        let db = setup_temp_db();
+        let registration = ErrorCodeTranslationRegistrationSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let fetching = ErrorCodeTranslationFetchingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+
+        ErrorCodeRegistrationSqlDbRepository {
+            db_config: db.provider.clone(),
+        }
+        .create(ErrorCode::new_external_error(
+            "TEST".into(),
+            1,
+            "Test error".into(),
+            false,
+        )?)
+        .await?;
+
+        let translation = |message: &str| {
+            ErrorCodeTranslation::new(
+                "TEST".into(),
+                1,
+                "pt-BR",
+                message.into(),
+                None,
+            )
+        };
+
+        registration.create(translation("Erro de teste")).await?;
+
+        // Seeding again keeps the customized translation
+        registration.upsert(translation("Erro customizado")).await?;
+        let seeded = registration.create(translation("Erro de teste")).await?;
+        assert!(matches!(seeded, CreateResponseKind::NotCreated(..)));
+
+        match fetching.list("TEST".into(), 1).await? {
+            FetchManyResponseKind::Found(records) => {
+                assert_eq!(records.len(), 1);
+                assert_eq!(records[0].locale, "pt-br");
+                assert_eq!(records[0].message, "Erro customizado");
+            }
+            _ => panic!("translation not found"),
+        }
+
+        let deleted = registration
+            .delete("TEST".into(), 1, "pt-br".into())
+            .await?;
+        assert!(matches!(deleted, DeletionResponseKind::Deleted));
+
+        assert!(matches!(
+            fetching.list("TEST".into(), 1).await?,
+            FetchManyResponseKind::NotFound
+        ));
+
+        Ok(())
+    }
+}
@@ -3,9 +3,13 @@ mod shared;
 mod error_code_deletion;
 mod error_code_fetching;
 mod error_code_registration;
+mod error_code_translation_fetching;
+mod error_code_translation_registration;
 mod error_code_updating;
 
 pub use error_code_deletion::*;
 pub use error_code_fetching::*;
 pub use error_code_registration::*;
+pub use error_code_translation_fetching::*;
+pub use error_code_translation_registration::*;
 pub use error_code_updating::*;
@@ -100,6 +100,7 @@ impl GuestRoleFetching for GuestRoleFetchingSqlDbRepository {
     #[tracing::instrument(name = "list_guest_roles", skip_all)]
     async fn list(
         &self,
+        tenant_id: Option<Uuid>,
         name: Option<String>,
         slug: Option<String>,
         system: Option<bool>,
@@ -117,6 +118,23 @@ impl GuestRoleFetching for GuestRoleFetchingSqlDbRepository {
         let page_size = page_size.unwrap_or(10) as i64;
         let skip = skip.unwrap_or(0) as i64;
 
+        // Instance-wide roles are always listed, tenant roles only to their
+        // tenant
+        match tenant_id {
+            Some(tenant_id) => {
+                let stm = guest_role::tenant_id
+                    .is_null()
+                    .or(guest_role::tenant_id.eq(uuid_to_text(&tenant_id)));
+                query_records = query_records.filter(stm.to_owned());
+                query_count = query_count.filter(stm);
+            }
+            None => {
+                let stm = guest_role::tenant_id.is_null();
+                query_records = query_records.filter(stm);
+                query_count = query_count.filter(stm);
+            }
+        }
+
         // SQLite's LIKE is case-insensitive for ASCII by default, matching
         // postgres's ILIKE for the common case.
         if let Some(name) = name {
@@ -3,7 +3,7 @@ use crate::{
     config::SqliteDbPoolProvider,
     models::guest_role::GuestRole as GuestRoleModel,
     schema::guest_role,
-    types::{naive_timestamp_to_text, uuid_to_text},
+    types::{naive_timestamp_to_text, string_array_to_text, uuid_to_text},
 };
 
 use async_trait::async_trait;
@@ -40,11 +40,21 @@ impl GuestRoleRegistration for GuestRoleRegistrationSqlDbRepository {
                 .with_code(NativeErrorCodes::MYC00001)
         })?;
 
-        // Check if role already exists
-        let existing = guest_role::table
+        // Check if role already exists in the role scope: the tenant of the
+        // role, or the instance-wide roles
+        let mut existing_query = guest_role::table
             .filter(guest_role::slug.eq(&guest_role_dto.slug).and(
-                guest_role::permission.eq(&guest_role_dto.permission.to_i32()),
+                guest_role::permission.eq(guest_role_dto.permission.to_i32()),
             ))
+            .into_boxed();
+
+        existing_query = match guest_role_dto.tenant_id {
+            Some(tenant_id) => existing_query
+                .filter(guest_role::tenant_id.eq(uuid_to_text(&tenant_id))),
+            None => existing_query.filter(guest_role::tenant_id.is_null()),
+        };
+
+        let existing = existing_query
             .select(GuestRoleModel::as_select())
             .first::<GuestRoleModel>(conn)
             .optional()
@@ -67,8 +77,14 @@ impl GuestRoleRegistration for GuestRoleRegistrationSqlDbRepository {
             description: guest_role_dto.description,
             permission: guest_role_dto.permission.to_i32(),
             system: guest_role_dto.system,
+            tenant_id: guest_role_dto
+                .tenant_id
+                .map(|tenant_id| uuid_to_text(&tenant_id)),
             created: naive_timestamp_to_text(&Utc::now().naive_utc()),
             updated: None,
+            permissions: Some(string_array_to_text(
+                &guest_role_dto.permissions,
+            )?),
         };
 
         let created = diesel::insert_into(guest_role::table)
@@ -3,7 +3,7 @@ use crate::{
     config::SqliteDbPoolProvider,
     models::guest_role::GuestRole as GuestRoleModel,
     schema::{guest_role, guest_role_children},
-    types::{naive_timestamp_to_text, uuid_to_text},
+    types::{naive_timestamp_to_text, string_array_to_text, uuid_to_text},
 };
 
 use async_trait::async_trait;
@@ -55,6 +55,9 @@ impl GuestRoleUpdating for GuestRoleUpdatingSqlDbRepository {
                     guest_role::slug.eq(&user_role.slug),
                     guest_role::description.eq(user_role.description.clone()),
                     guest_role::permission.eq(user_role.permission.to_i32()),
+                    guest_role::permissions.eq(Some(string_array_to_text(
+                        &user_role.permissions,
+                    )?)),
                     guest_role::updated.eq(Some(naive_timestamp_to_text(
                         &Utc::now().naive_utc(),
                     ))),
@@ -1,6 +1,7 @@
 use crate::{
     models::guest_role::GuestRole as GuestRoleModel,
-    repositories::account::created_at_from_text, types::uuid_from_text,
+    repositories::account::created_at_from_text,
+    types::{string_array_from_text, uuid_from_text},
 };
 
 use myc_core::domain::dtos::guest_role::{GuestRole, Permission};
@@ -14,7 +15,14 @@ pub(crate) fn map_model_to_dto(model: GuestRoleModel) -> GuestRole {
         permission: Permission::from_i32(model.permission),
         children: None,
         system: model.system,
+        tenant_id: model
+            .tenant_id
+            .map(|tenant_id| uuid_from_text(&tenant_id).unwrap()),
         created: created_at_from_text(&model.created),
         updated: model.updated.map(|dt| created_at_from_text(&dt)),
+        permissions: model
+            .permissions
+            .map(|permissions| string_array_from_text(&permissions).unwrap())
+            .unwrap_or_default(),
     }
 }
@@ -5,14 +5,24 @@ use crate::{
         guest_role::GuestRole as GuestRoleModel,
         guest_user::GuestUser as GuestUserModel,
     },
-    schema::{guest_role, guest_user, guest_user_on_account},
-    types::uuid_to_text,
+    repositories::parse_optional_written_by,
+    schema::{account, guest_role, guest_user, guest_user_on_account},
+    types::{
+        json_from_text, timestamp_from_text, timestamp_to_text, uuid_from_text,
+        uuid_to_text,
+    },
 };
 
 use async_trait::async_trait;
+use chrono::{DateTime, Local, Utc};
 use diesel::prelude::*;
 use myc_core::domain::{
-    dtos::{guest_user::GuestUser, native_error_codes::NativeErrorCodes},
+    dtos::{
+        email::Email,
+        guest_role::Permission,
+        guest_user::{ExpiringGuestGrant, GuestUser, PendingGuestInvitation},
+        native_error_codes::NativeErrorCodes,
+    },
     entities::GuestUserFetching,
 };
 use mycelium_base::{
@@ -82,4 +92,310 @@ impl GuestUserFetching for GuestUserFetchingSqlDbRepository {
                 .collect(),
         })
     }
+
+    #[tracing::instrument(name = "list_pending_invitations", skip_all)]
+    async fn list_pending_invitations(
+        &self,
+        created_from: DateTime<Utc>,
+        created_before: DateTime<Utc>,
+    ) -> Result<FetchManyResponseKind<PendingGuestInvitation>, MappedErrors>
+    {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let records = guest_user_on_account::table
+            .inner_join(guest_user::table.inner_join(guest_role::table))
+            .inner_join(account::table)
+            .filter(guest_user::was_verified.eq(false))
+            .filter(guest_user::created.ge(timestamp_to_text(&created_from)))
+            .filter(guest_user::created.lt(timestamp_to_text(&created_before)))
+            .filter(account::is_deleted.eq(false))
+            .select(INVITATION_COLUMNS)
+            .order(guest_user::created.asc())
+            .load::<InvitationRow>(conn)
+            .map_err(|e| {
+                fetching_err(format!(
+                    "Failed to fetch pending invitations: {}",
+                    e
+                ))
+            })?;
+
+        //
+        // Expiration dates are RFC 3339 text of any offset, so expired
+        // invitations are skipped once the dates are parsed.
+        //
+        let now = Utc::now();
+
+        map_invitation_rows(records, |invitation| {
+            invitation
+                .expires_at
+                .is_none_or(|expires_at| expires_at > now)
+        })
+    }
+
+    #[tracing::instrument(name = "list_account_invitations", skip_all)]
+    async fn list_account_invitations(
+        &self,
+        account_id: Uuid,
+    ) -> Result<FetchManyResponseKind<PendingGuestInvitation>, MappedErrors>
+    {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let records = guest_user_on_account::table
+            .inner_join(guest_user::table.inner_join(guest_role::table))
+            .inner_join(account::table)
+            .filter(
+                guest_user_on_account::account_id.eq(uuid_to_text(&account_id)),
+            )
+            .filter(guest_user::was_verified.eq(false))
+            .filter(account::is_deleted.eq(false))
+            .select(INVITATION_COLUMNS)
+            .order(guest_user::created.asc())
+            .load::<InvitationRow>(conn)
+            .map_err(|e| {
+                fetching_err(format!(
+                    "Failed to fetch account invitations: {}",
+                    e
+                ))
+            })?;
+
+        map_invitation_rows(records, |_| true)
+    }
+
+    #[tracing::instrument(name = "list_expired_invitations", skip_all)]
+    async fn list_expired_invitations(
+        &self,
+        expired_before: DateTime<Utc>,
+    ) -> Result<FetchManyResponseKind<PendingGuestInvitation>, MappedErrors>
+    {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let records = guest_user_on_account::table
+            .inner_join(guest_user::table.inner_join(guest_role::table))
+            .inner_join(account::table)
+            .filter(guest_user::was_verified.eq(false))
+            .filter(guest_user::invitation_expires_at.is_not_null())
+            .filter(account::is_deleted.eq(false))
+            .select(INVITATION_COLUMNS)
+            .order(guest_user::created.asc())
+            .load::<InvitationRow>(conn)
+            .map_err(|e| {
+                fetching_err(format!(
+                    "Failed to fetch expired invitations: {}",
+                    e
+                ))
+            })?;
+
+        map_invitation_rows(records, |invitation| {
+            invitation
+                .expires_at
+                .is_some_and(|expires_at| expires_at < expired_before)
+        })
+    }
+
+    #[tracing::instrument(name = "list_expiring_grants", skip_all)]
+    async fn list_expiring_grants(
+        &self,
+        valid_until_from: Option<DateTime<Utc>>,
+        valid_until_before: DateTime<Utc>,
+    ) -> Result<FetchManyResponseKind<ExpiringGuestGrant>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        //
+        // Grant end dates are RFC 3339 text of any offset, so the window is
+        // applied once the dates are parsed.
+        //
+        let records = guest_user_on_account::table
+            .inner_join(guest_user::table.inner_join(guest_role::table))
+            .inner_join(account::table)
+            .filter(guest_user_on_account::valid_until.is_not_null())
+            .filter(account::is_deleted.eq(false))
+            .select((
+                guest_user::id,
+                guest_user::email,
+                guest_role::id,
+                guest_role::name,
+                account::id,
+                account::name,
+                account::tenant_id,
+                guest_user_on_account::valid_until,
+            ))
+            .load::<(
+                String,
+                String,
+                String,
+                String,
+                String,
+                String,
+                Option<String>,
+                Option<String>,
+            )>(conn)
+            .map_err(|e| {
+                fetching_err(format!("Failed to fetch expiring grants: {}", e))
+            })?;
+
+        let mut grants = vec![];
+
+        for (
+            guest_user_id,
+            email,
+            guest_role_id,
+            role_name,
+            account_id,
+            account_name,
+            tenant_id,
+            valid_until,
+        ) in records
+        {
+            let Some(valid_until) = valid_until else {
+                continue;
+            };
+
+            let valid_until = timestamp_from_text(&valid_until)?;
+
+            if valid_until >= valid_until_before
+                || valid_until_from.is_some_and(|from| valid_until < from)
+            {
+                continue;
+            }
+
+            grants.push(ExpiringGuestGrant {
+                guest_user_id: uuid_from_text(&guest_user_id)?,
+                email: Email::from_string(email)?,
+                guest_role_id: uuid_from_text(&guest_role_id)?,
+                role_name,
+                account_id: uuid_from_text(&account_id)?,
+                account_name,
+                tenant_id: tenant_id
+                    .as_deref()
+                    .map(uuid_from_text)
+                    .transpose()?,
+                valid_until: valid_until.with_timezone(&Local),
+            });
+        }
+
+        if grants.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        grants.sort_by_key(|grant| grant.valid_until);
+
+        Ok(FetchManyResponseKind::Found(grants))
+    }
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Invitation rows
+// ? ---------------------------------------------------------------------------
+
+const INVITATION_COLUMNS: (
+    guest_user::id,
+    guest_user::email,
+    guest_user::created,
+    guest_user::guest_role_id,
+    guest_user::invited_by,
+    guest_user::invitation_sent_at,
+    guest_user::invitation_expires_at,
+    account::id,
+    account::name,
+    account::tenant_id,
+    guest_role::name,
+    guest_role::permission,
+) = (
+    guest_user::id,
+    guest_user::email,
+    guest_user::created,
+    guest_user::guest_role_id,
+    guest_user::invited_by,
+    guest_user::invitation_sent_at,
+    guest_user::invitation_expires_at,
+    account::id,
+    account::name,
+    account::tenant_id,
+    guest_role::name,
+    guest_role::permission,
+);
+
+type InvitationRow = (
+    String,
+    String,
+    String,
+    String,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    String,
+    String,
+    Option<String>,
+    String,
+    i32,
+);
+
+fn map_invitation_rows(
+    records: Vec<InvitationRow>,
+    keep: impl Fn(&PendingGuestInvitation) -> bool,
+) -> Result<FetchManyResponseKind<PendingGuestInvitation>, MappedErrors> {
+    let decode_timestamp = |value: Option<String>| {
+        value
+            .as_deref()
+            .map(timestamp_from_text)
+            .transpose()
+            .map(|moment| moment.map(|moment| moment.with_timezone(&Local)))
+    };
+
+    let mut invitations = vec![];
+
+    for (
+        guest_user_id,
+        email,
+        invited_at,
+        guest_role_id,
+        invited_by,
+        last_sent_at,
+        expires_at,
+        account_id,
+        account_name,
+        tenant_id,
+        role_name,
+        permission,
+    ) in records
+    {
+        let invitation = PendingGuestInvitation {
+            guest_user_id: uuid_from_text(&guest_user_id)?,
+            email: Email::from_string(email)?,
+            invited_at: timestamp_from_text(&invited_at)?.with_timezone(&Local),
+            account_id: uuid_from_text(&account_id)?,
+            account_name,
+            tenant_id: tenant_id.as_deref().map(uuid_from_text).transpose()?,
+            role_name,
+            role_permission: Permission::from_i32(permission),
+            guest_role_id: uuid_from_text(&guest_role_id)?,
+            invited_by: parse_optional_written_by(
+                invited_by.as_deref().map(json_from_text).transpose()?,
+            ),
+            last_sent_at: decode_timestamp(last_sent_at)?,
+            expires_at: decode_timestamp(expires_at)?,
+        };
+
+        if keep(&invitation) {
+            invitations.push(invitation);
+        }
+    }
+
+    if invitations.is_empty() {
+        return Ok(FetchManyResponseKind::NotFound);
+    }
+
+    Ok(FetchManyResponseKind::Found(invitations))
 }
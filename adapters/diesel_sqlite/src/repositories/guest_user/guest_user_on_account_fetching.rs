@@ -3,8 +3,8 @@ use crate::{
     models::guest_user_on_account::GuestUserOnAccount as GuestUserOnAccountModel,
     schema::{guest_user, guest_user_on_account},
     types::{
-        naive_timestamp_from_text, string_array_from_text, uuid_from_text,
-        uuid_to_text,
+        naive_timestamp_from_text, string_array_from_text, timestamp_from_text,
+        uuid_from_text, uuid_to_text,
     },
 };
 
@@ -88,6 +88,16 @@ impl GuestUserOnAccountFetching for GuestUserOnAccountFetchingSqlDbRepository {
                     .deny_flags
                     .map(|f| string_array_from_text(&f).unwrap())
                     .unwrap_or_default(),
+                valid_from: model.valid_from.map(|t| {
+                    timestamp_from_text(&t)
+                        .unwrap()
+                        .with_timezone(&chrono::Local)
+                }),
+                valid_until: model.valid_until.map(|t| {
+                    timestamp_from_text(&t)
+                        .unwrap()
+                        .with_timezone(&chrono::Local)
+                }),
             })
             .collect();
 
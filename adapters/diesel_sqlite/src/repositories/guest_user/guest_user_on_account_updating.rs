@@ -1,3 +1,4 @@
+use super::map_model_to_dto;
 use crate::{
     config::SqliteDbPoolProvider,
     models::{
@@ -5,14 +6,18 @@ use crate::{
         guest_user_on_account::GuestUserOnAccount as GuestUserOnAccountModel,
     },
     schema::{guest_role, guest_user, guest_user_on_account},
-    types::{string_array_to_text, uuid_from_text, uuid_to_text},
+    types::{
+        string_array_to_text, timestamp_to_text, uuid_from_text, uuid_to_text,
+    },
 };
 
 use async_trait::async_trait;
+use chrono::{DateTime, Local, Utc};
 use diesel::prelude::*;
 use myc_core::domain::{
     dtos::{
-        guest_role::Permission, guest_user_on_account::GuestUserOnAccount,
+        guest_role::Permission, guest_user::GuestUser,
+        guest_user_on_account::GuestUserOnAccount,
         native_error_codes::NativeErrorCodes,
     },
     entities::GuestUserOnAccountUpdating,
@@ -65,7 +70,10 @@ impl GuestUserOnAccountUpdating for GuestUserOnAccountUpdatingSqlDbRepository {
             ));
         };
 
-        // Find guest user by account
+        // Find guest user by account. Expiration dates are RFC 3339 text of
+        // any offset, so expired invitations are skipped once parsed.
+        let now = Local::now();
+
         let guest_user_record = guest_user::table
             .inner_join(guest_user_on_account::table)
             .filter(
@@ -73,11 +81,16 @@ impl GuestUserOnAccountUpdating for GuestUserOnAccountUpdatingSqlDbRepository {
             )
             .filter(guest_user::was_verified.eq(false))
             .select(GuestUserModel::as_select())
-            .first::<GuestUserModel>(conn)
-            .optional()
+            .load::<GuestUserModel>(conn)
             .map_err(|e| {
                 updating_err(format!("Failed to fetch guest user: {}", e))
-            })?;
+            })?
+            .into_iter()
+            .find(|user| {
+                user.invitation_expires_at
+                    .as_deref()
+                    .is_none_or(|expires_at| decode_timestamp(expires_at) > now)
+            });
 
         match guest_user_record {
             Some(user) => {
@@ -184,7 +197,7 @@ impl GuestUserOnAccountUpdating for GuestUserOnAccountUpdatingSqlDbRepository {
             account_id: uuid_from_text(&updated.account_id).unwrap(),
             created: crate::types::naive_timestamp_from_text(&updated.created)
                 .unwrap()
-                .and_local_timezone(chrono::Local)
+                .and_local_timezone(Local)
                 .unwrap(),
             permit_flags: updated
                 .permit_flags
@@ -194,12 +207,159 @@ impl GuestUserOnAccountUpdating for GuestUserOnAccountUpdatingSqlDbRepository {
                 .deny_flags
                 .map(|f| decode_string_array(&f))
                 .unwrap_or_default(),
+            valid_from: updated.valid_from.map(|t| decode_timestamp(&t)),
+            valid_until: updated.valid_until.map(|t| decode_timestamp(&t)),
         };
 
         Ok(UpdatingResponseKind::Updated(dto))
     }
+
+    #[tracing::instrument(name = "update_validity", skip_all)]
+    async fn update_validity(
+        &self,
+        guest_user_id: Uuid,
+        account_id: Uuid,
+        valid_from: Option<DateTime<Local>>,
+        valid_until: Option<DateTime<Local>>,
+    ) -> Result<UpdatingResponseKind<GuestUserOnAccount>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            updating_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let encode = |moment: Option<DateTime<Local>>| {
+            moment.map(|moment| timestamp_to_text(&moment.with_timezone(&Utc)))
+        };
+
+        let updated = diesel::update(
+            guest_user_on_account::table.filter(
+                guest_user_on_account::guest_user_id
+                    .eq(uuid_to_text(&guest_user_id))
+                    .and(
+                        guest_user_on_account::account_id
+                            .eq(uuid_to_text(&account_id)),
+                    ),
+            ),
+        )
+        .set((
+            guest_user_on_account::valid_from.eq(encode(valid_from)),
+            guest_user_on_account::valid_until.eq(encode(valid_until)),
+        ))
+        .returning(GuestUserOnAccountModel::as_returning())
+        .get_result::<GuestUserOnAccountModel>(conn)
+        .optional()
+        .map_err(|e| {
+            updating_err(format!(
+                "Failed to update guest grant validity: {}",
+                e
+            ))
+        })?;
+
+        let updated = match updated {
+            Some(record) => record,
+            None => {
+                return Ok(UpdatingResponseKind::NotUpdated(
+                    GuestUserOnAccount {
+                        guest_user_id,
+                        account_id,
+                        created: Local::now(),
+                        permit_flags: vec![],
+                        deny_flags: vec![],
+                        valid_from,
+                        valid_until,
+                    },
+                    "Guest user on account not found".to_string(),
+                ))
+            }
+        };
+
+        Ok(UpdatingResponseKind::Updated(GuestUserOnAccount {
+            guest_user_id,
+            account_id,
+            created: crate::types::naive_timestamp_from_text(&updated.created)?
+                .and_local_timezone(Local)
+                .unwrap(),
+            permit_flags: updated
+                .permit_flags
+                .map(|f| decode_string_array(&f))
+                .unwrap_or_default(),
+            deny_flags: updated
+                .deny_flags
+                .map(|f| decode_string_array(&f))
+                .unwrap_or_default(),
+            valid_from: updated.valid_from.map(|t| decode_timestamp(&t)),
+            valid_until: updated.valid_until.map(|t| decode_timestamp(&t)),
+        }))
+    }
+
+    #[tracing::instrument(name = "renew_invitation", skip_all)]
+    async fn renew_invitation(
+        &self,
+        guest_user_id: Uuid,
+        expires_at: DateTime<Local>,
+    ) -> Result<UpdatingResponseKind<GuestUser>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            updating_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let guest_user_record = guest_user::table
+            .find(uuid_to_text(&guest_user_id))
+            .select(GuestUserModel::as_select())
+            .first::<GuestUserModel>(conn)
+            .optional()
+            .map_err(|e| {
+                updating_err(format!("Failed to fetch guest user: {}", e))
+            })?;
+
+        let Some(guest_user_record) = guest_user_record else {
+            return updating_err(format!(
+                "Guest user not found: {guest_user_id}"
+            ))
+            .with_code(NativeErrorCodes::MYC00013)
+            .with_exp_true()
+            .as_error();
+        };
+
+        if guest_user_record.was_verified {
+            return Ok(UpdatingResponseKind::NotUpdated(
+                map_model_to_dto(guest_user_record, None),
+                "Invitation already accepted".to_string(),
+            ));
+        }
+
+        let now = timestamp_to_text(&Utc::now());
+
+        let updated =
+            diesel::update(guest_user::table.find(&guest_user_record.id))
+                .set((
+                    guest_user::invitation_sent_at.eq(Some(now.to_owned())),
+                    guest_user::invitation_expires_at.eq(Some(
+                        timestamp_to_text(&expires_at.with_timezone(&Utc)),
+                    )),
+                    guest_user::updated.eq(Some(now)),
+                ))
+                .returning(GuestUserModel::as_returning())
+                .get_result::<GuestUserModel>(conn)
+                .map_err(|e| {
+                    updating_err(format!(
+                        "Failed to renew guest invitation: {}",
+                        e
+                    ))
+                })?;
+
+        Ok(UpdatingResponseKind::Updated(map_model_to_dto(
+            updated, None,
+        )))
+    }
 }
 
 fn decode_string_array(value: &str) -> Vec<String> {
     crate::types::string_array_from_text(value).unwrap()
 }
+
+fn decode_timestamp(value: &str) -> DateTime<Local> {
+    crate::types::timestamp_from_text(value)
+        .unwrap()
+        .with_timezone(&Local)
+}
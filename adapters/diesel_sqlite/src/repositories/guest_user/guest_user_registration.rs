@@ -64,8 +64,44 @@ impl GuestUserRegistration for GuestUserRegistrationSqlDbRepository {
                 ))
             })?;
 
+        let invited_by = guest_user_dto
+            .invited_by
+            .to_owned()
+            .map(|m| serde_json::to_string(&m).unwrap());
+        let encode = |moment: Option<chrono::DateTime<Local>>| {
+            moment.map(|moment| timestamp_to_text(&moment.with_timezone(&Utc)))
+        };
+
         let guest_user_record = if let Some(record) = existing {
-            record
+            //
+            // Invitations not accepted yet are renewed when the user is
+            // invited again.
+            //
+            if record.was_verified
+                || guest_user_dto.invitation_expires_at.is_none()
+            {
+                record
+            } else {
+                diesel::update(guest_user::table.find(&record.id))
+                    .set((
+                        guest_user::invited_by.eq(invited_by),
+                        guest_user::invitation_sent_at
+                            .eq(encode(guest_user_dto.invitation_sent_at)),
+                        guest_user::invitation_expires_at
+                            .eq(encode(guest_user_dto.invitation_expires_at)),
+                        guest_user::updated.eq(Some(timestamp_to_text(
+                            &Local::now().with_timezone(&Utc),
+                        ))),
+                    ))
+                    .returning(GuestUserModel::as_returning())
+                    .get_result::<GuestUserModel>(conn)
+                    .map_err(|e| {
+                        creation_err(format!(
+                            "Failed to renew guest invitation: {}",
+                            e
+                        ))
+                    })?
+            }
         } else {
             // Create new guest user
             let guest_role_id = match guest_user_dto.guest_role {
@@ -85,6 +121,11 @@ impl GuestUserRegistration for GuestUserRegistrationSqlDbRepository {
                 created: timestamp_to_text(&Local::now().with_timezone(&Utc)),
                 updated: None,
                 was_verified: false,
+                invited_by,
+                invitation_sent_at: encode(guest_user_dto.invitation_sent_at),
+                invitation_expires_at: encode(
+                    guest_user_dto.invitation_expires_at,
+                ),
             };
 
             diesel::insert_into(guest_user::table)
@@ -142,19 +183,23 @@ mod tests {
                 GuestUserOnAccountFetchingSqlDbRepository,
                 GuestUserOnAccountUpdatingSqlDbRepository,
             },
+            licensed_resources::LicensedResourcesFetchingSqlDbRepository,
         },
         schema::account,
         test_support::setup_temp_db,
     };
+    use chrono::Duration;
     use myc_core::domain::{
         dtos::{
             email::Email,
             guest_role::{GuestRole, Permission},
             guest_user_on_account::GuestUserOnAccount,
+            written_by::WrittenBy,
         },
         entities::{
             GuestRoleRegistration, GuestUserDeletion, GuestUserFetching,
             GuestUserOnAccountFetching, GuestUserOnAccountUpdating,
+            LicensedResourcesFetching,
         },
     };
     use mycelium_base::entities::{
@@ -251,6 +296,8 @@ mod tests {
                 created: Local::now(),
                 permit_flags: vec!["read".into(), "write".into()],
                 deny_flags: vec!["delete".into()],
+                valid_from: None,
+                valid_until: None,
             })
             .await?
         {
@@ -295,4 +342,304 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn time_bound_grants_expire_through_sqlite(
+    ) -> Result<(), MappedErrors> {
+        let db = setup_temp_db();
+        let account_id = Uuid::new_v4();
+
+        {
+            let conn = &mut db.provider.get_pool().get().unwrap();
+            diesel::insert_into(account::table)
+                .values((
+                    account::id.eq(uuid_to_text(&account_id)),
+                    account::name.eq("Acme"),
+                    account::slug.eq("acme"),
+                    account::created
+                        .eq(naive_timestamp_to_text(&Utc::now().naive_utc())),
+                ))
+                .execute(conn)
+                .unwrap();
+        }
+
+        let role_registration = GuestRoleRegistrationSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let registration = GuestUserRegistrationSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let fetching = GuestUserFetchingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let on_account_updating = GuestUserOnAccountUpdatingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let licensed_resources = LicensedResourcesFetchingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+
+        let role = match role_registration
+            .get_or_create(GuestRole::new(
+                None,
+                "Contractor".into(),
+                None,
+                Permission::Read,
+                None,
+                false,
+            ))
+            .await?
+        {
+            GetOrCreateResponseKind::Created(role) => role,
+            GetOrCreateResponseKind::NotCreated(..) => {
+                panic!("expected the role to be created")
+            }
+        };
+        let role_id = role.id.expect("created role must have an id");
+
+        let email = Email::from_string("contractor@acme.test".into())?;
+        let guest_user_id = match registration
+            .get_or_create(
+                GuestUser::new_unverified(
+                    email.to_owned(),
+                    Parent::Id(role_id),
+                    None,
+                ),
+                account_id,
+            )
+            .await?
+        {
+            GetOrCreateResponseKind::Created(user) => user.id.unwrap(),
+            GetOrCreateResponseKind::NotCreated(..) => {
+                panic!("expected the guest user to be created")
+            }
+        };
+
+        let count_licenses = || async {
+            match licensed_resources
+                .list_licensed_resources(
+                    email.to_owned(),
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await?
+            {
+                FetchManyResponseKind::Found(records) => {
+                    Ok::<usize, MappedErrors>(records.len())
+                }
+                _ => Ok(0),
+            }
+        };
+
+        // A grant ending in an hour licenses the account and is about to
+        // expire
+        let now = Local::now();
+
+        on_account_updating
+            .update_validity(
+                guest_user_id,
+                account_id,
+                None,
+                Some(now + Duration::hours(1)),
+            )
+            .await?;
+
+        assert_eq!(count_licenses().await?, 1);
+
+        let expiring = match fetching
+            .list_expiring_grants(
+                Some(Utc::now()),
+                Utc::now() + Duration::hours(2),
+            )
+            .await?
+        {
+            FetchManyResponseKind::Found(records) => records,
+            _ => panic!("expected the expiring grant"),
+        };
+        assert_eq!(expiring.len(), 1);
+        assert_eq!(expiring[0].guest_user_id, guest_user_id);
+        assert_eq!(expiring[0].guest_role_id, role_id);
+
+        // Grants not started yet or already expired license nothing
+        on_account_updating
+            .update_validity(
+                guest_user_id,
+                account_id,
+                Some(now + Duration::hours(1)),
+                None,
+            )
+            .await?;
+
+        assert_eq!(count_licenses().await?, 0);
+
+        on_account_updating
+            .update_validity(
+                guest_user_id,
+                account_id,
+                None,
+                Some(now - Duration::hours(1)),
+            )
+            .await?;
+
+        assert_eq!(count_licenses().await?, 0);
+
+        let expired = fetching.list_expiring_grants(None, Utc::now()).await?;
+        assert!(
+            matches!(expired, FetchManyResponseKind::Found(records) if records.len() == 1)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn invitations_expire_and_renew_through_sqlite(
+    ) -> Result<(), MappedErrors> {
+        let db = setup_temp_db();
+        let account_id = Uuid::new_v4();
+        let manager_id = Uuid::new_v4();
+
+        {
+            let conn = &mut db.provider.get_pool().get().unwrap();
+            diesel::insert_into(account::table)
+                .values((
+                    account::id.eq(uuid_to_text(&account_id)),
+                    account::name.eq("Acme"),
+                    account::slug.eq("acme"),
+                    account::created
+                        .eq(naive_timestamp_to_text(&Utc::now().naive_utc())),
+                ))
+                .execute(conn)
+                .unwrap();
+        }
+
+        let role_registration = GuestRoleRegistrationSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let registration = GuestUserRegistrationSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let fetching = GuestUserFetchingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let on_account_updating = GuestUserOnAccountUpdatingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let licensed_resources = LicensedResourcesFetchingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+
+        let role = match role_registration
+            .get_or_create(GuestRole::new(
+                None,
+                "Reviewer".into(),
+                None,
+                Permission::Read,
+                None,
+                false,
+            ))
+            .await?
+        {
+            GetOrCreateResponseKind::Created(role) => role,
+            GetOrCreateResponseKind::NotCreated(..) => {
+                panic!("expected the role to be created")
+            }
+        };
+        let role_id = role.id.expect("created role must have an id");
+
+        // An invitation sent an hour after its expiration date is expired
+        // from the start
+        let email = Email::from_string("reviewer@acme.test".into())?;
+        let guest_user_id = match registration
+            .get_or_create(
+                GuestUser::new_unverified(
+                    email.to_owned(),
+                    Parent::Id(role_id),
+                    None,
+                )
+                .with_invitation(
+                    WrittenBy::new_from_account(manager_id),
+                    Duration::hours(-1),
+                ),
+                account_id,
+            )
+            .await?
+        {
+            GetOrCreateResponseKind::Created(user) => user.id.unwrap(),
+            GetOrCreateResponseKind::NotCreated(..) => {
+                panic!("expected the guest user to be created")
+            }
+        };
+
+        let count_licenses = || async {
+            match licensed_resources
+                .list_licensed_resources(
+                    email.to_owned(),
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await?
+            {
+                FetchManyResponseKind::Found(records) => {
+                    Ok::<usize, MappedErrors>(records.len())
+                }
+                _ => Ok(0),
+            }
+        };
+
+        assert_eq!(count_licenses().await?, 0);
+
+        // The account still lists the expired invitation, but reminders and
+        // the sweep see it as expired
+        let listed = match fetching.list_account_invitations(account_id).await?
+        {
+            FetchManyResponseKind::Found(records) => records,
+            _ => panic!("expected the account invitation"),
+        };
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].guest_user_id, guest_user_id);
+        assert_eq!(listed[0].guest_role_id, role_id);
+        assert_eq!(
+            listed[0].invited_by,
+            Some(WrittenBy::new_from_account(manager_id))
+        );
+
+        assert!(matches!(
+            fetching
+                .list_pending_invitations(
+                    Utc::now() - Duration::hours(1),
+                    Utc::now() + Duration::hours(1),
+                )
+                .await?,
+            FetchManyResponseKind::NotFound
+        ));
+
+        assert!(matches!(
+            fetching.list_expired_invitations(Utc::now()).await?,
+            FetchManyResponseKind::Found(records) if records.len() == 1
+        ));
+
+        // Resending the invitation renews it
+        let renewed = match on_account_updating
+            .renew_invitation(guest_user_id, Local::now() + Duration::days(7))
+            .await?
+        {
+            UpdatingResponseKind::Updated(guest_user) => guest_user,
+            UpdatingResponseKind::NotUpdated(..) => {
+                panic!("expected the invitation to be renewed")
+            }
+        };
+        assert!(renewed.invitation_expires_at.unwrap() > Local::now());
+
+        assert_eq!(count_licenses().await?, 1);
+        assert!(matches!(
+            fetching.list_expired_invitations(Utc::now()).await?,
+            FetchManyResponseKind::NotFound
+        ));
+
+        Ok(())
+    }
 }
@@ -3,8 +3,11 @@ use crate::{
         guest_role::GuestRole as GuestRoleModel,
         guest_user::GuestUser as GuestUserModel,
     },
-    repositories::account::created_at_from_text,
-    types::{timestamp_from_text, uuid_from_text},
+    repositories::{account::created_at_from_text, parse_optional_written_by},
+    types::{
+        json_from_text, string_array_from_text, timestamp_from_text,
+        uuid_from_text,
+    },
 };
 
 use myc_core::domain::dtos::{
@@ -34,8 +37,17 @@ pub(crate) fn map_model_to_dto(
                 permission: Permission::from_i32(role.permission),
                 children: None,
                 system: role.system,
+                tenant_id: role
+                    .tenant_id
+                    .map(|tenant_id| uuid_from_text(&tenant_id).unwrap()),
                 created: created_at_from_text(&role.created),
                 updated: role.updated.map(|dt| created_at_from_text(&dt)),
+                permissions: role
+                    .permissions
+                    .map(|permissions| {
+                        string_array_from_text(&permissions).unwrap()
+                    })
+                    .unwrap_or_default(),
             }),
             None => Parent::Id(uuid_from_text(&model.guest_role_id).unwrap()),
         },
@@ -49,5 +61,18 @@ pub(crate) fn map_model_to_dto(
                 .with_timezone(&chrono::Local)
         }),
         was_verified: model.was_verified,
+        invited_by: parse_optional_written_by(
+            model.invited_by.map(|s| json_from_text(&s).unwrap()),
+        ),
+        invitation_sent_at: model.invitation_sent_at.map(|dt| {
+            timestamp_from_text(&dt)
+                .unwrap()
+                .with_timezone(&chrono::Local)
+        }),
+        invitation_expires_at: model.invitation_expires_at.map(|dt| {
+            timestamp_from_text(&dt)
+                .unwrap()
+                .with_timezone(&chrono::Local)
+        }),
     }
 }
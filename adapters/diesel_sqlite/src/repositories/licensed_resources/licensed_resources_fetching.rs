@@ -1,8 +1,12 @@
 use crate::{
     config::SqliteDbPoolProvider,
     models::licensed_resource::LicensedResourceRow,
+    repositories::tenant::load_descendants,
     schema::{owner_on_tenant, tenant, user},
-    types::{string_array_from_text, uuid_from_text, uuid_to_text},
+    types::{
+        string_array_from_text, timestamp_from_text, uuid_from_text,
+        uuid_to_text,
+    },
 };
 
 use async_trait::async_trait;
@@ -24,7 +28,10 @@ use mycelium_base::{
     utils::errors::{fetching_err, MappedErrors},
 };
 use shaku::Component;
-use std::sync::Arc;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 use tracing::trace;
 use uuid::Uuid;
 
@@ -168,6 +175,15 @@ impl LicensedResourcesFetching for LicensedResourcesFetchingSqlDbRepository {
                 deny_flags: record
                     .deny_flags
                     .map(|f| string_array_from_text(&f).unwrap()),
+                permissions: record
+                    .gr_permissions
+                    .map(|p| string_array_from_text(&p).unwrap())
+                    .filter(|p| !p.is_empty()),
+                valid_until: record.valid_until.map(|valid_until| {
+                    timestamp_from_text(&valid_until)
+                        .unwrap()
+                        .with_timezone(&Local)
+                }),
             })
             .collect::<Vec<LicensedResource>>();
 
@@ -185,8 +201,7 @@ impl LicensedResourcesFetching for LicensedResourcesFetchingSqlDbRepository {
                 .with_code(NativeErrorCodes::MYC00001)
         })?;
 
-        let mut query = user::table
-            .into_boxed()
+        let rows = user::table
             .inner_join(owner_on_tenant::table)
             .inner_join(
                 tenant::table.on(owner_on_tenant::tenant_id.eq(tenant::id)),
@@ -196,35 +211,73 @@ impl LicensedResourcesFetching for LicensedResourcesFetchingSqlDbRepository {
                 owner_on_tenant::tenant_id,
                 owner_on_tenant::created,
                 tenant::name,
-            ));
-
-        if let Some(tenant_id) = tenant_id_filter {
-            query = query.filter(
-                owner_on_tenant::tenant_id.eq(uuid_to_text(&tenant_id)),
-            );
-        }
-
-        let rows =
-            query.load::<(String, String, String)>(conn).map_err(|e| {
+                tenant::parent_id,
+            ))
+            .load::<(String, String, String, Option<String>)>(conn)
+            .map_err(|e| {
                 fetching_err(format!("Failed to fetch tenant ownerships: {e}"))
             })?;
 
-        if rows.is_empty() {
-            return Ok(FetchManyResponseKind::NotFound);
-        }
-
-        Ok(FetchManyResponseKind::Found(
-            rows.into_iter()
-                .map(|(tenant_id, created, tenant_name)| TenantOwnership {
-                    id: uuid_from_text(&tenant_id).unwrap(),
-                    since: crate::types::naive_timestamp_from_text(&created)
-                        .unwrap()
+        let direct_ownerships = rows
+            .into_iter()
+            .map(|(tenant_id, created, tenant_name, parent_id)| {
+                Ok(TenantOwnership {
+                    id: uuid_from_text(&tenant_id)?,
+                    since: crate::types::naive_timestamp_from_text(&created)?
                         .and_local_timezone(Local)
                         .unwrap(),
                     name: tenant_name,
+                    parent_id: parent_id
+                        .as_deref()
+                        .map(uuid_from_text)
+                        .transpose()?,
+                    inherited_from: None,
                 })
-                .collect(),
-        ))
+            })
+            .collect::<Result<Vec<_>, MappedErrors>>()?;
+
+        //
+        // Owners of a tenant own its descendants too. Direct ownerships take
+        // precedence over the inherited ones.
+        //
+        let mut seen: HashSet<Uuid> =
+            direct_ownerships.iter().map(|o| o.id).collect();
+        let mut ownerships = direct_ownerships.to_owned();
+
+        for owned in direct_ownerships {
+            let mut lineage = HashMap::from([(owned.id, owned.to_owned())]);
+
+            for child in load_descendants(conn, &uuid_to_text(&owned.id))? {
+                let child_id = uuid_from_text(&child.id)?;
+
+                let Some(parent) = child
+                    .parent_id
+                    .as_deref()
+                    .map(uuid_from_text)
+                    .transpose()?
+                    .and_then(|parent_id| lineage.get(&parent_id))
+                else {
+                    continue;
+                };
+
+                let inherited = parent.inherit_to(child_id, child.name);
+                lineage.insert(child_id, inherited.to_owned());
+
+                if seen.insert(child_id) {
+                    ownerships.push(inherited);
+                }
+            }
+        }
+
+        if let Some(tenant_id) = tenant_id_filter {
+            ownerships.retain(|o| o.id == tenant_id);
+        }
+
+        if ownerships.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        Ok(FetchManyResponseKind::Found(ownerships))
     }
 }
 
@@ -347,7 +400,8 @@ mod tests {
             Permission::Write,
             None,
             false,
-        );
+        )
+        .with_permissions(vec!["invoices:approve".into()])?;
         let role = match role_registration.get_or_create(role).await? {
             mycelium_base::entities::GetOrCreateResponseKind::Created(r) => r,
             mycelium_base::entities::GetOrCreateResponseKind::NotCreated(
@@ -389,6 +443,10 @@ mod tests {
         assert_eq!(licenses.len(), 1);
         assert_eq!(licenses[0].acc_id, account_id);
         assert_eq!(licenses[0].role_id, role_id);
+        assert_eq!(
+            licenses[0].permissions,
+            Some(vec!["invoices:approve".to_string()])
+        );
 
         // list_tenants_ownership finds the owner's tenant
         let ownerships = match fetching
@@ -407,4 +465,134 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn tenant_roles_only_license_accounts_of_their_tenant(
+    ) -> Result<(), MappedErrors> {
+        let db = setup_temp_db();
+
+        let tenant_registration = TenantRegistrationSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let role_registration = GuestRoleRegistrationSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let guest_registration = GuestUserRegistrationSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let fetching = LicensedResourcesFetchingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+
+        let mut tenant_ids = vec![];
+        let mut account_ids = vec![];
+
+        for name in ["Lab Tenant", "Audit Tenant"] {
+            let tenant = Tenant::new_with_owners(
+                name.into(),
+                None,
+                Children::Records(vec![]),
+            );
+            let tenant_id = match tenant_registration
+                .create(tenant, "self".into())
+                .await?
+            {
+                mycelium_base::entities::CreateResponseKind::Created(t) => {
+                    t.id.expect("tenant must have an id")
+                }
+                mycelium_base::entities::CreateResponseKind::NotCreated(..) => {
+                    panic!("expected the tenant to be created")
+                }
+            };
+
+            let account_id = Uuid::new_v4();
+            {
+                let conn = &mut db.provider.get_pool().get().unwrap();
+                diesel::insert_into(account::table)
+                    .values((
+                        account::id.eq(uuid_to_text(&account_id)),
+                        account::name.eq(format!("{name} Account")),
+                        account::slug.eq(uuid_to_text(&account_id)),
+                        account::tenant_id.eq(uuid_to_text(&tenant_id)),
+                        account::created.eq(
+                            crate::types::naive_timestamp_to_text(
+                                &chrono::Utc::now().naive_utc(),
+                            ),
+                        ),
+                    ))
+                    .execute(conn)
+                    .unwrap();
+            }
+
+            tenant_ids.push(tenant_id);
+            account_ids.push(account_id);
+        }
+
+        // Both tenants register an "Auditor" role of their own
+        let mut role_ids = vec![];
+
+        for tenant_id in tenant_ids.iter() {
+            let role = GuestRole::new(
+                None,
+                "Auditor".into(),
+                None,
+                Permission::Read,
+                None,
+                false,
+            )
+            .with_tenant_id(*tenant_id);
+
+            match role_registration.get_or_create(role).await? {
+                mycelium_base::entities::GetOrCreateResponseKind::Created(
+                    r,
+                ) => {
+                    assert_eq!(r.tenant_id, Some(*tenant_id));
+                    role_ids.push(r.id.expect("role must have an id"));
+                }
+                mycelium_base::entities::GetOrCreateResponseKind::NotCreated(
+                    ..,
+                ) => panic!("expected the tenant role to be created"),
+            };
+        }
+
+        // The guest holds the first tenant role on the accounts of both
+        // tenants
+        let guest_email = "auditor@acme.test";
+
+        for account_id in account_ids.iter() {
+            guest_registration
+                .get_or_create(
+                    GuestUser::new_unverified(
+                        Email::from_string(guest_email.into())?,
+                        Parent::Id(role_ids[0]),
+                        None,
+                    ),
+                    *account_id,
+                )
+                .await?;
+        }
+
+        let licenses = match fetching
+            .list_licensed_resources(
+                Email::from_string(guest_email.into())?,
+                None,
+                Some(vec![PermissionedRole {
+                    name: "auditor".into(),
+                    permission: Some(Permission::Read),
+                }]),
+                None,
+                None,
+            )
+            .await?
+        {
+            FetchManyResponseKind::Found(records) => records,
+            _ => panic!("expected to find licensed resources"),
+        };
+
+        assert_eq!(licenses.len(), 1);
+        assert_eq!(licenses[0].acc_id, account_ids[0]);
+        assert_eq!(licenses[0].tenant_id, tenant_ids[0]);
+
+        Ok(())
+    }
 }
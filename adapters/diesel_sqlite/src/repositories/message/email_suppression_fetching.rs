@@ -0,0 +1,114 @@
+use crate::{
+    config::SqliteDbPoolProvider,
+    models::email_delivery::EmailSuppression as EmailSuppressionModel,
+    schema::email_suppression, types::timestamp_from_text,
+};
+
+use async_trait::async_trait;
+use chrono::Local;
+use diesel::prelude::*;
+use myc_core::domain::{
+    dtos::{
+        email::Email,
+        email_delivery::{EmailSuppression, EmailSuppressionReason},
+        native_error_codes::NativeErrorCodes,
+    },
+    entities::EmailSuppressionFetching,
+};
+use mycelium_base::{
+    entities::{FetchManyResponseKind, FetchResponseKind},
+    utils::errors::{dto_err, fetching_err, MappedErrors},
+};
+use shaku::Component;
+use std::{str::FromStr, sync::Arc};
+
+#[derive(Component)]
+#[shaku(interface = EmailSuppressionFetching)]
+pub struct EmailSuppressionFetchingSqlDbRepository {
+    #[shaku(inject)]
+    pub db_config: Arc<dyn SqliteDbPoolProvider>,
+}
+
+#[async_trait]
+impl EmailSuppressionFetching for EmailSuppressionFetchingSqlDbRepository {
+    #[tracing::instrument(name = "get_email_suppression", skip_all)]
+    async fn get(
+        &self,
+        email: Email,
+    ) -> Result<FetchResponseKind<EmailSuppression, String>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let record = email_suppression::table
+            .find(email.email())
+            .select(EmailSuppressionModel::as_select())
+            .first::<EmailSuppressionModel>(conn)
+            .optional()
+            .map_err(|e| {
+                fetching_err(format!("Failed to fetch email suppression: {e}"))
+            })?;
+
+        match record {
+            Some(record) => Ok(FetchResponseKind::Found(
+                map_suppression_model_to_dto(record)?,
+            )),
+            None => Ok(FetchResponseKind::NotFound(Some(email.email()))),
+        }
+    }
+
+    #[tracing::instrument(name = "list_email_suppressions", skip_all)]
+    async fn list(
+        &self,
+        page_size: Option<i32>,
+        skip: Option<i32>,
+    ) -> Result<FetchManyResponseKind<EmailSuppression>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let total = email_suppression::table
+            .count()
+            .get_result::<i64>(conn)
+            .map_err(|e| {
+                fetching_err(format!("Failed to get total count: {}", e))
+            })?;
+
+        let page_size = i64::from(page_size.unwrap_or(10));
+        let skip = i64::from(skip.unwrap_or(0));
+
+        let records = email_suppression::table
+            .order(email_suppression::created.desc())
+            .offset(skip)
+            .limit(page_size)
+            .select(EmailSuppressionModel::as_select())
+            .load::<EmailSuppressionModel>(conn)
+            .map_err(|e| {
+                fetching_err(format!("Failed to fetch email suppressions: {e}"))
+            })?;
+
+        Ok(FetchManyResponseKind::FoundPaginated {
+            count: total,
+            skip: Some(skip),
+            size: Some(page_size),
+            records: records
+                .into_iter()
+                .map(map_suppression_model_to_dto)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+fn map_suppression_model_to_dto(
+    model: EmailSuppressionModel,
+) -> Result<EmailSuppression, MappedErrors> {
+    Ok(EmailSuppression {
+        email: model.email,
+        reason: EmailSuppressionReason::from_str(&model.reason)
+            .map_err(dto_err)?,
+        detail: model.detail,
+        created: timestamp_from_text(&model.created)?.with_timezone(&Local),
+    })
+}
@@ -0,0 +1,162 @@
+use crate::{
+    config::SqliteDbPoolProvider,
+    models::email_delivery::EmailSuppression as EmailSuppressionModel,
+    schema::email_suppression, types::timestamp_to_text,
+};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use diesel::prelude::*;
+use myc_core::domain::{
+    dtos::{
+        email::Email, email_delivery::EmailSuppression,
+        native_error_codes::NativeErrorCodes,
+    },
+    entities::EmailSuppressionRegistration,
+};
+use mycelium_base::{
+    entities::DeletionResponseKind,
+    utils::errors::{creation_err, deletion_err, MappedErrors},
+};
+use shaku::Component;
+use std::sync::Arc;
+
+#[derive(Component)]
+#[shaku(interface = EmailSuppressionRegistration)]
+pub struct EmailSuppressionRegistrationSqlDbRepository {
+    #[shaku(inject)]
+    pub db_config: Arc<dyn SqliteDbPoolProvider>,
+}
+
+#[async_trait]
+impl EmailSuppressionRegistration
+    for EmailSuppressionRegistrationSqlDbRepository
+{
+    #[tracing::instrument(name = "register_email_suppression", skip_all)]
+    async fn register(
+        &self,
+        suppression: EmailSuppression,
+    ) -> Result<(), MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            creation_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        diesel::insert_into(email_suppression::table)
+            .values(&EmailSuppressionModel {
+                email: suppression.email.to_lowercase(),
+                reason: suppression.reason.to_string(),
+                detail: suppression.detail,
+                created: timestamp_to_text(
+                    &suppression.created.with_timezone(&Utc),
+                ),
+            })
+            .on_conflict(email_suppression::email)
+            .do_nothing()
+            .execute(conn)
+            .map_err(|e| {
+                creation_err(format!(
+                    "Failed to register email suppression: {e}"
+                ))
+            })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "delete_email_suppression", skip_all)]
+    async fn delete(
+        &self,
+        email: Email,
+    ) -> Result<DeletionResponseKind<String>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            deletion_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let deleted =
+            diesel::delete(email_suppression::table.find(email.email()))
+                .execute(conn)
+                .map_err(|e| {
+                    deletion_err(format!(
+                        "Failed to delete email suppression: {e}"
+                    ))
+                })?;
+
+        if deleted == 0 {
+            return Ok(DeletionResponseKind::NotDeleted(
+                email.email(),
+                "Email is not suppressed".to_string(),
+            ));
+        }
+
+        Ok(DeletionResponseKind::Deleted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        repositories::message::EmailSuppressionFetchingSqlDbRepository,
+        test_support::setup_temp_db,
+    };
+    use chrono::Local;
+    use myc_core::domain::{
+        dtos::email_delivery::EmailSuppressionReason,
+        entities::EmailSuppressionFetching,
+    };
+    use mycelium_base::entities::FetchResponseKind;
+
+    #[tokio::test]
+    async fn suppressions_are_matched_case_insensitively_and_lifted(
+    ) -> Result<(), MappedErrors> {
+        let db = setup_temp_db();
+        let registration = EmailSuppressionRegistrationSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let fetching = EmailSuppressionFetchingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+
+        let suppression = |reason| EmailSuppression {
+            email: "Bounced@Acme.test".into(),
+            reason,
+            detail: Some("550 5.1.1 user unknown".into()),
+            created: Local::now(),
+        };
+
+        registration
+            .register(suppression(EmailSuppressionReason::HardBounce))
+            .await?;
+
+        // A later complaint keeps the original reason
+        registration
+            .register(suppression(EmailSuppressionReason::Complaint))
+            .await?;
+
+        let email = Email::from_string("bounced@acme.test".into())?;
+
+        match fetching.get(email.to_owned()).await? {
+            FetchResponseKind::Found(stored) => {
+                assert_eq!(stored.email, "bounced@acme.test");
+                assert_eq!(stored.reason, EmailSuppressionReason::HardBounce);
+            }
+            _ => panic!("expected the address to be suppressed"),
+        }
+
+        assert!(matches!(
+            registration.delete(email.to_owned()).await?,
+            DeletionResponseKind::Deleted
+        ));
+        assert!(matches!(
+            fetching.get(email.to_owned()).await?,
+            FetchResponseKind::NotFound(_)
+        ));
+        assert!(matches!(
+            registration.delete(email).await?,
+            DeletionResponseKind::NotDeleted(..)
+        ));
+
+        Ok(())
+    }
+}
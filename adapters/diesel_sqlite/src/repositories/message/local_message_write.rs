@@ -160,10 +160,15 @@ mod tests {
 
         let message = Message {
             from: FromEmail::NamedEmail("Mycelium".into()),
-            to: Email::from_string("owner@acme.test".into())?,
-            cc: None,
+            to: vec![Email::from_string("owner@acme.test".into())?],
+            cc: vec![],
+            bcc: vec![],
+            reply_to: None,
+            headers: vec![],
             subject: "Welcome".into(),
             body: "<p>Hello</p>".into(),
+            text_body: None,
+            attachments: vec![],
         };
         let event = MessageSendingEvent::new(message);
         let event_id = event.id;
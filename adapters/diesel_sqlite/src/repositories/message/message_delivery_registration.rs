@@ -0,0 +1,105 @@
+use crate::{
+    config::SqliteDbPoolProvider,
+    models::email_delivery::MessageDelivery as MessageDeliveryModel,
+    schema::message_delivery,
+    types::{timestamp_to_text, uuid_to_text},
+};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use diesel::{prelude::*, upsert::excluded};
+use myc_core::domain::{
+    dtos::{
+        email_delivery::{MessageDelivery, MessageDeliveryStatus},
+        native_error_codes::NativeErrorCodes,
+    },
+    entities::MessageDeliveryRegistration,
+};
+use mycelium_base::utils::errors::{creation_err, updating_err, MappedErrors};
+use shaku::Component;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Component)]
+#[shaku(interface = MessageDeliveryRegistration)]
+pub struct MessageDeliveryRegistrationSqlDbRepository {
+    #[shaku(inject)]
+    pub db_config: Arc<dyn SqliteDbPoolProvider>,
+}
+
+#[async_trait]
+impl MessageDeliveryRegistration
+    for MessageDeliveryRegistrationSqlDbRepository
+{
+    #[tracing::instrument(name = "register_message_delivery", skip_all)]
+    async fn register(
+        &self,
+        delivery: MessageDelivery,
+    ) -> Result<(), MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            creation_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        diesel::insert_into(message_delivery::table)
+            .values(&MessageDeliveryModel {
+                id: uuid_to_text(&delivery.id),
+                recipient: delivery.recipient,
+                subject: delivery.subject,
+                status: delivery.status.to_string(),
+                detail: delivery.detail,
+                created: timestamp_to_text(
+                    &delivery.created.with_timezone(&Utc),
+                ),
+                updated: delivery.updated.map(|updated| {
+                    timestamp_to_text(&updated.with_timezone(&Utc))
+                }),
+            })
+            .on_conflict(message_delivery::id)
+            .do_update()
+            .set((
+                message_delivery::status.eq(excluded(message_delivery::status)),
+                message_delivery::detail.eq(excluded(message_delivery::detail)),
+                message_delivery::updated
+                    .eq(excluded(message_delivery::updated)),
+            ))
+            .execute(conn)
+            .map_err(|e| {
+                creation_err(format!(
+                    "Failed to register message delivery: {e}"
+                ))
+            })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "update_message_delivery_status", skip_all)]
+    async fn update_status(
+        &self,
+        id: Uuid,
+        status: MessageDeliveryStatus,
+        detail: Option<String>,
+    ) -> Result<bool, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            updating_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let updated =
+            diesel::update(message_delivery::table.find(uuid_to_text(&id)))
+                .set((
+                    message_delivery::status.eq(status.to_string()),
+                    message_delivery::detail.eq(detail),
+                    message_delivery::updated
+                        .eq(Some(timestamp_to_text(&Utc::now()))),
+                ))
+                .execute(conn)
+                .map_err(|e| {
+                    updating_err(format!(
+                        "Failed to update message delivery status: {e}"
+                    ))
+                })?;
+
+        Ok(updated > 0)
+    }
+}
@@ -1,5 +1,13 @@
+mod email_suppression_fetching;
+mod email_suppression_registration;
 mod local_message_read;
 mod local_message_write;
+mod message_delivery_fetching;
+mod message_delivery_registration;
 
+pub use email_suppression_fetching::*;
+pub use email_suppression_registration::*;
 pub use local_message_read::*;
 pub use local_message_write::*;
+pub use message_delivery_fetching::*;
+pub use message_delivery_registration::*;
@@ -3,6 +3,7 @@ mod optional_written_by_parser;
 
 pub mod account;
 pub mod account_tag;
+pub mod bulk_import;
 pub mod encryption_key;
 pub mod error_code;
 pub mod guest_role;
@@ -10,8 +11,11 @@ pub mod guest_user;
 pub mod instance_settings;
 pub mod licensed_resources;
 pub mod message;
+pub mod notification;
 pub mod profile;
 pub mod resource_audit_log;
+pub mod scheduled_job;
+pub mod scim;
 pub mod tenant;
 pub mod tenant_tag;
 pub mod token;
@@ -22,6 +26,7 @@ use shaku::module;
 
 use account::*;
 use account_tag::*;
+use bulk_import::*;
 use encryption_key::*;
 use error_code::*;
 use guest_role::*;
@@ -29,9 +34,12 @@ use guest_user::*;
 use instance_settings::*;
 use licensed_resources::*;
 use message::*;
+use notification::*;
 use optional_written_by_parser::*;
 use profile::*;
 use resource_audit_log::*;
+use scheduled_job::*;
+use scim::*;
 use tenant::*;
 use tenant_tag::*;
 use token::*;
@@ -57,10 +65,17 @@ module! {
             AccountTagDeletionSqlDbRepository,
             AccountTagRegistrationSqlDbRepository,
             AccountTagUpdatingSqlDbRepository,
+            BulkImportFetchingSqlDbRepository,
+            BulkImportRegistrationSqlDbRepository,
+            BulkImportUpdatingSqlDbRepository,
+            EmailSuppressionFetchingSqlDbRepository,
+            EmailSuppressionRegistrationSqlDbRepository,
             EncryptionKeyFetchingSqlDbRepository,
             ErrorCodeDeletionSqlDbRepository,
             ErrorCodeFetchingSqlDbRepository,
             ErrorCodeRegistrationSqlDbRepository,
+            ErrorCodeTranslationFetchingSqlDbRepository,
+            ErrorCodeTranslationRegistrationSqlDbRepository,
             ErrorCodeUpdatingSqlDbRepository,
             GuestRoleDeletionSqlDbRepository,
             GuestRoleFetchingSqlDbRepository,
@@ -77,8 +92,20 @@ module! {
             LicensedResourcesFetchingSqlDbRepository,
             LocalMessageReadSqlDbRepository,
             LocalMessageWriteSqlDbRepository,
+            MessageDeliveryFetchingSqlDbRepository,
+            MessageDeliveryRegistrationSqlDbRepository,
+            NotificationRecipientFetchingSqlDbRepository,
+            ResourceAuditLogArchivalSqlDbRepository,
             ResourceAuditLogFetchingSqlDbRepository,
             ResourceAuditLogRegistrationSqlDbRepository,
+            ScheduledJobClaimingSqlDbRepository,
+            ScimCredentialDeletionSqlDbRepository,
+            ScimCredentialFetchingSqlDbRepository,
+            ScimCredentialRegistrationSqlDbRepository,
+            ScimResourceDeletionSqlDbRepository,
+            ScimResourceFetchingSqlDbRepository,
+            ScimResourceRegistrationSqlDbRepository,
+            ScimResourceUpdatingSqlDbRepository,
             TenantDeletionSqlDbRepository,
             TenantFetchingSqlDbRepository,
             TenantRegistrationSqlDbRepository,
@@ -0,0 +1,171 @@
+use crate::{
+    config::SqliteDbPoolProvider,
+    schema::{account, user},
+    types::uuid_from_text,
+};
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use myc_core::domain::{
+    dtos::{
+        account::AccountMetaKey, email::Email,
+        native_error_codes::NativeErrorCodes,
+        notification::NotificationRecipient,
+    },
+    entities::NotificationRecipientFetching,
+};
+use mycelium_base::{
+    entities::FetchResponseKind,
+    utils::errors::{fetching_err, MappedErrors},
+};
+use shaku::Component;
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+#[derive(Component)]
+#[shaku(interface = NotificationRecipientFetching)]
+pub struct NotificationRecipientFetchingSqlDbRepository {
+    #[shaku(inject)]
+    pub db_config: Arc<dyn SqliteDbPoolProvider>,
+}
+
+#[async_trait]
+impl NotificationRecipientFetching
+    for NotificationRecipientFetchingSqlDbRepository
+{
+    #[tracing::instrument(name = "get_notification_recipient", skip_all)]
+    async fn get_by_email(
+        &self,
+        email: Email,
+    ) -> Result<FetchResponseKind<NotificationRecipient, String>, MappedErrors>
+    {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let result = user::table
+            .inner_join(account::table)
+            .filter(user::email.eq(email.email()))
+            .select((account::id, account::meta))
+            .first::<(String, Option<String>)>(conn)
+            .optional()
+            .map_err(|e| {
+                fetching_err(format!(
+                    "Failed to fetch notification recipient: {}",
+                    e
+                ))
+            })?;
+
+        let Some((account_id, meta)) = result else {
+            return Ok(FetchResponseKind::NotFound(None));
+        };
+
+        let meta: HashMap<String, String> = match meta {
+            Some(meta) => serde_json::from_str(&meta).unwrap_or_default(),
+            None => HashMap::new(),
+        };
+
+        let meta = meta
+            .into_iter()
+            .filter_map(|(key, value)| {
+                AccountMetaKey::from_str(&key).ok().map(|key| (key, value))
+            })
+            .collect();
+
+        Ok(FetchResponseKind::Found(
+            NotificationRecipient::from_account_meta(
+                uuid_from_text(&account_id)?,
+                &meta,
+            ),
+        ))
+    }
+}
+
+// ? ---------------------------------------------------------------------------
+// ? TESTS
+// ? ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_support::setup_temp_db,
+        types::{naive_timestamp_to_text, uuid_to_text},
+    };
+    use chrono::Utc;
+    use myc_core::domain::dtos::notification::{
+        NotificationChannel, NotificationKind,
+    };
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn get_by_email_reads_the_account_meta() -> Result<(), MappedErrors> {
+        let db = setup_temp_db();
+        let fetching = NotificationRecipientFetchingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+
+        let account_id = Uuid::new_v4();
+        let now = naive_timestamp_to_text(&Utc::now().naive_utc());
+
+        {
+            let conn = &mut db.provider.get_pool().get().unwrap();
+            diesel::insert_into(account::table)
+                .values((
+                    account::id.eq(uuid_to_text(&account_id)),
+                    account::name.eq("Acme"),
+                    account::slug.eq("acme"),
+                    account::account_type.eq("\"user\""),
+                    account::created.eq(&now),
+                    account::meta.eq(serde_json::json!({
+                        "notification_preferences":
+                            r#"{"channels":{"magicLink":["sms","email"]}}"#,
+                        "phone_number": "+5511999999999",
+                    })
+                    .to_string()),
+                ))
+                .execute(conn)
+                .unwrap();
+
+            diesel::insert_into(user::table)
+                .values((
+                    user::id.eq(uuid_to_text(&Uuid::new_v4())),
+                    user::username.eq("owner"),
+                    user::email.eq("owner@acme.test"),
+                    user::first_name.eq("Own"),
+                    user::last_name.eq("Er"),
+                    user::is_active.eq(true),
+                    user::created.eq(&now),
+                    user::account_id.eq(uuid_to_text(&account_id)),
+                    user::is_principal.eq(true),
+                ))
+                .execute(conn)
+                .unwrap();
+        }
+
+        let recipient = match fetching
+            .get_by_email(Email::from_string("owner@acme.test".into())?)
+            .await?
+        {
+            FetchResponseKind::Found(recipient) => recipient,
+            FetchResponseKind::NotFound(_) => {
+                panic!("expected the recipient to be found")
+            }
+        };
+        assert_eq!(recipient.account_id, account_id);
+        assert_eq!(recipient.phone_number.as_deref(), Some("+5511999999999"));
+        assert_eq!(
+            recipient
+                .preferences
+                .channels_for(NotificationKind::MagicLink),
+            vec![NotificationChannel::Sms, NotificationChannel::Email]
+        );
+
+        let not_found = fetching
+            .get_by_email(Email::from_string("nobody@acme.test".into())?)
+            .await?;
+        assert!(matches!(not_found, FetchResponseKind::NotFound(_)));
+
+        Ok(())
+    }
+}
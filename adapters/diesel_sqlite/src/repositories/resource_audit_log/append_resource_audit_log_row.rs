@@ -12,46 +12,157 @@
 // in SQLite (see the migration's header comment), so this helper generates
 // the row's UUID itself, the same way every other SQLite registration
 // repository in this crate does.
+//
+// Where Postgres serialises appends per chain with an advisory lock, SQLite
+// has a single writer anyway: an immediate transaction takes the write lock
+// before the chain head is read, so no other writer can slip in between.
+// Archiving takes the same lock.
 // ? ---------------------------------------------------------------------------
 
 use super::shared::{event_kind_to_text, resource_type_to_text};
 use crate::{
-    models::resource_audit_log::ResourceAuditLog as NewResourceAuditLog,
-    schema::resource_audit_log as resource_audit_log_model,
+    models::resource_audit_log::{
+        ResourceAuditCheckpoint as ResourceAuditCheckpointModel,
+        ResourceAuditLog as NewResourceAuditLog,
+    },
+    schema::{
+        resource_audit_archive, resource_audit_checkpoint,
+        resource_audit_log as resource_audit_log_model,
+    },
     types::{json_to_text, timestamp_to_text, uuid_to_text},
 };
 
 use diesel::prelude::*;
 use diesel::SqliteConnection;
-use myc_core::domain::dtos::resource_audit_log::NewResourceAuditLogEvent;
+use myc_core::domain::{
+    dtos::resource_audit_log::{
+        NewResourceAuditLogEvent, ResourceAuditChainLink,
+        ResourceAuditCheckpoint, RESOURCE_AUDIT_CHAIN_GENESIS_HASH,
+    },
+    utils::SYSTEM_TENANT_ID,
+};
 use mycelium_base::utils::errors::{creation_err, MappedErrors};
 use uuid::Uuid;
 
 pub fn append_resource_audit_log_row(
     conn: &mut SqliteConnection,
     event: &NewResourceAuditLogEvent,
-) -> Result<(), MappedErrors> {
+) -> Result<ResourceAuditChainLink, MappedErrors> {
     let performed_by =
         json_to_text(&serde_json::to_value(&event.performed_by).map_err(
             |e| creation_err(format!("Failed to encode performed_by: {e}")),
         )?)?;
 
+    let metadata = json_to_text(&event.metadata)?;
+
+    conn.immediate_transaction(|conn| {
+        insert_chained_row(conn, event, performed_by, metadata)
+    })
+    .map_err(|e: diesel::result::Error| {
+        creation_err(format!("Failed to insert resource audit log: {e}"))
+    })
+}
+
+/// Link `event` into its chain and insert it, inside the immediate
+/// transaction the caller holds.
+pub(super) fn insert_chained_row(
+    conn: &mut SqliteConnection,
+    event: &NewResourceAuditLogEvent,
+    performed_by: String,
+    metadata: String,
+) -> QueryResult<ResourceAuditChainLink> {
+    let tenant_id = event.tenant_id.map(|id| uuid_to_text(&id));
+
+    let head = match &tenant_id {
+        Some(tenant_id) => resource_audit_log_model::table
+            .filter(resource_audit_log_model::tenant_id.eq(tenant_id))
+            .into_boxed(),
+        None => resource_audit_log_model::table
+            .filter(resource_audit_log_model::tenant_id.is_null())
+            .into_boxed(),
+    }
+    .filter(resource_audit_log_model::chain_seq.is_not_null())
+    .order(resource_audit_log_model::chain_seq.desc())
+    .select((
+        resource_audit_log_model::chain_seq,
+        resource_audit_log_model::row_hash,
+    ))
+    .first::<(Option<i64>, Option<String>)>(conn)
+    .optional()?;
+
+    //
+    // A chain whose rows were all archived resumes after its latest
+    // archive rather than restarting from the genesis.
+    //
+    let head =
+        match head {
+            Some((Some(seq), Some(hash))) => Some((seq, hash)),
+            _ => resource_audit_archive::table
+                .filter(resource_audit_archive::chain_tenant_id.eq(
+                    uuid_to_text(&event.tenant_id.unwrap_or(SYSTEM_TENANT_ID)),
+                ))
+                .filter(resource_audit_archive::last_seq.is_not_null())
+                .order_by(resource_audit_archive::last_seq.desc())
+                .select((
+                    resource_audit_archive::last_seq,
+                    resource_audit_archive::last_hash,
+                ))
+                .first::<(Option<i64>, Option<String>)>(conn)
+                .optional()?
+                .and_then(|(seq, hash)| seq.zip(hash)),
+        };
+
+    let (seq, prev_hash) = match head {
+        Some((seq, hash)) => (seq + 1, hash),
+        None => (1, RESOURCE_AUDIT_CHAIN_GENESIS_HASH.to_string()),
+    };
+
+    let link = event.chain_link(seq, &prev_hash);
+
     let new_row = NewResourceAuditLog {
         id: uuid_to_text(&Uuid::new_v4()),
         resource_type: resource_type_to_text(&event.resource_type).to_owned(),
         resource_id: uuid_to_text(&event.resource_id),
-        tenant_id: event.tenant_id.map(|id| uuid_to_text(&id)),
+        tenant_id,
         event: event_kind_to_text(&event.event).to_owned(),
         performed_by,
-        metadata: json_to_text(&event.metadata)?,
+        metadata,
         created_at: timestamp_to_text(&event.created_at),
+        chain_seq: Some(link.seq),
+        prev_hash: Some(link.prev_hash.to_owned()),
+        row_hash: Some(link.hash.to_owned()),
+        redacted_at: None,
     };
 
     diesel::insert_into(resource_audit_log_model::table)
+        .values(&new_row)
+        .execute(conn)?;
+
+    Ok(link)
+}
+
+pub fn append_resource_audit_checkpoint_row(
+    conn: &mut SqliteConnection,
+    checkpoint: &ResourceAuditCheckpoint,
+) -> Result<(), MappedErrors> {
+    let new_row = ResourceAuditCheckpointModel {
+        id: uuid_to_text(&checkpoint.id),
+        chain_tenant_id: uuid_to_text(
+            &checkpoint.chain_tenant_id.unwrap_or(SYSTEM_TENANT_ID),
+        ),
+        chain_seq: checkpoint.seq,
+        root_hash: checkpoint.root_hash.to_owned(),
+        signature: checkpoint.signature.to_owned(),
+        created_at: timestamp_to_text(&checkpoint.created_at),
+    };
+
+    diesel::insert_into(resource_audit_checkpoint::table)
         .values(&new_row)
         .execute(conn)
         .map_err(|e| {
-            creation_err(format!("Failed to insert resource audit log: {e}"))
+            creation_err(format!(
+                "Failed to insert resource audit checkpoint: {e}"
+            ))
         })?;
 
     Ok(())
@@ -0,0 +1,684 @@
+use super::{
+    append_resource_audit_log_row::insert_chained_row,
+    shared::{event_kind_to_text, resource_type_to_text},
+};
+use crate::{
+    config::SqliteDbPoolProvider,
+    models::resource_audit_log::{
+        ResourceAuditArchive as ResourceAuditArchiveModel,
+        ResourceAuditLog as ResourceAuditLogModel,
+        ResourceAuditRetentionPolicy as ResourceAuditRetentionPolicyModel,
+    },
+    schema::{
+        resource_audit_archive, resource_audit_log,
+        resource_audit_retention_policy,
+    },
+    types::{
+        json_to_text, timestamp_from_text, timestamp_to_text, uuid_from_text,
+        uuid_to_text,
+    },
+};
+
+use async_trait::async_trait;
+use diesel::{
+    prelude::*,
+    sql_types::{BigInt, Nullable, Text},
+    SqliteConnection,
+};
+use myc_core::domain::{
+    dtos::{
+        native_error_codes::NativeErrorCodes,
+        resource_audit_log::{
+            NewResourceAuditLogEvent, ResourceAuditArchive, ResourceAuditLog,
+            ResourceAuditRetentionPolicy,
+        },
+    },
+    entities::ResourceAuditLogArchival,
+    utils::SYSTEM_TENANT_ID,
+};
+use mycelium_base::utils::errors::{
+    creation_err, deletion_err, updating_err, MappedErrors,
+};
+use shaku::Component;
+use std::sync::Arc;
+use uuid::Uuid;
+
+const RESTORE_BATCH_SIZE: usize = 500;
+
+#[derive(Component)]
+#[shaku(interface = ResourceAuditLogArchival)]
+pub struct ResourceAuditLogArchivalSqlDbRepository {
+    #[shaku(inject)]
+    pub db_config: Arc<dyn SqliteDbPoolProvider>,
+}
+
+#[async_trait]
+impl ResourceAuditLogArchival for ResourceAuditLogArchivalSqlDbRepository {
+    #[tracing::instrument(
+        name = "upsert_resource_audit_retention_policy",
+        skip_all
+    )]
+    async fn upsert_retention_policy(
+        &self,
+        policy: ResourceAuditRetentionPolicy,
+    ) -> Result<ResourceAuditRetentionPolicy, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            creation_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let model = ResourceAuditRetentionPolicyModel {
+            chain_tenant_id: uuid_to_text(
+                &policy.chain_tenant_id.unwrap_or(SYSTEM_TENANT_ID),
+            ),
+            retention_days: policy.retention_days,
+            legal_hold: policy.legal_hold,
+            updated_at: timestamp_to_text(&policy.updated_at),
+        };
+
+        diesel::insert_into(resource_audit_retention_policy::table)
+            .values(&model)
+            .on_conflict(resource_audit_retention_policy::chain_tenant_id)
+            .do_update()
+            .set(&model)
+            .execute(conn)
+            .map_err(|e| {
+                creation_err(format!(
+                    "Failed to store resource audit retention policy: {e}"
+                ))
+            })?;
+
+        retention_policy_from_model(model)
+    }
+
+    #[tracing::instrument(
+        name = "register_resource_audit_archive_and_purge",
+        skip_all
+    )]
+    async fn register_archive_and_purge(
+        &self,
+        archive: ResourceAuditArchive,
+    ) -> Result<u64, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            creation_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let chain_tenant_id =
+            uuid_to_text(&archive.chain_tenant_id.unwrap_or(SYSTEM_TENANT_ID));
+
+        let model = archive_to_model(&archive);
+
+        //
+        // An immediate transaction takes the write lock first, exactly like
+        // `append_resource_audit_log_row`: no row is appended to the chain
+        // while its archived prefix is being removed.
+        //
+        let outcome = conn
+            .immediate_transaction(|conn| {
+                if is_on_legal_hold(conn, &chain_tenant_id)? {
+                    return Ok(Err("the chain is on legal hold".to_string()));
+                }
+
+                let archived_through = resource_audit_archive::table
+                    .filter(
+                        resource_audit_archive::chain_tenant_id
+                            .eq(&chain_tenant_id),
+                    )
+                    .select(diesel::dsl::max(resource_audit_archive::last_seq))
+                    .first::<Option<i64>>(conn)?
+                    .unwrap_or(0);
+
+                if let Some(first_seq) = archive.first_seq {
+                    if first_seq != archived_through + 1 {
+                        return Ok(Err(format!(
+                            "the chain is archived through seq \
+                             {archived_through}, not {}",
+                            first_seq - 1
+                        )));
+                    }
+                }
+
+                diesel::insert_into(resource_audit_archive::table)
+                    .values(&model)
+                    .execute(conn)?;
+
+                Ok(Ok(delete_archived_rows(conn, &chain_tenant_id)?))
+            })
+            .map_err(|e: diesel::result::Error| {
+                deletion_err(format!(
+                    "Failed to archive resource audit log rows: {e}"
+                ))
+            })?;
+
+        outcome.map_err(|reason| {
+            deletion_err(format!("Resource audit archive refused: {reason}"))
+                .with_exp_true()
+        })
+    }
+
+    #[tracing::instrument(
+        name = "purge_archived_resource_audit_rows",
+        skip_all
+    )]
+    async fn purge_archived_rows(
+        &self,
+        chain_tenant_id: Option<Uuid>,
+    ) -> Result<u64, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            deletion_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let chain_tenant_id =
+            uuid_to_text(&chain_tenant_id.unwrap_or(SYSTEM_TENANT_ID));
+
+        conn.immediate_transaction(|conn| {
+            if is_on_legal_hold(conn, &chain_tenant_id)? {
+                return Ok(0);
+            }
+
+            delete_archived_rows(conn, &chain_tenant_id)
+        })
+        .map_err(|e: diesel::result::Error| {
+            deletion_err(format!(
+                "Failed to purge archived resource audit log rows: {e}"
+            ))
+        })
+    }
+
+    #[tracing::instrument(name = "restore_resource_audit_rows", skip_all)]
+    async fn restore_rows(
+        &self,
+        rows: Vec<ResourceAuditLog>,
+    ) -> Result<u64, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            creation_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let models = rows
+            .into_iter()
+            .map(|row| {
+                Ok(ResourceAuditLogModel {
+                    id: uuid_to_text(&row.id),
+                    resource_type: resource_type_to_text(&row.resource_type)
+                        .to_owned(),
+                    resource_id: uuid_to_text(&row.resource_id),
+                    tenant_id: row.tenant_id.map(|id| uuid_to_text(&id)),
+                    event: event_kind_to_text(&row.event).to_owned(),
+                    performed_by: json_to_text(
+                        &serde_json::to_value(&row.performed_by).map_err(
+                            |e| {
+                                creation_err(format!(
+                                    "Failed to encode performed_by: {e}"
+                                ))
+                            },
+                        )?,
+                    )?,
+                    metadata: json_to_text(&row.metadata)?,
+                    created_at: timestamp_to_text(&row.created_at),
+                    chain_seq: row.chain.as_ref().map(|link| link.seq),
+                    prev_hash: row
+                        .chain
+                        .as_ref()
+                        .map(|link| link.prev_hash.to_owned()),
+                    row_hash: row.chain.map(|link| link.hash),
+                    redacted_at: row
+                        .redacted_at
+                        .map(|at| timestamp_to_text(&at)),
+                })
+            })
+            .collect::<Result<Vec<_>, MappedErrors>>()?;
+
+        conn.immediate_transaction(|conn| {
+            let mut restored = 0;
+
+            for batch in models.chunks(RESTORE_BATCH_SIZE) {
+                restored +=
+                    diesel::insert_or_ignore_into(resource_audit_log::table)
+                        .values(batch)
+                        .execute(conn)? as u64;
+            }
+
+            Ok(restored)
+        })
+        .map_err(|e: diesel::result::Error| {
+            creation_err(format!(
+                "Failed to restore resource audit log rows: {e}"
+            ))
+        })
+    }
+
+    #[tracing::instrument(name = "redact_resource_audit_rows", skip_all)]
+    async fn redact_rows(
+        &self,
+        rows: Vec<ResourceAuditLog>,
+        erasure: NewResourceAuditLogEvent,
+    ) -> Result<u64, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            updating_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let erasure_performed_by = json_to_text(
+            &serde_json::to_value(&erasure.performed_by).map_err(|e| {
+                updating_err(format!("Failed to encode performed_by: {e}"))
+            })?,
+        )?;
+
+        let erasure_metadata = json_to_text(&erasure.metadata)?;
+
+        let updates = rows
+            .into_iter()
+            .map(|row| {
+                Ok((
+                    uuid_to_text(&row.id),
+                    uuid_to_text(&row.tenant_id.unwrap_or(SYSTEM_TENANT_ID)),
+                    json_to_text(
+                        &serde_json::to_value(&row.performed_by).map_err(
+                            |e| {
+                                updating_err(format!(
+                                    "Failed to encode performed_by: {e}"
+                                ))
+                            },
+                        )?,
+                    )?,
+                    json_to_text(&row.metadata)?,
+                    timestamp_to_text(
+                        &row.redacted_at.unwrap_or_else(chrono::Utc::now),
+                    ),
+                ))
+            })
+            .collect::<Result<Vec<_>, MappedErrors>>()?;
+
+        conn.immediate_transaction(|conn| {
+            let mut redacted = 0;
+
+            for (id, chain_tenant_id, performed_by, metadata, redacted_at) in
+                updates
+            {
+                if is_on_legal_hold(conn, &chain_tenant_id)? {
+                    continue;
+                }
+
+                redacted += diesel::update(resource_audit_log::table.find(id))
+                    .set((
+                        resource_audit_log::performed_by.eq(performed_by),
+                        resource_audit_log::metadata.eq(metadata),
+                        resource_audit_log::redacted_at.eq(Some(redacted_at)),
+                    ))
+                    .execute(conn)? as u64;
+            }
+
+            insert_chained_row(
+                conn,
+                &erasure,
+                erasure_performed_by,
+                erasure_metadata,
+            )?;
+
+            Ok(redacted)
+        })
+        .map_err(|e: diesel::result::Error| {
+            updating_err(format!(
+                "Failed to redact resource audit log rows: {e}"
+            ))
+        })
+    }
+}
+
+fn is_on_legal_hold(
+    conn: &mut SqliteConnection,
+    chain_tenant_id: &str,
+) -> Result<bool, diesel::result::Error> {
+    Ok(resource_audit_retention_policy::table
+        .filter(
+            resource_audit_retention_policy::chain_tenant_id
+                .eq(chain_tenant_id),
+        )
+        .select(resource_audit_retention_policy::legal_hold)
+        .first::<bool>(conn)
+        .optional()?
+        .unwrap_or(false))
+}
+
+/// Delete every row of the chain covered by one of its archives. Mirrors the
+/// condition the `trg_resource_audit_log_no_delete` trigger enforces.
+fn delete_archived_rows(
+    conn: &mut SqliteConnection,
+    chain_tenant_id: &str,
+) -> Result<u64, diesel::result::Error> {
+    let (archived_through, cutoff) = resource_audit_archive::table
+        .filter(resource_audit_archive::chain_tenant_id.eq(chain_tenant_id))
+        .select((
+            diesel::dsl::max(resource_audit_archive::last_seq),
+            diesel::dsl::max(resource_audit_archive::cutoff),
+        ))
+        .first::<(Option<i64>, Option<String>)>(conn)?;
+
+    let Some(cutoff) = cutoff else {
+        return Ok(0);
+    };
+
+    diesel::sql_query(
+        "DELETE FROM resource_audit_log \
+         WHERE COALESCE(tenant_id, ?) = ? \
+           AND ((chain_seq IS NOT NULL AND chain_seq <= ?) \
+             OR (chain_seq IS NULL AND created_at < ?))",
+    )
+    .bind::<Text, _>(uuid_to_text(&SYSTEM_TENANT_ID))
+    .bind::<Text, _>(chain_tenant_id)
+    .bind::<Nullable<BigInt>, _>(archived_through)
+    .bind::<Text, _>(cutoff)
+    .execute(conn)
+    .map(|deleted| deleted as u64)
+}
+
+fn archive_to_model(
+    archive: &ResourceAuditArchive,
+) -> ResourceAuditArchiveModel {
+    ResourceAuditArchiveModel {
+        id: uuid_to_text(&archive.id),
+        chain_tenant_id: uuid_to_text(
+            &archive.chain_tenant_id.unwrap_or(SYSTEM_TENANT_ID),
+        ),
+        first_seq: archive.first_seq,
+        last_seq: archive.last_seq,
+        first_prev_hash: archive.first_prev_hash.to_owned(),
+        last_hash: archive.last_hash.to_owned(),
+        cutoff: timestamp_to_text(&archive.cutoff),
+        row_count: archive.row_count,
+        location: archive.location.to_owned(),
+        sha256: archive.sha256.to_owned(),
+        signature: archive.signature.to_owned(),
+        created_at: timestamp_to_text(&archive.created_at),
+    }
+}
+
+pub(super) fn archive_from_model(
+    record: ResourceAuditArchiveModel,
+) -> Result<ResourceAuditArchive, MappedErrors> {
+    Ok(ResourceAuditArchive {
+        id: uuid_from_text(&record.id)?,
+        chain_tenant_id: Some(uuid_from_text(&record.chain_tenant_id)?)
+            .filter(|id| *id != SYSTEM_TENANT_ID),
+        first_seq: record.first_seq,
+        last_seq: record.last_seq,
+        first_prev_hash: record.first_prev_hash,
+        last_hash: record.last_hash,
+        cutoff: timestamp_from_text(&record.cutoff)?,
+        row_count: record.row_count,
+        location: record.location,
+        sha256: record.sha256,
+        signature: record.signature,
+        created_at: timestamp_from_text(&record.created_at)?,
+    })
+}
+
+pub(super) fn retention_policy_from_model(
+    record: ResourceAuditRetentionPolicyModel,
+) -> Result<ResourceAuditRetentionPolicy, MappedErrors> {
+    Ok(ResourceAuditRetentionPolicy {
+        chain_tenant_id: Some(uuid_from_text(&record.chain_tenant_id)?)
+            .filter(|id| *id != SYSTEM_TENANT_ID),
+        retention_days: record.retention_days,
+        legal_hold: record.legal_hold,
+        updated_at: timestamp_from_text(&record.updated_at)?,
+    })
+}
+
+// ? ---------------------------------------------------------------------------
+// ? TESTS
+// ? ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        repositories::resource_audit_log::{
+            append_resource_audit_log_row,
+            ResourceAuditLogFetchingSqlDbRepository,
+        },
+        test_support::setup_temp_db,
+    };
+    use chrono::Utc;
+    use myc_core::domain::{
+        dtos::{
+            resource_audit_log::{
+                NewResourceAuditLogEvent, ResourceAuditEventKind,
+                ResourceAuditRedaction, ResourceAuditResourceType,
+                RESOURCE_AUDIT_CHAIN_GENESIS_HASH,
+                RESOURCE_AUDIT_ERASURE_ACTION,
+            },
+            written_by::WrittenBy,
+        },
+        entities::ResourceAuditLogFetching,
+    };
+    use mycelium_base::entities::FetchManyResponseKind;
+    use std::collections::HashSet;
+
+    fn new_event(tenant_id: Option<Uuid>) -> NewResourceAuditLogEvent {
+        NewResourceAuditLogEvent {
+            resource_type: ResourceAuditResourceType::Account,
+            resource_id: Uuid::new_v4(),
+            tenant_id,
+            event: ResourceAuditEventKind::Created,
+            performed_by: WrittenBy::new_anemic(),
+            metadata: serde_json::json!({}),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn archive_of(
+        chain_tenant_id: Option<Uuid>,
+        rows: &[ResourceAuditLog],
+    ) -> ResourceAuditArchive {
+        let first = rows.first().and_then(|row| row.chain.to_owned());
+        let last = rows.last().and_then(|row| row.chain.to_owned());
+
+        ResourceAuditArchive {
+            id: Uuid::new_v4(),
+            chain_tenant_id,
+            first_seq: first.as_ref().map(|link| link.seq),
+            last_seq: last.as_ref().map(|link| link.seq),
+            first_prev_hash: first.map(|link| link.prev_hash),
+            last_hash: last.map(|link| link.hash),
+            cutoff: Utc::now(),
+            row_count: rows.len() as i64,
+            location: "test".to_string(),
+            sha256: String::new(),
+            signature: String::new(),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn archived_prefix_is_purged_unless_held_and_can_be_restored(
+    ) -> Result<(), MappedErrors> {
+        let db = setup_temp_db();
+        let tenant_id = Some(Uuid::new_v4());
+
+        let mut conn = db.provider.get_pool().get().unwrap();
+        for _ in 0..3 {
+            append_resource_audit_log_row(&mut conn, &new_event(tenant_id))?;
+        }
+
+        let fetching = ResourceAuditLogFetchingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let archival = ResourceAuditLogArchivalSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+
+        let rows = match fetching.list_chain_page(tenant_id, 0, 10).await? {
+            FetchManyResponseKind::Found(records) => records,
+            other => panic!("expected Found, got {:?}", other),
+        };
+
+        // Rows no archive covers cannot be deleted.
+        assert!(diesel::delete(resource_audit_log::table)
+            .execute(&mut conn)
+            .is_err());
+
+        // An archive must start right after the previous one.
+        assert!(archival
+            .register_archive_and_purge(archive_of(tenant_id, &rows[1..2]))
+            .await
+            .is_err());
+
+        let purged = archival
+            .register_archive_and_purge(archive_of(tenant_id, &rows[..2]))
+            .await?;
+        assert_eq!(purged, 2);
+
+        // Appends resume after the archived prefix even once it is gone.
+        let link =
+            append_resource_audit_log_row(&mut conn, &new_event(tenant_id))?;
+        assert_eq!(link.seq, 4);
+        assert_ne!(link.prev_hash, RESOURCE_AUDIT_CHAIN_GENESIS_HASH);
+
+        // Restored rows are purged again, except under legal hold.
+        assert_eq!(archival.restore_rows(rows[..2].to_vec()).await?, 2);
+
+        archival
+            .upsert_retention_policy(ResourceAuditRetentionPolicy {
+                legal_hold: true,
+                ..ResourceAuditRetentionPolicy::default_for(tenant_id)
+            })
+            .await?;
+
+        assert_eq!(archival.purge_archived_rows(tenant_id).await?, 0);
+        assert!(diesel::delete(resource_audit_log::table)
+            .execute(&mut conn)
+            .is_err());
+        assert!(archival
+            .register_archive_and_purge(archive_of(tenant_id, &rows[2..3]))
+            .await
+            .is_err());
+
+        archival
+            .upsert_retention_policy(ResourceAuditRetentionPolicy::default_for(
+                tenant_id,
+            ))
+            .await?;
+
+        assert_eq!(archival.purge_archived_rows(tenant_id).await?, 2);
+
+        let archives = match fetching.list_archives(tenant_id).await? {
+            FetchManyResponseKind::Found(records) => records,
+            other => panic!("expected Found, got {:?}", other),
+        };
+        assert_eq!(archives.len(), 1);
+        assert_eq!(archives[0].last_seq, Some(2));
+        assert_eq!(archives[0].chain_tenant_id, tenant_id);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rows_are_redacted_unless_held_and_nothing_else_can_change(
+    ) -> Result<(), MappedErrors> {
+        let db = setup_temp_db();
+        let tenant_id = Some(Uuid::new_v4());
+
+        let mut conn = db.provider.get_pool().get().unwrap();
+        append_resource_audit_log_row(
+            &mut conn,
+            &NewResourceAuditLogEvent {
+                performed_by: WrittenBy::new_from_email("jane@example.com"),
+                metadata: serde_json::json!({ "email": "jane@example.com" }),
+                ..new_event(tenant_id)
+            },
+        )?;
+
+        let fetching = ResourceAuditLogFetchingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let archival = ResourceAuditLogArchivalSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+
+        let row = match fetching.list_chain_page(tenant_id, 0, 10).await? {
+            FetchManyResponseKind::Found(records) => records[0].to_owned(),
+            other => panic!("expected Found, got {:?}", other),
+        };
+
+        // Plain updates stay rejected.
+        assert!(diesel::update(resource_audit_log::table)
+            .set(resource_audit_log::metadata.eq("{}"))
+            .execute(&mut conn)
+            .is_err());
+
+        let redacted = row.pseudonymise("jane@example.com", "x").unwrap();
+        let redaction = ResourceAuditRedaction::of(&redacted).unwrap();
+        let erasure = NewResourceAuditLogEvent {
+            event: ResourceAuditEventKind::Updated,
+            metadata: serde_json::json!({
+                "action": RESOURCE_AUDIT_ERASURE_ACTION,
+                "redactions": [redaction],
+            }),
+            ..new_event(None)
+        };
+
+        archival
+            .upsert_retention_policy(ResourceAuditRetentionPolicy {
+                legal_hold: true,
+                ..ResourceAuditRetentionPolicy::default_for(tenant_id)
+            })
+            .await?;
+
+        assert_eq!(
+            archival
+                .redact_rows(vec![redacted.to_owned()], erasure.to_owned())
+                .await?,
+            0
+        );
+
+        archival
+            .upsert_retention_policy(ResourceAuditRetentionPolicy::default_for(
+                tenant_id,
+            ))
+            .await?;
+
+        assert_eq!(
+            archival
+                .redact_rows(vec![redacted.to_owned()], erasure.to_owned())
+                .await?,
+            1
+        );
+
+        let stored = match fetching.list_chain_page(tenant_id, 0, 10).await? {
+            FetchManyResponseKind::Found(records) => records[0].to_owned(),
+            other => panic!("expected Found, got {:?}", other),
+        };
+
+        assert!(stored.is_redacted());
+        assert_eq!(stored.chain, row.chain);
+        assert_eq!(stored.metadata, serde_json::json!({ "email": "x" }));
+        assert_eq!(stored.performed_by, WrittenBy::new_from_email("x"));
+        assert!(!stored.content_verifies(&HashSet::new()));
+        assert!(stored.content_verifies(&HashSet::from([redaction])));
+
+        // Every call chained its erasure event in the system chain, and the
+        // event vouches for the redacted content.
+        let erasures = match fetching.list_chain_page(None, 0, 10).await? {
+            FetchManyResponseKind::Found(records) => records,
+            other => panic!("expected Found, got {:?}", other),
+        };
+        assert_eq!(erasures.len(), 2);
+        assert!(stored.content_verifies(
+            &ResourceAuditRedaction::from_erasure_event(&erasures[1])
+                .into_iter()
+                .collect()
+        ));
+
+        // The chain link of a redacted row still cannot change.
+        assert!(diesel::update(resource_audit_log::table)
+            .set(resource_audit_log::row_hash.eq("tampered"))
+            .execute(&mut conn)
+            .is_err());
+
+        Ok(())
+    }
+}
@@ -1,18 +1,44 @@
-use super::shared::{map_model_to_dto, resource_type_to_text};
+use super::{
+    resource_audit_log_archival::{
+        archive_from_model, retention_policy_from_model,
+    },
+    shared::{event_kind_to_text, map_model_to_dto, resource_type_to_text},
+};
 use crate::{
     config::SqliteDbPoolProvider,
-    models::resource_audit_log::ResourceAuditLog as ResourceAuditLogModel,
-    schema::resource_audit_log, types::uuid_to_text,
+    models::resource_audit_log::{
+        ResourceAuditArchive as ResourceAuditArchiveModel,
+        ResourceAuditCheckpoint as ResourceAuditCheckpointModel,
+        ResourceAuditLog as ResourceAuditLogModel,
+        ResourceAuditRetentionPolicy as ResourceAuditRetentionPolicyModel,
+    },
+    schema::{
+        resource_audit_archive, resource_audit_checkpoint, resource_audit_log,
+        resource_audit_retention_policy,
+    },
+    types::{
+        timestamp_from_text, timestamp_to_text, uuid_from_text, uuid_to_text,
+    },
 };
 
 use async_trait::async_trait;
-use diesel::prelude::*;
+use chrono::{DateTime, Utc};
+use diesel::{
+    dsl::sql,
+    prelude::*,
+    sql_types::{Bool, Text},
+};
 use myc_core::domain::{
     dtos::{
         native_error_codes::NativeErrorCodes,
-        resource_audit_log::{ResourceAuditLog, ResourceAuditResourceType},
+        resource_audit_log::{
+            ResourceAuditArchive, ResourceAuditCheckpoint, ResourceAuditLog,
+            ResourceAuditLogCursor, ResourceAuditLogFilter,
+            ResourceAuditResourceType, ResourceAuditRetentionPolicy,
+        },
     },
     entities::ResourceAuditLogFetching,
+    utils::SYSTEM_TENANT_ID,
 };
 use mycelium_base::{
     entities::FetchManyResponseKind,
@@ -130,18 +156,437 @@ impl ResourceAuditLogFetching for ResourceAuditLogFetchingSqlDbRepository {
             records: logs,
         })
     }
+
+    #[tracing::instrument(name = "list_resource_audit_log_filtered", skip_all)]
+    async fn list_filtered(
+        &self,
+        filter: ResourceAuditLogFilter,
+        cursor: Option<ResourceAuditLogCursor>,
+        page_size: i64,
+    ) -> Result<FetchManyResponseKind<ResourceAuditLog>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let mut query = resource_audit_log::table.into_boxed();
+
+        if let Some(resource_type) = filter.resource_type {
+            query = query.filter(
+                resource_audit_log::resource_type
+                    .eq(resource_type_to_text(&resource_type)),
+            );
+        }
+
+        if let Some(resource_id) = filter.resource_id {
+            query = query.filter(
+                resource_audit_log::resource_id.eq(uuid_to_text(&resource_id)),
+            );
+        }
+
+        if let Some(tenant_id) = filter.tenant_id {
+            query = query.filter(
+                resource_audit_log::tenant_id.eq(uuid_to_text(&tenant_id)),
+            );
+        }
+
+        if let Some(event) = filter.event {
+            query = query.filter(
+                resource_audit_log::event.eq(event_kind_to_text(&event)),
+            );
+        }
+
+        if let Some(performed_by) = filter.performed_by {
+            query = query.filter(
+                sql::<Bool>("json_extract(performed_by, '$.id') = ")
+                    .bind::<Text, _>(uuid_to_text(&performed_by)),
+            );
+        }
+
+        if let Some(created_after) = filter.created_after {
+            query = query.filter(
+                resource_audit_log::created_at
+                    .ge(timestamp_to_text(&created_after)),
+            );
+        }
+
+        if let Some(created_before) = filter.created_before {
+            query = query.filter(
+                resource_audit_log::created_at
+                    .lt(timestamp_to_text(&created_before)),
+            );
+        }
+
+        if let Some(text) = filter.metadata_contains {
+            //
+            // SQLite's LIKE is already case-insensitive for ASCII.
+            //
+            query = query.filter(
+                sql::<Bool>("metadata LIKE ")
+                    .bind::<Text, _>(like_pattern(&text))
+                    .sql(" ESCAPE '\\'"),
+            );
+        }
+
+        if let Some(cursor) = cursor {
+            let created_at = timestamp_to_text(&cursor.created_at);
+
+            query = query.filter(
+                resource_audit_log::created_at.lt(created_at.to_owned()).or(
+                    resource_audit_log::created_at.eq(created_at).and(
+                        resource_audit_log::id.lt(uuid_to_text(&cursor.id)),
+                    ),
+                ),
+            );
+        }
+
+        let records = query
+            .order((
+                resource_audit_log::created_at.desc(),
+                resource_audit_log::id.desc(),
+            ))
+            .limit(page_size)
+            .select(ResourceAuditLogModel::as_select())
+            .load::<ResourceAuditLogModel>(conn)
+            .map_err(|e| {
+                fetching_err(format!(
+                    "Failed to fetch resource audit log: {}",
+                    e
+                ))
+            })?;
+
+        if records.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        let logs = records
+            .into_iter()
+            .map(map_model_to_dto)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(FetchManyResponseKind::Found(logs))
+    }
+
+    #[tracing::instrument(name = "list_resource_audit_chain_tenants", skip_all)]
+    async fn list_chain_tenants(
+        &self,
+    ) -> Result<FetchManyResponseKind<Option<Uuid>>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let tenants = resource_audit_log::table
+            .filter(resource_audit_log::chain_seq.is_not_null())
+            .select(resource_audit_log::tenant_id)
+            .distinct()
+            .load::<Option<String>>(conn)
+            .map_err(|e| {
+                fetching_err(format!(
+                    "Failed to fetch resource audit chains: {}",
+                    e
+                ))
+            })?;
+
+        if tenants.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        let tenants = tenants
+            .into_iter()
+            .map(|value| value.map(|id| uuid_from_text(&id)).transpose())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(FetchManyResponseKind::Found(tenants))
+    }
+
+    #[tracing::instrument(name = "list_resource_audit_chain_page", skip_all)]
+    async fn list_chain_page(
+        &self,
+        chain_tenant_id: Option<Uuid>,
+        after_seq: i64,
+        page_size: i64,
+    ) -> Result<FetchManyResponseKind<ResourceAuditLog>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let query = match chain_tenant_id {
+            Some(tenant_id) => resource_audit_log::table
+                .filter(
+                    resource_audit_log::tenant_id.eq(uuid_to_text(&tenant_id)),
+                )
+                .into_boxed(),
+            None => resource_audit_log::table
+                .filter(resource_audit_log::tenant_id.is_null())
+                .into_boxed(),
+        };
+
+        let records = query
+            .filter(resource_audit_log::chain_seq.gt(after_seq))
+            .order(resource_audit_log::chain_seq.asc())
+            .limit(page_size)
+            .select(ResourceAuditLogModel::as_select())
+            .load::<ResourceAuditLogModel>(conn)
+            .map_err(|e| {
+                fetching_err(format!(
+                    "Failed to fetch resource audit log: {}",
+                    e
+                ))
+            })?;
+
+        if records.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        let logs = records
+            .into_iter()
+            .map(map_model_to_dto)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(FetchManyResponseKind::Found(logs))
+    }
+
+    #[tracing::instrument(name = "list_resource_audit_checkpoints", skip_all)]
+    async fn list_checkpoints(
+        &self,
+        chain_tenant_id: Option<Uuid>,
+    ) -> Result<FetchManyResponseKind<ResourceAuditCheckpoint>, MappedErrors>
+    {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let records =
+            resource_audit_checkpoint::table
+                .filter(resource_audit_checkpoint::chain_tenant_id.eq(
+                    uuid_to_text(&chain_tenant_id.unwrap_or(SYSTEM_TENANT_ID)),
+                ))
+                .order(resource_audit_checkpoint::chain_seq.asc())
+                .select(ResourceAuditCheckpointModel::as_select())
+                .load::<ResourceAuditCheckpointModel>(conn)
+                .map_err(|e| {
+                    fetching_err(format!(
+                        "Failed to fetch resource audit checkpoints: {}",
+                        e
+                    ))
+                })?;
+
+        if records.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        let checkpoints = records
+            .into_iter()
+            .map(|record| {
+                Ok(ResourceAuditCheckpoint {
+                    id: uuid_from_text(&record.id)?,
+                    chain_tenant_id: Some(uuid_from_text(
+                        &record.chain_tenant_id,
+                    )?)
+                    .filter(|id| *id != SYSTEM_TENANT_ID),
+                    seq: record.chain_seq,
+                    root_hash: record.root_hash,
+                    signature: record.signature,
+                    created_at: timestamp_from_text(&record.created_at)?,
+                })
+            })
+            .collect::<Result<Vec<_>, MappedErrors>>()?;
+
+        Ok(FetchManyResponseKind::Found(checkpoints))
+    }
+
+    #[tracing::instrument(
+        name = "list_resource_audit_unchained_page",
+        skip_all
+    )]
+    async fn list_unchained_page(
+        &self,
+        chain_tenant_id: Option<Uuid>,
+        created_from: Option<DateTime<Utc>>,
+        created_before: DateTime<Utc>,
+        page_size: i64,
+        skip: i64,
+    ) -> Result<FetchManyResponseKind<ResourceAuditLog>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let mut query = resource_audit_log::table
+            .filter(resource_audit_log::chain_seq.is_null())
+            .filter(
+                resource_audit_log::created_at
+                    .lt(timestamp_to_text(&created_before)),
+            )
+            .into_boxed();
+
+        query = match chain_tenant_id {
+            Some(tenant_id) => query.filter(
+                resource_audit_log::tenant_id.eq(uuid_to_text(&tenant_id)),
+            ),
+            None => query.filter(resource_audit_log::tenant_id.is_null()),
+        };
+
+        if let Some(created_from) = created_from {
+            query = query.filter(
+                resource_audit_log::created_at
+                    .ge(timestamp_to_text(&created_from)),
+            );
+        }
+
+        let records = query
+            .order((
+                resource_audit_log::created_at.asc(),
+                resource_audit_log::id.asc(),
+            ))
+            .limit(page_size)
+            .offset(skip)
+            .select(ResourceAuditLogModel::as_select())
+            .load::<ResourceAuditLogModel>(conn)
+            .map_err(|e| {
+                fetching_err(format!(
+                    "Failed to fetch resource audit log: {}",
+                    e
+                ))
+            })?;
+
+        if records.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        let logs = records
+            .into_iter()
+            .map(map_model_to_dto)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(FetchManyResponseKind::Found(logs))
+    }
+
+    #[tracing::instrument(name = "list_resource_audit_archives", skip_all)]
+    async fn list_archives(
+        &self,
+        chain_tenant_id: Option<Uuid>,
+    ) -> Result<FetchManyResponseKind<ResourceAuditArchive>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let records =
+            resource_audit_archive::table
+                .filter(resource_audit_archive::chain_tenant_id.eq(
+                    uuid_to_text(&chain_tenant_id.unwrap_or(SYSTEM_TENANT_ID)),
+                ))
+                .order((
+                    resource_audit_archive::created_at.asc(),
+                    resource_audit_archive::id.asc(),
+                ))
+                .select(ResourceAuditArchiveModel::as_select())
+                .load::<ResourceAuditArchiveModel>(conn)
+                .map_err(|e| {
+                    fetching_err(format!(
+                        "Failed to fetch resource audit archives: {}",
+                        e
+                    ))
+                })?;
+
+        if records.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        let archives = records
+            .into_iter()
+            .map(archive_from_model)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(FetchManyResponseKind::Found(archives))
+    }
+
+    #[tracing::instrument(
+        name = "list_resource_audit_retention_policies",
+        skip_all
+    )]
+    async fn list_retention_policies(
+        &self,
+    ) -> Result<FetchManyResponseKind<ResourceAuditRetentionPolicy>, MappedErrors>
+    {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let records = resource_audit_retention_policy::table
+            .order(resource_audit_retention_policy::chain_tenant_id.asc())
+            .select(ResourceAuditRetentionPolicyModel::as_select())
+            .load::<ResourceAuditRetentionPolicyModel>(conn)
+            .map_err(|e| {
+                fetching_err(format!(
+                    "Failed to fetch resource audit retention policies: {}",
+                    e
+                ))
+            })?;
+
+        if records.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        let policies = records
+            .into_iter()
+            .map(retention_policy_from_model)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(FetchManyResponseKind::Found(policies))
+    }
 }
 
 // ? ---------------------------------------------------------------------------
 // ? TESTS
 // ? ---------------------------------------------------------------------------
 
+/// `%text%` with the `LIKE` wildcards of `text` escaped, so the search is a
+/// plain substring match.
+fn like_pattern(text: &str) -> String {
+    format!(
+        "%{}%",
+        text.replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_")
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_support::setup_temp_db;
+    use crate::{
+        repositories::resource_audit_log::{
+            append_resource_audit_checkpoint_row, append_resource_audit_log_row,
+        },
+        test_support::setup_temp_db,
+    };
+    use chrono::Utc;
     use diesel::RunQueryDsl;
-    use myc_core::domain::dtos::resource_audit_log::ResourceAuditEventKind;
+    use myc_core::domain::dtos::{
+        resource_audit_log::{
+            NewResourceAuditLogEvent, ResourceAuditEventKind,
+            RESOURCE_AUDIT_CHAIN_GENESIS_HASH,
+        },
+        written_by::WrittenBy,
+    };
+
+    fn new_event(tenant_id: Option<Uuid>) -> NewResourceAuditLogEvent {
+        NewResourceAuditLogEvent {
+            resource_type: ResourceAuditResourceType::Account,
+            resource_id: Uuid::new_v4(),
+            tenant_id,
+            event: ResourceAuditEventKind::Created,
+            performed_by: WrittenBy::new_anemic(),
+            metadata: serde_json::json!({ "name": "acc" }),
+            created_at: Utc::now(),
+        }
+    }
 
     fn insert_row(
         conn: &mut diesel::SqliteConnection,
@@ -162,6 +607,10 @@ mod tests {
             .unwrap(),
             metadata: "{}".to_string(),
             created_at: created_at.to_string(),
+            chain_seq: None,
+            prev_hash: None,
+            row_hash: None,
+            redacted_at: None,
         };
 
         diesel::insert_into(resource_audit_log::table)
@@ -296,4 +745,227 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn appended_rows_form_one_chain_per_tenant(
+    ) -> Result<(), MappedErrors> {
+        let db = setup_temp_db();
+        let mut conn = db.provider.get_pool().get().unwrap();
+
+        let tenant_id = Uuid::new_v4();
+
+        // A legacy row, written before chaining, stays outside the chain.
+        insert_row(
+            &mut conn,
+            "account",
+            &Uuid::new_v4().to_string(),
+            Some(&tenant_id.to_string()),
+            "2026-07-01T00:00:00+00:00",
+        );
+
+        let first = append_resource_audit_log_row(
+            &mut conn,
+            &new_event(Some(tenant_id)),
+        )?;
+        let system =
+            append_resource_audit_log_row(&mut conn, &new_event(None))?;
+        let second = append_resource_audit_log_row(
+            &mut conn,
+            &new_event(Some(tenant_id)),
+        )?;
+        drop(conn);
+
+        assert_eq!(first.seq, 1);
+        assert_eq!(first.prev_hash, RESOURCE_AUDIT_CHAIN_GENESIS_HASH);
+        assert_eq!(system.seq, 1);
+        assert_eq!(second.seq, 2);
+        assert_eq!(second.prev_hash, first.hash);
+
+        let fetching = ResourceAuditLogFetchingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+
+        let rows =
+            match fetching.list_chain_page(Some(tenant_id), 0, 10).await? {
+                FetchManyResponseKind::Found(records) => records,
+                other => panic!("expected Found, got {:?}", other),
+            };
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1].chain, Some(second));
+
+        // Stored rows recompute to the hash computed at append time.
+        assert!(rows
+            .iter()
+            .all(|row| row.recompute_chain_link() == row.chain));
+
+        let tail =
+            match fetching.list_chain_page(Some(tenant_id), 1, 10).await? {
+                FetchManyResponseKind::Found(records) => records,
+                other => panic!("expected Found, got {:?}", other),
+            };
+        assert_eq!(tail.len(), 1);
+
+        let mut tenants = match fetching.list_chain_tenants().await? {
+            FetchManyResponseKind::Found(records) => records,
+            other => panic!("expected Found, got {:?}", other),
+        };
+        tenants.sort();
+        assert_eq!(tenants, vec![None, Some(tenant_id)]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn checkpoints_round_trip_and_are_immutable(
+    ) -> Result<(), MappedErrors> {
+        let db = setup_temp_db();
+        let mut conn = db.provider.get_pool().get().unwrap();
+
+        let link = append_resource_audit_log_row(&mut conn, &new_event(None))?;
+        let checkpoint =
+            ResourceAuditCheckpoint::new_signed(None, &link, &[3u8; 32])?;
+
+        append_resource_audit_checkpoint_row(&mut conn, &checkpoint)?;
+
+        let deleted =
+            diesel::delete(resource_audit_checkpoint::table).execute(&mut conn);
+        assert!(deleted.is_err());
+        drop(conn);
+
+        let fetching = ResourceAuditLogFetchingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+
+        let found = match fetching.list_checkpoints(None).await? {
+            FetchManyResponseKind::Found(records) => records,
+            other => panic!("expected Found, got {:?}", other),
+        };
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].chain_tenant_id, None);
+        assert_eq!(found[0].root_hash, link.hash);
+        assert!(found[0].has_valid_signature(&[3u8; 32]));
+
+        assert!(matches!(
+            fetching.list_checkpoints(Some(Uuid::new_v4())).await?,
+            FetchManyResponseKind::NotFound
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn list_filtered_applies_filters_and_cursor(
+    ) -> Result<(), MappedErrors> {
+        let db = setup_temp_db();
+        let mut conn = db.provider.get_pool().get().unwrap();
+
+        let actor_id = Uuid::new_v4();
+        let start = Utc::now() - chrono::Duration::hours(1);
+
+        for minute in 0..5 {
+            let mut event = new_event(None);
+            event.created_at = start + chrono::Duration::minutes(minute);
+            event.performed_by = WrittenBy::new_from_user(actor_id);
+            event.metadata = serde_json::json!({ "note": "Rotated 50%_key" });
+            append_resource_audit_log_row(&mut conn, &event)?;
+        }
+
+        // Other actor and metadata -- must be excluded.
+        append_resource_audit_log_row(&mut conn, &new_event(None))?;
+        drop(conn);
+
+        let fetching = ResourceAuditLogFetchingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+
+        let filter = ResourceAuditLogFilter {
+            performed_by: Some(actor_id),
+            event: Some(ResourceAuditEventKind::Created),
+            metadata_contains: Some("rotated 50%_".to_string()),
+            created_after: Some(start + chrono::Duration::minutes(1)),
+            ..Default::default()
+        };
+
+        let first_page =
+            match fetching.list_filtered(filter.to_owned(), None, 2).await? {
+                FetchManyResponseKind::Found(records) => records,
+                other => panic!("expected Found, got {:?}", other),
+            };
+
+        assert_eq!(first_page.len(), 2);
+        assert!(first_page[0].created_at > first_page[1].created_at);
+
+        let rest = match fetching
+            .list_filtered(
+                filter,
+                Some(ResourceAuditLogCursor::from_log(&first_page[1])),
+                10,
+            )
+            .await?
+        {
+            FetchManyResponseKind::Found(records) => records,
+            other => panic!("expected Found, got {:?}", other),
+        };
+
+        assert_eq!(rest.len(), 2);
+        assert!(rest[0].created_at < first_page[1].created_at);
+        assert!(rest.iter().all(|row| row.performed_by.id == Some(actor_id)));
+
+        let unmatched = ResourceAuditLogFilter {
+            metadata_contains: Some("rotated 50x".to_string()),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            fetching.list_filtered(unmatched, None, 10).await?,
+            FetchManyResponseKind::NotFound
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn widened_resource_types_and_events_survive_the_table_rebuild(
+    ) -> Result<(), MappedErrors> {
+        let db = setup_temp_db();
+        let mut conn = db.provider.get_pool().get().unwrap();
+
+        let event = NewResourceAuditLogEvent {
+            resource_type: ResourceAuditResourceType::ConnectionString,
+            event: ResourceAuditEventKind::Accessed,
+            ..new_event(None)
+        };
+        let resource_id = event.resource_id;
+
+        append_resource_audit_log_row(&mut conn, &event)?;
+
+        // The rebuild must have restored the immutability triggers.
+        let deleted =
+            diesel::delete(resource_audit_log::table).execute(&mut conn);
+        assert!(deleted.is_err());
+        drop(conn);
+
+        let fetching = ResourceAuditLogFetchingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+
+        let found = match fetching
+            .list_by_resource(
+                ResourceAuditResourceType::ConnectionString,
+                resource_id,
+            )
+            .await?
+        {
+            FetchManyResponseKind::Found(records) => records,
+            other => panic!("expected Found, got {:?}", other),
+        };
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].event, ResourceAuditEventKind::Accessed);
+        assert_eq!(found[0].recompute_chain_link(), found[0].chain);
+
+        Ok(())
+    }
 }
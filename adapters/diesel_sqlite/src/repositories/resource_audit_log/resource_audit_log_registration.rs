@@ -1,25 +1,28 @@
 use myc_core::domain::{
     dtos::resource_audit_log::NewResourceAuditLogEvent,
-    entities::ResourceAuditLogRegistration,
+    entities::ResourceAuditLogRegistration, utils::ResourceAuditSpill,
 };
 use mycelium_base::utils::errors::MappedErrors;
 
 use async_trait::async_trait;
 use shaku::Component;
-use tokio::sync::mpsc::{error::TrySendError, Sender};
+use std::sync::Arc;
 
-/// Non-blocking enqueue into the audit log channel. Does no I/O itself --
-/// the actual insert happens in `resource_audit_log_dispatcher` (ports/api),
-/// the channel's single consumer. `sender` is a plain (non-`#[shaku(inject)]`)
-/// field, following `DieselSqliteDbPoolProvider`'s externally-parameterized
-/// `Component` pattern: shaku generates a
+/// Write-ahead enqueue of audit log events. Every event is appended to
+/// `spill` and flushed to disk on the blocking pool before
+/// `resource_audit_log_dispatcher` (ports/api), the file's single consumer,
+/// is woken up to perform the actual insert; events still in the file when
+/// the process dies are persisted by the next start. `spill` is a plain
+/// (non-`#[shaku(inject)]`) field, following
+/// `DieselSqliteDbPoolProvider`'s externally-parameterized `Component`
+/// pattern: shaku generates a
 /// `ResourceAuditLogRegistrationSqlDbRepositoryParameters` struct so the
-/// caller supplies `sender` via `.with_component_parameters(...)` at
-/// `SqlAppModule::builder()` time instead of DI-resolving it.
+/// caller supplies them via `.with_component_parameters(...)` at
+/// `SqlAppModule::builder()` time instead of DI-resolving them.
 #[derive(Component)]
 #[shaku(interface = ResourceAuditLogRegistration)]
 pub struct ResourceAuditLogRegistrationSqlDbRepository {
-    pub sender: Sender<NewResourceAuditLogEvent>,
+    pub spill: Arc<ResourceAuditSpill>,
 }
 
 #[async_trait]
@@ -31,18 +34,18 @@ impl ResourceAuditLogRegistration
         &self,
         event: NewResourceAuditLogEvent,
     ) -> Result<(), MappedErrors> {
-        match self.sender.try_send(event) {
-            Ok(_) => {}
-            Err(TrySendError::Full(dropped)) => {
-                tracing::warn!(
-                    resource_type = ?dropped.resource_type,
-                    resource_id = %dropped.resource_id,
-                    "audit log channel full, dropping event"
-                );
-            }
-            Err(TrySendError::Closed(_)) => {
-                tracing::error!("audit log channel closed, dropping event");
-            }
+        let spill = self.spill.clone();
+
+        //
+        // `spill` never fails: an event it cannot write is logged in full.
+        //
+        if let Err(err) =
+            tokio::task::spawn_blocking(move || spill.spill(&event)).await
+        {
+            tracing::error!(
+                error = %err,
+                "unable to write audit event ahead"
+            );
         }
 
         Ok(())
@@ -77,28 +80,32 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn create_never_blocks_and_always_returns_ok(
+    async fn create_writes_every_event_ahead_and_always_returns_ok(
     ) -> Result<(), MappedErrors> {
-        let (sender, mut receiver) = tokio::sync::mpsc::channel(1);
-        let repository = ResourceAuditLogRegistrationSqlDbRepository { sender };
+        let spill = Arc::new(ResourceAuditSpill::open(
+            std::env::temp_dir().join(format!(
+                "myc_sqlite_audit_registration_test_{}.jsonl",
+                Uuid::new_v4()
+            )),
+        )?);
+        let repository = ResourceAuditLogRegistrationSqlDbRepository {
+            spill: spill.clone(),
+        };
 
-        // First event: channel has room, `try_send` succeeds.
         let first = sample_event();
         let first_resource_id = first.resource_id;
         repository.create(first).await?;
 
-        // Second event: channel is now full (capacity 1, nothing drained
-        // yet), so `try_send` hits `Full` -- must still return `Ok(())`
-        // and must not panic.
-        repository.create(sample_event()).await?;
-
-        let received = receiver
-            .try_recv()
-            .expect("expected the first event to have been enqueued");
-        assert_eq!(received.resource_id, first_resource_id);
+        let second = sample_event();
+        let second_resource_id = second.resource_id;
+        repository.create(second).await?;
 
-        // Only the first event made it onto the channel.
-        assert!(receiver.try_recv().is_err());
+        // Both events are on disk before `create` returns, in order, for the
+        // dispatcher to persist.
+        let written = spill.take_pending()?;
+        assert_eq!(written.len(), 2);
+        assert_eq!(written[0].resource_id, first_resource_id);
+        assert_eq!(written[1].resource_id, second_resource_id);
 
         Ok(())
     }
@@ -5,7 +5,8 @@ use crate::{
 
 use myc_core::domain::dtos::{
     resource_audit_log::{
-        ResourceAuditEventKind, ResourceAuditLog, ResourceAuditResourceType,
+        ResourceAuditChainLink, ResourceAuditEventKind, ResourceAuditLog,
+        ResourceAuditResourceType,
     },
     written_by::WrittenBy,
 };
@@ -31,6 +32,9 @@ pub(crate) fn resource_type_to_text(
         ResourceAuditResourceType::TenantMeta => "tenant_meta",
         ResourceAuditResourceType::GuestRole => "guest_role",
         ResourceAuditResourceType::Webhook => "webhook",
+        ResourceAuditResourceType::GuestUser => "guest_user",
+        ResourceAuditResourceType::ConnectionString => "connection_string",
+        ResourceAuditResourceType::ErrorCode => "error_code",
     }
 }
 
@@ -45,6 +49,9 @@ fn resource_type_from_text(
         "tenant_meta" => Ok(ResourceAuditResourceType::TenantMeta),
         "guest_role" => Ok(ResourceAuditResourceType::GuestRole),
         "webhook" => Ok(ResourceAuditResourceType::Webhook),
+        "guest_user" => Ok(ResourceAuditResourceType::GuestUser),
+        "connection_string" => Ok(ResourceAuditResourceType::ConnectionString),
+        "error_code" => Ok(ResourceAuditResourceType::ErrorCode),
         other => Err(dto_err(format!(
             "Invalid resource_type in SQLite row: {other}"
         ))),
@@ -60,6 +67,7 @@ pub(crate) fn event_kind_to_text(
         ResourceAuditEventKind::Created => "created",
         ResourceAuditEventKind::Updated => "updated",
         ResourceAuditEventKind::Deleted => "deleted",
+        ResourceAuditEventKind::Accessed => "accessed",
     }
 }
 
@@ -70,6 +78,7 @@ fn event_kind_from_text(
         "created" => Ok(ResourceAuditEventKind::Created),
         "updated" => Ok(ResourceAuditEventKind::Updated),
         "deleted" => Ok(ResourceAuditEventKind::Deleted),
+        "accessed" => Ok(ResourceAuditEventKind::Accessed),
         other => Err(dto_err(format!("Invalid event in SQLite row: {other}"))),
     }
 }
@@ -97,5 +106,19 @@ pub(crate) fn map_model_to_dto(
         performed_by,
         metadata: json_from_text(&model.metadata)?,
         created_at: timestamp_from_text(&model.created_at)?,
+        chain: match (model.chain_seq, model.prev_hash, model.row_hash) {
+            (Some(seq), Some(prev_hash), Some(hash)) => {
+                Some(ResourceAuditChainLink {
+                    seq,
+                    prev_hash,
+                    hash,
+                })
+            }
+            _ => None,
+        },
+        redacted_at: model
+            .redacted_at
+            .map(|value| timestamp_from_text(&value))
+            .transpose()?,
     })
 }
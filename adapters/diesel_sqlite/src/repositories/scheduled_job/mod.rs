@@ -0,0 +1,3 @@
+mod scheduled_job_claiming;
+
+pub use scheduled_job_claiming::*;
@@ -0,0 +1,16 @@
+mod scim_credential_deletion;
+mod scim_credential_fetching;
+mod scim_credential_registration;
+mod scim_resource_deletion;
+mod scim_resource_fetching;
+mod scim_resource_registration;
+mod scim_resource_updating;
+mod shared;
+
+pub use scim_credential_deletion::*;
+pub use scim_credential_fetching::*;
+pub use scim_credential_registration::*;
+pub use scim_resource_deletion::*;
+pub use scim_resource_fetching::*;
+pub use scim_resource_registration::*;
+pub use scim_resource_updating::*;
@@ -0,0 +1,93 @@
+use super::shared::map_credential_model_to_dto;
+use crate::{
+    config::SqliteDbPoolProvider,
+    models::scim_credential::ScimCredential as ScimCredentialModel,
+    schema::scim_credential as scim_credential_model, types::uuid_to_text,
+};
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use myc_core::domain::{
+    dtos::{native_error_codes::NativeErrorCodes, scim::ScimCredential},
+    entities::ScimCredentialFetching,
+};
+use mycelium_base::{
+    entities::{FetchManyResponseKind, FetchResponseKind},
+    utils::errors::{fetching_err, MappedErrors},
+};
+use shaku::Component;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Component)]
+#[shaku(interface = ScimCredentialFetching)]
+pub struct ScimCredentialFetchingSqlDbRepository {
+    #[shaku(inject)]
+    pub db_config: Arc<dyn SqliteDbPoolProvider>,
+}
+
+#[async_trait]
+impl ScimCredentialFetching for ScimCredentialFetchingSqlDbRepository {
+    #[tracing::instrument(
+        name = "get_scim_credential_by_secret_hash",
+        skip_all
+    )]
+    async fn get_by_secret_hash(
+        &self,
+        secret_hash: String,
+    ) -> Result<FetchResponseKind<ScimCredential, String>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let record = scim_credential_model::table
+            .filter(scim_credential_model::secret_hash.eq(&secret_hash))
+            .select(ScimCredentialModel::as_select())
+            .first::<ScimCredentialModel>(conn)
+            .optional()
+            .map_err(|e| {
+                fetching_err(format!("Failed to fetch SCIM credential: {e}"))
+            })?;
+
+        match record {
+            Some(record) => Ok(FetchResponseKind::Found(
+                map_credential_model_to_dto(record)?,
+            )),
+            None => Ok(FetchResponseKind::NotFound(None)),
+        }
+    }
+
+    #[tracing::instrument(name = "list_scim_credentials", skip_all)]
+    async fn list(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<FetchManyResponseKind<ScimCredential>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let records = scim_credential_model::table
+            .filter(
+                scim_credential_model::tenant_id.eq(uuid_to_text(&tenant_id)),
+            )
+            .order(scim_credential_model::created.desc())
+            .select(ScimCredentialModel::as_select())
+            .load::<ScimCredentialModel>(conn)
+            .map_err(|e| {
+                fetching_err(format!("Failed to list SCIM credentials: {e}"))
+            })?;
+
+        if records.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        Ok(FetchManyResponseKind::Found(
+            records
+                .into_iter()
+                .map(map_credential_model_to_dto)
+                .collect::<Result<Vec<_>, _>>()?,
+        ))
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{
+    config::SqliteDbPoolProvider, schema::scim_resource as scim_resource_model,
+    types::uuid_to_text,
+};
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use myc_core::domain::{
+    dtos::{native_error_codes::NativeErrorCodes, scim::ScimResourceKind},
+    entities::ScimResourceDeletion,
+};
+use mycelium_base::{
+    entities::DeletionResponseKind,
+    utils::errors::{deletion_err, MappedErrors},
+};
+use shaku::Component;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Component)]
+#[shaku(interface = ScimResourceDeletion)]
+pub struct ScimResourceDeletionSqlDbRepository {
+    #[shaku(inject)]
+    pub db_config: Arc<dyn SqliteDbPoolProvider>,
+}
+
+#[async_trait]
+impl ScimResourceDeletion for ScimResourceDeletionSqlDbRepository {
+    #[tracing::instrument(name = "delete_scim_resource", skip_all)]
+    async fn delete(
+        &self,
+        tenant_id: Uuid,
+        kind: ScimResourceKind,
+        id: Uuid,
+    ) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            deletion_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let deleted = diesel::delete(
+            scim_resource_model::table
+                .filter(scim_resource_model::id.eq(uuid_to_text(&id)))
+                .filter(
+                    scim_resource_model::tenant_id.eq(uuid_to_text(&tenant_id)),
+                )
+                .filter(scim_resource_model::kind.eq(kind.to_string())),
+        )
+        .execute(conn)
+        .map_err(|e| {
+            deletion_err(format!("Failed to delete SCIM resource: {e}"))
+        })?;
+
+        match deleted {
+            0 => Ok(DeletionResponseKind::NotDeleted(
+                id,
+                "SCIM resource not found".to_string(),
+            )),
+            _ => Ok(DeletionResponseKind::Deleted),
+        }
+    }
+}
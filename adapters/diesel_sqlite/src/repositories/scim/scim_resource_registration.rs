@@ -0,0 +1,204 @@
+use super::shared::map_resource_dto_to_model;
+use crate::{
+    config::SqliteDbPoolProvider, schema::scim_resource as scim_resource_model,
+};
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use myc_core::domain::{
+    dtos::{native_error_codes::NativeErrorCodes, scim::ScimResource},
+    entities::ScimResourceRegistration,
+};
+use mycelium_base::{
+    entities::CreateResponseKind,
+    utils::errors::{creation_err, MappedErrors},
+};
+use shaku::Component;
+use std::sync::Arc;
+
+#[derive(Component)]
+#[shaku(interface = ScimResourceRegistration)]
+pub struct ScimResourceRegistrationSqlDbRepository {
+    #[shaku(inject)]
+    pub db_config: Arc<dyn SqliteDbPoolProvider>,
+}
+
+#[async_trait]
+impl ScimResourceRegistration for ScimResourceRegistrationSqlDbRepository {
+    #[tracing::instrument(name = "create_scim_resource", skip_all)]
+    async fn create(
+        &self,
+        resource: ScimResource,
+    ) -> Result<CreateResponseKind<ScimResource>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            creation_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        //
+        // The display names are unique per tenant and kind without regard to
+        // case, by an expression index the conflict target can not name.
+        //
+        let inserted = diesel::insert_into(scim_resource_model::table)
+            .values(&map_resource_dto_to_model(&resource)?)
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .map_err(|e| {
+                creation_err(format!("Failed to register SCIM resource: {e}"))
+            })?;
+
+        if inserted == 0 {
+            return Ok(CreateResponseKind::NotCreated(
+                resource.to_owned(),
+                format!(
+                    "A resource named {} already exists",
+                    resource.display_name
+                ),
+            ));
+        }
+
+        Ok(CreateResponseKind::Created(resource))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        repositories::scim::{
+            ScimResourceDeletionSqlDbRepository,
+            ScimResourceFetchingSqlDbRepository,
+            ScimResourceUpdatingSqlDbRepository,
+        },
+        schema::tenant,
+        test_support::setup_temp_db,
+        types::{naive_timestamp_to_text, uuid_to_text},
+    };
+
+    use chrono::{Local, Utc};
+    use myc_core::domain::{
+        dtos::scim::ScimResourceKind,
+        entities::{
+            ScimResourceDeletion, ScimResourceFetching, ScimResourceUpdating,
+        },
+    };
+    use mycelium_base::entities::{
+        DeletionResponseKind, FetchManyResponseKind, UpdatingResponseKind,
+    };
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn resources_are_unique_by_name_without_regard_to_case() {
+        let db = setup_temp_db();
+        let tenant_id = Uuid::new_v4();
+
+        {
+            let conn = &mut db.provider.get_pool().get().unwrap();
+            diesel::insert_into(tenant::table)
+                .values((
+                    tenant::id.eq(uuid_to_text(&tenant_id)),
+                    tenant::name.eq("Lab"),
+                    tenant::created
+                        .eq(naive_timestamp_to_text(&Utc::now().naive_utc())),
+                    tenant::kek_version.eq(1),
+                ))
+                .execute(conn)
+                .unwrap();
+        }
+
+        let registration = ScimResourceRegistrationSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let fetching = ScimResourceFetchingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let updating = ScimResourceUpdatingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let deletion = ScimResourceDeletionSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+
+        let mut user = ScimResource::new(
+            tenant_id,
+            ScimResourceKind::User,
+            "ana@example.com".to_string(),
+            serde_json::json!({ "userName": "ana@example.com" }),
+        );
+
+        user.account_id = Some(Uuid::new_v4());
+        user.provisioned = true;
+
+        assert!(matches!(
+            registration.create(user.to_owned()).await.unwrap(),
+            CreateResponseKind::Created(_)
+        ));
+
+        assert!(matches!(
+            registration
+                .create(ScimResource::new(
+                    tenant_id,
+                    ScimResourceKind::User,
+                    "ANA@example.com".to_string(),
+                    serde_json::json!({}),
+                ))
+                .await
+                .unwrap(),
+            CreateResponseKind::NotCreated(..)
+        ));
+
+        assert!(matches!(
+            registration
+                .create(ScimResource::new(
+                    tenant_id,
+                    ScimResourceKind::Group,
+                    "ana@example.com".to_string(),
+                    serde_json::json!({}),
+                ))
+                .await
+                .unwrap(),
+            CreateResponseKind::Created(_)
+        ));
+
+        user.attributes =
+            serde_json::json!({ "userName": "ana", "active": false });
+        user.display_name = "ana".to_string();
+        user.updated = Some(Local::now());
+
+        assert!(matches!(
+            updating.update(user.to_owned()).await.unwrap(),
+            UpdatingResponseKind::Updated(_)
+        ));
+
+        match fetching
+            .list(tenant_id, ScimResourceKind::User)
+            .await
+            .unwrap()
+        {
+            FetchManyResponseKind::Found(records) => {
+                assert_eq!(records.len(), 1);
+                assert_eq!(records[0].display_name, "ana");
+                assert_eq!(records[0].account_id, user.account_id);
+                assert!(records[0].provisioned);
+                assert_eq!(records[0].attributes["active"], false);
+            }
+            _ => panic!("Expected the provisioned user"),
+        }
+
+        assert!(matches!(
+            deletion
+                .delete(tenant_id, ScimResourceKind::Group, user.id)
+                .await
+                .unwrap(),
+            DeletionResponseKind::NotDeleted(..)
+        ));
+
+        assert!(matches!(
+            deletion
+                .delete(tenant_id, ScimResourceKind::User, user.id)
+                .await
+                .unwrap(),
+            DeletionResponseKind::Deleted
+        ));
+    }
+}
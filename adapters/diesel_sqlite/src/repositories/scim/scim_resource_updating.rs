@@ -0,0 +1,69 @@
+use super::shared::encode_timestamp;
+use crate::{
+    config::SqliteDbPoolProvider,
+    schema::scim_resource as scim_resource_model,
+    types::{json_to_text, uuid_to_text},
+};
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use myc_core::domain::{
+    dtos::{native_error_codes::NativeErrorCodes, scim::ScimResource},
+    entities::ScimResourceUpdating,
+};
+use mycelium_base::{
+    entities::UpdatingResponseKind,
+    utils::errors::{updating_err, MappedErrors},
+};
+use shaku::Component;
+use std::sync::Arc;
+
+#[derive(Component)]
+#[shaku(interface = ScimResourceUpdating)]
+pub struct ScimResourceUpdatingSqlDbRepository {
+    #[shaku(inject)]
+    pub db_config: Arc<dyn SqliteDbPoolProvider>,
+}
+
+#[async_trait]
+impl ScimResourceUpdating for ScimResourceUpdatingSqlDbRepository {
+    #[tracing::instrument(name = "update_scim_resource", skip_all)]
+    async fn update(
+        &self,
+        resource: ScimResource,
+    ) -> Result<UpdatingResponseKind<ScimResource>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            updating_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let updated = diesel::update(
+            scim_resource_model::table
+                .filter(scim_resource_model::id.eq(uuid_to_text(&resource.id)))
+                .filter(
+                    scim_resource_model::tenant_id
+                        .eq(uuid_to_text(&resource.tenant_id)),
+                ),
+        )
+        .set((
+            scim_resource_model::external_id.eq(&resource.external_id),
+            scim_resource_model::display_name.eq(&resource.display_name),
+            scim_resource_model::attributes
+                .eq(json_to_text(&resource.attributes)?),
+            scim_resource_model::updated
+                .eq(resource.updated.as_ref().map(encode_timestamp)),
+        ))
+        .execute(conn)
+        .map_err(|e| {
+            updating_err(format!("Failed to update SCIM resource: {e}"))
+        })?;
+
+        match updated {
+            0 => Ok(UpdatingResponseKind::NotUpdated(
+                resource,
+                "SCIM resource not found".to_string(),
+            )),
+            _ => Ok(UpdatingResponseKind::Updated(resource)),
+        }
+    }
+}
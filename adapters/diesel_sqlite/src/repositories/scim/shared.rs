@@ -0,0 +1,108 @@
+use crate::{
+    models::{
+        scim_credential::ScimCredential as ScimCredentialModel,
+        scim_resource::ScimResource as ScimResourceModel,
+    },
+    types::{
+        json_from_text, json_to_text, timestamp_from_text, timestamp_to_text,
+        uuid_from_text, uuid_to_text,
+    },
+};
+
+use chrono::{DateTime, Local, Utc};
+use myc_core::domain::dtos::scim::{
+    ScimCredential, ScimResource, ScimResourceKind,
+};
+use mycelium_base::utils::errors::{dto_err, fetching_err, MappedErrors};
+use serde_json::{from_value, to_value};
+use std::str::FromStr;
+
+pub(super) fn encode_timestamp(moment: &DateTime<Local>) -> String {
+    timestamp_to_text(&moment.with_timezone(&Utc))
+}
+
+fn decode_timestamp(value: &str) -> Result<DateTime<Local>, MappedErrors> {
+    Ok(timestamp_from_text(value)?.with_timezone(&Local))
+}
+
+pub(super) fn map_credential_dto_to_model(
+    credential: &ScimCredential,
+) -> Result<ScimCredentialModel, MappedErrors> {
+    Ok(ScimCredentialModel {
+        id: uuid_to_text(&credential.id),
+        tenant_id: uuid_to_text(&credential.tenant_id),
+        name: credential.name.to_owned(),
+        secret_hash: credential.secret_hash.to_owned(),
+        created_by: json_to_text(&to_value(&credential.created_by).map_err(
+            |e| {
+                dto_err(format!(
+                    "Failed to serialize the credential author: {e}"
+                ))
+            },
+        )?)?,
+        created: encode_timestamp(&credential.created),
+    })
+}
+
+pub(super) fn map_credential_model_to_dto(
+    model: ScimCredentialModel,
+) -> Result<ScimCredential, MappedErrors> {
+    Ok(ScimCredential {
+        id: uuid_from_text(&model.id)?,
+        tenant_id: uuid_from_text(&model.tenant_id)?,
+        name: model.name,
+        secret_hash: model.secret_hash,
+        created_by: from_value(json_from_text(&model.created_by)?).map_err(
+            |e| {
+                fetching_err(format!(
+                    "Failed to parse the credential author: {e}"
+                ))
+            },
+        )?,
+        created: decode_timestamp(&model.created)?,
+    })
+}
+
+pub(super) fn map_resource_dto_to_model(
+    resource: &ScimResource,
+) -> Result<ScimResourceModel, MappedErrors> {
+    Ok(ScimResourceModel {
+        id: uuid_to_text(&resource.id),
+        tenant_id: uuid_to_text(&resource.tenant_id),
+        kind: resource.kind.to_string(),
+        external_id: resource.external_id.to_owned(),
+        display_name: resource.display_name.to_owned(),
+        account_id: resource.account_id.as_ref().map(uuid_to_text),
+        guest_role_id: resource.guest_role_id.as_ref().map(uuid_to_text),
+        provisioned: resource.provisioned,
+        attributes: json_to_text(&resource.attributes)?,
+        created: encode_timestamp(&resource.created),
+        updated: resource.updated.as_ref().map(encode_timestamp),
+    })
+}
+
+pub(super) fn map_resource_model_to_dto(
+    model: ScimResourceModel,
+) -> Result<ScimResource, MappedErrors> {
+    Ok(ScimResource {
+        id: uuid_from_text(&model.id)?,
+        tenant_id: uuid_from_text(&model.tenant_id)?,
+        kind: ScimResourceKind::from_str(&model.kind).map_err(fetching_err)?,
+        external_id: model.external_id,
+        display_name: model.display_name,
+        account_id: model
+            .account_id
+            .as_deref()
+            .map(uuid_from_text)
+            .transpose()?,
+        guest_role_id: model
+            .guest_role_id
+            .as_deref()
+            .map(uuid_from_text)
+            .transpose()?,
+        provisioned: model.provisioned,
+        attributes: json_from_text(&model.attributes)?,
+        created: decode_timestamp(&model.created)?,
+        updated: model.updated.as_deref().map(decode_timestamp).transpose()?,
+    })
+}
@@ -1,10 +1,28 @@
 use crate::{
-    models::tenant::Tenant as TenantModel,
-    types::{json_array_from_text, json_from_text, uuid_from_text},
+    models::{
+        tenant::Tenant as TenantModel,
+        tenant_email_template::TenantEmailTemplate as TenantEmailTemplateModel,
+    },
+    types::{
+        json_array_from_text, json_from_text, timestamp_from_text,
+        uuid_from_text,
+    },
 };
 
-use myc_core::domain::dtos::tenant::{Tenant, TenantMetaKey, TenantStatus};
-use mycelium_base::dtos::Children;
+use chrono::Local;
+use diesel::{
+    prelude::*,
+    sql_query,
+    sql_types::{Bool, Text},
+    SqliteConnection,
+};
+use myc_core::domain::dtos::tenant::{
+    EmailTemplateKind, Tenant, TenantEmailTemplate, TenantMetaKey, TenantStatus,
+};
+use mycelium_base::{
+    dtos::Children,
+    utils::errors::{dto_err, fetching_err, MappedErrors},
+};
 use std::{collections::HashMap, str::FromStr};
 
 /// Decodes the `status` column (a JSON-array-of-statuses TEXT value) into the
@@ -28,6 +46,7 @@ pub(crate) fn map_tenant_model_to_dto(model: TenantModel) -> Tenant {
         id: Some(uuid_from_text(&model.id).unwrap()),
         name: model.name,
         description: model.description,
+        parent_id: model.parent_id.map(|id| uuid_from_text(&id).unwrap()),
         owners: Children::Records(vec![]),
         manager: None,
         tags: None,
@@ -40,6 +59,7 @@ pub(crate) fn map_tenant_model_to_dto(model: TenantModel) -> Tenant {
             .map(|(k, v)| (TenantMetaKey::from_str(k).unwrap(), v.to_string()))
             .collect()
         }),
+        inherited_meta: None,
         status: Some(decode_status(model.status)),
         created: crate::repositories::account::created_at_from_text(
             &model.created,
@@ -49,3 +69,134 @@ pub(crate) fn map_tenant_model_to_dto(model: TenantModel) -> Tenant {
             .map(|dt| crate::repositories::account::created_at_from_text(&dt)),
     }
 }
+
+/// Maps a tenant model with the meta inherited from its ancestors, nearest
+/// first (see `load_ancestors`).
+pub(crate) fn map_tenant_model_with_ancestors_to_dto(
+    model: TenantModel,
+    ancestors: Vec<TenantModel>,
+) -> Tenant {
+    map_tenant_model_to_dto(model).with_inherited_meta(
+        ancestors
+            .into_iter()
+            .filter_map(|ancestor| map_tenant_model_to_dto(ancestor).meta)
+            .collect(),
+    )
+}
+
+/// The lineage of the tenant bound first: the tenant itself at depth 0, then
+/// its ancestors, nearest first. The walk stops on missing parents and on
+/// cycles.
+pub(crate) const TENANT_LINEAGE_CTE: &str = "
+    WITH RECURSIVE lineage(id, parent_id, depth, path) AS (
+        SELECT id, parent_id, 0, ',' || id || ','
+        FROM tenant
+        WHERE id = ?
+        UNION ALL
+        SELECT t.id, t.parent_id, l.depth + 1, l.path || t.id || ','
+        FROM tenant t
+        JOIN lineage l ON t.id = l.parent_id
+        WHERE instr(l.path, ',' || t.id || ',') = 0
+    )";
+
+#[derive(QueryableByName)]
+struct LineageOwnership {
+    #[diesel(sql_type = Bool)]
+    owned: bool,
+}
+
+/// Loads the ancestors of a tenant, nearest first. The walk stops on missing
+/// parents and on cycles.
+pub(crate) fn load_ancestors(
+    conn: &mut SqliteConnection,
+    model: &TenantModel,
+) -> Result<Vec<TenantModel>, MappedErrors> {
+    if model.parent_id.is_none() {
+        return Ok(vec![]);
+    }
+
+    sql_query(format!(
+        "{TENANT_LINEAGE_CTE}
+        SELECT t.*
+        FROM tenant t
+        JOIN lineage l ON t.id = l.id
+        WHERE l.depth > 0
+        ORDER BY l.depth"
+    ))
+    .bind::<Text, _>(&model.id)
+    .load::<TenantModel>(conn)
+    .map_err(|e| fetching_err(format!("Failed to fetch parent tenants: {e}")))
+}
+
+/// Whether the owners own the tenant, directly or through one of its
+/// ancestors.
+pub(crate) fn is_owned_through_lineage(
+    conn: &mut SqliteConnection,
+    tenant_id_text: &str,
+    owner_ids_text: Vec<String>,
+) -> Result<bool, MappedErrors> {
+    let owner_ids = serde_json::to_string(&owner_ids_text).map_err(|e| {
+        fetching_err(format!("Failed to serialize owners: {e}"))
+    })?;
+
+    sql_query(format!(
+        "{TENANT_LINEAGE_CTE}
+        SELECT EXISTS (
+            SELECT 1
+            FROM owner_on_tenant o
+            JOIN lineage l ON o.tenant_id = l.id
+            WHERE o.owner_id IN (SELECT value FROM json_each(?))
+        ) AS owned"
+    ))
+    .bind::<Text, _>(tenant_id_text)
+    .bind::<Text, _>(owner_ids)
+    .get_result::<LineageOwnership>(conn)
+    .map(|ownership| ownership.owned)
+    .map_err(|e| fetching_err(format!("Failed to fetch ownership: {e}")))
+}
+
+/// Loads the descendants of a tenant, children before their own children.
+pub(crate) fn load_descendants(
+    conn: &mut SqliteConnection,
+    tenant_id_text: &str,
+) -> Result<Vec<TenantModel>, MappedErrors> {
+    sql_query(
+        "WITH RECURSIVE descendants(id, depth, path) AS (
+            SELECT id, 1, ',' || parent_id || ',' || id || ','
+            FROM tenant
+            WHERE parent_id = ?1 AND id <> ?1
+            UNION ALL
+            SELECT t.id, d.depth + 1, d.path || t.id || ','
+            FROM tenant t
+            JOIN descendants d ON t.parent_id = d.id
+            WHERE instr(d.path, ',' || t.id || ',') = 0
+        )
+        SELECT t.*
+        FROM tenant t
+        JOIN descendants d ON t.id = d.id
+        ORDER BY d.depth, t.name",
+    )
+    .bind::<Text, _>(tenant_id_text)
+    .load::<TenantModel>(conn)
+    .map_err(|e| fetching_err(format!("Failed to fetch child tenants: {e}")))
+}
+
+pub(crate) fn map_email_template_model_to_dto(
+    model: TenantEmailTemplateModel,
+) -> Result<TenantEmailTemplate, MappedErrors> {
+    Ok(TenantEmailTemplate {
+        id: Some(uuid_from_text(&model.id)?),
+        tenant_id: uuid_from_text(&model.tenant_id)?,
+        kind: EmailTemplateKind::from_str(&model.kind).map_err(dto_err)?,
+        locale: model.locale,
+        subject: model.subject,
+        body: model.body,
+        created: timestamp_from_text(&model.created)?.with_timezone(&Local),
+        updated: match model.updated {
+            Some(updated) => {
+                Some(timestamp_from_text(&updated)?.with_timezone(&Local))
+            }
+            None => None,
+        },
+    })
+}
@@ -1,16 +1,23 @@
+use super::shared::map_tenant_model_to_dto;
 use crate::{
     config::SqliteDbPoolProvider,
     models::tenant::Tenant as TenantModel,
-    schema::{owner_on_tenant, tenant, user as users_model},
+    schema::{
+        account, guest_role, guest_role_children, guest_user,
+        guest_user_on_account, owner_on_tenant, tenant, tenant_email_template,
+        user as users_model,
+    },
     types::uuid_to_text,
 };
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use diesel::prelude::*;
 use myc_core::domain::{
     dtos::{
-        email::Email, native_error_codes::NativeErrorCodes,
-        tenant::TenantMetaKey,
+        email::Email,
+        native_error_codes::NativeErrorCodes,
+        tenant::{EmailTemplateKind, TenantMetaKey},
     },
     entities::TenantDeletion,
 };
@@ -56,6 +63,17 @@ impl TenantDeletion for TenantDeletionSqlDbRepository {
 
         match exists {
             Some(_) => {
+                // Detach children, since the parent column carries no
+                // foreign key
+                diesel::update(
+                    tenant::table.filter(tenant::parent_id.eq(&id_text)),
+                )
+                .set(tenant::parent_id.eq(None::<String>))
+                .execute(conn)
+                .map_err(|e| {
+                    deletion_err(format!("Failed to detach children: {}", e))
+                })?;
+
                 // Delete tenant
                 diesel::delete(tenant::table.find(&id_text))
                     .execute(conn)
@@ -72,6 +90,141 @@ impl TenantDeletion for TenantDeletionSqlDbRepository {
         }
     }
 
+    #[tracing::instrument(name = "purge_tenant", skip_all)]
+    async fn purge(
+        &self,
+        id: Uuid,
+        trashed_before: DateTime<Utc>,
+    ) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            deletion_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let id_text = uuid_to_text(&id);
+
+        //
+        // Accounts, guest users and guest role links carry no cascading
+        // foreign key, so they are deleted before the tenant. Tags, meta,
+        // owners, email templates and the encrypted DEK go with the tenant
+        // row itself.
+        //
+        let not_purged = conn
+            .immediate_transaction::<Option<&str>, diesel::result::Error, _>(
+                |conn| {
+                    //
+                    // The tenant may have been restored since it was listed for
+                    // the purge, so its status is checked again. The immediate
+                    // transaction holds the write lock until the purge commits.
+                    //
+                    let tenant = tenant::table
+                        .find(&id_text)
+                        .select(TenantModel::as_select())
+                        .first::<TenantModel>(conn)
+                        .optional()?;
+
+                    let Some(tenant) = tenant else {
+                        return Ok(Some("Tenant not found"));
+                    };
+
+                    if map_tenant_model_to_dto(tenant)
+                        .trashed_at()
+                        .is_none_or(|at| at > trashed_before)
+                    {
+                        return Ok(Some("Tenant is no longer trashed"));
+                    }
+
+                    let account_ids = account::table
+                        .filter(account::tenant_id.eq(&id_text))
+                        .select(account::id)
+                        .load::<String>(conn)?;
+
+                    let role_ids = guest_role::table
+                        .filter(guest_role::tenant_id.eq(&id_text))
+                        .select(guest_role::id)
+                        .load::<String>(conn)?;
+
+                    let guest_user_ids = guest_user_on_account::table
+                        .filter(
+                            guest_user_on_account::account_id
+                                .eq_any(&account_ids),
+                        )
+                        .select(guest_user_on_account::guest_user_id)
+                        .load::<String>(conn)?;
+
+                    diesel::delete(guest_user_on_account::table.filter(
+                        guest_user_on_account::account_id.eq_any(&account_ids),
+                    ))
+                    .execute(conn)?;
+
+                    //
+                    // Guest users left without accounts were only guests of the
+                    // purged tenant
+                    //
+                    let still_guests = guest_user_on_account::table
+                        .filter(
+                            guest_user_on_account::guest_user_id
+                                .eq_any(&guest_user_ids),
+                        )
+                        .select(guest_user_on_account::guest_user_id)
+                        .load::<String>(conn)?;
+
+                    let orphan_guest_user_ids = guest_user_ids
+                        .into_iter()
+                        .filter(|id| !still_guests.contains(id))
+                        .collect::<Vec<_>>();
+
+                    diesel::delete(
+                        guest_user::table.filter(
+                            guest_user::guest_role_id
+                                .eq_any(&role_ids)
+                                .or(guest_user::id
+                                    .eq_any(&orphan_guest_user_ids)),
+                        ),
+                    )
+                    .execute(conn)?;
+
+                    diesel::delete(
+                        guest_role_children::table.filter(
+                            guest_role_children::parent_id
+                                .eq_any(&role_ids)
+                                .or(guest_role_children::child_role_id
+                                    .eq_any(&role_ids)),
+                        ),
+                    )
+                    .execute(conn)?;
+
+                    diesel::delete(
+                        account::table.filter(account::id.eq_any(&account_ids)),
+                    )
+                    .execute(conn)?;
+
+                    // Detach children, since the parent column carries no
+                    // foreign key
+                    diesel::update(
+                        tenant::table.filter(tenant::parent_id.eq(&id_text)),
+                    )
+                    .set(tenant::parent_id.eq(None::<String>))
+                    .execute(conn)?;
+
+                    diesel::delete(tenant::table.find(&id_text))
+                        .execute(conn)?;
+
+                    Ok(None)
+                },
+            )
+            .map_err(|e| {
+                deletion_err(format!("Failed to purge tenant: {}", e))
+            })?;
+
+        match not_purged {
+            None => Ok(DeletionResponseKind::Deleted),
+            Some(reason) => {
+                Ok(DeletionResponseKind::NotDeleted(id, reason.to_string()))
+            }
+        }
+    }
+
     #[tracing::instrument(name = "delete_tenant_owner", skip_all)]
     async fn delete_owner(
         &self,
@@ -195,4 +348,40 @@ impl TenantDeletion for TenantDeletionSqlDbRepository {
 
         Ok(DeletionResponseKind::Deleted)
     }
+
+    #[tracing::instrument(name = "delete_email_template", skip_all)]
+    async fn delete_email_template(
+        &self,
+        tenant_id: Uuid,
+        kind: EmailTemplateKind,
+        locale: String,
+    ) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            deletion_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let deleted = diesel::delete(
+            tenant_email_template::table
+                .filter(
+                    tenant_email_template::tenant_id
+                        .eq(uuid_to_text(&tenant_id)),
+                )
+                .filter(tenant_email_template::kind.eq(kind.to_string()))
+                .filter(tenant_email_template::locale.eq(&locale)),
+        )
+        .execute(conn)
+        .map_err(|e| {
+            deletion_err(format!("Failed to delete email template: {e}"))
+        })?;
+
+        if deleted == 0 {
+            return Ok(DeletionResponseKind::NotDeleted(
+                tenant_id,
+                format!("Email template {kind}/{locale} not found"),
+            ));
+        }
+
+        Ok(DeletionResponseKind::Deleted)
+    }
 }
@@ -1,17 +1,23 @@
-use super::map_tenant_model_to_dto;
+use super::{
+    is_owned_through_lineage, load_ancestors, load_descendants,
+    map_email_template_model_to_dto, map_tenant_model_to_dto,
+    map_tenant_model_with_ancestors_to_dto,
+};
 use crate::{
     config::SqliteDbPoolProvider,
     models::{
         account::Account as AccountModel,
         owner_on_tenant::OwnerOnTenant as OwnerOnTenantModel,
-        tenant::Tenant as TenantModel, tenant_tag::TenantTag as TenantTagModel,
-        user::User as UserModel,
+        tenant::Tenant as TenantModel,
+        tenant_email_template::TenantEmailTemplate as TenantEmailTemplateModel,
+        tenant_tag::TenantTag as TenantTagModel, user::User as UserModel,
     },
     repositories::account::map_account_model_to_dto,
     schema::{
         account, manager_account_on_tenant,
         owner_on_tenant::{self, dsl as owner_on_tenant_dsl},
         tenant::{self, dsl as tenant_dsl},
+        tenant_email_template,
         tenant_tag::dsl as tenant_tag_dsl,
         user,
     },
@@ -19,13 +25,16 @@ use crate::{
 };
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use diesel::{dsl::sql, prelude::*, sql_types::Text, BelongingToDsl, QueryDsl};
 use myc_core::domain::{
     dtos::{
         native_error_codes::NativeErrorCodes,
         profile::Owner,
         tag::Tag,
-        tenant::{Tenant, TenantMetaKey},
+        tenant::{
+            EmailTemplateKind, Tenant, TenantEmailTemplate, TenantMetaKey,
+        },
     },
     entities::TenantFetching,
 };
@@ -63,9 +72,7 @@ impl TenantFetching for TenantFetchingSqlDbRepository {
             owners_ids.iter().map(uuid_to_text).collect();
 
         let record = tenant::table
-            .inner_join(owner_on_tenant::table)
             .filter(tenant::id.eq(&id_text))
-            .filter(owner_on_tenant::owner_id.eq_any(owner_ids_text))
             .select(TenantModel::as_select())
             .first::<TenantModel>(conn)
             .optional()
@@ -77,8 +84,17 @@ impl TenantFetching for TenantFetchingSqlDbRepository {
             return Ok(FetchResponseKind::NotFound(Some(id.to_string())));
         };
 
+        //
+        // Owners of an ancestor own the tenant too
+        //
+        if !is_owned_through_lineage(conn, &record.id, owner_ids_text)? {
+            return Ok(FetchResponseKind::NotFound(Some(id.to_string())));
+        }
+
+        let ancestors = load_ancestors(conn, &record)?;
+
         Ok(FetchResponseKind::Found(
-            self.hydrate(conn, record, &id_text)?,
+            self.hydrate(conn, record, ancestors, &id_text)?,
         ))
     }
 
@@ -114,7 +130,9 @@ impl TenantFetching for TenantFetchingSqlDbRepository {
                 fetching_err(format!("Failed to fetch tags: {}", e))
             })?;
 
-        let mut tenant = map_tenant_model_to_dto(record);
+        let ancestors = load_ancestors(conn, &record)?;
+        let mut tenant =
+            map_tenant_model_with_ancestors_to_dto(record, ancestors);
 
         tenant.tags =
             Some(tags.into_iter().map(tag_model_to_dto).collect::<Vec<Tag>>());
@@ -155,8 +173,10 @@ impl TenantFetching for TenantFetchingSqlDbRepository {
             return Ok(FetchResponseKind::NotFound(Some(id.to_string())));
         };
 
+        let ancestors = load_ancestors(conn, &record)?;
+
         Ok(FetchResponseKind::Found(
-            self.hydrate(conn, record, &id_text)?,
+            self.hydrate(conn, record, ancestors, &id_text)?,
         ))
     }
 
@@ -304,6 +324,176 @@ impl TenantFetching for TenantFetchingSqlDbRepository {
             records: tenants,
         })
     }
+
+    #[tracing::instrument(name = "get_email_template", skip_all)]
+    async fn get_email_template(
+        &self,
+        tenant_id: Uuid,
+        kind: EmailTemplateKind,
+        locale: String,
+    ) -> Result<FetchResponseKind<TenantEmailTemplate, String>, MappedErrors>
+    {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let template = tenant_email_template::table
+            .filter(
+                tenant_email_template::tenant_id.eq(uuid_to_text(&tenant_id)),
+            )
+            .filter(tenant_email_template::kind.eq(kind.to_string()))
+            .filter(tenant_email_template::locale.eq(&locale))
+            .select(TenantEmailTemplateModel::as_select())
+            .first::<TenantEmailTemplateModel>(conn)
+            .optional()
+            .map_err(|e| {
+                fetching_err(format!("Failed to fetch email template: {e}"))
+            })?;
+
+        match template {
+            Some(record) => Ok(FetchResponseKind::Found(
+                map_email_template_model_to_dto(record)?,
+            )),
+            None => Ok(FetchResponseKind::NotFound(Some(format!(
+                "{kind}/{locale}"
+            )))),
+        }
+    }
+
+    #[tracing::instrument(name = "list_email_templates", skip_all)]
+    async fn list_email_templates(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<FetchManyResponseKind<TenantEmailTemplate>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let templates = tenant_email_template::table
+            .filter(
+                tenant_email_template::tenant_id.eq(uuid_to_text(&tenant_id)),
+            )
+            .order((
+                tenant_email_template::kind.asc(),
+                tenant_email_template::locale.asc(),
+            ))
+            .select(TenantEmailTemplateModel::as_select())
+            .load::<TenantEmailTemplateModel>(conn)
+            .map_err(|e| {
+                fetching_err(format!("Failed to fetch email templates: {e}"))
+            })?
+            .into_iter()
+            .map(map_email_template_model_to_dto)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if templates.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        Ok(FetchManyResponseKind::Found(templates))
+    }
+
+    #[tracing::instrument(name = "list_descendants", skip_all)]
+    async fn list_descendants(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<FetchManyResponseKind<Tenant>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let descendants: Vec<Tenant> =
+            load_descendants(conn, &uuid_to_text(&tenant_id))?
+                .into_iter()
+                .map(map_tenant_model_to_dto)
+                .collect();
+
+        if descendants.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        Ok(FetchManyResponseKind::Found(descendants))
+    }
+
+    #[tracing::instrument(name = "list_trashed", skip_all)]
+    async fn list_trashed(
+        &self,
+        trashed_after: Option<DateTime<Utc>>,
+        trashed_before: DateTime<Utc>,
+    ) -> Result<FetchManyResponseKind<Tenant>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        //
+        // The current status is resolved once mapped, so the query only
+        // narrows the tenants down to those ever trashed
+        //
+        let tenants: Vec<Tenant> = tenant::table
+            .filter(tenant::status.like("%\"trashed\"%"))
+            .select(TenantModel::as_select())
+            .load::<TenantModel>(conn)
+            .map_err(|e| {
+                fetching_err(format!("Failed to fetch trashed tenants: {}", e))
+            })?
+            .into_iter()
+            .map(map_tenant_model_to_dto)
+            .filter(|tenant| {
+                tenant.trashed_at().is_some_and(|at| {
+                    at <= trashed_before
+                        && trashed_after.is_none_or(|after| at > after)
+                })
+            })
+            .collect();
+
+        if tenants.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        Ok(FetchManyResponseKind::Found(tenants))
+    }
+
+    #[tracing::instrument(name = "list_owners", skip_all)]
+    async fn list_owners(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<FetchManyResponseKind<Owner>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let owners = owner_on_tenant::table
+            .inner_join(user::table)
+            .filter(owner_on_tenant::tenant_id.eq(uuid_to_text(&tenant_id)))
+            .select(UserModel::as_select())
+            .load::<UserModel>(conn)
+            .map_err(|e| {
+                fetching_err(format!("Failed to fetch owners: {}", e))
+            })?
+            .into_iter()
+            .map(|u| {
+                Ok(Owner {
+                    id: crate::types::uuid_from_text(&u.id)?,
+                    email: u.email,
+                    first_name: Some(u.first_name),
+                    last_name: Some(u.last_name),
+                    username: Some(u.username),
+                    is_principal: u.is_principal,
+                })
+            })
+            .collect::<Result<Vec<Owner>, MappedErrors>>()?;
+
+        if owners.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        Ok(FetchManyResponseKind::Found(owners))
+    }
 }
 
 fn tag_model_to_dto(t: TenantTagModel) -> Tag {
@@ -323,6 +513,7 @@ impl TenantFetchingSqlDbRepository {
         &self,
         conn: &mut diesel::SqliteConnection,
         record: TenantModel,
+        ancestors: Vec<TenantModel>,
         tenant_id_text: &str,
     ) -> Result<Tenant, MappedErrors> {
         let owners = OwnerOnTenantModel::belonging_to(&record)
@@ -361,7 +552,8 @@ impl TenantFetchingSqlDbRepository {
             .map(tag_model_to_dto)
             .collect::<Vec<Tag>>();
 
-        let mut tenant = map_tenant_model_to_dto(record);
+        let mut tenant =
+            map_tenant_model_with_ancestors_to_dto(record, ancestors);
 
         tenant.owners = match owners.len() {
             0 => Children::Records(vec![]),
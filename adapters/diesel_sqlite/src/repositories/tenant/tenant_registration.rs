@@ -1,21 +1,28 @@
-use super::map_tenant_model_to_dto;
+use super::{
+    is_owned_through_lineage, map_email_template_model_to_dto,
+    map_tenant_model_to_dto,
+};
 use crate::{
     config::SqliteDbPoolProvider,
     models::{
         owner_on_tenant::OwnerOnTenant as OwnerOnTenantModel,
         tenant::Tenant as TenantModel,
+        tenant_email_template::TenantEmailTemplate as TenantEmailTemplateModel,
+    },
+    schema::{owner_on_tenant, tenant, tenant_email_template},
+    types::{
+        json_array_to_text, naive_timestamp_to_text, timestamp_to_text,
+        uuid_to_text,
     },
-    schema::{owner_on_tenant, tenant},
-    types::{json_array_to_text, naive_timestamp_to_text, uuid_to_text},
 };
 
 use async_trait::async_trait;
-use chrono::Local;
-use diesel::prelude::*;
+use chrono::{Local, Utc};
+use diesel::{prelude::*, upsert::excluded};
 use myc_core::domain::{
     dtos::{
         native_error_codes::NativeErrorCodes,
-        tenant::{Tenant, TenantMetaKey},
+        tenant::{Tenant, TenantEmailTemplate, TenantMetaKey},
     },
     entities::TenantRegistration,
 };
@@ -88,6 +95,7 @@ impl TenantRegistration for TenantRegistrationSqlDbRepository {
             updated: None,
             encrypted_dek: None,
             kek_version: 1,
+            parent_id: tenant_dto.parent_id.as_ref().map(uuid_to_text),
         };
 
         let created: TenantModel = conn
@@ -161,11 +169,10 @@ impl TenantRegistration for TenantRegistrationSqlDbRepository {
         let owner_ids_text: Vec<String> =
             owners_ids.iter().map(uuid_to_text).collect();
 
-        // Verify if the tenant exists and if the user has permission
+        // Verify if the tenant exists and if the user has permission, directly
+        // or through an ancestor tenant
         let found = tenant::table
-            .inner_join(owner_on_tenant::table)
             .filter(tenant::id.eq(&tenant_id_text))
-            .filter(owner_on_tenant::owner_id.eq_any(owner_ids_text))
             .select(TenantModel::as_select())
             .first::<TenantModel>(conn)
             .optional()
@@ -173,6 +180,14 @@ impl TenantRegistration for TenantRegistrationSqlDbRepository {
                 creation_err(format!("Failed to check tenant: {}", e))
             })?;
 
+        let found = match found {
+            Some(found) => {
+                is_owned_through_lineage(conn, &found.id, owner_ids_text)?
+                    .then_some(found)
+            }
+            None => None,
+        };
+
         let Some(found) = found else {
             return Ok(CreateResponseKind::NotCreated(
                 HashMap::new(),
@@ -199,6 +214,60 @@ impl TenantRegistration for TenantRegistrationSqlDbRepository {
 
         Ok(CreateResponseKind::Created(meta_map))
     }
+
+    #[tracing::instrument(name = "register_email_template", skip_all)]
+    async fn register_email_template(
+        &self,
+        template: TenantEmailTemplate,
+    ) -> Result<TenantEmailTemplate, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            creation_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let tenant_id = uuid_to_text(&template.tenant_id);
+        let kind = template.kind.to_string();
+        let now = timestamp_to_text(&Utc::now());
+
+        conn.immediate_transaction(|conn| {
+            diesel::insert_into(tenant_email_template::table)
+                .values(&TenantEmailTemplateModel {
+                    id: uuid_to_text(&Uuid::new_v4()),
+                    tenant_id: tenant_id.to_owned(),
+                    kind: kind.to_owned(),
+                    locale: template.locale.to_owned(),
+                    subject: template.subject,
+                    body: template.body,
+                    created: now.to_owned(),
+                    updated: None,
+                })
+                .on_conflict((
+                    tenant_email_template::tenant_id,
+                    tenant_email_template::kind,
+                    tenant_email_template::locale,
+                ))
+                .do_update()
+                .set((
+                    tenant_email_template::subject
+                        .eq(excluded(tenant_email_template::subject)),
+                    tenant_email_template::body
+                        .eq(excluded(tenant_email_template::body)),
+                    tenant_email_template::updated.eq(Some(now.to_owned())),
+                ))
+                .execute(conn)?;
+
+            tenant_email_template::table
+                .filter(tenant_email_template::tenant_id.eq(&tenant_id))
+                .filter(tenant_email_template::kind.eq(&kind))
+                .filter(tenant_email_template::locale.eq(&template.locale))
+                .select(TenantEmailTemplateModel::as_select())
+                .first::<TenantEmailTemplateModel>(conn)
+        })
+        .map_err(|e: diesel::result::Error| {
+            creation_err(format!("Failed to register email template: {e}"))
+        })
+        .and_then(map_email_template_model_to_dto)
+    }
 }
 
 // ? ---------------------------------------------------------------------------
@@ -210,6 +279,8 @@ mod tests {
     use super::*;
     use crate::{
         repositories::{
+            encryption_key::EncryptionKeyFetchingSqlDbRepository,
+            licensed_resources::LicensedResourcesFetchingSqlDbRepository,
             tenant::{
                 TenantDeletionSqlDbRepository, TenantFetchingSqlDbRepository,
                 TenantUpdatingSqlDbRepository,
@@ -220,14 +291,19 @@ mod tests {
         test_support::setup_temp_db,
     };
     use myc_core::domain::{
-        dtos::{profile::Owner, tenant::TenantStatus},
+        dtos::{
+            email::Email,
+            profile::Owner,
+            tenant::{EmailTemplateKind, TenantMetaKey, TenantStatus},
+        },
         entities::{
-            TenantDeletion, TenantFetching, TenantTagRegistration,
-            TenantUpdating,
+            EncryptionKeyFetching, LicensedResourcesFetching, TenantDeletion,
+            TenantFetching, TenantTagRegistration, TenantUpdating,
         },
     };
     use mycelium_base::entities::{
-        DeletionResponseKind, FetchResponseKind, UpdatingResponseKind,
+        DeletionResponseKind, FetchManyResponseKind, FetchResponseKind,
+        UpdatingResponseKind,
     };
 
     #[tokio::test]
@@ -354,6 +430,54 @@ mod tests {
             ) if tag.value == "vip"
         ));
 
+        // Email template (upsert keeps the id and creation date)
+        let template = TenantEmailTemplate::new(
+            tenant_id,
+            EmailTemplateKind::MagicLinkRequest,
+            "pt-br".into(),
+            "Entrar".into(),
+            "<a href=\"{{ magic_link_url }}\">Entrar</a>".into(),
+        )
+        .await?;
+        let stored = registration
+            .register_email_template(template.clone())
+            .await?;
+        assert!(stored.updated.is_none());
+
+        let replaced = registration
+            .register_email_template(TenantEmailTemplate {
+                subject: "Acessar".into(),
+                ..template
+            })
+            .await?;
+        assert_eq!(replaced.id, stored.id);
+        assert!(replaced.updated.is_some());
+
+        let found = fetching
+            .get_email_template(
+                tenant_id,
+                EmailTemplateKind::MagicLinkRequest,
+                "pt-br".into(),
+            )
+            .await?;
+        assert!(matches!(
+            found,
+            FetchResponseKind::Found(ref t) if t.subject == "Acessar"
+        ));
+        assert!(matches!(
+            fetching.list_email_templates(tenant_id).await?,
+            FetchManyResponseKind::Found(ref t) if t.len() == 1
+        ));
+
+        let template_deleted = deletion
+            .delete_email_template(
+                tenant_id,
+                EmailTemplateKind::MagicLinkRequest,
+                "pt-br".into(),
+            )
+            .await?;
+        assert!(matches!(template_deleted, DeletionResponseKind::Deleted));
+
         // Delete owner
         let owner_deleted = deletion
             .delete_owner(tenant_id, Some(owner_id), None)
@@ -366,4 +490,377 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn tenant_hierarchy_round_trips_through_sqlite(
+    ) -> Result<(), MappedErrors> {
+        let db = setup_temp_db();
+        let owner_id = Uuid::new_v4();
+
+        {
+            let conn = &mut db.provider.get_pool().get().unwrap();
+            diesel::insert_into(user::table)
+                .values((
+                    user::id.eq(uuid_to_text(&owner_id)),
+                    user::username.eq("owner"),
+                    user::email.eq("owner@group.test"),
+                    user::first_name.eq("Own"),
+                    user::last_name.eq("Er"),
+                    user::is_active.eq(true),
+                    user::created
+                        .eq(naive_timestamp_to_text(&Local::now().naive_utc())),
+                    user::is_principal.eq(true),
+                ))
+                .execute(conn)
+                .unwrap();
+        }
+
+        let registration = TenantRegistrationSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let fetching = TenantFetchingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let updating = TenantUpdatingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let deletion = TenantDeletionSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let licensed_resources = LicensedResourcesFetchingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+
+        let mut ids = vec![];
+
+        for (name, owners) in [
+            ("Group", vec![owner_id]),
+            ("Company", vec![]),
+            ("Unit", vec![]),
+        ] {
+            let tenant = Tenant::new_with_owners(
+                name.into(),
+                None,
+                Children::Ids(owners),
+            );
+
+            match registration.create(tenant, "self".into()).await? {
+                CreateResponseKind::Created(tenant) => {
+                    ids.push(tenant.id.unwrap())
+                }
+                CreateResponseKind::NotCreated(..) => {
+                    panic!("expected the tenant to be created")
+                }
+            }
+        }
+
+        let (group_id, company_id, unit_id) = (ids[0], ids[1], ids[2]);
+
+        updating
+            .update_tenant_meta(
+                group_id,
+                TenantMetaKey::WebsiteUrl,
+                "https://group.test".into(),
+            )
+            .await?;
+        updating
+            .update_tenant_parent(company_id, Some(group_id))
+            .await?;
+        updating
+            .update_tenant_parent(unit_id, Some(company_id))
+            .await?;
+
+        // Owners of the group own the unit, which inherits the group meta
+        let unit = match fetching
+            .get_tenant_owned_by_me(unit_id, vec![owner_id])
+            .await?
+        {
+            FetchResponseKind::Found(tenant) => tenant,
+            FetchResponseKind::NotFound(_) => {
+                panic!("expected the tenant to be found")
+            }
+        };
+        assert_eq!(unit.parent_id, Some(company_id));
+        assert_eq!(
+            unit.effective_meta().get(&TenantMetaKey::WebsiteUrl),
+            Some(&"https://group.test".to_string())
+        );
+        assert!(matches!(
+            fetching
+                .get_tenant_owned_by_me(unit_id, vec![Uuid::new_v4()])
+                .await?,
+            FetchResponseKind::NotFound(_)
+        ));
+
+        // Descendants are listed before their own children
+        let descendants = match fetching.list_descendants(group_id).await? {
+            FetchManyResponseKind::Found(records) => records,
+            _ => panic!("expected the descendants to be found"),
+        };
+        assert_eq!(
+            descendants
+                .iter()
+                .map(|t| t.id.unwrap())
+                .collect::<Vec<_>>(),
+            vec![company_id, unit_id]
+        );
+
+        // Descendants and missing tenants could not be parents
+        assert!(matches!(
+            updating
+                .update_tenant_parent(group_id, Some(unit_id))
+                .await?,
+            UpdatingResponseKind::NotUpdated(..)
+        ));
+        assert!(matches!(
+            updating
+                .update_tenant_parent(group_id, Some(Uuid::new_v4()))
+                .await?,
+            UpdatingResponseKind::NotUpdated(..)
+        ));
+        assert!(matches!(
+            fetching.get_tenant_public_by_id(group_id).await?,
+            FetchResponseKind::Found(ref t) if t.parent_id.is_none()
+        ));
+
+        // The ownership of the group is inherited by its descendants
+        let ownerships = match licensed_resources
+            .list_tenants_ownership(
+                Email::from_string("owner@group.test".into())?,
+                Some(unit_id),
+            )
+            .await?
+        {
+            FetchManyResponseKind::Found(records) => records,
+            _ => panic!("expected the ownership to be found"),
+        };
+        assert_eq!(ownerships.len(), 1);
+        assert_eq!(ownerships[0].parent_id, Some(company_id));
+        assert_eq!(ownerships[0].inherited_from, Some(group_id));
+
+        // Deleting a parent detaches its children
+        deletion.delete(company_id).await?;
+        assert!(matches!(
+            fetching.get_tenant_public_by_id(unit_id).await?,
+            FetchResponseKind::Found(ref t) if t.parent_id.is_none()
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn trashed_tenant_is_purged_through_sqlite(
+    ) -> Result<(), MappedErrors> {
+        let db = setup_temp_db();
+
+        let registration = TenantRegistrationSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let fetching = TenantFetchingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let updating = TenantUpdatingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let deletion = TenantDeletionSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+
+        let mut ids = vec![];
+
+        for name in ["Trashed", "Child", "Restored"] {
+            let tenant = Tenant::new_with_owners(
+                name.into(),
+                None,
+                Children::Ids(vec![]),
+            );
+
+            match registration.create(tenant, "self".into()).await? {
+                CreateResponseKind::Created(tenant) => {
+                    ids.push(tenant.id.unwrap())
+                }
+                CreateResponseKind::NotCreated(..) => {
+                    panic!("expected the tenant to be created")
+                }
+            }
+        }
+
+        let (tenant_id, child_id, restored_id) = (ids[0], ids[1], ids[2]);
+        let account_id = Uuid::new_v4();
+        let role_id = Uuid::new_v4();
+        let guest_user_id = Uuid::new_v4();
+
+        updating
+            .update_tenant_parent(child_id, Some(tenant_id))
+            .await?;
+
+        {
+            let conn = &mut db.provider.get_pool().get().unwrap();
+            diesel::insert_into(crate::schema::account::table)
+                .values((
+                    crate::schema::account::id.eq(uuid_to_text(&account_id)),
+                    crate::schema::account::name.eq("Billing"),
+                    crate::schema::account::created
+                        .eq(naive_timestamp_to_text(&Local::now().naive_utc())),
+                    crate::schema::account::is_active.eq(true),
+                    crate::schema::account::is_checked.eq(true),
+                    crate::schema::account::is_archived.eq(false),
+                    crate::schema::account::is_deleted.eq(false),
+                    crate::schema::account::is_default.eq(false),
+                    crate::schema::account::slug.eq("billing"),
+                    crate::schema::account::account_type.eq("{}"),
+                    crate::schema::account::tenant_id
+                        .eq(uuid_to_text(&tenant_id)),
+                ))
+                .execute(conn)
+                .unwrap();
+
+            // A guest of the account through a tenant-less role
+            diesel::insert_into(crate::schema::guest_role::table)
+                .values((
+                    crate::schema::guest_role::id.eq(uuid_to_text(&role_id)),
+                    crate::schema::guest_role::name.eq("Reader"),
+                    crate::schema::guest_role::permission.eq(0),
+                    crate::schema::guest_role::slug.eq("reader"),
+                    crate::schema::guest_role::system.eq(false),
+                    crate::schema::guest_role::created
+                        .eq(naive_timestamp_to_text(&Local::now().naive_utc())),
+                ))
+                .execute(conn)
+                .unwrap();
+            diesel::insert_into(crate::schema::guest_user::table)
+                .values((
+                    crate::schema::guest_user::id
+                        .eq(uuid_to_text(&guest_user_id)),
+                    crate::schema::guest_user::email.eq("guest@acme.test"),
+                    crate::schema::guest_user::guest_role_id
+                        .eq(uuid_to_text(&role_id)),
+                    crate::schema::guest_user::created
+                        .eq(naive_timestamp_to_text(&Local::now().naive_utc())),
+                    crate::schema::guest_user::was_verified.eq(true),
+                ))
+                .execute(conn)
+                .unwrap();
+            diesel::insert_into(crate::schema::guest_user_on_account::table)
+                .values((
+                    crate::schema::guest_user_on_account::guest_user_id
+                        .eq(uuid_to_text(&guest_user_id)),
+                    crate::schema::guest_user_on_account::account_id
+                        .eq(uuid_to_text(&account_id)),
+                    crate::schema::guest_user_on_account::created
+                        .eq(naive_timestamp_to_text(&Local::now().naive_utc())),
+                ))
+                .execute(conn)
+                .unwrap();
+        }
+
+        let trashed_at = Local::now() - chrono::Duration::days(31);
+        let trashed_before = chrono::Utc::now() - chrono::Duration::days(30);
+
+        for id in [tenant_id, restored_id] {
+            updating
+                .update_tenant_status(
+                    id,
+                    TenantStatus::Trashed {
+                        at: trashed_at,
+                        by: "owner@acme.test".into(),
+                    },
+                )
+                .await?;
+        }
+
+        // Only tenants trashed inside the window are listed
+        let trashed = match fetching.list_trashed(None, trashed_before).await? {
+            FetchManyResponseKind::Found(records) => records,
+            _ => panic!("expected the trashed tenants to be found"),
+        };
+        assert_eq!(trashed.len(), 2);
+        assert!(trashed.iter().any(|t| t.id == Some(tenant_id)));
+        assert!(matches!(
+            fetching
+                .list_trashed(Some(trashed_at.into()), trashed_before)
+                .await?,
+            FetchManyResponseKind::NotFound
+        ));
+
+        let encryption_key = EncryptionKeyFetchingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let kek = [5u8; 32];
+        encryption_key
+            .get_or_provision_dek(Some(tenant_id), &kek)
+            .await?;
+
+        // A tenant restored after being listed is not purged
+        updating
+            .update_tenant_status(
+                restored_id,
+                TenantStatus::Restored {
+                    at: Local::now(),
+                    by: "owner@acme.test".into(),
+                },
+            )
+            .await?;
+
+        assert!(matches!(
+            deletion.purge(restored_id, trashed_before).await?,
+            DeletionResponseKind::NotDeleted(..)
+        ));
+        assert!(matches!(
+            fetching.get_tenant_public_by_id(restored_id).await?,
+            FetchResponseKind::Found(_)
+        ));
+
+        // Purging removes the tenant accounts and detaches its children
+        assert!(matches!(
+            deletion.purge(tenant_id, trashed_before).await?,
+            DeletionResponseKind::Deleted
+        ));
+
+        // The key of a purged tenant is gone and never provisioned again
+        assert_eq!(
+            encryption_key.fetch_dek(Some(tenant_id), &kek).await?,
+            None
+        );
+        assert!(encryption_key
+            .get_or_provision_dek(Some(tenant_id), &kek)
+            .await
+            .is_err());
+        assert!(matches!(
+            fetching.get_tenant_public_by_id(tenant_id).await?,
+            FetchResponseKind::NotFound(_)
+        ));
+        assert!(matches!(
+            fetching.get_tenant_public_by_id(child_id).await?,
+            FetchResponseKind::Found(ref t) if t.parent_id.is_none()
+        ));
+
+        let remaining_accounts = {
+            let conn = &mut db.provider.get_pool().get().unwrap();
+            crate::schema::account::table
+                .filter(
+                    crate::schema::account::id.eq(uuid_to_text(&account_id)),
+                )
+                .count()
+                .get_result::<i64>(conn)
+                .unwrap()
+        };
+        assert_eq!(remaining_accounts, 0);
+
+        // Guest users of the purged accounts only are purged too
+        let remaining_guests = {
+            let conn = &mut db.provider.get_pool().get().unwrap();
+            crate::schema::guest_user::table
+                .filter(
+                    crate::schema::guest_user::id
+                        .eq(uuid_to_text(&guest_user_id)),
+                )
+                .count()
+                .get_result::<i64>(conn)
+                .unwrap()
+        };
+        assert_eq!(remaining_guests, 0);
+
+        Ok(())
+    }
 }
@@ -1,4 +1,4 @@
-use super::{decode_status, map_tenant_model_to_dto};
+use super::{decode_status, map_tenant_model_to_dto, TENANT_LINEAGE_CTE};
 use crate::{
     config::SqliteDbPoolProvider,
     models::{
@@ -12,7 +12,7 @@ use crate::{
 
 use async_trait::async_trait;
 use chrono::Local;
-use diesel::prelude::*;
+use diesel::{prelude::*, sql_query, sql_types::Text};
 use myc_core::domain::{
     dtos::{
         native_error_codes::NativeErrorCodes,
@@ -28,6 +28,12 @@ use shaku::Component;
 use std::{str::FromStr, sync::Arc};
 use uuid::Uuid;
 
+#[derive(QueryableByName)]
+struct LineageTenant {
+    #[diesel(sql_type = Text)]
+    id: String,
+}
+
 #[derive(Component)]
 #[shaku(interface = TenantUpdating)]
 pub struct TenantUpdatingSqlDbRepository {
@@ -119,6 +125,77 @@ impl TenantUpdating for TenantUpdatingSqlDbRepository {
         )))
     }
 
+    #[tracing::instrument(name = "update_tenant_parent", skip_all)]
+    async fn update_tenant_parent(
+        &self,
+        tenant_id: Uuid,
+        parent_id: Option<Uuid>,
+    ) -> Result<UpdatingResponseKind<Tenant>, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            updating_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let tenant_id_text = uuid_to_text(&tenant_id);
+
+        //
+        // An immediate transaction takes the database write lock before the
+        // lineage of the next parent is read, so concurrent moves could not
+        // close a cycle between the check and the update.
+        //
+        let result = conn.immediate_transaction::<_, diesel::result::Error, _>(
+            |conn| {
+                let tenant = tenant::table
+                    .find(&tenant_id_text)
+                    .select(TenantModel::as_select())
+                    .first::<TenantModel>(conn)?;
+
+                if let Some(parent_id) = parent_id {
+                    let lineage = sql_query(format!(
+                        "{TENANT_LINEAGE_CTE} SELECT id FROM lineage"
+                    ))
+                    .bind::<Text, _>(uuid_to_text(&parent_id))
+                    .load::<LineageTenant>(conn)?;
+
+                    if lineage.is_empty() {
+                        return Ok(Err((tenant, "Parent tenant not found")));
+                    }
+
+                    if lineage.iter().any(|t| t.id == tenant_id_text) {
+                        return Ok(Err((
+                            tenant,
+                            "A descendant of the tenant could not be its parent",
+                        )));
+                    }
+                }
+
+                diesel::update(tenant::table.find(&tenant_id_text))
+                    .set((
+                        tenant::parent_id
+                            .eq(parent_id.as_ref().map(uuid_to_text)),
+                        tenant::updated.eq(Some(naive_timestamp_to_text(
+                            &Local::now().naive_utc(),
+                        ))),
+                    ))
+                    .returning(TenantModel::as_returning())
+                    .get_result::<TenantModel>(conn)
+                    .map(Ok)
+            },
+        );
+
+        match result.map_err(|e| {
+            updating_err(format!("Failed to update tenant: {}", e))
+        })? {
+            Ok(updated) => Ok(UpdatingResponseKind::Updated(
+                map_tenant_model_to_dto(updated),
+            )),
+            Err((tenant, msg)) => Ok(UpdatingResponseKind::NotUpdated(
+                map_tenant_model_to_dto(tenant),
+                msg.to_string(),
+            )),
+        }
+    }
+
     #[tracing::instrument(name = "register_owner", skip_all)]
     async fn register_owner(
         &self,
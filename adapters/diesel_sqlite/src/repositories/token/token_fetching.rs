@@ -5,11 +5,11 @@ use crate::{
         public_connection_string_info::PublicConnectionStringInfoModel,
         token::Token as TokenModel,
     },
-    types::naive_timestamp_from_text,
+    types::{naive_timestamp_from_text, naive_timestamp_to_text},
 };
 
 use async_trait::async_trait;
-use chrono::Local;
+use chrono::{DateTime, Local, Utc};
 use diesel::{sql_types::Text, RunQueryDsl};
 use myc_core::domain::{
     dtos::{
@@ -199,4 +199,56 @@ impl TokenFetching for TokenFetchingSqlDbRepository {
             Err(e) => Err(e),
         }
     }
+
+    #[tracing::instrument(
+        name = "list_connection_strings_expiring_between",
+        skip_all
+    )]
+    async fn list_connection_strings_expiring_between(
+        &self,
+        expiring_from: DateTime<Utc>,
+        expiring_before: DateTime<Utc>,
+    ) -> Result<FetchManyResponseKind<PublicConnectionStringInfo>, MappedErrors>
+    {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            fetching_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        //
+        // Expirations are stored as naive UTC text, which sorts as time does
+        //
+        let sql = r#"
+            SELECT id, innerId, accountId, email, name, expiration, createdAt, scope
+            FROM public_connection_string_info
+            WHERE expiration >= ? AND expiration < ?
+            ORDER BY expiration ASC
+        "#;
+
+        let rows = diesel::sql_query(sql)
+            .bind::<Text, _>(naive_timestamp_to_text(
+                &expiring_from.naive_utc(),
+            ))
+            .bind::<Text, _>(naive_timestamp_to_text(
+                &expiring_before.naive_utc(),
+            ))
+            .load::<PublicConnectionStringInfoModel>(conn)
+            .map_err(|e| {
+                fetching_err(format!(
+                    "Failed to fetch connection strings: {}",
+                    e
+                ))
+            })?;
+
+        if rows.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        let connection_strings = rows
+            .into_iter()
+            .map(map_public_connection_string_info_model_to_dto)
+            .collect::<Result<Vec<_>, MappedErrors>>()?;
+
+        Ok(FetchManyResponseKind::Found(connection_strings))
+    }
 }
@@ -272,4 +272,59 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn execution_payloads_are_pseudonymised_through_sqlite(
+    ) -> Result<(), MappedErrors> {
+        let db = setup_temp_db();
+        let registration = WebHookRegistrationSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let fetching = WebHookFetchingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+        let updating = WebHookUpdatingSqlDbRepository {
+            db_config: db.provider.clone(),
+        };
+
+        for payload in [
+            "{\"email\":\"Jane@example.com\"}",
+            "{\"email\":\"john@example.com\"}",
+        ] {
+            let artifact = WebHookPayloadArtifact::new(
+                None,
+                payload.into(),
+                PayloadId::Uuid(Uuid::new_v4()),
+                WebHookTrigger::SubscriptionAccountCreated,
+            )
+            .encode_payload()?;
+
+            registration.register_execution_event(artifact).await?;
+        }
+
+        let pseudonymised = updating
+            .pseudonymise_execution_events(
+                "jane@example.com".into(),
+                "erased@erased.invalid".into(),
+            )
+            .await?;
+        assert_eq!(pseudonymised, 1);
+
+        let payloads = match fetching.fetch_execution_event(10, 5, None).await?
+        {
+            FetchManyResponseKind::Found(events) => events
+                .iter()
+                .map(|event| Ok(event.decode_payload()?.payload))
+                .collect::<Result<Vec<_>, MappedErrors>>()?,
+            _ => panic!("expected to find pending execution events"),
+        };
+
+        assert!(payloads
+            .contains(&"{\"email\":\"erased@erased.invalid\"}".to_string()));
+        assert!(
+            payloads.contains(&"{\"email\":\"john@example.com\"}".to_string())
+        );
+
+        Ok(())
+    }
 }
@@ -6,7 +6,9 @@ use crate::{
         webhook_execution::WebHookExecution as WebHookExecutionModel,
     },
     schema::{webhook, webhook_execution},
-    types::{json_to_text, naive_timestamp_to_text, uuid_to_text},
+    types::{
+        json_from_text, json_to_text, naive_timestamp_to_text, uuid_to_text,
+    },
 };
 
 use async_trait::async_trait;
@@ -15,7 +17,7 @@ use diesel::{prelude::*, result::DatabaseErrorKind, result::Error};
 use myc_core::domain::{
     dtos::{
         native_error_codes::NativeErrorCodes,
-        webhook::{WebHook, WebHookPayloadArtifact},
+        webhook::{PayloadId, WebHook, WebHookPayloadArtifact},
     },
     entities::WebHookUpdating,
 };
@@ -24,7 +26,9 @@ use mycelium_base::{
     utils::errors::{updating_err, MappedErrors},
 };
 use shaku::Component;
-use std::sync::Arc;
+use std::{str::FromStr, sync::Arc};
+
+const PSEUDONYMISATION_PAGE_SIZE: i64 = 500;
 
 #[derive(Component)]
 #[shaku(interface = WebHookUpdating)]
@@ -143,4 +147,104 @@ impl WebHookUpdating for WebHookUpdatingSqlDbRepository {
 
         Ok(UpdatingResponseKind::Updated(artifact))
     }
+
+    #[tracing::instrument(
+        name = "pseudonymise_webhook_execution_events",
+        skip_all
+    )]
+    async fn pseudonymise_execution_events(
+        &self,
+        subject: String,
+        pseudonym: String,
+    ) -> Result<u64, MappedErrors> {
+        let conn = &mut self.db_config.get_pool().get().map_err(|e| {
+            updating_err(format!("Failed to get DB connection: {}", e))
+                .with_code(NativeErrorCodes::MYC00001)
+        })?;
+
+        let mut after: Option<String> = None;
+        let mut updated = 0;
+
+        loop {
+            let mut query = webhook_execution::table
+                .order(webhook_execution::id.asc())
+                .limit(PSEUDONYMISATION_PAGE_SIZE)
+                .select(WebHookExecutionModel::as_select())
+                .into_boxed();
+
+            if let Some(after) = after {
+                query = query.filter(webhook_execution::id.gt(after));
+            }
+
+            let records =
+                query.load::<WebHookExecutionModel>(conn).map_err(|e| {
+                    updating_err(format!(
+                        "Failed to fetch webhook execution events: {e}"
+                    ))
+                })?;
+
+            let page_len = records.len() as i64;
+            after = records.last().map(|record| record.id.to_owned());
+
+            for record in records {
+                let Ok(trigger) = record.trigger.parse() else {
+                    continue;
+                };
+
+                let mut artifact = WebHookPayloadArtifact::new(
+                    None,
+                    record.payload,
+                    PayloadId::from_str(&record.payload_id)?,
+                    trigger,
+                );
+
+                artifact.propagations = record
+                    .propagations
+                    .and_then(|propagations| json_from_text(&propagations).ok())
+                    .and_then(|propagations| {
+                        serde_json::from_value(propagations).ok()
+                    });
+
+                let Some(pseudonymised) =
+                    artifact.pseudonymise(&subject, &pseudonym)?
+                else {
+                    continue;
+                };
+
+                let target = webhook_execution::table.find(record.id);
+
+                let result = match pseudonymised.propagations {
+                    Some(propagations) => diesel::update(target)
+                        .set((
+                            webhook_execution::payload
+                                .eq(pseudonymised.payload),
+                            webhook_execution::propagations.eq(
+                                serde_json::to_value(propagations)
+                                    .ok()
+                                    .map(|value| value.to_string()),
+                            ),
+                        ))
+                        .execute(conn),
+                    None => diesel::update(target)
+                        .set(
+                            webhook_execution::payload
+                                .eq(pseudonymised.payload),
+                        )
+                        .execute(conn),
+                };
+
+                updated += result.map_err(|e| {
+                    updating_err(format!(
+                        "Failed to pseudonymise webhook execution: {e}"
+                    ))
+                })? as u64;
+            }
+
+            if page_len < PSEUDONYMISATION_PAGE_SIZE {
+                break;
+            }
+        }
+
+        Ok(updated)
+    }
 }
@@ -33,6 +33,22 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    bulk_import (id) {
+        id -> Text,
+        tenant_id -> Text,
+        checksum -> Text,
+        status -> Text,
+        import_rows -> Text,
+        row_results -> Text,
+        created_by -> Text,
+        created -> Text,
+        claimed_by -> Nullable<Text>,
+        started_at -> Nullable<Text>,
+        finished_at -> Nullable<Text>,
+    }
+}
+
 diesel::table! {
     error_code (prefix, code) {
         code -> Integer,
@@ -44,6 +60,16 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    error_code_translation (prefix, code, locale) {
+        prefix -> Text,
+        code -> Integer,
+        locale -> Text,
+        message -> Text,
+        details -> Nullable<Text>,
+    }
+}
+
 diesel::table! {
     guest_role (id) {
         id -> Text,
@@ -52,8 +78,10 @@ diesel::table! {
         permission -> Integer,
         slug -> Text,
         system -> Bool,
+        tenant_id -> Nullable<Text>,
         created -> Text,
         updated -> Nullable<Text>,
+        permissions -> Nullable<Text>,
     }
 }
 
@@ -75,6 +103,9 @@ diesel::table! {
         created -> Text,
         updated -> Nullable<Text>,
         was_verified -> Bool,
+        invited_by -> Nullable<Text>,
+        invitation_sent_at -> Nullable<Text>,
+        invitation_expires_at -> Nullable<Text>,
     }
 }
 
@@ -85,6 +116,8 @@ diesel::table! {
         created -> Text,
         permit_flags -> Nullable<Text>,
         deny_flags -> Nullable<Text>,
+        valid_from -> Nullable<Text>,
+        valid_until -> Nullable<Text>,
     }
 }
 
@@ -127,6 +160,83 @@ diesel::table! {
         performed_by -> Text,
         metadata -> Text,
         created_at -> Text,
+        chain_seq -> Nullable<BigInt>,
+        prev_hash -> Nullable<Text>,
+        row_hash -> Nullable<Text>,
+        redacted_at -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    resource_audit_archive (id) {
+        id -> Text,
+        chain_tenant_id -> Text,
+        first_seq -> Nullable<BigInt>,
+        last_seq -> Nullable<BigInt>,
+        first_prev_hash -> Nullable<Text>,
+        last_hash -> Nullable<Text>,
+        cutoff -> Text,
+        row_count -> BigInt,
+        location -> Text,
+        sha256 -> Text,
+        signature -> Text,
+        created_at -> Text,
+    }
+}
+
+diesel::table! {
+    resource_audit_checkpoint (id) {
+        id -> Text,
+        chain_tenant_id -> Text,
+        chain_seq -> BigInt,
+        root_hash -> Text,
+        signature -> Text,
+        created_at -> Text,
+    }
+}
+
+diesel::table! {
+    resource_audit_retention_policy (chain_tenant_id) {
+        chain_tenant_id -> Text,
+        retention_days -> Nullable<Integer>,
+        legal_hold -> Bool,
+        updated_at -> Text,
+    }
+}
+
+diesel::table! {
+    scheduled_job (name) {
+        name -> Text,
+        last_run_at -> Nullable<Text>,
+        next_run_at -> Text,
+        claimed_by -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    scim_credential (id) {
+        id -> Text,
+        tenant_id -> Text,
+        name -> Text,
+        secret_hash -> Text,
+        created_by -> Text,
+        created -> Text,
+    }
+}
+
+diesel::table! {
+    scim_resource (id) {
+        id -> Text,
+        tenant_id -> Text,
+        kind -> Text,
+        external_id -> Nullable<Text>,
+        display_name -> Text,
+        account_id -> Nullable<Text>,
+        guest_role_id -> Nullable<Text>,
+        provisioned -> Bool,
+        attributes -> Text,
+        created -> Text,
+        updated -> Nullable<Text>,
     }
 }
 
@@ -141,6 +251,7 @@ diesel::table! {
         updated -> Nullable<Text>,
         encrypted_dek -> Nullable<Text>,
         kek_version -> Integer,
+        parent_id -> Nullable<Text>,
     }
 }
 
@@ -153,6 +264,19 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    tenant_email_template (id) {
+        id -> Text,
+        tenant_id -> Text,
+        kind -> Text,
+        locale -> Text,
+        subject -> Text,
+        body -> Text,
+        created -> Text,
+        updated -> Nullable<Text>,
+    }
+}
+
 diesel::table! {
     token (id) {
         id -> Integer,
@@ -221,6 +345,27 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    message_delivery (id) {
+        id -> Text,
+        recipient -> Text,
+        subject -> Text,
+        status -> Text,
+        detail -> Nullable<Text>,
+        created -> Text,
+        updated -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    email_suppression (email) {
+        email -> Text,
+        reason -> Text,
+        detail -> Nullable<Text>,
+        created -> Text,
+    }
+}
+
 diesel::table! {
     healthcheck_logs (service_id, checked_at) {
         service_id -> Text,
@@ -252,22 +397,29 @@ diesel::table! {
 
 diesel::joinable!(account -> tenant (tenant_id));
 diesel::joinable!(account_tag -> account (account_id));
+diesel::joinable!(bulk_import -> tenant (tenant_id));
 diesel::joinable!(guest_user -> guest_role (guest_role_id));
 diesel::joinable!(guest_user_on_account -> account (account_id));
 diesel::joinable!(guest_user_on_account -> guest_user (guest_user_id));
+diesel::joinable!(guest_role -> tenant (tenant_id));
 diesel::joinable!(guest_role_children -> guest_role (child_role_id));
 diesel::joinable!(identity_provider -> user (user_id));
 diesel::joinable!(manager_account_on_tenant -> account (account_id));
 diesel::joinable!(manager_account_on_tenant -> tenant (tenant_id));
 diesel::joinable!(owner_on_tenant -> tenant (tenant_id));
 diesel::joinable!(owner_on_tenant -> user (owner_id));
+diesel::joinable!(scim_credential -> tenant (tenant_id));
+diesel::joinable!(scim_resource -> tenant (tenant_id));
+diesel::joinable!(tenant_email_template -> tenant (tenant_id));
 diesel::joinable!(tenant_tag -> tenant (tenant_id));
 diesel::joinable!(user -> account (account_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     account,
     account_tag,
+    bulk_import,
     error_code,
+    error_code_translation,
     guest_role,
     guest_role_children,
     guest_user,
@@ -276,8 +428,14 @@ diesel::allow_tables_to_appear_in_same_query!(
     identity_provider,
     manager_account_on_tenant,
     owner_on_tenant,
+    resource_audit_archive,
+    resource_audit_checkpoint,
     resource_audit_log,
+    resource_audit_retention_policy,
+    scim_credential,
+    scim_resource,
     tenant,
+    tenant_email_template,
     tenant_tag,
     token,
     user,
@@ -0,0 +1,383 @@
+use super::migration::MIGRATIONS;
+
+use diesel::{
+    connection::SimpleConnection,
+    sql_types::{BigInt, Integer, Text},
+    Connection, QueryableByName, RunQueryDsl, SqliteConnection,
+};
+use diesel_migrations::MigrationHarness;
+use myc_core::domain::utils::TableChecksum;
+use mycelium_base::utils::errors::{execution_err, fetching_err, MappedErrors};
+use serde_json::{Map, Value};
+
+/// Tables whose rows reference other rows of the same table, with the
+/// referencing column. Their rows are read parents first, so a copy that
+/// checks foreign keys row by row never meets a child before its parent.
+const SELF_REFERENCING_TABLES: &[(&str, &str)] = &[("tenant", "parent_id")];
+
+#[derive(QueryableByName)]
+struct TableName {
+    #[diesel(sql_type = Text)]
+    name: String,
+}
+
+#[derive(QueryableByName)]
+struct TableColumn {
+    #[diesel(sql_type = Text)]
+    name: String,
+
+    #[diesel(sql_type = Integer)]
+    pk: i32,
+}
+
+#[derive(QueryableByName)]
+struct RowCount {
+    #[diesel(sql_type = BigInt)]
+    count: i64,
+}
+
+#[derive(QueryableByName)]
+struct JsonRow {
+    #[diesel(sql_type = Text)]
+    row: String,
+}
+
+/// Read-only access to the raw rows of a standalone SQLite database
+///
+/// Rows are read as JSON objects keyed by column name, with the values as
+/// SQLite stores them (UUIDs, timestamps, JSON and arrays as TEXT, booleans
+/// as integers). Used by `myc-cli migrate-backend` to stream a standalone
+/// installation to another backend.
+pub struct SqliteTableReader {
+    conn: SqliteConnection,
+}
+
+impl SqliteTableReader {
+    /// Open the database at `url`
+    ///
+    /// The database is never migrated here: a database with pending
+    /// migrations was written by an older release, and is rejected so its
+    /// rows are never read against a schema they do not follow.
+    pub fn open(url: &str) -> Result<Self, MappedErrors> {
+        let mut conn = SqliteConnection::establish(url).map_err(|err| {
+            fetching_err(format!(
+                "Failed to establish SQLite connection at {url}: {err}"
+            ))
+        })?;
+
+        conn.batch_execute("PRAGMA busy_timeout = 5000;")
+            .map_err(|err| {
+                execution_err(format!("Failed to set busy_timeout: {err}"))
+            })?;
+
+        let pending =
+            conn.has_pending_migration(MIGRATIONS).map_err(|err| {
+                fetching_err(format!(
+                    "Failed to check SQLite migrations: {err}"
+                ))
+            })?;
+
+        if pending {
+            return execution_err(
+                "The SQLite database has pending migrations: start the \
+                 standalone API of this release once before migrating it",
+            )
+            .as_error();
+        }
+
+        Ok(Self { conn })
+    }
+
+    /// List the data tables of the database, by name
+    pub fn tables(&mut self) -> Result<Vec<String>, MappedErrors> {
+        list_tables(&mut self.conn)
+    }
+
+    /// Count the rows of `table`
+    pub fn count_rows(&mut self, table: &str) -> Result<u64, MappedErrors> {
+        count_table_rows(&mut self.conn, table, None)
+    }
+
+    /// Checksum every row of `table`, leaving out `excluded_columns`
+    ///
+    /// Rows are read in pages of `page_size`, as stored, so the checksum
+    /// describes the source itself rather than what was copied from it.
+    pub fn checksum_rows(
+        &mut self,
+        table: &str,
+        excluded_columns: &[&str],
+        page_size: u64,
+    ) -> Result<TableChecksum, MappedErrors> {
+        let columns = table_columns(&mut self.conn, table)?
+            .into_iter()
+            .map(|(name, _)| name)
+            .filter(|name| !excluded_columns.contains(&name.as_str()))
+            .collect::<Vec<_>>();
+
+        let mut checksum = TableChecksum::new(columns);
+
+        loop {
+            let rows = read_table_rows(
+                &mut self.conn,
+                table,
+                None,
+                checksum.rows,
+                page_size,
+            )?;
+
+            rows.iter().for_each(|row| checksum.add_row(row));
+
+            if (rows.len() as u64) < page_size {
+                break;
+            }
+        }
+
+        Ok(checksum)
+    }
+
+    /// Read `limit` rows of `table`, skipping the first `offset` ones
+    ///
+    /// Rows come in a stable order (parents first, then by primary key), so
+    /// consecutive calls page through the table as long as it is not written
+    /// to meanwhile.
+    pub fn read_rows(
+        &mut self,
+        table: &str,
+        offset: u64,
+        limit: u64,
+    ) -> Result<Vec<Map<String, Value>>, MappedErrors> {
+        read_table_rows(&mut self.conn, table, None, offset, limit)
+    }
+}
+
+pub(crate) fn list_tables(
+    conn: &mut SqliteConnection,
+) -> Result<Vec<String>, MappedErrors> {
+    let tables: Vec<TableName> = diesel::sql_query(
+        "SELECT name FROM sqlite_master \
+         WHERE type = 'table' \
+           AND name NOT LIKE 'sqlite_%' \
+           AND name != '__diesel_schema_migrations' \
+         ORDER BY name",
+    )
+    .load(conn)
+    .map_err(|err| {
+        fetching_err(format!("Failed to list SQLite tables: {err}"))
+    })?;
+
+    Ok(tables.into_iter().map(|table| table.name).collect())
+}
+
+/// The columns of `table` in declaration order, with their position in the
+/// primary key (0 outside of it)
+pub(crate) fn table_columns(
+    conn: &mut SqliteConnection,
+    table: &str,
+) -> Result<Vec<(String, i32)>, MappedErrors> {
+    let columns: Vec<TableColumn> = diesel::sql_query(
+        "SELECT name, pk FROM pragma_table_info(?) ORDER BY cid",
+    )
+    .bind::<Text, _>(table)
+    .load(conn)
+    .map_err(|err| {
+        fetching_err(format!("Failed to read the columns of {table}: {err}"))
+    })?;
+
+    if columns.is_empty() {
+        return fetching_err(format!("Table {table} does not exist"))
+            .as_error();
+    }
+
+    Ok(columns
+        .into_iter()
+        .map(|column| (column.name, column.pk))
+        .collect())
+}
+
+/// Count the rows of `table` matching `filter`
+pub(crate) fn count_table_rows(
+    conn: &mut SqliteConnection,
+    table: &str,
+    filter: Option<&str>,
+) -> Result<u64, MappedErrors> {
+    let count: RowCount = diesel::sql_query(format!(
+        "SELECT COUNT(*) AS count FROM {} WHERE {}",
+        quote_identifier(table),
+        filter.unwrap_or("true")
+    ))
+    .get_result(conn)
+    .map_err(|err| {
+        fetching_err(format!("Failed to count the rows of {table}: {err}"))
+    })?;
+
+    Ok(count.count as u64)
+}
+
+/// Read `limit` rows of `table` matching `filter`, skipping the first
+/// `offset` ones, parents first and then by primary key
+pub(crate) fn read_table_rows(
+    conn: &mut SqliteConnection,
+    table: &str,
+    filter: Option<&str>,
+    offset: u64,
+    limit: u64,
+) -> Result<Vec<Map<String, Value>>, MappedErrors> {
+    let columns = table_columns(conn, table)?;
+    let quoted_table = quote_identifier(table);
+    let filter = filter.unwrap_or("true");
+
+    let object = columns
+        .iter()
+        .map(|(name, _)| {
+            format!(
+                "'{}', {quoted_table}.{}",
+                name.replace('\'', "''"),
+                quote_identifier(name)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut keys = columns.iter().filter(|(_, pk)| *pk > 0).collect::<Vec<_>>();
+
+    keys.sort_by_key(|(_, pk)| *pk);
+
+    let order = match keys.is_empty() {
+        true => format!("{quoted_table}.rowid"),
+        false => keys
+            .iter()
+            .map(|(name, _)| {
+                format!("{quoted_table}.{}", quote_identifier(name))
+            })
+            .collect::<Vec<_>>()
+            .join(", "),
+    };
+
+    let query = match SELF_REFERENCING_TABLES
+        .iter()
+        .find(|(name, _)| *name == table)
+    {
+        //
+        // Depth of each row in its hierarchy, roots first. Rows whose parent
+        // is missing are roots too, so none is left out.
+        //
+        Some((_, parent)) => {
+            let parent = quote_identifier(parent);
+
+            format!(
+                "WITH RECURSIVE depth(rid, level) AS ( \
+                     SELECT child.rowid, 0 FROM {quoted_table} child \
+                     WHERE child.{parent} IS NULL OR NOT EXISTS ( \
+                         SELECT 1 FROM {quoted_table} up \
+                         WHERE up.id = child.{parent}) \
+                     UNION ALL \
+                     SELECT child.rowid, depth.level + 1 \
+                     FROM {quoted_table} child \
+                     JOIN {quoted_table} up ON up.id = child.{parent} \
+                     JOIN depth ON depth.rid = up.rowid \
+                 ) \
+                 SELECT json_object({object}) AS row \
+                 FROM {quoted_table} \
+                 JOIN depth ON depth.rid = {quoted_table}.rowid \
+                 WHERE {filter} \
+                 ORDER BY depth.level, {order} \
+                 LIMIT ? OFFSET ?"
+            )
+        }
+        None => format!(
+            "SELECT json_object({object}) AS row FROM {quoted_table} \
+             WHERE {filter} ORDER BY {order} LIMIT ? OFFSET ?"
+        ),
+    };
+
+    let rows: Vec<JsonRow> = diesel::sql_query(query)
+        .bind::<BigInt, _>(limit as i64)
+        .bind::<BigInt, _>(offset as i64)
+        .load(conn)
+        .map_err(|err| {
+            fetching_err(format!("Failed to read the rows of {table}: {err}"))
+        })?;
+
+    rows.into_iter()
+        .map(|row| {
+            serde_json::from_str(&row.row).map_err(|err| {
+                fetching_err(format!(
+                    "Failed to decode a row of {table}: {err}"
+                ))
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::setup_temp_db;
+
+    #[test]
+    fn rows_are_read_as_json_with_parents_first() {
+        let db = setup_temp_db();
+        let mut conn = db.provider.get_pool().get().unwrap();
+
+        for (id, name, parent) in [
+            ("00000000-0000-0000-0000-000000000003", "grandchild", "2"),
+            ("00000000-0000-0000-0000-000000000002", "child", "1"),
+            ("00000000-0000-0000-0000-000000000001", "root", ""),
+        ] {
+            let parent = match parent {
+                "" => "NULL".to_string(),
+                parent => {
+                    format!("'00000000-0000-0000-0000-00000000000{parent}'")
+                }
+            };
+
+            conn.batch_execute(&format!(
+                "INSERT INTO tenant (id, name, created, kek_version, \
+                 parent_id) VALUES ('{id}', '{name}', \
+                 '2026-10-19T00:00:00', 1, {parent})"
+            ))
+            .unwrap();
+        }
+
+        drop(conn);
+
+        let mut reader =
+            SqliteTableReader::open(db.path().to_str().unwrap()).unwrap();
+
+        assert!(reader.tables().unwrap().contains(&"tenant".to_string()));
+        assert!(!reader
+            .tables()
+            .unwrap()
+            .contains(&"__diesel_schema_migrations".to_string()));
+        assert_eq!(reader.count_rows("tenant").unwrap(), 3);
+
+        let names = [0, 1, 2]
+            .into_iter()
+            .flat_map(|offset| reader.read_rows("tenant", offset, 1).unwrap())
+            .map(|row| row["name"].as_str().unwrap().to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(names, ["root", "child", "grandchild"]);
+
+        let row = reader.read_rows("tenant", 0, 1).unwrap().remove(0);
+
+        assert_eq!(row["kek_version"], Value::from(1));
+        assert_eq!(row["parent_id"], Value::Null);
+        assert!(reader.read_rows("tenant", 3, 10).unwrap().is_empty());
+        assert!(reader.read_rows("missing", 0, 10).is_err());
+
+        let checksum = reader.checksum_rows("tenant", &["name"], 2).unwrap();
+
+        assert_eq!(checksum.rows, 3);
+        assert!(!checksum.columns.contains(&"name".to_string()));
+        assert_eq!(
+            checksum,
+            reader.checksum_rows("tenant", &["name"], 10).unwrap()
+        );
+        assert_ne!(checksum, reader.checksum_rows("tenant", &[], 10).unwrap());
+    }
+}
@@ -14,6 +14,12 @@ pub(crate) struct TempDb {
     path: PathBuf,
 }
 
+impl TempDb {
+    pub(crate) fn path(&self) -> &PathBuf {
+        &self.path
+    }
+}
+
 impl Drop for TempDb {
     fn drop(&mut self) {
         let _ = std::fs::remove_file(&self.path);
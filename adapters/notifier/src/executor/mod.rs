@@ -1,8 +1,13 @@
 use chrono::Local;
-use myc_core::domain::dtos::message::{MessageSendingEvent, MessageStatus};
+use myc_core::domain::dtos::{
+    email_delivery::{DeliveryEvent, MessageDelivery, MessageDeliveryStatus},
+    message::{MessageSendingEvent, MessageStatus},
+};
 use myc_core::domain::entities::{
-    LocalMessageReading, LocalMessageWrite, RemoteMessageWrite,
+    EmailSuppressionRegistration, LocalMessageReading, LocalMessageWrite,
+    MessageDeliveryRegistration, RemoteMessageWrite,
 };
+use myc_core::use_cases::register_delivery_events;
 use mycelium_base::entities::FetchManyResponseKind;
 use mycelium_base::{
     entities::CreateResponseKind,
@@ -14,12 +19,16 @@ use uuid::Uuid;
 /// Consumes messages from the message queue
 ///
 /// This function consumes messages from the message queue sending by smtp.
+/// Messages leaving the queue, either sent or given up, are recorded with
+/// their delivery status.
 #[tracing::instrument(
     name = "consume_messages",
     skip(
         local_message_read_repo,
         local_message_write_repo,
-        remote_message_write_repo
+        remote_message_write_repo,
+        message_delivery_registration_repo,
+        email_suppression_registration_repo
     )
 )]
 pub async fn consume_messages(
@@ -28,6 +37,8 @@ pub async fn consume_messages(
     local_message_read_repo: Arc<dyn LocalMessageReading>,
     local_message_write_repo: Arc<dyn LocalMessageWrite>,
     remote_message_write_repo: Arc<dyn RemoteMessageWrite>,
+    message_delivery_registration_repo: Arc<dyn MessageDeliveryRegistration>,
+    email_suppression_registration_repo: Arc<dyn EmailSuppressionRegistration>,
 ) -> Result<(i32, i32), MappedErrors> {
     let max_retries = 3;
     let mut retries = 0;
@@ -83,6 +94,13 @@ pub async fn consume_messages(
 
                 if _message.attempts >= 5 {
                     _message.status = MessageStatus::Failed;
+
+                    register_delivery(
+                        &_message,
+                        MessageDeliveryStatus::Failed,
+                        message_delivery_registration_repo.clone(),
+                    )
+                    .await;
                 }
 
                 processed_messages_failed.push(_message.id);
@@ -103,6 +121,20 @@ pub async fn consume_messages(
 
                 processed_messages_success += 1;
 
+                register_delivery(
+                    &_message,
+                    MessageDeliveryStatus::Sent,
+                    message_delivery_registration_repo.clone(),
+                )
+                .await;
+
+                simulate_delivery_events(
+                    &_message,
+                    message_delivery_registration_repo.clone(),
+                    email_suppression_registration_repo.clone(),
+                )
+                .await;
+
                 if let Err(err) = local_message_write_repo
                     .delete_message_event(_message.id)
                     .await
@@ -145,3 +177,68 @@ async fn process_record(
 
     Ok(record.id)
 }
+
+/// Record the delivery status of a message leaving the queue
+///
+/// The message was already sent or given up, so failing to record it should
+/// not stop the queue consumption.
+async fn register_delivery(
+    record: &MessageSendingEvent,
+    status: MessageDeliveryStatus,
+    message_delivery_registration_repo: Arc<dyn MessageDeliveryRegistration>,
+) {
+    let delivery = MessageDelivery {
+        id: record.id,
+        recipient: record
+            .message
+            .to
+            .first()
+            .map(|email| email.email())
+            .unwrap_or_default(),
+        subject: record.message.subject.to_owned(),
+        status,
+        detail: record.error.to_owned(),
+        created: record.created,
+        updated: Some(Local::now()),
+    };
+
+    if let Err(err) =
+        message_delivery_registration_repo.register(delivery).await
+    {
+        tracing::error!("Failed to register message delivery: {err}");
+    }
+}
+
+/// Report the events of the mailbox simulator addresses of a sent message
+///
+/// This is the local stand-in of the provider delivery webhook: messages to
+/// `bounce@`, `complaint@` or any other address of the simulator domain are
+/// handled as the matching provider events.
+async fn simulate_delivery_events(
+    record: &MessageSendingEvent,
+    message_delivery_registration_repo: Arc<dyn MessageDeliveryRegistration>,
+    email_suppression_registration_repo: Arc<dyn EmailSuppressionRegistration>,
+) {
+    let events: Vec<DeliveryEvent> = record
+        .message
+        .to
+        .iter()
+        .chain(record.message.cc.iter())
+        .chain(record.message.bcc.iter())
+        .filter_map(|email| DeliveryEvent::simulated(record.id, email))
+        .collect();
+
+    if events.is_empty() {
+        return;
+    }
+
+    if let Err(err) = register_delivery_events(
+        events,
+        Box::new(&*message_delivery_registration_repo),
+        Box::new(&*email_suppression_registration_repo),
+    )
+    .await
+    {
+        tracing::error!("Failed to register simulated delivery events: {err}");
+    }
+}
@@ -1,6 +1,6 @@
 #![cfg(feature = "local-transport")]
 
-use myc_core::domain::dtos::message::Message;
+use myc_core::domain::dtos::{email::Email, message::Message};
 
 /// Terminal wrap width for the rendered body text.
 const WIDTH: usize = 72;
@@ -22,8 +22,19 @@ pub fn render_stub_email_for_terminal(message: &Message) -> String {
     out.push_str(&format!("{rule}\n"));
     out.push_str("  STUB EMAIL — not actually delivered\n");
     out.push_str(&format!("{thin}\n"));
-    out.push_str(&format!("  To:      {}\n", message.to.email()));
+    out.push_str(&format!("  To:      {}\n", join_emails(&message.to)));
+    if !message.cc.is_empty() {
+        out.push_str(&format!("  Cc:      {}\n", join_emails(&message.cc)));
+    }
     out.push_str(&format!("  Subject: {}\n", message.subject));
+    for attachment in &message.attachments {
+        out.push_str(&format!(
+            "  Attached: {} ({}, {} bytes)\n",
+            attachment.filename,
+            attachment.content_type,
+            attachment.content.len()
+        ));
+    }
     out.push_str(&render_links_section(&extract_links(&message.body)));
     out.push_str(&format!("{thin}\n"));
     out.push_str(&render_body_section(&message.body));
@@ -32,6 +43,14 @@ pub fn render_stub_email_for_terminal(message: &Message) -> String {
     out
 }
 
+fn join_emails(emails: &[Email]) -> String {
+    emails
+        .iter()
+        .map(|email| email.email())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 /// Render the "Links:" section, or nothing when the body carried no URLs.
 fn render_links_section(links: &[String]) -> String {
     let Some(_) = links.first() else {
@@ -116,20 +135,24 @@ fn push_unique(links: &mut Vec<String>, link: &str) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use myc_core::domain::dtos::{
-        email::Email,
-        message::{FromEmail, Message},
-    };
+    use myc_core::domain::dtos::message::{FromEmail, Message};
 
     fn sample_message(body: &str) -> Message {
         Message {
             from: FromEmail::Email(
                 Email::from_string("noreply@mycelium.com".to_string()).unwrap(),
             ),
-            to: Email::from_string("user@mycelium.com".to_string()).unwrap(),
-            cc: None,
+            to: vec![
+                Email::from_string("user@mycelium.com".to_string()).unwrap()
+            ],
+            cc: vec![],
+            bcc: vec![],
+            reply_to: None,
+            headers: vec![],
             subject: "Your Login Link".to_string(),
             body: body.to_string(),
+            text_body: None,
+            attachments: vec![],
         }
     }
 
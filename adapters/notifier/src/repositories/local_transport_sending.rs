@@ -125,10 +125,17 @@ mod tests {
             from: FromEmail::Email(
                 Email::from_string("noreply@mycelium.com".to_string()).unwrap(),
             ),
-            to: Email::from_string("user@mycelium.com".to_string()).unwrap(),
-            cc: None,
+            to: vec![
+                Email::from_string("user@mycelium.com".to_string()).unwrap()
+            ],
+            cc: vec![],
+            bcc: vec![],
+            reply_to: None,
+            headers: vec![],
             subject: "Your magic link".to_string(),
             body: body.to_string(),
+            text_body: None,
+            attachments: vec![],
         }
     }
 
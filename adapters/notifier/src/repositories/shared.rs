@@ -1,5 +1,14 @@
-use lettre::{message::header::ContentType, Message as LettreMessage};
-use myc_core::domain::dtos::message::{FromEmail, Message};
+use lettre::{
+    message::{
+        header::{ContentType, HeaderName, HeaderValue},
+        Attachment, Mailbox, MultiPart, SinglePart,
+    },
+    Message as LettreMessage,
+};
+use myc_core::domain::dtos::{
+    email::Email,
+    message::{FromEmail, Message},
+};
 use mycelium_base::utils::errors::{creation_err, MappedErrors};
 
 /// Format an SMTP send failure for the caller.
@@ -26,6 +35,12 @@ pub(crate) fn smtp_send_error_message(err: impl ToString) -> String {
     format!("Could not send email: {err}")
 }
 
+/// Build the MIME message sent over the wire.
+///
+/// The HTML body goes alone when there is nothing else to send. With a
+/// plain-text alternative both go in a `multipart/alternative`, and with
+/// attachments the body is wrapped in a `multipart/mixed` followed by one
+/// part per file. Bcc recipients are part of the envelope only.
 pub(crate) fn build_lettre_message(
     message: &Message,
 ) -> Result<LettreMessage, MappedErrors> {
@@ -36,25 +51,153 @@ pub(crate) fn build_lettre_message(
     .parse()
     .map_err(|e| creation_err(format!("Invalid from email address: {e}")))?;
 
-    let to_addr =
-        message.to_owned().to.email().parse().map_err(|e| {
-            creation_err(format!("Invalid to email address: {e}"))
-        })?;
-
-    LettreMessage::builder()
+    let mut builder = LettreMessage::builder()
         .from(from_addr)
-        .to(to_addr)
-        .subject(message.to_owned().subject)
-        .header(ContentType::TEXT_HTML)
-        .body(message.to_owned().body)
-        .map_err(|e| {
-            creation_err(format!("Could not build email message: {e}"))
-        })
+        .subject(message.subject.to_owned());
+
+    for email in &message.to {
+        builder = builder.to(parse_mailbox(email, "to")?);
+    }
+
+    for email in &message.cc {
+        builder = builder.cc(parse_mailbox(email, "cc")?);
+    }
+
+    for email in &message.bcc {
+        builder = builder.bcc(parse_mailbox(email, "bcc")?);
+    }
+
+    if let Some(email) = &message.reply_to {
+        builder = builder.reply_to(parse_mailbox(email, "reply-to")?);
+    }
+
+    for header in &message.headers {
+        let name = HeaderName::new_from_ascii(header.name.to_owned()).map_err(
+            |e| creation_err(format!("Invalid header {}: {e}", header.name)),
+        )?;
+
+        builder =
+            builder.raw_header(HeaderValue::new(name, header.value.to_owned()));
+    }
+
+    let html = SinglePart::html(message.body.to_owned());
+
+    let body = message.text_body.as_ref().map(|text| {
+        MultiPart::alternative()
+            .singlepart(SinglePart::plain(text.to_owned()))
+            .singlepart(html.to_owned())
+    });
+
+    let built = if message.attachments.is_empty() {
+        match body {
+            Some(body) => builder.multipart(body),
+            None => builder.singlepart(html),
+        }
+    } else {
+        let mut mixed = match body {
+            Some(body) => MultiPart::mixed().multipart(body),
+            None => MultiPart::mixed().singlepart(html),
+        };
+
+        for attachment in &message.attachments {
+            let content_type = ContentType::parse(&attachment.content_type)
+                .map_err(|e| {
+                    creation_err(format!(
+                        "Invalid content type of attachment {}: {e}",
+                        attachment.filename
+                    ))
+                })?;
+
+            mixed = mixed.singlepart(
+                Attachment::new(attachment.filename.to_owned())
+                    .body(attachment.content.to_owned(), content_type),
+            );
+        }
+
+        builder.multipart(mixed)
+    };
+
+    built.map_err(|e| {
+        creation_err(format!("Could not build email message: {e}"))
+    })
+}
+
+fn parse_mailbox(email: &Email, field: &str) -> Result<Mailbox, MappedErrors> {
+    email.email().parse().map_err(|e| {
+        creation_err(format!("Invalid {field} email address: {e}"))
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use myc_core::domain::dtos::message::MessageAttachment;
+
+    fn sample_message() -> Message {
+        let email = |address: &str| Email::from_string(address.into()).unwrap();
+
+        Message {
+            from: FromEmail::NamedEmail(
+                "Mycelium <noreply@mycelium.com>".into(),
+            ),
+            to: vec![email("one@mycelium.com"), email("two@mycelium.com")],
+            cc: vec![email("cc@mycelium.com")],
+            bcc: vec![email("hidden@mycelium.com")],
+            reply_to: Some(email("support@mycelium.com")),
+            headers: vec![],
+            subject: "Your invoice".into(),
+            body: "<p>Your invoice is attached.</p>".into(),
+            text_body: Some("Your invoice is attached.".into()),
+            attachments: vec![],
+        }
+        .with_header("List-Unsubscribe", "<https://mycelium.com/unsubscribe>")
+        .unwrap()
+    }
+
+    #[test]
+    fn html_only_message_is_a_single_part() {
+        let message = Message {
+            text_body: None,
+            ..sample_message()
+        };
+
+        let raw = String::from_utf8(
+            build_lettre_message(&message).unwrap().formatted(),
+        )
+        .unwrap();
+
+        assert!(raw.contains("Content-Type: text/html"));
+        assert!(!raw.contains("multipart/"));
+    }
+
+    #[test]
+    fn text_alternative_and_attachments_build_nested_multiparts() {
+        let message = sample_message()
+            .with_attachment(MessageAttachment {
+                filename: "invoice.pdf".into(),
+                content_type: "application/pdf".into(),
+                content: b"%PDF-1.4".to_vec(),
+            })
+            .unwrap();
+
+        let email = build_lettre_message(&message).unwrap();
+        let raw = String::from_utf8(email.formatted()).unwrap();
+
+        assert!(raw.contains("multipart/mixed"));
+        assert!(raw.contains("multipart/alternative"));
+        assert!(raw.contains("Content-Type: text/plain"));
+        assert!(raw.contains("Content-Type: text/html"));
+        assert!(raw.contains("filename=\"invoice.pdf\""));
+        assert!(raw
+            .contains("List-Unsubscribe: <https://mycelium.com/unsubscribe>"));
+        assert!(raw.contains("Reply-To: support@mycelium.com"));
+        assert!(raw.contains("one@mycelium.com, two@mycelium.com"));
+        assert!(raw.contains("Cc: cc@mycelium.com"));
+
+        // Bcc recipients get the message without appearing in it
+        assert!(!raw.contains("hidden@mycelium.com"));
+        assert_eq!(email.envelope().to().len(), 4);
+    }
 
     #[test]
     fn wrong_version_number_error_gets_starttls_hint() {
@@ -0,0 +1,408 @@
+use super::{profile::Profile, tenant::TenantMetaKey};
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// A declarative access policy
+///
+/// Policies protect routes with conditions evaluated against the request and
+/// the requester profile attributes. The request is allowed only when all
+/// rules pass. Policies without rules deny every request.
+///
+#[derive(
+    Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq, ToSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessPolicy {
+    /// The policy rules
+    ///
+    /// Rules are evaluated in the declaration order.
+    pub rules: Vec<AccessPolicyRule>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessPolicyRule {
+    /// The rule name
+    ///
+    /// Used to explain why a request was denied.
+    pub name: String,
+
+    /// The rule condition
+    ///
+    /// A boolean expression over the `request` and `profile` attributes, as
+    /// `request.hour >= 8 && "beta" in profile.permitFlags`.
+    pub condition: String,
+}
+
+/// The request attributes exposed to the policy conditions as `request`
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessPolicyRequestAttributes {
+    /// The request method, in uppercase
+    pub method: String,
+
+    /// The request path, including the service name
+    pub path: String,
+
+    /// The request path segments
+    ///
+    /// Gateway routes are matched by wildcards, so path parameters are
+    /// exposed by position, as `request.segments[2]`.
+    pub segments: Vec<String>,
+
+    /// The request query parameters
+    pub query: HashMap<String, String>,
+
+    /// The request headers, with lowercase names
+    ///
+    /// Credentials headers are not exposed.
+    pub headers: HashMap<String, String>,
+
+    /// The client IP address
+    pub client_ip: Option<String>,
+
+    /// The hour of the day (0-23) of the gateway local time
+    pub hour: u32,
+
+    /// The minute of the hour (0-59) of the gateway local time
+    pub minute: u32,
+
+    /// The day of the week, from 1 (Monday) to 7 (Sunday)
+    pub weekday: u32,
+}
+
+/// The profile attributes exposed to the policy conditions as `profile`
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessPolicyProfileAttributes {
+    /// The account id
+    pub account_id: Option<Uuid>,
+
+    /// The account type, as `staff`, `manager`, `subscription` or `user`
+    pub account_type: String,
+
+    /// The tenant of the request, from the `x-mycelium-tenant-id` header
+    pub tenant_id: Option<Uuid>,
+
+    /// The guest roles of the profile inside the tenant
+    ///
+    /// This and the other license attributes are `None` when no tenant is
+    /// given, so conditions reading them fail instead of seeing the licenses
+    /// of every tenant.
+    pub roles: Option<Vec<String>>,
+
+    /// The named permissions of the profile inside the tenant
+    pub permissions: Option<Vec<String>>,
+
+    /// The permit flags of the profile inside the tenant
+    pub permit_flags: Option<Vec<String>>,
+
+    /// The deny flags of the profile inside the tenant
+    pub deny_flags: Option<Vec<String>>,
+
+    /// The account metadata
+    pub account_meta: HashMap<String, String>,
+
+    /// The tenant metadata
+    pub tenant_meta: HashMap<String, String>,
+}
+
+impl AccessPolicyProfileAttributes {
+    /// Collect the policy attributes of a profile
+    ///
+    /// Roles, permissions and flags are collected from the licenses of the
+    /// given tenant only. Without a tenant they are left unset.
+    pub fn from_profile(profile: &Profile, tenant_id: Option<Uuid>) -> Self {
+        let licenses = profile
+            .licensed_resources
+            .as_ref()
+            .map(|resources| resources.to_licenses_vector())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|license| Some(license.tenant_id) == tenant_id)
+            .collect::<Vec<_>>();
+
+        let collect = |values: Vec<String>| {
+            let mut values = values;
+            values.sort();
+            values.dedup();
+            tenant_id.map(|_| values)
+        };
+
+        let account_type = if profile.is_staff {
+            "staff"
+        } else if profile.is_manager {
+            "manager"
+        } else if profile.is_subscription {
+            "subscription"
+        } else {
+            "user"
+        };
+
+        Self {
+            account_id: Some(profile.acc_id),
+            account_type: account_type.to_string(),
+            tenant_id,
+            roles: collect(
+                licenses
+                    .iter()
+                    .map(|license| license.role.clone())
+                    .collect(),
+            ),
+            permissions: collect(
+                licenses
+                    .iter()
+                    .flat_map(|license| {
+                        license.permissions.clone().unwrap_or_default()
+                    })
+                    .collect(),
+            ),
+            permit_flags: collect(
+                licenses
+                    .iter()
+                    .flat_map(|license| {
+                        license.permit_flags.clone().unwrap_or_default()
+                    })
+                    .collect(),
+            ),
+            deny_flags: collect(
+                licenses
+                    .iter()
+                    .flat_map(|license| {
+                        license.deny_flags.clone().unwrap_or_default()
+                    })
+                    .collect(),
+            ),
+            account_meta: profile
+                .meta
+                .as_ref()
+                .map(|meta| {
+                    meta.iter()
+                        .map(|(key, value)| (key.to_string(), value.to_owned()))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            tenant_meta: HashMap::new(),
+        }
+    }
+
+    /// Set the tenant metadata
+    pub fn with_tenant_meta(
+        self,
+        tenant_meta: HashMap<TenantMetaKey, String>,
+    ) -> Self {
+        Self {
+            tenant_meta: tenant_meta
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect(),
+            ..self
+        }
+    }
+}
+
+/// The attributes a policy is evaluated against
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessPolicyAttributes {
+    pub request: AccessPolicyRequestAttributes,
+    pub profile: AccessPolicyProfileAttributes,
+}
+
+/// The outcome of a single policy rule
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessPolicyRuleOutcome {
+    pub name: String,
+    pub condition: String,
+    pub passed: bool,
+
+    /// The evaluation error, if the condition could not be evaluated
+    ///
+    /// Rules with errors do not pass.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// The decision of a policy, with the outcome of every rule
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessPolicyDecision {
+    pub allowed: bool,
+
+    /// Why the request is allowed or denied
+    pub reason: String,
+
+    pub rules: Vec<AccessPolicyRuleOutcome>,
+}
+
+/// The explanation of a policy decision, as returned by dry-runs
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessPolicyExplanation {
+    pub policy: AccessPolicy,
+    pub attributes: AccessPolicyAttributes,
+    pub decision: AccessPolicyDecision,
+}
+
+/// Evaluate policy conditions
+///
+/// Implemented by the gateway with its expression language engine.
+pub trait AccessPolicyEvaluator: Send + Sync {
+    /// Evaluate a condition to a boolean, or return the evaluation error
+    fn evaluate_condition(
+        &self,
+        condition: &str,
+        attributes: &AccessPolicyAttributes,
+    ) -> Result<bool, String>;
+}
+
+impl AccessPolicy {
+    /// Evaluate all rules of the policy
+    ///
+    /// All rules are evaluated, even after a failure, so that the decision
+    /// explains each of them. The reason points to the first rule that did not
+    /// pass.
+    pub fn evaluate(
+        &self,
+        attributes: &AccessPolicyAttributes,
+        evaluator: &dyn AccessPolicyEvaluator,
+    ) -> AccessPolicyDecision {
+        let rules = self
+            .rules
+            .iter()
+            .map(|rule| {
+                let (passed, error) = match evaluator
+                    .evaluate_condition(&rule.condition, attributes)
+                {
+                    Ok(passed) => (passed, None),
+                    Err(err) => (false, Some(err)),
+                };
+
+                AccessPolicyRuleOutcome {
+                    name: rule.name.to_owned(),
+                    condition: rule.condition.to_owned(),
+                    passed,
+                    error,
+                }
+            })
+            .collect::<Vec<AccessPolicyRuleOutcome>>();
+
+        let reason = match rules.iter().find(|outcome| !outcome.passed) {
+            _ if rules.is_empty() => "Policy has no rules".to_string(),
+            Some(AccessPolicyRuleOutcome {
+                name,
+                error: Some(err),
+                ..
+            }) => format!("Rule '{name}' could not be evaluated: {err}"),
+            Some(outcome) => format!("Rule '{}' did not pass", outcome.name),
+            None => format!("All {} rules passed", rules.len()),
+        };
+
+        AccessPolicyDecision {
+            allowed: !rules.is_empty() && rules.iter().all(|i| i.passed),
+            reason,
+            rules,
+        }
+    }
+}
+
+// ? ---------------------------------------------------------------------------
+// ? TESTS
+// ? ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct StubEvaluator;
+
+    impl AccessPolicyEvaluator for StubEvaluator {
+        fn evaluate_condition(
+            &self,
+            condition: &str,
+            _: &AccessPolicyAttributes,
+        ) -> Result<bool, String> {
+            match condition {
+                "true" => Ok(true),
+                "false" => Ok(false),
+                other => Err(format!("Unknown condition: {other}")),
+            }
+        }
+    }
+
+    fn rule(name: &str, condition: &str) -> AccessPolicyRule {
+        AccessPolicyRule {
+            name: name.to_string(),
+            condition: condition.to_string(),
+        }
+    }
+
+    #[test]
+    fn policy_is_allowed_only_when_all_rules_pass() {
+        let attributes = AccessPolicyAttributes::default();
+
+        let decision = AccessPolicy {
+            rules: vec![rule("a", "true"), rule("b", "true")],
+        }
+        .evaluate(&attributes, &StubEvaluator);
+
+        assert!(decision.allowed);
+        assert_eq!(decision.reason, "All 2 rules passed");
+
+        let decision = AccessPolicy {
+            rules: vec![rule("a", "true"), rule("b", "false"), rule("c", "x")],
+        }
+        .evaluate(&attributes, &StubEvaluator);
+
+        assert!(!decision.allowed);
+        assert_eq!(decision.reason, "Rule 'b' did not pass");
+        assert_eq!(decision.rules.len(), 3);
+        assert_eq!(
+            decision.rules[2].error,
+            Some("Unknown condition: x".to_string())
+        );
+
+        let decision = AccessPolicy { rules: vec![] }
+            .evaluate(&attributes, &StubEvaluator);
+
+        assert!(!decision.allowed);
+        assert_eq!(decision.reason, "Policy has no rules");
+    }
+
+    #[test]
+    fn license_attributes_are_unset_without_tenant() {
+        let profile = Profile::default();
+
+        let attributes =
+            AccessPolicyProfileAttributes::from_profile(&profile, None);
+
+        assert_eq!(attributes.roles, None);
+        assert_eq!(attributes.permit_flags, None);
+        assert_eq!(attributes.deny_flags, None);
+
+        let attributes = AccessPolicyProfileAttributes::from_profile(
+            &profile,
+            Some(Uuid::new_v4()),
+        );
+
+        assert_eq!(attributes.roles, Some(vec![]));
+        assert_eq!(attributes.deny_flags, Some(vec![]));
+    }
+
+    #[test]
+    fn policy_is_deserialized_from_camel_case() {
+        let policy: AccessPolicy = serde_json::from_str(
+            r#"{"rules":[{"name":"office-hours","condition":"request.hour < 18"}]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            policy.rules,
+            vec![rule("office-hours", "request.hour < 18")]
+        );
+    }
+}
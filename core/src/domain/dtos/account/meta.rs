@@ -35,6 +35,16 @@ pub enum AccountMetaKey {
     /// The account holder's job title or role within their organization
     JobTitle,
 
+    /// The notification channels preferred by the account holder
+    ///
+    /// Stored JSON shape: `{ "channels": { "<kind>": ["<channel>", ...] } }`.
+    /// See `NotificationPreferences`.
+    NotificationPreferences,
+
+    /// The URL notifications are posted to when the webhook channel is
+    /// preferred
+    NotificationWebhookUrl,
+
     /// To specify any other meta key
     ///
     /// Specify any other meta key that is not listed here.
@@ -55,6 +65,12 @@ impl Display for AccountMetaKey {
                 write!(f, "emergency_contact_phone")
             }
             AccountMetaKey::JobTitle => write!(f, "job_title"),
+            AccountMetaKey::NotificationPreferences => {
+                write!(f, "notification_preferences")
+            }
+            AccountMetaKey::NotificationWebhookUrl => {
+                write!(f, "notification_webhook_url")
+            }
             AccountMetaKey::Custom(key) => write!(f, "custom:{}", key),
         }
     }
@@ -80,6 +96,12 @@ impl FromStr for AccountMetaKey {
                 Ok(AccountMetaKey::EmergencyContactPhone)
             }
             "job_title" => Ok(AccountMetaKey::JobTitle),
+            "notification_preferences" => {
+                Ok(AccountMetaKey::NotificationPreferences)
+            }
+            "notification_webhook_url" => {
+                Ok(AccountMetaKey::NotificationWebhookUrl)
+            }
             _ => Err(format!("Invalid key: {}", s)),
         }
     }
@@ -0,0 +1,82 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use uuid::Uuid;
+
+/// The version of the backup archive layout written by this release
+///
+/// Bumped whenever the framing, the records or their encoding change in a way
+/// older releases cannot read.
+pub const BACKUP_FORMAT_VERSION: u32 = 1;
+
+/// The database backend a backup was read from
+///
+/// Rows are archived with the values as the backend renders them, so the
+/// backend tells how to read them back.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum BackupBackend {
+    Postgres,
+    Sqlite,
+}
+
+impl Display for BackupBackend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Postgres => write!(f, "postgres"),
+            Self::Sqlite => write!(f, "sqlite"),
+        }
+    }
+}
+
+/// What a backup holds
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum BackupScope {
+    /// Every table of the instance
+    Instance,
+
+    /// The rows of a single tenant: the tenant, its accounts, users, roles,
+    /// memberships, settings and audit chain
+    Tenant(Uuid),
+}
+
+impl Display for BackupScope {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Instance => write!(f, "instance"),
+            Self::Tenant(id) => write!(f, "tenant {id}"),
+        }
+    }
+}
+
+/// The first record of a backup archive
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupHeader {
+    /// The archive layout version (`BACKUP_FORMAT_VERSION`)
+    pub format_version: u32,
+
+    /// The Mycelium release that wrote the archive
+    pub mycelium_version: String,
+
+    /// The backend the rows were read from
+    pub backend: BackupBackend,
+
+    pub scope: BackupScope,
+
+    pub created_at: DateTime<Utc>,
+}
+
+/// The rows of a table in a backup archive
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupTableSummary {
+    pub table: String,
+
+    pub rows: u64,
+
+    /// Hex-encoded SHA-256 of the archived rows of the table, in archive
+    /// order
+    pub sha256: String,
+}
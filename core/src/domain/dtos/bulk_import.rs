@@ -0,0 +1,553 @@
+use super::written_by::WrittenBy;
+
+use chrono::{DateTime, Local};
+use mycelium_base::utils::errors::{dto_err, MappedErrors};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// The maximum number of rows of a single import
+pub const BULK_IMPORT_MAX_ROWS: usize = 5_000;
+
+/// How long a job may run before it is claimed again
+///
+/// Jobs still running after this are assumed to belong to a dispatcher that
+/// stopped. Rows are applied idempotently, so running them again is safe.
+pub const BULK_IMPORT_STALE_AFTER_IN_SECS: i64 = 3_600;
+
+/// The separator of the list cells of CSV imports, as tags and flags
+const CSV_LIST_SEPARATOR: char = ';';
+
+/// The formats accepted by the bulk import
+#[derive(
+    Clone, Copy, Debug, Deserialize, Serialize, ToSchema, Eq, PartialEq,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum BulkImportFormat {
+    /// Comma separated values with a header line
+    ///
+    /// The columns are `email`, `role`, `accountName`, `tags`, `permitFlags`
+    /// and `denyFlags`. The items of the list columns are separated by `;`.
+    Csv,
+
+    /// A JSON array of rows
+    Json,
+}
+
+impl Display for BulkImportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BulkImportFormat::Csv => write!(f, "csv"),
+            BulkImportFormat::Json => write!(f, "json"),
+        }
+    }
+}
+
+impl FromStr for BulkImportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "csv" => Ok(BulkImportFormat::Csv),
+            "json" => Ok(BulkImportFormat::Json),
+            _ => Err(format!("Invalid bulk import format: {s}")),
+        }
+    }
+}
+
+/// A row of a bulk import
+///
+/// Each row invites an email to a guest role on a subscription account. The
+/// account is created when no subscription account of the tenant has the
+/// given name, and the tags are added to it.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkImportRow {
+    /// The email of the guest
+    pub email: String,
+
+    /// The guest role, by id, slug or name
+    pub role: String,
+
+    /// The name of the subscription account
+    #[serde(alias = "account_name")]
+    pub account_name: String,
+
+    /// The tags of the subscription account
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    /// The permit flags of the guest on the account
+    #[serde(default, alias = "permit_flags")]
+    pub permit_flags: Vec<String>,
+
+    /// The deny flags of the guest on the account
+    #[serde(default, alias = "deny_flags")]
+    pub deny_flags: Vec<String>,
+}
+
+/// A CSV line, before the list cells are split
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CsvRow {
+    email: String,
+    role: String,
+    #[serde(alias = "account_name")]
+    account_name: String,
+    #[serde(default)]
+    tags: Option<String>,
+    #[serde(default, alias = "permit_flags")]
+    permit_flags: Option<String>,
+    #[serde(default, alias = "deny_flags")]
+    deny_flags: Option<String>,
+}
+
+fn split_list_cell(cell: Option<String>) -> Vec<String> {
+    cell.unwrap_or_default()
+        .split(CSV_LIST_SEPARATOR)
+        .map(|item| item.to_string())
+        .collect()
+}
+
+fn normalize_list(items: Vec<String>) -> Vec<String> {
+    let mut normalized: Vec<String> = vec![];
+
+    for item in items.into_iter().map(|item| item.trim().to_string()) {
+        if !item.is_empty() && !normalized.contains(&item) {
+            normalized.push(item);
+        }
+    }
+
+    normalized
+}
+
+impl BulkImportRow {
+    /// Parse the rows of an import
+    ///
+    /// Rows are normalized while parsed: cells are trimmed, emails lowercased
+    /// and empty or repeated list items dropped. Malformed content fails the
+    /// whole parsing, with the offending line in the error message.
+    pub fn parse_many(
+        format: BulkImportFormat,
+        content: &str,
+    ) -> Result<Vec<Self>, MappedErrors> {
+        let rows: Vec<Self> = match format {
+            BulkImportFormat::Json => {
+                serde_json::from_str(content).map_err(|err| {
+                    dto_err(format!("Invalid JSON import: {err}"))
+                })?
+            }
+            BulkImportFormat::Csv => {
+                let mut reader = csv::ReaderBuilder::new()
+                    .trim(csv::Trim::All)
+                    .from_reader(content.as_bytes());
+
+                let mut rows = vec![];
+
+                for record in reader.deserialize::<CsvRow>() {
+                    let record = record.map_err(|err| {
+                        dto_err(format!("Invalid CSV import: {err}"))
+                    })?;
+
+                    rows.push(Self {
+                        email: record.email,
+                        role: record.role,
+                        account_name: record.account_name,
+                        tags: split_list_cell(record.tags),
+                        permit_flags: split_list_cell(record.permit_flags),
+                        deny_flags: split_list_cell(record.deny_flags),
+                    });
+                }
+
+                rows
+            }
+        };
+
+        if rows.is_empty() {
+            return dto_err("The import has no rows").as_error();
+        }
+
+        if rows.len() > BULK_IMPORT_MAX_ROWS {
+            return dto_err(format!(
+                "The import has {} rows, the maximum is {BULK_IMPORT_MAX_ROWS}",
+                rows.len()
+            ))
+            .as_error();
+        }
+
+        Ok(rows.into_iter().map(Self::normalized).collect())
+    }
+
+    fn normalized(self) -> Self {
+        Self {
+            email: self.email.trim().to_lowercase(),
+            role: self.role.trim().to_string(),
+            account_name: self.account_name.trim().to_string(),
+            tags: normalize_list(self.tags),
+            permit_flags: normalize_list(self.permit_flags),
+            deny_flags: normalize_list(self.deny_flags),
+        }
+    }
+}
+
+/// The status of a bulk import job
+#[derive(
+    Clone, Copy, Debug, Deserialize, Serialize, ToSchema, Eq, PartialEq,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum BulkImportStatus {
+    /// Waiting to be claimed by a dispatcher
+    Pending,
+
+    /// Claimed and running
+    Running,
+
+    /// Every row was processed. Rows may still have failed individually.
+    Done,
+
+    /// The job stopped before processing every row
+    Failed,
+}
+
+impl Display for BulkImportStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BulkImportStatus::Pending => write!(f, "pending"),
+            BulkImportStatus::Running => write!(f, "running"),
+            BulkImportStatus::Done => write!(f, "done"),
+            BulkImportStatus::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+impl FromStr for BulkImportStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(BulkImportStatus::Pending),
+            "running" => Ok(BulkImportStatus::Running),
+            "done" => Ok(BulkImportStatus::Done),
+            "failed" => Ok(BulkImportStatus::Failed),
+            _ => Err(format!("Invalid bulk import status: {s}")),
+        }
+    }
+}
+
+/// The outcome of a row of a bulk import
+#[derive(
+    Clone, Copy, Debug, Deserialize, Serialize, ToSchema, Eq, PartialEq,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum BulkImportRowStatus {
+    /// The row passed the validation. Only reported by dry runs.
+    Valid,
+
+    /// The row did not pass the validation and was skipped
+    Invalid,
+
+    /// The row was applied
+    Done,
+
+    /// The row was valid but could not be applied
+    Failed,
+}
+
+/// The result of a row of a bulk import
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkImportRowResult {
+    /// The position of the row in the import, starting from one
+    pub row: usize,
+
+    /// The email of the row
+    pub email: String,
+
+    /// The account name of the row
+    pub account_name: String,
+
+    /// The row outcome
+    pub status: BulkImportRowStatus,
+
+    /// The subscription account of the row, once found or created
+    pub account_id: Option<Uuid>,
+
+    /// The guest user of the row, once invited
+    pub guest_user_id: Option<Uuid>,
+
+    /// The account was created by this row
+    #[serde(default)]
+    pub account_created: bool,
+
+    /// The guest was invited by this row. Guests already invited to the role
+    /// on the account are not invited again.
+    #[serde(default)]
+    pub invited: bool,
+
+    /// Why the row is invalid or failed
+    pub message: Option<String>,
+}
+
+impl BulkImportRowResult {
+    pub fn new(row: usize, import_row: &BulkImportRow) -> Self {
+        Self {
+            row,
+            email: import_row.email.to_owned(),
+            account_name: import_row.account_name.to_owned(),
+            status: BulkImportRowStatus::Valid,
+            account_id: None,
+            guest_user_id: None,
+            account_created: false,
+            invited: false,
+            message: None,
+        }
+    }
+
+    pub fn with_status(
+        self,
+        status: BulkImportRowStatus,
+        message: Option<String>,
+    ) -> Self {
+        Self {
+            status,
+            message,
+            ..self
+        }
+    }
+}
+
+/// The counts of the row results of a bulk import
+#[derive(
+    Clone, Debug, Default, Deserialize, Serialize, ToSchema, Eq, PartialEq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkImportSummary {
+    pub total: usize,
+    pub valid: usize,
+    pub invalid: usize,
+    pub done: usize,
+    pub failed: usize,
+    pub accounts_created: usize,
+    pub invited: usize,
+}
+
+/// A bulk import job
+///
+/// Jobs are identified by the checksum of their tenant and normalized rows:
+/// uploading the same rows again returns the existing job instead of
+/// importing them twice.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkImport {
+    /// The job id
+    pub id: Uuid,
+
+    /// The tenant the rows are imported into
+    pub tenant_id: Uuid,
+
+    /// The SHA-256 of the tenant and the normalized rows
+    pub checksum: String,
+
+    /// The job status
+    pub status: BulkImportStatus,
+
+    /// The job only validated the rows. Dry runs are never stored.
+    #[serde(default)]
+    pub dry_run: bool,
+
+    /// The normalized rows
+    pub rows: Vec<BulkImportRow>,
+
+    /// The row results, in the order of the rows
+    #[serde(default)]
+    pub results: Vec<BulkImportRowResult>,
+
+    /// Who submitted the job
+    pub created_by: WrittenBy,
+
+    /// When the job was submitted
+    pub created_at: DateTime<Local>,
+
+    /// The dispatcher which claimed the job
+    pub claimed_by: Option<String>,
+
+    /// When the job was claimed
+    pub started_at: Option<DateTime<Local>>,
+
+    /// When the job stopped
+    pub finished_at: Option<DateTime<Local>>,
+}
+
+impl BulkImport {
+    /// Create a pending job
+    pub fn new(
+        tenant_id: Uuid,
+        rows: Vec<BulkImportRow>,
+        created_by: WrittenBy,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            tenant_id,
+            checksum: Self::checksum_of(tenant_id, &rows),
+            status: BulkImportStatus::Pending,
+            dry_run: false,
+            rows,
+            results: vec![],
+            created_by,
+            created_at: Local::now(),
+            claimed_by: None,
+            started_at: None,
+            finished_at: None,
+        }
+    }
+
+    /// Mark the job as claimed by a dispatcher
+    pub fn claimed(self, claimed_by: String) -> Self {
+        Self {
+            status: BulkImportStatus::Running,
+            claimed_by: Some(claimed_by),
+            started_at: Some(Local::now()),
+            ..self
+        }
+    }
+
+    /// Compute the checksum identifying the job
+    pub fn checksum_of(tenant_id: Uuid, rows: &[BulkImportRow]) -> String {
+        let mut hasher = Sha256::new();
+
+        hasher.update(tenant_id.as_bytes());
+        hasher.update(serde_json::to_vec(rows).unwrap_or_default());
+
+        hex::encode(hasher.finalize())
+    }
+
+    /// Count the row results
+    pub fn summary(&self) -> BulkImportSummary {
+        self.results.iter().fold(
+            BulkImportSummary {
+                total: self.rows.len(),
+                ..Default::default()
+            },
+            |mut summary, result| {
+                match result.status {
+                    BulkImportRowStatus::Valid => summary.valid += 1,
+                    BulkImportRowStatus::Invalid => summary.invalid += 1,
+                    BulkImportRowStatus::Done => summary.done += 1,
+                    BulkImportRowStatus::Failed => summary.failed += 1,
+                }
+
+                if result.account_created {
+                    summary.accounts_created += 1;
+                }
+
+                if result.invited {
+                    summary.invited += 1;
+                }
+
+                summary
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_and_json_imports_parse_to_the_same_rows() {
+        let csv = "email,role,accountName,tags,permitFlags,denyFlags\n\
+            Ana@Example.com , viewer, Lab A ,genomics; lab-a;genomics,,\n\
+            bob@example.com,editor,Lab B,,write:samples,\n";
+
+        let json = r#"[
+            {
+                "email": "ana@example.com",
+                "role": "viewer",
+                "accountName": "Lab A",
+                "tags": ["genomics", "lab-a"]
+            },
+            {
+                "email": "bob@example.com",
+                "role": "editor",
+                "account_name": "Lab B",
+                "permitFlags": ["write:samples"]
+            }
+        ]"#;
+
+        let from_csv =
+            BulkImportRow::parse_many(BulkImportFormat::Csv, csv).unwrap();
+        let from_json =
+            BulkImportRow::parse_many(BulkImportFormat::Json, json).unwrap();
+
+        assert_eq!(from_csv, from_json);
+        assert_eq!(from_csv[0].email, "ana@example.com");
+        assert_eq!(from_csv[0].tags, vec!["genomics", "lab-a"]);
+        assert!(from_csv[1].deny_flags.is_empty());
+    }
+
+    #[test]
+    fn malformed_and_empty_imports_are_rejected() {
+        assert!(BulkImportRow::parse_many(
+            BulkImportFormat::Csv,
+            "email,role\nana@example.com,viewer\n"
+        )
+        .is_err());
+
+        assert!(
+            BulkImportRow::parse_many(BulkImportFormat::Json, "[]").is_err()
+        );
+
+        assert!(
+            BulkImportRow::parse_many(BulkImportFormat::Json, "{}").is_err()
+        );
+    }
+
+    #[test]
+    fn checksum_depends_on_tenant_and_rows_only() {
+        let rows = BulkImportRow::parse_many(
+            BulkImportFormat::Json,
+            r#"[{"email": "ana@example.com", "role": "viewer", "accountName": "Lab A"}]"#,
+        )
+        .unwrap();
+
+        let tenant_id = Uuid::new_v4();
+
+        let first = BulkImport::new(
+            tenant_id,
+            rows.to_owned(),
+            WrittenBy::new_anemic(),
+        );
+        let second = BulkImport::new(
+            tenant_id,
+            rows.to_owned(),
+            WrittenBy::new_anemic(),
+        );
+        let other_tenant =
+            BulkImport::new(Uuid::new_v4(), rows, WrittenBy::new_anemic());
+
+        assert_ne!(first.id, second.id);
+        assert_eq!(first.checksum, second.checksum);
+        assert_ne!(first.checksum, other_tenant.checksum);
+    }
+
+    #[test]
+    fn bulk_import_status_round_trips_through_its_name() {
+        for status in [
+            BulkImportStatus::Pending,
+            BulkImportStatus::Running,
+            BulkImportStatus::Done,
+            BulkImportStatus::Failed,
+        ] {
+            assert_eq!(
+                BulkImportStatus::from_str(&status.to_string()),
+                Ok(status)
+            );
+        }
+    }
+}
@@ -0,0 +1,257 @@
+use super::{
+    account::Account, email::Email, profile::LicensedResource,
+    resource_audit_log::ResourceAuditLog, token::PublicConnectionStringInfo,
+    user::User,
+};
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use mycelium_base::utils::errors::{dto_err, MappedErrors};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::Sha256;
+use utoipa::ToSchema;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// The domain of the pseudonyms written in place of erased emails
+///
+/// The `.invalid` top level domain is reserved (RFC 2606), so a pseudonym can
+/// never be delivered to.
+const PSEUDONYM_DOMAIN: &str = "erased.invalid";
+
+/// Everything Mycelium holds about the owners of a personal account
+///
+/// Secrets are never part of the export: password hashes are blanked and TOTP
+/// secrets redacted by the `User` serialization, and connection strings are
+/// listed by their public metadata only.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DataSubjectExport {
+    /// When the export was generated
+    pub generated_at: DateTime<Utc>,
+
+    /// The users owning the account, with their TOTP state
+    pub users: Vec<User>,
+
+    /// The personal account, with its meta
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account: Option<Account>,
+
+    /// The guest memberships of the users on other accounts
+    pub guest_memberships: Vec<LicensedResource>,
+
+    /// The metadata of the connection strings of the account
+    pub connection_strings: Vec<PublicConnectionStringInfo>,
+
+    /// One page of the audit entries about the users or the account, or
+    /// performed by them, newest first
+    pub audit_entries: Vec<ResourceAuditLog>,
+
+    /// Cursor of the next page of audit entries; `None` on the last page
+    pub audit_next_cursor: Option<String>,
+}
+
+/// What an erasure pseudonymised
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DataSubjectErasure {
+    /// The number of audit rows pseudonymised
+    pub audit_rows: u64,
+
+    /// The number of webhook payload artifacts pseudonymised
+    pub webhook_artifacts: u64,
+}
+
+/// The pseudonym written in place of an erased email
+///
+/// The pseudonym is an HMAC-SHA256 of the lowercased email keyed by an
+/// instance secret (the system DEK), so every reference to the same person
+/// keeps pointing to the same (anonymous) subject after the erasure, while
+/// nobody without the key can confirm a guessed email against it.
+pub fn pseudonym_for(
+    email: &Email,
+    key: &[u8; 32],
+) -> Result<String, MappedErrors> {
+    let mut mac = match HmacSha256::new_from_slice(key) {
+        Ok(mac) => mac,
+        Err(err) => {
+            tracing::error!("Could not create HMAC: {err}");
+            return dto_err("Unable to derive the pseudonym").as_error();
+        }
+    };
+
+    mac.update(email.email().to_lowercase().as_bytes());
+
+    let digest = hex::encode(mac.finalize().into_bytes());
+
+    Ok(format!("erased-{}@{PSEUDONYM_DOMAIN}", &digest[..32]))
+}
+
+/// Replace every case insensitive occurrence of `subject` in `text`
+///
+/// Returns `None` when `text` does not mention `subject`.
+pub fn pseudonymise_text(
+    text: &str,
+    subject: &str,
+    pseudonym: &str,
+) -> Option<String> {
+    if subject.is_empty() {
+        return None;
+    }
+
+    //
+    // ASCII lowercasing keeps byte offsets, so the matches found on the
+    // lowercased copy are valid ranges of the original text.
+    //
+    let haystack = text.to_ascii_lowercase();
+    let needle = subject.to_ascii_lowercase();
+
+    if !haystack.contains(&needle) {
+        return None;
+    }
+
+    let mut replaced = String::with_capacity(text.len());
+    let mut last = 0;
+
+    for (start, _) in haystack.match_indices(&needle) {
+        replaced.push_str(&text[last..start]);
+        replaced.push_str(pseudonym);
+        last = start + needle.len();
+    }
+
+    replaced.push_str(&text[last..]);
+
+    Some(replaced)
+}
+
+/// Replace every case insensitive occurrence of `subject` in the strings of
+/// `value`, keys included
+///
+/// Returns whether anything was replaced.
+pub fn pseudonymise_json(
+    value: &mut Value,
+    subject: &str,
+    pseudonym: &str,
+) -> bool {
+    match value {
+        Value::String(text) => {
+            match pseudonymise_text(text, subject, pseudonym) {
+                Some(replaced) => {
+                    *text = replaced;
+                    true
+                }
+                None => false,
+            }
+        }
+        Value::Array(items) => {
+            let mut changed = false;
+
+            for item in items.iter_mut() {
+                changed |= pseudonymise_json(item, subject, pseudonym);
+            }
+
+            changed
+        }
+        Value::Object(map) => {
+            let mut changed = false;
+
+            for (key, mut item) in std::mem::take(map) {
+                changed |= pseudonymise_json(&mut item, subject, pseudonym);
+
+                let key = match pseudonymise_text(&key, subject, pseudonym) {
+                    Some(replaced) => {
+                        changed = true;
+                        replaced
+                    }
+                    None => key,
+                };
+
+                map.insert(key, item);
+            }
+
+            changed
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const KEY: [u8; 32] = [7; 32];
+
+    #[test]
+    fn pseudonym_is_stable_and_case_insensitive() {
+        let lower = Email::from_string("jane@example.com".to_string()).unwrap();
+        let upper = Email::from_string("Jane@Example.com".to_string()).unwrap();
+
+        let pseudonym = pseudonym_for(&lower, &KEY).unwrap();
+
+        assert_eq!(pseudonym, pseudonym_for(&upper, &KEY).unwrap());
+        assert!(pseudonym.starts_with("erased-"));
+        assert!(pseudonym.ends_with("@erased.invalid"));
+        assert!(!pseudonym.contains("jane"));
+    }
+
+    #[test]
+    fn pseudonym_is_not_derivable_without_the_key() {
+        use sha2::Digest;
+
+        let email = Email::from_string("jane@example.com".to_string()).unwrap();
+        let pseudonym = pseudonym_for(&email, &KEY).unwrap();
+
+        //
+        // Neither another key nor an unkeyed digest of the email lead to the
+        // same pseudonym, so guessing the email is not enough to confirm it.
+        //
+        assert_ne!(pseudonym, pseudonym_for(&email, &[8; 32]).unwrap());
+
+        let unkeyed = hex::encode(Sha256::digest(b"jane@example.com"));
+
+        assert!(!pseudonym.contains(&unkeyed[..16]));
+        assert!(!pseudonym.contains(&unkeyed[unkeyed.len() - 16..]));
+    }
+
+    #[test]
+    fn pseudonymise_text_replaces_every_occurrence() {
+        assert_eq!(
+            pseudonymise_text(
+                "from JANE@example.com to jane@example.com",
+                "jane@example.com",
+                "x",
+            ),
+            Some("from x to x".to_string())
+        );
+
+        assert_eq!(
+            pseudonymise_text("nothing here", "jane@example.com", "x"),
+            None
+        );
+    }
+
+    #[test]
+    fn pseudonymise_json_walks_nested_values_and_keys() {
+        let mut value = json!({
+            "action": "invite",
+            "guests": ["jane@example.com", "john@example.com"],
+            "byEmail": { "jane@example.com": { "note": "Jane@example.com" } },
+            "count": 2,
+        });
+
+        assert!(pseudonymise_json(&mut value, "jane@example.com", "x"));
+        assert_eq!(
+            value,
+            json!({
+                "action": "invite",
+                "guests": ["x", "john@example.com"],
+                "byEmail": { "x": { "note": "x" } },
+                "count": 2,
+            })
+        );
+
+        assert!(!pseudonymise_json(&mut value, "jane@example.com", "x"));
+    }
+}
@@ -0,0 +1,274 @@
+use super::email::Email;
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Domain of the mailbox simulator handled by the local email transport
+///
+/// Messages the local transport delivers to `bounce@`, `complaint@` or
+/// `delivered@` this domain produce the matching delivery events, so bounce
+/// handling can be exercised without an email provider.
+pub const DELIVERY_SIMULATOR_DOMAIN: &str = "simulator.mycelium.local";
+
+/// Delivery status of a message that left the queue
+#[derive(
+    Clone, Copy, Debug, Deserialize, Serialize, ToSchema, Eq, PartialEq,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum MessageDeliveryStatus {
+    /// Accepted by the SMTP server
+    Sent,
+
+    /// Given up after the maximum number of attempts
+    Failed,
+
+    /// Reported as delivered by the provider
+    Delivered,
+
+    /// Reported as bounced by the provider
+    Bounced,
+
+    /// Reported as spam by the recipient
+    Complained,
+}
+
+impl Display for MessageDeliveryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MessageDeliveryStatus::Sent => write!(f, "sent"),
+            MessageDeliveryStatus::Failed => write!(f, "failed"),
+            MessageDeliveryStatus::Delivered => write!(f, "delivered"),
+            MessageDeliveryStatus::Bounced => write!(f, "bounced"),
+            MessageDeliveryStatus::Complained => write!(f, "complained"),
+        }
+    }
+}
+
+impl FromStr for MessageDeliveryStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sent" => Ok(MessageDeliveryStatus::Sent),
+            "failed" => Ok(MessageDeliveryStatus::Failed),
+            "delivered" => Ok(MessageDeliveryStatus::Delivered),
+            "bounced" => Ok(MessageDeliveryStatus::Bounced),
+            "complained" => Ok(MessageDeliveryStatus::Complained),
+            _ => Err(format!("Invalid message delivery status: {}", s)),
+        }
+    }
+}
+
+/// The delivery record of a message
+///
+/// Only the envelope is kept: message bodies may carry login codes and are
+/// deleted from the queue once sent.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageDelivery {
+    /// The id of the queued message, also sent as the `X-Mycelium-Message-Id`
+    /// header
+    pub id: Uuid,
+    pub recipient: String,
+    pub subject: String,
+    pub status: MessageDeliveryStatus,
+    pub detail: Option<String>,
+    pub created: DateTime<Local>,
+    pub updated: Option<DateTime<Local>>,
+}
+
+/// Kinds of events reported by email providers
+#[derive(
+    Clone, Copy, Debug, Deserialize, Serialize, ToSchema, Eq, PartialEq,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum DeliveryEventKind {
+    Delivered,
+    Bounced,
+    Complained,
+}
+
+/// A delivery, bounce or complaint reported by an email provider
+///
+/// This is the provider-agnostic shape accepted by the delivery events
+/// endpoint. Providers are expected to echo the `X-Mycelium-Message-Id`
+/// header as `messageId`; events without it only affect the suppression list.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryEvent {
+    pub event: DeliveryEventKind,
+    pub email: String,
+    pub message_id: Option<Uuid>,
+
+    /// Whether a bounce is permanent (hard) or transient (soft)
+    #[serde(default)]
+    pub permanent: bool,
+
+    /// Provider diagnostic, such as the SMTP response
+    pub detail: Option<String>,
+}
+
+impl DeliveryEvent {
+    /// Build the event the mailbox simulator reports for a recipient
+    ///
+    /// Returns `None` for addresses outside `DELIVERY_SIMULATOR_DOMAIN`.
+    pub fn simulated(message_id: Uuid, recipient: &Email) -> Option<Self> {
+        if recipient.domain.to_lowercase() != DELIVERY_SIMULATOR_DOMAIN {
+            return None;
+        }
+
+        let (event, permanent) =
+            match recipient.username.to_lowercase().as_str() {
+                "bounce" => (DeliveryEventKind::Bounced, true),
+                "complaint" => (DeliveryEventKind::Complained, false),
+                _ => (DeliveryEventKind::Delivered, false),
+            };
+
+        Some(Self {
+            event,
+            email: recipient.email(),
+            message_id: Some(message_id),
+            permanent,
+            detail: Some("Simulated by the local email transport".to_string()),
+        })
+    }
+
+    /// Whether the event should stop further emails to the address
+    pub fn suppression_reason(&self) -> Option<EmailSuppressionReason> {
+        match self.event {
+            DeliveryEventKind::Bounced if self.permanent => {
+                Some(EmailSuppressionReason::HardBounce)
+            }
+            DeliveryEventKind::Complained => {
+                Some(EmailSuppressionReason::Complaint)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Why an address is in the suppression list
+#[derive(
+    Clone, Copy, Debug, Deserialize, Serialize, ToSchema, Eq, PartialEq,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum EmailSuppressionReason {
+    HardBounce,
+    Complaint,
+}
+
+impl Display for EmailSuppressionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EmailSuppressionReason::HardBounce => write!(f, "hardBounce"),
+            EmailSuppressionReason::Complaint => write!(f, "complaint"),
+        }
+    }
+}
+
+impl FromStr for EmailSuppressionReason {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "hardBounce" => Ok(EmailSuppressionReason::HardBounce),
+            "complaint" => Ok(EmailSuppressionReason::Complaint),
+            _ => Err(format!("Invalid email suppression reason: {}", s)),
+        }
+    }
+}
+
+/// An address no email is sent to
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailSuppression {
+    /// Lowercase address
+    pub email: String,
+    pub reason: EmailSuppressionReason,
+    pub detail: Option<String>,
+    pub created: DateTime<Local>,
+}
+
+// ? --------------------------------------------------------------------------
+// ? TESTS
+// ? --------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_hard_bounces_and_complaints_suppress() {
+        let event = |event, permanent| DeliveryEvent {
+            event,
+            email: "user@example.com".to_string(),
+            message_id: None,
+            permanent,
+            detail: None,
+        };
+
+        assert_eq!(
+            event(DeliveryEventKind::Bounced, true).suppression_reason(),
+            Some(EmailSuppressionReason::HardBounce)
+        );
+        assert_eq!(
+            event(DeliveryEventKind::Bounced, false).suppression_reason(),
+            None
+        );
+        assert_eq!(
+            event(DeliveryEventKind::Complained, false).suppression_reason(),
+            Some(EmailSuppressionReason::Complaint)
+        );
+        assert_eq!(
+            event(DeliveryEventKind::Delivered, false).suppression_reason(),
+            None
+        );
+    }
+
+    #[test]
+    fn mailbox_simulator_maps_addresses_to_events() {
+        let message_id = Uuid::new_v4();
+        let simulated = |email: &str| {
+            DeliveryEvent::simulated(
+                message_id,
+                &Email::from_string(email.to_string()).unwrap(),
+            )
+        };
+
+        let bounce = simulated("bounce@simulator.mycelium.local").unwrap();
+        assert_eq!(bounce.event, DeliveryEventKind::Bounced);
+        assert!(bounce.permanent);
+        assert_eq!(bounce.message_id, Some(message_id));
+
+        assert_eq!(
+            simulated("complaint@simulator.mycelium.local")
+                .unwrap()
+                .event,
+            DeliveryEventKind::Complained
+        );
+        assert_eq!(
+            simulated("anyone@simulator.mycelium.local").unwrap().event,
+            DeliveryEventKind::Delivered
+        );
+        assert!(simulated("bounce@example.com").is_none());
+    }
+
+    #[test]
+    fn generic_events_deserialize_with_defaults() {
+        let event: DeliveryEvent = serde_json::from_value(serde_json::json!({
+            "event": "bounced",
+            "email": "user@example.com"
+        }))
+        .unwrap();
+
+        assert_eq!(event.event, DeliveryEventKind::Bounced);
+        assert!(!event.permanent);
+        assert!(event.message_id.is_none());
+    }
+}
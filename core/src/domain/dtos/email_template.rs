@@ -0,0 +1,65 @@
+use super::tenant::EmailTemplateKind;
+
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use utoipa::ToSchema;
+
+/// A notification email available in the file templates
+///
+/// Templates live under `templates/<locale>/email/<name>.jinja`, next to the
+/// `<name>.subject` file.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailTemplateInfo {
+    /// The template name, as `magic-link-request`
+    pub name: String,
+
+    /// The locales the template is available in
+    pub locales: Vec<String>,
+
+    /// If tenant owners may override the template
+    pub tenant_overridable: bool,
+
+    /// Parameters injected by the use-case sending the email
+    pub parameters: Vec<String>,
+}
+
+impl EmailTemplateInfo {
+    /// Parameters injected by the use-case sending the named email, with
+    /// placeholder values used on previews and test sends
+    ///
+    /// Tenant overridable emails share the samples of their
+    /// `EmailTemplateKind`. Unknown templates have no samples, so their
+    /// parameters should be supplied by the caller.
+    pub fn sample_parameters(name: &str) -> Vec<(&'static str, &'static str)> {
+        if let Ok(kind) = EmailTemplateKind::from_str(name) {
+            return kind.sample_parameters();
+        }
+
+        match name {
+            "activation-code" => vec![("verification_code", "123456")],
+            "create-user-account" => vec![("account_name", "ACME RESEARCH")],
+            _ => vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_parameters_cover_overridable_and_system_templates() {
+        assert_eq!(
+            EmailTemplateInfo::sample_parameters("activation-code"),
+            vec![("verification_code", "123456")]
+        );
+
+        assert_eq!(
+            EmailTemplateInfo::sample_parameters("magic-link-request"),
+            EmailTemplateKind::MagicLinkRequest.sample_parameters()
+        );
+
+        assert!(EmailTemplateInfo::sample_parameters("mfa-disable").is_empty());
+    }
+}
@@ -1,6 +1,7 @@
 use mycelium_base::utils::errors::{execution_err, MappedErrors};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
+use uuid::Uuid;
 
 /// ErrorCode is a struct that represents an error code.
 ///
@@ -114,6 +115,38 @@ impl ErrorCode {
         self
     }
 
+    // ? -----------------------------------------------------------------------
+    // ? PUBLIC STATIC METHODS
+    // ? -----------------------------------------------------------------------
+
+    /// Split a compiled code (e.g. `MYC00001`) into its prefix and number.
+    ///
+    /// Returns `None` for strings which are not compiled error codes, such as
+    /// the `none` code of errors raised without one.
+    pub fn parse_code(code: &str) -> Option<(String, i32)> {
+        let split = code.find(|c: char| c.is_ascii_digit())?;
+        let (prefix, number) = code.split_at(split);
+
+        ErrorCode::validate_prefix(prefix).ok()?;
+
+        if !number.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+
+        Some((prefix.to_string(), number.parse::<i32>().ok()?))
+    }
+
+    /// Stable UUID identifying an error code in the resource audit trail.
+    ///
+    /// Error codes are keyed by prefix and number instead of a UUID, so a
+    /// name-based UUID is derived from the compiled code (e.g. `MYC00001`).
+    pub fn audit_resource_id(prefix: &str, error_number: i32) -> Uuid {
+        Uuid::new_v3(
+            &Uuid::NAMESPACE_OID,
+            format!("{prefix}{error_number:05}").as_bytes(),
+        )
+    }
+
     // ? -----------------------------------------------------------------------
     // ? PRIVATE STATIC METHODS
     // ? -----------------------------------------------------------------------
@@ -154,6 +187,52 @@ impl ErrorCode {
     }
 }
 
+/// A translation of the message and details of an error code
+///
+/// Translations are keyed by locale (e.g. `pt-br`) and replace the default
+/// message in API responses for callers preferring that locale.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorCodeTranslation {
+    /// The prefix of the error.
+    pub prefix: String,
+
+    /// The code of the error.
+    pub error_number: i32,
+
+    /// The lowercase locale of the translation.
+    pub locale: String,
+
+    /// The translated message.
+    pub message: String,
+
+    /// The translated details.
+    pub details: Option<String>,
+}
+
+impl ErrorCodeTranslation {
+    pub fn new(
+        prefix: String,
+        error_number: i32,
+        locale: &str,
+        message: String,
+        details: Option<String>,
+    ) -> Self {
+        Self {
+            prefix,
+            error_number,
+            locale: Self::normalize_locale(locale),
+            message,
+            details,
+        }
+    }
+
+    /// Normalize a locale tag, so `pt_BR`, `pt-BR` and `pt-br` match.
+    pub fn normalize_locale(locale: &str) -> String {
+        locale.trim().replace('_', "-").to_lowercase()
+    }
+}
+
 // * ---------------------------------------------------------------------------
 // * TESTS
 // * ---------------------------------------------------------------------------
@@ -238,4 +317,27 @@ mod tests {
             assert_eq!(error_code.code, Some(format!("TEST{:05}", i)));
         }
     }
+
+    #[test]
+    fn test_parse_code() {
+        assert_eq!(
+            ErrorCode::parse_code("MYC00019"),
+            Some(("MYC".to_string(), 19))
+        );
+        assert_eq!(ErrorCode::parse_code("none"), None);
+        assert_eq!(ErrorCode::parse_code("MYC"), None);
+        assert_eq!(ErrorCode::parse_code("MYC0001A"), None);
+    }
+
+    #[test]
+    fn test_audit_resource_id_is_stable_per_code() {
+        assert_eq!(
+            ErrorCode::audit_resource_id("TEST", 1),
+            ErrorCode::audit_resource_id("TEST", 1)
+        );
+        assert_ne!(
+            ErrorCode::audit_resource_id("TEST", 1),
+            ErrorCode::audit_resource_id("TEST", 2)
+        );
+    }
 }
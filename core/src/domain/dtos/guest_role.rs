@@ -1,5 +1,8 @@
 use chrono::{DateTime, Local};
-use mycelium_base::dtos::Children;
+use mycelium_base::{
+    dtos::Children,
+    utils::errors::{dto_err, MappedErrors},
+};
 use serde::{Deserialize, Serialize};
 use slugify::slugify;
 use std::str::FromStr;
@@ -82,6 +85,22 @@ pub struct GuestRole {
     ///
     pub system: bool,
 
+    /// The tenant owning the role
+    ///
+    /// Tenant roles are managed by the tenant owners and managers, and are
+    /// only listed and granted inside the tenant. Roles without a tenant are
+    /// instance-wide.
+    #[serde(default)]
+    pub tenant_id: Option<Uuid>,
+
+    /// Named permissions granted by the role
+    ///
+    /// Declarative permissions as `invoices:approve` or `samples:delete`,
+    /// required by routes protected by permissions and injected into the
+    /// downstream profile.
+    #[serde(default)]
+    pub permissions: Vec<String>,
+
     /// Children roles represents guest roles that are children of the current
     /// role, and should be used to determine the allowed roles for the role
     /// owner guest other users.
@@ -105,8 +124,147 @@ impl GuestRole {
             permission,
             children,
             system,
+            tenant_id: None,
+            permissions: vec![],
             created: Local::now(),
             updated: None,
         }
     }
+
+    /// Scope the role to a tenant
+    pub fn with_tenant_id(self, tenant_id: Uuid) -> Self {
+        Self {
+            tenant_id: Some(tenant_id),
+            ..self
+        }
+    }
+
+    /// Replace the role named permissions
+    ///
+    /// Permissions are deduplicated and sorted. An error is returned if any of
+    /// them is not a valid permission name (see `is_valid_named_permission`).
+    pub fn with_permissions(
+        self,
+        permissions: Vec<String>,
+    ) -> Result<Self, MappedErrors> {
+        let mut permissions = permissions
+            .into_iter()
+            .map(|permission| permission.trim().to_string())
+            .collect::<Vec<String>>();
+
+        if let Some(invalid) = permissions
+            .iter()
+            .find(|permission| !is_valid_named_permission(permission))
+        {
+            return dto_err(format!(
+                "Invalid permission name: {invalid}. Expected as \
+                 invoices:approve"
+            ))
+            .with_exp_true()
+            .as_error();
+        }
+
+        permissions.sort();
+        permissions.dedup();
+
+        Ok(Self {
+            permissions,
+            ..self
+        })
+    }
+
+    /// Check if the role may be used inside the tenant
+    ///
+    /// Instance-wide roles are available to every tenant. Tenant roles are
+    /// available only to their own tenant.
+    pub fn is_available_to_tenant(&self, tenant_id: Option<Uuid>) -> bool {
+        match self.tenant_id {
+            None => true,
+            Some(role_tenant_id) => Some(role_tenant_id) == tenant_id,
+        }
+    }
+}
+
+/// Check if a string is a valid named permission
+///
+/// Named permissions are lowercase segments separated by colons, with at
+/// least a resource and an action, as `invoices:approve` or
+/// `lab:samples:delete`. Segments accept ASCII letters, digits, `-`, `_` and
+/// `.`.
+pub fn is_valid_named_permission(value: &str) -> bool {
+    let segments = value.split(':').collect::<Vec<&str>>();
+
+    segments.len() >= 2
+        && segments.iter().all(|segment| {
+            !segment.is_empty()
+                && segment.chars().all(|c| {
+                    c.is_ascii_lowercase()
+                        || c.is_ascii_digit()
+                        || matches!(c, '-' | '_' | '.')
+                })
+        })
+}
+
+// ? ---------------------------------------------------------------------------
+// ? TESTS
+// ? ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tenant_roles_are_only_available_to_their_tenant() {
+        let tenant_id = Uuid::new_v4();
+
+        let instance_role = GuestRole::new(
+            None,
+            "Auditor".to_string(),
+            None,
+            Permission::Read,
+            None,
+            false,
+        );
+
+        let tenant_role = instance_role.clone().with_tenant_id(tenant_id);
+
+        assert!(instance_role.is_available_to_tenant(None));
+        assert!(instance_role.is_available_to_tenant(Some(tenant_id)));
+        assert!(tenant_role.is_available_to_tenant(Some(tenant_id)));
+        assert!(!tenant_role.is_available_to_tenant(Some(Uuid::new_v4())));
+        assert!(!tenant_role.is_available_to_tenant(None));
+    }
+
+    #[test]
+    fn named_permissions_are_validated_and_normalized() {
+        let role = GuestRole::new(
+            None,
+            "Approver".to_string(),
+            None,
+            Permission::Read,
+            None,
+            false,
+        );
+
+        let role = role
+            .with_permissions(vec![
+                "invoices:approve".to_string(),
+                " samples:delete ".to_string(),
+                "invoices:approve".to_string(),
+            ])
+            .unwrap();
+
+        assert_eq!(
+            role.permissions,
+            vec!["invoices:approve".to_string(), "samples:delete".to_string()]
+        );
+
+        for invalid in ["invoices", "Invoices:approve", "invoices:", ":x"] {
+            assert!(!is_valid_named_permission(invalid));
+        }
+
+        assert!(role
+            .with_permissions(vec!["invoices approve".to_string()])
+            .is_err());
+    }
 }
@@ -1,6 +1,11 @@
-use super::{account::Account, email::Email, guest_role::GuestRole};
+use super::{
+    account::Account,
+    email::Email,
+    guest_role::{GuestRole, Permission},
+    written_by::WrittenBy,
+};
 
-use chrono::{DateTime, Local};
+use chrono::{DateTime, Duration, Local};
 use mycelium_base::{
     dtos::{Children, Parent},
     utils::errors::{dto_err, MappedErrors},
@@ -42,6 +47,20 @@ pub struct GuestUser {
     /// the account.
     ///
     pub was_verified: bool,
+
+    /// Who sent the invitation
+    #[serde(default)]
+    pub invited_by: Option<WrittenBy>,
+
+    /// When the invitation was last sent
+    #[serde(default)]
+    pub invitation_sent_at: Option<DateTime<Local>>,
+
+    /// When the invitation stops being acceptable
+    ///
+    /// Invitations without an expiration date never expire.
+    #[serde(default)]
+    pub invitation_expires_at: Option<DateTime<Local>>,
 }
 
 impl GuestUser {
@@ -78,6 +97,25 @@ impl GuestUser {
             updated: None,
             accounts,
             was_verified: false,
+            invited_by: None,
+            invitation_sent_at: None,
+            invitation_expires_at: None,
+        }
+    }
+
+    /// Set who sent the invitation and how long it may be accepted
+    pub fn with_invitation(
+        self,
+        invited_by: WrittenBy,
+        expires_in: Duration,
+    ) -> Self {
+        let sent_at = Local::now();
+
+        Self {
+            invited_by: Some(invited_by),
+            invitation_sent_at: Some(sent_at),
+            invitation_expires_at: Some(sent_at + expires_in),
+            ..self
         }
     }
 
@@ -98,6 +136,140 @@ impl GuestUser {
             updated,
             accounts,
             was_verified,
+            invited_by: None,
+            invitation_sent_at: None,
+            invitation_expires_at: None,
         }
     }
 }
+
+/// An invitation not accepted yet, with the account and role it grants
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingGuestInvitation {
+    /// The guest user id
+    pub guest_user_id: Uuid,
+
+    /// The invited email
+    pub email: Email,
+
+    /// The invitation date
+    pub invited_at: DateTime<Local>,
+
+    /// The account the guest was invited to
+    pub account_id: Uuid,
+
+    /// The name of the account the guest was invited to
+    pub account_name: String,
+
+    /// The tenant of the account, if any
+    pub tenant_id: Option<Uuid>,
+
+    /// The name of the granted role
+    pub role_name: String,
+
+    /// The permission of the granted role
+    pub role_permission: Permission,
+
+    /// The granted role id
+    pub guest_role_id: Uuid,
+
+    /// Who sent the invitation
+    pub invited_by: Option<WrittenBy>,
+
+    /// When the invitation was last sent
+    pub last_sent_at: Option<DateTime<Local>>,
+
+    /// When the invitation stops being acceptable
+    pub expires_at: Option<DateTime<Local>>,
+}
+
+impl PendingGuestInvitation {
+    /// Check if the invitation can no longer be accepted
+    pub fn is_expired(&self, now: DateTime<Local>) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+/// The payload of the guest invitation webhooks
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GuestInvitationEvent {
+    /// The guest user id, when known
+    pub guest_user_id: Option<Uuid>,
+
+    /// The invited email
+    pub email: Email,
+
+    /// The account the guest was invited to
+    pub account_id: Uuid,
+
+    /// The tenant of the account, if any
+    pub tenant_id: Option<Uuid>,
+
+    /// The granted role id
+    pub guest_role_id: Uuid,
+
+    /// When the invitation stops being acceptable
+    pub expires_at: Option<DateTime<Local>>,
+}
+
+impl GuestInvitationEvent {
+    /// Build the event of an invitation just sent
+    pub fn from_guest_user(
+        guest_user: &GuestUser,
+        account_id: Uuid,
+        tenant_id: Option<Uuid>,
+    ) -> Result<Self, MappedErrors> {
+        Ok(Self {
+            guest_user_id: guest_user.id,
+            email: guest_user.email.to_owned(),
+            account_id,
+            tenant_id,
+            guest_role_id: guest_user.guest_role_id()?,
+            expires_at: guest_user.invitation_expires_at,
+        })
+    }
+}
+
+impl From<&PendingGuestInvitation> for GuestInvitationEvent {
+    fn from(invitation: &PendingGuestInvitation) -> Self {
+        Self {
+            guest_user_id: Some(invitation.guest_user_id),
+            email: invitation.email.to_owned(),
+            account_id: invitation.account_id,
+            tenant_id: invitation.tenant_id,
+            guest_role_id: invitation.guest_role_id,
+            expires_at: invitation.expires_at,
+        }
+    }
+}
+
+/// A guest grant with an end date, with the account and role it grants
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpiringGuestGrant {
+    /// The guest user id
+    pub guest_user_id: Uuid,
+
+    /// The guest email
+    pub email: Email,
+
+    /// The granted role id
+    pub guest_role_id: Uuid,
+
+    /// The name of the granted role
+    pub role_name: String,
+
+    /// The account the grant applies to
+    pub account_id: Uuid,
+
+    /// The name of the account the grant applies to
+    pub account_name: String,
+
+    /// The tenant of the account, if any
+    pub tenant_id: Option<Uuid>,
+
+    /// The moment the grant stops licensing the account
+    pub valid_until: DateTime<Local>,
+}
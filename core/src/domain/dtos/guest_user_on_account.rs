@@ -1,4 +1,5 @@
 use chrono::{DateTime, Local};
+use mycelium_base::utils::errors::{dto_err, MappedErrors};
 use serde::{Deserialize, Serialize};
 use utoipa::{ToResponse, ToSchema};
 use uuid::Uuid;
@@ -22,4 +23,89 @@ pub struct GuestUserOnAccount {
 
     /// The deny flags
     pub deny_flags: Vec<String>,
+
+    /// The moment the grant starts licensing the account
+    ///
+    /// Grants without a start date are valid since their creation.
+    #[serde(default)]
+    pub valid_from: Option<DateTime<Local>>,
+
+    /// The moment the grant stops licensing the account
+    ///
+    /// Grants without an end date never expire. Expired grants are revoked by
+    /// the scheduler.
+    #[serde(default)]
+    pub valid_until: Option<DateTime<Local>>,
+}
+
+impl GuestUserOnAccount {
+    /// Check the validity window of a guest grant
+    ///
+    /// The window must end after it starts, and an end date must be in the
+    /// future at the given moment.
+    pub fn check_validity_window(
+        valid_from: Option<DateTime<Local>>,
+        valid_until: Option<DateTime<Local>>,
+        now: DateTime<Local>,
+    ) -> Result<(), MappedErrors> {
+        if let Some(valid_until) = valid_until {
+            if valid_until <= now {
+                return dto_err("The grant end date must be in the future")
+                    .with_exp_true()
+                    .as_error();
+            }
+
+            if let Some(valid_from) = valid_from {
+                if valid_until <= valid_from {
+                    return dto_err(
+                        "The grant end date must be after its start date",
+                    )
+                    .with_exp_true()
+                    .as_error();
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// ? ---------------------------------------------------------------------------
+// ? TESTS
+// ? ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn validity_window_must_end_in_the_future_after_its_start() {
+        let now = Local::now();
+
+        assert!(
+            GuestUserOnAccount::check_validity_window(None, None, now).is_ok()
+        );
+
+        assert!(GuestUserOnAccount::check_validity_window(
+            Some(now + Duration::days(1)),
+            Some(now + Duration::days(30)),
+            now,
+        )
+        .is_ok());
+
+        assert!(GuestUserOnAccount::check_validity_window(
+            None,
+            Some(now - Duration::days(1)),
+            now,
+        )
+        .is_err());
+
+        assert!(GuestUserOnAccount::check_validity_window(
+            Some(now + Duration::days(30)),
+            Some(now + Duration::days(1)),
+            now,
+        )
+        .is_err());
+    }
 }
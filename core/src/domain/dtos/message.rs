@@ -1,7 +1,9 @@
 use super::email::Email;
 
+use base64::{engine::general_purpose, Engine};
 use chrono::{DateTime, Local};
-use serde::{Deserialize, Serialize};
+use mycelium_base::utils::errors::{invalid_arg_err, MappedErrors};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::{
     fmt::{self, Display},
     str::FromStr,
@@ -9,6 +11,36 @@ use std::{
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+/// Largest total size, in bytes, of the attachments of a message
+///
+/// Messages wait in the queue (database or Redis) as JSON, so attachments are
+/// meant for small documents such as an invoice or an `.ics` invitation.
+pub const MAX_MESSAGE_ATTACHMENTS_SIZE: usize = 512 * 1024;
+
+/// Header carrying the id of the queued message
+///
+/// Email providers echo custom headers in their delivery webhooks, which is
+/// how bounces and complaints are matched to the message they refer to.
+pub const MESSAGE_ID_HEADER: &str = "X-Mycelium-Message-Id";
+
+/// Headers written by the message builder itself, which a custom header must
+/// not override.
+const RESERVED_HEADERS: [&str; 13] = [
+    "bcc",
+    "cc",
+    "content-disposition",
+    "content-transfer-encoding",
+    "content-type",
+    "date",
+    "from",
+    "message-id",
+    "mime-version",
+    "reply-to",
+    "sender",
+    "subject",
+    "to",
+];
+
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub enum FromEmail {
@@ -21,12 +53,209 @@ pub enum FromEmail {
 pub struct Message {
     // Addresses
     pub from: FromEmail,
-    pub to: Email,
-    pub cc: Option<Email>,
+
+    /// Recipients
+    ///
+    /// A single address is accepted as well, which is how messages queued
+    /// before recipient lists existed were stored.
+    #[serde(deserialize_with = "deserialize_addresses")]
+    pub to: Vec<Email>,
+
+    /// Carbon-copy recipients
+    #[serde(default, deserialize_with = "deserialize_addresses")]
+    pub cc: Vec<Email>,
+
+    /// Blind carbon-copy recipients
+    ///
+    /// They receive the message but are left out of its headers.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bcc: Vec<Email>,
+
+    /// Address replies should go to instead of `from`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply_to: Option<Email>,
+
+    /// Custom headers, such as `List-Unsubscribe`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub headers: Vec<MessageHeader>,
 
     // Message
     pub subject: String,
+
+    /// HTML body
     pub body: String,
+
+    /// Plain-text alternative of the HTML body
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_body: Option<String>,
+
+    /// Files attached to the message
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<MessageAttachment>,
+}
+
+impl Message {
+    /// Add a custom header
+    ///
+    /// Fails when the name is not a valid header name, when it would override
+    /// a header set from the message fields (e.g. `To` or `Content-Type`), or
+    /// when the value spans several lines.
+    pub fn with_header(
+        mut self,
+        name: &str,
+        value: &str,
+    ) -> Result<Self, MappedErrors> {
+        let is_valid_name = !name.is_empty()
+            && name
+                .bytes()
+                .all(|byte| byte.is_ascii_graphic() && byte != b':');
+
+        if !is_valid_name {
+            return invalid_arg_err(format!("Invalid header name: {name:?}"))
+                .as_error();
+        }
+
+        if RESERVED_HEADERS.contains(&name.to_lowercase().as_str()) {
+            return invalid_arg_err(format!(
+                "Header {name} is set from the message fields"
+            ))
+            .as_error();
+        }
+
+        if value.contains(['\r', '\n']) {
+            return invalid_arg_err(format!(
+                "Header {name} value must be a single line"
+            ))
+            .as_error();
+        }
+
+        self.headers.push(MessageHeader {
+            name: name.to_owned(),
+            value: value.to_owned(),
+        });
+
+        Ok(self)
+    }
+
+    /// Attach a file
+    ///
+    /// Fails when the file name or content type is malformed, or when the
+    /// attachments would exceed `MAX_MESSAGE_ATTACHMENTS_SIZE` altogether.
+    pub fn with_attachment(
+        mut self,
+        attachment: MessageAttachment,
+    ) -> Result<Self, MappedErrors> {
+        let is_valid_filename = !attachment.filename.trim().is_empty()
+            && !attachment
+                .filename
+                .contains(|c: char| c.is_control() || matches!(c, '/' | '\\'));
+
+        if !is_valid_filename {
+            return invalid_arg_err(format!(
+                "Invalid attachment file name: {:?}",
+                attachment.filename
+            ))
+            .as_error();
+        }
+
+        let is_valid_content_type = attachment
+            .content_type
+            .split_once('/')
+            .is_some_and(|(kind, subtype)| {
+                !kind.is_empty()
+                    && !subtype.is_empty()
+                    && !attachment
+                        .content_type
+                        .contains(|c: char| c.is_control())
+            });
+
+        if !is_valid_content_type {
+            return invalid_arg_err(format!(
+                "Invalid attachment content type: {:?}",
+                attachment.content_type
+            ))
+            .as_error();
+        }
+
+        let total_size = self
+            .attachments
+            .iter()
+            .map(|attachment| attachment.content.len())
+            .sum::<usize>()
+            + attachment.content.len();
+
+        if total_size > MAX_MESSAGE_ATTACHMENTS_SIZE {
+            return invalid_arg_err(format!(
+                "Attachments exceed {MAX_MESSAGE_ATTACHMENTS_SIZE} bytes"
+            ))
+            .as_error();
+        }
+
+        self.attachments.push(attachment);
+
+        Ok(self)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageHeader {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageAttachment {
+    /// File name shown to the recipient
+    pub filename: String,
+
+    /// MIME type, e.g. `application/pdf` or `text/calendar`
+    pub content_type: String,
+
+    /// File content, base64-encoded once serialized
+    #[serde(
+        serialize_with = "serialize_base64",
+        deserialize_with = "deserialize_base64"
+    )]
+    #[schema(value_type = String, format = Byte)]
+    pub content: Vec<u8>,
+}
+
+fn deserialize_addresses<'de, D>(
+    deserializer: D,
+) -> Result<Vec<Email>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Addresses {
+        One(Email),
+        Many(Vec<Email>),
+    }
+
+    Ok(match Option::<Addresses>::deserialize(deserializer)? {
+        None => vec![],
+        Some(Addresses::One(email)) => vec![email],
+        Some(Addresses::Many(emails)) => emails,
+    })
+}
+
+fn serialize_base64<S>(content: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&general_purpose::STANDARD.encode(content))
+}
+
+fn deserialize_base64<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    general_purpose::STANDARD
+        .decode(String::deserialize(deserializer)?)
+        .map_err(serde::de::Error::custom)
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
@@ -71,9 +300,20 @@ pub struct MessageSendingEvent {
 }
 
 impl MessageSendingEvent {
-    pub fn new(message: Message) -> Self {
+    pub fn new(mut message: Message) -> Self {
+        let id = Uuid::new_v4();
+
+        message.headers.retain(|header| {
+            !header.name.eq_ignore_ascii_case(MESSAGE_ID_HEADER)
+        });
+
+        message.headers.push(MessageHeader {
+            name: MESSAGE_ID_HEADER.to_owned(),
+            value: id.to_string(),
+        });
+
         Self {
-            id: Uuid::new_v4(),
+            id,
             message,
             created: Local::now(),
             attempted: None,
@@ -83,3 +323,119 @@ impl MessageSendingEvent {
         }
     }
 }
+
+// ? --------------------------------------------------------------------------
+// ? TESTS
+// ? --------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_message() -> Message {
+        Message {
+            from: FromEmail::NamedEmail(
+                "Mycelium <noreply@mycelium.com>".into(),
+            ),
+            to: vec![Email::from_string("user@mycelium.com".into()).unwrap()],
+            cc: vec![],
+            bcc: vec![],
+            reply_to: None,
+            headers: vec![],
+            subject: "Welcome".into(),
+            body: "<p>Hello</p>".into(),
+            text_body: None,
+            attachments: vec![],
+        }
+    }
+
+    #[test]
+    fn messages_queued_with_single_recipients_still_deserialize() {
+        let message: Message = serde_json::from_value(serde_json::json!({
+            "from": { "email": { "username": "noreply", "domain": "a.com" } },
+            "to": { "username": "user", "domain": "a.com" },
+            "cc": null,
+            "subject": "Welcome",
+            "body": "<p>Hello</p>"
+        }))
+        .unwrap();
+
+        assert_eq!(message.to.len(), 1);
+        assert!(message.cc.is_empty());
+        assert!(message.bcc.is_empty());
+        assert!(message.text_body.is_none());
+    }
+
+    #[test]
+    fn attachments_round_trip_as_base64() {
+        let message = sample_message()
+            .with_attachment(MessageAttachment {
+                filename: "invite.ics".into(),
+                content_type: "text/calendar".into(),
+                content: b"BEGIN:VCALENDAR".to_vec(),
+            })
+            .unwrap();
+
+        let value = serde_json::to_value(&message).unwrap();
+        assert_eq!(
+            value["attachments"][0]["content"],
+            general_purpose::STANDARD.encode(b"BEGIN:VCALENDAR")
+        );
+
+        let decoded: Message = serde_json::from_value(value).unwrap();
+        assert_eq!(decoded.attachments, message.attachments);
+    }
+
+    #[test]
+    fn oversized_or_malformed_attachments_are_rejected() {
+        let attachment = |filename: &str, content_type: &str, size: usize| {
+            MessageAttachment {
+                filename: filename.into(),
+                content_type: content_type.into(),
+                content: vec![0u8; size],
+            }
+        };
+
+        assert!(sample_message()
+            .with_attachment(attachment(
+                "big.pdf",
+                "application/pdf",
+                MAX_MESSAGE_ATTACHMENTS_SIZE + 1
+            ))
+            .is_err());
+        assert!(sample_message()
+            .with_attachment(attachment("../a.pdf", "application/pdf", 1))
+            .is_err());
+        assert!(sample_message()
+            .with_attachment(attachment("a.pdf", "pdf", 1))
+            .is_err());
+    }
+
+    #[test]
+    fn reserved_or_multiline_headers_are_rejected() {
+        assert!(sample_message()
+            .with_header("List-Unsubscribe", "<https://a.com/unsubscribe>")
+            .is_ok());
+        assert!(sample_message().with_header("Reply-To", "a@a.com").is_err());
+        assert!(sample_message().with_header("X Bad", "value").is_err());
+        assert!(sample_message()
+            .with_header("X-Tag", "value\r\nBcc: a@a.com")
+            .is_err());
+    }
+    #[test]
+    fn sending_events_carry_their_id_as_a_header() {
+        let event = MessageSendingEvent::new(
+            sample_message()
+                .with_header(MESSAGE_ID_HEADER, "forged")
+                .unwrap(),
+        );
+
+        assert_eq!(
+            event.message.headers,
+            vec![MessageHeader {
+                name: MESSAGE_ID_HEADER.into(),
+                value: event.id.to_string(),
+            }]
+        );
+    }
+}
@@ -1,7 +1,13 @@
+pub mod access_policy;
 pub mod account;
 pub mod account_type;
+pub mod backup;
+pub mod bulk_import;
 pub mod callback;
+pub mod data_subject;
 pub mod email;
+pub mod email_delivery;
+pub mod email_template;
 pub mod error_code;
 pub mod guest_role;
 pub mod guest_user;
@@ -13,11 +19,14 @@ pub mod identity_source;
 pub mod instance_settings;
 pub mod message;
 pub mod native_error_codes;
+pub mod notification;
 pub mod profile;
 pub mod related_accounts;
 pub mod resolved_http_secret;
 pub mod resource_audit_log;
 pub mod route;
+pub mod scheduled_job;
+pub mod scim;
 pub mod security_group;
 pub mod service;
 pub mod tag;
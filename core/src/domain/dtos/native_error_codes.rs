@@ -1,4 +1,4 @@
-use crate::domain::dtos::error_code::ErrorCode;
+use crate::domain::dtos::error_code::{ErrorCode, ErrorCodeTranslation};
 
 use enum_iterator::{all, Sequence};
 use mycelium_base::utils::errors::{execution_err, MappedErrors};
@@ -466,6 +466,140 @@ impl NativeErrorCodes {
         }
     }
 
+    /// Get the seeded translations of the error message
+    ///
+    /// Pairs of locale and message, matching the locales of the bundled email
+    /// templates. English is the default message itself.
+    pub fn translations(&self) -> [(&'static str, &'static str); 2] {
+        match self {
+            Self::MYC00001 => [
+                ("pt-br", "Erro de indisponibilidade do cliente de banco de dados"),
+                ("es", "Error de indisponibilidad del cliente de base de datos"),
+            ],
+            Self::MYC00002 => [
+                ("pt-br", "Usuário já registrado no Mycelium"),
+                ("es", "Usuario ya registrado en Mycelium"),
+            ],
+            Self::MYC00003 => [
+                ("pt-br", "Conta já registrada no Mycelium"),
+                ("es", "Cuenta ya registrada en Mycelium"),
+            ],
+            Self::MYC00004 => [
+                ("pt-br", "Não foi possível verificar o status do perfil"),
+                ("es", "No se pudo verificar el estado del perfil"),
+            ],
+            Self::MYC00005 => [
+                ("pt-br", "Ação restrita a usuários ativos"),
+                ("es", "Acción restringida a usuarios activos"),
+            ],
+            Self::MYC00006 => [
+                ("pt-br", "Ação restrita a usuários gerentes"),
+                ("es", "Acción restringida a usuarios administradores"),
+            ],
+            Self::MYC00007 => [
+                ("pt-br", "Falha na atualização"),
+                ("es", "Error en la actualización"),
+            ],
+            Self::MYC00008 => [
+                ("pt-br", "Token não encontrado ou expirado"),
+                ("es", "Token no encontrado o expirado"),
+            ],
+            Self::MYC00009 => [
+                ("pt-br", "Usuário não encontrado"),
+                ("es", "Usuario no encontrado"),
+            ],
+            Self::MYC00010 => [
+                ("pt-br", "Não foi possível notificar o usuário"),
+                ("es", "No se pudo notificar al usuario"),
+            ],
+            Self::MYC00011 => [
+                ("pt-br", "A nova senha é igual à anterior"),
+                ("es", "La nueva contraseña es igual a la anterior"),
+            ],
+            Self::MYC00012 => [
+                ("pt-br", "Não foi possível validar a senha"),
+                ("es", "No se pudo validar la contraseña"),
+            ],
+            Self::MYC00013 => [
+                ("pt-br", "Ação não autorizada"),
+                ("es", "Acción no autorizada"),
+            ],
+            Self::MYC00014 => [
+                ("pt-br", "O nome do tenant já existe"),
+                ("es", "El nombre del tenant ya existe"),
+            ],
+            Self::MYC00015 => [
+                ("pt-br", "O proprietário do tenant já existe"),
+                ("es", "El propietario del tenant ya existe"),
+            ],
+            Self::MYC00016 => [
+                ("pt-br", "Proprietário do tenant não encontrado"),
+                ("es", "Propietario del tenant no encontrado"),
+            ],
+            Self::MYC00017 => [
+                ("pt-br", "Convidado já existe"),
+                ("es", "El invitado ya existe"),
+            ],
+            Self::MYC00018 => [
+                ("pt-br", "Operação de usuário inválida"),
+                ("es", "Operación de usuario inválida"),
+            ],
+            Self::MYC00019 => [
+                ("pt-br", "Privilégios insuficientes"),
+                ("es", "Privilegios insuficientes"),
+            ],
+            Self::MYC00020 => [
+                ("pt-br", "Possível problema de segurança"),
+                ("es", "Posible problema de seguridad"),
+            ],
+            Self::MYC00021 => [
+                ("pt-br", "TOTP já habilitado"),
+                ("es", "TOTP ya habilitado"),
+            ],
+            Self::MYC00022 => [
+                ("pt-br", "TOTP desabilitado"),
+                ("es", "TOTP deshabilitado"),
+            ],
+            Self::MYC00023 => [
+                ("pt-br", "Token TOTP inválido"),
+                ("es", "Token TOTP inválido"),
+            ],
+            Self::MYC00030 => [
+                ("pt-br", "A string de conexão não informa a versão da chave HMAC (KVR)."),
+                ("es", "La cadena de conexión no indica la versión de la clave HMAC (KVR)."),
+            ],
+            Self::MYC00031 => [
+                ("pt-br", "A string de conexão referencia uma versão de chave HMAC desconhecida."),
+                ("es", "La cadena de conexión referencia una versión de clave HMAC desconocida."),
+            ],
+            Self::MYC00032 => [
+                ("pt-br", "A assinatura da string de conexão não confere."),
+                ("es", "La firma de la cadena de conexión no coincide."),
+            ],
+        }
+    }
+
+    /// Get the seeded translations of every native error code.
+    pub fn to_error_code_translations() -> Vec<ErrorCodeTranslation> {
+        Self::iter()
+            .flat_map(|item| {
+                let (prefix, error_number) = item.parts();
+
+                item.translations()
+                    .into_iter()
+                    .map(move |(locale, message)| {
+                        ErrorCodeTranslation::new(
+                            prefix.to_owned(),
+                            error_number,
+                            locale,
+                            message.to_string(),
+                            None,
+                        )
+                    })
+            })
+            .collect()
+    }
+
     /// Get the error code options.
     ///
     /// This method will check if all entries in the enum are in the correct.
@@ -598,4 +732,16 @@ mod tests {
             assert!(pattern.is_match(error_item));
         }
     }
+
+    #[test]
+    fn should_seed_translations_for_every_native_code() {
+        let translations = NativeErrorCodes::to_error_code_translations();
+
+        assert_eq!(translations.len(), NativeErrorCodes::to_vec().len() * 2);
+        assert!(translations.iter().any(|translation| {
+            translation.prefix == "MYC"
+                && translation.error_number == 19
+                && translation.locale == "pt-br"
+        }));
+    }
 }
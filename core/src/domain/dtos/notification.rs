@@ -0,0 +1,282 @@
+use super::{
+    account::AccountMetaKey,
+    telegram::{TelegramUser, TelegramUserId},
+};
+
+use mycelium_base::utils::errors::{invalid_arg_err, MappedErrors};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Channels a notification may be delivered through
+#[derive(
+    Clone, Copy, Debug, Deserialize, Serialize, ToSchema, Eq, Hash, PartialEq,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum NotificationChannel {
+    /// Email queued for SMTP delivery (the default channel)
+    Email,
+
+    /// Message sent by the tenant Telegram bot to the linked Telegram user
+    Telegram,
+
+    /// Text message sent through the configured SMS provider to the account
+    /// phone number
+    Sms,
+
+    /// JSON document posted to the account notification webhook URL
+    Webhook,
+}
+
+impl Display for NotificationChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NotificationChannel::Email => write!(f, "email"),
+            NotificationChannel::Telegram => write!(f, "telegram"),
+            NotificationChannel::Sms => write!(f, "sms"),
+            NotificationChannel::Webhook => write!(f, "webhook"),
+        }
+    }
+}
+
+/// Notifications users may route to other channels than email
+///
+/// Notifications not listed here (e.g. account activation codes, which prove
+/// the ownership of the email address) are always sent by email.
+#[derive(
+    Clone, Copy, Debug, Deserialize, Serialize, ToSchema, Eq, Hash, PartialEq,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum NotificationKind {
+    /// Passwordless login links
+    MagicLink,
+
+    /// Two-factor authentication activation and deactivation alerts
+    MfaAlert,
+
+    /// Guest invitations to accounts
+    Invitation,
+}
+
+impl NotificationKind {
+    /// Find the notification kind of an email template path, e.g.
+    /// `email/magic-link-request`
+    pub fn from_template_path(path: &str) -> Option<Self> {
+        match path {
+            "email/magic-link-request" => Some(NotificationKind::MagicLink),
+            "email/mfa-activation-start"
+            | "email/mfa-activation-validated"
+            | "email/mfa-disable" => Some(NotificationKind::MfaAlert),
+            "email/guest-to-subscription-account"
+            | "email/guest-invitation-reminder" => {
+                Some(NotificationKind::Invitation)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Display for NotificationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NotificationKind::MagicLink => write!(f, "magicLink"),
+            NotificationKind::MfaAlert => write!(f, "mfaAlert"),
+            NotificationKind::Invitation => write!(f, "invitation"),
+        }
+    }
+}
+
+/// The channels an account holder prefers for each notification kind
+///
+/// Stored as JSON under the `NotificationPreferences` account meta key.
+/// Kinds without preferences are sent by email.
+#[derive(
+    Clone, Debug, Default, Deserialize, Serialize, ToSchema, Eq, PartialEq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationPreferences {
+    pub channels: HashMap<NotificationKind, Vec<NotificationChannel>>,
+}
+
+impl NotificationPreferences {
+    /// Channels the notification kind should be delivered through
+    pub fn channels_for(
+        &self,
+        kind: NotificationKind,
+    ) -> Vec<NotificationChannel> {
+        match self.channels.get(&kind) {
+            Some(channels) if !channels.is_empty() => channels.to_owned(),
+            _ => vec![NotificationChannel::Email],
+        }
+    }
+
+    /// Fails when a kind lists no channel or the same channel twice
+    pub fn validate(&self) -> Result<(), MappedErrors> {
+        for (kind, channels) in &self.channels {
+            if channels.is_empty() {
+                return invalid_arg_err(format!(
+                    "At least one channel should be informed for {kind}"
+                ))
+                .as_error();
+            }
+
+            for (index, channel) in channels.iter().enumerate() {
+                if channels[..index].contains(channel) {
+                    return invalid_arg_err(format!(
+                        "Channel {channel} is repeated for {kind}"
+                    ))
+                    .as_error();
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The preferences and channel addresses of a notification recipient
+///
+/// Built from the meta of the recipient personal account.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationRecipient {
+    pub account_id: Uuid,
+    pub preferences: NotificationPreferences,
+    pub phone_number: Option<String>,
+    pub telegram_user_id: Option<TelegramUserId>,
+    pub webhook_url: Option<String>,
+}
+
+impl NotificationRecipient {
+    /// Read the recipient from the account meta
+    ///
+    /// Malformed values are ignored, so the affected channels fall back to
+    /// email instead of blocking the notification.
+    pub fn from_account_meta(
+        account_id: Uuid,
+        meta: &HashMap<AccountMetaKey, String>,
+    ) -> Self {
+        let preferences = meta
+            .get(&AccountMetaKey::NotificationPreferences)
+            .and_then(|value| {
+                serde_json::from_str::<NotificationPreferences>(value)
+                    .map_err(|err| {
+                        tracing::warn!(
+                            "Ignoring malformed notification preferences of \
+                             account {account_id}: {err}"
+                        )
+                    })
+                    .ok()
+            })
+            .unwrap_or_default();
+
+        let telegram_user_id = meta
+            .get(&AccountMetaKey::TelegramUser)
+            .and_then(|value| serde_json::from_str::<TelegramUser>(value).ok())
+            .map(|user| user.id);
+
+        Self {
+            account_id,
+            preferences,
+            phone_number: meta.get(&AccountMetaKey::PhoneNumber).cloned(),
+            telegram_user_id,
+            webhook_url: meta
+                .get(&AccountMetaKey::NotificationWebhookUrl)
+                .cloned(),
+        }
+    }
+}
+
+// ? --------------------------------------------------------------------------
+// ? TESTS
+// ? --------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kinds_without_preferences_are_sent_by_email() {
+        let preferences: NotificationPreferences =
+            serde_json::from_value(serde_json::json!({
+                "channels": { "magicLink": ["telegram", "email"] }
+            }))
+            .unwrap();
+
+        assert_eq!(
+            preferences.channels_for(NotificationKind::MagicLink),
+            vec![NotificationChannel::Telegram, NotificationChannel::Email]
+        );
+        assert_eq!(
+            preferences.channels_for(NotificationKind::MfaAlert),
+            vec![NotificationChannel::Email]
+        );
+    }
+
+    #[test]
+    fn empty_or_repeated_channels_are_rejected() {
+        let preferences =
+            |channels: Vec<NotificationChannel>| NotificationPreferences {
+                channels: HashMap::from([(
+                    NotificationKind::Invitation,
+                    channels,
+                )]),
+            };
+
+        assert!(preferences(vec![NotificationChannel::Sms])
+            .validate()
+            .is_ok());
+        assert!(preferences(vec![]).validate().is_err());
+        assert!(preferences(vec![
+            NotificationChannel::Sms,
+            NotificationChannel::Sms
+        ])
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn recipient_is_read_from_account_meta() {
+        let account_id = Uuid::new_v4();
+
+        let recipient = NotificationRecipient::from_account_meta(
+            account_id,
+            &HashMap::from([
+                (
+                    AccountMetaKey::NotificationPreferences,
+                    r#"{"channels":{"mfaAlert":["sms"]}}"#.to_string(),
+                ),
+                (AccountMetaKey::PhoneNumber, "+5511999999999".to_string()),
+                (
+                    AccountMetaKey::TelegramUser,
+                    r#"{"id":42,"username":null}"#.to_string(),
+                ),
+            ]),
+        );
+
+        assert_eq!(recipient.account_id, account_id);
+        assert_eq!(
+            recipient
+                .preferences
+                .channels_for(NotificationKind::MfaAlert),
+            vec![NotificationChannel::Sms]
+        );
+        assert_eq!(recipient.phone_number.as_deref(), Some("+5511999999999"));
+        assert_eq!(recipient.telegram_user_id, Some(TelegramUserId(42)));
+        assert!(recipient.webhook_url.is_none());
+
+        let recipient = NotificationRecipient::from_account_meta(
+            account_id,
+            &HashMap::from([(
+                AccountMetaKey::NotificationPreferences,
+                "not json".to_string(),
+            )]),
+        );
+
+        assert_eq!(recipient.preferences, NotificationPreferences::default());
+    }
+}
@@ -3,6 +3,7 @@ use crate::domain::dtos::{
 };
 
 use base64::{engine::general_purpose, Engine};
+use chrono::{DateTime, Local, TimeZone};
 use mycelium_base::utils::errors::{execution_err, MappedErrors};
 use reqwest::Url;
 use serde::{Deserialize, Serialize};
@@ -98,6 +99,29 @@ pub struct LicensedResource {
     ///
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deny_flags: Option<Vec<String>>,
+
+    /// Named permissions
+    ///
+    /// This is the list of named permissions granted by the guest role.
+    ///
+    /// Example:
+    ///
+    /// ```json
+    /// [
+    ///   "invoices:approve",
+    ///   "samples:delete",
+    /// ]
+    /// ```
+    ///
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Vec<String>>,
+
+    /// The end of the grant validity window
+    ///
+    /// The license is no longer listed once this instant passes. Unset for
+    /// grants without an end.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub valid_until: Option<DateTime<Local>>,
 }
 
 impl LicensedResource {
@@ -161,6 +185,14 @@ impl ToString for LicensedResource {
             result += &format!("&df={}", deny_flags.join(","));
         }
 
+        if let Some(permissions) = &self.permissions {
+            result += &format!("&ps={}", permissions.join(","));
+        }
+
+        if let Some(valid_until) = &self.valid_until {
+            result += &format!("&vu={}", valid_until.timestamp());
+        }
+
         result
     }
 }
@@ -303,6 +335,35 @@ impl FromStr for LicensedResource {
             None => None,
         };
 
+        //
+        // Try to extract optional named permissions
+        //
+        let permissions =
+            url.query_pairs()
+                .find(|(key, _)| key == "ps")
+                .map(|(_, value)| {
+                    value.split(',').map(|i| i.to_string()).collect()
+                });
+
+        //
+        // Try to extract the optional end of the validity window
+        //
+        let valid_until = match url
+            .query_pairs()
+            .find(|(key, _)| key == "vu")
+            .map(|(_, value)| value)
+        {
+            Some(value) => {
+                match value.parse::<i64>().ok().and_then(|timestamp| {
+                    Local.timestamp_opt(timestamp, 0).single()
+                }) {
+                    Some(valid_until) => Some(valid_until),
+                    None => return Err("Invalid validity end".to_string()),
+                }
+            }
+            None => None,
+        };
+
         Ok(Self {
             tenant_id: Uuid::from_str(tenant_id).unwrap(),
             acc_id: Uuid::from_str(account_id).unwrap(),
@@ -314,6 +375,8 @@ impl FromStr for LicensedResource {
             verified,
             permit_flags,
             deny_flags,
+            permissions,
+            valid_until,
         })
     }
 }
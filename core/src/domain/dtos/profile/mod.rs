@@ -17,6 +17,7 @@ use crate::domain::{
     dtos::{account::AccountMetaKey, email::Email},
 };
 
+use chrono::{DateTime, Local};
 use mycelium_base::utils::errors::{execution_err, MappedErrors};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
@@ -649,6 +650,96 @@ impl Profile {
         }
     }
 
+    /// Filter the licensed resources by named permissions
+    ///
+    /// This method filters resources whose guest role grants ALL the specified
+    /// named permissions, as `invoices:approve`.
+    #[tracing::instrument(name = "with_named_permissions", skip_all)]
+    pub fn with_named_permissions<T: ToString>(
+        &self,
+        permissions: Vec<T>,
+    ) -> Self {
+        let permissions: Vec<String> =
+            permissions.into_iter().map(|p| p.to_string()).collect();
+
+        //
+        // Filter the licensed resources by named permissions
+        //
+        let licensed_resources =
+            if let Some(resources) = self.licensed_resources.as_ref() {
+                let records: Vec<LicensedResource> = resources
+                    .to_licenses_vector()
+                    .iter()
+                    .filter(|i| {
+                        if let Some(granted) = &i.permissions {
+                            permissions.iter().all(|p| granted.contains(p))
+                        } else {
+                            false
+                        }
+                    })
+                    .map(|i| i.to_owned())
+                    .collect();
+
+                if records.is_empty() {
+                    None
+                } else {
+                    Some(LicensedResources::Records(records))
+                }
+            } else {
+                None
+            };
+
+        //
+        // Return the new profile
+        //
+        Self {
+            licensed_resources,
+            ..self
+                .update_state(
+                    "namedPermissions".to_string(),
+                    permissions.join(","),
+                )
+                .clone()
+        }
+    }
+
+    /// Get the named permissions granted by the licensed resources
+    ///
+    /// Returns the sorted and deduplicated union of the named permissions of
+    /// all licensed resources.
+    pub fn named_permissions(&self) -> Vec<String> {
+        let mut permissions = self
+            .licensed_resources
+            .as_ref()
+            .map(|resources| {
+                resources
+                    .to_licenses_vector()
+                    .into_iter()
+                    .flat_map(|i| i.permissions.unwrap_or_default())
+                    .collect::<Vec<String>>()
+            })
+            .unwrap_or_default();
+
+        permissions.sort();
+        permissions.dedup();
+        permissions
+    }
+
+    /// Get the earliest end of validity of the licensed resources
+    ///
+    /// Returns `None` when no license has an end of validity. Anything caching
+    /// the profile must not keep it past this instant, otherwise an expired
+    /// grant would still be served.
+    pub fn licenses_valid_until(&self) -> Option<DateTime<Local>> {
+        self.licensed_resources.as_ref().and_then(|resources| {
+            resources
+                .to_licenses_vector()
+                .into_iter()
+                .filter_map(|license| license.valid_until)
+                .min()
+        })
+    }
+
     /// Filter the licensed resources to include only the roles
     ///
     /// This method filters the licensed resources to include only the roles
@@ -931,7 +1022,7 @@ mod tests {
     use crate::domain::dtos::{
         guest_role::Permission, related_accounts::RelatedAccounts,
     };
-    use chrono::Local;
+    use chrono::{Local, TimeZone};
     use std::str::FromStr;
     use test_log::test;
     use uuid::Uuid;
@@ -969,6 +1060,8 @@ mod tests {
                     verified: true,
                     permit_flags: None,
                     deny_flags: None,
+                    permissions: None,
+                    valid_until: None,
                 },
                 LicensedResource {
                     acc_id: Uuid::new_v4(),
@@ -981,6 +1074,8 @@ mod tests {
                     verified: true,
                     permit_flags: None,
                     deny_flags: None,
+                    permissions: None,
+                    valid_until: None,
                 },
                 LicensedResource {
                     acc_id: Uuid::new_v4(),
@@ -993,6 +1088,8 @@ mod tests {
                     verified: true,
                     permit_flags: None,
                     deny_flags: None,
+                    permissions: None,
+                    valid_until: None,
                 },
             ])),
             tenants_ownership: Some(TenantsOwnership::Records(vec![
@@ -1000,6 +1097,8 @@ mod tests {
                     id: tenant_id,
                     name: "Tenant Name".to_string(),
                     since: Local::now(),
+                    parent_id: None,
+                    inherited_from: None,
                 },
             ])),
             filtering_state: None,
@@ -1019,6 +1118,11 @@ mod tests {
             verified: true,
             permit_flags: None,
             deny_flags: None,
+            permissions: Some(vec![
+                "invoices:approve".to_string(),
+                "samples:delete".to_string(),
+            ]),
+            valid_until: None,
         };
 
         let licensed_resource_string = licensed_resource.to_string();
@@ -1196,6 +1300,37 @@ mod tests {
             .is_err());
     }
 
+    #[test]
+    fn test_inherited_tenant_ownership() {
+        let parent_id = tenant_id();
+        let child_id = Uuid::new_v4();
+        let mut profile = profile();
+
+        let parent_ownership = profile
+            .tenants_ownership
+            .as_ref()
+            .unwrap()
+            .to_ownership_vector()
+            .into_iter()
+            .find(|i| i.id == parent_id)
+            .unwrap();
+
+        profile.tenants_ownership = Some(TenantsOwnership::Records(vec![
+            parent_ownership.to_owned(),
+            parent_ownership.inherit_to(child_id, "child".to_string()),
+        ]));
+
+        let profile_on_child = profile.on_tenant(child_id);
+
+        assert!(profile_on_child
+            .with_tenant_ownership_or_error(child_id)
+            .is_ok());
+
+        assert!(profile_on_child
+            .get_tenant_wide_permission_or_error(child_id, Permission::Write)
+            .is_ok());
+    }
+
     #[test]
     fn test_get_my_account_details() {
         let profile = profile();
@@ -1253,6 +1388,8 @@ mod tests {
                         "reports".to_string(),
                     ]),
                     deny_flags: None,
+                    permissions: None,
+                    valid_until: None,
                 },
                 LicensedResource {
                     acc_id: Uuid::new_v4(),
@@ -1268,6 +1405,8 @@ mod tests {
                         "settings".to_string(),
                         "admin_panel".to_string(),
                     ]),
+                    permissions: None,
+                    valid_until: None,
                 },
                 LicensedResource {
                     acc_id: Uuid::new_v4(),
@@ -1280,6 +1419,8 @@ mod tests {
                     verified: true,
                     permit_flags: None,
                     deny_flags: None,
+                    permissions: None,
+                    valid_until: None,
                 },
                 LicensedResource {
                     acc_id: Uuid::new_v4(),
@@ -1297,6 +1438,8 @@ mod tests {
                         "admin_panel".to_string(),
                     ]),
                     deny_flags: Some(vec!["dangerous_action".to_string()]),
+                    permissions: None,
+                    valid_until: None,
                 },
             ])),
             tenants_ownership: Some(TenantsOwnership::Records(vec![
@@ -1304,6 +1447,8 @@ mod tests {
                     id: tenant_id,
                     name: "Tenant Name".to_string(),
                     since: Local::now(),
+                    parent_id: None,
+                    inherited_from: None,
                 },
             ])),
             filtering_state: None,
@@ -1518,4 +1663,81 @@ mod tests {
                 .len()
         );
     }
+
+    #[test]
+    fn test_with_named_permissions() {
+        let mut profile = profile_with_flags();
+
+        let mut records =
+            profile.licensed_resources.unwrap().to_licenses_vector();
+
+        records[0].permissions = Some(vec![
+            "invoices:approve".to_string(),
+            "invoices:read".to_string(),
+        ]);
+        records[1].permissions = Some(vec!["invoices:read".to_string()]);
+
+        profile.licensed_resources = Some(LicensedResources::Records(records));
+
+        assert_eq!(
+            vec!["invoices:approve".to_string(), "invoices:read".to_string()],
+            profile.named_permissions()
+        );
+
+        let filtered = profile.with_named_permissions(vec!["invoices:read"]);
+
+        assert_eq!(
+            2,
+            filtered
+                .licensed_resources
+                .unwrap()
+                .to_licenses_vector()
+                .len()
+        );
+
+        let filtered = profile
+            .with_named_permissions(vec!["invoices:approve", "invoices:read"]);
+
+        assert_eq!(
+            1,
+            filtered
+                .licensed_resources
+                .unwrap()
+                .to_licenses_vector()
+                .len()
+        );
+
+        assert!(profile
+            .with_named_permissions(vec!["samples:delete"])
+            .licensed_resources
+            .is_none());
+    }
+
+    #[test]
+    fn test_licenses_valid_until() {
+        let mut profile = profile();
+
+        assert!(profile.licenses_valid_until().is_none());
+
+        let earliest = Local
+            .timestamp_opt(Local::now().timestamp() + 60, 0)
+            .unwrap();
+
+        let mut records =
+            profile.licensed_resources.unwrap().to_licenses_vector();
+
+        records[0].valid_until = Some(earliest + chrono::Duration::hours(1));
+        records[1].valid_until = Some(earliest);
+
+        let encoded = records[1].to_string();
+        assert!(encoded.contains(&format!("&vu={}", earliest.timestamp())));
+        assert_eq!(
+            Some(earliest),
+            LicensedResource::from_str(&encoded).unwrap().valid_until
+        );
+
+        profile.licensed_resources = Some(LicensedResources::Records(records));
+
+        assert_eq!(Some(earliest), profile.licenses_valid_until());
+    }
 }
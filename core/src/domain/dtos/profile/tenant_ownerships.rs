@@ -11,6 +11,7 @@ use uuid::Uuid;
 #[derive(
     Clone, Debug, Deserialize, Serialize, ToSchema, Eq, PartialEq, ToResponse,
 )]
+#[serde(rename_all = "camelCase")]
 pub struct TenantOwnership {
     /// The tenant ID that the profile has administration privileges
     pub id: Uuid,
@@ -20,6 +21,35 @@ pub struct TenantOwnership {
 
     /// The date and time the tenant was granted to the profile
     pub since: DateTime<Local>,
+
+    /// The parent tenant, if the tenant is a child tenant
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<Uuid>,
+
+    /// The owned ancestor tenant the ownership is inherited from
+    ///
+    /// Owners of a tenant own its descendants too. The ownership of a
+    /// directly owned tenant is not inherited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inherited_from: Option<Uuid>,
+}
+
+impl TenantOwnership {
+    /// The ownership of a child tenant, inherited from this one
+    pub fn inherit_to(&self, child_id: Uuid, child_name: String) -> Self {
+        Self {
+            id: child_id,
+            name: child_name,
+            since: self.since,
+            parent_id: Some(self.id),
+            inherited_from: self.inherited_from.or(Some(self.id)),
+        }
+    }
+
+    /// Whether the ownership is inherited from an ancestor tenant
+    pub fn is_inherited(&self) -> bool {
+        self.inherited_from.is_some()
+    }
 }
 
 #[derive(
@@ -36,12 +66,25 @@ impl ToString for TenantOwnership {
         let encoded_name =
             general_purpose::STANDARD.encode(self.name.as_bytes());
 
-        format!(
+        //
+        // Plus signs of offsets and base64 values would be decoded as spaces
+        //
+        let mut url = format!(
             "tid/{tenant_id}?since={since}&name={name}",
             tenant_id = self.id.to_string().replace("-", ""),
-            since = self.since.to_rfc3339(),
-            name = encoded_name,
-        )
+            since = self.since.to_rfc3339().replace('+', "%2B"),
+            name = encoded_name.replace('+', "%2B"),
+        );
+
+        if let Some(parent_id) = self.parent_id {
+            url.push_str(&format!("&parent={parent_id}"));
+        }
+
+        if let Some(inherited_from) = self.inherited_from {
+            url.push_str(&format!("&inheritedFrom={inherited_from}"));
+        }
+
+        url
     }
 }
 
@@ -115,10 +158,24 @@ impl FromStr for TenantOwnership {
                 }
             };
 
+        //
+        // Extract the optional hierarchy parameters
+        //
+        let parse_optional_uuid = |key: &str| -> Result<Option<Uuid>, String> {
+            match url.query_pairs().find(|(k, _)| k == key) {
+                Some((_, value)) => Uuid::from_str(&value)
+                    .map(Some)
+                    .map_err(|_| format!("Invalid tenant ownership {key}")),
+                None => Ok(None),
+            }
+        };
+
         Ok(Self {
             id: Uuid::from_str(tenant_id).unwrap(),
             name: name_decoded,
             since,
+            parent_id: parse_optional_uuid("parent")?,
+            inherited_from: parse_optional_uuid("inheritedFrom")?,
         })
     }
 }
@@ -134,3 +191,51 @@ impl TenantsOwnership {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inherited_ownerships_point_to_the_owned_ancestor() {
+        let root = TenantOwnership {
+            id: Uuid::new_v4(),
+            name: "Group".to_string(),
+            since: Local::now(),
+            parent_id: None,
+            inherited_from: None,
+        };
+
+        let child = root.inherit_to(Uuid::new_v4(), "Company".to_string());
+        let grandchild = child.inherit_to(Uuid::new_v4(), "Unit".to_string());
+
+        assert!(!root.is_inherited());
+        assert_eq!(child.parent_id, Some(root.id));
+        assert_eq!(grandchild.parent_id, Some(child.id));
+        assert_eq!(grandchild.inherited_from, Some(root.id));
+        assert_eq!(grandchild.since, root.since);
+    }
+
+    #[test]
+    fn ownerships_keep_the_hierarchy_through_urls() {
+        let root = TenantOwnership {
+            id: Uuid::new_v4(),
+            name: "Group".to_string(),
+            since: Local::now(),
+            parent_id: None,
+            inherited_from: None,
+        };
+
+        let child = root.inherit_to(Uuid::new_v4(), "Company".to_string());
+
+        for ownership in [root, child] {
+            let parsed =
+                TenantOwnership::from_str(&ownership.to_string()).unwrap();
+
+            assert_eq!(parsed.id, ownership.id);
+            assert_eq!(parsed.name, ownership.name);
+            assert_eq!(parsed.parent_id, ownership.parent_id);
+            assert_eq!(parsed.inherited_from, ownership.inherited_from);
+        }
+    }
+}
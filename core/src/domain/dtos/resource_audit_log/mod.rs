@@ -1,9 +1,29 @@
 mod new_resource_audit_log_event;
+mod resource_audit_archive;
+mod resource_audit_chain_link;
+mod resource_audit_chain_report;
+mod resource_audit_checkpoint;
 mod resource_audit_event_kind;
+mod resource_audit_export_format;
 mod resource_audit_log;
+mod resource_audit_log_cursor;
+mod resource_audit_log_filter;
+mod resource_audit_redaction;
 mod resource_audit_resource_type;
+mod resource_audit_retention_policy;
+mod resource_audit_retention_report;
 
 pub use new_resource_audit_log_event::*;
+pub use resource_audit_archive::*;
+pub use resource_audit_chain_link::*;
+pub use resource_audit_chain_report::*;
+pub use resource_audit_checkpoint::*;
 pub use resource_audit_event_kind::*;
+pub use resource_audit_export_format::*;
 pub use resource_audit_log::*;
+pub use resource_audit_log_cursor::*;
+pub use resource_audit_log_filter::*;
+pub use resource_audit_redaction::*;
 pub use resource_audit_resource_type::*;
+pub use resource_audit_retention_policy::*;
+pub use resource_audit_retention_report::*;
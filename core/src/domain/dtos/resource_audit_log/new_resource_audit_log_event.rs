@@ -7,12 +7,15 @@
 // dispatcher performs the actual insert.
 // ? ---------------------------------------------------------------------------
 
+use super::resource_audit_chain_link::ResourceAuditChainLink;
 use super::resource_audit_event_kind::ResourceAuditEventKind;
 use super::resource_audit_resource_type::ResourceAuditResourceType;
 use crate::domain::dtos::written_by::WrittenBy;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
@@ -42,6 +45,87 @@ pub struct NewResourceAuditLogEvent {
     pub created_at: DateTime<Utc>,
 }
 
+impl NewResourceAuditLogEvent {
+    /// Deterministic encoding of the event as the `seq`-th row of its chain.
+    ///
+    /// Object keys are sorted at every nesting level and `created_at` is
+    /// reduced to whole microseconds, so the encoding survives a round trip
+    /// through either database backend (JSONB reorders keys, and both
+    /// backends store timestamps at microsecond precision at best).
+    pub fn canonical_encoding(&self, seq: i64) -> String {
+        let value = serde_json::json!({
+            "seq": seq,
+            "resourceType": self.resource_type,
+            "resourceId": self.resource_id,
+            "tenantId": self.tenant_id,
+            "event": self.event,
+            "performedBy": self.performed_by,
+            "metadata": self.metadata,
+            "createdAt": self.created_at.timestamp_micros(),
+        });
+
+        let mut encoded = String::new();
+        write_canonical_json(&value, &mut encoded);
+        encoded
+    }
+
+    /// Build the chain link of this event when appended as the `seq`-th row
+    /// after a row whose hash is `prev_hash`.
+    pub fn chain_link(
+        &self,
+        seq: i64,
+        prev_hash: &str,
+    ) -> ResourceAuditChainLink {
+        let mut hasher = Sha256::new();
+        hasher.update(prev_hash.as_bytes());
+        hasher.update(b"\n");
+        hasher.update(self.canonical_encoding(seq).as_bytes());
+
+        ResourceAuditChainLink {
+            seq,
+            prev_hash: prev_hash.to_owned(),
+            hash: hex::encode(hasher.finalize()),
+        }
+    }
+}
+
+fn write_canonical_json(value: &Value, encoded: &mut String) {
+    match value {
+        Value::Object(map) => {
+            let mut keys = map.keys().collect::<Vec<_>>();
+            keys.sort();
+
+            encoded.push('{');
+
+            for (index, key) in keys.into_iter().enumerate() {
+                if index > 0 {
+                    encoded.push(',');
+                }
+
+                encoded.push_str(&Value::String(key.to_owned()).to_string());
+                encoded.push(':');
+                write_canonical_json(&map[key], encoded);
+            }
+
+            encoded.push('}');
+        }
+        Value::Array(items) => {
+            encoded.push('[');
+
+            for (index, item) in items.iter().enumerate() {
+                if index > 0 {
+                    encoded.push(',');
+                }
+
+                write_canonical_json(item, encoded);
+            }
+
+            encoded.push(']');
+        }
+        other => encoded.push_str(&other.to_string()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -70,4 +154,52 @@ mod tests {
         assert_eq!(parsed.metadata, event.metadata);
         assert_eq!(parsed.created_at, event.created_at);
     }
+
+    fn sample_event() -> NewResourceAuditLogEvent {
+        NewResourceAuditLogEvent {
+            resource_type: ResourceAuditResourceType::Account,
+            resource_id: Uuid::from_u128(1),
+            tenant_id: Some(Uuid::from_u128(2)),
+            event: ResourceAuditEventKind::Created,
+            performed_by: WrittenBy::new_from_account(Uuid::from_u128(3)),
+            metadata: serde_json::json!({ "b": 1, "a": { "d": 2, "c": 3 } }),
+            created_at: DateTime::<Utc>::UNIX_EPOCH,
+        }
+    }
+
+    #[test]
+    fn canonical_encoding_sorts_keys_at_every_level() {
+        let encoded = sample_event().canonical_encoding(1);
+
+        assert!(encoded.starts_with(r#"{"createdAt":0,"event":"created""#));
+        assert!(encoded.contains(r#""metadata":{"a":{"c":3,"d":2},"b":1}"#));
+    }
+
+    #[test]
+    fn canonical_encoding_ignores_sub_microsecond_precision() {
+        let event = sample_event();
+        let mut with_nanos = event.to_owned();
+        with_nanos.created_at =
+            event.created_at + chrono::Duration::nanoseconds(999);
+
+        assert_eq!(
+            event.canonical_encoding(1),
+            with_nanos.canonical_encoding(1)
+        );
+    }
+
+    #[test]
+    fn chain_link_depends_on_prev_hash_seq_and_content() {
+        let event = sample_event();
+        let link = event.chain_link(1, "00");
+
+        assert_eq!(link.hash.len(), 64);
+        assert_eq!(link, event.chain_link(1, "00"));
+        assert_ne!(link.hash, event.chain_link(1, "01").hash);
+        assert_ne!(link.hash, event.chain_link(2, "00").hash);
+
+        let mut modified = event.to_owned();
+        modified.metadata = serde_json::json!({ "b": 2 });
+        assert_ne!(link.hash, modified.chain_link(1, "00").hash);
+    }
 }
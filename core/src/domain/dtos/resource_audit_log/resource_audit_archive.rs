@@ -0,0 +1,178 @@
+// ? ---------------------------------------------------------------------------
+// ? ResourceAuditArchive
+//
+// Manifest of a range of `resource_audit_log` rows moved to the archive
+// store. The rows themselves live in a zstd-compressed JSON Lines file at
+// `location`; the manifest is stored both as a database record and as a JSON
+// sidecar next to that file, so an archive can be restored without the
+// database that produced it.
+//
+// An archive covers a contiguous `seq` prefix of its chain (`first_seq` up to
+// `last_seq`) plus the pre-chain rows created before `cutoff`. The manifest
+// is signed like a checkpoint -- HMAC-SHA256 keyed with the chain's DEK -- so
+// neither the range nor the file digest can be altered unnoticed, and
+// `last_hash` lets chain verification resume right after the archived rows.
+// ? ---------------------------------------------------------------------------
+
+use crate::domain::utils::SYSTEM_TENANT_ID;
+
+use chrono::{DateTime, SecondsFormat, SubsecRound, Utc};
+use hmac::{Hmac, Mac};
+use mycelium_base::utils::errors::{dto_err, MappedErrors};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceAuditArchive {
+    /// The archive's own identifier.
+    pub id: Uuid,
+
+    /// The archived chain; `None` for the system chain.
+    pub chain_tenant_id: Option<Uuid>,
+
+    /// The first archived chain `seq`; `None` if only pre-chain rows were
+    /// archived.
+    pub first_seq: Option<i64>,
+
+    /// The last archived chain `seq`.
+    pub last_seq: Option<i64>,
+
+    /// The `prev_hash` of the row at `first_seq`.
+    pub first_prev_hash: Option<String>,
+
+    /// The hash of the row at `last_seq`.
+    pub last_hash: Option<String>,
+
+    /// Every archived row was created before this moment.
+    pub cutoff: DateTime<Utc>,
+
+    /// Number of rows in the archive file.
+    pub row_count: i64,
+
+    /// Key of the archive file inside the archive store.
+    pub location: String,
+
+    /// Hex-encoded SHA-256 of the (compressed) archive file.
+    pub sha256: String,
+
+    /// Hex-encoded HMAC-SHA256 over the fields above, keyed with the chain's
+    /// DEK.
+    pub signature: String,
+
+    /// The moment the archive was written.
+    pub created_at: DateTime<Utc>,
+}
+
+impl ResourceAuditArchive {
+    /// Sign the manifest, replacing any previous signature. Timestamps are
+    /// truncated to microseconds first -- the precision every backend stores
+    /// -- so the signature still verifies once read back from the database.
+    pub fn signed(mut self, dek: &[u8; 32]) -> Result<Self, MappedErrors> {
+        self.cutoff = self.cutoff.trunc_subsecs(6);
+        self.created_at = self.created_at.trunc_subsecs(6);
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(dek).map_err(|err| {
+            dto_err(format!("Unable to sign audit archive: {err}"))
+        })?;
+
+        mac.update(self.signing_payload().as_bytes());
+        self.signature = hex::encode(mac.finalize().into_bytes());
+
+        Ok(self)
+    }
+
+    /// Check the manifest's signature against the chain's DEK.
+    pub fn has_valid_signature(&self, dek: &[u8; 32]) -> bool {
+        let Ok(expected) = hex::decode(self.signature.as_bytes()) else {
+            return false;
+        };
+
+        let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(dek) else {
+            return false;
+        };
+
+        mac.update(self.signing_payload().as_bytes());
+        mac.verify_slice(&expected).is_ok()
+    }
+
+    fn signing_payload(&self) -> String {
+        fn or_empty<T: ToString>(value: &Option<T>) -> String {
+            value.as_ref().map(ToString::to_string).unwrap_or_default()
+        }
+
+        format!(
+            "resource_audit_archive:v1\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}",
+            self.id,
+            self.chain_tenant_id.unwrap_or(SYSTEM_TENANT_ID),
+            or_empty(&self.first_seq),
+            or_empty(&self.last_seq),
+            or_empty(&self.first_prev_hash),
+            or_empty(&self.last_hash),
+            self.cutoff.to_rfc3339_opts(SecondsFormat::Micros, true),
+            self.row_count,
+            self.location,
+            self.sha256,
+            self.created_at.to_rfc3339_opts(SecondsFormat::Micros, true),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(dek: &[u8; 32]) -> ResourceAuditArchive {
+        ResourceAuditArchive {
+            id: Uuid::new_v4(),
+            chain_tenant_id: Some(Uuid::new_v4()),
+            first_seq: Some(1),
+            last_seq: Some(10),
+            first_prev_hash: Some("0".repeat(64)),
+            last_hash: Some("a".repeat(64)),
+            cutoff: Utc::now(),
+            row_count: 12,
+            location: "tenant/archive.jsonl.zst".to_string(),
+            sha256: "b".repeat(64),
+            signature: String::new(),
+            created_at: Utc::now(),
+        }
+        .signed(dek)
+        .unwrap()
+    }
+
+    #[test]
+    fn signature_verifies_with_the_signing_dek_only() {
+        let archive = sample(&[1u8; 32]);
+
+        assert!(archive.has_valid_signature(&[1u8; 32]));
+        assert!(!archive.has_valid_signature(&[2u8; 32]));
+    }
+
+    #[test]
+    fn altered_range_or_digest_invalidates_the_signature() {
+        let dek = [1u8; 32];
+
+        let mut widened = sample(&dek);
+        widened.last_seq = Some(11);
+        assert!(!widened.has_valid_signature(&dek));
+
+        let mut swapped = sample(&dek);
+        swapped.sha256 = "c".repeat(64);
+        assert!(!swapped.has_valid_signature(&dek));
+    }
+
+    #[test]
+    fn signature_survives_a_json_round_trip() {
+        let dek = [1u8; 32];
+        let archive = sample(&dek);
+
+        let decoded: ResourceAuditArchive =
+            serde_json::from_str(&serde_json::to_string(&archive).unwrap())
+                .unwrap();
+
+        assert!(decoded.has_valid_signature(&dek));
+    }
+}
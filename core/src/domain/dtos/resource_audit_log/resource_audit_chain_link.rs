@@ -0,0 +1,82 @@
+// ? ---------------------------------------------------------------------------
+// ? ResourceAuditChainLink
+//
+// The tamper-evidence part of a `resource_audit_log` row. Every row is
+// chained to its predecessor inside the same tenant chain (rows without a
+// tenant share the system chain, keyed by `SYSTEM_TENANT_ID`): `hash` is
+// SHA-256 over `prev_hash` and the row's canonical encoding (see
+// `NewResourceAuditLogEvent::canonical_encoding`). Editing, deleting, or
+// re-ordering any row breaks every link after it.
+// ? ---------------------------------------------------------------------------
+
+use super::resource_audit_checkpoint::RESOURCE_AUDIT_CHECKPOINT_INTERVAL;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// `prev_hash` of the first row of every chain.
+pub const RESOURCE_AUDIT_CHAIN_GENESIS_HASH: &str =
+    "0000000000000000000000000000000000000000000000000000000000000000";
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceAuditChainLink {
+    /// Position of the row inside its tenant chain, starting at 1.
+    pub seq: i64,
+
+    /// Hex-encoded hash of the previous row of the same chain, or
+    /// `RESOURCE_AUDIT_CHAIN_GENESIS_HASH` for the first row.
+    pub prev_hash: String,
+
+    /// Hex-encoded SHA-256 over `prev_hash` and the row's canonical
+    /// encoding.
+    pub hash: String,
+}
+
+impl ResourceAuditChainLink {
+    /// Whether a signed checkpoint should be taken at this link.
+    pub fn is_checkpoint_due(&self) -> bool {
+        self.seq > 0 && self.seq % RESOURCE_AUDIT_CHECKPOINT_INTERVAL == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn genesis_hash_has_sha256_hex_length() {
+        assert_eq!(RESOURCE_AUDIT_CHAIN_GENESIS_HASH.len(), 64);
+    }
+
+    #[test]
+    fn resource_audit_chain_link_serializes_fields_as_camel_case() {
+        let link = ResourceAuditChainLink {
+            seq: 1,
+            prev_hash: RESOURCE_AUDIT_CHAIN_GENESIS_HASH.to_string(),
+            hash: "ab".repeat(32),
+        };
+
+        let json = serde_json::to_value(&link).unwrap();
+        let object = json.as_object().unwrap();
+
+        assert!(object.contains_key("seq"));
+        assert!(object.contains_key("prevHash"));
+        assert!(object.contains_key("hash"));
+    }
+
+    #[test]
+    fn checkpoint_is_due_on_interval_multiples_only() {
+        let link = |seq| ResourceAuditChainLink {
+            seq,
+            prev_hash: String::new(),
+            hash: String::new(),
+        };
+
+        assert!(!link(1).is_checkpoint_due());
+        assert!(link(RESOURCE_AUDIT_CHECKPOINT_INTERVAL).is_checkpoint_due());
+        assert!(
+            !link(RESOURCE_AUDIT_CHECKPOINT_INTERVAL + 1).is_checkpoint_due()
+        );
+    }
+}
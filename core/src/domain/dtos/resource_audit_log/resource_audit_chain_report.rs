@@ -0,0 +1,141 @@
+// ? ---------------------------------------------------------------------------
+// ? ResourceAuditChainReport
+//
+// Outcome of walking one tenant chain of the resource audit log (see
+// `verify_resource_audit_chain`). An empty `issues` list means every chained
+// row recomputes to its stored hash, links to its predecessor without gaps,
+// and every checkpoint is validly signed and still matches the chain.
+// ? ---------------------------------------------------------------------------
+
+use super::resource_audit_chain_link::ResourceAuditChainLink;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase",
+    tag = "kind"
+)]
+pub enum ResourceAuditChainIssue {
+    /// One or more rows are missing between two chained rows.
+    Gap { expected_seq: i64, found_seq: i64 },
+
+    /// The row's `prev_hash` does not match its predecessor's hash.
+    BrokenLink {
+        seq: i64,
+        expected_prev_hash: String,
+        found_prev_hash: String,
+    },
+
+    /// The row's stored hash does not match its recomputed content hash.
+    Modified {
+        seq: i64,
+        row_id: Uuid,
+        stored_hash: String,
+        computed_hash: String,
+    },
+
+    /// A checkpoint's signature does not verify under the chain's DEK.
+    InvalidCheckpointSignature { seq: i64 },
+
+    /// A checkpoint's root hash differs from the chain row at its `seq`.
+    CheckpointMismatch {
+        seq: i64,
+        checkpoint_hash: String,
+        row_hash: String,
+    },
+
+    /// A checkpoint references a row that no longer exists (e.g. the chain
+    /// tail was truncated).
+    CheckpointWithoutRow { seq: i64 },
+
+    /// The chain's DEK could not be resolved, so checkpoint and archive
+    /// signatures were not checked.
+    CheckpointKeyUnavailable { reason: String },
+
+    /// The chain has checkpoints or archives but its tenant holds no DEK, so
+    /// their signatures cannot be checked.
+    CheckpointKeyMissing,
+
+    /// An archive manifest's signature does not verify under the chain's DEK.
+    InvalidArchiveSignature { archive_id: Uuid },
+
+    /// An archive does not pick up the chain where the previous archive (or
+    /// the genesis) left off.
+    ArchiveDiscontinuity {
+        archive_id: Uuid,
+        expected_seq: i64,
+        found_seq: i64,
+    },
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceAuditChainReport {
+    /// The verified chain; `None` for the system chain.
+    pub chain_tenant_id: Option<Uuid>,
+
+    /// Number of chained rows walked.
+    pub rows: u64,
+
+    /// Number of checkpoints checked.
+    pub checkpoints: u64,
+
+    /// Number of archives the walk resumed after. Archived rows are not
+    /// walked; the archive manifests are checked instead.
+    pub archives: u64,
+
+    /// Number of walked rows pseudonymised by a data subject erasure. Their
+    /// content is not re-hashed; only their links are checked.
+    pub redacted: u64,
+
+    /// The last link of the chain, if it has any rows.
+    pub head: Option<ResourceAuditChainLink>,
+
+    /// Every problem found while walking the chain.
+    pub issues: Vec<ResourceAuditChainIssue>,
+}
+
+impl ResourceAuditChainReport {
+    pub fn new(chain_tenant_id: Option<Uuid>) -> Self {
+        Self {
+            chain_tenant_id,
+            rows: 0,
+            checkpoints: 0,
+            archives: 0,
+            redacted: 0,
+            head: None,
+            issues: vec![],
+        }
+    }
+
+    pub fn is_intact(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resource_audit_chain_issue_is_tagged_by_kind() {
+        let json = serde_json::to_value(ResourceAuditChainIssue::Gap {
+            expected_seq: 2,
+            found_seq: 4,
+        })
+        .unwrap();
+
+        assert_eq!(json["kind"], "gap");
+        assert_eq!(json["expectedSeq"], 2);
+        assert_eq!(json["foundSeq"], 4);
+    }
+
+    #[test]
+    fn new_report_is_intact() {
+        assert!(ResourceAuditChainReport::new(None).is_intact());
+    }
+}
@@ -0,0 +1,165 @@
+// ? ---------------------------------------------------------------------------
+// ? ResourceAuditCheckpoint
+//
+// A signed snapshot of a tenant chain's head. The hash chain alone detects
+// edits in the middle of a chain, but someone with write access could still
+// truncate the tail or rebuild the whole chain from scratch. Checkpoints close
+// that gap: their HMAC is keyed with the tenant's DEK (the system DEK for the
+// tenant-less chain), which never leaves the envelope-encryption
+// infrastructure in plaintext, so a rewritten chain cannot carry valid
+// checkpoints.
+// ? ---------------------------------------------------------------------------
+
+use super::resource_audit_chain_link::ResourceAuditChainLink;
+use crate::domain::utils::SYSTEM_TENANT_ID;
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use mycelium_base::utils::errors::{dto_err, MappedErrors};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// A checkpoint is signed every time a chain's `seq` reaches a multiple of
+/// this value.
+pub const RESOURCE_AUDIT_CHECKPOINT_INTERVAL: i64 = 100;
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceAuditCheckpoint {
+    /// The checkpoint's own identifier.
+    pub id: Uuid,
+
+    /// The tenant chain this checkpoint covers; `None` for the system chain.
+    pub chain_tenant_id: Option<Uuid>,
+
+    /// The `seq` of the chain row the checkpoint was taken at.
+    pub seq: i64,
+
+    /// The hash of the chain row at `seq`.
+    pub root_hash: String,
+
+    /// Hex-encoded HMAC-SHA256 over the fields above, keyed with the chain's
+    /// DEK.
+    pub signature: String,
+
+    /// The moment the checkpoint was signed.
+    pub created_at: DateTime<Utc>,
+}
+
+impl ResourceAuditCheckpoint {
+    /// Sign a checkpoint at `link` for the given chain.
+    pub fn new_signed(
+        chain_tenant_id: Option<Uuid>,
+        link: &ResourceAuditChainLink,
+        dek: &[u8; 32],
+    ) -> Result<Self, MappedErrors> {
+        let signature =
+            sign_payload(chain_tenant_id, link.seq, &link.hash, dek)?;
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            chain_tenant_id,
+            seq: link.seq,
+            root_hash: link.hash.to_owned(),
+            signature,
+            created_at: Utc::now(),
+        })
+    }
+
+    /// Check the checkpoint's signature against the chain's DEK.
+    pub fn has_valid_signature(&self, dek: &[u8; 32]) -> bool {
+        let Ok(expected) = hex::decode(self.signature.as_bytes()) else {
+            return false;
+        };
+
+        let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(dek) else {
+            return false;
+        };
+
+        mac.update(
+            signing_payload(self.chain_tenant_id, self.seq, &self.root_hash)
+                .as_bytes(),
+        );
+
+        mac.verify_slice(&expected).is_ok()
+    }
+}
+
+fn signing_payload(
+    chain_tenant_id: Option<Uuid>,
+    seq: i64,
+    root_hash: &str,
+) -> String {
+    format!(
+        "resource_audit_checkpoint:v1\n{}\n{seq}\n{root_hash}",
+        chain_tenant_id.unwrap_or(SYSTEM_TENANT_ID)
+    )
+}
+
+fn sign_payload(
+    chain_tenant_id: Option<Uuid>,
+    seq: i64,
+    root_hash: &str,
+    dek: &[u8; 32],
+) -> Result<String, MappedErrors> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(dek).map_err(|err| {
+        dto_err(format!("Unable to sign audit checkpoint: {err}"))
+    })?;
+
+    mac.update(signing_payload(chain_tenant_id, seq, root_hash).as_bytes());
+
+    Ok(hex::encode(mac.finalize().into_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_link() -> ResourceAuditChainLink {
+        ResourceAuditChainLink {
+            seq: RESOURCE_AUDIT_CHECKPOINT_INTERVAL,
+            prev_hash: "00".repeat(32),
+            hash: "ab".repeat(32),
+        }
+    }
+
+    #[test]
+    fn signed_checkpoint_verifies_with_the_same_dek() {
+        let dek = [7u8; 32];
+        let tenant_id = Some(Uuid::new_v4());
+
+        let checkpoint = ResourceAuditCheckpoint::new_signed(
+            tenant_id,
+            &sample_link(),
+            &dek,
+        )
+        .unwrap();
+
+        assert_eq!(checkpoint.seq, RESOURCE_AUDIT_CHECKPOINT_INTERVAL);
+        assert!(checkpoint.has_valid_signature(&dek));
+        assert!(!checkpoint.has_valid_signature(&[8u8; 32]));
+    }
+
+    #[test]
+    fn tampered_checkpoint_fields_invalidate_the_signature() {
+        let dek = [7u8; 32];
+
+        let checkpoint =
+            ResourceAuditCheckpoint::new_signed(None, &sample_link(), &dek)
+                .unwrap();
+
+        let mut moved = checkpoint.to_owned();
+        moved.chain_tenant_id = Some(Uuid::new_v4());
+        assert!(!moved.has_valid_signature(&dek));
+
+        let mut rehashed = checkpoint.to_owned();
+        rehashed.root_hash = "cd".repeat(32);
+        assert!(!rehashed.has_valid_signature(&dek));
+
+        let mut shifted = checkpoint;
+        shifted.seq += 1;
+        assert!(!shifted.has_valid_signature(&dek));
+    }
+}
@@ -1,9 +1,10 @@
 // ? ---------------------------------------------------------------------------
 // ? ResourceAuditEventKind
 //
-// Deliberately three values only. Status/privilege changes are modeled as
-// `Updated` plus a `metadata` payload describing what changed, avoiding an
-// ever-growing event enum.
+// Deliberately small. Status/privilege changes are modeled as `Updated` plus a
+// `metadata` payload describing what changed, avoiding an ever-growing event
+// enum. `Accessed` is the only non-mutating kind: it records sensitive reads
+// and authentication attempts, with the specifics again in `metadata`.
 // ? ---------------------------------------------------------------------------
 
 use serde::{Deserialize, Serialize};
@@ -20,6 +21,10 @@ pub enum ResourceAuditEventKind {
 
     /// The resource was deleted.
     Deleted,
+
+    /// The resource was read or authenticated against without being changed
+    /// (e.g. another user's profile was fetched, or a login was attempted).
+    Accessed,
 }
 
 #[cfg(test)]
@@ -32,6 +37,7 @@ mod tests {
             ResourceAuditEventKind::Created,
             ResourceAuditEventKind::Updated,
             ResourceAuditEventKind::Deleted,
+            ResourceAuditEventKind::Accessed,
         ];
 
         for variant in variants {
@@ -0,0 +1,185 @@
+// ? ---------------------------------------------------------------------------
+// ? ResourceAuditExportFormat
+//
+// File formats an audit trail can be exported to. Both are line oriented, so
+// an export is written one page at a time: the header (CSV only) first, then
+// one encoded line per row.
+// ? ---------------------------------------------------------------------------
+
+use super::resource_audit_log::ResourceAuditLog;
+
+use mycelium_base::utils::errors::{dto_err, MappedErrors};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+const CSV_COLUMNS: [&str; 13] = [
+    "id",
+    "createdAt",
+    "resourceType",
+    "resourceId",
+    "tenantId",
+    "event",
+    "performedById",
+    "performedByFrom",
+    "performedByEmail",
+    "metadata",
+    "chainSeq",
+    "prevHash",
+    "rowHash",
+];
+
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum ResourceAuditExportFormat {
+    /// Comma-separated values (RFC 4180), one row per line after a header.
+    Csv,
+
+    /// JSON Lines: one `ResourceAuditLog` JSON object per line.
+    Jsonl,
+}
+
+impl ResourceAuditExportFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Jsonl => "application/x-ndjson",
+        }
+    }
+
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Jsonl => "jsonl",
+        }
+    }
+
+    /// Leading line of the export, if the format has one.
+    pub fn header(&self) -> Option<String> {
+        match self {
+            Self::Csv => Some(format!("{}\r\n", CSV_COLUMNS.join(","))),
+            Self::Jsonl => None,
+        }
+    }
+
+    /// Encode a row as one terminated line.
+    pub fn encode(
+        &self,
+        log: &ResourceAuditLog,
+    ) -> Result<String, MappedErrors> {
+        match self {
+            Self::Jsonl => serde_json::to_string(log)
+                .map(|line| format!("{line}\n"))
+                .map_err(|err| {
+                    dto_err(format!("Unable to encode audit row: {err}"))
+                }),
+            Self::Csv => {
+                let chain = log.chain.as_ref();
+
+                let fields = [
+                    log.id.to_string(),
+                    log.created_at.to_rfc3339(),
+                    json_string(&log.resource_type)?,
+                    log.resource_id.to_string(),
+                    log.tenant_id.map(|id| id.to_string()).unwrap_or_default(),
+                    json_string(&log.event)?,
+                    log.performed_by
+                        .id
+                        .map(|id| id.to_string())
+                        .unwrap_or_default(),
+                    match &log.performed_by.from {
+                        Some(from) => json_string(from)?,
+                        None => String::new(),
+                    },
+                    log.performed_by.email.to_owned().unwrap_or_default(),
+                    log.metadata.to_string(),
+                    chain.map(|link| link.seq.to_string()).unwrap_or_default(),
+                    chain
+                        .map(|link| link.prev_hash.to_owned())
+                        .unwrap_or_default(),
+                    chain.map(|link| link.hash.to_owned()).unwrap_or_default(),
+                ];
+
+                Ok(format!(
+                    "{}\r\n",
+                    fields
+                        .iter()
+                        .map(|field| csv_field(field))
+                        .collect::<Vec<_>>()
+                        .join(",")
+                ))
+            }
+        }
+    }
+}
+
+/// The bare serde representation of a unit enum variant (e.g. `accountMeta`).
+fn json_string<T: Serialize>(value: &T) -> Result<String, MappedErrors> {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(value)) => Ok(value),
+        Ok(other) => Ok(other.to_string()),
+        Err(err) => Err(dto_err(format!("Unable to encode audit row: {err}"))),
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::dtos::{
+        resource_audit_log::{
+            ResourceAuditEventKind, ResourceAuditResourceType,
+        },
+        written_by::WrittenBy,
+    };
+
+    use chrono::{DateTime, Utc};
+    use uuid::Uuid;
+
+    fn log() -> ResourceAuditLog {
+        ResourceAuditLog {
+            id: Uuid::from_u128(1),
+            resource_type: ResourceAuditResourceType::AccountMeta,
+            resource_id: Uuid::from_u128(2),
+            tenant_id: None,
+            event: ResourceAuditEventKind::Updated,
+            performed_by: WrittenBy::new_from_user(Uuid::from_u128(3)),
+            metadata: serde_json::json!({ "note": "a,\"b\"" }),
+            created_at: DateTime::<Utc>::UNIX_EPOCH,
+            chain: None,
+            redacted_at: None,
+        }
+    }
+
+    #[test]
+    fn csv_export_quotes_fields_and_uses_serde_names() {
+        let format = ResourceAuditExportFormat::Csv;
+        let line = format.encode(&log()).unwrap();
+
+        assert!(line.contains(",accountMeta,"));
+        assert!(line.contains(",updated,"));
+        assert!(line.contains(r#","{""note"":""a,\""b\""""}","#));
+        assert!(format.header().unwrap().starts_with("id,createdAt,"));
+    }
+
+    #[test]
+    fn jsonl_export_writes_one_object_per_line() {
+        let line = ResourceAuditExportFormat::Jsonl.encode(&log()).unwrap();
+
+        assert!(line.ends_with('\n'));
+        assert_eq!(line.matches('\n').count(), 1);
+
+        let parsed: ResourceAuditLog =
+            serde_json::from_str(line.trim_end()).unwrap();
+        assert_eq!(parsed.id, Uuid::from_u128(1));
+        assert!(ResourceAuditExportFormat::Jsonl.header().is_none());
+    }
+}
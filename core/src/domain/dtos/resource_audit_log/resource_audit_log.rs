@@ -6,14 +6,24 @@
 // resource) and at the database level (a trigger rejects UPDATE/DELETE
 // regardless of who issues the SQL). See `NewResourceAuditLogEvent` for the
 // pre-insert shape a use case builds before this row's `id` exists.
+//
+// The one exception is the erasure of a data subject: `metadata` and
+// `performed_by` may then be pseudonymised, which stamps `redacted_at`, and
+// the erasure event records the redaction (see `ResourceAuditRedaction`).
 // ? ---------------------------------------------------------------------------
 
+use super::new_resource_audit_log_event::NewResourceAuditLogEvent;
+use super::resource_audit_chain_link::ResourceAuditChainLink;
 use super::resource_audit_event_kind::ResourceAuditEventKind;
+use super::resource_audit_redaction::ResourceAuditRedaction;
 use super::resource_audit_resource_type::ResourceAuditResourceType;
-use crate::domain::dtos::written_by::WrittenBy;
+use crate::domain::dtos::{
+    data_subject::pseudonymise_json, written_by::WrittenBy,
+};
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
@@ -45,6 +55,81 @@ pub struct ResourceAuditLog {
     /// The moment the triggering operation succeeded -- captured
     /// synchronously by the caller, not derived from insert time.
     pub created_at: DateTime<Utc>,
+
+    /// The row's position in its tenant hash chain. `None` for rows written
+    /// before hash chaining was introduced.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chain: Option<ResourceAuditChainLink>,
+
+    /// When `metadata` and `performed_by` were pseudonymised by a data
+    /// subject erasure. A redacted row no longer matches the content its
+    /// chain link was computed from, so it verifies against the redaction
+    /// recorded by the erasure instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redacted_at: Option<DateTime<Utc>>,
+}
+
+impl ResourceAuditLog {
+    /// Recompute this row's chain link from its stored content and stored
+    /// `prev_hash`. Comparing the result against `chain` is what detects a
+    /// modified row; `None` when the row is not chained.
+    pub fn recompute_chain_link(&self) -> Option<ResourceAuditChainLink> {
+        let chain = self.chain.as_ref()?;
+
+        let event = NewResourceAuditLogEvent {
+            resource_type: self.resource_type.to_owned(),
+            resource_id: self.resource_id,
+            tenant_id: self.tenant_id,
+            event: self.event.to_owned(),
+            performed_by: self.performed_by.to_owned(),
+            metadata: self.metadata.to_owned(),
+            created_at: self.created_at,
+        };
+
+        Some(event.chain_link(chain.seq, &chain.prev_hash))
+    }
+
+    pub fn is_redacted(&self) -> bool {
+        self.redacted_at.is_some()
+    }
+
+    /// Whether the stored content still hashes to the stored chain link.
+    /// Redacted rows verify only when one of `redactions` vouches for their
+    /// current content.
+    pub fn content_verifies(
+        &self,
+        redactions: &HashSet<ResourceAuditRedaction>,
+    ) -> bool {
+        if !self.is_redacted() {
+            return self.recompute_chain_link().as_ref() == self.chain.as_ref();
+        }
+
+        ResourceAuditRedaction::of(self)
+            .is_some_and(|redaction| redactions.contains(&redaction))
+    }
+
+    /// Replace every mention of `subject` (an email) in `metadata` and
+    /// `performed_by` by `pseudonym`, stamping `redacted_at`. Returns `None`
+    /// when the row does not mention `subject`.
+    pub fn pseudonymise(&self, subject: &str, pseudonym: &str) -> Option<Self> {
+        let mut metadata = self.metadata.to_owned();
+        let metadata_changed =
+            pseudonymise_json(&mut metadata, subject, pseudonym);
+
+        let performed_by = self.performed_by.pseudonymise(subject, pseudonym);
+
+        if !metadata_changed && performed_by.is_none() {
+            return None;
+        }
+
+        Some(Self {
+            metadata,
+            performed_by: performed_by
+                .unwrap_or_else(|| self.performed_by.to_owned()),
+            redacted_at: Some(Utc::now()),
+            ..self.to_owned()
+        })
+    }
 }
 
 #[cfg(test)]
@@ -62,6 +147,8 @@ mod tests {
             performed_by: WrittenBy::new_from_user(Uuid::new_v4()),
             metadata: serde_json::json!({ "action": "create_subscription_account" }),
             created_at: Utc::now(),
+            chain: None,
+            redacted_at: None,
         };
 
         let json = serde_json::to_string(&log).unwrap();
@@ -88,6 +175,8 @@ mod tests {
             performed_by: WrittenBy::new_anemic(),
             metadata: serde_json::json!({}),
             created_at: DateTime::<Utc>::UNIX_EPOCH,
+            chain: None,
+            redacted_at: None,
         };
 
         let json = serde_json::to_value(&log).unwrap();
@@ -98,5 +187,83 @@ mod tests {
         assert!(object.contains_key("tenantId"));
         assert!(object.contains_key("performedBy"));
         assert!(object.contains_key("createdAt"));
+        assert!(!object.contains_key("chain"));
+    }
+
+    #[test]
+    fn recompute_chain_link_detects_modified_content() {
+        let event = NewResourceAuditLogEvent {
+            resource_type: ResourceAuditResourceType::Webhook,
+            resource_id: Uuid::new_v4(),
+            tenant_id: None,
+            event: ResourceAuditEventKind::Updated,
+            performed_by: WrittenBy::new_anemic(),
+            metadata: serde_json::json!({ "field": "url" }),
+            created_at: Utc::now(),
+        };
+
+        let chain = event.chain_link(1, "00");
+
+        let mut log = ResourceAuditLog {
+            id: Uuid::new_v4(),
+            resource_type: event.resource_type.to_owned(),
+            resource_id: event.resource_id,
+            tenant_id: event.tenant_id,
+            event: event.event.to_owned(),
+            performed_by: event.performed_by.to_owned(),
+            metadata: event.metadata.to_owned(),
+            created_at: event.created_at,
+            chain: Some(chain.to_owned()),
+            redacted_at: None,
+        };
+
+        assert_eq!(log.recompute_chain_link(), Some(chain.to_owned()));
+
+        log.metadata = serde_json::json!({ "field": "secret" });
+        assert_ne!(log.recompute_chain_link(), Some(chain));
+    }
+
+    #[test]
+    fn pseudonymised_rows_keep_their_link_and_verify_against_their_redaction() {
+        let event = NewResourceAuditLogEvent {
+            resource_type: ResourceAuditResourceType::GuestUser,
+            resource_id: Uuid::new_v4(),
+            tenant_id: None,
+            event: ResourceAuditEventKind::Created,
+            performed_by: WrittenBy::new_from_email("jane@example.com"),
+            metadata: serde_json::json!({ "email": "jane@example.com" }),
+            created_at: Utc::now(),
+        };
+
+        let chain = event.chain_link(1, "00");
+
+        let log = ResourceAuditLog {
+            id: Uuid::new_v4(),
+            resource_type: event.resource_type.to_owned(),
+            resource_id: event.resource_id,
+            tenant_id: event.tenant_id,
+            event: event.event.to_owned(),
+            performed_by: event.performed_by.to_owned(),
+            metadata: event.metadata.to_owned(),
+            created_at: event.created_at,
+            chain: Some(chain.to_owned()),
+            redacted_at: None,
+        };
+
+        assert!(log.content_verifies(&HashSet::new()));
+        assert!(log.pseudonymise("john@example.com", "x").is_none());
+
+        let redacted = log.pseudonymise("jane@example.com", "x").unwrap();
+
+        assert!(redacted.is_redacted());
+        assert_eq!(redacted.chain, Some(chain.to_owned()));
+        assert_eq!(redacted.metadata, serde_json::json!({ "email": "x" }));
+        assert_eq!(redacted.performed_by, WrittenBy::new_from_email("x"));
+        assert_ne!(redacted.recompute_chain_link(), redacted.chain);
+        assert!(!redacted.content_verifies(&HashSet::new()));
+
+        let redaction = ResourceAuditRedaction::of(&redacted).unwrap();
+        assert_eq!(redaction.hash, chain.hash);
+        assert!(redacted.content_verifies(&HashSet::from([redaction])));
     }
 }
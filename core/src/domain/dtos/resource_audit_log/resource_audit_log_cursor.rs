@@ -0,0 +1,97 @@
+// ? ---------------------------------------------------------------------------
+// ? ResourceAuditLogCursor
+//
+// Keyset position in a newest-first audit listing. Rows are ordered by
+// `created_at` then `id`, both descending, so `(created_at, id)` of the last
+// row of a page is enough to resume right after it -- stable even while new
+// rows are appended, which skip/offset pagination is not.
+// ? ---------------------------------------------------------------------------
+
+use super::resource_audit_log::ResourceAuditLog;
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, SecondsFormat, Utc};
+use mycelium_base::utils::errors::{dto_err, MappedErrors};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResourceAuditLogCursor {
+    pub created_at: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+impl ResourceAuditLogCursor {
+    pub fn from_log(log: &ResourceAuditLog) -> Self {
+        Self {
+            created_at: log.created_at,
+            id: log.id,
+        }
+    }
+
+    /// Opaque, URL-safe representation handed to clients.
+    pub fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(format!(
+            "{}|{}",
+            self.created_at.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            self.id
+        ))
+    }
+
+    pub fn decode(value: &str) -> Result<Self, MappedErrors> {
+        let invalid = || dto_err("Invalid resource audit log cursor");
+
+        let decoded = URL_SAFE_NO_PAD
+            .decode(value)
+            .ok()
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .ok_or_else(invalid)?;
+
+        let (created_at, id) = decoded.split_once('|').ok_or_else(invalid)?;
+
+        Ok(Self {
+            created_at: DateTime::parse_from_rfc3339(created_at)
+                .map_err(|_| invalid())?
+                .with_timezone(&Utc),
+            id: Uuid::parse_str(id).map_err(|_| invalid())?,
+        })
+    }
+}
+
+/// One page of a newest-first audit listing.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceAuditLogPage {
+    /// The page rows, newest first.
+    pub records: Vec<ResourceAuditLog>,
+
+    /// Cursor of the next page; `None` on the last page.
+    pub next_cursor: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resource_audit_log_cursor_round_trips() {
+        let cursor = ResourceAuditLogCursor {
+            created_at: Utc::now(),
+            id: Uuid::new_v4(),
+        };
+
+        let decoded = ResourceAuditLogCursor::decode(&cursor.encode()).unwrap();
+
+        assert_eq!(decoded, cursor);
+    }
+
+    #[test]
+    fn resource_audit_log_cursor_rejects_garbage() {
+        assert!(ResourceAuditLogCursor::decode("not a cursor").is_err());
+        assert!(ResourceAuditLogCursor::decode(
+            &URL_SAFE_NO_PAD.encode("2026-10-18T00:00:00Z")
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,45 @@
+// ? ---------------------------------------------------------------------------
+// ? ResourceAuditLogFilter
+//
+// Search criteria over `resource_audit_log`. Every field is optional and the
+// set ones are combined with AND; the default filter matches every row.
+// Which rows a caller may actually see is decided by
+// `search_resource_audit_trail`, never by the filter alone.
+// ? ---------------------------------------------------------------------------
+
+use super::resource_audit_event_kind::ResourceAuditEventKind;
+use super::resource_audit_resource_type::ResourceAuditResourceType;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceAuditLogFilter {
+    /// Only rows about this kind of resource.
+    pub resource_type: Option<ResourceAuditResourceType>,
+
+    /// Only rows about this resource.
+    pub resource_id: Option<Uuid>,
+
+    /// Only rows of this tenant.
+    pub tenant_id: Option<Uuid>,
+
+    /// Only rows whose actor (`WrittenBy::id`) is this user or account.
+    pub performed_by: Option<Uuid>,
+
+    /// Only rows of this event kind.
+    pub event: Option<ResourceAuditEventKind>,
+
+    /// Only rows created at or after this moment.
+    pub created_after: Option<DateTime<Utc>>,
+
+    /// Only rows created strictly before this moment.
+    pub created_before: Option<DateTime<Utc>>,
+
+    /// Only rows whose JSON-encoded `metadata` contains this text,
+    /// case-insensitively.
+    pub metadata_contains: Option<String>,
+}
@@ -0,0 +1,62 @@
+// ? ---------------------------------------------------------------------------
+// ? ResourceAuditRedaction
+//
+// The record of one row pseudonymised by a data subject erasure. A redacted
+// row no longer hashes to its chain link, so the erasure event lists, for
+// every row it redacted, the link hash computed before the redaction and the
+// hash the redacted content computes to. The erasure event is itself chained
+// in the system chain, so a row is only accepted as redacted when such an
+// intact record vouches for its current content.
+// ? ---------------------------------------------------------------------------
+
+use super::resource_audit_log::ResourceAuditLog;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// `metadata.action` of the erasure events carrying redaction records.
+pub const RESOURCE_AUDIT_ERASURE_ACTION: &str = "erase_my_data";
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceAuditRedaction {
+    /// The redacted row.
+    pub row_id: Uuid,
+
+    /// The row's chain link hash, computed before the redaction.
+    pub hash: String,
+
+    /// The hash the redacted content computes to under the same link.
+    pub redacted_hash: String,
+}
+
+impl ResourceAuditRedaction {
+    /// The record vouching for the current content of `row`. `None` when
+    /// the row is not chained.
+    pub fn of(row: &ResourceAuditLog) -> Option<Self> {
+        let chain = row.chain.as_ref()?;
+
+        Some(Self {
+            row_id: row.id,
+            hash: chain.hash.to_owned(),
+            redacted_hash: row.recompute_chain_link()?.hash,
+        })
+    }
+
+    /// The redaction records carried by `row`, when it is an intact erasure
+    /// event of the system chain.
+    pub fn from_erasure_event(row: &ResourceAuditLog) -> Vec<Self> {
+        let is_erasure = row.tenant_id.is_none()
+            && row.metadata["action"] == RESOURCE_AUDIT_ERASURE_ACTION
+            && !row.is_redacted()
+            && row.chain.is_some()
+            && row.recompute_chain_link() == row.chain;
+
+        if !is_erasure {
+            return vec![];
+        }
+
+        serde_json::from_value(row.metadata["redactions"].to_owned())
+            .unwrap_or_default()
+    }
+}
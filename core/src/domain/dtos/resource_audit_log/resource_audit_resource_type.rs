@@ -33,6 +33,19 @@ pub enum ResourceAuditResourceType {
 
     /// A `webhook` row was affected.
     Webhook,
+
+    /// A guest grant (an email invited to a role on an account) was affected.
+    /// Keyed by the account the grant applies to; the email and role are in
+    /// `metadata`.
+    GuestUser,
+
+    /// A connection string was affected. Keyed by the owning account, since
+    /// tokens have integer ids; the token id is in `metadata`.
+    ConnectionString,
+
+    /// An `error_code` row was affected. Error codes are keyed by prefix and
+    /// code rather than by a UUID; see `ErrorCode::audit_resource_id`.
+    ErrorCode,
 }
 
 #[cfg(test)]
@@ -49,6 +62,9 @@ mod tests {
             ResourceAuditResourceType::TenantMeta,
             ResourceAuditResourceType::GuestRole,
             ResourceAuditResourceType::Webhook,
+            ResourceAuditResourceType::GuestUser,
+            ResourceAuditResourceType::ConnectionString,
+            ResourceAuditResourceType::ErrorCode,
         ];
 
         for variant in variants {
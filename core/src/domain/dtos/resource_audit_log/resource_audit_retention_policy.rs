@@ -0,0 +1,52 @@
+// ? ---------------------------------------------------------------------------
+// ? ResourceAuditRetentionPolicy
+//
+// Per-chain override of how long `resource_audit_log` rows are kept before
+// being archived. A chain without a policy follows `core.audit.retentionDays`;
+// a chain on legal hold is never archived nor purged, whatever its retention.
+// ? ---------------------------------------------------------------------------
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceAuditRetentionPolicy {
+    /// The chain the policy applies to; `None` for the system chain.
+    pub chain_tenant_id: Option<Uuid>,
+
+    /// Days a row is kept before being archived; `None` falls back to the
+    /// configured default.
+    pub retention_days: Option<i32>,
+
+    /// Blocks every archival and deletion from the chain while set.
+    pub legal_hold: bool,
+
+    /// The moment the policy was last changed.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ResourceAuditRetentionPolicy {
+    /// The policy a chain follows when it has none stored.
+    pub fn default_for(chain_tenant_id: Option<Uuid>) -> Self {
+        Self {
+            chain_tenant_id,
+            retention_days: None,
+            legal_hold: false,
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// The retention in days the chain follows, if any.
+    pub fn effective_retention_days(
+        &self,
+        default_retention_days: Option<u64>,
+    ) -> Option<u64> {
+        match self.retention_days {
+            Some(days) => Some(days.max(1) as u64),
+            None => default_retention_days,
+        }
+    }
+}
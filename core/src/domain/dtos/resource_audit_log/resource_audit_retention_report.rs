@@ -0,0 +1,49 @@
+// ? ---------------------------------------------------------------------------
+// ? ResourceAuditRetentionReport
+//
+// Outcome of applying the retention policy to one tenant chain of the
+// resource audit log (see `apply_resource_audit_retention`).
+// ? ---------------------------------------------------------------------------
+
+use super::resource_audit_archive::ResourceAuditArchive;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceAuditRetentionReport {
+    /// The chain the policy was applied to; `None` for the system chain.
+    pub chain_tenant_id: Option<Uuid>,
+
+    /// The retention the chain follows; `None` keeps its rows forever.
+    pub retention_days: Option<u64>,
+
+    /// Whether the chain is on legal hold, in which case nothing was touched.
+    pub legal_hold: bool,
+
+    /// The archive written by this run, if any row had expired.
+    pub archive: Option<ResourceAuditArchive>,
+
+    /// Rows removed from the log, including previously restored rows of
+    /// older archives.
+    pub purged_rows: u64,
+
+    /// Why the chain could not be processed, if it could not. Other chains
+    /// are still processed.
+    pub error: Option<String>,
+}
+
+impl ResourceAuditRetentionReport {
+    pub fn new(chain_tenant_id: Option<Uuid>) -> Self {
+        Self {
+            chain_tenant_id,
+            retention_days: None,
+            legal_hold: false,
+            archive: None,
+            purged_rows: 0,
+            error: None,
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+use utoipa::ToSchema;
+
+/// Jobs run by the notification scheduler
+#[derive(
+    Clone, Copy, Debug, Deserialize, Serialize, ToSchema, Eq, Hash, PartialEq,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum ScheduledJob {
+    /// Remind guests of invitations they did not accept yet
+    InvitationReminder,
+
+    /// Warn owners of connection strings about to expire
+    ConnectionStringExpiryWarning,
+
+    /// Send tenant owners a digest of the membership changes
+    TenantMembershipDigest,
+
+    /// Warn guests of grants about to expire
+    GuestGrantExpiryWarning,
+
+    /// Revoke the expired guest grants
+    GuestGrantExpirySweep,
+
+    /// Remove the expired guest invitations
+    GuestInvitationExpirySweep,
+
+    /// Warn tenant owners of trashed tenants about to be purged
+    TenantPurgeWarning,
+
+    /// Purge the tenants trashed for longer than the retention period
+    TrashedTenantPurge,
+}
+
+impl Display for ScheduledJob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScheduledJob::InvitationReminder => {
+                write!(f, "invitation-reminder")
+            }
+            ScheduledJob::ConnectionStringExpiryWarning => {
+                write!(f, "connection-string-expiry-warning")
+            }
+            ScheduledJob::TenantMembershipDigest => {
+                write!(f, "tenant-membership-digest")
+            }
+            ScheduledJob::GuestGrantExpiryWarning => {
+                write!(f, "guest-grant-expiry-warning")
+            }
+            ScheduledJob::GuestGrantExpirySweep => {
+                write!(f, "guest-grant-expiry-sweep")
+            }
+            ScheduledJob::GuestInvitationExpirySweep => {
+                write!(f, "guest-invitation-expiry-sweep")
+            }
+            ScheduledJob::TenantPurgeWarning => {
+                write!(f, "tenant-purge-warning")
+            }
+            ScheduledJob::TrashedTenantPurge => {
+                write!(f, "trashed-tenant-purge")
+            }
+        }
+    }
+}
+
+impl FromStr for ScheduledJob {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "invitation-reminder" => Ok(ScheduledJob::InvitationReminder),
+            "connection-string-expiry-warning" => {
+                Ok(ScheduledJob::ConnectionStringExpiryWarning)
+            }
+            "tenant-membership-digest" => {
+                Ok(ScheduledJob::TenantMembershipDigest)
+            }
+            "guest-grant-expiry-warning" => {
+                Ok(ScheduledJob::GuestGrantExpiryWarning)
+            }
+            "guest-grant-expiry-sweep" => {
+                Ok(ScheduledJob::GuestGrantExpirySweep)
+            }
+            "guest-invitation-expiry-sweep" => {
+                Ok(ScheduledJob::GuestInvitationExpirySweep)
+            }
+            "tenant-purge-warning" => Ok(ScheduledJob::TenantPurgeWarning),
+            "trashed-tenant-purge" => Ok(ScheduledJob::TrashedTenantPurge),
+            _ => Err(format!("Invalid scheduled job: {s}")),
+        }
+    }
+}
+
+/// A run of a scheduled job, claimed by a single API replica
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledJobRun {
+    /// The claimed job
+    pub job: ScheduledJob,
+
+    /// The moment the run was claimed
+    pub started_at: DateTime<Utc>,
+
+    /// The moment the previous run was claimed, if the job ever ran
+    pub previous_run_at: Option<DateTime<Utc>>,
+
+    /// The replica which claimed the run
+    pub claimed_by: String,
+}
+
+impl ScheduledJobRun {
+    /// The period covered by this run, as `[start, started_at)`
+    ///
+    /// The period starts where the previous run stopped, so consecutive runs
+    /// cover every moment exactly once. After a long outage the period is
+    /// bounded to two intervals, so old events are not notified all at once.
+    pub fn period(&self, interval: Duration) -> (DateTime<Utc>, DateTime<Utc>) {
+        let earliest = self.started_at - interval * 2;
+
+        let start = match self.previous_run_at {
+            Some(previous) => previous.max(earliest),
+            None => self.started_at - interval,
+        };
+
+        (start, self.started_at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(previous_run_at: Option<DateTime<Utc>>) -> ScheduledJobRun {
+        ScheduledJobRun {
+            job: ScheduledJob::InvitationReminder,
+            started_at: DateTime::parse_from_rfc3339("2026-10-19T12:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+            previous_run_at,
+            claimed_by: "pod-a".to_string(),
+        }
+    }
+
+    #[test]
+    fn period_starts_at_the_previous_run() {
+        let interval = Duration::hours(24);
+        let previous = run(None).started_at - Duration::hours(25);
+        let (start, end) = run(Some(previous)).period(interval);
+
+        assert_eq!(start, previous);
+        assert_eq!(end, run(None).started_at);
+
+        let (start, _) = run(None).period(interval);
+        assert_eq!(start, run(None).started_at - interval);
+
+        let (start, _) =
+            run(Some(previous - Duration::days(30))).period(interval);
+        assert_eq!(start, run(None).started_at - interval * 2);
+    }
+
+    #[test]
+    fn scheduled_job_round_trips_through_its_name() {
+        for job in [
+            ScheduledJob::InvitationReminder,
+            ScheduledJob::ConnectionStringExpiryWarning,
+            ScheduledJob::TenantMembershipDigest,
+            ScheduledJob::GuestGrantExpiryWarning,
+            ScheduledJob::GuestGrantExpirySweep,
+            ScheduledJob::GuestInvitationExpirySweep,
+            ScheduledJob::TenantPurgeWarning,
+            ScheduledJob::TrashedTenantPurge,
+        ] {
+            assert_eq!(ScheduledJob::from_str(&job.to_string()), Ok(job));
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use crate::domain::dtos::written_by::WrittenBy;
+
+use chrono::{DateTime, Local};
+use rand::{distributions::Alphanumeric, thread_rng, Rng};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// The prefix of the SCIM credential secrets
+///
+/// Makes leaked secrets easy to recognize by secret scanners.
+pub const SCIM_SECRET_PREFIX: &str = "myc_scim_";
+
+/// The length of the random part of the SCIM credential secrets
+const SCIM_SECRET_LENGTH: usize = 48;
+
+/// A bearer credential of a SCIM client
+///
+/// Identity providers authenticate on the SCIM endpoints of a single tenant
+/// with the credential secret. Only the hash of the secret is stored: the
+/// secret is shown once, when the credential is issued.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimCredential {
+    /// The credential id
+    pub id: Uuid,
+
+    /// The tenant provisioned with the credential
+    pub tenant_id: Uuid,
+
+    /// A name to tell the credentials apart, as the identity provider name
+    pub name: String,
+
+    /// The SHA-256 hash of the secret, hex encoded
+    #[serde(skip_serializing, default)]
+    pub secret_hash: String,
+
+    /// Who issued the credential
+    pub created_by: WrittenBy,
+
+    /// When the credential was issued
+    pub created: DateTime<Local>,
+}
+
+impl ScimCredential {
+    /// Build a new credential and its secret
+    pub fn new(
+        tenant_id: Uuid,
+        name: String,
+        created_by: WrittenBy,
+    ) -> (Self, String) {
+        let secret = format!(
+            "{SCIM_SECRET_PREFIX}{}",
+            thread_rng()
+                .sample_iter(&Alphanumeric)
+                .take(SCIM_SECRET_LENGTH)
+                .map(char::from)
+                .collect::<String>()
+        );
+
+        (
+            Self {
+                id: Uuid::new_v4(),
+                tenant_id,
+                name,
+                secret_hash: Self::hash_secret(&secret),
+                created_by,
+                created: Local::now(),
+            },
+            secret,
+        )
+    }
+
+    /// Hash a secret as stored by the credentials
+    pub fn hash_secret(secret: &str) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(secret.as_bytes());
+
+        hex::encode(hasher.finalize())
+    }
+}
+
+/// A credential just issued, with its secret
+#[derive(Clone, Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IssuedScimCredential {
+    #[serde(flatten)]
+    pub credential: ScimCredential,
+
+    /// The bearer secret. It is not shown again.
+    pub secret: String,
+}
@@ -0,0 +1,491 @@
+use super::{find_attribute_key, split_attribute_path, ScimErrorType};
+
+use mycelium_base::utils::errors::{dto_err, MappedErrors};
+use serde_json::Value;
+use std::{cmp::Ordering, str::FromStr};
+
+/// The comparison operators of the SCIM filters
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ScimFilterOperator {
+    Eq,
+    Ne,
+    Co,
+    Sw,
+    Ew,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+impl FromStr for ScimFilterOperator {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "eq" => Ok(ScimFilterOperator::Eq),
+            "ne" => Ok(ScimFilterOperator::Ne),
+            "co" => Ok(ScimFilterOperator::Co),
+            "sw" => Ok(ScimFilterOperator::Sw),
+            "ew" => Ok(ScimFilterOperator::Ew),
+            "gt" => Ok(ScimFilterOperator::Gt),
+            "ge" => Ok(ScimFilterOperator::Ge),
+            "lt" => Ok(ScimFilterOperator::Lt),
+            "le" => Ok(ScimFilterOperator::Le),
+            _ => Err(format!("Invalid filter operator: {s}")),
+        }
+    }
+}
+
+/// A SCIM filter, as defined by RFC 7644, section 3.4.2.2
+///
+/// Filters combine attribute comparisons with `and`, `or`, `not` and
+/// parentheses. Strings are compared without regard to case. Filters on a
+/// multi-valued attribute match when any of its values matches, and filters
+/// on complex values compare their `value` sub-attribute.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ScimFilter {
+    Present(String),
+    Compare {
+        attribute: String,
+        operator: ScimFilterOperator,
+        value: Value,
+    },
+    Not(Box<ScimFilter>),
+    And(Box<ScimFilter>, Box<ScimFilter>),
+    Or(Box<ScimFilter>, Box<ScimFilter>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Open,
+    Close,
+    Word(String),
+    Text(String),
+}
+
+fn invalid_filter(msg: String) -> MappedErrors {
+    dto_err(msg)
+        .with_code(ScimErrorType::InvalidFilter.as_str())
+        .with_exp_true()
+}
+
+fn tokenize(filter: &str) -> Result<Vec<Token>, MappedErrors> {
+    let mut tokens = vec![];
+    let mut chars = filter.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {}
+            '(' => tokens.push(Token::Open),
+            ')' => tokens.push(Token::Close),
+            '"' => {
+                let mut text = String::new();
+                let mut closed = false;
+
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' => match chars.next() {
+                            Some(escaped) => text.push(escaped),
+                            None => break,
+                        },
+                        '"' => {
+                            closed = true;
+                            break;
+                        }
+                        c => text.push(c),
+                    }
+                }
+
+                if !closed {
+                    return Err(invalid_filter(format!(
+                        "Unterminated string in filter: {filter}"
+                    )));
+                }
+
+                tokens.push(Token::Text(text));
+            }
+            c => {
+                let mut word = String::from(c);
+
+                while let Some(&next) = chars.peek() {
+                    if next.is_whitespace() || next == '(' || next == ')' {
+                        break;
+                    }
+
+                    word.push(next);
+                    chars.next();
+                }
+
+                tokens.push(Token::Word(word));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(
+            self.tokens.get(self.position),
+            Some(Token::Word(word)) if word.eq_ignore_ascii_case(keyword)
+        )
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn parse_or(&mut self) -> Result<ScimFilter, MappedErrors> {
+        let mut filter = self.parse_and()?;
+
+        while self.peek_keyword("or") {
+            self.position += 1;
+            filter =
+                ScimFilter::Or(Box::new(filter), Box::new(self.parse_and()?));
+        }
+
+        Ok(filter)
+    }
+
+    fn parse_and(&mut self) -> Result<ScimFilter, MappedErrors> {
+        let mut filter = self.parse_atom()?;
+
+        while self.peek_keyword("and") {
+            self.position += 1;
+            filter =
+                ScimFilter::And(Box::new(filter), Box::new(self.parse_atom()?));
+        }
+
+        Ok(filter)
+    }
+
+    fn parse_group(&mut self) -> Result<ScimFilter, MappedErrors> {
+        let filter = self.parse_or()?;
+
+        match self.next() {
+            Some(Token::Close) => Ok(filter),
+            _ => Err(invalid_filter("Unbalanced parentheses".to_string())),
+        }
+    }
+
+    fn parse_atom(&mut self) -> Result<ScimFilter, MappedErrors> {
+        match self.next() {
+            Some(Token::Open) => self.parse_group(),
+            Some(Token::Word(word)) if word.eq_ignore_ascii_case("not") => {
+                match self.next() {
+                    Some(Token::Open) => {
+                        Ok(ScimFilter::Not(Box::new(self.parse_group()?)))
+                    }
+                    _ => Err(invalid_filter(
+                        "Expected a parenthesis after not".to_string(),
+                    )),
+                }
+            }
+            Some(Token::Word(attribute)) => {
+                if attribute.contains('[') {
+                    return Err(invalid_filter(format!(
+                        "Value filters are not supported: {attribute}"
+                    )));
+                }
+
+                let operator = match self.next() {
+                    Some(Token::Word(operator)) => operator,
+                    _ => {
+                        return Err(invalid_filter(format!(
+                            "Expected an operator after {attribute}"
+                        )))
+                    }
+                };
+
+                if operator.eq_ignore_ascii_case("pr") {
+                    return Ok(ScimFilter::Present(attribute));
+                }
+
+                let operator = ScimFilterOperator::from_str(&operator)
+                    .map_err(invalid_filter)?;
+
+                let value = match self.next() {
+                    Some(Token::Text(text)) => Value::String(text),
+                    Some(Token::Word(word)) => match word.as_str() {
+                        "true" => Value::Bool(true),
+                        "false" => Value::Bool(false),
+                        "null" => Value::Null,
+                        number => {
+                            serde_json::from_str::<serde_json::Number>(number)
+                                .map(Value::Number)
+                                .map_err(|_| {
+                                    invalid_filter(format!(
+                                        "Invalid filter value: {number}"
+                                    ))
+                                })?
+                        }
+                    },
+                    _ => {
+                        return Err(invalid_filter(format!(
+                            "Expected a value after {attribute}"
+                        )))
+                    }
+                };
+
+                Ok(ScimFilter::Compare {
+                    attribute,
+                    operator,
+                    value,
+                })
+            }
+            _ => Err(invalid_filter("Expected an attribute".to_string())),
+        }
+    }
+}
+
+/// Collect the values of an attribute path in a document
+///
+/// Multi-valued attributes contribute each of their values.
+fn collect_values<'a>(document: &'a Value, keys: &[String]) -> Vec<&'a Value> {
+    let (key, rest) = match keys.split_first() {
+        Some(split) => split,
+        None => {
+            return match document {
+                Value::Array(items) => items.iter().collect(),
+                value => vec![value],
+            }
+        }
+    };
+
+    match document {
+        Value::Object(object) => match find_attribute_key(object, key) {
+            Some(found) => collect_values(&object[&found], rest),
+            None => vec![],
+        },
+        Value::Array(items) => items
+            .iter()
+            .flat_map(|item| collect_values(item, keys))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+fn compare(
+    candidate: &Value,
+    operator: ScimFilterOperator,
+    expected: &Value,
+) -> bool {
+    //
+    // Complex values are compared by their value sub-attribute
+    //
+    let candidate = match candidate {
+        Value::Object(object) => match find_attribute_key(object, "value") {
+            Some(key) => &object[&key],
+            None => return false,
+        },
+        candidate => candidate,
+    };
+
+    match (candidate, expected) {
+        (Value::String(candidate), Value::String(expected)) => {
+            let candidate = candidate.to_lowercase();
+            let expected = expected.to_lowercase();
+
+            match operator {
+                ScimFilterOperator::Eq => candidate == expected,
+                ScimFilterOperator::Ne => candidate != expected,
+                ScimFilterOperator::Co => candidate.contains(&expected),
+                ScimFilterOperator::Sw => candidate.starts_with(&expected),
+                ScimFilterOperator::Ew => candidate.ends_with(&expected),
+                ScimFilterOperator::Gt => candidate > expected,
+                ScimFilterOperator::Ge => candidate >= expected,
+                ScimFilterOperator::Lt => candidate < expected,
+                ScimFilterOperator::Le => candidate <= expected,
+            }
+        }
+        (Value::Number(candidate), Value::Number(expected)) => {
+            let ordering = candidate.as_f64().zip(expected.as_f64()).and_then(
+                |(candidate, expected)| candidate.partial_cmp(&expected),
+            );
+
+            match (operator, ordering) {
+                (ScimFilterOperator::Eq, Some(ordering)) => {
+                    ordering == Ordering::Equal
+                }
+                (ScimFilterOperator::Ne, Some(ordering)) => {
+                    ordering != Ordering::Equal
+                }
+                (ScimFilterOperator::Gt, Some(ordering)) => {
+                    ordering == Ordering::Greater
+                }
+                (ScimFilterOperator::Ge, Some(ordering)) => {
+                    ordering != Ordering::Less
+                }
+                (ScimFilterOperator::Lt, Some(ordering)) => {
+                    ordering == Ordering::Less
+                }
+                (ScimFilterOperator::Le, Some(ordering)) => {
+                    ordering != Ordering::Greater
+                }
+                _ => false,
+            }
+        }
+        (candidate, expected) => match operator {
+            ScimFilterOperator::Eq => candidate == expected,
+            ScimFilterOperator::Ne => candidate != expected,
+            _ => false,
+        },
+    }
+}
+
+fn is_present(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::String(text) => !text.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        Value::Object(object) => !object.is_empty(),
+        _ => true,
+    }
+}
+
+impl ScimFilter {
+    /// Parse a filter expression
+    pub fn parse(filter: &str) -> Result<Self, MappedErrors> {
+        let mut parser = Parser {
+            tokens: tokenize(filter)?,
+            position: 0,
+        };
+
+        let parsed = parser.parse_or()?;
+
+        if parser.position < parser.tokens.len() {
+            return Err(invalid_filter(format!(
+                "Unexpected content in filter: {filter}"
+            )));
+        }
+
+        Ok(parsed)
+    }
+
+    /// Check whether a SCIM document matches the filter
+    pub fn matches(&self, document: &Value) -> bool {
+        match self {
+            ScimFilter::Present(attribute) => {
+                collect_values(document, &split_attribute_path(attribute))
+                    .into_iter()
+                    .any(is_present)
+            }
+            ScimFilter::Compare {
+                attribute,
+                operator,
+                value,
+            } => {
+                let candidates =
+                    collect_values(document, &split_attribute_path(attribute));
+
+                match operator {
+                    //
+                    // A multi-valued attribute is different from a value
+                    // when none of its values is equal to it
+                    //
+                    ScimFilterOperator::Ne => candidates
+                        .iter()
+                        .all(|c| compare(c, ScimFilterOperator::Ne, value)),
+                    operator => {
+                        candidates.iter().any(|c| compare(c, *operator, value))
+                    }
+                }
+            }
+            ScimFilter::Not(filter) => !filter.matches(document),
+            ScimFilter::And(left, right) => {
+                left.matches(document) && right.matches(document)
+            }
+            ScimFilter::Or(left, right) => {
+                left.matches(document) || right.matches(document)
+            }
+        }
+    }
+}
+
+// ? ---------------------------------------------------------------------------
+// ? TESTS
+// ? ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user() -> Value {
+        serde_json::json!({
+            "userName": "Ana.Silva@example.com",
+            "externalId": "00u1",
+            "name": { "givenName": "Ana", "familyName": "Silva" },
+            "emails": [
+                { "value": "ana.silva@example.com", "type": "work" },
+                { "value": "ana@home.example", "type": "home" }
+            ],
+            "active": true,
+            "urn:mycelium:params:scim:schemas:extension:2.0:Group": {
+                "role": "viewer"
+            }
+        })
+    }
+
+    #[test]
+    fn filters_match_attributes_without_regard_to_case() {
+        for (filter, expected) in [
+            (r#"userName eq "ana.silva@example.com""#, true),
+            (r#"USERNAME Eq "ANA.SILVA@EXAMPLE.COM""#, true),
+            (r#"userName eq "bob@example.com""#, false),
+            (r#"externalId eq "00u1" and active eq true"#, true),
+            (r#"externalId eq "00u2" or name.givenName sw "an""#, true),
+            (r#"emails co "home.example""#, true),
+            (r#"emails.type eq "home""#, true),
+            (r#"emails ne "ana@home.example""#, false),
+            (r#"title pr"#, false),
+            (r#"name.familyName pr"#, true),
+            (r#"not (active eq false)"#, true),
+            (
+                r#"(externalId eq "00u2" or externalId eq "00u1") and userName ew ".com""#,
+                true,
+            ),
+            (
+                r#"urn:ietf:params:scim:schemas:core:2.0:User:userName sw "ana""#,
+                true,
+            ),
+            (
+                r#"urn:mycelium:params:scim:schemas:extension:2.0:Group:role eq "viewer""#,
+                true,
+            ),
+        ] {
+            assert_eq!(
+                ScimFilter::parse(filter).unwrap().matches(&user()),
+                expected,
+                "{filter}"
+            );
+        }
+    }
+
+    #[test]
+    fn malformed_filters_are_rejected_as_invalid_filters() {
+        for filter in [
+            r#"userName eq"#,
+            r#"userName equals "ana""#,
+            r#"(userName eq "ana""#,
+            r#"userName eq "ana"#,
+            r#"emails[type eq "work"].value eq "ana""#,
+            r#"userName eq "ana" extra"#,
+        ] {
+            let err = ScimFilter::parse(filter).unwrap_err();
+
+            assert!(
+                err.has_str_code(ScimErrorType::InvalidFilter.as_str()),
+                "{filter}"
+            );
+        }
+    }
+}
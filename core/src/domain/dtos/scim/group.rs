@@ -0,0 +1,206 @@
+use super::{
+    ScimErrorType, ScimMeta, ScimResource, SCIM_GROUP_EXTENSION_SCHEMA,
+    SCIM_GROUP_SCHEMA,
+};
+
+use mycelium_base::utils::errors::{dto_err, MappedErrors};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// A member of a SCIM group
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimMember {
+    /// The SCIM id of the member user
+    pub value: Uuid,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display: Option<String>,
+
+    #[serde(rename = "$ref", default, skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+}
+
+/// The Mycelium extension of the SCIM groups
+///
+/// Names the subscription account and the guest role the group maps onto.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimGroupTarget {
+    /// The name of the subscription account, created when missing
+    pub account_name: String,
+
+    /// The guest role, by id, slug or name
+    pub role: String,
+}
+
+fn default_group_schemas() -> Vec<String> {
+    vec![SCIM_GROUP_SCHEMA.to_string()]
+}
+
+/// A SCIM group, as defined by RFC 7643, section 4.2
+///
+/// Groups map onto a guest role of a subscription account of the tenant:
+/// members are invited to the role on the account. The account and the role
+/// are given by the Mycelium extension, or by a display name formatted as
+/// `<account name>:<role>`.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimGroup {
+    #[serde(default = "default_group_schemas")]
+    pub schemas: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Uuid>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+
+    pub display_name: String,
+
+    #[serde(default)]
+    pub members: Vec<ScimMember>,
+
+    #[serde(
+        rename = "urn:mycelium:params:scim:schemas:extension:2.0:Group",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub target: Option<ScimGroupTarget>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<ScimMeta>,
+}
+
+impl ScimGroup {
+    /// Parse the group of a SCIM document
+    pub fn from_document(
+        document: serde_json::Value,
+    ) -> Result<Self, MappedErrors> {
+        let group: ScimGroup =
+            serde_json::from_value(document).map_err(|err| {
+                dto_err(format!("Invalid SCIM group: {err}"))
+                    .with_code(ScimErrorType::InvalidValue.as_str())
+                    .with_exp_true()
+            })?;
+
+        if group.display_name.trim().is_empty() {
+            return dto_err("The group display name is empty")
+                .with_code(ScimErrorType::InvalidValue.as_str())
+                .with_exp_true()
+                .as_error();
+        }
+
+        Ok(group)
+    }
+
+    /// Render the group of a provisioned resource
+    pub fn from_resource(
+        resource: &ScimResource,
+    ) -> Result<Self, MappedErrors> {
+        let mut group = Self::from_document(resource.attributes.to_owned())?;
+
+        group.id = Some(resource.id);
+        group.external_id = resource.external_id.to_owned();
+        group.meta = Some(ScimMeta::of(resource));
+
+        Ok(group)
+    }
+
+    /// The attributes stored for the group
+    pub fn to_document(&self) -> Result<serde_json::Value, MappedErrors> {
+        let mut group = self.to_owned();
+        group.id = None;
+        group.meta = None;
+
+        serde_json::to_value(group).map_err(|err| {
+            dto_err(format!("Unable to serialize the SCIM group: {err}"))
+        })
+    }
+
+    /// The subscription account and guest role the group maps onto
+    pub fn target(&self) -> Result<ScimGroupTarget, MappedErrors> {
+        if let Some(target) = self.target.to_owned() {
+            return Ok(target);
+        }
+
+        match self.display_name.rsplit_once(':') {
+            Some((account_name, role))
+                if !account_name.trim().is_empty()
+                    && !role.trim().is_empty() =>
+            {
+                Ok(ScimGroupTarget {
+                    account_name: account_name.trim().to_string(),
+                    role: role.trim().to_string(),
+                })
+            }
+            _ => dto_err(format!(
+                "The group should name its account and role, with the \
+                {SCIM_GROUP_EXTENSION_SCHEMA} extension or a display name \
+                formatted as <account name>:<role>: {}",
+                self.display_name
+            ))
+            .with_code(ScimErrorType::InvalidValue.as_str())
+            .with_exp_true()
+            .as_error(),
+        }
+    }
+
+    /// The SCIM ids of the members
+    pub fn member_ids(&self) -> Vec<Uuid> {
+        let mut ids: Vec<Uuid> = vec![];
+
+        for member in self.members.iter() {
+            if !ids.contains(&member.value) {
+                ids.push(member.value);
+            }
+        }
+
+        ids
+    }
+}
+
+// ? ---------------------------------------------------------------------------
+// ? TESTS
+// ? ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups_name_their_target_by_extension_or_display_name() {
+        let by_name = ScimGroup::from_document(serde_json::json!({
+            "displayName": "Lab A: viewer",
+        }))
+        .unwrap();
+
+        assert_eq!(
+            by_name.target().unwrap(),
+            ScimGroupTarget {
+                account_name: "Lab A".to_string(),
+                role: "viewer".to_string(),
+            }
+        );
+
+        let by_extension = ScimGroup::from_document(serde_json::json!({
+            "schemas": [SCIM_GROUP_SCHEMA, SCIM_GROUP_EXTENSION_SCHEMA],
+            "displayName": "Viewers",
+            SCIM_GROUP_EXTENSION_SCHEMA: {
+                "accountName": "Lab B",
+                "role": "editor",
+            },
+        }))
+        .unwrap();
+
+        assert_eq!(by_extension.target().unwrap().account_name, "Lab B");
+
+        let unnamed = ScimGroup::from_document(serde_json::json!({
+            "displayName": "Viewers",
+        }))
+        .unwrap();
+
+        assert!(unnamed.target().is_err());
+    }
+}
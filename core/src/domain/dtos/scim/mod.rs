@@ -0,0 +1,251 @@
+mod credential;
+mod filter;
+mod group;
+mod patch;
+mod resource;
+mod user;
+
+pub use credential::*;
+pub use filter::*;
+pub use group::*;
+pub use patch::*;
+pub use resource::*;
+pub use user::*;
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt::{self, Display};
+use utoipa::ToSchema;
+
+pub const SCIM_USER_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:User";
+
+pub const SCIM_GROUP_SCHEMA: &str =
+    "urn:ietf:params:scim:schemas:core:2.0:Group";
+
+/// The schema of the Mycelium extension of the groups
+///
+/// Names the subscription account and the guest role a group maps onto.
+pub const SCIM_GROUP_EXTENSION_SCHEMA: &str =
+    "urn:mycelium:params:scim:schemas:extension:2.0:Group";
+
+pub const SCIM_LIST_RESPONSE_SCHEMA: &str =
+    "urn:ietf:params:scim:api:messages:2.0:ListResponse";
+
+pub const SCIM_PATCH_OP_SCHEMA: &str =
+    "urn:ietf:params:scim:api:messages:2.0:PatchOp";
+
+pub const SCIM_ERROR_SCHEMA: &str =
+    "urn:ietf:params:scim:api:messages:2.0:Error";
+
+/// The largest page served by the list endpoints
+pub const SCIM_MAX_PAGE_SIZE: usize = 200;
+
+/// The SCIM error types
+///
+/// Errors of SCIM requests are tagged with the type as their code, so the
+/// port tells the identity provider what was wrong with the request, as
+/// defined by RFC 7644, section 3.12.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ScimErrorType {
+    InvalidFilter,
+    InvalidPath,
+    InvalidSyntax,
+    InvalidValue,
+    NoTarget,
+    Uniqueness,
+}
+
+impl ScimErrorType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScimErrorType::InvalidFilter => "invalidFilter",
+            ScimErrorType::InvalidPath => "invalidPath",
+            ScimErrorType::InvalidSyntax => "invalidSyntax",
+            ScimErrorType::InvalidValue => "invalidValue",
+            ScimErrorType::NoTarget => "noTarget",
+            ScimErrorType::Uniqueness => "uniqueness",
+        }
+    }
+
+    pub fn all() -> Vec<ScimErrorType> {
+        vec![
+            ScimErrorType::InvalidFilter,
+            ScimErrorType::InvalidPath,
+            ScimErrorType::InvalidSyntax,
+            ScimErrorType::InvalidValue,
+            ScimErrorType::NoTarget,
+            ScimErrorType::Uniqueness,
+        ]
+    }
+}
+
+impl Display for ScimErrorType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The metadata of a SCIM resource
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimMeta {
+    pub resource_type: ScimResourceKind,
+
+    pub created: DateTime<Local>,
+
+    pub last_modified: DateTime<Local>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+impl ScimMeta {
+    pub fn of(resource: &ScimResource) -> Self {
+        Self {
+            resource_type: resource.kind,
+            created: resource.created,
+            last_modified: resource.updated.unwrap_or(resource.created),
+            location: None,
+        }
+    }
+}
+
+/// A page of SCIM resources
+#[derive(Clone, Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimListResponse<T: ToSchema> {
+    pub schemas: Vec<String>,
+
+    pub total_results: usize,
+
+    pub start_index: usize,
+
+    pub items_per_page: usize,
+
+    #[serde(rename = "Resources")]
+    pub resources: Vec<T>,
+}
+
+impl<T: ToSchema> ScimListResponse<T> {
+    /// Build a page of the resources
+    ///
+    /// The start index is one-based, as defined by SCIM. A missing count
+    /// serves the largest page.
+    pub fn paginate(
+        resources: Vec<T>,
+        start_index: Option<usize>,
+        count: Option<usize>,
+    ) -> Self {
+        let total_results = resources.len();
+        let start_index = start_index.unwrap_or(1).max(1);
+        let count = count.unwrap_or(SCIM_MAX_PAGE_SIZE).min(SCIM_MAX_PAGE_SIZE);
+
+        let resources: Vec<T> = resources
+            .into_iter()
+            .skip(start_index - 1)
+            .take(count)
+            .collect();
+
+        Self {
+            schemas: vec![SCIM_LIST_RESPONSE_SCHEMA.to_string()],
+            total_results,
+            start_index,
+            items_per_page: resources.len(),
+            resources,
+        }
+    }
+
+    pub fn map<U: ToSchema>(
+        self,
+        f: impl FnMut(T) -> U,
+    ) -> ScimListResponse<U> {
+        ScimListResponse {
+            schemas: self.schemas,
+            total_results: self.total_results,
+            start_index: self.start_index,
+            items_per_page: self.items_per_page,
+            resources: self.resources.into_iter().map(f).collect(),
+        }
+    }
+}
+
+/// Deserialize a boolean sent either as a JSON boolean or as a string
+///
+/// Some identity providers send `"True"` and `"False"` on PATCH requests.
+pub(crate) fn deserialize_lenient_bool<'de, D>(
+    deserializer: D,
+) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::Bool(value) => Ok(value),
+        serde_json::Value::String(value)
+            if value.eq_ignore_ascii_case("true") =>
+        {
+            Ok(true)
+        }
+        serde_json::Value::String(value)
+            if value.eq_ignore_ascii_case("false") =>
+        {
+            Ok(false)
+        }
+        value => Err(serde::de::Error::custom(format!(
+            "Invalid boolean: {value}"
+        ))),
+    }
+}
+
+/// Find the key of an object matching an attribute name
+///
+/// SCIM attribute names are case insensitive.
+pub(crate) fn find_attribute_key(
+    object: &serde_json::Map<String, serde_json::Value>,
+    name: &str,
+) -> Option<String> {
+    object
+        .keys()
+        .find(|key| key.eq_ignore_ascii_case(name))
+        .cloned()
+}
+
+/// Remove the core schema prefix of an attribute path
+///
+/// `urn:ietf:params:scim:schemas:core:2.0:User:userName` is `userName`.
+/// Paths of extension schemas are kept as they are.
+pub(crate) fn strip_core_schema(path: &str) -> &str {
+    for schema in [SCIM_USER_SCHEMA, SCIM_GROUP_SCHEMA] {
+        if let Some(attribute) = path
+            .get(..schema.len())
+            .filter(|prefix| prefix.eq_ignore_ascii_case(schema))
+            .and_then(|_| path[schema.len()..].strip_prefix(':'))
+        {
+            return attribute;
+        }
+    }
+
+    path
+}
+
+/// Split an attribute path into the keys leading to the attribute
+///
+/// Attributes of the extension schemas are nested in an object named after
+/// the schema: `urn:...:Group:accountName` leads to the `accountName` key of
+/// the `urn:...:Group` object.
+pub(crate) fn split_attribute_path(path: &str) -> Vec<String> {
+    let path = strip_core_schema(path);
+
+    if path
+        .get(..4)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("urn:"))
+    {
+        if let Some((schema, attribute)) = path.rsplit_once(':') {
+            let mut keys = vec![schema.to_string()];
+            keys.extend(attribute.split('.').map(|key| key.to_string()));
+
+            return keys;
+        }
+    }
+
+    path.split('.').map(|key| key.to_string()).collect()
+}
@@ -0,0 +1,510 @@
+use super::{
+    find_attribute_key, split_attribute_path, ScimErrorType, ScimFilter,
+    SCIM_PATCH_OP_SCHEMA,
+};
+
+use mycelium_base::utils::errors::{dto_err, MappedErrors};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+use std::str::FromStr;
+use utoipa::ToSchema;
+
+/// The operations of a SCIM PATCH request
+#[derive(Clone, Copy, Debug, Serialize, ToSchema, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ScimPatchOperationKind {
+    Add,
+    Remove,
+    Replace,
+}
+
+impl FromStr for ScimPatchOperationKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "add" => Ok(ScimPatchOperationKind::Add),
+            "remove" => Ok(ScimPatchOperationKind::Remove),
+            "replace" => Ok(ScimPatchOperationKind::Replace),
+            _ => Err(format!("Invalid patch operation: {s}")),
+        }
+    }
+}
+
+//
+// Identity providers do not agree on the case of the operation names, as
+// `replace` and `Replace`.
+//
+impl<'de> Deserialize<'de> for ScimPatchOperationKind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let op = String::deserialize(deserializer)?;
+
+        ScimPatchOperationKind::from_str(&op).map_err(serde::de::Error::custom)
+    }
+}
+
+/// An operation of a SCIM PATCH request
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimPatchOperation {
+    pub op: ScimPatchOperationKind,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<Value>,
+}
+
+/// A SCIM PATCH request, as defined by RFC 7644, section 3.5.2
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+pub struct ScimPatchRequest {
+    #[serde(default = "default_patch_schemas")]
+    pub schemas: Vec<String>,
+
+    #[serde(rename = "Operations")]
+    pub operations: Vec<ScimPatchOperation>,
+}
+
+fn default_patch_schemas() -> Vec<String> {
+    vec![SCIM_PATCH_OP_SCHEMA.to_string()]
+}
+
+/// A parsed PATCH path, as `emails[type eq "work"].value`
+struct ScimPatchPath {
+    keys: Vec<String>,
+    filter: Option<ScimFilter>,
+    sub_attribute: Option<String>,
+}
+
+fn invalid_path(msg: String) -> MappedErrors {
+    dto_err(msg)
+        .with_code(ScimErrorType::InvalidPath.as_str())
+        .with_exp_true()
+}
+
+fn invalid_value(msg: String) -> MappedErrors {
+    dto_err(msg)
+        .with_code(ScimErrorType::InvalidValue.as_str())
+        .with_exp_true()
+}
+
+impl ScimPatchPath {
+    fn parse(path: &str) -> Result<Self, MappedErrors> {
+        let (attribute, filter, sub_attribute) = match path.find('[') {
+            None => (path, None, None),
+            Some(open) => {
+                let close = path.rfind(']').ok_or_else(|| {
+                    invalid_path(format!("Unbalanced brackets: {path}"))
+                })?;
+
+                if close < open {
+                    return Err(invalid_path(format!(
+                        "Unbalanced brackets: {path}"
+                    )));
+                }
+
+                let sub_attribute = match &path[close + 1..] {
+                    "" => None,
+                    rest => match rest.strip_prefix('.') {
+                        Some(sub) if !sub.is_empty() => Some(sub.to_string()),
+                        _ => {
+                            return Err(invalid_path(format!(
+                                "Invalid sub-attribute: {path}"
+                            )))
+                        }
+                    },
+                };
+
+                (
+                    &path[..open],
+                    Some(ScimFilter::parse(&path[open + 1..close]).map_err(
+                        |err| invalid_path(format!("{path}: {}", err.msg())),
+                    )?),
+                    sub_attribute,
+                )
+            }
+        };
+
+        let keys = split_attribute_path(attribute.trim());
+
+        if keys.iter().any(|key| key.is_empty()) {
+            return Err(invalid_path(format!("Invalid path: {path}")));
+        }
+
+        Ok(Self {
+            keys,
+            filter,
+            sub_attribute,
+        })
+    }
+}
+
+/// Get the object holding the last key of a path, creating missing parents
+fn parent_object<'a>(
+    document: &'a mut Value,
+    keys: &[String],
+) -> Result<(&'a mut Map<String, Value>, String), MappedErrors> {
+    let (last, parents) = match keys.split_last() {
+        Some(split) => split,
+        None => return Err(invalid_path("Empty path".to_string())),
+    };
+
+    let mut current = document;
+
+    for key in parents {
+        let object = current.as_object_mut().ok_or_else(|| {
+            invalid_path(format!("The parent of {key} is not an object"))
+        })?;
+
+        let key = find_attribute_key(object, key).unwrap_or(key.to_owned());
+
+        current = object
+            .entry(key)
+            .or_insert_with(|| Value::Object(Map::new()));
+    }
+
+    let object = current.as_object_mut().ok_or_else(|| {
+        invalid_path(format!("The parent of {last} is not an object"))
+    })?;
+
+    let last = find_attribute_key(object, last).unwrap_or(last.to_owned());
+
+    Ok((object, last))
+}
+
+/// Check whether two values of a multi-valued attribute are the same
+///
+/// Complex values are the same when their `value` sub-attributes are.
+fn same_value(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Object(left), Value::Object(right)) => {
+            match (
+                find_attribute_key(left, "value"),
+                find_attribute_key(right, "value"),
+            ) {
+                (Some(left_key), Some(right_key)) => {
+                    left[&left_key] == right[&right_key]
+                }
+                _ => left == right,
+            }
+        }
+        (left, right) => left == right,
+    }
+}
+
+fn merge_values(target: &mut Vec<Value>, values: Value) {
+    let values = match values {
+        Value::Array(values) => values,
+        value => vec![value],
+    };
+
+    for value in values {
+        match target.iter_mut().find(|item| same_value(item, &value)) {
+            Some(item) => *item = value,
+            None => target.push(value),
+        }
+    }
+}
+
+impl ScimPatchOperation {
+    /// Apply the operation to a SCIM document
+    pub fn apply_to(&self, document: &mut Value) -> Result<(), MappedErrors> {
+        let path = match &self.path {
+            Some(path) => path,
+            None => {
+                //
+                // Operations without a path carry an object of attributes,
+                // each applied as if it was the path.
+                //
+                let attributes = match (&self.op, &self.value) {
+                    (ScimPatchOperationKind::Remove, _) => {
+                        return Err(dto_err("Remove operations need a path")
+                            .with_code(ScimErrorType::NoTarget.as_str())
+                            .with_exp_true())
+                    }
+                    (_, Some(Value::Object(attributes))) => attributes,
+                    _ => {
+                        return Err(invalid_value(
+                            "Operations without a path need an object value"
+                                .to_string(),
+                        ))
+                    }
+                };
+
+                for (path, value) in attributes {
+                    ScimPatchOperation {
+                        op: self.op,
+                        path: Some(path.to_owned()),
+                        value: Some(value.to_owned()),
+                    }
+                    .apply_to(document)?;
+                }
+
+                return Ok(());
+            }
+        };
+
+        let path = ScimPatchPath::parse(path)?;
+        let (object, key) = parent_object(document, &path.keys)?;
+
+        match (self.op, path.filter) {
+            (ScimPatchOperationKind::Remove, None) => {
+                match (&self.value, object.get_mut(&key)) {
+                    //
+                    // Removing values from a multi-valued attribute, as sent
+                    // by some identity providers to remove group members.
+                    //
+                    (Some(values), Some(Value::Array(items))) => {
+                        let values = match values {
+                            Value::Array(values) => values.to_owned(),
+                            value => vec![value.to_owned()],
+                        };
+
+                        items.retain(|item| {
+                            !values.iter().any(|value| same_value(item, value))
+                        });
+                    }
+                    _ => {
+                        object.remove(&key);
+                    }
+                }
+            }
+            (op, None) => {
+                let value = self.value.to_owned().ok_or_else(|| {
+                    invalid_value(format!("The {op:?} operation needs a value"))
+                })?;
+
+                match (op, object.get_mut(&key)) {
+                    (
+                        ScimPatchOperationKind::Add,
+                        Some(Value::Array(items)),
+                    ) => merge_values(items, value),
+                    (
+                        ScimPatchOperationKind::Add,
+                        Some(Value::Object(current)),
+                    ) => match value {
+                        Value::Object(value) => current.extend(value),
+                        value => {
+                            object.insert(key, value);
+                        }
+                    },
+                    _ => {
+                        object.insert(key, value);
+                    }
+                }
+            }
+            (op, Some(filter)) => {
+                let items = match object.get_mut(&key) {
+                    Some(Value::Array(items)) => items,
+                    _ if op == ScimPatchOperationKind::Remove => return Ok(()),
+                    _ => {
+                        return Err(dto_err(format!(
+                            "No values of {key} match the filter"
+                        ))
+                        .with_code(ScimErrorType::NoTarget.as_str())
+                        .with_exp_true())
+                    }
+                };
+
+                match (op, path.sub_attribute) {
+                    (ScimPatchOperationKind::Remove, None) => {
+                        items.retain(|item| !filter.matches(item));
+                    }
+                    (ScimPatchOperationKind::Remove, Some(sub)) => {
+                        for item in items.iter_mut() {
+                            if let (true, Some(item)) =
+                                (filter.matches(item), item.as_object_mut())
+                            {
+                                if let Some(sub) =
+                                    find_attribute_key(item, &sub)
+                                {
+                                    item.remove(&sub);
+                                }
+                            }
+                        }
+                    }
+                    (op, sub) => {
+                        let value = self.value.to_owned().ok_or_else(|| {
+                            invalid_value(format!(
+                                "The {op:?} operation needs a value"
+                            ))
+                        })?;
+
+                        let mut matched = false;
+
+                        for item in items.iter_mut() {
+                            if !filter.matches(item) {
+                                continue;
+                            }
+
+                            matched = true;
+
+                            match (&sub, item.as_object_mut(), &value) {
+                                (Some(sub), Some(item), value) => {
+                                    let sub = find_attribute_key(item, sub)
+                                        .unwrap_or(sub.to_owned());
+
+                                    item.insert(sub, value.to_owned());
+                                }
+                                (None, Some(item), Value::Object(value)) => {
+                                    item.extend(value.to_owned());
+                                }
+                                _ => *item = value.to_owned(),
+                            }
+                        }
+
+                        if !matched {
+                            return Err(dto_err(format!(
+                                "No values of {key} match the filter"
+                            ))
+                            .with_code(ScimErrorType::NoTarget.as_str())
+                            .with_exp_true());
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl ScimPatchRequest {
+    /// Apply the operations in order to a SCIM document
+    ///
+    /// The document is left untouched when any operation fails.
+    pub fn apply_to(&self, document: &Value) -> Result<Value, MappedErrors> {
+        let mut patched = document.to_owned();
+
+        for operation in self.operations.iter() {
+            operation.apply_to(&mut patched)?;
+        }
+
+        Ok(patched)
+    }
+}
+
+/// A change to a provisioned SCIM resource
+///
+/// Resources are replaced as a whole by PUT requests, and patched by PATCH
+/// requests.
+#[derive(Clone, Debug)]
+pub enum ScimUpdate {
+    Replace(Value),
+    Patch(ScimPatchRequest),
+}
+
+impl ScimUpdate {
+    /// The SCIM document resulting from the change
+    pub fn apply_to(&self, document: &Value) -> Result<Value, MappedErrors> {
+        match self {
+            ScimUpdate::Replace(replacement) => Ok(replacement.to_owned()),
+            ScimUpdate::Patch(patch) => patch.apply_to(document),
+        }
+    }
+}
+
+// ? ---------------------------------------------------------------------------
+// ? TESTS
+// ? ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patch(operations: Value) -> ScimPatchRequest {
+        serde_json::from_value(serde_json::json!({
+            "schemas": [SCIM_PATCH_OP_SCHEMA],
+            "Operations": operations,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn patches_replace_add_and_remove_attributes() {
+        let document = serde_json::json!({
+            "userName": "ana@example.com",
+            "active": true,
+            "name": { "givenName": "Ana" },
+            "emails": [{ "value": "ana@example.com", "type": "work" }],
+        });
+
+        let patched = patch(serde_json::json!([
+            { "op": "Replace", "value": { "active": false, "name.familyName": "Silva" } },
+            { "op": "add", "path": "emails", "value": [{ "value": "ana@home.example", "type": "home" }] },
+            { "op": "replace", "path": "emails[type eq \"work\"].value", "value": "ana.silva@example.com" },
+            { "op": "remove", "path": "name.givenName" },
+            { "op": "add", "path": "urn:ietf:params:scim:schemas:core:2.0:User:displayName", "value": "Ana Silva" },
+        ]))
+        .apply_to(&document)
+        .unwrap();
+
+        assert_eq!(
+            patched,
+            serde_json::json!({
+                "userName": "ana@example.com",
+                "active": false,
+                "displayName": "Ana Silva",
+                "name": { "familyName": "Silva" },
+                "emails": [
+                    { "value": "ana.silva@example.com", "type": "work" },
+                    { "value": "ana@home.example", "type": "home" },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn patches_add_and_remove_group_members() {
+        let document = serde_json::json!({
+            "displayName": "Lab A:viewer",
+            "members": [{ "value": "a" }, { "value": "b" }],
+        });
+
+        let patched = patch(serde_json::json!([
+            { "op": "add", "path": "members", "value": [{ "value": "b" }, { "value": "c" }] },
+            { "op": "remove", "path": "members[value eq \"a\"]" },
+            { "op": "remove", "path": "members", "value": [{ "value": "b" }] },
+        ]))
+        .apply_to(&document)
+        .unwrap();
+
+        assert_eq!(patched["members"], serde_json::json!([{ "value": "c" }]));
+
+        let emptied = patch(serde_json::json!([
+            { "op": "remove", "path": "members" },
+        ]))
+        .apply_to(&document)
+        .unwrap();
+
+        assert!(emptied.get("members").is_none());
+    }
+
+    #[test]
+    fn invalid_patches_leave_the_document_untouched() {
+        let document = serde_json::json!({ "userName": "ana@example.com" });
+
+        for (operations, error_type) in [
+            (
+                serde_json::json!([{ "op": "remove" }]),
+                ScimErrorType::NoTarget,
+            ),
+            (
+                serde_json::json!([{ "op": "replace", "path": "emails[type eq]", "value": "x" }]),
+                ScimErrorType::InvalidPath,
+            ),
+            (
+                serde_json::json!([{ "op": "add", "path": "title" }]),
+                ScimErrorType::InvalidValue,
+            ),
+        ] {
+            let err = patch(operations).apply_to(&document).unwrap_err();
+
+            assert!(err.has_str_code(error_type.as_str()), "{error_type}");
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// The kinds of SCIM resources
+#[derive(
+    Clone, Copy, Debug, Deserialize, Serialize, ToSchema, Eq, PartialEq,
+)]
+pub enum ScimResourceKind {
+    User,
+    Group,
+}
+
+impl Display for ScimResourceKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScimResourceKind::User => write!(f, "User"),
+            ScimResourceKind::Group => write!(f, "Group"),
+        }
+    }
+}
+
+impl FromStr for ScimResourceKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "User" => Ok(ScimResourceKind::User),
+            "Group" => Ok(ScimResourceKind::Group),
+            _ => Err(format!("Invalid SCIM resource kind: {s}")),
+        }
+    }
+}
+
+/// A resource provisioned through SCIM
+///
+/// Links the resource known by the identity provider to the Mycelium records
+/// it maps onto. Users map onto a personal account, through `account_id`.
+/// Groups map onto a guest role of a subscription account, through
+/// `account_id` and `guest_role_id`.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimResource {
+    /// The SCIM id of the resource
+    pub id: Uuid,
+
+    /// The tenant the resource was provisioned into
+    pub tenant_id: Uuid,
+
+    /// The resource kind
+    pub kind: ScimResourceKind,
+
+    /// The id given by the identity provider
+    pub external_id: Option<String>,
+
+    /// The user name of users, or the display name of groups
+    pub display_name: String,
+
+    /// The personal account of users, or the subscription account of groups
+    pub account_id: Option<Uuid>,
+
+    /// The guest role granted to the members of groups
+    pub guest_role_id: Option<Uuid>,
+
+    /// Whether the personal account of a user was created by the provisioning
+    ///
+    /// Only accounts created by the provisioning are deactivated when their
+    /// user is deprovisioned. Users matched to an existing account only lose
+    /// the guest roles granted by the tenant groups.
+    pub provisioned: bool,
+
+    /// The attributes last written by the identity provider
+    pub attributes: serde_json::Value,
+
+    /// When the resource was provisioned
+    pub created: DateTime<Local>,
+
+    /// When the resource was last changed
+    pub updated: Option<DateTime<Local>>,
+}
+
+impl ScimResource {
+    pub fn new(
+        tenant_id: Uuid,
+        kind: ScimResourceKind,
+        display_name: String,
+        attributes: serde_json::Value,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            tenant_id,
+            kind,
+            external_id: None,
+            display_name,
+            account_id: None,
+            guest_role_id: None,
+            provisioned: false,
+            attributes,
+            created: Local::now(),
+            updated: None,
+        }
+    }
+}
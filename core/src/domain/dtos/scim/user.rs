@@ -0,0 +1,229 @@
+use super::{
+    deserialize_lenient_bool, ScimErrorType, ScimMeta, ScimResource,
+    SCIM_USER_SCHEMA,
+};
+use crate::domain::dtos::email::Email;
+
+use mycelium_base::utils::errors::{dto_err, MappedErrors};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// The name of a SCIM user
+#[derive(
+    Clone, Debug, Default, Deserialize, Serialize, ToSchema, PartialEq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimName {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub formatted: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub family_name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub given_name: Option<String>,
+}
+
+/// An email of a SCIM user
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimEmail {
+    pub value: String,
+
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+
+    #[serde(default, deserialize_with = "deserialize_lenient_bool")]
+    pub primary: bool,
+}
+
+fn default_user_schemas() -> Vec<String> {
+    vec![SCIM_USER_SCHEMA.to_string()]
+}
+
+fn default_active() -> bool {
+    true
+}
+
+/// A SCIM user, as defined by RFC 7643, section 4.1
+///
+/// Users map onto a Mycelium user and its personal account. The email of the
+/// user is the primary email, or the user name when no email is given.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimUser {
+    #[serde(default = "default_user_schemas")]
+    pub schemas: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Uuid>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+
+    pub user_name: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<ScimName>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub emails: Vec<ScimEmail>,
+
+    #[serde(
+        default = "default_active",
+        deserialize_with = "deserialize_lenient_bool"
+    )]
+    pub active: bool,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<ScimMeta>,
+}
+
+impl ScimUser {
+    /// Parse the user of a SCIM document
+    pub fn from_document(
+        document: serde_json::Value,
+    ) -> Result<Self, MappedErrors> {
+        let user: ScimUser =
+            serde_json::from_value(document).map_err(|err| {
+                dto_err(format!("Invalid SCIM user: {err}"))
+                    .with_code(ScimErrorType::InvalidValue.as_str())
+                    .with_exp_true()
+            })?;
+
+        if user.user_name.trim().is_empty() {
+            return dto_err("The user name is empty")
+                .with_code(ScimErrorType::InvalidValue.as_str())
+                .with_exp_true()
+                .as_error();
+        }
+
+        Ok(user)
+    }
+
+    /// Render the user of a provisioned resource
+    pub fn from_resource(
+        resource: &ScimResource,
+    ) -> Result<Self, MappedErrors> {
+        let mut user = Self::from_document(resource.attributes.to_owned())?;
+
+        user.id = Some(resource.id);
+        user.external_id = resource.external_id.to_owned();
+        user.meta = Some(ScimMeta::of(resource));
+
+        Ok(user)
+    }
+
+    /// The attributes stored for the user
+    ///
+    /// The id and the metadata are owned by the server, and are not stored.
+    pub fn to_document(&self) -> Result<serde_json::Value, MappedErrors> {
+        let mut user = self.to_owned();
+        user.id = None;
+        user.meta = None;
+
+        serde_json::to_value(user).map_err(|err| {
+            dto_err(format!("Unable to serialize the SCIM user: {err}"))
+        })
+    }
+
+    /// The email of the Mycelium user
+    pub fn email(&self) -> Result<Email, MappedErrors> {
+        let email = self
+            .emails
+            .iter()
+            .find(|email| email.primary)
+            .or(self.emails.first())
+            .map(|email| email.value.to_owned())
+            .unwrap_or(self.user_name.to_owned());
+
+        Email::from_string(email.to_owned()).map_err(|_| {
+            dto_err(format!("The user has no valid email: {email}"))
+                .with_code(ScimErrorType::InvalidValue.as_str())
+                .with_exp_true()
+        })
+    }
+
+    /// The name of the personal account of the user
+    pub fn account_name(&self) -> String {
+        if let Some(display_name) = self
+            .display_name
+            .as_ref()
+            .filter(|name| !name.trim().is_empty())
+        {
+            return display_name.to_owned();
+        }
+
+        if let Some(name) = self.name.as_ref() {
+            if let Some(formatted) = name
+                .formatted
+                .as_ref()
+                .filter(|name| !name.trim().is_empty())
+            {
+                return formatted.to_owned();
+            }
+
+            let parts: Vec<String> = [&name.given_name, &name.family_name]
+                .into_iter()
+                .flatten()
+                .filter(|part| !part.trim().is_empty())
+                .cloned()
+                .collect();
+
+            if !parts.is_empty() {
+                return parts.join(" ");
+            }
+        }
+
+        self.user_name.to_owned()
+    }
+}
+
+// ? ---------------------------------------------------------------------------
+// ? TESTS
+// ? ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn users_parse_lenient_booleans_and_pick_their_email() {
+        let user = ScimUser::from_document(serde_json::json!({
+            "schemas": [SCIM_USER_SCHEMA],
+            "userName": "ana",
+            "name": { "givenName": "Ana", "familyName": "Silva" },
+            "emails": [
+                { "value": "ana@home.example", "primary": "False" },
+                { "value": "ana@example.com", "primary": "True" },
+            ],
+            "active": "False",
+        }))
+        .unwrap();
+
+        assert!(!user.active);
+        assert_eq!(user.email().unwrap().email(), "ana@example.com");
+        assert_eq!(user.account_name(), "Ana Silva");
+
+        let document = user.to_document().unwrap();
+
+        assert!(document.get("id").is_none());
+        assert_eq!(ScimUser::from_document(document).unwrap(), user);
+    }
+
+    #[test]
+    fn users_without_an_email_are_rejected() {
+        let user = ScimUser::from_document(serde_json::json!({
+            "userName": "ana",
+        }))
+        .unwrap();
+
+        assert!(user.active);
+        assert!(user.email().is_err());
+        assert!(ScimUser::from_document(serde_json::json!({})).is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use super::guest_role::Permission;
+use super::{access_policy::AccessPolicy, guest_role::Permission};
 
 use serde::{Deserialize, Serialize};
 use utoipa::{ToResponse, ToSchema};
@@ -61,6 +61,21 @@ pub enum SecurityGroup {
     ///
     #[serde(rename_all = "camelCase")]
     ProtectedByRoles(Vec<PermissionedRole>),
+    ///
+    /// Protect the route with the user profile filtered by named permissions
+    ///
+    /// Only licenses whose guest role grants all the listed permissions, as
+    /// `invoices:approve`, are kept in the profile.
+    ///
+    ProtectedByPermissions(Vec<String>),
+    ///
+    /// Protect the route with a declarative access policy
+    ///
+    /// The full user profile is injected when all policy rules pass against
+    /// the request and profile attributes.
+    ///
+    #[serde(rename_all = "camelCase")]
+    ProtectedByPolicy(AccessPolicy),
 }
 
 impl ToString for SecurityGroup {
@@ -88,6 +103,20 @@ impl ToString for SecurityGroup {
                         .join(", ")
                 )
             }
+            SecurityGroup::ProtectedByPermissions(permissions) => {
+                format!("protected_by_permissions({})", permissions.join(", "))
+            }
+            SecurityGroup::ProtectedByPolicy(policy) => {
+                format!(
+                    "protected_by_policy({})",
+                    policy
+                        .rules
+                        .iter()
+                        .map(|rule| rule.name.to_owned())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )
+            }
         }
     }
 }
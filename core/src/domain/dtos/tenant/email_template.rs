@@ -0,0 +1,638 @@
+use chrono::{DateTime, Local};
+use mycelium_base::utils::errors::{dto_err, MappedErrors};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap, fmt::Display, io::Write, str::FromStr, time::Duration,
+};
+use tera::{Context, Tera, Value};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Maximum size of a tenant email template body, in bytes
+pub const MAX_EMAIL_TEMPLATE_BODY_SIZE: usize = 64 * 1024;
+
+/// Maximum size of a tenant email template subject, in bytes
+pub const MAX_EMAIL_TEMPLATE_SUBJECT_SIZE: usize = 255;
+
+/// Maximum size of a rendered tenant email subject or body, in bytes
+pub const MAX_RENDERED_EMAIL_SIZE: usize = 256 * 1024;
+
+/// Time a tenant template is given to render
+const EMAIL_TEMPLATE_RENDER_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Tera built-ins not available to tenant templates
+///
+/// `get_env` would expose the process environment and `range` allows
+/// arbitrarily large loops.
+const SANDBOX_DISABLED_FUNCTIONS: [&str; 2] = ["get_env", "range"];
+
+/// Notification emails a tenant may override
+///
+/// Only notifications sent on behalf of a tenant are listed. The variant name
+/// matches the file template under `templates/<locale>/email/`.
+#[derive(
+    Clone, Copy, Debug, Deserialize, Serialize, ToSchema, Eq, Hash, PartialEq,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum EmailTemplateKind {
+    /// Login link sent by `request_magic_link`
+    MagicLinkRequest,
+
+    /// Verification code sent when a password reset starts
+    PasswordResetInitiated,
+
+    /// Notice sent once the password was reset
+    PasswordResetConfirmation,
+
+    /// Invitation sent when a user is guested to an account
+    GuestToSubscriptionAccount,
+
+    /// Notice sent when a connection string is created
+    CreateConnectionString,
+
+    /// Reminder of an invitation not accepted yet
+    GuestInvitationReminder,
+
+    /// Warning sent before a connection string expires
+    ConnectionStringExpiring,
+
+    /// Periodic digest of the tenant membership changes, sent to its owners
+    TenantMembershipDigest,
+
+    /// Warning sent before a time-bound guest grant expires
+    GuestAccessExpiring,
+
+    /// Warning sent to the tenant owners before a trashed tenant is purged
+    TenantPurgeScheduled,
+}
+
+impl EmailTemplateKind {
+    /// The file template path prefix, as used by `dispatch_notification`
+    pub fn template_path(&self) -> String {
+        format!("email/{self}")
+    }
+
+    /// Resolve the kind from a file template path prefix
+    pub fn from_template_path(path: &str) -> Option<Self> {
+        path.strip_prefix("email/")
+            .and_then(|name| Self::from_str(name).ok())
+    }
+
+    /// Parameters injected by the use-case sending this notification, with
+    /// placeholder values used on validation and previews
+    pub fn sample_parameters(&self) -> Vec<(&'static str, &'static str)> {
+        match self {
+            EmailTemplateKind::MagicLinkRequest => vec![(
+                "magic_link_url",
+                "https://example.com/magic-link/display?token=preview",
+            )],
+            EmailTemplateKind::PasswordResetInitiated
+            | EmailTemplateKind::PasswordResetConfirmation => {
+                vec![("verification_code", "123456")]
+            }
+            EmailTemplateKind::GuestToSubscriptionAccount => vec![
+                ("account_name", "ACME RESEARCH"),
+                ("role_name", "VIEWER"),
+                ("role_permissions", "read"),
+            ],
+            EmailTemplateKind::CreateConnectionString => {
+                vec![("expires_in", "30 days")]
+            }
+            EmailTemplateKind::GuestInvitationReminder => vec![
+                ("account_name", "ACME RESEARCH"),
+                ("role_name", "VIEWER"),
+                ("role_permissions", "read"),
+                ("invited_at", "2026-10-12"),
+            ],
+            EmailTemplateKind::ConnectionStringExpiring => vec![
+                ("connection_string_name", "ci-pipeline"),
+                ("expires_at", "2026-10-26 12:00 UTC"),
+            ],
+            EmailTemplateKind::TenantMembershipDigest => vec![
+                ("period_start", "2026-10-12"),
+                ("period_end", "2026-10-19"),
+                ("invited_count", "2"),
+                ("accepted_count", "1"),
+                ("revoked_count", "1"),
+                ("invited_members", "alice@example.com\nbob@example.com"),
+                ("revoked_members", "carol@example.com"),
+            ],
+            EmailTemplateKind::GuestAccessExpiring => vec![
+                ("account_name", "ACME RESEARCH"),
+                ("role_name", "VIEWER"),
+                ("expires_at", "2026-10-26 12:00 UTC"),
+            ],
+            EmailTemplateKind::TenantPurgeScheduled => vec![
+                ("tenant_name", "ACME"),
+                ("trashed_at", "2026-09-26 12:00 UTC"),
+                ("purge_at", "2026-10-26 12:00 UTC"),
+            ],
+        }
+    }
+
+    /// Context used to validate and preview templates of this kind
+    ///
+    /// Besides the kind parameters, it carries the tenant values injected on
+    /// every notification.
+    pub fn sample_context(&self) -> Context {
+        let mut context = Context::new();
+
+        context.insert("domain_name", "Example Tenant");
+        context.insert("domain_url", "https://example.com");
+        context.insert("support_email", "support@example.com");
+        context.insert("tenant_id", &Uuid::nil().to_string());
+
+        for (key, value) in self.sample_parameters() {
+            context.insert(key, value);
+        }
+
+        context
+    }
+}
+
+impl Display for EmailTemplateKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EmailTemplateKind::MagicLinkRequest => {
+                write!(f, "magic-link-request")
+            }
+            EmailTemplateKind::PasswordResetInitiated => {
+                write!(f, "password-reset-initiated")
+            }
+            EmailTemplateKind::PasswordResetConfirmation => {
+                write!(f, "password-reset-confirmation")
+            }
+            EmailTemplateKind::GuestToSubscriptionAccount => {
+                write!(f, "guest-to-subscription-account")
+            }
+            EmailTemplateKind::CreateConnectionString => {
+                write!(f, "create-connection-string")
+            }
+            EmailTemplateKind::GuestInvitationReminder => {
+                write!(f, "guest-invitation-reminder")
+            }
+            EmailTemplateKind::ConnectionStringExpiring => {
+                write!(f, "connection-string-expiring")
+            }
+            EmailTemplateKind::TenantMembershipDigest => {
+                write!(f, "tenant-membership-digest")
+            }
+            EmailTemplateKind::GuestAccessExpiring => {
+                write!(f, "guest-access-expiring")
+            }
+            EmailTemplateKind::TenantPurgeScheduled => {
+                write!(f, "tenant-purge-scheduled")
+            }
+        }
+    }
+}
+
+impl FromStr for EmailTemplateKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "magic-link-request" => Ok(EmailTemplateKind::MagicLinkRequest),
+            "password-reset-initiated" => {
+                Ok(EmailTemplateKind::PasswordResetInitiated)
+            }
+            "password-reset-confirmation" => {
+                Ok(EmailTemplateKind::PasswordResetConfirmation)
+            }
+            "guest-to-subscription-account" => {
+                Ok(EmailTemplateKind::GuestToSubscriptionAccount)
+            }
+            "create-connection-string" => {
+                Ok(EmailTemplateKind::CreateConnectionString)
+            }
+            "guest-invitation-reminder" => {
+                Ok(EmailTemplateKind::GuestInvitationReminder)
+            }
+            "connection-string-expiring" => {
+                Ok(EmailTemplateKind::ConnectionStringExpiring)
+            }
+            "tenant-membership-digest" => {
+                Ok(EmailTemplateKind::TenantMembershipDigest)
+            }
+            "guest-access-expiring" => {
+                Ok(EmailTemplateKind::GuestAccessExpiring)
+            }
+            "tenant-purge-scheduled" => {
+                Ok(EmailTemplateKind::TenantPurgeScheduled)
+            }
+            _ => Err(format!("Invalid email template kind: {s}")),
+        }
+    }
+}
+
+/// A tenant override of a notification email
+///
+/// Overrides are keyed by tenant, kind and locale. When no override matches
+/// the notification locale, the file templates are used.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantEmailTemplate {
+    /// The unique identifier of the template
+    pub id: Option<Uuid>,
+
+    /// The tenant owning the template
+    pub tenant_id: Uuid,
+
+    /// The overridden notification
+    pub kind: EmailTemplateKind,
+
+    /// The locale the template is written in (e.g. `en-us`)
+    pub locale: String,
+
+    /// The subject template, rendered as plain text
+    pub subject: String,
+
+    /// The HTML body template
+    ///
+    /// Variables are HTML-escaped. Templates are rendered in isolation, so
+    /// `extends`, `include` and `import` are not available.
+    pub body: String,
+
+    /// The template creation date
+    pub created: DateTime<Local>,
+
+    /// The template last update date
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated: Option<DateTime<Local>>,
+}
+
+/// A rendered email, as returned by template previews
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RenderedEmailTemplate {
+    /// The rendered subject
+    pub subject: String,
+
+    /// The rendered HTML body
+    pub body: String,
+
+    /// The plain-text alternative sent with the HTML body
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_body: Option<String>,
+}
+
+impl TenantEmailTemplate {
+    /// Create a validated template
+    ///
+    /// The template is rendered against the kind sample context, so syntax
+    /// errors and unknown variables are reported before it is stored.
+    pub async fn new(
+        tenant_id: Uuid,
+        kind: EmailTemplateKind,
+        locale: String,
+        subject: String,
+        body: String,
+    ) -> Result<Self, MappedErrors> {
+        let template = Self {
+            id: None,
+            tenant_id,
+            kind,
+            locale: locale.trim().to_lowercase(),
+            subject,
+            body,
+            created: Local::now(),
+            updated: None,
+        };
+
+        template.validate().await?;
+
+        Ok(template)
+    }
+
+    /// Check the template limits and render it against sample data
+    pub async fn validate(&self) -> Result<(), MappedErrors> {
+        if !is_valid_locale(&self.locale) {
+            return dto_err(format!(
+                "Invalid locale '{}': expected a language code as `en` or \
+                 `pt-br`",
+                self.locale
+            ))
+            .as_error();
+        }
+
+        if self.subject.trim().is_empty() {
+            return dto_err("Email template subject should not be empty")
+                .as_error();
+        }
+
+        if self.subject.len() > MAX_EMAIL_TEMPLATE_SUBJECT_SIZE {
+            return dto_err(format!(
+                "Email template subject exceeds {MAX_EMAIL_TEMPLATE_SUBJECT_SIZE} bytes"
+            ))
+            .as_error();
+        }
+
+        if self.subject.contains(['\r', '\n']) {
+            return dto_err("Email template subject should be a single line")
+                .as_error();
+        }
+
+        if self.body.trim().is_empty() {
+            return dto_err("Email template body should not be empty")
+                .as_error();
+        }
+
+        if self.body.len() > MAX_EMAIL_TEMPLATE_BODY_SIZE {
+            return dto_err(format!(
+                "Email template body exceeds {MAX_EMAIL_TEMPLATE_BODY_SIZE} bytes"
+            ))
+            .as_error();
+        }
+
+        self.render(&self.kind.sample_context()).await?;
+
+        Ok(())
+    }
+
+    /// Render the subject and body in a sandboxed engine
+    ///
+    /// Returns the `(subject, body)` pair. Line breaks produced in the subject
+    /// are collapsed, since headers must be single-line.
+    ///
+    /// Tenants write these templates, so rendering runs on the blocking pool
+    /// under a budget: it fails once the output outgrows
+    /// `MAX_RENDERED_EMAIL_SIZE`, which stops runaway loops, or when it
+    /// outlasts `EMAIL_TEMPLATE_RENDER_TIMEOUT`.
+    pub async fn render(
+        &self,
+        context: &Context,
+    ) -> Result<(String, String), MappedErrors> {
+        let template = self.to_owned();
+        let context = context.to_owned();
+
+        let rendering = tokio::task::spawn_blocking(move || {
+            template.render_within_size(&context)
+        });
+
+        match tokio::time::timeout(EMAIL_TEMPLATE_RENDER_TIMEOUT, rendering)
+            .await
+        {
+            Ok(Ok(rendered)) => rendered,
+            Ok(Err(err)) => {
+                dto_err(format!("Unable to render email template: {err}"))
+                    .as_error()
+            }
+            Err(_) => dto_err(format!(
+                "Email template rendering exceeded {} seconds",
+                EMAIL_TEMPLATE_RENDER_TIMEOUT.as_secs()
+            ))
+            .as_error(),
+        }
+    }
+
+    fn render_within_size(
+        &self,
+        context: &Context,
+    ) -> Result<(String, String), MappedErrors> {
+        let mut tera = Tera::default();
+
+        for name in SANDBOX_DISABLED_FUNCTIONS {
+            tera.register_function(
+                name,
+                move |_: &HashMap<String, Value>| -> tera::Result<Value> {
+                    Err(tera::Error::msg(format!(
+                        "Function `{name}` is not available in tenant \
+                         templates"
+                    )))
+                },
+            );
+        }
+
+        //
+        // The `.html` suffix turns auto-escaping on for the body only.
+        //
+        if let Err(err) = tera.add_raw_templates(vec![
+            ("subject", self.subject.as_str()),
+            ("body.html", self.body.as_str()),
+        ]) {
+            return dto_err(format!(
+                "Invalid email template: {}",
+                describe_tera_error(&err)
+            ))
+            .as_error();
+        }
+
+        let subject = match render_capped(&tera, "subject", context) {
+            Ok(subject) => {
+                subject.split_whitespace().collect::<Vec<_>>().join(" ")
+            }
+            Err(err) => {
+                return dto_err(format!(
+                    "Unable to render email template subject: {}",
+                    describe_tera_error(&err)
+                ))
+                .as_error()
+            }
+        };
+
+        let body = match render_capped(&tera, "body.html", context) {
+            Ok(body) => body,
+            Err(err) => {
+                return dto_err(format!(
+                    "Unable to render email template body: {}",
+                    describe_tera_error(&err)
+                ))
+                .as_error()
+            }
+        };
+
+        Ok((subject, body))
+    }
+}
+
+/// Render `name`, aborting as soon as the output exceeds
+/// `MAX_RENDERED_EMAIL_SIZE`
+fn render_capped(
+    tera: &Tera,
+    name: &str,
+    context: &Context,
+) -> tera::Result<String> {
+    let mut output = CappedOutput(Vec::new());
+
+    tera.render_to(name, context, &mut output)?;
+
+    String::from_utf8(output.0).map_err(tera::Error::msg)
+}
+
+/// A buffer refusing writes past `MAX_RENDERED_EMAIL_SIZE`
+struct CappedOutput(Vec<u8>);
+
+impl Write for CappedOutput {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if self.0.len() + buf.len() > MAX_RENDERED_EMAIL_SIZE {
+            return Err(std::io::Error::other(format!(
+                "rendered email exceeds {MAX_RENDERED_EMAIL_SIZE} bytes"
+            )));
+        }
+
+        self.0.extend_from_slice(buf);
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Accept `ll` and `ll-rr` lowercase language tags, as the template folders
+fn is_valid_locale(locale: &str) -> bool {
+    let mut parts = locale.split('-');
+
+    let is_tag = |part: Option<&str>| {
+        part.is_some_and(|p| {
+            p.len() == 2 && p.chars().all(|c| c.is_ascii_lowercase())
+        })
+    };
+
+    match (parts.next(), parts.next(), parts.next()) {
+        (language, None, None) => is_tag(language),
+        (language, region @ Some(_), None) => {
+            is_tag(language) && is_tag(region)
+        }
+        _ => false,
+    }
+}
+
+/// Tera nests the useful message in the error sources
+fn describe_tera_error(err: &tera::Error) -> String {
+    let mut message = err.to_string();
+    let mut source = std::error::Error::source(err);
+
+    while let Some(inner) = source {
+        message.push_str(&format!(": {inner}"));
+        source = inner.source();
+    }
+
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn template(
+        subject: &str,
+        body: &str,
+    ) -> Result<TenantEmailTemplate, MappedErrors> {
+        TenantEmailTemplate::new(
+            Uuid::new_v4(),
+            EmailTemplateKind::MagicLinkRequest,
+            "pt-BR".to_string(),
+            subject.to_string(),
+            body.to_string(),
+        )
+        .await
+    }
+
+    #[test]
+    fn email_template_kind_round_trips_through_template_path() {
+        for kind in [
+            EmailTemplateKind::MagicLinkRequest,
+            EmailTemplateKind::PasswordResetInitiated,
+            EmailTemplateKind::PasswordResetConfirmation,
+            EmailTemplateKind::GuestToSubscriptionAccount,
+            EmailTemplateKind::CreateConnectionString,
+            EmailTemplateKind::GuestInvitationReminder,
+            EmailTemplateKind::ConnectionStringExpiring,
+            EmailTemplateKind::TenantMembershipDigest,
+            EmailTemplateKind::GuestAccessExpiring,
+            EmailTemplateKind::TenantPurgeScheduled,
+        ] {
+            let json = serde_json::to_string(&kind).unwrap();
+            assert_eq!(json, format!("\"{kind}\""));
+            assert_eq!(
+                EmailTemplateKind::from_template_path(&kind.template_path()),
+                Some(kind)
+            );
+        }
+
+        assert_eq!(
+            EmailTemplateKind::from_template_path("email/mfa-disable"),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn valid_template_renders_escaped_variables() {
+        let template = template(
+            "Sign in to {{ domain_name }}",
+            "<h1 style=\"color: #ff6600\">{{ domain_name }}</h1>\
+             <a href=\"{{ magic_link_url }}\">Sign in</a>",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(template.locale, "pt-br");
+
+        let mut context = template.kind.sample_context();
+        context.insert("domain_name", "<b>Acme</b>");
+
+        let (subject, body) = template.render(&context).await.unwrap();
+
+        assert_eq!(subject, "Sign in to <b>Acme</b>");
+        assert!(body.contains("&lt;b&gt;Acme&lt;&#x2F;b&gt;"));
+        assert!(body.contains("color: #ff6600"));
+    }
+
+    #[tokio::test]
+    async fn invalid_templates_are_rejected() {
+        for (subject, body) in [
+            ("Hello", "{{ unknown_variable }}"),
+            ("Hello", "{% if domain_name %}unclosed"),
+            ("Hello", "{% extends \"en-us/email/base.jinja\" %}"),
+            ("Hello", "{% include \"en-us/email/base.jinja\" %}"),
+            ("Hello", "{{ get_env(name=\"HOME\") }}"),
+            (
+                "Hello",
+                "{% for i in range(end=100000000) %}{{ i }}{% endfor %}",
+            ),
+            ("Hello\r\nBcc: someone@example.com", "<p>Hi</p>"),
+            ("", "<p>Hi</p>"),
+        ] {
+            assert!(
+                template(subject, body).await.is_err(),
+                "template should be rejected: {subject:?} / {body:?}"
+            );
+        }
+
+        let oversized = "a".repeat(MAX_EMAIL_TEMPLATE_BODY_SIZE + 1);
+        assert!(template("Hello", &oversized).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn pathological_loops_exhaust_the_render_budget() {
+        //
+        // Three nested loops over the characters of a 2000 characters string
+        // would write 8 billion times: the output cap stops them early.
+        //
+        let body = format!(
+            "{{% set chars = \"{}\" | split(pat=\"\") %}}\
+             {{% for a in chars %}}{{% for b in chars %}}\
+             {{% for c in chars %}}{{{{ a }}}}{{{{ b }}}}{{{{ c }}}}\
+             {{% endfor %}}{{% endfor %}}{{% endfor %}}",
+            "x".repeat(2000)
+        );
+
+        let started = std::time::Instant::now();
+        let rejected = template("Hello", &body).await.unwrap_err();
+
+        assert!(started.elapsed() < EMAIL_TEMPLATE_RENDER_TIMEOUT);
+        assert!(rejected.to_string().contains("exceeds"));
+    }
+
+    #[test]
+    fn locales_follow_template_folders() {
+        assert!(is_valid_locale("en"));
+        assert!(is_valid_locale("en-us"));
+        assert!(!is_valid_locale("en_us"));
+        assert!(!is_valid_locale("english"));
+        assert!(!is_valid_locale("../en"));
+        assert!(!is_valid_locale("en-us-x"));
+    }
+}
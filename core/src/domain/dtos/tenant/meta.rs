@@ -107,6 +107,20 @@ pub enum TenantMetaKey {
     Custom(String),
 }
 
+impl TenantMetaKey {
+    /// If child tenants inherit the key from their parent tenant
+    ///
+    /// Secrets are encrypted with the key of the tenant owning them, so child
+    /// tenants can not use them and do not inherit them.
+    pub fn is_inheritable(&self) -> bool {
+        !matches!(
+            self,
+            TenantMetaKey::TelegramBotToken
+                | TenantMetaKey::TelegramWebhookSecret
+        )
+    }
+}
+
 impl Display for TenantMetaKey {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
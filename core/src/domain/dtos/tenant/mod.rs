@@ -1,6 +1,8 @@
+mod email_template;
 mod meta;
 mod status;
 
+pub use email_template::*;
 pub use meta::TenantMetaKey;
 pub use status::TenantStatus;
 
@@ -31,6 +33,13 @@ pub struct Tenant {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
+    /// The parent tenant
+    ///
+    /// Owners of the parent tenant administer the child tenants, and child
+    /// tenants inherit the meta of the parent tenant.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<Uuid>,
+
     /// The owner of the tenant
     ///
     /// This is the email of the tenant owner, which is also the pub owner. The
@@ -58,6 +67,13 @@ pub struct Tenant {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub meta: Option<HashMap<TenantMetaKey, String>>,
 
+    /// Meta information inherited from the ancestor tenants
+    ///
+    /// Only the keys the tenant does not override are included, with the
+    /// value of the nearest ancestor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inherited_meta: Option<TenantMeta>,
+
     /// The status of the tenant
     ///
     /// This is the status of the tenant. The status is a key-value pair of
@@ -84,16 +100,74 @@ impl Tenant {
             id: None,
             name,
             description,
+            parent_id: None,
             owners,
             manager: None,
             tags: None,
             meta: None,
+            inherited_meta: None,
             status: None,
             created: Local::now(),
             updated: None,
         }
     }
 
+    /// Set the meta inherited from the ancestor tenants
+    ///
+    /// The ancestors meta are ordered from the parent to the root tenant, so
+    /// the nearest ancestor wins. Keys set on the tenant override the
+    /// inherited ones.
+    pub fn with_inherited_meta(self, ancestors_meta: Vec<TenantMeta>) -> Self {
+        let own_meta = self.meta.to_owned().unwrap_or_default();
+        let mut inherited_meta = TenantMeta::new();
+
+        for meta in ancestors_meta {
+            for (key, value) in meta {
+                if key.is_inheritable()
+                    && !own_meta.contains_key(&key)
+                    && !inherited_meta.contains_key(&key)
+                {
+                    inherited_meta.insert(key, value);
+                }
+            }
+        }
+
+        Self {
+            inherited_meta: match inherited_meta.is_empty() {
+                true => None,
+                false => Some(inherited_meta),
+            },
+            ..self
+        }
+    }
+
+    /// The meta in effect for the tenant
+    ///
+    /// The meta of the tenant over the meta inherited from the ancestors.
+    pub fn effective_meta(&self) -> TenantMeta {
+        let mut meta = self.inherited_meta.to_owned().unwrap_or_default();
+        meta.extend(self.meta.to_owned().unwrap_or_default());
+        meta
+    }
+
+    /// The most recent status of the tenant
+    pub fn current_status(&self) -> Option<&TenantStatus> {
+        self.status
+            .as_ref()?
+            .iter()
+            .max_by_key(|status| status.at())
+    }
+
+    /// The moment the tenant was moved to the trash
+    ///
+    /// Returns `None` unless the current status is `Trashed`, so restored
+    /// tenants are no longer considered trashed.
+    pub fn trashed_at(&self) -> Option<DateTime<Local>> {
+        self.current_status()
+            .filter(|status| status.is_trashed())
+            .map(TenantStatus::at)
+    }
+
     pub fn tenant_string_or_error(&self) -> Result<String, MappedErrors> {
         if let Some(id) = self.id {
             Ok(format!("tid/{}", id))
@@ -102,3 +176,96 @@ impl Tenant {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meta(entries: Vec<(TenantMetaKey, &str)>) -> TenantMeta {
+        entries
+            .into_iter()
+            .map(|(key, value)| (key, value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn child_tenants_inherit_the_meta_they_do_not_override() {
+        let mut tenant = Tenant::new_with_owners(
+            "Child".to_string(),
+            None,
+            Children::Records(vec![]),
+        );
+
+        tenant.meta = Some(meta(vec![(TenantMetaKey::Locale, "pt-br")]));
+
+        let tenant = tenant.with_inherited_meta(vec![
+            meta(vec![
+                (TenantMetaKey::Locale, "en-us"),
+                (TenantMetaKey::SupportEmail, "help@parent.example"),
+            ]),
+            meta(vec![
+                (TenantMetaKey::SupportEmail, "help@root.example"),
+                (TenantMetaKey::WebsiteUrl, "https://root.example"),
+                (TenantMetaKey::TelegramBotToken, "encrypted"),
+            ]),
+        ]);
+
+        assert_eq!(
+            tenant.inherited_meta,
+            Some(meta(vec![
+                (TenantMetaKey::SupportEmail, "help@parent.example"),
+                (TenantMetaKey::WebsiteUrl, "https://root.example"),
+            ]))
+        );
+
+        let effective_meta = tenant.effective_meta();
+
+        assert_eq!(
+            effective_meta.get(&TenantMetaKey::Locale),
+            Some(&"pt-br".to_string())
+        );
+        assert_eq!(
+            effective_meta.get(&TenantMetaKey::SupportEmail),
+            Some(&"help@parent.example".to_string())
+        );
+        assert!(!effective_meta.contains_key(&TenantMetaKey::TelegramBotToken));
+    }
+
+    #[test]
+    fn trashed_at_follows_the_most_recent_status() {
+        let mut tenant = Tenant::new_with_owners(
+            "Tenant".to_string(),
+            None,
+            Children::Records(vec![]),
+        );
+
+        assert_eq!(tenant.trashed_at(), None);
+
+        let trashed_at = Local::now() - chrono::Duration::days(2);
+
+        tenant.status = Some(vec![
+            TenantStatus::Trashed {
+                at: trashed_at,
+                by: "owner".to_string(),
+            },
+            TenantStatus::Verified {
+                at: trashed_at - chrono::Duration::days(1),
+                by: "owner".to_string(),
+            },
+        ]);
+
+        assert_eq!(tenant.trashed_at(), Some(trashed_at));
+
+        tenant
+            .status
+            .as_mut()
+            .unwrap()
+            .push(TenantStatus::Restored {
+                at: trashed_at + chrono::Duration::days(1),
+                by: "owner".to_string(),
+            });
+
+        assert_eq!(tenant.trashed_at(), None);
+        assert!(tenant.current_status().unwrap().is_restored());
+    }
+}
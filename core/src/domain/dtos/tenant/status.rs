@@ -7,9 +7,24 @@ use utoipa::ToSchema;
 )]
 #[serde(rename_all = "camelCase")]
 pub enum TenantStatus {
-    Verified { at: DateTime<Local>, by: String },
-    Trashed { at: DateTime<Local>, by: String },
-    Archived { at: DateTime<Local>, by: String },
+    Verified {
+        at: DateTime<Local>,
+        by: String,
+    },
+    Trashed {
+        at: DateTime<Local>,
+        by: String,
+    },
+    Archived {
+        at: DateTime<Local>,
+        by: String,
+    },
+
+    /// The tenant was taken out of the trash before being purged
+    Restored {
+        at: DateTime<Local>,
+        by: String,
+    },
 }
 
 impl TenantStatus {
@@ -24,4 +39,18 @@ impl TenantStatus {
     pub fn is_verified(&self) -> bool {
         matches!(self, TenantStatus::Verified { .. })
     }
+
+    pub fn is_restored(&self) -> bool {
+        matches!(self, TenantStatus::Restored { .. })
+    }
+
+    /// The moment the status was recorded
+    pub fn at(&self) -> DateTime<Local> {
+        match self {
+            TenantStatus::Verified { at, .. }
+            | TenantStatus::Trashed { at, .. }
+            | TenantStatus::Archived { at, .. }
+            | TenantStatus::Restored { at, .. } => *at,
+        }
+    }
 }
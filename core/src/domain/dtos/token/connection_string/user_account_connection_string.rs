@@ -422,6 +422,7 @@ mod tests {
             domain_name: SecretResolver::Value("test".to_string()),
             locale: None,
             token_expiration: SecretResolver::Value(30),
+            invitation_expiration: SecretResolver::Value(604_800),
             noreply_name: None,
             noreply_email: SecretResolver::Value("test".to_string()),
             support_name: None,
@@ -432,6 +433,8 @@ mod tests {
             hmac_primary_version: primary,
             hmac_secrets: HmacSecretSet::new(entries),
             staff_bootstrap_secret: None,
+            sms_provider: None,
+            delivery_webhook_secret: None,
         }
     }
 
@@ -535,6 +535,7 @@ mod tests {
             domain_url: None,
             locale: None,
             token_expiration: SecretResolver::Value(30),
+            invitation_expiration: SecretResolver::Value(604_800),
             noreply_name: None,
             noreply_email: SecretResolver::Value("test".to_string()),
             support_name: None,
@@ -548,6 +549,8 @@ mod tests {
                 secret: SecretResolver::Value("test-hmac".to_string()),
             }]),
             staff_bootstrap_secret: None,
+            sms_provider: None,
+            delivery_webhook_secret: None,
         }
     }
 
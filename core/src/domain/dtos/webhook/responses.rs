@@ -1,4 +1,5 @@
 use super::WebHookTrigger;
+use crate::domain::dtos::data_subject::pseudonymise_text;
 
 use base64::{engine::general_purpose, Engine};
 use chrono::{DateTime, Local};
@@ -251,4 +252,44 @@ impl WebHookPayloadArtifact {
             ..self.clone()
         })
     }
+
+    /// Pseudonymise an encoded artifact
+    ///
+    /// Replace every mention of `subject` in the payload and in the bodies of
+    /// the propagation responses by `pseudonym`. Returns `None` when the
+    /// artifact does not mention `subject`.
+    ///
+    pub fn pseudonymise(
+        &self,
+        subject: &str,
+        pseudonym: &str,
+    ) -> Result<Option<Self>, MappedErrors> {
+        let mut decoded = self.decode_payload()?;
+        let mut changed = false;
+
+        if let Some(payload) =
+            pseudonymise_text(&decoded.payload, subject, pseudonym)
+        {
+            decoded.payload = payload;
+            changed = true;
+        }
+
+        if let Some(propagations) = decoded.propagations.as_mut() {
+            for body in propagations.iter_mut().filter_map(|r| r.body.as_mut())
+            {
+                if let Some(replaced) =
+                    pseudonymise_text(body, subject, pseudonym)
+                {
+                    *body = replaced;
+                    changed = true;
+                }
+            }
+        }
+
+        if !changed {
+            return Ok(None);
+        }
+
+        decoded.encode_payload().map(Some)
+    }
 }
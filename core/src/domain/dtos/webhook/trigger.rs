@@ -26,6 +26,26 @@ pub enum WebHookTrigger {
     UserAccountUpdated,
     #[serde(rename = "userAccount.deleted")]
     UserAccountDeleted,
+
+    // ? -----------------------------------------------------------------------
+    // ? Guest invitation related actions
+    // ? -----------------------------------------------------------------------
+    #[serde(rename = "guestInvitation.created")]
+    GuestInvitationCreated,
+    #[serde(rename = "guestInvitation.resent")]
+    GuestInvitationResent,
+    #[serde(rename = "guestInvitation.accepted")]
+    GuestInvitationAccepted,
+    #[serde(rename = "guestInvitation.cancelled")]
+    GuestInvitationCancelled,
+    #[serde(rename = "guestInvitation.expired")]
+    GuestInvitationExpired,
+
+    // ? -----------------------------------------------------------------------
+    // ? Guest user related actions
+    // ? -----------------------------------------------------------------------
+    #[serde(rename = "guestUser.revoked")]
+    GuestUserRevoked,
 }
 
 impl Display for WebHookTrigger {
@@ -43,6 +63,20 @@ impl Display for WebHookTrigger {
             Self::UserAccountCreated => write!(f, "userAccount.created"),
             Self::UserAccountUpdated => write!(f, "userAccount.updated"),
             Self::UserAccountDeleted => write!(f, "userAccount.deleted"),
+            Self::GuestInvitationCreated => {
+                write!(f, "guestInvitation.created")
+            }
+            Self::GuestInvitationResent => write!(f, "guestInvitation.resent"),
+            Self::GuestInvitationAccepted => {
+                write!(f, "guestInvitation.accepted")
+            }
+            Self::GuestInvitationCancelled => {
+                write!(f, "guestInvitation.cancelled")
+            }
+            Self::GuestInvitationExpired => {
+                write!(f, "guestInvitation.expired")
+            }
+            Self::GuestUserRevoked => write!(f, "guestUser.revoked"),
         }
     }
 }
@@ -64,6 +98,12 @@ impl FromStr for WebHookTrigger {
             "userAccount.created" => Ok(Self::UserAccountCreated),
             "userAccount.updated" => Ok(Self::UserAccountUpdated),
             "userAccount.deleted" => Ok(Self::UserAccountDeleted),
+            "guestInvitation.created" => Ok(Self::GuestInvitationCreated),
+            "guestInvitation.resent" => Ok(Self::GuestInvitationResent),
+            "guestInvitation.accepted" => Ok(Self::GuestInvitationAccepted),
+            "guestInvitation.cancelled" => Ok(Self::GuestInvitationCancelled),
+            "guestInvitation.expired" => Ok(Self::GuestInvitationExpired),
+            "guestUser.revoked" => Ok(Self::GuestUserRevoked),
             _ => Err(format!("Unknown webhook trigger: {}", s)),
         }
     }
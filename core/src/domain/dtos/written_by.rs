@@ -93,6 +93,23 @@ impl WrittenBy {
     fn encode_email(email: &str) -> String {
         general_purpose::STANDARD.encode(email.as_bytes())
     }
+
+    /// Replace the actor email by `pseudonym` when it is `subject`
+    ///
+    /// Returns `None` when the actor is someone else or has no email.
+    pub fn pseudonymise(&self, subject: &str, pseudonym: &str) -> Option<Self> {
+        let encoded = self.email.as_ref()?;
+        let decoded = general_purpose::STANDARD.decode(encoded).ok()?;
+
+        if !String::from_utf8_lossy(&decoded).eq_ignore_ascii_case(subject) {
+            return None;
+        }
+
+        Some(Self {
+            email: Some(Self::encode_email(pseudonym)),
+            ..self.to_owned()
+        })
+    }
 }
 
 impl Default for WrittenBy {
@@ -169,6 +186,26 @@ mod tests {
         assert_eq!(written_by.from, None);
     }
 
+    #[test]
+    fn pseudonymise_replaces_only_the_subject_email() {
+        let id = Uuid::new_v4();
+        let written_by =
+            WrittenBy::new_from_user_with_email(id, "Staff@Example.com");
+
+        let pseudonymised = written_by
+            .pseudonymise("staff@example.com", "erased@erased.invalid")
+            .unwrap();
+
+        assert_eq!(
+            pseudonymised,
+            WrittenBy::new_from_user_with_email(id, "erased@erased.invalid")
+        );
+        assert!(written_by.pseudonymise("other@example.com", "x").is_none());
+        assert!(WrittenBy::new_from_user(id)
+            .pseudonymise("staff@example.com", "x")
+            .is_none());
+    }
+
     #[test]
     fn deserializes_pre_existing_records_without_an_email_field() {
         let legacy_json = serde_json::json!({
@@ -0,0 +1,26 @@
+use crate::domain::dtos::bulk_import::BulkImport;
+
+use async_trait::async_trait;
+use mycelium_base::{
+    entities::{FetchManyResponseKind, FetchResponseKind},
+    utils::errors::MappedErrors,
+};
+use shaku::Interface;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait BulkImportFetching: Interface + Send + Sync {
+    async fn get(
+        &self,
+        tenant_id: Uuid,
+        id: Uuid,
+    ) -> Result<FetchResponseKind<BulkImport, Uuid>, MappedErrors>;
+
+    /// List the jobs of a tenant, the most recent first
+    async fn list(
+        &self,
+        tenant_id: Uuid,
+        page_size: Option<i32>,
+        skip: Option<i32>,
+    ) -> Result<FetchManyResponseKind<BulkImport>, MappedErrors>;
+}
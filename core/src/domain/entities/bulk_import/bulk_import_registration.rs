@@ -0,0 +1,22 @@
+use crate::domain::dtos::bulk_import::BulkImport;
+
+use async_trait::async_trait;
+#[cfg(test)]
+use mockall::automock;
+use mycelium_base::{
+    entities::GetOrCreateResponseKind, utils::errors::MappedErrors,
+};
+use shaku::Interface;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait BulkImportRegistration: Interface + Send + Sync {
+    /// Register a bulk import job
+    ///
+    /// A job of the same tenant with the same checksum is returned instead of
+    /// registering the job again.
+    async fn get_or_create(
+        &self,
+        bulk_import: BulkImport,
+    ) -> Result<GetOrCreateResponseKind<BulkImport>, MappedErrors>;
+}
@@ -0,0 +1,34 @@
+use crate::domain::dtos::bulk_import::{
+    BulkImport, BulkImportRowResult, BulkImportStatus,
+};
+
+use async_trait::async_trait;
+use chrono::Duration;
+use mycelium_base::{
+    entities::UpdatingResponseKind, utils::errors::MappedErrors,
+};
+use shaku::Interface;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait BulkImportUpdating: Interface + Send + Sync {
+    /// Claim the oldest pending job
+    ///
+    /// The claim is atomic: when several dispatchers try to claim the same
+    /// job, a single one gets it. Jobs running for longer than `stale_after`
+    /// are claimed again, since their dispatcher is assumed gone. Returns
+    /// `None` when no job is waiting.
+    async fn claim_next(
+        &self,
+        claimed_by: String,
+        stale_after: Duration,
+    ) -> Result<Option<BulkImport>, MappedErrors>;
+
+    /// Record the outcome of a job
+    async fn finish(
+        &self,
+        id: Uuid,
+        status: BulkImportStatus,
+        results: Vec<BulkImportRowResult>,
+    ) -> Result<UpdatingResponseKind<BulkImport>, MappedErrors>;
+}
@@ -0,0 +1,7 @@
+mod bulk_import_fetching;
+mod bulk_import_registration;
+mod bulk_import_updating;
+
+pub use bulk_import_fetching::*;
+pub use bulk_import_registration::*;
+pub use bulk_import_updating::*;
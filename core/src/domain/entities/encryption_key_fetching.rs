@@ -8,10 +8,15 @@ use uuid::Uuid;
 /// Implementations must fetch the tenant's wrapped DEK from the database,
 /// unwrap it with the supplied KEK, and return the plaintext DEK.  When a
 /// tenant has no DEK yet, the implementation generates one, wraps it, and
-/// persists it before returning.
+/// persists it before returning. Tenants without a row, such as purged ones,
+/// are never provisioned: only the system tenant row is created on first use.
 ///
 /// `tenant_id = None` addresses the system DEK used for accounts that have no
 /// tenant affiliation (e.g. Staff).
+///
+/// `fetch_dek` is the read-only counterpart for callers that must not write,
+/// such as integrity verification: it returns `None` instead of provisioning
+/// when the tenant has no DEK.
 #[async_trait]
 pub trait EncryptionKeyFetching: Interface + Send + Sync {
     async fn get_or_provision_dek(
@@ -19,4 +24,10 @@ pub trait EncryptionKeyFetching: Interface + Send + Sync {
         tenant_id: Option<Uuid>,
         kek: &[u8; 32],
     ) -> Result<[u8; 32], MappedErrors>;
+
+    async fn fetch_dek(
+        &self,
+        tenant_id: Option<Uuid>,
+        kek: &[u8; 32],
+    ) -> Result<Option<[u8; 32]>, MappedErrors>;
 }
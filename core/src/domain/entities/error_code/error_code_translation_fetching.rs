@@ -0,0 +1,20 @@
+use crate::domain::dtos::error_code::ErrorCodeTranslation;
+
+use async_trait::async_trait;
+#[cfg(test)]
+use mockall::automock;
+use mycelium_base::{
+    entities::FetchManyResponseKind, utils::errors::MappedErrors,
+};
+use shaku::Interface;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ErrorCodeTranslationFetching: Interface + Send + Sync {
+    /// List every translation of an error code
+    async fn list(
+        &self,
+        prefix: String,
+        code: i32,
+    ) -> Result<FetchManyResponseKind<ErrorCodeTranslation>, MappedErrors>;
+}
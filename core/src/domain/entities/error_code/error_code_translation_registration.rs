@@ -0,0 +1,33 @@
+use crate::domain::dtos::error_code::ErrorCodeTranslation;
+
+use async_trait::async_trait;
+#[cfg(test)]
+use mockall::automock;
+use mycelium_base::{
+    entities::{CreateResponseKind, DeletionResponseKind},
+    utils::errors::MappedErrors,
+};
+use shaku::Interface;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ErrorCodeTranslationRegistration: Interface + Send + Sync {
+    /// Register a translation, keeping the existing one for the locale
+    async fn create(
+        &self,
+        translation: ErrorCodeTranslation,
+    ) -> Result<CreateResponseKind<ErrorCodeTranslation>, MappedErrors>;
+
+    /// Register a translation, replacing the existing one for the locale
+    async fn upsert(
+        &self,
+        translation: ErrorCodeTranslation,
+    ) -> Result<ErrorCodeTranslation, MappedErrors>;
+
+    async fn delete(
+        &self,
+        prefix: String,
+        code: i32,
+        locale: String,
+    ) -> Result<DeletionResponseKind<(String, i32, String)>, MappedErrors>;
+}
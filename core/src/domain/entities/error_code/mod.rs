@@ -1,9 +1,13 @@
 mod error_code_deletion;
 mod error_code_fetching;
 mod error_code_registration;
+mod error_code_translation_fetching;
+mod error_code_translation_registration;
 mod error_code_updating;
 
 pub use error_code_deletion::ErrorCodeDeletion;
 pub use error_code_fetching::ErrorCodeFetching;
 pub use error_code_registration::ErrorCodeRegistration;
+pub use error_code_translation_fetching::*;
+pub use error_code_translation_registration::*;
 pub use error_code_updating::ErrorCodeUpdating;
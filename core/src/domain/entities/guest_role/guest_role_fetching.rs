@@ -20,8 +20,13 @@ pub trait GuestRoleFetching: Interface + Send + Sync {
         id: Uuid,
     ) -> Result<FetchResponseKind<GuestRole, Uuid>, MappedErrors>;
 
+    /// List guest roles
+    ///
+    /// Instance-wide roles are always listed. When `tenant_id` is informed,
+    /// the roles of the tenant are listed too.
     async fn list(
         &self,
+        tenant_id: Option<Uuid>,
         name: Option<String>,
         slug: Option<String>,
         system: Option<bool>,
@@ -1,6 +1,9 @@
-use crate::domain::dtos::guest_user::GuestUser;
+use crate::domain::dtos::guest_user::{
+    ExpiringGuestGrant, GuestUser, PendingGuestInvitation,
+};
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use mycelium_base::{
     entities::FetchManyResponseKind, utils::errors::MappedErrors,
 };
@@ -15,4 +18,43 @@ pub trait GuestUserFetching: Interface + Send + Sync {
         page_size: Option<i32>,
         skip: Option<i32>,
     ) -> Result<FetchManyResponseKind<GuestUser>, MappedErrors>;
+
+    /// List the invitations not accepted yet, created in
+    /// `[created_from, created_before)`
+    ///
+    /// Invitations to deleted accounts and expired invitations are not
+    /// listed.
+    async fn list_pending_invitations(
+        &self,
+        created_from: DateTime<Utc>,
+        created_before: DateTime<Utc>,
+    ) -> Result<FetchManyResponseKind<PendingGuestInvitation>, MappedErrors>;
+
+    /// List the invitations to an account not accepted yet
+    ///
+    /// Expired invitations are listed until the scheduler removes them.
+    async fn list_account_invitations(
+        &self,
+        account_id: Uuid,
+    ) -> Result<FetchManyResponseKind<PendingGuestInvitation>, MappedErrors>;
+
+    /// List the invitations not accepted yet that expired before
+    /// `expired_before`
+    ///
+    /// Invitations to deleted accounts are not listed.
+    async fn list_expired_invitations(
+        &self,
+        expired_before: DateTime<Utc>,
+    ) -> Result<FetchManyResponseKind<PendingGuestInvitation>, MappedErrors>;
+
+    /// List the guest grants whose end date is in
+    /// `[valid_until_from, valid_until_before)`
+    ///
+    /// Without a lower bound, every grant ending before `valid_until_before`
+    /// is listed. Grants of deleted accounts are not listed.
+    async fn list_expiring_grants(
+        &self,
+        valid_until_from: Option<DateTime<Utc>>,
+        valid_until_before: DateTime<Utc>,
+    ) -> Result<FetchManyResponseKind<ExpiringGuestGrant>, MappedErrors>;
 }
@@ -1,8 +1,10 @@
 use crate::domain::dtos::{
-    guest_role::Permission, guest_user_on_account::GuestUserOnAccount,
+    guest_role::Permission, guest_user::GuestUser,
+    guest_user_on_account::GuestUserOnAccount,
 };
 
 use async_trait::async_trait;
+use chrono::{DateTime, Local};
 use mycelium_base::{
     entities::UpdatingResponseKind, utils::errors::MappedErrors,
 };
@@ -11,6 +13,9 @@ use uuid::Uuid;
 
 #[async_trait]
 pub trait GuestUserOnAccountUpdating: Interface + Send + Sync {
+    /// Accept the invitation of the guest to the account
+    ///
+    /// Expired invitations are not accepted.
     async fn accept_invitation(
         &self,
         guest_role_name: String,
@@ -22,4 +27,23 @@ pub trait GuestUserOnAccountUpdating: Interface + Send + Sync {
         &self,
         guest_user_on_account: GuestUserOnAccount,
     ) -> Result<UpdatingResponseKind<GuestUserOnAccount>, MappedErrors>;
+
+    /// Set the validity window of the grant of a guest user to an account
+    async fn update_validity(
+        &self,
+        guest_user_id: Uuid,
+        account_id: Uuid,
+        valid_from: Option<DateTime<Local>>,
+        valid_until: Option<DateTime<Local>>,
+    ) -> Result<UpdatingResponseKind<GuestUserOnAccount>, MappedErrors>;
+
+    /// Mark the invitation of a guest user as sent again
+    ///
+    /// The invitation may be accepted until `expires_at`. Accepted
+    /// invitations are not updated.
+    async fn renew_invitation(
+        &self,
+        guest_user_id: Uuid,
+        expires_at: DateTime<Local>,
+    ) -> Result<UpdatingResponseKind<GuestUser>, MappedErrors>;
 }
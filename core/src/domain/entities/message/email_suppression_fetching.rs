@@ -0,0 +1,26 @@
+use crate::domain::dtos::{email::Email, email_delivery::EmailSuppression};
+
+use async_trait::async_trait;
+#[cfg(test)]
+use mockall::automock;
+use mycelium_base::{
+    entities::{FetchManyResponseKind, FetchResponseKind},
+    utils::errors::MappedErrors,
+};
+use shaku::Interface;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait EmailSuppressionFetching: Interface + Send + Sync {
+    async fn get(
+        &self,
+        email: Email,
+    ) -> Result<FetchResponseKind<EmailSuppression, String>, MappedErrors>;
+
+    /// List suppressed addresses, newest first
+    async fn list(
+        &self,
+        page_size: Option<i32>,
+        skip: Option<i32>,
+    ) -> Result<FetchManyResponseKind<EmailSuppression>, MappedErrors>;
+}
@@ -0,0 +1,25 @@
+use crate::domain::dtos::{email::Email, email_delivery::EmailSuppression};
+
+use async_trait::async_trait;
+#[cfg(test)]
+use mockall::automock;
+use mycelium_base::{
+    entities::DeletionResponseKind, utils::errors::MappedErrors,
+};
+use shaku::Interface;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait EmailSuppressionRegistration: Interface + Send + Sync {
+    /// Add an address to the suppression list, keeping the reason of an
+    /// earlier entry
+    async fn register(
+        &self,
+        suppression: EmailSuppression,
+    ) -> Result<(), MappedErrors>;
+
+    async fn delete(
+        &self,
+        email: Email,
+    ) -> Result<DeletionResponseKind<String>, MappedErrors>;
+}
@@ -0,0 +1,24 @@
+use crate::domain::dtos::email_delivery::{
+    MessageDelivery, MessageDeliveryStatus,
+};
+
+use async_trait::async_trait;
+#[cfg(test)]
+use mockall::automock;
+use mycelium_base::{
+    entities::FetchManyResponseKind, utils::errors::MappedErrors,
+};
+use shaku::Interface;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait MessageDeliveryFetching: Interface + Send + Sync {
+    /// List recorded messages, newest first
+    async fn list(
+        &self,
+        recipient: Option<String>,
+        status: Option<MessageDeliveryStatus>,
+        page_size: Option<i32>,
+        skip: Option<i32>,
+    ) -> Result<FetchManyResponseKind<MessageDelivery>, MappedErrors>;
+}
@@ -0,0 +1,31 @@
+use crate::domain::dtos::email_delivery::{
+    MessageDelivery, MessageDeliveryStatus,
+};
+
+use async_trait::async_trait;
+#[cfg(test)]
+use mockall::automock;
+use mycelium_base::utils::errors::MappedErrors;
+use shaku::Interface;
+use uuid::Uuid;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait MessageDeliveryRegistration: Interface + Send + Sync {
+    /// Record a message that left the queue, replacing the record of a
+    /// previous attempt
+    async fn register(
+        &self,
+        delivery: MessageDelivery,
+    ) -> Result<(), MappedErrors>;
+
+    /// Update the status of a recorded message
+    ///
+    /// Returns `false` when no message has the given id.
+    async fn update_status(
+        &self,
+        id: Uuid,
+        status: MessageDeliveryStatus,
+        detail: Option<String>,
+    ) -> Result<bool, MappedErrors>;
+}
@@ -1,5 +1,13 @@
+mod email_suppression_fetching;
+mod email_suppression_registration;
+mod message_delivery_fetching;
+mod message_delivery_registration;
 mod message_read;
 mod message_write;
 
+pub use email_suppression_fetching::*;
+pub use email_suppression_registration::*;
+pub use message_delivery_fetching::*;
+pub use message_delivery_registration::*;
 pub use message_read::*;
 pub use message_write::*;
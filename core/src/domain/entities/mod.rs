@@ -1,5 +1,6 @@
 mod account;
 mod account_tag;
+mod bulk_import;
 mod encryption_key_fetching;
 mod error_code;
 mod guest_role;
@@ -9,9 +10,12 @@ mod instance_settings;
 mod kv_artifact;
 mod licensed_resource;
 mod message;
+mod notification;
 mod profile;
 mod resource_audit_log;
 mod route;
+mod scheduled_job;
+mod scim;
 mod service;
 mod telegram;
 mod tenant;
@@ -22,6 +26,7 @@ mod webhook;
 
 pub use account::*;
 pub use account_tag::*;
+pub use bulk_import::*;
 pub use encryption_key_fetching::*;
 pub use error_code::*;
 pub use guest_role::*;
@@ -31,9 +36,12 @@ pub use instance_settings::*;
 pub use kv_artifact::*;
 pub use licensed_resource::*;
 pub use message::*;
+pub use notification::*;
 pub use profile::*;
 pub use resource_audit_log::*;
 pub use route::*;
+pub use scheduled_job::*;
+pub use scim::*;
 pub use service::*;
 pub use telegram::*;
 pub use tenant::*;
@@ -0,0 +1,18 @@
+use crate::domain::dtos::{email::Email, notification::NotificationRecipient};
+
+use async_trait::async_trait;
+#[cfg(test)]
+use mockall::automock;
+use mycelium_base::{entities::FetchResponseKind, utils::errors::MappedErrors};
+use shaku::Interface;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait NotificationRecipientFetching: Interface + Send + Sync {
+    /// Fetch the notification preferences and channel addresses stored in
+    /// the meta of the personal account of the user owning the email
+    async fn get_by_email(
+        &self,
+        email: Email,
+    ) -> Result<FetchResponseKind<NotificationRecipient, String>, MappedErrors>;
+}
@@ -1,5 +1,7 @@
+mod resource_audit_log_archival;
 mod resource_audit_log_fetching;
 mod resource_audit_log_registration;
 
+pub use resource_audit_log_archival::*;
 pub use resource_audit_log_fetching::*;
 pub use resource_audit_log_registration::*;
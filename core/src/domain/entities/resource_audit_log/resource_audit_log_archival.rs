@@ -0,0 +1,62 @@
+use crate::domain::dtos::resource_audit_log::{
+    NewResourceAuditLogEvent, ResourceAuditArchive, ResourceAuditLog,
+    ResourceAuditRetentionPolicy,
+};
+
+use async_trait::async_trait;
+#[cfg(test)]
+use mockall::automock;
+use mycelium_base::utils::errors::MappedErrors;
+use shaku::Interface;
+use uuid::Uuid;
+
+/// The only way rows ever leave `resource_audit_log`, or see their content
+/// change. Both backends refuse, at the database level, to delete a row no
+/// archive record covers, to update anything but the pseudonymised fields of
+/// a row, and to touch a row of a chain on legal hold.
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ResourceAuditLogArchival: Interface + Send + Sync {
+    /// Create or replace the retention policy of a chain.
+    async fn upsert_retention_policy(
+        &self,
+        policy: ResourceAuditRetentionPolicy,
+    ) -> Result<ResourceAuditRetentionPolicy, MappedErrors>;
+
+    /// Record an archive and delete the rows it covers, atomically. Fails if
+    /// the chain is on legal hold or if the archive does not start right
+    /// after the chain's latest archive (e.g. a concurrent run won).
+    /// Returns the number of deleted rows.
+    async fn register_archive_and_purge(
+        &self,
+        archive: ResourceAuditArchive,
+    ) -> Result<u64, MappedErrors>;
+
+    /// Delete the rows of a chain already covered by one of its archives
+    /// (typically rows restored for querying). A chain on legal hold is left
+    /// untouched. Returns the number of deleted rows.
+    async fn purge_archived_rows(
+        &self,
+        chain_tenant_id: Option<Uuid>,
+    ) -> Result<u64, MappedErrors>;
+
+    /// Insert archived rows back into the log, keeping their ids and chain
+    /// links. Rows already present are skipped. Returns the number of
+    /// inserted rows.
+    async fn restore_rows(
+        &self,
+        rows: Vec<ResourceAuditLog>,
+    ) -> Result<u64, MappedErrors>;
+
+    /// Overwrite the `metadata` and `performed_by` of rows pseudonymised by a
+    /// data subject erasure, stamping their `redacted_at`, and append the
+    /// `erasure` event carrying the redaction records to its chain in the
+    /// same transaction, so a row is never left redacted without the record
+    /// vouching for it. Rows of a chain on legal hold are left untouched.
+    /// Returns the number of updated rows.
+    async fn redact_rows(
+        &self,
+        rows: Vec<ResourceAuditLog>,
+        erasure: NewResourceAuditLogEvent,
+    ) -> Result<u64, MappedErrors>;
+}
@@ -1,8 +1,11 @@
 use crate::domain::dtos::resource_audit_log::{
-    ResourceAuditLog, ResourceAuditResourceType,
+    ResourceAuditArchive, ResourceAuditCheckpoint, ResourceAuditLog,
+    ResourceAuditLogCursor, ResourceAuditLogFilter, ResourceAuditResourceType,
+    ResourceAuditRetentionPolicy,
 };
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 #[cfg(test)]
 use mockall::automock;
 use mycelium_base::{
@@ -26,4 +29,59 @@ pub trait ResourceAuditLogFetching: Interface + Send + Sync {
         page_size: i32,
         skip: i32,
     ) -> Result<FetchManyResponseKind<ResourceAuditLog>, MappedErrors>;
+
+    /// List up to `page_size` rows matching `filter`, newest first
+    /// (`created_at`, then `id`, both descending). With a `cursor`, only the
+    /// rows ordered after it are listed.
+    async fn list_filtered(
+        &self,
+        filter: ResourceAuditLogFilter,
+        cursor: Option<ResourceAuditLogCursor>,
+        page_size: i64,
+    ) -> Result<FetchManyResponseKind<ResourceAuditLog>, MappedErrors>;
+
+    /// List the tenants owning at least one chained row. `None` stands for
+    /// the system chain (rows without a tenant).
+    async fn list_chain_tenants(
+        &self,
+    ) -> Result<FetchManyResponseKind<Option<Uuid>>, MappedErrors>;
+
+    /// List up to `page_size` chained rows of a tenant chain with
+    /// `seq > after_seq`, ordered by ascending `seq`.
+    async fn list_chain_page(
+        &self,
+        chain_tenant_id: Option<Uuid>,
+        after_seq: i64,
+        page_size: i64,
+    ) -> Result<FetchManyResponseKind<ResourceAuditLog>, MappedErrors>;
+
+    /// List every checkpoint of a tenant chain, ordered by ascending `seq`.
+    async fn list_checkpoints(
+        &self,
+        chain_tenant_id: Option<Uuid>,
+    ) -> Result<FetchManyResponseKind<ResourceAuditCheckpoint>, MappedErrors>;
+
+    /// List up to `page_size` rows of a tenant chain written before hash
+    /// chaining existed (no chain link) and created in
+    /// `[created_from, created_before)`, ordered by ascending `created_at`
+    /// then `id`, skipping the first `skip` of them.
+    async fn list_unchained_page(
+        &self,
+        chain_tenant_id: Option<Uuid>,
+        created_from: Option<DateTime<Utc>>,
+        created_before: DateTime<Utc>,
+        page_size: i64,
+        skip: i64,
+    ) -> Result<FetchManyResponseKind<ResourceAuditLog>, MappedErrors>;
+
+    /// List every archive of a tenant chain, oldest first.
+    async fn list_archives(
+        &self,
+        chain_tenant_id: Option<Uuid>,
+    ) -> Result<FetchManyResponseKind<ResourceAuditArchive>, MappedErrors>;
+
+    /// List every stored retention policy.
+    async fn list_retention_policies(
+        &self,
+    ) -> Result<FetchManyResponseKind<ResourceAuditRetentionPolicy>, MappedErrors>;
 }
@@ -0,0 +1,25 @@
+use crate::domain::dtos::scheduled_job::{ScheduledJob, ScheduledJobRun};
+
+use async_trait::async_trait;
+use chrono::Duration;
+#[cfg(test)]
+use mockall::automock;
+use mycelium_base::utils::errors::MappedErrors;
+use shaku::Interface;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ScheduledJobClaiming: Interface + Send + Sync {
+    /// Claim the due run of a job
+    ///
+    /// The claim is atomic: when several replicas try to claim the same run,
+    /// a single one gets it. The next run becomes due after `interval`.
+    /// Returns `None` when the job is not due yet or another replica claimed
+    /// it first.
+    async fn claim_due_run(
+        &self,
+        job: ScheduledJob,
+        claimed_by: String,
+        interval: Duration,
+    ) -> Result<Option<ScheduledJobRun>, MappedErrors>;
+}
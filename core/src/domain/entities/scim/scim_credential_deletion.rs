@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use mycelium_base::{
+    entities::DeletionResponseKind, utils::errors::MappedErrors,
+};
+use shaku::Interface;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait ScimCredentialDeletion: Interface + Send + Sync {
+    async fn delete(
+        &self,
+        tenant_id: Uuid,
+        credential_id: Uuid,
+    ) -> Result<DeletionResponseKind<Uuid>, MappedErrors>;
+}
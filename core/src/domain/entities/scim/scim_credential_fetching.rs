@@ -0,0 +1,27 @@
+use crate::domain::dtos::scim::ScimCredential;
+
+use async_trait::async_trait;
+#[cfg(test)]
+use mockall::automock;
+use mycelium_base::{
+    entities::{FetchManyResponseKind, FetchResponseKind},
+    utils::errors::MappedErrors,
+};
+use shaku::Interface;
+use uuid::Uuid;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ScimCredentialFetching: Interface + Send + Sync {
+    /// Find the credential of a secret, by the secret hash
+    async fn get_by_secret_hash(
+        &self,
+        secret_hash: String,
+    ) -> Result<FetchResponseKind<ScimCredential, String>, MappedErrors>;
+
+    /// List the credentials of a tenant, the most recent first
+    async fn list(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<FetchManyResponseKind<ScimCredential>, MappedErrors>;
+}
@@ -0,0 +1,15 @@
+use crate::domain::dtos::scim::ScimCredential;
+
+use async_trait::async_trait;
+use mycelium_base::{
+    entities::CreateResponseKind, utils::errors::MappedErrors,
+};
+use shaku::Interface;
+
+#[async_trait]
+pub trait ScimCredentialRegistration: Interface + Send + Sync {
+    async fn create(
+        &self,
+        credential: ScimCredential,
+    ) -> Result<CreateResponseKind<ScimCredential>, MappedErrors>;
+}
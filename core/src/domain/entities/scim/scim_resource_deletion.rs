@@ -0,0 +1,18 @@
+use crate::domain::dtos::scim::ScimResourceKind;
+
+use async_trait::async_trait;
+use mycelium_base::{
+    entities::DeletionResponseKind, utils::errors::MappedErrors,
+};
+use shaku::Interface;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait ScimResourceDeletion: Interface + Send + Sync {
+    async fn delete(
+        &self,
+        tenant_id: Uuid,
+        kind: ScimResourceKind,
+        id: Uuid,
+    ) -> Result<DeletionResponseKind<Uuid>, MappedErrors>;
+}
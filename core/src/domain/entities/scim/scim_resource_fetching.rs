@@ -0,0 +1,26 @@
+use crate::domain::dtos::scim::{ScimResource, ScimResourceKind};
+
+use async_trait::async_trait;
+use mycelium_base::{
+    entities::{FetchManyResponseKind, FetchResponseKind},
+    utils::errors::MappedErrors,
+};
+use shaku::Interface;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait ScimResourceFetching: Interface + Send + Sync {
+    async fn get(
+        &self,
+        tenant_id: Uuid,
+        kind: ScimResourceKind,
+        id: Uuid,
+    ) -> Result<FetchResponseKind<ScimResource, Uuid>, MappedErrors>;
+
+    /// List the resources of a kind of a tenant, the oldest first
+    async fn list(
+        &self,
+        tenant_id: Uuid,
+        kind: ScimResourceKind,
+    ) -> Result<FetchManyResponseKind<ScimResource>, MappedErrors>;
+}
@@ -0,0 +1,19 @@
+use crate::domain::dtos::scim::ScimResource;
+
+use async_trait::async_trait;
+use mycelium_base::{
+    entities::CreateResponseKind, utils::errors::MappedErrors,
+};
+use shaku::Interface;
+
+#[async_trait]
+pub trait ScimResourceRegistration: Interface + Send + Sync {
+    /// Register a provisioned resource
+    ///
+    /// A resource of the same tenant and kind with the same display name is
+    /// reported as not created.
+    async fn create(
+        &self,
+        resource: ScimResource,
+    ) -> Result<CreateResponseKind<ScimResource>, MappedErrors>;
+}
@@ -0,0 +1,16 @@
+use crate::domain::dtos::scim::ScimResource;
+
+use async_trait::async_trait;
+use mycelium_base::{
+    entities::UpdatingResponseKind, utils::errors::MappedErrors,
+};
+use shaku::Interface;
+
+#[async_trait]
+pub trait ScimResourceUpdating: Interface + Send + Sync {
+    /// Replace the external id, display name and attributes of a resource
+    async fn update(
+        &self,
+        resource: ScimResource,
+    ) -> Result<UpdatingResponseKind<ScimResource>, MappedErrors>;
+}
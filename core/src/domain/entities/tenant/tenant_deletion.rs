@@ -1,7 +1,8 @@
 use crate::domain::dtos::email::Email;
-use crate::domain::dtos::tenant::TenantMetaKey;
+use crate::domain::dtos::tenant::{EmailTemplateKind, TenantMetaKey};
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use mycelium_base::{
     entities::DeletionResponseKind, utils::errors::MappedErrors,
 };
@@ -17,6 +18,22 @@ pub trait TenantDeletion: Interface + Send + Sync {
         id: Uuid,
     ) -> Result<DeletionResponseKind<Uuid>, MappedErrors>;
 
+    /// Permanently delete a tenant and everything it holds
+    ///
+    /// Accounts, guest links, guest roles, tags, meta and the tenant data
+    /// encryption key are removed in a single transaction. Dropping the key
+    /// leaves any remaining ciphertext of the tenant unreadable.
+    ///
+    /// The tenant row is locked in that transaction and only purged when its
+    /// current status is still `Trashed` since `trashed_before` at the
+    /// latest. A tenant restored after being listed for the purge is left
+    /// untouched and `NotDeleted` is returned.
+    async fn purge(
+        &self,
+        id: Uuid,
+        trashed_before: DateTime<Utc>,
+    ) -> Result<DeletionResponseKind<Uuid>, MappedErrors>;
+
     async fn delete_owner(
         &self,
         tenant_id: Uuid,
@@ -29,6 +46,13 @@ pub trait TenantDeletion: Interface + Send + Sync {
         tenant_id: Uuid,
         key: TenantMetaKey,
     ) -> Result<DeletionResponseKind<Uuid>, MappedErrors>;
+
+    async fn delete_email_template(
+        &self,
+        tenant_id: Uuid,
+        kind: EmailTemplateKind,
+        locale: String,
+    ) -> Result<DeletionResponseKind<Uuid>, MappedErrors>;
 }
 
 impl Display for dyn TenantDeletion {
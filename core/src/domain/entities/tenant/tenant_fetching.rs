@@ -1,6 +1,10 @@
-use crate::domain::dtos::tenant::{Tenant, TenantMetaKey};
+use crate::domain::dtos::{
+    profile::Owner,
+    tenant::{EmailTemplateKind, Tenant, TenantEmailTemplate, TenantMetaKey},
+};
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use mycelium_base::{
     entities::{FetchManyResponseKind, FetchResponseKind},
     utils::errors::MappedErrors,
@@ -48,4 +52,48 @@ pub trait TenantFetching: Interface + Send + Sync {
         page_size: Option<i32>,
         skip: Option<i32>,
     ) -> Result<FetchManyResponseKind<Tenant>, MappedErrors>;
+
+    /// Get the tenant override of a notification email
+    ///
+    /// Used on dispatching notifications, so no ownership is checked.
+    async fn get_email_template(
+        &self,
+        tenant_id: Uuid,
+        kind: EmailTemplateKind,
+        locale: String,
+    ) -> Result<FetchResponseKind<TenantEmailTemplate, String>, MappedErrors>;
+
+    /// List the notification email overrides of a tenant
+    async fn list_email_templates(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<FetchManyResponseKind<TenantEmailTemplate>, MappedErrors>;
+
+    /// List the descendants of a tenant
+    ///
+    /// Children are listed before their own children. No ownership is
+    /// checked.
+    async fn list_descendants(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<FetchManyResponseKind<Tenant>, MappedErrors>;
+
+    /// List the tenants currently in the trash
+    ///
+    /// Only tenants whose most recent status is `Trashed`, recorded inside
+    /// `(trashed_after, trashed_before]`, are listed. Used by the purge jobs,
+    /// so no ownership is checked.
+    async fn list_trashed(
+        &self,
+        trashed_after: Option<DateTime<Utc>>,
+        trashed_before: DateTime<Utc>,
+    ) -> Result<FetchManyResponseKind<Tenant>, MappedErrors>;
+
+    /// List the owners of a tenant
+    ///
+    /// Used on dispatching notifications, so no ownership is checked.
+    async fn list_owners(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<FetchManyResponseKind<Owner>, MappedErrors>;
 }
@@ -1,4 +1,4 @@
-use crate::domain::dtos::tenant::{Tenant, TenantMetaKey};
+use crate::domain::dtos::tenant::{Tenant, TenantEmailTemplate, TenantMetaKey};
 
 use async_trait::async_trait;
 use chrono::{DateTime, Local};
@@ -38,6 +38,15 @@ pub trait TenantRegistration: Interface + Send + Sync {
         key: TenantMetaKey,
         value: String,
     ) -> Result<CreateResponseKind<HashMap<String, String>>, MappedErrors>;
+
+    /// Create or replace the tenant override of a notification email
+    ///
+    /// Templates are unique by tenant, kind and locale. Replacing keeps the
+    /// template id and creation date.
+    async fn register_email_template(
+        &self,
+        template: TenantEmailTemplate,
+    ) -> Result<TenantEmailTemplate, MappedErrors>;
 }
 
 impl Display for dyn TenantRegistration {
@@ -34,6 +34,17 @@ pub trait TenantUpdating: Interface + Send + Sync {
         guest_by: String,
     ) -> Result<CreateResponseKind<TenantOwnerConnection>, MappedErrors>;
 
+    /// Set or remove the parent of a tenant
+    ///
+    /// The next parent is checked and set in a single transaction that locks
+    /// the tenant and the lineage of the parent. Missing parents and parents
+    /// descending from the tenant are refused with `NotUpdated`.
+    async fn update_tenant_parent(
+        &self,
+        tenant_id: Uuid,
+        parent_id: Option<Uuid>,
+    ) -> Result<UpdatingResponseKind<Tenant>, MappedErrors>;
+
     async fn update_tenant_meta(
         &self,
         tenant_id: Uuid,
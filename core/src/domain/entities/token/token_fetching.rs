@@ -3,6 +3,7 @@ use crate::domain::dtos::token::{
 };
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use mycelium_base::{
     entities::{FetchManyResponseKind, FetchResponseKind},
     utils::errors::MappedErrors,
@@ -31,4 +32,15 @@ pub trait TokenFetching: Interface + Send + Sync {
         &self,
         account_id: Uuid,
     ) -> Result<FetchManyResponseKind<PublicConnectionStringInfo>, MappedErrors>;
+
+    /// List connection strings expiring in `[expiring_from, expiring_before)`
+    ///
+    /// This should be used to warn the owners of connection strings about to
+    /// expire.
+    ///
+    async fn list_connection_strings_expiring_between(
+        &self,
+        expiring_from: DateTime<Utc>,
+        expiring_before: DateTime<Utc>,
+    ) -> Result<FetchManyResponseKind<PublicConnectionStringInfo>, MappedErrors>;
 }
@@ -17,4 +17,13 @@ pub trait WebHookUpdating: Interface + Send + Sync {
         &self,
         artifact: WebHookPayloadArtifact,
     ) -> Result<UpdatingResponseKind<WebHookPayloadArtifact>, MappedErrors>;
+
+    /// Replace every mention of `subject` in the stored execution payloads
+    /// and propagation responses by `pseudonym`. Returns the number of
+    /// updated artifacts.
+    async fn pseudonymise_execution_events(
+        &self,
+        subject: String,
+        pseudonym: String,
+    ) -> Result<u64, MappedErrors>;
 }
@@ -0,0 +1,784 @@
+// ? ---------------------------------------------------------------------------
+// ? BackupArchive
+//
+// The file written by `myc-cli backup` and read by `myc-cli restore`. The file
+// starts with a plain prelude:
+//
+//   "MYCBAK" | format version (u32, big endian) | mode (0 plain, 1 encrypted)
+//   [| Argon2id salt (16 bytes), when encrypted]
+//
+// followed by a zstd-compressed JSON Lines stream: a header record, one record
+// per row, and a trailer record with the row count and SHA-256 of each table.
+// Encrypted archives seal the compressed stream in AES-256-GCM chunks, under a
+// key derived from a passphrase. Each chunk is framed as
+//
+//   last (u8) | length (u32, big endian) | ciphertext and tag
+//
+// with the chunk counter as nonce and the `last` flag as additional data, so
+// a reordered, dropped or truncated chunk fails to open.
+// ? ---------------------------------------------------------------------------
+
+use crate::domain::dtos::backup::{
+    BackupHeader, BackupTableSummary, BACKUP_FORMAT_VERSION,
+};
+
+use argon2::Argon2;
+use mycelium_base::utils::errors::{creation_err, fetching_err, MappedErrors};
+use ring::{
+    aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN},
+    rand::{SecureRandom, SystemRandom},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+use std::{
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Lines, Read, Write},
+    path::{Path, PathBuf},
+};
+
+const MAGIC: &[u8; 6] = b"MYCBAK";
+const MODE_PLAIN: u8 = 0;
+const MODE_ENCRYPTED: u8 = 1;
+const SALT_LEN: usize = 16;
+const TAG_LEN: usize = 16;
+
+/// Plaintext bytes sealed per chunk of an encrypted archive.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+#[derive(Deserialize, Serialize)]
+#[serde(tag = "record", rename_all = "camelCase")]
+enum BackupRecord {
+    Header(BackupHeader),
+    Row {
+        table: String,
+        row: Map<String, Value>,
+    },
+    Trailer {
+        tables: Vec<BackupTableSummary>,
+    },
+}
+
+/// Running count and digest of the rows of a table.
+struct TableDigest {
+    table: String,
+    rows: u64,
+    hasher: Sha256,
+}
+
+impl TableDigest {
+    fn summary(self) -> BackupTableSummary {
+        BackupTableSummary {
+            table: self.table,
+            rows: self.rows,
+            sha256: hex::encode(self.hasher.finalize()),
+        }
+    }
+}
+
+/// Record a row line in the digest of its table, in archive order
+fn digest_row(digests: &mut Vec<TableDigest>, table: &str, line: &[u8]) {
+    let digest = match digests.iter().position(|d| d.table == table) {
+        Some(index) => &mut digests[index],
+        None => {
+            digests.push(TableDigest {
+                table: table.to_string(),
+                rows: 0,
+                hasher: Sha256::new(),
+            });
+
+            digests.last_mut().expect("a digest was just pushed")
+        }
+    };
+
+    digest.rows += 1;
+    digest.hasher.update(line);
+}
+
+/// A backup archive being written. Nothing is visible under the final name
+/// until `finish` succeeds.
+pub struct BackupArchiveWriter {
+    path: PathBuf,
+    tmp_path: PathBuf,
+    encoder: zstd::Encoder<'static, ArchiveSink>,
+    digests: Vec<TableDigest>,
+}
+
+impl BackupArchiveWriter {
+    /// Start writing the archive at `path`, encrypted with `passphrase` if
+    /// given.
+    pub fn create(
+        path: &Path,
+        header: &BackupHeader,
+        passphrase: Option<&str>,
+    ) -> Result<Self, MappedErrors> {
+        let tmp_path = with_suffix(path, ".tmp");
+
+        let mut file = File::create(&tmp_path).map_err(|err| {
+            creation_err(format!("Unable to create backup archive: {err}"))
+        })?;
+
+        let mut prelude = MAGIC.to_vec();
+        prelude.extend_from_slice(&BACKUP_FORMAT_VERSION.to_be_bytes());
+
+        let sink = match passphrase {
+            None => {
+                prelude.push(MODE_PLAIN);
+                file.write_all(&prelude).map(|_| ArchiveSink::Plain(file))
+            }
+            Some(passphrase) => {
+                let mut salt = [0u8; SALT_LEN];
+
+                SystemRandom::new().fill(&mut salt).map_err(|_| {
+                    creation_err("Unable to generate the backup archive salt")
+                })?;
+
+                let key = derive_archive_key(passphrase, &salt)?;
+
+                prelude.push(MODE_ENCRYPTED);
+                prelude.extend_from_slice(&salt);
+
+                file.write_all(&prelude).map(|_| {
+                    ArchiveSink::Sealed(Box::new(SealingWriter {
+                        inner: file,
+                        key,
+                        buffer: Vec::with_capacity(CHUNK_SIZE),
+                        counter: 0,
+                    }))
+                })
+            }
+        }
+        .map_err(|err| {
+            creation_err(format!("Unable to write backup archive: {err}"))
+        })?;
+
+        let encoder = zstd::Encoder::new(sink, 0).map_err(|err| {
+            creation_err(format!("Unable to create backup archive: {err}"))
+        })?;
+
+        let mut writer = Self {
+            path: path.to_path_buf(),
+            tmp_path,
+            encoder,
+            digests: vec![],
+        };
+
+        writer.write_record(&BackupRecord::Header(header.to_owned()))?;
+
+        Ok(writer)
+    }
+
+    /// Append a row of `table`.
+    pub fn append(
+        &mut self,
+        table: &str,
+        row: Map<String, Value>,
+    ) -> Result<(), MappedErrors> {
+        let line = self.write_record(&BackupRecord::Row {
+            table: table.to_string(),
+            row,
+        })?;
+
+        digest_row(&mut self.digests, table, &line);
+
+        Ok(())
+    }
+
+    /// Write the trailer, flush the file to disk under its final name and
+    /// return the summary of the archived tables.
+    pub fn finish(mut self) -> Result<Vec<BackupTableSummary>, MappedErrors> {
+        let tables = std::mem::take(&mut self.digests)
+            .into_iter()
+            .map(TableDigest::summary)
+            .collect::<Vec<_>>();
+
+        let trailer = BackupRecord::Trailer {
+            tables: tables.to_owned(),
+        };
+
+        if let Err(err) = self.write_record(&trailer) {
+            self.abort();
+            return Err(err);
+        }
+
+        let Self {
+            path,
+            tmp_path,
+            encoder,
+            ..
+        } = self;
+
+        let result = encoder
+            .finish()
+            .and_then(ArchiveSink::finish)
+            .and_then(|file| {
+                file.sync_all()?;
+                fs::rename(&tmp_path, &path)
+            })
+            .map_err(|err| {
+                creation_err(format!("Unable to write backup archive: {err}"))
+            });
+
+        if let Err(err) = result {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(err);
+        }
+
+        Ok(tables)
+    }
+
+    /// Drop the unfinished file.
+    pub fn abort(self) {
+        let _ = fs::remove_file(&self.tmp_path);
+    }
+
+    fn write_record(
+        &mut self,
+        record: &BackupRecord,
+    ) -> Result<Vec<u8>, MappedErrors> {
+        let line = serde_json::to_vec(record).map_err(|err| {
+            creation_err(format!("Unable to encode backup record: {err}"))
+        })?;
+
+        self.encoder
+            .write_all(&line)
+            .and_then(|_| self.encoder.write_all(b"\n"))
+            .map_err(|err| {
+                creation_err(format!("Unable to write backup archive: {err}"))
+            })?;
+
+        Ok(line)
+    }
+}
+
+/// A row of a backup archive, with its table
+pub type BackupRow = (String, Map<String, Value>);
+
+/// A backup archive being read
+///
+/// Rows are checked against the trailer as they are read: `next_row` only
+/// returns `None` once every table matched its row count and digest, so a
+/// caller writing rows as they come must not commit them before.
+pub struct BackupArchiveReader {
+    lines: RecordLines,
+    header: BackupHeader,
+    digests: Vec<TableDigest>,
+    verified: bool,
+}
+
+impl BackupArchiveReader {
+    /// Open the archive at `path`. Encrypted archives require the passphrase
+    /// they were written with.
+    pub fn open(
+        path: &Path,
+        passphrase: Option<&str>,
+    ) -> Result<Self, MappedErrors> {
+        let (mode, file) = open_prelude(path)?;
+
+        let source = match (mode, passphrase) {
+            (MODE_PLAIN, _) => ArchiveSource::Plain(file),
+            (MODE_ENCRYPTED, None) => {
+                return fetching_err(
+                    "The backup archive is encrypted: a passphrase is required",
+                )
+                .as_error();
+            }
+            (MODE_ENCRYPTED, Some(passphrase)) => {
+                let mut file = file;
+                let mut salt = [0u8; SALT_LEN];
+
+                file.read_exact(&mut salt).map_err(|err| {
+                    fetching_err(format!(
+                        "Unable to read backup archive: {err}"
+                    ))
+                })?;
+
+                ArchiveSource::Opened(Box::new(OpeningReader {
+                    inner: file,
+                    key: derive_archive_key(passphrase, &salt)?,
+                    plain: vec![],
+                    position: 0,
+                    counter: 0,
+                    finished: false,
+                }))
+            }
+            (mode, _) => {
+                return fetching_err(format!(
+                    "Unknown backup archive mode {mode}"
+                ))
+                .as_error();
+            }
+        };
+
+        let decoder = zstd::Decoder::new(source).map_err(|err| {
+            fetching_err(format!("Unable to decompress backup archive: {err}"))
+        })?;
+
+        let mut lines = BufReader::new(decoder).lines();
+
+        let header = match next_record(&mut lines)? {
+            Some((BackupRecord::Header(header), _)) => header,
+            _ => {
+                return fetching_err(
+                    "The backup archive does not start with a header",
+                )
+                .as_error();
+            }
+        };
+
+        Ok(Self {
+            lines,
+            header,
+            digests: vec![],
+            verified: false,
+        })
+    }
+
+    /// Whether the archive at `path` is encrypted, i.e. needs a passphrase to
+    /// be opened.
+    pub fn is_encrypted(path: &Path) -> Result<bool, MappedErrors> {
+        open_prelude(path).map(|(mode, _)| mode == MODE_ENCRYPTED)
+    }
+
+    pub fn header(&self) -> &BackupHeader {
+        &self.header
+    }
+
+    /// The next row of the archive, with its table.
+    pub fn next_row(&mut self) -> Result<Option<BackupRow>, MappedErrors> {
+        if self.verified {
+            return Ok(None);
+        }
+
+        match next_record(&mut self.lines)? {
+            Some((BackupRecord::Row { table, row }, line)) => {
+                digest_row(&mut self.digests, &table, &line);
+                Ok(Some((table, row)))
+            }
+            Some((BackupRecord::Trailer { tables }, _)) => {
+                let digests = std::mem::take(&mut self.digests)
+                    .into_iter()
+                    .map(TableDigest::summary)
+                    .collect::<Vec<_>>();
+
+                if digests != tables {
+                    return fetching_err(
+                        "The backup archive rows do not match its trailer: \
+                         the file is corrupted",
+                    )
+                    .as_error();
+                }
+
+                if next_record(&mut self.lines)?.is_some() {
+                    return fetching_err(
+                        "The backup archive has records after its trailer",
+                    )
+                    .as_error();
+                }
+
+                self.verified = true;
+
+                Ok(None)
+            }
+            Some((BackupRecord::Header(_), _)) => {
+                fetching_err("The backup archive has more than one header")
+                    .as_error()
+            }
+            None => fetching_err(
+                "The backup archive ends before its trailer: the file is \
+                 truncated",
+            )
+            .as_error(),
+        }
+    }
+}
+
+/// Open the archive at `path` past its prelude, returning its mode.
+fn open_prelude(path: &Path) -> Result<(u8, BufReader<File>), MappedErrors> {
+    let mut file = BufReader::new(File::open(path).map_err(|err| {
+        fetching_err(format!("Unable to open backup archive: {err}"))
+    })?);
+
+    let mut prelude = [0u8; MAGIC.len() + 5];
+
+    file.read_exact(&mut prelude).map_err(|err| {
+        fetching_err(format!("Unable to read backup archive: {err}"))
+    })?;
+
+    if &prelude[..MAGIC.len()] != MAGIC {
+        return fetching_err("The file is not a Mycelium backup archive")
+            .as_error();
+    }
+
+    let version = u32::from_be_bytes(
+        prelude[MAGIC.len()..MAGIC.len() + 4]
+            .try_into()
+            .expect("the prelude holds four version bytes"),
+    );
+
+    if version != BACKUP_FORMAT_VERSION {
+        return fetching_err(format!(
+            "Unsupported backup archive version {version}: this release \
+             reads version {BACKUP_FORMAT_VERSION}"
+        ))
+        .as_error();
+    }
+
+    Ok((prelude[MAGIC.len() + 4], file))
+}
+
+type RecordLines =
+    Lines<BufReader<zstd::Decoder<'static, BufReader<ArchiveSource>>>>;
+
+/// The next record of the archive, with its line as written.
+fn next_record(
+    lines: &mut RecordLines,
+) -> Result<Option<(BackupRecord, Vec<u8>)>, MappedErrors> {
+    let Some(line) = lines.next() else {
+        return Ok(None);
+    };
+
+    let line = line.map_err(|err| {
+        fetching_err(format!("Unable to read backup archive: {err}"))
+    })?;
+
+    let record = serde_json::from_str(&line).map_err(|err| {
+        fetching_err(format!("Invalid backup archive record: {err}"))
+    })?;
+
+    Ok(Some((record, line.into_bytes())))
+}
+
+/// Derive the archive key from a passphrase with Argon2id.
+fn derive_archive_key(
+    passphrase: &str,
+    salt: &[u8],
+) -> Result<LessSafeKey, MappedErrors> {
+    let mut key = [0u8; 32];
+
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|err| {
+            creation_err(format!(
+                "Unable to derive the backup archive key: {err}"
+            ))
+        })?;
+
+    let key = UnboundKey::new(&AES_256_GCM, &key)
+        .map_err(|_| creation_err("Unable to derive the backup archive key"))?;
+
+    Ok(LessSafeKey::new(key))
+}
+
+fn chunk_nonce(counter: u64) -> Nonce {
+    let mut nonce = [0u8; NONCE_LEN];
+    nonce[NONCE_LEN - 8..].copy_from_slice(&counter.to_be_bytes());
+    Nonce::assume_unique_for_key(nonce)
+}
+
+enum ArchiveSink {
+    Plain(File),
+    Sealed(Box<SealingWriter>),
+}
+
+impl ArchiveSink {
+    fn finish(self) -> io::Result<File> {
+        match self {
+            Self::Plain(file) => Ok(file),
+            Self::Sealed(writer) => writer.finish(),
+        }
+    }
+}
+
+impl Write for ArchiveSink {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Self::Plain(file) => file.write(buf),
+            Self::Sealed(writer) => writer.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::Plain(file) => file.flush(),
+            Self::Sealed(writer) => writer.flush(),
+        }
+    }
+}
+
+struct SealingWriter {
+    inner: File,
+    key: LessSafeKey,
+    buffer: Vec<u8>,
+    counter: u64,
+}
+
+impl SealingWriter {
+    fn seal_chunk(&mut self, mut chunk: Vec<u8>, last: bool) -> io::Result<()> {
+        self.key
+            .seal_in_place_append_tag(
+                chunk_nonce(self.counter),
+                Aad::from([last as u8]),
+                &mut chunk,
+            )
+            .map_err(|_| io::Error::other("unable to seal archive chunk"))?;
+
+        self.counter += 1;
+
+        self.inner.write_all(&[last as u8])?;
+        self.inner.write_all(&(chunk.len() as u32).to_be_bytes())?;
+        self.inner.write_all(&chunk)
+    }
+
+    /// Seal the buffered bytes as the last chunk.
+    fn finish(mut self) -> io::Result<File> {
+        let chunk = std::mem::take(&mut self.buffer);
+        self.seal_chunk(chunk, true)?;
+        Ok(self.inner)
+    }
+}
+
+impl Write for SealingWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+
+        //
+        // A full buffer is only sealed once more bytes follow it, so the last
+        // chunk is never left empty by a stream ending on a chunk boundary.
+        //
+        while self.buffer.len() > CHUNK_SIZE {
+            let chunk = self.buffer.drain(..CHUNK_SIZE).collect();
+            self.seal_chunk(chunk, false)?;
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+enum ArchiveSource {
+    Plain(BufReader<File>),
+    Opened(Box<OpeningReader>),
+}
+
+impl Read for ArchiveSource {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Self::Plain(file) => file.read(buf),
+            Self::Opened(reader) => reader.read(buf),
+        }
+    }
+}
+
+struct OpeningReader {
+    inner: BufReader<File>,
+    key: LessSafeKey,
+    plain: Vec<u8>,
+    position: usize,
+    counter: u64,
+    finished: bool,
+}
+
+impl OpeningReader {
+    fn open_chunk(&mut self) -> io::Result<()> {
+        let mut frame = [0u8; 5];
+
+        self.inner
+            .read_exact(&mut frame)
+            .map_err(|err| match err.kind() {
+                io::ErrorKind::UnexpectedEof => {
+                    io::Error::other("the encrypted archive is truncated")
+                }
+                _ => err,
+            })?;
+
+        let last = match frame[0] {
+            0 => false,
+            1 => true,
+            _ => return Err(io::Error::other("invalid archive chunk frame")),
+        };
+
+        let length = u32::from_be_bytes(
+            frame[1..]
+                .try_into()
+                .expect("the frame holds four length bytes"),
+        ) as usize;
+
+        if !(TAG_LEN..=CHUNK_SIZE + TAG_LEN).contains(&length) {
+            return Err(io::Error::other("invalid archive chunk length"));
+        }
+
+        let mut chunk = vec![0u8; length];
+        self.inner.read_exact(&mut chunk)?;
+
+        let plain_len = self
+            .key
+            .open_in_place(
+                chunk_nonce(self.counter),
+                Aad::from([last as u8]),
+                &mut chunk,
+            )
+            .map_err(|_| {
+                io::Error::other(
+                    "unable to decrypt the archive: wrong passphrase or \
+                     corrupted file",
+                )
+            })?
+            .len();
+
+        chunk.truncate(plain_len);
+
+        self.counter += 1;
+        self.plain = chunk;
+        self.position = 0;
+        self.finished = last;
+
+        Ok(())
+    }
+}
+
+impl Read for OpeningReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.position == self.plain.len() {
+            if self.finished {
+                return Ok(0);
+            }
+
+            self.open_chunk()?;
+        }
+
+        let read = buf.len().min(self.plain.len() - self.position);
+        buf[..read]
+            .copy_from_slice(&self.plain[self.position..self.position + read]);
+        self.position += read;
+
+        Ok(read)
+    }
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(suffix);
+    PathBuf::from(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::dtos::backup::{BackupBackend, BackupScope};
+
+    use chrono::Utc;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    fn header() -> BackupHeader {
+        BackupHeader {
+            format_version: BACKUP_FORMAT_VERSION,
+            mycelium_version: "test".to_string(),
+            backend: BackupBackend::Sqlite,
+            scope: BackupScope::Tenant(Uuid::new_v4()),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn temp_path() -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("myc_backup_{}.mycbak", Uuid::new_v4()))
+    }
+
+    fn write_archive(
+        path: &Path,
+        passphrase: Option<&str>,
+        rows: usize,
+    ) -> Vec<BackupTableSummary> {
+        let mut writer =
+            BackupArchiveWriter::create(path, &header(), passphrase).unwrap();
+
+        for index in 0..rows {
+            let table = if index % 3 == 0 { "tenant" } else { "account" };
+
+            writer
+                .append(
+                    table,
+                    json!({ "id": index, "name": "x".repeat(index % 50) })
+                        .as_object()
+                        .unwrap()
+                        .to_owned(),
+                )
+                .unwrap();
+        }
+
+        writer.finish().unwrap()
+    }
+
+    fn read_archive(
+        path: &Path,
+        passphrase: Option<&str>,
+    ) -> Result<Vec<BackupRow>, MappedErrors> {
+        let mut reader = BackupArchiveReader::open(path, passphrase)?;
+        let mut rows = vec![];
+
+        while let Some(row) = reader.next_row()? {
+            rows.push(row);
+        }
+
+        Ok(rows)
+    }
+
+    #[test]
+    fn plain_archives_round_trip() {
+        let path = temp_path();
+        let tables = write_archive(&path, None, 10);
+
+        assert_eq!(tables.len(), 2);
+        assert_eq!(tables[0].table, "tenant");
+        assert_eq!(tables[0].rows, 4);
+        assert_eq!(tables[1].rows, 6);
+
+        let rows = read_archive(&path, None).unwrap();
+
+        assert_eq!(rows.len(), 10);
+        assert_eq!(rows[3].0, "tenant");
+        assert_eq!(rows[3].1["id"], json!(3));
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn encrypted_archives_require_their_passphrase() {
+        let path = temp_path();
+
+        //
+        // Enough rows to span several chunks.
+        //
+        write_archive(&path, Some("correct horse"), 20_000);
+
+        assert_eq!(
+            read_archive(&path, Some("correct horse")).unwrap().len(),
+            20_000
+        );
+        assert!(read_archive(&path, Some("wrong horse")).is_err());
+        assert!(read_archive(&path, None).is_err());
+        assert!(BackupArchiveReader::is_encrypted(&path).unwrap());
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn truncated_archives_are_rejected() {
+        for passphrase in [None, Some("passphrase")] {
+            let path = temp_path();
+            write_archive(&path, passphrase, 20_000);
+
+            let content = fs::read(&path).unwrap();
+            fs::write(&path, &content[..content.len() - 64]).unwrap();
+
+            assert!(read_archive(&path, passphrase).is_err());
+
+            let _ = fs::remove_file(&path);
+        }
+    }
+}
@@ -0,0 +1,1110 @@
+// ? ---------------------------------------------------------------------------
+// ? BackupStore
+//
+// The tables `myc-cli backup` archives and how `myc-cli restore` writes them
+// back, over a database backend implementing `BackupStore`. Rows travel as
+// JSON objects keyed by column name, with the values as the source backend
+// renders them; the header of the archive tells which backend that was.
+//
+// A tenant backup holds the rows of the tenant: the tenant itself, its tags,
+// templates, accounts, users, owners, guest roles and memberships, SCIM and
+// bulk import state and its audit chain. Restoring it puts those rows back as
+// they were when the archive was written, either on the same instance (after
+// an administration mistake) or on another one.
+// ? ---------------------------------------------------------------------------
+
+use super::{
+    backup_archive::{BackupArchiveReader, BackupArchiveWriter},
+    envelope::{unwrap_dek, wrap_dek},
+};
+use crate::domain::dtos::backup::{
+    BackupBackend, BackupHeader, BackupScope, BackupTableSummary,
+    BACKUP_FORMAT_VERSION,
+};
+
+use chrono::Utc;
+use mycelium_base::utils::errors::{execution_err, MappedErrors};
+use serde_json::{Map, Value};
+use std::path::Path;
+use uuid::Uuid;
+
+/// How a tenant restore treats the rows of a table already in the target.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TenantRestore {
+    /// The rows of the tenant are deleted, then the archived rows inserted
+    Replace,
+
+    /// Archived rows overwrite the rows with the same key, and no row is
+    /// deleted: other rows may still reference the ones in the target
+    Upsert,
+
+    /// Only archived rows missing from the target are inserted: the table is
+    /// append-only
+    Append,
+}
+
+/// How `BackupStore::write_rows` treats rows whose key already exists.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BackupRowWrite {
+    /// A row with an existing key fails the write
+    Insert,
+
+    /// A row with an existing key overwrites it
+    Upsert,
+
+    /// A row with an existing key is left out
+    InsertMissing,
+}
+
+/// A table archived by `backup`.
+#[derive(Clone, Copy, Debug)]
+pub struct BackupTable {
+    pub name: &'static str,
+
+    /// The condition selecting the rows of a tenant, with `{tenant}` standing
+    /// for the quoted tenant id. Tables without one are left out of tenant
+    /// backups.
+    pub tenant_rows: Option<&'static str>,
+
+    pub tenant_restore: TenantRestore,
+}
+
+macro_rules! tenant_accounts {
+    () => {
+        "SELECT id FROM account WHERE tenant_id = {tenant}"
+    };
+}
+
+macro_rules! tenant_roles {
+    () => {
+        "SELECT id FROM guest_role WHERE tenant_id = {tenant}"
+    };
+}
+
+const fn table(
+    name: &'static str,
+    tenant_rows: Option<&'static str>,
+    tenant_restore: TenantRestore,
+) -> BackupTable {
+    BackupTable {
+        name,
+        tenant_rows,
+        tenant_restore,
+    }
+}
+
+/// Tables archived by `backup`, in the order they are written and restored
+///
+/// Referenced tables come before the tables referencing them, so foreign keys
+/// hold after every restored batch. A database table missing from this list
+/// and from `UNARCHIVED_TABLES` stops the backup instead of being left out.
+pub const BACKUP_TABLES: &[BackupTable] = &[
+    table("tenant", Some("id = {tenant}"), TenantRestore::Upsert),
+    table(
+        "tenant_tag",
+        Some("tenant_id = {tenant}"),
+        TenantRestore::Replace,
+    ),
+    table(
+        "tenant_email_template",
+        Some("tenant_id = {tenant}"),
+        TenantRestore::Replace,
+    ),
+    table(
+        "account",
+        Some("tenant_id = {tenant}"),
+        TenantRestore::Replace,
+    ),
+    table(
+        "account_tag",
+        Some(concat!("account_id IN (", tenant_accounts!(), ")")),
+        TenantRestore::Replace,
+    ),
+    table(
+        "user",
+        Some(concat!("account_id IN (", tenant_accounts!(), ")")),
+        TenantRestore::Replace,
+    ),
+    table(
+        "identity_provider",
+        Some(concat!(
+            "user_id IN (SELECT id FROM \"user\" WHERE account_id IN (",
+            tenant_accounts!(),
+            "))"
+        )),
+        TenantRestore::Replace,
+    ),
+    table(
+        "owner_on_tenant",
+        Some("tenant_id = {tenant}"),
+        TenantRestore::Replace,
+    ),
+    table(
+        "manager_account_on_tenant",
+        Some("tenant_id = {tenant}"),
+        TenantRestore::Replace,
+    ),
+    table(
+        "guest_role",
+        Some("tenant_id = {tenant}"),
+        TenantRestore::Upsert,
+    ),
+    table(
+        "guest_role_children",
+        Some(concat!("parent_id IN (", tenant_roles!(), ")")),
+        TenantRestore::Replace,
+    ),
+    table(
+        "guest_user",
+        Some(concat!(
+            "guest_role_id IN (",
+            tenant_roles!(),
+            ") OR id IN (SELECT guest_user_id FROM guest_user_on_account \
+             WHERE account_id IN (",
+            tenant_accounts!(),
+            "))"
+        )),
+        TenantRestore::Upsert,
+    ),
+    table(
+        "guest_user_on_account",
+        Some(concat!("account_id IN (", tenant_accounts!(), ")")),
+        TenantRestore::Replace,
+    ),
+    table(
+        "telegram_identity_audit",
+        Some("tenant_id = {tenant}"),
+        TenantRestore::Append,
+    ),
+    table("token", None, TenantRestore::Replace),
+    table("webhook", None, TenantRestore::Replace),
+    table("webhook_execution", None, TenantRestore::Replace),
+    table("error_code", None, TenantRestore::Replace),
+    table("error_code_translation", None, TenantRestore::Replace),
+    table("instance_settings", None, TenantRestore::Replace),
+    table("message_queue", None, TenantRestore::Replace),
+    table("message_delivery", None, TenantRestore::Replace),
+    table("email_suppression", None, TenantRestore::Replace),
+    table("scheduled_job", None, TenantRestore::Replace),
+    table(
+        "bulk_import",
+        Some("tenant_id = {tenant}"),
+        TenantRestore::Replace,
+    ),
+    table(
+        "scim_credential",
+        Some("tenant_id = {tenant}"),
+        TenantRestore::Replace,
+    ),
+    table(
+        "scim_resource",
+        Some("tenant_id = {tenant}"),
+        TenantRestore::Replace,
+    ),
+    table(
+        "resource_audit_retention_policy",
+        Some("chain_tenant_id = {tenant}"),
+        TenantRestore::Replace,
+    ),
+    table(
+        "resource_audit_log",
+        Some("tenant_id = {tenant}"),
+        TenantRestore::Append,
+    ),
+    table(
+        "resource_audit_checkpoint",
+        Some("chain_tenant_id = {tenant}"),
+        TenantRestore::Append,
+    ),
+    table(
+        "resource_audit_archive",
+        Some("chain_tenant_id = {tenant}"),
+        TenantRestore::Append,
+    ),
+];
+
+/// Database tables left out of backups
+///
+/// Health check history, cached artifacts and the progress of a backend
+/// migration are transient: a restored instance starts new ones.
+pub const UNARCHIVED_TABLES: &[&str] = &[
+    "healthcheck_logs",
+    "kv_artifact",
+    "backend_migration_progress",
+];
+
+/// Outcome of a `BackupStore::write_rows` call.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WrittenRows {
+    pub written: u64,
+
+    /// Rows left out because they reference rows missing from the store
+    pub skipped: u64,
+}
+
+/// Raw row access to a database backend
+///
+/// Rows are JSON objects keyed by column name. Filters are SQL conditions
+/// over the columns of the table, as listed in `BACKUP_TABLES`.
+pub trait BackupStore {
+    /// The backend rows are read from and written to
+    fn backend(&self) -> BackupBackend;
+
+    /// The data tables of the database
+    fn tables(&mut self) -> Result<Vec<String>, MappedErrors>;
+
+    /// The number of rows of `table` matching `filter`
+    fn count_rows(
+        &mut self,
+        table: &str,
+        filter: Option<&str>,
+    ) -> Result<u64, MappedErrors>;
+
+    /// `limit` rows of `table` matching `filter` after the first `offset`
+    /// ones, in a stable order with parents before their children
+    fn read_rows(
+        &mut self,
+        table: &str,
+        filter: Option<&str>,
+        offset: u64,
+        limit: u64,
+    ) -> Result<Vec<Map<String, Value>>, MappedErrors>;
+
+    /// Delete the rows of `table` matching `filter`, returning their number
+    fn delete_rows(
+        &mut self,
+        table: &str,
+        filter: &str,
+    ) -> Result<u64, MappedErrors>;
+
+    /// Write rows archived from a `source` backend to `table`
+    ///
+    /// With `skip_dangling`, a row referencing a row missing from the store
+    /// is left out and counted instead of failing the write.
+    fn write_rows(
+        &mut self,
+        table: &str,
+        rows: Vec<Map<String, Value>>,
+        source: BackupBackend,
+        write: BackupRowWrite,
+        skip_dangling: bool,
+    ) -> Result<WrittenRows, MappedErrors>;
+
+    /// Start the transaction every following call runs in
+    ///
+    /// Reads see a single snapshot of the database until the transaction
+    /// ends.
+    fn begin(&mut self) -> Result<(), MappedErrors>;
+
+    /// Make the written rows durable and end the transaction
+    fn commit(&mut self) -> Result<(), MappedErrors>;
+
+    /// Drop the written rows and end the transaction
+    fn rollback(&mut self) -> Result<(), MappedErrors>;
+}
+
+/// The condition selecting the rows of `table` belonging to `tenant_id`.
+pub fn tenant_filter(table: &BackupTable, tenant_id: Uuid) -> Option<String> {
+    table
+        .tenant_rows
+        .map(|rows| rows.replace("{tenant}", &format!("'{tenant_id}'")))
+}
+
+/// Write the rows of `scope` in `store` to a new archive at `path`
+///
+/// Every table is read from the same snapshot. The archive is encrypted with
+/// `passphrase` if given. Returns the summary of the archived tables.
+#[tracing::instrument(name = "backup", skip_all)]
+pub fn backup(
+    store: &mut dyn BackupStore,
+    scope: BackupScope,
+    path: &Path,
+    passphrase: Option<&str>,
+    batch_size: u64,
+) -> Result<Vec<BackupTableSummary>, MappedErrors> {
+    if batch_size == 0 {
+        return execution_err("backup requires a batch size above 0")
+            .as_error();
+    }
+
+    let store_tables = store.tables()?;
+
+    let unknown_tables = store_tables
+        .iter()
+        .filter(|name| {
+            !BACKUP_TABLES.iter().any(|table| table.name == *name)
+                && !UNARCHIVED_TABLES.contains(&name.as_str())
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+
+    if !unknown_tables.is_empty() {
+        return execution_err(format!(
+            "The database has tables this release does not back up: {}",
+            unknown_tables.join(", ")
+        ))
+        .as_error();
+    }
+
+    let header = BackupHeader {
+        format_version: BACKUP_FORMAT_VERSION,
+        mycelium_version: env!("CARGO_PKG_VERSION").to_string(),
+        backend: store.backend(),
+        scope,
+        created_at: Utc::now(),
+    };
+
+    let mut writer = BackupArchiveWriter::create(path, &header, passphrase)?;
+
+    store.begin()?;
+
+    let written =
+        write_scope(store, &store_tables, scope, &mut writer, batch_size);
+
+    //
+    // The transaction only gave the reads a snapshot: nothing to commit.
+    //
+    let ended = store.rollback();
+
+    if let Err(err) = written.and(ended) {
+        writer.abort();
+        return Err(err);
+    }
+
+    writer.finish()
+}
+
+fn write_scope(
+    store: &mut dyn BackupStore,
+    store_tables: &[String],
+    scope: BackupScope,
+    writer: &mut BackupArchiveWriter,
+    batch_size: u64,
+) -> Result<(), MappedErrors> {
+    if let BackupScope::Tenant(tenant_id) = scope {
+        if store.count_rows("tenant", Some(&format!("id = '{tenant_id}'")))?
+            == 0
+        {
+            return execution_err(format!("Tenant {tenant_id} not found"))
+                .as_error();
+        }
+    }
+
+    for table in BACKUP_TABLES
+        .iter()
+        .filter(|table| store_tables.iter().any(|name| name == table.name))
+    {
+        let filter = match scope {
+            BackupScope::Instance => None,
+            BackupScope::Tenant(tenant_id) => {
+                match tenant_filter(table, tenant_id) {
+                    Some(filter) => Some(filter),
+                    None => continue,
+                }
+            }
+        };
+
+        let mut offset = 0;
+
+        loop {
+            let rows = store.read_rows(
+                table.name,
+                filter.as_deref(),
+                offset,
+                batch_size,
+            )?;
+
+            if rows.is_empty() {
+                break;
+            }
+
+            offset += rows.len() as u64;
+
+            for row in rows {
+                writer.append(table.name, row)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Rows written to a table by `restore`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RestoredTable {
+    pub table: String,
+    pub rows: u64,
+
+    /// Rows left out because they reference rows missing from the target
+    pub skipped: u64,
+}
+
+/// Summary of a `restore` run.
+#[derive(Clone, Debug)]
+pub struct RestoreReport {
+    pub header: BackupHeader,
+    pub tables: Vec<RestoredTable>,
+
+    /// Tenant DEKs rewrapped from the source KEK to the target KEK
+    pub rewrapped_deks: usize,
+
+    /// Whether the restored tenant was detached from a parent tenant missing
+    /// from the target
+    pub detached_parent: bool,
+}
+
+/// Write the rows of the archive at `path` to `store`
+///
+/// An instance archive is restored into an empty database only. A tenant
+/// archive replaces the rows of the tenant in the target: tables marked
+/// `TenantRestore::Replace` lose the rows of the tenant first, and rows
+/// referencing rows the target does not have (e.g. the personal user of an
+/// owner, on another instance) are left out and counted.
+///
+/// Tenant DEKs in the archive are wrapped with the KEK of the instance the
+/// backup was written on. With `source_kek` they are rewrapped to `kek`;
+/// without it they must already open with `kek`.
+///
+/// Everything is written in a single transaction, committed only once the
+/// whole archive matched its trailer.
+#[tracing::instrument(name = "restore", skip_all)]
+pub fn restore(
+    store: &mut dyn BackupStore,
+    path: &Path,
+    passphrase: Option<&str>,
+    kek: &[u8; 32],
+    source_kek: Option<&[u8; 32]>,
+    batch_size: u64,
+) -> Result<RestoreReport, MappedErrors> {
+    if batch_size == 0 {
+        return execution_err("restore requires a batch size above 0")
+            .as_error();
+    }
+
+    let mut reader = BackupArchiveReader::open(path, passphrase)?;
+
+    let mut report = RestoreReport {
+        header: reader.header().to_owned(),
+        tables: vec![],
+        rewrapped_deks: 0,
+        detached_parent: false,
+    };
+
+    if report.header.backend == BackupBackend::Postgres
+        && store.backend() != BackupBackend::Postgres
+    {
+        return execution_err(format!(
+            "A {} backup cannot be restored into a {} database",
+            report.header.backend,
+            store.backend()
+        ))
+        .as_error();
+    }
+
+    let store_tables = store.tables()?;
+
+    store.begin()?;
+
+    match restore_rows(
+        store,
+        &store_tables,
+        &mut reader,
+        kek,
+        source_kek,
+        batch_size,
+        &mut report,
+    ) {
+        Ok(()) => store.commit()?,
+        Err(err) => {
+            if let Err(rollback_err) = store.rollback() {
+                tracing::error!(
+                    "Failed to roll the restore back: {rollback_err}"
+                );
+            }
+
+            return Err(err);
+        }
+    }
+
+    Ok(report)
+}
+
+fn restore_rows(
+    store: &mut dyn BackupStore,
+    store_tables: &[String],
+    reader: &mut BackupArchiveReader,
+    kek: &[u8; 32],
+    source_kek: Option<&[u8; 32]>,
+    batch_size: u64,
+    report: &mut RestoreReport,
+) -> Result<(), MappedErrors> {
+    let in_store = |table: &BackupTable| {
+        store_tables.iter().any(|name| name == table.name)
+    };
+
+    match report.header.scope {
+        BackupScope::Instance => {
+            for table in BACKUP_TABLES.iter().filter(|table| in_store(table)) {
+                if store.count_rows(table.name, None)? > 0 {
+                    return execution_err(format!(
+                        "Table {} already has rows: restore an instance \
+                         backup into a freshly installed database",
+                        table.name
+                    ))
+                    .as_error();
+                }
+            }
+        }
+        BackupScope::Tenant(tenant_id) => {
+            for table in BACKUP_TABLES.iter().rev().filter(|table| {
+                table.tenant_restore == TenantRestore::Replace
+                    && in_store(table)
+            }) {
+                if let Some(filter) = tenant_filter(table, tenant_id) {
+                    store.delete_rows(table.name, &filter)?;
+                }
+            }
+        }
+    }
+
+    let mut batch: Vec<Map<String, Value>> = vec![];
+    let mut current: Option<(usize, &BackupTable)> = None;
+
+    while let Some((name, mut row)) = reader.next_row()? {
+        let Some((index, table)) = BACKUP_TABLES
+            .iter()
+            .enumerate()
+            .find(|(_, table)| table.name == name)
+        else {
+            return execution_err(format!(
+                "The archive has rows of table {name}, unknown to this release"
+            ))
+            .as_error();
+        };
+
+        if !in_store(table) {
+            return execution_err(format!(
+                "The archive has rows of table {name}, which the target \
+                 database does not have"
+            ))
+            .as_error();
+        }
+
+        if let BackupScope::Tenant(_) = report.header.scope {
+            if table.tenant_rows.is_none() {
+                return execution_err(format!(
+                    "The tenant archive has rows of the instance table {name}"
+                ))
+                .as_error();
+            }
+        }
+
+        match current {
+            Some((current_index, _)) if current_index == index => (),
+            Some((current_index, current_table)) => {
+                if index < current_index {
+                    return execution_err(format!(
+                        "The archive has rows of {name} after rows of {}",
+                        current_table.name
+                    ))
+                    .as_error();
+                }
+
+                write_batch(store, current_table, &mut batch, report)?;
+                current = Some((index, table));
+            }
+            None => current = Some((index, table)),
+        }
+
+        if table.name == "tenant" {
+            restore_tenant_row(store, &mut row, kek, source_kek, report)?;
+        }
+
+        batch.push(row);
+
+        if batch.len() as u64 >= batch_size {
+            write_batch(store, table, &mut batch, report)?;
+        }
+    }
+
+    if let Some((_, table)) = current {
+        write_batch(store, table, &mut batch, report)?;
+    }
+
+    Ok(())
+}
+
+fn write_batch(
+    store: &mut dyn BackupStore,
+    table: &BackupTable,
+    batch: &mut Vec<Map<String, Value>>,
+    report: &mut RestoreReport,
+) -> Result<(), MappedErrors> {
+    if batch.is_empty() {
+        return Ok(());
+    }
+
+    let (write, skip_dangling) = match report.header.scope {
+        BackupScope::Instance => (BackupRowWrite::Insert, false),
+        BackupScope::Tenant(_) => (
+            match table.tenant_restore {
+                TenantRestore::Replace => BackupRowWrite::Insert,
+                TenantRestore::Upsert => BackupRowWrite::Upsert,
+                TenantRestore::Append => BackupRowWrite::InsertMissing,
+            },
+            true,
+        ),
+    };
+
+    let written = store.write_rows(
+        table.name,
+        std::mem::take(batch),
+        report.header.backend,
+        write,
+        skip_dangling,
+    )?;
+
+    match report.tables.iter_mut().find(|t| t.table == table.name) {
+        Some(restored) => {
+            restored.rows += written.written;
+            restored.skipped += written.skipped;
+        }
+        None => report.tables.push(RestoredTable {
+            table: table.name.to_string(),
+            rows: written.written,
+            skipped: written.skipped,
+        }),
+    }
+
+    Ok(())
+}
+
+/// Prepare an archived tenant row for the target: rewrap its DEK and, in a
+/// tenant restore, detach it from a parent the target does not have
+fn restore_tenant_row(
+    store: &mut dyn BackupStore,
+    row: &mut Map<String, Value>,
+    kek: &[u8; 32],
+    source_kek: Option<&[u8; 32]>,
+    report: &mut RestoreReport,
+) -> Result<(), MappedErrors> {
+    match source_kek {
+        Some(source_kek) => {
+            if rewrap_tenant_dek(row, source_kek, kek)? {
+                report.rewrapped_deks += 1;
+            }
+        }
+        None => {
+            if let Some(Value::String(wrapped)) = row.get("encrypted_dek") {
+                let tid = tenant_row_id(row)?;
+
+                if unwrap_dek(wrapped, kek, tid.as_bytes()).is_err() {
+                    return execution_err(format!(
+                        "The DEK of tenant {tid} does not open with the KEK \
+                         of this instance: provide the token secret of the \
+                         instance the backup was written on"
+                    ))
+                    .as_error();
+                }
+            }
+        }
+    }
+
+    if let BackupScope::Tenant(_) = report.header.scope {
+        let parent = row
+            .get("parent_id")
+            .and_then(Value::as_str)
+            .map(|parent| {
+                Uuid::parse_str(parent).map_err(|_| {
+                    execution_err("Tenant row with an invalid parent_id")
+                })
+            })
+            .transpose()?;
+
+        if let Some(parent) = parent {
+            if store.count_rows("tenant", Some(&format!("id = '{parent}'")))?
+                == 0
+            {
+                row.insert("parent_id".to_string(), Value::Null);
+                report.detached_parent = true;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn tenant_row_id(row: &Map<String, Value>) -> Result<Uuid, MappedErrors> {
+    row.get("id")
+        .and_then(Value::as_str)
+        .and_then(|id| Uuid::parse_str(id).ok())
+        .ok_or_else(|| execution_err("Tenant row without a valid id"))
+}
+
+/// Rewrap the DEK of a raw tenant row from `source_kek` to `target_kek`
+///
+/// Returns whether the row had a DEK.
+pub fn rewrap_tenant_dek(
+    row: &mut Map<String, Value>,
+    source_kek: &[u8; 32],
+    target_kek: &[u8; 32],
+) -> Result<bool, MappedErrors> {
+    let Some(Value::String(wrapped)) = row.get("encrypted_dek") else {
+        return Ok(false);
+    };
+
+    let tid = tenant_row_id(row)?;
+
+    let dek =
+        unwrap_dek(wrapped, source_kek, tid.as_bytes()).map_err(|_| {
+            execution_err(format!(
+                "Failed to unwrap the DEK of tenant {tid} with the source KEK: \
+                 check the source token secret"
+            ))
+        })?;
+
+    row.insert(
+        "encrypted_dek".to_string(),
+        Value::String(wrap_dek(&dek, target_kek, tid.as_bytes())?),
+    );
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::utils::generate_dek;
+
+    use serde_json::json;
+    use std::collections::BTreeMap;
+
+    /// A store keeping rows in memory. Filters are only understood as far as
+    /// `id = '<uuid>'`; any other filter matches every row.
+    struct MemoryStore {
+        backend: BackupBackend,
+        tables: BTreeMap<String, Vec<Map<String, Value>>>,
+        snapshot: Option<BTreeMap<String, Vec<Map<String, Value>>>>,
+        deleted: Vec<String>,
+    }
+
+    impl MemoryStore {
+        fn new(backend: BackupBackend, tables: &[&str]) -> Self {
+            Self {
+                backend,
+                tables: tables
+                    .iter()
+                    .map(|table| (table.to_string(), vec![]))
+                    .collect(),
+                snapshot: None,
+                deleted: vec![],
+            }
+        }
+
+        fn matching(
+            &self,
+            table: &str,
+            filter: Option<&str>,
+        ) -> Vec<Map<String, Value>> {
+            let id = filter
+                .and_then(|filter| filter.strip_prefix("id = '"))
+                .and_then(|id| id.strip_suffix('\''));
+
+            self.tables[table]
+                .iter()
+                .filter(|row| match id {
+                    Some(id) => row["id"] == json!(id),
+                    None => true,
+                })
+                .cloned()
+                .collect()
+        }
+    }
+
+    impl BackupStore for MemoryStore {
+        fn backend(&self) -> BackupBackend {
+            self.backend
+        }
+
+        fn tables(&mut self) -> Result<Vec<String>, MappedErrors> {
+            Ok(self.tables.keys().cloned().collect())
+        }
+
+        fn count_rows(
+            &mut self,
+            table: &str,
+            filter: Option<&str>,
+        ) -> Result<u64, MappedErrors> {
+            Ok(self.matching(table, filter).len() as u64)
+        }
+
+        fn read_rows(
+            &mut self,
+            table: &str,
+            filter: Option<&str>,
+            offset: u64,
+            limit: u64,
+        ) -> Result<Vec<Map<String, Value>>, MappedErrors> {
+            Ok(self
+                .matching(table, filter)
+                .into_iter()
+                .skip(offset as usize)
+                .take(limit as usize)
+                .collect())
+        }
+
+        fn delete_rows(
+            &mut self,
+            table: &str,
+            _filter: &str,
+        ) -> Result<u64, MappedErrors> {
+            self.deleted.push(table.to_string());
+
+            let rows = self.tables.get_mut(table).unwrap();
+            let count = rows.len() as u64;
+            rows.clear();
+
+            Ok(count)
+        }
+
+        fn write_rows(
+            &mut self,
+            table: &str,
+            rows: Vec<Map<String, Value>>,
+            _source: BackupBackend,
+            _write: BackupRowWrite,
+            _skip_dangling: bool,
+        ) -> Result<WrittenRows, MappedErrors> {
+            let written = rows.len() as u64;
+            let target = self.tables.get_mut(table).unwrap();
+
+            for row in rows {
+                target.retain(|existing| existing.get("id") != row.get("id"));
+                target.push(row);
+            }
+
+            Ok(WrittenRows {
+                written,
+                skipped: 0,
+            })
+        }
+
+        fn begin(&mut self) -> Result<(), MappedErrors> {
+            self.snapshot = Some(self.tables.clone());
+            Ok(())
+        }
+
+        fn commit(&mut self) -> Result<(), MappedErrors> {
+            self.snapshot = None;
+            Ok(())
+        }
+
+        fn rollback(&mut self) -> Result<(), MappedErrors> {
+            if let Some(snapshot) = self.snapshot.take() {
+                self.tables = snapshot;
+            }
+
+            Ok(())
+        }
+    }
+
+    const TABLES: &[&str] = &["tenant", "tenant_tag", "account", "token"];
+
+    fn row(value: Value) -> Map<String, Value> {
+        value.as_object().unwrap().to_owned()
+    }
+
+    fn temp_path() -> std::path::PathBuf {
+        std::env::temp_dir()
+            .join(format!("myc_backup_store_{}.mycbak", Uuid::new_v4()))
+    }
+
+    #[test]
+    fn instance_backups_restore_into_empty_stores_only() {
+        let tid = Uuid::new_v4();
+        let dek = generate_dek().unwrap();
+        let source_kek = [1u8; 32];
+        let target_kek = [2u8; 32];
+
+        let mut source = MemoryStore::new(BackupBackend::Sqlite, TABLES);
+
+        source.tables.insert(
+            "tenant".to_string(),
+            vec![row(json!({
+                "id": tid.to_string(),
+                "encrypted_dek":
+                    wrap_dek(&dek, &source_kek, tid.as_bytes()).unwrap(),
+            }))],
+        );
+
+        source.tables.insert(
+            "account".to_string(),
+            (0..5).map(|id| row(json!({ "id": id }))).collect(),
+        );
+
+        let path = temp_path();
+
+        let summary =
+            backup(&mut source, BackupScope::Instance, &path, None, 2).unwrap();
+
+        assert_eq!(summary.iter().map(|t| t.rows).collect::<Vec<_>>(), [1, 5]);
+
+        let mut target = MemoryStore::new(BackupBackend::Postgres, TABLES);
+
+        //
+        // The DEK does not open with the target KEK without the source one.
+        //
+        assert!(
+            restore(&mut target, &path, None, &target_kek, None, 2).is_err()
+        );
+        assert!(target.tables["account"].is_empty());
+
+        let report = restore(
+            &mut target,
+            &path,
+            None,
+            &target_kek,
+            Some(&source_kek),
+            2,
+        )
+        .unwrap();
+
+        assert_eq!(report.rewrapped_deks, 1);
+        assert_eq!(target.tables["account"], source.tables["account"]);
+        assert_eq!(
+            unwrap_dek(
+                target.tables["tenant"][0]["encrypted_dek"]
+                    .as_str()
+                    .unwrap(),
+                &target_kek,
+                tid.as_bytes()
+            )
+            .unwrap(),
+            dek
+        );
+
+        assert!(restore(
+            &mut target,
+            &path,
+            None,
+            &target_kek,
+            Some(&source_kek),
+            2
+        )
+        .is_err());
+        assert_eq!(target.tables["account"].len(), 5);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn tenant_restores_replace_tenant_rows_and_detach_missing_parents() {
+        let tid = Uuid::new_v4();
+        let kek = [1u8; 32];
+
+        let mut source = MemoryStore::new(BackupBackend::Sqlite, TABLES);
+
+        source.tables.insert(
+            "tenant".to_string(),
+            vec![row(json!({
+                "id": tid.to_string(),
+                "parent_id": Uuid::new_v4().to_string(),
+            }))],
+        );
+
+        source
+            .tables
+            .insert("token".to_string(), vec![row(json!({ "id": 1 }))]);
+
+        let path = temp_path();
+
+        let summary =
+            backup(&mut source, BackupScope::Tenant(tid), &path, None, 10)
+                .unwrap();
+
+        //
+        // Instance tables are left out of tenant backups.
+        //
+        assert_eq!(
+            summary.iter().map(|t| t.table.as_str()).collect::<Vec<_>>(),
+            ["tenant"]
+        );
+
+        assert!(backup(
+            &mut source,
+            BackupScope::Tenant(Uuid::new_v4()),
+            &temp_path(),
+            None,
+            10
+        )
+        .is_err());
+
+        let mut target = MemoryStore::new(BackupBackend::Sqlite, TABLES);
+
+        target.tables.insert(
+            "account".to_string(),
+            vec![row(json!({ "id": "created after the backup" }))],
+        );
+
+        let report = restore(&mut target, &path, None, &kek, None, 10).unwrap();
+
+        assert!(report.detached_parent);
+        assert_eq!(target.deleted, ["account", "tenant_tag"]);
+        assert!(target.tables["account"].is_empty());
+        assert_eq!(target.tables["tenant"][0]["parent_id"], Value::Null);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn postgres_backups_are_not_restored_into_sqlite() {
+        let mut source = MemoryStore::new(BackupBackend::Postgres, TABLES);
+        let path = temp_path();
+
+        backup(&mut source, BackupScope::Instance, &path, None, 10).unwrap();
+
+        let mut target = MemoryStore::new(BackupBackend::Sqlite, TABLES);
+
+        assert!(
+            restore(&mut target, &path, None, &[0u8; 32], None, 10).is_err()
+        );
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn tenant_deks_are_rewrapped_to_the_target_kek() {
+        let tid = Uuid::new_v4();
+        let dek = generate_dek().unwrap();
+        let source_kek = [1u8; 32];
+        let target_kek = [2u8; 32];
+
+        let mut tenant = row(json!({
+            "id": tid.to_string(),
+            "encrypted_dek": wrap_dek(&dek, &source_kek, tid.as_bytes()).unwrap(),
+        }));
+
+        assert!(
+            rewrap_tenant_dek(&mut tenant, &source_kek, &target_kek).unwrap()
+        );
+
+        let rewrapped = tenant["encrypted_dek"].as_str().unwrap();
+
+        assert_eq!(
+            unwrap_dek(rewrapped, &target_kek, tid.as_bytes()).unwrap(),
+            dek
+        );
+
+        assert!(
+            rewrap_tenant_dek(&mut tenant, &source_kek, &target_kek).is_err()
+        );
+
+        let mut without_dek =
+            row(json!({ "id": tid.to_string(), "encrypted_dek": null }));
+
+        assert!(
+            !rewrap_tenant_dek(&mut without_dek, &source_kek, &target_kek)
+                .unwrap()
+        );
+    }
+}
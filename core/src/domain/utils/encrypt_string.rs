@@ -169,6 +169,7 @@ mod tests {
             domain_url: None,
             locale: None,
             token_expiration: SecretResolver::Value(3600),
+            invitation_expiration: SecretResolver::Value(604_800),
             noreply_name: None,
             noreply_email: SecretResolver::Value(
                 "noreply@example.com".to_string(),
@@ -186,6 +187,8 @@ mod tests {
                 secret: SecretResolver::Value("test-hmac".to_string()),
             }]),
             staff_bootstrap_secret: None,
+            sms_provider: None,
+            delivery_webhook_secret: None,
         }
     }
 
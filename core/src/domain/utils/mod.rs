@@ -1,8 +1,15 @@
+mod backup_archive;
+mod backup_store;
 mod derive_key_from_uuid;
 pub mod encrypt_string;
 pub mod envelope;
+mod resource_audit_archive_store;
+mod resource_audit_spill;
+mod table_checksum;
 mod try_as_uuid;
 
+pub use backup_archive::*;
+pub use backup_store::*;
 pub(crate) use derive_key_from_uuid::*;
 pub use encrypt_string::{
     decrypt_string, decrypt_string_with_dek, encrypt_string,
@@ -13,4 +20,7 @@ pub use envelope::{
     AAD_FIELD_TELEGRAM_WEBHOOK_SECRET, AAD_FIELD_TOTP_SECRET, SYSTEM_TENANT_ID,
     SYSTEM_TENANT_NAME,
 };
+pub use resource_audit_archive_store::*;
+pub use resource_audit_spill::*;
+pub use table_checksum::*;
 pub use try_as_uuid::*;
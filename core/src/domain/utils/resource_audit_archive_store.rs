@@ -0,0 +1,387 @@
+// ? ---------------------------------------------------------------------------
+// ? ResourceAuditArchiveStore
+//
+// Where archived `resource_audit_log` ranges are kept. Each archive is a
+// zstd-compressed JSON Lines file -- one `ResourceAuditLog` per line -- with
+// its signed manifest as a `<file>.manifest.json` sidecar, laid out as
+// `<chain>/<archive id>.jsonl.zst` under the store root (`<chain>` is the
+// tenant id, or `system` for the system chain).
+//
+// The store is a plain directory. An S3-compatible bucket is used by mounting
+// it at the store root (s3fs, rclone mount, ...): files are written once
+// under a temporary name, fsync'ed and renamed, and never modified after.
+// ? ---------------------------------------------------------------------------
+
+use crate::domain::dtos::resource_audit_log::{
+    ResourceAuditArchive, ResourceAuditLog,
+};
+
+use mycelium_base::utils::errors::{creation_err, fetching_err, MappedErrors};
+use sha2::{Digest, Sha256};
+use std::{
+    fs::{self, File},
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+};
+use uuid::Uuid;
+
+const ARCHIVE_EXTENSION: &str = "jsonl.zst";
+const MANIFEST_SUFFIX: &str = ".manifest.json";
+
+#[derive(Clone, Debug)]
+pub struct ResourceAuditArchiveStore {
+    root: PathBuf,
+}
+
+impl ResourceAuditArchiveStore {
+    /// Open the store rooted at `root`, creating the directory if needed.
+    pub fn open(root: impl Into<PathBuf>) -> Result<Self, MappedErrors> {
+        let root: PathBuf = root.into();
+
+        fs::create_dir_all(&root).map_err(|err| {
+            creation_err(format!(
+                "Unable to create audit archive directory: {err}"
+            ))
+        })?;
+
+        Ok(Self { root })
+    }
+
+    /// The store key of a new archive of the given chain.
+    pub fn archive_key(
+        chain_tenant_id: Option<Uuid>,
+        archive_id: Uuid,
+    ) -> String {
+        format!(
+            "{}/{archive_id}.{ARCHIVE_EXTENSION}",
+            chain_tenant_id
+                .map(|id| id.to_string())
+                .unwrap_or_else(|| "system".to_string())
+        )
+    }
+
+    /// The path of an archive file inside the store.
+    pub fn path_of(&self, key: &str) -> PathBuf {
+        self.root.join(key)
+    }
+
+    /// The path of the manifest sidecar of an archive file inside the store.
+    pub fn manifest_path_of(&self, key: &str) -> PathBuf {
+        self.root.join(format!("{key}{MANIFEST_SUFFIX}"))
+    }
+
+    /// Start writing the archive file stored under `key`.
+    pub fn writer(
+        &self,
+        key: &str,
+    ) -> Result<ResourceAuditArchiveWriter, MappedErrors> {
+        let path = self.path_of(key);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|err| {
+                creation_err(format!(
+                    "Unable to create audit archive directory: {err}"
+                ))
+            })?;
+        }
+
+        let tmp_path = with_suffix(&path, ".tmp");
+
+        let file = File::create(&tmp_path).map_err(|err| {
+            creation_err(format!("Unable to create audit archive: {err}"))
+        })?;
+
+        let encoder = zstd::Encoder::new(
+            HashingWriter {
+                inner: file,
+                hasher: Sha256::new(),
+            },
+            0,
+        )
+        .map_err(|err| {
+            creation_err(format!("Unable to create audit archive: {err}"))
+        })?;
+
+        Ok(ResourceAuditArchiveWriter {
+            path,
+            tmp_path,
+            encoder,
+            rows: 0,
+        })
+    }
+
+    /// Write the manifest sidecar of an archive.
+    pub fn write_manifest(
+        &self,
+        archive: &ResourceAuditArchive,
+    ) -> Result<PathBuf, MappedErrors> {
+        let path = self.manifest_path_of(&archive.location);
+
+        let content = serde_json::to_vec_pretty(archive).map_err(|err| {
+            creation_err(format!("Unable to encode audit manifest: {err}"))
+        })?;
+
+        write_durably(&path, &content).map_err(|err| {
+            creation_err(format!("Unable to write audit manifest: {err}"))
+        })?;
+
+        Ok(path)
+    }
+
+    /// Remove an archive and its manifest, e.g. after the database refused to
+    /// record it. Missing files are ignored.
+    pub fn remove(&self, key: &str) {
+        for path in [self.path_of(key), self.manifest_path_of(key)] {
+            if let Err(err) = fs::remove_file(&path) {
+                if err.kind() != std::io::ErrorKind::NotFound {
+                    tracing::warn!(
+                        path = %path.display(),
+                        "unable to remove audit archive file: {err}"
+                    );
+                }
+            }
+        }
+    }
+
+    /// Read a manifest sidecar.
+    pub fn read_manifest(
+        manifest_path: &Path,
+    ) -> Result<ResourceAuditArchive, MappedErrors> {
+        let content = fs::read(manifest_path).map_err(|err| {
+            fetching_err(format!("Unable to read audit manifest: {err}"))
+        })?;
+
+        serde_json::from_slice(&content).map_err(|err| {
+            fetching_err(format!("Invalid audit manifest: {err}"))
+        })
+    }
+
+    /// The archive file a manifest sidecar belongs to.
+    pub fn archive_path_of_manifest(
+        manifest_path: &Path,
+    ) -> Result<PathBuf, MappedErrors> {
+        manifest_path
+            .to_str()
+            .and_then(|path| path.strip_suffix(MANIFEST_SUFFIX))
+            .map(PathBuf::from)
+            .ok_or_else(|| {
+                fetching_err(format!(
+                    "Audit manifest path must end with {MANIFEST_SUFFIX}"
+                ))
+            })
+    }
+
+    /// Read every row of an archive file, along with the hex-encoded SHA-256
+    /// of the file as stored.
+    pub fn read_rows(
+        archive_path: &Path,
+    ) -> Result<(Vec<ResourceAuditLog>, String), MappedErrors> {
+        let content = fs::read(archive_path).map_err(|err| {
+            fetching_err(format!("Unable to read audit archive: {err}"))
+        })?;
+
+        let sha256 = hex::encode(Sha256::digest(&content));
+
+        let decoder =
+            zstd::Decoder::new(content.as_slice()).map_err(|err| {
+                fetching_err(format!(
+                    "Unable to decompress audit archive: {err}"
+                ))
+            })?;
+
+        let mut rows = vec![];
+
+        for line in BufReader::new(decoder).lines() {
+            let line = line.map_err(|err| {
+                fetching_err(format!(
+                    "Unable to decompress audit archive: {err}"
+                ))
+            })?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            rows.push(serde_json::from_str(&line).map_err(|err| {
+                fetching_err(format!("Invalid audit archive row: {err}"))
+            })?);
+        }
+
+        Ok((rows, sha256))
+    }
+}
+
+/// An archive file being written. Nothing is visible under the final name
+/// until `finish` succeeds.
+pub struct ResourceAuditArchiveWriter {
+    path: PathBuf,
+    tmp_path: PathBuf,
+    encoder: zstd::Encoder<'static, HashingWriter>,
+    rows: i64,
+}
+
+impl ResourceAuditArchiveWriter {
+    pub fn append(
+        &mut self,
+        row: &ResourceAuditLog,
+    ) -> Result<(), MappedErrors> {
+        let mut line = serde_json::to_vec(row).map_err(|err| {
+            creation_err(format!("Unable to encode audit row: {err}"))
+        })?;
+
+        line.push(b'\n');
+
+        self.encoder.write_all(&line).map_err(|err| {
+            creation_err(format!("Unable to write audit archive: {err}"))
+        })?;
+
+        self.rows += 1;
+
+        Ok(())
+    }
+
+    pub fn rows(&self) -> i64 {
+        self.rows
+    }
+
+    /// Flush the file to disk under its final name and return the
+    /// hex-encoded SHA-256 of the compressed content.
+    pub fn finish(self) -> Result<String, MappedErrors> {
+        let Self {
+            path,
+            tmp_path,
+            encoder,
+            ..
+        } = self;
+
+        let result = encoder
+            .finish()
+            .and_then(|writer| {
+                writer.inner.sync_all()?;
+                fs::rename(&tmp_path, &path)?;
+                Ok(hex::encode(writer.hasher.finalize()))
+            })
+            .map_err(|err| {
+                creation_err(format!("Unable to write audit archive: {err}"))
+            });
+
+        if result.is_err() {
+            let _ = fs::remove_file(&tmp_path);
+        }
+
+        result
+    }
+
+    /// Drop the unfinished file.
+    pub fn abort(self) {
+        let _ = fs::remove_file(&self.tmp_path);
+    }
+}
+
+struct HashingWriter {
+    inner: File,
+    hasher: Sha256,
+}
+
+impl Write for HashingWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.hasher.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(suffix);
+    PathBuf::from(path)
+}
+
+fn write_durably(path: &Path, content: &[u8]) -> std::io::Result<()> {
+    let tmp_path = with_suffix(path, ".tmp");
+
+    let mut file = File::create(&tmp_path)?;
+    file.write_all(content)?;
+    file.sync_all()?;
+
+    fs::rename(&tmp_path, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::dtos::{
+        resource_audit_log::{
+            ResourceAuditEventKind, ResourceAuditResourceType,
+        },
+        written_by::WrittenBy,
+    };
+
+    use chrono::Utc;
+
+    fn row(seq: i64) -> ResourceAuditLog {
+        ResourceAuditLog {
+            id: Uuid::new_v4(),
+            resource_type: ResourceAuditResourceType::Tenant,
+            resource_id: Uuid::new_v4(),
+            tenant_id: None,
+            event: ResourceAuditEventKind::Updated,
+            performed_by: WrittenBy::new_anemic(),
+            metadata: serde_json::json!({ "seq": seq }),
+            created_at: Utc::now(),
+            chain: None,
+            redacted_at: None,
+        }
+    }
+
+    #[test]
+    fn archive_round_trips_rows_and_digest() {
+        let store = ResourceAuditArchiveStore::open(
+            std::env::temp_dir()
+                .join(format!("myc_audit_archive_test_{}", Uuid::new_v4())),
+        )
+        .unwrap();
+
+        let key = ResourceAuditArchiveStore::archive_key(None, Uuid::new_v4());
+        let rows = (1..=3).map(row).collect::<Vec<_>>();
+
+        let mut writer = store.writer(&key).unwrap();
+        for row in &rows {
+            writer.append(row).unwrap();
+        }
+        assert_eq!(writer.rows(), 3);
+        let sha256 = writer.finish().unwrap();
+
+        assert!(key.starts_with("system/"));
+
+        let (read, read_sha256) =
+            ResourceAuditArchiveStore::read_rows(&store.path_of(&key)).unwrap();
+
+        assert_eq!(read_sha256, sha256);
+        assert_eq!(
+            read.iter().map(|row| row.id).collect::<Vec<_>>(),
+            rows.iter().map(|row| row.id).collect::<Vec<_>>()
+        );
+
+        store.remove(&key);
+        assert!(!store.path_of(&key).exists());
+    }
+
+    #[test]
+    fn archive_path_is_derived_from_its_manifest() {
+        let path = ResourceAuditArchiveStore::archive_path_of_manifest(
+            Path::new("/a/system/x.jsonl.zst.manifest.json"),
+        )
+        .unwrap();
+
+        assert_eq!(path, PathBuf::from("/a/system/x.jsonl.zst"));
+        assert!(ResourceAuditArchiveStore::archive_path_of_manifest(
+            Path::new("/a/x.json")
+        )
+        .is_err());
+    }
+}
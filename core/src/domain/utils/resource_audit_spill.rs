@@ -0,0 +1,436 @@
+// ? ---------------------------------------------------------------------------
+// ? ResourceAuditSpill
+//
+// Write-ahead log of the resource audit log pipeline. Every event is appended
+// here as JSON Lines and fsync'ed before the caller moves on, and only then is
+// the dispatcher woken up to persist it. An event is removed from the file
+// once persisted, so whatever is still in it when the process dies is
+// persisted by the next start.
+//
+// The dispatcher claims the whole file at once by renaming it to
+// `<path>.replay`, so new events keep landing in a fresh file while the
+// claimed batch is being written to the database. A claimed batch that
+// survives a crash is picked up again on the next start: delivery is
+// at-least-once, never at-most-once.
+//
+// Also keeps the pipeline counters exported as metrics by the API port, since
+// both the registration repositories and the dispatcher already share this
+// value.
+// ? ---------------------------------------------------------------------------
+
+use crate::domain::dtos::resource_audit_log::NewResourceAuditLogEvent;
+
+use mycelium_base::utils::errors::{creation_err, fetching_err, MappedErrors};
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+};
+use tokio::sync::Notify;
+
+/// Snapshot of the resource audit pipeline counters.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ResourceAuditPipelineStats {
+    /// Events waiting in the spill file to be persisted.
+    pub pending: u64,
+
+    /// Events written to the spill file since startup.
+    pub spilled: u64,
+
+    /// Spilled events persisted since startup.
+    pub replayed: u64,
+
+    /// Failed insert attempts that were retried since startup.
+    pub retried: u64,
+
+    /// Events lost since startup: the spill file itself could not be written,
+    /// or a spilled line could not be decoded.
+    pub dropped: u64,
+}
+
+#[derive(Debug)]
+pub struct ResourceAuditSpill {
+    path: PathBuf,
+    replay_path: PathBuf,
+    lock: Mutex<()>,
+    notify: Notify,
+    pending: AtomicU64,
+    spilled: AtomicU64,
+    replayed: AtomicU64,
+    retried: AtomicU64,
+    dropped: AtomicU64,
+}
+
+impl ResourceAuditSpill {
+    /// Open the spill file at `path`, creating its parent directory if
+    /// needed. Events left behind by a previous run are counted as pending.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, MappedErrors> {
+        let path: PathBuf = path.into();
+
+        if let Some(parent) = path.parent() {
+            if !parent.as_os_str().is_empty() {
+                fs::create_dir_all(parent).map_err(|err| {
+                    creation_err(format!(
+                        "Unable to create audit spill directory: {err}"
+                    ))
+                })?;
+            }
+        }
+
+        let mut replay_path = path.as_os_str().to_owned();
+        replay_path.push(".replay");
+        let replay_path = PathBuf::from(replay_path);
+
+        let pending = count_lines(&path)? + count_lines(&replay_path)?;
+
+        Ok(Self {
+            path,
+            replay_path,
+            lock: Mutex::new(()),
+            notify: Notify::new(),
+            pending: AtomicU64::new(pending),
+            spilled: AtomicU64::new(0),
+            replayed: AtomicU64::new(0),
+            retried: AtomicU64::new(0),
+            dropped: AtomicU64::new(0),
+        })
+    }
+
+    /// Append an event to the spill file, flush it to disk and wake the
+    /// dispatcher up. Blocks on the file: call it from blocking threads.
+    ///
+    /// Never fails the caller: when even the spill file cannot be written the
+    /// event is logged in full, counted as dropped, and `false` is returned.
+    pub fn spill(&self, event: &NewResourceAuditLogEvent) -> bool {
+        match self.append(event) {
+            Ok(()) => {
+                self.spilled.fetch_add(1, Ordering::Relaxed);
+                self.pending.fetch_add(1, Ordering::Relaxed);
+                self.notify.notify_one();
+                true
+            }
+            Err(err) => {
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+
+                tracing::error!(
+                    error = ?err,
+                    event = %serde_json::to_string(event).unwrap_or_default(),
+                    "unable to spill resource audit event, event dropped"
+                );
+
+                false
+            }
+        }
+    }
+
+    /// Claim every spilled event for replay.
+    ///
+    /// Events claimed by an unfinished earlier replay are returned first and
+    /// nothing new is claimed until they are settled with `finish_replay`.
+    pub fn take_pending(
+        &self,
+    ) -> Result<Vec<NewResourceAuditLogEvent>, MappedErrors> {
+        {
+            let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+
+            if !self.replay_path.exists() && self.path.exists() {
+                fs::rename(&self.path, &self.replay_path).map_err(|err| {
+                    fetching_err(format!(
+                        "Unable to claim audit spill file: {err}"
+                    ))
+                })?;
+            }
+        }
+
+        if !self.replay_path.exists() {
+            return Ok(vec![]);
+        }
+
+        let file = File::open(&self.replay_path).map_err(|err| {
+            fetching_err(format!("Unable to read audit spill file: {err}"))
+        })?;
+
+        let mut events = vec![];
+
+        for line in BufReader::new(file).lines() {
+            let line = line.map_err(|err| {
+                fetching_err(format!("Unable to read audit spill file: {err}"))
+            })?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            match serde_json::from_str::<NewResourceAuditLogEvent>(&line) {
+                Ok(event) => events.push(event),
+                Err(err) => {
+                    //
+                    // Typically the torn last line of a crash mid-write.
+                    // Nothing can be recovered from it, so keep it in the
+                    // logs and move on.
+                    //
+                    self.dropped.fetch_add(1, Ordering::Relaxed);
+                    self.pending.fetch_sub(1, Ordering::Relaxed);
+
+                    tracing::error!(
+                        error = %err,
+                        line,
+                        "undecodable resource audit spill line, event dropped"
+                    );
+                }
+            }
+        }
+
+        Ok(events)
+    }
+
+    /// Settle the batch claimed by `take_pending`, keeping `remaining` (the
+    /// events that could not be persisted) for the next replay.
+    pub fn finish_replay(
+        &self,
+        claimed: usize,
+        remaining: &[NewResourceAuditLogEvent],
+    ) -> Result<(), MappedErrors> {
+        let persisted = claimed.saturating_sub(remaining.len()) as u64;
+
+        if remaining.is_empty() {
+            if self.replay_path.exists() {
+                fs::remove_file(&self.replay_path).map_err(|err| {
+                    creation_err(format!(
+                        "Unable to remove audit spill file: {err}"
+                    ))
+                })?;
+            }
+        } else {
+            let mut tmp_path = self.replay_path.as_os_str().to_owned();
+            tmp_path.push(".tmp");
+            let tmp_path = PathBuf::from(tmp_path);
+
+            write_events(&tmp_path, remaining, false)?;
+
+            fs::rename(&tmp_path, &self.replay_path).map_err(|err| {
+                creation_err(format!(
+                    "Unable to rewrite audit spill file: {err}"
+                ))
+            })?;
+        }
+
+        self.replayed.fetch_add(persisted, Ordering::Relaxed);
+        self.pending.fetch_sub(persisted, Ordering::Relaxed);
+
+        Ok(())
+    }
+
+    /// Record a failed insert attempt that is about to be retried.
+    pub fn record_retry(&self) {
+        self.retried.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Wait until an event is spilled. Returns at once when an event was
+    /// spilled since the last wait.
+    pub async fn wait_for_events(&self) {
+        self.notify.notified().await
+    }
+
+    pub fn stats(&self) -> ResourceAuditPipelineStats {
+        ResourceAuditPipelineStats {
+            pending: self.pending.load(Ordering::Relaxed),
+            spilled: self.spilled.load(Ordering::Relaxed),
+            replayed: self.replayed.load(Ordering::Relaxed),
+            retried: self.retried.load(Ordering::Relaxed),
+            dropped: self.dropped.load(Ordering::Relaxed),
+        }
+    }
+
+    fn append(
+        &self,
+        event: &NewResourceAuditLogEvent,
+    ) -> Result<(), MappedErrors> {
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+
+        write_events(&self.path, std::slice::from_ref(event), true)
+    }
+}
+
+fn write_events(
+    path: &Path,
+    events: &[NewResourceAuditLogEvent],
+    append: bool,
+) -> Result<(), MappedErrors> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(append)
+        .truncate(!append)
+        .open(path)
+        .map_err(|err| {
+            creation_err(format!("Unable to open audit spill file: {err}"))
+        })?;
+
+    let mut buffer = String::new();
+
+    for event in events {
+        let line = serde_json::to_string(event).map_err(|err| {
+            creation_err(format!("Unable to encode audit event: {err}"))
+        })?;
+
+        buffer.push_str(&line);
+        buffer.push('\n');
+    }
+
+    file.write_all(buffer.as_bytes())
+        .and_then(|_| file.sync_data())
+        .map_err(|err| {
+            creation_err(format!("Unable to write audit spill file: {err}"))
+        })
+}
+
+fn count_lines(path: &Path) -> Result<u64, MappedErrors> {
+    if !path.exists() {
+        return Ok(0);
+    }
+
+    let file = File::open(path).map_err(|err| {
+        fetching_err(format!("Unable to read audit spill file: {err}"))
+    })?;
+
+    Ok(BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .filter(|line| !line.trim().is_empty())
+        .count() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::dtos::{
+        resource_audit_log::{
+            ResourceAuditEventKind, ResourceAuditResourceType,
+        },
+        written_by::WrittenBy,
+    };
+
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    fn sample_event() -> NewResourceAuditLogEvent {
+        NewResourceAuditLogEvent {
+            resource_type: ResourceAuditResourceType::Account,
+            resource_id: Uuid::new_v4(),
+            tenant_id: None,
+            event: ResourceAuditEventKind::Created,
+            performed_by: WrittenBy::new_anemic(),
+            metadata: serde_json::json!({ "name": "acc" }),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn temp_spill_path() -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("myc_audit_spill_test_{}", Uuid::new_v4()))
+            .join("spill.jsonl")
+    }
+
+    #[test]
+    fn spilled_events_are_replayed_in_order() {
+        let spill = ResourceAuditSpill::open(temp_spill_path()).unwrap();
+        let first = sample_event();
+        let second = sample_event();
+
+        assert!(spill.spill(&first));
+        assert!(spill.spill(&second));
+        assert_eq!(spill.stats().pending, 2);
+
+        let claimed = spill.take_pending().unwrap();
+        assert_eq!(claimed.len(), 2);
+        assert_eq!(claimed[0].resource_id, first.resource_id);
+        assert_eq!(claimed[1].resource_id, second.resource_id);
+
+        spill.finish_replay(claimed.len(), &[]).unwrap();
+
+        let stats = spill.stats();
+        assert_eq!(stats.pending, 0);
+        assert_eq!(stats.spilled, 2);
+        assert_eq!(stats.replayed, 2);
+        assert!(spill.take_pending().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn spilled_events_wake_the_dispatcher_up() {
+        let spill = ResourceAuditSpill::open(temp_spill_path()).unwrap();
+
+        spill.spill(&sample_event());
+
+        tokio::time::timeout(
+            std::time::Duration::from_secs(1),
+            spill.wait_for_events(),
+        )
+        .await
+        .expect("the spilled event should wake the dispatcher up");
+    }
+
+    #[test]
+    fn unfinished_replay_is_resumed_before_new_spills() {
+        let spill = ResourceAuditSpill::open(temp_spill_path()).unwrap();
+        let stuck = sample_event();
+        let later = sample_event();
+
+        spill.spill(&stuck);
+        let claimed = spill.take_pending().unwrap();
+
+        // New events keep landing in the spill file while a batch is claimed.
+        spill.spill(&later);
+
+        // The stuck event could not be persisted and stays claimed.
+        spill.finish_replay(claimed.len(), &claimed).unwrap();
+
+        let retried = spill.take_pending().unwrap();
+        assert_eq!(retried.len(), 1);
+        assert_eq!(retried[0].resource_id, stuck.resource_id);
+        spill.finish_replay(retried.len(), &[]).unwrap();
+
+        let fresh = spill.take_pending().unwrap();
+        assert_eq!(fresh.len(), 1);
+        assert_eq!(fresh[0].resource_id, later.resource_id);
+    }
+
+    #[test]
+    fn events_left_by_a_previous_run_are_pending_on_open() {
+        let path = temp_spill_path();
+
+        {
+            let spill = ResourceAuditSpill::open(&path).unwrap();
+            spill.spill(&sample_event());
+            spill.take_pending().unwrap();
+            spill.spill(&sample_event());
+        }
+
+        let reopened = ResourceAuditSpill::open(&path).unwrap();
+        assert_eq!(reopened.stats().pending, 2);
+        assert_eq!(reopened.take_pending().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn torn_lines_are_counted_as_dropped() {
+        let path = temp_spill_path();
+        let spill = ResourceAuditSpill::open(&path).unwrap();
+
+        spill.spill(&sample_event());
+        OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(b"{\"resourceType\":\"acc")
+            .unwrap();
+
+        let reopened = ResourceAuditSpill::open(&path).unwrap();
+        assert_eq!(reopened.take_pending().unwrap().len(), 1);
+        assert_eq!(reopened.stats().dropped, 1);
+        assert_eq!(reopened.stats().pending, 1);
+    }
+}
@@ -0,0 +1,247 @@
+// ? ---------------------------------------------------------------------------
+// ? TableChecksum
+//
+// Order-independent checksum of the rows of a table, comparable across
+// database backends. `myc-cli migrate-backend` checksums the rows it reads
+// from the source and the rows it reads back from the target, so a copy is
+// only accepted when both hold the same data.
+//
+// Backends render the same value differently: SQLite stores UUIDs,
+// timestamps, JSON and arrays as TEXT and booleans as integers, while
+// Postgres renders them with their own types. Each value is brought to a
+// canonical form before it is hashed:
+//
+// - booleans become 0 or 1, and integral floats become integers;
+// - UUIDs are written lowercase and hyphenated;
+// - timestamps are written in UTC with microseconds, naive ones being UTC;
+// - text holding JSON is replaced by the JSON it holds;
+// - JSON objects are written with their keys sorted.
+// ? ---------------------------------------------------------------------------
+
+use chrono::{DateTime, Duration, NaiveDateTime, Timelike, Utc};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+/// Formats of the timestamps written without an offset.
+const NAIVE_TIMESTAMP_FORMATS: &[&str] =
+    &["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableChecksum {
+    /// The columns checksummed, sorted by name. Other columns of the rows
+    /// are ignored.
+    pub columns: Vec<String>,
+
+    /// The number of rows checksummed
+    pub rows: u64,
+
+    /// The wrapping sum of the first 64 bits of the SHA-256 of each row
+    pub checksum: u64,
+}
+
+impl TableChecksum {
+    pub fn new(mut columns: Vec<String>) -> Self {
+        columns.sort();
+
+        Self {
+            columns,
+            rows: 0,
+            checksum: 0,
+        }
+    }
+
+    /// Add a row, given as a JSON object keyed by column name. Columns
+    /// missing from the row count as `NULL`.
+    pub fn add_row(&mut self, row: &Map<String, Value>) {
+        let mut encoded = String::new();
+
+        for column in self.columns.iter() {
+            encoded.push_str(&Value::String(column.to_owned()).to_string());
+            encoded.push(':');
+            write_canonical(
+                &canonical_value(row.get(column).unwrap_or(&Value::Null)),
+                &mut encoded,
+            );
+            encoded.push(',');
+        }
+
+        let digest = Sha256::digest(encoded.as_bytes());
+        let mut head = [0u8; 8];
+        head.copy_from_slice(&digest[..8]);
+
+        self.rows += 1;
+        self.checksum = self.checksum.wrapping_add(u64::from_be_bytes(head));
+    }
+
+    /// The checksum as 16 hex digits
+    pub fn to_hex(&self) -> String {
+        format!("{:016x}", self.checksum)
+    }
+}
+
+fn canonical_value(value: &Value) -> Value {
+    match value {
+        Value::Bool(flag) => Value::from(*flag as i64),
+        Value::Number(number) => match number.as_f64() {
+            Some(float)
+                if !number.is_i64()
+                    && !number.is_u64()
+                    && float.fract() == 0.0
+                    && float.abs() < i64::MAX as f64 =>
+            {
+                Value::from(float as i64)
+            }
+            _ => value.to_owned(),
+        },
+        Value::String(text) => canonical_text(text),
+        _ => value.to_owned(),
+    }
+}
+
+fn canonical_text(text: &str) -> Value {
+    if let Ok(id) = Uuid::parse_str(text) {
+        return Value::String(id.hyphenated().to_string());
+    }
+
+    if let Some(moment) = parse_timestamp(text) {
+        return Value::String(
+            moment.format("%Y-%m-%dT%H:%M:%S%.6fZ").to_string(),
+        );
+    }
+
+    match serde_json::from_str::<Value>(text) {
+        Ok(Value::String(inner)) => canonical_text(&inner),
+        Ok(parsed) => canonical_value(&parsed),
+        Err(_) => Value::String(text.to_string()),
+    }
+}
+
+/// Parse a timestamp, rounded to microseconds as Postgres stores it
+fn parse_timestamp(text: &str) -> Option<DateTime<Utc>> {
+    let moment = DateTime::parse_from_rfc3339(text)
+        .map(|moment| moment.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NAIVE_TIMESTAMP_FORMATS.iter().find_map(|format| {
+                NaiveDateTime::parse_from_str(text, format)
+                    .ok()
+                    .map(|naive| naive.and_utc())
+            })
+        })?;
+
+    let micros = (moment.nanosecond() as i64 + 500) / 1_000;
+
+    Some(moment.with_nanosecond(0)? + Duration::microseconds(micros))
+}
+
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Object(map) => {
+            let mut entries = map.iter().collect::<Vec<_>>();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+
+            out.push('{');
+
+            for (index, (key, value)) in entries.into_iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+
+                out.push_str(&Value::String(key.to_owned()).to_string());
+                out.push(':');
+                write_canonical(value, out);
+            }
+
+            out.push('}');
+        }
+        Value::Array(items) => {
+            out.push('[');
+
+            for (index, item) in items.iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+
+                write_canonical(item, out);
+            }
+
+            out.push(']');
+        }
+        _ => out.push_str(&value.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn checksum(row: Value) -> TableChecksum {
+        let mut checksum = TableChecksum::new(vec![
+            "id".to_string(),
+            "created".to_string(),
+            "is_active".to_string(),
+            "meta".to_string(),
+            "status".to_string(),
+            "name".to_string(),
+            "weight".to_string(),
+        ]);
+
+        checksum.add_row(row.as_object().unwrap());
+        checksum
+    }
+
+    #[test]
+    fn sqlite_and_postgres_renderings_of_a_row_checksum_alike() {
+        let sqlite = checksum(json!({
+            "id": "8D3E0B4C-9B36-4A8C-9F0B-6B1C2D3E4F50",
+            "created": "2026-10-19T08:30:00.123456789",
+            "is_active": 1,
+            "meta": "{\"b\":1,\"a\":[true]}",
+            "status": "[{\"active\":true}]",
+            "name": "Acme",
+            "weight": 2,
+            "encrypted_dek": "source",
+        }));
+
+        let postgres = checksum(json!({
+            "id": "8d3e0b4c-9b36-4a8c-9f0b-6b1c2d3e4f50",
+            "created": "2026-10-19T08:30:00.123457+00:00",
+            "is_active": true,
+            "meta": { "a": [true], "b": 1 },
+            "status": [{ "active": true }],
+            "name": "Acme",
+            "weight": 2.0,
+            "encrypted_dek": "target",
+        }));
+
+        assert_eq!(sqlite.rows, 1);
+        assert_eq!(sqlite, postgres);
+    }
+
+    #[test]
+    fn changed_values_change_the_checksum() {
+        let row = json!({ "id": Uuid::nil().to_string(), "name": "Acme" });
+        let changed = json!({ "id": Uuid::nil().to_string(), "name": "Acme!" });
+
+        assert_ne!(checksum(row.to_owned()), checksum(changed));
+        assert_ne!(checksum(row).to_hex(), TableChecksum::new(vec![]).to_hex());
+    }
+
+    #[test]
+    fn checksums_do_not_depend_on_the_row_order() {
+        let rows = [json!({ "name": "a" }), json!({ "name": "b" })];
+
+        let mut forward = TableChecksum::new(vec!["name".to_string()]);
+        let mut backward = forward.to_owned();
+
+        rows.iter()
+            .for_each(|row| forward.add_row(row.as_object().unwrap()));
+        rows.iter()
+            .rev()
+            .for_each(|row| backward.add_row(row.as_object().unwrap()));
+
+        assert_eq!(forward, backward);
+    }
+}
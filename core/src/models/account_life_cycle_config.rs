@@ -1,8 +1,9 @@
 use crate::{
     domain::utils::derive_key_from_uuid,
-    models::{HmacSecretEntry, HmacSecretSet},
+    models::{HmacSecretEntry, HmacSecretSet, SmsProviderConfig},
 };
 
+use chrono::Duration;
 use myc_config::secret_resolver::SecretResolver;
 use mycelium_base::utils::errors::{dto_err, MappedErrors};
 use serde::{Deserialize, Serialize};
@@ -32,6 +33,14 @@ pub struct AccountLifeCycle {
     #[serde(default = "default_token_expiration")]
     pub token_expiration: SecretResolver<i64>,
 
+    /// Guest invitation expiration time in seconds
+    ///
+    /// Invitations not accepted within this time can no longer be accepted
+    /// and are removed by the scheduler. Resending an invitation restarts the
+    /// countdown.
+    #[serde(default = "default_invitation_expiration")]
+    pub invitation_expiration: SecretResolver<i64>,
+
     /// General Purpose email name
     pub noreply_name: Option<SecretResolver<String>>,
 
@@ -64,17 +73,47 @@ pub struct AccountLifeCycle {
     /// (`/_adm/instance/bootstrap*`). Absent by default — bootstrap stays
     /// fully disabled (404) until the operator opts in by setting this.
     pub staff_bootstrap_secret: Option<SecretResolver<String>>,
+
+    /// Provider of the SMS notification channel
+    ///
+    /// Users preferring SMS notifications receive them by email while no
+    /// provider is configured.
+    pub sms_provider: Option<SmsProviderConfig>,
+
+    /// Shared secret of the email provider delivery webhook
+    /// (`/_adm/instance/delivery-events`). The webhook answers 404 while
+    /// absent, so bounces and complaints are only learned from the local
+    /// mailbox simulator.
+    pub delivery_webhook_secret: Option<SecretResolver<String>>,
 }
 
 fn default_token_expiration() -> SecretResolver<i64> {
     SecretResolver::Value(3600)
 }
 
+fn default_invitation_expiration() -> SecretResolver<i64> {
+    SecretResolver::Value(604_800)
+}
+
 fn default_hmac_primary_version() -> u32 {
     1
 }
 
 impl AccountLifeCycle {
+    /// How long guest invitations may be accepted
+    pub async fn invitation_expires_in(
+        &self,
+    ) -> Result<Duration, MappedErrors> {
+        let secs = self.invitation_expiration.async_get_or_error().await?;
+
+        if secs <= 0 {
+            return dto_err("Invitation expiration must be positive")
+                .as_error();
+        }
+
+        Ok(Duration::seconds(secs))
+    }
+
     /// Derive the 256-bit KEK (Key Encryption Key) bytes from `token_secret`.
     ///
     /// **Derivation:** SHA-256(token_secret UUID bytes) → 32-byte KEK.
@@ -166,9 +205,19 @@ impl AccountLifeCycle {
     pub fn primary_hmac_secret_resolver(
         &self,
     ) -> Option<&SecretResolver<String>> {
-        self.hmac_secrets
-            .lookup(self.hmac_primary_version)
-            .map(|entry| &entry.secret)
+        self.hmac_secret_resolver(self.hmac_primary_version)
+    }
+
+    /// Read-only access to the resolver for the HMAC key of `version`, if
+    /// configured.
+    ///
+    /// Used by `myc-cli migrate-backend` to check that the connection strings
+    /// signed by a standalone instance stay verifiable on the target.
+    pub fn hmac_secret_resolver(
+        &self,
+        version: u32,
+    ) -> Option<&SecretResolver<String>> {
+        self.hmac_secrets.lookup(version).map(|entry| &entry.secret)
     }
 
     /// Return a clone of this config with a single HMAC key (version 1)
@@ -241,6 +290,7 @@ mod tests {
             domain_url: None,
             locale: None,
             token_expiration: SecretResolver::Value(3600),
+            invitation_expiration: SecretResolver::Value(604_800),
             noreply_name: None,
             noreply_email: SecretResolver::Value(
                 "noreply@example.com".to_string(),
@@ -256,6 +306,8 @@ mod tests {
                 secret: SecretResolver::Value("placeholder".to_string()),
             }]),
             staff_bootstrap_secret: None,
+            sms_provider: None,
+            delivery_webhook_secret: None,
         }
     }
 
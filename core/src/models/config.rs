@@ -1,4 +1,7 @@
-use super::{account_life_cycle_config::AccountLifeCycle, WebhookConfig};
+use super::{
+    account_life_cycle_config::AccountLifeCycle, ResourceAuditConfig,
+    SchedulerConfig, WebhookConfig,
+};
 
 use myc_config::load_config_from_file;
 use mycelium_base::utils::errors::{creation_err, MappedErrors};
@@ -10,6 +13,16 @@ use std::path::PathBuf;
 pub struct CoreConfig {
     pub account_life_cycle: AccountLifeCycle,
     pub webhook: WebhookConfig,
+
+    /// Optional: every field falls back to its default when `[core.audit]`
+    /// is omitted.
+    #[serde(default)]
+    pub audit: ResourceAuditConfig,
+
+    /// Optional: every field falls back to its default when
+    /// `[core.scheduler]` is omitted.
+    #[serde(default)]
+    pub scheduler: SchedulerConfig,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -1,9 +1,15 @@
 mod account_life_cycle_config;
 mod config;
 mod hmac_secret_set;
+mod resource_audit_config;
+mod scheduler_config;
+mod sms_provider_config;
 mod webhook_config;
 
 pub use account_life_cycle_config::*;
 pub use config::*;
 pub use hmac_secret_set::*;
+pub use resource_audit_config::*;
+pub use scheduler_config::*;
+pub use sms_provider_config::*;
 pub use webhook_config::*;
@@ -0,0 +1,134 @@
+use myc_config::secret_resolver::SecretResolver;
+use serde::{Deserialize, Serialize};
+
+/// This struct is used to manage the resource audit log pipeline
+/// configurations.
+///
+/// Audit events are written ahead to a local spill file, then inserted by a
+/// single writer that removes them from the file once persisted. Events the
+/// database keeps rejecting after every retry stay in the file and are
+/// replayed from there.
+///
+/// Rows are kept forever unless a retention is configured, either here as the
+/// default or per tenant (`myc-cli audit retention`). Expired rows are moved
+/// to signed, compressed archive files under `archivePath`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceAuditConfig {
+    /// Spill file path
+    ///
+    /// JSON Lines file holding events waiting to be persisted. Must live on
+    /// persistent storage: events are only as durable as this file.
+    #[serde(default = "default_spill_path")]
+    pub spill_path: SecretResolver<String>,
+
+    /// Insert attempts before an event is left for the next replay
+    #[serde(default = "default_max_insert_attempts")]
+    pub max_insert_attempts: SecretResolver<u64>,
+
+    /// Backoff before the first retry, doubled on every further retry
+    #[serde(default = "default_initial_retry_backoff_in_ms")]
+    pub initial_retry_backoff_in_ms: SecretResolver<u64>,
+
+    /// Upper bound of the retry backoff
+    #[serde(default = "default_max_retry_backoff_in_ms")]
+    pub max_retry_backoff_in_ms: SecretResolver<u64>,
+
+    /// Spill file replay interval in seconds
+    #[serde(default = "default_replay_interval_in_secs")]
+    pub replay_interval_in_secs: SecretResolver<u64>,
+
+    /// Default retention in days
+    ///
+    /// Applies to every chain without a retention of its own. When absent,
+    /// such chains are never archived.
+    #[serde(default)]
+    pub retention_days: Option<SecretResolver<u64>>,
+
+    /// Archive store root directory
+    ///
+    /// An S3-compatible bucket can be used by mounting it at this path.
+    #[serde(default = "default_archive_path")]
+    pub archive_path: SecretResolver<String>,
+
+    /// Interval in seconds between two retention runs of the API
+    #[serde(default = "default_retention_interval_in_secs")]
+    pub retention_interval_in_secs: SecretResolver<u64>,
+}
+
+impl Default for ResourceAuditConfig {
+    fn default() -> Self {
+        Self {
+            spill_path: default_spill_path(),
+            max_insert_attempts: default_max_insert_attempts(),
+            initial_retry_backoff_in_ms: default_initial_retry_backoff_in_ms(),
+            max_retry_backoff_in_ms: default_max_retry_backoff_in_ms(),
+            replay_interval_in_secs: default_replay_interval_in_secs(),
+            retention_days: None,
+            archive_path: default_archive_path(),
+            retention_interval_in_secs: default_retention_interval_in_secs(),
+        }
+    }
+}
+
+fn default_spill_path() -> SecretResolver<String> {
+    SecretResolver::Value("audit-spill.jsonl".to_string())
+}
+
+fn default_max_insert_attempts() -> SecretResolver<u64> {
+    SecretResolver::Value(5)
+}
+
+fn default_initial_retry_backoff_in_ms() -> SecretResolver<u64> {
+    SecretResolver::Value(200)
+}
+
+fn default_max_retry_backoff_in_ms() -> SecretResolver<u64> {
+    SecretResolver::Value(30_000)
+}
+
+fn default_replay_interval_in_secs() -> SecretResolver<u64> {
+    SecretResolver::Value(30)
+}
+
+fn default_archive_path() -> SecretResolver<String> {
+    SecretResolver::Value("audit-archive".to_string())
+}
+
+fn default_retention_interval_in_secs() -> SecretResolver<u64> {
+    SecretResolver::Value(86_400)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resource_audit_config_defaults_when_fields_absent() {
+        let config: ResourceAuditConfig = toml::from_str("").unwrap();
+
+        assert_eq!(
+            config.spill_path,
+            SecretResolver::Value("audit-spill.jsonl".to_string())
+        );
+        assert_eq!(config.max_insert_attempts, SecretResolver::Value(5));
+        assert_eq!(
+            config.initial_retry_backoff_in_ms,
+            SecretResolver::Value(200)
+        );
+        assert_eq!(
+            config.max_retry_backoff_in_ms,
+            SecretResolver::Value(30_000)
+        );
+        assert_eq!(config.replay_interval_in_secs, SecretResolver::Value(30));
+        assert!(config.retention_days.is_none());
+        assert_eq!(
+            config.archive_path,
+            SecretResolver::Value("audit-archive".to_string())
+        );
+        assert_eq!(
+            config.retention_interval_in_secs,
+            SecretResolver::Value(86_400)
+        );
+    }
+}
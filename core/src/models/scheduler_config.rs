@@ -0,0 +1,201 @@
+use myc_config::secret_resolver::SecretResolver;
+use serde::{Deserialize, Serialize};
+
+/// This struct is used to manage the notification scheduler configurations.
+///
+/// Every API replica checks for due jobs every `tickIntervalInSecs`. A run is
+/// claimed in the database before it starts, so a single replica executes
+/// each run. Setting the interval or the days of a job to zero disables it.
+///
+/// Trashed tenants are purged `tenantTrashRetentionDays` after being trashed,
+/// and their owners warned `tenantPurgeWarningDays` ahead. With a zero
+/// retention, trashed tenants are kept until deleted by a manager.
+///
+/// Bulk import jobs are polled every `bulkImportIntervalInSecs` and claimed
+/// one at a time, so several replicas share the queue.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SchedulerConfig {
+    /// Interval in seconds between two checks for due jobs
+    #[serde(default = "default_tick_interval_in_secs")]
+    pub tick_interval_in_secs: SecretResolver<u64>,
+
+    /// Days after which unaccepted invitations are reminded
+    #[serde(default = "default_invitation_reminder_after_days")]
+    pub invitation_reminder_after_days: SecretResolver<u64>,
+
+    /// Interval in seconds between two invitation reminder runs
+    #[serde(default = "default_daily_interval_in_secs")]
+    pub invitation_reminder_interval_in_secs: SecretResolver<u64>,
+
+    /// Days before expiration the connection string owners are warned
+    #[serde(default = "default_connection_string_expiry_warning_days")]
+    pub connection_string_expiry_warning_days: SecretResolver<u64>,
+
+    /// Interval in seconds between two connection string expiry runs
+    #[serde(default = "default_daily_interval_in_secs")]
+    pub connection_string_expiry_interval_in_secs: SecretResolver<u64>,
+
+    /// Interval in seconds between two tenant membership digests
+    #[serde(default = "default_tenant_digest_interval_in_secs")]
+    pub tenant_digest_interval_in_secs: SecretResolver<u64>,
+
+    /// Days before expiration the guests are warned of their grant end date
+    #[serde(default = "default_guest_grant_expiry_warning_days")]
+    pub guest_grant_expiry_warning_days: SecretResolver<u64>,
+
+    /// Interval in seconds between two guest grant expiry warning runs
+    #[serde(default = "default_daily_interval_in_secs")]
+    pub guest_grant_expiry_warning_interval_in_secs: SecretResolver<u64>,
+
+    /// Interval in seconds between two revocations of expired guest grants
+    #[serde(default = "default_guest_grant_sweep_interval_in_secs")]
+    pub guest_grant_sweep_interval_in_secs: SecretResolver<u64>,
+
+    /// Interval in seconds between two removals of expired invitations
+    #[serde(default = "default_guest_grant_sweep_interval_in_secs")]
+    pub invitation_sweep_interval_in_secs: SecretResolver<u64>,
+
+    /// Days trashed tenants are kept before being purged
+    #[serde(default = "default_tenant_trash_retention_days")]
+    pub tenant_trash_retention_days: SecretResolver<u64>,
+
+    /// Days before the purge the owners of trashed tenants are warned
+    #[serde(default = "default_tenant_purge_warning_days")]
+    pub tenant_purge_warning_days: SecretResolver<u64>,
+
+    /// Interval in seconds between two tenant purge warning runs
+    #[serde(default = "default_daily_interval_in_secs")]
+    pub tenant_purge_warning_interval_in_secs: SecretResolver<u64>,
+
+    /// Interval in seconds between two purges of trashed tenants
+    #[serde(default = "default_guest_grant_sweep_interval_in_secs")]
+    pub tenant_purge_interval_in_secs: SecretResolver<u64>,
+
+    /// Interval in seconds between two checks for pending bulk imports
+    #[serde(default = "default_bulk_import_interval_in_secs")]
+    pub bulk_import_interval_in_secs: SecretResolver<u64>,
+}
+
+impl Default for SchedulerConfig {
+    fn default() -> Self {
+        Self {
+            tick_interval_in_secs: default_tick_interval_in_secs(),
+            invitation_reminder_after_days:
+                default_invitation_reminder_after_days(),
+            invitation_reminder_interval_in_secs:
+                default_daily_interval_in_secs(),
+            connection_string_expiry_warning_days:
+                default_connection_string_expiry_warning_days(),
+            connection_string_expiry_interval_in_secs:
+                default_daily_interval_in_secs(),
+            tenant_digest_interval_in_secs:
+                default_tenant_digest_interval_in_secs(),
+            guest_grant_expiry_warning_days:
+                default_guest_grant_expiry_warning_days(),
+            guest_grant_expiry_warning_interval_in_secs:
+                default_daily_interval_in_secs(),
+            guest_grant_sweep_interval_in_secs:
+                default_guest_grant_sweep_interval_in_secs(),
+            invitation_sweep_interval_in_secs:
+                default_guest_grant_sweep_interval_in_secs(),
+            tenant_trash_retention_days: default_tenant_trash_retention_days(),
+            tenant_purge_warning_days: default_tenant_purge_warning_days(),
+            tenant_purge_warning_interval_in_secs:
+                default_daily_interval_in_secs(),
+            tenant_purge_interval_in_secs:
+                default_guest_grant_sweep_interval_in_secs(),
+            bulk_import_interval_in_secs: default_bulk_import_interval_in_secs(
+            ),
+        }
+    }
+}
+
+fn default_tick_interval_in_secs() -> SecretResolver<u64> {
+    SecretResolver::Value(60)
+}
+
+fn default_invitation_reminder_after_days() -> SecretResolver<u64> {
+    SecretResolver::Value(3)
+}
+
+fn default_connection_string_expiry_warning_days() -> SecretResolver<u64> {
+    SecretResolver::Value(7)
+}
+
+fn default_guest_grant_expiry_warning_days() -> SecretResolver<u64> {
+    SecretResolver::Value(3)
+}
+
+fn default_guest_grant_sweep_interval_in_secs() -> SecretResolver<u64> {
+    SecretResolver::Value(3_600)
+}
+
+fn default_tenant_trash_retention_days() -> SecretResolver<u64> {
+    SecretResolver::Value(30)
+}
+
+fn default_tenant_purge_warning_days() -> SecretResolver<u64> {
+    SecretResolver::Value(7)
+}
+
+fn default_bulk_import_interval_in_secs() -> SecretResolver<u64> {
+    SecretResolver::Value(10)
+}
+
+fn default_daily_interval_in_secs() -> SecretResolver<u64> {
+    SecretResolver::Value(86_400)
+}
+
+fn default_tenant_digest_interval_in_secs() -> SecretResolver<u64> {
+    SecretResolver::Value(604_800)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scheduler_config_defaults_when_fields_absent() {
+        let config: SchedulerConfig = toml::from_str("").unwrap();
+
+        assert_eq!(config.tick_interval_in_secs, SecretResolver::Value(60));
+        assert_eq!(
+            config.invitation_reminder_after_days,
+            SecretResolver::Value(3)
+        );
+        assert_eq!(
+            config.connection_string_expiry_warning_days,
+            SecretResolver::Value(7)
+        );
+        assert_eq!(
+            config.tenant_digest_interval_in_secs,
+            SecretResolver::Value(604_800)
+        );
+        assert_eq!(
+            config.guest_grant_expiry_warning_days,
+            SecretResolver::Value(3)
+        );
+        assert_eq!(
+            config.guest_grant_sweep_interval_in_secs,
+            SecretResolver::Value(3_600)
+        );
+        assert_eq!(
+            config.invitation_sweep_interval_in_secs,
+            SecretResolver::Value(3_600)
+        );
+        assert_eq!(
+            config.tenant_trash_retention_days,
+            SecretResolver::Value(30)
+        );
+        assert_eq!(config.tenant_purge_warning_days, SecretResolver::Value(7));
+        assert_eq!(
+            config.tenant_purge_interval_in_secs,
+            SecretResolver::Value(3_600)
+        );
+        assert_eq!(
+            config.bulk_import_interval_in_secs,
+            SecretResolver::Value(10)
+        );
+    }
+}
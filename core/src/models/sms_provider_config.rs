@@ -0,0 +1,19 @@
+use myc_config::secret_resolver::SecretResolver;
+use serde::{Deserialize, Serialize};
+
+/// This struct is used to manage the HTTP provider used to send text messages.
+///
+/// Messages are posted as JSON (`{"from", "to", "text"}`) to `url`. The
+/// `apiKey`, when informed, is sent as a bearer token.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SmsProviderConfig {
+    /// Endpoint the messages are posted to
+    pub url: SecretResolver<String>,
+
+    /// Bearer token of the provider API
+    pub api_key: Option<SecretResolver<String>>,
+
+    /// Sender number or alphanumeric id
+    pub sender: Option<SecretResolver<String>>,
+}
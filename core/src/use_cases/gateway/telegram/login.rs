@@ -1,10 +1,18 @@
 use super::resolve::resolve_account_by_telegram_id;
 use crate::{
     domain::{
-        dtos::{telegram::TelegramUser, token::UserAccountScope},
-        entities::AccountFetching,
+        dtos::{
+            resource_audit_log::{
+                ResourceAuditEventKind, ResourceAuditResourceType,
+            },
+            telegram::TelegramUser,
+            token::UserAccountScope,
+            written_by::WrittenBy,
+        },
+        entities::{AccountFetching, ResourceAuditLogRegistration},
     },
     models::AccountLifeCycle,
+    use_cases::shared::audit::emit_resource_audit_event,
 };
 
 use chrono::{Duration, Local};
@@ -20,21 +28,23 @@ use uuid::Uuid;
 /// subsequent Mycelium MCP or REST API calls (Mode A authentication).
 #[tracing::instrument(
     name = "login_via_telegram",
-    skip(account_fetching, config)
+    skip(account_fetching, config, audit_repo)
 )]
 pub async fn login_via_telegram(
     tenant_id: Uuid,
     telegram_user: TelegramUser,
     account_fetching: Box<&dyn AccountFetching>,
     config: AccountLifeCycle,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
 ) -> Result<(UserAccountScope, chrono::DateTime<Local>), MappedErrors> {
-    let account =
-        resolve_account_by_telegram_id(telegram_user.id, account_fetching)
-            .await
-            .map_err(|e| {
-                use_case_err(format!("telegram_id_not_linked: {e}"))
-                    .with_exp_true()
-            })?;
+    let account = resolve_account_by_telegram_id(
+        telegram_user.id.to_owned(),
+        account_fetching,
+    )
+    .await
+    .map_err(|e| {
+        use_case_err(format!("telegram_id_not_linked: {e}")).with_exp_true()
+    })?;
 
     let account_id = account.id.ok_or_else(|| {
         use_case_err("Account has no id — data integrity error")
@@ -53,5 +63,21 @@ pub async fn login_via_telegram(
     )
     .await?;
 
+    emit_resource_audit_event(
+        audit_repo,
+        ResourceAuditResourceType::Account,
+        account_id,
+        Some(tenant_id),
+        ResourceAuditEventKind::Accessed,
+        WrittenBy::new_from_account(account_id),
+        serde_json::json!({
+            "action": "login",
+            "method": "telegram",
+            "outcome": "success",
+            "telegramUserId": telegram_user.id,
+        }),
+    )
+    .await;
+
     Ok((connection_string, expires_at))
 }
@@ -43,7 +43,15 @@ pub mod super_users;
 /// functionalities.
 ///
 pub(crate) mod support;
-pub use support::dispatch_webhooks;
+pub use support::{
+    dispatch_webhooks, purge_trashed_tenants, register_delivery_events,
+    run_bulk_import, run_pending_bulk_imports,
+    send_connection_string_expiry_warnings, send_guest_grant_expiry_warnings,
+    send_invitation_reminders, send_tenant_membership_digests,
+    send_tenant_purge_warnings, sweep_expired_guest_grants,
+    sweep_expired_guest_invitations, translate_error_code,
+    validate_bulk_import_rows, validate_delivery_webhook_secret,
+};
 
 /// Shared use cases
 ///
@@ -2,18 +2,33 @@ use crate::{
     domain::{
         actors::SystemActor,
         dtos::{
-            account::VerboseStatus, account_type::AccountType, email::Email,
-            guest_user::GuestUser, native_error_codes::NativeErrorCodes,
+            account::VerboseStatus,
+            account_type::AccountType,
+            email::Email,
+            guest_user::{GuestInvitationEvent, GuestUser},
+            native_error_codes::NativeErrorCodes,
             profile::Profile,
+            resource_audit_log::{
+                ResourceAuditEventKind, ResourceAuditResourceType,
+            },
+            webhook::{PayloadId, WebHookTrigger},
+            written_by::WrittenBy,
         },
         entities::{
-            AccountFetching, GuestRoleFetching, GuestUserRegistration,
-            LocalMessageWrite, TenantFetching,
+            AccountFetching, EmailSuppressionFetching, EncryptionKeyFetching,
+            GuestRoleFetching, GuestUserRegistration, LocalMessageWrite,
+            NotificationRecipientFetching, ResourceAuditLogRegistration,
+            TenantFetching, WebHookRegistration,
         },
     },
     models::AccountLifeCycle,
     settings::DEFAULT_TENANT_ID_KEY,
-    use_cases::support::dispatch_notification,
+    use_cases::{
+        shared::audit::emit_resource_audit_event,
+        support::{
+            dispatch_channel_notification, register_webhook_dispatching_event,
+        },
+    },
 };
 
 use futures::future;
@@ -41,7 +56,14 @@ pub async fn guest_to_children_account(
     guest_role_fetching_repo: Box<&dyn GuestRoleFetching>,
     guest_user_registration_repo: Box<&dyn GuestUserRegistration>,
     message_sending_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
     tenant_fetching_repo: Box<&dyn TenantFetching>,
+    notification_recipient_fetching_repo: Box<
+        &dyn NotificationRecipientFetching,
+    >,
+    encryption_key_fetching_repo: Box<&dyn EncryptionKeyFetching>,
+    webhook_registration_repo: Box<&dyn WebHookRegistration>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
 ) -> Result<GetOrCreateResponseKind<GuestUser>, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Check if the current account has sufficient privileges
@@ -132,17 +154,22 @@ pub async fn guest_to_children_account(
     //
     // The target role must be a child of the parent role.
     //
+    // Roles of other tenants are reported as not found.
+    //
     let target_role = match target_role_response? {
-        FetchResponseKind::NotFound(id) => {
+        FetchResponseKind::Found(role)
+            if role.is_available_to_tenant(Some(tenant_id)) =>
+        {
+            role
+        }
+        _ => {
             return use_case_err(format!(
-                "Guest role not found: {:?}",
-                id.unwrap()
+                "Guest role not found: {target_role_id}"
             ))
             .with_exp_true()
             .with_code(NativeErrorCodes::MYC00013)
             .as_error()
         }
-        FetchResponseKind::Found(role) => role,
     };
 
     tracing::debug!("target_role: {:?}", target_role);
@@ -219,12 +246,19 @@ pub async fn guest_to_children_account(
     // ? Persist changes
     // ? -----------------------------------------------------------------------
 
+    let invitation_expires_in =
+        life_cycle_settings.invitation_expires_in().await?;
+
     let guest_user = match guest_user_registration_repo
         .get_or_create(
             GuestUser::new_unverified(
                 email.to_owned(),
                 Parent::Id(target_role_id),
                 None,
+            )
+            .with_invitation(
+                WrittenBy::new_from_account(profile.acc_id),
+                invitation_expires_in,
             ),
             target_account_id,
         )
@@ -239,11 +273,44 @@ pub async fn guest_to_children_account(
         }
     };
 
+    if let GetOrCreateResponseKind::Created(guest) = &guest_user {
+        emit_resource_audit_event(
+            audit_repo,
+            ResourceAuditResourceType::GuestUser,
+            target_account_id,
+            Some(tenant_id),
+            ResourceAuditEventKind::Created,
+            WrittenBy::new_from_account(profile.acc_id),
+            serde_json::json!({
+                "action": "guest_to_children_account",
+                "guestUserId": guest.id,
+                "guestRoleId": target_role_id,
+                "email": email.email(),
+            }),
+        )
+        .await;
+
+        if let (false, Some(guest_user_id)) = (guest.was_verified, guest.id) {
+            register_webhook_dispatching_event(
+                Uuid::new_v4(),
+                WebHookTrigger::GuestInvitationCreated,
+                GuestInvitationEvent::from_guest_user(
+                    guest,
+                    target_account_id,
+                    Some(tenant_id),
+                )?,
+                PayloadId::Uuid(guest_user_id),
+                webhook_registration_repo,
+            )
+            .await?;
+        }
+    }
+
     // ? -----------------------------------------------------------------------
     // ? Notify guest user
     // ? -----------------------------------------------------------------------
 
-    if let Err(err) = dispatch_notification(
+    if let Err(err) = dispatch_channel_notification(
         vec![
             ("account_name", target_account.name.to_uppercase()),
             ("role_name", target_role.name.to_uppercase()),
@@ -255,7 +322,10 @@ pub async fn guest_to_children_account(
         email,
         None,
         message_sending_repo,
+        email_suppression_fetching_repo,
         tenant_fetching_repo,
+        notification_recipient_fetching_repo,
+        encryption_key_fetching_repo,
     )
     .await
     {
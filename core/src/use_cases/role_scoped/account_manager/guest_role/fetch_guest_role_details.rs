@@ -34,5 +34,14 @@ pub async fn fetch_guest_role_details(
     // ? Fetch Guest Role
     // ? -----------------------------------------------------------------------
 
-    guest_role_fetching_repo.get(guest_role_id).await
+    // Roles of other tenants are reported as not found.
+    //
+    match guest_role_fetching_repo.get(guest_role_id).await? {
+        FetchResponseKind::Found(role)
+            if !role.is_available_to_tenant(tenant_id) =>
+        {
+            Ok(FetchResponseKind::NotFound(Some(guest_role_id)))
+        }
+        res => Ok(res),
+    }
 }
@@ -13,7 +13,7 @@ use crate::{
             written_by::WrittenBy,
         },
         entities::{
-            AccountRegistration, LocalMessageWrite,
+            AccountRegistration, EmailSuppressionFetching, LocalMessageWrite,
             ResourceAuditLogRegistration, TenantFetching, UserFetching,
             UserRegistration, WebHookRegistration,
         },
@@ -55,6 +55,7 @@ pub async fn create_user_account(
     account_registration_repo: Box<&dyn AccountRegistration>,
     webhook_registration_repo: Box<&dyn WebHookRegistration>,
     message_sending_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
     tenant_fetching_repo: Box<&dyn TenantFetching>,
     audit_repo: Box<&dyn ResourceAuditLogRegistration>,
 ) -> Result<Account, MappedErrors> {
@@ -176,6 +177,7 @@ pub async fn create_user_account(
             email,
             None,
             message_sending_repo,
+            email_suppression_fetching_repo,
             tenant_fetching_repo,
         ),
         register_webhook_dispatching_event(
@@ -244,7 +246,9 @@ mod tests {
             user::Provider,
             webhook::WebHookPayloadArtifact,
         },
-        entities::MockResourceAuditLogRegistration,
+        entities::{
+            MockEmailSuppressionFetching, MockResourceAuditLogRegistration,
+        },
     };
     use crate::models::{HmacSecretEntry, HmacSecretSet};
 
@@ -272,6 +276,7 @@ mod tests {
             )),
             locale: Some(SecretResolver::Value("en-us".to_string())),
             token_expiration: SecretResolver::Value(3600),
+            invitation_expiration: SecretResolver::Value(604_800),
             noreply_name: Some(SecretResolver::Value(
                 "Test System".to_string(),
             )),
@@ -289,6 +294,8 @@ mod tests {
                 secret: SecretResolver::Value("test-hmac".to_string()),
             }]),
             staff_bootstrap_secret: None,
+            sms_provider: None,
+            delivery_webhook_secret: None,
         }
     }
 
@@ -506,6 +513,65 @@ mod tests {
         ) -> Result<FetchManyResponseKind<Tenant>, MappedErrors> {
             unimplemented!()
         }
+
+        async fn get_email_template(
+            &self,
+            _: Uuid,
+            _: crate::domain::dtos::tenant::EmailTemplateKind,
+            _: String,
+        ) -> Result<
+            FetchResponseKind<
+                crate::domain::dtos::tenant::TenantEmailTemplate,
+                String,
+            >,
+            MappedErrors,
+        > {
+            unimplemented!()
+        }
+
+        async fn list_email_templates(
+            &self,
+            _: Uuid,
+        ) -> Result<
+            FetchManyResponseKind<
+                crate::domain::dtos::tenant::TenantEmailTemplate,
+            >,
+            MappedErrors,
+        > {
+            unimplemented!()
+        }
+
+        async fn list_descendants(
+            &self,
+            _: Uuid,
+        ) -> Result<
+            mycelium_base::entities::FetchManyResponseKind<
+                crate::domain::dtos::tenant::Tenant,
+            >,
+            MappedErrors,
+        > {
+            unimplemented!()
+        }
+
+        async fn list_trashed(
+            &self,
+            _: Option<chrono::DateTime<chrono::Utc>>,
+            _: chrono::DateTime<chrono::Utc>,
+        ) -> Result<FetchManyResponseKind<Tenant>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn list_owners(
+            &self,
+            _: Uuid,
+        ) -> Result<
+            mycelium_base::entities::FetchManyResponseKind<
+                crate::domain::dtos::profile::Owner,
+            >,
+            MappedErrors,
+        > {
+            unimplemented!()
+        }
     }
 
     #[tokio::test]
@@ -522,6 +588,10 @@ mod tests {
             FakeAccountRegistrationRepo { account_id };
         let webhook_registration_repo = FakeWebHookRegistrationRepo;
         let message_sending_repo = FakeLocalMessageWriteRepo;
+        let mut suppression_repo = MockEmailSuppressionFetching::new();
+        suppression_repo
+            .expect_get()
+            .returning(|_| Ok(FetchResponseKind::NotFound(None)));
         let tenant_fetching_repo = FakeTenantFetchingRepo;
 
         let mut audit_mock = MockResourceAuditLogRegistration::new();
@@ -546,6 +616,7 @@ mod tests {
             Box::new(&account_registration_repo),
             Box::new(&webhook_registration_repo),
             Box::new(&message_sending_repo),
+            Box::new(&suppression_repo),
             Box::new(&tenant_fetching_repo),
             Box::new(&audit_mock),
         )
@@ -595,6 +666,10 @@ mod tests {
             FakeAccountRegistrationRepo { account_id };
         let webhook_registration_repo = FakeWebHookRegistrationRepo;
         let message_sending_repo = FakeLocalMessageWriteRepo;
+        let mut suppression_repo = MockEmailSuppressionFetching::new();
+        suppression_repo
+            .expect_get()
+            .returning(|_| Ok(FetchResponseKind::NotFound(None)));
         let tenant_fetching_repo = FakeTenantFetchingRepo;
 
         let mut audit_mock = MockResourceAuditLogRegistration::new();
@@ -610,6 +685,7 @@ mod tests {
             Box::new(&account_registration_repo),
             Box::new(&webhook_registration_repo),
             Box::new(&message_sending_repo),
+            Box::new(&suppression_repo),
             Box::new(&tenant_fetching_repo),
             Box::new(&audit_mock),
         )
@@ -0,0 +1,347 @@
+use crate::{
+    domain::{
+        dtos::{
+            data_subject::{pseudonym_for, DataSubjectErasure},
+            email::Email,
+            profile::Profile,
+            resource_audit_log::{
+                NewResourceAuditLogEvent, ResourceAuditEventKind,
+                ResourceAuditLog, ResourceAuditRedaction,
+                ResourceAuditResourceType, RESOURCE_AUDIT_ERASURE_ACTION,
+            },
+            written_by::WrittenBy,
+        },
+        entities::{
+            EncryptionKeyFetching, ResourceAuditLogArchival,
+            ResourceAuditLogFetching, WebHookUpdating,
+        },
+    },
+    models::AccountLifeCycle,
+    use_cases::shared::audit::list_resource_audit_rows_about,
+};
+
+use chrono::Utc;
+use mycelium_base::utils::errors::MappedErrors;
+use serde_json::json;
+
+/// Erase the owners of the current account from audit and webhook references
+///
+/// Every mention of an owner email in the audit trail and in the stored
+/// webhook payload artifacts is replaced by a pseudonym derived from the
+/// email and keyed by the system DEK, so the references stay consistent with
+/// each other without naming the person. The erasure event records the
+/// redacted rows, so the chain verification tells them from tampered ones,
+/// and is written in the same transaction as the redaction itself. Chains on
+/// legal hold are left untouched. Records themselves are not deleted:
+/// `delete_my_account` does that, and should be called after the erasure.
+///
+#[tracing::instrument(
+    name = "erase_my_data",
+    fields(profile_id = %profile.acc_id),
+    skip_all
+)]
+pub async fn erase_my_data(
+    profile: Profile,
+    life_cycle_settings: AccountLifeCycle,
+    encryption_key_repo: Box<&dyn EncryptionKeyFetching>,
+    audit_fetching_repo: Box<&dyn ResourceAuditLogFetching>,
+    audit_archival_repo: Box<&dyn ResourceAuditLogArchival>,
+    webhook_updating_repo: Box<&dyn WebHookUpdating>,
+) -> Result<DataSubjectErasure, MappedErrors> {
+    let kek = life_cycle_settings.derive_kek_bytes().await?;
+    let pseudonym_key =
+        encryption_key_repo.get_or_provision_dek(None, &kek).await?;
+
+    let subjects = profile
+        .owners
+        .iter()
+        .map(|owner| {
+            let email = Email::from_string(owner.email.to_owned())?;
+            let pseudonym = pseudonym_for(&email, &pseudonym_key)?;
+
+            Ok((email.email(), pseudonym))
+        })
+        .collect::<Result<Vec<_>, MappedErrors>>()?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Pseudonymise the webhook payload artifacts
+    // ? -----------------------------------------------------------------------
+
+    let mut webhook_artifacts = 0;
+
+    for (email, pseudonym) in subjects.iter() {
+        webhook_artifacts += webhook_updating_repo
+            .pseudonymise_execution_events(
+                email.to_owned(),
+                pseudonym.to_owned(),
+            )
+            .await?;
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Pseudonymise the audit trail
+    // ? -----------------------------------------------------------------------
+
+    let ids = profile
+        .get_owners_ids()
+        .into_iter()
+        .chain([profile.acc_id])
+        .collect::<Vec<_>>();
+
+    let emails = subjects
+        .iter()
+        .map(|(email, _)| email.to_owned())
+        .collect::<Vec<_>>();
+
+    let redacted_rows =
+        list_resource_audit_rows_about(&ids, &emails, audit_fetching_repo)
+            .await?
+            .into_iter()
+            .filter_map(|row| {
+                subjects.iter().fold(
+                    None::<ResourceAuditLog>,
+                    |redacted, (email, pseudonym)| {
+                        redacted
+                            .as_ref()
+                            .unwrap_or(&row)
+                            .pseudonymise(email, pseudonym)
+                            .or(redacted)
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+
+    //
+    // Redacted rows no longer hash to their chain links, so the erasure event
+    // records what each of them hashes to now. The archival repository writes
+    // it along with the redacted rows, never one without the other.
+    //
+    let redactions = redacted_rows
+        .iter()
+        .filter_map(ResourceAuditRedaction::of)
+        .collect::<Vec<_>>();
+
+    let erasure = NewResourceAuditLogEvent {
+        resource_type: ResourceAuditResourceType::Account,
+        resource_id: profile.acc_id,
+        tenant_id: None,
+        event: ResourceAuditEventKind::Updated,
+        performed_by: WrittenBy::new_from_account(profile.acc_id),
+        metadata: json!({
+            "action": RESOURCE_AUDIT_ERASURE_ACTION,
+            "webhookArtifacts": webhook_artifacts,
+            "redactions": redactions,
+        }),
+        created_at: Utc::now(),
+    };
+
+    let audit_rows = audit_archival_repo
+        .redact_rows(redacted_rows, erasure)
+        .await?;
+
+    Ok(DataSubjectErasure {
+        audit_rows,
+        webhook_artifacts,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        dtos::{
+            profile::Owner,
+            resource_audit_log::{
+                ResourceAuditChainLink, RESOURCE_AUDIT_CHAIN_GENESIS_HASH,
+            },
+            webhook::{WebHook, WebHookPayloadArtifact},
+        },
+        entities::{
+            MockResourceAuditLogArchival, MockResourceAuditLogFetching,
+        },
+    };
+    use crate::models::{HmacSecretEntry, HmacSecretSet};
+
+    use async_trait::async_trait;
+    use myc_config::secret_resolver::SecretResolver;
+    use mycelium_base::entities::{
+        FetchManyResponseKind, UpdatingResponseKind,
+    };
+    use shaku::Component;
+    use uuid::Uuid;
+
+    #[derive(Component)]
+    #[shaku(interface = EncryptionKeyFetching)]
+    struct FakeEncryptionKeyFetchingRepo;
+
+    #[async_trait]
+    impl EncryptionKeyFetching for FakeEncryptionKeyFetchingRepo {
+        async fn get_or_provision_dek(
+            &self,
+            tenant_id: Option<Uuid>,
+            _: &[u8; 32],
+        ) -> Result<[u8; 32], MappedErrors> {
+            assert!(tenant_id.is_none());
+
+            Ok([7u8; 32])
+        }
+
+        async fn fetch_dek(
+            &self,
+            _: Option<Uuid>,
+            _: &[u8; 32],
+        ) -> Result<Option<[u8; 32]>, MappedErrors> {
+            unimplemented!()
+        }
+    }
+
+    struct FakeWebHookUpdatingRepo;
+
+    #[async_trait]
+    impl WebHookUpdating for FakeWebHookUpdatingRepo {
+        async fn update(
+            &self,
+            _: WebHook,
+        ) -> Result<UpdatingResponseKind<WebHook>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn update_execution_event(
+            &self,
+            _: WebHookPayloadArtifact,
+        ) -> Result<UpdatingResponseKind<WebHookPayloadArtifact>, MappedErrors>
+        {
+            unimplemented!()
+        }
+
+        async fn pseudonymise_execution_events(
+            &self,
+            subject: String,
+            pseudonym: String,
+        ) -> Result<u64, MappedErrors> {
+            let email = Email::from_string(subject.to_owned()).unwrap();
+
+            assert_eq!(subject, "jane@example.com");
+            assert_eq!(pseudonym, pseudonym_for(&email, &[7u8; 32]).unwrap());
+
+            Ok(2)
+        }
+    }
+
+    fn test_config() -> AccountLifeCycle {
+        AccountLifeCycle {
+            domain_name: SecretResolver::Value("example.com".to_string()),
+            domain_url: None,
+            locale: None,
+            token_expiration: SecretResolver::Value(3600),
+            invitation_expiration: SecretResolver::Value(604_800),
+            noreply_name: None,
+            noreply_email: SecretResolver::Value(
+                "noreply@example.com".to_string(),
+            ),
+            support_name: None,
+            support_email: SecretResolver::Value(
+                "support@example.com".to_string(),
+            ),
+            token_secret: SecretResolver::Value(Uuid::new_v4().to_string()),
+            hmac_primary_version: 1,
+            hmac_secrets: HmacSecretSet::new(vec![HmacSecretEntry {
+                version: 1,
+                secret: SecretResolver::Value("test-hmac".to_string()),
+            }]),
+            staff_bootstrap_secret: None,
+            sms_provider: None,
+            delivery_webhook_secret: None,
+        }
+    }
+
+    fn row(metadata: serde_json::Value) -> ResourceAuditLog {
+        let mut row = ResourceAuditLog {
+            id: Uuid::new_v4(),
+            resource_type: ResourceAuditResourceType::GuestUser,
+            resource_id: Uuid::new_v4(),
+            tenant_id: None,
+            event: ResourceAuditEventKind::Created,
+            performed_by: WrittenBy::new_anemic(),
+            metadata,
+            created_at: Utc::now(),
+            chain: Some(ResourceAuditChainLink {
+                seq: 1,
+                prev_hash: RESOURCE_AUDIT_CHAIN_GENESIS_HASH.to_string(),
+                hash: String::new(),
+            }),
+            redacted_at: None,
+        };
+
+        row.chain = row.recompute_chain_link();
+        row
+    }
+
+    #[tokio::test]
+    async fn erase_my_data_pseudonymises_only_rows_naming_the_owner() {
+        let mut profile = Profile::default();
+        profile.owners = vec![Owner {
+            id: Uuid::new_v4(),
+            email: "jane@example.com".to_string(),
+            first_name: None,
+            last_name: None,
+            username: None,
+            is_principal: true,
+        }];
+
+        let naming = row(json!({ "email": "Jane@example.com" }));
+        let naming_id = naming.id;
+        let naming_hash = naming.chain.to_owned().unwrap().hash;
+        let anonymous = row(json!({ "action": "create" }));
+
+        let mut fetching_mock = MockResourceAuditLogFetching::new();
+        fetching_mock
+            .expect_list_filtered()
+            .returning(move |_, _, _| {
+                Ok(FetchManyResponseKind::Found(vec![
+                    naming.to_owned(),
+                    anonymous.to_owned(),
+                ]))
+            });
+
+        let mut archival_mock = MockResourceAuditLogArchival::new();
+        archival_mock
+            .expect_redact_rows()
+            .times(1)
+            .withf(move |rows, erasure| {
+                let redactions: Vec<ResourceAuditRedaction> =
+                    serde_json::from_value(
+                        erasure.metadata["redactions"].to_owned(),
+                    )
+                    .unwrap();
+
+                rows.len() == 1
+                    && rows[0].id == naming_id
+                    && rows[0].is_redacted()
+                    && !rows[0].metadata.to_string().contains("example.com")
+                    && erasure.tenant_id.is_none()
+                    && erasure.event == ResourceAuditEventKind::Updated
+                    && erasure.metadata["webhookArtifacts"] == 2
+                    && !erasure.metadata.to_string().contains("example.com")
+                    && redactions.len() == 1
+                    && redactions[0].row_id == naming_id
+                    && redactions[0].hash == naming_hash
+                    && redactions[0].redacted_hash != naming_hash
+            })
+            .returning(|rows, _| Ok(rows.len() as u64));
+
+        let erasure = erase_my_data(
+            profile,
+            test_config(),
+            Box::new(&FakeEncryptionKeyFetchingRepo),
+            Box::new(&fetching_mock),
+            Box::new(&archival_mock),
+            Box::new(&FakeWebHookUpdatingRepo),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(erasure.audit_rows, 1);
+        assert_eq!(erasure.webhook_artifacts, 2);
+    }
+}
@@ -0,0 +1,173 @@
+use crate::{
+    domain::{
+        dtos::{
+            data_subject::DataSubjectExport,
+            email::Email,
+            profile::Profile,
+            related_accounts::RelatedAccounts,
+            resource_audit_log::{
+                ResourceAuditEventKind, ResourceAuditLogCursor,
+                ResourceAuditResourceType,
+            },
+            written_by::WrittenBy,
+        },
+        entities::{
+            AccountFetching, LicensedResourcesFetching,
+            ResourceAuditLogFetching, ResourceAuditLogRegistration,
+            TokenFetching, UserFetching,
+        },
+    },
+    use_cases::shared::audit::{
+        emit_resource_audit_event, list_resource_audit_page_about,
+        RESOURCE_AUDIT_DEFAULT_PAGE_SIZE, RESOURCE_AUDIT_MAX_PAGE_SIZE,
+    },
+};
+
+use chrono::Utc;
+use mycelium_base::{
+    entities::{FetchManyResponseKind, FetchResponseKind},
+    utils::errors::MappedErrors,
+};
+use serde_json::json;
+
+/// Export everything Mycelium holds about the owners of the current account
+///
+/// The export covers the users (with their TOTP state, secrets redacted), the
+/// personal account and its meta, the guest memberships of the users, the
+/// metadata of the account connection strings and the audit entries about
+/// them. The audit entries come one page at a time, newest first: follow
+/// `audit_next_cursor` to get the next one. The export itself is recorded in
+/// the audit trail when its first page is requested.
+///
+#[tracing::instrument(
+    name = "export_my_data",
+    fields(profile_id = %profile.acc_id),
+    skip_all
+)]
+pub async fn export_my_data(
+    profile: Profile,
+    audit_cursor: Option<ResourceAuditLogCursor>,
+    audit_page_size: Option<i64>,
+    user_fetching_repo: Box<&dyn UserFetching>,
+    account_fetching_repo: Box<&dyn AccountFetching>,
+    licensed_resources_fetching_repo: Box<&dyn LicensedResourcesFetching>,
+    token_fetching_repo: Box<&dyn TokenFetching>,
+    audit_fetching_repo: Box<&dyn ResourceAuditLogFetching>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<DataSubjectExport, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Collect the users and their guest memberships
+    // ? -----------------------------------------------------------------------
+
+    let mut users = vec![];
+    let mut guest_memberships = vec![];
+
+    for owner in profile.owners.iter() {
+        if let FetchResponseKind::Found(user) =
+            user_fetching_repo.get_user_by_id(owner.id).await?
+        {
+            users.push(user);
+        }
+
+        match licensed_resources_fetching_repo
+            .list_licensed_resources(
+                Email::from_string(owner.email.to_owned())?,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await?
+        {
+            FetchManyResponseKind::NotFound => (),
+            FetchManyResponseKind::Found(records) => {
+                guest_memberships.extend(records)
+            }
+            FetchManyResponseKind::FoundPaginated { records, .. } => {
+                guest_memberships.extend(records)
+            }
+        }
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Collect the account and its connection strings
+    // ? -----------------------------------------------------------------------
+
+    let account = match account_fetching_repo
+        .get(
+            profile.acc_id,
+            RelatedAccounts::AllowedAccounts(vec![profile.acc_id]),
+        )
+        .await?
+    {
+        FetchResponseKind::Found(account) => Some(account),
+        FetchResponseKind::NotFound(_) => None,
+    };
+
+    let connection_strings = match token_fetching_repo
+        .list_connection_strings_by_account_id(profile.acc_id)
+        .await?
+    {
+        FetchManyResponseKind::NotFound => vec![],
+        FetchManyResponseKind::Found(records) => records,
+        FetchManyResponseKind::FoundPaginated { records, .. } => records,
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Collect the audit entries
+    // ? -----------------------------------------------------------------------
+
+    let ids = profile
+        .get_owners_ids()
+        .into_iter()
+        .chain([profile.acc_id])
+        .collect::<Vec<_>>();
+
+    let emails = profile
+        .owners
+        .iter()
+        .map(|owner| owner.email.to_owned())
+        .collect::<Vec<_>>();
+
+    let audit_page_size = audit_page_size
+        .unwrap_or(RESOURCE_AUDIT_DEFAULT_PAGE_SIZE)
+        .clamp(1, RESOURCE_AUDIT_MAX_PAGE_SIZE);
+
+    let is_first_page = audit_cursor.is_none();
+
+    let audit_page = list_resource_audit_page_about(
+        &ids,
+        &emails,
+        audit_cursor,
+        audit_page_size,
+        audit_fetching_repo,
+    )
+    .await?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Register the export
+    // ? -----------------------------------------------------------------------
+
+    if is_first_page {
+        emit_resource_audit_event(
+            audit_repo,
+            ResourceAuditResourceType::Account,
+            profile.acc_id,
+            None,
+            ResourceAuditEventKind::Accessed,
+            WrittenBy::new_from_account(profile.acc_id),
+            json!({ "action": "export_my_data" }),
+        )
+        .await;
+    }
+
+    Ok(DataSubjectExport {
+        generated_at: Utc::now(),
+        users,
+        account,
+        guest_memberships,
+        connection_strings,
+        audit_entries: audit_page.records,
+        audit_next_cursor: audit_page.next_cursor,
+    })
+}
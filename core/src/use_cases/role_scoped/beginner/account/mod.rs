@@ -1,9 +1,13 @@
 mod create_account_from_existing_user;
 mod delete_my_account;
+mod erase_my_data;
+mod export_my_data;
 mod get_my_account_details;
 mod update_own_account_name;
 
 pub use create_account_from_existing_user::*;
 pub use delete_my_account::*;
+pub use erase_my_data::*;
+pub use export_my_data::*;
 pub use get_my_account_details::*;
 pub use update_own_account_name::*;
@@ -1,9 +1,24 @@
 use crate::domain::{
     dtos::{
-        guest_role::Permission, native_error_codes::NativeErrorCodes,
+        email::Email,
+        guest_role::Permission,
+        guest_user::GuestInvitationEvent,
+        native_error_codes::NativeErrorCodes,
         profile::Profile,
+        resource_audit_log::{
+            ResourceAuditEventKind, ResourceAuditResourceType,
+        },
+        webhook::{PayloadId, WebHookTrigger},
+        written_by::WrittenBy,
     },
-    entities::GuestUserOnAccountUpdating,
+    entities::{
+        GuestUserOnAccountUpdating, ResourceAuditLogRegistration,
+        WebHookRegistration,
+    },
+};
+use crate::use_cases::{
+    shared::audit::emit_resource_audit_event,
+    support::register_webhook_dispatching_event,
 };
 
 use mycelium_base::{
@@ -15,7 +30,7 @@ use uuid::Uuid;
 /// Accept invitation to join an account
 ///
 /// After a guest user has been invited to join an account, they can accept the
-/// invitation to join the account.
+/// invitation to join the account. Expired invitations are not accepted.
 ///
 #[tracing::instrument(name = "accept_invitation", skip_all)]
 pub async fn accept_invitation(
@@ -24,6 +39,8 @@ pub async fn accept_invitation(
     role_name: String,
     permission: Permission,
     guest_user_on_account_repo: Box<&dyn GuestUserOnAccountUpdating>,
+    webhook_registration_repo: Box<&dyn WebHookRegistration>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
 ) -> Result<UpdatingResponseKind<(String, Uuid, Permission)>, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Check if the profile licenses has the guest_user_id
@@ -72,7 +89,60 @@ pub async fn accept_invitation(
     // ? Accept invitation
     // ? -----------------------------------------------------------------------
 
-    guest_user_on_account_repo
+    let tenant_id = target_license.tenant_id;
+    let guest_role_id = target_license.role_id;
+
+    let owner = match profile
+        .owners
+        .iter()
+        .find(|owner| owner.is_principal)
+        .or(profile.owners.first())
+    {
+        None => {
+            return use_case_err("Profile does not have an owner")
+                .with_exp_true()
+                .as_error()
+        }
+        Some(owner) => owner,
+    };
+
+    let email = Email::from_string(owner.email.to_owned())?;
+
+    let response = guest_user_on_account_repo
         .accept_invitation(role_name, account_id, permission)
-        .await
+        .await?;
+
+    if let UpdatingResponseKind::Updated(_) = response {
+        emit_resource_audit_event(
+            audit_repo,
+            ResourceAuditResourceType::GuestUser,
+            account_id,
+            Some(tenant_id),
+            ResourceAuditEventKind::Updated,
+            WrittenBy::new_from_account(profile.acc_id),
+            serde_json::json!({
+                "action": "accept_invitation",
+                "guestRoleId": guest_role_id,
+            }),
+        )
+        .await;
+
+        register_webhook_dispatching_event(
+            Uuid::new_v4(),
+            WebHookTrigger::GuestInvitationAccepted,
+            GuestInvitationEvent {
+                guest_user_id: None,
+                email,
+                account_id,
+                tenant_id: Some(tenant_id),
+                guest_role_id,
+                expires_at: None,
+            },
+            PayloadId::Uuid(account_id),
+            webhook_registration_repo,
+        )
+        .await?;
+    }
+
+    Ok(response)
 }
@@ -1,7 +1,9 @@
 mod create_account_meta;
 mod delete_account_meta;
 mod update_account_meta;
+mod update_notification_preferences;
 
 pub use create_account_meta::*;
 pub use delete_account_meta::*;
 pub use update_account_meta::*;
+pub use update_notification_preferences::*;
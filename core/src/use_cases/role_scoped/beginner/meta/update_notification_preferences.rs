@@ -0,0 +1,236 @@
+use crate::{
+    domain::{
+        dtos::{
+            account::{AccountMeta, AccountMetaKey},
+            notification::NotificationPreferences,
+            profile::Profile,
+            resource_audit_log::{
+                ResourceAuditEventKind, ResourceAuditResourceType,
+            },
+            written_by::WrittenBy,
+        },
+        entities::{AccountUpdating, ResourceAuditLogRegistration},
+    },
+    use_cases::{
+        shared::audit::emit_resource_audit_event,
+        support::check_public_webhook_url,
+    },
+};
+
+use mycelium_base::{
+    entities::UpdatingResponseKind,
+    utils::errors::{use_case_err, MappedErrors},
+};
+
+/// Set the channels the profile owner receives notifications through
+///
+/// The webhook URL, when informed, is the target of the `webhook` channel. It
+/// should use HTTPS and resolve to public addresses only (see
+/// `check_public_webhook_url`).
+#[tracing::instrument(
+    name = "update_notification_preferences",
+    fields(profile_id = %profile.acc_id),
+    skip(preferences, webhook_url, account_updating_repo, audit_repo)
+)]
+pub async fn update_notification_preferences(
+    profile: Profile,
+    preferences: NotificationPreferences,
+    webhook_url: Option<String>,
+    account_updating_repo: Box<&dyn AccountUpdating>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<UpdatingResponseKind<AccountMeta>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Validate the preferences
+    // ? -----------------------------------------------------------------------
+
+    preferences.validate()?;
+
+    if let Some(url) = &webhook_url {
+        check_public_webhook_url(url).await?;
+    }
+
+    let serialized = match serde_json::to_string(&preferences) {
+        Ok(serialized) => serialized,
+        Err(err) => {
+            return use_case_err(format!(
+                "Unable to serialize the notification preferences: {err}"
+            ))
+            .as_error()
+        }
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Store the preferences in the account meta
+    // ? -----------------------------------------------------------------------
+
+    let mut response = account_updating_repo
+        .update_account_meta(
+            profile.acc_id,
+            AccountMetaKey::NotificationPreferences,
+            serialized,
+        )
+        .await?;
+
+    if let (UpdatingResponseKind::Updated(_), Some(url)) =
+        (&response, webhook_url.to_owned())
+    {
+        response = account_updating_repo
+            .update_account_meta(
+                profile.acc_id,
+                AccountMetaKey::NotificationWebhookUrl,
+                url,
+            )
+            .await?;
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Emit the audit event
+    // ? -----------------------------------------------------------------------
+
+    if let UpdatingResponseKind::Updated(_) = &response {
+        emit_resource_audit_event(
+            audit_repo,
+            ResourceAuditResourceType::AccountMeta,
+            profile.acc_id,
+            None,
+            ResourceAuditEventKind::Updated,
+            WrittenBy::new_from_account(profile.acc_id),
+            serde_json::json!({
+                "action": "update_notification_preferences",
+                "preferences": preferences,
+                "webhook_url_updated": webhook_url.is_some(),
+            }),
+        )
+        .await;
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        dtos::{
+            account::Account,
+            account_type::AccountType,
+            notification::{NotificationChannel, NotificationKind},
+        },
+        entities::MockResourceAuditLogRegistration,
+    };
+
+    use async_trait::async_trait;
+    use std::{collections::HashMap, sync::Mutex};
+    use uuid::Uuid;
+
+    #[derive(Default)]
+    struct MockAccountUpdating {
+        updated_keys: Mutex<Vec<AccountMetaKey>>,
+    }
+
+    #[async_trait]
+    impl AccountUpdating for MockAccountUpdating {
+        async fn update(
+            &self,
+            _: Account,
+        ) -> Result<UpdatingResponseKind<Account>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn update_own_account_name(
+            &self,
+            _: Uuid,
+            _: String,
+        ) -> Result<UpdatingResponseKind<Account>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn update_account_type(
+            &self,
+            _: Uuid,
+            _: AccountType,
+        ) -> Result<UpdatingResponseKind<Account>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn update_account_meta(
+            &self,
+            _: Uuid,
+            key: AccountMetaKey,
+            _: String,
+        ) -> Result<UpdatingResponseKind<AccountMeta>, MappedErrors> {
+            self.updated_keys.lock().unwrap().push(key);
+
+            Ok(UpdatingResponseKind::Updated(HashMap::new()))
+        }
+    }
+
+    fn preferences() -> NotificationPreferences {
+        NotificationPreferences {
+            channels: HashMap::from([(
+                NotificationKind::MfaAlert,
+                vec![NotificationChannel::Webhook, NotificationChannel::Email],
+            )]),
+        }
+    }
+
+    #[tokio::test]
+    async fn update_notification_preferences_stores_meta_and_audits() {
+        let updating = MockAccountUpdating::default();
+
+        let mut audit_mock = MockResourceAuditLogRegistration::new();
+        audit_mock
+            .expect_create()
+            .times(1)
+            .withf(|event| {
+                event.resource_type == ResourceAuditResourceType::AccountMeta
+                    && event.event == ResourceAuditEventKind::Updated
+            })
+            .returning(|_| Ok(()));
+
+        let result = update_notification_preferences(
+            Profile::default(),
+            preferences(),
+            Some("https://93.184.216.34/notify".to_string()),
+            Box::new(&updating as &dyn AccountUpdating),
+            Box::new(&audit_mock),
+        )
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(
+            *updating.updated_keys.lock().unwrap(),
+            vec![
+                AccountMetaKey::NotificationPreferences,
+                AccountMetaKey::NotificationWebhookUrl
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn update_notification_preferences_rejects_internal_webhooks() {
+        for url in [
+            "http://93.184.216.34/notify",
+            "https://10.0.0.1/notify",
+            "https://169.254.169.254/latest/meta-data",
+            "https://[::1]/notify",
+        ] {
+            let updating = MockAccountUpdating::default();
+
+            let mut audit_mock = MockResourceAuditLogRegistration::new();
+            audit_mock.expect_create().times(0);
+
+            let result = update_notification_preferences(
+                Profile::default(),
+                preferences(),
+                Some(url.to_string()),
+                Box::new(&updating as &dyn AccountUpdating),
+                Box::new(&audit_mock),
+            )
+            .await;
+
+            assert!(result.is_err(), "{url} should be rejected");
+            assert!(updating.updated_keys.lock().unwrap().is_empty());
+        }
+    }
+}
@@ -4,14 +4,24 @@ use crate::{
             email::Email,
             native_error_codes::NativeErrorCodes,
             profile::Profile,
+            resource_audit_log::{
+                ResourceAuditEventKind, ResourceAuditResourceType,
+            },
             security_group::PermissionedRole,
             token::{UserAccountConnectionString, UserAccountScope},
+            written_by::WrittenBy,
+        },
+        entities::{
+            EmailSuppressionFetching, LocalMessageWrite,
+            ResourceAuditLogRegistration, TenantFetching, TokenRegistration,
         },
-        entities::{LocalMessageWrite, TenantFetching, TokenRegistration},
     },
     models::AccountLifeCycle,
     settings::DEFAULT_TENANT_ID_KEY,
-    use_cases::support::dispatch_notification,
+    use_cases::{
+        shared::audit::emit_resource_audit_event,
+        support::dispatch_notification,
+    },
 };
 
 use chrono::{Duration, Local};
@@ -43,7 +53,9 @@ pub async fn create_connection_string(
     life_cycle_settings: AccountLifeCycle,
     token_registration_repo: Box<&dyn TokenRegistration>,
     message_sending_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
     tenant_fetching_repo: Box<&dyn TenantFetching>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
 ) -> Result<String, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Build the scoped account token
@@ -79,7 +91,7 @@ pub async fn create_connection_string(
             profile.acc_id,
             Email::from_string(owner.email.to_owned())?,
             life_cycle_settings.to_owned(),
-            Some(name),
+            Some(name.to_owned()),
         )
         .await?;
 
@@ -87,17 +99,37 @@ pub async fn create_connection_string(
     // ? Register the token
     // ? -----------------------------------------------------------------------
 
-    if let CreateResponseKind::NotCreated(_, msg) = token_registration_repo
+    let token = match token_registration_repo
         .create_connection_string(
             role_scoped_connection_string.to_owned(),
             expires_at,
         )
         .await?
     {
-        tracing::error!("Unable to register connection string: {msg}");
-        return use_case_err("Unable to register token").as_error();
+        CreateResponseKind::Created(token) => token,
+        CreateResponseKind::NotCreated(_, msg) => {
+            tracing::error!("Unable to register connection string: {msg}");
+            return use_case_err("Unable to register token").as_error();
+        }
     };
 
+    emit_resource_audit_event(
+        audit_repo,
+        ResourceAuditResourceType::ConnectionString,
+        profile.acc_id,
+        tenant_id,
+        ResourceAuditEventKind::Created,
+        WrittenBy::new_from_account(profile.acc_id),
+        serde_json::json!({
+            "action": "create_connection_string",
+            "tokenId": token.get_id(),
+            "name": name,
+            "expiresAt": expires_at,
+            "subscriptionAccountId": subscription_account_id,
+        }),
+    )
+    .await;
+
     // ? -----------------------------------------------------------------------
     // ? Notify guest user
     // ? -----------------------------------------------------------------------
@@ -118,6 +150,7 @@ pub async fn create_connection_string(
         Email::from_string(owner.email.to_owned())?,
         None,
         message_sending_repo,
+        email_suppression_fetching_repo,
         tenant_fetching_repo,
     )
     .await
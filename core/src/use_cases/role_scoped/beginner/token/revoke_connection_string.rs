@@ -1,4 +1,14 @@
-use crate::domain::{dtos::profile::Profile, entities::TokenDeletion};
+use crate::domain::{
+    dtos::{
+        profile::Profile,
+        resource_audit_log::{
+            ResourceAuditEventKind, ResourceAuditResourceType,
+        },
+        written_by::WrittenBy,
+    },
+    entities::{ResourceAuditLogRegistration, TokenDeletion},
+};
+use crate::use_cases::shared::audit::emit_resource_audit_event;
 
 use mycelium_base::{
     entities::DeletionResponseKind, utils::errors::MappedErrors,
@@ -18,8 +28,27 @@ pub async fn revoke_connection_string(
     profile: Profile,
     token_id: u32,
     token_deletion_repo: Box<&dyn TokenDeletion>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
 ) -> Result<DeletionResponseKind<u32>, MappedErrors> {
-    token_deletion_repo
+    let response = token_deletion_repo
         .revoke_connection_string(profile.acc_id, token_id)
-        .await
+        .await?;
+
+    if let DeletionResponseKind::Deleted = response {
+        emit_resource_audit_event(
+            audit_repo,
+            ResourceAuditResourceType::ConnectionString,
+            profile.acc_id,
+            None,
+            ResourceAuditEventKind::Updated,
+            WrittenBy::new_from_account(profile.acc_id),
+            serde_json::json!({
+                "action": "revoke_connection_string",
+                "tokenId": token_id,
+            }),
+        )
+        .await;
+    }
+
+    Ok(response)
 }
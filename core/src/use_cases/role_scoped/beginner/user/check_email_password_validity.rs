@@ -1,10 +1,15 @@
 use crate::domain::{
     dtos::{
         email::Email,
+        resource_audit_log::{
+            ResourceAuditEventKind, ResourceAuditResourceType,
+        },
         user::{Provider, User},
+        written_by::WrittenBy,
     },
-    entities::UserFetching,
+    entities::{ResourceAuditLogRegistration, UserFetching},
 };
+use crate::use_cases::shared::audit::emit_resource_audit_event;
 
 use mycelium_base::{entities::FetchResponseKind, utils::errors::MappedErrors};
 
@@ -13,13 +18,14 @@ pub async fn check_email_password_validity(
     email: Email,
     password: String,
     user_fetching_repo: Box<&dyn UserFetching>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
 ) -> Result<(bool, Option<User>), MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Fetch user
     // ? -----------------------------------------------------------------------
 
     let user = match user_fetching_repo
-        .get_not_redacted_user_by_email(email)
+        .get_not_redacted_user_by_email(email.to_owned())
         .await
     {
         Ok(FetchResponseKind::Found(user)) => user,
@@ -27,32 +33,45 @@ pub async fn check_email_password_validity(
     };
 
     // ? -----------------------------------------------------------------------
-    // ? Check if user is active
-    // ? -----------------------------------------------------------------------
-
-    let user = match user.is_active {
-        true => user,
-        false => return Ok((false, None)),
-    };
-
-    // ? -----------------------------------------------------------------------
-    // ? Get credentials
+    // ? Check user status, credentials and password validity
+    //
+    // Attempts against an existing user are audited whatever the outcome.
+    // Attempts against unknown emails are not: there is no resource to
+    // attach them to.
     // ? -----------------------------------------------------------------------
 
-    let credentials = match user.provider() {
-        None => return Ok((false, None)),
-        Some(provider) => match provider {
-            Provider::External(_) => return Ok((false, None)),
-            Provider::Internal(credentials) => credentials,
-        },
+    let failure = match (user.is_active, user.provider()) {
+        (false, _) => Some("inactive_user"),
+        (true, None) | (true, Some(Provider::External(_))) => {
+            Some("no_internal_credentials")
+        }
+        (true, Some(Provider::Internal(credentials))) => {
+            match credentials.check_password(password.as_bytes()) {
+                Err(_) => Some("invalid_password"),
+                Ok(_) => None,
+            }
+        }
     };
 
-    // ? -----------------------------------------------------------------------
-    // ? Check password validity
-    // ? -----------------------------------------------------------------------
+    if let Some(user_id) = user.id {
+        emit_resource_audit_event(
+            audit_repo,
+            ResourceAuditResourceType::User,
+            user_id,
+            None,
+            ResourceAuditEventKind::Accessed,
+            WrittenBy::new_from_user_with_email(user_id, &email.email()),
+            serde_json::json!({
+                "action": "login",
+                "method": "password",
+                "outcome": failure.unwrap_or("success"),
+            }),
+        )
+        .await;
+    }
 
-    match credentials.check_password(password.as_bytes()) {
-        Err(_) => Ok((false, None)),
-        Ok(_) => Ok((true, Some(user))),
+    match failure {
+        None => Ok((true, Some(user))),
+        Some(_) => Ok((false, None)),
     }
 }
@@ -5,11 +5,12 @@ use crate::{
             token::PasswordChangeTokenMeta, user::PasswordHash,
         },
         entities::{
-            LocalMessageWrite, TenantFetching, TokenInvalidation, UserFetching,
-            UserUpdating,
+            EmailSuppressionFetching, LocalMessageWrite, TenantFetching,
+            TokenInvalidation, UserFetching, UserUpdating,
         },
     },
     models::AccountLifeCycle,
+    settings::DEFAULT_TENANT_ID_KEY,
     use_cases::support::dispatch_notification,
 };
 
@@ -17,17 +18,20 @@ use mycelium_base::{
     entities::{FetchResponseKind, UpdatingResponseKind},
     utils::errors::{use_case_err, MappedErrors},
 };
+use uuid::Uuid;
 
 #[tracing::instrument(name = "check_token_and_reset_password", skip_all)]
 pub async fn check_token_and_reset_password(
     token: String,
     email: Email,
     new_password: String,
+    tenant_id: Option<Uuid>,
     life_cycle_settings: AccountLifeCycle,
     user_fetching_repo: Box<&dyn UserFetching>,
     user_updating_repo: Box<&dyn UserUpdating>,
     token_invalidation_repo: Box<&dyn TokenInvalidation>,
     message_sending_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
     tenant_fetching_repo: Box<&dyn TenantFetching>,
 ) -> Result<(), MappedErrors> {
     // ? -----------------------------------------------------------------------
@@ -112,13 +116,20 @@ pub async fn check_token_and_reset_password(
     // ? Notify guest user
     // ? -----------------------------------------------------------------------
 
+    let mut parameters = vec![("verification_code", meta.get_token())];
+
+    if let Some(tenant_id) = tenant_id {
+        parameters.push((DEFAULT_TENANT_ID_KEY, tenant_id.to_string()));
+    }
+
     if let Err(err) = dispatch_notification(
-        vec![("verification_code", meta.get_token())],
+        parameters,
         "email/password-reset-confirmation",
         life_cycle_settings,
         email,
         None,
         message_sending_repo,
+        email_suppression_fetching_repo,
         tenant_fetching_repo,
     )
     .await
@@ -12,8 +12,9 @@ use crate::{
             written_by::WrittenBy,
         },
         entities::{
-            LocalMessageWrite, ResourceAuditLogRegistration, TenantFetching,
-            TokenRegistration, UserDeletion, UserRegistration,
+            EmailSuppressionFetching, LocalMessageWrite,
+            ResourceAuditLogRegistration, TenantFetching, TokenRegistration,
+            UserDeletion, UserRegistration,
         },
     },
     models::AccountLifeCycle,
@@ -49,6 +50,7 @@ pub async fn create_default_user(
     user_registration_repo: Box<&dyn UserRegistration>,
     token_registration_repo: Box<&dyn TokenRegistration>,
     message_sending_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
     user_deletion_repo: Box<&dyn UserDeletion>,
     tenant_fetching_repo: Box<&dyn TenantFetching>,
     audit_repo: Box<&dyn ResourceAuditLogRegistration>,
@@ -176,6 +178,7 @@ pub async fn create_default_user(
             life_cycle_settings,
             token_registration_repo,
             message_sending_repo,
+            email_suppression_fetching_repo,
             user_deletion_repo,
             tenant_fetching_repo,
             audit_repo,
@@ -196,7 +199,9 @@ pub async fn create_default_user(
 mod tests {
     use super::*;
     use crate::{
-        domain::entities::MockResourceAuditLogRegistration,
+        domain::entities::{
+            MockEmailSuppressionFetching, MockResourceAuditLogRegistration,
+        },
         models::{HmacSecretEntry, HmacSecretSet},
     };
 
@@ -376,6 +381,70 @@ mod tests {
         > {
             unimplemented!()
         }
+
+        async fn get_email_template(
+            &self,
+            _: Uuid,
+            _: crate::domain::dtos::tenant::EmailTemplateKind,
+            _: String,
+        ) -> Result<
+            mycelium_base::entities::FetchResponseKind<
+                crate::domain::dtos::tenant::TenantEmailTemplate,
+                String,
+            >,
+            MappedErrors,
+        > {
+            unimplemented!()
+        }
+
+        async fn list_email_templates(
+            &self,
+            _: Uuid,
+        ) -> Result<
+            mycelium_base::entities::FetchManyResponseKind<
+                crate::domain::dtos::tenant::TenantEmailTemplate,
+            >,
+            MappedErrors,
+        > {
+            unimplemented!()
+        }
+
+        async fn list_descendants(
+            &self,
+            _: Uuid,
+        ) -> Result<
+            mycelium_base::entities::FetchManyResponseKind<
+                crate::domain::dtos::tenant::Tenant,
+            >,
+            MappedErrors,
+        > {
+            unimplemented!()
+        }
+
+        async fn list_trashed(
+            &self,
+            _: Option<chrono::DateTime<chrono::Utc>>,
+            _: chrono::DateTime<chrono::Utc>,
+        ) -> Result<
+            mycelium_base::entities::FetchManyResponseKind<
+                crate::domain::dtos::tenant::Tenant,
+            >,
+            MappedErrors,
+        > {
+            unimplemented!()
+        }
+
+        async fn list_owners(
+            &self,
+            _: Uuid,
+        ) -> Result<
+            mycelium_base::entities::FetchManyResponseKind<
+                crate::domain::dtos::profile::Owner,
+            >,
+            MappedErrors,
+        > {
+            unimplemented!()
+        }
     }
 
     // ? -----------------------------------------------------------------------
@@ -388,6 +457,7 @@ mod tests {
             domain_url: None,
             locale: None,
             token_expiration: SecretResolver::Value(3600),
+            invitation_expiration: SecretResolver::Value(604_800),
             noreply_name: None,
             noreply_email: SecretResolver::Value(
                 "noreply@test.com".to_string(),
@@ -403,6 +473,8 @@ mod tests {
                 secret: SecretResolver::Value("test-hmac".to_string()),
             }]),
             staff_bootstrap_secret: None,
+            sms_provider: None,
+            delivery_webhook_secret: None,
         }
     }
 
@@ -442,6 +514,7 @@ mod tests {
             Box::new(&user_registration_repo),
             Box::new(&token_registration_repo),
             Box::new(&message_sending_repo),
+            Box::new(&MockEmailSuppressionFetching::new()),
             Box::new(&user_deletion_repo),
             Box::new(&tenant_fetching_repo),
             Box::new(&audit_mock),
@@ -475,6 +548,7 @@ mod tests {
             Box::new(&user_registration_repo),
             Box::new(&token_registration_repo),
             Box::new(&message_sending_repo),
+            Box::new(&MockEmailSuppressionFetching::new()),
             Box::new(&user_deletion_repo),
             Box::new(&tenant_fetching_repo),
             Box::new(&audit_mock),
@@ -6,8 +6,9 @@ use crate::{
             token::{EmailConfirmationTokenMeta, MultiTypeMeta},
         },
         entities::{
-            LocalMessageWrite, ResourceAuditLogRegistration, TenantFetching,
-            TokenRegistration, UserDeletion,
+            EmailSuppressionFetching, LocalMessageWrite,
+            ResourceAuditLogRegistration, TenantFetching, TokenRegistration,
+            UserDeletion,
         },
     },
     models::AccountLifeCycle,
@@ -31,6 +32,7 @@ pub(super) async fn register_token_and_notify_user(
     life_cycle_settings: AccountLifeCycle,
     token_registration_repo: Box<&dyn TokenRegistration>,
     message_sending_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
     user_deletion_repo: Box<&dyn UserDeletion>,
     tenant_fetching_repo: Box<&dyn TenantFetching>,
     audit_repo: Box<&dyn ResourceAuditLogRegistration>,
@@ -131,6 +133,7 @@ pub(super) async fn register_token_and_notify_user(
         token_metadata.email,
         None,
         message_sending_repo,
+        email_suppression_fetching_repo,
         tenant_fetching_repo,
     )
     .await
@@ -5,10 +5,14 @@ use crate::{
             native_error_codes::NativeErrorCodes,
             token::{MagicLinkTokenMeta, MultiTypeMeta},
         },
-        entities::{LocalMessageWrite, TenantFetching, TokenRegistration},
+        entities::{
+            EmailSuppressionFetching, EncryptionKeyFetching, LocalMessageWrite,
+            NotificationRecipientFetching, TenantFetching, TokenRegistration,
+        },
     },
     models::AccountLifeCycle,
-    use_cases::support::dispatch_notification,
+    settings::DEFAULT_TENANT_ID_KEY,
+    use_cases::support::dispatch_channel_notification,
 };
 
 use chrono::Local;
@@ -16,6 +20,7 @@ use mycelium_base::{
     entities::CreateResponseKind,
     utils::errors::{use_case_err, MappedErrors},
 };
+use uuid::Uuid;
 
 #[tracing::instrument(name = "request_magic_link", skip_all)]
 pub async fn request_magic_link(
@@ -24,10 +29,18 @@ pub async fn request_magic_link(
     // the caller (port layer) — e.g.
     // "https://example.com/_adm/beginners/users/magic-link/display".
     display_base_url: String,
+    // Tenant on behalf of which the link is requested. When informed, the
+    // tenant branding and email template overrides are used.
+    tenant_id: Option<Uuid>,
     life_cycle_settings: AccountLifeCycle,
     token_registration_repo: Box<&dyn TokenRegistration>,
     message_sending_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
     tenant_fetching_repo: Box<&dyn TenantFetching>,
+    notification_recipient_fetching_repo: Box<
+        &dyn NotificationRecipientFetching,
+    >,
+    encryption_key_fetching_repo: Box<&dyn EncryptionKeyFetching>,
 ) -> Result<(), MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Generate magic link token meta (UUID + 6-digit code)
@@ -93,17 +106,26 @@ pub async fn request_magic_link(
     );
 
     // ? -----------------------------------------------------------------------
-    // ? Dispatch magic link email
+    // ? Dispatch the magic link through the preferred channels
     // ? -----------------------------------------------------------------------
 
-    if let Err(err) = dispatch_notification(
-        vec![("magic_link_url", display_url)],
+    let mut parameters = vec![("magic_link_url", display_url)];
+
+    if let Some(tenant_id) = tenant_id {
+        parameters.push((DEFAULT_TENANT_ID_KEY, tenant_id.to_string()));
+    }
+
+    if let Err(err) = dispatch_channel_notification(
+        parameters,
         "email/magic-link-request",
         life_cycle_settings,
         email,
         None,
         message_sending_repo,
+        email_suppression_fetching_repo,
         tenant_fetching_repo,
+        notification_recipient_fetching_repo,
+        encryption_key_fetching_repo,
     )
     .await
     {
@@ -6,10 +6,12 @@ use crate::{
             token::{MultiTypeMeta, PasswordChangeTokenMeta},
         },
         entities::{
-            LocalMessageWrite, TenantFetching, TokenRegistration, UserFetching,
+            EmailSuppressionFetching, LocalMessageWrite, TenantFetching,
+            TokenRegistration, UserFetching,
         },
     },
     models::AccountLifeCycle,
+    settings::DEFAULT_TENANT_ID_KEY,
     use_cases::support::dispatch_notification,
 };
 
@@ -18,14 +20,17 @@ use mycelium_base::{
     entities::{CreateResponseKind, FetchResponseKind},
     utils::errors::{use_case_err, MappedErrors},
 };
+use uuid::Uuid;
 
 #[tracing::instrument(name = "start_password_redefinition", skip_all)]
 pub async fn start_password_redefinition(
     email: Email,
+    tenant_id: Option<Uuid>,
     life_cycle_settings: AccountLifeCycle,
     user_fetching_repo: Box<&dyn UserFetching>,
     token_registration_repo: Box<&dyn TokenRegistration>,
     message_sending_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
     tenant_fetching_repo: Box<&dyn TenantFetching>,
 ) -> Result<(), MappedErrors> {
     // ? -----------------------------------------------------------------------
@@ -102,13 +107,20 @@ pub async fn start_password_redefinition(
     // ? Notify user owner
     // ? -----------------------------------------------------------------------
 
+    let mut parameters = vec![("verification_code", meta.get_token())];
+
+    if let Some(tenant_id) = tenant_id {
+        parameters.push((DEFAULT_TENANT_ID_KEY, tenant_id.to_string()));
+    }
+
     if let Err(err) = dispatch_notification(
-        vec![("verification_code", meta.get_token())],
+        parameters,
         "email/password-reset-initiated",
         life_cycle_settings,
         token_metadata.email,
         None,
         message_sending_repo,
+        email_suppression_fetching_repo,
         tenant_fetching_repo,
     )
     .await
@@ -3,13 +3,20 @@ use crate::{
         dtos::{
             email::Email,
             native_error_codes::NativeErrorCodes,
+            resource_audit_log::{
+                ResourceAuditEventKind, ResourceAuditResourceType,
+            },
             user::{Totp, User},
+            written_by::WrittenBy,
+        },
+        entities::{
+            EncryptionKeyFetching, ResourceAuditLogRegistration, UserFetching,
         },
-        entities::{EncryptionKeyFetching, UserFetching},
         utils::{build_aad, AAD_FIELD_TOTP_SECRET},
     },
     models::AccountLifeCycle,
     settings::DEFAULT_TOTP_DOMAIN,
+    use_cases::shared::audit::emit_resource_audit_event,
 };
 
 use mycelium_base::{
@@ -27,6 +34,7 @@ pub async fn totp_check_token(
     life_cycle_settings: AccountLifeCycle,
     user_fetching_repo: Box<&dyn UserFetching>,
     encryption_key_fetching_repo: Box<&dyn EncryptionKeyFetching>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
 ) -> Result<User, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Fetch user from email
@@ -128,6 +136,23 @@ pub async fn totp_check_token(
         }
     };
 
+    if let Some(user_id) = user.id {
+        emit_resource_audit_event(
+            audit_repo,
+            ResourceAuditResourceType::User,
+            user_id,
+            None,
+            ResourceAuditEventKind::Accessed,
+            WrittenBy::new_from_user_with_email(user_id, &account_email),
+            serde_json::json!({
+                "action": "login",
+                "method": "totp",
+                "outcome": if is_valid { "success" } else { "invalid_token" },
+            }),
+        )
+        .await;
+    }
+
     if !is_valid {
         return use_case_err(format!("Invalid TOTP token: {}", email.email()))
             .with_code(NativeErrorCodes::MYC00023)
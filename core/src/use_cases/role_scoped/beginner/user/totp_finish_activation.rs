@@ -6,14 +6,15 @@ use crate::{
             user::{MultiFactorAuthentication, Totp},
         },
         entities::{
-            EncryptionKeyFetching, LocalMessageWrite, TenantFetching,
-            UserFetching, UserUpdating,
+            EmailSuppressionFetching, EncryptionKeyFetching, LocalMessageWrite,
+            NotificationRecipientFetching, TenantFetching, UserFetching,
+            UserUpdating,
         },
         utils::{build_aad, AAD_FIELD_TOTP_SECRET},
     },
     models::AccountLifeCycle,
-    settings::DEFAULT_TOTP_DOMAIN,
-    use_cases::support::dispatch_notification,
+    settings::{DEFAULT_TENANT_ID_KEY, DEFAULT_TOTP_DOMAIN},
+    use_cases::support::dispatch_channel_notification,
 };
 
 use mycelium_base::{
@@ -32,7 +33,11 @@ pub async fn totp_finish_activation(
     user_fetching_repo: Box<&dyn UserFetching>,
     user_updating_repo: Box<&dyn UserUpdating>,
     message_sending_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
     tenant_fetching_repo: Box<&dyn TenantFetching>,
+    notification_recipient_fetching_repo: Box<
+        &dyn NotificationRecipientFetching,
+    >,
     encryption_key_fetching_repo: Box<&dyn EncryptionKeyFetching>,
 ) -> Result<(), MappedErrors> {
     // ? -----------------------------------------------------------------------
@@ -196,14 +201,21 @@ pub async fn totp_finish_activation(
     // ? Inform user about TOTP activation
     // ? -----------------------------------------------------------------------
 
-    if let Err(err) = dispatch_notification(
-        vec![],
+    let parameters = tenant_id
+        .map(|id| vec![(DEFAULT_TENANT_ID_KEY, id.to_string())])
+        .unwrap_or_default();
+
+    if let Err(err) = dispatch_channel_notification(
+        parameters,
         "email/mfa-activation-validated",
         life_cycle_settings.to_owned(),
         email.to_owned(),
         None,
         message_sending_repo,
+        email_suppression_fetching_repo,
         tenant_fetching_repo,
+        notification_recipient_fetching_repo,
+        encryption_key_fetching_repo,
     )
     .await
     {
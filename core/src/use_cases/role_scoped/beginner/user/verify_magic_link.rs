@@ -2,12 +2,18 @@ use crate::domain::{
     dtos::{
         email::Email,
         native_error_codes::NativeErrorCodes,
+        resource_audit_log::{
+            ResourceAuditEventKind, ResourceAuditResourceType,
+        },
         user::{PasswordHash, Provider, User},
+        written_by::WrittenBy,
     },
     entities::{
-        TokenInvalidation, UserFetching, UserRegistration, UserUpdating,
+        ResourceAuditLogRegistration, TokenInvalidation, UserFetching,
+        UserRegistration, UserUpdating,
     },
 };
+use crate::use_cases::shared::audit::emit_resource_audit_event;
 
 use chrono::Local;
 use mycelium_base::{
@@ -33,6 +39,7 @@ pub async fn verify_magic_link(
     user_registration_repo: Box<&dyn UserRegistration>,
     user_updating_repo: Box<&dyn UserUpdating>,
     token_invalidation_repo: Box<&dyn TokenInvalidation>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
 ) -> Result<User, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Consume the code (phase 2 — deletes record)
@@ -107,5 +114,22 @@ pub async fn verify_magic_link(
         }
     };
 
+    if let Some(user_id) = user.id {
+        emit_resource_audit_event(
+            audit_repo,
+            ResourceAuditResourceType::User,
+            user_id,
+            None,
+            ResourceAuditEventKind::Accessed,
+            WrittenBy::new_from_user_with_email(user_id, &email.email()),
+            serde_json::json!({
+                "action": "login",
+                "method": "magic_link",
+                "outcome": "success",
+            }),
+        )
+        .await;
+    }
+
     Ok(user)
 }
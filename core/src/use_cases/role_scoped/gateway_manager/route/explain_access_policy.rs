@@ -0,0 +1,233 @@
+use crate::domain::{
+    actors::SystemActor,
+    dtos::{
+        access_policy::{
+            AccessPolicy, AccessPolicyAttributes, AccessPolicyEvaluator,
+            AccessPolicyExplanation,
+        },
+        profile::Profile,
+        security_group::SecurityGroup,
+    },
+    entities::RoutesRead,
+};
+
+use http::uri::PathAndQuery;
+use mycelium_base::{
+    entities::FetchResponseKind,
+    utils::errors::{use_case_err, MappedErrors},
+};
+
+/// Explain the access policy decision of a request
+///
+/// This function is restricted to the GatewayManager users. The policy is
+/// evaluated as a dry-run against the given attributes. When no policy is
+/// given, the policy of the route matching the path is used.
+///
+#[tracing::instrument(
+    name = "explain_access_policy",
+    fields(profile_id = %profile.acc_id),
+    skip(profile, policy, attributes, routes_fetching_repo, evaluator)
+)]
+pub async fn explain_access_policy(
+    profile: Profile,
+    path: PathAndQuery,
+    policy: Option<AccessPolicy>,
+    attributes: AccessPolicyAttributes,
+    routes_fetching_repo: Box<&dyn RoutesRead>,
+    evaluator: Box<&dyn AccessPolicyEvaluator>,
+) -> Result<AccessPolicyExplanation, MappedErrors> {
+    // ? ----------------------------------------------------------------------
+    // ? Check if the current account has sufficient privileges
+    // ? ----------------------------------------------------------------------
+
+    profile
+        .with_system_accounts_access()
+        .with_read_access()
+        .with_roles(vec![SystemActor::GatewayManager])
+        .get_ids_or_error()?;
+
+    // ? ----------------------------------------------------------------------
+    // ? Resolve the policy from the matching route if not given
+    // ? ----------------------------------------------------------------------
+
+    let policy = match policy {
+        Some(policy) => policy,
+        None => match routes_fetching_repo
+            .match_single_path_or_error(path.to_owned())
+            .await?
+        {
+            FetchResponseKind::NotFound(_) => {
+                return use_case_err(format!(
+                    "No route matches the path: {}",
+                    path.path()
+                ))
+                .with_exp_true()
+                .as_error();
+            }
+            FetchResponseKind::Found(route) => match route.security_group {
+                SecurityGroup::ProtectedByPolicy(policy) => policy,
+                group => {
+                    return use_case_err(format!(
+                        "Route {} is not protected by a policy: {}",
+                        route.path,
+                        group.to_string()
+                    ))
+                    .with_exp_true()
+                    .as_error();
+                }
+            },
+        },
+    };
+
+    // ? ----------------------------------------------------------------------
+    // ? Evaluate the policy
+    // ? ----------------------------------------------------------------------
+
+    let decision = policy.evaluate(&attributes, *evaluator);
+
+    Ok(AccessPolicyExplanation {
+        policy,
+        attributes,
+        decision,
+    })
+}
+
+// ? ---------------------------------------------------------------------------
+// ? TESTS
+// ? ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::dtos::{access_policy::AccessPolicyRule, route::Route};
+
+    use async_trait::async_trait;
+    use mycelium_base::entities::FetchManyResponseKind;
+    use std::str::FromStr;
+    use uuid::Uuid;
+
+    struct StubRoutesRead {
+        route: Route,
+    }
+
+    #[async_trait]
+    impl RoutesRead for StubRoutesRead {
+        async fn match_single_path_or_error(
+            &self,
+            _: PathAndQuery,
+        ) -> Result<FetchResponseKind<Route, String>, MappedErrors> {
+            Ok(FetchResponseKind::Found(self.route.to_owned()))
+        }
+
+        async fn list_routes_paginated(
+            &self,
+            _: Option<Uuid>,
+            _: Option<String>,
+            _: Option<i32>,
+            _: Option<i32>,
+        ) -> Result<FetchManyResponseKind<Route>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn list_routes(
+            &self,
+            _: Option<Uuid>,
+            _: Option<String>,
+        ) -> Result<FetchManyResponseKind<Route>, MappedErrors> {
+            unimplemented!()
+        }
+    }
+
+    struct MethodEvaluator;
+
+    impl AccessPolicyEvaluator for MethodEvaluator {
+        fn evaluate_condition(
+            &self,
+            condition: &str,
+            attributes: &AccessPolicyAttributes,
+        ) -> Result<bool, String> {
+            Ok(condition == attributes.request.method)
+        }
+    }
+
+    fn route(group: serde_json::Value) -> Route {
+        serde_json::from_value(serde_json::json!({
+            "id": null,
+            "group": group,
+            "methods": ["GET"],
+            "path": "/items/*",
+        }))
+        .unwrap()
+    }
+
+    fn staff_profile() -> Profile {
+        let mut profile = Profile::default();
+        profile.is_staff = true;
+        profile
+    }
+
+    #[tokio::test]
+    async fn explain_uses_the_matching_route_policy() {
+        let repo = StubRoutesRead {
+            route: route(serde_json::json!({
+                "protectedByPolicy": {
+                    "rules": [{ "name": "only-get", "condition": "GET" }]
+                }
+            })),
+        };
+
+        let mut attributes = AccessPolicyAttributes::default();
+        attributes.request.method = "DELETE".to_string();
+
+        let explanation = explain_access_policy(
+            staff_profile(),
+            PathAndQuery::from_str("/service/items/1").unwrap(),
+            None,
+            attributes,
+            Box::new(&repo),
+            Box::new(&MethodEvaluator),
+        )
+        .await
+        .unwrap();
+
+        assert!(!explanation.decision.allowed);
+        assert_eq!(explanation.decision.reason, "Rule 'only-get' did not pass");
+
+        let explanation = explain_access_policy(
+            staff_profile(),
+            PathAndQuery::from_str("/service/items/1").unwrap(),
+            Some(AccessPolicy {
+                rules: vec![AccessPolicyRule {
+                    name: "only-delete".to_string(),
+                    condition: "DELETE".to_string(),
+                }],
+            }),
+            explanation.attributes,
+            Box::new(&repo),
+            Box::new(&MethodEvaluator),
+        )
+        .await
+        .unwrap();
+
+        assert!(explanation.decision.allowed);
+    }
+
+    #[tokio::test]
+    async fn explain_fails_for_routes_without_policy() {
+        let repo = StubRoutesRead {
+            route: route(serde_json::json!("protected")),
+        };
+
+        let result = explain_access_policy(
+            staff_profile(),
+            PathAndQuery::from_str("/service/items/1").unwrap(),
+            None,
+            AccessPolicyAttributes::default(),
+            Box::new(&repo),
+            Box::new(&MethodEvaluator),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+}
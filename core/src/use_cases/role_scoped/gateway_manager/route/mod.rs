@@ -1,3 +1,5 @@
+mod explain_access_policy;
 mod list_routes;
 
+pub use explain_access_policy::*;
 pub use list_routes::*;
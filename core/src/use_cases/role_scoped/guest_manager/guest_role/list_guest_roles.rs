@@ -8,7 +8,9 @@ use mycelium_base::{
     entities::FetchManyResponseKind, utils::errors::MappedErrors,
 };
 
-/// List guest roles
+/// List instance-wide guest roles
+///
+/// Tenant roles are listed by the tenant owners and managers.
 #[tracing::instrument(name = "list_guest_roles", skip_all)]
 pub async fn list_guest_roles(
     profile: Profile,
@@ -34,6 +36,6 @@ pub async fn list_guest_roles(
     // ? -----------------------------------------------------------------------
 
     guest_role_fetching_repo
-        .list(name, slug, system, page_size, skip)
+        .list(None, name, slug, system, page_size, skip)
         .await
 }
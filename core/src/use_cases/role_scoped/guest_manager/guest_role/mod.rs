@@ -4,6 +4,7 @@ mod insert_role_child;
 mod list_guest_roles;
 mod remove_role_child;
 mod update_guest_role_name_and_description;
+mod update_guest_role_named_permissions;
 mod update_guest_role_permissions;
 
 pub use create_guest_role::*;
@@ -12,4 +13,5 @@ pub use insert_role_child::*;
 pub use list_guest_roles::*;
 pub use remove_role_child::*;
 pub use update_guest_role_name_and_description::*;
+pub use update_guest_role_named_permissions::*;
 pub use update_guest_role_permissions::*;
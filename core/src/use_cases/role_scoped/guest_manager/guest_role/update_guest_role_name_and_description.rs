@@ -142,6 +142,7 @@ mod tests {
 
         async fn list(
             &self,
+            _: Option<Uuid>,
             _: Option<String>,
             _: Option<String>,
             _: Option<bool>,
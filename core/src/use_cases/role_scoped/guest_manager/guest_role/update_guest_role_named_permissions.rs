@@ -0,0 +1,242 @@
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::{
+            guest_role::GuestRole,
+            profile::Profile,
+            resource_audit_log::{
+                ResourceAuditEventKind, ResourceAuditResourceType,
+            },
+            written_by::WrittenBy,
+        },
+        entities::{
+            GuestRoleFetching, GuestRoleUpdating, ResourceAuditLogRegistration,
+        },
+    },
+    use_cases::shared::audit::emit_resource_audit_event,
+};
+
+use mycelium_base::{
+    entities::{FetchResponseKind, UpdatingResponseKind},
+    utils::errors::{use_case_err, MappedErrors},
+};
+use uuid::Uuid;
+
+/// Replace the named permissions granted by a single role, as
+/// `invoices:approve`. Only guests managers should perform such action.
+#[tracing::instrument(name = "update_guest_role_named_permissions", skip_all)]
+pub async fn update_guest_role_named_permissions(
+    profile: Profile,
+    guest_role_id: Uuid,
+    permissions: Vec<String>,
+    guest_role_fetching_repo: Box<&dyn GuestRoleFetching>,
+    guest_role_updating_repo: Box<&dyn GuestRoleUpdating>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<UpdatingResponseKind<GuestRole>, MappedErrors> {
+    // ? ----------------------------------------------------------------------
+    // ? Check the profile permissions
+    // ? ----------------------------------------------------------------------
+
+    profile
+        .with_system_accounts_access()
+        .with_write_access()
+        .with_roles(vec![SystemActor::GuestsManager])
+        .get_ids_or_error()?;
+
+    // ? ----------------------------------------------------------------------
+    // ? Fetch role from data persistence layer
+    // ? ----------------------------------------------------------------------
+
+    let user_role = match guest_role_fetching_repo.get(guest_role_id).await? {
+        FetchResponseKind::NotFound(id) => {
+            return use_case_err(format!(
+                "Unable to update record: {}",
+                id.unwrap(),
+            ))
+            .as_error();
+        }
+        FetchResponseKind::Found(role) => role,
+    };
+
+    // ? ----------------------------------------------------------------------
+    // ? Validate and replace the named permissions
+    // ? ----------------------------------------------------------------------
+
+    let user_role = user_role.with_permissions(permissions)?;
+
+    // ? ----------------------------------------------------------------------
+    // ? Perform the updating operation
+    // ? ----------------------------------------------------------------------
+
+    let response = guest_role_updating_repo.update(user_role).await?;
+
+    if let UpdatingResponseKind::Updated(ref role) = response {
+        emit_resource_audit_event(
+            audit_repo,
+            ResourceAuditResourceType::GuestRole,
+            guest_role_id,
+            role.tenant_id,
+            ResourceAuditEventKind::Updated,
+            WrittenBy::new_from_account(profile.acc_id),
+            serde_json::json!({
+                "action": "update_guest_role_named_permissions",
+                "permissions": role.permissions,
+            }),
+        )
+        .await;
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        dtos::guest_role::Permission,
+        entities::MockResourceAuditLogRegistration,
+    };
+
+    use async_trait::async_trait;
+    use mycelium_base::entities::FetchManyResponseKind;
+
+    fn role(id: Uuid) -> GuestRole {
+        GuestRole::new(
+            Some(id),
+            "Approver".to_string(),
+            None,
+            Permission::Read,
+            None,
+            false,
+        )
+    }
+
+    struct StubGuestRoleFetching {
+        role: GuestRole,
+    }
+
+    #[async_trait]
+    impl GuestRoleFetching for StubGuestRoleFetching {
+        async fn get(
+            &self,
+            _: Uuid,
+        ) -> Result<FetchResponseKind<GuestRole, Uuid>, MappedErrors> {
+            Ok(FetchResponseKind::Found(self.role.to_owned()))
+        }
+
+        async fn get_parent_by_child_id(
+            &self,
+            _: Uuid,
+        ) -> Result<FetchResponseKind<GuestRole, Uuid>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn list(
+            &self,
+            _: Option<Uuid>,
+            _: Option<String>,
+            _: Option<String>,
+            _: Option<bool>,
+            _: Option<i32>,
+            _: Option<i32>,
+        ) -> Result<FetchManyResponseKind<GuestRole>, MappedErrors> {
+            unimplemented!()
+        }
+    }
+
+    /// Echoes the role received for update
+    struct EchoGuestRoleUpdating;
+
+    #[async_trait]
+    impl GuestRoleUpdating for EchoGuestRoleUpdating {
+        async fn update(
+            &self,
+            role: GuestRole,
+        ) -> Result<UpdatingResponseKind<GuestRole>, MappedErrors> {
+            Ok(UpdatingResponseKind::Updated(role))
+        }
+
+        async fn insert_role_child(
+            &self,
+            _: Uuid,
+            _: Uuid,
+            _: Uuid,
+        ) -> Result<UpdatingResponseKind<Option<GuestRole>>, MappedErrors>
+        {
+            unimplemented!()
+        }
+
+        async fn remove_role_child(
+            &self,
+            _: Uuid,
+            _: Uuid,
+            _: Uuid,
+        ) -> Result<UpdatingResponseKind<Option<GuestRole>>, MappedErrors>
+        {
+            unimplemented!()
+        }
+    }
+
+    fn staff_profile() -> Profile {
+        let mut profile = Profile::default();
+        profile.is_staff = true;
+        profile
+    }
+
+    #[tokio::test]
+    async fn replaces_the_role_named_permissions() {
+        let guest_role_id = Uuid::new_v4();
+
+        let fetching = StubGuestRoleFetching {
+            role: role(guest_role_id),
+        };
+
+        let mut audit_repo = MockResourceAuditLogRegistration::new();
+        audit_repo.expect_create().times(1).returning(|_| Ok(()));
+
+        let result = update_guest_role_named_permissions(
+            staff_profile(),
+            guest_role_id,
+            vec!["samples:delete".to_string(), "invoices:approve".to_string()],
+            Box::new(&fetching as &dyn GuestRoleFetching),
+            Box::new(&EchoGuestRoleUpdating as &dyn GuestRoleUpdating),
+            Box::new(&audit_repo),
+        )
+        .await;
+
+        match result {
+            Ok(UpdatingResponseKind::Updated(role)) => assert_eq!(
+                role.permissions,
+                vec![
+                    "invoices:approve".to_string(),
+                    "samples:delete".to_string()
+                ]
+            ),
+            _ => panic!("Expected an updated role"),
+        }
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_permission_names() {
+        let guest_role_id = Uuid::new_v4();
+
+        let fetching = StubGuestRoleFetching {
+            role: role(guest_role_id),
+        };
+
+        let mut audit_repo = MockResourceAuditLogRegistration::new();
+        audit_repo.expect_create().times(0);
+
+        let result = update_guest_role_named_permissions(
+            staff_profile(),
+            guest_role_id,
+            vec!["approve invoices".to_string()],
+            Box::new(&fetching as &dyn GuestRoleFetching),
+            Box::new(&EchoGuestRoleUpdating as &dyn GuestRoleUpdating),
+            Box::new(&audit_repo),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -1,11 +1,18 @@
 use crate::domain::{
     actors::SystemActor,
     dtos::{
-        account::Account, guest_role::Permission,
-        native_error_codes::NativeErrorCodes, profile::Profile,
+        account::Account,
+        guest_role::Permission,
+        native_error_codes::NativeErrorCodes,
+        profile::Profile,
+        resource_audit_log::{
+            ResourceAuditEventKind, ResourceAuditResourceType,
+        },
+        written_by::WrittenBy,
     },
-    entities::AccountFetching,
+    entities::{AccountFetching, ResourceAuditLogRegistration},
 };
+use crate::use_cases::shared::audit::emit_resource_audit_event;
 
 use mycelium_base::{
     entities::FetchResponseKind,
@@ -16,7 +23,8 @@ use uuid::Uuid;
 /// Get details of a single account
 ///
 /// These details could include information about guest accounts, modifications
-/// and others.
+/// and others. Reading an account other than the caller's own is recorded in
+/// the resource audit trail as an `Accessed` event.
 #[tracing::instrument(
     name = "get_account_details",
     fields(profile_id = %profile.acc_id),
@@ -27,11 +35,14 @@ pub async fn get_account_details(
     tenant_id: Option<Uuid>,
     account_id: Uuid,
     account_fetching_repo: Box<&dyn AccountFetching>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
 ) -> Result<FetchResponseKind<Account, Uuid>, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Check if the current account has sufficient privileges
     // ? -----------------------------------------------------------------------
 
+    let requester_id = profile.acc_id;
+
     let related_accounts = match profile.has_admin_privileges_or_error() {
         //
         // If the current account has administration privileges, the tenant id
@@ -71,7 +82,24 @@ pub async fn get_account_details(
     // ? Fetch account
     // ? -----------------------------------------------------------------------
 
-    account_fetching_repo
+    let response = account_fetching_repo
         .get(account_id, related_accounts)
-        .await
+        .await?;
+
+    if let FetchResponseKind::Found(_) = response {
+        if account_id != requester_id {
+            emit_resource_audit_event(
+                audit_repo,
+                ResourceAuditResourceType::Account,
+                account_id,
+                tenant_id,
+                ResourceAuditEventKind::Accessed,
+                WrittenBy::new_from_account(requester_id),
+                serde_json::json!({ "action": "get_account_details" }),
+            )
+            .await;
+        }
+    }
+
+    Ok(response)
 }
@@ -0,0 +1,59 @@
+use crate::domain::{
+    actors::SystemActor,
+    dtos::{
+        bulk_import::BulkImport, native_error_codes::NativeErrorCodes,
+        profile::Profile,
+    },
+    entities::BulkImportFetching,
+};
+
+use mycelium_base::{
+    entities::FetchResponseKind,
+    utils::errors::{use_case_err, MappedErrors},
+};
+use uuid::Uuid;
+
+/// Get a bulk import job with the result of each row
+#[tracing::instrument(
+    name = "get_bulk_import",
+    fields(profile_id = %profile.acc_id),
+    skip_all
+)]
+pub async fn get_bulk_import(
+    profile: Profile,
+    tenant_id: Uuid,
+    bulk_import_id: Uuid,
+    bulk_import_fetching_repo: Box<&dyn BulkImportFetching>,
+) -> Result<FetchResponseKind<BulkImport, Uuid>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check if the current account has sufficient privileges
+    // ? -----------------------------------------------------------------------
+
+    let is_owner = profile.with_tenant_ownership_or_error(tenant_id).is_ok();
+
+    let has_access = profile
+        .on_tenant(tenant_id)
+        .with_system_accounts_access()
+        .with_read_access()
+        .with_roles(vec![
+            SystemActor::TenantManager,
+            SystemActor::SubscriptionsManager,
+        ])
+        .get_related_account_or_error()
+        .is_ok();
+
+    if ![is_owner, has_access].iter().any(|&x| x) {
+        return use_case_err("Insufficient privileges to get bulk imports")
+            .with_code(NativeErrorCodes::MYC00019)
+            .with_exp_true()
+            .as_error();
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Fetch the job
+    // ? -----------------------------------------------------------------------
+
+    bulk_import_fetching_repo
+        .get(tenant_id, bulk_import_id)
+        .await
+}
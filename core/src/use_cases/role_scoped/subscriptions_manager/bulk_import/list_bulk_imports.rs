@@ -0,0 +1,60 @@
+use crate::domain::{
+    actors::SystemActor,
+    dtos::{
+        bulk_import::BulkImport, native_error_codes::NativeErrorCodes,
+        profile::Profile,
+    },
+    entities::BulkImportFetching,
+};
+
+use mycelium_base::{
+    entities::FetchManyResponseKind,
+    utils::errors::{use_case_err, MappedErrors},
+};
+use uuid::Uuid;
+
+/// List the bulk import jobs of a tenant, the most recent first
+#[tracing::instrument(
+    name = "list_bulk_imports",
+    fields(profile_id = %profile.acc_id),
+    skip_all
+)]
+pub async fn list_bulk_imports(
+    profile: Profile,
+    tenant_id: Uuid,
+    page_size: Option<i32>,
+    skip: Option<i32>,
+    bulk_import_fetching_repo: Box<&dyn BulkImportFetching>,
+) -> Result<FetchManyResponseKind<BulkImport>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check if the current account has sufficient privileges
+    // ? -----------------------------------------------------------------------
+
+    let is_owner = profile.with_tenant_ownership_or_error(tenant_id).is_ok();
+
+    let has_access = profile
+        .on_tenant(tenant_id)
+        .with_system_accounts_access()
+        .with_read_access()
+        .with_roles(vec![
+            SystemActor::TenantManager,
+            SystemActor::SubscriptionsManager,
+        ])
+        .get_related_account_or_error()
+        .is_ok();
+
+    if ![is_owner, has_access].iter().any(|&x| x) {
+        return use_case_err("Insufficient privileges to list bulk imports")
+            .with_code(NativeErrorCodes::MYC00019)
+            .with_exp_true()
+            .as_error();
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Fetch the jobs
+    // ? -----------------------------------------------------------------------
+
+    bulk_import_fetching_repo
+        .list(tenant_id, page_size, skip)
+        .await
+}
@@ -0,0 +1,14 @@
+// All actions listed below should ve performed by:
+//
+// - Subscription Manager
+// - Tenant Manager
+// - Tenant Owner
+//
+
+mod get_bulk_import;
+mod list_bulk_imports;
+mod submit_bulk_import;
+
+pub use get_bulk_import::*;
+pub use list_bulk_imports::*;
+pub use submit_bulk_import::*;
@@ -0,0 +1,242 @@
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::{
+            bulk_import::{
+                BulkImport, BulkImportFormat, BulkImportRow, BulkImportStatus,
+            },
+            native_error_codes::NativeErrorCodes,
+            profile::Profile,
+            written_by::WrittenBy,
+        },
+        entities::{BulkImportRegistration, GuestRoleFetching},
+    },
+    use_cases::support::validate_bulk_import_rows,
+};
+
+use mycelium_base::{
+    entities::GetOrCreateResponseKind,
+    utils::errors::{use_case_err, MappedErrors},
+};
+use uuid::Uuid;
+
+/// Submit a bulk import of guests and subscription accounts
+///
+/// The rows are parsed and stored as a pending job, applied in background by
+/// the API. Uploading rows already imported into the tenant returns the
+/// existing job, so nothing is imported twice.
+///
+/// Dry runs validate the rows and return the result of each one, without
+/// storing the job.
+///
+#[tracing::instrument(
+    name = "submit_bulk_import",
+    fields(profile_id = %profile.acc_id),
+    skip_all
+)]
+pub async fn submit_bulk_import(
+    profile: Profile,
+    tenant_id: Uuid,
+    format: BulkImportFormat,
+    content: String,
+    dry_run: bool,
+    guest_role_fetching_repo: Box<&dyn GuestRoleFetching>,
+    bulk_import_registration_repo: Box<&dyn BulkImportRegistration>,
+) -> Result<GetOrCreateResponseKind<BulkImport>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check if the current account has sufficient privileges
+    //
+    // Rows may create accounts anywhere in the tenant, so tenant wide
+    // privileges are required.
+    //
+    // ? -----------------------------------------------------------------------
+
+    let is_owner = profile.with_tenant_ownership_or_error(tenant_id).is_ok();
+
+    let has_access = profile
+        .on_tenant(tenant_id)
+        .with_system_accounts_access()
+        .with_write_access()
+        .with_roles(vec![
+            SystemActor::TenantManager,
+            SystemActor::SubscriptionsManager,
+        ])
+        .get_related_account_or_error()
+        .is_ok();
+
+    if ![is_owner, has_access].iter().any(|&x| x) {
+        return use_case_err("Insufficient privileges to import accounts")
+            .with_code(NativeErrorCodes::MYC00019)
+            .with_exp_true()
+            .as_error();
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Parse the rows
+    // ? -----------------------------------------------------------------------
+
+    let rows = BulkImportRow::parse_many(format, &content)
+        .map_err(|err| err.with_exp_true())?;
+
+    let bulk_import = BulkImport::new(
+        tenant_id,
+        rows,
+        WrittenBy::new_from_account(profile.acc_id),
+    );
+
+    // ? -----------------------------------------------------------------------
+    // ? Validate the rows only
+    // ? -----------------------------------------------------------------------
+
+    if dry_run {
+        let results = validate_bulk_import_rows(
+            tenant_id,
+            &bulk_import.rows,
+            guest_role_fetching_repo,
+        )
+        .await?;
+
+        return Ok(GetOrCreateResponseKind::NotCreated(
+            BulkImport {
+                status: BulkImportStatus::Done,
+                dry_run: true,
+                results,
+                ..bulk_import
+            },
+            "Dry run. The rows were validated only.".to_string(),
+        ));
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Register the job
+    // ? -----------------------------------------------------------------------
+
+    bulk_import_registration_repo
+        .get_or_create(bulk_import)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        dtos::{
+            bulk_import::BulkImportRowStatus,
+            guest_role::GuestRole,
+            profile::{TenantOwnership, TenantsOwnership},
+        },
+        entities::MockBulkImportRegistration,
+    };
+
+    use async_trait::async_trait;
+    use chrono::Local;
+    use mycelium_base::entities::{FetchManyResponseKind, FetchResponseKind};
+
+    struct NoRoles;
+
+    #[async_trait]
+    impl GuestRoleFetching for NoRoles {
+        async fn get(
+            &self,
+            id: Uuid,
+        ) -> Result<FetchResponseKind<GuestRole, Uuid>, MappedErrors> {
+            Ok(FetchResponseKind::NotFound(Some(id)))
+        }
+
+        async fn get_parent_by_child_id(
+            &self,
+            _: Uuid,
+        ) -> Result<FetchResponseKind<GuestRole, Uuid>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn list(
+            &self,
+            _: Option<Uuid>,
+            _: Option<String>,
+            _: Option<String>,
+            _: Option<bool>,
+            _: Option<i32>,
+            _: Option<i32>,
+        ) -> Result<FetchManyResponseKind<GuestRole>, MappedErrors> {
+            Ok(FetchManyResponseKind::NotFound)
+        }
+    }
+
+    fn profile_owning_tenant(tenant_id: Uuid) -> Profile {
+        Profile::new(
+            vec![],
+            Uuid::new_v4(),
+            false,
+            false,
+            false,
+            true,
+            true,
+            true,
+            false,
+            false,
+            None,
+            None,
+            Some(TenantsOwnership::Records(vec![TenantOwnership {
+                id: tenant_id,
+                name: "Tenant Name".to_string(),
+                since: Local::now(),
+                parent_id: None,
+                inherited_from: None,
+            }])),
+        )
+    }
+
+    const CONTENT: &str =
+        "email,role,accountName\nana@example.com,viewer,Lab A\n";
+
+    #[tokio::test]
+    async fn dry_runs_validate_without_registering_the_job() {
+        let tenant_id = Uuid::new_v4();
+
+        let mut registration = MockBulkImportRegistration::new();
+        registration.expect_get_or_create().times(0);
+
+        let response = submit_bulk_import(
+            profile_owning_tenant(tenant_id),
+            tenant_id,
+            BulkImportFormat::Csv,
+            CONTENT.to_string(),
+            true,
+            Box::new(&NoRoles),
+            Box::new(&registration),
+        )
+        .await
+        .unwrap();
+
+        match response {
+            GetOrCreateResponseKind::NotCreated(bulk_import, _) => {
+                assert!(bulk_import.dry_run);
+                assert_eq!(
+                    bulk_import.results[0].status,
+                    BulkImportRowStatus::Invalid
+                );
+            }
+            _ => panic!("dry runs are never registered"),
+        }
+    }
+
+    #[tokio::test]
+    async fn imports_require_tenant_wide_privileges() {
+        let mut registration = MockBulkImportRegistration::new();
+        registration.expect_get_or_create().times(0);
+
+        let response = submit_bulk_import(
+            Profile::default(),
+            Uuid::new_v4(),
+            BulkImportFormat::Csv,
+            CONTENT.to_string(),
+            false,
+            Box::new(&NoRoles),
+            Box::new(&registration),
+        )
+        .await;
+
+        assert!(response.is_err());
+    }
+}
@@ -0,0 +1,137 @@
+use crate::domain::{
+    actors::SystemActor,
+    dtos::{
+        guest_role::Permission,
+        guest_user::GuestInvitationEvent,
+        native_error_codes::NativeErrorCodes,
+        profile::Profile,
+        resource_audit_log::{
+            ResourceAuditEventKind, ResourceAuditResourceType,
+        },
+        webhook::{PayloadId, WebHookTrigger},
+        written_by::WrittenBy,
+    },
+    entities::{
+        GuestUserDeletion, GuestUserFetching, ResourceAuditLogRegistration,
+        WebHookRegistration,
+    },
+};
+use crate::use_cases::{
+    shared::audit::emit_resource_audit_event,
+    support::register_webhook_dispatching_event,
+};
+
+use mycelium_base::{
+    entities::{DeletionResponseKind, FetchManyResponseKind},
+    utils::errors::{use_case_err, MappedErrors},
+};
+use uuid::Uuid;
+
+/// Cancel an invitation not accepted yet
+///
+/// Accepted invitations are guest memberships and should be revoked instead.
+///
+#[tracing::instrument(
+    name = "cancel_guest_invitation",
+    fields(profile_id = %profile.acc_id),
+    skip_all
+)]
+pub async fn cancel_guest_invitation(
+    profile: Profile,
+    tenant_id: Uuid,
+    account_id: Uuid,
+    guest_user_id: Uuid,
+    guest_user_fetching_repo: Box<&dyn GuestUserFetching>,
+    guest_user_deletion_repo: Box<&dyn GuestUserDeletion>,
+    webhook_registration_repo: Box<&dyn WebHookRegistration>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<DeletionResponseKind<(Uuid, Uuid)>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check if the current account has sufficient privileges
+    // ? -----------------------------------------------------------------------
+
+    profile
+        .on_tenant(tenant_id)
+        .on_account(account_id)
+        .with_system_accounts_access()
+        .with_write_access()
+        .with_roles(vec![
+            SystemActor::TenantManager,
+            SystemActor::SubscriptionsManager,
+        ])
+        .get_related_accounts_or_tenant_wide_permission_or_error(
+            tenant_id,
+            Permission::Write,
+        )?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Fetch the pending invitation
+    // ? -----------------------------------------------------------------------
+
+    let invitation = match guest_user_fetching_repo
+        .list_account_invitations(account_id)
+        .await?
+    {
+        FetchManyResponseKind::Found(records) => records,
+        FetchManyResponseKind::FoundPaginated { records, .. } => records,
+        FetchManyResponseKind::NotFound => vec![],
+    }
+    .into_iter()
+    .find(|invitation| {
+        invitation.guest_user_id == guest_user_id
+            && invitation.tenant_id == Some(tenant_id)
+    });
+
+    let invitation = match invitation {
+        Some(invitation) => invitation,
+        None => {
+            return use_case_err(format!(
+                "Pending invitation not found: {guest_user_id}"
+            ))
+            .with_code(NativeErrorCodes::MYC00013)
+            .with_exp_true()
+            .as_error()
+        }
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Remove the invitation
+    // ? -----------------------------------------------------------------------
+
+    let response = guest_user_deletion_repo
+        .delete(
+            invitation.guest_role_id,
+            account_id,
+            invitation.email.email(),
+        )
+        .await?;
+
+    if let DeletionResponseKind::Deleted = response {
+        emit_resource_audit_event(
+            audit_repo,
+            ResourceAuditResourceType::GuestUser,
+            account_id,
+            Some(tenant_id),
+            ResourceAuditEventKind::Deleted,
+            WrittenBy::new_from_account(profile.acc_id),
+            serde_json::json!({
+                "action": "cancel_guest_invitation",
+                "guestUserId": guest_user_id,
+                "guestRoleId": invitation.guest_role_id,
+                "email": invitation.email.email(),
+            }),
+        )
+        .await;
+
+        register_webhook_dispatching_event(
+            Uuid::new_v4(),
+            WebHookTrigger::GuestInvitationCancelled,
+            GuestInvitationEvent::from(&invitation),
+            PayloadId::Uuid(guest_user_id),
+            webhook_registration_repo,
+        )
+        .await?;
+    }
+
+    Ok(response)
+}
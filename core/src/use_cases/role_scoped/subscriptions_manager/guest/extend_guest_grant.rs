@@ -0,0 +1,130 @@
+use crate::domain::{
+    actors::SystemActor,
+    dtos::{
+        guest_role::Permission,
+        guest_user_on_account::GuestUserOnAccount,
+        native_error_codes::NativeErrorCodes,
+        profile::Profile,
+        resource_audit_log::{
+            ResourceAuditEventKind, ResourceAuditResourceType,
+        },
+        written_by::WrittenBy,
+    },
+    entities::{
+        GuestUserOnAccountFetching, GuestUserOnAccountUpdating,
+        ResourceAuditLogRegistration,
+    },
+};
+use crate::use_cases::shared::audit::emit_resource_audit_event;
+
+use chrono::{DateTime, Local};
+use mycelium_base::{
+    entities::{FetchManyResponseKind, UpdatingResponseKind},
+    utils::errors::{use_case_err, MappedErrors},
+};
+use uuid::Uuid;
+
+/// Change the end date of a guest grant
+///
+/// The grant keeps its start date. Setting no end date makes the grant
+/// permanent. Otherwise the end date must be in the future, so expired grants
+/// can not be extended, but invited again.
+///
+#[tracing::instrument(
+    name = "extend_guest_grant",
+    fields(profile_id = %profile.acc_id),
+    skip_all
+)]
+pub async fn extend_guest_grant(
+    profile: Profile,
+    tenant_id: Uuid,
+    account_id: Uuid,
+    guest_role_id: Uuid,
+    valid_until: Option<DateTime<Local>>,
+    guest_user_on_account_fetching_repo: Box<&dyn GuestUserOnAccountFetching>,
+    guest_user_on_account_updating_repo: Box<&dyn GuestUserOnAccountUpdating>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<UpdatingResponseKind<GuestUserOnAccount>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check if the current account has sufficient privileges
+    // ? -----------------------------------------------------------------------
+
+    profile
+        .on_tenant(tenant_id)
+        .on_account(account_id)
+        .with_system_accounts_access()
+        .with_write_access()
+        .with_roles(vec![
+            SystemActor::TenantManager,
+            SystemActor::SubscriptionsManager,
+        ])
+        .get_related_accounts_or_tenant_wide_permission_or_error(
+            tenant_id,
+            Permission::Write,
+        )?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Fetch the grant
+    // ? -----------------------------------------------------------------------
+
+    let grant = match guest_user_on_account_fetching_repo
+        .list_by_guest_role_id(guest_role_id, account_id)
+        .await?
+    {
+        FetchManyResponseKind::Found(records) if records.len() == 1 => {
+            records[0].to_owned()
+        }
+        FetchManyResponseKind::NotFound => {
+            return use_case_err("No guest user on account found for the given guest role id and account id.")
+                .with_code(NativeErrorCodes::MYC00018)
+                .with_exp_true()
+                .as_error()
+        }
+        _ => {
+            return use_case_err("Invalid operation. Operation restricted to single guest user on account. Please contact support.")
+                .with_code(NativeErrorCodes::MYC00018)
+                .with_exp_true()
+                .as_error()
+        }
+    };
+
+    GuestUserOnAccount::check_validity_window(
+        grant.valid_from,
+        valid_until,
+        Local::now(),
+    )?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Update the grant end date
+    // ? -----------------------------------------------------------------------
+
+    let response = guest_user_on_account_updating_repo
+        .update_validity(
+            grant.guest_user_id,
+            account_id,
+            grant.valid_from,
+            valid_until,
+        )
+        .await?;
+
+    if let UpdatingResponseKind::Updated(_) = &response {
+        emit_resource_audit_event(
+            audit_repo,
+            ResourceAuditResourceType::GuestUser,
+            account_id,
+            Some(tenant_id),
+            ResourceAuditEventKind::Updated,
+            WrittenBy::new_from_account(profile.acc_id),
+            serde_json::json!({
+                "action": "extend_guest_grant",
+                "guestUserId": grant.guest_user_id,
+                "guestRoleId": guest_role_id,
+                "previousValidUntil": grant.valid_until,
+                "validUntil": valid_until,
+            }),
+        )
+        .await;
+    }
+
+    Ok(response)
+}
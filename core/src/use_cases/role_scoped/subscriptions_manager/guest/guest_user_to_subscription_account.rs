@@ -2,20 +2,39 @@ use crate::{
     domain::{
         actors::SystemActor,
         dtos::{
-            account::VerboseStatus, account_type::AccountType, email::Email,
-            guest_role::Permission, guest_user::GuestUser,
-            native_error_codes::NativeErrorCodes, profile::Profile,
+            account::VerboseStatus,
+            account_type::AccountType,
+            email::Email,
+            guest_role::Permission,
+            guest_user::{GuestInvitationEvent, GuestUser},
+            guest_user_on_account::GuestUserOnAccount,
+            native_error_codes::NativeErrorCodes,
+            profile::Profile,
+            resource_audit_log::{
+                ResourceAuditEventKind, ResourceAuditResourceType,
+            },
+            webhook::{PayloadId, WebHookTrigger},
+            written_by::WrittenBy,
         },
         entities::{
-            AccountFetching, GuestRoleFetching, GuestUserRegistration,
-            LocalMessageWrite, TenantFetching,
+            AccountFetching, EmailSuppressionFetching, EncryptionKeyFetching,
+            GuestRoleFetching, GuestUserOnAccountUpdating,
+            GuestUserRegistration, LocalMessageWrite,
+            NotificationRecipientFetching, ResourceAuditLogRegistration,
+            TenantFetching, WebHookRegistration,
         },
     },
     models::AccountLifeCycle,
     settings::DEFAULT_TENANT_ID_KEY,
-    use_cases::support::dispatch_notification,
+    use_cases::{
+        shared::audit::emit_resource_audit_event,
+        support::{
+            dispatch_channel_notification, register_webhook_dispatching_event,
+        },
+    },
 };
 
+use chrono::{DateTime, Local};
 use futures::future;
 use mycelium_base::{
     dtos::Parent,
@@ -25,6 +44,10 @@ use mycelium_base::{
 use uuid::Uuid;
 
 /// Guest a user to perform actions into an account.
+///
+/// The grant may be time-bound: it licenses the account from `valid_from` and
+/// until `valid_until` only, when given.
+///
 #[tracing::instrument(
     name = "guest_user_to_subscription_account",
     fields(profile_id = %profile.acc_id),
@@ -36,12 +59,22 @@ pub async fn guest_user_to_subscription_account(
     email: Email,
     role_id: Uuid,
     target_account_id: Uuid,
+    valid_from: Option<DateTime<Local>>,
+    valid_until: Option<DateTime<Local>>,
     life_cycle_settings: AccountLifeCycle,
     account_fetching_repo: Box<&dyn AccountFetching>,
     guest_role_fetching_repo: Box<&dyn GuestRoleFetching>,
     guest_user_registration_repo: Box<&dyn GuestUserRegistration>,
+    guest_user_on_account_updating_repo: Box<&dyn GuestUserOnAccountUpdating>,
     message_sending_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
     tenant_fetching_repo: Box<&dyn TenantFetching>,
+    notification_recipient_fetching_repo: Box<
+        &dyn NotificationRecipientFetching,
+    >,
+    encryption_key_fetching_repo: Box<&dyn EncryptionKeyFetching>,
+    webhook_registration_repo: Box<&dyn WebHookRegistration>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
 ) -> Result<GetOrCreateResponseKind<GuestUser>, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Check if the current account has sufficient privileges
@@ -60,6 +93,12 @@ pub async fn guest_user_to_subscription_account(
             Permission::Write,
         )?;
 
+    GuestUserOnAccount::check_validity_window(
+        valid_from,
+        valid_until,
+        Local::now(),
+    )?;
+
     // ? -----------------------------------------------------------------------
     // ? Guarantee needed information to evaluate guesting
     //
@@ -170,28 +209,39 @@ pub async fn guest_user_to_subscription_account(
         .as_error();
     }
 
+    //
+    // Roles of other tenants are reported as not found.
+    //
     let target_role = match target_role_response? {
-        FetchResponseKind::NotFound(id) => {
-            return use_case_err(format!(
-                "Guest role not found: {:?}",
-                id.unwrap()
-            ))
-            .with_code(NativeErrorCodes::MYC00013)
-            .as_error()
+        FetchResponseKind::Found(role)
+            if role.is_available_to_tenant(Some(tenant_id)) =>
+        {
+            role
+        }
+        _ => {
+            return use_case_err(format!("Guest role not found: {role_id}"))
+                .with_code(NativeErrorCodes::MYC00013)
+                .as_error()
         }
-        FetchResponseKind::Found(role) => role,
     };
 
     // ? -----------------------------------------------------------------------
     // ? Persist changes
     // ? -----------------------------------------------------------------------
 
+    let invitation_expires_in =
+        life_cycle_settings.invitation_expires_in().await?;
+
     let guest_user = match guest_user_registration_repo
         .get_or_create(
             GuestUser::new_unverified(
                 email.to_owned(),
                 Parent::Id(role_id),
                 None,
+            )
+            .with_invitation(
+                WrittenBy::new_from_account(profile.acc_id),
+                invitation_expires_in,
             ),
             target_account_id,
         )
@@ -206,11 +256,66 @@ pub async fn guest_user_to_subscription_account(
         }
     };
 
+    if let GetOrCreateResponseKind::Created(guest) = &guest_user {
+        let guest_user_id =
+            match guest.id {
+                Some(id) => id,
+                None => return use_case_err(
+                    "Unable to find guest user id. This should never happen.",
+                )
+                .as_error(),
+            };
+
+        if valid_from.is_some() || valid_until.is_some() {
+            guest_user_on_account_updating_repo
+                .update_validity(
+                    guest_user_id,
+                    target_account_id,
+                    valid_from,
+                    valid_until,
+                )
+                .await?;
+        }
+
+        emit_resource_audit_event(
+            audit_repo,
+            ResourceAuditResourceType::GuestUser,
+            target_account_id,
+            Some(tenant_id),
+            ResourceAuditEventKind::Created,
+            WrittenBy::new_from_account(profile.acc_id),
+            serde_json::json!({
+                "action": "guest_user_to_subscription_account",
+                "guestUserId": guest_user_id,
+                "guestRoleId": role_id,
+                "email": email.email(),
+                "validFrom": valid_from,
+                "validUntil": valid_until,
+            }),
+        )
+        .await;
+
+        if !guest.was_verified {
+            register_webhook_dispatching_event(
+                Uuid::new_v4(),
+                WebHookTrigger::GuestInvitationCreated,
+                GuestInvitationEvent::from_guest_user(
+                    guest,
+                    target_account_id,
+                    Some(tenant_id),
+                )?,
+                PayloadId::Uuid(guest_user_id),
+                webhook_registration_repo,
+            )
+            .await?;
+        }
+    }
+
     // ? -----------------------------------------------------------------------
     // ? Notify guest user
     // ? -----------------------------------------------------------------------
 
-    if let Err(err) = dispatch_notification(
+    if let Err(err) = dispatch_channel_notification(
         vec![
             ("account_name", target_account.name.to_uppercase()),
             ("role_name", target_role.name.to_uppercase()),
@@ -222,7 +327,10 @@ pub async fn guest_user_to_subscription_account(
         email,
         None,
         message_sending_repo,
+        email_suppression_fetching_repo,
         tenant_fetching_repo,
+        notification_recipient_fetching_repo,
+        encryption_key_fetching_repo,
     )
     .await
     {
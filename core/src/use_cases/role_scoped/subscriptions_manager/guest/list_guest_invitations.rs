@@ -0,0 +1,74 @@
+use crate::domain::{
+    actors::SystemActor,
+    dtos::{
+        guest_role::Permission, guest_user::PendingGuestInvitation,
+        profile::Profile,
+    },
+    entities::GuestUserFetching,
+};
+
+use mycelium_base::{
+    entities::FetchManyResponseKind, utils::errors::MappedErrors,
+};
+use uuid::Uuid;
+
+/// List the invitations to an account not accepted yet
+///
+/// Each invitation carries who sent it, when it was last sent and when it
+/// expires. Expired invitations are listed until the scheduler removes them,
+/// so they can still be resent.
+///
+#[tracing::instrument(
+    name = "list_guest_invitations",
+    fields(profile_id = %profile.acc_id),
+    skip_all
+)]
+pub async fn list_guest_invitations(
+    profile: Profile,
+    tenant_id: Uuid,
+    account_id: Uuid,
+    guest_user_fetching_repo: Box<&dyn GuestUserFetching>,
+) -> Result<FetchManyResponseKind<PendingGuestInvitation>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check if the current account has sufficient privileges
+    // ? -----------------------------------------------------------------------
+
+    profile
+        .on_tenant(tenant_id)
+        .on_account(account_id)
+        .with_system_accounts_access()
+        .with_read_access()
+        .with_roles(vec![
+            SystemActor::TenantManager,
+            SystemActor::SubscriptionsManager,
+        ])
+        .get_related_accounts_or_tenant_wide_permission_or_error(
+            tenant_id,
+            Permission::Read,
+        )?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Fetch the invitations
+    //
+    // Accounts of other tenants are reported as without invitations.
+    //
+    // ? -----------------------------------------------------------------------
+
+    let invitations = match guest_user_fetching_repo
+        .list_account_invitations(account_id)
+        .await?
+    {
+        FetchManyResponseKind::Found(records) => records,
+        FetchManyResponseKind::FoundPaginated { records, .. } => records,
+        FetchManyResponseKind::NotFound => vec![],
+    }
+    .into_iter()
+    .filter(|invitation| invitation.tenant_id == Some(tenant_id))
+    .collect::<Vec<PendingGuestInvitation>>();
+
+    if invitations.is_empty() {
+        return Ok(FetchManyResponseKind::NotFound);
+    }
+
+    Ok(FetchManyResponseKind::Found(invitations))
+}
@@ -5,14 +5,22 @@
 // - Tenant Owner
 //
 
+mod cancel_guest_invitation;
+mod extend_guest_grant;
 mod guest_user_to_subscription_account;
+mod list_guest_invitations;
 mod list_guest_on_subscription_account;
 mod list_licensed_accounts_of_email;
+mod resend_guest_invitation;
 mod revoke_user_guest_to_subscription_account;
 mod update_flags_from_subscription_account;
 
+pub use cancel_guest_invitation::*;
+pub use extend_guest_grant::*;
 pub use guest_user_to_subscription_account::*;
+pub use list_guest_invitations::*;
 pub use list_guest_on_subscription_account::*;
 pub use list_licensed_accounts_of_email::*;
+pub use resend_guest_invitation::*;
 pub use revoke_user_guest_to_subscription_account::*;
 pub use update_flags_from_subscription_account::*;
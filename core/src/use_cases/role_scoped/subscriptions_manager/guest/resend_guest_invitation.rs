@@ -0,0 +1,198 @@
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::{
+            guest_role::Permission,
+            guest_user::{GuestInvitationEvent, PendingGuestInvitation},
+            native_error_codes::NativeErrorCodes,
+            profile::Profile,
+            resource_audit_log::{
+                ResourceAuditEventKind, ResourceAuditResourceType,
+            },
+            webhook::{PayloadId, WebHookTrigger},
+            written_by::WrittenBy,
+        },
+        entities::{
+            EmailSuppressionFetching, EncryptionKeyFetching, GuestUserFetching,
+            GuestUserOnAccountUpdating, LocalMessageWrite,
+            NotificationRecipientFetching, ResourceAuditLogRegistration,
+            TenantFetching, WebHookRegistration,
+        },
+    },
+    models::AccountLifeCycle,
+    settings::DEFAULT_TENANT_ID_KEY,
+    use_cases::{
+        shared::audit::emit_resource_audit_event,
+        support::{
+            dispatch_channel_notification, register_webhook_dispatching_event,
+        },
+    },
+};
+
+use chrono::Local;
+use mycelium_base::{
+    entities::{FetchManyResponseKind, UpdatingResponseKind},
+    utils::errors::{use_case_err, MappedErrors},
+};
+use uuid::Uuid;
+
+/// Send an invitation not accepted yet again
+///
+/// The invitation email is sent again and the invitation may be accepted for
+/// another `invitationExpiration` seconds. Expired invitations not removed by
+/// the scheduler yet may be resent too.
+///
+#[tracing::instrument(
+    name = "resend_guest_invitation",
+    fields(profile_id = %profile.acc_id),
+    skip_all
+)]
+pub async fn resend_guest_invitation(
+    profile: Profile,
+    tenant_id: Uuid,
+    account_id: Uuid,
+    guest_user_id: Uuid,
+    life_cycle_settings: AccountLifeCycle,
+    guest_user_fetching_repo: Box<&dyn GuestUserFetching>,
+    guest_user_on_account_updating_repo: Box<&dyn GuestUserOnAccountUpdating>,
+    message_sending_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
+    tenant_fetching_repo: Box<&dyn TenantFetching>,
+    notification_recipient_fetching_repo: Box<
+        &dyn NotificationRecipientFetching,
+    >,
+    encryption_key_fetching_repo: Box<&dyn EncryptionKeyFetching>,
+    webhook_registration_repo: Box<&dyn WebHookRegistration>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<UpdatingResponseKind<PendingGuestInvitation>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check if the current account has sufficient privileges
+    // ? -----------------------------------------------------------------------
+
+    profile
+        .on_tenant(tenant_id)
+        .on_account(account_id)
+        .with_system_accounts_access()
+        .with_write_access()
+        .with_roles(vec![
+            SystemActor::TenantManager,
+            SystemActor::SubscriptionsManager,
+        ])
+        .get_related_accounts_or_tenant_wide_permission_or_error(
+            tenant_id,
+            Permission::Write,
+        )?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Fetch the pending invitation
+    // ? -----------------------------------------------------------------------
+
+    let invitation = match guest_user_fetching_repo
+        .list_account_invitations(account_id)
+        .await?
+    {
+        FetchManyResponseKind::Found(records) => records,
+        FetchManyResponseKind::FoundPaginated { records, .. } => records,
+        FetchManyResponseKind::NotFound => vec![],
+    }
+    .into_iter()
+    .find(|invitation| {
+        invitation.guest_user_id == guest_user_id
+            && invitation.tenant_id == Some(tenant_id)
+    });
+
+    let invitation = match invitation {
+        Some(invitation) => invitation,
+        None => {
+            return use_case_err(format!(
+                "Pending invitation not found: {guest_user_id}"
+            ))
+            .with_code(NativeErrorCodes::MYC00013)
+            .with_exp_true()
+            .as_error()
+        }
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Renew the invitation
+    // ? -----------------------------------------------------------------------
+
+    let expires_at =
+        Local::now() + life_cycle_settings.invitation_expires_in().await?;
+
+    let guest_user = match guest_user_on_account_updating_repo
+        .renew_invitation(guest_user_id, expires_at)
+        .await?
+    {
+        UpdatingResponseKind::Updated(guest_user) => guest_user,
+        UpdatingResponseKind::NotUpdated(_, msg) => {
+            return Ok(UpdatingResponseKind::NotUpdated(invitation, msg))
+        }
+    };
+
+    let invitation = PendingGuestInvitation {
+        last_sent_at: guest_user.invitation_sent_at,
+        expires_at: guest_user.invitation_expires_at,
+        ..invitation
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Notify guest user
+    // ? -----------------------------------------------------------------------
+
+    if let Err(err) = dispatch_channel_notification(
+        vec![
+            ("account_name", invitation.account_name.to_uppercase()),
+            ("role_name", invitation.role_name.to_uppercase()),
+            ("role_permissions", invitation.role_permission.to_string()),
+            (DEFAULT_TENANT_ID_KEY, tenant_id.to_string()),
+        ],
+        "email/guest-to-subscription-account",
+        life_cycle_settings,
+        invitation.email.to_owned(),
+        None,
+        message_sending_repo,
+        email_suppression_fetching_repo,
+        tenant_fetching_repo,
+        notification_recipient_fetching_repo,
+        encryption_key_fetching_repo,
+    )
+    .await
+    {
+        return use_case_err(format!("Unable to send email: {err}"))
+            .with_code(NativeErrorCodes::MYC00010)
+            .as_error();
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Register side effects
+    // ? -----------------------------------------------------------------------
+
+    emit_resource_audit_event(
+        audit_repo,
+        ResourceAuditResourceType::GuestUser,
+        account_id,
+        Some(tenant_id),
+        ResourceAuditEventKind::Updated,
+        WrittenBy::new_from_account(profile.acc_id),
+        serde_json::json!({
+            "action": "resend_guest_invitation",
+            "guestUserId": guest_user_id,
+            "guestRoleId": invitation.guest_role_id,
+            "email": invitation.email.email(),
+            "expiresAt": invitation.expires_at,
+        }),
+    )
+    .await;
+
+    register_webhook_dispatching_event(
+        Uuid::new_v4(),
+        WebHookTrigger::GuestInvitationResent,
+        GuestInvitationEvent::from(&invitation),
+        PayloadId::Uuid(guest_user_id),
+        webhook_registration_repo,
+    )
+    .await?;
+
+    Ok(UpdatingResponseKind::Updated(invitation))
+}
@@ -1,7 +1,23 @@
 use crate::domain::{
     actors::SystemActor,
-    dtos::{guest_role::Permission, profile::Profile},
-    entities::GuestUserDeletion,
+    dtos::{
+        email::Email,
+        guest_role::Permission,
+        guest_user::GuestInvitationEvent,
+        profile::Profile,
+        resource_audit_log::{
+            ResourceAuditEventKind, ResourceAuditResourceType,
+        },
+        webhook::{PayloadId, WebHookTrigger},
+        written_by::WrittenBy,
+    },
+    entities::{
+        GuestUserDeletion, ResourceAuditLogRegistration, WebHookRegistration,
+    },
+};
+use crate::use_cases::{
+    shared::audit::emit_resource_audit_event,
+    support::register_webhook_dispatching_event,
 };
 
 use mycelium_base::{
@@ -23,6 +39,8 @@ pub async fn revoke_user_guest_to_subscription_account(
     guest_role_id: Uuid,
     email: String,
     guest_user_deletion_repo: Box<&dyn GuestUserDeletion>,
+    webhook_registration_repo: Box<&dyn WebHookRegistration>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
 ) -> Result<DeletionResponseKind<(Uuid, Uuid)>, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Check if the current account has sufficient privileges
@@ -50,7 +68,44 @@ pub async fn revoke_user_guest_to_subscription_account(
     // ? Uninvite guest
     // ? -----------------------------------------------------------------------
 
-    guest_user_deletion_repo
-        .delete(guest_role_id, account_id, email)
-        .await
+    let response = guest_user_deletion_repo
+        .delete(guest_role_id, account_id, email.to_owned())
+        .await?;
+
+    if let DeletionResponseKind::Deleted = response {
+        emit_resource_audit_event(
+            audit_repo,
+            ResourceAuditResourceType::GuestUser,
+            account_id,
+            Some(tenant_id),
+            ResourceAuditEventKind::Deleted,
+            WrittenBy::new_from_account(profile.acc_id),
+            serde_json::json!({
+                "action": "revoke_user_guest_to_subscription_account",
+                "guestRoleId": guest_role_id,
+                "email": email,
+            }),
+        )
+        .await;
+
+        if let Ok(email) = Email::from_string(email) {
+            register_webhook_dispatching_event(
+                Uuid::new_v4(),
+                WebHookTrigger::GuestUserRevoked,
+                GuestInvitationEvent {
+                    guest_user_id: None,
+                    email,
+                    account_id,
+                    tenant_id: Some(tenant_id),
+                    guest_role_id,
+                    expires_at: None,
+                },
+                PayloadId::Uuid(account_id),
+                webhook_registration_repo,
+            )
+            .await?;
+        }
+    }
+
+    Ok(response)
 }
@@ -197,6 +197,7 @@ mod tests {
             account::{Account, VerboseStatus},
             account_type::AccountType,
             guest_role::Permission,
+            guest_user::GuestUser,
             profile::{LicensedResource, LicensedResources, Profile},
             related_accounts::RelatedAccounts,
             telegram::TelegramUserId,
@@ -205,7 +206,7 @@ mod tests {
     };
 
     use async_trait::async_trait;
-    use chrono::Local;
+    use chrono::{DateTime, Local};
     use mycelium_base::entities::{
         FetchManyResponseKind, FetchResponseKind, UpdatingResponseKind,
     };
@@ -242,6 +243,8 @@ mod tests {
             created: Local::now(),
             permit_flags,
             deny_flags,
+            valid_from: None,
+            valid_until: None,
         }
     }
 
@@ -284,6 +287,8 @@ mod tests {
                 verified: true,
                 permit_flags: None,
                 deny_flags: None,
+                permissions: None,
+                valid_until: None,
             }])),
             None, // tenants_ownership
         )
@@ -478,6 +483,25 @@ mod tests {
                 self.updated_record.clone().unwrap_or(record),
             ))
         }
+
+        async fn update_validity(
+            &self,
+            _: Uuid,
+            _: Uuid,
+            _: Option<DateTime<Local>>,
+            _: Option<DateTime<Local>>,
+        ) -> Result<UpdatingResponseKind<GuestUserOnAccount>, MappedErrors>
+        {
+            unimplemented!()
+        }
+
+        async fn renew_invitation(
+            &self,
+            _: Uuid,
+            _: DateTime<Local>,
+        ) -> Result<UpdatingResponseKind<GuestUser>, MappedErrors> {
+            unimplemented!()
+        }
     }
 
     // ? -----------------------------------------------------------------------
@@ -1039,6 +1063,8 @@ mod tests {
                 verified: true,
                 permit_flags: None,
                 deny_flags: None,
+                permissions: None,
+                valid_until: None,
             });
 
             profile = Profile::new(
@@ -1058,6 +1084,8 @@ mod tests {
                     id: tenant_id,
                     name: "Test Tenant".to_string(),
                     since: Local::now(),
+                    parent_id: None,
+                    inherited_from: None,
                 }])),
             );
         }
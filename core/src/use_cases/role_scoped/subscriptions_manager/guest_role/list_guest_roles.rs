@@ -13,6 +13,9 @@ use mycelium_base::{
 use uuid::Uuid;
 
 /// List guest roles
+///
+/// Lists the instance-wide roles and, when `tenant_id` is informed, the roles
+/// of the tenant.
 #[tracing::instrument(name = "list_guest_roles", skip_all)]
 pub async fn list_guest_roles(
     profile: Profile,
@@ -42,6 +45,6 @@ pub async fn list_guest_roles(
     // ? -----------------------------------------------------------------------
 
     guest_role_fetching_repo
-        .list(name, slug, system, page_size, skip)
+        .list(tenant_id, name, slug, system, page_size, skip)
         .await
 }
@@ -1,4 +1,5 @@
 pub mod account;
+pub mod bulk_import;
 pub mod guest;
 pub mod guest_role;
 pub mod tag;
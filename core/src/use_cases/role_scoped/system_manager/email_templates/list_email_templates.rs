@@ -0,0 +1,148 @@
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::{
+            email_template::EmailTemplateInfo, profile::Profile,
+            tenant::EmailTemplateKind,
+        },
+    },
+    settings::TEMPLATES,
+};
+
+use mycelium_base::{
+    entities::FetchManyResponseKind, utils::errors::MappedErrors,
+};
+use std::{collections::BTreeMap, str::FromStr};
+
+/// List the notification email templates available per locale
+///
+/// Only templates with both a body and a subject are listed, so layouts like
+/// `base.jinja` are left out. When `locale` is informed, only the templates
+/// available in that locale are listed.
+///
+/// This action should be only performed by manager or staff users.
+#[tracing::instrument(name = "list_email_templates", skip(profile))]
+pub async fn list_email_templates(
+    profile: Profile,
+    locale: Option<String>,
+) -> Result<FetchManyResponseKind<EmailTemplateInfo>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check if the current account has sufficient privileges
+    // ? -----------------------------------------------------------------------
+
+    profile
+        .with_system_accounts_access()
+        .with_read_access()
+        .with_roles(vec![SystemActor::SystemManager.to_string()])
+        .get_ids_or_error()?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Collect the templates
+    // ? -----------------------------------------------------------------------
+
+    Ok(list_available_email_templates(locale))
+}
+
+/// List the notification email templates available per locale, without
+/// checking the caller privileges
+///
+/// Used by the CLI, which works on the local templates directory.
+pub fn list_available_email_templates(
+    locale: Option<String>,
+) -> FetchManyResponseKind<EmailTemplateInfo> {
+    let templates = collect_email_templates(
+        TEMPLATES.get_template_names(),
+        locale.map(|locale| locale.trim().to_lowercase()).as_deref(),
+    );
+
+    if templates.is_empty() {
+        return FetchManyResponseKind::NotFound;
+    }
+
+    FetchManyResponseKind::Found(templates)
+}
+
+/// Group the `<locale>/email/<name>.subject` template names by email
+fn collect_email_templates<'a>(
+    template_names: impl Iterator<Item = &'a str>,
+    locale: Option<&str>,
+) -> Vec<EmailTemplateInfo> {
+    let mut locales_by_name = BTreeMap::<String, Vec<String>>::new();
+
+    for template_name in template_names {
+        let Some((template_locale, path)) = template_name.split_once('/')
+        else {
+            continue;
+        };
+
+        let Some(name) = path
+            .strip_prefix("email/")
+            .and_then(|path| path.strip_suffix(".subject"))
+        else {
+            continue;
+        };
+
+        locales_by_name
+            .entry(name.to_string())
+            .or_default()
+            .push(template_locale.to_string());
+    }
+
+    locales_by_name
+        .into_iter()
+        .filter(|(_, locales)| {
+            locale.is_none_or(|locale| locales.iter().any(|l| l == locale))
+        })
+        .map(|(name, mut locales)| {
+            locales.sort();
+
+            EmailTemplateInfo {
+                tenant_overridable: EmailTemplateKind::from_str(&name).is_ok(),
+                parameters: EmailTemplateInfo::sample_parameters(&name)
+                    .into_iter()
+                    .map(|(key, _)| key.to_string())
+                    .collect(),
+                name,
+                locales,
+            }
+        })
+        .collect()
+}
+
+// ? ---------------------------------------------------------------------------
+// ? TESTS
+// ? ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NAMES: [&str; 7] = [
+        "en-us/email/base.jinja",
+        "en-us/email/magic-link-request.jinja",
+        "en-us/email/magic-link-request.subject",
+        "pt-br/email/magic-link-request.subject",
+        "en-us/email/mfa-disable.subject",
+        "web/index.html",
+        "README",
+    ];
+
+    #[test]
+    fn templates_are_grouped_by_name_with_their_locales() {
+        let templates = collect_email_templates(NAMES.into_iter(), None);
+
+        assert_eq!(templates.len(), 2);
+        assert_eq!(templates[0].name, "magic-link-request");
+        assert_eq!(templates[0].locales, vec!["en-us", "pt-br"]);
+        assert!(templates[0].tenant_overridable);
+        assert_eq!(templates[0].parameters, vec!["magic_link_url"]);
+        assert_eq!(templates[1].name, "mfa-disable");
+        assert!(!templates[1].tenant_overridable);
+
+        let templates =
+            collect_email_templates(NAMES.into_iter(), Some("pt-br"));
+
+        assert_eq!(templates.len(), 1);
+        assert_eq!(templates[0].name, "magic-link-request");
+    }
+}
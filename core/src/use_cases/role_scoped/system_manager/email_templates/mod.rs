@@ -0,0 +1,19 @@
+// All actions listed below should ve performed by:
+//
+// - System Manager
+//
+// The above cited roles should be able to exercise the notification email
+// templates without a real flow firing them:
+//
+// - List the available templates per locale;
+// - Render a template with sample or supplied parameters;
+// - Send a test message of a template to a given address;
+//
+
+mod list_email_templates;
+mod render_email_template;
+mod send_test_email;
+
+pub use list_email_templates::*;
+pub use render_email_template::*;
+pub use send_test_email::*;
@@ -0,0 +1,170 @@
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::{
+            email::Email, email_template::EmailTemplateInfo, profile::Profile,
+            tenant::RenderedEmailTemplate,
+        },
+        entities::TenantFetching,
+    },
+    models::AccountLifeCycle,
+    settings::{DEFAULT_TENANT_ID_KEY, TEMPLATES},
+    use_cases::support::{
+        populate_tenant_info, render_file_templates, render_text_body,
+        RenderedNotification,
+    },
+};
+
+use mycelium_base::utils::errors::{use_case_err, MappedErrors};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Render a notification email template to HTML and text
+///
+/// The template is rendered with the sample parameters of the email, replaced
+/// by the `parameters` informed. When `tenant_id` is informed, the tenant
+/// name, links and locale are used as on dispatching. Tenant overrides are
+/// not applied, see `preview_tenant_email_template` for them.
+///
+/// This action should be only performed by manager or staff users.
+#[tracing::instrument(
+    name = "render_email_template",
+    skip(profile, parameters, config, tenant_fetching_repo)
+)]
+pub async fn render_email_template(
+    profile: Profile,
+    name: String,
+    locale: Option<String>,
+    parameters: Option<HashMap<String, String>>,
+    tenant_id: Option<Uuid>,
+    config: AccountLifeCycle,
+    tenant_fetching_repo: Box<&dyn TenantFetching>,
+) -> Result<RenderedEmailTemplate, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check if the current account has sufficient privileges
+    // ? -----------------------------------------------------------------------
+
+    profile
+        .with_system_accounts_access()
+        .with_read_access()
+        .with_roles(vec![SystemActor::SystemManager.to_string()])
+        .get_ids_or_error()?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Render the template
+    // ? -----------------------------------------------------------------------
+
+    preview_email_template(
+        name,
+        locale,
+        parameters,
+        tenant_id,
+        config,
+        tenant_fetching_repo,
+    )
+    .await
+}
+
+/// Render a notification email template to HTML and text, without checking
+/// the caller privileges
+///
+/// Used by the CLI. See `render_email_template` for the rendering rules.
+pub async fn preview_email_template(
+    name: String,
+    locale: Option<String>,
+    parameters: Option<HashMap<String, String>>,
+    tenant_id: Option<Uuid>,
+    config: AccountLifeCycle,
+    tenant_fetching_repo: Box<&dyn TenantFetching>,
+) -> Result<RenderedEmailTemplate, MappedErrors> {
+    let rendered = render_system_email_template(
+        &name,
+        locale,
+        parameters,
+        tenant_id,
+        &config,
+        *tenant_fetching_repo,
+    )
+    .await?;
+
+    Ok(RenderedEmailTemplate {
+        subject: rendered.subject,
+        body: rendered.body,
+        text_body: rendered.text_body,
+    })
+}
+
+/// Render a file template as `dispatch_notification` would
+///
+/// The locale falls back to the tenant locale, the configured locale and
+/// `en-us`, in that order.
+pub(super) async fn render_system_email_template(
+    name: &str,
+    locale: Option<String>,
+    parameters: Option<HashMap<String, String>>,
+    tenant_id: Option<Uuid>,
+    config: &AccountLifeCycle,
+    tenant_fetching_repo: &dyn TenantFetching,
+) -> Result<RenderedNotification, MappedErrors> {
+    let name = name.trim().to_lowercase();
+
+    if !TEMPLATES
+        .get_template_names()
+        .any(|template| template == format!("en-us/email/{name}.subject"))
+    {
+        return use_case_err(format!("Unknown email template: {name}"))
+            .with_exp_true()
+            .as_error();
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Build the context as on dispatching
+    // ? -----------------------------------------------------------------------
+
+    let supplied = parameters.unwrap_or_default();
+
+    let mut context_parameters: Vec<(String, String)> =
+        EmailTemplateInfo::sample_parameters(&name)
+            .into_iter()
+            .filter(|(key, _)| !supplied.contains_key(*key))
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+
+    context_parameters.extend(supplied);
+
+    if let Some(tenant_id) = tenant_id {
+        context_parameters
+            .push((DEFAULT_TENANT_ID_KEY.to_string(), tenant_id.to_string()));
+    }
+
+    let (context, tenant_locale, tenant_id) =
+        populate_tenant_info(&context_parameters, config, tenant_fetching_repo)
+            .await?;
+
+    let locale = match (locale, tenant_locale, &config.locale) {
+        (Some(locale), _, _) => locale.trim().to_lowercase(),
+        (None, Some(locale), _) => locale,
+        (None, None, Some(locale)) => locale.async_get_or_error().await?,
+        (None, None, None) => "en-us".to_string(),
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Render the subject and bodies
+    // ? -----------------------------------------------------------------------
+
+    let (subject, body) =
+        render_file_templates(&format!("email/{name}"), locale, &context)?;
+
+    let reply_to = context
+        .get("support_email")
+        .and_then(|value| value.as_str())
+        .and_then(|email| Email::from_string(email.to_string()).ok());
+
+    Ok(RenderedNotification {
+        text_body: render_text_body(&body),
+        subject,
+        body,
+        reply_to,
+        tenant_id,
+    })
+}
@@ -0,0 +1,137 @@
+use super::render_email_template::render_system_email_template;
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::{email::Email, profile::Profile},
+        entities::{
+            EmailSuppressionFetching, LocalMessageWrite, TenantFetching,
+        },
+    },
+    models::AccountLifeCycle,
+    use_cases::support::queue_notification_email,
+};
+
+use mycelium_base::{
+    entities::CreateResponseKind, utils::errors::MappedErrors,
+};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Prefix of the subject of test messages
+const TEST_SUBJECT_PREFIX: &str = "[TEST]";
+
+/// Send a test message of a notification email template
+///
+/// The template is rendered as in `render_email_template` and queued to the
+/// email dispatcher, which delivers it through the configured transport. The
+/// subject is prefixed with `[TEST]`. Suppressed addresses are not emailed.
+///
+/// Returns the id of the queued message, used to track its delivery status.
+///
+/// This action should be only performed by manager or staff users.
+#[tracing::instrument(
+    name = "send_test_email",
+    skip(
+        profile,
+        parameters,
+        config,
+        local_message_write_repo,
+        email_suppression_fetching_repo,
+        tenant_fetching_repo
+    )
+)]
+pub async fn send_test_email(
+    profile: Profile,
+    name: String,
+    locale: Option<String>,
+    parameters: Option<HashMap<String, String>>,
+    tenant_id: Option<Uuid>,
+    to: Email,
+    config: AccountLifeCycle,
+    local_message_write_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
+    tenant_fetching_repo: Box<&dyn TenantFetching>,
+) -> Result<CreateResponseKind<Option<Uuid>>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check if the current account has sufficient privileges
+    // ? -----------------------------------------------------------------------
+
+    profile
+        .with_system_accounts_access()
+        .with_write_access()
+        .with_roles(vec![SystemActor::SystemManager.to_string()])
+        .get_ids_or_error()?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Queue the test message
+    // ? -----------------------------------------------------------------------
+
+    queue_test_email(
+        name,
+        locale,
+        parameters,
+        tenant_id,
+        to,
+        config,
+        local_message_write_repo,
+        email_suppression_fetching_repo,
+        tenant_fetching_repo,
+    )
+    .await
+}
+
+/// Queue a test message of a notification email template, without checking
+/// the caller privileges
+///
+/// Used by the CLI. See `send_test_email` for the sending rules.
+#[tracing::instrument(
+    name = "queue_test_email",
+    skip(
+        parameters,
+        config,
+        local_message_write_repo,
+        email_suppression_fetching_repo,
+        tenant_fetching_repo
+    )
+)]
+pub async fn queue_test_email(
+    name: String,
+    locale: Option<String>,
+    parameters: Option<HashMap<String, String>>,
+    tenant_id: Option<Uuid>,
+    to: Email,
+    config: AccountLifeCycle,
+    local_message_write_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
+    tenant_fetching_repo: Box<&dyn TenantFetching>,
+) -> Result<CreateResponseKind<Option<Uuid>>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Render the template
+    // ? -----------------------------------------------------------------------
+
+    let mut rendered = render_system_email_template(
+        &name,
+        locale,
+        parameters,
+        tenant_id,
+        &config,
+        *tenant_fetching_repo,
+    )
+    .await?;
+
+    rendered.subject = format!("{TEST_SUBJECT_PREFIX} {}", rendered.subject);
+
+    // ? -----------------------------------------------------------------------
+    // ? Queue the message
+    // ? -----------------------------------------------------------------------
+
+    queue_notification_email(
+        rendered,
+        config,
+        to,
+        None,
+        *local_message_write_repo,
+        *email_suppression_fetching_repo,
+    )
+    .await
+}
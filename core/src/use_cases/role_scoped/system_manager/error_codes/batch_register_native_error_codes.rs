@@ -1,6 +1,6 @@
 use crate::domain::{
     dtos::{error_code::ErrorCode, native_error_codes::NativeErrorCodes},
-    entities::ErrorCodeRegistration,
+    entities::{ErrorCodeRegistration, ErrorCodeTranslationRegistration},
 };
 
 use futures::future::join_all;
@@ -16,12 +16,19 @@ use tracing::error;
 pub struct ErrorPersistenceStatistics {
     pub persisted_errors: i32,
     pub unpersisted_errors: Vec<ErrorCode>,
+    pub persisted_translations: i32,
 }
 
 /// Persist all native error codes in the data repository
+///
+/// The seeded translations of the native messages are registered too, without
+/// replacing translations already customized by system managers.
 #[tracing::instrument(name = "batch_register_native_error_codes", skip_all)]
 pub async fn batch_register_native_error_codes(
     error_code_registration_repo: Box<&dyn ErrorCodeRegistration>,
+    error_code_translation_registration_repo: Box<
+        &dyn ErrorCodeTranslationRegistration,
+    >,
 ) -> Result<ErrorPersistenceStatistics, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Try to register errors
@@ -57,6 +64,27 @@ pub async fn batch_register_native_error_codes(
             }
         });
 
+    // ? -----------------------------------------------------------------------
+    // ? Try to register translations
+    // ? -----------------------------------------------------------------------
+
+    let mut persisted_translations: i32 = 0;
+
+    let translation_operations = NativeErrorCodes::to_error_code_translations()
+        .into_iter()
+        .map(|translation| {
+            error_code_translation_registration_repo.create(translation)
+        });
+
+    join_all(translation_operations)
+        .await
+        .into_iter()
+        .for_each(|response| match response {
+            Err(err) => error!("{}", err.to_string()),
+            Ok(CreateResponseKind::Created(_)) => persisted_translations += 1,
+            Ok(CreateResponseKind::NotCreated(..)) => (),
+        });
+
     // ? -----------------------------------------------------------------------
     // ? Return a positive response
     // ? -----------------------------------------------------------------------
@@ -64,5 +92,6 @@ pub async fn batch_register_native_error_codes(
     Ok(ErrorPersistenceStatistics {
         persisted_errors,
         unpersisted_errors,
+        persisted_translations,
     })
 }
@@ -1,8 +1,17 @@
 use crate::domain::{
     actors::SystemActor,
-    dtos::{native_error_codes::NativeErrorCodes, profile::Profile},
-    entities::ErrorCodeDeletion,
+    dtos::{
+        error_code::ErrorCode,
+        native_error_codes::NativeErrorCodes,
+        profile::Profile,
+        resource_audit_log::{
+            ResourceAuditEventKind, ResourceAuditResourceType,
+        },
+        written_by::WrittenBy,
+    },
+    entities::{ErrorCodeDeletion, ResourceAuditLogRegistration},
 };
+use crate::use_cases::shared::audit::emit_resource_audit_event;
 
 use mycelium_base::{
     entities::DeletionResponseKind,
@@ -14,13 +23,14 @@ use mycelium_base::{
 /// This action should be only performed by manager or staff users.
 #[tracing::instrument(
     name = "delete_error_code",
-    skip(profile, error_code_deletion_repo)
+    skip(profile, error_code_deletion_repo, audit_repo)
 )]
 pub async fn delete_error_code(
     profile: Profile,
     prefix: String,
     code: i32,
     error_code_deletion_repo: Box<&dyn ErrorCodeDeletion>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
 ) -> Result<(String, i32), MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Check if the current account has sufficient privileges
@@ -36,16 +46,30 @@ pub async fn delete_error_code(
     // ? Try to delete error code
     // ? -----------------------------------------------------------------------
 
-    match error_code_deletion_repo
+    if let DeletionResponseKind::NotDeleted(_, msg) = error_code_deletion_repo
         .delete(prefix.to_owned(), code)
         .await?
     {
-        DeletionResponseKind::Deleted => Ok((prefix, code)),
-        DeletionResponseKind::NotDeleted(_, msg) => {
-            return use_case_err(msg)
-                .with_code(NativeErrorCodes::MYC00018)
-                .with_exp_true()
-                .as_error()
-        }
+        return use_case_err(msg)
+            .with_code(NativeErrorCodes::MYC00018)
+            .with_exp_true()
+            .as_error();
     }
+
+    emit_resource_audit_event(
+        audit_repo,
+        ResourceAuditResourceType::ErrorCode,
+        ErrorCode::audit_resource_id(&prefix, code),
+        None,
+        ResourceAuditEventKind::Deleted,
+        WrittenBy::new_from_account(profile.acc_id),
+        serde_json::json!({
+            "action": "delete_error_code",
+            "prefix": prefix,
+            "errorNumber": code,
+        }),
+    )
+    .await;
+
+    Ok((prefix, code))
 }
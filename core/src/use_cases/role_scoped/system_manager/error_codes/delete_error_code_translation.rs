@@ -0,0 +1,85 @@
+use crate::domain::{
+    actors::SystemActor,
+    dtos::{
+        error_code::{ErrorCode, ErrorCodeTranslation},
+        native_error_codes::NativeErrorCodes,
+        profile::Profile,
+        resource_audit_log::{
+            ResourceAuditEventKind, ResourceAuditResourceType,
+        },
+        written_by::WrittenBy,
+    },
+    entities::{
+        ErrorCodeTranslationRegistration, ResourceAuditLogRegistration,
+    },
+};
+use crate::use_cases::shared::audit::emit_resource_audit_event;
+
+use mycelium_base::{
+    entities::DeletionResponseKind,
+    utils::errors::{use_case_err, MappedErrors},
+};
+
+/// Delete the translation of an error code for a locale
+///
+/// Callers preferring the locale get the default message afterwards. This
+/// action should be only performed by manager or staff users.
+#[tracing::instrument(
+    name = "delete_error_code_translation",
+    skip(profile, error_code_translation_registration_repo, audit_repo)
+)]
+pub async fn delete_error_code_translation(
+    profile: Profile,
+    prefix: String,
+    code: i32,
+    locale: String,
+    error_code_translation_registration_repo: Box<
+        &dyn ErrorCodeTranslationRegistration,
+    >,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<(), MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check if the current account has sufficient privileges
+    // ? -----------------------------------------------------------------------
+
+    profile
+        .with_system_accounts_access()
+        .with_write_access()
+        .with_roles(vec![SystemActor::SystemManager.to_string()])
+        .get_ids_or_error()?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Try to delete the translation
+    // ? -----------------------------------------------------------------------
+
+    let locale = ErrorCodeTranslation::normalize_locale(&locale);
+
+    if let DeletionResponseKind::NotDeleted(_, msg) =
+        error_code_translation_registration_repo
+            .delete(prefix.to_owned(), code, locale.to_owned())
+            .await?
+    {
+        return use_case_err(msg)
+            .with_code(NativeErrorCodes::MYC00018)
+            .with_exp_true()
+            .as_error();
+    }
+
+    emit_resource_audit_event(
+        audit_repo,
+        ResourceAuditResourceType::ErrorCode,
+        ErrorCode::audit_resource_id(&prefix, code),
+        None,
+        ResourceAuditEventKind::Updated,
+        WrittenBy::new_from_account(profile.acc_id),
+        serde_json::json!({
+            "action": "delete_error_code_translation",
+            "prefix": prefix,
+            "errorNumber": code,
+            "locale": locale,
+        }),
+    )
+    .await;
+
+    Ok(())
+}
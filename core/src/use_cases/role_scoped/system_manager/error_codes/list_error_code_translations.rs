@@ -0,0 +1,43 @@
+use crate::domain::{
+    actors::SystemActor,
+    dtos::{error_code::ErrorCodeTranslation, profile::Profile},
+    entities::ErrorCodeTranslationFetching,
+};
+
+use mycelium_base::{
+    entities::FetchManyResponseKind, utils::errors::MappedErrors,
+};
+
+/// List the translations of a single error code
+///
+/// This action should be only performed by manager or staff users.
+#[tracing::instrument(
+    name = "list_error_code_translations",
+    skip(profile, error_code_translation_fetching_repo)
+)]
+pub async fn list_error_code_translations(
+    profile: Profile,
+    prefix: String,
+    code: i32,
+    error_code_translation_fetching_repo: Box<
+        &dyn ErrorCodeTranslationFetching,
+    >,
+) -> Result<FetchManyResponseKind<ErrorCodeTranslation>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check if the current account has sufficient privileges
+    // ? -----------------------------------------------------------------------
+
+    profile
+        .with_system_accounts_access()
+        .with_read_access()
+        .with_roles(vec![SystemActor::SystemManager.to_string()])
+        .get_ids_or_error()?;
+
+    // ? -----------------------------------------------------------------------
+    // ? List translations
+    // ? -----------------------------------------------------------------------
+
+    error_code_translation_fetching_repo
+        .list(prefix, code)
+        .await
+}
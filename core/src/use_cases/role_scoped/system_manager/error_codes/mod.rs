@@ -1,13 +1,19 @@
 mod batch_register_native_error_codes;
 mod delete_error_code;
+mod delete_error_code_translation;
 mod get_error_code;
+mod list_error_code_translations;
 mod list_error_codes;
 mod register_error_code;
+mod set_error_code_translation;
 mod update_error_code_message_and_details;
 
 pub use batch_register_native_error_codes::batch_register_native_error_codes;
 pub use delete_error_code::delete_error_code;
+pub use delete_error_code_translation::delete_error_code_translation;
 pub use get_error_code::get_error_code;
+pub use list_error_code_translations::list_error_code_translations;
 pub use list_error_codes::list_error_codes;
 pub use register_error_code::register_error_code;
+pub use set_error_code_translation::set_error_code_translation;
 pub use update_error_code_message_and_details::update_error_code_message_and_details;
@@ -1,8 +1,16 @@
 use crate::domain::{
     actors::SystemActor,
-    dtos::{error_code::ErrorCode, profile::Profile},
-    entities::ErrorCodeRegistration,
+    dtos::{
+        error_code::ErrorCode,
+        profile::Profile,
+        resource_audit_log::{
+            ResourceAuditEventKind, ResourceAuditResourceType,
+        },
+        written_by::WrittenBy,
+    },
+    entities::{ErrorCodeRegistration, ResourceAuditLogRegistration},
 };
+use crate::use_cases::shared::audit::emit_resource_audit_event;
 
 use mycelium_base::{
     entities::CreateResponseKind,
@@ -14,7 +22,7 @@ use mycelium_base::{
 /// This action should be only performed by manager or staff users.
 #[tracing::instrument(
     name = "register_error_code",
-    skip(profile, message, details, error_code_registration_repo)
+    skip(profile, message, details, error_code_registration_repo, audit_repo)
 )]
 pub async fn register_error_code(
     profile: Profile,
@@ -23,6 +31,7 @@ pub async fn register_error_code(
     details: Option<String>,
     is_internal: bool,
     error_code_registration_repo: Box<&dyn ErrorCodeRegistration>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
 ) -> Result<ErrorCode, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Check if the current account has sufficient privileges
@@ -51,12 +60,33 @@ pub async fn register_error_code(
     // ? Register error code
     // ? -----------------------------------------------------------------------
 
-    match error_code_registration_repo.create(error_code).await? {
-        CreateResponseKind::Created(error_code) => Ok(error_code),
-        CreateResponseKind::NotCreated(_, msg) => {
-            return use_case_err(msg).as_error()
-        }
-    }
+    let error_code =
+        match error_code_registration_repo.create(error_code).await? {
+            CreateResponseKind::Created(error_code) => error_code,
+            CreateResponseKind::NotCreated(_, msg) => {
+                return use_case_err(msg).as_error()
+            }
+        };
+
+    emit_resource_audit_event(
+        audit_repo,
+        ResourceAuditResourceType::ErrorCode,
+        ErrorCode::audit_resource_id(
+            &error_code.prefix,
+            error_code.error_number,
+        ),
+        None,
+        ResourceAuditEventKind::Created,
+        WrittenBy::new_from_account(profile.acc_id),
+        serde_json::json!({
+            "action": "register_error_code",
+            "prefix": error_code.prefix,
+            "errorNumber": error_code.error_number,
+        }),
+    )
+    .await;
+
+    Ok(error_code)
 }
 
 // * ---------------------------------------------------------------------------
@@ -71,7 +101,7 @@ mod tests {
             error_code::ErrorCode,
             profile::{Owner, Profile},
         },
-        entities::ErrorCodeRegistration,
+        entities::{ErrorCodeRegistration, MockResourceAuditLogRegistration},
     };
 
     use async_trait::async_trait;
@@ -143,6 +173,15 @@ mod tests {
 
         let details = Some("details".to_string());
 
+        let mut audit_mock = MockResourceAuditLogRegistration::new();
+        audit_mock
+            .expect_create()
+            .times(1)
+            .withf(|event| {
+                event.resource_id == ErrorCode::audit_resource_id("TEST", 0)
+            })
+            .returning(|_| Ok(()));
+
         let error_code = register_error_code(
             profile,
             "TEST".to_string(),
@@ -152,6 +191,7 @@ mod tests {
             Box::new(&MockErrorCodeRegistrationRepo {
                 generate_error: false,
             }),
+            Box::new(&audit_mock),
         )
         .await
         .unwrap();
@@ -189,6 +229,9 @@ mod tests {
             None,
         );
 
+        let mut audit_mock = MockResourceAuditLogRegistration::new();
+        audit_mock.expect_create().times(0);
+
         let error_code = register_error_code(
             profile,
             "".to_string(),
@@ -198,6 +241,7 @@ mod tests {
             Box::new(&MockErrorCodeRegistrationRepo {
                 generate_error: false,
             }),
+            Box::new(&audit_mock),
         )
         .await;
 
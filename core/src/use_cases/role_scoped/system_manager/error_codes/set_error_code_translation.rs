@@ -0,0 +1,123 @@
+use crate::domain::{
+    actors::SystemActor,
+    dtos::{
+        error_code::{ErrorCode, ErrorCodeTranslation},
+        native_error_codes::NativeErrorCodes,
+        profile::Profile,
+        resource_audit_log::{
+            ResourceAuditEventKind, ResourceAuditResourceType,
+        },
+        written_by::WrittenBy,
+    },
+    entities::{
+        ErrorCodeFetching, ErrorCodeTranslationRegistration,
+        ResourceAuditLogRegistration,
+    },
+};
+use crate::use_cases::shared::audit::emit_resource_audit_event;
+
+use mycelium_base::{
+    entities::FetchResponseKind,
+    utils::errors::{use_case_err, MappedErrors},
+};
+
+/// Set the translation of an error code for a locale
+///
+/// An existing translation for the same locale is replaced. This action
+/// should be only performed by manager or staff users.
+#[tracing::instrument(
+    name = "set_error_code_translation",
+    skip(
+        profile,
+        message,
+        details,
+        error_code_fetching_repo,
+        error_code_translation_registration_repo,
+        audit_repo
+    )
+)]
+pub async fn set_error_code_translation(
+    profile: Profile,
+    prefix: String,
+    code: i32,
+    locale: String,
+    message: String,
+    details: Option<String>,
+    error_code_fetching_repo: Box<&dyn ErrorCodeFetching>,
+    error_code_translation_registration_repo: Box<
+        &dyn ErrorCodeTranslationRegistration,
+    >,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<ErrorCodeTranslation, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check if the current account has sufficient privileges
+    // ? -----------------------------------------------------------------------
+
+    profile
+        .with_system_accounts_access()
+        .with_write_access()
+        .with_roles(vec![SystemActor::SystemManager.to_string()])
+        .get_ids_or_error()?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Validate the translation
+    // ? -----------------------------------------------------------------------
+
+    let translation =
+        ErrorCodeTranslation::new(prefix, code, &locale, message, details);
+
+    if translation.locale.is_empty()
+        || !translation
+            .locale
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-')
+    {
+        return use_case_err(format!("Invalid locale: {locale}"))
+            .with_exp_true()
+            .as_error();
+    }
+
+    if translation.message.trim().is_empty() {
+        return use_case_err("Translated message should not be empty")
+            .with_exp_true()
+            .as_error();
+    }
+
+    if let FetchResponseKind::NotFound(_) = error_code_fetching_repo
+        .get(translation.prefix.to_owned(), code)
+        .await?
+    {
+        return use_case_err(format!(
+            "Unable to match errors with prefix {} and code {}",
+            translation.prefix, code
+        ))
+        .with_code(NativeErrorCodes::MYC00006)
+        .as_error();
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Register the translation
+    // ? -----------------------------------------------------------------------
+
+    let translation = error_code_translation_registration_repo
+        .upsert(translation)
+        .await?;
+
+    emit_resource_audit_event(
+        audit_repo,
+        ResourceAuditResourceType::ErrorCode,
+        ErrorCode::audit_resource_id(&translation.prefix, code),
+        None,
+        ResourceAuditEventKind::Updated,
+        WrittenBy::new_from_account(profile.acc_id),
+        serde_json::json!({
+            "action": "set_error_code_translation",
+            "prefix": translation.prefix,
+            "errorNumber": code,
+            "locale": translation.locale,
+        }),
+    )
+    .await;
+
+    Ok(translation)
+}
@@ -1,11 +1,19 @@
 use crate::domain::{
     actors::SystemActor,
     dtos::{
-        error_code::ErrorCode, native_error_codes::NativeErrorCodes,
+        error_code::ErrorCode,
+        native_error_codes::NativeErrorCodes,
         profile::Profile,
+        resource_audit_log::{
+            ResourceAuditEventKind, ResourceAuditResourceType,
+        },
+        written_by::WrittenBy,
+    },
+    entities::{
+        ErrorCodeFetching, ErrorCodeUpdating, ResourceAuditLogRegistration,
     },
-    entities::{ErrorCodeFetching, ErrorCodeUpdating},
 };
+use crate::use_cases::shared::audit::emit_resource_audit_event;
 
 use mycelium_base::{
     entities::{FetchResponseKind, UpdatingResponseKind},
@@ -22,7 +30,8 @@ use mycelium_base::{
         message,
         details,
         error_code_fetching_repo,
-        error_code_updating_repo
+        error_code_updating_repo,
+        audit_repo
     )
 )]
 pub async fn update_error_code_message_and_details(
@@ -33,6 +42,7 @@ pub async fn update_error_code_message_and_details(
     details: Option<String>,
     error_code_fetching_repo: Box<&dyn ErrorCodeFetching>,
     error_code_updating_repo: Box<&dyn ErrorCodeUpdating>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
 ) -> Result<ErrorCode, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Check if the current account has sufficient privileges
@@ -70,12 +80,29 @@ pub async fn update_error_code_message_and_details(
     error_code.message = message;
     error_code.details = details;
 
-    match error_code_updating_repo.update(error_code).await? {
+    let error_code = match error_code_updating_repo.update(error_code).await? {
         UpdatingResponseKind::NotUpdated(_, msg) => {
             return use_case_err(msg)
                 .with_code(NativeErrorCodes::MYC00007)
                 .as_error();
         }
-        UpdatingResponseKind::Updated(error_code) => Ok(error_code),
-    }
+        UpdatingResponseKind::Updated(error_code) => error_code,
+    };
+
+    emit_resource_audit_event(
+        audit_repo,
+        ResourceAuditResourceType::ErrorCode,
+        ErrorCode::audit_resource_id(&prefix, code),
+        None,
+        ResourceAuditEventKind::Updated,
+        WrittenBy::new_from_account(profile.acc_id),
+        serde_json::json!({
+            "action": "update_error_code_message_and_details",
+            "prefix": prefix,
+            "errorNumber": code,
+        }),
+    )
+    .await;
+
+    Ok(error_code)
 }
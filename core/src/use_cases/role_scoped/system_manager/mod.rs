@@ -1,2 +1,3 @@
+pub mod email_templates;
 pub mod error_codes;
 pub mod webhook;
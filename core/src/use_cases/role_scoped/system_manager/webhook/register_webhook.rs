@@ -167,6 +167,14 @@ mod tests {
         ) -> Result<[u8; 32], MappedErrors> {
             Ok([0u8; 32])
         }
+
+        async fn fetch_dek(
+            &self,
+            _: Option<Uuid>,
+            _: &[u8; 32],
+        ) -> Result<Option<[u8; 32]>, MappedErrors> {
+            Ok(Some([0u8; 32]))
+        }
     }
 
     fn system_manager_profile() -> Profile {
@@ -201,6 +209,7 @@ mod tests {
             domain_url: None,
             locale: None,
             token_expiration: SecretResolver::Value(3600),
+            invitation_expiration: SecretResolver::Value(604_800),
             noreply_name: None,
             noreply_email: SecretResolver::Value(
                 "noreply@example.com".to_string(),
@@ -216,6 +225,8 @@ mod tests {
                 secret: SecretResolver::Value("test-hmac".to_string()),
             }]),
             staff_bootstrap_secret: None,
+            sms_provider: None,
+            delivery_webhook_secret: None,
         }
     }
 
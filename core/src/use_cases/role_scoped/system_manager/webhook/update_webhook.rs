@@ -239,6 +239,14 @@ mod tests {
         > {
             unimplemented!()
         }
+
+        async fn pseudonymise_execution_events(
+            &self,
+            _: String,
+            _: String,
+        ) -> Result<u64, MappedErrors> {
+            unimplemented!()
+        }
     }
 
     #[derive(Component)]
@@ -254,6 +262,14 @@ mod tests {
         ) -> Result<[u8; 32], MappedErrors> {
             Ok([0u8; 32])
         }
+
+        async fn fetch_dek(
+            &self,
+            _: Option<Uuid>,
+            _: &[u8; 32],
+        ) -> Result<Option<[u8; 32]>, MappedErrors> {
+            Ok(Some([0u8; 32]))
+        }
     }
 
     fn system_manager_profile() -> Profile {
@@ -288,6 +304,7 @@ mod tests {
             domain_url: None,
             locale: None,
             token_expiration: SecretResolver::Value(3600),
+            invitation_expiration: SecretResolver::Value(604_800),
             noreply_name: None,
             noreply_email: SecretResolver::Value(
                 "noreply@example.com".to_string(),
@@ -303,6 +320,8 @@ mod tests {
                 secret: SecretResolver::Value("test-hmac".to_string()),
             }]),
             staff_bootstrap_secret: None,
+            sms_provider: None,
+            delivery_webhook_secret: None,
         }
     }
 
@@ -1,18 +1,34 @@
 use crate::{
     domain::{
         dtos::{
-            account::VerboseStatus, account_type::AccountType, email::Email,
-            guest_role::Permission, guest_user::GuestUser,
-            native_error_codes::NativeErrorCodes, profile::Profile,
+            account::VerboseStatus,
+            account_type::AccountType,
+            email::Email,
+            guest_role::Permission,
+            guest_user::{GuestInvitationEvent, GuestUser},
+            native_error_codes::NativeErrorCodes,
+            profile::Profile,
+            resource_audit_log::{
+                ResourceAuditEventKind, ResourceAuditResourceType,
+            },
+            webhook::{PayloadId, WebHookTrigger},
+            written_by::WrittenBy,
         },
         entities::{
-            AccountFetching, GuestUserRegistration, LocalMessageWrite,
-            TenantFetching,
+            AccountFetching, EmailSuppressionFetching, EncryptionKeyFetching,
+            GuestUserRegistration, LocalMessageWrite,
+            NotificationRecipientFetching, ResourceAuditLogRegistration,
+            TenantFetching, WebHookRegistration,
         },
     },
     models::AccountLifeCycle,
     settings::DEFAULT_TENANT_ID_KEY,
-    use_cases::support::dispatch_notification,
+    use_cases::{
+        shared::audit::emit_resource_audit_event,
+        support::{
+            dispatch_channel_notification, register_webhook_dispatching_event,
+        },
+    },
 };
 
 use mycelium_base::{
@@ -37,7 +53,12 @@ use uuid::Uuid;
         account_fetching_repo,
         guest_user_registration_repo,
         message_sending_repo,
+        email_suppression_fetching_repo,
         tenant_fetching_repo,
+        notification_recipient_fetching_repo,
+        encryption_key_fetching_repo,
+        webhook_registration_repo,
+        audit_repo,
     )
 )]
 pub async fn guest_user_to_subscription_manager_account(
@@ -50,7 +71,14 @@ pub async fn guest_user_to_subscription_manager_account(
     account_fetching_repo: Box<&dyn AccountFetching>,
     guest_user_registration_repo: Box<&dyn GuestUserRegistration>,
     message_sending_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
     tenant_fetching_repo: Box<&dyn TenantFetching>,
+    notification_recipient_fetching_repo: Box<
+        &dyn NotificationRecipientFetching,
+    >,
+    encryption_key_fetching_repo: Box<&dyn EncryptionKeyFetching>,
+    webhook_registration_repo: Box<&dyn WebHookRegistration>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
 ) -> Result<GetOrCreateResponseKind<GuestUser>, MappedErrors> {
     let span: tracing::Span = tracing::Span::current();
 
@@ -132,14 +160,25 @@ pub async fn guest_user_to_subscription_manager_account(
     // ? Create guest user
     // ? -----------------------------------------------------------------------
 
-    let guest_role = match permission {
-        Permission::Read => Parent::Id(read_role_id),
-        Permission::Write => Parent::Id(write_role_id),
+    let guest_role_id = match permission {
+        Permission::Read => read_role_id,
+        Permission::Write => write_role_id,
     };
 
+    let invitation_expires_in =
+        life_cycle_settings.invitation_expires_in().await?;
+
     let guest_user = match guest_user_registration_repo
         .get_or_create(
-            GuestUser::new_unverified(email.to_owned(), guest_role, None),
+            GuestUser::new_unverified(
+                email.to_owned(),
+                Parent::Id(guest_role_id),
+                None,
+            )
+            .with_invitation(
+                WrittenBy::new_from_account(profile.acc_id),
+                invitation_expires_in,
+            ),
             account_id,
         )
         .await
@@ -153,11 +192,44 @@ pub async fn guest_user_to_subscription_manager_account(
         }
     };
 
+    if let GetOrCreateResponseKind::Created(guest) = &guest_user {
+        emit_resource_audit_event(
+            audit_repo,
+            ResourceAuditResourceType::GuestUser,
+            account_id,
+            Some(tenant_id),
+            ResourceAuditEventKind::Created,
+            WrittenBy::new_from_account(profile.acc_id),
+            serde_json::json!({
+                "action": "guest_user_to_subscription_manager_account",
+                "guestUserId": guest.id,
+                "guestRoleId": guest_role_id,
+                "email": email.email(),
+            }),
+        )
+        .await;
+
+        if let (false, Some(guest_user_id)) = (guest.was_verified, guest.id) {
+            register_webhook_dispatching_event(
+                Uuid::new_v4(),
+                WebHookTrigger::GuestInvitationCreated,
+                GuestInvitationEvent::from_guest_user(
+                    guest,
+                    account_id,
+                    Some(tenant_id),
+                )?,
+                PayloadId::Uuid(guest_user_id),
+                webhook_registration_repo,
+            )
+            .await?;
+        }
+    }
+
     // ? -----------------------------------------------------------------------
     // ? Notify guest user
     // ? -----------------------------------------------------------------------
 
-    if let Err(err) = dispatch_notification(
+    if let Err(err) = dispatch_channel_notification(
         vec![
             ("account_name", account.name.to_uppercase()),
             ("role_name", role_name.to_uppercase()),
@@ -169,7 +241,10 @@ pub async fn guest_user_to_subscription_manager_account(
         email,
         None,
         message_sending_repo,
+        email_suppression_fetching_repo,
         tenant_fetching_repo,
+        notification_recipient_fetching_repo,
+        encryption_key_fetching_repo,
     )
     .await
     {
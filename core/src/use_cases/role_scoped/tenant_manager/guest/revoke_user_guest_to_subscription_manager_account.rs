@@ -1,7 +1,16 @@
 use crate::domain::{
-    dtos::{email::Email, guest_role::Permission, profile::Profile},
-    entities::GuestUserDeletion,
+    dtos::{
+        email::Email,
+        guest_role::Permission,
+        profile::Profile,
+        resource_audit_log::{
+            ResourceAuditEventKind, ResourceAuditResourceType,
+        },
+        written_by::WrittenBy,
+    },
+    entities::{GuestUserDeletion, ResourceAuditLogRegistration},
 };
+use crate::use_cases::shared::audit::emit_resource_audit_event;
 
 use mycelium_base::{
     entities::DeletionResponseKind, utils::errors::MappedErrors,
@@ -21,6 +30,7 @@ use uuid::Uuid;
         profile,
         email,
         guest_user_deletion_repo,
+        audit_repo,
     )
 )]
 pub async fn revoke_user_guest_to_subscription_manager_account(
@@ -30,6 +40,7 @@ pub async fn revoke_user_guest_to_subscription_manager_account(
     guest_role_id: Uuid,
     email: Email,
     guest_user_deletion_repo: Box<&dyn GuestUserDeletion>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
 ) -> Result<DeletionResponseKind<(Uuid, Uuid)>, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Check if the current account has sufficient privileges
@@ -46,7 +57,26 @@ pub async fn revoke_user_guest_to_subscription_manager_account(
     // ? Uninvite guest
     // ? -----------------------------------------------------------------------
 
-    guest_user_deletion_repo
+    let response = guest_user_deletion_repo
         .delete(guest_role_id, account_id, email.to_string())
-        .await
+        .await?;
+
+    if let DeletionResponseKind::Deleted = response {
+        emit_resource_audit_event(
+            audit_repo,
+            ResourceAuditResourceType::GuestUser,
+            account_id,
+            Some(tenant_id),
+            ResourceAuditEventKind::Deleted,
+            WrittenBy::new_from_account(profile.acc_id),
+            serde_json::json!({
+                "action": "revoke_user_guest_to_subscription_manager_account",
+                "guestRoleId": guest_role_id,
+                "email": email.email(),
+            }),
+        )
+        .await;
+    }
+
+    Ok(response)
 }
@@ -0,0 +1,199 @@
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::{
+            guest_role::{GuestRole, Permission},
+            profile::Profile,
+            resource_audit_log::{
+                ResourceAuditEventKind, ResourceAuditResourceType,
+            },
+            written_by::WrittenBy,
+        },
+        entities::{GuestRoleRegistration, ResourceAuditLogRegistration},
+    },
+    use_cases::shared::audit::emit_resource_audit_event,
+};
+
+use mycelium_base::{
+    entities::GetOrCreateResponseKind, utils::errors::MappedErrors,
+};
+use uuid::Uuid;
+
+/// Create a guest role owned by the tenant
+///
+/// The role name should be unique inside the tenant, for the given
+/// permission. Other tenants may register roles with the same name.
+#[tracing::instrument(
+    name = "create_tenant_guest_role",
+    fields(profile_id = %profile.acc_id),
+    skip_all
+)]
+pub async fn create_tenant_guest_role(
+    profile: Profile,
+    tenant_id: Uuid,
+    name: String,
+    description: Option<String>,
+    permission: Option<Permission>,
+    guest_role_registration_repo: Box<&dyn GuestRoleRegistration>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<GetOrCreateResponseKind<GuestRole>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check the user permissions
+    // ? -----------------------------------------------------------------------
+
+    profile
+        .on_tenant(tenant_id)
+        .with_system_accounts_access()
+        .with_write_access()
+        .with_roles(vec![SystemActor::TenantManager])
+        .get_related_accounts_or_tenant_wide_permission_or_error(
+            tenant_id,
+            Permission::Write,
+        )?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Persist the role
+    // ? -----------------------------------------------------------------------
+
+    let response = guest_role_registration_repo
+        .get_or_create(
+            GuestRole::new(
+                None,
+                name,
+                description,
+                permission.unwrap_or(Permission::Read),
+                None,
+                false,
+            )
+            .with_tenant_id(tenant_id),
+        )
+        .await?;
+
+    if let GetOrCreateResponseKind::Created(GuestRole {
+        id: Some(role_id),
+        ..
+    }) = &response
+    {
+        emit_resource_audit_event(
+            audit_repo,
+            ResourceAuditResourceType::GuestRole,
+            *role_id,
+            Some(tenant_id),
+            ResourceAuditEventKind::Created,
+            WrittenBy::new_from_account(profile.acc_id),
+            serde_json::json!({ "action": "create_tenant_guest_role" }),
+        )
+        .await;
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        dtos::profile::{LicensedResource, LicensedResources},
+        entities::MockResourceAuditLogRegistration,
+    };
+
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct RecordingGuestRoleRegistration {
+        registered: Mutex<Option<GuestRole>>,
+    }
+
+    #[async_trait]
+    impl GuestRoleRegistration for RecordingGuestRoleRegistration {
+        async fn get_or_create(
+            &self,
+            guest_role: GuestRole,
+        ) -> Result<GetOrCreateResponseKind<GuestRole>, MappedErrors> {
+            let role = GuestRole {
+                id: Some(Uuid::new_v4()),
+                ..guest_role
+            };
+
+            *self.registered.lock().unwrap() = Some(role.clone());
+
+            Ok(GetOrCreateResponseKind::Created(role))
+        }
+    }
+
+    fn tenant_manager_profile(tenant_id: Uuid) -> Profile {
+        let mut profile = Profile::default();
+        profile.licensed_resources =
+            Some(LicensedResources::Records(vec![LicensedResource {
+                acc_id: Uuid::new_v4(),
+                tenant_id,
+                role_id: Uuid::new_v4(),
+                role: SystemActor::TenantManager.to_string(),
+                perm: Permission::Write,
+                sys_acc: true,
+                acc_name: "Tenant Manager".to_string(),
+                verified: true,
+                permit_flags: None,
+                deny_flags: None,
+                permissions: None,
+                valid_until: None,
+            }]));
+        profile
+    }
+
+    #[tokio::test]
+    async fn registers_the_role_under_the_tenant() {
+        let tenant_id = Uuid::new_v4();
+        let registration = RecordingGuestRoleRegistration::default();
+
+        let mut audit_repo = MockResourceAuditLogRegistration::new();
+        audit_repo
+            .expect_create()
+            .times(1)
+            .withf(move |event| event.tenant_id == Some(tenant_id))
+            .returning(|_| Ok(()));
+
+        let result = create_tenant_guest_role(
+            tenant_manager_profile(tenant_id),
+            tenant_id,
+            "Lab Technician".to_string(),
+            None,
+            None,
+            Box::new(&registration as &dyn GuestRoleRegistration),
+            Box::new(&audit_repo),
+        )
+        .await;
+
+        assert!(result.is_ok());
+
+        let registered = registration.registered.lock().unwrap().clone();
+        let registered = registered.unwrap();
+
+        assert_eq!(registered.tenant_id, Some(tenant_id));
+        assert_eq!(registered.slug, "lab-technician");
+        assert!(!registered.system);
+    }
+
+    #[tokio::test]
+    async fn managers_of_other_tenants_are_refused() {
+        let registration = RecordingGuestRoleRegistration::default();
+
+        let mut audit_repo = MockResourceAuditLogRegistration::new();
+        audit_repo.expect_create().times(0);
+
+        let result = create_tenant_guest_role(
+            tenant_manager_profile(Uuid::new_v4()),
+            Uuid::new_v4(),
+            "Lab Technician".to_string(),
+            None,
+            None,
+            Box::new(&registration as &dyn GuestRoleRegistration),
+            Box::new(&audit_repo),
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert!(registration.registered.lock().unwrap().is_none());
+    }
+}
@@ -0,0 +1,206 @@
+use super::fetch_tenant_guest_role;
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::{
+            guest_role::Permission,
+            profile::Profile,
+            resource_audit_log::{
+                ResourceAuditEventKind, ResourceAuditResourceType,
+            },
+            written_by::WrittenBy,
+        },
+        entities::{
+            GuestRoleDeletion, GuestRoleFetching, ResourceAuditLogRegistration,
+        },
+    },
+    use_cases::shared::audit::emit_resource_audit_event,
+};
+
+use mycelium_base::{
+    entities::DeletionResponseKind, utils::errors::MappedErrors,
+};
+use uuid::Uuid;
+
+/// Delete a tenant guest role
+///
+/// Only roles owned by the tenant may be deleted. Instance-wide roles are
+/// maintained by the guests managers.
+#[tracing::instrument(
+    name = "delete_tenant_guest_role",
+    fields(profile_id = %profile.acc_id),
+    skip_all
+)]
+pub async fn delete_tenant_guest_role(
+    profile: Profile,
+    tenant_id: Uuid,
+    guest_role_id: Uuid,
+    guest_role_fetching_repo: Box<&dyn GuestRoleFetching>,
+    guest_role_deletion_repo: Box<&dyn GuestRoleDeletion>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check the user permissions
+    // ? -----------------------------------------------------------------------
+
+    profile
+        .on_tenant(tenant_id)
+        .with_system_accounts_access()
+        .with_write_access()
+        .with_roles(vec![SystemActor::TenantManager])
+        .get_related_accounts_or_tenant_wide_permission_or_error(
+            tenant_id,
+            Permission::Write,
+        )?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Check the role ownership and delete it
+    // ? -----------------------------------------------------------------------
+
+    fetch_tenant_guest_role(
+        tenant_id,
+        guest_role_id,
+        *guest_role_fetching_repo,
+    )
+    .await?;
+
+    let response = guest_role_deletion_repo.delete(guest_role_id).await?;
+
+    if let DeletionResponseKind::Deleted = response {
+        emit_resource_audit_event(
+            audit_repo,
+            ResourceAuditResourceType::GuestRole,
+            guest_role_id,
+            Some(tenant_id),
+            ResourceAuditEventKind::Deleted,
+            WrittenBy::new_from_account(profile.acc_id),
+            serde_json::json!({ "action": "delete_tenant_guest_role" }),
+        )
+        .await;
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        dtos::guest_role::GuestRole, entities::MockResourceAuditLogRegistration,
+    };
+
+    use async_trait::async_trait;
+    use mycelium_base::entities::{FetchManyResponseKind, FetchResponseKind};
+
+    struct StubGuestRoleFetching {
+        role: GuestRole,
+    }
+
+    #[async_trait]
+    impl GuestRoleFetching for StubGuestRoleFetching {
+        async fn get(
+            &self,
+            _: Uuid,
+        ) -> Result<FetchResponseKind<GuestRole, Uuid>, MappedErrors> {
+            Ok(FetchResponseKind::Found(self.role.clone()))
+        }
+
+        async fn get_parent_by_child_id(
+            &self,
+            id: Uuid,
+        ) -> Result<FetchResponseKind<GuestRole, Uuid>, MappedErrors> {
+            Ok(FetchResponseKind::NotFound(Some(id)))
+        }
+
+        async fn list(
+            &self,
+            _: Option<Uuid>,
+            _: Option<String>,
+            _: Option<String>,
+            _: Option<bool>,
+            _: Option<i32>,
+            _: Option<i32>,
+        ) -> Result<FetchManyResponseKind<GuestRole>, MappedErrors> {
+            Ok(FetchManyResponseKind::NotFound)
+        }
+    }
+
+    struct StubGuestRoleDeletion;
+
+    #[async_trait]
+    impl GuestRoleDeletion for StubGuestRoleDeletion {
+        async fn delete(
+            &self,
+            _: Uuid,
+        ) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
+            Ok(DeletionResponseKind::Deleted)
+        }
+    }
+
+    fn staff_profile() -> Profile {
+        let mut profile = Profile::default();
+        profile.is_staff = true;
+        profile
+    }
+
+    fn role(tenant_id: Uuid) -> GuestRole {
+        GuestRole::new(
+            Some(Uuid::new_v4()),
+            "Auditor".to_string(),
+            None,
+            Permission::Read,
+            None,
+            false,
+        )
+        .with_tenant_id(tenant_id)
+    }
+
+    #[tokio::test]
+    async fn deletes_roles_of_the_tenant() {
+        let tenant_id = Uuid::new_v4();
+        let fetching = StubGuestRoleFetching {
+            role: role(tenant_id),
+        };
+
+        let mut audit_repo = MockResourceAuditLogRegistration::new();
+        audit_repo
+            .expect_create()
+            .times(1)
+            .withf(move |event| event.tenant_id == Some(tenant_id))
+            .returning(|_| Ok(()));
+
+        let result = delete_tenant_guest_role(
+            staff_profile(),
+            tenant_id,
+            Uuid::new_v4(),
+            Box::new(&fetching as &dyn GuestRoleFetching),
+            Box::new(&StubGuestRoleDeletion as &dyn GuestRoleDeletion),
+            Box::new(&audit_repo),
+        )
+        .await;
+
+        assert!(matches!(result, Ok(DeletionResponseKind::Deleted)));
+    }
+
+    #[tokio::test]
+    async fn roles_of_other_tenants_are_not_deleted() {
+        let fetching = StubGuestRoleFetching {
+            role: role(Uuid::new_v4()),
+        };
+
+        let mut audit_repo = MockResourceAuditLogRegistration::new();
+        audit_repo.expect_create().times(0);
+
+        let result = delete_tenant_guest_role(
+            staff_profile(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Box::new(&fetching as &dyn GuestRoleFetching),
+            Box::new(&StubGuestRoleDeletion as &dyn GuestRoleDeletion),
+            Box::new(&audit_repo),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+}
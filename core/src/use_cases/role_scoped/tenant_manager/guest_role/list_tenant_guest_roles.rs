@@ -0,0 +1,53 @@
+use crate::domain::{
+    actors::SystemActor,
+    dtos::{
+        guest_role::{GuestRole, Permission},
+        profile::Profile,
+    },
+    entities::GuestRoleFetching,
+};
+
+use mycelium_base::{
+    entities::FetchManyResponseKind, utils::errors::MappedErrors,
+};
+use uuid::Uuid;
+
+/// List the guest roles available to the tenant
+///
+/// Lists the roles owned by the tenant beside the instance-wide roles.
+#[tracing::instrument(
+    name = "list_tenant_guest_roles",
+    fields(profile_id = %profile.acc_id),
+    skip_all
+)]
+pub async fn list_tenant_guest_roles(
+    profile: Profile,
+    tenant_id: Uuid,
+    name: Option<String>,
+    slug: Option<String>,
+    page_size: Option<i32>,
+    skip: Option<i32>,
+    guest_role_fetching_repo: Box<&dyn GuestRoleFetching>,
+) -> Result<FetchManyResponseKind<GuestRole>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check the user permissions
+    // ? -----------------------------------------------------------------------
+
+    profile
+        .on_tenant(tenant_id)
+        .with_system_accounts_access()
+        .with_read_access()
+        .with_roles(vec![SystemActor::TenantManager])
+        .get_related_accounts_or_tenant_wide_permission_or_error(
+            tenant_id,
+            Permission::Read,
+        )?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Fetch roles
+    // ? -----------------------------------------------------------------------
+
+    guest_role_fetching_repo
+        .list(Some(tenant_id), name, slug, None, page_size, skip)
+        .await
+}
@@ -0,0 +1,51 @@
+// Tenant owners and managers maintain the guest roles catalogue of their
+// tenant. Tenant roles are listed and granted only inside the tenant, beside
+// the instance-wide roles maintained by the guests managers. Then, the accounts
+// with the above cited roles should be able to perform the following
+// functions:
+//
+// - Create tenant roles;
+// - List the roles available to the tenant;
+// - Update tenant roles;
+// - Delete tenant roles.
+//
+
+mod create_tenant_guest_role;
+mod delete_tenant_guest_role;
+mod list_tenant_guest_roles;
+mod update_tenant_guest_role;
+
+pub use create_tenant_guest_role::*;
+pub use delete_tenant_guest_role::*;
+pub use list_tenant_guest_roles::*;
+pub use update_tenant_guest_role::*;
+
+use crate::domain::{
+    dtos::{guest_role::GuestRole, native_error_codes::NativeErrorCodes},
+    entities::GuestRoleFetching,
+};
+
+use mycelium_base::{
+    entities::FetchResponseKind,
+    utils::errors::{use_case_err, MappedErrors},
+};
+use uuid::Uuid;
+
+/// Fetch a role owned by the tenant
+///
+/// Instance-wide roles and roles of other tenants are reported as not found.
+async fn fetch_tenant_guest_role(
+    tenant_id: Uuid,
+    guest_role_id: Uuid,
+    guest_role_fetching_repo: &dyn GuestRoleFetching,
+) -> Result<GuestRole, MappedErrors> {
+    match guest_role_fetching_repo.get(guest_role_id).await? {
+        FetchResponseKind::Found(role) if role.tenant_id == Some(tenant_id) => {
+            Ok(role)
+        }
+        _ => use_case_err(format!("Guest role not found: {guest_role_id}"))
+            .with_exp_true()
+            .with_code(NativeErrorCodes::MYC00013)
+            .as_error(),
+    }
+}
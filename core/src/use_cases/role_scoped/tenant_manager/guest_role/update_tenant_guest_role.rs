@@ -0,0 +1,110 @@
+use super::fetch_tenant_guest_role;
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::{
+            guest_role::{GuestRole, Permission},
+            profile::Profile,
+            resource_audit_log::{
+                ResourceAuditEventKind, ResourceAuditResourceType,
+            },
+            written_by::WrittenBy,
+        },
+        entities::{
+            GuestRoleFetching, GuestRoleUpdating, ResourceAuditLogRegistration,
+        },
+    },
+    use_cases::shared::audit::emit_resource_audit_event,
+};
+
+use mycelium_base::{
+    entities::UpdatingResponseKind, utils::errors::MappedErrors,
+};
+use slugify::slugify;
+use uuid::Uuid;
+
+/// Update the name, description or permissions of a tenant guest role
+///
+/// Only roles owned by the tenant may be updated. Instance-wide roles are
+/// maintained by the guests managers.
+#[tracing::instrument(
+    name = "update_tenant_guest_role",
+    fields(profile_id = %profile.acc_id),
+    skip_all
+)]
+pub async fn update_tenant_guest_role(
+    profile: Profile,
+    tenant_id: Uuid,
+    guest_role_id: Uuid,
+    name: Option<String>,
+    description: Option<String>,
+    permission: Option<Permission>,
+    permissions: Option<Vec<String>>,
+    guest_role_fetching_repo: Box<&dyn GuestRoleFetching>,
+    guest_role_updating_repo: Box<&dyn GuestRoleUpdating>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<UpdatingResponseKind<GuestRole>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check the user permissions
+    // ? -----------------------------------------------------------------------
+
+    profile
+        .on_tenant(tenant_id)
+        .with_system_accounts_access()
+        .with_write_access()
+        .with_roles(vec![SystemActor::TenantManager])
+        .get_related_accounts_or_tenant_wide_permission_or_error(
+            tenant_id,
+            Permission::Write,
+        )?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Fetch and update the role
+    // ? -----------------------------------------------------------------------
+
+    let mut role = fetch_tenant_guest_role(
+        tenant_id,
+        guest_role_id,
+        *guest_role_fetching_repo,
+    )
+    .await?;
+
+    if let Some(name) = name {
+        role.slug = slugify!(&name);
+        role.name = name;
+    }
+
+    if description.is_some() {
+        role.description = description;
+    }
+
+    if let Some(permission) = permission {
+        role.permission = permission;
+    }
+
+    if let Some(permissions) = permissions {
+        role = role.with_permissions(permissions)?;
+    }
+
+    let response = guest_role_updating_repo.update(role).await?;
+
+    if let UpdatingResponseKind::Updated(ref role) = response {
+        emit_resource_audit_event(
+            audit_repo,
+            ResourceAuditResourceType::GuestRole,
+            guest_role_id,
+            Some(tenant_id),
+            ResourceAuditEventKind::Updated,
+            WrittenBy::new_from_account(profile.acc_id),
+            serde_json::json!({
+                "action": "update_tenant_guest_role",
+                "name": role.name,
+                "permission": role.permission.to_string(),
+                "permissions": role.permissions,
+            }),
+        )
+        .await;
+    }
+
+    Ok(response)
+}
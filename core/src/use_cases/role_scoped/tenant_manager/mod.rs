@@ -1,9 +1,11 @@
 mod account;
 mod guest;
+mod guest_role;
 mod tag;
 mod tenant;
 
 pub use account::*;
 pub use guest::*;
+pub use guest_role::*;
 pub use tag::*;
 pub use tenant::*;
@@ -185,6 +185,8 @@ mod tests {
                 id: tenant_id,
                 name: "Tenant Name".to_string(),
                 since: Local::now(),
+                parent_id: None,
+                inherited_from: None,
             }])),
         )
     }
@@ -141,6 +141,8 @@ mod tests {
                 id: tenant_id,
                 name: "Tenant Name".to_string(),
                 since: Local::now(),
+                parent_id: None,
+                inherited_from: None,
             }])),
         )
     }
@@ -0,0 +1,75 @@
+use crate::{
+    domain::{
+        dtos::{
+            profile::Profile,
+            resource_audit_log::{
+                ResourceAuditEventKind, ResourceAuditResourceType,
+            },
+            tenant::EmailTemplateKind,
+            written_by::WrittenBy,
+        },
+        entities::{ResourceAuditLogRegistration, TenantDeletion},
+    },
+    use_cases::shared::audit::emit_resource_audit_event,
+};
+
+use mycelium_base::{
+    entities::DeletionResponseKind, utils::errors::MappedErrors,
+};
+use uuid::Uuid;
+
+/// Delete the tenant override of a notification email
+///
+/// Once deleted, the notification is rendered from the file templates again.
+#[tracing::instrument(
+    name = "delete_tenant_email_template",
+    fields(profile_id = %profile.acc_id),
+    skip(tenant_deletion_repo, audit_repo)
+)]
+pub async fn delete_tenant_email_template(
+    profile: Profile,
+    tenant_id: Uuid,
+    kind: EmailTemplateKind,
+    locale: String,
+    tenant_deletion_repo: Box<&dyn TenantDeletion>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check if the profile is the owner of the tenant
+    // ? -----------------------------------------------------------------------
+
+    profile.with_tenant_ownership_or_error(tenant_id)?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Delete the template
+    // ? -----------------------------------------------------------------------
+
+    let locale = locale.trim().to_lowercase();
+
+    let response = tenant_deletion_repo
+        .delete_email_template(tenant_id, kind, locale.to_owned())
+        .await?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Emit the audit event
+    // ? -----------------------------------------------------------------------
+
+    if let DeletionResponseKind::Deleted = &response {
+        emit_resource_audit_event(
+            audit_repo,
+            ResourceAuditResourceType::TenantMeta,
+            tenant_id,
+            Some(tenant_id),
+            ResourceAuditEventKind::Deleted,
+            WrittenBy::new_from_account(profile.acc_id),
+            serde_json::json!({
+                "action": "delete_tenant_email_template",
+                "kind": kind.to_string(),
+                "locale": locale,
+            }),
+        )
+        .await;
+    }
+
+    Ok(response)
+}
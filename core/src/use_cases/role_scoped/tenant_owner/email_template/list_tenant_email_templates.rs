@@ -0,0 +1,33 @@
+use crate::domain::{
+    dtos::{profile::Profile, tenant::TenantEmailTemplate},
+    entities::TenantFetching,
+};
+
+use mycelium_base::{
+    entities::FetchManyResponseKind, utils::errors::MappedErrors,
+};
+use uuid::Uuid;
+
+/// List the notification email overrides of a tenant
+#[tracing::instrument(
+    name = "list_tenant_email_templates",
+    fields(profile_id = %profile.acc_id),
+    skip(tenant_fetching_repo)
+)]
+pub async fn list_tenant_email_templates(
+    profile: Profile,
+    tenant_id: Uuid,
+    tenant_fetching_repo: Box<&dyn TenantFetching>,
+) -> Result<FetchManyResponseKind<TenantEmailTemplate>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check if the profile is the owner of the tenant
+    // ? -----------------------------------------------------------------------
+
+    profile.with_tenant_ownership_or_error(tenant_id)?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Fetch templates
+    // ? -----------------------------------------------------------------------
+
+    tenant_fetching_repo.list_email_templates(tenant_id).await
+}
@@ -0,0 +1,22 @@
+// All actions listed below should ve performed by:
+//
+// - Tenant Owner
+//
+// The above cited roles should be able to manage the tenant overrides of the
+// notification emails:
+//
+// - Create or replace an email template;
+// - List email templates;
+// - Delete an email template;
+// - Preview an email template;
+//
+
+mod delete_tenant_email_template;
+mod list_tenant_email_templates;
+mod preview_tenant_email_template;
+mod set_tenant_email_template;
+
+pub use delete_tenant_email_template::*;
+pub use list_tenant_email_templates::*;
+pub use preview_tenant_email_template::*;
+pub use set_tenant_email_template::*;
@@ -0,0 +1,106 @@
+use crate::{
+    domain::{
+        dtos::{
+            profile::Profile,
+            tenant::{
+                EmailTemplateKind, RenderedEmailTemplate, TenantEmailTemplate,
+            },
+        },
+        entities::TenantFetching,
+    },
+    models::AccountLifeCycle,
+    settings::DEFAULT_TENANT_ID_KEY,
+    use_cases::support::{
+        populate_tenant_info, render_file_templates, render_text_body,
+    },
+};
+
+use mycelium_base::{
+    entities::FetchResponseKind,
+    utils::errors::{use_case_err, MappedErrors},
+};
+use uuid::Uuid;
+
+/// Render a notification email as the tenant users would receive it
+///
+/// When `subject` and `body` are informed, the unsaved draft is rendered.
+/// Otherwise the stored override is rendered, or the file templates when the
+/// tenant has no override for the kind and locale. Use-case parameters (the
+/// magic link, verification codes, etc) are filled with sample values.
+#[tracing::instrument(
+    name = "preview_tenant_email_template",
+    fields(profile_id = %profile.acc_id),
+    skip(subject, body, config, tenant_fetching_repo)
+)]
+pub async fn preview_tenant_email_template(
+    profile: Profile,
+    tenant_id: Uuid,
+    kind: EmailTemplateKind,
+    locale: String,
+    subject: Option<String>,
+    body: Option<String>,
+    config: AccountLifeCycle,
+    tenant_fetching_repo: Box<&dyn TenantFetching>,
+) -> Result<RenderedEmailTemplate, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check if the profile is the owner of the tenant
+    // ? -----------------------------------------------------------------------
+
+    profile.with_tenant_ownership_or_error(tenant_id)?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Build the context as on dispatching
+    // ? -----------------------------------------------------------------------
+
+    let mut parameters =
+        vec![(DEFAULT_TENANT_ID_KEY.to_string(), tenant_id.to_string())];
+
+    parameters.extend(
+        kind.sample_parameters()
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value.to_string())),
+    );
+
+    let (context, _, _) =
+        populate_tenant_info(&parameters, &config, *tenant_fetching_repo)
+            .await?;
+
+    let locale = locale.trim().to_lowercase();
+
+    // ? -----------------------------------------------------------------------
+    // ? Render the draft, the stored override or the defaults
+    // ? -----------------------------------------------------------------------
+
+    let (subject, body) = match (subject, body) {
+        (Some(subject), Some(body)) => {
+            TenantEmailTemplate::new(tenant_id, kind, locale, subject, body)
+                .await?
+                .render(&context)
+                .await?
+        }
+        (None, None) => match tenant_fetching_repo
+            .get_email_template(tenant_id, kind, locale.to_owned())
+            .await?
+        {
+            FetchResponseKind::Found(template) => {
+                template.render(&context).await?
+            }
+            FetchResponseKind::NotFound(_) => {
+                render_file_templates(&kind.template_path(), locale, &context)?
+            }
+        },
+        _ => {
+            return use_case_err(
+                "Subject and body should be informed together to preview a \
+                 draft",
+            )
+            .as_error()
+        }
+    };
+
+    Ok(RenderedEmailTemplate {
+        text_body: render_text_body(&body),
+        subject,
+        body,
+    })
+}
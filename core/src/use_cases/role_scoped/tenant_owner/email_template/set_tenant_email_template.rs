@@ -0,0 +1,227 @@
+use crate::{
+    domain::{
+        dtos::{
+            profile::Profile,
+            resource_audit_log::{
+                ResourceAuditEventKind, ResourceAuditResourceType,
+            },
+            tenant::{EmailTemplateKind, TenantEmailTemplate},
+            written_by::WrittenBy,
+        },
+        entities::{ResourceAuditLogRegistration, TenantRegistration},
+    },
+    use_cases::shared::audit::emit_resource_audit_event,
+};
+
+use mycelium_base::utils::errors::MappedErrors;
+use uuid::Uuid;
+
+/// Create or replace the tenant override of a notification email
+///
+/// The template is validated before being stored: it should render against
+/// the sample parameters of its kind in the sandboxed engine.
+#[tracing::instrument(
+    name = "set_tenant_email_template",
+    fields(profile_id = %profile.acc_id),
+    skip(subject, body, tenant_registration_repo, audit_repo)
+)]
+pub async fn set_tenant_email_template(
+    profile: Profile,
+    tenant_id: Uuid,
+    kind: EmailTemplateKind,
+    locale: String,
+    subject: String,
+    body: String,
+    tenant_registration_repo: Box<&dyn TenantRegistration>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<TenantEmailTemplate, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check if the profile is the owner of the tenant
+    // ? -----------------------------------------------------------------------
+
+    profile.with_tenant_ownership_or_error(tenant_id)?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Validate and register the template
+    // ? -----------------------------------------------------------------------
+
+    let template =
+        TenantEmailTemplate::new(tenant_id, kind, locale, subject, body)
+            .await?;
+
+    let template = tenant_registration_repo
+        .register_email_template(template)
+        .await?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Emit the audit event
+    // ? -----------------------------------------------------------------------
+
+    emit_resource_audit_event(
+        audit_repo,
+        ResourceAuditResourceType::TenantMeta,
+        tenant_id,
+        Some(tenant_id),
+        match template.updated {
+            Some(_) => ResourceAuditEventKind::Updated,
+            None => ResourceAuditEventKind::Created,
+        },
+        WrittenBy::new_from_account(profile.acc_id),
+        serde_json::json!({
+            "action": "set_tenant_email_template",
+            "kind": kind.to_string(),
+            "locale": template.locale,
+        }),
+    )
+    .await;
+
+    Ok(template)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        dtos::{
+            profile::{TenantOwnership, TenantsOwnership},
+            tenant::{Tenant, TenantMetaKey},
+        },
+        entities::MockResourceAuditLogRegistration,
+    };
+
+    use async_trait::async_trait;
+    use chrono::Local;
+    use mycelium_base::entities::CreateResponseKind;
+    use std::collections::HashMap;
+
+    struct MockTenantRegistrationRepo;
+
+    #[async_trait]
+    impl TenantRegistration for MockTenantRegistrationRepo {
+        async fn create(
+            &self,
+            _: Tenant,
+            _: String,
+        ) -> Result<CreateResponseKind<Tenant>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn register_tenant_meta(
+            &self,
+            _: Vec<Uuid>,
+            _: Uuid,
+            _: TenantMetaKey,
+            _: String,
+        ) -> Result<CreateResponseKind<HashMap<String, String>>, MappedErrors>
+        {
+            unimplemented!()
+        }
+
+        async fn register_email_template(
+            &self,
+            template: TenantEmailTemplate,
+        ) -> Result<TenantEmailTemplate, MappedErrors> {
+            Ok(TenantEmailTemplate {
+                id: Some(Uuid::new_v4()),
+                ..template
+            })
+        }
+    }
+
+    fn profile_owning_tenant(tenant_id: Uuid) -> Profile {
+        Profile::new(
+            vec![],
+            Uuid::new_v4(),
+            false,
+            false,
+            false,
+            true,
+            true,
+            true,
+            false,
+            false,
+            None,
+            None,
+            Some(TenantsOwnership::Records(vec![TenantOwnership {
+                id: tenant_id,
+                name: "Tenant Name".to_string(),
+                since: Local::now(),
+                parent_id: None,
+                inherited_from: None,
+            }])),
+        )
+    }
+
+    #[tokio::test]
+    async fn set_tenant_email_template_stores_valid_template() {
+        let tenant_id = Uuid::new_v4();
+
+        let mut audit_mock = MockResourceAuditLogRegistration::new();
+        audit_mock
+            .expect_create()
+            .times(1)
+            .withf(move |event| {
+                event.tenant_id == Some(tenant_id)
+                    && event.event == ResourceAuditEventKind::Created
+            })
+            .returning(|_| Ok(()));
+
+        let template = set_tenant_email_template(
+            profile_owning_tenant(tenant_id),
+            tenant_id,
+            EmailTemplateKind::PasswordResetInitiated,
+            "en-us".to_string(),
+            "Reset your {{ domain_name }} password".to_string(),
+            "<p>Your code: {{ verification_code }}</p>".to_string(),
+            Box::new(&MockTenantRegistrationRepo),
+            Box::new(&audit_mock),
+        )
+        .await
+        .unwrap();
+
+        assert!(template.id.is_some());
+        assert_eq!(template.tenant_id, tenant_id);
+    }
+
+    #[tokio::test]
+    async fn set_tenant_email_template_rejects_invalid_template() {
+        let tenant_id = Uuid::new_v4();
+
+        let mut audit_mock = MockResourceAuditLogRegistration::new();
+        audit_mock.expect_create().times(0);
+
+        let result = set_tenant_email_template(
+            profile_owning_tenant(tenant_id),
+            tenant_id,
+            EmailTemplateKind::PasswordResetInitiated,
+            "en-us".to_string(),
+            "Reset your password".to_string(),
+            "<p>{{ magic_link_url }}</p>".to_string(),
+            Box::new(&MockTenantRegistrationRepo),
+            Box::new(&audit_mock),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn set_tenant_email_template_requires_ownership() {
+        let mut audit_mock = MockResourceAuditLogRegistration::new();
+        audit_mock.expect_create().times(0);
+
+        let result = set_tenant_email_template(
+            Profile::default(),
+            Uuid::new_v4(),
+            EmailTemplateKind::MagicLinkRequest,
+            "en-us".to_string(),
+            "Sign in".to_string(),
+            "<a href=\"{{ magic_link_url }}\">Sign in</a>".to_string(),
+            Box::new(&MockTenantRegistrationRepo),
+            Box::new(&audit_mock),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -122,6 +122,16 @@ mod tests {
 
             Ok(CreateResponseKind::Created(map))
         }
+
+        async fn register_email_template(
+            &self,
+            _: crate::domain::dtos::tenant::TenantEmailTemplate,
+        ) -> Result<
+            crate::domain::dtos::tenant::TenantEmailTemplate,
+            MappedErrors,
+        > {
+            unimplemented!()
+        }
     }
 
     fn profile_owning_tenant(tenant_id: Uuid) -> Profile {
@@ -142,6 +152,8 @@ mod tests {
                 id: tenant_id,
                 name: "Tenant Name".to_string(),
                 since: Local::now(),
+                parent_id: None,
+                inherited_from: None,
             }])),
         )
     }
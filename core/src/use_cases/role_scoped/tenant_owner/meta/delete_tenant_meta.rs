@@ -91,6 +91,14 @@ mod tests {
             unimplemented!()
         }
 
+        async fn purge(
+            &self,
+            _: Uuid,
+            _: chrono::DateTime<chrono::Utc>,
+        ) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
+            unimplemented!()
+        }
+
         async fn delete_owner(
             &self,
             _: Uuid,
@@ -114,6 +122,15 @@ mod tests {
 
             Ok(DeletionResponseKind::Deleted)
         }
+
+        async fn delete_email_template(
+            &self,
+            _: Uuid,
+            _: crate::domain::dtos::tenant::EmailTemplateKind,
+            _: String,
+        ) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
+            unimplemented!()
+        }
     }
 
     fn profile_owning_tenant(tenant_id: Uuid) -> Profile {
@@ -134,6 +151,8 @@ mod tests {
                 id: tenant_id,
                 name: "Tenant Name".to_string(),
                 since: Local::now(),
+                parent_id: None,
+                inherited_from: None,
             }])),
         )
     }
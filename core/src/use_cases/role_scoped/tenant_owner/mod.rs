@@ -1,9 +1,13 @@
 mod account;
+mod email_template;
 mod meta;
 mod owner;
+mod scim;
 mod tenant;
 
 pub use account::*;
+pub use email_template::*;
 pub use meta::*;
 pub use owner::*;
+pub use scim::*;
 pub use tenant::*;
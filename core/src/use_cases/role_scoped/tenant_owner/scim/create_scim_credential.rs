@@ -0,0 +1,183 @@
+use crate::{
+    domain::{
+        dtos::{
+            profile::Profile,
+            resource_audit_log::{
+                ResourceAuditEventKind, ResourceAuditResourceType,
+            },
+            scim::{IssuedScimCredential, ScimCredential},
+            written_by::WrittenBy,
+        },
+        entities::{ResourceAuditLogRegistration, ScimCredentialRegistration},
+    },
+    use_cases::shared::audit::emit_resource_audit_event,
+};
+
+use mycelium_base::{
+    entities::CreateResponseKind,
+    utils::errors::{use_case_err, MappedErrors},
+};
+use uuid::Uuid;
+
+/// Issue a bearer credential for the SCIM client of a tenant
+///
+/// The secret is returned once: only its hash is stored.
+#[tracing::instrument(
+    name = "create_scim_credential",
+    fields(profile_id = %profile.acc_id),
+    skip(scim_credential_registration_repo, audit_repo)
+)]
+pub async fn create_scim_credential(
+    profile: Profile,
+    tenant_id: Uuid,
+    name: String,
+    scim_credential_registration_repo: Box<&dyn ScimCredentialRegistration>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<IssuedScimCredential, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check if the profile is the owner of the tenant
+    // ? -----------------------------------------------------------------------
+
+    profile.with_tenant_ownership_or_error(tenant_id)?;
+
+    let name = name.trim().to_string();
+
+    if name.is_empty() {
+        return use_case_err("The credential name is empty")
+            .with_exp_true()
+            .as_error();
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Register the credential
+    // ? -----------------------------------------------------------------------
+
+    let (credential, secret) = ScimCredential::new(
+        tenant_id,
+        name,
+        WrittenBy::new_from_account(profile.acc_id),
+    );
+
+    let credential =
+        match scim_credential_registration_repo.create(credential).await? {
+            CreateResponseKind::Created(credential) => credential,
+            CreateResponseKind::NotCreated(_, msg) => {
+                return use_case_err(format!("Credential not created: {msg}"))
+                    .as_error()
+            }
+        };
+
+    // ? -----------------------------------------------------------------------
+    // ? Emit the audit event
+    // ? -----------------------------------------------------------------------
+
+    emit_resource_audit_event(
+        audit_repo,
+        ResourceAuditResourceType::TenantMeta,
+        tenant_id,
+        Some(tenant_id),
+        ResourceAuditEventKind::Created,
+        WrittenBy::new_from_account(profile.acc_id),
+        serde_json::json!({
+            "action": "create_scim_credential",
+            "credentialId": credential.id,
+            "name": credential.name,
+        }),
+    )
+    .await;
+
+    Ok(IssuedScimCredential { credential, secret })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        dtos::{
+            profile::{TenantOwnership, TenantsOwnership},
+            scim::SCIM_SECRET_PREFIX,
+        },
+        entities::MockResourceAuditLogRegistration,
+    };
+
+    use async_trait::async_trait;
+    use chrono::Local;
+
+    struct MockScimCredentialRegistrationRepo;
+
+    #[async_trait]
+    impl ScimCredentialRegistration for MockScimCredentialRegistrationRepo {
+        async fn create(
+            &self,
+            credential: ScimCredential,
+        ) -> Result<CreateResponseKind<ScimCredential>, MappedErrors> {
+            Ok(CreateResponseKind::Created(credential))
+        }
+    }
+
+    fn profile_owning_tenant(tenant_id: Uuid) -> Profile {
+        Profile::new(
+            vec![],
+            Uuid::new_v4(),
+            false,
+            false,
+            false,
+            true,
+            true,
+            true,
+            false,
+            false,
+            None,
+            None,
+            Some(TenantsOwnership::Records(vec![TenantOwnership {
+                id: tenant_id,
+                name: "Tenant Name".to_string(),
+                since: Local::now(),
+                parent_id: None,
+                inherited_from: None,
+            }])),
+        )
+    }
+
+    #[tokio::test]
+    async fn create_scim_credential_returns_the_secret_of_the_stored_hash() {
+        let tenant_id = Uuid::new_v4();
+
+        let mut audit_mock = MockResourceAuditLogRegistration::new();
+        audit_mock.expect_create().times(1).returning(|_| Ok(()));
+
+        let issued = create_scim_credential(
+            profile_owning_tenant(tenant_id),
+            tenant_id,
+            " Okta ".to_string(),
+            Box::new(&MockScimCredentialRegistrationRepo),
+            Box::new(&audit_mock),
+        )
+        .await
+        .unwrap();
+
+        assert!(issued.secret.starts_with(SCIM_SECRET_PREFIX));
+        assert_eq!(issued.credential.name, "Okta");
+        assert_eq!(
+            issued.credential.secret_hash,
+            ScimCredential::hash_secret(&issued.secret)
+        );
+    }
+
+    #[tokio::test]
+    async fn create_scim_credential_is_restricted_to_tenant_owners() {
+        let mut audit_mock = MockResourceAuditLogRegistration::new();
+        audit_mock.expect_create().times(0);
+
+        let result = create_scim_credential(
+            profile_owning_tenant(Uuid::new_v4()),
+            Uuid::new_v4(),
+            "Okta".to_string(),
+            Box::new(&MockScimCredentialRegistrationRepo),
+            Box::new(&audit_mock),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{
+    domain::{
+        dtos::{
+            profile::Profile,
+            resource_audit_log::{
+                ResourceAuditEventKind, ResourceAuditResourceType,
+            },
+            written_by::WrittenBy,
+        },
+        entities::{ResourceAuditLogRegistration, ScimCredentialDeletion},
+    },
+    use_cases::shared::audit::emit_resource_audit_event,
+};
+
+use mycelium_base::{
+    entities::DeletionResponseKind, utils::errors::MappedErrors,
+};
+use uuid::Uuid;
+
+/// Revoke a SCIM credential of a tenant
+///
+/// The identity provider using the credential is rejected from the next
+/// request on. Resources it provisioned are kept.
+#[tracing::instrument(
+    name = "delete_scim_credential",
+    fields(profile_id = %profile.acc_id),
+    skip(scim_credential_deletion_repo, audit_repo)
+)]
+pub async fn delete_scim_credential(
+    profile: Profile,
+    tenant_id: Uuid,
+    credential_id: Uuid,
+    scim_credential_deletion_repo: Box<&dyn ScimCredentialDeletion>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check if the profile is the owner of the tenant
+    // ? -----------------------------------------------------------------------
+
+    profile.with_tenant_ownership_or_error(tenant_id)?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Delete the credential
+    // ? -----------------------------------------------------------------------
+
+    let response = scim_credential_deletion_repo
+        .delete(tenant_id, credential_id)
+        .await?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Emit the audit event
+    // ? -----------------------------------------------------------------------
+
+    if let DeletionResponseKind::Deleted = &response {
+        emit_resource_audit_event(
+            audit_repo,
+            ResourceAuditResourceType::TenantMeta,
+            tenant_id,
+            Some(tenant_id),
+            ResourceAuditEventKind::Deleted,
+            WrittenBy::new_from_account(profile.acc_id),
+            serde_json::json!({
+                "action": "delete_scim_credential",
+                "credentialId": credential_id,
+            }),
+        )
+        .await;
+    }
+
+    Ok(response)
+}
@@ -0,0 +1,35 @@
+use crate::domain::{
+    dtos::{profile::Profile, scim::ScimCredential},
+    entities::ScimCredentialFetching,
+};
+
+use mycelium_base::{
+    entities::FetchManyResponseKind, utils::errors::MappedErrors,
+};
+use uuid::Uuid;
+
+/// List the SCIM credentials of a tenant
+///
+/// Secrets are not listed: only their hashes are stored.
+#[tracing::instrument(
+    name = "list_scim_credentials",
+    fields(profile_id = %profile.acc_id),
+    skip(scim_credential_fetching_repo)
+)]
+pub async fn list_scim_credentials(
+    profile: Profile,
+    tenant_id: Uuid,
+    scim_credential_fetching_repo: Box<&dyn ScimCredentialFetching>,
+) -> Result<FetchManyResponseKind<ScimCredential>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check if the profile is the owner of the tenant
+    // ? -----------------------------------------------------------------------
+
+    profile.with_tenant_ownership_or_error(tenant_id)?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Fetch credentials
+    // ? -----------------------------------------------------------------------
+
+    scim_credential_fetching_repo.list(tenant_id).await
+}
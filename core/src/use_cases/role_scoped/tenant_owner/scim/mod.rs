@@ -0,0 +1,19 @@
+// All actions listed below should ve performed by:
+//
+// - Tenant Owner
+//
+// The above cited roles should be able to manage the bearer credentials of
+// the SCIM clients of the tenant:
+//
+// - Issue a credential;
+// - List credentials;
+// - Revoke a credential;
+//
+
+mod create_scim_credential;
+mod delete_scim_credential;
+mod list_scim_credentials;
+
+pub use create_scim_credential::*;
+pub use delete_scim_credential::*;
+pub use list_scim_credentials::*;
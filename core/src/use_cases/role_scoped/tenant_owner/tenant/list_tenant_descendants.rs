@@ -0,0 +1,36 @@
+use crate::domain::{
+    dtos::{profile::Profile, tenant::Tenant},
+    entities::TenantFetching,
+};
+
+use mycelium_base::{
+    entities::FetchManyResponseKind, utils::errors::MappedErrors,
+};
+use uuid::Uuid;
+
+/// List the descendants of a tenant
+///
+/// Children are listed before their own children, allowing owners of a
+/// parent tenant to inspect the whole subtree from a single call.
+#[tracing::instrument(
+    name = "list_tenant_descendants",
+    fields(profile_id = %profile.acc_id),
+    skip_all
+)]
+pub async fn list_tenant_descendants(
+    profile: Profile,
+    tenant_id: Uuid,
+    tenant_fetching_repo: Box<&dyn TenantFetching>,
+) -> Result<FetchManyResponseKind<Tenant>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check if the profile is the owner of the tenant
+    // ? -----------------------------------------------------------------------
+
+    profile.with_tenant_ownership_or_error(tenant_id)?;
+
+    // ? -----------------------------------------------------------------------
+    // ? List descendants
+    // ? -----------------------------------------------------------------------
+
+    tenant_fetching_repo.list_descendants(tenant_id).await
+}
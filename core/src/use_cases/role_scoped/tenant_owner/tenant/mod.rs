@@ -10,15 +10,24 @@
 // - Update Tenant Archiving Status
 // - Update Tenant Trashing Status
 // - Update Tenant Verifying Status
+// - Update Tenant Parent
+// - List Tenant Descendants
+// - Restore Trashed Tenant
 //
 
+mod list_tenant_descendants;
+mod restore_trashed_tenant;
 mod update_tenant_archiving_status;
 mod update_tenant_name_and_description;
+mod update_tenant_parent;
 mod update_tenant_status;
 mod update_tenant_trashing_status;
 mod update_tenant_verifying_status;
 
+pub use list_tenant_descendants::*;
+pub use restore_trashed_tenant::*;
 pub use update_tenant_archiving_status::*;
 pub use update_tenant_name_and_description::*;
+pub use update_tenant_parent::*;
 pub use update_tenant_trashing_status::*;
 pub use update_tenant_verifying_status::*;
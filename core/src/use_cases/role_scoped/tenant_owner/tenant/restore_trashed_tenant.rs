@@ -0,0 +1,344 @@
+use crate::domain::{
+    dtos::{
+        native_error_codes::NativeErrorCodes,
+        profile::Profile,
+        resource_audit_log::{
+            ResourceAuditEventKind, ResourceAuditResourceType,
+        },
+        tenant::{Tenant, TenantStatus},
+        written_by::WrittenBy,
+    },
+    entities::{ResourceAuditLogRegistration, TenantFetching, TenantUpdating},
+};
+use crate::use_cases::shared::audit::emit_resource_audit_event;
+
+use chrono::Local;
+use mycelium_base::{
+    entities::{FetchResponseKind, UpdatingResponseKind},
+    utils::errors::{fetching_err, use_case_err, MappedErrors},
+};
+use uuid::Uuid;
+
+/// Take a tenant out of the trash
+///
+/// Trashed tenants are purged once the trash retention period ends, so they
+/// could be restored until then. The `Restored` status is appended to the
+/// tenant history, keeping the trashing record.
+#[tracing::instrument(
+    name = "restore_trashed_tenant",
+    fields(profile_id = %profile.acc_id),
+    skip_all
+)]
+pub async fn restore_trashed_tenant(
+    profile: Profile,
+    tenant_id: Uuid,
+    tenant_fetching_repo: Box<&dyn TenantFetching>,
+    tenant_updating_repo: Box<&dyn TenantUpdating>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<UpdatingResponseKind<Tenant>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check if the profile is the owner of the tenant
+    // ? -----------------------------------------------------------------------
+
+    profile.with_tenant_ownership_or_error(tenant_id)?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Fetch tenant
+    // ? -----------------------------------------------------------------------
+
+    let tenant = match tenant_fetching_repo
+        .get_tenant_owned_by_me(tenant_id, profile.get_owners_ids())
+        .await?
+    {
+        FetchResponseKind::Found(tenant) => tenant,
+        FetchResponseKind::NotFound(_) => {
+            return fetching_err("Tenant not found")
+                .with_code(NativeErrorCodes::MYC00013)
+                .as_error();
+        }
+    };
+
+    let Some(trashed_at) = tenant.trashed_at() else {
+        return use_case_err("Tenant is not in the trash")
+            .with_exp_true()
+            .as_error();
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Update tenant
+    // ? -----------------------------------------------------------------------
+
+    let result = tenant_updating_repo
+        .update_tenant_status(
+            tenant_id,
+            TenantStatus::Restored {
+                at: Local::now(),
+                by: profile.profile_string(),
+            },
+        )
+        .await;
+
+    let Ok(UpdatingResponseKind::Updated(_)) = result else {
+        return result;
+    };
+
+    emit_resource_audit_event(
+        audit_repo,
+        ResourceAuditResourceType::Tenant,
+        tenant_id,
+        Some(tenant_id),
+        ResourceAuditEventKind::Updated,
+        WrittenBy::new_from_account(profile.acc_id),
+        serde_json::json!({
+            "action": "restore_trashed_tenant",
+            "trashedAt": trashed_at,
+        }),
+    )
+    .await;
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::dtos::profile::{
+        Owner, TenantOwnership, TenantsOwnership,
+    };
+    use crate::domain::dtos::tenant::{
+        EmailTemplateKind, TenantEmailTemplate, TenantMeta, TenantMetaKey,
+    };
+    use crate::domain::entities::{
+        MockResourceAuditLogRegistration, TenantOwnerConnection,
+    };
+
+    use async_trait::async_trait;
+    use mycelium_base::entities::{CreateResponseKind, FetchManyResponseKind};
+
+    struct MockTenantFetching {
+        tenant: Tenant,
+    }
+
+    #[async_trait]
+    impl TenantFetching for MockTenantFetching {
+        async fn get_tenant_owned_by_me(
+            &self,
+            _: Uuid,
+            _: Vec<Uuid>,
+        ) -> Result<FetchResponseKind<Tenant, String>, MappedErrors> {
+            Ok(FetchResponseKind::Found(self.tenant.clone()))
+        }
+
+        async fn get_tenant_public_by_id(
+            &self,
+            _: Uuid,
+        ) -> Result<FetchResponseKind<Tenant, String>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn get_tenants_by_manager_account(
+            &self,
+            _: Uuid,
+            _: Vec<Uuid>,
+        ) -> Result<FetchResponseKind<Tenant, String>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn filter_tenants_as_manager(
+            &self,
+            _: Option<String>,
+            _: Option<Uuid>,
+            _: Option<(TenantMetaKey, String)>,
+            _: Option<(String, String)>,
+            _: Option<i32>,
+            _: Option<i32>,
+        ) -> Result<FetchManyResponseKind<Tenant>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn get_email_template(
+            &self,
+            _: Uuid,
+            _: EmailTemplateKind,
+            _: String,
+        ) -> Result<FetchResponseKind<TenantEmailTemplate, String>, MappedErrors>
+        {
+            unimplemented!()
+        }
+
+        async fn list_email_templates(
+            &self,
+            _: Uuid,
+        ) -> Result<FetchManyResponseKind<TenantEmailTemplate>, MappedErrors>
+        {
+            unimplemented!()
+        }
+
+        async fn list_descendants(
+            &self,
+            _: Uuid,
+        ) -> Result<FetchManyResponseKind<Tenant>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn list_trashed(
+            &self,
+            _: Option<chrono::DateTime<chrono::Utc>>,
+            _: chrono::DateTime<chrono::Utc>,
+        ) -> Result<FetchManyResponseKind<Tenant>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn list_owners(
+            &self,
+            _: Uuid,
+        ) -> Result<FetchManyResponseKind<Owner>, MappedErrors> {
+            unimplemented!()
+        }
+    }
+
+    struct MockTenantUpdating;
+
+    #[async_trait]
+    impl TenantUpdating for MockTenantUpdating {
+        async fn update_name_and_description(
+            &self,
+            _: Uuid,
+            _: Tenant,
+        ) -> Result<UpdatingResponseKind<Tenant>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn update_tenant_status(
+            &self,
+            tenant_id: Uuid,
+            status: TenantStatus,
+        ) -> Result<UpdatingResponseKind<Tenant>, MappedErrors> {
+            let mut tenant = sample_tenant(tenant_id);
+            tenant.status = Some(vec![status]);
+            Ok(UpdatingResponseKind::Updated(tenant))
+        }
+
+        async fn register_owner(
+            &self,
+            _: Uuid,
+            _: Uuid,
+            _: String,
+        ) -> Result<CreateResponseKind<TenantOwnerConnection>, MappedErrors>
+        {
+            unimplemented!()
+        }
+
+        async fn update_tenant_parent(
+            &self,
+            _: Uuid,
+            _: Option<Uuid>,
+        ) -> Result<UpdatingResponseKind<Tenant>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn update_tenant_meta(
+            &self,
+            _: Uuid,
+            _: TenantMetaKey,
+            _: String,
+        ) -> Result<UpdatingResponseKind<TenantMeta>, MappedErrors> {
+            unimplemented!()
+        }
+    }
+
+    fn sample_tenant(tenant_id: Uuid) -> Tenant {
+        let mut tenant = Tenant::new_with_owners(
+            "tenant".to_string(),
+            None,
+            mycelium_base::dtos::Children::Records(vec![]),
+        );
+        tenant.id = Some(tenant_id);
+        tenant
+    }
+
+    fn trashed_tenant(tenant_id: Uuid) -> Tenant {
+        let mut tenant = sample_tenant(tenant_id);
+        tenant.status = Some(vec![TenantStatus::Trashed {
+            at: Local::now(),
+            by: "owner".to_string(),
+        }]);
+        tenant
+    }
+
+    fn owner_profile(tenants_ids: Vec<Uuid>) -> Profile {
+        let mut profile = Profile::default();
+        profile.tenants_ownership = Some(TenantsOwnership::Records(
+            tenants_ids
+                .into_iter()
+                .map(|id| TenantOwnership {
+                    id,
+                    name: "tenant".to_string(),
+                    since: Local::now(),
+                    parent_id: None,
+                    inherited_from: None,
+                })
+                .collect(),
+        ));
+        profile
+    }
+
+    #[tokio::test]
+    async fn restore_trashed_tenant_appends_the_restored_status() {
+        let tenant_id = Uuid::new_v4();
+
+        let tenant_fetching = MockTenantFetching {
+            tenant: trashed_tenant(tenant_id),
+        };
+
+        let mut audit_mock = MockResourceAuditLogRegistration::new();
+        audit_mock
+            .expect_create()
+            .times(1)
+            .withf(move |event| {
+                event.resource_type == ResourceAuditResourceType::Tenant
+                    && event.resource_id == tenant_id
+                    && event.event == ResourceAuditEventKind::Updated
+            })
+            .returning(|_| Ok(()));
+
+        let result = restore_trashed_tenant(
+            owner_profile(vec![tenant_id]),
+            tenant_id,
+            Box::new(&tenant_fetching),
+            Box::new(&MockTenantUpdating),
+            Box::new(&audit_mock),
+        )
+        .await;
+
+        match result {
+            Ok(UpdatingResponseKind::Updated(tenant)) => {
+                assert!(tenant.current_status().unwrap().is_restored())
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn restore_trashed_tenant_rejects_tenants_out_of_the_trash() {
+        let tenant_id = Uuid::new_v4();
+
+        let tenant_fetching = MockTenantFetching {
+            tenant: sample_tenant(tenant_id),
+        };
+
+        let mut audit_mock = MockResourceAuditLogRegistration::new();
+        audit_mock.expect_create().times(0);
+
+        let result = restore_trashed_tenant(
+            owner_profile(vec![tenant_id]),
+            tenant_id,
+            Box::new(&tenant_fetching),
+            Box::new(&MockTenantUpdating),
+            Box::new(&audit_mock),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -138,6 +138,65 @@ mod tests {
         ) -> Result<FetchManyResponseKind<Tenant>, MappedErrors> {
             unimplemented!()
         }
+
+        async fn get_email_template(
+            &self,
+            _: Uuid,
+            _: crate::domain::dtos::tenant::EmailTemplateKind,
+            _: String,
+        ) -> Result<
+            FetchResponseKind<
+                crate::domain::dtos::tenant::TenantEmailTemplate,
+                String,
+            >,
+            MappedErrors,
+        > {
+            unimplemented!()
+        }
+
+        async fn list_email_templates(
+            &self,
+            _: Uuid,
+        ) -> Result<
+            FetchManyResponseKind<
+                crate::domain::dtos::tenant::TenantEmailTemplate,
+            >,
+            MappedErrors,
+        > {
+            unimplemented!()
+        }
+
+        async fn list_descendants(
+            &self,
+            _: Uuid,
+        ) -> Result<
+            mycelium_base::entities::FetchManyResponseKind<
+                crate::domain::dtos::tenant::Tenant,
+            >,
+            MappedErrors,
+        > {
+            unimplemented!()
+        }
+
+        async fn list_trashed(
+            &self,
+            _: Option<chrono::DateTime<chrono::Utc>>,
+            _: chrono::DateTime<chrono::Utc>,
+        ) -> Result<FetchManyResponseKind<Tenant>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn list_owners(
+            &self,
+            _: Uuid,
+        ) -> Result<
+            mycelium_base::entities::FetchManyResponseKind<
+                crate::domain::dtos::profile::Owner,
+            >,
+            MappedErrors,
+        > {
+            unimplemented!()
+        }
     }
 
     struct MockTenantUpdating {
@@ -172,6 +231,17 @@ mod tests {
             unimplemented!()
         }
 
+        async fn update_tenant_parent(
+            &self,
+            _: Uuid,
+            _: Option<Uuid>,
+        ) -> Result<
+            UpdatingResponseKind<crate::domain::dtos::tenant::Tenant>,
+            MappedErrors,
+        > {
+            unimplemented!()
+        }
+
         async fn update_tenant_meta(
             &self,
             _: Uuid,
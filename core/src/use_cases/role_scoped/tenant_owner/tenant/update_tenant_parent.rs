@@ -0,0 +1,414 @@
+use crate::domain::{
+    dtos::{
+        native_error_codes::NativeErrorCodes,
+        profile::Profile,
+        resource_audit_log::{
+            ResourceAuditEventKind, ResourceAuditResourceType,
+        },
+        tenant::Tenant,
+        written_by::WrittenBy,
+    },
+    entities::{ResourceAuditLogRegistration, TenantFetching, TenantUpdating},
+};
+use crate::use_cases::shared::audit::emit_resource_audit_event;
+
+use mycelium_base::{
+    entities::{FetchResponseKind, UpdatingResponseKind},
+    utils::errors::{fetching_err, use_case_err, MappedErrors},
+};
+use uuid::Uuid;
+
+/// Attach a tenant to a parent tenant, or detach it from its parent
+///
+/// The profile should own the tenant and the new parent. Detaching requires
+/// the ownership of the current parent. Parents could not be descendants of
+/// the tenant itself; the repository checks it while setting the parent.
+#[tracing::instrument(
+    name = "update_tenant_parent",
+    fields(profile_id = %profile.acc_id),
+    skip_all
+)]
+pub async fn update_tenant_parent(
+    profile: Profile,
+    tenant_id: Uuid,
+    parent_id: Option<Uuid>,
+    tenant_fetching_repo: Box<&dyn TenantFetching>,
+    tenant_updating_repo: Box<&dyn TenantUpdating>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<UpdatingResponseKind<Tenant>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check if the profile is the owner of the tenant
+    // ? -----------------------------------------------------------------------
+
+    profile.with_tenant_ownership_or_error(tenant_id)?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Fetch tenant
+    // ? -----------------------------------------------------------------------
+
+    let tenant = match tenant_fetching_repo
+        .get_tenant_owned_by_me(tenant_id, profile.get_owners_ids())
+        .await?
+    {
+        FetchResponseKind::Found(tenant) => tenant,
+        FetchResponseKind::NotFound(_) => {
+            return fetching_err("Tenant not found")
+                .with_code(NativeErrorCodes::MYC00013)
+                .as_error();
+        }
+    };
+
+    if tenant.parent_id == parent_id {
+        return Ok(UpdatingResponseKind::NotUpdated(
+            tenant,
+            "Tenant parent is already the same as the next parent".to_string(),
+        ));
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Check the ownership of the parents
+    // ? -----------------------------------------------------------------------
+
+    match parent_id {
+        Some(parent_id) => {
+            if parent_id == tenant_id {
+                return use_case_err("A tenant could not be its own parent")
+                    .with_exp_true()
+                    .as_error();
+            }
+
+            profile.with_tenant_ownership_or_error(parent_id)?;
+        }
+        None => {
+            if let Some(current_parent_id) = tenant.parent_id {
+                profile.with_tenant_ownership_or_error(current_parent_id)?;
+            }
+        }
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Update tenant
+    //
+    // Descendants of the tenant are refused as parents by the repository,
+    // in the same transaction as the update.
+    //
+    // ? -----------------------------------------------------------------------
+
+    let result = tenant_updating_repo
+        .update_tenant_parent(tenant_id, parent_id)
+        .await;
+
+    let Ok(UpdatingResponseKind::Updated(_)) = result else {
+        return result;
+    };
+
+    emit_resource_audit_event(
+        audit_repo,
+        ResourceAuditResourceType::Tenant,
+        tenant_id,
+        Some(tenant_id),
+        ResourceAuditEventKind::Updated,
+        WrittenBy::new_from_account(profile.acc_id),
+        serde_json::json!({
+            "action": "update_tenant_parent",
+            "previousParentId": tenant.parent_id,
+            "parentId": parent_id,
+        }),
+    )
+    .await;
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::dtos::profile::{
+        Owner, TenantOwnership, TenantsOwnership,
+    };
+    use crate::domain::dtos::tenant::{
+        EmailTemplateKind, TenantEmailTemplate, TenantMeta, TenantMetaKey,
+        TenantStatus,
+    };
+    use crate::domain::entities::{
+        MockResourceAuditLogRegistration, TenantOwnerConnection,
+    };
+
+    use async_trait::async_trait;
+    use chrono::Local;
+    use mycelium_base::entities::{CreateResponseKind, FetchManyResponseKind};
+
+    struct MockTenantFetching {
+        tenant: Tenant,
+    }
+
+    #[async_trait]
+    impl TenantFetching for MockTenantFetching {
+        async fn get_tenant_owned_by_me(
+            &self,
+            _: Uuid,
+            _: Vec<Uuid>,
+        ) -> Result<FetchResponseKind<Tenant, String>, MappedErrors> {
+            Ok(FetchResponseKind::Found(self.tenant.clone()))
+        }
+
+        async fn get_tenant_public_by_id(
+            &self,
+            _: Uuid,
+        ) -> Result<FetchResponseKind<Tenant, String>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn get_tenants_by_manager_account(
+            &self,
+            _: Uuid,
+            _: Vec<Uuid>,
+        ) -> Result<FetchResponseKind<Tenant, String>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn filter_tenants_as_manager(
+            &self,
+            _: Option<String>,
+            _: Option<Uuid>,
+            _: Option<(TenantMetaKey, String)>,
+            _: Option<(String, String)>,
+            _: Option<i32>,
+            _: Option<i32>,
+        ) -> Result<FetchManyResponseKind<Tenant>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn get_email_template(
+            &self,
+            _: Uuid,
+            _: EmailTemplateKind,
+            _: String,
+        ) -> Result<FetchResponseKind<TenantEmailTemplate, String>, MappedErrors>
+        {
+            unimplemented!()
+        }
+
+        async fn list_email_templates(
+            &self,
+            _: Uuid,
+        ) -> Result<FetchManyResponseKind<TenantEmailTemplate>, MappedErrors>
+        {
+            unimplemented!()
+        }
+
+        async fn list_descendants(
+            &self,
+            _: Uuid,
+        ) -> Result<FetchManyResponseKind<Tenant>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn list_trashed(
+            &self,
+            _: Option<chrono::DateTime<chrono::Utc>>,
+            _: chrono::DateTime<chrono::Utc>,
+        ) -> Result<FetchManyResponseKind<Tenant>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn list_owners(
+            &self,
+            _: Uuid,
+        ) -> Result<FetchManyResponseKind<Owner>, MappedErrors> {
+            unimplemented!()
+        }
+    }
+
+    #[derive(Default)]
+    struct MockTenantUpdating {
+        descendants: Vec<Uuid>,
+    }
+
+    #[async_trait]
+    impl TenantUpdating for MockTenantUpdating {
+        async fn update_name_and_description(
+            &self,
+            _: Uuid,
+            _: Tenant,
+        ) -> Result<UpdatingResponseKind<Tenant>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn update_tenant_status(
+            &self,
+            _: Uuid,
+            _: TenantStatus,
+        ) -> Result<UpdatingResponseKind<Tenant>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn register_owner(
+            &self,
+            _: Uuid,
+            _: Uuid,
+            _: String,
+        ) -> Result<CreateResponseKind<TenantOwnerConnection>, MappedErrors>
+        {
+            unimplemented!()
+        }
+
+        async fn update_tenant_parent(
+            &self,
+            tenant_id: Uuid,
+            parent_id: Option<Uuid>,
+        ) -> Result<UpdatingResponseKind<Tenant>, MappedErrors> {
+            let mut tenant = sample_tenant(tenant_id);
+
+            if parent_id.is_some_and(|id| self.descendants.contains(&id)) {
+                return Ok(UpdatingResponseKind::NotUpdated(
+                    tenant,
+                    "A descendant of the tenant could not be its parent"
+                        .to_string(),
+                ));
+            }
+
+            tenant.parent_id = parent_id;
+            Ok(UpdatingResponseKind::Updated(tenant))
+        }
+
+        async fn update_tenant_meta(
+            &self,
+            _: Uuid,
+            _: TenantMetaKey,
+            _: String,
+        ) -> Result<UpdatingResponseKind<TenantMeta>, MappedErrors> {
+            unimplemented!()
+        }
+    }
+
+    fn sample_tenant(tenant_id: Uuid) -> Tenant {
+        let mut tenant = Tenant::new_with_owners(
+            "tenant".to_string(),
+            None,
+            mycelium_base::dtos::Children::Records(vec![]),
+        );
+        tenant.id = Some(tenant_id);
+        tenant
+    }
+
+    fn owner_profile(tenants_ids: Vec<Uuid>) -> Profile {
+        let mut profile = Profile::default();
+        profile.tenants_ownership = Some(TenantsOwnership::Records(
+            tenants_ids
+                .into_iter()
+                .map(|id| TenantOwnership {
+                    id,
+                    name: "tenant".to_string(),
+                    since: Local::now(),
+                    parent_id: None,
+                    inherited_from: None,
+                })
+                .collect(),
+        ));
+        profile
+    }
+
+    #[tokio::test]
+    async fn update_tenant_parent_attaches_owned_tenants() {
+        let tenant_id = Uuid::new_v4();
+        let parent_id = Uuid::new_v4();
+
+        let tenant_fetching = MockTenantFetching {
+            tenant: sample_tenant(tenant_id),
+        };
+
+        let mut audit_mock = MockResourceAuditLogRegistration::new();
+        audit_mock
+            .expect_create()
+            .times(1)
+            .withf(move |event| {
+                event.resource_type == ResourceAuditResourceType::Tenant
+                    && event.resource_id == tenant_id
+                    && event.event == ResourceAuditEventKind::Updated
+            })
+            .returning(|_| Ok(()));
+
+        let result = update_tenant_parent(
+            owner_profile(vec![tenant_id, parent_id]),
+            tenant_id,
+            Some(parent_id),
+            Box::new(&tenant_fetching),
+            Box::new(&MockTenantUpdating::default()),
+            Box::new(&audit_mock),
+        )
+        .await;
+
+        match result {
+            Ok(UpdatingResponseKind::Updated(tenant)) => {
+                assert_eq!(tenant.parent_id, Some(parent_id))
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn update_tenant_parent_requires_the_parent_ownership() {
+        let tenant_id = Uuid::new_v4();
+
+        let tenant_fetching = MockTenantFetching {
+            tenant: sample_tenant(tenant_id),
+        };
+
+        let mut audit_mock = MockResourceAuditLogRegistration::new();
+        audit_mock.expect_create().times(0);
+
+        let result = update_tenant_parent(
+            owner_profile(vec![tenant_id]),
+            tenant_id,
+            Some(Uuid::new_v4()),
+            Box::new(&tenant_fetching),
+            Box::new(&MockTenantUpdating::default()),
+            Box::new(&audit_mock),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn update_tenant_parent_rejects_cycles() {
+        let tenant_id = Uuid::new_v4();
+        let child_id = Uuid::new_v4();
+
+        let tenant_fetching = MockTenantFetching {
+            tenant: sample_tenant(tenant_id),
+        };
+
+        let tenant_updating = MockTenantUpdating {
+            descendants: vec![child_id],
+        };
+
+        let mut audit_mock = MockResourceAuditLogRegistration::new();
+        audit_mock.expect_create().times(0);
+
+        let result = update_tenant_parent(
+            owner_profile(vec![tenant_id, child_id]),
+            tenant_id,
+            Some(child_id),
+            Box::new(&tenant_fetching),
+            Box::new(&tenant_updating),
+            Box::new(&audit_mock),
+        )
+        .await;
+
+        assert!(matches!(result, Ok(UpdatingResponseKind::NotUpdated(..))));
+
+        let result = update_tenant_parent(
+            owner_profile(vec![tenant_id]),
+            tenant_id,
+            Some(tenant_id),
+            Box::new(&tenant_fetching),
+            Box::new(&MockTenantUpdating::default()),
+            Box::new(&audit_mock),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+}
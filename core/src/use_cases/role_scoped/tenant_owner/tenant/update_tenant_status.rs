@@ -61,17 +61,14 @@ pub(super) async fn update_tenant_status(
         //
         // Sort statuses by date to select the last status
         //
-        status.sort_by_key(|s| match s {
-            TenantStatus::Archived { at, .. } => *at,
-            TenantStatus::Trashed { at, .. } => *at,
-            TenantStatus::Verified { at, .. } => *at,
-        });
+        status.sort_by_key(TenantStatus::at);
 
         if let Some(last_status) = status.last() {
             let is_the_same = match next_status {
                 TenantStatus::Verified { .. } => last_status.is_verified(),
                 TenantStatus::Trashed { .. } => last_status.is_trashed(),
                 TenantStatus::Archived { .. } => last_status.is_archived(),
+                TenantStatus::Restored { .. } => last_status.is_restored(),
             };
 
             if is_the_same {
@@ -93,6 +90,7 @@ pub(super) async fn update_tenant_status(
         TenantStatus::Archived { .. } => "update_tenant_archiving_status",
         TenantStatus::Trashed { .. } => "update_tenant_trashing_status",
         TenantStatus::Verified { .. } => "update_tenant_verifying_status",
+        TenantStatus::Restored { .. } => "restore_trashed_tenant",
     };
 
     let result = tenant_updating_repo
@@ -174,6 +172,65 @@ mod tests {
         ) -> Result<FetchManyResponseKind<Tenant>, MappedErrors> {
             unimplemented!()
         }
+
+        async fn get_email_template(
+            &self,
+            _: Uuid,
+            _: crate::domain::dtos::tenant::EmailTemplateKind,
+            _: String,
+        ) -> Result<
+            FetchResponseKind<
+                crate::domain::dtos::tenant::TenantEmailTemplate,
+                String,
+            >,
+            MappedErrors,
+        > {
+            unimplemented!()
+        }
+
+        async fn list_email_templates(
+            &self,
+            _: Uuid,
+        ) -> Result<
+            FetchManyResponseKind<
+                crate::domain::dtos::tenant::TenantEmailTemplate,
+            >,
+            MappedErrors,
+        > {
+            unimplemented!()
+        }
+
+        async fn list_descendants(
+            &self,
+            _: Uuid,
+        ) -> Result<
+            mycelium_base::entities::FetchManyResponseKind<
+                crate::domain::dtos::tenant::Tenant,
+            >,
+            MappedErrors,
+        > {
+            unimplemented!()
+        }
+
+        async fn list_trashed(
+            &self,
+            _: Option<chrono::DateTime<chrono::Utc>>,
+            _: chrono::DateTime<chrono::Utc>,
+        ) -> Result<FetchManyResponseKind<Tenant>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn list_owners(
+            &self,
+            _: Uuid,
+        ) -> Result<
+            mycelium_base::entities::FetchManyResponseKind<
+                crate::domain::dtos::profile::Owner,
+            >,
+            MappedErrors,
+        > {
+            unimplemented!()
+        }
     }
 
     struct MockTenantUpdating {
@@ -208,6 +265,17 @@ mod tests {
             unimplemented!()
         }
 
+        async fn update_tenant_parent(
+            &self,
+            _: Uuid,
+            _: Option<Uuid>,
+        ) -> Result<
+            UpdatingResponseKind<crate::domain::dtos::tenant::Tenant>,
+            MappedErrors,
+        > {
+            unimplemented!()
+        }
+
         async fn update_tenant_meta(
             &self,
             _: Uuid,
@@ -247,6 +315,8 @@ mod tests {
                     id: tenant_id,
                     name: "tenant".to_string(),
                     since: Local::now(),
+                    parent_id: None,
+                    inherited_from: None,
                 }],
             ));
         profile
@@ -103,8 +103,49 @@ on accounts with more privileges than himself.",
     // ? Update account status
     // ? -----------------------------------------------------------------------
 
+    let tenant_id = match &account.account_type {
+        AccountType::Subscription { tenant_id } => Some(*tenant_id),
+        AccountType::RoleAssociated { tenant_id, .. } => Some(*tenant_id),
+        AccountType::TenantManager { tenant_id } => Some(*tenant_id),
+        _ => None,
+    };
+
+    apply_account_activation_status(
+        account,
+        is_active,
+        WrittenBy::new_from_account(profile.acc_id),
+        tenant_id,
+        None,
+        account_updating_repo,
+        audit_repo,
+    )
+    .await
+}
+
+/// Move an already authorized account to the activation status and audit it
+///
+/// This is the part of the activation change shared by the users managers and
+/// the SCIM provisioning: callers check their own permissions over the account
+/// and then go through here, so both write the same audit event. The SCIM user
+/// ID, when given, is added to the event metadata.
+pub(crate) async fn apply_account_activation_status(
+    account: Account,
+    is_active: bool,
+    written_by: WrittenBy,
+    tenant_id: Option<Uuid>,
+    scim_user_id: Option<Uuid>,
+    account_updating_repo: Box<&dyn AccountUpdating>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<UpdatingResponseKind<Account>, MappedErrors> {
+    let account_id = match account.id {
+        Some(id) => id,
+        None => {
+            return use_case_err("Account ID not found".to_string()).as_error()
+        }
+    };
+
     let updated_account = try_to_reach_desired_status(
-        account.to_owned(),
+        account,
         match is_active {
             true => VerboseStatus::Verified,
             false => VerboseStatus::Inactive,
@@ -114,13 +155,13 @@ on accounts with more privileges than himself.",
 
     let response = account_updating_repo.update(updated_account).await?;
 
-    if let UpdatingResponseKind::Updated(account) = &response {
-        let tenant_id = match &account.account_type {
-            AccountType::Subscription { tenant_id } => Some(*tenant_id),
-            AccountType::RoleAssociated { tenant_id, .. } => Some(*tenant_id),
-            AccountType::TenantManager { tenant_id } => Some(*tenant_id),
-            _ => None,
-        };
+    if let UpdatingResponseKind::Updated(_) = &response {
+        let mut metadata =
+            serde_json::json!({ "action": "change_account_activation_status" });
+
+        if let Some(scim_user_id) = scim_user_id {
+            metadata["scimUserId"] = serde_json::json!(scim_user_id);
+        }
 
         emit_resource_audit_event(
             audit_repo,
@@ -128,8 +169,8 @@ on accounts with more privileges than himself.",
             account_id,
             tenant_id,
             ResourceAuditEventKind::Updated,
-            WrittenBy::new_from_account(profile.acc_id),
-            serde_json::json!({ "action": "change_account_activation_status" }),
+            written_by,
+            metadata,
         )
         .await;
     }
@@ -0,0 +1,112 @@
+use crate::domain::{
+    actors::SystemActor,
+    dtos::{email::Email, profile::Profile},
+    entities::EmailSuppressionRegistration,
+};
+
+use mycelium_base::{
+    entities::DeletionResponseKind, utils::errors::MappedErrors,
+};
+
+/// Remove an address from the suppression list
+///
+/// Emails are sent to the address again, e.g. after the recipient fixed
+/// their mailbox or asked to receive notifications again.
+#[tracing::instrument(name = "delete_email_suppression", skip_all)]
+pub async fn delete_email_suppression(
+    profile: Profile,
+    email: Email,
+    email_suppression_registration_repo: Box<&dyn EmailSuppressionRegistration>,
+) -> Result<DeletionResponseKind<String>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check permissions
+    // ? -----------------------------------------------------------------------
+
+    profile
+        .with_system_accounts_access()
+        .with_write_access()
+        .with_roles(vec![SystemActor::UsersManager])
+        .get_related_account_or_error()?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Delete the suppression
+    // ? -----------------------------------------------------------------------
+
+    let response = email_suppression_registration_repo
+        .delete(email.to_owned())
+        .await?;
+
+    if let DeletionResponseKind::Deleted = response {
+        tracing::info!(
+            "Suppression of {} lifted by account {}",
+            email.email(),
+            profile.acc_id
+        );
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::MockEmailSuppressionRegistration;
+
+    use uuid::Uuid;
+
+    fn users_manager_profile() -> Profile {
+        Profile::new(
+            vec![],
+            Uuid::new_v4(),
+            false,
+            false,
+            true,
+            true,
+            true,
+            true,
+            false,
+            false,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn email() -> Email {
+        Email::from_string("user@example.com".to_string()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn delete_email_suppression_lifts_the_suppression() {
+        let mut suppression_repo = MockEmailSuppressionRegistration::new();
+        suppression_repo
+            .expect_delete()
+            .withf(|candidate| *candidate == email())
+            .times(1)
+            .returning(|_| Ok(DeletionResponseKind::Deleted));
+
+        let result = delete_email_suppression(
+            users_manager_profile(),
+            email(),
+            Box::new(&suppression_repo),
+        )
+        .await;
+
+        assert!(matches!(result, Ok(DeletionResponseKind::Deleted)));
+    }
+
+    #[tokio::test]
+    async fn delete_email_suppression_requires_users_manager() {
+        let mut suppression_repo = MockEmailSuppressionRegistration::new();
+        suppression_repo.expect_delete().never();
+
+        let result = delete_email_suppression(
+            Profile::default(),
+            email(),
+            Box::new(&suppression_repo),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+}
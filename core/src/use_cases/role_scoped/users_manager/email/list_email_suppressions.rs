@@ -0,0 +1,34 @@
+use crate::domain::{
+    actors::SystemActor,
+    dtos::{email_delivery::EmailSuppression, profile::Profile},
+    entities::EmailSuppressionFetching,
+};
+
+use mycelium_base::{
+    entities::FetchManyResponseKind, utils::errors::MappedErrors,
+};
+
+/// List the addresses no email is sent to
+#[tracing::instrument(name = "list_email_suppressions", skip_all)]
+pub async fn list_email_suppressions(
+    profile: Profile,
+    page_size: Option<i32>,
+    skip: Option<i32>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
+) -> Result<FetchManyResponseKind<EmailSuppression>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check permissions
+    // ? -----------------------------------------------------------------------
+
+    profile
+        .with_system_accounts_access()
+        .with_read_access()
+        .with_roles(vec![SystemActor::UsersManager])
+        .get_related_account_or_error()?;
+
+    // ? -----------------------------------------------------------------------
+    // ? List suppressions
+    // ? -----------------------------------------------------------------------
+
+    email_suppression_fetching_repo.list(page_size, skip).await
+}
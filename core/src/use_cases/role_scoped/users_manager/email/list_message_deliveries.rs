@@ -0,0 +1,46 @@
+use crate::domain::{
+    actors::SystemActor,
+    dtos::{
+        email_delivery::{MessageDelivery, MessageDeliveryStatus},
+        profile::Profile,
+    },
+    entities::MessageDeliveryFetching,
+};
+
+use mycelium_base::{
+    entities::FetchManyResponseKind, utils::errors::MappedErrors,
+};
+
+/// List the delivery status of the emails sent by the platform
+#[tracing::instrument(name = "list_message_deliveries", skip_all)]
+pub async fn list_message_deliveries(
+    profile: Profile,
+    recipient: Option<String>,
+    status: Option<MessageDeliveryStatus>,
+    page_size: Option<i32>,
+    skip: Option<i32>,
+    message_delivery_fetching_repo: Box<&dyn MessageDeliveryFetching>,
+) -> Result<FetchManyResponseKind<MessageDelivery>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check permissions
+    // ? -----------------------------------------------------------------------
+
+    profile
+        .with_system_accounts_access()
+        .with_read_access()
+        .with_roles(vec![SystemActor::UsersManager])
+        .get_related_account_or_error()?;
+
+    // ? -----------------------------------------------------------------------
+    // ? List deliveries
+    // ? -----------------------------------------------------------------------
+
+    message_delivery_fetching_repo
+        .list(
+            recipient.map(|recipient| recipient.trim().to_lowercase()),
+            status,
+            page_size,
+            skip,
+        )
+        .await
+}
@@ -0,0 +1,7 @@
+mod delete_email_suppression;
+mod list_email_suppressions;
+mod list_message_deliveries;
+
+pub use delete_email_suppression::*;
+pub use list_email_suppressions::*;
+pub use list_message_deliveries::*;
@@ -1,2 +1,3 @@
 pub mod profile;
+pub mod scim;
 pub mod service;
@@ -0,0 +1,79 @@
+use crate::domain::{
+    dtos::{native_error_codes::NativeErrorCodes, scim::ScimCredential},
+    entities::ScimCredentialFetching,
+};
+
+use mycelium_base::{
+    entities::FetchResponseKind,
+    utils::errors::{use_case_err, MappedErrors},
+};
+
+/// Find the SCIM credential of a bearer secret
+///
+/// Unknown secrets are reported as invalid identity, so the port can answer
+/// them as unauthorized.
+#[tracing::instrument(name = "authenticate_scim_credential", skip_all)]
+pub async fn authenticate_scim_credential(
+    secret: String,
+    scim_credential_fetching_repo: Box<&dyn ScimCredentialFetching>,
+) -> Result<ScimCredential, MappedErrors> {
+    match scim_credential_fetching_repo
+        .get_by_secret_hash(ScimCredential::hash_secret(secret.trim()))
+        .await?
+    {
+        FetchResponseKind::Found(credential) => Ok(credential),
+        FetchResponseKind::NotFound(_) => {
+            use_case_err("Invalid SCIM credential")
+                .with_code(NativeErrorCodes::MYC00008)
+                .with_exp_true()
+                .as_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        dtos::written_by::WrittenBy, entities::MockScimCredentialFetching,
+    };
+
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn authenticate_scim_credential_matches_the_secret_hash() {
+        let (credential, secret) = ScimCredential::new(
+            Uuid::new_v4(),
+            "Okta".to_string(),
+            WrittenBy::new_from_account(Uuid::new_v4()),
+        );
+
+        let stored = credential.to_owned();
+        let mut fetching_mock = MockScimCredentialFetching::new();
+
+        fetching_mock
+            .expect_get_by_secret_hash()
+            .returning(move |hash| {
+                Ok(match hash == stored.secret_hash {
+                    true => FetchResponseKind::Found(stored.to_owned()),
+                    false => FetchResponseKind::NotFound(Some(hash)),
+                })
+            });
+
+        let found =
+            authenticate_scim_credential(secret, Box::new(&fetching_mock))
+                .await
+                .unwrap();
+
+        assert_eq!(found.id, credential.id);
+
+        let denied = authenticate_scim_credential(
+            "myc_scim_unknown".to_string(),
+            Box::new(&fetching_mock),
+        )
+        .await
+        .unwrap_err();
+
+        assert!(denied.has_str_code(NativeErrorCodes::MYC00008.as_str()));
+    }
+}
@@ -0,0 +1,172 @@
+use super::shared::{
+    check_uniqueness, find_member, get_or_create_subscription_account,
+    invalid_value, list_resources, uniqueness_error, ScimGuestGrants,
+};
+use crate::{
+    domain::{
+        dtos::scim::{
+            ScimCredential, ScimGroup, ScimResource, ScimResourceKind,
+        },
+        entities::{
+            AccountFetching, AccountRegistration, EmailSuppressionFetching,
+            EncryptionKeyFetching, GuestRoleFetching,
+            GuestUserOnAccountUpdating, GuestUserRegistration,
+            LocalMessageWrite, NotificationRecipientFetching,
+            ResourceAuditLogRegistration, ScimResourceFetching,
+            ScimResourceRegistration, TenantFetching, WebHookRegistration,
+        },
+    },
+    models::AccountLifeCycle,
+    use_cases::support::resolve_role,
+};
+
+use mycelium_base::{
+    entities::CreateResponseKind,
+    utils::errors::{use_case_err, MappedErrors},
+};
+
+/// Provision a group to the tenant of a SCIM credential
+///
+/// The group maps onto a guest role of a subscription account of the tenant,
+/// and the account is created when missing. The active members are invited
+/// to the role on the account.
+#[tracing::instrument(
+    name = "create_scim_group",
+    fields(tenant_id = %credential.tenant_id),
+    skip_all
+)]
+pub async fn create_scim_group(
+    credential: ScimCredential,
+    document: serde_json::Value,
+    life_cycle_settings: AccountLifeCycle,
+    scim_resource_fetching_repo: Box<&dyn ScimResourceFetching>,
+    scim_resource_registration_repo: Box<&dyn ScimResourceRegistration>,
+    account_fetching_repo: Box<&dyn AccountFetching>,
+    account_registration_repo: Box<&dyn AccountRegistration>,
+    guest_role_fetching_repo: Box<&dyn GuestRoleFetching>,
+    guest_user_registration_repo: Box<&dyn GuestUserRegistration>,
+    guest_user_on_account_updating_repo: Box<&dyn GuestUserOnAccountUpdating>,
+    message_sending_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
+    tenant_fetching_repo: Box<&dyn TenantFetching>,
+    notification_recipient_fetching_repo: Box<
+        &dyn NotificationRecipientFetching,
+    >,
+    encryption_key_fetching_repo: Box<&dyn EncryptionKeyFetching>,
+    webhook_registration_repo: Box<&dyn WebHookRegistration>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<ScimGroup, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Validate the group
+    // ? -----------------------------------------------------------------------
+
+    let group = ScimGroup::from_document(document)?;
+    let target = group.target()?;
+
+    check_uniqueness(
+        &list_resources(
+            &credential,
+            ScimResourceKind::Group,
+            *scim_resource_fetching_repo,
+        )
+        .await?,
+        &group.display_name,
+        None,
+    )?;
+
+    let users = list_resources(
+        &credential,
+        ScimResourceKind::User,
+        *scim_resource_fetching_repo,
+    )
+    .await?;
+
+    let mut members = vec![];
+
+    for member_id in group.member_ids() {
+        members.push(find_member(&users, member_id)?);
+    }
+
+    let role = match resolve_role(
+        credential.tenant_id,
+        &target.role,
+        *guest_role_fetching_repo,
+    )
+    .await?
+    {
+        Some(role) => role,
+        None => {
+            return invalid_value(format!(
+                "The guest role {} was not found",
+                target.role
+            ))
+        }
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Resolve the account and register the resource
+    // ? -----------------------------------------------------------------------
+
+    let account_id = get_or_create_subscription_account(
+        &credential,
+        &target.account_name,
+        *account_fetching_repo,
+        *account_registration_repo,
+        *webhook_registration_repo,
+        *audit_repo,
+    )
+    .await?;
+
+    let mut resource = ScimResource::new(
+        credential.tenant_id,
+        ScimResourceKind::Group,
+        group.display_name.to_owned(),
+        group.to_document()?,
+    );
+
+    resource.external_id = group.external_id.to_owned();
+    resource.account_id = Some(account_id);
+    resource.guest_role_id = role.id;
+
+    if resource.guest_role_id.is_none() {
+        return use_case_err("Guest role ID not found".to_string()).as_error();
+    }
+
+    let resource = match scim_resource_registration_repo
+        .create(resource)
+        .await?
+    {
+        CreateResponseKind::Created(resource) => resource,
+        CreateResponseKind::NotCreated(_, msg) => {
+            return uniqueness_error(format!("Group not provisioned: {msg}"))
+        }
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Invite the active members
+    // ? -----------------------------------------------------------------------
+
+    let grants = ScimGuestGrants {
+        credential: &credential,
+        life_cycle_settings,
+        account_fetching_repo: *account_fetching_repo,
+        guest_role_fetching_repo: *guest_role_fetching_repo,
+        guest_user_registration_repo: *guest_user_registration_repo,
+        guest_user_on_account_updating_repo:
+            *guest_user_on_account_updating_repo,
+        message_sending_repo: *message_sending_repo,
+        email_suppression_fetching_repo: *email_suppression_fetching_repo,
+        tenant_fetching_repo: *tenant_fetching_repo,
+        notification_recipient_fetching_repo:
+            *notification_recipient_fetching_repo,
+        encryption_key_fetching_repo: *encryption_key_fetching_repo,
+        webhook_registration_repo: *webhook_registration_repo,
+        audit_repo: *audit_repo,
+    };
+
+    for member in members.iter().filter(|member| member.active) {
+        grants.grant(&resource, member.email()?).await?;
+    }
+
+    ScimGroup::from_resource(&resource)
+}
@@ -0,0 +1,165 @@
+use super::shared::{
+    check_uniqueness, list_resources, set_user_account_activation,
+    uniqueness_error,
+};
+use crate::{
+    domain::{
+        dtos::scim::{
+            ScimCredential, ScimResource, ScimResourceKind, ScimUser,
+        },
+        entities::{
+            AccountFetching, AccountRegistration, AccountUpdating,
+            EmailSuppressionFetching, LocalMessageWrite,
+            ResourceAuditLogRegistration, ScimResourceFetching,
+            ScimResourceRegistration, TenantFetching, UserFetching,
+            UserRegistration, WebHookRegistration,
+        },
+    },
+    models::AccountLifeCycle,
+    use_cases::role_scoped::beginner::account::create_user_account,
+};
+
+use mycelium_base::{
+    dtos::Parent,
+    entities::{CreateResponseKind, FetchResponseKind},
+    utils::errors::{use_case_err, MappedErrors},
+};
+
+/// The identity provider registered for users created by SCIM
+const SCIM_PROVIDER: &str = "scim";
+
+/// Provision a user to the tenant of a SCIM credential
+///
+/// Users map onto the Mycelium user of their email. Users already registered
+/// keep their personal account, which is linked to the SCIM user; the others
+/// are registered with a new personal account. Users provisioned as inactive
+/// have the account deactivated, when created by the provisioning.
+#[tracing::instrument(
+    name = "create_scim_user",
+    fields(tenant_id = %credential.tenant_id),
+    skip_all
+)]
+pub async fn create_scim_user(
+    credential: ScimCredential,
+    document: serde_json::Value,
+    life_cycle_settings: AccountLifeCycle,
+    scim_resource_fetching_repo: Box<&dyn ScimResourceFetching>,
+    scim_resource_registration_repo: Box<&dyn ScimResourceRegistration>,
+    user_fetching_repo: Box<&dyn UserFetching>,
+    user_registration_repo: Box<&dyn UserRegistration>,
+    account_registration_repo: Box<&dyn AccountRegistration>,
+    account_fetching_repo: Box<&dyn AccountFetching>,
+    account_updating_repo: Box<&dyn AccountUpdating>,
+    message_sending_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
+    tenant_fetching_repo: Box<&dyn TenantFetching>,
+    webhook_registration_repo: Box<&dyn WebHookRegistration>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<ScimUser, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Validate the user
+    // ? -----------------------------------------------------------------------
+
+    let user = ScimUser::from_document(document)?;
+    let email = user.email()?;
+
+    let resources = list_resources(
+        &credential,
+        ScimResourceKind::User,
+        *scim_resource_fetching_repo,
+    )
+    .await?;
+
+    check_uniqueness(&resources, &user.user_name, None)?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Link or register the personal account
+    // ? -----------------------------------------------------------------------
+
+    let existing_account_id = match user_fetching_repo
+        .get_user_by_email(email.to_owned())
+        .await?
+    {
+        FetchResponseKind::Found(existing) => match existing.account {
+            Some(Parent::Id(id)) => Some(id),
+            Some(Parent::Record(account)) => account.id,
+            None => None,
+        },
+        FetchResponseKind::NotFound(_) => None,
+    };
+
+    let (account_id, provisioned) = match existing_account_id {
+        Some(account_id) => (account_id, false),
+        None => {
+            let account = create_user_account(
+                email.to_owned(),
+                Some(SCIM_PROVIDER.to_string()),
+                user.account_name(),
+                life_cycle_settings,
+                user_fetching_repo,
+                user_registration_repo,
+                account_registration_repo,
+                webhook_registration_repo.to_owned(),
+                message_sending_repo,
+                email_suppression_fetching_repo,
+                tenant_fetching_repo,
+                audit_repo.to_owned(),
+            )
+            .await?;
+
+            let account_id = account.id.ok_or_else(|| {
+                use_case_err("Account ID not found".to_string())
+            })?;
+
+            (account_id, true)
+        }
+    };
+
+    if resources
+        .iter()
+        .any(|resource| resource.account_id == Some(account_id))
+    {
+        return uniqueness_error(format!(
+            "The email {} was already provisioned",
+            email.email()
+        ));
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Register the resource
+    // ? -----------------------------------------------------------------------
+
+    let mut resource = ScimResource::new(
+        credential.tenant_id,
+        ScimResourceKind::User,
+        user.user_name.to_owned(),
+        user.to_document()?,
+    );
+
+    resource.external_id = user.external_id.to_owned();
+    resource.account_id = Some(account_id);
+    resource.provisioned = provisioned;
+
+    let resource =
+        match scim_resource_registration_repo.create(resource).await? {
+            CreateResponseKind::Created(resource) => resource,
+            CreateResponseKind::NotCreated(_, msg) => {
+                return uniqueness_error(format!("User not provisioned: {msg}"))
+            }
+        };
+
+    if !user.active {
+        set_user_account_activation(
+            &credential,
+            &resource,
+            false,
+            *account_fetching_repo,
+            *account_updating_repo,
+            *webhook_registration_repo,
+            *audit_repo,
+        )
+        .await?;
+    }
+
+    ScimUser::from_resource(&resource)
+}
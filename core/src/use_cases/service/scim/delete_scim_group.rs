@@ -0,0 +1,79 @@
+use super::shared::{
+    find_member, get_resource, list_resources, revoke_group_grant,
+};
+use crate::domain::{
+    dtos::scim::{ScimCredential, ScimGroup, ScimResourceKind},
+    entities::{
+        GuestUserDeletion, ResourceAuditLogRegistration, ScimResourceDeletion,
+        ScimResourceFetching, WebHookRegistration,
+    },
+};
+
+use mycelium_base::{
+    entities::DeletionResponseKind, utils::errors::MappedErrors,
+};
+use uuid::Uuid;
+
+/// Deprovision a group from the tenant of a SCIM credential
+///
+/// The members lose the guest role the group grants. The subscription
+/// account the group maps onto is kept.
+#[tracing::instrument(
+    name = "delete_scim_group",
+    fields(tenant_id = %credential.tenant_id),
+    skip_all
+)]
+pub async fn delete_scim_group(
+    credential: ScimCredential,
+    group_id: Uuid,
+    scim_resource_fetching_repo: Box<&dyn ScimResourceFetching>,
+    scim_resource_deletion_repo: Box<&dyn ScimResourceDeletion>,
+    guest_user_deletion_repo: Box<&dyn GuestUserDeletion>,
+    webhook_registration_repo: Box<&dyn WebHookRegistration>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
+    let resource = match get_resource(
+        &credential,
+        ScimResourceKind::Group,
+        group_id,
+        *scim_resource_fetching_repo,
+    )
+    .await?
+    {
+        Some(resource) => resource,
+        None => {
+            return Ok(DeletionResponseKind::NotDeleted(
+                group_id,
+                "Group not found".to_string(),
+            ))
+        }
+    };
+
+    let users = list_resources(
+        &credential,
+        ScimResourceKind::User,
+        *scim_resource_fetching_repo,
+    )
+    .await?;
+
+    for member_id in ScimGroup::from_resource(&resource)?.member_ids() {
+        let member = match find_member(&users, member_id) {
+            Ok(member) if member.active => member,
+            _ => continue,
+        };
+
+        revoke_group_grant(
+            &credential,
+            &resource,
+            &member.email()?,
+            *guest_user_deletion_repo,
+            *webhook_registration_repo,
+            *audit_repo,
+        )
+        .await?;
+    }
+
+    scim_resource_deletion_repo
+        .delete(credential.tenant_id, ScimResourceKind::Group, group_id)
+        .await
+}
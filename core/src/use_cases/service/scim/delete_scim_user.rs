@@ -0,0 +1,124 @@
+use super::shared::{
+    get_resource, groups_of_member, list_resources, revoke_group_grant,
+    set_user_account_activation,
+};
+use crate::domain::{
+    dtos::scim::{ScimCredential, ScimGroup, ScimResourceKind, ScimUser},
+    entities::{
+        AccountFetching, AccountUpdating, GuestUserDeletion,
+        ResourceAuditLogRegistration, ScimResourceDeletion,
+        ScimResourceFetching, ScimResourceUpdating, WebHookRegistration,
+    },
+};
+
+use chrono::Local;
+use mycelium_base::{
+    entities::{DeletionResponseKind, UpdatingResponseKind},
+    utils::errors::{use_case_err, MappedErrors},
+};
+use uuid::Uuid;
+
+/// Deprovision a user from the tenant of a SCIM credential
+///
+/// The user loses the guest roles its groups grant and leaves the groups.
+/// Its personal account is deactivated, when created by the provisioning,
+/// and kept otherwise.
+#[tracing::instrument(
+    name = "delete_scim_user",
+    fields(tenant_id = %credential.tenant_id),
+    skip_all
+)]
+pub async fn delete_scim_user(
+    credential: ScimCredential,
+    user_id: Uuid,
+    scim_resource_fetching_repo: Box<&dyn ScimResourceFetching>,
+    scim_resource_updating_repo: Box<&dyn ScimResourceUpdating>,
+    scim_resource_deletion_repo: Box<&dyn ScimResourceDeletion>,
+    account_fetching_repo: Box<&dyn AccountFetching>,
+    account_updating_repo: Box<&dyn AccountUpdating>,
+    guest_user_deletion_repo: Box<&dyn GuestUserDeletion>,
+    webhook_registration_repo: Box<&dyn WebHookRegistration>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
+    let resource = match get_resource(
+        &credential,
+        ScimResourceKind::User,
+        user_id,
+        *scim_resource_fetching_repo,
+    )
+    .await?
+    {
+        Some(resource) => resource,
+        None => {
+            return Ok(DeletionResponseKind::NotDeleted(
+                user_id,
+                "User not found".to_string(),
+            ))
+        }
+    };
+
+    let user = ScimUser::from_resource(&resource)?;
+    let email = user.email()?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Revoke the grants and leave the groups
+    // ? -----------------------------------------------------------------------
+
+    let groups = groups_of_member(
+        list_resources(
+            &credential,
+            ScimResourceKind::Group,
+            *scim_resource_fetching_repo,
+        )
+        .await?,
+        user_id,
+    )?;
+
+    for mut group in groups {
+        if user.active {
+            revoke_group_grant(
+                &credential,
+                &group,
+                &email,
+                *guest_user_deletion_repo,
+                *webhook_registration_repo,
+                *audit_repo,
+            )
+            .await?;
+        }
+
+        let mut members = ScimGroup::from_resource(&group)?;
+        members.members.retain(|member| member.value != user_id);
+
+        group.attributes = members.to_document()?;
+        group.updated = Some(Local::now());
+
+        if let UpdatingResponseKind::NotUpdated(_, msg) =
+            scim_resource_updating_repo.update(group).await?
+        {
+            return use_case_err(format!("Group not updated: {msg}"))
+                .as_error();
+        }
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Deactivate the account and delete the user
+    // ? -----------------------------------------------------------------------
+
+    if user.active {
+        set_user_account_activation(
+            &credential,
+            &resource,
+            false,
+            *account_fetching_repo,
+            *account_updating_repo,
+            *webhook_registration_repo,
+            *audit_repo,
+        )
+        .await?;
+    }
+
+    scim_resource_deletion_repo
+        .delete(credential.tenant_id, ScimResourceKind::User, user_id)
+        .await
+}
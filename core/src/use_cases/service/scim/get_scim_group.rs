@@ -0,0 +1,34 @@
+use super::shared::get_resource;
+use crate::domain::{
+    dtos::scim::{ScimCredential, ScimGroup, ScimResourceKind},
+    entities::ScimResourceFetching,
+};
+
+use mycelium_base::{entities::FetchResponseKind, utils::errors::MappedErrors};
+use uuid::Uuid;
+
+/// Get a group provisioned to the tenant of a SCIM credential
+#[tracing::instrument(
+    name = "get_scim_group",
+    fields(tenant_id = %credential.tenant_id),
+    skip(credential, scim_resource_fetching_repo)
+)]
+pub async fn get_scim_group(
+    credential: ScimCredential,
+    group_id: Uuid,
+    scim_resource_fetching_repo: Box<&dyn ScimResourceFetching>,
+) -> Result<FetchResponseKind<ScimGroup, Uuid>, MappedErrors> {
+    match get_resource(
+        &credential,
+        ScimResourceKind::Group,
+        group_id,
+        *scim_resource_fetching_repo,
+    )
+    .await?
+    {
+        Some(resource) => Ok(FetchResponseKind::Found(
+            ScimGroup::from_resource(&resource)?,
+        )),
+        None => Ok(FetchResponseKind::NotFound(Some(group_id))),
+    }
+}
@@ -0,0 +1,34 @@
+use super::shared::get_resource;
+use crate::domain::{
+    dtos::scim::{ScimCredential, ScimResourceKind, ScimUser},
+    entities::ScimResourceFetching,
+};
+
+use mycelium_base::{entities::FetchResponseKind, utils::errors::MappedErrors};
+use uuid::Uuid;
+
+/// Get a user provisioned to the tenant of a SCIM credential
+#[tracing::instrument(
+    name = "get_scim_user",
+    fields(tenant_id = %credential.tenant_id),
+    skip(credential, scim_resource_fetching_repo)
+)]
+pub async fn get_scim_user(
+    credential: ScimCredential,
+    user_id: Uuid,
+    scim_resource_fetching_repo: Box<&dyn ScimResourceFetching>,
+) -> Result<FetchResponseKind<ScimUser, Uuid>, MappedErrors> {
+    match get_resource(
+        &credential,
+        ScimResourceKind::User,
+        user_id,
+        *scim_resource_fetching_repo,
+    )
+    .await?
+    {
+        Some(resource) => Ok(FetchResponseKind::Found(
+            ScimUser::from_resource(&resource)?,
+        )),
+        None => Ok(FetchResponseKind::NotFound(Some(user_id))),
+    }
+}
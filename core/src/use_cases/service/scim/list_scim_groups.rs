@@ -0,0 +1,42 @@
+use super::shared::{filter_resources, list_resources};
+use crate::domain::{
+    dtos::scim::{
+        ScimCredential, ScimGroup, ScimListResponse, ScimResourceKind,
+    },
+    entities::ScimResourceFetching,
+};
+
+use mycelium_base::utils::errors::MappedErrors;
+
+/// List the groups provisioned to the tenant of a SCIM credential
+///
+/// Groups are filtered by the SCIM filter expression and paginated from the
+/// one-based start index.
+#[tracing::instrument(
+    name = "list_scim_groups",
+    fields(tenant_id = %credential.tenant_id),
+    skip(credential, scim_resource_fetching_repo)
+)]
+pub async fn list_scim_groups(
+    credential: ScimCredential,
+    filter: Option<String>,
+    start_index: Option<usize>,
+    count: Option<usize>,
+    scim_resource_fetching_repo: Box<&dyn ScimResourceFetching>,
+) -> Result<ScimListResponse<ScimGroup>, MappedErrors> {
+    let groups = list_resources(
+        &credential,
+        ScimResourceKind::Group,
+        *scim_resource_fetching_repo,
+    )
+    .await?
+    .iter()
+    .map(ScimGroup::from_resource)
+    .collect::<Result<Vec<ScimGroup>, MappedErrors>>()?;
+
+    Ok(ScimListResponse::paginate(
+        filter_resources(groups, filter)?,
+        start_index,
+        count,
+    ))
+}
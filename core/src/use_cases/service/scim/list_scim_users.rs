@@ -0,0 +1,42 @@
+use super::shared::{filter_resources, list_resources};
+use crate::domain::{
+    dtos::scim::{
+        ScimCredential, ScimListResponse, ScimResourceKind, ScimUser,
+    },
+    entities::ScimResourceFetching,
+};
+
+use mycelium_base::utils::errors::MappedErrors;
+
+/// List the users provisioned to the tenant of a SCIM credential
+///
+/// Users are filtered by the SCIM filter expression and paginated from the
+/// one-based start index.
+#[tracing::instrument(
+    name = "list_scim_users",
+    fields(tenant_id = %credential.tenant_id),
+    skip(credential, scim_resource_fetching_repo)
+)]
+pub async fn list_scim_users(
+    credential: ScimCredential,
+    filter: Option<String>,
+    start_index: Option<usize>,
+    count: Option<usize>,
+    scim_resource_fetching_repo: Box<&dyn ScimResourceFetching>,
+) -> Result<ScimListResponse<ScimUser>, MappedErrors> {
+    let users = list_resources(
+        &credential,
+        ScimResourceKind::User,
+        *scim_resource_fetching_repo,
+    )
+    .await?
+    .iter()
+    .map(ScimUser::from_resource)
+    .collect::<Result<Vec<ScimUser>, MappedErrors>>()?;
+
+    Ok(ScimListResponse::paginate(
+        filter_resources(users, filter)?,
+        start_index,
+        count,
+    ))
+}
@@ -0,0 +1,34 @@
+// ? ---------------------------------------------------------------------------
+// ? SCIM provisioning
+//
+// Use cases performed by the SCIM clients of the tenants, as identity
+// providers provisioning users and groups. Clients are authenticated by the
+// SCIM credentials issued by the tenant owners, and act as owners of the
+// credential tenant.
+//
+// ? ---------------------------------------------------------------------------
+
+mod authenticate_scim_credential;
+mod create_scim_group;
+mod create_scim_user;
+mod delete_scim_group;
+mod delete_scim_user;
+mod get_scim_group;
+mod get_scim_user;
+mod list_scim_groups;
+mod list_scim_users;
+mod shared;
+mod update_scim_group;
+mod update_scim_user;
+
+pub use authenticate_scim_credential::*;
+pub use create_scim_group::*;
+pub use create_scim_user::*;
+pub use delete_scim_group::*;
+pub use delete_scim_user::*;
+pub use get_scim_group::*;
+pub use get_scim_user::*;
+pub use list_scim_groups::*;
+pub use list_scim_users::*;
+pub use update_scim_group::*;
+pub use update_scim_user::*;
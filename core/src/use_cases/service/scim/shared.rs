@@ -0,0 +1,799 @@
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::{
+            account::VerboseStatus,
+            account_type::AccountType,
+            email::Email,
+            guest_role::Permission,
+            native_error_codes::NativeErrorCodes,
+            profile::{
+                LicensedResource, LicensedResources, Profile, TenantOwnership,
+                TenantsOwnership,
+            },
+            related_accounts::RelatedAccounts,
+            scim::{
+                ScimCredential, ScimErrorType, ScimFilter, ScimGroup,
+                ScimResource, ScimResourceKind, ScimUser,
+            },
+            webhook::{PayloadId, WebHookTrigger},
+            written_by::WrittenBy,
+        },
+        entities::{
+            AccountFetching, AccountRegistration, AccountUpdating,
+            EmailSuppressionFetching, EncryptionKeyFetching, GuestRoleFetching,
+            GuestUserDeletion, GuestUserOnAccountUpdating,
+            GuestUserRegistration, LocalMessageWrite,
+            NotificationRecipientFetching, ResourceAuditLogRegistration,
+            ScimResourceFetching, TenantFetching, WebHookRegistration,
+        },
+    },
+    models::AccountLifeCycle,
+    use_cases::{
+        role_scoped::{
+            subscriptions_manager::account::create_subscription_account,
+            subscriptions_manager::guest::{
+                guest_user_to_subscription_account,
+                revoke_user_guest_to_subscription_account,
+            },
+            users_manager::account::apply_account_activation_status,
+        },
+        support::register_webhook_dispatching_event,
+    },
+};
+
+use mycelium_base::{
+    entities::{
+        FetchManyResponseKind, FetchResponseKind, UpdatingResponseKind,
+    },
+    utils::errors::{use_case_err, MappedErrors},
+};
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Build the profile a SCIM credential acts with
+///
+/// SCIM requests go through the use cases of the tenant administration, which
+/// check the profile of the caller. The credential acts as an owner of its
+/// tenant, and is licensed to the given accounts with the given roles for the
+/// use cases that check an account license as well.
+pub(super) fn credential_profile(
+    credential: &ScimCredential,
+    licenses: Vec<(Uuid, SystemActor)>,
+) -> Profile {
+    let licensed_resources = match licenses.is_empty() {
+        true => None,
+        false => Some(LicensedResources::Records(
+            licenses
+                .into_iter()
+                .map(|(account_id, actor)| LicensedResource {
+                    acc_id: account_id,
+                    sys_acc: true,
+                    tenant_id: credential.tenant_id,
+                    acc_name: credential.name.to_owned(),
+                    role: actor.to_string(),
+                    role_id: Uuid::nil(),
+                    perm: Permission::Write,
+                    verified: true,
+                    permit_flags: None,
+                    deny_flags: None,
+                    permissions: None,
+                    valid_until: None,
+                })
+                .collect(),
+        )),
+    };
+
+    Profile::new(
+        vec![],
+        credential.id,
+        false,
+        false,
+        false,
+        true,
+        true,
+        true,
+        false,
+        false,
+        Some(VerboseStatus::Verified),
+        licensed_resources,
+        Some(TenantsOwnership::Records(vec![TenantOwnership {
+            id: credential.tenant_id,
+            name: credential.name.to_owned(),
+            since: credential.created,
+            parent_id: None,
+            inherited_from: None,
+        }])),
+    )
+}
+
+/// List all resources of a kind of the credential tenant
+pub(super) async fn list_resources(
+    credential: &ScimCredential,
+    kind: ScimResourceKind,
+    scim_resource_fetching_repo: &dyn ScimResourceFetching,
+) -> Result<Vec<ScimResource>, MappedErrors> {
+    Ok(
+        match scim_resource_fetching_repo
+            .list(credential.tenant_id, kind)
+            .await?
+        {
+            FetchManyResponseKind::Found(records) => records,
+            FetchManyResponseKind::FoundPaginated { records, .. } => records,
+            FetchManyResponseKind::NotFound => vec![],
+        },
+    )
+}
+
+/// Get a resource of a kind of the credential tenant
+pub(super) async fn get_resource(
+    credential: &ScimCredential,
+    kind: ScimResourceKind,
+    id: Uuid,
+    scim_resource_fetching_repo: &dyn ScimResourceFetching,
+) -> Result<Option<ScimResource>, MappedErrors> {
+    Ok(
+        match scim_resource_fetching_repo
+            .get(credential.tenant_id, kind, id)
+            .await?
+        {
+            FetchResponseKind::Found(resource) => Some(resource),
+            FetchResponseKind::NotFound(_) => None,
+        },
+    )
+}
+
+/// Keep the resources matching a filter
+pub(super) fn filter_resources<T: Serialize>(
+    resources: Vec<T>,
+    filter: Option<String>,
+) -> Result<Vec<T>, MappedErrors> {
+    let filter = match filter.filter(|filter| !filter.trim().is_empty()) {
+        Some(filter) => ScimFilter::parse(&filter)?,
+        None => return Ok(resources),
+    };
+
+    let mut matched = vec![];
+
+    for resource in resources {
+        let document = serde_json::to_value(&resource).map_err(|err| {
+            use_case_err(format!("Unable to serialize the resource: {err}"))
+        })?;
+
+        if filter.matches(&document) {
+            matched.push(resource);
+        }
+    }
+
+    Ok(matched)
+}
+
+/// Fail when another resource of the tenant has the same display name
+///
+/// User names and group display names are unique without regard to case.
+pub(super) fn check_uniqueness(
+    resources: &[ScimResource],
+    display_name: &str,
+    except: Option<Uuid>,
+) -> Result<(), MappedErrors> {
+    if resources.iter().any(|resource| {
+        Some(resource.id) != except
+            && resource.display_name.eq_ignore_ascii_case(display_name)
+    }) {
+        return uniqueness_error(format!(
+            "A resource named {display_name} was already provisioned"
+        ));
+    }
+
+    Ok(())
+}
+
+/// Fail with the SCIM error of a uniqueness conflict
+pub(super) fn uniqueness_error<T>(msg: String) -> Result<T, MappedErrors> {
+    use_case_err(msg)
+        .with_code(ScimErrorType::Uniqueness.as_str())
+        .with_exp_true()
+        .as_error()
+}
+
+/// Fail with the SCIM error of an invalid value
+pub(super) fn invalid_value<T>(msg: String) -> Result<T, MappedErrors> {
+    use_case_err(msg)
+        .with_code(ScimErrorType::InvalidValue.as_str())
+        .with_exp_true()
+        .as_error()
+}
+
+/// Get the account a resource maps onto
+pub(super) fn resource_account_id(
+    resource: &ScimResource,
+) -> Result<Uuid, MappedErrors> {
+    resource.account_id.ok_or_else(|| {
+        use_case_err(format!(
+            "The SCIM resource {} has no account",
+            resource.id
+        ))
+    })
+}
+
+/// The groups a user is a member of
+pub(super) fn groups_of_member(
+    groups: Vec<ScimResource>,
+    user_id: Uuid,
+) -> Result<Vec<ScimResource>, MappedErrors> {
+    let mut member_of = vec![];
+
+    for group in groups {
+        if ScimGroup::from_resource(&group)?
+            .member_ids()
+            .contains(&user_id)
+        {
+            member_of.push(group);
+        }
+    }
+
+    Ok(member_of)
+}
+
+/// Find a provisioned member user of a group
+pub(super) fn find_member(
+    users: &[ScimResource],
+    user_id: Uuid,
+) -> Result<ScimUser, MappedErrors> {
+    match users.iter().find(|user| user.id == user_id) {
+        Some(user) => ScimUser::from_resource(user),
+        None => invalid_value(format!("The member {user_id} was not found")),
+    }
+}
+
+/// Find the subscription account of the tenant a group maps onto
+///
+/// Goes through the subscription account use case when missing, so the
+/// account is audited and announced to the webhooks.
+pub(super) async fn get_or_create_subscription_account(
+    credential: &ScimCredential,
+    account_name: &str,
+    account_fetching_repo: &dyn AccountFetching,
+    account_registration_repo: &dyn AccountRegistration,
+    webhook_registration_repo: &dyn WebHookRegistration,
+    audit_repo: &dyn ResourceAuditLogRegistration,
+) -> Result<Uuid, MappedErrors> {
+    let tenant_id = credential.tenant_id;
+
+    let existing = match account_fetching_repo
+        .list(
+            RelatedAccounts::HasTenantWidePrivileges(tenant_id),
+            Some(account_name.to_string()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            AccountType::Subscription { tenant_id },
+            Some(100),
+            None,
+        )
+        .await?
+    {
+        FetchManyResponseKind::Found(records) => records,
+        FetchManyResponseKind::FoundPaginated { records, .. } => records,
+        FetchManyResponseKind::NotFound => vec![],
+    }
+    .into_iter()
+    .find(|account| account.name == account_name);
+
+    if let Some(account_id) = existing.and_then(|account| account.id) {
+        return Ok(account_id);
+    }
+
+    create_subscription_account(
+        credential_profile(credential, vec![]),
+        tenant_id,
+        account_name.to_string(),
+        Box::new(account_registration_repo),
+        Box::new(webhook_registration_repo),
+        Box::new(audit_repo),
+    )
+    .await?
+    .id
+    .ok_or_else(|| use_case_err("Account ID not found".to_string()))
+}
+
+/// Activate or deactivate the personal account of a provisioned user
+///
+/// Personal accounts are not owned by any tenant, so the credential only
+/// reaches the accounts its own provisioning created: the user must be a
+/// resource of the credential tenant mapped onto a personal account. Once
+/// these checks pass, the change goes through the same path as the users
+/// managers' activation change, and is also announced to the webhooks. Accounts not created by
+/// the provisioning are left untouched.
+pub(super) async fn set_user_account_activation(
+    credential: &ScimCredential,
+    user: &ScimResource,
+    is_active: bool,
+    account_fetching_repo: &dyn AccountFetching,
+    account_updating_repo: &dyn AccountUpdating,
+    webhook_registration_repo: &dyn WebHookRegistration,
+    audit_repo: &dyn ResourceAuditLogRegistration,
+) -> Result<(), MappedErrors> {
+    if !user.provisioned {
+        return Ok(());
+    }
+
+    if user.tenant_id != credential.tenant_id
+        || user.kind != ScimResourceKind::User
+    {
+        return use_case_err(format!(
+            "The SCIM user {} was not provisioned into the tenant {}",
+            user.id, credential.tenant_id
+        ))
+        .with_exp_true()
+        .as_error();
+    }
+
+    let account_id = resource_account_id(user)?;
+
+    let account = match account_fetching_repo
+        .get(
+            account_id,
+            RelatedAccounts::AllowedAccounts(vec![account_id]),
+        )
+        .await?
+    {
+        FetchResponseKind::NotFound(id) => {
+            return use_case_err(format!("Invalid account ID: {:?}", id))
+                .as_error()
+        }
+        FetchResponseKind::Found(account) => account,
+    };
+
+    if account.account_type != AccountType::User {
+        return use_case_err(format!(
+            "The SCIM user {} does not map onto a personal account",
+            user.id
+        ))
+        .with_exp_true()
+        .as_error();
+    }
+
+    if let UpdatingResponseKind::Updated(account) =
+        apply_account_activation_status(
+            account,
+            is_active,
+            WrittenBy::new_from_account(credential.id),
+            Some(credential.tenant_id),
+            Some(user.id),
+            Box::new(account_updating_repo),
+            Box::new(audit_repo),
+        )
+        .await?
+    {
+        register_webhook_dispatching_event(
+            Uuid::new_v4(),
+            WebHookTrigger::UserAccountUpdated,
+            account,
+            PayloadId::Uuid(account_id),
+            Box::new(webhook_registration_repo),
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Revoke the guest role a group grants to a member
+///
+/// Goes through the guest revocation use case, so the change is audited and
+/// announced to the webhooks. Members not granted the role are ignored.
+pub(super) async fn revoke_group_grant(
+    credential: &ScimCredential,
+    group: &ScimResource,
+    email: &Email,
+    guest_user_deletion_repo: &dyn GuestUserDeletion,
+    webhook_registration_repo: &dyn WebHookRegistration,
+    audit_repo: &dyn ResourceAuditLogRegistration,
+) -> Result<(), MappedErrors> {
+    let guest_role_id = group.guest_role_id.ok_or_else(|| {
+        use_case_err(format!("The SCIM group {} has no guest role", group.id))
+    })?;
+
+    revoke_user_guest_to_subscription_account(
+        credential_profile(credential, vec![]),
+        credential.tenant_id,
+        resource_account_id(group)?,
+        guest_role_id,
+        email.email(),
+        Box::new(guest_user_deletion_repo),
+        Box::new(webhook_registration_repo),
+        Box::new(audit_repo),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// The repositories needed to grant the guest roles of the groups
+pub(super) struct ScimGuestGrants<'a> {
+    pub(super) credential: &'a ScimCredential,
+    pub(super) life_cycle_settings: AccountLifeCycle,
+    pub(super) account_fetching_repo: &'a dyn AccountFetching,
+    pub(super) guest_role_fetching_repo: &'a dyn GuestRoleFetching,
+    pub(super) guest_user_registration_repo: &'a dyn GuestUserRegistration,
+    pub(super) guest_user_on_account_updating_repo:
+        &'a dyn GuestUserOnAccountUpdating,
+    pub(super) message_sending_repo: &'a dyn LocalMessageWrite,
+    pub(super) email_suppression_fetching_repo:
+        &'a dyn EmailSuppressionFetching,
+    pub(super) tenant_fetching_repo: &'a dyn TenantFetching,
+    pub(super) notification_recipient_fetching_repo:
+        &'a dyn NotificationRecipientFetching,
+    pub(super) encryption_key_fetching_repo: &'a dyn EncryptionKeyFetching,
+    pub(super) webhook_registration_repo: &'a dyn WebHookRegistration,
+    pub(super) audit_repo: &'a dyn ResourceAuditLogRegistration,
+}
+
+impl ScimGuestGrants<'_> {
+    /// Invite a member to the guest role a group grants
+    ///
+    /// Goes through the guest invitation use case, so the invitation is
+    /// audited, notified and announced to the webhooks. Members already
+    /// invited are left as they are.
+    pub(super) async fn grant(
+        &self,
+        group: &ScimResource,
+        email: Email,
+    ) -> Result<(), MappedErrors> {
+        let account_id = resource_account_id(group)?;
+
+        let guest_role_id = group.guest_role_id.ok_or_else(|| {
+            use_case_err(format!(
+                "The SCIM group {} has no guest role",
+                group.id
+            ))
+        })?;
+
+        match guest_user_to_subscription_account(
+            credential_profile(
+                self.credential,
+                vec![(account_id, SystemActor::SubscriptionsManager)],
+            ),
+            self.credential.tenant_id,
+            email,
+            guest_role_id,
+            account_id,
+            None,
+            None,
+            self.life_cycle_settings.to_owned(),
+            Box::new(self.account_fetching_repo),
+            Box::new(self.guest_role_fetching_repo),
+            Box::new(self.guest_user_registration_repo),
+            Box::new(self.guest_user_on_account_updating_repo),
+            Box::new(self.message_sending_repo),
+            Box::new(self.email_suppression_fetching_repo),
+            Box::new(self.tenant_fetching_repo),
+            Box::new(self.notification_recipient_fetching_repo),
+            Box::new(self.encryption_key_fetching_repo),
+            Box::new(self.webhook_registration_repo),
+            Box::new(self.audit_repo),
+        )
+        .await
+        {
+            Ok(_) => Ok(()),
+            Err(err)
+                if err.has_str_code(NativeErrorCodes::MYC00017.as_str()) =>
+            {
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+// ? ---------------------------------------------------------------------------
+// ? TESTS
+// ? ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        dtos::{
+            account::{Account, AccountMetaKey},
+            telegram::TelegramUserId,
+            webhook::{WebHook, WebHookPayloadArtifact},
+        },
+        entities::MockResourceAuditLogRegistration,
+    };
+
+    use async_trait::async_trait;
+    use mycelium_base::entities::CreateResponseKind;
+    use std::{
+        collections::HashMap,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    struct FakeAccountFetchingRepo {
+        account_type: AccountType,
+    }
+
+    #[async_trait]
+    impl AccountFetching for FakeAccountFetchingRepo {
+        async fn get(
+            &self,
+            id: Uuid,
+            related_accounts: RelatedAccounts,
+        ) -> Result<FetchResponseKind<Account, Uuid>, MappedErrors> {
+            assert_eq!(
+                related_accounts,
+                RelatedAccounts::AllowedAccounts(vec![id])
+            );
+
+            let mut account = Account::new_actor_related_account(
+                "Provisioned User".to_string(),
+                SystemActor::UsersManager,
+                false,
+                None,
+            );
+            account.id = Some(id);
+            account.account_type = self.account_type.to_owned();
+
+            Ok(FetchResponseKind::Found(account))
+        }
+
+        async fn list(
+            &self,
+            _: RelatedAccounts,
+            _: Option<String>,
+            _: Option<bool>,
+            _: Option<bool>,
+            _: Option<bool>,
+            _: Option<bool>,
+            _: Option<bool>,
+            _: Option<Uuid>,
+            _: Option<String>,
+            _: Option<Uuid>,
+            _: AccountType,
+            _: Option<i32>,
+            _: Option<i32>,
+        ) -> Result<FetchManyResponseKind<Account>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn get_by_telegram_id(
+            &self,
+            _: TelegramUserId,
+        ) -> Result<FetchResponseKind<Account, i64>, MappedErrors> {
+            unimplemented!()
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeAccountUpdatingRepo {
+        updates: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl AccountUpdating for FakeAccountUpdatingRepo {
+        async fn update(
+            &self,
+            account: Account,
+        ) -> Result<UpdatingResponseKind<Account>, MappedErrors> {
+            self.updates.fetch_add(1, Ordering::SeqCst);
+
+            Ok(UpdatingResponseKind::Updated(account))
+        }
+
+        async fn update_own_account_name(
+            &self,
+            _: Uuid,
+            _: String,
+        ) -> Result<UpdatingResponseKind<Account>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn update_account_type(
+            &self,
+            _: Uuid,
+            _: AccountType,
+        ) -> Result<UpdatingResponseKind<Account>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn update_account_meta(
+            &self,
+            _: Uuid,
+            _: AccountMetaKey,
+            _: String,
+        ) -> Result<
+            UpdatingResponseKind<HashMap<AccountMetaKey, String>>,
+            MappedErrors,
+        > {
+            unimplemented!()
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeWebHookRegistrationRepo {
+        events: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl WebHookRegistration for FakeWebHookRegistrationRepo {
+        async fn create(
+            &self,
+            _: WebHook,
+        ) -> Result<CreateResponseKind<WebHook>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn register_execution_event(
+            &self,
+            _: WebHookPayloadArtifact,
+        ) -> Result<CreateResponseKind<Uuid>, MappedErrors> {
+            self.events.fetch_add(1, Ordering::SeqCst);
+
+            Ok(CreateResponseKind::Created(Uuid::new_v4()))
+        }
+    }
+
+    fn credential() -> ScimCredential {
+        ScimCredential::new(
+            Uuid::new_v4(),
+            "Okta".to_string(),
+            WrittenBy::new_from_account(Uuid::new_v4()),
+        )
+        .0
+    }
+
+    fn provisioned_user(tenant_id: Uuid) -> ScimResource {
+        let mut user = ScimResource::new(
+            tenant_id,
+            ScimResourceKind::User,
+            "jane.doe".to_string(),
+            serde_json::json!({}),
+        );
+        user.account_id = Some(Uuid::new_v4());
+        user.provisioned = true;
+
+        user
+    }
+
+    #[tokio::test]
+    async fn user_activation_changes_personal_accounts_of_the_credential_tenant(
+    ) {
+        let credential = credential();
+        let user = provisioned_user(credential.tenant_id);
+        let tenant_id = credential.tenant_id;
+
+        let fetching_repo = FakeAccountFetchingRepo {
+            account_type: AccountType::User,
+        };
+        let updating_repo = FakeAccountUpdatingRepo::default();
+        let webhook_repo = FakeWebHookRegistrationRepo::default();
+
+        let mut audit_mock = MockResourceAuditLogRegistration::new();
+        audit_mock
+            .expect_create()
+            .times(1)
+            .withf(move |event| event.tenant_id == Some(tenant_id))
+            .returning(|_| Ok(()));
+
+        set_user_account_activation(
+            &credential,
+            &user,
+            false,
+            &fetching_repo,
+            &updating_repo,
+            &webhook_repo,
+            &audit_mock,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(updating_repo.updates.load(Ordering::SeqCst), 1);
+        assert_eq!(webhook_repo.events.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn user_activation_refuses_users_of_other_tenants() {
+        let credential = credential();
+        let user = provisioned_user(Uuid::new_v4());
+
+        let fetching_repo = FakeAccountFetchingRepo {
+            account_type: AccountType::User,
+        };
+        let updating_repo = FakeAccountUpdatingRepo::default();
+        let webhook_repo = FakeWebHookRegistrationRepo::default();
+
+        let mut audit_mock = MockResourceAuditLogRegistration::new();
+        audit_mock.expect_create().times(0);
+
+        assert!(set_user_account_activation(
+            &credential,
+            &user,
+            false,
+            &fetching_repo,
+            &updating_repo,
+            &webhook_repo,
+            &audit_mock,
+        )
+        .await
+        .is_err());
+
+        assert_eq!(updating_repo.updates.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn user_activation_refuses_accounts_other_than_personal_ones() {
+        let credential = credential();
+        let user = provisioned_user(credential.tenant_id);
+
+        let fetching_repo = FakeAccountFetchingRepo {
+            account_type: AccountType::Staff,
+        };
+        let updating_repo = FakeAccountUpdatingRepo::default();
+        let webhook_repo = FakeWebHookRegistrationRepo::default();
+
+        let mut audit_mock = MockResourceAuditLogRegistration::new();
+        audit_mock.expect_create().times(0);
+
+        assert!(set_user_account_activation(
+            &credential,
+            &user,
+            false,
+            &fetching_repo,
+            &updating_repo,
+            &webhook_repo,
+            &audit_mock,
+        )
+        .await
+        .is_err());
+
+        assert_eq!(updating_repo.updates.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn credential_profiles_pass_the_checks_of_the_reused_use_cases() {
+        let tenant_id = Uuid::new_v4();
+        let account_id = Uuid::new_v4();
+
+        let (credential, _) = ScimCredential::new(
+            tenant_id,
+            "Okta".to_string(),
+            WrittenBy::new_from_account(Uuid::new_v4()),
+        );
+
+        let profile = credential_profile(
+            &credential,
+            vec![(account_id, SystemActor::SubscriptionsManager)],
+        );
+
+        assert!(profile.with_tenant_ownership_or_error(tenant_id).is_ok());
+        assert!(profile
+            .with_tenant_ownership_or_error(Uuid::new_v4())
+            .is_err());
+
+        match profile
+            .on_account(account_id)
+            .with_write_access()
+            .with_roles(vec![SystemActor::SubscriptionsManager])
+            .get_related_account_or_error()
+            .unwrap()
+        {
+            RelatedAccounts::AllowedAccounts(ids) => {
+                assert_eq!(ids, vec![account_id])
+            }
+            _ => panic!("Expected the licensed account"),
+        }
+
+        assert!(profile
+            .with_system_accounts_access()
+            .with_write_access()
+            .with_roles(vec![SystemActor::UsersManager])
+            .get_related_account_or_error()
+            .is_err());
+    }
+}
@@ -0,0 +1,172 @@
+use super::shared::{
+    check_uniqueness, find_member, get_resource, list_resources,
+    revoke_group_grant, ScimGuestGrants,
+};
+use crate::{
+    domain::{
+        dtos::scim::{ScimCredential, ScimGroup, ScimResourceKind, ScimUpdate},
+        entities::{
+            AccountFetching, EmailSuppressionFetching, EncryptionKeyFetching,
+            GuestRoleFetching, GuestUserDeletion, GuestUserOnAccountUpdating,
+            GuestUserRegistration, LocalMessageWrite,
+            NotificationRecipientFetching, ResourceAuditLogRegistration,
+            ScimResourceFetching, ScimResourceUpdating, TenantFetching,
+            WebHookRegistration,
+        },
+    },
+    models::AccountLifeCycle,
+};
+
+use chrono::Local;
+use mycelium_base::{
+    entities::{FetchResponseKind, UpdatingResponseKind},
+    utils::errors::{use_case_err, MappedErrors},
+};
+use uuid::Uuid;
+
+/// Replace or patch a group provisioned to the tenant of a SCIM credential
+///
+/// The active members added to the group are invited to its guest role, and
+/// the members removed from it lose the role. The account and the role a
+/// group maps onto are fixed when the group is provisioned.
+#[tracing::instrument(
+    name = "update_scim_group",
+    fields(tenant_id = %credential.tenant_id),
+    skip_all
+)]
+pub async fn update_scim_group(
+    credential: ScimCredential,
+    group_id: Uuid,
+    update: ScimUpdate,
+    life_cycle_settings: AccountLifeCycle,
+    scim_resource_fetching_repo: Box<&dyn ScimResourceFetching>,
+    scim_resource_updating_repo: Box<&dyn ScimResourceUpdating>,
+    account_fetching_repo: Box<&dyn AccountFetching>,
+    guest_role_fetching_repo: Box<&dyn GuestRoleFetching>,
+    guest_user_registration_repo: Box<&dyn GuestUserRegistration>,
+    guest_user_on_account_updating_repo: Box<&dyn GuestUserOnAccountUpdating>,
+    guest_user_deletion_repo: Box<&dyn GuestUserDeletion>,
+    message_sending_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
+    tenant_fetching_repo: Box<&dyn TenantFetching>,
+    notification_recipient_fetching_repo: Box<
+        &dyn NotificationRecipientFetching,
+    >,
+    encryption_key_fetching_repo: Box<&dyn EncryptionKeyFetching>,
+    webhook_registration_repo: Box<&dyn WebHookRegistration>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<FetchResponseKind<ScimGroup, Uuid>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Apply the change to the group
+    // ? -----------------------------------------------------------------------
+
+    let mut resource = match get_resource(
+        &credential,
+        ScimResourceKind::Group,
+        group_id,
+        *scim_resource_fetching_repo,
+    )
+    .await?
+    {
+        Some(resource) => resource,
+        None => return Ok(FetchResponseKind::NotFound(Some(group_id))),
+    };
+
+    let current = ScimGroup::from_document(resource.attributes.to_owned())?;
+    let group =
+        ScimGroup::from_document(update.apply_to(&resource.attributes)?)?;
+
+    if !group
+        .display_name
+        .eq_ignore_ascii_case(&resource.display_name)
+    {
+        check_uniqueness(
+            &list_resources(
+                &credential,
+                ScimResourceKind::Group,
+                *scim_resource_fetching_repo,
+            )
+            .await?,
+            &group.display_name,
+            Some(group_id),
+        )?;
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Propagate the membership changes
+    // ? -----------------------------------------------------------------------
+
+    let users = list_resources(
+        &credential,
+        ScimResourceKind::User,
+        *scim_resource_fetching_repo,
+    )
+    .await?;
+
+    let current_ids = current.member_ids();
+    let ids = group.member_ids();
+
+    let mut added = vec![];
+
+    for member_id in ids.iter().filter(|id| !current_ids.contains(id)) {
+        added.push(find_member(&users, *member_id)?);
+    }
+
+    let removed = current_ids
+        .iter()
+        .filter(|id| !ids.contains(id))
+        .filter_map(|id| find_member(&users, *id).ok())
+        .collect::<Vec<_>>();
+
+    let grants = ScimGuestGrants {
+        credential: &credential,
+        life_cycle_settings,
+        account_fetching_repo: *account_fetching_repo,
+        guest_role_fetching_repo: *guest_role_fetching_repo,
+        guest_user_registration_repo: *guest_user_registration_repo,
+        guest_user_on_account_updating_repo:
+            *guest_user_on_account_updating_repo,
+        message_sending_repo: *message_sending_repo,
+        email_suppression_fetching_repo: *email_suppression_fetching_repo,
+        tenant_fetching_repo: *tenant_fetching_repo,
+        notification_recipient_fetching_repo:
+            *notification_recipient_fetching_repo,
+        encryption_key_fetching_repo: *encryption_key_fetching_repo,
+        webhook_registration_repo: *webhook_registration_repo,
+        audit_repo: *audit_repo,
+    };
+
+    for member in added.iter().filter(|member| member.active) {
+        grants.grant(&resource, member.email()?).await?;
+    }
+
+    for member in removed.iter().filter(|member| member.active) {
+        revoke_group_grant(
+            &credential,
+            &resource,
+            &member.email()?,
+            *guest_user_deletion_repo,
+            *webhook_registration_repo,
+            *audit_repo,
+        )
+        .await?;
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Store the group
+    // ? -----------------------------------------------------------------------
+
+    resource.display_name = group.display_name.to_owned();
+    resource.external_id = group.external_id.to_owned();
+    resource.attributes = group.to_document()?;
+    resource.updated = Some(Local::now());
+
+    match scim_resource_updating_repo.update(resource).await? {
+        UpdatingResponseKind::Updated(resource) => Ok(
+            FetchResponseKind::Found(ScimGroup::from_resource(&resource)?),
+        ),
+        UpdatingResponseKind::NotUpdated(_, msg) => {
+            use_case_err(format!("Group not updated: {msg}")).as_error()
+        }
+    }
+}
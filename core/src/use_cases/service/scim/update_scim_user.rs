@@ -0,0 +1,194 @@
+use super::shared::{
+    check_uniqueness, get_resource, groups_of_member, invalid_value,
+    list_resources, revoke_group_grant, set_user_account_activation,
+    ScimGuestGrants,
+};
+use crate::{
+    domain::{
+        dtos::scim::{ScimCredential, ScimResourceKind, ScimUpdate, ScimUser},
+        entities::{
+            AccountFetching, AccountUpdating, EmailSuppressionFetching,
+            EncryptionKeyFetching, GuestRoleFetching, GuestUserDeletion,
+            GuestUserOnAccountUpdating, GuestUserRegistration,
+            LocalMessageWrite, NotificationRecipientFetching,
+            ResourceAuditLogRegistration, ScimResourceFetching,
+            ScimResourceUpdating, TenantFetching, WebHookRegistration,
+        },
+    },
+    models::AccountLifeCycle,
+};
+
+use chrono::Local;
+use mycelium_base::{
+    entities::{FetchResponseKind, UpdatingResponseKind},
+    utils::errors::{use_case_err, MappedErrors},
+};
+use uuid::Uuid;
+
+/// Replace or patch a user provisioned to the tenant of a SCIM credential
+///
+/// Deactivating a user revokes the guest roles its groups grant and
+/// deactivates its personal account, when created by the provisioning.
+/// Reactivating it restores both. The email of a user can not change, since
+/// it identifies the Mycelium user.
+#[tracing::instrument(
+    name = "update_scim_user",
+    fields(tenant_id = %credential.tenant_id),
+    skip_all
+)]
+pub async fn update_scim_user(
+    credential: ScimCredential,
+    user_id: Uuid,
+    update: ScimUpdate,
+    life_cycle_settings: AccountLifeCycle,
+    scim_resource_fetching_repo: Box<&dyn ScimResourceFetching>,
+    scim_resource_updating_repo: Box<&dyn ScimResourceUpdating>,
+    account_fetching_repo: Box<&dyn AccountFetching>,
+    account_updating_repo: Box<&dyn AccountUpdating>,
+    guest_role_fetching_repo: Box<&dyn GuestRoleFetching>,
+    guest_user_registration_repo: Box<&dyn GuestUserRegistration>,
+    guest_user_on_account_updating_repo: Box<&dyn GuestUserOnAccountUpdating>,
+    guest_user_deletion_repo: Box<&dyn GuestUserDeletion>,
+    message_sending_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
+    tenant_fetching_repo: Box<&dyn TenantFetching>,
+    notification_recipient_fetching_repo: Box<
+        &dyn NotificationRecipientFetching,
+    >,
+    encryption_key_fetching_repo: Box<&dyn EncryptionKeyFetching>,
+    webhook_registration_repo: Box<&dyn WebHookRegistration>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<FetchResponseKind<ScimUser, Uuid>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Apply the change to the user
+    // ? -----------------------------------------------------------------------
+
+    let mut resource = match get_resource(
+        &credential,
+        ScimResourceKind::User,
+        user_id,
+        *scim_resource_fetching_repo,
+    )
+    .await?
+    {
+        Some(resource) => resource,
+        None => return Ok(FetchResponseKind::NotFound(Some(user_id))),
+    };
+
+    let current = ScimUser::from_document(resource.attributes.to_owned())?;
+    let user = ScimUser::from_document(update.apply_to(&resource.attributes)?)?;
+
+    let email = current.email()?;
+
+    if user.email()? != email {
+        return invalid_value(format!(
+            "The email of the user {user_id} can not change"
+        ));
+    }
+
+    if !user.user_name.eq_ignore_ascii_case(&resource.display_name) {
+        check_uniqueness(
+            &list_resources(
+                &credential,
+                ScimResourceKind::User,
+                *scim_resource_fetching_repo,
+            )
+            .await?,
+            &user.user_name,
+            Some(user_id),
+        )?;
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Propagate the activation status
+    // ? -----------------------------------------------------------------------
+
+    if current.active != user.active {
+        let groups = groups_of_member(
+            list_resources(
+                &credential,
+                ScimResourceKind::Group,
+                *scim_resource_fetching_repo,
+            )
+            .await?,
+            user_id,
+        )?;
+
+        if user.active {
+            set_user_account_activation(
+                &credential,
+                &resource,
+                true,
+                *account_fetching_repo,
+                *account_updating_repo,
+                *webhook_registration_repo,
+                *audit_repo,
+            )
+            .await?;
+
+            let grants = ScimGuestGrants {
+                credential: &credential,
+                life_cycle_settings,
+                account_fetching_repo: *account_fetching_repo,
+                guest_role_fetching_repo: *guest_role_fetching_repo,
+                guest_user_registration_repo: *guest_user_registration_repo,
+                guest_user_on_account_updating_repo:
+                    *guest_user_on_account_updating_repo,
+                message_sending_repo: *message_sending_repo,
+                email_suppression_fetching_repo:
+                    *email_suppression_fetching_repo,
+                tenant_fetching_repo: *tenant_fetching_repo,
+                notification_recipient_fetching_repo:
+                    *notification_recipient_fetching_repo,
+                encryption_key_fetching_repo: *encryption_key_fetching_repo,
+                webhook_registration_repo: *webhook_registration_repo,
+                audit_repo: *audit_repo,
+            };
+
+            for group in groups.iter() {
+                grants.grant(group, email.to_owned()).await?;
+            }
+        } else {
+            for group in groups.iter() {
+                revoke_group_grant(
+                    &credential,
+                    group,
+                    &email,
+                    *guest_user_deletion_repo,
+                    *webhook_registration_repo,
+                    *audit_repo,
+                )
+                .await?;
+            }
+
+            set_user_account_activation(
+                &credential,
+                &resource,
+                false,
+                *account_fetching_repo,
+                *account_updating_repo,
+                *webhook_registration_repo,
+                *audit_repo,
+            )
+            .await?;
+        }
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Store the user
+    // ? -----------------------------------------------------------------------
+
+    resource.display_name = user.user_name.to_owned();
+    resource.external_id = user.external_id.to_owned();
+    resource.attributes = user.to_document()?;
+    resource.updated = Some(Local::now());
+
+    match scim_resource_updating_repo.update(resource).await? {
+        UpdatingResponseKind::Updated(resource) => Ok(
+            FetchResponseKind::Found(ScimUser::from_resource(&resource)?),
+        ),
+        UpdatingResponseKind::NotUpdated(_, msg) => {
+            use_case_err(format!("User not updated: {msg}")).as_error()
+        }
+    }
+}
@@ -0,0 +1,670 @@
+// ? ---------------------------------------------------------------------------
+// ? archive_expired_resource_audit_rows
+//
+// Applies the retention policy to every chain of `resource_audit_log`. Backs
+// `myc-cli audit archive` and the periodic retention run of the API.
+//
+// For each chain not on legal hold, the rows older than its retention are
+// written to a signed archive file and only then deleted, in the same
+// transaction that records the archive. Archiving always takes a seq prefix
+// of the chain -- it stops at the first row still within retention -- so
+// what stays in the log is an unbroken chain that picks up where the latest
+// archive left off. Rows written before hash chaining existed are archived
+// by `created_at` alone.
+//
+// The rows are re-verified while they are archived: a chain that does not
+// verify is never archived, since that would launder the tampering into a
+// validly signed archive.
+// ? ---------------------------------------------------------------------------
+
+use crate::{
+    domain::{
+        dtos::resource_audit_log::{
+            ResourceAuditArchive, ResourceAuditRedaction,
+            ResourceAuditRetentionPolicy, ResourceAuditRetentionReport,
+            RESOURCE_AUDIT_CHAIN_GENESIS_HASH,
+        },
+        entities::{
+            EncryptionKeyFetching, ResourceAuditLogArchival,
+            ResourceAuditLogFetching,
+        },
+        utils::{ResourceAuditArchiveStore, ResourceAuditArchiveWriter},
+    },
+    models::AccountLifeCycle,
+    use_cases::shared::audit::list_resource_audit_redactions,
+};
+
+use chrono::{DateTime, Duration, SubsecRound, Utc};
+use mycelium_base::{
+    entities::FetchManyResponseKind,
+    utils::errors::{use_case_err, MappedErrors},
+};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use uuid::Uuid;
+
+const ARCHIVAL_PAGE_SIZE: i64 = 500;
+
+#[tracing::instrument(name = "archive_expired_resource_audit_rows", skip_all)]
+pub async fn archive_expired_resource_audit_rows(
+    default_retention_days: Option<u64>,
+    store: &ResourceAuditArchiveStore,
+    life_cycle_settings: AccountLifeCycle,
+    fetching_repo: Box<&dyn ResourceAuditLogFetching>,
+    archival_repo: Box<&dyn ResourceAuditLogArchival>,
+    encryption_key_repo: Box<&dyn EncryptionKeyFetching>,
+) -> Result<Vec<ResourceAuditRetentionReport>, MappedErrors> {
+    let policies: BTreeMap<Option<Uuid>, ResourceAuditRetentionPolicy> =
+        match fetching_repo.list_retention_policies().await? {
+            FetchManyResponseKind::NotFound => vec![],
+            FetchManyResponseKind::Found(records) => records,
+            FetchManyResponseKind::FoundPaginated { records, .. } => records,
+        }
+        .into_iter()
+        .map(|policy| (policy.chain_tenant_id, policy))
+        .collect();
+
+    let mut chains: BTreeSet<Option<Uuid>> = policies.keys().cloned().collect();
+
+    match fetching_repo.list_chain_tenants().await? {
+        FetchManyResponseKind::NotFound => {}
+        FetchManyResponseKind::Found(records)
+        | FetchManyResponseKind::FoundPaginated { records, .. } => {
+            chains.extend(records)
+        }
+    }
+
+    let redactions = list_resource_audit_redactions(*fetching_repo).await?;
+
+    let run = RetentionRun {
+        default_retention_days,
+        store,
+        life_cycle_settings,
+        redactions,
+        fetching_repo: *fetching_repo,
+        archival_repo: *archival_repo,
+        encryption_key_repo: *encryption_key_repo,
+    };
+
+    let mut reports = vec![];
+
+    for chain_tenant_id in chains {
+        let policy =
+            policies.get(&chain_tenant_id).cloned().unwrap_or_else(|| {
+                ResourceAuditRetentionPolicy::default_for(chain_tenant_id)
+            });
+
+        let mut report = ResourceAuditRetentionReport::new(chain_tenant_id);
+
+        if let Err(err) = run.apply(&policy, &mut report).await {
+            tracing::error!(
+                ?chain_tenant_id,
+                "unable to apply audit retention: {err}"
+            );
+
+            report.error = Some(err.msg());
+        }
+
+        reports.push(report);
+    }
+
+    Ok(reports)
+}
+
+struct RetentionRun<'a> {
+    default_retention_days: Option<u64>,
+    store: &'a ResourceAuditArchiveStore,
+    life_cycle_settings: AccountLifeCycle,
+    redactions: HashSet<ResourceAuditRedaction>,
+    fetching_repo: &'a dyn ResourceAuditLogFetching,
+    archival_repo: &'a dyn ResourceAuditLogArchival,
+    encryption_key_repo: &'a dyn EncryptionKeyFetching,
+}
+
+impl RetentionRun<'_> {
+    async fn apply(
+        &self,
+        policy: &ResourceAuditRetentionPolicy,
+        report: &mut ResourceAuditRetentionReport,
+    ) -> Result<(), MappedErrors> {
+        let chain_tenant_id = policy.chain_tenant_id;
+
+        report.legal_hold = policy.legal_hold;
+        report.retention_days =
+            policy.effective_retention_days(self.default_retention_days);
+
+        if policy.legal_hold {
+            return Ok(());
+        }
+
+        let archives =
+            match self.fetching_repo.list_archives(chain_tenant_id).await? {
+                FetchManyResponseKind::NotFound => vec![],
+                FetchManyResponseKind::Found(records) => records,
+                FetchManyResponseKind::FoundPaginated { records, .. } => {
+                    records
+                }
+            };
+
+        //
+        // Rows restored from an archive for querying go back to the archive
+        // first, whatever the retention says.
+        //
+        if !archives.is_empty() {
+            report.purged_rows += self
+                .archival_repo
+                .purge_archived_rows(chain_tenant_id)
+                .await?;
+        }
+
+        let Some(retention_days) = report.retention_days else {
+            return Ok(());
+        };
+
+        //
+        // Truncated up front to the precision the signed manifest keeps, so
+        // the rows selected here are exactly the rows the manifest covers.
+        //
+        let cutoff = (Utc::now() - Duration::days(retention_days as i64))
+            .trunc_subsecs(6);
+
+        let archive_id = Uuid::new_v4();
+        let location =
+            ResourceAuditArchiveStore::archive_key(chain_tenant_id, archive_id);
+
+        let mut writer = self.store.writer(&location)?;
+
+        let range = match self
+            .write_expired_rows(&mut writer, &archives, chain_tenant_id, cutoff)
+            .await
+        {
+            Ok(range) => range,
+            Err(err) => {
+                writer.abort();
+                return Err(err);
+            }
+        };
+
+        if writer.rows() == 0 {
+            writer.abort();
+            return Ok(());
+        }
+
+        let row_count = writer.rows();
+        let sha256 = writer.finish()?;
+
+        let archive = match self
+            .sign(ResourceAuditArchive {
+                id: archive_id,
+                chain_tenant_id,
+                first_seq: range.as_ref().map(|range| range.0),
+                last_seq: range.as_ref().map(|range| range.2),
+                first_prev_hash: range.as_ref().map(|range| range.1.to_owned()),
+                last_hash: range.as_ref().map(|range| range.3.to_owned()),
+                cutoff,
+                row_count,
+                location: location.to_owned(),
+                sha256,
+                signature: String::new(),
+                created_at: Utc::now(),
+            })
+            .await
+        {
+            Ok(archive) => archive,
+            Err(err) => {
+                self.store.remove(&location);
+                return Err(err);
+            }
+        };
+
+        //
+        // The archive is durable before any row is deleted. If recording it
+        // fails (e.g. a concurrent run archived the same range first), the
+        // file is discarded and the rows stay in the log.
+        //
+        let purged = match self.store.write_manifest(&archive) {
+            Ok(_) => {
+                self.archival_repo
+                    .register_archive_and_purge(archive.to_owned())
+                    .await
+            }
+            Err(err) => Err(err),
+        };
+
+        match purged {
+            Ok(purged) => {
+                report.purged_rows += purged;
+                report.archive = Some(archive);
+                Ok(())
+            }
+            Err(err) => {
+                self.store.remove(&location);
+                Err(err)
+            }
+        }
+    }
+
+    /// Write the expired rows of the chain not covered by an archive yet.
+    /// Returns the archived seq range as `(first_seq, first_prev_hash,
+    /// last_seq, last_hash)`, if any chained row was written.
+    async fn write_expired_rows(
+        &self,
+        writer: &mut ResourceAuditArchiveWriter,
+        archives: &[ResourceAuditArchive],
+        chain_tenant_id: Option<Uuid>,
+        cutoff: DateTime<Utc>,
+    ) -> Result<Option<(i64, String, i64, String)>, MappedErrors> {
+        // ? -------------------------------------------------------------------
+        // ? Rows written before hash chaining existed
+        // ? -------------------------------------------------------------------
+
+        let previous_cutoff =
+            archives.iter().map(|archive| archive.cutoff).max();
+        let mut skip = 0;
+
+        loop {
+            let rows = match self
+                .fetching_repo
+                .list_unchained_page(
+                    chain_tenant_id,
+                    previous_cutoff,
+                    cutoff,
+                    ARCHIVAL_PAGE_SIZE,
+                    skip,
+                )
+                .await?
+            {
+                FetchManyResponseKind::NotFound => break,
+                FetchManyResponseKind::Found(records) => records,
+                FetchManyResponseKind::FoundPaginated { records, .. } => {
+                    records
+                }
+            };
+
+            let page_len = rows.len() as i64;
+
+            for row in rows {
+                writer.append(&row)?;
+            }
+
+            if page_len < ARCHIVAL_PAGE_SIZE {
+                break;
+            }
+
+            skip += page_len;
+        }
+
+        // ? -------------------------------------------------------------------
+        // ? Chained rows, resuming after the latest archive
+        // ? -------------------------------------------------------------------
+
+        let (mut expected_seq, mut expected_prev_hash) = archives
+            .iter()
+            .filter_map(|archive| {
+                Some((archive.last_seq? + 1, archive.last_hash.to_owned()?))
+            })
+            .max_by_key(|(seq, _)| *seq)
+            .unwrap_or((1, RESOURCE_AUDIT_CHAIN_GENESIS_HASH.to_string()));
+
+        let mut range: Option<(i64, String, i64, String)> = None;
+
+        'pages: loop {
+            let rows = match self
+                .fetching_repo
+                .list_chain_page(
+                    chain_tenant_id,
+                    expected_seq - 1,
+                    ARCHIVAL_PAGE_SIZE,
+                )
+                .await?
+            {
+                FetchManyResponseKind::NotFound => break,
+                FetchManyResponseKind::Found(records) => records,
+                FetchManyResponseKind::FoundPaginated { records, .. } => {
+                    records
+                }
+            };
+
+            let page_len = rows.len() as i64;
+
+            for row in rows {
+                let Some(link) = row.chain.to_owned() else {
+                    continue;
+                };
+
+                if row.created_at >= cutoff {
+                    break 'pages;
+                }
+
+                let intact = link.seq == expected_seq
+                    && link.prev_hash == expected_prev_hash
+                    && row.content_verifies(&self.redactions);
+
+                if !intact {
+                    return use_case_err(format!(
+                        "Audit chain does not verify at seq {}; run `myc-cli \
+                         audit verify` before archiving it",
+                        link.seq
+                    ))
+                    .with_exp_true()
+                    .as_error();
+                }
+
+                writer.append(&row)?;
+
+                range = Some(match range {
+                    Some((first_seq, first_prev_hash, ..)) => (
+                        first_seq,
+                        first_prev_hash,
+                        link.seq,
+                        link.hash.to_owned(),
+                    ),
+                    None => (
+                        link.seq,
+                        link.prev_hash.to_owned(),
+                        link.seq,
+                        link.hash.to_owned(),
+                    ),
+                });
+
+                expected_seq = link.seq + 1;
+                expected_prev_hash = link.hash;
+            }
+
+            if page_len < ARCHIVAL_PAGE_SIZE {
+                break;
+            }
+        }
+
+        Ok(range)
+    }
+
+    async fn sign(
+        &self,
+        archive: ResourceAuditArchive,
+    ) -> Result<ResourceAuditArchive, MappedErrors> {
+        let kek = self.life_cycle_settings.derive_kek_bytes().await?;
+
+        let dek = self
+            .encryption_key_repo
+            .get_or_provision_dek(archive.chain_tenant_id, &kek)
+            .await?;
+
+        archive.signed(&dek)
+    }
+}
+
+// * ---------------------------------------------------------------------------
+// * TESTS
+// * ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{
+            dtos::{
+                resource_audit_log::{
+                    NewResourceAuditLogEvent, ResourceAuditEventKind,
+                    ResourceAuditLog, ResourceAuditResourceType,
+                },
+                written_by::WrittenBy,
+            },
+            entities::{
+                MockResourceAuditLogArchival, MockResourceAuditLogFetching,
+            },
+        },
+        models::{HmacSecretEntry, HmacSecretSet},
+    };
+
+    use async_trait::async_trait;
+    use myc_config::secret_resolver::SecretResolver;
+    use shaku::Component;
+
+    const DEK: [u8; 32] = [7u8; 32];
+
+    #[derive(Component)]
+    #[shaku(interface = EncryptionKeyFetching)]
+    struct FixedDekRepo;
+
+    #[async_trait]
+    impl EncryptionKeyFetching for FixedDekRepo {
+        async fn get_or_provision_dek(
+            &self,
+            _: Option<Uuid>,
+            _: &[u8; 32],
+        ) -> Result<[u8; 32], MappedErrors> {
+            Ok(DEK)
+        }
+
+        async fn fetch_dek(
+            &self,
+            _: Option<Uuid>,
+            _: &[u8; 32],
+        ) -> Result<Option<[u8; 32]>, MappedErrors> {
+            Ok(Some(DEK))
+        }
+    }
+
+    fn test_config() -> AccountLifeCycle {
+        AccountLifeCycle {
+            domain_name: SecretResolver::Value("example.com".to_string()),
+            domain_url: None,
+            locale: None,
+            token_expiration: SecretResolver::Value(3600),
+            invitation_expiration: SecretResolver::Value(604_800),
+            noreply_name: None,
+            noreply_email: SecretResolver::Value(
+                "noreply@example.com".to_string(),
+            ),
+            support_name: None,
+            support_email: SecretResolver::Value(
+                "support@example.com".to_string(),
+            ),
+            token_secret: SecretResolver::Value(Uuid::new_v4().to_string()),
+            hmac_primary_version: 1,
+            hmac_secrets: HmacSecretSet::new(vec![HmacSecretEntry {
+                version: 1,
+                secret: SecretResolver::Value("test-hmac".to_string()),
+            }]),
+            staff_bootstrap_secret: None,
+            sms_provider: None,
+            delivery_webhook_secret: None,
+        }
+    }
+
+    /// A chain of rows created `ages` days ago, oldest first.
+    fn chain(tenant_id: Option<Uuid>, ages: &[i64]) -> Vec<ResourceAuditLog> {
+        let mut prev_hash = RESOURCE_AUDIT_CHAIN_GENESIS_HASH.to_string();
+
+        ages.iter()
+            .enumerate()
+            .map(|(index, age)| {
+                let event = NewResourceAuditLogEvent {
+                    resource_type: ResourceAuditResourceType::Tenant,
+                    resource_id: Uuid::new_v4(),
+                    tenant_id,
+                    event: ResourceAuditEventKind::Updated,
+                    performed_by: WrittenBy::new_anemic(),
+                    metadata: serde_json::json!({}),
+                    created_at: Utc::now() - Duration::days(*age),
+                };
+
+                let link = event.chain_link(index as i64 + 1, &prev_hash);
+                prev_hash = link.hash.to_owned();
+
+                ResourceAuditLog {
+                    id: Uuid::new_v4(),
+                    resource_type: event.resource_type,
+                    resource_id: event.resource_id,
+                    tenant_id: event.tenant_id,
+                    event: event.event,
+                    performed_by: event.performed_by,
+                    metadata: event.metadata,
+                    created_at: event.created_at,
+                    chain: Some(link),
+                    redacted_at: None,
+                }
+            })
+            .collect()
+    }
+
+    fn fetching_serving(
+        rows: Vec<ResourceAuditLog>,
+        policy: Option<ResourceAuditRetentionPolicy>,
+    ) -> MockResourceAuditLogFetching {
+        let mut mock = MockResourceAuditLogFetching::new();
+
+        mock.expect_list_retention_policies().returning(move || {
+            Ok(match policy.to_owned() {
+                Some(policy) => FetchManyResponseKind::Found(vec![policy]),
+                None => FetchManyResponseKind::NotFound,
+            })
+        });
+
+        let tenant_id = rows[0].tenant_id;
+        mock.expect_list_chain_tenants().returning(move || {
+            Ok(FetchManyResponseKind::Found(vec![tenant_id]))
+        });
+
+        mock.expect_list_archives()
+            .returning(|_| Ok(FetchManyResponseKind::NotFound));
+
+        mock.expect_list_filtered()
+            .returning(|_, _, _| Ok(FetchManyResponseKind::NotFound));
+
+        mock.expect_list_unchained_page()
+            .returning(|_, _, _, _, _| Ok(FetchManyResponseKind::NotFound));
+
+        mock.expect_list_chain_page().returning(
+            move |_, after_seq, page_size| {
+                let page = rows
+                    .iter()
+                    .filter(|row| row.chain.as_ref().unwrap().seq > after_seq)
+                    .take(page_size as usize)
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                if page.is_empty() {
+                    return Ok(FetchManyResponseKind::NotFound);
+                }
+
+                Ok(FetchManyResponseKind::Found(page))
+            },
+        );
+
+        mock
+    }
+
+    fn temp_store() -> ResourceAuditArchiveStore {
+        ResourceAuditArchiveStore::open(
+            std::env::temp_dir()
+                .join(format!("myc_audit_retention_test_{}", Uuid::new_v4())),
+        )
+        .unwrap()
+    }
+
+    async fn run(
+        fetching: &MockResourceAuditLogFetching,
+        archival: &MockResourceAuditLogArchival,
+        store: &ResourceAuditArchiveStore,
+    ) -> Vec<ResourceAuditRetentionReport> {
+        archive_expired_resource_audit_rows(
+            Some(30),
+            store,
+            test_config(),
+            Box::new(fetching),
+            Box::new(archival),
+            Box::new(&FixedDekRepo),
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn expired_prefix_is_archived_and_the_rest_kept() {
+        let tenant_id = Some(Uuid::new_v4());
+        let rows = chain(tenant_id, &[90, 60, 40, 10, 50, 1]);
+        let fetching = fetching_serving(rows.to_owned(), None);
+
+        let mut archival = MockResourceAuditLogArchival::new();
+        archival
+            .expect_register_archive_and_purge()
+            .times(1)
+            .withf(|archive| {
+                archive.first_seq == Some(1)
+                    && archive.last_seq == Some(3)
+                    && archive.row_count == 3
+            })
+            .returning(|archive| Ok(archive.row_count as u64));
+
+        let store = temp_store();
+        let reports = run(&fetching, &archival, &store).await;
+
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].purged_rows, 3);
+        assert!(reports[0].error.is_none());
+
+        let archive = reports[0].archive.to_owned().unwrap();
+        assert!(archive.has_valid_signature(&DEK));
+        assert_eq!(
+            archive.last_hash,
+            rows[2].chain.as_ref().map(|l| l.hash.to_owned())
+        );
+
+        let (archived, sha256) = ResourceAuditArchiveStore::read_rows(
+            &store.path_of(&archive.location),
+        )
+        .unwrap();
+        assert_eq!(sha256, archive.sha256);
+        assert_eq!(archived.len(), 3);
+
+        let manifest = ResourceAuditArchiveStore::read_manifest(
+            &store.manifest_path_of(&archive.location),
+        )
+        .unwrap();
+        assert_eq!(manifest, archive);
+    }
+
+    #[tokio::test]
+    async fn chain_on_legal_hold_is_left_untouched() {
+        let tenant_id = Some(Uuid::new_v4());
+        let rows = chain(tenant_id, &[90, 60]);
+        let policy = ResourceAuditRetentionPolicy {
+            legal_hold: true,
+            ..ResourceAuditRetentionPolicy::default_for(tenant_id)
+        };
+        let fetching = fetching_serving(rows, Some(policy));
+
+        let mut archival = MockResourceAuditLogArchival::new();
+        archival.expect_register_archive_and_purge().times(0);
+        archival.expect_purge_archived_rows().times(0);
+
+        let reports = run(&fetching, &archival, &temp_store()).await;
+
+        assert!(reports[0].legal_hold);
+        assert!(reports[0].archive.is_none());
+        assert_eq!(reports[0].purged_rows, 0);
+    }
+
+    #[tokio::test]
+    async fn tampered_chain_is_not_archived() {
+        let tenant_uuid = Uuid::new_v4();
+        let tenant_id = Some(tenant_uuid);
+        let mut rows = chain(tenant_id, &[90, 60, 40]);
+        rows[1].metadata = serde_json::json!({ "tampered": true });
+        let fetching = fetching_serving(rows, None);
+
+        let mut archival = MockResourceAuditLogArchival::new();
+        archival.expect_register_archive_and_purge().times(0);
+
+        let store = temp_store();
+        let reports = run(&fetching, &archival, &store).await;
+
+        assert!(reports[0].archive.is_none());
+        assert!(reports[0].error.as_ref().unwrap().contains("seq 2"));
+        assert!(std::fs::read_dir(store.path_of(&tenant_uuid.to_string()))
+            .unwrap()
+            .next()
+            .is_none());
+    }
+}
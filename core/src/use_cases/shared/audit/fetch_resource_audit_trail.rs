@@ -73,7 +73,7 @@ pub async fn fetch_resource_audit_trail(
 ///   (a pure filter, no bypass), then checks the filtered
 ///   `licensed_resources` field directly for a match -- without calling the
 ///   terminal `get_ids_or_error()`, which is what carries the global bypass.
-fn check_tenant_owner_or_manager(
+pub(super) fn check_tenant_owner_or_manager(
     profile: &Profile,
     tenant_id: Uuid,
 ) -> Result<(), MappedErrors> {
@@ -110,7 +110,7 @@ fn check_tenant_owner_or_manager(
 /// question and short-circuits to `Ok` whenever the profile's global
 /// `is_staff` or `is_manager` flag is set, which would wrongly grant access
 /// to any globally-flagged manager regardless of account ownership.
-fn check_personal_resource_owner(
+pub(super) fn check_personal_resource_owner(
     profile: &Profile,
     resource_owner_account_id: Option<Uuid>,
 ) -> Result<(), MappedErrors> {
@@ -214,6 +214,8 @@ mod tests {
                 id: tenant_id,
                 name: "Tenant Name".to_string(),
                 since: Local::now(),
+                parent_id: None,
+                inherited_from: None,
             }])),
         )
     }
@@ -242,6 +244,8 @@ mod tests {
                 verified: true,
                 permit_flags: None,
                 deny_flags: None,
+                permissions: None,
+                valid_until: None,
             }])),
             None,
         )
@@ -0,0 +1,68 @@
+// ? ---------------------------------------------------------------------------
+// ? list_resource_audit_redactions
+//
+// Collects the redactions recorded by the data subject erasures, which back
+// the verification of redacted rows. Only erasure events still intact in the
+// system chain are trusted; see `ResourceAuditRedaction`.
+// ? ---------------------------------------------------------------------------
+
+use crate::domain::{
+    dtos::resource_audit_log::{
+        ResourceAuditEventKind, ResourceAuditLogCursor, ResourceAuditLogFilter,
+        ResourceAuditRedaction, ResourceAuditResourceType,
+        RESOURCE_AUDIT_ERASURE_ACTION,
+    },
+    entities::ResourceAuditLogFetching,
+};
+
+use mycelium_base::{
+    entities::FetchManyResponseKind, utils::errors::MappedErrors,
+};
+use std::collections::HashSet;
+
+const COLLECTION_PAGE_SIZE: i64 = 500;
+
+/// List every redaction recorded by an intact erasure event.
+#[tracing::instrument(name = "list_resource_audit_redactions", skip_all)]
+pub(crate) async fn list_resource_audit_redactions(
+    fetching_repo: &dyn ResourceAuditLogFetching,
+) -> Result<HashSet<ResourceAuditRedaction>, MappedErrors> {
+    let filter = ResourceAuditLogFilter {
+        resource_type: Some(ResourceAuditResourceType::Account),
+        event: Some(ResourceAuditEventKind::Updated),
+        metadata_contains: Some(RESOURCE_AUDIT_ERASURE_ACTION.to_string()),
+        ..Default::default()
+    };
+
+    let mut redactions = HashSet::new();
+    let mut cursor: Option<ResourceAuditLogCursor> = None;
+
+    loop {
+        let page = match fetching_repo
+            .list_filtered(
+                filter.to_owned(),
+                cursor.to_owned(),
+                COLLECTION_PAGE_SIZE,
+            )
+            .await?
+        {
+            FetchManyResponseKind::NotFound => break,
+            FetchManyResponseKind::Found(records) => records,
+            FetchManyResponseKind::FoundPaginated { records, .. } => records,
+        };
+
+        let page_len = page.len() as i64;
+        cursor = page.last().map(ResourceAuditLogCursor::from_log);
+
+        redactions.extend(
+            page.iter()
+                .flat_map(ResourceAuditRedaction::from_erasure_event),
+        );
+
+        if page_len < COLLECTION_PAGE_SIZE {
+            break;
+        }
+    }
+
+    Ok(redactions)
+}
@@ -0,0 +1,259 @@
+// ? ---------------------------------------------------------------------------
+// ? list_resource_audit_rows_about
+//
+// Collects every audit row about a data subject: rows about one of their
+// ids, rows performed by one of them, and rows whose metadata mentions one of
+// their emails. Backs both the data subject export, one page at a time, and
+// the erasure, so both always cover the very same rows.
+// ? ---------------------------------------------------------------------------
+
+use crate::domain::{
+    dtos::resource_audit_log::{
+        ResourceAuditLog, ResourceAuditLogCursor, ResourceAuditLogFilter,
+        ResourceAuditLogPage,
+    },
+    entities::ResourceAuditLogFetching,
+};
+
+use mycelium_base::{
+    entities::FetchManyResponseKind, utils::errors::MappedErrors,
+};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+const COLLECTION_PAGE_SIZE: i64 = 500;
+
+/// List the audit rows about `ids` or `emails`, newest first.
+#[tracing::instrument(name = "list_resource_audit_rows_about", skip_all)]
+pub(crate) async fn list_resource_audit_rows_about(
+    ids: &[Uuid],
+    emails: &[String],
+    fetching_repo: Box<&dyn ResourceAuditLogFetching>,
+) -> Result<Vec<ResourceAuditLog>, MappedErrors> {
+    let mut rows: HashMap<Uuid, ResourceAuditLog> = HashMap::new();
+
+    for filter in filters_about(ids, emails) {
+        let mut cursor: Option<ResourceAuditLogCursor> = None;
+
+        loop {
+            let page = match fetching_repo
+                .list_filtered(
+                    filter.to_owned(),
+                    cursor.to_owned(),
+                    COLLECTION_PAGE_SIZE,
+                )
+                .await?
+            {
+                FetchManyResponseKind::NotFound => break,
+                FetchManyResponseKind::Found(records) => records,
+                FetchManyResponseKind::FoundPaginated { records, .. } => {
+                    records
+                }
+            };
+
+            let page_len = page.len() as i64;
+            cursor = page.last().map(ResourceAuditLogCursor::from_log);
+
+            for row in page {
+                rows.entry(row.id).or_insert(row);
+            }
+
+            if page_len < COLLECTION_PAGE_SIZE {
+                break;
+            }
+        }
+    }
+
+    Ok(newest_first(rows))
+}
+
+/// List one page of the audit rows about `ids` or `emails`, newest first.
+///
+/// The rows are spread over several filters, so each of them is listed after
+/// `cursor` for one more row than the page holds: the newest rows of the union
+/// are always among the newest rows of each filter.
+#[tracing::instrument(name = "list_resource_audit_page_about", skip_all)]
+pub(crate) async fn list_resource_audit_page_about(
+    ids: &[Uuid],
+    emails: &[String],
+    cursor: Option<ResourceAuditLogCursor>,
+    page_size: i64,
+    fetching_repo: Box<&dyn ResourceAuditLogFetching>,
+) -> Result<ResourceAuditLogPage, MappedErrors> {
+    let mut rows: HashMap<Uuid, ResourceAuditLog> = HashMap::new();
+
+    for filter in filters_about(ids, emails) {
+        let page = match fetching_repo
+            .list_filtered(filter, cursor.to_owned(), page_size + 1)
+            .await?
+        {
+            FetchManyResponseKind::NotFound => continue,
+            FetchManyResponseKind::Found(records) => records,
+            FetchManyResponseKind::FoundPaginated { records, .. } => records,
+        };
+
+        for row in page {
+            rows.entry(row.id).or_insert(row);
+        }
+    }
+
+    let mut records = newest_first(rows);
+
+    let next_cursor = if records.len() as i64 > page_size {
+        records.truncate(page_size as usize);
+        records
+            .last()
+            .map(|log| ResourceAuditLogCursor::from_log(log).encode())
+    } else {
+        None
+    };
+
+    Ok(ResourceAuditLogPage {
+        records,
+        next_cursor,
+    })
+}
+
+fn filters_about(
+    ids: &[Uuid],
+    emails: &[String],
+) -> Vec<ResourceAuditLogFilter> {
+    ids.iter()
+        .flat_map(|id| {
+            [
+                ResourceAuditLogFilter {
+                    resource_id: Some(*id),
+                    ..Default::default()
+                },
+                ResourceAuditLogFilter {
+                    performed_by: Some(*id),
+                    ..Default::default()
+                },
+            ]
+        })
+        .chain(emails.iter().map(|email| ResourceAuditLogFilter {
+            metadata_contains: Some(email.to_owned()),
+            ..Default::default()
+        }))
+        .collect()
+}
+
+fn newest_first(
+    rows: HashMap<Uuid, ResourceAuditLog>,
+) -> Vec<ResourceAuditLog> {
+    let mut rows: Vec<ResourceAuditLog> = rows.into_values().collect();
+    rows.sort_by(|a, b| {
+        b.created_at
+            .cmp(&a.created_at)
+            .then_with(|| b.id.cmp(&a.id))
+    });
+
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        dtos::{
+            resource_audit_log::{
+                ResourceAuditEventKind, ResourceAuditResourceType,
+            },
+            written_by::WrittenBy,
+        },
+        entities::MockResourceAuditLogFetching,
+    };
+
+    use chrono::{Duration, Utc};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn pages_cover_every_row_of_the_union_once() {
+        let subject_id = Uuid::new_v4();
+        let email = "jane@example.com".to_string();
+        let now = Utc::now();
+
+        //
+        // Rows 0, 2 and 4 are about the subject, rows 1, 2 and 3 mention
+        // their email: row 2 is listed by both filters.
+        //
+        let rows = (0..5)
+            .map(|index| ResourceAuditLog {
+                id: Uuid::new_v4(),
+                resource_type: ResourceAuditResourceType::Account,
+                resource_id: if index % 2 == 0 {
+                    subject_id
+                } else {
+                    Uuid::new_v4()
+                },
+                tenant_id: None,
+                event: ResourceAuditEventKind::Updated,
+                performed_by: WrittenBy::new_anemic(),
+                metadata: if (1..4).contains(&index) {
+                    json!({ "email": "jane@example.com" })
+                } else {
+                    json!({})
+                },
+                created_at: now - Duration::seconds(index),
+                chain: None,
+                redacted_at: None,
+            })
+            .collect::<Vec<_>>();
+
+        let stored = rows.to_owned();
+        let mut fetching_mock = MockResourceAuditLogFetching::new();
+        fetching_mock.expect_list_filtered().returning(
+            move |filter, cursor, page_size| {
+                let records = stored
+                    .iter()
+                    .filter(|row| {
+                        // No row is performed by the subject.
+                        filter.performed_by.is_none()
+                            && filter
+                                .resource_id
+                                .is_none_or(|id| row.resource_id == id)
+                            && filter.metadata_contains.as_ref().is_none_or(
+                                |text| row.metadata.to_string().contains(text),
+                            )
+                            && cursor.as_ref().is_none_or(|cursor| {
+                                (row.created_at, row.id)
+                                    < (cursor.created_at, cursor.id)
+                            })
+                    })
+                    .take(page_size as usize)
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                Ok(FetchManyResponseKind::Found(records))
+            },
+        );
+
+        let mut listed = vec![];
+        let mut cursor = None;
+
+        loop {
+            let page = list_resource_audit_page_about(
+                &[subject_id],
+                &[email.to_owned()],
+                cursor,
+                2,
+                Box::new(&fetching_mock),
+            )
+            .await
+            .unwrap();
+
+            assert!(page.records.len() <= 2);
+            listed.extend(page.records.into_iter().map(|row| row.id));
+
+            match page.next_cursor {
+                Some(next) => {
+                    cursor =
+                        Some(ResourceAuditLogCursor::decode(&next).unwrap())
+                }
+                None => break,
+            }
+        }
+
+        assert_eq!(listed, rows.iter().map(|row| row.id).collect::<Vec<_>>());
+    }
+}
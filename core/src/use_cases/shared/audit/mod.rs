@@ -1,5 +1,21 @@
+mod archive_expired_resource_audit_rows;
 mod emit_resource_audit_event;
 mod fetch_resource_audit_trail;
+mod list_resource_audit_redactions;
+mod list_resource_audit_rows_about;
+mod restore_resource_audit_archive;
+mod search_resource_audit_trail;
+mod set_resource_audit_retention_policy;
+mod sign_resource_audit_checkpoint;
+mod verify_resource_audit_chain;
 
+pub use archive_expired_resource_audit_rows::*;
 pub use emit_resource_audit_event::*;
 pub use fetch_resource_audit_trail::*;
+pub(crate) use list_resource_audit_redactions::*;
+pub(crate) use list_resource_audit_rows_about::*;
+pub use restore_resource_audit_archive::*;
+pub use search_resource_audit_trail::*;
+pub use set_resource_audit_retention_policy::*;
+pub use sign_resource_audit_checkpoint::*;
+pub use verify_resource_audit_chain::*;
@@ -0,0 +1,346 @@
+// ? ---------------------------------------------------------------------------
+// ? restore_resource_audit_archive
+//
+// Puts the rows of an archive back into `resource_audit_log` so they can be
+// queried again. Backs `myc-cli audit restore`. Only the manifest sidecar and
+// the archive file next to it are needed, so an archive can also be restored
+// into a database other than the one it was taken from.
+//
+// Nothing is inserted unless the archive checks out: the manifest signature
+// under the chain's DEK, the file digest and row count it records, and the
+// hash chain of the archived rows from `first_prev_hash` to `last_hash`.
+//
+// Restored rows keep their ids and chain links. They stay out of chain
+// verification, which resumes after the latest archive, and the next
+// retention run deletes them again unless the chain is on legal hold.
+// ? ---------------------------------------------------------------------------
+
+use crate::{
+    domain::{
+        dtos::resource_audit_log::{ResourceAuditArchive, ResourceAuditLog},
+        entities::{EncryptionKeyFetching, ResourceAuditLogArchival},
+        utils::ResourceAuditArchiveStore,
+    },
+    models::AccountLifeCycle,
+};
+
+use mycelium_base::utils::errors::{use_case_err, MappedErrors};
+use std::{collections::HashSet, path::Path};
+
+#[tracing::instrument(
+    name = "restore_resource_audit_archive",
+    skip_all,
+    fields(manifest = %manifest_path.display())
+)]
+pub async fn restore_resource_audit_archive(
+    manifest_path: &Path,
+    life_cycle_settings: AccountLifeCycle,
+    archival_repo: Box<&dyn ResourceAuditLogArchival>,
+    encryption_key_repo: Box<&dyn EncryptionKeyFetching>,
+) -> Result<u64, MappedErrors> {
+    let archive = ResourceAuditArchiveStore::read_manifest(manifest_path)?;
+
+    let (rows, sha256) = ResourceAuditArchiveStore::read_rows(
+        &ResourceAuditArchiveStore::archive_path_of_manifest(manifest_path)?,
+    )?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Check the manifest and the file it describes
+    // ? -----------------------------------------------------------------------
+
+    let kek = life_cycle_settings.derive_kek_bytes().await?;
+
+    let dek = encryption_key_repo
+        .get_or_provision_dek(archive.chain_tenant_id, &kek)
+        .await?;
+
+    if !archive.has_valid_signature(&dek) {
+        return use_case_err("Audit archive manifest signature is invalid")
+            .with_exp_true()
+            .as_error();
+    }
+
+    if sha256 != archive.sha256 {
+        return use_case_err(
+            "Audit archive file does not match its manifest digest",
+        )
+        .with_exp_true()
+        .as_error();
+    }
+
+    check_rows(&archive, &rows)?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Restore
+    // ? -----------------------------------------------------------------------
+
+    archival_repo.restore_rows(rows).await
+}
+
+/// Check that the rows are exactly the range the manifest describes.
+fn check_rows(
+    archive: &ResourceAuditArchive,
+    rows: &[ResourceAuditLog],
+) -> Result<(), MappedErrors> {
+    let invalid = |reason: String| {
+        use_case_err(format!("Audit archive does not verify: {reason}"))
+            .with_exp_true()
+            .as_error()
+    };
+
+    if rows.len() as i64 != archive.row_count {
+        return invalid(format!(
+            "expected {} rows, found {}",
+            archive.row_count,
+            rows.len()
+        ));
+    }
+
+    let mut expected =
+        archive.first_seq.zip(archive.first_prev_hash.to_owned());
+
+    for row in rows {
+        if row.tenant_id != archive.chain_tenant_id {
+            return invalid(format!("row {} belongs to another chain", row.id));
+        }
+
+        if row.created_at >= archive.cutoff {
+            return invalid(format!("row {} is newer than the cutoff", row.id));
+        }
+
+        let Some(link) = row.chain.as_ref() else {
+            continue;
+        };
+
+        let Some((expected_seq, expected_prev_hash)) = expected.to_owned()
+        else {
+            return invalid(format!("unexpected chained row {}", row.id));
+        };
+
+        //
+        // Redacted rows were checked against their redactions when archived,
+        // and the signed digest covers them since.
+        //
+        let intact = link.seq == expected_seq
+            && link.prev_hash == expected_prev_hash
+            && (row.is_redacted() || row.content_verifies(&HashSet::new()));
+
+        if !intact {
+            return invalid(format!("chain breaks at seq {}", link.seq));
+        }
+
+        expected = Some((link.seq + 1, link.hash.to_owned()));
+    }
+
+    let ends_where_declared = match (archive.last_seq, &archive.last_hash) {
+        (Some(last_seq), Some(last_hash)) => {
+            expected == Some((last_seq + 1, last_hash.to_owned()))
+        }
+        (None, None) => archive.first_seq.is_none(),
+        _ => false,
+    };
+
+    if !ends_where_declared {
+        return invalid("chain does not end where declared".to_string());
+    }
+
+    Ok(())
+}
+
+// * ---------------------------------------------------------------------------
+// * TESTS
+// * ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{
+            dtos::{
+                resource_audit_log::{
+                    NewResourceAuditLogEvent, ResourceAuditEventKind,
+                    ResourceAuditResourceType,
+                    RESOURCE_AUDIT_CHAIN_GENESIS_HASH,
+                },
+                written_by::WrittenBy,
+            },
+            entities::MockResourceAuditLogArchival,
+        },
+        models::{HmacSecretEntry, HmacSecretSet},
+    };
+
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use myc_config::secret_resolver::SecretResolver;
+    use shaku::Component;
+    use uuid::Uuid;
+
+    const DEK: [u8; 32] = [5u8; 32];
+
+    #[derive(Component)]
+    #[shaku(interface = EncryptionKeyFetching)]
+    struct FixedDekRepo;
+
+    #[async_trait]
+    impl EncryptionKeyFetching for FixedDekRepo {
+        async fn get_or_provision_dek(
+            &self,
+            _: Option<Uuid>,
+            _: &[u8; 32],
+        ) -> Result<[u8; 32], MappedErrors> {
+            Ok(DEK)
+        }
+
+        async fn fetch_dek(
+            &self,
+            _: Option<Uuid>,
+            _: &[u8; 32],
+        ) -> Result<Option<[u8; 32]>, MappedErrors> {
+            Ok(Some(DEK))
+        }
+    }
+
+    fn test_config() -> AccountLifeCycle {
+        AccountLifeCycle {
+            domain_name: SecretResolver::Value("example.com".to_string()),
+            domain_url: None,
+            locale: None,
+            token_expiration: SecretResolver::Value(3600),
+            invitation_expiration: SecretResolver::Value(604_800),
+            noreply_name: None,
+            noreply_email: SecretResolver::Value(
+                "noreply@example.com".to_string(),
+            ),
+            support_name: None,
+            support_email: SecretResolver::Value(
+                "support@example.com".to_string(),
+            ),
+            token_secret: SecretResolver::Value(Uuid::new_v4().to_string()),
+            hmac_primary_version: 1,
+            hmac_secrets: HmacSecretSet::new(vec![HmacSecretEntry {
+                version: 1,
+                secret: SecretResolver::Value("test-hmac".to_string()),
+            }]),
+            staff_bootstrap_secret: None,
+            sms_provider: None,
+            delivery_webhook_secret: None,
+        }
+    }
+
+    /// Write a signed archive of a three-row chain and return its manifest
+    /// path.
+    fn write_archive(tamper: bool) -> std::path::PathBuf {
+        let store = ResourceAuditArchiveStore::open(
+            std::env::temp_dir()
+                .join(format!("myc_audit_restore_test_{}", Uuid::new_v4())),
+        )
+        .unwrap();
+
+        let mut prev_hash = RESOURCE_AUDIT_CHAIN_GENESIS_HASH.to_string();
+        let rows = (1..=3)
+            .map(|seq| {
+                let event = NewResourceAuditLogEvent {
+                    resource_type: ResourceAuditResourceType::Webhook,
+                    resource_id: Uuid::new_v4(),
+                    tenant_id: None,
+                    event: ResourceAuditEventKind::Deleted,
+                    performed_by: WrittenBy::new_anemic(),
+                    metadata: serde_json::json!({}),
+                    created_at: Utc::now(),
+                };
+
+                let link = event.chain_link(seq, &prev_hash);
+                prev_hash = link.hash.to_owned();
+
+                ResourceAuditLog {
+                    id: Uuid::new_v4(),
+                    resource_type: event.resource_type,
+                    resource_id: event.resource_id,
+                    tenant_id: None,
+                    event: event.event,
+                    performed_by: event.performed_by,
+                    metadata: if tamper && seq == 2 {
+                        serde_json::json!({ "tampered": true })
+                    } else {
+                        event.metadata
+                    },
+                    created_at: event.created_at,
+                    chain: Some(link),
+                    redacted_at: None,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let archive_id = Uuid::new_v4();
+        let location = ResourceAuditArchiveStore::archive_key(None, archive_id);
+
+        let mut writer = store.writer(&location).unwrap();
+        for row in &rows {
+            writer.append(row).unwrap();
+        }
+        let sha256 = writer.finish().unwrap();
+
+        let archive = ResourceAuditArchive {
+            id: archive_id,
+            chain_tenant_id: None,
+            first_seq: Some(1),
+            last_seq: Some(3),
+            first_prev_hash: Some(
+                RESOURCE_AUDIT_CHAIN_GENESIS_HASH.to_string(),
+            ),
+            last_hash: Some(prev_hash),
+            cutoff: Utc::now() + chrono::Duration::seconds(1),
+            row_count: 3,
+            location,
+            sha256,
+            signature: String::new(),
+            created_at: Utc::now(),
+        }
+        .signed(&DEK)
+        .unwrap();
+
+        store.write_manifest(&archive).unwrap()
+    }
+
+    #[tokio::test]
+    async fn verified_archive_is_restored() {
+        let manifest_path = write_archive(false);
+
+        let mut archival = MockResourceAuditLogArchival::new();
+        archival
+            .expect_restore_rows()
+            .times(1)
+            .withf(|rows| rows.len() == 3)
+            .returning(|rows| Ok(rows.len() as u64));
+
+        let restored = restore_resource_audit_archive(
+            &manifest_path,
+            test_config(),
+            Box::new(&archival),
+            Box::new(&FixedDekRepo),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(restored, 3);
+    }
+
+    #[tokio::test]
+    async fn tampered_archive_is_refused() {
+        let manifest_path = write_archive(true);
+
+        let mut archival = MockResourceAuditLogArchival::new();
+        archival.expect_restore_rows().times(0);
+
+        let err = restore_resource_audit_archive(
+            &manifest_path,
+            test_config(),
+            Box::new(&archival),
+            Box::new(&FixedDekRepo),
+        )
+        .await
+        .unwrap_err();
+
+        assert!(err.msg().contains("chain breaks at seq 2"));
+    }
+}
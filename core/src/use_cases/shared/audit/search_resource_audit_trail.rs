@@ -0,0 +1,248 @@
+// ? ---------------------------------------------------------------------------
+// ? search_resource_audit_trail
+//
+// Filtered, cursor-paginated counterpart of `fetch_resource_audit_trail`,
+// backing both the search and the export ports. Applies the very same
+// staff / tenant owner-manager / personal-owner rule, then narrows the
+// filter to what the granted standing covers.
+// ? ---------------------------------------------------------------------------
+
+use super::fetch_resource_audit_trail::{
+    check_personal_resource_owner, check_tenant_owner_or_manager,
+};
+use crate::domain::{
+    dtos::{
+        profile::Profile,
+        resource_audit_log::{
+            ResourceAuditLogCursor, ResourceAuditLogFilter,
+            ResourceAuditLogPage,
+        },
+    },
+    entities::ResourceAuditLogFetching,
+};
+
+use mycelium_base::{
+    entities::FetchManyResponseKind, utils::errors::MappedErrors,
+};
+use uuid::Uuid;
+
+pub const RESOURCE_AUDIT_DEFAULT_PAGE_SIZE: i64 = 50;
+pub const RESOURCE_AUDIT_MAX_PAGE_SIZE: i64 = 1000;
+
+/// Search the audit trail.
+///
+/// `tenant_id`/`resource_owner_account_id` are the standing to check, as in
+/// `fetch_resource_audit_trail`: resolved by the port from
+/// `filter.resource_id` when set, otherwise `tenant_id` is the tenant being
+/// searched. A tenant owner or manager searching without a resource is
+/// pinned to their tenant's rows, whatever `filter.tenant_id` says.
+#[tracing::instrument(name = "search_resource_audit_trail", skip_all)]
+pub async fn search_resource_audit_trail(
+    profile: Profile,
+    mut filter: ResourceAuditLogFilter,
+    tenant_id: Option<Uuid>,
+    resource_owner_account_id: Option<Uuid>,
+    cursor: Option<ResourceAuditLogCursor>,
+    page_size: Option<i64>,
+    fetching_repo: Box<&dyn ResourceAuditLogFetching>,
+) -> Result<ResourceAuditLogPage, MappedErrors> {
+    if !profile.is_staff {
+        if let Some(tenant_id) = tenant_id {
+            check_tenant_owner_or_manager(&profile, tenant_id)?;
+
+            if filter.resource_id.is_none() {
+                filter.tenant_id = Some(tenant_id);
+            }
+        } else {
+            check_personal_resource_owner(&profile, resource_owner_account_id)?;
+        }
+    }
+
+    let page_size = page_size
+        .unwrap_or(RESOURCE_AUDIT_DEFAULT_PAGE_SIZE)
+        .clamp(1, RESOURCE_AUDIT_MAX_PAGE_SIZE);
+
+    //
+    // One extra row tells whether a next page exists without a count query.
+    //
+    let mut records = match fetching_repo
+        .list_filtered(filter, cursor, page_size + 1)
+        .await?
+    {
+        FetchManyResponseKind::NotFound => vec![],
+        FetchManyResponseKind::Found(records) => records,
+        FetchManyResponseKind::FoundPaginated { records, .. } => records,
+    };
+
+    let next_cursor = if records.len() as i64 > page_size {
+        records.truncate(page_size as usize);
+        records
+            .last()
+            .map(|log| ResourceAuditLogCursor::from_log(log).encode())
+    } else {
+        None
+    };
+
+    Ok(ResourceAuditLogPage {
+        records,
+        next_cursor,
+    })
+}
+
+// * ---------------------------------------------------------------------------
+// * TESTS
+// * ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        dtos::{
+            profile::{Profile, TenantOwnership, TenantsOwnership},
+            resource_audit_log::{
+                ResourceAuditEventKind, ResourceAuditLog,
+                ResourceAuditResourceType,
+            },
+            written_by::WrittenBy,
+        },
+        entities::MockResourceAuditLogFetching,
+    };
+
+    use chrono::{Local, Utc};
+
+    fn profile(
+        is_staff: bool,
+        tenants_ownership: Option<TenantsOwnership>,
+    ) -> Profile {
+        Profile::new(
+            vec![],
+            Uuid::new_v4(),
+            false,
+            false,
+            is_staff,
+            true,
+            true,
+            true,
+            false,
+            false,
+            None,
+            None,
+            tenants_ownership,
+        )
+    }
+
+    fn log() -> ResourceAuditLog {
+        ResourceAuditLog {
+            id: Uuid::new_v4(),
+            resource_type: ResourceAuditResourceType::Account,
+            resource_id: Uuid::new_v4(),
+            tenant_id: None,
+            event: ResourceAuditEventKind::Created,
+            performed_by: WrittenBy::new_anemic(),
+            metadata: serde_json::json!({}),
+            created_at: Utc::now(),
+            chain: None,
+            redacted_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn extra_row_becomes_next_cursor() {
+        let mut mock = MockResourceAuditLogFetching::new();
+
+        mock.expect_list_filtered()
+            .withf(|_, _, page_size| *page_size == 3)
+            .times(1)
+            .returning(|_, _, _| {
+                Ok(FetchManyResponseKind::Found(vec![log(), log(), log()]))
+            });
+
+        let page = search_resource_audit_trail(
+            profile(true, None),
+            ResourceAuditLogFilter::default(),
+            None,
+            None,
+            None,
+            Some(2),
+            Box::new(&mock),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(page.records.len(), 2);
+
+        let cursor =
+            ResourceAuditLogCursor::decode(&page.next_cursor.unwrap()).unwrap();
+        assert_eq!(cursor.id, page.records[1].id);
+    }
+
+    #[tokio::test]
+    async fn tenant_owner_search_is_pinned_to_own_tenant() {
+        let tenant_id = Uuid::new_v4();
+        let mut mock = MockResourceAuditLogFetching::new();
+
+        mock.expect_list_filtered()
+            .withf(move |filter, _, _| filter.tenant_id == Some(tenant_id))
+            .times(1)
+            .returning(|_, _, _| Ok(FetchManyResponseKind::NotFound));
+
+        let page = search_resource_audit_trail(
+            profile(
+                false,
+                Some(TenantsOwnership::Records(vec![TenantOwnership {
+                    id: tenant_id,
+                    name: "Tenant Name".to_string(),
+                    since: Local::now(),
+                    parent_id: None,
+                    inherited_from: None,
+                }])),
+            ),
+            ResourceAuditLogFilter {
+                tenant_id: Some(Uuid::new_v4()),
+                ..Default::default()
+            },
+            Some(tenant_id),
+            None,
+            None,
+            None,
+            Box::new(&mock),
+        )
+        .await
+        .unwrap();
+
+        assert!(page.records.is_empty());
+        assert!(page.next_cursor.is_none());
+    }
+
+    #[tokio::test]
+    async fn search_without_standing_is_denied() {
+        let mut mock = MockResourceAuditLogFetching::new();
+        mock.expect_list_filtered().times(0);
+
+        let result = search_resource_audit_trail(
+            profile(false, None),
+            ResourceAuditLogFilter::default(),
+            Some(Uuid::new_v4()),
+            None,
+            None,
+            None,
+            Box::new(&mock),
+        )
+        .await;
+
+        assert!(result.is_err());
+
+        let result = search_resource_audit_trail(
+            profile(false, None),
+            ResourceAuditLogFilter::default(),
+            None,
+            None,
+            None,
+            None,
+            Box::new(&mock),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,146 @@
+// ? ---------------------------------------------------------------------------
+// ? set_resource_audit_retention_policy
+//
+// Change one field of a chain's retention policy, keeping the other. Backs
+// `myc-cli audit retention` and `myc-cli audit hold`. A chain without a
+// stored policy starts from the default one (configured retention, no hold).
+// ? ---------------------------------------------------------------------------
+
+use crate::domain::{
+    dtos::resource_audit_log::ResourceAuditRetentionPolicy,
+    entities::{ResourceAuditLogArchival, ResourceAuditLogFetching},
+};
+
+use chrono::Utc;
+use mycelium_base::{
+    entities::FetchManyResponseKind,
+    utils::errors::{use_case_err, MappedErrors},
+};
+use uuid::Uuid;
+
+/// Set the retention of a chain; `None` falls back to the configured default.
+#[tracing::instrument(
+    name = "set_resource_audit_retention_days",
+    skip_all,
+    fields(?chain_tenant_id, ?retention_days)
+)]
+pub async fn set_resource_audit_retention_days(
+    chain_tenant_id: Option<Uuid>,
+    retention_days: Option<i32>,
+    fetching_repo: Box<&dyn ResourceAuditLogFetching>,
+    archival_repo: Box<&dyn ResourceAuditLogArchival>,
+) -> Result<ResourceAuditRetentionPolicy, MappedErrors> {
+    if retention_days.is_some_and(|days| days < 1) {
+        return use_case_err("Retention must be at least one day")
+            .with_exp_true()
+            .as_error();
+    }
+
+    let mut policy = current_policy(chain_tenant_id, fetching_repo).await?;
+    policy.retention_days = retention_days;
+    policy.updated_at = Utc::now();
+
+    archival_repo.upsert_retention_policy(policy).await
+}
+
+/// Place a chain on legal hold, or release it.
+#[tracing::instrument(
+    name = "set_resource_audit_legal_hold",
+    skip_all,
+    fields(?chain_tenant_id, legal_hold)
+)]
+pub async fn set_resource_audit_legal_hold(
+    chain_tenant_id: Option<Uuid>,
+    legal_hold: bool,
+    fetching_repo: Box<&dyn ResourceAuditLogFetching>,
+    archival_repo: Box<&dyn ResourceAuditLogArchival>,
+) -> Result<ResourceAuditRetentionPolicy, MappedErrors> {
+    let mut policy = current_policy(chain_tenant_id, fetching_repo).await?;
+    policy.legal_hold = legal_hold;
+    policy.updated_at = Utc::now();
+
+    archival_repo.upsert_retention_policy(policy).await
+}
+
+async fn current_policy(
+    chain_tenant_id: Option<Uuid>,
+    fetching_repo: Box<&dyn ResourceAuditLogFetching>,
+) -> Result<ResourceAuditRetentionPolicy, MappedErrors> {
+    let policies = match fetching_repo.list_retention_policies().await? {
+        FetchManyResponseKind::NotFound => vec![],
+        FetchManyResponseKind::Found(records) => records,
+        FetchManyResponseKind::FoundPaginated { records, .. } => records,
+    };
+
+    Ok(policies
+        .into_iter()
+        .find(|policy| policy.chain_tenant_id == chain_tenant_id)
+        .unwrap_or_else(|| {
+            ResourceAuditRetentionPolicy::default_for(chain_tenant_id)
+        }))
+}
+
+// * ---------------------------------------------------------------------------
+// * TESTS
+// * ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{
+        MockResourceAuditLogArchival, MockResourceAuditLogFetching,
+    };
+
+    #[tokio::test]
+    async fn changing_retention_keeps_the_legal_hold() {
+        let tenant_id = Some(Uuid::new_v4());
+
+        let mut fetching = MockResourceAuditLogFetching::new();
+        fetching
+            .expect_list_retention_policies()
+            .returning(move || {
+                Ok(FetchManyResponseKind::Found(vec![
+                    ResourceAuditRetentionPolicy {
+                        legal_hold: true,
+                        ..ResourceAuditRetentionPolicy::default_for(tenant_id)
+                    },
+                ]))
+            });
+
+        let mut archival = MockResourceAuditLogArchival::new();
+        archival
+            .expect_upsert_retention_policy()
+            .times(1)
+            .withf(move |policy| {
+                policy.chain_tenant_id == tenant_id
+                    && policy.legal_hold
+                    && policy.retention_days == Some(365)
+            })
+            .returning(Ok);
+
+        set_resource_audit_retention_days(
+            tenant_id,
+            Some(365),
+            Box::new(&fetching),
+            Box::new(&archival),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn non_positive_retention_is_rejected() {
+        let fetching = MockResourceAuditLogFetching::new();
+        let archival = MockResourceAuditLogArchival::new();
+
+        let result = set_resource_audit_retention_days(
+            None,
+            Some(0),
+            Box::new(&fetching),
+            Box::new(&archival),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,53 @@
+// ? ---------------------------------------------------------------------------
+// ? sign_resource_audit_checkpoint
+//
+// Called by the resource audit log dispatcher whenever an appended row's
+// link is due for a checkpoint (see `ResourceAuditChainLink::
+// is_checkpoint_due`). Resolves the chain's DEK through the envelope
+// encryption infrastructure -- the tenant DEK, or the system DEK for the
+// tenant-less chain -- and signs the chain head with it. Persisting the
+// result is the dispatcher's job, on the same connection it appends rows on.
+//
+// Keys are only provisioned for chains that have none yet, and never for
+// purged tenants: the repository refuses tenants without a row, so late
+// events of a purged tenant are left unsigned instead of re-keying its chain.
+// ? ---------------------------------------------------------------------------
+
+use crate::{
+    domain::{
+        dtos::resource_audit_log::{
+            ResourceAuditChainLink, ResourceAuditCheckpoint,
+        },
+        entities::EncryptionKeyFetching,
+    },
+    models::AccountLifeCycle,
+};
+
+use mycelium_base::utils::errors::MappedErrors;
+use uuid::Uuid;
+
+#[tracing::instrument(
+    name = "sign_resource_audit_checkpoint",
+    skip_all,
+    fields(?chain_tenant_id, seq = link.seq)
+)]
+pub async fn sign_resource_audit_checkpoint(
+    chain_tenant_id: Option<Uuid>,
+    link: ResourceAuditChainLink,
+    life_cycle_settings: AccountLifeCycle,
+    encryption_key_repo: Box<&dyn EncryptionKeyFetching>,
+) -> Result<ResourceAuditCheckpoint, MappedErrors> {
+    let kek = life_cycle_settings.derive_kek_bytes().await?;
+
+    let dek = match encryption_key_repo.fetch_dek(chain_tenant_id, &kek).await?
+    {
+        Some(dek) => dek,
+        None => {
+            encryption_key_repo
+                .get_or_provision_dek(chain_tenant_id, &kek)
+                .await?
+        }
+    };
+
+    ResourceAuditCheckpoint::new_signed(chain_tenant_id, &link, &dek)
+}
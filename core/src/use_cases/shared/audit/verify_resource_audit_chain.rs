@@ -0,0 +1,730 @@
+// ? ---------------------------------------------------------------------------
+// ? verify_resource_audit_chain
+//
+// Walks one tenant chain of `resource_audit_log` in `seq` order and reports
+// every gap, broken link, modified row, and bad checkpoint it finds. Backs
+// `myc-cli audit verify`. Rows written before hash chaining existed carry no
+// chain link and are not part of any chain, so they are never reported.
+//
+// Archived rows are no longer in the log: their signed manifests are checked
+// instead, and the walk resumes after the latest one. Rows pseudonymised by a
+// data subject erasure are counted, and verify against the redaction recorded
+// by the erasure event; a redacted row without such a record is modified.
+// ? ---------------------------------------------------------------------------
+
+use crate::{
+    domain::{
+        dtos::resource_audit_log::{
+            ResourceAuditChainIssue, ResourceAuditChainReport,
+            ResourceAuditCheckpoint, RESOURCE_AUDIT_CHAIN_GENESIS_HASH,
+        },
+        entities::{EncryptionKeyFetching, ResourceAuditLogFetching},
+    },
+    models::AccountLifeCycle,
+    use_cases::shared::audit::list_resource_audit_redactions,
+};
+
+use mycelium_base::{
+    entities::FetchManyResponseKind, utils::errors::MappedErrors,
+};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+const VERIFICATION_PAGE_SIZE: i64 = 500;
+
+#[tracing::instrument(
+    name = "verify_resource_audit_chain",
+    skip_all,
+    fields(?chain_tenant_id)
+)]
+pub async fn verify_resource_audit_chain(
+    chain_tenant_id: Option<Uuid>,
+    life_cycle_settings: AccountLifeCycle,
+    fetching_repo: Box<&dyn ResourceAuditLogFetching>,
+    encryption_key_repo: Box<&dyn EncryptionKeyFetching>,
+) -> Result<ResourceAuditChainReport, MappedErrors> {
+    let mut report = ResourceAuditChainReport::new(chain_tenant_id);
+
+    // ? -----------------------------------------------------------------------
+    // ? Check checkpoint and archive signatures
+    // ? -----------------------------------------------------------------------
+
+    let mut checkpoints: BTreeMap<i64, ResourceAuditCheckpoint> =
+        match fetching_repo.list_checkpoints(chain_tenant_id).await? {
+            FetchManyResponseKind::NotFound => vec![],
+            FetchManyResponseKind::Found(records) => records,
+            FetchManyResponseKind::FoundPaginated { records, .. } => records,
+        }
+        .into_iter()
+        .map(|checkpoint| (checkpoint.seq, checkpoint))
+        .collect();
+
+    let archives = match fetching_repo.list_archives(chain_tenant_id).await? {
+        FetchManyResponseKind::NotFound => vec![],
+        FetchManyResponseKind::Found(records) => records,
+        FetchManyResponseKind::FoundPaginated { records, .. } => records,
+    };
+
+    if !checkpoints.is_empty() || !archives.is_empty() {
+        //
+        // Verification is read-only: a chain whose tenant holds no DEK is a
+        // finding, never a reason to provision one.
+        //
+        let dek = match life_cycle_settings.derive_kek_bytes().await {
+            Ok(kek) => {
+                encryption_key_repo.fetch_dek(chain_tenant_id, &kek).await
+            }
+            Err(err) => Err(err),
+        };
+
+        match dek {
+            Ok(Some(dek)) => {
+                for checkpoint in checkpoints.values() {
+                    report.checkpoints += 1;
+
+                    if !checkpoint.has_valid_signature(&dek) {
+                        report.issues.push(
+                            ResourceAuditChainIssue::InvalidCheckpointSignature {
+                                seq: checkpoint.seq,
+                            },
+                        );
+                    }
+                }
+
+                for archive in archives.iter() {
+                    if !archive.has_valid_signature(&dek) {
+                        report.issues.push(
+                            ResourceAuditChainIssue::InvalidArchiveSignature {
+                                archive_id: archive.id,
+                            },
+                        );
+                    }
+                }
+            }
+            Ok(None) => report
+                .issues
+                .push(ResourceAuditChainIssue::CheckpointKeyMissing),
+            Err(err) => report.issues.push(
+                ResourceAuditChainIssue::CheckpointKeyUnavailable {
+                    reason: err.to_string(),
+                },
+            ),
+        }
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Follow the archives
+    //
+    // Each archive must pick up the chain exactly where the previous one
+    // left off. Checkpoints inside archived ranges have no row left to match.
+    // ? -----------------------------------------------------------------------
+
+    let mut expected_seq = 1;
+    let mut expected_prev_hash = RESOURCE_AUDIT_CHAIN_GENESIS_HASH.to_string();
+
+    for archive in archives.iter() {
+        report.archives += 1;
+
+        let (
+            Some(first_seq),
+            Some(first_prev_hash),
+            Some(last_seq),
+            Some(last_hash),
+        ) = (
+            archive.first_seq,
+            archive.first_prev_hash.as_ref(),
+            archive.last_seq,
+            archive.last_hash.as_ref(),
+        )
+        else {
+            continue;
+        };
+
+        if first_seq != expected_seq || *first_prev_hash != expected_prev_hash {
+            report
+                .issues
+                .push(ResourceAuditChainIssue::ArchiveDiscontinuity {
+                    archive_id: archive.id,
+                    expected_seq,
+                    found_seq: first_seq,
+                });
+        }
+
+        expected_seq = last_seq + 1;
+        expected_prev_hash = last_hash.to_owned();
+    }
+
+    checkpoints.retain(|seq, _| *seq >= expected_seq);
+
+    // ? -----------------------------------------------------------------------
+    // ? Walk the chain
+    // ? -----------------------------------------------------------------------
+
+    let redactions = list_resource_audit_redactions(*fetching_repo).await?;
+
+    loop {
+        let rows = match fetching_repo
+            .list_chain_page(
+                chain_tenant_id,
+                expected_seq - 1,
+                VERIFICATION_PAGE_SIZE,
+            )
+            .await?
+        {
+            FetchManyResponseKind::NotFound => break,
+            FetchManyResponseKind::Found(records) => records,
+            FetchManyResponseKind::FoundPaginated { records, .. } => records,
+        };
+
+        let page_len = rows.len() as i64;
+
+        for row in rows {
+            let Some(stored) = row.chain.to_owned() else {
+                continue;
+            };
+
+            //
+            // A gap necessarily comes with a mismatching `prev_hash`, so only
+            // the gap itself is reported.
+            //
+            if stored.seq != expected_seq {
+                report.issues.push(ResourceAuditChainIssue::Gap {
+                    expected_seq,
+                    found_seq: stored.seq,
+                });
+            } else if stored.prev_hash != expected_prev_hash {
+                report.issues.push(ResourceAuditChainIssue::BrokenLink {
+                    seq: stored.seq,
+                    expected_prev_hash: expected_prev_hash.to_owned(),
+                    found_prev_hash: stored.prev_hash.to_owned(),
+                });
+            }
+
+            if row.is_redacted() {
+                report.redacted += 1;
+            }
+
+            if !row.content_verifies(&redactions) {
+                if let Some(computed) = row.recompute_chain_link() {
+                    report.issues.push(ResourceAuditChainIssue::Modified {
+                        seq: stored.seq,
+                        row_id: row.id,
+                        stored_hash: stored.hash.to_owned(),
+                        computed_hash: computed.hash,
+                    });
+                }
+            }
+
+            if let Some(checkpoint) = checkpoints.remove(&stored.seq) {
+                if checkpoint.root_hash != stored.hash {
+                    report.issues.push(
+                        ResourceAuditChainIssue::CheckpointMismatch {
+                            seq: stored.seq,
+                            checkpoint_hash: checkpoint.root_hash,
+                            row_hash: stored.hash.to_owned(),
+                        },
+                    );
+                }
+            }
+
+            expected_seq = stored.seq + 1;
+            expected_prev_hash = stored.hash.to_owned();
+            report.rows += 1;
+            report.head = Some(stored);
+        }
+
+        if page_len < VERIFICATION_PAGE_SIZE {
+            break;
+        }
+    }
+
+    //
+    // Checkpoints left over point past the last row still present: the tail
+    // of the chain was removed.
+    //
+    for seq in checkpoints.into_keys() {
+        report
+            .issues
+            .push(ResourceAuditChainIssue::CheckpointWithoutRow { seq });
+    }
+
+    Ok(report)
+}
+
+// * ---------------------------------------------------------------------------
+// * TESTS
+// * ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{
+            dtos::{
+                resource_audit_log::{
+                    NewResourceAuditLogEvent, ResourceAuditArchive,
+                    ResourceAuditEventKind, ResourceAuditLog,
+                    ResourceAuditRedaction, ResourceAuditResourceType,
+                    RESOURCE_AUDIT_ERASURE_ACTION,
+                },
+                written_by::WrittenBy,
+            },
+            entities::MockResourceAuditLogFetching,
+        },
+        models::{HmacSecretEntry, HmacSecretSet},
+    };
+
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use myc_config::secret_resolver::SecretResolver;
+    use shaku::Component;
+
+    const DEK: [u8; 32] = [9u8; 32];
+
+    #[derive(Component)]
+    #[shaku(interface = EncryptionKeyFetching)]
+    struct FixedDekRepo;
+
+    #[async_trait]
+    impl EncryptionKeyFetching for FixedDekRepo {
+        async fn get_or_provision_dek(
+            &self,
+            _: Option<Uuid>,
+            _: &[u8; 32],
+        ) -> Result<[u8; 32], MappedErrors> {
+            Ok(DEK)
+        }
+
+        async fn fetch_dek(
+            &self,
+            _: Option<Uuid>,
+            _: &[u8; 32],
+        ) -> Result<Option<[u8; 32]>, MappedErrors> {
+            Ok(Some(DEK))
+        }
+    }
+
+    #[derive(Component)]
+    #[shaku(interface = EncryptionKeyFetching)]
+    struct NoDekRepo;
+
+    #[async_trait]
+    impl EncryptionKeyFetching for NoDekRepo {
+        async fn get_or_provision_dek(
+            &self,
+            _: Option<Uuid>,
+            _: &[u8; 32],
+        ) -> Result<[u8; 32], MappedErrors> {
+            panic!("verification must not provision a DEK")
+        }
+
+        async fn fetch_dek(
+            &self,
+            _: Option<Uuid>,
+            _: &[u8; 32],
+        ) -> Result<Option<[u8; 32]>, MappedErrors> {
+            Ok(None)
+        }
+    }
+
+    fn test_config() -> AccountLifeCycle {
+        AccountLifeCycle {
+            domain_name: SecretResolver::Value("example.com".to_string()),
+            domain_url: None,
+            locale: None,
+            token_expiration: SecretResolver::Value(3600),
+            invitation_expiration: SecretResolver::Value(604_800),
+            noreply_name: None,
+            noreply_email: SecretResolver::Value(
+                "noreply@example.com".to_string(),
+            ),
+            support_name: None,
+            support_email: SecretResolver::Value(
+                "support@example.com".to_string(),
+            ),
+            token_secret: SecretResolver::Value(Uuid::new_v4().to_string()),
+            hmac_primary_version: 1,
+            hmac_secrets: HmacSecretSet::new(vec![HmacSecretEntry {
+                version: 1,
+                secret: SecretResolver::Value("test-hmac".to_string()),
+            }]),
+            staff_bootstrap_secret: None,
+            sms_provider: None,
+            delivery_webhook_secret: None,
+        }
+    }
+
+    /// Build a well-formed chain of `len` rows for `tenant_id`.
+    fn chain(tenant_id: Option<Uuid>, len: i64) -> Vec<ResourceAuditLog> {
+        let mut prev_hash = RESOURCE_AUDIT_CHAIN_GENESIS_HASH.to_string();
+
+        (1..=len)
+            .map(|seq| {
+                let event = NewResourceAuditLogEvent {
+                    resource_type: ResourceAuditResourceType::Account,
+                    resource_id: Uuid::new_v4(),
+                    tenant_id,
+                    event: ResourceAuditEventKind::Created,
+                    performed_by: WrittenBy::new_anemic(),
+                    metadata: serde_json::json!({ "seq": seq }),
+                    created_at: Utc::now(),
+                };
+
+                let link = event.chain_link(seq, &prev_hash);
+                prev_hash = link.hash.to_owned();
+
+                ResourceAuditLog {
+                    id: Uuid::new_v4(),
+                    resource_type: event.resource_type,
+                    resource_id: event.resource_id,
+                    tenant_id: event.tenant_id,
+                    event: event.event,
+                    performed_by: event.performed_by,
+                    metadata: event.metadata,
+                    created_at: event.created_at,
+                    chain: Some(link),
+                    redacted_at: None,
+                }
+            })
+            .collect()
+    }
+
+    fn mock_serving(
+        rows: Vec<ResourceAuditLog>,
+        checkpoints: Vec<ResourceAuditCheckpoint>,
+    ) -> MockResourceAuditLogFetching {
+        mock_serving_archived(rows, checkpoints, vec![])
+    }
+
+    fn mock_serving_archived(
+        rows: Vec<ResourceAuditLog>,
+        checkpoints: Vec<ResourceAuditCheckpoint>,
+        archives: Vec<ResourceAuditArchive>,
+    ) -> MockResourceAuditLogFetching {
+        let mut mock = MockResourceAuditLogFetching::new();
+
+        mock.expect_list_archives().returning(move |_| {
+            Ok(FetchManyResponseKind::Found(archives.to_owned()))
+        });
+
+        mock.expect_list_checkpoints().returning(move |_| {
+            Ok(FetchManyResponseKind::Found(checkpoints.to_owned()))
+        });
+
+        let erasures = rows
+            .iter()
+            .filter(|row| {
+                row.metadata["action"] == RESOURCE_AUDIT_ERASURE_ACTION
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        mock.expect_list_filtered().returning(move |_, _, _| {
+            Ok(FetchManyResponseKind::Found(erasures.to_owned()))
+        });
+
+        mock.expect_list_chain_page().returning(
+            move |_, after_seq, page_size| {
+                let page = rows
+                    .iter()
+                    .filter(|row| row.chain.as_ref().unwrap().seq > after_seq)
+                    .take(page_size as usize)
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                if page.is_empty() {
+                    return Ok(FetchManyResponseKind::NotFound);
+                }
+
+                Ok(FetchManyResponseKind::Found(page))
+            },
+        );
+
+        mock
+    }
+
+    fn checkpoint_at(
+        rows: &[ResourceAuditLog],
+        seq: i64,
+    ) -> ResourceAuditCheckpoint {
+        let link = rows[(seq - 1) as usize].chain.to_owned().unwrap();
+        ResourceAuditCheckpoint::new_signed(rows[0].tenant_id, &link, &DEK)
+            .unwrap()
+    }
+
+    async fn verify(
+        mock: &MockResourceAuditLogFetching,
+    ) -> ResourceAuditChainReport {
+        verify_resource_audit_chain(
+            None,
+            test_config(),
+            Box::new(mock),
+            Box::new(&FixedDekRepo),
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn intact_chain_spanning_several_pages_is_reported_intact() {
+        let rows = chain(None, VERIFICATION_PAGE_SIZE + 3);
+        let checkpoints = vec![checkpoint_at(&rows, 100)];
+
+        let report = verify(&mock_serving(rows, checkpoints)).await;
+
+        assert!(report.is_intact(), "{:?}", report.issues);
+        assert_eq!(report.rows, (VERIFICATION_PAGE_SIZE + 3) as u64);
+        assert_eq!(report.checkpoints, 1);
+        assert_eq!(report.head.unwrap().seq, VERIFICATION_PAGE_SIZE + 3);
+    }
+
+    #[tokio::test]
+    async fn modified_row_is_reported() {
+        let mut rows = chain(None, 3);
+        rows[1].metadata = serde_json::json!({ "tampered": true });
+        let modified_id = rows[1].id;
+
+        let report = verify(&mock_serving(rows, vec![])).await;
+
+        assert_eq!(report.issues.len(), 1);
+        assert!(matches!(
+            &report.issues[0],
+            ResourceAuditChainIssue::Modified { seq: 2, row_id, .. }
+                if *row_id == modified_id
+        ));
+    }
+
+    /// Append an erasure event recording the redaction of `rows[index]`.
+    fn redact(rows: &mut Vec<ResourceAuditLog>, index: usize) {
+        rows[index].metadata = serde_json::json!({ "email": "erased" });
+        rows[index].redacted_at = Some(Utc::now());
+
+        let head = rows.last().unwrap().chain.to_owned().unwrap();
+        let event = NewResourceAuditLogEvent {
+            resource_type: ResourceAuditResourceType::Account,
+            resource_id: Uuid::new_v4(),
+            tenant_id: None,
+            event: ResourceAuditEventKind::Updated,
+            performed_by: WrittenBy::new_anemic(),
+            metadata: serde_json::json!({
+                "action": RESOURCE_AUDIT_ERASURE_ACTION,
+                "redactions": [ResourceAuditRedaction::of(&rows[index])],
+            }),
+            created_at: Utc::now(),
+        };
+
+        rows.push(ResourceAuditLog {
+            id: Uuid::new_v4(),
+            resource_type: event.resource_type.to_owned(),
+            resource_id: event.resource_id,
+            tenant_id: event.tenant_id,
+            event: event.event.to_owned(),
+            performed_by: event.performed_by.to_owned(),
+            metadata: event.metadata.to_owned(),
+            created_at: event.created_at,
+            chain: Some(event.chain_link(head.seq + 1, &head.hash)),
+            redacted_at: None,
+        });
+    }
+
+    #[tokio::test]
+    async fn recorded_redaction_is_counted_instead_of_reported() {
+        let mut rows = chain(None, 3);
+        redact(&mut rows, 1);
+
+        let report = verify(&mock_serving(rows, vec![])).await;
+
+        assert!(report.is_intact(), "{:?}", report.issues);
+        assert_eq!(report.rows, 4);
+        assert_eq!(report.redacted, 1);
+    }
+
+    #[tokio::test]
+    async fn forged_redaction_is_reported_as_modified() {
+        let mut rows = chain(None, 3);
+        redact(&mut rows, 1);
+
+        //
+        // Stamping `redacted_at` on a tampered row does not hide it, and
+        // neither does tampering further with a row redacted for real.
+        //
+        rows[0].metadata = serde_json::json!({ "tampered": true });
+        rows[0].redacted_at = Some(Utc::now());
+        rows[1].metadata = serde_json::json!({ "email": "someone else" });
+        let (forged_id, tampered_id) = (rows[0].id, rows[1].id);
+
+        let report = verify(&mock_serving(rows, vec![])).await;
+
+        assert_eq!(report.issues.len(), 2, "{:?}", report.issues);
+        assert!(matches!(
+            &report.issues[0],
+            ResourceAuditChainIssue::Modified { seq: 1, row_id, .. }
+                if *row_id == forged_id
+        ));
+        assert!(matches!(
+            &report.issues[1],
+            ResourceAuditChainIssue::Modified { seq: 2, row_id, .. }
+                if *row_id == tampered_id
+        ));
+    }
+
+    #[tokio::test]
+    async fn deleted_row_is_reported_as_gap() {
+        let mut rows = chain(None, 4);
+        rows.remove(1);
+
+        let report = verify(&mock_serving(rows, vec![])).await;
+
+        assert_eq!(
+            report.issues,
+            vec![ResourceAuditChainIssue::Gap {
+                expected_seq: 2,
+                found_seq: 3
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn rehashed_row_breaks_the_next_link() {
+        let mut rows = chain(None, 3);
+
+        //
+        // Rewrite row 2 and recompute its own hash so it is self-consistent:
+        // the successor's `prev_hash` still points at the original.
+        //
+        rows[1].metadata = serde_json::json!({ "tampered": true });
+        rows[1].chain = rows[1].recompute_chain_link();
+
+        let report = verify(&mock_serving(rows, vec![])).await;
+
+        assert_eq!(report.issues.len(), 1);
+        assert!(matches!(
+            report.issues[0],
+            ResourceAuditChainIssue::BrokenLink { seq: 3, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn truncated_tail_is_caught_by_checkpoint() {
+        let rows = chain(None, 5);
+        let checkpoints = vec![checkpoint_at(&rows, 5)];
+
+        let truncated = rows[..3].to_vec();
+        let report = verify(&mock_serving(truncated, checkpoints)).await;
+
+        assert_eq!(
+            report.issues,
+            vec![ResourceAuditChainIssue::CheckpointWithoutRow { seq: 5 }]
+        );
+    }
+
+    #[tokio::test]
+    async fn forged_checkpoint_is_reported() {
+        let rows = chain(None, 2);
+        let mut forged = checkpoint_at(&rows, 2);
+        forged.signature = "00".repeat(32);
+
+        let report = verify(&mock_serving(rows, vec![forged])).await;
+
+        assert_eq!(
+            report.issues,
+            vec![ResourceAuditChainIssue::InvalidCheckpointSignature {
+                seq: 2
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn missing_dek_is_reported_without_provisioning_one() {
+        let rows = chain(None, 2);
+        let checkpoints = vec![checkpoint_at(&rows, 2)];
+        let mock = mock_serving(rows, checkpoints);
+
+        let report = verify_resource_audit_chain(
+            None,
+            test_config(),
+            Box::new(&mock),
+            Box::new(&NoDekRepo),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            report.issues,
+            vec![ResourceAuditChainIssue::CheckpointKeyMissing]
+        );
+        assert_eq!(report.rows, 2);
+    }
+
+    fn archive_of(rows: &[ResourceAuditLog]) -> ResourceAuditArchive {
+        let first = rows.first().unwrap().chain.to_owned().unwrap();
+        let last = rows.last().unwrap().chain.to_owned().unwrap();
+
+        ResourceAuditArchive {
+            id: Uuid::new_v4(),
+            chain_tenant_id: None,
+            first_seq: Some(first.seq),
+            last_seq: Some(last.seq),
+            first_prev_hash: Some(first.prev_hash),
+            last_hash: Some(last.hash),
+            cutoff: Utc::now(),
+            row_count: rows.len() as i64,
+            location: "system/archive.jsonl.zst".to_string(),
+            sha256: "0".repeat(64),
+            signature: String::new(),
+            created_at: Utc::now(),
+        }
+        .signed(&DEK)
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn walk_resumes_after_the_latest_archive() {
+        let rows = chain(None, 6);
+        let checkpoints =
+            vec![checkpoint_at(&rows, 2), checkpoint_at(&rows, 5)];
+        let archives = vec![archive_of(&rows[..2]), archive_of(&rows[2..4])];
+
+        let report = verify(&mock_serving_archived(
+            rows[4..].to_vec(),
+            checkpoints,
+            archives,
+        ))
+        .await;
+
+        assert!(report.is_intact(), "{:?}", report.issues);
+        assert_eq!(report.archives, 2);
+        assert_eq!(report.rows, 2);
+    }
+
+    #[tokio::test]
+    async fn archive_skipping_rows_is_reported() {
+        let rows = chain(None, 6);
+        let skipped = archive_of(&rows[3..4]);
+        let skipped_id = skipped.id;
+        let archives = vec![archive_of(&rows[..2]), skipped];
+
+        let mut forged = archive_of(&rows[..2]);
+        forged.row_count = 1;
+
+        let report = verify(&mock_serving_archived(
+            rows[4..].to_vec(),
+            vec![],
+            [archives, vec![forged.to_owned()]].concat(),
+        ))
+        .await;
+
+        assert!(report.issues.contains(
+            &ResourceAuditChainIssue::ArchiveDiscontinuity {
+                archive_id: skipped_id,
+                expected_seq: 3,
+                found_seq: 4,
+            }
+        ));
+        assert!(report.issues.contains(
+            &ResourceAuditChainIssue::InvalidArchiveSignature {
+                archive_id: forged.id,
+            }
+        ));
+    }
+}
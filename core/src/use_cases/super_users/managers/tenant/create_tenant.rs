@@ -186,6 +186,16 @@ mod tests {
         {
             unimplemented!()
         }
+
+        async fn register_email_template(
+            &self,
+            _: crate::domain::dtos::tenant::TenantEmailTemplate,
+        ) -> Result<
+            crate::domain::dtos::tenant::TenantEmailTemplate,
+            MappedErrors,
+        > {
+            unimplemented!()
+        }
     }
 
     fn staff_profile() -> Profile {
@@ -78,6 +78,14 @@ mod tests {
             Ok(self.response.clone())
         }
 
+        async fn purge(
+            &self,
+            _: Uuid,
+            _: chrono::DateTime<chrono::Utc>,
+        ) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
+            unimplemented!()
+        }
+
         async fn delete_owner(
             &self,
             _: Uuid,
@@ -94,6 +102,15 @@ mod tests {
         ) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
             unimplemented!()
         }
+
+        async fn delete_email_template(
+            &self,
+            _: Uuid,
+            _: crate::domain::dtos::tenant::EmailTemplateKind,
+            _: String,
+        ) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
+            unimplemented!()
+        }
     }
 
     fn staff_profile() -> Profile {
@@ -117,6 +117,19 @@ mod tests {
             Ok(self.response.clone())
         }
 
+        async fn update_tenant_parent(
+            &self,
+            _: Uuid,
+            _: Option<Uuid>,
+        ) -> Result<
+            mycelium_base::entities::UpdatingResponseKind<
+                crate::domain::dtos::tenant::Tenant,
+            >,
+            MappedErrors,
+        > {
+            unimplemented!()
+        }
+
         async fn update_tenant_meta(
             &self,
             _: Uuid,
@@ -100,7 +100,10 @@ pub async fn downgrade_account_privileges(
             tenant_id,
             ResourceAuditEventKind::Updated,
             WrittenBy::new_from_account(profile.acc_id),
-            serde_json::json!({ "action": "downgrade_account_privileges" }),
+            serde_json::json!({
+                "action": "downgrade_account_privileges",
+                "accountType": account.account_type,
+            }),
         )
         .await;
     }
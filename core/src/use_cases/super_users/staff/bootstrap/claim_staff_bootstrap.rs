@@ -1,11 +1,20 @@
 use crate::domain::{
     dtos::{
-        account::Account, account_type::AccountType,
-        instance_settings::STAFF_BOOTSTRAP_KEY, user::User,
+        account::Account,
+        account_type::AccountType,
+        instance_settings::STAFF_BOOTSTRAP_KEY,
+        resource_audit_log::{
+            ResourceAuditEventKind, ResourceAuditResourceType,
+        },
+        user::User,
         written_by::WrittenBy,
     },
-    entities::{AccountRegistration, InstanceSettingsRegistration},
+    entities::{
+        AccountRegistration, InstanceSettingsRegistration,
+        ResourceAuditLogRegistration,
+    },
 };
+use crate::use_cases::shared::audit::emit_resource_audit_event;
 
 use mycelium_base::{
     entities::GetOrCreateResponseKind,
@@ -26,6 +35,7 @@ pub async fn claim_staff_bootstrap(
     user: User,
     account_registration_repo: Box<&dyn AccountRegistration>,
     instance_settings_registration_repo: Box<&dyn InstanceSettingsRegistration>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
 ) -> Result<GetOrCreateResponseKind<Account>, MappedErrors> {
     let user_id = user.id.ok_or_else(|| {
         use_case_err("User ID not found".to_string()).with_exp_true()
@@ -55,24 +65,49 @@ pub async fn claim_staff_bootstrap(
     let created_by =
         WrittenBy::new_from_user_with_email(user_id, &account_name);
 
-    account_registration_repo
+    let response = account_registration_repo
         .get_or_create_user_account(
             Account::new(
                 account_name,
                 user,
                 AccountType::Staff,
-                Some(created_by),
+                Some(created_by.to_owned()),
             ),
             true,
             false,
         )
-        .await
+        .await?;
+
+    if let GetOrCreateResponseKind::Created(Account {
+        id: Some(account_id),
+        ..
+    }) = &response
+    {
+        emit_resource_audit_event(
+            audit_repo,
+            ResourceAuditResourceType::Account,
+            *account_id,
+            None,
+            ResourceAuditEventKind::Created,
+            created_by,
+            serde_json::json!({
+                "action": "claim_staff_bootstrap",
+                "accountType": "staff",
+            }),
+        )
+        .await;
+    }
+
+    Ok(response)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::{dtos::account::AccountMetaKey, dtos::email::Email};
+    use crate::domain::{
+        dtos::account::AccountMetaKey, dtos::email::Email,
+        entities::MockResourceAuditLogRegistration,
+    };
 
     use async_trait::async_trait;
     use chrono::Local;
@@ -115,11 +150,12 @@ mod tests {
     impl AccountRegistration for SpyAccountRegistration {
         async fn get_or_create_user_account(
             &self,
-            account: Account,
+            mut account: Account,
             _: bool,
             _: bool,
         ) -> Result<GetOrCreateResponseKind<Account>, MappedErrors> {
             self.called.store(true, Ordering::SeqCst);
+            account.id = Some(Uuid::new_v4());
             Ok(GetOrCreateResponseKind::Created(account))
         }
 
@@ -202,10 +238,22 @@ mod tests {
         };
         let account_repo = SpyAccountRegistration::default();
 
+        let mut audit_mock = MockResourceAuditLogRegistration::new();
+        audit_mock
+            .expect_create()
+            .times(1)
+            .withf(|event| {
+                event.resource_type == ResourceAuditResourceType::Account
+                    && event.event == ResourceAuditEventKind::Created
+                    && event.metadata["action"] == "claim_staff_bootstrap"
+            })
+            .returning(|_| Ok(()));
+
         let result = claim_staff_bootstrap(
             test_user(),
             Box::new(&account_repo as &dyn AccountRegistration),
             Box::new(&registration_repo as &dyn InstanceSettingsRegistration),
+            Box::new(&audit_mock),
         )
         .await?;
 
@@ -225,10 +273,14 @@ mod tests {
         };
         let account_repo = SpyAccountRegistration::default();
 
+        let mut audit_mock = MockResourceAuditLogRegistration::new();
+        audit_mock.expect_create().times(0);
+
         let result = claim_staff_bootstrap(
             test_user(),
             Box::new(&account_repo as &dyn AccountRegistration),
             Box::new(&registration_repo as &dyn InstanceSettingsRegistration),
+            Box::new(&audit_mock),
         )
         .await;
 
@@ -0,0 +1,136 @@
+use mycelium_base::utils::errors::{invalid_arg_err, MappedErrors};
+use reqwest::Url;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use tokio::net::lookup_host;
+
+/// Check that a notification webhook URL is HTTPS and reaches a public host
+///
+/// The host is resolved and every address it resolves to must be public:
+/// loopback, private, shared (CGNAT), link-local (cloud metadata included),
+/// unique-local, multicast and unspecified addresses are rejected. Accounts
+/// register these URLs themselves, so the check runs when the URL is saved and
+/// again before each delivery, the host being free to resolve elsewhere in
+/// between.
+pub(crate) async fn check_public_webhook_url(
+    url: &str,
+) -> Result<Url, MappedErrors> {
+    let parsed = match Url::parse(url) {
+        Ok(parsed) if parsed.scheme() == "https" => parsed,
+        _ => {
+            return invalid_arg_err(
+                "The notification webhook URL should be a valid HTTPS URL",
+            )
+            .with_exp_true()
+            .as_error()
+        }
+    };
+
+    let host = match parsed.host_str() {
+        Some(host) => host.trim_start_matches('[').trim_end_matches(']'),
+        None => {
+            return invalid_arg_err(
+                "The notification webhook URL should have a host",
+            )
+            .with_exp_true()
+            .as_error()
+        }
+    };
+
+    let port = parsed.port_or_known_default().unwrap_or(443);
+
+    let addresses = match host.parse::<IpAddr>() {
+        Ok(address) => vec![address],
+        Err(_) => match lookup_host((host, port)).await {
+            Ok(addresses) => {
+                addresses.map(|address| address.ip()).collect::<Vec<_>>()
+            }
+            Err(err) => {
+                return invalid_arg_err(format!(
+                    "Unable to resolve the notification webhook host: {err}"
+                ))
+                .with_exp_true()
+                .as_error()
+            }
+        },
+    };
+
+    if addresses.is_empty() || !addresses.into_iter().all(is_public_address) {
+        return invalid_arg_err(
+            "The notification webhook URL should reach a public host",
+        )
+        .with_exp_true()
+        .as_error();
+    }
+
+    Ok(parsed)
+}
+
+fn is_public_address(address: IpAddr) -> bool {
+    match address {
+        IpAddr::V4(address) => is_public_ipv4(address),
+        IpAddr::V6(address) => match address.to_ipv4_mapped() {
+            Some(mapped) => is_public_ipv4(mapped),
+            None => is_public_ipv6(address),
+        },
+    }
+}
+
+fn is_public_ipv4(address: Ipv4Addr) -> bool {
+    let [first, second, ..] = address.octets();
+
+    !(address.is_loopback()
+        || address.is_private()
+        || address.is_link_local()
+        || address.is_unspecified()
+        || address.is_broadcast()
+        || address.is_multicast()
+        || address.is_documentation()
+        || first == 0
+        || (first == 100 && (64..128).contains(&second)))
+}
+
+fn is_public_ipv6(address: Ipv6Addr) -> bool {
+    let first = address.segments()[0];
+
+    !(address.is_loopback()
+        || address.is_unspecified()
+        || address.is_multicast()
+        || (first & 0xfe00) == 0xfc00
+        || (first & 0xffc0) == 0xfe80)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn internal_hosts_are_rejected() {
+        for url in [
+            "http://93.184.216.34/notify",
+            "https://127.0.0.1/notify",
+            "https://localhost/notify",
+            "https://10.0.0.1/notify",
+            "https://172.16.5.4/notify",
+            "https://192.168.1.10/notify",
+            "https://169.254.169.254/latest/meta-data",
+            "https://100.64.0.1/notify",
+            "https://0.0.0.0/notify",
+            "https://[::1]/notify",
+            "https://[fd00::1]/notify",
+            "https://[fe80::1]/notify",
+            "https://[::ffff:127.0.0.1]/notify",
+        ] {
+            assert!(
+                check_public_webhook_url(url).await.is_err(),
+                "{url} should be rejected"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn public_hosts_are_accepted() {
+        for url in ["https://93.184.216.34/notify", "https://[2606:4700::1]/"] {
+            assert!(check_public_webhook_url(url).await.is_ok(), "{url}");
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use crate::{
+    domain::{
+        dtos::{
+            notification::{
+                NotificationChannel, NotificationKind, NotificationRecipient,
+            },
+            tenant::TenantMetaKey,
+        },
+        entities::{EncryptionKeyFetching, TenantFetching},
+        utils::AAD_FIELD_TELEGRAM_BOT_TOKEN,
+    },
+    models::AccountLifeCycle,
+    use_cases::{
+        gateway::telegram::decrypt_telegram_secret,
+        support::check_public_webhook_url,
+    },
+};
+
+use lazy_static::lazy_static;
+use mycelium_base::{
+    entities::FetchResponseKind,
+    utils::errors::{use_case_err, MappedErrors},
+};
+use reqwest::{redirect::Policy, Client, RequestBuilder};
+use serde_json::json;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// Base URL of the Telegram Bot API
+const TELEGRAM_API_URL: &str = "https://api.telegram.org";
+
+/// Time to wait for a channel provider before giving up
+const CHANNEL_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+lazy_static! {
+    /// Client shared by the channel deliveries
+    ///
+    /// Redirects are not followed: the webhook channel posts to URLs the
+    /// accounts register themselves, and a redirect would skip the check of
+    /// the target host.
+    static ref CHANNEL_CLIENT: Result<Client, String> = Client::builder()
+        .redirect(Policy::none())
+        .timeout(CHANNEL_REQUEST_TIMEOUT)
+        .build()
+        .map_err(|err| err.to_string());
+}
+
+/// Deliver a rendered notification through a non-email channel
+///
+/// Fails when the recipient has no address for the channel, when the channel
+/// is not configured (no tenant bot, no SMS provider) or when the provider
+/// rejects the message. Callers are expected to fall back to email.
+#[tracing::instrument(
+    name = "deliver_channel_message",
+    fields(channel = %channel, kind = %kind),
+    skip_all
+)]
+pub(crate) async fn deliver_channel_message(
+    channel: NotificationChannel,
+    kind: NotificationKind,
+    recipient: &NotificationRecipient,
+    subject: &str,
+    text: &str,
+    tenant_id: Option<Uuid>,
+    config: &AccountLifeCycle,
+    tenant_fetching_repo: &dyn TenantFetching,
+    encryption_key_fetching_repo: &dyn EncryptionKeyFetching,
+) -> Result<(), MappedErrors> {
+    let client = match CHANNEL_CLIENT.as_ref() {
+        Ok(client) => client,
+        Err(err) => {
+            return use_case_err(format!(
+                "Unable to build the channel client: {err}"
+            ))
+            .as_error()
+        }
+    };
+
+    let message = format!("{subject}\n\n{text}");
+
+    let request = match channel {
+        NotificationChannel::Email => {
+            return use_case_err("Emails are queued, not delivered inline")
+                .as_error()
+        }
+        NotificationChannel::Telegram => {
+            let chat_id = match &recipient.telegram_user_id {
+                Some(id) => id.0,
+                None => {
+                    return use_case_err("No Telegram user linked")
+                        .with_exp_true()
+                        .as_error()
+                }
+            };
+
+            //
+            // Telegram users are linked through a tenant bot, so only
+            // tenant-bound notifications can reach them.
+            //
+            let tenant_id = match tenant_id {
+                Some(id) => id,
+                None => {
+                    return use_case_err(
+                        "Telegram notifications require a tenant bot",
+                    )
+                    .with_exp_true()
+                    .as_error()
+                }
+            };
+
+            let bot_token = resolve_bot_token(
+                tenant_id,
+                config,
+                tenant_fetching_repo,
+                encryption_key_fetching_repo,
+            )
+            .await?;
+
+            client
+                .post(format!("{TELEGRAM_API_URL}/bot{bot_token}/sendMessage"))
+                .json(&json!({ "chat_id": chat_id, "text": message }))
+        }
+        NotificationChannel::Sms => {
+            let phone_number = match &recipient.phone_number {
+                Some(phone_number) => phone_number,
+                None => {
+                    return use_case_err("No phone number registered")
+                        .with_exp_true()
+                        .as_error()
+                }
+            };
+
+            let provider = match &config.sms_provider {
+                Some(provider) => provider,
+                None => {
+                    return use_case_err("No SMS provider configured")
+                        .with_exp_true()
+                        .as_error()
+                }
+            };
+
+            let sender = match &provider.sender {
+                Some(sender) => Some(sender.async_get_or_error().await?),
+                None => None,
+            };
+
+            let request = client
+                .post(provider.url.async_get_or_error().await?)
+                .json(&json!({
+                    "from": sender,
+                    "to": phone_number,
+                    "text": message,
+                }));
+
+            match &provider.api_key {
+                Some(api_key) => {
+                    request.bearer_auth(api_key.async_get_or_error().await?)
+                }
+                None => request,
+            }
+        }
+        NotificationChannel::Webhook => {
+            let url = match &recipient.webhook_url {
+                Some(url) => url,
+                None => {
+                    return use_case_err("No notification webhook registered")
+                        .with_exp_true()
+                        .as_error()
+                }
+            };
+
+            let url = check_public_webhook_url(url).await?;
+
+            client.post(url).json(&json!({
+                "kind": kind,
+                "accountId": recipient.account_id,
+                "subject": subject,
+                "text": text,
+            }))
+        }
+    };
+
+    send_request(request).await
+}
+
+/// Send a provider request, treating non-2xx responses as failures
+///
+/// URLs are stripped from the errors since the Telegram one embeds the bot
+/// token.
+async fn send_request(request: RequestBuilder) -> Result<(), MappedErrors> {
+    match request.send().await {
+        Ok(response) if response.status().is_success() => Ok(()),
+        Ok(response) => use_case_err(format!(
+            "Channel provider responded with status {}",
+            response.status()
+        ))
+        .as_error(),
+        Err(err) => use_case_err(format!(
+            "Unable to reach the channel provider: {}",
+            err.without_url()
+        ))
+        .as_error(),
+    }
+}
+
+/// Decrypt the bot token stored in the tenant meta by `set_telegram_config`
+async fn resolve_bot_token(
+    tenant_id: Uuid,
+    config: &AccountLifeCycle,
+    tenant_fetching_repo: &dyn TenantFetching,
+    encryption_key_fetching_repo: &dyn EncryptionKeyFetching,
+) -> Result<String, MappedErrors> {
+    let encrypted_bot_token = match tenant_fetching_repo
+        .get_tenant_public_by_id(tenant_id)
+        .await?
+    {
+        FetchResponseKind::Found(tenant) => tenant.meta.and_then(|meta| {
+            meta.get(&TenantMetaKey::TelegramBotToken).cloned()
+        }),
+        FetchResponseKind::NotFound(_) => None,
+    };
+
+    match encrypted_bot_token {
+        Some(encrypted) => {
+            decrypt_telegram_secret(
+                &encrypted,
+                tenant_id,
+                config.to_owned(),
+                encryption_key_fetching_repo,
+                AAD_FIELD_TELEGRAM_BOT_TOKEN,
+            )
+            .await
+        }
+        None => use_case_err("Telegram is not configured for the tenant")
+            .with_exp_true()
+            .as_error(),
+    }
+}
@@ -3,14 +3,20 @@ use crate::{
         dtos::{
             email::Email,
             message::{FromEmail, Message, MessageSendingEvent},
-            tenant::TenantMetaKey,
+            notification::{NotificationChannel, NotificationKind},
+            tenant::{EmailTemplateKind, TenantMetaKey},
+        },
+        entities::{
+            EmailSuppressionFetching, EncryptionKeyFetching, LocalMessageWrite,
+            NotificationRecipientFetching, TenantFetching,
         },
-        entities::{LocalMessageWrite, TenantFetching},
     },
     models::AccountLifeCycle,
     settings::{DEFAULT_TENANT_ID_KEY, TEMPLATES},
 };
 
+use super::deliver_channel_message;
+
 use mycelium_base::{
     entities::{CreateResponseKind, FetchResponseKind},
     utils::errors::{use_case_err, MappedErrors},
@@ -18,6 +24,9 @@ use mycelium_base::{
 use tera::Context;
 use uuid::Uuid;
 
+/// Wrap width of the plain-text alternative of notification emails.
+const TEXT_BODY_WIDTH: usize = 78;
+
 #[tracing::instrument(name = "dispatch_notification", skip_all)]
 pub(crate) async fn dispatch_notification<T: ToString>(
     parameters: Vec<(T, String)>,
@@ -26,13 +35,171 @@ pub(crate) async fn dispatch_notification<T: ToString>(
     to: Email,
     cc: Option<Email>,
     local_message_write_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
     tenant_fetching_repo: Box<&dyn TenantFetching>,
 ) -> Result<CreateResponseKind<Option<Uuid>>, MappedErrors> {
     tracing::info!("Dispatching notification");
 
-    let (context, locale) =
-        populate_tenant_info(&parameters, &config, tenant_fetching_repo)
-            .await?;
+    let rendered = render_notification(
+        &parameters,
+        &template_path_prefix.to_string(),
+        &config,
+        *tenant_fetching_repo,
+    )
+    .await?;
+
+    queue_notification_email(
+        rendered,
+        config,
+        to,
+        cc,
+        *local_message_write_repo,
+        *email_suppression_fetching_repo,
+    )
+    .await
+}
+
+/// Dispatch a notification through the channels preferred by the recipient
+///
+/// Notifications of a `NotificationKind` are delivered through the channels
+/// listed in the recipient account preferences. Email is used when the
+/// recipient has no preferences for the kind, has no account, or when none of
+/// the preferred channels could deliver the notification. Other
+/// notifications are always sent by email.
+#[tracing::instrument(name = "dispatch_channel_notification", skip_all)]
+pub(crate) async fn dispatch_channel_notification<T: ToString>(
+    parameters: Vec<(T, String)>,
+    template_path_prefix: T,
+    config: AccountLifeCycle,
+    to: Email,
+    cc: Option<Email>,
+    local_message_write_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
+    tenant_fetching_repo: Box<&dyn TenantFetching>,
+    notification_recipient_fetching_repo: Box<
+        &dyn NotificationRecipientFetching,
+    >,
+    encryption_key_fetching_repo: Box<&dyn EncryptionKeyFetching>,
+) -> Result<(), MappedErrors> {
+    tracing::info!("Dispatching notification");
+
+    let template_path_prefix = template_path_prefix.to_string();
+
+    let rendered = render_notification(
+        &parameters,
+        &template_path_prefix,
+        &config,
+        *tenant_fetching_repo,
+    )
+    .await?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Resolve the recipient preferred channels
+    // ? -----------------------------------------------------------------------
+
+    let preferred =
+        match NotificationKind::from_template_path(&template_path_prefix) {
+            Some(kind) => match notification_recipient_fetching_repo
+                .get_by_email(to.to_owned())
+                .await
+            {
+                Ok(FetchResponseKind::Found(recipient)) => {
+                    let channels = recipient.preferences.channels_for(kind);
+                    Some((kind, recipient, channels))
+                }
+                Ok(FetchResponseKind::NotFound(_)) => None,
+                Err(err) => {
+                    tracing::warn!(
+                    "Unable to fetch the notification preferences, sending \
+                     by email: {err}"
+                );
+
+                    None
+                }
+            },
+            None => None,
+        };
+
+    // ? -----------------------------------------------------------------------
+    // ? Deliver through the non-email channels
+    // ? -----------------------------------------------------------------------
+
+    let mut send_email = true;
+
+    if let Some((kind, recipient, channels)) = preferred {
+        let mut delivered = false;
+        let text = rendered.text_body.clone().unwrap_or_default();
+
+        for channel in channels
+            .iter()
+            .filter(|channel| **channel != NotificationChannel::Email)
+        {
+            match deliver_channel_message(
+                *channel,
+                kind,
+                &recipient,
+                &rendered.subject,
+                &text,
+                rendered.tenant_id,
+                &config,
+                *tenant_fetching_repo,
+                *encryption_key_fetching_repo,
+            )
+            .await
+            {
+                Ok(()) => delivered = true,
+                Err(err) => tracing::warn!(
+                    "Unable to deliver the notification through {channel}: \
+                     {err}"
+                ),
+            }
+        }
+
+        //
+        // Email is kept as the last resort when every preferred channel
+        // failed.
+        //
+        send_email =
+            channels.contains(&NotificationChannel::Email) || !delivered;
+    }
+
+    if send_email {
+        queue_notification_email(
+            rendered,
+            config,
+            to,
+            cc,
+            *local_message_write_repo,
+            *email_suppression_fetching_repo,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// A notification rendered from the tenant or the file templates
+pub(crate) struct RenderedNotification {
+    pub(crate) subject: String,
+    pub(crate) body: String,
+    pub(crate) text_body: Option<String>,
+    pub(crate) reply_to: Option<Email>,
+    pub(crate) tenant_id: Option<Uuid>,
+}
+
+/// Render the subject and bodies of a notification
+///
+/// The tenant override of the notification is preferred, if any. A broken
+/// override should not block the notification, so errors fall back to the
+/// file templates.
+async fn render_notification<T: ToString>(
+    parameters: &Vec<(T, String)>,
+    template_path_prefix: &str,
+    config: &AccountLifeCycle,
+    tenant_fetching_repo: &dyn TenantFetching,
+) -> Result<RenderedNotification, MappedErrors> {
+    let (context, locale, tenant_id) =
+        populate_tenant_info(parameters, config, tenant_fetching_repo).await?;
 
     let locale = if let Some(locale) = locale {
         //
@@ -40,7 +207,7 @@ pub(crate) async fn dispatch_notification<T: ToString>(
         //
         tracing::trace!("Communicating with tenant locale: {:?}", locale);
         locale
-    } else if let Some(locale) = config.locale {
+    } else if let Some(locale) = &config.locale {
         //
         // Use the account locale if available
         //
@@ -54,16 +221,206 @@ pub(crate) async fn dispatch_notification<T: ToString>(
         "en-us".to_string()
     };
 
+    let tenant_rendered = match (
+        tenant_id,
+        EmailTemplateKind::from_template_path(template_path_prefix),
+    ) {
+        (Some(tenant_id), Some(kind)) => {
+            render_tenant_template(
+                tenant_id,
+                kind,
+                &locale,
+                &context,
+                tenant_fetching_repo,
+            )
+            .await
+        }
+        _ => None,
+    };
+
+    let (subject, body) = match tenant_rendered {
+        Some(rendered) => rendered,
+        None => render_file_templates(template_path_prefix, locale, &context)?,
+    };
+
+    //
+    // Replies go to the support address rather than the no-reply sender
+    //
+    let reply_to = context
+        .get("support_email")
+        .and_then(|value| value.as_str())
+        .and_then(|email| Email::from_string(email.to_string()).ok());
+
+    Ok(RenderedNotification {
+        text_body: render_text_body(&body),
+        subject,
+        body,
+        reply_to,
+        tenant_id,
+    })
+}
+
+/// Queue a rendered notification for SMTP delivery
+///
+/// Addresses in the suppression list (hard bounces and complaints) are not
+/// emailed: the notification is not queued when the recipient is suppressed,
+/// and a suppressed carbon-copy address is dropped.
+pub(crate) async fn queue_notification_email(
+    rendered: RenderedNotification,
+    config: AccountLifeCycle,
+    to: Email,
+    cc: Option<Email>,
+    local_message_write_repo: &dyn LocalMessageWrite,
+    email_suppression_fetching_repo: &dyn EmailSuppressionFetching,
+) -> Result<CreateResponseKind<Option<Uuid>>, MappedErrors> {
+    if let FetchResponseKind::Found(suppression) =
+        email_suppression_fetching_repo.get(to.to_owned()).await?
+    {
+        tracing::warn!(
+            "Notification not sent: {} is suppressed ({})",
+            suppression.email,
+            suppression.reason
+        );
+
+        return Ok(CreateResponseKind::NotCreated(
+            None,
+            format!("Recipient is suppressed: {}", suppression.reason),
+        ));
+    }
+
+    let cc = match cc {
+        Some(cc) => {
+            match email_suppression_fetching_repo.get(cc.to_owned()).await? {
+                FetchResponseKind::Found(suppression) => {
+                    tracing::warn!(
+                        "Carbon copy dropped: {} is suppressed ({})",
+                        suppression.email,
+                        suppression.reason
+                    );
+
+                    None
+                }
+                FetchResponseKind::NotFound(_) => Some(cc),
+            }
+        }
+        None => None,
+    };
+
+    let from_email =
+        Email::from_string(config.noreply_email.async_get_or_error().await?)?;
+
+    let from = if let Some(name) = config.noreply_name {
+        FromEmail::NamedEmail(format!(
+            "{} <{}>",
+            name.async_get_or_error().await?,
+            from_email.email()
+        ))
+    } else {
+        FromEmail::Email(from_email)
+    };
+
+    local_message_write_repo
+        .send(MessageSendingEvent::new(Message {
+            from,
+            to: vec![to],
+            cc: cc.into_iter().collect(),
+            bcc: vec![],
+            reply_to: rendered.reply_to,
+            headers: vec![],
+            subject: rendered.subject,
+            body: rendered.body,
+            text_body: rendered.text_body,
+            attachments: vec![],
+        }))
+        .await
+}
+
+/// Render the plain-text alternative of a rendered HTML email, so both parts
+/// come from the same template. Layout tables are flattened and links are
+/// listed as numbered references at the end of the text.
+pub(crate) fn render_text_body(html: &str) -> Option<String> {
+    match html2text::config::plain()
+        .raw_mode(true)
+        .no_link_wrapping()
+        .string_from_read(html.as_bytes(), TEXT_BODY_WIDTH)
+    {
+        Ok(text) => {
+            let mut lines: Vec<&str> = vec![];
+
+            for line in text.lines().map(str::trim_end) {
+                //
+                // Nested layout cells leave runs of empty lines behind.
+                //
+                if line.is_empty() && lines.last().is_some_and(|l| l.is_empty())
+                {
+                    continue;
+                }
+
+                lines.push(line);
+            }
+
+            Some(lines.join("\n").trim().to_string())
+        }
+        Err(err) => {
+            tracing::warn!("Unable to render email text alternative: {err}");
+            None
+        }
+    }
+}
+
+/// Render the tenant override of a notification, if one exists for the
+/// locale
+async fn render_tenant_template(
+    tenant_id: Uuid,
+    kind: EmailTemplateKind,
+    locale: &str,
+    context: &Context,
+    tenant_fetching_repo: &dyn TenantFetching,
+) -> Option<(String, String)> {
+    let template = match tenant_fetching_repo
+        .get_email_template(tenant_id, kind, locale.to_owned())
+        .await
+    {
+        Ok(FetchResponseKind::Found(template)) => template,
+        Ok(FetchResponseKind::NotFound(_)) => return None,
+        Err(err) => {
+            tracing::warn!("Unable to fetch tenant email template: {err}");
+            return None;
+        }
+    };
+
+    match template.render(context).await {
+        Ok(rendered) => {
+            tracing::trace!("Using tenant email template for {kind}");
+            Some(rendered)
+        }
+        Err(err) => {
+            tracing::warn!(
+                "Unable to render tenant email template, falling back to \
+                 defaults: {err}"
+            );
+            None
+        }
+    }
+}
+
+/// Render the subject and body of a notification from the file templates
+///
+/// Falls back to the `en-us` templates when the locale has no template.
+pub(crate) fn render_file_templates(
+    template_path_prefix: &str,
+    locale: String,
+    context: &Context,
+) -> Result<(String, String), MappedErrors> {
     //
     // Verify if the selected locale exists in templates folder
     // If not, use the default en-us one
     //
     let verified_locale = {
-        let template_path_prefix_str = template_path_prefix.to_string();
         let body_path = format!(
             "{locale}/{prefix}.jinja",
             locale = locale,
-            prefix = template_path_prefix_str
+            prefix = template_path_prefix
         );
 
         let template_names: Vec<_> = TEMPLATES.get_template_names().collect();
@@ -79,16 +436,9 @@ pub(crate) async fn dispatch_notification<T: ToString>(
         }
     };
 
-    let body_path = format!(
-        "{locale}/{path}",
-        locale = verified_locale,
-        path = format!(
-            "{prefix}.jinja",
-            prefix = template_path_prefix.to_string()
-        )
-    );
+    let body_path = format!("{verified_locale}/{template_path_prefix}.jinja");
 
-    let body = match TEMPLATES.render(body_path.as_str(), &context) {
+    let body = match TEMPLATES.render(body_path.as_str(), context) {
         Ok(res) => res,
         Err(err) => {
             return use_case_err(format!(
@@ -98,16 +448,10 @@ pub(crate) async fn dispatch_notification<T: ToString>(
         }
     };
 
-    let subject_path = format!(
-        "{locale}/{path}",
-        locale = verified_locale,
-        path = format!(
-            "{prefix}.subject",
-            prefix = template_path_prefix.to_string()
-        )
-    );
+    let subject_path =
+        format!("{verified_locale}/{template_path_prefix}.subject");
 
-    let subject_ = match TEMPLATES.render(subject_path.as_str(), &context) {
+    let subject = match TEMPLATES.render(subject_path.as_str(), context) {
         Ok(res) => res,
         Err(err) => {
             return use_case_err(format!(
@@ -117,36 +461,19 @@ pub(crate) async fn dispatch_notification<T: ToString>(
         }
     };
 
-    let from_email =
-        Email::from_string(config.noreply_email.async_get_or_error().await?)?;
-
-    let from = if let Some(name) = config.noreply_name {
-        FromEmail::NamedEmail(format!(
-            "{} <{}>",
-            name.async_get_or_error().await?,
-            from_email.email()
-        ))
-    } else {
-        FromEmail::Email(from_email)
-    };
-
-    local_message_write_repo
-        .send(MessageSendingEvent::new(Message {
-            from,
-            to,
-            cc,
-            subject: subject_,
-            body,
-        }))
-        .await
+    Ok((subject, body))
 }
 
+/// Build the template context of a notification
+///
+/// Returns the context, the tenant preferred locale and the id of the tenant
+/// found from the `tenant_id` parameter, if any.
 #[tracing::instrument(name = "populate_tenant_info", skip_all)]
-async fn populate_tenant_info<T: ToString>(
+pub(crate) async fn populate_tenant_info<T: ToString>(
     parameters: &Vec<(T, String)>,
     config: &AccountLifeCycle,
-    tenant_fetching_repo: Box<&dyn TenantFetching>,
-) -> Result<(Context, Option<String>), MappedErrors> {
+    tenant_fetching_repo: &dyn TenantFetching,
+) -> Result<(Context, Option<String>, Option<Uuid>), MappedErrors> {
     let mut context = Context::new();
     let mut optional_locale = None;
 
@@ -164,45 +491,45 @@ async fn populate_tenant_info<T: ToString>(
                 //
                 context.insert("domain_name", tenant.name.as_str());
 
-                if let Some(meta) = &tenant.meta {
-                    //
-                    // Inject the tenant website URL
-                    //
-                    if let Some(website_url) =
-                        meta.get(&TenantMetaKey::WebsiteUrl)
-                    {
-                        context.insert("domain_url", website_url.as_str());
-                    }
-
-                    //
-                    // Inject the tenant support email
-                    //
-                    if let Some(support_email) =
-                        meta.get(&TenantMetaKey::SupportEmail)
-                    {
-                        context.insert("support_email", support_email.as_str());
-                    }
-
-                    //
-                    // Populate the tenant preferred locale
-                    //
-                    optional_locale = meta
-                        .get(&TenantMetaKey::Locale)
-                        .map(|locale| locale.to_owned());
+                let meta = tenant.effective_meta();
+
+                //
+                // Inject the tenant website URL
+                //
+                if let Some(website_url) = meta.get(&TenantMetaKey::WebsiteUrl)
+                {
+                    context.insert("domain_url", website_url.as_str());
                 }
-                true
+
+                //
+                // Inject the tenant support email
+                //
+                if let Some(support_email) =
+                    meta.get(&TenantMetaKey::SupportEmail)
+                {
+                    context.insert("support_email", support_email.as_str());
+                }
+
+                //
+                // Populate the tenant preferred locale
+                //
+                optional_locale = meta
+                    .get(&TenantMetaKey::Locale)
+                    .map(|locale| locale.to_owned());
+
+                Some(tenant_id)
             } else {
-                false
+                None
             }
         } else {
-            false
+            None
         }
     } else {
-        false
+        None
     };
 
     // If tenant was not found or not provided, use config values as fallback
-    if !tenant_found {
+    if tenant_found.is_none() {
         context.insert(
             "domain_name",
             config.domain_name.async_get_or_error().await?.as_str(),
@@ -225,7 +552,7 @@ async fn populate_tenant_info<T: ToString>(
         context.insert(key.to_string(), &value.to_string());
     }
 
-    Ok((context, optional_locale))
+    Ok((context, optional_locale, tenant_found))
 }
 
 // * ---------------------------------------------------------------------------
@@ -243,10 +570,18 @@ async fn populate_tenant_info<T: ToString>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::dtos::{
-        email::Email,
-        profile::Owner,
-        tenant::{Tenant, TenantMeta, TenantMetaKey},
+    use crate::domain::{
+        dtos::{
+            email::Email,
+            email_delivery::{EmailSuppression, EmailSuppressionReason},
+            notification::{NotificationPreferences, NotificationRecipient},
+            profile::Owner,
+            telegram::TelegramUserId,
+            tenant::{Tenant, TenantEmailTemplate, TenantMeta, TenantMetaKey},
+        },
+        entities::{
+            MockEmailSuppressionFetching, MockNotificationRecipientFetching,
+        },
     };
     use async_trait::async_trait;
     use chrono::Local;
@@ -254,6 +589,7 @@ mod tests {
     use mycelium_base::{dtos::Children, entities::FetchManyResponseKind};
     use std::collections::HashMap;
     use std::env;
+    use std::sync::Mutex;
 
     // ? -----------------------------------------------------------------------
     // ? Setup function to ensure TEMPLATES_DIR is set
@@ -296,6 +632,7 @@ mod tests {
     struct MockLocalMessageWrite {
         should_fail: bool,
         message_id: Option<Uuid>,
+        sent: Mutex<Vec<Message>>,
     }
 
     impl MockLocalMessageWrite {
@@ -303,6 +640,7 @@ mod tests {
             Self {
                 should_fail: false,
                 message_id: Some(Uuid::new_v4()),
+                sent: Mutex::new(vec![]),
             }
         }
 
@@ -310,6 +648,7 @@ mod tests {
             Self {
                 should_fail: true,
                 message_id: None,
+                sent: Mutex::new(vec![]),
             }
         }
     }
@@ -318,13 +657,15 @@ mod tests {
     impl LocalMessageWrite for MockLocalMessageWrite {
         async fn send(
             &self,
-            _message_event: MessageSendingEvent,
+            message_event: MessageSendingEvent,
         ) -> Result<CreateResponseKind<Option<Uuid>>, MappedErrors> {
             if self.should_fail {
                 return use_case_err("Failed to send message".to_string())
                     .as_error();
             }
 
+            self.sent.lock().unwrap().push(message_event.message);
+
             Ok(CreateResponseKind::Created(self.message_id))
         }
 
@@ -349,6 +690,7 @@ mod tests {
 
     struct MockTenantFetching {
         tenant: Option<Tenant>,
+        template: Option<TenantEmailTemplate>,
         should_fail: bool,
     }
 
@@ -356,6 +698,18 @@ mod tests {
         fn with_tenant(tenant: Tenant) -> Self {
             Self {
                 tenant: Some(tenant),
+                template: None,
+                should_fail: false,
+            }
+        }
+
+        fn with_template(
+            tenant: Tenant,
+            template: TenantEmailTemplate,
+        ) -> Self {
+            Self {
+                tenant: Some(tenant),
+                template: Some(template),
                 should_fail: false,
             }
         }
@@ -363,6 +717,7 @@ mod tests {
         fn not_found() -> Self {
             Self {
                 tenant: None,
+                template: None,
                 should_fail: false,
             }
         }
@@ -370,6 +725,7 @@ mod tests {
         fn with_error() -> Self {
             Self {
                 tenant: None,
+                template: None,
                 should_fail: true,
             }
         }
@@ -421,6 +777,86 @@ mod tests {
         ) -> Result<FetchManyResponseKind<Tenant>, MappedErrors> {
             unimplemented!()
         }
+
+        async fn get_email_template(
+            &self,
+            tenant_id: Uuid,
+            kind: EmailTemplateKind,
+            locale: String,
+        ) -> Result<FetchResponseKind<TenantEmailTemplate, String>, MappedErrors>
+        {
+            match &self.template {
+                Some(template)
+                    if template.tenant_id == tenant_id
+                        && template.kind == kind
+                        && template.locale == locale =>
+                {
+                    Ok(FetchResponseKind::Found(template.clone()))
+                }
+                _ => Ok(FetchResponseKind::NotFound(None)),
+            }
+        }
+
+        async fn list_email_templates(
+            &self,
+            _tenant_id: Uuid,
+        ) -> Result<FetchManyResponseKind<TenantEmailTemplate>, MappedErrors>
+        {
+            unimplemented!()
+        }
+
+        async fn list_descendants(
+            &self,
+            _: Uuid,
+        ) -> Result<
+            mycelium_base::entities::FetchManyResponseKind<
+                crate::domain::dtos::tenant::Tenant,
+            >,
+            MappedErrors,
+        > {
+            unimplemented!()
+        }
+
+        async fn list_trashed(
+            &self,
+            _: Option<chrono::DateTime<chrono::Utc>>,
+            _: chrono::DateTime<chrono::Utc>,
+        ) -> Result<FetchManyResponseKind<Tenant>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn list_owners(
+            &self,
+            _: Uuid,
+        ) -> Result<
+            mycelium_base::entities::FetchManyResponseKind<
+                crate::domain::dtos::profile::Owner,
+            >,
+            MappedErrors,
+        > {
+            unimplemented!()
+        }
+    }
+
+    struct MockEncryptionKeyFetching;
+
+    #[async_trait]
+    impl EncryptionKeyFetching for MockEncryptionKeyFetching {
+        async fn get_or_provision_dek(
+            &self,
+            _tenant_id: Option<Uuid>,
+            _kek: &[u8; 32],
+        ) -> Result<[u8; 32], MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn fetch_dek(
+            &self,
+            _tenant_id: Option<Uuid>,
+            _kek: &[u8; 32],
+        ) -> Result<Option<[u8; 32]>, MappedErrors> {
+            unimplemented!()
+        }
     }
 
     // ? -----------------------------------------------------------------------
@@ -435,6 +871,7 @@ mod tests {
             )),
             locale: Some(SecretResolver::Value("en-us".to_string())),
             token_expiration: SecretResolver::Value(3600),
+            invitation_expiration: SecretResolver::Value(604_800),
             noreply_name: Some(SecretResolver::Value(
                 "Test System".to_string(),
             )),
@@ -454,6 +891,8 @@ mod tests {
                 },
             ]),
             staff_bootstrap_secret: None,
+            sms_provider: None,
+            delivery_webhook_secret: None,
         }
     }
 
@@ -461,6 +900,33 @@ mod tests {
         Email::from_string("test@example.com".to_string()).unwrap()
     }
 
+    fn not_suppressed() -> MockEmailSuppressionFetching {
+        let mut suppression_repo = MockEmailSuppressionFetching::new();
+        suppression_repo
+            .expect_get()
+            .returning(|_| Ok(FetchResponseKind::NotFound(None)));
+
+        suppression_repo
+    }
+
+    fn suppressed(email: Email) -> MockEmailSuppressionFetching {
+        let mut suppression_repo = MockEmailSuppressionFetching::new();
+        suppression_repo.expect_get().returning(move |candidate| {
+            if candidate != email {
+                return Ok(FetchResponseKind::NotFound(None));
+            }
+
+            Ok(FetchResponseKind::Found(EmailSuppression {
+                email: candidate.email(),
+                reason: EmailSuppressionReason::HardBounce,
+                detail: None,
+                created: Local::now(),
+            }))
+        });
+
+        suppression_repo
+    }
+
     fn create_test_tenant_with_meta(meta: Option<TenantMeta>) -> Tenant {
         let owner = Owner {
             id: Uuid::new_v4(),
@@ -475,10 +941,12 @@ mod tests {
             id: Some(Uuid::new_v4()),
             name: "Test Tenant".to_string(),
             description: Some("Test tenant description".to_string()),
+            parent_id: None,
             owners: Children::Records(vec![owner]),
             manager: None,
             tags: None,
             meta,
+            inherited_meta: None,
             status: None,
             created: Local::now(),
             updated: None,
@@ -521,6 +989,7 @@ mod tests {
             email,
             None,
             Box::new(&message_repo),
+            Box::new(&not_suppressed()),
             Box::new(&tenant_repo),
         )
         .await;
@@ -548,6 +1017,7 @@ mod tests {
             email,
             None,
             Box::new(&message_repo),
+            Box::new(&not_suppressed()),
             Box::new(&tenant_repo),
         )
         .await;
@@ -579,6 +1049,7 @@ mod tests {
             email,
             None,
             Box::new(&message_repo),
+            Box::new(&not_suppressed()),
             Box::new(&tenant_repo),
         )
         .await;
@@ -615,6 +1086,7 @@ mod tests {
             email,
             None,
             Box::new(&message_repo),
+            Box::new(&not_suppressed()),
             Box::new(&tenant_repo),
         )
         .await;
@@ -651,6 +1123,7 @@ mod tests {
             email,
             None,
             Box::new(&message_repo),
+            Box::new(&not_suppressed()),
             Box::new(&tenant_repo),
         )
         .await;
@@ -684,6 +1157,7 @@ mod tests {
             email,
             None,
             Box::new(&message_repo),
+            Box::new(&not_suppressed()),
             Box::new(&tenant_repo),
         )
         .await;
@@ -711,6 +1185,7 @@ mod tests {
             email,
             None,
             Box::new(&message_repo),
+            Box::new(&not_suppressed()),
             Box::new(&tenant_repo),
         )
         .await;
@@ -736,6 +1211,7 @@ mod tests {
             email,
             Some(cc_email),
             Box::new(&message_repo),
+            Box::new(&not_suppressed()),
             Box::new(&tenant_repo),
         )
         .await;
@@ -745,6 +1221,141 @@ mod tests {
             Ok(_) => panic!("Expected Created response"),
             Err(err) => panic!("Expected success but got error: {:?}", err),
         }
+
+        let sent = message_repo.sent.lock().unwrap();
+        assert_eq!(sent[0].cc[0].email(), "cc@example.com");
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_notification_renders_text_alternative() {
+        setup_templates_dir();
+        let config = create_test_config();
+
+        let message_repo = MockLocalMessageWrite::new();
+        let tenant_repo = MockTenantFetching::not_found();
+
+        dispatch_notification(
+            vec![(
+                "magic_link_url",
+                "https://test.com/magic-link/abc123".to_string(),
+            )],
+            "email/magic-link-request",
+            config,
+            create_test_email(),
+            None,
+            Box::new(&message_repo),
+            Box::new(&not_suppressed()),
+            Box::new(&tenant_repo),
+        )
+        .await
+        .unwrap();
+
+        let sent = message_repo.sent.lock().unwrap();
+        let text = sent[0].text_body.as_deref().unwrap();
+
+        assert!(sent[0].body.contains("<html"));
+        assert!(!text.contains('<'));
+        assert!(text.contains("https://test.com/magic-link/abc123"));
+        assert_eq!(
+            sent[0].reply_to.as_ref().map(|email| email.email()),
+            Some("support@test.com".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_notification_uses_tenant_template() {
+        setup_templates_dir();
+        let tenant_id = Uuid::new_v4();
+        let mut meta = HashMap::new();
+        meta.insert(TenantMetaKey::Locale, "pt-br".to_string());
+
+        let template = TenantEmailTemplate::new(
+            tenant_id,
+            EmailTemplateKind::MagicLinkRequest,
+            "pt-br".to_string(),
+            "Entre no {{ domain_name }}".to_string(),
+            "<p style=\"color: #0a7\">{{ domain_name }}</p>\
+             <a href=\"{{ magic_link_url }}\">Entrar</a>"
+                .to_string(),
+        )
+        .await
+        .unwrap();
+
+        let message_repo = MockLocalMessageWrite::new();
+        let tenant_repo = MockTenantFetching::with_template(
+            create_test_tenant_with_meta(Some(meta)),
+            template,
+        );
+
+        dispatch_notification(
+            vec![
+                (DEFAULT_TENANT_ID_KEY, tenant_id.to_string()),
+                ("magic_link_url", "https://test.com/ml/abc".to_string()),
+            ],
+            "email/magic-link-request",
+            create_test_config(),
+            create_test_email(),
+            None,
+            Box::new(&message_repo),
+            Box::new(&not_suppressed()),
+            Box::new(&tenant_repo),
+        )
+        .await
+        .unwrap();
+
+        let sent = message_repo.sent.lock().unwrap();
+
+        assert_eq!(sent[0].subject, "Entre no Test Tenant");
+        assert!(sent[0].body.contains("color: #0a7"));
+        assert!(sent[0]
+            .text_body
+            .as_deref()
+            .unwrap()
+            .contains("https://test.com/ml/abc"));
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_notification_tenant_template_other_locale_falls_back(
+    ) {
+        setup_templates_dir();
+        let tenant_id = Uuid::new_v4();
+
+        let template = TenantEmailTemplate::new(
+            tenant_id,
+            EmailTemplateKind::MagicLinkRequest,
+            "pt-br".to_string(),
+            "Entre no {{ domain_name }}".to_string(),
+            "<a href=\"{{ magic_link_url }}\">Entrar</a>".to_string(),
+        )
+        .await
+        .unwrap();
+
+        let message_repo = MockLocalMessageWrite::new();
+        let tenant_repo = MockTenantFetching::with_template(
+            create_test_tenant_with_meta(None),
+            template,
+        );
+
+        dispatch_notification(
+            vec![
+                (DEFAULT_TENANT_ID_KEY, tenant_id.to_string()),
+                ("magic_link_url", "https://test.com/ml/abc".to_string()),
+            ],
+            "email/magic-link-request",
+            create_test_config(),
+            create_test_email(),
+            None,
+            Box::new(&message_repo),
+            Box::new(&not_suppressed()),
+            Box::new(&tenant_repo),
+        )
+        .await
+        .unwrap();
+
+        let sent = message_repo.sent.lock().unwrap();
+
+        assert_eq!(sent[0].subject, "Your Test Tenant login link");
+        assert!(sent[0].body.contains("<html"));
     }
 
     #[tokio::test]
@@ -764,6 +1375,7 @@ mod tests {
             email,
             None,
             Box::new(&message_repo),
+            Box::new(&not_suppressed()),
             Box::new(&tenant_repo),
         )
         .await;
@@ -792,6 +1404,7 @@ mod tests {
             email,
             None,
             Box::new(&message_repo),
+            Box::new(&not_suppressed()),
             Box::new(&tenant_repo),
         )
         .await;
@@ -827,6 +1440,7 @@ mod tests {
             email,
             None,
             Box::new(&message_repo),
+            Box::new(&not_suppressed()),
             Box::new(&tenant_repo),
         )
         .await;
@@ -837,4 +1451,171 @@ mod tests {
             Err(err) => panic!("Expected success but got error: {:?}", err),
         }
     }
+
+    fn create_test_recipient(
+        channels: Vec<NotificationChannel>,
+    ) -> NotificationRecipient {
+        NotificationRecipient {
+            account_id: Uuid::new_v4(),
+            preferences: NotificationPreferences {
+                channels: HashMap::from([(
+                    NotificationKind::MagicLink,
+                    channels,
+                )]),
+            },
+            phone_number: None,
+            telegram_user_id: Some(TelegramUserId(42)),
+            webhook_url: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_channel_notification_unknown_recipient_sends_email()
+    {
+        setup_templates_dir();
+
+        let message_repo = MockLocalMessageWrite::new();
+        let tenant_repo = MockTenantFetching::not_found();
+        let mut recipient_repo = MockNotificationRecipientFetching::new();
+        recipient_repo
+            .expect_get_by_email()
+            .times(1)
+            .returning(|_| Ok(FetchResponseKind::NotFound(None)));
+
+        dispatch_channel_notification(
+            vec![("magic_link_url", "https://test.com/link".to_string())],
+            "email/magic-link-request",
+            create_test_config(),
+            create_test_email(),
+            None,
+            Box::new(&message_repo),
+            Box::new(&not_suppressed()),
+            Box::new(&tenant_repo),
+            Box::new(&recipient_repo),
+            Box::new(&MockEncryptionKeyFetching),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(message_repo.sent.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_channel_notification_falls_back_to_email() {
+        setup_templates_dir();
+
+        let message_repo = MockLocalMessageWrite::new();
+        let tenant_repo = MockTenantFetching::not_found();
+        let mut recipient_repo = MockNotificationRecipientFetching::new();
+
+        //
+        // Telegram can not deliver notifications not bound to a tenant and
+        // no SMS provider is configured, so email is the last resort.
+        //
+        recipient_repo
+            .expect_get_by_email()
+            .times(1)
+            .returning(|_| {
+                Ok(FetchResponseKind::Found(create_test_recipient(vec![
+                    NotificationChannel::Telegram,
+                    NotificationChannel::Sms,
+                ])))
+            });
+
+        dispatch_channel_notification(
+            vec![("magic_link_url", "https://test.com/link".to_string())],
+            "email/magic-link-request",
+            create_test_config(),
+            create_test_email(),
+            None,
+            Box::new(&message_repo),
+            Box::new(&not_suppressed()),
+            Box::new(&tenant_repo),
+            Box::new(&recipient_repo),
+            Box::new(&MockEncryptionKeyFetching),
+        )
+        .await
+        .unwrap();
+
+        let sent = message_repo.sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].to, vec![create_test_email()]);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_channel_notification_other_kinds_skip_preferences() {
+        setup_templates_dir();
+
+        let message_repo = MockLocalMessageWrite::new();
+        let tenant_repo = MockTenantFetching::not_found();
+        let mut recipient_repo = MockNotificationRecipientFetching::new();
+        recipient_repo.expect_get_by_email().never();
+
+        dispatch_channel_notification(
+            vec![("verification_code", "123456".to_string())],
+            "email/activation-code",
+            create_test_config(),
+            create_test_email(),
+            None,
+            Box::new(&message_repo),
+            Box::new(&not_suppressed()),
+            Box::new(&tenant_repo),
+            Box::new(&recipient_repo),
+            Box::new(&MockEncryptionKeyFetching),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(message_repo.sent.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_notification_skips_suppressed_recipient() {
+        setup_templates_dir();
+
+        let message_repo = MockLocalMessageWrite::new();
+        let tenant_repo = MockTenantFetching::not_found();
+
+        let result = dispatch_notification(
+            vec![("verification_code", "123456".to_string())],
+            "email/activation-code",
+            create_test_config(),
+            create_test_email(),
+            None,
+            Box::new(&message_repo),
+            Box::new(&suppressed(create_test_email())),
+            Box::new(&tenant_repo),
+        )
+        .await
+        .unwrap();
+
+        assert!(matches!(result, CreateResponseKind::NotCreated(None, _)));
+        assert!(message_repo.sent.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_notification_drops_suppressed_cc() {
+        setup_templates_dir();
+
+        let message_repo = MockLocalMessageWrite::new();
+        let tenant_repo = MockTenantFetching::not_found();
+        let cc = Email::from_string("bounced@example.com".to_string()).unwrap();
+
+        dispatch_notification(
+            vec![("verification_code", "123456".to_string())],
+            "email/activation-code",
+            create_test_config(),
+            create_test_email(),
+            Some(cc.to_owned()),
+            Box::new(&message_repo),
+            Box::new(&suppressed(cc)),
+            Box::new(&tenant_repo),
+        )
+        .await
+        .unwrap();
+
+        let sent = message_repo.sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        assert!(sent[0].cc.is_empty());
+    }
 }
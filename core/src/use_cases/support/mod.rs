@@ -1,7 +1,39 @@
+mod check_public_webhook_url;
+mod deliver_channel_message;
 mod dispatch_notification;
 mod dispatch_webhooks;
+mod purge_trashed_tenants;
+mod register_delivery_events;
 mod register_webhook_dispatching_event;
+mod run_bulk_import;
+mod run_pending_bulk_imports;
+mod send_connection_string_expiry_warnings;
+mod send_guest_grant_expiry_warnings;
+mod send_invitation_reminders;
+mod send_tenant_membership_digests;
+mod send_tenant_purge_warnings;
+mod sweep_expired_guest_grants;
+mod sweep_expired_guest_invitations;
+mod translate_error_code;
+mod validate_bulk_import_rows;
+mod validate_delivery_webhook_secret;
 
+pub(crate) use check_public_webhook_url::*;
+pub(crate) use deliver_channel_message::*;
 pub(crate) use dispatch_notification::*;
 pub use dispatch_webhooks::*;
+pub use purge_trashed_tenants::*;
+pub use register_delivery_events::*;
 pub(crate) use register_webhook_dispatching_event::*;
+pub use run_bulk_import::*;
+pub use run_pending_bulk_imports::*;
+pub use send_connection_string_expiry_warnings::*;
+pub use send_guest_grant_expiry_warnings::*;
+pub use send_invitation_reminders::*;
+pub use send_tenant_membership_digests::*;
+pub use send_tenant_purge_warnings::*;
+pub use sweep_expired_guest_grants::*;
+pub use sweep_expired_guest_invitations::*;
+pub use translate_error_code::*;
+pub use validate_bulk_import_rows::*;
+pub use validate_delivery_webhook_secret::*;
@@ -0,0 +1,423 @@
+use crate::{
+    domain::{
+        dtos::{
+            resource_audit_log::{
+                ResourceAuditEventKind, ResourceAuditResourceType,
+            },
+            scheduled_job::ScheduledJob,
+            written_by::WrittenBy,
+        },
+        entities::{
+            ResourceAuditLogRegistration, ScheduledJobClaiming, TenantDeletion,
+            TenantFetching,
+        },
+    },
+    use_cases::shared::audit::emit_resource_audit_event,
+};
+
+use chrono::Duration;
+use mycelium_base::{
+    entities::{DeletionResponseKind, FetchManyResponseKind},
+    utils::errors::MappedErrors,
+};
+
+/// Purge the tenants trashed for longer than the retention period
+///
+/// Purging deletes the tenant with its accounts, guest links, tags, meta and
+/// data encryption key, and records the deletion in the instance audit chain,
+/// since the chain of the tenant could not be signed without its key. The
+/// trashed status is checked again by the purge itself, so a tenant restored
+/// after being listed is kept.
+/// Nothing is done when another replica claimed the run or the job is not due
+/// yet, and `None` is returned.
+///
+/// Returns the number of purged tenants.
+#[tracing::instrument(name = "purge_trashed_tenants", skip_all)]
+pub async fn purge_trashed_tenants(
+    claimed_by: String,
+    interval: Duration,
+    retention: Duration,
+    scheduled_job_claiming_repo: Box<&dyn ScheduledJobClaiming>,
+    tenant_fetching_repo: Box<&dyn TenantFetching>,
+    tenant_deletion_repo: Box<&dyn TenantDeletion>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<Option<usize>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Claim the run
+    // ? -----------------------------------------------------------------------
+
+    let run = match scheduled_job_claiming_repo
+        .claim_due_run(ScheduledJob::TrashedTenantPurge, claimed_by, interval)
+        .await?
+    {
+        Some(run) => run,
+        None => return Ok(None),
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Fetch the tenants out of the retention period
+    //
+    // Every tenant trashed before the retention window is fetched, so tenants
+    // missed by a failed run are purged by the next one.
+    //
+    // ? -----------------------------------------------------------------------
+
+    let trashed_before = run.started_at - retention;
+
+    let tenants = match tenant_fetching_repo
+        .list_trashed(None, trashed_before)
+        .await?
+    {
+        FetchManyResponseKind::Found(records) => records,
+        FetchManyResponseKind::FoundPaginated { records, .. } => records,
+        FetchManyResponseKind::NotFound => return Ok(Some(0)),
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Purge the tenants
+    // ? -----------------------------------------------------------------------
+
+    let mut purged = 0;
+
+    for tenant in tenants {
+        let Some(tenant_id) = tenant.id else {
+            continue;
+        };
+
+        match tenant_deletion_repo.purge(tenant_id, trashed_before).await {
+            Ok(DeletionResponseKind::Deleted) => (),
+            Ok(DeletionResponseKind::NotDeleted(_, msg)) => {
+                tracing::warn!(%tenant_id, "Trashed tenant not purged: {msg}");
+
+                continue;
+            }
+            Err(err) => {
+                tracing::error!(
+                    %tenant_id,
+                    "Unable to purge the trashed tenant: {err}"
+                );
+
+                continue;
+            }
+        }
+
+        emit_resource_audit_event(
+            audit_repo.to_owned(),
+            ResourceAuditResourceType::Tenant,
+            tenant_id,
+            None,
+            ResourceAuditEventKind::Deleted,
+            WrittenBy::new_anemic(),
+            serde_json::json!({
+                "action": "purge_trashed_tenants",
+                "name": tenant.name,
+                "trashedAt": tenant.trashed_at(),
+            }),
+        )
+        .await;
+
+        purged += 1;
+    }
+
+    Ok(Some(purged))
+}
+
+// ? ---------------------------------------------------------------------------
+// ? TESTS
+// ? ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::dtos::{
+        email::Email,
+        profile::Owner,
+        resource_audit_log::NewResourceAuditLogEvent,
+        scheduled_job::ScheduledJobRun,
+        tenant::{
+            EmailTemplateKind, Tenant, TenantEmailTemplate, TenantMetaKey,
+            TenantStatus,
+        },
+    };
+
+    use async_trait::async_trait;
+    use chrono::{DateTime, Local, Utc};
+    use mycelium_base::{dtos::Children, entities::FetchResponseKind};
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    struct StubClaiming;
+
+    #[async_trait]
+    impl ScheduledJobClaiming for StubClaiming {
+        async fn claim_due_run(
+            &self,
+            job: ScheduledJob,
+            claimed_by: String,
+            _: Duration,
+        ) -> Result<Option<ScheduledJobRun>, MappedErrors> {
+            Ok(Some(ScheduledJobRun {
+                job,
+                started_at: Utc::now(),
+                previous_run_at: None,
+                claimed_by,
+            }))
+        }
+    }
+
+    struct StubFetching {
+        tenants: Vec<Tenant>,
+    }
+
+    #[async_trait]
+    impl TenantFetching for StubFetching {
+        async fn get_tenant_owned_by_me(
+            &self,
+            _: Uuid,
+            _: Vec<Uuid>,
+        ) -> Result<FetchResponseKind<Tenant, String>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn get_tenant_public_by_id(
+            &self,
+            _: Uuid,
+        ) -> Result<FetchResponseKind<Tenant, String>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn get_tenants_by_manager_account(
+            &self,
+            _: Uuid,
+            _: Vec<Uuid>,
+        ) -> Result<FetchResponseKind<Tenant, String>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn filter_tenants_as_manager(
+            &self,
+            _: Option<String>,
+            _: Option<Uuid>,
+            _: Option<(TenantMetaKey, String)>,
+            _: Option<(String, String)>,
+            _: Option<i32>,
+            _: Option<i32>,
+        ) -> Result<FetchManyResponseKind<Tenant>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn get_email_template(
+            &self,
+            _: Uuid,
+            _: EmailTemplateKind,
+            _: String,
+        ) -> Result<FetchResponseKind<TenantEmailTemplate, String>, MappedErrors>
+        {
+            unimplemented!()
+        }
+
+        async fn list_email_templates(
+            &self,
+            _: Uuid,
+        ) -> Result<FetchManyResponseKind<TenantEmailTemplate>, MappedErrors>
+        {
+            unimplemented!()
+        }
+
+        async fn list_descendants(
+            &self,
+            _: Uuid,
+        ) -> Result<FetchManyResponseKind<Tenant>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn list_trashed(
+            &self,
+            trashed_after: Option<DateTime<Utc>>,
+            trashed_before: DateTime<Utc>,
+        ) -> Result<FetchManyResponseKind<Tenant>, MappedErrors> {
+            assert!(trashed_after.is_none());
+
+            Ok(FetchManyResponseKind::Found(
+                self.tenants
+                    .iter()
+                    .filter(|tenant| {
+                        tenant
+                            .trashed_at()
+                            .is_some_and(|at| at <= trashed_before)
+                    })
+                    .cloned()
+                    .collect(),
+            ))
+        }
+
+        async fn list_owners(
+            &self,
+            _: Uuid,
+        ) -> Result<FetchManyResponseKind<Owner>, MappedErrors> {
+            unimplemented!()
+        }
+    }
+
+    /// Purges the tenants of `trashed`, re-checking them as the repositories
+    /// do, so tenants restored after the listing are kept
+    struct StubDeletion {
+        trashed: Mutex<Vec<Tenant>>,
+    }
+
+    #[async_trait]
+    impl TenantDeletion for StubDeletion {
+        async fn delete(
+            &self,
+            _: Uuid,
+        ) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn purge(
+            &self,
+            id: Uuid,
+            trashed_before: DateTime<Utc>,
+        ) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
+            let mut trashed = self.trashed.lock().unwrap();
+
+            let Some(index) = trashed.iter().position(|tenant| {
+                tenant.id == Some(id)
+                    && tenant
+                        .trashed_at()
+                        .is_some_and(|at| at <= trashed_before)
+            }) else {
+                return Ok(DeletionResponseKind::NotDeleted(
+                    id,
+                    "Tenant is no longer trashed".to_string(),
+                ));
+            };
+
+            trashed.remove(index);
+
+            Ok(DeletionResponseKind::Deleted)
+        }
+
+        async fn delete_owner(
+            &self,
+            _: Uuid,
+            _: Option<Uuid>,
+            _: Option<Email>,
+        ) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn delete_tenant_meta(
+            &self,
+            _: Uuid,
+            _: TenantMetaKey,
+        ) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn delete_email_template(
+            &self,
+            _: Uuid,
+            _: EmailTemplateKind,
+            _: String,
+        ) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
+            unimplemented!()
+        }
+    }
+
+    #[derive(Default)]
+    struct StubAudit {
+        events: Mutex<Vec<NewResourceAuditLogEvent>>,
+    }
+
+    #[async_trait]
+    impl ResourceAuditLogRegistration for StubAudit {
+        async fn create(
+            &self,
+            event: NewResourceAuditLogEvent,
+        ) -> Result<(), MappedErrors> {
+            self.events.lock().unwrap().push(event);
+            Ok(())
+        }
+    }
+
+    fn trashed_tenant(id: Uuid, trashed_days_ago: i64) -> Tenant {
+        let mut tenant = Tenant::new_with_owners(
+            "Trashed".to_string(),
+            None,
+            Children::Records(vec![]),
+        );
+
+        tenant.id = Some(id);
+        tenant.status = Some(vec![TenantStatus::Trashed {
+            at: Local::now() - Duration::days(trashed_days_ago),
+            by: "owner".to_string(),
+        }]);
+
+        tenant
+    }
+
+    #[tokio::test]
+    async fn tenants_out_of_the_retention_period_are_purged_and_audited() {
+        let purged_tenant = Uuid::new_v4();
+        let restored_tenant = Uuid::new_v4();
+
+        let fetching = StubFetching {
+            tenants: vec![
+                trashed_tenant(purged_tenant, 31),
+                trashed_tenant(restored_tenant, 40),
+                trashed_tenant(Uuid::new_v4(), 10),
+            ],
+        };
+
+        //
+        // The owner restores a listed tenant before the purge reaches it
+        //
+        let mut restored = trashed_tenant(restored_tenant, 40);
+        restored
+            .status
+            .as_mut()
+            .unwrap()
+            .push(TenantStatus::Restored {
+                at: Local::now(),
+                by: "owner".to_string(),
+            });
+
+        let deletion = StubDeletion {
+            trashed: Mutex::new(
+                fetching
+                    .tenants
+                    .iter()
+                    .filter(|tenant| tenant.id != Some(restored_tenant))
+                    .cloned()
+                    .chain([restored])
+                    .collect(),
+            ),
+        };
+        let audit = StubAudit::default();
+
+        let purged = purge_trashed_tenants(
+            "pod-a".to_string(),
+            Duration::hours(1),
+            Duration::days(30),
+            Box::new(&StubClaiming),
+            Box::new(&fetching),
+            Box::new(&deletion),
+            Box::new(&audit),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(purged, Some(1));
+
+        let events = audit.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].resource_id, purged_tenant);
+        assert_eq!(events[0].tenant_id, None);
+        assert_eq!(events[0].event, ResourceAuditEventKind::Deleted);
+        assert_eq!(events[0].metadata["action"], "purge_trashed_tenants");
+
+        let kept = deletion.trashed.lock().unwrap();
+        assert!(kept.iter().any(|t| t.id == Some(restored_tenant)));
+    }
+}
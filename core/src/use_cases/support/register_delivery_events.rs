@@ -0,0 +1,202 @@
+use crate::domain::{
+    dtos::{
+        email::Email,
+        email_delivery::{
+            DeliveryEvent, DeliveryEventKind, EmailSuppression,
+            MessageDeliveryStatus,
+        },
+    },
+    entities::{EmailSuppressionRegistration, MessageDeliveryRegistration},
+};
+
+use chrono::Local;
+use mycelium_base::utils::errors::{invalid_arg_err, MappedErrors};
+
+/// Apply delivery events reported by an email provider
+///
+/// The status of the message referenced by each event is updated, and
+/// addresses which hard-bounced or complained are added to the suppression
+/// list so no further email is sent to them. The whole batch is rejected when
+/// an event carries an invalid address.
+///
+/// Returns the number of messages whose status was updated.
+#[tracing::instrument(
+    name = "register_delivery_events",
+    fields(events = events.len()),
+    skip_all
+)]
+pub async fn register_delivery_events(
+    events: Vec<DeliveryEvent>,
+    message_delivery_registration_repo: Box<&dyn MessageDeliveryRegistration>,
+    email_suppression_registration_repo: Box<&dyn EmailSuppressionRegistration>,
+) -> Result<usize, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Validate the reported addresses
+    // ? -----------------------------------------------------------------------
+
+    let mut parsed = vec![];
+
+    for event in events {
+        let email = match Email::from_string(event.email.to_owned()) {
+            Ok(email) => email,
+            Err(_) => {
+                return invalid_arg_err(format!(
+                    "Invalid email address in delivery event: {}",
+                    event.email
+                ))
+                .with_exp_true()
+                .as_error();
+            }
+        };
+
+        parsed.push((email, event));
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Update the messages and the suppression list
+    // ? -----------------------------------------------------------------------
+
+    let mut updated = 0;
+
+    for (email, event) in parsed {
+        if let Some(message_id) = event.message_id {
+            let status = match event.event {
+                DeliveryEventKind::Delivered => {
+                    MessageDeliveryStatus::Delivered
+                }
+                DeliveryEventKind::Bounced => MessageDeliveryStatus::Bounced,
+                DeliveryEventKind::Complained => {
+                    MessageDeliveryStatus::Complained
+                }
+            };
+
+            if message_delivery_registration_repo
+                .update_status(message_id, status, event.detail.to_owned())
+                .await?
+            {
+                updated += 1;
+            } else {
+                tracing::warn!(
+                    "Delivery event for unknown message: {message_id}"
+                );
+            }
+        }
+
+        if let Some(reason) = event.suppression_reason() {
+            tracing::info!("Suppressing {} ({reason})", email.email());
+
+            email_suppression_registration_repo
+                .register(EmailSuppression {
+                    email: email.email(),
+                    reason,
+                    detail: event.detail,
+                    created: Local::now(),
+                })
+                .await?;
+        }
+    }
+
+    Ok(updated)
+}
+
+// ? --------------------------------------------------------------------------
+// ? TESTS
+// ? --------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        dtos::email_delivery::EmailSuppressionReason,
+        entities::{
+            MockEmailSuppressionRegistration, MockMessageDeliveryRegistration,
+        },
+    };
+    use uuid::Uuid;
+
+    fn event(kind: DeliveryEventKind, permanent: bool) -> DeliveryEvent {
+        DeliveryEvent {
+            event: kind,
+            email: "User@Example.com".to_string(),
+            message_id: Some(Uuid::new_v4()),
+            permanent,
+            detail: Some("550 5.1.1 user unknown".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn hard_bounces_update_the_message_and_suppress_the_address() {
+        let mut delivery_repo = MockMessageDeliveryRegistration::new();
+        delivery_repo
+            .expect_update_status()
+            .withf(|_, status, _| *status == MessageDeliveryStatus::Bounced)
+            .times(1)
+            .returning(|_, _, _| Ok(true));
+
+        let mut suppression_repo = MockEmailSuppressionRegistration::new();
+        suppression_repo
+            .expect_register()
+            .withf(|suppression| {
+                suppression.email == "user@example.com"
+                    && suppression.reason == EmailSuppressionReason::HardBounce
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let updated = register_delivery_events(
+            vec![event(DeliveryEventKind::Bounced, true)],
+            Box::new(&delivery_repo),
+            Box::new(&suppression_repo),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(updated, 1);
+    }
+
+    #[tokio::test]
+    async fn soft_bounces_and_deliveries_do_not_suppress() {
+        let mut delivery_repo = MockMessageDeliveryRegistration::new();
+        delivery_repo
+            .expect_update_status()
+            .times(2)
+            .returning(|_, _, _| Ok(true));
+
+        let mut suppression_repo = MockEmailSuppressionRegistration::new();
+        suppression_repo.expect_register().never();
+
+        let updated = register_delivery_events(
+            vec![
+                event(DeliveryEventKind::Bounced, false),
+                event(DeliveryEventKind::Delivered, false),
+            ],
+            Box::new(&delivery_repo),
+            Box::new(&suppression_repo),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(updated, 2);
+    }
+
+    #[tokio::test]
+    async fn invalid_addresses_reject_the_batch() {
+        let mut delivery_repo = MockMessageDeliveryRegistration::new();
+        delivery_repo.expect_update_status().never();
+
+        let mut suppression_repo = MockEmailSuppressionRegistration::new();
+        suppression_repo.expect_register().never();
+
+        let mut invalid = event(DeliveryEventKind::Complained, false);
+        invalid.email = "not-an-email".to_string();
+
+        let result = register_delivery_events(
+            vec![event(DeliveryEventKind::Complained, false), invalid],
+            Box::new(&delivery_repo),
+            Box::new(&suppression_repo),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+}
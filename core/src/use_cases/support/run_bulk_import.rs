@@ -0,0 +1,525 @@
+use crate::{
+    domain::{
+        dtos::{
+            account::Account,
+            account_type::AccountType,
+            bulk_import::{
+                BulkImport, BulkImportRowResult, BulkImportRowStatus,
+                BulkImportStatus,
+            },
+            email::Email,
+            guest_user::{GuestInvitationEvent, GuestUser},
+            native_error_codes::NativeErrorCodes,
+            related_accounts::RelatedAccounts,
+            resource_audit_log::{
+                ResourceAuditEventKind, ResourceAuditResourceType,
+            },
+            webhook::{PayloadId, WebHookTrigger},
+        },
+        entities::{
+            AccountFetching, AccountRegistration, AccountTagRegistration,
+            BulkImportUpdating, EmailSuppressionFetching,
+            EncryptionKeyFetching, GuestRoleFetching, GuestUserFetching,
+            GuestUserOnAccountFetching, GuestUserOnAccountUpdating,
+            GuestUserRegistration, LocalMessageWrite,
+            NotificationRecipientFetching, ResourceAuditLogRegistration,
+            TenantFetching, WebHookRegistration,
+        },
+    },
+    models::AccountLifeCycle,
+    settings::DEFAULT_TENANT_ID_KEY,
+    use_cases::shared::audit::emit_resource_audit_event,
+};
+
+use super::{
+    dispatch_channel_notification, register_webhook_dispatching_event,
+    resolve_bulk_import_rows, ResolvedBulkImportRow,
+};
+
+use chrono::{Duration, Local};
+use mycelium_base::{
+    dtos::Parent,
+    entities::{
+        CreateResponseKind, FetchManyResponseKind, GetOrCreateResponseKind,
+        UpdatingResponseKind,
+    },
+    utils::errors::{use_case_err, MappedErrors},
+};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// The page size of the guests looked up on an account
+const GUEST_PAGE_SIZE: i32 = 100;
+
+/// Apply the rows of a claimed bulk import job
+///
+/// Rows are validated again, since roles may have changed since the job was
+/// submitted, and applied one by one: the subscription account is found by
+/// name or created, the tags are added to it, the guest is invited and the
+/// flags are set. Every step is idempotent, so a job claimed again after its
+/// dispatcher stopped does not duplicate what was already applied.
+///
+/// A row failing does not stop the job. The outcome of every row is stored
+/// with the job and a single summary is written to the resource audit log.
+#[tracing::instrument(
+    name = "run_bulk_import",
+    fields(bulk_import_id = %bulk_import.id),
+    skip_all
+)]
+pub async fn run_bulk_import(
+    bulk_import: BulkImport,
+    life_cycle_settings: AccountLifeCycle,
+    bulk_import_updating_repo: Box<&dyn BulkImportUpdating>,
+    guest_role_fetching_repo: Box<&dyn GuestRoleFetching>,
+    account_fetching_repo: Box<&dyn AccountFetching>,
+    account_registration_repo: Box<&dyn AccountRegistration>,
+    account_tag_registration_repo: Box<&dyn AccountTagRegistration>,
+    guest_user_registration_repo: Box<&dyn GuestUserRegistration>,
+    guest_user_fetching_repo: Box<&dyn GuestUserFetching>,
+    guest_user_on_account_fetching_repo: Box<&dyn GuestUserOnAccountFetching>,
+    guest_user_on_account_updating_repo: Box<&dyn GuestUserOnAccountUpdating>,
+    message_sending_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
+    tenant_fetching_repo: Box<&dyn TenantFetching>,
+    notification_recipient_fetching_repo: Box<
+        &dyn NotificationRecipientFetching,
+    >,
+    encryption_key_fetching_repo: Box<&dyn EncryptionKeyFetching>,
+    webhook_registration_repo: Box<&dyn WebHookRegistration>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<BulkImport, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Validate the rows
+    // ? -----------------------------------------------------------------------
+
+    let resolved_rows = match resolve_bulk_import_rows(
+        bulk_import.tenant_id,
+        &bulk_import.rows,
+        guest_role_fetching_repo,
+    )
+    .await
+    {
+        Ok(rows) => rows,
+        Err(err) => {
+            tracing::error!("Unable to validate the bulk import rows: {err}");
+
+            return finish(
+                bulk_import,
+                BulkImportStatus::Failed,
+                vec![],
+                *bulk_import_updating_repo,
+                audit_repo,
+            )
+            .await;
+        }
+    };
+
+    let invitation_expires_in =
+        life_cycle_settings.invitation_expires_in().await?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Apply the rows
+    // ? -----------------------------------------------------------------------
+
+    let run = BulkImportRun {
+        bulk_import: &bulk_import,
+        invitation_expires_in,
+        life_cycle_settings: life_cycle_settings.to_owned(),
+        account_fetching_repo: *account_fetching_repo,
+        account_registration_repo: *account_registration_repo,
+        account_tag_registration_repo: *account_tag_registration_repo,
+        guest_user_registration_repo: *guest_user_registration_repo,
+        guest_user_fetching_repo: *guest_user_fetching_repo,
+        guest_user_on_account_fetching_repo:
+            *guest_user_on_account_fetching_repo,
+        guest_user_on_account_updating_repo:
+            *guest_user_on_account_updating_repo,
+        message_sending_repo: *message_sending_repo,
+        email_suppression_fetching_repo: *email_suppression_fetching_repo,
+        tenant_fetching_repo: *tenant_fetching_repo,
+        notification_recipient_fetching_repo:
+            *notification_recipient_fetching_repo,
+        encryption_key_fetching_repo: *encryption_key_fetching_repo,
+        webhook_registration_repo: *webhook_registration_repo,
+    };
+
+    let mut accounts: HashMap<String, Uuid> = HashMap::new();
+    let mut results = vec![];
+
+    for resolved in resolved_rows {
+        if resolved.result.status == BulkImportRowStatus::Invalid {
+            results.push(resolved.result);
+            continue;
+        }
+
+        let result = resolved.result.to_owned();
+
+        results.push(match run.apply_row(resolved, &mut accounts).await {
+            Ok(result) => result,
+            Err(err) => {
+                result.with_status(BulkImportRowStatus::Failed, Some(err.msg()))
+            }
+        });
+    }
+
+    finish(
+        bulk_import,
+        BulkImportStatus::Done,
+        results,
+        *bulk_import_updating_repo,
+        audit_repo,
+    )
+    .await
+}
+
+/// Store the outcome of the job and write its summary to the audit log
+async fn finish(
+    bulk_import: BulkImport,
+    status: BulkImportStatus,
+    results: Vec<BulkImportRowResult>,
+    bulk_import_updating_repo: &dyn BulkImportUpdating,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<BulkImport, MappedErrors> {
+    let bulk_import = match bulk_import_updating_repo
+        .finish(bulk_import.id, status, results.to_owned())
+        .await?
+    {
+        UpdatingResponseKind::Updated(bulk_import) => bulk_import,
+        UpdatingResponseKind::NotUpdated(_, msg) => {
+            tracing::warn!("Bulk import outcome not stored: {msg}");
+
+            BulkImport {
+                status,
+                results,
+                finished_at: Some(Local::now()),
+                ..bulk_import
+            }
+        }
+    };
+
+    emit_resource_audit_event(
+        audit_repo,
+        ResourceAuditResourceType::Tenant,
+        bulk_import.tenant_id,
+        Some(bulk_import.tenant_id),
+        ResourceAuditEventKind::Updated,
+        bulk_import.created_by.to_owned(),
+        serde_json::json!({
+            "action": "bulk_import",
+            "bulkImportId": bulk_import.id,
+            "checksum": bulk_import.checksum,
+            "status": bulk_import.status,
+            "summary": bulk_import.summary(),
+        }),
+    )
+    .await;
+
+    Ok(bulk_import)
+}
+
+struct BulkImportRun<'a> {
+    bulk_import: &'a BulkImport,
+    invitation_expires_in: Duration,
+    life_cycle_settings: AccountLifeCycle,
+    account_fetching_repo: &'a dyn AccountFetching,
+    account_registration_repo: &'a dyn AccountRegistration,
+    account_tag_registration_repo: &'a dyn AccountTagRegistration,
+    guest_user_registration_repo: &'a dyn GuestUserRegistration,
+    guest_user_fetching_repo: &'a dyn GuestUserFetching,
+    guest_user_on_account_fetching_repo: &'a dyn GuestUserOnAccountFetching,
+    guest_user_on_account_updating_repo: &'a dyn GuestUserOnAccountUpdating,
+    message_sending_repo: &'a dyn LocalMessageWrite,
+    email_suppression_fetching_repo: &'a dyn EmailSuppressionFetching,
+    tenant_fetching_repo: &'a dyn TenantFetching,
+    notification_recipient_fetching_repo: &'a dyn NotificationRecipientFetching,
+    encryption_key_fetching_repo: &'a dyn EncryptionKeyFetching,
+    webhook_registration_repo: &'a dyn WebHookRegistration,
+}
+
+impl BulkImportRun<'_> {
+    async fn apply_row(
+        &self,
+        resolved: ResolvedBulkImportRow,
+        accounts: &mut HashMap<String, Uuid>,
+    ) -> Result<BulkImportRowResult, MappedErrors> {
+        let ResolvedBulkImportRow {
+            row,
+            mut result,
+            email,
+            role,
+        } = resolved;
+
+        let (email, role) = match (email, role) {
+            (Some(email), Some(role)) => (email, role),
+            _ => {
+                return use_case_err("Row resolved without email or role")
+                    .as_error()
+            }
+        };
+
+        let role_id = match role.id {
+            Some(id) => id,
+            None => return use_case_err("Guest role id not found").as_error(),
+        };
+
+        let tenant_id = self.bulk_import.tenant_id;
+
+        // ? -------------------------------------------------------------------
+        // ? Find or create the subscription account
+        // ? -------------------------------------------------------------------
+
+        let account_id = match accounts.get(&row.account_name) {
+            Some(account_id) => *account_id,
+            None => {
+                let (account_id, created) =
+                    self.get_or_create_account(&row.account_name).await?;
+
+                accounts.insert(row.account_name.to_owned(), account_id);
+                result.account_created = created;
+
+                account_id
+            }
+        };
+
+        result.account_id = Some(account_id);
+
+        for tag in row.tags.iter() {
+            self.account_tag_registration_repo
+                .get_or_create(account_id, tag.to_owned(), HashMap::new())
+                .await?;
+        }
+
+        // ? -------------------------------------------------------------------
+        // ? Invite the guest
+        // ? -------------------------------------------------------------------
+
+        //
+        // Guests already on the account are reported by the registration as
+        // an error, and are looked up instead of invited again.
+        //
+        let (guest_user, invited) = match self
+            .guest_user_registration_repo
+            .get_or_create(
+                GuestUser::new_unverified(
+                    email.to_owned(),
+                    Parent::Id(role_id),
+                    None,
+                )
+                .with_invitation(
+                    self.bulk_import.created_by.to_owned(),
+                    self.invitation_expires_in,
+                ),
+                account_id,
+            )
+            .await
+        {
+            Ok(GetOrCreateResponseKind::Created(guest_user)) => {
+                (guest_user, true)
+            }
+            Ok(GetOrCreateResponseKind::NotCreated(guest_user, _)) => {
+                (guest_user, false)
+            }
+            Err(err)
+                if err.has_str_code(NativeErrorCodes::MYC00017.as_str()) =>
+            {
+                (self.find_guest(&email, role_id, account_id).await?, false)
+            }
+            Err(err) => return Err(err),
+        };
+
+        let guest_user_id = match guest_user.id {
+            Some(id) => id,
+            None => return use_case_err("Guest user id not found").as_error(),
+        };
+
+        result.guest_user_id = Some(guest_user_id);
+        result.invited = invited;
+
+        if invited {
+            dispatch_channel_notification(
+                vec![
+                    ("account_name", row.account_name.to_uppercase()),
+                    ("role_name", role.name.to_uppercase()),
+                    ("role_permissions", role.permission.to_string()),
+                    (DEFAULT_TENANT_ID_KEY, tenant_id.to_string()),
+                ],
+                "email/guest-to-subscription-account",
+                self.life_cycle_settings.to_owned(),
+                email,
+                None,
+                Box::new(self.message_sending_repo),
+                Box::new(self.email_suppression_fetching_repo),
+                Box::new(self.tenant_fetching_repo),
+                Box::new(self.notification_recipient_fetching_repo),
+                Box::new(self.encryption_key_fetching_repo),
+            )
+            .await?;
+
+            register_webhook_dispatching_event(
+                Uuid::new_v4(),
+                WebHookTrigger::GuestInvitationCreated,
+                GuestInvitationEvent::from_guest_user(
+                    &guest_user,
+                    account_id,
+                    Some(tenant_id),
+                )?,
+                PayloadId::Uuid(guest_user_id),
+                Box::new(self.webhook_registration_repo),
+            )
+            .await?;
+        }
+
+        // ? -------------------------------------------------------------------
+        // ? Set the flags
+        // ? -------------------------------------------------------------------
+
+        if !row.permit_flags.is_empty() || !row.deny_flags.is_empty() {
+            let guest_user_on_account = match self
+                .guest_user_on_account_fetching_repo
+                .list_by_guest_role_id(role_id, account_id)
+                .await?
+            {
+                FetchManyResponseKind::Found(records) => records,
+                FetchManyResponseKind::FoundPaginated { records, .. } => {
+                    records
+                }
+                FetchManyResponseKind::NotFound => vec![],
+            }
+            .into_iter()
+            .find(|record| record.guest_user_id == guest_user_id);
+
+            let mut guest_user_on_account = match guest_user_on_account {
+                Some(record) => record,
+                None => {
+                    return use_case_err(
+                        "Guest user not found on the account to set flags",
+                    )
+                    .as_error()
+                }
+            };
+
+            guest_user_on_account.permit_flags = row.permit_flags;
+            guest_user_on_account.deny_flags = row.deny_flags;
+
+            self.guest_user_on_account_updating_repo
+                .update(guest_user_on_account)
+                .await?;
+        }
+
+        Ok(result.with_status(BulkImportRowStatus::Done, None))
+    }
+
+    /// Find a guest of a role on an account by email
+    async fn find_guest(
+        &self,
+        email: &Email,
+        role_id: Uuid,
+        account_id: Uuid,
+    ) -> Result<GuestUser, MappedErrors> {
+        let mut skip = 0;
+
+        loop {
+            let guests = match self
+                .guest_user_fetching_repo
+                .list(account_id, Some(GUEST_PAGE_SIZE), Some(skip))
+                .await?
+            {
+                FetchManyResponseKind::Found(records) => records,
+                FetchManyResponseKind::FoundPaginated { records, .. } => {
+                    records
+                }
+                FetchManyResponseKind::NotFound => vec![],
+            };
+
+            if guests.is_empty() {
+                return use_case_err("Guest user not found on the account")
+                    .as_error();
+            }
+
+            if let Some(guest) = guests.iter().find(|guest| {
+                guest.email.email() == email.email()
+                    && guest.guest_role_id().ok() == Some(role_id)
+            }) {
+                return Ok(guest.to_owned());
+            }
+
+            skip += GUEST_PAGE_SIZE;
+        }
+    }
+
+    /// Find the subscription account of the tenant by name, or create it
+    ///
+    /// Returns the account id and whether the account was created.
+    async fn get_or_create_account(
+        &self,
+        account_name: &str,
+    ) -> Result<(Uuid, bool), MappedErrors> {
+        let tenant_id = self.bulk_import.tenant_id;
+
+        let existing = match self
+            .account_fetching_repo
+            .list(
+                RelatedAccounts::HasTenantWidePrivileges(tenant_id),
+                Some(account_name.to_string()),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                AccountType::Subscription { tenant_id },
+                Some(100),
+                None,
+            )
+            .await?
+        {
+            FetchManyResponseKind::Found(records) => records,
+            FetchManyResponseKind::FoundPaginated { records, .. } => records,
+            FetchManyResponseKind::NotFound => vec![],
+        }
+        .into_iter()
+        .find(|account| account.name == account_name);
+
+        if let Some(account_id) = existing.and_then(|account| account.id) {
+            return Ok((account_id, false));
+        }
+
+        let mut unchecked_account = Account::new_subscription_account(
+            account_name.to_string(),
+            tenant_id,
+            Some(self.bulk_import.created_by.to_owned()),
+        );
+
+        unchecked_account.is_checked = true;
+
+        let account = match self
+            .account_registration_repo
+            .create_subscription_account(unchecked_account, tenant_id)
+            .await?
+        {
+            CreateResponseKind::Created(account) => account,
+            CreateResponseKind::NotCreated(account, msg) => {
+                return use_case_err(format!("({}): {}", account.name, msg))
+                    .as_error()
+            }
+        };
+
+        let account_id = match account.id {
+            Some(id) => id,
+            None => return use_case_err("Account id not found").as_error(),
+        };
+
+        register_webhook_dispatching_event(
+            Uuid::new_v4(),
+            WebHookTrigger::SubscriptionAccountCreated,
+            account,
+            PayloadId::Uuid(account_id),
+            Box::new(self.webhook_registration_repo),
+        )
+        .await?;
+
+        Ok((account_id, true))
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{
+    domain::{
+        dtos::bulk_import::BULK_IMPORT_STALE_AFTER_IN_SECS,
+        entities::{
+            AccountFetching, AccountRegistration, AccountTagRegistration,
+            BulkImportUpdating, EmailSuppressionFetching,
+            EncryptionKeyFetching, GuestRoleFetching, GuestUserFetching,
+            GuestUserOnAccountFetching, GuestUserOnAccountUpdating,
+            GuestUserRegistration, LocalMessageWrite,
+            NotificationRecipientFetching, ResourceAuditLogRegistration,
+            TenantFetching, WebHookRegistration,
+        },
+    },
+    models::AccountLifeCycle,
+};
+
+use super::run_bulk_import;
+
+use chrono::Duration;
+use mycelium_base::utils::errors::MappedErrors;
+
+/// Run the pending bulk import jobs
+///
+/// Jobs are claimed one at a time until none is left, so several API
+/// replicas share the queue. Jobs whose dispatcher stopped are claimed again
+/// after `BULK_IMPORT_STALE_AFTER_IN_SECS`.
+///
+/// Returns the number of jobs run.
+#[tracing::instrument(name = "run_pending_bulk_imports", skip_all)]
+pub async fn run_pending_bulk_imports(
+    claimed_by: String,
+    life_cycle_settings: AccountLifeCycle,
+    bulk_import_updating_repo: Box<&dyn BulkImportUpdating>,
+    guest_role_fetching_repo: Box<&dyn GuestRoleFetching>,
+    account_fetching_repo: Box<&dyn AccountFetching>,
+    account_registration_repo: Box<&dyn AccountRegistration>,
+    account_tag_registration_repo: Box<&dyn AccountTagRegistration>,
+    guest_user_registration_repo: Box<&dyn GuestUserRegistration>,
+    guest_user_fetching_repo: Box<&dyn GuestUserFetching>,
+    guest_user_on_account_fetching_repo: Box<&dyn GuestUserOnAccountFetching>,
+    guest_user_on_account_updating_repo: Box<&dyn GuestUserOnAccountUpdating>,
+    message_sending_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
+    tenant_fetching_repo: Box<&dyn TenantFetching>,
+    notification_recipient_fetching_repo: Box<
+        &dyn NotificationRecipientFetching,
+    >,
+    encryption_key_fetching_repo: Box<&dyn EncryptionKeyFetching>,
+    webhook_registration_repo: Box<&dyn WebHookRegistration>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<usize, MappedErrors> {
+    let mut ran = 0;
+
+    while let Some(bulk_import) = bulk_import_updating_repo
+        .claim_next(
+            claimed_by.to_owned(),
+            Duration::seconds(BULK_IMPORT_STALE_AFTER_IN_SECS),
+        )
+        .await?
+    {
+        let bulk_import_id = bulk_import.id;
+
+        if let Err(err) = run_bulk_import(
+            bulk_import,
+            life_cycle_settings.to_owned(),
+            bulk_import_updating_repo.to_owned(),
+            guest_role_fetching_repo.to_owned(),
+            account_fetching_repo.to_owned(),
+            account_registration_repo.to_owned(),
+            account_tag_registration_repo.to_owned(),
+            guest_user_registration_repo.to_owned(),
+            guest_user_fetching_repo.to_owned(),
+            guest_user_on_account_fetching_repo.to_owned(),
+            guest_user_on_account_updating_repo.to_owned(),
+            message_sending_repo.to_owned(),
+            email_suppression_fetching_repo.to_owned(),
+            tenant_fetching_repo.to_owned(),
+            notification_recipient_fetching_repo.to_owned(),
+            encryption_key_fetching_repo.to_owned(),
+            webhook_registration_repo.to_owned(),
+            audit_repo.to_owned(),
+        )
+        .await
+        {
+            tracing::error!(
+                bulk_import_id = %bulk_import_id,
+                "Unable to run the bulk import: {err}"
+            );
+        }
+
+        ran += 1;
+    }
+
+    Ok(ran)
+}
@@ -0,0 +1,125 @@
+use crate::{
+    domain::{
+        dtos::{scheduled_job::ScheduledJob, token::ConnectionStringBean},
+        entities::{
+            EmailSuppressionFetching, LocalMessageWrite, ScheduledJobClaiming,
+            TenantFetching, TokenFetching,
+        },
+    },
+    models::AccountLifeCycle,
+    settings::DEFAULT_TENANT_ID_KEY,
+};
+
+use super::dispatch_notification;
+
+use chrono::{Duration, Utc};
+use mycelium_base::{
+    entities::FetchManyResponseKind, utils::errors::MappedErrors,
+};
+
+/// Warn the owners of connection strings about to expire
+///
+/// Connection strings are warned once, by the run covering the moment they
+/// get `warn_before` away from their expiration. Nothing is done when another
+/// replica claimed the run or the job is not due yet, and `None` is returned.
+///
+/// Returns the number of warned connection strings.
+#[tracing::instrument(
+    name = "send_connection_string_expiry_warnings",
+    skip_all
+)]
+pub async fn send_connection_string_expiry_warnings(
+    claimed_by: String,
+    interval: Duration,
+    warn_before: Duration,
+    config: AccountLifeCycle,
+    scheduled_job_claiming_repo: Box<&dyn ScheduledJobClaiming>,
+    token_fetching_repo: Box<&dyn TokenFetching>,
+    local_message_write_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
+    tenant_fetching_repo: Box<&dyn TenantFetching>,
+) -> Result<Option<usize>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Claim the run
+    // ? -----------------------------------------------------------------------
+
+    let run = match scheduled_job_claiming_repo
+        .claim_due_run(
+            ScheduledJob::ConnectionStringExpiryWarning,
+            claimed_by,
+            interval,
+        )
+        .await?
+    {
+        Some(run) => run,
+        None => return Ok(None),
+    };
+
+    let (start, end) = run.period(interval);
+
+    // ? -----------------------------------------------------------------------
+    // ? Fetch the connection strings entering the warning window
+    // ? -----------------------------------------------------------------------
+
+    let connection_strings = match token_fetching_repo
+        .list_connection_strings_expiring_between(
+            start + warn_before,
+            end + warn_before,
+        )
+        .await?
+    {
+        FetchManyResponseKind::Found(records) => records,
+        FetchManyResponseKind::FoundPaginated { records, .. } => records,
+        FetchManyResponseKind::NotFound => return Ok(Some(0)),
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Warn the owners
+    // ? -----------------------------------------------------------------------
+
+    let mut warned = 0;
+
+    for connection_string in connection_strings {
+        let mut parameters = vec![
+            ("connection_string_name", connection_string.name.to_owned()),
+            (
+                "expires_at",
+                connection_string
+                    .expiration
+                    .with_timezone(&Utc)
+                    .format("%Y-%m-%d %H:%M UTC")
+                    .to_string(),
+            ),
+        ];
+
+        if let Some(tenant_id) =
+            connection_string.scope.iter().find_map(|bean| match bean {
+                ConnectionStringBean::TID(tenant_id) => Some(tenant_id),
+                _ => None,
+            })
+        {
+            parameters.push((DEFAULT_TENANT_ID_KEY, tenant_id.to_string()));
+        }
+
+        match dispatch_notification(
+            parameters,
+            "email/connection-string-expiring",
+            config.to_owned(),
+            connection_string.email.to_owned(),
+            None,
+            local_message_write_repo.to_owned(),
+            email_suppression_fetching_repo.to_owned(),
+            tenant_fetching_repo.to_owned(),
+        )
+        .await
+        {
+            Ok(_) => warned += 1,
+            Err(err) => tracing::error!(
+                connection_string_id = connection_string.id,
+                "Unable to warn the connection string expiration: {err}"
+            ),
+        }
+    }
+
+    Ok(Some(warned))
+}
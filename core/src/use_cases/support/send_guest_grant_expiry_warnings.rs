@@ -0,0 +1,123 @@
+use crate::{
+    domain::{
+        dtos::scheduled_job::ScheduledJob,
+        entities::{
+            EmailSuppressionFetching, EncryptionKeyFetching, GuestUserFetching,
+            LocalMessageWrite, NotificationRecipientFetching,
+            ScheduledJobClaiming, TenantFetching,
+        },
+    },
+    models::AccountLifeCycle,
+    settings::DEFAULT_TENANT_ID_KEY,
+};
+
+use super::dispatch_channel_notification;
+
+use chrono::{Duration, Utc};
+use mycelium_base::{
+    entities::FetchManyResponseKind, utils::errors::MappedErrors,
+};
+
+/// Warn guests of the grants about to expire
+///
+/// Grants are warned once, by the run covering the moment they get
+/// `warn_before` away from their end date. Nothing is done when another
+/// replica claimed the run or the job is not due yet, and `None` is returned.
+///
+/// Returns the number of warned grants.
+#[tracing::instrument(name = "send_guest_grant_expiry_warnings", skip_all)]
+pub async fn send_guest_grant_expiry_warnings(
+    claimed_by: String,
+    interval: Duration,
+    warn_before: Duration,
+    config: AccountLifeCycle,
+    scheduled_job_claiming_repo: Box<&dyn ScheduledJobClaiming>,
+    guest_user_fetching_repo: Box<&dyn GuestUserFetching>,
+    local_message_write_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
+    tenant_fetching_repo: Box<&dyn TenantFetching>,
+    notification_recipient_fetching_repo: Box<
+        &dyn NotificationRecipientFetching,
+    >,
+    encryption_key_fetching_repo: Box<&dyn EncryptionKeyFetching>,
+) -> Result<Option<usize>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Claim the run
+    // ? -----------------------------------------------------------------------
+
+    let run = match scheduled_job_claiming_repo
+        .claim_due_run(
+            ScheduledJob::GuestGrantExpiryWarning,
+            claimed_by,
+            interval,
+        )
+        .await?
+    {
+        Some(run) => run,
+        None => return Ok(None),
+    };
+
+    let (start, end) = run.period(interval);
+
+    // ? -----------------------------------------------------------------------
+    // ? Fetch the grants entering the warning window
+    // ? -----------------------------------------------------------------------
+
+    let grants = match guest_user_fetching_repo
+        .list_expiring_grants(Some(start + warn_before), end + warn_before)
+        .await?
+    {
+        FetchManyResponseKind::Found(records) => records,
+        FetchManyResponseKind::FoundPaginated { records, .. } => records,
+        FetchManyResponseKind::NotFound => return Ok(Some(0)),
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Warn the guests
+    // ? -----------------------------------------------------------------------
+
+    let mut warned = 0;
+
+    for grant in grants {
+        let mut parameters = vec![
+            ("account_name", grant.account_name.to_uppercase()),
+            ("role_name", grant.role_name.to_uppercase()),
+            (
+                "expires_at",
+                grant
+                    .valid_until
+                    .with_timezone(&Utc)
+                    .format("%Y-%m-%d %H:%M UTC")
+                    .to_string(),
+            ),
+        ];
+
+        if let Some(tenant_id) = grant.tenant_id {
+            parameters.push((DEFAULT_TENANT_ID_KEY, tenant_id.to_string()));
+        }
+
+        match dispatch_channel_notification(
+            parameters,
+            "email/guest-access-expiring",
+            config.to_owned(),
+            grant.email.to_owned(),
+            None,
+            local_message_write_repo.to_owned(),
+            email_suppression_fetching_repo.to_owned(),
+            tenant_fetching_repo.to_owned(),
+            notification_recipient_fetching_repo.to_owned(),
+            encryption_key_fetching_repo.to_owned(),
+        )
+        .await
+        {
+            Ok(()) => warned += 1,
+            Err(err) => tracing::error!(
+                guest_user_id = %grant.guest_user_id,
+                account_id = %grant.account_id,
+                "Unable to warn the guest grant expiration: {err}"
+            ),
+        }
+    }
+
+    Ok(Some(warned))
+}
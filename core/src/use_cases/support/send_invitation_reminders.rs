@@ -0,0 +1,115 @@
+use crate::{
+    domain::{
+        dtos::scheduled_job::ScheduledJob,
+        entities::{
+            EmailSuppressionFetching, EncryptionKeyFetching, GuestUserFetching,
+            LocalMessageWrite, NotificationRecipientFetching,
+            ScheduledJobClaiming, TenantFetching,
+        },
+    },
+    models::AccountLifeCycle,
+    settings::DEFAULT_TENANT_ID_KEY,
+};
+
+use super::dispatch_channel_notification;
+
+use chrono::Duration;
+use mycelium_base::{
+    entities::FetchManyResponseKind, utils::errors::MappedErrors,
+};
+
+/// Remind guests of the invitations they did not accept yet
+///
+/// Invitations are reminded once, by the run covering the moment they got
+/// `remind_after` old. Nothing is done when another replica claimed the run
+/// or the job is not due yet, and `None` is returned.
+///
+/// Returns the number of reminded invitations.
+#[tracing::instrument(name = "send_invitation_reminders", skip_all)]
+pub async fn send_invitation_reminders(
+    claimed_by: String,
+    interval: Duration,
+    remind_after: Duration,
+    config: AccountLifeCycle,
+    scheduled_job_claiming_repo: Box<&dyn ScheduledJobClaiming>,
+    guest_user_fetching_repo: Box<&dyn GuestUserFetching>,
+    local_message_write_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
+    tenant_fetching_repo: Box<&dyn TenantFetching>,
+    notification_recipient_fetching_repo: Box<
+        &dyn NotificationRecipientFetching,
+    >,
+    encryption_key_fetching_repo: Box<&dyn EncryptionKeyFetching>,
+) -> Result<Option<usize>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Claim the run
+    // ? -----------------------------------------------------------------------
+
+    let run = match scheduled_job_claiming_repo
+        .claim_due_run(ScheduledJob::InvitationReminder, claimed_by, interval)
+        .await?
+    {
+        Some(run) => run,
+        None => return Ok(None),
+    };
+
+    let (start, end) = run.period(interval);
+
+    // ? -----------------------------------------------------------------------
+    // ? Fetch the invitations getting old in the period
+    // ? -----------------------------------------------------------------------
+
+    let invitations = match guest_user_fetching_repo
+        .list_pending_invitations(start - remind_after, end - remind_after)
+        .await?
+    {
+        FetchManyResponseKind::Found(records) => records,
+        FetchManyResponseKind::FoundPaginated { records, .. } => records,
+        FetchManyResponseKind::NotFound => return Ok(Some(0)),
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Remind the guests
+    // ? -----------------------------------------------------------------------
+
+    let mut reminded = 0;
+
+    for invitation in invitations {
+        let mut parameters = vec![
+            ("account_name", invitation.account_name.to_uppercase()),
+            ("role_name", invitation.role_name.to_uppercase()),
+            ("role_permissions", invitation.role_permission.to_string()),
+            (
+                "invited_at",
+                invitation.invited_at.format("%Y-%m-%d").to_string(),
+            ),
+        ];
+
+        if let Some(tenant_id) = invitation.tenant_id {
+            parameters.push((DEFAULT_TENANT_ID_KEY, tenant_id.to_string()));
+        }
+
+        match dispatch_channel_notification(
+            parameters,
+            "email/guest-invitation-reminder",
+            config.to_owned(),
+            invitation.email.to_owned(),
+            None,
+            local_message_write_repo.to_owned(),
+            email_suppression_fetching_repo.to_owned(),
+            tenant_fetching_repo.to_owned(),
+            notification_recipient_fetching_repo.to_owned(),
+            encryption_key_fetching_repo.to_owned(),
+        )
+        .await
+        {
+            Ok(()) => reminded += 1,
+            Err(err) => tracing::error!(
+                guest_user_id = %invitation.guest_user_id,
+                "Unable to remind the invitation: {err}"
+            ),
+        }
+    }
+
+    Ok(Some(reminded))
+}
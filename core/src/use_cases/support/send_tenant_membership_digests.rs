@@ -0,0 +1,290 @@
+use crate::{
+    domain::{
+        dtos::{
+            email::Email,
+            resource_audit_log::{
+                ResourceAuditEventKind, ResourceAuditLog,
+                ResourceAuditLogCursor, ResourceAuditLogFilter,
+                ResourceAuditResourceType,
+            },
+            scheduled_job::ScheduledJob,
+        },
+        entities::{
+            EmailSuppressionFetching, LocalMessageWrite,
+            ResourceAuditLogFetching, ScheduledJobClaiming, TenantFetching,
+        },
+    },
+    models::AccountLifeCycle,
+    settings::DEFAULT_TENANT_ID_KEY,
+};
+
+use super::dispatch_notification;
+
+use chrono::Duration;
+use mycelium_base::{
+    entities::FetchManyResponseKind, utils::errors::MappedErrors,
+};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+/// Audit rows fetched per page while collecting the changes
+const AUDIT_PAGE_SIZE: i64 = 500;
+
+/// Membership changes of a tenant over a digest period
+#[derive(Debug, Default, PartialEq)]
+struct MembershipChanges {
+    invited: Vec<String>,
+    accepted: usize,
+    revoked: Vec<String>,
+}
+
+/// Send tenant owners a digest of the membership changes of their tenant
+///
+/// Changes are read from the guest grants of the resource audit log, over the
+/// period since the previous digest. Tenants without changes get no digest.
+/// Nothing is done when another replica claimed the run or the job is not due
+/// yet, and `None` is returned.
+///
+/// Returns the number of sent digests.
+#[tracing::instrument(name = "send_tenant_membership_digests", skip_all)]
+pub async fn send_tenant_membership_digests(
+    claimed_by: String,
+    interval: Duration,
+    config: AccountLifeCycle,
+    scheduled_job_claiming_repo: Box<&dyn ScheduledJobClaiming>,
+    resource_audit_log_fetching_repo: Box<&dyn ResourceAuditLogFetching>,
+    local_message_write_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
+    tenant_fetching_repo: Box<&dyn TenantFetching>,
+) -> Result<Option<usize>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Claim the run
+    // ? -----------------------------------------------------------------------
+
+    let run = match scheduled_job_claiming_repo
+        .claim_due_run(
+            ScheduledJob::TenantMembershipDigest,
+            claimed_by,
+            interval,
+        )
+        .await?
+    {
+        Some(run) => run,
+        None => return Ok(None),
+    };
+
+    let (start, end) = run.period(interval);
+
+    // ? -----------------------------------------------------------------------
+    // ? Collect the guest grant changes of the period
+    // ? -----------------------------------------------------------------------
+
+    let filter = ResourceAuditLogFilter {
+        resource_type: Some(ResourceAuditResourceType::GuestUser),
+        created_after: Some(start),
+        created_before: Some(end),
+        ..Default::default()
+    };
+
+    let mut rows = vec![];
+    let mut cursor = None;
+
+    loop {
+        let page = match resource_audit_log_fetching_repo
+            .list_filtered(filter.to_owned(), cursor, AUDIT_PAGE_SIZE)
+            .await?
+        {
+            FetchManyResponseKind::Found(records) => records,
+            FetchManyResponseKind::FoundPaginated { records, .. } => records,
+            FetchManyResponseKind::NotFound => vec![],
+        };
+
+        let page_len = page.len() as i64;
+        cursor = page.last().map(ResourceAuditLogCursor::from_log);
+        rows.extend(page);
+
+        if page_len < AUDIT_PAGE_SIZE {
+            break;
+        }
+    }
+
+    let changes = collect_membership_changes(&rows);
+
+    // ? -----------------------------------------------------------------------
+    // ? Send the digests to the tenant owners
+    // ? -----------------------------------------------------------------------
+
+    let mut sent = 0;
+
+    for (tenant_id, changes) in changes {
+        let owners = match tenant_fetching_repo.list_owners(tenant_id).await {
+            Ok(FetchManyResponseKind::Found(records)) => records,
+            Ok(FetchManyResponseKind::FoundPaginated { records, .. }) => {
+                records
+            }
+            Ok(FetchManyResponseKind::NotFound) => continue,
+            Err(err) => {
+                tracing::error!(
+                    %tenant_id,
+                    "Unable to fetch the tenant owners: {err}"
+                );
+
+                continue;
+            }
+        };
+
+        let parameters = vec![
+            ("period_start", start.format("%Y-%m-%d").to_string()),
+            ("period_end", end.format("%Y-%m-%d").to_string()),
+            ("invited_count", changes.invited.len().to_string()),
+            ("accepted_count", changes.accepted.to_string()),
+            ("revoked_count", changes.revoked.len().to_string()),
+            ("invited_members", changes.invited.join("\n")),
+            ("revoked_members", changes.revoked.join("\n")),
+            (DEFAULT_TENANT_ID_KEY, tenant_id.to_string()),
+        ];
+
+        for owner in owners {
+            let email = match Email::from_string(owner.email.to_owned()) {
+                Ok(email) => email,
+                Err(err) => {
+                    tracing::warn!("Invalid tenant owner email: {err}");
+                    continue;
+                }
+            };
+
+            match dispatch_notification(
+                parameters.to_owned(),
+                "email/tenant-membership-digest",
+                config.to_owned(),
+                email,
+                None,
+                local_message_write_repo.to_owned(),
+                email_suppression_fetching_repo.to_owned(),
+                tenant_fetching_repo.to_owned(),
+            )
+            .await
+            {
+                Ok(_) => sent += 1,
+                Err(err) => tracing::error!(
+                    %tenant_id,
+                    "Unable to send the membership digest: {err}"
+                ),
+            }
+        }
+    }
+
+    Ok(Some(sent))
+}
+
+/// Group the guest grant audit rows by tenant
+///
+/// Grants are counted as invitations, accepted invitations are the updates
+/// recorded by `accept_invitation`, and deletions are revocations. Rows
+/// without a tenant are ignored.
+fn collect_membership_changes(
+    rows: &[ResourceAuditLog],
+) -> BTreeMap<Uuid, MembershipChanges> {
+    let mut changes = BTreeMap::<Uuid, MembershipChanges>::new();
+
+    for row in rows {
+        let Some(tenant_id) = row.tenant_id else {
+            continue;
+        };
+
+        let email = row
+            .metadata
+            .get("email")
+            .and_then(|email| email.as_str())
+            .map(str::to_string);
+
+        let action = row.metadata.get("action").and_then(|a| a.as_str());
+
+        match (&row.event, email, action) {
+            (ResourceAuditEventKind::Created, Some(email), _) => {
+                changes.entry(tenant_id).or_default().invited.push(email)
+            }
+            (ResourceAuditEventKind::Updated, _, Some("accept_invitation")) => {
+                changes.entry(tenant_id).or_default().accepted += 1
+            }
+            (ResourceAuditEventKind::Deleted, Some(email), _) => {
+                changes.entry(tenant_id).or_default().revoked.push(email)
+            }
+            _ => (),
+        }
+    }
+
+    changes
+}
+
+// ? ---------------------------------------------------------------------------
+// ? TESTS
+// ? ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::dtos::written_by::WrittenBy;
+
+    use chrono::Utc;
+    use serde_json::json;
+
+    fn row(
+        tenant_id: Option<Uuid>,
+        event: ResourceAuditEventKind,
+        metadata: serde_json::Value,
+    ) -> ResourceAuditLog {
+        ResourceAuditLog {
+            id: Uuid::new_v4(),
+            resource_type: ResourceAuditResourceType::GuestUser,
+            resource_id: Uuid::new_v4(),
+            tenant_id,
+            event,
+            performed_by: WrittenBy::new_from_account(Uuid::new_v4()),
+            metadata,
+            created_at: Utc::now(),
+            chain: None,
+            redacted_at: None,
+        }
+    }
+
+    #[test]
+    fn membership_changes_are_grouped_by_tenant() {
+        let tenant_a = Uuid::new_v4();
+        let tenant_b = Uuid::new_v4();
+
+        let changes = collect_membership_changes(&[
+            row(
+                Some(tenant_a),
+                ResourceAuditEventKind::Created,
+                json!({ "action": "guest_user_to_subscription_account", "email": "alice@example.com" }),
+            ),
+            row(
+                Some(tenant_a),
+                ResourceAuditEventKind::Updated,
+                json!({ "action": "accept_invitation" }),
+            ),
+            row(
+                Some(tenant_b),
+                ResourceAuditEventKind::Deleted,
+                json!({ "action": "revoke_user_guest_to_subscription_account", "email": "bob@example.com" }),
+            ),
+            row(
+                None,
+                ResourceAuditEventKind::Created,
+                json!({ "email": "carol@example.com" }),
+            ),
+        ]);
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(
+            changes[&tenant_a],
+            MembershipChanges {
+                invited: vec!["alice@example.com".to_string()],
+                accepted: 1,
+                revoked: vec![],
+            }
+        );
+        assert_eq!(changes[&tenant_b].revoked, vec!["bob@example.com"]);
+    }
+}
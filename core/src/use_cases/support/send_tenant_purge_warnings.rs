@@ -0,0 +1,148 @@
+use crate::{
+    domain::{
+        dtos::{email::Email, scheduled_job::ScheduledJob},
+        entities::{
+            EmailSuppressionFetching, LocalMessageWrite, ScheduledJobClaiming,
+            TenantFetching,
+        },
+    },
+    models::AccountLifeCycle,
+    settings::DEFAULT_TENANT_ID_KEY,
+};
+
+use super::dispatch_notification;
+
+use chrono::{Duration, Utc};
+use mycelium_base::{
+    entities::FetchManyResponseKind, utils::errors::MappedErrors,
+};
+
+/// Warn the owners of trashed tenants about to be purged
+///
+/// Tenants are warned once, by the run covering the moment they get
+/// `warn_before` away from their purge. Warnings never start before the
+/// trashing, so `warn_before` is bounded to the retention period. Nothing is
+/// done when another replica claimed the run or the job is not due yet, and
+/// `None` is returned.
+///
+/// Returns the number of sent warnings.
+#[tracing::instrument(name = "send_tenant_purge_warnings", skip_all)]
+pub async fn send_tenant_purge_warnings(
+    claimed_by: String,
+    interval: Duration,
+    retention: Duration,
+    warn_before: Duration,
+    config: AccountLifeCycle,
+    scheduled_job_claiming_repo: Box<&dyn ScheduledJobClaiming>,
+    tenant_fetching_repo: Box<&dyn TenantFetching>,
+    local_message_write_repo: Box<&dyn LocalMessageWrite>,
+    email_suppression_fetching_repo: Box<&dyn EmailSuppressionFetching>,
+) -> Result<Option<usize>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Claim the run
+    // ? -----------------------------------------------------------------------
+
+    let run = match scheduled_job_claiming_repo
+        .claim_due_run(ScheduledJob::TenantPurgeWarning, claimed_by, interval)
+        .await?
+    {
+        Some(run) => run,
+        None => return Ok(None),
+    };
+
+    let (start, end) = run.period(interval);
+    let offset = warn_before.min(retention) - retention;
+
+    // ? -----------------------------------------------------------------------
+    // ? Fetch the tenants entering the warning window
+    // ? -----------------------------------------------------------------------
+
+    let tenants = match tenant_fetching_repo
+        .list_trashed(Some(start + offset), end + offset)
+        .await?
+    {
+        FetchManyResponseKind::Found(records) => records,
+        FetchManyResponseKind::FoundPaginated { records, .. } => records,
+        FetchManyResponseKind::NotFound => return Ok(Some(0)),
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Warn the tenant owners
+    // ? -----------------------------------------------------------------------
+
+    let mut warned = 0;
+
+    for tenant in tenants {
+        let (Some(tenant_id), Some(trashed_at)) =
+            (tenant.id, tenant.trashed_at())
+        else {
+            continue;
+        };
+
+        let owners = match tenant_fetching_repo.list_owners(tenant_id).await {
+            Ok(FetchManyResponseKind::Found(records)) => records,
+            Ok(FetchManyResponseKind::FoundPaginated { records, .. }) => {
+                records
+            }
+            Ok(FetchManyResponseKind::NotFound) => continue,
+            Err(err) => {
+                tracing::error!(
+                    %tenant_id,
+                    "Unable to fetch the tenant owners: {err}"
+                );
+
+                continue;
+            }
+        };
+
+        let parameters = vec![
+            ("tenant_name", tenant.name.to_owned()),
+            (
+                "trashed_at",
+                trashed_at
+                    .with_timezone(&Utc)
+                    .format("%Y-%m-%d %H:%M UTC")
+                    .to_string(),
+            ),
+            (
+                "purge_at",
+                (trashed_at + retention)
+                    .with_timezone(&Utc)
+                    .format("%Y-%m-%d %H:%M UTC")
+                    .to_string(),
+            ),
+            (DEFAULT_TENANT_ID_KEY, tenant_id.to_string()),
+        ];
+
+        for owner in owners {
+            let email = match Email::from_string(owner.email.to_owned()) {
+                Ok(email) => email,
+                Err(err) => {
+                    tracing::warn!("Invalid tenant owner email: {err}");
+                    continue;
+                }
+            };
+
+            match dispatch_notification(
+                parameters.to_owned(),
+                "email/tenant-purge-scheduled",
+                config.to_owned(),
+                email,
+                None,
+                local_message_write_repo.to_owned(),
+                email_suppression_fetching_repo.to_owned(),
+                tenant_fetching_repo.to_owned(),
+            )
+            .await
+            {
+                Ok(_) => warned += 1,
+                Err(err) => tracing::error!(
+                    %tenant_id,
+                    "Unable to warn the tenant purge: {err}"
+                ),
+            }
+        }
+    }
+
+    Ok(Some(warned))
+}
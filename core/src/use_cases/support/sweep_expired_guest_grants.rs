@@ -0,0 +1,304 @@
+use crate::{
+    domain::{
+        dtos::{
+            resource_audit_log::{
+                ResourceAuditEventKind, ResourceAuditResourceType,
+            },
+            scheduled_job::ScheduledJob,
+            written_by::WrittenBy,
+        },
+        entities::{
+            GuestUserDeletion, GuestUserFetching, ResourceAuditLogRegistration,
+            ScheduledJobClaiming,
+        },
+    },
+    use_cases::shared::audit::emit_resource_audit_event,
+};
+
+use chrono::Duration;
+use mycelium_base::{
+    entities::{DeletionResponseKind, FetchManyResponseKind},
+    utils::errors::MappedErrors,
+};
+
+/// Revoke the guest grants past their end date
+///
+/// Expired grants no longer license their accounts, since profiles only
+/// include the grants inside their validity window. The sweep deletes them
+/// and records the revocation in the resource audit log. Nothing is done
+/// when another replica claimed the run or the job is not due yet, and `None`
+/// is returned.
+///
+/// Returns the number of revoked grants.
+#[tracing::instrument(name = "sweep_expired_guest_grants", skip_all)]
+pub async fn sweep_expired_guest_grants(
+    claimed_by: String,
+    interval: Duration,
+    scheduled_job_claiming_repo: Box<&dyn ScheduledJobClaiming>,
+    guest_user_fetching_repo: Box<&dyn GuestUserFetching>,
+    guest_user_deletion_repo: Box<&dyn GuestUserDeletion>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<Option<usize>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Claim the run
+    // ? -----------------------------------------------------------------------
+
+    let run = match scheduled_job_claiming_repo
+        .claim_due_run(
+            ScheduledJob::GuestGrantExpirySweep,
+            claimed_by,
+            interval,
+        )
+        .await?
+    {
+        Some(run) => run,
+        None => return Ok(None),
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Fetch the expired grants
+    //
+    // Every grant expired before the run is fetched, so grants missed by a
+    // failed run are revoked by the next one.
+    //
+    // ? -----------------------------------------------------------------------
+
+    let grants = match guest_user_fetching_repo
+        .list_expiring_grants(None, run.started_at)
+        .await?
+    {
+        FetchManyResponseKind::Found(records) => records,
+        FetchManyResponseKind::FoundPaginated { records, .. } => records,
+        FetchManyResponseKind::NotFound => return Ok(Some(0)),
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Revoke the grants
+    // ? -----------------------------------------------------------------------
+
+    let mut revoked = 0;
+
+    for grant in grants {
+        match guest_user_deletion_repo
+            .delete(grant.guest_role_id, grant.account_id, grant.email.email())
+            .await
+        {
+            Ok(DeletionResponseKind::Deleted) => (),
+            Ok(DeletionResponseKind::NotDeleted(_, msg)) => {
+                tracing::warn!(
+                    guest_user_id = %grant.guest_user_id,
+                    account_id = %grant.account_id,
+                    "Expired guest grant not revoked: {msg}"
+                );
+
+                continue;
+            }
+            Err(err) => {
+                tracing::error!(
+                    guest_user_id = %grant.guest_user_id,
+                    account_id = %grant.account_id,
+                    "Unable to revoke the expired guest grant: {err}"
+                );
+
+                continue;
+            }
+        }
+
+        emit_resource_audit_event(
+            audit_repo.to_owned(),
+            ResourceAuditResourceType::GuestUser,
+            grant.account_id,
+            grant.tenant_id,
+            ResourceAuditEventKind::Deleted,
+            WrittenBy::new_anemic(),
+            serde_json::json!({
+                "action": "sweep_expired_guest_grants",
+                "guestUserId": grant.guest_user_id,
+                "guestRoleId": grant.guest_role_id,
+                "email": grant.email.email(),
+                "validUntil": grant.valid_until,
+            }),
+        )
+        .await;
+
+        revoked += 1;
+    }
+
+    Ok(Some(revoked))
+}
+
+// ? ---------------------------------------------------------------------------
+// ? TESTS
+// ? ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::dtos::{
+        email::Email,
+        guest_user::{ExpiringGuestGrant, GuestUser, PendingGuestInvitation},
+        resource_audit_log::NewResourceAuditLogEvent,
+        scheduled_job::ScheduledJobRun,
+    };
+
+    use async_trait::async_trait;
+    use chrono::{DateTime, Local, Utc};
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    struct StubClaiming;
+
+    #[async_trait]
+    impl ScheduledJobClaiming for StubClaiming {
+        async fn claim_due_run(
+            &self,
+            job: ScheduledJob,
+            claimed_by: String,
+            _: Duration,
+        ) -> Result<Option<ScheduledJobRun>, MappedErrors> {
+            Ok(Some(ScheduledJobRun {
+                job,
+                started_at: Utc::now(),
+                previous_run_at: None,
+                claimed_by,
+            }))
+        }
+    }
+
+    struct StubFetching {
+        grants: Vec<ExpiringGuestGrant>,
+    }
+
+    #[async_trait]
+    impl GuestUserFetching for StubFetching {
+        async fn list(
+            &self,
+            _: Uuid,
+            _: Option<i32>,
+            _: Option<i32>,
+        ) -> Result<FetchManyResponseKind<GuestUser>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn list_pending_invitations(
+            &self,
+            _: DateTime<Utc>,
+            _: DateTime<Utc>,
+        ) -> Result<FetchManyResponseKind<PendingGuestInvitation>, MappedErrors>
+        {
+            unimplemented!()
+        }
+
+        async fn list_account_invitations(
+            &self,
+            _: Uuid,
+        ) -> Result<FetchManyResponseKind<PendingGuestInvitation>, MappedErrors>
+        {
+            unimplemented!()
+        }
+
+        async fn list_expired_invitations(
+            &self,
+            _: DateTime<Utc>,
+        ) -> Result<FetchManyResponseKind<PendingGuestInvitation>, MappedErrors>
+        {
+            unimplemented!()
+        }
+
+        async fn list_expiring_grants(
+            &self,
+            valid_until_from: Option<DateTime<Utc>>,
+            _: DateTime<Utc>,
+        ) -> Result<FetchManyResponseKind<ExpiringGuestGrant>, MappedErrors>
+        {
+            assert!(valid_until_from.is_none());
+            Ok(FetchManyResponseKind::Found(self.grants.to_owned()))
+        }
+    }
+
+    struct StubDeletion {
+        missing: Uuid,
+    }
+
+    #[async_trait]
+    impl GuestUserDeletion for StubDeletion {
+        async fn delete(
+            &self,
+            guest_role_id: Uuid,
+            account_id: Uuid,
+            _: String,
+        ) -> Result<DeletionResponseKind<(Uuid, Uuid)>, MappedErrors> {
+            if account_id == self.missing {
+                return Ok(DeletionResponseKind::NotDeleted(
+                    (guest_role_id, account_id),
+                    "Guest user not found".to_string(),
+                ));
+            }
+
+            Ok(DeletionResponseKind::Deleted)
+        }
+    }
+
+    #[derive(Default)]
+    struct StubAudit {
+        events: Mutex<Vec<NewResourceAuditLogEvent>>,
+    }
+
+    #[async_trait]
+    impl ResourceAuditLogRegistration for StubAudit {
+        async fn create(
+            &self,
+            event: NewResourceAuditLogEvent,
+        ) -> Result<(), MappedErrors> {
+            self.events.lock().unwrap().push(event);
+            Ok(())
+        }
+    }
+
+    fn grant(account_id: Uuid) -> ExpiringGuestGrant {
+        ExpiringGuestGrant {
+            guest_user_id: Uuid::new_v4(),
+            email: Email::from_string("contractor@example.com".to_string())
+                .unwrap(),
+            guest_role_id: Uuid::new_v4(),
+            role_name: "auditor".to_string(),
+            account_id,
+            account_name: "Billing".to_string(),
+            tenant_id: Some(Uuid::new_v4()),
+            valid_until: Local::now() - Duration::hours(1),
+        }
+    }
+
+    #[tokio::test]
+    async fn expired_grants_are_revoked_and_audited() {
+        let revoked_account = Uuid::new_v4();
+        let missing_account = Uuid::new_v4();
+
+        let fetching = StubFetching {
+            grants: vec![grant(revoked_account), grant(missing_account)],
+        };
+        let deletion = StubDeletion {
+            missing: missing_account,
+        };
+        let audit = StubAudit::default();
+
+        let revoked = sweep_expired_guest_grants(
+            "pod-a".to_string(),
+            Duration::hours(1),
+            Box::new(&StubClaiming),
+            Box::new(&fetching),
+            Box::new(&deletion),
+            Box::new(&audit),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(revoked, Some(1));
+
+        let events = audit.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].resource_id, revoked_account);
+        assert_eq!(events[0].event, ResourceAuditEventKind::Deleted);
+        assert_eq!(events[0].metadata["action"], "sweep_expired_guest_grants");
+    }
+}
@@ -0,0 +1,345 @@
+use crate::{
+    domain::{
+        dtos::{
+            guest_user::GuestInvitationEvent,
+            resource_audit_log::{
+                ResourceAuditEventKind, ResourceAuditResourceType,
+            },
+            scheduled_job::ScheduledJob,
+            webhook::{PayloadId, WebHookTrigger},
+            written_by::WrittenBy,
+        },
+        entities::{
+            GuestUserDeletion, GuestUserFetching, ResourceAuditLogRegistration,
+            ScheduledJobClaiming, WebHookRegistration,
+        },
+    },
+    use_cases::shared::audit::emit_resource_audit_event,
+};
+
+use super::register_webhook_dispatching_event;
+
+use chrono::Duration;
+use mycelium_base::{
+    entities::{DeletionResponseKind, FetchManyResponseKind},
+    utils::errors::MappedErrors,
+};
+use uuid::Uuid;
+
+/// Remove the invitations not accepted before their expiration date
+///
+/// Expired invitations can no longer be accepted, since profiles do not
+/// include them. The sweep deletes them, records the removal in the resource
+/// audit log and fires the `guestInvitation.expired` webhook. Nothing is done
+/// when another replica claimed the run or the job is not due yet, and `None`
+/// is returned.
+///
+/// Returns the number of removed invitations.
+#[tracing::instrument(name = "sweep_expired_guest_invitations", skip_all)]
+pub async fn sweep_expired_guest_invitations(
+    claimed_by: String,
+    interval: Duration,
+    scheduled_job_claiming_repo: Box<&dyn ScheduledJobClaiming>,
+    guest_user_fetching_repo: Box<&dyn GuestUserFetching>,
+    guest_user_deletion_repo: Box<&dyn GuestUserDeletion>,
+    webhook_registration_repo: Box<&dyn WebHookRegistration>,
+    audit_repo: Box<&dyn ResourceAuditLogRegistration>,
+) -> Result<Option<usize>, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Claim the run
+    // ? -----------------------------------------------------------------------
+
+    let run = match scheduled_job_claiming_repo
+        .claim_due_run(
+            ScheduledJob::GuestInvitationExpirySweep,
+            claimed_by,
+            interval,
+        )
+        .await?
+    {
+        Some(run) => run,
+        None => return Ok(None),
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Fetch the expired invitations
+    // ? -----------------------------------------------------------------------
+
+    let invitations = match guest_user_fetching_repo
+        .list_expired_invitations(run.started_at)
+        .await?
+    {
+        FetchManyResponseKind::Found(records) => records,
+        FetchManyResponseKind::FoundPaginated { records, .. } => records,
+        FetchManyResponseKind::NotFound => return Ok(Some(0)),
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Remove the invitations
+    // ? -----------------------------------------------------------------------
+
+    let mut removed = 0;
+
+    for invitation in invitations {
+        match guest_user_deletion_repo
+            .delete(
+                invitation.guest_role_id,
+                invitation.account_id,
+                invitation.email.email(),
+            )
+            .await
+        {
+            Ok(DeletionResponseKind::Deleted) => (),
+            Ok(DeletionResponseKind::NotDeleted(_, msg)) => {
+                tracing::warn!(
+                    guest_user_id = %invitation.guest_user_id,
+                    account_id = %invitation.account_id,
+                    "Expired invitation not removed: {msg}"
+                );
+
+                continue;
+            }
+            Err(err) => {
+                tracing::error!(
+                    guest_user_id = %invitation.guest_user_id,
+                    account_id = %invitation.account_id,
+                    "Unable to remove the expired invitation: {err}"
+                );
+
+                continue;
+            }
+        }
+
+        emit_resource_audit_event(
+            audit_repo.to_owned(),
+            ResourceAuditResourceType::GuestUser,
+            invitation.account_id,
+            invitation.tenant_id,
+            ResourceAuditEventKind::Deleted,
+            WrittenBy::new_anemic(),
+            serde_json::json!({
+                "action": "sweep_expired_guest_invitations",
+                "guestUserId": invitation.guest_user_id,
+                "guestRoleId": invitation.guest_role_id,
+                "email": invitation.email.email(),
+                "expiresAt": invitation.expires_at,
+            }),
+        )
+        .await;
+
+        if let Err(err) = register_webhook_dispatching_event(
+            Uuid::new_v4(),
+            WebHookTrigger::GuestInvitationExpired,
+            GuestInvitationEvent::from(&invitation),
+            PayloadId::Uuid(invitation.guest_user_id),
+            webhook_registration_repo.to_owned(),
+        )
+        .await
+        {
+            tracing::error!(
+                guest_user_id = %invitation.guest_user_id,
+                "Unable to register the expired invitation webhook: {err}"
+            );
+        }
+
+        removed += 1;
+    }
+
+    Ok(Some(removed))
+}
+
+// ? ---------------------------------------------------------------------------
+// ? TESTS
+// ? ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::dtos::{
+        email::Email,
+        guest_role::Permission,
+        guest_user::{ExpiringGuestGrant, GuestUser, PendingGuestInvitation},
+        resource_audit_log::NewResourceAuditLogEvent,
+        scheduled_job::ScheduledJobRun,
+        webhook::{WebHook, WebHookPayloadArtifact},
+    };
+
+    use async_trait::async_trait;
+    use chrono::{DateTime, Local, Utc};
+    use mycelium_base::entities::CreateResponseKind;
+    use std::sync::Mutex;
+
+    struct StubClaiming;
+
+    #[async_trait]
+    impl ScheduledJobClaiming for StubClaiming {
+        async fn claim_due_run(
+            &self,
+            job: ScheduledJob,
+            claimed_by: String,
+            _: Duration,
+        ) -> Result<Option<ScheduledJobRun>, MappedErrors> {
+            Ok(Some(ScheduledJobRun {
+                job,
+                started_at: Utc::now(),
+                previous_run_at: None,
+                claimed_by,
+            }))
+        }
+    }
+
+    struct StubFetching {
+        invitations: Vec<PendingGuestInvitation>,
+    }
+
+    #[async_trait]
+    impl GuestUserFetching for StubFetching {
+        async fn list(
+            &self,
+            _: Uuid,
+            _: Option<i32>,
+            _: Option<i32>,
+        ) -> Result<FetchManyResponseKind<GuestUser>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn list_pending_invitations(
+            &self,
+            _: DateTime<Utc>,
+            _: DateTime<Utc>,
+        ) -> Result<FetchManyResponseKind<PendingGuestInvitation>, MappedErrors>
+        {
+            unimplemented!()
+        }
+
+        async fn list_account_invitations(
+            &self,
+            _: Uuid,
+        ) -> Result<FetchManyResponseKind<PendingGuestInvitation>, MappedErrors>
+        {
+            unimplemented!()
+        }
+
+        async fn list_expired_invitations(
+            &self,
+            _: DateTime<Utc>,
+        ) -> Result<FetchManyResponseKind<PendingGuestInvitation>, MappedErrors>
+        {
+            Ok(FetchManyResponseKind::Found(self.invitations.to_owned()))
+        }
+
+        async fn list_expiring_grants(
+            &self,
+            _: Option<DateTime<Utc>>,
+            _: DateTime<Utc>,
+        ) -> Result<FetchManyResponseKind<ExpiringGuestGrant>, MappedErrors>
+        {
+            unimplemented!()
+        }
+    }
+
+    struct StubDeletion;
+
+    #[async_trait]
+    impl GuestUserDeletion for StubDeletion {
+        async fn delete(
+            &self,
+            _: Uuid,
+            _: Uuid,
+            _: String,
+        ) -> Result<DeletionResponseKind<(Uuid, Uuid)>, MappedErrors> {
+            Ok(DeletionResponseKind::Deleted)
+        }
+    }
+
+    #[derive(Default)]
+    struct StubWebHooks {
+        triggers: Mutex<Vec<WebHookTrigger>>,
+    }
+
+    #[async_trait]
+    impl WebHookRegistration for StubWebHooks {
+        async fn create(
+            &self,
+            _: WebHook,
+        ) -> Result<CreateResponseKind<WebHook>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn register_execution_event(
+            &self,
+            artifact: WebHookPayloadArtifact,
+        ) -> Result<CreateResponseKind<Uuid>, MappedErrors> {
+            self.triggers.lock().unwrap().push(artifact.trigger);
+            Ok(CreateResponseKind::Created(Uuid::new_v4()))
+        }
+    }
+
+    #[derive(Default)]
+    struct StubAudit {
+        events: Mutex<Vec<NewResourceAuditLogEvent>>,
+    }
+
+    #[async_trait]
+    impl ResourceAuditLogRegistration for StubAudit {
+        async fn create(
+            &self,
+            event: NewResourceAuditLogEvent,
+        ) -> Result<(), MappedErrors> {
+            self.events.lock().unwrap().push(event);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn expired_invitations_are_removed_audited_and_notified() {
+        let account_id = Uuid::new_v4();
+
+        let fetching = StubFetching {
+            invitations: vec![PendingGuestInvitation {
+                guest_user_id: Uuid::new_v4(),
+                email: Email::from_string("former@example.com".to_string())
+                    .unwrap(),
+                invited_at: Local::now() - Duration::days(10),
+                account_id,
+                account_name: "Billing".to_string(),
+                tenant_id: Some(Uuid::new_v4()),
+                role_name: "auditor".to_string(),
+                role_permission: Permission::Read,
+                guest_role_id: Uuid::new_v4(),
+                invited_by: None,
+                last_sent_at: None,
+                expires_at: Some(Local::now() - Duration::days(3)),
+            }],
+        };
+        let webhooks = StubWebHooks::default();
+        let audit = StubAudit::default();
+
+        let removed = sweep_expired_guest_invitations(
+            "pod-a".to_string(),
+            Duration::hours(1),
+            Box::new(&StubClaiming),
+            Box::new(&fetching),
+            Box::new(&StubDeletion),
+            Box::new(&webhooks),
+            Box::new(&audit),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(removed, Some(1));
+
+        let events = audit.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].resource_id, account_id);
+        assert_eq!(
+            events[0].metadata["action"],
+            "sweep_expired_guest_invitations"
+        );
+
+        assert_eq!(
+            *webhooks.triggers.lock().unwrap(),
+            vec![WebHookTrigger::GuestInvitationExpired]
+        );
+    }
+}
@@ -0,0 +1,374 @@
+use crate::domain::{
+    dtos::{
+        error_code::{ErrorCode, ErrorCodeTranslation},
+        tenant::TenantMetaKey,
+    },
+    entities::{
+        ErrorCodeTranslationFetching, KVArtifactRead, KVArtifactWrite,
+        TenantFetching,
+    },
+};
+
+use mycelium_base::{
+    entities::{FetchManyResponseKind, FetchResponseKind},
+    utils::errors::MappedErrors,
+};
+use uuid::Uuid;
+
+/// Find the translation of an error code preferred by the caller
+///
+/// Locales are tried in order: the `Accept-Language` header, the `locale`
+/// meta of the caller account and the `locale` meta of the requested tenant.
+/// The tenant is only fetched when the previous sources give no match.
+/// Returns `None` when the code has no translation for any of them, so the
+/// default message is kept.
+///
+/// The outcome for each (code, locale) pair, including the lack of a
+/// translation, is cached for `cache_ttl` seconds, so the translations are
+/// only listed from the database on a cache miss.
+#[tracing::instrument(
+    name = "translate_error_code",
+    skip(
+        accept_language,
+        error_code_translation_fetching_repo,
+        tenant_fetching_repo,
+        kv_artifact_read_repo,
+        kv_artifact_write_repo
+    )
+)]
+pub async fn translate_error_code(
+    code: String,
+    accept_language: Option<String>,
+    account_locale: Option<String>,
+    tenant_id: Option<Uuid>,
+    cache_ttl: u64,
+    error_code_translation_fetching_repo: Box<
+        &dyn ErrorCodeTranslationFetching,
+    >,
+    tenant_fetching_repo: Box<&dyn TenantFetching>,
+    kv_artifact_read_repo: Box<&dyn KVArtifactRead>,
+    kv_artifact_write_repo: Box<&dyn KVArtifactWrite>,
+) -> Result<Option<ErrorCodeTranslation>, MappedErrors> {
+    let Some((prefix, error_number)) = ErrorCode::parse_code(&code) else {
+        return Ok(None);
+    };
+
+    let mut lookup = TranslationLookup {
+        code: &code,
+        prefix,
+        error_number,
+        cache_ttl,
+        translations: None,
+        error_code_translation_fetching_repo:
+            *error_code_translation_fetching_repo,
+        kv_artifact_read_repo: *kv_artifact_read_repo,
+        kv_artifact_write_repo: *kv_artifact_write_repo,
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Try the Accept-Language header
+    // ? -----------------------------------------------------------------------
+
+    if let Some(header) = accept_language {
+        for locale in parse_accept_language(&header) {
+            if let Some(translation) = lookup.find(&locale).await? {
+                return Ok(Some(translation));
+            }
+        }
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Try the account locale
+    // ? -----------------------------------------------------------------------
+
+    if let Some(locale) = account_locale {
+        if let Some(translation) = lookup.find(&locale).await? {
+            return Ok(Some(translation));
+        }
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Try the tenant locale
+    // ? -----------------------------------------------------------------------
+
+    if let Some(tenant_id) = tenant_id {
+        if let FetchResponseKind::Found(tenant) = tenant_fetching_repo
+            .get_tenant_public_by_id(tenant_id)
+            .await?
+        {
+            let locale =
+                tenant.effective_meta().get(&TenantMetaKey::Locale).cloned();
+
+            if let Some(locale) = locale {
+                return lookup.find(&locale).await;
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// Cached per-locale lookup of the translations of one error code
+///
+/// The translations are listed from the database at most once, on the first
+/// cache miss.
+struct TranslationLookup<'a> {
+    code: &'a str,
+    prefix: String,
+    error_number: i32,
+    cache_ttl: u64,
+    translations: Option<Vec<ErrorCodeTranslation>>,
+    error_code_translation_fetching_repo: &'a dyn ErrorCodeTranslationFetching,
+    kv_artifact_read_repo: &'a dyn KVArtifactRead,
+    kv_artifact_write_repo: &'a dyn KVArtifactWrite,
+}
+
+impl TranslationLookup<'_> {
+    async fn find(
+        &mut self,
+        locale: &str,
+    ) -> Result<Option<ErrorCodeTranslation>, MappedErrors> {
+        let locale = ErrorCodeTranslation::normalize_locale(locale);
+        let key = format!("error_code_translation_{}_{locale}", self.code);
+
+        //
+        // The cache only saves a database round trip: when it fails, fall
+        // back to the database instead of failing the response.
+        //
+        match self
+            .kv_artifact_read_repo
+            .get_encoded_artifact(key.to_owned())
+            .await
+        {
+            Ok(FetchResponseKind::Found(cached)) => {
+                match serde_json::from_str::<Option<ErrorCodeTranslation>>(
+                    &cached,
+                ) {
+                    Ok(translation) => return Ok(translation),
+                    Err(err) => tracing::warn!(
+                        "Ignoring undecodable cached translation {key}: {err}"
+                    ),
+                }
+            }
+            Ok(FetchResponseKind::NotFound(_)) => (),
+            Err(err) => {
+                tracing::warn!("Unable to read cached translation {key}: {err}")
+            }
+        }
+
+        if self.translations.is_none() {
+            self.translations = Some(
+                match self
+                    .error_code_translation_fetching_repo
+                    .list(self.prefix.to_owned(), self.error_number)
+                    .await?
+                {
+                    FetchManyResponseKind::Found(records) => records,
+                    FetchManyResponseKind::FoundPaginated {
+                        records, ..
+                    } => records,
+                    FetchManyResponseKind::NotFound => vec![],
+                },
+            );
+        }
+
+        let translation = select_translation(
+            self.translations.as_deref().unwrap_or_default(),
+            &[locale],
+        );
+
+        match serde_json::to_string(&translation) {
+            Ok(encoded) => {
+                if let Err(err) = self
+                    .kv_artifact_write_repo
+                    .set_encoded_artifact(
+                        key.to_owned(),
+                        encoded,
+                        self.cache_ttl,
+                    )
+                    .await
+                {
+                    tracing::warn!("Unable to cache translation {key}: {err}");
+                }
+            }
+            Err(err) => {
+                tracing::warn!("Unable to encode translation {key}: {err}")
+            }
+        }
+
+        Ok(translation)
+    }
+}
+
+/// Parse the locales of an `Accept-Language` header, by descending quality
+fn parse_accept_language(header: &str) -> Vec<String> {
+    let mut locales = header
+        .split(',')
+        .enumerate()
+        .filter_map(|(position, entry)| {
+            let mut parts = entry.split(';');
+            let locale = parts.next()?.trim();
+
+            if locale.is_empty() || locale == "*" {
+                return None;
+            }
+
+            let quality = parts
+                .find_map(|part| part.trim().strip_prefix("q="))
+                .map(|quality| quality.parse::<f32>().unwrap_or(0.0))
+                .unwrap_or(1.0);
+
+            if quality <= 0.0 {
+                return None;
+            }
+
+            Some((locale.to_string(), quality, position))
+        })
+        .collect::<Vec<_>>();
+
+    locales.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.2.cmp(&b.2)));
+
+    locales.into_iter().map(|(locale, ..)| locale).collect()
+}
+
+/// Pick the translation matching the first possible locale
+///
+/// An exact match wins; otherwise locales sharing the language match, so `pt`
+/// accepts `pt-br` and `es-ar` accepts `es`.
+fn select_translation(
+    translations: &[ErrorCodeTranslation],
+    locales: &[String],
+) -> Option<ErrorCodeTranslation> {
+    let language =
+        |locale: &str| locale.split('-').next().unwrap_or_default().to_string();
+
+    locales.iter().find_map(|locale| {
+        let locale = ErrorCodeTranslation::normalize_locale(locale);
+
+        translations
+            .iter()
+            .find(|translation| translation.locale == locale)
+            .or_else(|| {
+                translations.iter().find(|translation| {
+                    language(&translation.locale) == language(&locale)
+                })
+            })
+            .cloned()
+    })
+}
+
+// ? --------------------------------------------------------------------------
+// ? TESTS
+// ? --------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::MockErrorCodeTranslationFetching;
+
+    use async_trait::async_trait;
+    use mycelium_base::entities::CreateResponseKind;
+    use std::{collections::HashMap, sync::Mutex};
+
+    #[derive(Default)]
+    struct MemoryKV(Mutex<HashMap<String, String>>);
+
+    #[async_trait]
+    impl KVArtifactRead for MemoryKV {
+        async fn get_encoded_artifact(
+            &self,
+            key: String,
+        ) -> Result<FetchResponseKind<String, String>, MappedErrors> {
+            Ok(match self.0.lock().unwrap().get(&key) {
+                Some(value) => FetchResponseKind::Found(value.to_owned()),
+                None => FetchResponseKind::NotFound(Some(key)),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl KVArtifactWrite for MemoryKV {
+        async fn set_encoded_artifact(
+            &self,
+            key: String,
+            value: String,
+            _: u64,
+        ) -> Result<CreateResponseKind<String>, MappedErrors> {
+            self.0.lock().unwrap().insert(key, value.to_owned());
+            Ok(CreateResponseKind::Created(value))
+        }
+    }
+
+    fn translation(locale: &str) -> ErrorCodeTranslation {
+        ErrorCodeTranslation::new(
+            "MYC".to_string(),
+            19,
+            locale,
+            format!("message in {locale}"),
+            None,
+        )
+    }
+
+    #[test]
+    fn accept_language_is_ordered_by_quality() {
+        assert_eq!(
+            parse_accept_language("en;q=0.5, pt-BR, es;q=0.8, fr;q=0, *"),
+            vec!["pt-BR", "es", "en"]
+        );
+    }
+
+    #[test]
+    fn translations_match_exact_locales_before_languages() {
+        let translations =
+            vec![translation("es"), translation("pt-br"), translation("pt")];
+
+        let selected =
+            select_translation(&translations, &["pt_BR".to_string()]);
+        assert_eq!(selected.unwrap().locale, "pt-br");
+
+        let selected =
+            select_translation(&translations, &["es-AR".to_string()]);
+        assert_eq!(selected.unwrap().locale, "es");
+
+        let selected = select_translation(
+            &translations,
+            &["de".to_string(), "pt".to_string()],
+        );
+        assert_eq!(selected.unwrap().locale, "pt");
+
+        assert!(
+            select_translation(&translations, &["de".to_string()]).is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn translations_are_listed_once_per_cached_locale() {
+        let mut fetching = MockErrorCodeTranslationFetching::new();
+        fetching.expect_list().times(1).returning(|_, _| {
+            Ok(FetchManyResponseKind::Found(vec![translation("pt-br")]))
+        });
+
+        let kv = MemoryKV::default();
+
+        //
+        // Each lookup stands for a separate response: only the first one
+        // reaches the database, for the hit and for the miss alike.
+        //
+        for _ in 0..2 {
+            let mut lookup = TranslationLookup {
+                code: "MYC00019",
+                prefix: "MYC".to_string(),
+                error_number: 19,
+                cache_ttl: 60,
+                translations: None,
+                error_code_translation_fetching_repo: &fetching,
+                kv_artifact_read_repo: &kv,
+                kv_artifact_write_repo: &kv,
+            };
+
+            let found = lookup.find("pt_BR").await.unwrap();
+            assert_eq!(found.unwrap().locale, "pt-br");
+            assert!(lookup.find("de").await.unwrap().is_none());
+        }
+    }
+}
@@ -0,0 +1,271 @@
+use crate::domain::{
+    dtos::{
+        bulk_import::{
+            BulkImportRow, BulkImportRowResult, BulkImportRowStatus,
+        },
+        email::Email,
+        guest_role::GuestRole,
+    },
+    entities::GuestRoleFetching,
+};
+
+use mycelium_base::{
+    entities::{FetchManyResponseKind, FetchResponseKind},
+    utils::errors::MappedErrors,
+};
+use std::{collections::HashMap, str::FromStr};
+use uuid::Uuid;
+
+/// A row of a bulk import, with what its validation resolved
+pub(crate) struct ResolvedBulkImportRow {
+    pub(crate) row: BulkImportRow,
+    pub(crate) result: BulkImportRowResult,
+    pub(crate) email: Option<Email>,
+    pub(crate) role: Option<GuestRole>,
+}
+
+/// Validate the rows of a bulk import
+///
+/// A row is valid when its email is well formed, its account name is not
+/// empty, its role is a guest role available to the tenant and no previous
+/// row targets the same email, role and account. Rows are validated
+/// independently: an invalid row does not invalidate the others.
+#[tracing::instrument(name = "validate_bulk_import_rows", skip_all)]
+pub async fn validate_bulk_import_rows(
+    tenant_id: Uuid,
+    rows: &[BulkImportRow],
+    guest_role_fetching_repo: Box<&dyn GuestRoleFetching>,
+) -> Result<Vec<BulkImportRowResult>, MappedErrors> {
+    Ok(
+        resolve_bulk_import_rows(tenant_id, rows, guest_role_fetching_repo)
+            .await?
+            .into_iter()
+            .map(|resolved| resolved.result)
+            .collect(),
+    )
+}
+
+pub(crate) async fn resolve_bulk_import_rows(
+    tenant_id: Uuid,
+    rows: &[BulkImportRow],
+    guest_role_fetching_repo: Box<&dyn GuestRoleFetching>,
+) -> Result<Vec<ResolvedBulkImportRow>, MappedErrors> {
+    let mut roles: HashMap<String, Option<GuestRole>> = HashMap::new();
+    let mut seen: HashMap<(String, Uuid, String), usize> = HashMap::new();
+    let mut resolved_rows = vec![];
+
+    for (index, row) in rows.iter().enumerate() {
+        let result = BulkImportRowResult::new(index + 1, row);
+
+        if !roles.contains_key(&row.role) {
+            let role =
+                resolve_role(tenant_id, &row.role, *guest_role_fetching_repo)
+                    .await?;
+
+            roles.insert(row.role.to_owned(), role);
+        }
+
+        let role = roles.get(&row.role).cloned().flatten();
+        let email = Email::from_string(row.email.to_owned()).ok();
+
+        let problem = match (&email, &role) {
+            (None, _) => Some(format!("Invalid email: {}", row.email)),
+            _ if row.account_name.is_empty() => {
+                Some("The account name is empty".to_string())
+            }
+            (_, None) => Some(format!("Guest role not found: {}", row.role)),
+            (Some(_), Some(role)) => {
+                let key = (
+                    row.email.to_owned(),
+                    role.id.unwrap_or_default(),
+                    row.account_name.to_owned(),
+                );
+
+                match seen.get(&key) {
+                    Some(first) => Some(format!("Repeats row {first}")),
+                    None => {
+                        seen.insert(key, result.row);
+                        None
+                    }
+                }
+            }
+        };
+
+        let result = match problem {
+            Some(message) => {
+                result.with_status(BulkImportRowStatus::Invalid, Some(message))
+            }
+            None => result,
+        };
+
+        resolved_rows.push(ResolvedBulkImportRow {
+            row: row.to_owned(),
+            result,
+            email,
+            role,
+        });
+    }
+
+    Ok(resolved_rows)
+}
+
+/// Find a guest role of the tenant by id, slug or name
+pub(crate) async fn resolve_role(
+    tenant_id: Uuid,
+    reference: &str,
+    guest_role_fetching_repo: &dyn GuestRoleFetching,
+) -> Result<Option<GuestRole>, MappedErrors> {
+    if let Ok(id) = Uuid::from_str(reference) {
+        return Ok(match guest_role_fetching_repo.get(id).await? {
+            FetchResponseKind::Found(role)
+                if role.is_available_to_tenant(Some(tenant_id)) =>
+            {
+                Some(role)
+            }
+            _ => None,
+        });
+    }
+
+    for (name, slug) in [
+        (None, Some(reference.to_string())),
+        (Some(reference.to_string()), None),
+    ] {
+        let roles = match guest_role_fetching_repo
+            .list(Some(tenant_id), name, slug, None, Some(100), None)
+            .await?
+        {
+            FetchManyResponseKind::Found(records) => records,
+            FetchManyResponseKind::FoundPaginated { records, .. } => records,
+            FetchManyResponseKind::NotFound => vec![],
+        };
+
+        if let Some(role) = roles.into_iter().find(|role| {
+            role.slug == reference || role.name.eq_ignore_ascii_case(reference)
+        }) {
+            return Ok(Some(role));
+        }
+    }
+
+    Ok(None)
+}
+
+// ? ---------------------------------------------------------------------------
+// ? TESTS
+// ? ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::dtos::{
+        bulk_import::BulkImportFormat, guest_role::Permission,
+    };
+
+    use async_trait::async_trait;
+
+    struct StubRoles {
+        roles: Vec<GuestRole>,
+    }
+
+    #[async_trait]
+    impl GuestRoleFetching for StubRoles {
+        async fn get(
+            &self,
+            id: Uuid,
+        ) -> Result<FetchResponseKind<GuestRole, Uuid>, MappedErrors> {
+            Ok(match self.roles.iter().find(|role| role.id == Some(id)) {
+                Some(role) => FetchResponseKind::Found(role.to_owned()),
+                None => FetchResponseKind::NotFound(Some(id)),
+            })
+        }
+
+        async fn get_parent_by_child_id(
+            &self,
+            _: Uuid,
+        ) -> Result<FetchResponseKind<GuestRole, Uuid>, MappedErrors> {
+            unimplemented!()
+        }
+
+        async fn list(
+            &self,
+            tenant_id: Option<Uuid>,
+            _: Option<String>,
+            _: Option<String>,
+            _: Option<bool>,
+            _: Option<i32>,
+            _: Option<i32>,
+        ) -> Result<FetchManyResponseKind<GuestRole>, MappedErrors> {
+            Ok(FetchManyResponseKind::Found(
+                self.roles
+                    .iter()
+                    .filter(|role| role.is_available_to_tenant(tenant_id))
+                    .cloned()
+                    .collect(),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn rows_are_validated_independently() {
+        let tenant_id = Uuid::new_v4();
+        let viewer_id = Uuid::new_v4();
+
+        let roles = StubRoles {
+            roles: vec![
+                GuestRole::new(
+                    Some(viewer_id),
+                    "Viewer".to_string(),
+                    None,
+                    Permission::Read,
+                    None,
+                    false,
+                ),
+                GuestRole::new(
+                    Some(Uuid::new_v4()),
+                    "Other Tenant Role".to_string(),
+                    None,
+                    Permission::Write,
+                    None,
+                    false,
+                )
+                .with_tenant_id(Uuid::new_v4()),
+            ],
+        };
+
+        let rows = BulkImportRow::parse_many(
+            BulkImportFormat::Csv,
+            &format!(
+                "email,role,accountName\n\
+                ana@example.com,viewer,Lab A\n\
+                not-an-email,viewer,Lab A\n\
+                bob@example.com,other-tenant-role,Lab A\n\
+                ana@example.com,{viewer_id},Lab A\n\
+                ana@example.com,Viewer,Lab B\n\
+                carl@example.com,viewer,\n"
+            ),
+        )
+        .unwrap();
+
+        let results =
+            validate_bulk_import_rows(tenant_id, &rows, Box::new(&roles))
+                .await
+                .unwrap();
+
+        let statuses: Vec<BulkImportRowStatus> =
+            results.iter().map(|result| result.status).collect();
+
+        assert_eq!(
+            statuses,
+            vec![
+                BulkImportRowStatus::Valid,
+                BulkImportRowStatus::Invalid,
+                BulkImportRowStatus::Invalid,
+                BulkImportRowStatus::Invalid,
+                BulkImportRowStatus::Valid,
+                BulkImportRowStatus::Invalid,
+            ]
+        );
+
+        assert_eq!(results[3].message, Some("Repeats row 1".to_string()));
+        assert_eq!(results[5].row, 6);
+    }
+}
@@ -0,0 +1,53 @@
+use mycelium_base::utils::errors::{use_case_err, MappedErrors};
+use subtle::ConstantTimeEq;
+
+/// Check the secret presented by an email provider delivery webhook
+///
+/// Compared in constant time. Fails when no secret is configured, so the
+/// webhook stays disabled until the operator opts in.
+#[tracing::instrument(name = "validate_delivery_webhook_secret", skip_all)]
+pub fn validate_delivery_webhook_secret(
+    presented_secret: Option<&str>,
+    configured_secret: Option<&str>,
+) -> Result<(), MappedErrors> {
+    let Some(configured_secret) = configured_secret else {
+        return use_case_err("Delivery webhook is not enabled")
+            .with_exp_true()
+            .as_error();
+    };
+
+    let presented_bytes = presented_secret.unwrap_or_default().as_bytes();
+    let configured_bytes = configured_secret.as_bytes();
+
+    let secrets_match = presented_bytes.len() == configured_bytes.len()
+        && presented_bytes.ct_eq(configured_bytes).unwrap_u8() == 1;
+
+    if !secrets_match {
+        return use_case_err("Invalid delivery webhook secret")
+            .with_exp_true()
+            .as_error();
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_the_configured_secret_is_accepted() {
+        assert!(validate_delivery_webhook_secret(Some("s3cret"), None).is_err());
+        assert!(validate_delivery_webhook_secret(None, Some("s3cret")).is_err());
+        assert!(validate_delivery_webhook_secret(
+            Some("s3cre"),
+            Some("s3cret")
+        )
+        .is_err());
+        assert!(validate_delivery_webhook_secret(
+            Some("s3cret"),
+            Some("s3cret")
+        )
+        .is_ok());
+    }
+}
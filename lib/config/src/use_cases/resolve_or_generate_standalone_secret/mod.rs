@@ -8,7 +8,38 @@ use encrypted_file_store::{
 use keyring_store::{persist_secret_to_keyring, read_secret_from_keyring};
 
 use mycelium_base::utils::errors::MappedErrors;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+/// The OS keyring service the standalone secrets are stored under.
+pub const STANDALONE_KEYRING_SERVICE: &str = "mycelium-standalone";
+
+/// The directory of the encrypted secret files of a standalone instance,
+/// next to its SQLite database.
+pub fn standalone_secrets_dir(sqlite_path: &str) -> PathBuf {
+    Path::new(sqlite_path)
+        .parent()
+        .map(|parent| parent.join(".secrets"))
+        .unwrap_or_else(|| PathBuf::from(".secrets"))
+}
+
+/// Reads a named standalone secret persisted by
+/// `resolve_or_generate_standalone_secret`, without ever generating one.
+///
+/// Used by tools reading the data of a standalone instance (e.g. `myc-cli
+/// migrate-backend`), for which a freshly generated secret would silently
+/// decrypt nothing.
+#[tracing::instrument(name = "read_standalone_secret", skip_all)]
+pub fn read_standalone_secret(
+    keyring_service: &str,
+    secrets_dir: &Path,
+    name: &str,
+) -> Result<Option<String>, MappedErrors> {
+    if let Some(value) = read_secret_from_keyring(keyring_service, name) {
+        return Ok(Some(value));
+    }
+
+    read_secret_from_encrypted_file(secrets_dir, name)
+}
 
 /// Resolves a named standalone secret (SM-R9, DEC-2), called only when no
 /// explicit secret was configured (env/config `Value` -- `SecretResolver`
@@ -30,11 +61,9 @@ pub fn resolve_or_generate_standalone_secret(
     secrets_dir: &Path,
     name: &str,
 ) -> Result<String, MappedErrors> {
-    if let Some(value) = read_secret_from_keyring(keyring_service, name) {
-        return Ok(value);
-    }
-
-    if let Some(value) = read_secret_from_encrypted_file(secrets_dir, name)? {
+    if let Some(value) =
+        read_standalone_secret(keyring_service, secrets_dir, name)?
+    {
         return Ok(value);
     }
 
@@ -86,6 +115,32 @@ mod tests {
         let _ = std::fs::remove_dir_all(&dir);
     }
 
+    #[test]
+    fn reading_never_generates_a_secret() {
+        let dir = temp_secrets_dir();
+        let service = "mycelium-standalone-tests";
+
+        assert_eq!(
+            read_standalone_secret(service, &dir, "never_generated").unwrap(),
+            None
+        );
+
+        let generated = resolve_or_generate_standalone_secret(
+            service,
+            &dir,
+            "generated_then_read",
+        )
+        .unwrap();
+
+        assert_eq!(
+            read_standalone_secret(service, &dir, "generated_then_read")
+                .unwrap(),
+            Some(generated)
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn generated_secret_is_a_valid_uuid() {
         let dir = temp_secrets_dir();
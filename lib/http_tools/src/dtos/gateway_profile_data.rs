@@ -36,10 +36,19 @@ pub struct GatewayProfileData {
     pub licensed_resources: Option<LicensedResources>,
     pub tenants_ownership: Option<TenantsOwnership>,
     pub meta: Option<HashMap<AccountMetaKey, String>>,
+
+    /// The named permissions granted by the licensed resources
+    ///
+    /// The union of the permissions, as `invoices:approve`, of the guest roles
+    /// licensing the profile resources.
+    #[serde(default)]
+    pub permissions: Vec<String>,
 }
 
 impl GatewayProfileData {
     pub fn from_profile(profile: Profile) -> Self {
+        let permissions = profile.named_permissions();
+
         Self {
             owners: profile.owners,
             acc_id: profile.acc_id,
@@ -55,9 +64,15 @@ impl GatewayProfileData {
             licensed_resources: profile.licensed_resources,
             tenants_ownership: profile.tenants_ownership,
             meta: profile.meta,
+            permissions,
         }
     }
 
+    /// Check if any licensed resource grants the named permission
+    pub fn has_permission(&self, permission: &str) -> bool {
+        self.permissions.iter().any(|p| p == permission)
+    }
+
     pub fn to_profile(&self) -> Profile {
         let mut profile = Profile::new(
             self.owners.to_owned(),
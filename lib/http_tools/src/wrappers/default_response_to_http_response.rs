@@ -162,6 +162,13 @@ pub fn updating_response_kind<T: Serialize>(
     }
 }
 
+/// Error code of a response built by `handle_mapped_error`
+///
+/// Stored in the response extensions, so middlewares may replace the message
+/// by a translation of the error code without parsing the body.
+#[derive(Clone, Debug)]
+pub struct MappedErrorCode(pub String);
+
 /// Map a `MappedErrors` into a `HttpResponse`
 ///
 /// This function maps the error codes to the corresponding `HttpResponse`
@@ -200,10 +207,16 @@ pub fn handle_mapped_error(err: MappedErrors) -> HttpResponse {
         if err.is_in(vec![code]) {
             error!("Error: {err}");
 
-            return response.json(
+            let mut response = response.json(
                 HttpJsonResponse::new_message(err.to_string())
-                    .with_code(code_string),
+                    .with_code(code_string.to_owned()),
             );
+
+            response
+                .extensions_mut()
+                .insert(MappedErrorCode(code_string));
+
+            return response;
         }
     }
 
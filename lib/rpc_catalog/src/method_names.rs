@@ -0,0 +1,211 @@
+//! The JSON-RPC method catalogue of the gateway
+//!
+//! Declares a constant per method served by the `_adm/rpc` endpoint, and the
+//! list of all of them in [`METHODS`]. The gateway dispatches on the constants
+//! and the CLI builds its `admin` subcommands from the list.
+
+/// Declare the method constants and the [`METHODS`] list in one place, so a
+/// method can not be served without being listed.
+macro_rules! methods {
+    ($($name:ident = $value:literal;)*) => {
+        $(pub const $name: &str = $value;)*
+
+        /// Every method of the catalogue, in declaration order
+        pub const METHODS: &[&str] = &[$($name),*];
+    };
+}
+
+methods! {
+    // Discovery
+    RPC_DISCOVER = "rpc.discover";
+
+    // Managers
+    MANAGERS_ACCOUNTS_CREATE_SYSTEM_ACCOUNT =
+        "managers.accounts.createSystemAccount";
+    MANAGERS_GUEST_ROLES_CREATE_SYSTEM_ROLES =
+        "managers.guestRoles.createSystemRoles";
+    MANAGERS_TENANTS_CREATE = "managers.tenants.create";
+    MANAGERS_TENANTS_LIST = "managers.tenants.list";
+    MANAGERS_TENANTS_DELETE = "managers.tenants.delete";
+    MANAGERS_TENANTS_INCLUDE_TENANT_OWNER =
+        "managers.tenants.includeTenantOwner";
+    MANAGERS_TENANTS_EXCLUDE_TENANT_OWNER =
+        "managers.tenants.excludeTenantOwner";
+
+    // Audit (shared, not role scoped)
+    AUDIT_RESOURCE_TRAIL_FETCH = "audit.resourceTrail.fetch";
+    AUDIT_RESOURCE_TRAIL_SEARCH = "audit.resourceTrail.search";
+    AUDIT_RESOURCE_TRAIL_EXPORT = "audit.resourceTrail.export";
+
+    // Account manager
+    ACCOUNT_MANAGER_GUESTS_GUEST_TO_CHILDREN_ACCOUNT =
+        "accountManager.guests.guestToChildrenAccount";
+    ACCOUNT_MANAGER_GUEST_ROLES_LIST_GUEST_ROLES =
+        "accountManager.guestRoles.listGuestRoles";
+    ACCOUNT_MANAGER_GUEST_ROLES_FETCH_GUEST_ROLE_DETAILS =
+        "accountManager.guestRoles.fetchGuestRoleDetails";
+
+    // Gateway manager
+    GATEWAY_MANAGER_ROUTES_LIST = "gatewayManager.routes.list";
+    GATEWAY_MANAGER_ROUTES_EXPLAIN_POLICY =
+        "gatewayManager.routes.explainPolicy";
+    GATEWAY_MANAGER_SERVICES_LIST = "gatewayManager.services.list";
+    GATEWAY_MANAGER_TOOLS_LIST = "gatewayManager.tools.list";
+
+    // Beginners
+    BEGINNERS_APP_CONFIG_GET_PUBLIC_INFO = "beginners.appConfig.getPublicInfo";
+    BEGINNERS_ACCOUNTS_CREATE = "beginners.accounts.create";
+    BEGINNERS_ACCOUNTS_GET = "beginners.accounts.get";
+    BEGINNERS_ACCOUNTS_UPDATE_NAME = "beginners.accounts.updateName";
+    BEGINNERS_ACCOUNTS_DELETE = "beginners.accounts.delete";
+    BEGINNERS_ACCOUNTS_EXPORT_DATA = "beginners.accounts.exportData";
+    BEGINNERS_ACCOUNTS_ERASE_DATA = "beginners.accounts.eraseData";
+    BEGINNERS_GUESTS_ACCEPT_INVITATION = "beginners.guests.acceptInvitation";
+    BEGINNERS_META_CREATE = "beginners.meta.create";
+    BEGINNERS_META_UPDATE = "beginners.meta.update";
+    BEGINNERS_META_DELETE = "beginners.meta.delete";
+    BEGINNERS_PROFILE_GET = "beginners.profile.get";
+    BEGINNERS_TENANTS_GET_PUBLIC_INFO = "beginners.tenants.getPublicInfo";
+    BEGINNERS_TOKENS_CREATE = "beginners.tokens.create";
+    BEGINNERS_TOKENS_DELETE = "beginners.tokens.delete";
+    BEGINNERS_TOKENS_LIST = "beginners.tokens.list";
+    BEGINNERS_TOKENS_REVOKE = "beginners.tokens.revoke";
+    BEGINNERS_USERS = "beginners.users.create";
+    BEGINNERS_USERS_CHECK_TOKEN_AND_ACTIVATE_USER =
+        "beginners.users.checkTokenAndActivateUser";
+    BEGINNERS_USERS_START_PASSWORD_REDEFINITION =
+        "beginners.users.startPasswordRedefinition";
+    BEGINNERS_USERS_CHECK_TOKEN_AND_RESET_PASSWORD =
+        "beginners.users.checkTokenAndResetPassword";
+    BEGINNERS_USERS_CHECK_EMAIL_PASSWORD_VALIDITY =
+        "beginners.users.checkEmailPasswordValidity";
+    BEGINNERS_USERS_TOTP_START_ACTIVATION =
+        "beginners.users.totpStartActivation";
+    BEGINNERS_USERS_TOTP_FINISH_ACTIVATION =
+        "beginners.users.totpFinishActivation";
+    BEGINNERS_USERS_TOTP_CHECK_TOKEN = "beginners.users.totpCheckToken";
+    BEGINNERS_USERS_TOTP_DISABLE = "beginners.users.totpDisable";
+
+    // Guest manager
+    GUEST_MANAGER_GUEST_ROLES_CREATE = "guestManager.guestRoles.create";
+    GUEST_MANAGER_GUEST_ROLES_LIST = "guestManager.guestRoles.list";
+    GUEST_MANAGER_GUEST_ROLES_DELETE = "guestManager.guestRoles.delete";
+    GUEST_MANAGER_GUEST_ROLES_UPDATE_NAME_AND_DESCRIPTION =
+        "guestManager.guestRoles.updateNameAndDescription";
+    GUEST_MANAGER_GUEST_ROLES_UPDATE_PERMISSION =
+        "guestManager.guestRoles.updatePermission";
+    GUEST_MANAGER_GUEST_ROLES_UPDATE_NAMED_PERMISSIONS =
+        "guestManager.guestRoles.updateNamedPermissions";
+    GUEST_MANAGER_GUEST_ROLES_INSERT_ROLE_CHILD =
+        "guestManager.guestRoles.insertRoleChild";
+    GUEST_MANAGER_GUEST_ROLES_REMOVE_ROLE_CHILD =
+        "guestManager.guestRoles.removeRoleChild";
+
+    // System manager
+    SYSTEM_MANAGER_ERROR_CODES_CREATE = "systemManager.errorCodes.create";
+    SYSTEM_MANAGER_ERROR_CODES_LIST = "systemManager.errorCodes.list";
+    SYSTEM_MANAGER_ERROR_CODES_GET = "systemManager.errorCodes.get";
+    SYSTEM_MANAGER_ERROR_CODES_UPDATE_MESSAGE_AND_DETAILS =
+        "systemManager.errorCodes.updateMessageAndDetails";
+    SYSTEM_MANAGER_ERROR_CODES_DELETE = "systemManager.errorCodes.delete";
+    SYSTEM_MANAGER_WEBHOOKS_CREATE = "systemManager.webhooks.create";
+    SYSTEM_MANAGER_WEBHOOKS_LIST = "systemManager.webhooks.list";
+    SYSTEM_MANAGER_WEBHOOKS_UPDATE = "systemManager.webhooks.update";
+    SYSTEM_MANAGER_WEBHOOKS_DELETE = "systemManager.webhooks.delete";
+
+    // Subscriptions manager
+    SUBSCRIPTIONS_MANAGER_ACCOUNTS_CREATE_SUBSCRIPTION_ACCOUNT =
+        "subscriptionsManager.accounts.createSubscriptionAccount";
+    SUBSCRIPTIONS_MANAGER_ACCOUNTS_CREATE_ROLE_ASSOCIATED_ACCOUNT =
+        "subscriptionsManager.accounts.createRoleAssociatedAccount";
+    SUBSCRIPTIONS_MANAGER_ACCOUNTS_LIST = "subscriptionsManager.accounts.list";
+    SUBSCRIPTIONS_MANAGER_ACCOUNTS_GET = "subscriptionsManager.accounts.get";
+    SUBSCRIPTIONS_MANAGER_ACCOUNTS_UPDATE_NAME_AND_FLAGS =
+        "subscriptionsManager.accounts.updateNameAndFlags";
+    SUBSCRIPTIONS_MANAGER_ACCOUNTS_PROPAGATE_SUBSCRIPTION_ACCOUNT =
+        "subscriptionsManager.accounts.propagateSubscriptionAccount";
+    SUBSCRIPTIONS_MANAGER_GUESTS_LIST_LICENSED_ACCOUNTS_OF_EMAIL =
+        "subscriptionsManager.guests.listLicensedAccountsOfEmail";
+    SUBSCRIPTIONS_MANAGER_GUESTS_GUEST_USER_TO_SUBSCRIPTION_ACCOUNT =
+        "subscriptionsManager.guests.guestUserToSubscriptionAccount";
+    SUBSCRIPTIONS_MANAGER_GUESTS_UPDATE_FLAGS_FROM_SUBSCRIPTION_ACCOUNT =
+        "subscriptionsManager.guests.updateFlagsFromSubscriptionAccount";
+    SUBSCRIPTIONS_MANAGER_GUESTS_EXTEND_GUEST_GRANT =
+        "subscriptionsManager.guests.extendGuestGrant";
+    SUBSCRIPTIONS_MANAGER_GUESTS_REVOKE_USER_GUEST_TO_SUBSCRIPTION_ACCOUNT =
+        "subscriptionsManager.guests.revokeUserGuestToSubscriptionAccount";
+    SUBSCRIPTIONS_MANAGER_GUESTS_LIST_GUEST_ON_SUBSCRIPTION_ACCOUNT =
+        "subscriptionsManager.guests.listGuestOnSubscriptionAccount";
+    SUBSCRIPTIONS_MANAGER_GUESTS_LIST_INVITATIONS =
+        "subscriptionsManager.guests.listInvitations";
+    SUBSCRIPTIONS_MANAGER_GUESTS_RESEND_INVITATION =
+        "subscriptionsManager.guests.resendInvitation";
+    SUBSCRIPTIONS_MANAGER_GUESTS_CANCEL_INVITATION =
+        "subscriptionsManager.guests.cancelInvitation";
+    SUBSCRIPTIONS_MANAGER_GUEST_ROLES_LIST =
+        "subscriptionsManager.guestRoles.list";
+    SUBSCRIPTIONS_MANAGER_GUEST_ROLES_GET =
+        "subscriptionsManager.guestRoles.get";
+    SUBSCRIPTIONS_MANAGER_TAGS_CREATE = "subscriptionsManager.tags.create";
+    SUBSCRIPTIONS_MANAGER_TAGS_UPDATE = "subscriptionsManager.tags.update";
+    SUBSCRIPTIONS_MANAGER_TAGS_DELETE = "subscriptionsManager.tags.delete";
+    SUBSCRIPTIONS_MANAGER_BULK_IMPORTS_SUBMIT =
+        "subscriptionsManager.bulkImports.submit";
+    SUBSCRIPTIONS_MANAGER_BULK_IMPORTS_LIST =
+        "subscriptionsManager.bulkImports.list";
+    SUBSCRIPTIONS_MANAGER_BULK_IMPORTS_GET =
+        "subscriptionsManager.bulkImports.get";
+
+    // Tenant manager
+    TENANT_MANAGER_ACCOUNTS_CREATE_SUBSCRIPTION_MANAGER_ACCOUNT =
+        "tenantManager.accounts.createSubscriptionManagerAccount";
+    TENANT_MANAGER_ACCOUNTS_DELETE_SUBSCRIPTION_ACCOUNT =
+        "tenantManager.accounts.deleteSubscriptionAccount";
+    TENANT_MANAGER_GUESTS_GUEST_USER_TO_SUBSCRIPTION_MANAGER_ACCOUNT =
+        "tenantManager.guests.guestUserToSubscriptionManagerAccount";
+    TENANT_MANAGER_GUESTS_REVOKE_USER_GUEST_TO_SUBSCRIPTION_MANAGER_ACCOUNT =
+        "tenantManager.guests.revokeUserGuestToSubscriptionManagerAccount";
+    TENANT_MANAGER_TAGS_CREATE = "tenantManager.tags.create";
+    TENANT_MANAGER_TAGS_UPDATE = "tenantManager.tags.update";
+    TENANT_MANAGER_TAGS_DELETE = "tenantManager.tags.delete";
+    TENANT_MANAGER_TENANT_GET = "tenantManager.tenant.get";
+
+    // Tenant owner
+    TENANT_OWNER_ACCOUNTS_CREATE_MANAGEMENT_ACCOUNT =
+        "tenantOwner.accounts.createManagementAccount";
+    TENANT_OWNER_ACCOUNTS_DELETE_TENANT_MANAGER_ACCOUNT =
+        "tenantOwner.accounts.deleteTenantManagerAccount";
+    TENANT_OWNER_META_CREATE = "tenantOwner.meta.create";
+    TENANT_OWNER_META_DELETE = "tenantOwner.meta.delete";
+    TENANT_OWNER_OWNER_GUEST = "tenantOwner.owner.guest";
+    TENANT_OWNER_OWNER_REVOKE = "tenantOwner.owner.revoke";
+    TENANT_OWNER_SCIM_CREDENTIALS_CREATE = "tenantOwner.scimCredentials.create";
+    TENANT_OWNER_SCIM_CREDENTIALS_LIST = "tenantOwner.scimCredentials.list";
+    TENANT_OWNER_SCIM_CREDENTIALS_DELETE = "tenantOwner.scimCredentials.delete";
+    TENANT_OWNER_TENANT_UPDATE_AND_DESCRIPTION =
+        "tenantOwner.tenant.updateNameAndDescription";
+    TENANT_OWNER_TENANT_UPDATE_ARCHIVING_STATUS =
+        "tenantOwner.tenant.updateArchivingStatus";
+    TENANT_OWNER_TENANT_UPDATE_TRASHING_STATUS =
+        "tenantOwner.tenant.updateTrashingStatus";
+    TENANT_OWNER_TENANT_RESTORE = "tenantOwner.tenant.restore";
+    TENANT_OWNER_TENANT_UPDATE_VERIFYING_STATUS =
+        "tenantOwner.tenant.updateVerifyingStatus";
+    TENANT_OWNER_TENANT_UPDATE_PARENT = "tenantOwner.tenant.updateParent";
+    TENANT_OWNER_TENANT_LIST_DESCENDANTS = "tenantOwner.tenant.listDescendants";
+
+    // Users manager
+    USER_MANAGER_ACCOUNT_APPROVE = "userManager.account.approve";
+    USER_MANAGER_ACCOUNT_DISAPPROVE = "userManager.account.disapprove";
+    USER_MANAGER_ACCOUNT_ACTIVATE = "userManager.account.activate";
+    USER_MANAGER_ACCOUNT_DEACTIVATE = "userManager.account.deactivate";
+    USER_MANAGER_ACCOUNT_ARCHIVE = "userManager.account.archive";
+    USER_MANAGER_ACCOUNT_UNARCHIVE = "userManager.account.unarchive";
+
+    // Service
+    SERVICE_LIST_DISCOVERABLE_SERVICES = "service.listDiscoverableServices";
+
+    // Staff
+    STAFF_ACCOUNTS_UPGRADE_PRIVILEGES = "staff.accounts.upgradePrivileges";
+    STAFF_ACCOUNTS_DOWNGRADE_PRIVILEGES = "staff.accounts.downgradePrivileges";
+}
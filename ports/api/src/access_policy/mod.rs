@@ -0,0 +1,200 @@
+// -----------------------------------------------------------------------------
+// OPTIONAL: RHAI EVALUATOR
+// -----------------------------------------------------------------------------
+#[cfg(feature = "rhai")]
+mod rhai_evaluator;
+#[cfg(feature = "rhai")]
+pub(crate) use rhai_evaluator::*;
+
+use actix_web::{
+    http::header::{AUTHORIZATION, COOKIE},
+    web,
+};
+use chrono::{DateTime, Datelike, Local, Timelike};
+use http::uri::PathAndQuery;
+use lazy_static::lazy_static;
+use myc_core::domain::dtos::{
+    access_policy::{
+        AccessPolicyAttributes, AccessPolicyEvaluator,
+        AccessPolicyProfileAttributes, AccessPolicyRequestAttributes,
+    },
+    security_group::SecurityGroup,
+    service::Service,
+};
+use myc_http_tools::settings::MYCELIUM_HEADER_PREFIX;
+use std::collections::HashMap;
+
+/// Deny every condition when no expression language engine is enabled
+#[cfg(not(feature = "rhai"))]
+struct UnsupportedPolicyEvaluator;
+
+#[cfg(not(feature = "rhai"))]
+impl AccessPolicyEvaluator for UnsupportedPolicyEvaluator {
+    fn evaluate_condition(
+        &self,
+        _: &str,
+        _: &AccessPolicyAttributes,
+    ) -> Result<bool, String> {
+        Err("Policy conditions are not supported (feature not enabled)"
+            .to_string())
+    }
+}
+
+#[cfg(feature = "rhai")]
+lazy_static! {
+    static ref ACCESS_POLICY_EVALUATOR: RhaiPolicyEvaluator =
+        RhaiPolicyEvaluator::new();
+}
+
+#[cfg(not(feature = "rhai"))]
+lazy_static! {
+    static ref ACCESS_POLICY_EVALUATOR: UnsupportedPolicyEvaluator =
+        UnsupportedPolicyEvaluator;
+}
+
+/// Get the access policy evaluator of the gateway
+///
+/// Conditions are evaluated as Rhai expressions when the `rhai` feature is
+/// enabled. Otherwise, all conditions fail and policy protected routes deny
+/// every request.
+pub(crate) fn access_policy_evaluator() -> &'static dyn AccessPolicyEvaluator {
+    &*ACCESS_POLICY_EVALUATOR
+}
+
+/// Compile the access policies of the route table
+///
+/// Called when the routes are loaded, so requests evaluate precompiled
+/// conditions. Conditions which do not compile are reported here, and deny
+/// the requests of their routes.
+pub(crate) fn compile_route_policies(services: &[Service]) {
+    let conditions = services
+        .iter()
+        .flat_map(|service| service.routes.iter())
+        .filter_map(|route| match &route.security_group {
+            SecurityGroup::ProtectedByPolicy(policy) => Some(policy),
+            _ => None,
+        })
+        .flat_map(|policy| policy.rules.iter())
+        .map(|rule| rule.condition.as_str());
+
+    #[cfg(feature = "rhai")]
+    for (condition, err) in
+        ACCESS_POLICY_EVALUATOR.compile_conditions(conditions)
+    {
+        tracing::warn!("Invalid access policy condition '{condition}': {err}");
+    }
+
+    #[cfg(not(feature = "rhai"))]
+    if conditions.count() > 0 {
+        tracing::warn!(
+            "Routes declare access policies, but the gateway is built \
+             without the rhai feature: they deny every request"
+        );
+    }
+}
+
+/// Collect the policy attributes of a request
+///
+/// Credentials and Mycelium headers are not exposed to the policy.
+pub(crate) fn build_request_attributes<'a>(
+    method: &str,
+    path: &str,
+    query_string: &str,
+    headers: impl Iterator<Item = (&'a str, &'a str)>,
+    client_ip: Option<String>,
+    now: DateTime<Local>,
+) -> AccessPolicyRequestAttributes {
+    let headers = headers
+        .map(|(name, value)| (name.to_lowercase(), value.to_owned()))
+        .filter(|(name, _)| {
+            name != AUTHORIZATION.as_str()
+                && name != COOKIE.as_str()
+                && !name.starts_with(MYCELIUM_HEADER_PREFIX)
+        })
+        .collect::<HashMap<String, String>>();
+
+    let query = web::Query::<HashMap<String, String>>::from_query(query_string)
+        .map(|query| query.into_inner())
+        .unwrap_or_default();
+
+    AccessPolicyRequestAttributes {
+        method: method.to_uppercase(),
+        path: path.to_owned(),
+        segments: path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| segment.to_owned())
+            .collect(),
+        query,
+        headers,
+        client_ip,
+        hour: now.hour(),
+        minute: now.minute(),
+        weekday: now.weekday().number_from_monday(),
+    }
+}
+
+/// Collect the policy attributes of a request described by a dry-run
+pub(crate) fn build_dry_run_attributes(
+    method: &str,
+    path: &PathAndQuery,
+    headers: HashMap<String, String>,
+    client_ip: Option<String>,
+    at: DateTime<Local>,
+    profile: AccessPolicyProfileAttributes,
+) -> AccessPolicyAttributes {
+    AccessPolicyAttributes {
+        request: build_request_attributes(
+            method,
+            path.path(),
+            path.query().unwrap_or_default(),
+            headers
+                .iter()
+                .map(|(name, value)| (name.as_str(), value.as_str())),
+            client_ip,
+            at,
+        ),
+        profile,
+    }
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Tests
+// ? ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::build_request_attributes;
+
+    use chrono::{Local, TimeZone};
+    use myc_http_tools::settings::DEFAULT_CONNECTION_STRING_KEY;
+
+    #[test]
+    fn request_attributes_hide_credentials() {
+        let headers = [
+            ("Authorization", "Bearer secret"),
+            (DEFAULT_CONNECTION_STRING_KEY, "secret"),
+            ("X-Region", "eu"),
+        ];
+
+        let now = Local.with_ymd_and_hms(2026, 10, 19, 9, 30, 0).unwrap();
+
+        let attributes = build_request_attributes(
+            "post",
+            "/billing/invoices/42",
+            "draft=true",
+            headers.into_iter(),
+            Some("10.0.0.1".to_string()),
+            now,
+        );
+
+        assert_eq!(attributes.method, "POST");
+        assert_eq!(attributes.segments, vec!["billing", "invoices", "42"]);
+        assert_eq!(attributes.query.get("draft").unwrap(), "true");
+        assert_eq!(attributes.headers.get("x-region").unwrap(), "eu");
+        assert_eq!(attributes.headers.len(), 1);
+        assert_eq!(attributes.client_ip, Some("10.0.0.1".to_string()));
+        assert_eq!((attributes.hour, attributes.minute), (9, 30));
+        assert_eq!(attributes.weekday, 1);
+    }
+}
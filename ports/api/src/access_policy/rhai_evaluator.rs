@@ -0,0 +1,247 @@
+#![cfg(feature = "rhai")]
+
+use crate::callback_engines::json_value_to_rhai_dynamic;
+
+use myc_core::domain::dtos::access_policy::{
+    AccessPolicyAttributes, AccessPolicyEvaluator,
+};
+use rhai::{Engine, Scope, AST};
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+/// The maximum number of operations of a single condition
+const MAX_OPERATIONS: u64 = 10_000;
+
+/// Evaluate policy conditions as Rhai expressions
+///
+/// Conditions are compiled as expressions, so statements, loops, function
+/// definitions and module imports are rejected. The `request` and `profile`
+/// attributes are exposed as constants.
+///
+/// The conditions of the route table are compiled once, when the routes are
+/// loaded. Other conditions, such as dry-run drafts, are compiled on each
+/// evaluation and never cached.
+pub(crate) struct RhaiPolicyEvaluator {
+    engine: Engine,
+    compiled: RwLock<HashMap<String, Arc<AST>>>,
+}
+
+impl RhaiPolicyEvaluator {
+    pub(crate) fn new() -> Self {
+        let mut engine = Engine::new();
+
+        engine
+            .set_max_operations(MAX_OPERATIONS)
+            .set_max_expr_depths(32, 32)
+            .set_max_string_size(4096)
+            .set_max_array_size(1024)
+            .set_max_map_size(1024)
+            .disable_symbol("eval")
+            .on_print(|_| {})
+            .on_debug(|_, _, _| {});
+
+        Self {
+            engine,
+            compiled: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Compile the conditions of the route table
+    ///
+    /// Replaces the previously compiled conditions, and returns the conditions
+    /// which could not be compiled with their error.
+    pub(crate) fn compile_conditions<'a>(
+        &self,
+        conditions: impl Iterator<Item = &'a str>,
+    ) -> Vec<(String, String)> {
+        let mut compiled = HashMap::new();
+        let mut rejected = vec![];
+
+        for condition in conditions {
+            if compiled.contains_key(condition) {
+                continue;
+            }
+
+            match self.engine.compile_expression(condition) {
+                Ok(ast) => {
+                    compiled.insert(condition.to_owned(), Arc::new(ast));
+                }
+                Err(err) => {
+                    rejected.push((condition.to_owned(), err.to_string()))
+                }
+            }
+        }
+
+        *self.compiled.write().unwrap_or_else(|e| e.into_inner()) = compiled;
+
+        rejected
+    }
+
+    fn ast(&self, condition: &str) -> Result<Arc<AST>, String> {
+        if let Some(ast) = self
+            .compiled
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(condition)
+        {
+            return Ok(ast.clone());
+        }
+
+        self.engine
+            .compile_expression(condition)
+            .map(Arc::new)
+            .map_err(|e| e.to_string())
+    }
+}
+
+impl AccessPolicyEvaluator for RhaiPolicyEvaluator {
+    fn evaluate_condition(
+        &self,
+        condition: &str,
+        attributes: &AccessPolicyAttributes,
+    ) -> Result<bool, String> {
+        let ast = self.ast(condition)?;
+
+        let mut scope = Scope::new();
+
+        for (name, value) in [
+            ("request", serde_json::to_value(&attributes.request)),
+            ("profile", serde_json::to_value(&attributes.profile)),
+        ] {
+            let value = value.map_err(|e| {
+                format!("Failed to serialize policy attributes: {e}")
+            })?;
+
+            scope.push_constant(name, json_value_to_rhai_dynamic(value));
+        }
+
+        self.engine
+            .eval_ast_with_scope::<bool>(&mut scope, &ast)
+            .map_err(|e| e.to_string())
+    }
+}
+
+// ? ---------------------------------------------------------------------------
+// ? TESTS
+// ? ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attributes() -> AccessPolicyAttributes {
+        let mut attributes = AccessPolicyAttributes::default();
+
+        attributes.request.method = "POST".to_string();
+        attributes.request.hour = 9;
+        attributes.request.segments =
+            vec!["billing".to_string(), "invoices".to_string()];
+        attributes
+            .request
+            .headers
+            .insert("x-region".to_string(), "eu".to_string());
+        attributes.profile.account_type = "user".to_string();
+        attributes.profile.tenant_id = Some(uuid::Uuid::nil());
+        attributes.profile.permit_flags = Some(vec!["beta".to_string()]);
+        attributes.profile.deny_flags = Some(vec![]);
+        attributes
+            .profile
+            .tenant_meta
+            .insert("country".to_string(), "BR".to_string());
+
+        attributes
+    }
+
+    #[test]
+    fn conditions_see_request_and_profile_attributes() {
+        let evaluator = RhaiPolicyEvaluator::new();
+        let attributes = attributes();
+
+        for condition in [
+            r#"request.method == "POST" && request.hour >= 8"#,
+            r#"request.segments[1] == "invoices""#,
+            r#"request.headers["x-region"] == "eu""#,
+            r#""beta" in profile.permitFlags"#,
+            r#"!("blocked" in profile.denyFlags)"#,
+            r#"profile.tenantMeta.country == "BR""#,
+        ] {
+            assert_eq!(
+                evaluator.evaluate_condition(condition, &attributes),
+                Ok(true),
+                "{condition}"
+            );
+        }
+
+        assert_eq!(
+            evaluator.evaluate_condition(
+                r#"profile.accountType == "staff""#,
+                &attributes
+            ),
+            Ok(false)
+        );
+    }
+
+    #[test]
+    fn conditions_must_be_boolean_expressions() {
+        let evaluator = RhaiPolicyEvaluator::new();
+        let attributes = attributes();
+
+        for condition in [
+            "request.hour",
+            "let x = 1; x == 1",
+            "loop { }",
+            r#"eval("true")"#,
+        ] {
+            assert!(
+                evaluator
+                    .evaluate_condition(condition, &attributes)
+                    .is_err(),
+                "{condition}"
+            );
+        }
+    }
+
+    #[test]
+    fn compiled_conditions_are_reused() {
+        let evaluator = RhaiPolicyEvaluator::new();
+        let condition = r#"request.method == "POST""#;
+
+        let rejected = evaluator
+            .compile_conditions([condition, condition, "loop { }"].into_iter());
+
+        assert_eq!(rejected.len(), 1);
+        assert_eq!(rejected[0].0, "loop { }");
+        assert!(Arc::ptr_eq(
+            &evaluator.ast(condition).unwrap(),
+            &evaluator.ast(condition).unwrap()
+        ));
+        assert_eq!(
+            evaluator.evaluate_condition(condition, &attributes()),
+            Ok(true)
+        );
+    }
+
+    #[test]
+    fn license_conditions_fail_without_tenant() {
+        let evaluator = RhaiPolicyEvaluator::new();
+        let mut attributes = attributes();
+
+        attributes.profile.tenant_id = None;
+        attributes.profile.permit_flags = None;
+        attributes.profile.deny_flags = None;
+
+        for condition in [
+            r#""beta" in profile.permitFlags"#,
+            r#"!("blocked" in profile.denyFlags)"#,
+        ] {
+            assert!(
+                evaluator
+                    .evaluate_condition(condition, &attributes)
+                    .is_err(),
+                "{condition}"
+            );
+        }
+    }
+}
@@ -106,7 +106,7 @@ impl CallbackExecutor for RhaiCallback {
 }
 
 /// Convert a serde_json::Value to a Rhai Dynamic value
-fn json_value_to_rhai_dynamic(value: JsonValue) -> Dynamic {
+pub(crate) fn json_value_to_rhai_dynamic(value: JsonValue) -> Dynamic {
     match value {
         JsonValue::Null => Dynamic::UNIT,
         JsonValue::Bool(b) => Dynamic::from(b),
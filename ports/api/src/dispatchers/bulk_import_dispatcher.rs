@@ -0,0 +1,125 @@
+use crate::models::active_backend_modules::SqlAppModule;
+use myc_core::{
+    domain::entities::{
+        AccountFetching, AccountRegistration, AccountTagRegistration,
+        BulkImportUpdating, EmailSuppressionFetching, EncryptionKeyFetching,
+        GuestRoleFetching, GuestUserFetching, GuestUserOnAccountFetching,
+        GuestUserOnAccountUpdating, GuestUserRegistration, LocalMessageWrite,
+        NotificationRecipientFetching, ResourceAuditLogRegistration,
+        TenantFetching, WebHookRegistration,
+    },
+    models::CoreConfig,
+    use_cases::run_pending_bulk_imports,
+};
+use shaku::HasComponent;
+use std::{sync::Arc, time::Duration};
+use uuid::Uuid;
+
+/// Dispatch the pending bulk import jobs
+///
+/// Spawns a new thread checking for pending jobs every
+/// `core.scheduler.bulkImportIntervalInSecs`. Every API replica runs it: jobs
+/// are claimed in the database before they run, so each job is executed by a
+/// single replica. Setting the interval to zero disables the dispatcher.
+#[tracing::instrument(name = "bulk_import_dispatcher", skip_all)]
+pub(crate) async fn bulk_import_dispatcher(
+    config: CoreConfig,
+    app_modules: Arc<SqlAppModule>,
+) {
+    let interval = match config
+        .scheduler
+        .bulk_import_interval_in_secs
+        .async_get_or_error()
+        .await
+    {
+        Ok(value) => value,
+        Err(err) => panic!("Error on get bulk import interval: {err}"),
+    };
+
+    if interval == 0 {
+        tracing::info!("Bulk import dispatcher disabled");
+        return;
+    }
+
+    let claimed_by = format!(
+        "{}-{}",
+        std::env::var("HOSTNAME").unwrap_or("api".to_string()),
+        Uuid::new_v4()
+    );
+
+    tokio::spawn(async move {
+        tracing::info!(claimed_by, "Starting bulk import dispatcher");
+
+        let bulk_import_updating_repo: &dyn BulkImportUpdating =
+            app_modules.resolve_ref();
+        let guest_role_fetching_repo: &dyn GuestRoleFetching =
+            app_modules.resolve_ref();
+        let account_fetching_repo: &dyn AccountFetching =
+            app_modules.resolve_ref();
+        let account_registration_repo: &dyn AccountRegistration =
+            app_modules.resolve_ref();
+        let account_tag_registration_repo: &dyn AccountTagRegistration =
+            app_modules.resolve_ref();
+        let guest_user_registration_repo: &dyn GuestUserRegistration =
+            app_modules.resolve_ref();
+        let guest_user_fetching_repo: &dyn GuestUserFetching =
+            app_modules.resolve_ref();
+        let guest_user_on_account_fetching_repo: &dyn GuestUserOnAccountFetching =
+            app_modules.resolve_ref();
+        let guest_user_on_account_updating_repo: &dyn GuestUserOnAccountUpdating =
+            app_modules.resolve_ref();
+        let message_write_repo: &dyn LocalMessageWrite =
+            app_modules.resolve_ref();
+        let suppression_repo: &dyn EmailSuppressionFetching =
+            app_modules.resolve_ref();
+        let tenant_fetching_repo: &dyn TenantFetching =
+            app_modules.resolve_ref();
+        let recipient_repo: &dyn NotificationRecipientFetching =
+            app_modules.resolve_ref();
+        let enc_key_repo: &dyn EncryptionKeyFetching =
+            app_modules.resolve_ref();
+        let webhook_registration_repo: &dyn WebHookRegistration =
+            app_modules.resolve_ref();
+        let audit_registration_repo: &dyn ResourceAuditLogRegistration =
+            app_modules.resolve_ref();
+
+        let mut ticker = tokio::time::interval(Duration::from_secs(interval));
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            match run_pending_bulk_imports(
+                claimed_by.to_owned(),
+                config.account_life_cycle.to_owned(),
+                Box::new(bulk_import_updating_repo),
+                Box::new(guest_role_fetching_repo),
+                Box::new(account_fetching_repo),
+                Box::new(account_registration_repo),
+                Box::new(account_tag_registration_repo),
+                Box::new(guest_user_registration_repo),
+                Box::new(guest_user_fetching_repo),
+                Box::new(guest_user_on_account_fetching_repo),
+                Box::new(guest_user_on_account_updating_repo),
+                Box::new(message_write_repo),
+                Box::new(suppression_repo),
+                Box::new(tenant_fetching_repo),
+                Box::new(recipient_repo),
+                Box::new(enc_key_repo),
+                Box::new(webhook_registration_repo),
+                Box::new(audit_registration_repo),
+            )
+            .await
+            {
+                Ok(0) => (),
+                Ok(count) => tracing::info!(
+                    count,
+                    "bulk_import_dispatcher: ran bulk imports"
+                ),
+                Err(err) => {
+                    tracing::error!("Error on run bulk imports: {err}")
+                }
+            }
+        }
+    });
+}
@@ -1,5 +1,6 @@
 use myc_core::domain::entities::{
-    LocalMessageReading, LocalMessageWrite, RemoteMessageWrite,
+    EmailSuppressionRegistration, LocalMessageReading, LocalMessageWrite,
+    MessageDeliveryRegistration, RemoteMessageWrite,
 };
 use myc_notifier::{executor::consume_messages, models::QueueConfig};
 use rand::Rng;
@@ -15,6 +16,8 @@ pub(crate) async fn email_dispatcher(
     local_message_read_repo: Arc<dyn LocalMessageReading>,
     local_message_write_repo: Arc<dyn LocalMessageWrite>,
     remote_message_write_repo: Arc<dyn RemoteMessageWrite>,
+    message_delivery_registration_repo: Arc<dyn MessageDeliveryRegistration>,
+    email_suppression_registration_repo: Arc<dyn EmailSuppressionRegistration>,
 ) {
     tokio::spawn(async move {
         tracing::info!("Starting email dispatcher");
@@ -82,6 +85,8 @@ pub(crate) async fn email_dispatcher(
                 local_message_read_repo.clone(),
                 local_message_write_repo.clone(),
                 remote_message_write_repo.clone(),
+                message_delivery_registration_repo.clone(),
+                email_suppression_registration_repo.clone(),
             )
             .await
             {
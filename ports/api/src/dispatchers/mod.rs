@@ -1,9 +1,15 @@
+mod bulk_import_dispatcher;
 mod email_dispatcher;
 mod resource_audit_log_dispatcher;
+mod resource_audit_retention_dispatcher;
+mod scheduler_dispatcher;
 mod services_health_dispatcher;
 mod webhook_dispatcher;
 
+pub(crate) use bulk_import_dispatcher::*;
 pub(crate) use email_dispatcher::*;
 pub(crate) use resource_audit_log_dispatcher::*;
+pub(crate) use resource_audit_retention_dispatcher::*;
+pub(crate) use scheduler_dispatcher::*;
 pub(crate) use services_health_dispatcher::*;
 pub(crate) use webhook_dispatcher::*;
@@ -1,15 +1,46 @@
 use crate::models::active_backend_modules::SqlAppModule;
-use myc_core::domain::dtos::resource_audit_log::NewResourceAuditLogEvent;
+use myc_config::secret_resolver::SecretResolver;
+use myc_core::{
+    domain::{
+        dtos::resource_audit_log::{
+            NewResourceAuditLogEvent, ResourceAuditChainLink,
+            ResourceAuditCheckpoint,
+        },
+        entities::EncryptionKeyFetching,
+        utils::{ResourceAuditPipelineStats, ResourceAuditSpill},
+    },
+    models::CoreConfig,
+    use_cases::shared::audit::sign_resource_audit_checkpoint,
+};
+use mycelium_base::utils::errors::{creation_err, MappedErrors};
+use opentelemetry::{
+    global,
+    metrics::{ObservableCounter, ObservableGauge},
+    KeyValue,
+};
 use shaku::HasComponent;
-use std::sync::Arc;
-use tokio::sync::mpsc;
+use std::{sync::Arc, time::Duration};
+use uuid::Uuid;
 
 /// Dispatch resource audit log events
 ///
-/// Spawns a new thread to consume events from the resource audit log
-/// channel and persist them one at a time. Never panics on a failed
-/// connection-get or insert -- logs and moves on to the next event, same as
-/// `webhook_dispatcher`'s per-item error handling.
+/// Spawns a single writer consuming the local spill file, to which
+/// `ResourceAuditLogRegistrationSqlDbRepository` writes every event ahead.
+/// Both backend bodies only build the two append closures and hand over to
+/// `run_resource_audit_pipeline`, which holds the delivery policy:
+///
+/// - the spill file is drained whenever an event is written to it, on
+///   startup, and then every `core.audit.replayIntervalInSecs`;
+/// - a failed connection-get or insert is retried with exponential backoff
+///   (`core.audit.maxInsertAttempts`, `initialRetryBackoffInMs`,
+///   `maxRetryBackoffInMs`);
+/// - an event still failing after the last attempt stays in the spill file,
+///   with the events after it, for the next drain.
+///
+/// Every appended row extends its tenant's hash chain. When a row lands on a
+/// checkpoint interval, the chain head is signed and stored as a
+/// `resource_audit_checkpoint`; a failed checkpoint is logged and skipped --
+/// the next interval signs a newer head, which covers this one.
 ///
 /// Two backend-specific bodies (Postgres / SQLite) follow the same
 /// cfg-gated-function-name precedent established by `main.rs`'s
@@ -19,78 +50,451 @@ use tokio::sync::mpsc;
 #[cfg(any(feature = "full", feature = "postgres-only"))]
 #[tracing::instrument(name = "resource_audit_log_dispatcher", skip_all)]
 pub(crate) async fn resource_audit_log_dispatcher(
+    config: CoreConfig,
     app_modules: Arc<SqlAppModule>,
-    mut receiver: mpsc::Receiver<NewResourceAuditLogEvent>,
+    spill: Arc<ResourceAuditSpill>,
 ) {
     use myc_diesel::models::config::DbPoolProvider;
-    use myc_diesel::repositories::append_resource_audit_log_row;
+    use myc_diesel::repositories::{
+        append_resource_audit_checkpoint_row, append_resource_audit_log_row,
+    };
 
-    tokio::spawn(async move {
-        tracing::info!("Starting resource audit log dispatcher");
+    let modules = app_modules.clone();
+    let append_row: AppendRow = Arc::new(move |event| {
+        let pool_provider: &dyn DbPoolProvider = modules.resolve_ref();
+        let mut conn = pool_provider.get_pool().get().map_err(|err| {
+            creation_err(format!("Failed to get db connection: {err}"))
+        })?;
 
-        let pool_provider: &dyn DbPoolProvider = app_modules.resolve_ref();
-
-        while let Some(event) = receiver.recv().await {
-            let mut conn = match pool_provider.get_pool().get() {
-                Ok(conn) => conn,
-                Err(err) => {
-                    tracing::error!(
-                        error = ?err,
-                        "resource_audit_log_dispatcher: failed to get db connection"
-                    );
-                    continue;
-                }
-            };
-
-            if let Err(err) = append_resource_audit_log_row(&mut conn, &event) {
-                tracing::error!(
-                    error = ?err,
-                    resource_type = ?event.resource_type,
-                    resource_id = %event.resource_id,
-                    "resource_audit_log_dispatcher: insert failed"
-                );
-                continue;
-            }
-        }
+        append_resource_audit_log_row(&mut conn, event)
     });
+
+    let modules = app_modules.clone();
+    let append_checkpoint: AppendCheckpoint = Arc::new(move |checkpoint| {
+        let pool_provider: &dyn DbPoolProvider = modules.resolve_ref();
+        let mut conn = pool_provider.get_pool().get().map_err(|err| {
+            creation_err(format!("Failed to get db connection: {err}"))
+        })?;
+
+        append_resource_audit_checkpoint_row(&mut conn, checkpoint)
+    });
+
+    run_resource_audit_pipeline(
+        config,
+        app_modules,
+        spill,
+        append_row,
+        append_checkpoint,
+    )
+    .await;
 }
 
 #[cfg(feature = "standalone")]
 #[tracing::instrument(name = "resource_audit_log_dispatcher", skip_all)]
 pub(crate) async fn resource_audit_log_dispatcher(
+    config: CoreConfig,
     app_modules: Arc<SqlAppModule>,
-    mut receiver: mpsc::Receiver<NewResourceAuditLogEvent>,
+    spill: Arc<ResourceAuditSpill>,
 ) {
     use myc_diesel_sqlite::config::SqliteDbPoolProvider;
-    use myc_diesel_sqlite::repositories::resource_audit_log::append_resource_audit_log_row;
+    use myc_diesel_sqlite::repositories::resource_audit_log::{
+        append_resource_audit_checkpoint_row, append_resource_audit_log_row,
+    };
+
+    let modules = app_modules.clone();
+    let append_row: AppendRow = Arc::new(move |event| {
+        let pool_provider: &dyn SqliteDbPoolProvider = modules.resolve_ref();
+        let mut conn = pool_provider.get_pool().get().map_err(|err| {
+            creation_err(format!("Failed to get db connection: {err}"))
+        })?;
+
+        append_resource_audit_log_row(&mut conn, event)
+    });
+
+    let modules = app_modules.clone();
+    let append_checkpoint: AppendCheckpoint = Arc::new(move |checkpoint| {
+        let pool_provider: &dyn SqliteDbPoolProvider = modules.resolve_ref();
+        let mut conn = pool_provider.get_pool().get().map_err(|err| {
+            creation_err(format!("Failed to get db connection: {err}"))
+        })?;
+
+        append_resource_audit_checkpoint_row(&mut conn, checkpoint)
+    });
+
+    run_resource_audit_pipeline(
+        config,
+        app_modules,
+        spill,
+        append_row,
+        append_checkpoint,
+    )
+    .await;
+}
+
+/// Both append closures block on Diesel, so the pipeline only ever calls them
+/// through `run_blocking`.
+type AppendRow = Arc<
+    dyn Fn(
+            &NewResourceAuditLogEvent,
+        ) -> Result<ResourceAuditChainLink, MappedErrors>
+        + Send
+        + Sync,
+>;
+
+type AppendCheckpoint = Arc<
+    dyn Fn(&ResourceAuditCheckpoint) -> Result<(), MappedErrors> + Send + Sync,
+>;
+
+/// Run blocking database or spill-file work on the blocking thread pool, so
+/// it never stalls the runtime worker driving the dispatcher.
+async fn run_blocking<T, F>(task: F) -> Result<T, MappedErrors>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T, MappedErrors> + Send + 'static,
+{
+    tokio::task::spawn_blocking(task)
+        .await
+        .unwrap_or_else(|err| {
+            creation_err(format!("Blocking audit task failed: {err}"))
+                .as_error()
+        })
+}
+
+/// Retry policy resolved from `core.audit`.
+#[derive(Clone, Copy)]
+struct RetryPolicy {
+    max_attempts: u64,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+/// Backend-agnostic body of `resource_audit_log_dispatcher`.
+///
+/// A single task drains the spill file, so a single writer appends to the
+/// chains at any time.
+async fn run_resource_audit_pipeline(
+    config: CoreConfig,
+    app_modules: Arc<SqlAppModule>,
+    spill: Arc<ResourceAuditSpill>,
+    append_row: AppendRow,
+    append_checkpoint: AppendCheckpoint,
+) {
+    let audit_config = config.audit.to_owned();
+
+    let retry_policy = RetryPolicy {
+        max_attempts: resolve_audit_setting(
+            &audit_config.max_insert_attempts,
+            "maxInsertAttempts",
+        )
+        .await
+        .max(1),
+        initial_backoff: Duration::from_millis(
+            resolve_audit_setting(
+                &audit_config.initial_retry_backoff_in_ms,
+                "initialRetryBackoffInMs",
+            )
+            .await,
+        ),
+        max_backoff: Duration::from_millis(
+            resolve_audit_setting(
+                &audit_config.max_retry_backoff_in_ms,
+                "maxRetryBackoffInMs",
+            )
+            .await,
+        ),
+    };
+
+    let replay_interval = Duration::from_secs(
+        resolve_audit_setting(
+            &audit_config.replay_interval_in_secs,
+            "replayIntervalInSecs",
+        )
+        .await
+        .max(1),
+    );
 
     tokio::spawn(async move {
         tracing::info!("Starting resource audit log dispatcher");
 
-        let pool_provider: &dyn SqliteDbPoolProvider =
+        let enc_key_repo: &dyn EncryptionKeyFetching =
             app_modules.resolve_ref();
 
-        while let Some(event) = receiver.recv().await {
-            let mut conn = match pool_provider.get_pool().get() {
-                Ok(conn) => conn,
-                Err(err) => {
-                    tracing::error!(
-                        error = ?err,
-                        "resource_audit_log_dispatcher: failed to get db connection"
-                    );
-                    continue;
-                }
-            };
-
-            if let Err(err) = append_resource_audit_log_row(&mut conn, &event) {
+        //
+        // Instruments report through their callbacks for as long as they
+        // are alive, so keep them for the whole life of the dispatcher.
+        //
+        let _instruments = register_pipeline_metrics(spill.clone());
+
+        //
+        // The first tick completes immediately, so whatever a previous
+        // process left in the spill file is persisted on startup. Later
+        // ticks retry the events a failing database left behind.
+        //
+        let mut replay_ticker = tokio::time::interval(replay_interval);
+        replay_ticker
+            .set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                _ = spill.wait_for_events() => {}
+                _ = replay_ticker.tick() => {}
+            }
+
+            drain_spill(
+                &config,
+                enc_key_repo,
+                &spill,
+                retry_policy,
+                &append_row,
+                &append_checkpoint,
+            )
+            .await;
+        }
+    });
+}
+
+async fn resolve_audit_setting(
+    setting: &SecretResolver<u64>,
+    name: &str,
+) -> u64 {
+    match setting.async_get_or_error().await {
+        Ok(value) => value,
+        Err(err) => panic!("Error on get audit setting {name}: {err}"),
+    }
+}
+
+/// Persist the spilled events in order, retrying each one with exponential
+/// backoff and stopping at the first event still failing after the last
+/// attempt, so the rest waits for the next drain instead of hammering an
+/// unavailable database.
+async fn drain_spill(
+    config: &CoreConfig,
+    enc_key_repo: &dyn EncryptionKeyFetching,
+    spill: &Arc<ResourceAuditSpill>,
+    retry_policy: RetryPolicy,
+    append_row: &AppendRow,
+    append_checkpoint: &AppendCheckpoint,
+) {
+    let claiming = spill.clone();
+
+    let events = match run_blocking(move || claiming.take_pending()).await {
+        Ok(events) => events,
+        Err(err) => {
+            tracing::error!(
+                error = ?err,
+                "resource_audit_log_dispatcher: unable to read spill file"
+            );
+            return;
+        }
+    };
+
+    let claimed = events.len();
+    let mut persisted = 0;
+
+    for event in events.iter() {
+        let Some(link) =
+            persist_event(spill, retry_policy, append_row, event).await
+        else {
+            tracing::warn!(
+                remaining = claimed - persisted,
+                "resource_audit_log_dispatcher: spill drain interrupted"
+            );
+            break;
+        };
+
+        persisted += 1;
+
+        store_checkpoint_if_due(
+            config,
+            enc_key_repo,
+            append_checkpoint,
+            event.tenant_id,
+            link,
+        )
+        .await;
+    }
+
+    //
+    // Settle even an empty claim, so a file holding only undecodable lines
+    // does not block later drains. Delivery is at-least-once: should
+    // settling fail, events persisted in this round are persisted again by
+    // the next drain.
+    //
+    let settling = spill.clone();
+
+    if let Err(err) = run_blocking(move || {
+        settling.finish_replay(claimed, &events[persisted..])
+    })
+    .await
+    {
+        tracing::error!(
+            error = ?err,
+            "resource_audit_log_dispatcher: unable to settle spill drain"
+        );
+    }
+}
+
+/// Persist an event, retrying with exponential backoff. `None` once every
+/// attempt failed.
+async fn persist_event(
+    spill: &Arc<ResourceAuditSpill>,
+    retry_policy: RetryPolicy,
+    append_row: &AppendRow,
+    event: &NewResourceAuditLogEvent,
+) -> Option<ResourceAuditChainLink> {
+    let mut backoff = retry_policy.initial_backoff;
+    let mut attempt = 1;
+
+    loop {
+        let append = append_row.clone();
+        let pending = event.to_owned();
+
+        match run_blocking(move || append(&pending)).await {
+            Ok(link) => return Some(link),
+            Err(err) if attempt < retry_policy.max_attempts => {
+                tracing::warn!(
+                    error = ?err,
+                    attempt,
+                    backoff_in_ms = backoff.as_millis() as u64,
+                    resource_type = ?event.resource_type,
+                    resource_id = %event.resource_id,
+                    "resource_audit_log_dispatcher: insert failed, retrying"
+                );
+
+                spill.record_retry();
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(retry_policy.max_backoff);
+                attempt += 1;
+            }
+            Err(err) => {
                 tracing::error!(
                     error = ?err,
+                    attempt,
                     resource_type = ?event.resource_type,
                     resource_id = %event.resource_id,
-                    "resource_audit_log_dispatcher: insert failed"
+                    "resource_audit_log_dispatcher: insert failed, keeping \
+                     the event in the spill file"
                 );
-                continue;
+
+                return None;
             }
         }
-    });
+    }
+}
+
+async fn store_checkpoint_if_due(
+    config: &CoreConfig,
+    enc_key_repo: &dyn EncryptionKeyFetching,
+    append_checkpoint: &AppendCheckpoint,
+    chain_tenant_id: Option<Uuid>,
+    link: ResourceAuditChainLink,
+) {
+    let Some(checkpoint) =
+        checkpoint_if_due(config, enc_key_repo, chain_tenant_id, link).await
+    else {
+        return;
+    };
+
+    let append = append_checkpoint.clone();
+    let seq = checkpoint.seq;
+
+    if let Err(err) = run_blocking(move || append(&checkpoint)).await {
+        tracing::error!(
+            error = ?err,
+            seq,
+            "resource_audit_log_dispatcher: checkpoint insert failed"
+        );
+    }
+}
+
+/// Expose the pipeline counters as OpenTelemetry observable instruments.
+fn register_pipeline_metrics(
+    spill: Arc<ResourceAuditSpill>,
+) -> (Vec<ObservableCounter<u64>>, ObservableGauge<u64>) {
+    let meter = global::meter("resource_audit_log");
+
+    let counter =
+        |name: &'static str,
+         description: &'static str,
+         read: fn(&ResourceAuditPipelineStats) -> u64| {
+            let spill = spill.clone();
+
+            meter
+                .u64_observable_counter(name)
+                .with_description(description)
+                .with_callback(move |observer| {
+                    observer.observe(read(&spill.stats()), &[]);
+                })
+                .build()
+        };
+
+    let counters = vec![
+        counter(
+            "resource_audit_log_dropped",
+            "Audit events lost for good",
+            |stats| stats.dropped,
+        ),
+        counter(
+            "resource_audit_log_spilled",
+            "Audit events written to the spill file",
+            |stats| stats.spilled,
+        ),
+        counter(
+            "resource_audit_log_replayed",
+            "Spilled audit events persisted",
+            |stats| stats.replayed,
+        ),
+        counter(
+            "resource_audit_log_retried",
+            "Failed audit inserts retried",
+            |stats| stats.retried,
+        ),
+    ];
+
+    let backlog = meter
+        .u64_observable_gauge("resource_audit_log_backlog")
+        .with_description("Audit events waiting to be persisted")
+        .with_callback(move |observer| {
+            observer.observe(
+                spill.stats().pending,
+                &[KeyValue::new("stage", "spill")],
+            );
+        })
+        .build();
+
+    (counters, backlog)
+}
+
+/// Sign a checkpoint at `link` when it lands on a checkpoint interval.
+/// Backend-agnostic, so shared by both dispatcher bodies.
+async fn checkpoint_if_due(
+    config: &CoreConfig,
+    enc_key_repo: &dyn EncryptionKeyFetching,
+    chain_tenant_id: Option<Uuid>,
+    link: ResourceAuditChainLink,
+) -> Option<ResourceAuditCheckpoint> {
+    if !link.is_checkpoint_due() {
+        return None;
+    }
+
+    let seq = link.seq;
+
+    match sign_resource_audit_checkpoint(
+        chain_tenant_id,
+        link,
+        config.account_life_cycle.to_owned(),
+        Box::new(enc_key_repo),
+    )
+    .await
+    {
+        Ok(checkpoint) => Some(checkpoint),
+        Err(err) => {
+            tracing::error!(
+                error = ?err,
+                seq,
+                "resource_audit_log_dispatcher: checkpoint signing failed"
+            );
+            None
+        }
+    }
 }
@@ -0,0 +1,111 @@
+use crate::models::active_backend_modules::SqlAppModule;
+use myc_core::{
+    domain::{
+        entities::{
+            EncryptionKeyFetching, ResourceAuditLogArchival,
+            ResourceAuditLogFetching,
+        },
+        utils::ResourceAuditArchiveStore,
+    },
+    models::CoreConfig,
+    use_cases::shared::audit::archive_expired_resource_audit_rows,
+};
+use shaku::HasComponent;
+use std::{sync::Arc, time::Duration};
+
+/// Dispatch resource audit log retention
+///
+/// Spawns a new thread archiving the expired rows of `resource_audit_log`
+/// every `core.audit.retentionIntervalInSecs`, into the archive store at
+/// `core.audit.archivePath`. The first run happens right after startup.
+///
+/// Several API replicas may run it at the same time: the archive boundary is
+/// checked under the chain lock, so a racing run is refused and only removes
+/// the files it wrote.
+#[tracing::instrument(name = "resource_audit_retention_dispatcher", skip_all)]
+pub(crate) async fn resource_audit_retention_dispatcher(
+    config: CoreConfig,
+    app_modules: Arc<SqlAppModule>,
+) {
+    let audit_config = config.audit.to_owned();
+
+    let interval = match audit_config
+        .retention_interval_in_secs
+        .async_get_or_error()
+        .await
+    {
+        Ok(interval) => Duration::from_secs(interval.max(1)),
+        Err(err) => panic!("Error on get retention interval: {err}"),
+    };
+
+    let store = match audit_config.archive_path.async_get_or_error().await {
+        Ok(path) => match ResourceAuditArchiveStore::open(path) {
+            Ok(store) => store,
+            Err(err) => panic!("Error on open audit archive store: {err}"),
+        },
+        Err(err) => panic!("Error on get audit archive path: {err}"),
+    };
+
+    tokio::spawn(async move {
+        tracing::info!("Starting resource audit retention dispatcher");
+
+        let fetching_repo: &dyn ResourceAuditLogFetching =
+            app_modules.resolve_ref();
+        let archival_repo: &dyn ResourceAuditLogArchival =
+            app_modules.resolve_ref();
+        let enc_key_repo: &dyn EncryptionKeyFetching =
+            app_modules.resolve_ref();
+
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            //
+            // Resolved on every run, so a changed default retention applies
+            // without a restart.
+            //
+            let default_retention_days = match &audit_config.retention_days {
+                None => None,
+                Some(days) => match days.async_get_or_error().await {
+                    Ok(days) => Some(days),
+                    Err(err) => {
+                        tracing::error!(
+                            "Error on get default audit retention: {err}"
+                        );
+                        continue;
+                    }
+                },
+            };
+
+            let reports = match archive_expired_resource_audit_rows(
+                default_retention_days,
+                &store,
+                config.account_life_cycle.to_owned(),
+                Box::new(fetching_repo),
+                Box::new(archival_repo),
+                Box::new(enc_key_repo),
+            )
+            .await
+            {
+                Ok(reports) => reports,
+                Err(err) => {
+                    tracing::error!("Error on archive audit rows: {err}");
+                    continue;
+                }
+            };
+
+            for report in reports {
+                if report.archive.is_some() || report.purged_rows > 0 {
+                    tracing::info!(
+                        chain_tenant_id = ?report.chain_tenant_id,
+                        purged_rows = report.purged_rows,
+                        archive = ?report.archive.as_ref().map(|a| &a.location),
+                        "resource_audit_retention_dispatcher: archived rows"
+                    );
+                }
+            }
+        }
+    });
+}
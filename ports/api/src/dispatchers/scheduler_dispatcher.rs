@@ -0,0 +1,393 @@
+use crate::models::active_backend_modules::SqlAppModule;
+use myc_config::secret_resolver::SecretResolver;
+use myc_core::{
+    domain::entities::{
+        EmailSuppressionFetching, EncryptionKeyFetching, GuestUserDeletion,
+        GuestUserFetching, LocalMessageWrite, NotificationRecipientFetching,
+        ResourceAuditLogFetching, ResourceAuditLogRegistration,
+        ScheduledJobClaiming, TenantDeletion, TenantFetching, TokenFetching,
+        WebHookRegistration,
+    },
+    models::CoreConfig,
+    use_cases::{
+        purge_trashed_tenants, send_connection_string_expiry_warnings,
+        send_guest_grant_expiry_warnings, send_invitation_reminders,
+        send_tenant_membership_digests, send_tenant_purge_warnings,
+        sweep_expired_guest_grants, sweep_expired_guest_invitations,
+    },
+};
+use shaku::HasComponent;
+use std::{sync::Arc, time::Duration};
+use uuid::Uuid;
+
+/// Dispatch the scheduled notification jobs
+///
+/// Spawns a new thread checking for due jobs every
+/// `core.scheduler.tickIntervalInSecs`. Every API replica runs it: a run is
+/// claimed in the database before it starts, so each run is executed by a
+/// single replica. Jobs whose interval or days are set to zero are disabled.
+#[tracing::instrument(name = "scheduler_dispatcher", skip_all)]
+pub(crate) async fn scheduler_dispatcher(
+    config: CoreConfig,
+    app_modules: Arc<SqlAppModule>,
+) {
+    let scheduler_config = config.scheduler.to_owned();
+
+    let tick_interval =
+        resolve_secs(&scheduler_config.tick_interval_in_secs, "tick interval")
+            .await;
+
+    let invitation_reminder_interval = resolve_secs(
+        &scheduler_config.invitation_reminder_interval_in_secs,
+        "invitation reminder interval",
+    )
+    .await;
+
+    let invitation_reminder_after_days = resolve_secs(
+        &scheduler_config.invitation_reminder_after_days,
+        "invitation reminder days",
+    )
+    .await;
+
+    let connection_string_expiry_interval = resolve_secs(
+        &scheduler_config.connection_string_expiry_interval_in_secs,
+        "connection string expiry interval",
+    )
+    .await;
+
+    let connection_string_expiry_warning_days = resolve_secs(
+        &scheduler_config.connection_string_expiry_warning_days,
+        "connection string expiry warning days",
+    )
+    .await;
+
+    let tenant_digest_interval = resolve_secs(
+        &scheduler_config.tenant_digest_interval_in_secs,
+        "tenant digest interval",
+    )
+    .await;
+
+    let guest_grant_expiry_warning_interval = resolve_secs(
+        &scheduler_config.guest_grant_expiry_warning_interval_in_secs,
+        "guest grant expiry warning interval",
+    )
+    .await;
+
+    let guest_grant_expiry_warning_days = resolve_secs(
+        &scheduler_config.guest_grant_expiry_warning_days,
+        "guest grant expiry warning days",
+    )
+    .await;
+
+    let guest_grant_sweep_interval = resolve_secs(
+        &scheduler_config.guest_grant_sweep_interval_in_secs,
+        "guest grant sweep interval",
+    )
+    .await;
+
+    let invitation_sweep_interval = resolve_secs(
+        &scheduler_config.invitation_sweep_interval_in_secs,
+        "invitation sweep interval",
+    )
+    .await;
+
+    let tenant_trash_retention_days = resolve_secs(
+        &scheduler_config.tenant_trash_retention_days,
+        "tenant trash retention days",
+    )
+    .await;
+
+    let tenant_purge_warning_days = resolve_secs(
+        &scheduler_config.tenant_purge_warning_days,
+        "tenant purge warning days",
+    )
+    .await;
+
+    let tenant_purge_warning_interval = resolve_secs(
+        &scheduler_config.tenant_purge_warning_interval_in_secs,
+        "tenant purge warning interval",
+    )
+    .await;
+
+    let tenant_purge_interval = resolve_secs(
+        &scheduler_config.tenant_purge_interval_in_secs,
+        "tenant purge interval",
+    )
+    .await;
+
+    //
+    // Identifies the replica in the claimed runs. Pods get their name as the
+    // hostname, the suffix keeps restarted replicas apart.
+    //
+    let claimed_by = format!(
+        "{}-{}",
+        std::env::var("HOSTNAME").unwrap_or("api".to_string()),
+        Uuid::new_v4()
+    );
+
+    tokio::spawn(async move {
+        tracing::info!(claimed_by, "Starting scheduler dispatcher");
+
+        let claiming_repo: &dyn ScheduledJobClaiming =
+            app_modules.resolve_ref();
+        let guest_user_fetching_repo: &dyn GuestUserFetching =
+            app_modules.resolve_ref();
+        let guest_user_deletion_repo: &dyn GuestUserDeletion =
+            app_modules.resolve_ref();
+        let token_fetching_repo: &dyn TokenFetching = app_modules.resolve_ref();
+        let audit_fetching_repo: &dyn ResourceAuditLogFetching =
+            app_modules.resolve_ref();
+        let audit_registration_repo: &dyn ResourceAuditLogRegistration =
+            app_modules.resolve_ref();
+        let message_write_repo: &dyn LocalMessageWrite =
+            app_modules.resolve_ref();
+        let suppression_repo: &dyn EmailSuppressionFetching =
+            app_modules.resolve_ref();
+        let tenant_fetching_repo: &dyn TenantFetching =
+            app_modules.resolve_ref();
+        let tenant_deletion_repo: &dyn TenantDeletion =
+            app_modules.resolve_ref();
+        let recipient_repo: &dyn NotificationRecipientFetching =
+            app_modules.resolve_ref();
+        let enc_key_repo: &dyn EncryptionKeyFetching =
+            app_modules.resolve_ref();
+        let webhook_registration_repo: &dyn WebHookRegistration =
+            app_modules.resolve_ref();
+
+        let mut ticker =
+            tokio::time::interval(Duration::from_secs(tick_interval.max(1)));
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            if invitation_reminder_interval > 0
+                && invitation_reminder_after_days > 0
+            {
+                match send_invitation_reminders(
+                    claimed_by.to_owned(),
+                    chrono::Duration::seconds(
+                        invitation_reminder_interval as i64,
+                    ),
+                    chrono::Duration::days(
+                        invitation_reminder_after_days as i64,
+                    ),
+                    config.account_life_cycle.to_owned(),
+                    Box::new(claiming_repo),
+                    Box::new(guest_user_fetching_repo),
+                    Box::new(message_write_repo),
+                    Box::new(suppression_repo),
+                    Box::new(tenant_fetching_repo),
+                    Box::new(recipient_repo),
+                    Box::new(enc_key_repo),
+                )
+                .await
+                {
+                    Ok(Some(count)) => tracing::info!(
+                        count,
+                        "scheduler_dispatcher: reminded invitations"
+                    ),
+                    Ok(None) => (),
+                    Err(err) => {
+                        tracing::error!("Error on remind invitations: {err}")
+                    }
+                }
+            }
+
+            if connection_string_expiry_interval > 0
+                && connection_string_expiry_warning_days > 0
+            {
+                match send_connection_string_expiry_warnings(
+                    claimed_by.to_owned(),
+                    chrono::Duration::seconds(
+                        connection_string_expiry_interval as i64,
+                    ),
+                    chrono::Duration::days(
+                        connection_string_expiry_warning_days as i64,
+                    ),
+                    config.account_life_cycle.to_owned(),
+                    Box::new(claiming_repo),
+                    Box::new(token_fetching_repo),
+                    Box::new(message_write_repo),
+                    Box::new(suppression_repo),
+                    Box::new(tenant_fetching_repo),
+                )
+                .await
+                {
+                    Ok(Some(count)) => tracing::info!(
+                        count,
+                        "scheduler_dispatcher: warned expiring connection strings"
+                    ),
+                    Ok(None) => (),
+                    Err(err) => tracing::error!(
+                        "Error on warn expiring connection strings: {err}"
+                    ),
+                }
+            }
+
+            if tenant_digest_interval > 0 {
+                match send_tenant_membership_digests(
+                    claimed_by.to_owned(),
+                    chrono::Duration::seconds(tenant_digest_interval as i64),
+                    config.account_life_cycle.to_owned(),
+                    Box::new(claiming_repo),
+                    Box::new(audit_fetching_repo),
+                    Box::new(message_write_repo),
+                    Box::new(suppression_repo),
+                    Box::new(tenant_fetching_repo),
+                )
+                .await
+                {
+                    Ok(Some(count)) => tracing::info!(
+                        count,
+                        "scheduler_dispatcher: sent tenant membership digests"
+                    ),
+                    Ok(None) => (),
+                    Err(err) => tracing::error!(
+                        "Error on send tenant membership digests: {err}"
+                    ),
+                }
+            }
+
+            if guest_grant_expiry_warning_interval > 0
+                && guest_grant_expiry_warning_days > 0
+            {
+                match send_guest_grant_expiry_warnings(
+                    claimed_by.to_owned(),
+                    chrono::Duration::seconds(
+                        guest_grant_expiry_warning_interval as i64,
+                    ),
+                    chrono::Duration::days(
+                        guest_grant_expiry_warning_days as i64,
+                    ),
+                    config.account_life_cycle.to_owned(),
+                    Box::new(claiming_repo),
+                    Box::new(guest_user_fetching_repo),
+                    Box::new(message_write_repo),
+                    Box::new(suppression_repo),
+                    Box::new(tenant_fetching_repo),
+                    Box::new(recipient_repo),
+                    Box::new(enc_key_repo),
+                )
+                .await
+                {
+                    Ok(Some(count)) => tracing::info!(
+                        count,
+                        "scheduler_dispatcher: warned expiring guest grants"
+                    ),
+                    Ok(None) => (),
+                    Err(err) => tracing::error!(
+                        "Error on warn expiring guest grants: {err}"
+                    ),
+                }
+            }
+
+            if guest_grant_sweep_interval > 0 {
+                match sweep_expired_guest_grants(
+                    claimed_by.to_owned(),
+                    chrono::Duration::seconds(
+                        guest_grant_sweep_interval as i64,
+                    ),
+                    Box::new(claiming_repo),
+                    Box::new(guest_user_fetching_repo),
+                    Box::new(guest_user_deletion_repo),
+                    Box::new(audit_registration_repo),
+                )
+                .await
+                {
+                    Ok(Some(count)) => tracing::info!(
+                        count,
+                        "scheduler_dispatcher: revoked expired guest grants"
+                    ),
+                    Ok(None) => (),
+                    Err(err) => tracing::error!(
+                        "Error on revoke expired guest grants: {err}"
+                    ),
+                }
+            }
+
+            if invitation_sweep_interval > 0 {
+                match sweep_expired_guest_invitations(
+                    claimed_by.to_owned(),
+                    chrono::Duration::seconds(invitation_sweep_interval as i64),
+                    Box::new(claiming_repo),
+                    Box::new(guest_user_fetching_repo),
+                    Box::new(guest_user_deletion_repo),
+                    Box::new(webhook_registration_repo),
+                    Box::new(audit_registration_repo),
+                )
+                .await
+                {
+                    Ok(Some(count)) => tracing::info!(
+                        count,
+                        "scheduler_dispatcher: removed expired invitations"
+                    ),
+                    Ok(None) => (),
+                    Err(err) => tracing::error!(
+                        "Error on remove expired invitations: {err}"
+                    ),
+                }
+            }
+
+            if tenant_purge_warning_interval > 0
+                && tenant_purge_warning_days > 0
+                && tenant_trash_retention_days > 0
+            {
+                match send_tenant_purge_warnings(
+                    claimed_by.to_owned(),
+                    chrono::Duration::seconds(
+                        tenant_purge_warning_interval as i64,
+                    ),
+                    chrono::Duration::days(tenant_trash_retention_days as i64),
+                    chrono::Duration::days(tenant_purge_warning_days as i64),
+                    config.account_life_cycle.to_owned(),
+                    Box::new(claiming_repo),
+                    Box::new(tenant_fetching_repo),
+                    Box::new(message_write_repo),
+                    Box::new(suppression_repo),
+                )
+                .await
+                {
+                    Ok(Some(count)) => tracing::info!(
+                        count,
+                        "scheduler_dispatcher: warned tenant purges"
+                    ),
+                    Ok(None) => (),
+                    Err(err) => {
+                        tracing::error!("Error on warn tenant purges: {err}")
+                    }
+                }
+            }
+
+            if tenant_purge_interval > 0 && tenant_trash_retention_days > 0 {
+                match purge_trashed_tenants(
+                    claimed_by.to_owned(),
+                    chrono::Duration::seconds(tenant_purge_interval as i64),
+                    chrono::Duration::days(tenant_trash_retention_days as i64),
+                    Box::new(claiming_repo),
+                    Box::new(tenant_fetching_repo),
+                    Box::new(tenant_deletion_repo),
+                    Box::new(audit_registration_repo),
+                )
+                .await
+                {
+                    Ok(Some(count)) => tracing::info!(
+                        count,
+                        "scheduler_dispatcher: purged trashed tenants"
+                    ),
+                    Ok(None) => (),
+                    Err(err) => {
+                        tracing::error!("Error on purge trashed tenants: {err}")
+                    }
+                }
+            }
+        }
+    });
+}
+
+async fn resolve_secs(value: &SecretResolver<u64>, name: &str) -> u64 {
+    match value.async_get_or_error().await {
+        Ok(value) => value,
+        Err(err) => panic!("Error on get scheduler {name}: {err}"),
+    }
+}
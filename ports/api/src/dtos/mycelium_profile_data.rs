@@ -1,6 +1,6 @@
 use crate::middleware::{
     fetch_profile_from_request_connection_string,
-    fetch_profile_from_request_token,
+    fetch_profile_from_request_token, register_account_locale,
 };
 
 use actix_web::{dev::Payload, FromRequest, HttpRequest};
@@ -164,16 +164,31 @@ impl FromRequest for MyceliumProfileData {
         {
             if !connection_string.is_empty() {
                 return Box::pin(async move {
-                    fetch_profile_from_request_connection_string(
-                        req_clone, tenant, roles,
+                    let profile = fetch_profile_from_request_connection_string(
+                        req_clone.to_owned(),
+                        tenant,
+                        roles,
                     )
-                    .await
+                    .await?;
+
+                    register_account_locale(&req_clone, &profile);
+
+                    Ok(profile)
                 });
             }
         }
 
         Box::pin(async move {
-            fetch_profile_from_request_token(req_clone, tenant, roles).await
+            let profile = fetch_profile_from_request_token(
+                req_clone.to_owned(),
+                tenant,
+                roles,
+            )
+            .await?;
+
+            register_account_locale(&req_clone, &profile);
+
+            Ok(profile)
         })
     }
 }
@@ -1,3 +1,4 @@
+mod access_policy;
 mod callback_engines;
 mod dispatchers;
 mod dtos;
@@ -62,9 +63,11 @@ use actix_web::{
 use actix_web_opentelemetry::RequestTracing;
 use awc::{error::HeaderValue, Client};
 use dispatchers::{
-    email_dispatcher, resource_audit_log_dispatcher,
+    bulk_import_dispatcher, email_dispatcher, resource_audit_log_dispatcher,
+    resource_audit_retention_dispatcher, scheduler_dispatcher,
     services_health_dispatcher, webhook_dispatcher,
 };
+use middleware::localize_error_response;
 use models::active_backend_modules::{KVAppModule, SqlAppModule};
 use models::config_handler::ConfigHandler;
 #[cfg(feature = "full")]
@@ -76,20 +79,21 @@ use myc_adapters_shared_lib::models::{
 use myc_config::init_vault_config_from_file;
 use myc_config::optional_config::OptionalConfig;
 #[cfg(feature = "standalone")]
-use myc_config::resolve_or_generate_standalone_secret;
-#[cfg(feature = "standalone")]
 use myc_config::secret_resolver::SecretResolver;
+#[cfg(feature = "standalone")]
+use myc_config::{
+    resolve_or_generate_standalone_secret, standalone_secrets_dir,
+    STANDALONE_KEYRING_SERVICE,
+};
 use myc_core::{
     domain::{
-        dtos::{
-            callback::CallbackExecutor,
-            resource_audit_log::NewResourceAuditLogEvent,
-        },
+        dtos::callback::CallbackExecutor,
         entities::{
-            GuestRoleRegistration, InstanceSettingsFetching,
-            LocalMessageReading, LocalMessageWrite, RemoteMessageWrite,
-            ServiceRead,
+            EmailSuppressionRegistration, GuestRoleRegistration,
+            InstanceSettingsFetching, LocalMessageReading, LocalMessageWrite,
+            MessageDeliveryRegistration, RemoteMessageWrite, ServiceRead,
         },
+        utils::ResourceAuditSpill,
     },
     models::AccountLifeCycle,
     use_cases::{
@@ -166,6 +170,7 @@ use rest::{
     },
     openid::well_known_endpoints,
     role_scoped::configure as configure_standard_endpoints,
+    scim::configure as configure_scim_endpoints,
     service::tools_endpoints as service_tools_endpoints,
     shared::insert_role_header,
     staff::account_endpoints as staff_account_endpoints,
@@ -244,14 +249,11 @@ pub async fn main() -> std::io::Result<()> {
             Err(err) => panic!("Error on get sqlite path: {err}"),
         };
 
-        let secrets_dir = std::path::Path::new(&sqlite_path)
-            .parent()
-            .map(|parent| parent.join(".secrets"))
-            .unwrap_or_else(|| PathBuf::from(".secrets"));
+        let secrets_dir = standalone_secrets_dir(&sqlite_path);
 
         let token_secret = match resolve_standalone_secret(
             Some(config.core.account_life_cycle.token_secret_resolver()),
-            "mycelium-standalone",
+            STANDALONE_KEYRING_SERVICE,
             &secrets_dir,
             "token_secret",
         )
@@ -266,7 +268,7 @@ pub async fn main() -> std::io::Result<()> {
                 .core
                 .account_life_cycle
                 .primary_hmac_secret_resolver(),
-            "mycelium-standalone",
+            STANDALONE_KEYRING_SERVICE,
             &secrets_dir,
             "hmac_secret",
         )
@@ -289,7 +291,7 @@ pub async fn main() -> std::io::Result<()> {
         if let OptionalConfig::Enabled(internal) = &config.auth.internal {
             let jwt_secret = match resolve_standalone_secret(
                 Some(&internal.jwt_secret),
-                "mycelium-standalone",
+                STANDALONE_KEYRING_SERVICE,
                 &secrets_dir,
                 "jwt_secret",
             )
@@ -358,7 +360,7 @@ pub async fn main() -> std::io::Result<()> {
         notifier_module,
         kv_module,
         mem_module,
-        resource_audit_log_rx,
+        resource_audit_spill,
     ) = initialize_modules(&config.to_owned())
         .await
         .map_err(|err| {
@@ -373,7 +375,7 @@ pub async fn main() -> std::io::Result<()> {
         notifier_module,
         kv_module,
         mem_module,
-        resource_audit_log_rx,
+        resource_audit_spill,
     ) = initialize_modules(&config.to_owned())
         .await
         .map_err(|err| {
@@ -437,6 +439,8 @@ pub async fn main() -> std::io::Result<()> {
         HasComponent::<dyn LocalMessageReading>::resolve(&*sql_module),
         HasComponent::<dyn LocalMessageWrite>::resolve(&*sql_module),
         HasComponent::<dyn RemoteMessageWrite>::resolve(&*notifier_module),
+        HasComponent::<dyn MessageDeliveryRegistration>::resolve(&*sql_module),
+        HasComponent::<dyn EmailSuppressionRegistration>::resolve(&*sql_module),
     )
     .instrument(span.to_owned())
     .await;
@@ -464,7 +468,56 @@ pub async fn main() -> std::io::Result<()> {
     // ? -----------------------------------------------------------------------
     info!("Fire resource audit log dispatcher");
 
-    resource_audit_log_dispatcher(sql_module.clone(), resource_audit_log_rx)
+    resource_audit_log_dispatcher(
+        config.core.to_owned(),
+        sql_module.clone(),
+        resource_audit_spill,
+    )
+    .instrument(span.to_owned())
+    .await;
+
+    // ? -----------------------------------------------------------------------
+    // ? FIRE THE RESOURCE AUDIT RETENTION DISPATCHER
+    //
+    // The resource audit retention dispatcher should be fired to allow
+    // expired resource audit log rows to be archived. Dispatching will occur
+    // in a separate thread.
+    //
+    // ? -----------------------------------------------------------------------
+    info!("Fire resource audit retention dispatcher");
+
+    resource_audit_retention_dispatcher(
+        config.core.to_owned(),
+        sql_module.clone(),
+    )
+    .instrument(span.to_owned())
+    .await;
+
+    // ? -----------------------------------------------------------------------
+    // ? FIRE THE SCHEDULER DISPATCHER
+    //
+    // The scheduler dispatcher should be fired to allow the scheduled
+    // notifications (invitation reminders, connection string expiry warnings
+    // and tenant digests) to be sent. Dispatching will occur in a separate
+    // thread.
+    //
+    // ? -----------------------------------------------------------------------
+    info!("Fire scheduler dispatcher");
+
+    scheduler_dispatcher(config.core.to_owned(), sql_module.clone())
+        .instrument(span.to_owned())
+        .await;
+
+    // ? -----------------------------------------------------------------------
+    // ? FIRE THE BULK IMPORT DISPATCHER
+    //
+    // The bulk import dispatcher should be fired to allow the submitted bulk
+    // imports to be run. Dispatching will occur in a separate thread.
+    //
+    // ? -----------------------------------------------------------------------
+    info!("Fire bulk import dispatcher");
+
+    bulk_import_dispatcher(config.core.to_owned(), sql_module.clone())
         .instrument(span.to_owned())
         .await;
 
@@ -560,6 +613,14 @@ pub async fn main() -> std::io::Result<()> {
             .wrap(RequestTracing::default())
             .wrap(TracingLogger::default())
             //
+            // Translate the error messages to the caller locale
+            //
+            .wrap_fn(|req, srv| {
+                let res = srv.call(req);
+
+                async move { localize_error_response(res.await?).await }
+            })
+            //
             // Inject configuration
             //
             .app_data(web::Data::new(openrpc_spec_config))
@@ -786,6 +847,11 @@ pub async fn main() -> std::io::Result<()> {
                     .configure(configure_telegram_endpoints),
             )
             //
+            // SCIM 2.0 provisioning endpoints (authenticated by the SCIM
+            // credentials of the tenants, not by a Mycelium profile)
+            //
+            .service(web::scope("/scim/v2").configure(configure_scim_endpoints))
+            //
             // Configure gateway routes
             //
             .app_data(web::Data::new(Client::new()))
@@ -980,6 +1046,8 @@ async fn build_mem_db_module(config: &ConfigHandler) -> Arc<MemDbAppModule> {
         trace!("Service: {:?}", service);
     }
 
+    access_policy::compile_route_policies(&config.api.services);
+
     // ? -----------------------------------------------------------------------
     // ? CREATE CALLBACK ENGINES FROM CONFIGURED CALLBACKS
     //
@@ -1029,6 +1097,22 @@ async fn build_mem_db_module(config: &ConfigHandler) -> Arc<MemDbAppModule> {
     )
 }
 
+/// Open the resource audit spill file shared by the audit log registration
+/// repository and `resource_audit_log_dispatcher`.
+///
+/// Backend-agnostic, so used by every `initialize_modules` variant.
+async fn build_resource_audit_spill(
+    config: &ConfigHandler,
+) -> Result<Arc<ResourceAuditSpill>, MappedErrors> {
+    let spill_path =
+        match config.core.audit.spill_path.async_get_or_error().await {
+            Ok(path) => path,
+            Err(err) => panic!("Error on get audit spill path: {err}"),
+        };
+
+    Ok(Arc::new(ResourceAuditSpill::open(spill_path)?))
+}
+
 /// Initialize the modules for the application (full mode: PostgreSQL +
 /// Redis + SMTP).
 ///
@@ -1052,12 +1136,11 @@ async fn initialize_modules(
         Arc<NotifierAppModule>,
         Arc<KVAppModule>,
         Arc<MemDbAppModule>,
-        tokio::sync::mpsc::Receiver<NewResourceAuditLogEvent>,
+        Arc<ResourceAuditSpill>,
     ),
     MappedErrors,
 > {
-    let (resource_audit_log_tx, resource_audit_log_rx) =
-        tokio::sync::mpsc::channel::<NewResourceAuditLogEvent>(2048);
+    let resource_audit_spill = build_resource_audit_spill(config).await?;
 
     let visibility_timeout_secs = match config
         .queue
@@ -1096,7 +1179,7 @@ async fn initialize_modules(
             )
             .with_component_parameters::<ResourceAuditLogRegistrationSqlDbRepository>(
                 ResourceAuditLogRegistrationSqlDbRepositoryParameters {
-                    sender: resource_audit_log_tx,
+                    spill: resource_audit_spill.clone(),
                 },
             )
             .build(),
@@ -1166,7 +1249,7 @@ async fn initialize_modules(
         notifier_module,
         kv_module,
         mem_module,
-        resource_audit_log_rx,
+        resource_audit_spill,
     ))
 }
 
@@ -1185,7 +1268,7 @@ async fn initialize_modules(
         Arc<LocalNotifierAppModule>,
         Arc<KVAppModule>,
         Arc<MemDbAppModule>,
-        tokio::sync::mpsc::Receiver<NewResourceAuditLogEvent>,
+        Arc<ResourceAuditSpill>,
     ),
     MappedErrors,
 > {
@@ -1198,8 +1281,7 @@ async fn initialize_modules(
         panic!("Error provisioning SQLite database: {err}");
     }
 
-    let (resource_audit_log_tx, resource_audit_log_rx) =
-        tokio::sync::mpsc::channel::<NewResourceAuditLogEvent>(2048);
+    let resource_audit_spill = build_resource_audit_spill(config).await?;
 
     let sql_module = Arc::new(
         SqlAppModule::builder()
@@ -1210,7 +1292,7 @@ async fn initialize_modules(
             )
             .with_component_parameters::<ResourceAuditLogRegistrationSqlDbRepository>(
                 ResourceAuditLogRegistrationSqlDbRepositoryParameters {
-                    sender: resource_audit_log_tx,
+                    spill: resource_audit_spill.clone(),
                 },
             )
             .build(),
@@ -1278,7 +1360,7 @@ async fn initialize_modules(
         notifier_module,
         kv_module,
         mem_module,
-        resource_audit_log_rx,
+        resource_audit_spill,
     ))
 }
 
@@ -1300,12 +1382,11 @@ async fn initialize_modules(
         Arc<LocalNotifierAppModule>,
         Arc<KVAppModule>,
         Arc<MemDbAppModule>,
-        tokio::sync::mpsc::Receiver<NewResourceAuditLogEvent>,
+        Arc<ResourceAuditSpill>,
     ),
     MappedErrors,
 > {
-    let (resource_audit_log_tx, resource_audit_log_rx) =
-        tokio::sync::mpsc::channel::<NewResourceAuditLogEvent>(2048);
+    let resource_audit_spill = build_resource_audit_spill(config).await?;
 
     // ? Build the Postgres pool once and share it between the SQL module and
     // ? the Postgres KV cache adapter -- a single pool, no second set of
@@ -1340,7 +1421,7 @@ async fn initialize_modules(
             )
             .with_component_parameters::<ResourceAuditLogRegistrationSqlDbRepository>(
                 ResourceAuditLogRegistrationSqlDbRepositoryParameters {
-                    sender: resource_audit_log_tx,
+                    spill: resource_audit_spill.clone(),
                 },
             )
             .build(),
@@ -1384,6 +1465,6 @@ async fn initialize_modules(
         notifier_module,
         kv_module,
         mem_module,
-        resource_audit_log_rx,
+        resource_audit_spill,
     ))
 }
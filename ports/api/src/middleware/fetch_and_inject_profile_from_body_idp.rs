@@ -42,7 +42,7 @@ pub(crate) async fn fetch_and_inject_profile_from_body_idp(
         .resolve_email(&body_idp.user_id, &req)
         .await?;
 
-    let profile =
+    let mut profile =
         recovery_profile_from_storage_engines(req, email, None, roles.clone())
             .await?;
 
@@ -50,6 +50,11 @@ pub(crate) async fn fetch_and_inject_profile_from_body_idp(
         enforce_rbac(&profile, r)?;
     }
 
+    if let SecurityGroup::ProtectedByPermissions(permissions) = security_group {
+        profile = profile.with_named_permissions(permissions.to_owned());
+        enforce_rbac(&profile, &[])?;
+    }
+
     let encoded = compress_and_encode_profile_to_base64(profile.clone())?;
 
     downstream_request.headers_mut().insert(
@@ -22,6 +22,9 @@ use uuid::Uuid;
 /// and, then find the profile from the email and inject profile into the
 /// forward request.
 ///
+/// When `permissions` is given, the licensed resources are filtered to the ones
+/// granting all the named permissions.
+///
 /// These use-case is usual over middleware or routers parts of the application.
 #[tracing::instrument(
     name = "fetch_and_inject_profile_from_token_to_forward", 
@@ -43,6 +46,7 @@ pub async fn fetch_and_inject_profile_from_token_to_forward(
     mut forwarded_req: ClientRequest,
     tenant: Option<Uuid>,
     roles: Option<Vec<PermissionedRole>>,
+    permissions: Option<Vec<String>>,
     service_name: String,
 ) -> Result<(ClientRequest, Profile), GatewayError> {
     let span = tracing::Span::current();
@@ -73,6 +77,12 @@ pub async fn fetch_and_inject_profile_from_token_to_forward(
             .await?
     };
 
+    let mut profile = profile.to_profile();
+
+    if let Some(ref permissions) = permissions {
+        profile = profile.with_named_permissions(permissions.to_owned());
+    }
+
     tracing::debug!("Profile: {:?}", profile);
 
     span.record("myc.router.profile_id", &Some(profile.acc_id.to_string()))
@@ -88,15 +98,13 @@ pub async fn fetch_and_inject_profile_from_token_to_forward(
             &Some(profile.licensed_resources.is_some()),
         );
 
-    if let Some(_) = roles {
-        if profile.licensed_resources.is_none()
-            && (!profile.is_manager && !profile.is_staff)
-        {
-            return Err(GatewayError::Forbidden(
-                "User does not have permission to perform this action"
-                    .to_string(),
-            ));
-        }
+    if (roles.is_some() || permissions.is_some())
+        && profile.licensed_resources.is_none()
+        && (!profile.is_manager && !profile.is_staff)
+    {
+        return Err(GatewayError::Forbidden(
+            "User does not have permission to perform this action".to_string(),
+        ));
     }
 
     // Get a meter
@@ -122,7 +130,7 @@ pub async fn fetch_and_inject_profile_from_token_to_forward(
     forwarded_req.headers_mut().insert(
         HeaderName::from_str(DEFAULT_PROFILE_KEY).unwrap(),
         HeaderValue::from_str(&compress_and_encode_profile_to_base64(
-            profile.to_profile(),
+            profile.to_owned(),
         )?)
         .unwrap(),
     );
@@ -133,5 +141,5 @@ pub async fn fetch_and_inject_profile_from_token_to_forward(
         "Profile injected into downstream request"
     );
 
-    Ok((forwarded_req, profile.to_profile()))
+    Ok((forwarded_req, profile))
 }
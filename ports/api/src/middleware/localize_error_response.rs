@@ -0,0 +1,165 @@
+use crate::{
+    dtos::MyceliumProfileData,
+    models::{
+        active_backend_modules::{KVAppModule, SqlAppModule},
+        api_config::ApiConfig,
+    },
+};
+
+use actix_web::{
+    body::{to_bytes, EitherBody, MessageBody},
+    dev::ServiceResponse,
+    error::ErrorInternalServerError,
+    http::header::{
+        HeaderValue, ACCEPT_LANGUAGE, CONTENT_LANGUAGE, CONTENT_LENGTH,
+        CONTENT_TYPE,
+    },
+    web, Error, HttpMessage, HttpRequest,
+};
+use myc_core::use_cases::translate_error_code;
+use myc_http_tools::{
+    settings::DEFAULT_TENANT_ID_KEY, utils::HttpJsonResponse,
+    wrappers::default_response_to_http_response::MappedErrorCode,
+    AccountMetaKey,
+};
+use serde_json::Value;
+use shaku::HasComponent;
+use tracing::warn;
+use uuid::Uuid;
+
+/// The `locale` meta of the account which performed the request
+///
+/// Stored in the request extensions by the `MyceliumProfileData` extractor.
+#[derive(Clone, Debug)]
+pub(crate) struct AccountLocale(pub String);
+
+/// Remember the account locale of a resolved profile for the error responses
+pub(crate) fn register_account_locale(
+    req: &HttpRequest,
+    profile: &MyceliumProfileData,
+) {
+    if let Some(locale) = profile
+        .meta
+        .as_ref()
+        .and_then(|meta| meta.get(&AccountMetaKey::Locale))
+    {
+        req.extensions_mut()
+            .insert(AccountLocale(locale.to_owned()));
+    }
+}
+
+/// Translate the message of the responses built by `handle_mapped_error`
+///
+/// The locale is picked from the `Accept-Language` header, the account locale
+/// or the locale of the requested tenant. Only the `msg` field of the original
+/// body is replaced, and responses keep the default message when the error
+/// code has no matching translation.
+pub(crate) async fn localize_error_response<B: MessageBody + 'static>(
+    res: ServiceResponse<B>,
+) -> Result<ServiceResponse<EitherBody<B>>, Error> {
+    let Some(MappedErrorCode(code)) = res
+        .response()
+        .extensions()
+        .get::<MappedErrorCode>()
+        .cloned()
+    else {
+        return Ok(res.map_into_left_body());
+    };
+
+    let request = res.request().clone();
+
+    let (Some(app_module), Some(kv_module)) = (
+        request.app_data::<web::Data<SqlAppModule>>().cloned(),
+        request.app_data::<web::Data<KVAppModule>>().cloned(),
+    ) else {
+        return Ok(res.map_into_left_body());
+    };
+
+    let cache_ttl = request
+        .app_data::<web::Data<ApiConfig>>()
+        .and_then(|config| config.cache.translation_ttl)
+        .unwrap_or(600);
+
+    let accept_language = request
+        .headers()
+        .get(ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+
+    let account_locale = request
+        .extensions()
+        .get::<AccountLocale>()
+        .map(|locale| locale.0.to_owned());
+
+    let tenant_id = request
+        .headers()
+        .get(DEFAULT_TENANT_ID_KEY)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| Uuid::parse_str(value).ok());
+
+    let translation = match translate_error_code(
+        code.to_owned(),
+        accept_language,
+        account_locale,
+        tenant_id,
+        cache_ttl,
+        Box::new(app_module.resolve_ref()),
+        Box::new(app_module.resolve_ref()),
+        Box::new(kv_module.resolve_ref()),
+        Box::new(kv_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(Some(translation)) => translation,
+        Ok(None) => return Ok(res.map_into_left_body()),
+        Err(err) => {
+            warn!("Unable to translate error code {code}: {err}");
+            return Ok(res.map_into_left_body());
+        }
+    };
+
+    let (req, res) = res.into_parts();
+    let (mut head, body) = res.into_parts();
+
+    let body = to_bytes(body)
+        .await
+        .map_err(|err| ErrorInternalServerError(err.into()))?;
+
+    //
+    // Merge the translated message into the original body, so fields other
+    // than the message (e.g. the error code) reach the client unchanged.
+    // Bodies which are not a JSON object are replaced by a plain message.
+    //
+    let localized = match serde_json::from_slice::<Value>(&body) {
+        Ok(Value::Object(mut fields)) => {
+            fields
+                .insert("msg".to_string(), Value::String(translation.message));
+            serde_json::to_vec(&fields)
+        }
+        _ => {
+            head.headers_mut().insert(
+                CONTENT_TYPE,
+                HeaderValue::from_static("application/json"),
+            );
+
+            serde_json::to_vec(
+                &HttpJsonResponse::new_message(translation.message)
+                    .with_code(code),
+            )
+        }
+    }
+    .map_err(ErrorInternalServerError)?;
+
+    let headers = head.headers_mut();
+    headers.remove(CONTENT_LENGTH);
+
+    if let Ok(locale) = HeaderValue::from_str(&translation.locale) {
+        headers.insert(CONTENT_LANGUAGE, locale);
+    }
+
+    Ok(ServiceResponse::new(
+        req,
+        head.set_body(localized).map_into_boxed_body(),
+    )
+    .map_into_right_body())
+}
@@ -7,6 +7,7 @@ mod fetch_connection_string_from_request;
 mod fetch_profile_from_request_connection_string;
 mod fetch_profile_from_request_token;
 mod get_email_or_provider_from_request;
+mod localize_error_response;
 mod parse_issuer_from_request;
 mod recovery_profile_from_storage_engines;
 
@@ -18,6 +19,7 @@ pub(crate) use fetch_and_inject_profile_from_token_to_forward::*;
 pub(crate) use fetch_connection_string_from_request::*;
 pub(crate) use fetch_profile_from_request_connection_string::*;
 pub(crate) use fetch_profile_from_request_token::*;
+pub(crate) use localize_error_response::*;
 pub(crate) use parse_issuer_from_request::*;
 pub(crate) use recovery_profile_from_storage_engines::*;
 
@@ -4,6 +4,7 @@ use crate::models::active_backend_modules::KVAppModule;
 use crate::models::active_backend_modules::SqlAppModule;
 use actix_web::{web, HttpRequest};
 use base64::{engine::general_purpose, Engine};
+use chrono::Local;
 use hex;
 use myc_core::{
     domain::{
@@ -284,11 +285,28 @@ async fn cache_profile(search_key: String, profile: Profile, req: HttpRequest) {
         }
     };
 
-    let ttl = if let Some(api_config) = req.app_data::<web::Data<ApiConfig>>() {
-        api_config.cache.profile_ttl.unwrap_or(60)
-    } else {
-        60
-    };
+    let mut ttl =
+        if let Some(api_config) = req.app_data::<web::Data<ApiConfig>>() {
+            api_config.cache.profile_ttl.unwrap_or(60)
+        } else {
+            60
+        };
+
+    //
+    // A cached profile must not outlive its earliest grant. Otherwise an
+    // expired license would still be served until the cache entry expires.
+    //
+    if let Some(valid_until) = profile.licenses_valid_until() {
+        let remaining = (valid_until - Local::now()).num_seconds();
+
+        if remaining <= 0 {
+            tracing::trace!("Profile has expired grants, skipping cache");
+
+            return;
+        }
+
+        ttl = ttl.min(remaining as u64);
+    }
 
     let kv_artifact_write: &dyn KVArtifactWrite = app_module.resolve_ref();
 
@@ -98,14 +98,20 @@ pub struct CacheConfig {
     ///
     /// The time to live for the profile cache.
     pub profile_ttl: Option<u64>,
+
+    /// Translation TTL
+    ///
+    /// The time to live for the error code translation cache.
+    pub translation_ttl: Option<u64>,
 }
 
 impl Default for CacheConfig {
     fn default() -> Self {
         Self {
-            jwks_ttl: Some(60 * 60 * 12), // 12 hours
-            email_ttl: Some(60 * 10),     // 10 minutes
-            profile_ttl: Some(60 * 10),   // 10 minutes
+            jwks_ttl: Some(60 * 60 * 12),   // 12 hours
+            email_ttl: Some(60 * 10),       // 10 minutes
+            profile_ttl: Some(60 * 10),     // 10 minutes
+            translation_ttl: Some(60 * 10), // 10 minutes
         }
     }
 }
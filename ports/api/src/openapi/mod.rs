@@ -1,10 +1,14 @@
 use crate::modifiers::security::MyceliumSecurity;
-use crate::rest::{audit, index, manager, role_scoped, service, staff};
+use crate::rest::{
+    audit, index, manager, role_scoped, scim as scim_endpoints, service, staff,
+};
 
 use myc_core::domain::dtos::{
-    account, account_type, email, error_code, guest_role, guest_user,
-    http_secret, profile, resource_audit_log, route, service as service_dtos,
-    tag, tenant, token, user, webhook,
+    access_policy, account, account_type, bulk_import, data_subject, email,
+    email_delivery, email_template, error_code, guest_role, guest_user,
+    guest_user_on_account, http_secret, notification, profile,
+    resource_audit_log, route, scim, service as service_dtos, tag, tenant,
+    token, user, webhook,
 };
 use myc_http_tools::{utils::HttpJsonResponse, SystemActor};
 use mycelium_base::dtos::{Children, Parent};
@@ -32,20 +36,27 @@ use role_scoped::gateway_manager::service_endpoints as Gateway_Manager__Service;
 use role_scoped::gateway_manager::tools_endpoints as Gateway_Manager__Tools;
 use role_scoped::guest_manager::guest_role_endpoints as Guest_Manager__Guest_Role;
 use role_scoped::subscriptions_manager::account_endpoints as Subscriptions_Manager__Account;
+use role_scoped::subscriptions_manager::bulk_import_endpoints as Subscriptions_Manager__Bulk_Import;
 use role_scoped::subscriptions_manager::guest_endpoints as Subscriptions_Manager__Guest;
 use role_scoped::subscriptions_manager::guest_role_endpoints as Subscriptions_Manager__Guest_Role;
 use role_scoped::subscriptions_manager::tag_endpoints as Subscriptions_Manager__Tag;
+use role_scoped::system_manager::email_template_endpoints as System_Manager__Email_Template;
 use role_scoped::system_manager::error_code_endpoints as System_Manager__Error_Code;
 use role_scoped::system_manager::webhook_endpoints as System_Manager__Webhook;
 use role_scoped::tenant_manager::account_endpoints as Tenant_Manager__Account;
 use role_scoped::tenant_manager::guest_endpoints as Tenant_Manager__Guest;
+use role_scoped::tenant_manager::guest_role_endpoints as Tenant_Manager__Guest_Role;
 use role_scoped::tenant_manager::tag_endpoints as Tenant_Manager__Tag;
 use role_scoped::tenant_manager::tenant_endpoints as Tenant_Manager__Tenant;
 use role_scoped::tenant_owner::account_endpoints as Tenant_Owner__Account;
+use role_scoped::tenant_owner::email_template_endpoints as Tenant_Owner__Email_Template;
 use role_scoped::tenant_owner::meta_endpoints as Tenant_Owner__Meta;
 use role_scoped::tenant_owner::owner_endpoints as Tenant_Owner__Owner;
+use role_scoped::tenant_owner::scim_credential_endpoints as Tenant_Owner__Scim_Credential;
 use role_scoped::tenant_owner::tenant_endpoints as Tenant_Owner__Tenant;
 use role_scoped::users_manager::account_endpoints as Users_Manager__Account;
+use role_scoped::users_manager::email_endpoints as Users_Manager__Email;
+use scim_endpoints as Scim;
 use service::tools_endpoints as Service__Tools;
 use staff::account_endpoints as Staffs__Accounts;
 
@@ -123,10 +134,12 @@ struct StaffsAccountsApiDoc;
 #[openapi(
     info(
         title = "Audit | Resource Trail Endpoints",
-        description = "Fetch the immutable audit trail of a resource. Visibility depends on the caller's standing (staff, tenant owner/manager, or personal-account owner).",
+        description = "Fetch, search and export the immutable audit trail. Visibility depends on the caller's standing (staff, tenant owner/manager, or personal-account owner).",
     ),
     paths(
         Audit__Resource_Trail::fetch_resource_audit_trail_url,
+        Audit__Resource_Trail::search_resource_audit_trail_url,
+        Audit__Resource_Trail::export_resource_audit_trail_url,
     ),
     security(("Bearer" = [], "ConnectionString" = [])),
 )]
@@ -144,6 +157,35 @@ struct AuditResourceTrailApiDoc;
 )]
 struct ServiceToolsApiDoc;
 
+/// SCIM 2.0 Provisioning Endpoints
+///
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "SCIM | Provisioning Endpoints",
+        description = "Endpoints reserved for the SCIM clients of the tenants to provision users and groups",
+    ),
+    paths(
+        Scim::service_provider_config_url,
+        Scim::resource_types_url,
+        Scim::schemas_url,
+        Scim::list_scim_users_url,
+        Scim::create_scim_user_url,
+        Scim::get_scim_user_url,
+        Scim::replace_scim_user_url,
+        Scim::patch_scim_user_url,
+        Scim::delete_scim_user_url,
+        Scim::list_scim_groups_url,
+        Scim::create_scim_group_url,
+        Scim::get_scim_group_url,
+        Scim::replace_scim_group_url,
+        Scim::patch_scim_group_url,
+        Scim::delete_scim_group_url,
+    ),
+    security(("Bearer" = [])),
+)]
+struct ScimApiDoc;
+
 /// Account Manager Endpoints for Guests Management
 ///
 #[derive(OpenApi)]
@@ -185,6 +227,8 @@ struct AccountManagerGuestRoleApiDoc;
         Beginners__Account::create_default_account_url,
         Beginners__Account::update_own_account_name_url,
         Beginners__Account::get_my_account_details_url,
+        Beginners__Account::export_my_data_url,
+        Beginners__Account::erase_my_data_url,
         Beginners__Account::delete_my_account_url,
     ),
     security(("Bearer" = [], "ConnectionString" = []))
@@ -203,6 +247,7 @@ struct BeginnersAccountApiDoc;
         Beginners__Meta::create_account_meta_url,
         Beginners__Meta::update_account_meta_url,
         Beginners__Meta::delete_account_meta_url,
+        Beginners__Meta::update_notification_preferences_url,
     ),
     security(("Bearer" = [], "ConnectionString" = []))
 )]
@@ -283,6 +328,7 @@ struct BeginnersTokenApiDoc;
     ),
     paths(
         Gateway_Manager__Route::list_routes_url,
+        Gateway_Manager__Route::explain_access_policy_url,
     ),
     security(("Bearer" = [], "ConnectionString" = []))
 )]
@@ -332,6 +378,7 @@ struct GatewayManagerToolsApiDoc;
         Guest_Manager__Guest_Role::delete_guest_role_url,
         Guest_Manager__Guest_Role::update_guest_role_name_and_description_url,
         Guest_Manager__Guest_Role::update_guest_role_permissions_url,
+        Guest_Manager__Guest_Role::update_guest_role_named_permissions_url,
         Guest_Manager__Guest_Role::insert_role_child_url,
         Guest_Manager__Guest_Role::remove_role_child_url,
     ),
@@ -388,8 +435,12 @@ struct SubscriptionsManagerTagApiDoc;
         Subscriptions_Manager__Guest::list_licensed_accounts_of_email_url,
         Subscriptions_Manager__Guest::guest_user_url,
         Subscriptions_Manager__Guest::update_flags_from_subscription_account_url,
+        Subscriptions_Manager__Guest::extend_guest_grant_url,
         Subscriptions_Manager__Guest::uninvite_guest_url,
         Subscriptions_Manager__Guest::list_guest_on_subscription_account_url,
+        Subscriptions_Manager__Guest::list_guest_invitations_url,
+        Subscriptions_Manager__Guest::resend_guest_invitation_url,
+        Subscriptions_Manager__Guest::cancel_guest_invitation_url,
     ),
     security(("Bearer" = [], "ConnectionString" = [])),
 )]
@@ -411,6 +462,40 @@ struct SubscriptionsManagerGuestApiDoc;
 )]
 struct SubscriptionsManagerGuestRoleApiDoc;
 
+/// Role Scoped Endpoints for Subscriptions Manager for Bulk Imports
+///
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Subscriptions Manager | Bulk Import Endpoints",
+        description = "Endpoints reserved for the application subscriptions managers to import guests and subscription accounts in bulk",
+    ),
+    paths(
+        Subscriptions_Manager__Bulk_Import::submit_bulk_import_url,
+        Subscriptions_Manager__Bulk_Import::list_bulk_imports_url,
+        Subscriptions_Manager__Bulk_Import::get_bulk_import_url,
+    ),
+    security(("Bearer" = [], "ConnectionString" = [])),
+)]
+struct SubscriptionsManagerBulkImportApiDoc;
+
+/// Role Scoped Endpoints for System Manager for Email Template Management
+///
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "System Manager | Email Template Endpoints",
+        description = "Endpoints reserved for the application system managers to preview and test the notification email templates",
+    ),
+    paths(
+        System_Manager__Email_Template::list_email_templates_url,
+        System_Manager__Email_Template::render_email_template_url,
+        System_Manager__Email_Template::send_test_email_url,
+    ),
+    security(("Bearer" = [], "ConnectionString" = [])),
+)]
+struct SystemManagerEmailTemplateApiDoc;
+
 /// Role Scoped Endpoints for System Manager for Error Code Management
 ///
 #[derive(OpenApi)]
@@ -425,6 +510,9 @@ struct SubscriptionsManagerGuestRoleApiDoc;
         System_Manager__Error_Code::get_error_code_url,
         System_Manager__Error_Code::update_error_code_message_and_details_url,
         System_Manager__Error_Code::delete_error_code_url,
+        System_Manager__Error_Code::list_error_code_translations_url,
+        System_Manager__Error_Code::set_error_code_translation_url,
+        System_Manager__Error_Code::delete_error_code_translation_url,
     ),
     security(("Bearer" = [], "ConnectionString" = [])),
 )]
@@ -464,6 +552,24 @@ struct SystemManagerWebhookApiDoc;
 )]
 struct TenantOwnerAccountApiDoc;
 
+/// Role Scoped Endpoints for Tenant Owner for Email Template Management
+///
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Tenant Owner | Email Template Endpoints",
+        description = "Endpoints reserved for the application tenant owners to customize notification emails",
+    ),
+    paths(
+        Tenant_Owner__Email_Template::list_tenant_email_templates_url,
+        Tenant_Owner__Email_Template::set_tenant_email_template_url,
+        Tenant_Owner__Email_Template::delete_tenant_email_template_url,
+        Tenant_Owner__Email_Template::preview_tenant_email_template_url,
+    ),
+    security(("Bearer" = [], "ConnectionString" = [])),
+)]
+struct TenantOwnerEmailTemplateApiDoc;
+
 /// Role Scoped Endpoints for Tenant Owner for Meta Management
 ///
 #[derive(OpenApi)]
@@ -496,6 +602,23 @@ struct TenantOwnerMetaApiDoc;
 )]
 struct TenantOwnerOwnerApiDoc;
 
+/// Role Scoped Endpoints for Tenant Owner for SCIM Credential Management
+///
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Tenant Owner | SCIM Credential Endpoints",
+        description = "Endpoints reserved for the application tenant owners to manage the credentials of the SCIM clients",
+    ),
+    paths(
+        Tenant_Owner__Scim_Credential::create_scim_credential_url,
+        Tenant_Owner__Scim_Credential::list_scim_credentials_url,
+        Tenant_Owner__Scim_Credential::delete_scim_credential_url,
+    ),
+    security(("Bearer" = [], "ConnectionString" = [])),
+)]
+struct TenantOwnerScimCredentialApiDoc;
+
 /// Role Scoped Endpoints for Tenant Owner for Tenant Management
 ///
 #[derive(OpenApi)]
@@ -508,7 +631,10 @@ struct TenantOwnerOwnerApiDoc;
         Tenant_Owner__Tenant::update_tenant_name_and_description_url,
         Tenant_Owner__Tenant::update_tenant_archiving_status_url,
         Tenant_Owner__Tenant::update_tenant_trashing_status_url,
+        Tenant_Owner__Tenant::restore_trashed_tenant_url,
         Tenant_Owner__Tenant::update_tenant_verifying_status_url,
+        Tenant_Owner__Tenant::update_tenant_parent_url,
+        Tenant_Owner__Tenant::list_tenant_descendants_url,
     ),
     security(("Bearer" = [], "ConnectionString" = [])),
 )]
@@ -546,6 +672,24 @@ struct TenantManagerAccountApiDoc;
 )]
 struct TenantManagerGuestApiDoc;
 
+/// Role Scoped Endpoints for Tenant Manager for Guest Role Management
+///
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Tenant Manager | Guest Role Endpoints",
+        description = "Endpoints reserved for the application tenant managers to manage tenant guest roles",
+    ),
+    paths(
+        Tenant_Manager__Guest_Role::create_tenant_guest_role_url,
+        Tenant_Manager__Guest_Role::list_tenant_guest_roles_url,
+        Tenant_Manager__Guest_Role::update_tenant_guest_role_url,
+        Tenant_Manager__Guest_Role::delete_tenant_guest_role_url,
+    ),
+    security(("Bearer" = [], "ConnectionString" = [])),
+)]
+struct TenantManagerGuestRoleApiDoc;
+
 /// Role Scoped Endpoints for Tenant Manager for Tag Management
 ///
 #[derive(OpenApi)]
@@ -598,6 +742,23 @@ struct TenantManagerTenantApiDoc;
 )]
 struct UsersManagerAccountApiDoc;
 
+/// Role Scoped Endpoints for Users Manager for Email Delivery
+///
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Users Manager | Email Endpoints",
+        description = "Endpoints reserved for the application users managers to follow email deliveries and the suppression list",
+    ),
+    paths(
+        Users_Manager__Email::list_message_deliveries_url,
+        Users_Manager__Email::list_email_suppressions_url,
+        Users_Manager__Email::delete_email_suppression_url,
+    ),
+    security(("Bearer" = [], "ConnectionString" = [])),
+)]
+struct UsersManagerEmailApiDoc;
+
 // ? ---------------------------------------------------------------------------
 // ? MAIN ENDPOINT GROUP
 // ? ---------------------------------------------------------------------------
@@ -654,33 +815,43 @@ struct UsersManagerAccountApiDoc;
         (path = "/_adm/subscriptions-manager/tags", api = SubscriptionsManagerTagApiDoc),
         (path = "/_adm/subscriptions-manager/guests", api = SubscriptionsManagerGuestApiDoc),
         (path = "/_adm/subscriptions-manager/guest-roles", api = SubscriptionsManagerGuestRoleApiDoc),
+        (path = "/_adm/subscriptions-manager/bulk-imports", api = SubscriptionsManagerBulkImportApiDoc),
         //
         // System Manager Endpoints
         //
+        (path = "/_adm/system-manager/email-templates", api = SystemManagerEmailTemplateApiDoc),
         (path = "/_adm/system-manager/error-codes", api = SystemManagerErrorCodeApiDoc),
         (path = "/_adm/system-manager/webhooks", api = SystemManagerWebhookApiDoc),
         //
         // Tenant Owner Endpoints
         //
         (path = "/_adm/tenant-owner/accounts", api = TenantOwnerAccountApiDoc),
+        (path = "/_adm/tenant-owner/email-templates", api = TenantOwnerEmailTemplateApiDoc),
         (path = "/_adm/tenant-owner/meta", api = TenantOwnerMetaApiDoc),
         (path = "/_adm/tenant-owner/owners", api = TenantOwnerOwnerApiDoc),
+        (path = "/_adm/tenant-owner/scim-credentials", api = TenantOwnerScimCredentialApiDoc),
         (path = "/_adm/tenant-owner/tenants", api = TenantOwnerTenantApiDoc),
         //
         // Tenant Manager Endpoints
         //
         (path = "/_adm/tenant-manager/accounts", api = TenantManagerAccountApiDoc),
         (path = "/_adm/tenant-manager/guests", api = TenantManagerGuestApiDoc),
+        (path = "/_adm/tenant-manager/guest-roles", api = TenantManagerGuestRoleApiDoc),
         (path = "/_adm/tenant-manager/tags", api = TenantManagerTagApiDoc),
         (path = "/_adm/tenant-manager/tenants", api = TenantManagerTenantApiDoc),
         //
         // Users Manager Endpoints
         //
         (path = "/_adm/users-manager/accounts", api = UsersManagerAccountApiDoc),
+        (path = "/_adm/users-manager/emails", api = UsersManagerEmailApiDoc),
         //
         // Service endpoints
         //
         (path = "/tools", api = ServiceToolsApiDoc),
+        //
+        // SCIM endpoints
+        //
+        (path = "/scim/v2", api = ScimApiDoc),
     ),
     paths(
         //
@@ -703,23 +874,70 @@ struct UsersManagerAccountApiDoc;
             // APPLICATION SCHEMAS
             //
             SystemActor,
+            access_policy::AccessPolicy,
+            access_policy::AccessPolicyAttributes,
+            access_policy::AccessPolicyDecision,
+            access_policy::AccessPolicyExplanation,
+            access_policy::AccessPolicyProfileAttributes,
+            access_policy::AccessPolicyRequestAttributes,
+            access_policy::AccessPolicyRule,
+            access_policy::AccessPolicyRuleOutcome,
             account::Account,
+            bulk_import::BulkImport,
+            bulk_import::BulkImportFormat,
+            bulk_import::BulkImportRow,
+            bulk_import::BulkImportRowResult,
+            bulk_import::BulkImportRowStatus,
+            bulk_import::BulkImportStatus,
+            bulk_import::BulkImportSummary,
             account::VerboseStatus,
             account_type::AccountType,
+            data_subject::DataSubjectErasure,
+            data_subject::DataSubjectExport,
             email::Email,
+            email_delivery::EmailSuppression,
+            email_delivery::EmailSuppressionReason,
+            email_delivery::MessageDelivery,
+            email_delivery::MessageDeliveryStatus,
+            email_template::EmailTemplateInfo,
             error_code::ErrorCode,
+            error_code::ErrorCodeTranslation,
             guest_role::GuestRole,
             guest_role::Permission,
+            guest_user::GuestInvitationEvent,
+            guest_user::PendingGuestInvitation,
+            guest_user_on_account::GuestUserOnAccount,
             http_secret::HttpSecret,
+            notification::NotificationChannel,
+            notification::NotificationKind,
+            notification::NotificationPreferences,
             profile::Owner,
             profile::LicensedResource,
             profile::Profile,
             resource_audit_log::ResourceAuditLog,
+            resource_audit_log::ResourceAuditLogPage,
+            resource_audit_log::ResourceAuditExportFormat,
             resource_audit_log::ResourceAuditResourceType,
+            scim::ScimCredential,
+            scim::IssuedScimCredential,
+            scim::ScimEmail,
+            scim::ScimGroup,
+            scim::ScimGroupTarget,
+            scim::ScimMember,
+            scim::ScimMeta,
+            scim::ScimName,
+            scim::ScimPatchOperation,
+            scim::ScimPatchOperationKind,
+            scim::ScimPatchRequest,
+            scim::ScimUser,
+            Scim::ScimErrorResponse,
             service_dtos::Service,
             route::Route,
             tag::Tag,
+            tenant::EmailTemplateKind,
+            tenant::RenderedEmailTemplate,
             tenant::Tenant,
+            tenant::TenantEmailTemplate,
             tenant::TenantMetaKey,
             tenant::TenantStatus,
             token::PublicConnectionStringInfo,
@@ -752,6 +970,7 @@ struct UsersManagerAccountApiDoc;
             Beginners__Account::UpdateOwnAccountNameAccountBody,
             Beginners__Meta::CreateAccountMetaBody,
             Beginners__Meta::DeleteAccountMetaParams,
+            Beginners__Meta::UpdateNotificationPreferencesBody,
             Beginners__User::TotpUpdatingValidationBody,
             Beginners__User::CreateDefaultUserBody,
             Beginners__User::CheckTokenBody,
@@ -764,6 +983,7 @@ struct UsersManagerAccountApiDoc;
             // GATEWAY MANAGER
             //
             Gateway_Manager__Route::ListRoutesByServiceParams,
+            Gateway_Manager__Route::ExplainAccessPolicyBody,
             Gateway_Manager__Service::ListServicesParams,
 
             //
@@ -772,6 +992,7 @@ struct UsersManagerAccountApiDoc;
             Guest_Manager__Guest_Role::CreateGuestRoleBody,
             Guest_Manager__Guest_Role::UpdateGuestRoleNameAndDescriptionBody,
             Guest_Manager__Guest_Role::UpdateGuestRolePermissionsBody,
+            Guest_Manager__Guest_Role::UpdateGuestRoleNamedPermissionsBody,
             Guest_Manager__Guest_Role::ListGuestRolesParams,
 
             //
@@ -782,7 +1003,10 @@ struct UsersManagerAccountApiDoc;
             Subscriptions_Manager__Account::UpdateSubscriptionAccountNameAndFlagsBody,
             Subscriptions_Manager__Account::APIAccountType,
             Subscriptions_Manager__Account::ListSubscriptionAccountParams,
+            Subscriptions_Manager__Bulk_Import::SubmitBulkImportParams,
             Subscriptions_Manager__Guest::GuestUserBody,
+            Subscriptions_Manager__Guest::UninviteGuestParams,
+            Subscriptions_Manager__Guest::ExtendGuestGrantBody,
             Subscriptions_Manager__Guest::ListLicensedAccountsOfEmailParams,
             Subscriptions_Manager__Guest::UpdateFlagsFromSubscriptionAccountBody,
             Subscriptions_Manager__Tag::CreateAccountTagBody,
@@ -792,7 +1016,11 @@ struct UsersManagerAccountApiDoc;
             //
             // SYSTEM MANAGER
             //
+            System_Manager__Email_Template::ListEmailTemplatesParams,
+            System_Manager__Email_Template::RenderEmailTemplateBody,
+            System_Manager__Email_Template::SendTestEmailBody,
             System_Manager__Error_Code::CreateErrorCodeBody,
+            System_Manager__Error_Code::SetErrorCodeTranslationBody,
             System_Manager__Error_Code::ListErrorCodesParams,
             System_Manager__Error_Code::UpdateErrorCodeMessageAndDetailsBody,
             System_Manager__Webhook::CreateWebHookBody,
@@ -805,14 +1033,26 @@ struct UsersManagerAccountApiDoc;
             Tenant_Manager__Tag::CreateTagBody,
             Tenant_Manager__Guest::GuestUserToSubscriptionManagerAccountBody,
             Tenant_Manager__Guest::RevokeUserGuestToSubscriptionManagerAccountParams,
+            Tenant_Manager__Guest_Role::CreateTenantGuestRoleBody,
+            Tenant_Manager__Guest_Role::ListTenantGuestRolesParams,
+            Tenant_Manager__Guest_Role::UpdateTenantGuestRoleBody,
 
             //
             // TENANT OWNER
             //
+            Tenant_Owner__Email_Template::SetTenantEmailTemplateBody,
+            Tenant_Owner__Email_Template::PreviewTenantEmailTemplateBody,
             Tenant_Owner__Meta::CreateTenantMetaBody,
             Tenant_Owner__Meta::DeleteTenantMetaBody,
             Tenant_Owner__Owner::GuestTenantOwnerBody,
+            Tenant_Owner__Scim_Credential::CreateScimCredentialBody,
             Tenant_Owner__Tenant::UpdateTenantNameAndDescriptionBody,
+            Tenant_Owner__Tenant::UpdateTenantParentBody,
+
+            //
+            // USERS MANAGER
+            //
+            Users_Manager__Email::ListMessageDeliveriesParams,
         ),
         responses(
             //
@@ -1,17 +1,28 @@
 use crate::dtos::MyceliumProfileData;
 
 use crate::models::active_backend_modules::SqlAppModule;
-use actix_web::{get, web, HttpResponse, Responder};
+use actix_web::{
+    error::ErrorInternalServerError, get, web, HttpResponse, Responder,
+};
+use chrono::{DateTime, Utc};
 use myc_core::{
     domain::{
         dtos::{
             account_type::AccountType,
             related_accounts::RelatedAccounts,
-            resource_audit_log::{ResourceAuditLog, ResourceAuditResourceType},
+            resource_audit_log::{
+                ResourceAuditEventKind, ResourceAuditExportFormat,
+                ResourceAuditLog, ResourceAuditLogCursor,
+                ResourceAuditLogFilter, ResourceAuditLogPage,
+                ResourceAuditResourceType,
+            },
         },
         entities::AccountFetching,
     },
-    use_cases::shared::audit::fetch_resource_audit_trail,
+    use_cases::shared::audit::{
+        fetch_resource_audit_trail, search_resource_audit_trail,
+        RESOURCE_AUDIT_MAX_PAGE_SIZE,
+    },
 };
 use myc_http_tools::{
     utils::HttpJsonResponse,
@@ -30,7 +41,10 @@ use uuid::Uuid;
 // ? ---------------------------------------------------------------------------
 
 pub fn configure(config: &mut web::ServiceConfig) {
-    config.service(fetch_resource_audit_trail_url);
+    config
+        .service(search_resource_audit_trail_url)
+        .service(export_resource_audit_trail_url)
+        .service(fetch_resource_audit_trail_url);
 }
 
 // ? ---------------------------------------------------------------------------
@@ -47,6 +61,63 @@ pub struct FetchResourceAuditTrailParams {
     resource_id: Uuid,
 }
 
+#[derive(Deserialize, ToSchema, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResourceAuditTrailParams {
+    /// Only rows about this kind of resource. Required with `resourceId`.
+    resource_type: Option<ResourceAuditResourceType>,
+
+    /// Only rows about this resource.
+    resource_id: Option<Uuid>,
+
+    /// Only rows of this tenant. Without `resourceId`, tenant owners and
+    /// managers must set it to their own tenant.
+    tenant_id: Option<Uuid>,
+
+    /// Only rows written by this user or account.
+    performed_by: Option<Uuid>,
+
+    /// Only rows of this event kind.
+    event: Option<ResourceAuditEventKind>,
+
+    /// Only rows created at or after this moment (RFC 3339).
+    created_after: Option<DateTime<Utc>>,
+
+    /// Only rows created strictly before this moment (RFC 3339).
+    created_before: Option<DateTime<Utc>>,
+
+    /// Only rows whose metadata contains this text, case-insensitively.
+    metadata_contains: Option<String>,
+
+    /// The `nextCursor` of the previous page.
+    cursor: Option<String>,
+
+    /// Rows per page (default 50, at most 1000).
+    page_size: Option<i64>,
+}
+
+impl SearchResourceAuditTrailParams {
+    fn to_filter(&self) -> ResourceAuditLogFilter {
+        ResourceAuditLogFilter {
+            resource_type: self.resource_type.to_owned(),
+            resource_id: self.resource_id,
+            tenant_id: self.tenant_id,
+            performed_by: self.performed_by,
+            event: self.event.to_owned(),
+            created_after: self.created_after,
+            created_before: self.created_before,
+            metadata_contains: self.metadata_contains.to_owned(),
+        }
+    }
+}
+
+#[derive(Deserialize, ToSchema, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportResourceAuditTrailParams {
+    /// The export file format.
+    format: ResourceAuditExportFormat,
+}
+
 // ? ---------------------------------------------------------------------------
 // ? Define API paths
 //
@@ -134,6 +205,240 @@ pub async fn fetch_resource_audit_trail_url(
     }
 }
 
+/// Search the audit trail
+///
+/// Filters the audit trail by resource, tenant, actor, event kind, time
+/// range and metadata text, newest first, one cursor page at a time. The
+/// caller's standing is checked as in `fetch_resource_audit_trail`: against
+/// the resource when `resourceId` is set, otherwise against `tenantId`.
+/// Only staff can search without either.
+#[utoipa::path(
+    get,
+    operation_id = "search_resource_audit_trail",
+    params(SearchResourceAuditTrailParams),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Invalid resource reference or cursor.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 204,
+            description = "No audit rows match.",
+        ),
+        (
+            status = 200,
+            description = "Audit rows fetched, newest first.",
+            body = ResourceAuditLogPage,
+        ),
+    ),
+)]
+#[get("/search")]
+pub async fn search_resource_audit_trail_url(
+    query: web::Query<SearchResourceAuditTrailParams>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    let cursor = match parse_cursor(query.cursor.as_deref()) {
+        Ok(cursor) => cursor,
+        Err(response) => return response,
+    };
+
+    let (tenant_id, resource_owner_account_id) =
+        match resolve_search_context(&query, &app_module).await {
+            Ok(context) => context,
+            Err(response) => return response,
+        };
+
+    match search_resource_audit_trail(
+        profile.to_profile(),
+        query.to_filter(),
+        tenant_id,
+        resource_owner_account_id,
+        cursor,
+        query.page_size,
+        Box::new(app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(page) if page.records.is_empty() => {
+            HttpResponse::NoContent().finish()
+        }
+        Ok(page) => HttpResponse::Ok().json(page),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
+/// Export the audit trail
+///
+/// Streams every row matching the search filters as CSV or JSON Lines,
+/// newest first, starting after `cursor` when set (`pageSize` is ignored).
+/// Standing is checked exactly as in the search endpoint.
+#[utoipa::path(
+    get,
+    operation_id = "export_resource_audit_trail",
+    params(SearchResourceAuditTrailParams, ExportResourceAuditTrailParams),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Invalid resource reference or cursor.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 200,
+            description = "Audit rows exported, newest first, as `text/csv` or `application/x-ndjson` depending on `format`.",
+            body = String,
+            content_type = "text/csv",
+        ),
+    ),
+)]
+#[get("/export")]
+pub async fn export_resource_audit_trail_url(
+    query: web::Query<SearchResourceAuditTrailParams>,
+    export: web::Query<ExportResourceAuditTrailParams>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    let format = export.format;
+
+    let cursor = match parse_cursor(query.cursor.as_deref()) {
+        Ok(cursor) => cursor,
+        Err(response) => return response,
+    };
+
+    let (tenant_id, resource_owner_account_id) =
+        match resolve_search_context(&query, &app_module).await {
+            Ok(context) => context,
+            Err(response) => return response,
+        };
+
+    let profile = profile.to_profile();
+    let filter = query.to_filter();
+
+    let fetch_page = move |cursor: Option<ResourceAuditLogCursor>| {
+        let profile = profile.to_owned();
+        let filter = filter.to_owned();
+        let app_module = app_module.clone();
+
+        async move {
+            search_resource_audit_trail(
+                profile,
+                filter,
+                tenant_id,
+                resource_owner_account_id,
+                cursor,
+                Some(RESOURCE_AUDIT_MAX_PAGE_SIZE),
+                Box::new(app_module.resolve_ref()),
+            )
+            .await
+        }
+    };
+
+    //
+    // The first page is fetched before answering, so a denied or failing
+    // request still gets a proper error status instead of a broken stream.
+    //
+    let first_page = match fetch_page(cursor).await {
+        Ok(page) => page,
+        Err(err) => return handle_mapped_error(err),
+    };
+
+    let body = futures::stream::unfold(
+        (Some(first_page), None::<String>, format.header()),
+        move |(page, next_cursor, header)| {
+            let fetch_page = fetch_page.clone();
+
+            async move {
+                let page = match (page, next_cursor) {
+                    (Some(page), _) => page,
+                    (None, Some(cursor)) => {
+                        match ResourceAuditLogCursor::decode(&cursor) {
+                            Ok(cursor) => {
+                                match fetch_page(Some(cursor)).await {
+                                    Ok(page) => page,
+                                    Err(err) => {
+                                        return Some((
+                                            Err(ErrorInternalServerError(err)),
+                                            (None, None, None),
+                                        ))
+                                    }
+                                }
+                            }
+                            Err(err) => {
+                                return Some((
+                                    Err(ErrorInternalServerError(err)),
+                                    (None, None, None),
+                                ))
+                            }
+                        }
+                    }
+                    (None, None) => return None,
+                };
+
+                let mut chunk = header.unwrap_or_default();
+
+                for log in page.records.iter() {
+                    match format.encode(log) {
+                        Ok(line) => chunk.push_str(&line),
+                        Err(err) => {
+                            return Some((
+                                Err(ErrorInternalServerError(err)),
+                                (None, None, None),
+                            ))
+                        }
+                    }
+                }
+
+                Some((
+                    Ok(web::Bytes::from(chunk)),
+                    (None, page.next_cursor, None),
+                ))
+            }
+        },
+    );
+
+    HttpResponse::Ok()
+        .content_type(format.content_type())
+        .insert_header((
+            "Content-Disposition",
+            format!(
+                "attachment; filename=\"resource-audit-trail.{}\"",
+                format.file_extension()
+            ),
+        ))
+        .streaming(body)
+}
+
 // ? ---------------------------------------------------------------------------
 // ? Private helpers
 // ? ---------------------------------------------------------------------------
@@ -205,3 +510,35 @@ fn account_tenant_id(account_type: &AccountType) -> Option<Uuid> {
         _ => None,
     }
 }
+
+fn parse_cursor(
+    cursor: Option<&str>,
+) -> Result<Option<ResourceAuditLogCursor>, HttpResponse> {
+    cursor
+        .map(ResourceAuditLogCursor::decode)
+        .transpose()
+        .map_err(|_| {
+            HttpResponse::BadRequest()
+                .json(HttpJsonResponse::new_message("Invalid cursor"))
+        })
+}
+
+/// Resolve the standing a search is checked against: the resource's, via
+/// `resolve_audit_context`, when a resource is named, otherwise the
+/// searched tenant.
+async fn resolve_search_context(
+    query: &SearchResourceAuditTrailParams,
+    app_module: &web::Data<SqlAppModule>,
+) -> Result<(Option<Uuid>, Option<Uuid>), HttpResponse> {
+    match (&query.resource_type, query.resource_id) {
+        (Some(resource_type), Some(resource_id)) => {
+            resolve_audit_context(resource_type, resource_id, app_module).await
+        }
+        (None, Some(_)) => Err(HttpResponse::BadRequest().json(
+            HttpJsonResponse::new_message(
+                "resourceType is required when resourceId is set",
+            ),
+        )),
+        _ => Ok((query.tenant_id, None)),
+    }
+}
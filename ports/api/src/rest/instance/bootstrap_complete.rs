@@ -97,6 +97,7 @@ pub async fn bootstrap_complete_url(
         Box::new(&*app_module.resolve_ref() as &dyn UserRegistration),
         Box::new(&*app_module.resolve_ref() as &dyn UserUpdating),
         Box::new(&*app_module.resolve_ref() as &dyn TokenInvalidation),
+        Box::new(app_module.resolve_ref()),
     )
     .await
     {
@@ -110,6 +111,7 @@ pub async fn bootstrap_complete_url(
         Box::new(
             &*app_module.resolve_ref() as &dyn InstanceSettingsRegistration
         ),
+        Box::new(app_module.resolve_ref()),
     )
     .await
     {
@@ -5,8 +5,9 @@ use myc_core::{
     domain::{
         dtos::email::Email,
         entities::{
-            InstanceSettingsFetching, LocalMessageWrite, TenantFetching,
-            TokenRegistration,
+            EmailSuppressionFetching, EncryptionKeyFetching,
+            InstanceSettingsFetching, LocalMessageWrite,
+            NotificationRecipientFetching, TenantFetching, TokenRegistration,
         },
     },
     models::AccountLifeCycle,
@@ -114,10 +115,17 @@ pub async fn bootstrap_request_code_url(
     if let Err(err) = request_magic_link(
         email,
         display_base_url,
+        None,
         life_cycle_settings.get_ref().to_owned(),
         Box::new(&*sql_app_module.resolve_ref() as &dyn TokenRegistration),
         Box::new(&*sql_app_module.resolve_ref() as &dyn LocalMessageWrite),
+        Box::new(
+            &*sql_app_module.resolve_ref() as &dyn EmailSuppressionFetching
+        ),
         Box::new(&*sql_app_module.resolve_ref() as &dyn TenantFetching),
+        Box::new(&*sql_app_module.resolve_ref()
+            as &dyn NotificationRecipientFetching),
+        Box::new(&*sql_app_module.resolve_ref() as &dyn EncryptionKeyFetching),
     )
     .await
     {
@@ -0,0 +1,109 @@
+use crate::models::active_backend_modules::SqlAppModule;
+
+use actix_web::{post, web, HttpRequest, HttpResponse, Responder};
+use myc_core::{
+    domain::{
+        dtos::email_delivery::DeliveryEvent,
+        entities::{EmailSuppressionRegistration, MessageDeliveryRegistration},
+    },
+    models::AccountLifeCycle,
+    use_cases::{register_delivery_events, validate_delivery_webhook_secret},
+};
+use myc_http_tools::wrappers::default_response_to_http_response::handle_mapped_error;
+use serde::{Deserialize, Serialize};
+use shaku::HasComponent;
+use utoipa::ToSchema;
+
+/// Header carrying the shared secret configured as `deliveryWebhookSecret`
+const DELIVERY_SECRET_HEADER: &str = "X-Mycelium-Delivery-Secret";
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryEventsBody {
+    events: Vec<DeliveryEvent>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryEventsResponse {
+    updated: usize,
+}
+
+/// Receive delivery, bounce and complaint events from the email provider.
+///
+/// Public, authenticated by the `X-Mycelium-Delivery-Secret` header. Events
+/// update the delivery status of the referenced messages, and hard bounces and
+/// complaints add the address to the suppression list. The endpoint answers
+/// 404 while no `deliveryWebhookSecret` is configured.
+#[utoipa::path(
+    post,
+    operation_id = "register_delivery_events",
+    context_path = "/instance",
+    request_body = DeliveryEventsBody,
+    responses(
+        (
+            status = 200,
+            description = "Events registered.",
+            body = DeliveryEventsResponse,
+        ),
+        (
+            status = 400,
+            description = "An event carries an invalid address.",
+        ),
+        (
+            status = 401,
+            description = "Missing or invalid secret.",
+        ),
+        (
+            status = 404,
+            description = "Delivery webhook not enabled.",
+        ),
+    ),
+    security(()),
+)]
+#[post("/delivery-events")]
+pub async fn delivery_events_url(
+    req: HttpRequest,
+    body: web::Json<DeliveryEventsBody>,
+    app_module: web::Data<SqlAppModule>,
+    core_config: web::Data<AccountLifeCycle>,
+) -> impl Responder {
+    let configured_secret = match &core_config.delivery_webhook_secret {
+        Some(resolver) => match resolver.async_get_or_error().await {
+            Ok(secret) => secret,
+            Err(err) => return handle_mapped_error(err),
+        },
+        None => return HttpResponse::NotFound().finish(),
+    };
+
+    let presented_secret = req
+        .headers()
+        .get(DELIVERY_SECRET_HEADER)
+        .and_then(|value| value.to_str().ok());
+
+    if validate_delivery_webhook_secret(
+        presented_secret,
+        Some(configured_secret.as_str()),
+    )
+    .is_err()
+    {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    match register_delivery_events(
+        body.into_inner().events,
+        Box::new(
+            &*app_module.resolve_ref() as &dyn MessageDeliveryRegistration
+        ),
+        Box::new(
+            &*app_module.resolve_ref() as &dyn EmailSuppressionRegistration
+        ),
+    )
+    .await
+    {
+        Ok(updated) => {
+            HttpResponse::Ok().json(DeliveryEventsResponse { updated })
+        }
+        Err(err) => handle_mapped_error(err),
+    }
+}
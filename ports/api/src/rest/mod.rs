@@ -4,6 +4,7 @@ pub(crate) mod instance;
 pub(crate) mod manager;
 pub(crate) mod openid;
 pub(crate) mod role_scoped;
+pub(crate) mod scim;
 pub(crate) mod service;
 pub(crate) mod shared;
 pub(crate) mod staff;
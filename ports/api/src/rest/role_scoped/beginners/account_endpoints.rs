@@ -8,10 +8,14 @@ use actix_web::{
     delete, get, patch, post, web, HttpRequest, HttpResponse, Responder,
 };
 use myc_core::{
+    domain::dtos::{
+        data_subject::{DataSubjectErasure, DataSubjectExport},
+        resource_audit_log::ResourceAuditLogCursor,
+    },
     models::AccountLifeCycle,
     use_cases::role_scoped::beginner::account::{
-        create_user_account, delete_my_account, get_my_account_details,
-        update_own_account_name,
+        create_user_account, delete_my_account, erase_my_data, export_my_data,
+        get_my_account_details, update_own_account_name,
     },
 };
 use myc_http_tools::{
@@ -26,7 +30,7 @@ use myc_http_tools::{
 use serde::Deserialize;
 use shaku::HasComponent;
 use tracing::warn;
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
 // ? ---------------------------------------------------------------------------
@@ -38,6 +42,8 @@ pub fn configure(config: &mut web::ServiceConfig) {
         .service(create_default_account_url)
         .service(update_own_account_name_url)
         .service(get_my_account_details_url)
+        .service(export_my_data_url)
+        .service(erase_my_data_url)
         .service(delete_my_account_url);
 }
 
@@ -57,6 +63,16 @@ pub struct UpdateOwnAccountNameAccountBody {
     name: String,
 }
 
+#[derive(Deserialize, ToSchema, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportMyDataParams {
+    /// The `auditNextCursor` of the previous page.
+    cursor: Option<String>,
+
+    /// Audit entries per page (default 50, at most 1000).
+    page_size: Option<i64>,
+}
+
 // ? ---------------------------------------------------------------------------
 // ? Define API paths
 //
@@ -146,6 +162,7 @@ pub async fn create_default_account_url(
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
     )
     .await
     {
@@ -329,3 +346,165 @@ pub async fn delete_my_account_url(
         Err(err) => handle_mapped_error(err),
     }
 }
+
+/// Export my data
+///
+/// Export everything Mycelium holds about the owners of the current account:
+/// users and TOTP state, the account and its meta, guest memberships,
+/// connection string metadata and audit entries. Secrets are redacted. Audit
+/// entries come one page at a time: pass `auditNextCursor` as `cursor` to get
+/// the next one.
+///
+#[utoipa::path(
+    get,
+    operation_id = "export_my_data",
+    params(
+        ("account_id" = Uuid, Path, description = "The account primary key."),
+        ExportMyDataParams,
+    ),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Invalid cursor.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 200,
+            description = "Data successfully exported.",
+            body = DataSubjectExport,
+        ),
+    ),
+)]
+#[get("/{account_id}/export")]
+pub async fn export_my_data_url(
+    path: web::Path<Uuid>,
+    query: web::Query<ExportMyDataParams>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    if path.to_owned() != profile.acc_id {
+        warn!("No account owner trying to export account data.");
+
+        return HttpResponse::Forbidden().json(HttpJsonResponse::new_message(
+            String::from(
+                "Invalid operation. Operation restricted to account owners.",
+            ),
+        ));
+    }
+
+    let cursor = match query
+        .cursor
+        .as_deref()
+        .map(ResourceAuditLogCursor::decode)
+        .transpose()
+    {
+        Ok(cursor) => cursor,
+        Err(_) => {
+            return HttpResponse::BadRequest()
+                .json(HttpJsonResponse::new_message("Invalid cursor"))
+        }
+    };
+
+    match export_my_data(
+        profile.to_profile(),
+        cursor,
+        query.page_size,
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(res) => HttpResponse::Ok()
+            .insert_header((
+                "Content-Disposition",
+                format!("attachment; filename=\"mycelium-data-{}.json\"", path),
+            ))
+            .json(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
+/// Erase my data
+///
+/// Pseudonymise every mention of the account owners in the audit trail and
+/// in the stored webhook payloads. Call it before deleting the account.
+///
+#[utoipa::path(
+    post,
+    operation_id = "erase_my_data",
+    params(
+        ("account_id" = Uuid, Path, description = "The account primary key."),
+    ),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 200,
+            description = "Data successfully erased.",
+            body = DataSubjectErasure,
+        ),
+    ),
+)]
+#[post("/{account_id}/erase")]
+pub async fn erase_my_data_url(
+    path: web::Path<Uuid>,
+    profile: MyceliumProfileData,
+    life_cycle_settings: web::Data<AccountLifeCycle>,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    if path.to_owned() != profile.acc_id {
+        warn!("No account owner trying to erase account data.");
+
+        return HttpResponse::Forbidden().json(HttpJsonResponse::new_message(
+            String::from(
+                "Invalid operation. Operation restricted to account owners.",
+            ),
+        ));
+    }
+
+    match erase_my_data(
+        profile.to_profile(),
+        life_cycle_settings.get_ref().to_owned(),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(res) => HttpResponse::Ok().json(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
@@ -81,6 +81,8 @@ pub async fn accept_invitation_url(
         guest_role_name,
         Permission::from_i32(permission.into()),
         Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(app_module.resolve_ref()),
     )
     .await
     {
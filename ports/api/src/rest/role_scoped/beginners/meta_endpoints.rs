@@ -5,9 +5,13 @@ use crate::dtos::MyceliumProfileData;
 use crate::models::active_backend_modules::SqlAppModule;
 use actix_web::{delete, post, put, web, HttpResponse, Responder};
 use myc_core::{
-    domain::dtos::account::{AccountMeta, AccountMetaKey},
+    domain::dtos::{
+        account::{AccountMeta, AccountMetaKey},
+        notification::NotificationPreferences,
+    },
     use_cases::role_scoped::beginner::meta::{
         create_account_meta, delete_account_meta, update_account_meta,
+        update_notification_preferences,
     },
 };
 use myc_http_tools::{
@@ -29,7 +33,8 @@ pub fn configure(config: &mut web::ServiceConfig) {
     config
         .service(create_account_meta_url)
         .service(update_account_meta_url)
-        .service(delete_account_meta_url);
+        .service(delete_account_meta_url)
+        .service(update_notification_preferences_url);
 }
 
 // ? ---------------------------------------------------------------------------
@@ -49,6 +54,35 @@ pub struct DeleteAccountMetaParams {
     key: String,
 }
 
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateNotificationPreferencesBody {
+    preferences: NotificationPreferences,
+
+    /// HTTPS URL receiving the notifications of the `webhook` channel
+    webhook_url: Option<String>,
+}
+
+/// Notification meta keys are validated by the notification preferences
+/// endpoint and should not be written as free text
+fn parse_writable_meta_key(key: &str) -> Result<AccountMetaKey, HttpResponse> {
+    match AccountMetaKey::from_str(key) {
+        Ok(
+            AccountMetaKey::NotificationPreferences
+            | AccountMetaKey::NotificationWebhookUrl,
+        ) => Err(HttpResponse::BadRequest().json(
+            HttpJsonResponse::new_message(
+                "Use the notification preferences endpoint to set this key"
+                    .to_string(),
+            ),
+        )),
+        Ok(key) => Ok(key),
+        Err(_) => Err(HttpResponse::BadRequest().json(
+            HttpJsonResponse::new_message("The key is invalid".to_string()),
+        )),
+    }
+}
+
 // ? ---------------------------------------------------------------------------
 // ? Define API paths
 // ? ---------------------------------------------------------------------------
@@ -92,13 +126,9 @@ pub async fn create_account_meta_url(
     profile: MyceliumProfileData,
     app_module: web::Data<SqlAppModule>,
 ) -> impl Responder {
-    let key = match AccountMetaKey::from_str(&body.key) {
+    let key = match parse_writable_meta_key(&body.key) {
         Ok(key) => key,
-        Err(_) => {
-            return HttpResponse::BadRequest().json(
-                HttpJsonResponse::new_message("The key is invalid".to_string()),
-            );
-        }
+        Err(res) => return res,
     };
 
     match create_account_meta(
@@ -153,13 +183,9 @@ pub async fn update_account_meta_url(
     profile: MyceliumProfileData,
     app_module: web::Data<SqlAppModule>,
 ) -> impl Responder {
-    let key = match AccountMetaKey::from_str(&body.key) {
+    let key = match parse_writable_meta_key(&body.key) {
         Ok(key) => key,
-        Err(_) => {
-            return HttpResponse::BadRequest().json(
-                HttpJsonResponse::new_message("The key is invalid".to_string()),
-            );
-        }
+        Err(res) => return res,
     };
 
     match update_account_meta(
@@ -235,3 +261,59 @@ pub async fn delete_account_meta_url(
         Err(err) => handle_mapped_error(err),
     }
 }
+
+/// Update the notification channel preferences
+///
+/// Magic links, MFA alerts and invitations are delivered through the
+/// channels listed for each notification kind, falling back to email when
+/// none of them succeeds. Kinds without preferences are sent by email.
+#[utoipa::path(
+    put,
+    operation_id = "update_notification_preferences",
+    request_body = UpdateNotificationPreferencesBody,
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Preferences not updated.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 202,
+            description = "Preferences updated.",
+        ),
+    ),
+)]
+#[put("/notification-preferences")]
+pub async fn update_notification_preferences_url(
+    body: web::Json<UpdateNotificationPreferencesBody>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    match update_notification_preferences(
+        profile.to_profile(),
+        body.preferences.to_owned(),
+        body.webhook_url.to_owned(),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(res) => updating_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
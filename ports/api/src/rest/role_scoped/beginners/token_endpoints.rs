@@ -133,6 +133,8 @@ pub async fn create_connection_string_url(
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(sql_app_module.resolve_ref()),
     )
     .await
     {
@@ -39,6 +39,7 @@ use shaku::HasComponent;
 use tera::Context as TeraContext;
 use tracing::warn;
 use utoipa::{IntoParams, ToResponse, ToSchema};
+use uuid::Uuid;
 
 // ? ---------------------------------------------------------------------------
 // ? Configure application
@@ -124,6 +125,7 @@ pub struct TotpUpdatingValidationBody {
 #[serde(rename_all = "camelCase")]
 pub struct MagicLinkRequestBody {
     email: String,
+    tenant_id: Option<Uuid>,
 }
 
 #[derive(Serialize, ToResponse, ToSchema)]
@@ -166,6 +168,7 @@ pub struct CheckTokenBody {
 #[serde(rename_all = "camelCase")]
 pub struct StartPasswordResetBody {
     email: String,
+    tenant_id: Option<Uuid>,
 }
 
 #[derive(Deserialize, ToSchema)]
@@ -174,6 +177,7 @@ pub struct ResetPasswordBody {
     token: String,
     email: String,
     new_password: String,
+    tenant_id: Option<Uuid>,
 }
 
 #[derive(Deserialize, ToSchema)]
@@ -292,6 +296,7 @@ pub async fn create_default_user_url(
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
     )
     .await
     {
@@ -416,11 +421,13 @@ pub async fn start_password_redefinition_url(
 
     match start_password_redefinition(
         email,
+        body.tenant_id,
         life_cycle_settings.get_ref().to_owned(),
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
     )
     .await
     {
@@ -483,12 +490,14 @@ pub async fn check_token_and_reset_password_url(
         body.token.to_owned(),
         email,
         body.new_password.to_owned(),
+        body.tenant_id,
         life_cycle_settings.get_ref().to_owned(),
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
     )
     .await
     {
@@ -554,6 +563,7 @@ pub async fn check_email_password_validity_url(
         email_instance,
         body.password.to_owned(),
         Box::new(&*app_module.resolve_ref()),
+        Box::new(app_module.resolve_ref()),
     )
     .await
     {
@@ -708,6 +718,8 @@ pub async fn totp_start_activation_url(
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
     )
     .await
     {
@@ -782,6 +794,8 @@ pub async fn totp_finish_activation_url(
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
     )
     .await
     {
@@ -848,6 +862,7 @@ pub async fn totp_check_token_url(
         life_cycle_settings.get_ref().to_owned(),
         Box::new(&*app_module.resolve_ref()),
         Box::new(&*app_module.resolve_ref()),
+        Box::new(app_module.resolve_ref()),
     )
     .await
     {
@@ -933,6 +948,8 @@ pub async fn totp_disable_url(
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
     )
     .await
     {
@@ -1011,10 +1028,14 @@ pub async fn request_magic_link_url(
     if let Err(err) = request_magic_link(
         email,
         display_base_url,
+        body.tenant_id,
         life_cycle_settings.get_ref().to_owned(),
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
     )
     .await
     {
@@ -1165,6 +1186,7 @@ pub async fn verify_magic_link_url(
         Box::new(&*app_module.resolve_ref()),
         Box::new(&*app_module.resolve_ref()),
         Box::new(&*app_module.resolve_ref()),
+        Box::new(app_module.resolve_ref()),
     )
     .await
     {
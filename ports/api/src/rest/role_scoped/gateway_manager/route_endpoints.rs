@@ -1,9 +1,23 @@
-use crate::{dtos::MyceliumProfileData, rest::shared::PaginationParams};
+use crate::{
+    access_policy::{access_policy_evaluator, build_dry_run_attributes},
+    dtos::MyceliumProfileData,
+    rest::shared::PaginationParams,
+};
 
-use actix_web::{get, web, Responder};
+use actix_web::{get, post, web, HttpResponse, Responder};
+use chrono::{DateTime, Local};
+use http::uri::PathAndQuery;
 use myc_core::{
-    domain::dtos::route::Route,
-    use_cases::role_scoped::gateway_manager::route::list_routes,
+    domain::dtos::{
+        access_policy::{
+            AccessPolicy, AccessPolicyExplanation,
+            AccessPolicyProfileAttributes,
+        },
+        route::Route,
+    },
+    use_cases::role_scoped::gateway_manager::route::{
+        explain_access_policy, list_routes,
+    },
 };
 use myc_http_tools::{
     utils::HttpJsonResponse,
@@ -14,6 +28,7 @@ use myc_http_tools::{
 use myc_mem_db::repositories::MemDbAppModule;
 use serde::Deserialize;
 use shaku::HasComponent;
+use std::{collections::HashMap, str::FromStr};
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
@@ -22,7 +37,9 @@ use uuid::Uuid;
 // ? ---------------------------------------------------------------------------
 
 pub fn configure(config: &mut web::ServiceConfig) {
-    config.service(list_routes_url);
+    config
+        .service(list_routes_url)
+        .service(explain_access_policy_url);
 }
 
 // ? ---------------------------------------------------------------------------
@@ -36,6 +53,33 @@ pub struct ListRoutesByServiceParams {
     name: Option<String>,
 }
 
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ExplainAccessPolicyBody {
+    /// The request method
+    method: String,
+
+    /// The request path, including the service name and the query string
+    path: String,
+
+    /// The request headers
+    headers: Option<HashMap<String, String>>,
+
+    /// The client IP address
+    client_ip: Option<String>,
+
+    /// The request date and time. Defaults to now.
+    at: Option<DateTime<Local>>,
+
+    /// The profile attributes of the requester. Defaults to the attributes of
+    /// the current profile.
+    profile: Option<AccessPolicyProfileAttributes>,
+
+    /// The policy to evaluate. Defaults to the policy of the route matching
+    /// the path.
+    policy: Option<AccessPolicy>,
+}
+
 // ? ---------------------------------------------------------------------------
 // ? Define API paths
 // ? ---------------------------------------------------------------------------
@@ -99,3 +143,88 @@ pub async fn list_routes_url(
         Err(err) => handle_mapped_error(err),
     }
 }
+
+/// Explain access policy
+///
+/// This function is restricted to the GatewayManager users. Evaluate an
+/// access policy as a dry-run and explain why the request would be allowed or
+/// denied. The policy of the route matching the path is used unless a draft
+/// policy is given.
+///
+#[utoipa::path(
+    post,
+    operation_id = "explain_access_policy",
+    request_body = ExplainAccessPolicyBody,
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Invalid path or route not protected by a policy.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 200,
+            description = "Policy evaluated.",
+            body = AccessPolicyExplanation,
+        ),
+    ),
+)]
+#[post("/explain-policy")]
+pub async fn explain_access_policy_url(
+    body: web::Json<ExplainAccessPolicyBody>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<MemDbAppModule>,
+) -> impl Responder {
+    let path = match PathAndQuery::from_str(&body.path) {
+        Ok(path) => path,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(
+                HttpJsonResponse::new_message(
+                    "Invalid request path".to_string(),
+                ),
+            )
+        }
+    };
+
+    let profile = profile.to_profile();
+
+    let attributes = build_dry_run_attributes(
+        &body.method,
+        &path,
+        body.headers.to_owned().unwrap_or_default(),
+        body.client_ip.to_owned(),
+        body.at.unwrap_or_else(Local::now),
+        match body.profile.to_owned() {
+            Some(attributes) => attributes,
+            None => AccessPolicyProfileAttributes::from_profile(&profile, None),
+        },
+    );
+
+    match explain_access_policy(
+        profile,
+        path,
+        body.policy.to_owned(),
+        attributes,
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(access_policy_evaluator()),
+    )
+    .await
+    {
+        Ok(res) => HttpResponse::Ok().json(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
@@ -7,7 +7,8 @@ use myc_core::{
     use_cases::role_scoped::guest_manager::guest_role::{
         create_guest_role, delete_guest_role, insert_role_child,
         list_guest_roles, remove_role_child,
-        update_guest_role_name_and_description, update_guest_role_permission,
+        update_guest_role_name_and_description,
+        update_guest_role_named_permissions, update_guest_role_permission,
     },
 };
 use myc_http_tools::{
@@ -34,6 +35,7 @@ pub fn configure(config: &mut web::ServiceConfig) {
         .service(delete_guest_role_url)
         .service(update_guest_role_name_and_description_url)
         .service(update_guest_role_permissions_url)
+        .service(update_guest_role_named_permissions_url)
         .service(insert_role_child_url)
         .service(remove_role_child_url);
 }
@@ -77,6 +79,13 @@ pub struct UpdateGuestRolePermissionsBody {
     pub permission: Permission,
 }
 
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateGuestRoleNamedPermissionsBody {
+    /// Named permissions granted by the role, as `invoices:approve`.
+    pub permissions: Vec<String>,
+}
+
 /// Create Guest Role
 ///
 /// Guest Roles provide permissions to simple Roles.
@@ -367,6 +376,67 @@ pub async fn update_guest_role_permissions_url(
     }
 }
 
+/// Update Guest Role Named Permissions
+///
+/// Replace the named permissions granted by a guest role, as
+/// `invoices:approve`.
+#[utoipa::path(
+    patch,
+    operation_id = "update_guest_role_named_permissions",
+    params(
+        ("guest_role_id" = Uuid, Path, description = "The guest-role primary key."),
+    ),
+    request_body = UpdateGuestRoleNamedPermissionsBody,
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Guest Role not updated.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 202,
+            description = "Guest Role updated.",
+            body = GuestRole,
+        ),
+    ),
+)]
+#[patch("/{guest_role_id}/named-permissions")]
+pub async fn update_guest_role_named_permissions_url(
+    path: web::Path<Uuid>,
+    body: web::Json<UpdateGuestRoleNamedPermissionsBody>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    match update_guest_role_named_permissions(
+        profile.to_profile(),
+        path.to_owned(),
+        body.permissions.to_owned(),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(res) => updating_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
 /// Set Child Role
 ///
 /// Insert a child role to a parent role.
@@ -33,28 +33,34 @@ use guest_manager::guest_role_endpoints as guest_manager_guest_role_endpoints;
 use myc_core::domain::actors::SystemActor;
 use subscriptions_manager::{
     account_endpoints as subscription_manager_account_endpoints,
+    bulk_import_endpoints as subscription_manager_bulk_import_endpoints,
     guest_endpoints as subscription_manager_guest_endpoints,
     guest_role_endpoints as subscription_manager_guest_role_endpoints,
     tag_endpoints as subscription_manager_tag_endpoints,
 };
 use system_manager::{
+    email_template_endpoints as system_manager_email_template_endpoints,
     error_code_endpoints as system_manager_error_code_endpoints,
     webhook_endpoints as system_manager_webhook_endpoints,
 };
 use tenant_manager::{
     account_endpoints as tenant_manager_account_endpoints,
     guest_endpoints as tenant_manager_guest_endpoints,
+    guest_role_endpoints as tenant_manager_guest_role_endpoints,
     tag_endpoints as tenant_manager_tag_endpoints,
     tenant_endpoints as tenant_manager_tenant_endpoints,
 };
 use tenant_owner::{
     account_endpoints as tenant_owner_account_endpoints,
+    email_template_endpoints as tenant_owner_email_template_endpoints,
     meta_endpoints as tenant_owner_meta_endpoints,
     owner_endpoints as tenant_owner_owner_endpoints,
+    scim_credential_endpoints as tenant_owner_scim_credential_endpoints,
     telegram_config_endpoints as tenant_owner_telegram_config_endpoints,
     tenant_endpoints as tenant_owner_tenant_endpoints,
 };
 use users_manager::account_endpoints as user_manager_account_endpoints;
+use users_manager::email_endpoints as user_manager_email_endpoints;
 
 // ? ---------------------------------------------------------------------------
 // ? Configure application re-routing
@@ -205,6 +211,9 @@ pub(crate) fn configure(config: &mut web::ServiceConfig) {
                 )
                 .service(web::scope(UrlGroup::GuestRoles.str()).configure(
                     subscription_manager_guest_role_endpoints::configure,
+                ))
+                .service(web::scope(UrlGroup::BulkImports.str()).configure(
+                    subscription_manager_bulk_import_endpoints::configure,
                 )),
         )
         //
@@ -259,6 +268,9 @@ pub(crate) fn configure(config: &mut web::ServiceConfig) {
                 //
                 // Configure the standard role endpoints
                 //
+                .service(web::scope(UrlGroup::EmailTemplates.str()).configure(
+                    system_manager_email_template_endpoints::configure,
+                ))
                 .service(
                     web::scope(UrlGroup::ErrorCodes.str()).configure(
                         system_manager_error_code_endpoints::configure,
@@ -300,6 +312,11 @@ pub(crate) fn configure(config: &mut web::ServiceConfig) {
                     web::scope(UrlGroup::Guests.str())
                         .configure(tenant_manager_guest_endpoints::configure),
                 )
+                .service(
+                    web::scope(UrlGroup::GuestRoles.str()).configure(
+                        tenant_manager_guest_role_endpoints::configure,
+                    ),
+                )
                 .service(
                     web::scope(UrlGroup::Tags.str())
                         .configure(tenant_manager_tag_endpoints::configure),
@@ -321,6 +338,9 @@ pub(crate) fn configure(config: &mut web::ServiceConfig) {
                     web::scope(UrlGroup::Accounts.str())
                         .configure(tenant_owner_account_endpoints::configure),
                 )
+                .service(web::scope(UrlGroup::EmailTemplates.str()).configure(
+                    tenant_owner_email_template_endpoints::configure,
+                ))
                 .service(
                     web::scope(UrlGroup::Meta.str())
                         .configure(tenant_owner_meta_endpoints::configure),
@@ -329,6 +349,9 @@ pub(crate) fn configure(config: &mut web::ServiceConfig) {
                     web::scope(UrlGroup::Owners.str())
                         .configure(tenant_owner_owner_endpoints::configure),
                 )
+                .service(web::scope(UrlGroup::ScimCredentials.str()).configure(
+                    tenant_owner_scim_credential_endpoints::configure,
+                ))
                 .service(web::scope("/telegram/config").configure(
                     tenant_owner_telegram_config_endpoints::configure,
                 ))
@@ -362,6 +385,10 @@ pub(crate) fn configure(config: &mut web::ServiceConfig) {
                 .service(
                     web::scope(UrlGroup::Accounts.str())
                         .configure(user_manager_account_endpoints::configure),
+                )
+                .service(
+                    web::scope(UrlGroup::Emails.str())
+                        .configure(user_manager_email_endpoints::configure),
                 ),
         );
 }
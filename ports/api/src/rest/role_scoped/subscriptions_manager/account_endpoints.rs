@@ -489,6 +489,7 @@ pub async fn get_account_details_url(
         tenant.map(|t| t.tenant_id().to_owned()),
         account_id,
         Box::new(&*app_module.resolve_ref()),
+        Box::new(app_module.resolve_ref()),
     )
     .await
     {
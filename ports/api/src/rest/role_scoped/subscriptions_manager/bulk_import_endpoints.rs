@@ -0,0 +1,263 @@
+use crate::{
+    dtos::{MyceliumProfileData, TenantData},
+    rest::shared::PaginationParams,
+};
+
+use crate::models::active_backend_modules::SqlAppModule;
+use actix_web::{get, post, web, HttpResponse, Responder};
+use myc_core::{
+    domain::dtos::bulk_import::{BulkImport, BulkImportFormat},
+    use_cases::role_scoped::subscriptions_manager::bulk_import::{
+        get_bulk_import, list_bulk_imports, submit_bulk_import,
+    },
+};
+use myc_http_tools::{
+    utils::HttpJsonResponse,
+    wrappers::default_response_to_http_response::{
+        fetch_many_response_kind, fetch_response_kind, handle_mapped_error,
+    },
+};
+use mycelium_base::entities::GetOrCreateResponseKind;
+use serde::Deserialize;
+use shaku::HasComponent;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+// ? ---------------------------------------------------------------------------
+// ? Configure application
+// ? ---------------------------------------------------------------------------
+
+pub fn configure(config: &mut web::ServiceConfig) {
+    config
+        .service(submit_bulk_import_url)
+        .service(list_bulk_imports_url)
+        .service(get_bulk_import_url);
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Define API structs
+// ? ---------------------------------------------------------------------------
+
+#[derive(Deserialize, ToSchema, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitBulkImportParams {
+    /// The format of the request body
+    pub format: BulkImportFormat,
+
+    /// Only validate the rows, without importing them
+    pub dry_run: Option<bool>,
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Define API paths
+// ? ---------------------------------------------------------------------------
+
+/// Submit a bulk import
+///
+/// The body holds the rows to import as CSV or as a JSON array. Each row
+/// creates (or reuses) the subscription account named in the row and invites
+/// the email with the row role into it. The rows are validated right away and
+/// imported in background.
+///
+/// Dry runs return the validation of each row without importing them.
+/// Submitting the same rows again returns the job registered first.
+#[utoipa::path(
+    post,
+    operation_id = "submit_bulk_import",
+    params(
+        SubmitBulkImportParams,
+        (
+            "x-mycelium-tenant-id" = Uuid,
+            Header,
+            description = "The tenant unique id."
+        ),
+    ),
+    request_body(content = String, content_type = "text/plain"),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Malformed import.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 201,
+            description = "Bulk import submitted.",
+            body = BulkImport,
+        ),
+        (
+            status = 200,
+            description = "Dry run or bulk import already submitted.",
+            body = BulkImport,
+        ),
+    ),
+)]
+#[post("")]
+pub async fn submit_bulk_import_url(
+    tenant: TenantData,
+    query: web::Query<SubmitBulkImportParams>,
+    body: String,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    match submit_bulk_import(
+        profile.to_profile(),
+        tenant.tenant_id().to_owned(),
+        query.format,
+        body,
+        query.dry_run.unwrap_or(false),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Err(err) => handle_mapped_error(err),
+        Ok(res) => match res {
+            GetOrCreateResponseKind::Created(bulk_import) => {
+                HttpResponse::Created().json(bulk_import)
+            }
+            GetOrCreateResponseKind::NotCreated(bulk_import, msg) => {
+                tracing::info!("{}", msg);
+
+                HttpResponse::Ok().json(bulk_import)
+            }
+        },
+    }
+}
+
+/// List bulk imports
+///
+/// List the bulk imports of the tenant, the most recent first.
+#[utoipa::path(
+    get,
+    operation_id = "list_bulk_imports",
+    params(
+        PaginationParams,
+        (
+            "x-mycelium-tenant-id" = Uuid,
+            Header,
+            description = "The tenant unique id."
+        ),
+    ),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 204,
+            description = "Not found.",
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 200,
+            description = "Success.",
+            body = [BulkImport],
+        ),
+    ),
+)]
+#[get("")]
+pub async fn list_bulk_imports_url(
+    tenant: TenantData,
+    page: web::Query<PaginationParams>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    match list_bulk_imports(
+        profile.to_profile(),
+        tenant.tenant_id().to_owned(),
+        page.page_size,
+        page.skip,
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(res) => fetch_many_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
+/// Get a bulk import
+///
+/// Get the status and the row results of a bulk import.
+#[utoipa::path(
+    get,
+    operation_id = "get_bulk_import",
+    params(
+        ("bulk_import_id" = Uuid, Path, description = "The bulk import id."),
+        (
+            "x-mycelium-tenant-id" = Uuid,
+            Header,
+            description = "The tenant unique id."
+        ),
+    ),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 204,
+            description = "Not found.",
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 200,
+            description = "Success.",
+            body = BulkImport,
+        ),
+    ),
+)]
+#[get("/{bulk_import_id}")]
+pub async fn get_bulk_import_url(
+    tenant: TenantData,
+    path: web::Path<Uuid>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    match get_bulk_import(
+        profile.to_profile(),
+        tenant.tenant_id().to_owned(),
+        path.into_inner(),
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(res) => fetch_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
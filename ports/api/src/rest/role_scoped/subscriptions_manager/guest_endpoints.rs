@@ -5,16 +5,21 @@ use crate::{
 
 use crate::models::active_backend_modules::SqlAppModule;
 use actix_web::{delete, get, patch, post, web, HttpResponse, Responder};
+use chrono::{DateTime, Local};
 use myc_core::{
     domain::dtos::{
-        email::Email, guest_user::GuestUser, profile::LicensedResources,
+        email::Email,
+        guest_user::{GuestUser, PendingGuestInvitation},
+        guest_user_on_account::GuestUserOnAccount,
+        profile::LicensedResources,
         security_group::PermissionedRole,
     },
     models::AccountLifeCycle,
     use_cases::role_scoped::subscriptions_manager::guest::{
-        guest_user_to_subscription_account, list_guest_on_subscription_account,
-        list_licensed_accounts_of_email,
-        revoke_user_guest_to_subscription_account,
+        cancel_guest_invitation, extend_guest_grant,
+        guest_user_to_subscription_account, list_guest_invitations,
+        list_guest_on_subscription_account, list_licensed_accounts_of_email,
+        resend_guest_invitation, revoke_user_guest_to_subscription_account,
         update_flags_from_subscription_account,
     },
 };
@@ -41,8 +46,12 @@ pub fn configure(config: &mut web::ServiceConfig) {
         .service(list_licensed_accounts_of_email_url)
         .service(guest_user_url)
         .service(update_flags_from_subscription_account_url)
+        .service(extend_guest_grant_url)
         .service(uninvite_guest_url)
-        .service(list_guest_on_subscription_account_url);
+        .service(list_guest_on_subscription_account_url)
+        .service(list_guest_invitations_url)
+        .service(resend_guest_invitation_url)
+        .service(cancel_guest_invitation_url);
 }
 
 // ? ---------------------------------------------------------------------------
@@ -53,6 +62,31 @@ pub fn configure(config: &mut web::ServiceConfig) {
 #[serde(rename_all = "camelCase")]
 pub struct GuestUserBody {
     email: String,
+
+    /// The moment the grant starts licensing the account
+    ///
+    /// Grants without a start date are valid right away.
+    valid_from: Option<DateTime<Local>>,
+
+    /// The moment the grant stops licensing the account
+    ///
+    /// Grants without an end date never expire.
+    valid_until: Option<DateTime<Local>>,
+}
+
+#[derive(Deserialize, ToSchema, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct UninviteGuestParams {
+    email: String,
+}
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtendGuestGrantBody {
+    /// The new end date of the grant
+    ///
+    /// Omit it to make the grant permanent.
+    valid_until: Option<DateTime<Local>>,
 }
 
 #[derive(Deserialize, ToSchema, IntoParams)]
@@ -228,12 +262,20 @@ pub async fn guest_user_url(
         email,
         role_id,
         account_id,
+        body.valid_from,
+        body.valid_until,
         life_cycle_settings.get_ref().to_owned(),
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(sql_app_module.resolve_ref()),
     )
     .await
     {
@@ -324,6 +366,79 @@ pub async fn update_flags_from_subscription_account_url(
     }
 }
 
+/// Change the end date of a guest grant
+///
+/// Extends or shortens the time-bound access of a guest to an account. The
+/// grant keeps its start date. Expired grants are revoked by the scheduler, so
+/// they can not be extended but must be invited again.
+#[utoipa::path(
+    patch,
+    operation_id = "extend_guest_grant",
+    params(
+        (
+            "x-mycelium-tenant-id" = Uuid,
+            Header,
+            description = "The tenant unique id."
+        ),
+        ("account_id" = Uuid, Path, description = "The account primary key."),
+        ("role_id" = Uuid, Path, description = "The guest-role unique id."),
+    ),
+    request_body = ExtendGuestGrantBody,
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Bad request.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 202,
+            description = "Grant end date updated.",
+            body = GuestUserOnAccount,
+        ),
+    ),
+)]
+#[patch("/accounts/{account_id}/roles/{role_id}/validity")]
+pub async fn extend_guest_grant_url(
+    tenant: TenantData,
+    path: web::Path<(Uuid, Uuid)>,
+    body: web::Json<ExtendGuestGrantBody>,
+    profile: MyceliumProfileData,
+    sql_app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    let (account_id, role_id) = path.to_owned();
+
+    match extend_guest_grant(
+        profile.to_profile(),
+        tenant.tenant_id().to_owned(),
+        account_id,
+        role_id,
+        body.valid_until,
+        Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(sql_app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(res) => updating_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
 /// Uninvite user to perform a role to account
 #[utoipa::path(
     delete,
@@ -336,7 +451,7 @@ pub async fn update_flags_from_subscription_account_url(
         ),
         ("account_id" = Uuid, Path, description = "The account primary key."),
         ("role_id" = Uuid, Path, description = "The guest-role unique id."),
-        GuestUserBody,
+        UninviteGuestParams,
     ),
     responses(
         (
@@ -369,7 +484,7 @@ pub async fn update_flags_from_subscription_account_url(
 pub async fn uninvite_guest_url(
     tenant: TenantData,
     path: web::Path<(Uuid, Uuid)>,
-    query: web::Query<GuestUserBody>,
+    query: web::Query<UninviteGuestParams>,
     profile: MyceliumProfileData,
     app_module: web::Data<SqlAppModule>,
 ) -> impl Responder {
@@ -382,6 +497,8 @@ pub async fn uninvite_guest_url(
         role_id,
         query.email.to_owned(),
         Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(app_module.resolve_ref()),
     )
     .await
     {
@@ -455,3 +572,219 @@ pub async fn list_guest_on_subscription_account_url(
         Err(err) => handle_mapped_error(err),
     }
 }
+
+/// List the pending invitations of an account
+///
+/// Lists the invitations not accepted yet, with who sent them, when they were
+/// last sent and when they expire.
+#[utoipa::path(
+    get,
+    operation_id = "list_guest_invitations",
+    params(
+        (
+            "x-mycelium-tenant-id" = Uuid,
+            Header,
+            description = "The tenant unique id."
+        ),
+        ("account_id" = Uuid, Path, description = "The account primary key."),
+    ),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 204,
+            description = "Not found.",
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 200,
+            description = "Fetching success.",
+            body = [PendingGuestInvitation],
+        ),
+    ),
+)]
+#[get("/accounts/{account_id}/invitations")]
+pub async fn list_guest_invitations_url(
+    tenant: TenantData,
+    path: web::Path<Uuid>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    match list_guest_invitations(
+        profile.to_profile(),
+        tenant.tenant_id().to_owned(),
+        path.to_owned(),
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(res) => fetch_many_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
+/// Resend a pending invitation
+///
+/// Sends the invitation email again. The invitation expiration date is
+/// renewed.
+#[utoipa::path(
+    post,
+    operation_id = "resend_guest_invitation",
+    params(
+        (
+            "x-mycelium-tenant-id" = Uuid,
+            Header,
+            description = "The tenant unique id."
+        ),
+        ("account_id" = Uuid, Path, description = "The account primary key."),
+        (
+            "guest_user_id" = Uuid,
+            Path,
+            description = "The guest user unique id."
+        ),
+    ),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Invitation not resent.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 202,
+            description = "Invitation resent.",
+            body = PendingGuestInvitation,
+        ),
+    ),
+)]
+#[post("/accounts/{account_id}/invitations/{guest_user_id}/resend")]
+pub async fn resend_guest_invitation_url(
+    tenant: TenantData,
+    path: web::Path<(Uuid, Uuid)>,
+    profile: MyceliumProfileData,
+    life_cycle_settings: web::Data<AccountLifeCycle>,
+    sql_app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    let (account_id, guest_user_id) = path.to_owned();
+
+    match resend_guest_invitation(
+        profile.to_profile(),
+        tenant.tenant_id().to_owned(),
+        account_id,
+        guest_user_id,
+        life_cycle_settings.get_ref().to_owned(),
+        Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(sql_app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(res) => updating_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
+/// Cancel a pending invitation
+///
+/// Removes an invitation not accepted yet. Accepted invitations should be
+/// uninvited instead.
+#[utoipa::path(
+    delete,
+    operation_id = "cancel_guest_invitation",
+    params(
+        (
+            "x-mycelium-tenant-id" = Uuid,
+            Header,
+            description = "The tenant unique id."
+        ),
+        ("account_id" = Uuid, Path, description = "The account primary key."),
+        (
+            "guest_user_id" = Uuid,
+            Path,
+            description = "The guest user unique id."
+        ),
+    ),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Invitation not cancelled.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 204,
+            description = "Invitation cancelled.",
+        ),
+    ),
+)]
+#[delete("/accounts/{account_id}/invitations/{guest_user_id}")]
+pub async fn cancel_guest_invitation_url(
+    tenant: TenantData,
+    path: web::Path<(Uuid, Uuid)>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    let (account_id, guest_user_id) = path.to_owned();
+
+    match cancel_guest_invitation(
+        profile.to_profile(),
+        tenant.tenant_id().to_owned(),
+        account_id,
+        guest_user_id,
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(res) => delete_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
@@ -1,4 +1,5 @@
 pub(crate) mod account_endpoints;
+pub(crate) mod bulk_import_endpoints;
 pub(crate) mod guest_endpoints;
 pub(crate) mod guest_role_endpoints;
 pub(crate) mod tag_endpoints;
@@ -0,0 +1,262 @@
+use crate::dtos::MyceliumProfileData;
+
+use crate::models::active_backend_modules::SqlAppModule;
+use actix_web::{get, post, web, HttpResponse, Responder};
+use myc_core::{
+    domain::{
+        dtos::{
+            email::Email, email_template::EmailTemplateInfo,
+            tenant::RenderedEmailTemplate,
+        },
+        entities::{
+            EmailSuppressionFetching, LocalMessageWrite, TenantFetching,
+        },
+    },
+    models::AccountLifeCycle,
+    use_cases::role_scoped::system_manager::email_templates::{
+        list_email_templates, render_email_template, send_test_email,
+    },
+};
+use myc_http_tools::{
+    utils::HttpJsonResponse,
+    wrappers::default_response_to_http_response::{
+        create_response_kind, fetch_many_response_kind, handle_mapped_error,
+    },
+};
+use serde::Deserialize;
+use shaku::HasComponent;
+use std::collections::HashMap;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+// ? ---------------------------------------------------------------------------
+// ? Configure application
+// ? ---------------------------------------------------------------------------
+
+pub fn configure(config: &mut web::ServiceConfig) {
+    config
+        .service(list_email_templates_url)
+        .service(render_email_template_url)
+        .service(send_test_email_url);
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Define API structs
+// ? ---------------------------------------------------------------------------
+
+#[derive(Deserialize, ToSchema, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct ListEmailTemplatesParams {
+    locale: Option<String>,
+}
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RenderEmailTemplateBody {
+    locale: Option<String>,
+    parameters: Option<HashMap<String, String>>,
+    tenant_id: Option<Uuid>,
+}
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SendTestEmailBody {
+    to: String,
+    locale: Option<String>,
+    parameters: Option<HashMap<String, String>>,
+    tenant_id: Option<Uuid>,
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Define API paths
+// ? ---------------------------------------------------------------------------
+
+/// List the notification email templates
+///
+/// Templates are listed with the locales they are available in. Filter by
+/// `locale` to list only the templates available in a locale.
+#[utoipa::path(
+    get,
+    operation_id = "list_email_templates",
+    params(ListEmailTemplatesParams),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 204,
+            description = "Not found.",
+        ),
+        (
+            status = 200,
+            description = "Fetching success.",
+            body = [EmailTemplateInfo],
+        ),
+    ),
+)]
+#[get("")]
+pub async fn list_email_templates_url(
+    query: web::Query<ListEmailTemplatesParams>,
+    profile: MyceliumProfileData,
+) -> impl Responder {
+    match list_email_templates(profile.to_profile(), query.locale.to_owned())
+        .await
+    {
+        Ok(res) => fetch_many_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
+/// Render a notification email template
+///
+/// The template is rendered to HTML and text with its sample parameters,
+/// replaced by the informed ones. Nothing is sent.
+#[utoipa::path(
+    post,
+    operation_id = "render_email_template",
+    params(
+        ("name" = String, Path, description = "The template name."),
+    ),
+    request_body = RenderEmailTemplateBody,
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Unknown template or rendering error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 200,
+            description = "Rendered template.",
+            body = RenderedEmailTemplate,
+        ),
+    ),
+)]
+#[post("/{name}/render")]
+pub async fn render_email_template_url(
+    path: web::Path<String>,
+    body: web::Json<RenderEmailTemplateBody>,
+    profile: MyceliumProfileData,
+    life_cycle_settings: web::Data<AccountLifeCycle>,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    let body = body.into_inner();
+
+    match render_email_template(
+        profile.to_profile(),
+        path.into_inner(),
+        body.locale,
+        body.parameters,
+        body.tenant_id,
+        life_cycle_settings.get_ref().clone(),
+        Box::new(&*app_module.resolve_ref() as &dyn TenantFetching),
+    )
+    .await
+    {
+        Ok(rendered) => HttpResponse::Ok().json(rendered),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
+/// Send a test message of a notification email template
+///
+/// The template is rendered as in the render endpoint and queued to the email
+/// dispatcher, which delivers it through the configured transport. The
+/// subject is prefixed with `[TEST]`.
+#[utoipa::path(
+    post,
+    operation_id = "send_test_email",
+    params(
+        ("name" = String, Path, description = "The template name."),
+    ),
+    request_body = SendTestEmailBody,
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Unknown template, invalid or suppressed recipient.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 201,
+            description = "Test message queued.",
+            body = Option<Uuid>,
+        ),
+    ),
+)]
+#[post("/{name}/test-send")]
+pub async fn send_test_email_url(
+    path: web::Path<String>,
+    body: web::Json<SendTestEmailBody>,
+    profile: MyceliumProfileData,
+    life_cycle_settings: web::Data<AccountLifeCycle>,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    let body = body.into_inner();
+
+    let to = match Email::from_string(body.to) {
+        Ok(email) => email,
+        Err(err) => {
+            return HttpResponse::BadRequest()
+                .json(HttpJsonResponse::new_message(err.to_string()))
+        }
+    };
+
+    match send_test_email(
+        profile.to_profile(),
+        path.into_inner(),
+        body.locale,
+        body.parameters,
+        body.tenant_id,
+        to,
+        life_cycle_settings.get_ref().clone(),
+        Box::new(&*app_module.resolve_ref() as &dyn LocalMessageWrite),
+        Box::new(&*app_module.resolve_ref() as &dyn EmailSuppressionFetching),
+        Box::new(&*app_module.resolve_ref() as &dyn TenantFetching),
+    )
+    .await
+    {
+        Ok(res) => create_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
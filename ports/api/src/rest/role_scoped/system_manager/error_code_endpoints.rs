@@ -1,12 +1,13 @@
 use crate::{dtos::MyceliumProfileData, rest::shared::PaginationParams};
 
 use crate::models::active_backend_modules::SqlAppModule;
-use actix_web::{delete, get, patch, post, web, HttpResponse, Responder};
+use actix_web::{delete, get, patch, post, put, web, HttpResponse, Responder};
 use myc_core::{
-    domain::dtos::error_code::ErrorCode,
+    domain::dtos::error_code::{ErrorCode, ErrorCodeTranslation},
     use_cases::role_scoped::system_manager::error_codes::{
-        delete_error_code, get_error_code, list_error_codes,
-        register_error_code, update_error_code_message_and_details,
+        delete_error_code, delete_error_code_translation, get_error_code,
+        list_error_code_translations, list_error_codes, register_error_code,
+        set_error_code_translation, update_error_code_message_and_details,
     },
 };
 use myc_http_tools::{
@@ -30,7 +31,10 @@ pub fn configure(config: &mut web::ServiceConfig) {
         .service(list_error_codes_url)
         .service(get_error_code_url)
         .service(update_error_code_message_and_details_url)
-        .service(delete_error_code_url);
+        .service(delete_error_code_url)
+        .service(list_error_code_translations_url)
+        .service(set_error_code_translation_url)
+        .service(delete_error_code_translation_url);
 }
 
 // ? ---------------------------------------------------------------------------
@@ -54,6 +58,13 @@ pub struct ListErrorCodesParams {
     is_internal: Option<bool>,
 }
 
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SetErrorCodeTranslationBody {
+    message: String,
+    details: Option<String>,
+}
+
 #[derive(Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateErrorCodeMessageAndDetailsBody {
@@ -113,6 +124,7 @@ pub async fn register_error_code_url(
         body.details.to_owned(),
         body.is_internal.to_owned(),
         Box::new(&*app_module.resolve_ref()),
+        Box::new(app_module.resolve_ref()),
     )
     .await
     {
@@ -306,6 +318,7 @@ pub async fn update_error_code_message_and_details_url(
         body.details.to_owned(),
         Box::new(&*app_module.resolve_ref()),
         Box::new(&*app_module.resolve_ref()),
+        Box::new(app_module.resolve_ref()),
     )
     .await
     {
@@ -365,6 +378,198 @@ pub async fn delete_error_code_url(
         prefix,
         code,
         Box::new(&*app_module.resolve_ref()),
+        Box::new(app_module.resolve_ref()),
+    )
+    .await
+    {
+        Err(err) => handle_mapped_error(err),
+        Ok(_) => HttpResponse::NoContent().finish(),
+    }
+}
+
+/// List the translations of an error code.
+///
+/// Translations replace the error message in responses for callers
+/// preferring their locale.
+///
+#[utoipa::path(
+    get,
+    operation_id = "list_error_code_translations",
+    params(
+        ("prefix" = String, Path, description = "The error prefix."),
+        ("code" = i32, Path, description = "The error code."),
+    ),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 204,
+            description = "Not found.",
+        ),
+        (
+            status = 200,
+            description = "Fetching success.",
+            body = [ErrorCodeTranslation],
+        ),
+    ),
+)]
+#[get("/prefixes/{prefix}/codes/{code}/translations")]
+pub async fn list_error_code_translations_url(
+    path: web::Path<(String, i32)>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    let (prefix, code) = path.into_inner();
+
+    match list_error_code_translations(
+        profile.to_profile(),
+        prefix,
+        code,
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(res) => fetch_many_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
+/// Set the translation of an error code.
+///
+/// Register or replace the message and details of an error code for a
+/// locale (e.g. `pt-br`).
+///
+#[utoipa::path(
+    put,
+    operation_id = "set_error_code_translation",
+    params(
+        ("prefix" = String, Path, description = "The error prefix."),
+        ("code" = i32, Path, description = "The error code."),
+        ("locale" = String, Path, description = "The translation locale."),
+    ),
+    request_body = SetErrorCodeTranslationBody,
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Translation not registered.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 202,
+            description = "Translation registered.",
+            body = ErrorCodeTranslation,
+        ),
+    ),
+)]
+#[put("/prefixes/{prefix}/codes/{code}/translations/{locale}")]
+pub async fn set_error_code_translation_url(
+    path: web::Path<(String, i32, String)>,
+    body: web::Json<SetErrorCodeTranslationBody>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    let (prefix, code, locale) = path.into_inner();
+
+    match set_error_code_translation(
+        profile.to_profile(),
+        prefix,
+        code,
+        locale,
+        body.message.to_owned(),
+        body.details.to_owned(),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(app_module.resolve_ref()),
+    )
+    .await
+    {
+        Err(err) => handle_mapped_error(err),
+        Ok(res) => HttpResponse::Accepted().json(res),
+    }
+}
+
+/// Delete the translation of an error code.
+///
+/// Callers preferring the locale get the default message afterwards.
+///
+#[utoipa::path(
+    delete,
+    operation_id = "delete_error_code_translation",
+    params(
+        ("prefix" = String, Path, description = "The error prefix."),
+        ("code" = i32, Path, description = "The error code."),
+        ("locale" = String, Path, description = "The translation locale."),
+    ),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Translation not deleted.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 204,
+            description = "Translation deleted.",
+        ),
+    ),
+)]
+#[delete("/prefixes/{prefix}/codes/{code}/translations/{locale}")]
+pub async fn delete_error_code_translation_url(
+    path: web::Path<(String, i32, String)>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    let (prefix, code, locale) = path.into_inner();
+
+    match delete_error_code_translation(
+        profile.to_profile(),
+        prefix,
+        code,
+        locale,
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(app_module.resolve_ref()),
     )
     .await
     {
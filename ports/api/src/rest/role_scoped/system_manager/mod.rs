@@ -1,2 +1,3 @@
+pub(crate) mod email_template_endpoints;
 pub(crate) mod error_code_endpoints;
 pub(crate) mod webhook_endpoints;
@@ -136,6 +136,11 @@ pub async fn guest_user_to_subscription_manager_account_url(
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
         Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(&*sql_app_module.resolve_ref()),
+        Box::new(sql_app_module.resolve_ref()),
     )
     .await
     {
@@ -210,6 +215,7 @@ pub async fn revoke_user_guest_to_subscription_manager_account_url(
         role_id,
         email,
         Box::new(&*app_module.resolve_ref()),
+        Box::new(app_module.resolve_ref()),
     )
     .await
     {
@@ -0,0 +1,344 @@
+use crate::{
+    dtos::{MyceliumProfileData, TenantData},
+    rest::shared::PaginationParams,
+};
+
+use crate::models::active_backend_modules::SqlAppModule;
+use actix_web::{delete, get, patch, post, web, Responder};
+use myc_core::{
+    domain::dtos::guest_role::{GuestRole, Permission},
+    use_cases::role_scoped::tenant_manager::{
+        create_tenant_guest_role, delete_tenant_guest_role,
+        list_tenant_guest_roles, update_tenant_guest_role,
+    },
+};
+use myc_http_tools::{
+    utils::HttpJsonResponse,
+    wrappers::default_response_to_http_response::{
+        delete_response_kind, fetch_many_response_kind,
+        get_or_create_response_kind, handle_mapped_error,
+        updating_response_kind,
+    },
+};
+use serde::Deserialize;
+use shaku::HasComponent;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+// ? ---------------------------------------------------------------------------
+// ? Configure application
+// ? ---------------------------------------------------------------------------
+
+pub fn configure(config: &mut web::ServiceConfig) {
+    config
+        .service(create_tenant_guest_role_url)
+        .service(list_tenant_guest_roles_url)
+        .service(update_tenant_guest_role_url)
+        .service(delete_tenant_guest_role_url);
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Define API structs
+// ? ---------------------------------------------------------------------------
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTenantGuestRoleBody {
+    pub name: String,
+    pub description: Option<String>,
+    pub permission: Option<Permission>,
+}
+
+#[derive(Deserialize, ToSchema, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct ListTenantGuestRolesParams {
+    /// The name of the guest role.
+    pub name: Option<String>,
+
+    /// The slug of the guest role.
+    pub slug: Option<String>,
+}
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateTenantGuestRoleBody {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub permission: Option<Permission>,
+
+    /// Named permissions granted by the role, as `invoices:approve`. Replaces
+    /// the current ones when given.
+    pub permissions: Option<Vec<String>>,
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Define API paths
+// ? ---------------------------------------------------------------------------
+
+/// Create Tenant Guest Role
+///
+/// Register a guest role in the tenant catalogue. Tenant roles are listed and
+/// granted only inside the tenant.
+#[utoipa::path(
+    post,
+    operation_id = "create_tenant_guest_role",
+    params(
+        (
+            "x-mycelium-tenant-id" = Uuid,
+            Header,
+            description = "The tenant unique id."
+        ),
+    ),
+    request_body = CreateTenantGuestRoleBody,
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 200,
+            description = "Guest role already exists.",
+            body = GuestRole,
+        ),
+        (
+            status = 201,
+            description = "Guest role created.",
+            body = GuestRole,
+        ),
+    ),
+)]
+#[post("")]
+pub async fn create_tenant_guest_role_url(
+    tenant: TenantData,
+    body: web::Json<CreateTenantGuestRoleBody>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    match create_tenant_guest_role(
+        profile.to_profile(),
+        tenant.tenant_id().to_owned(),
+        body.name.to_owned(),
+        body.description.to_owned(),
+        body.permission.to_owned(),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(res) => get_or_create_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
+/// List Tenant Guest Roles
+///
+/// List the guest roles available to the tenant: the tenant roles and the
+/// instance-wide ones.
+#[utoipa::path(
+    get,
+    operation_id = "list_tenant_guest_roles",
+    params(
+        ListTenantGuestRolesParams,
+        PaginationParams,
+        (
+            "x-mycelium-tenant-id" = Uuid,
+            Header,
+            description = "The tenant unique id."
+        ),
+    ),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 204,
+            description = "Not found.",
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 200,
+            description = "Success.",
+            body = [GuestRole],
+        ),
+    ),
+)]
+#[get("")]
+pub async fn list_tenant_guest_roles_url(
+    tenant: TenantData,
+    info: web::Query<ListTenantGuestRolesParams>,
+    page: web::Query<PaginationParams>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    match list_tenant_guest_roles(
+        profile.to_profile(),
+        tenant.tenant_id().to_owned(),
+        info.name.to_owned(),
+        info.slug.to_owned(),
+        page.page_size,
+        page.skip,
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(res) => fetch_many_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
+/// Partial Update Tenant Guest Role
+///
+/// Update the name, description or permissions of a tenant guest role.
+/// Instance-wide roles are not updated through this endpoint.
+#[utoipa::path(
+    patch,
+    operation_id = "update_tenant_guest_role",
+    params(
+        (
+            "x-mycelium-tenant-id" = Uuid,
+            Header,
+            description = "The tenant unique id."
+        ),
+        ("guest_role_id" = Uuid, Path, description = "The guest-role primary key."),
+    ),
+    request_body = UpdateTenantGuestRoleBody,
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Guest role not found or not updated.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 202,
+            description = "Guest role updated.",
+            body = GuestRole,
+        ),
+    ),
+)]
+#[patch("/{guest_role_id}")]
+pub async fn update_tenant_guest_role_url(
+    tenant: TenantData,
+    path: web::Path<Uuid>,
+    body: web::Json<UpdateTenantGuestRoleBody>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    match update_tenant_guest_role(
+        profile.to_profile(),
+        tenant.tenant_id().to_owned(),
+        path.into_inner(),
+        body.name.to_owned(),
+        body.description.to_owned(),
+        body.permission.to_owned(),
+        body.permissions.to_owned(),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(res) => updating_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
+/// Delete Tenant Guest Role
+///
+/// Delete a tenant guest role. Instance-wide roles are not deleted through
+/// this endpoint.
+#[utoipa::path(
+    delete,
+    operation_id = "delete_tenant_guest_role",
+    params(
+        (
+            "x-mycelium-tenant-id" = Uuid,
+            Header,
+            description = "The tenant unique id."
+        ),
+        ("guest_role_id" = Uuid, Path, description = "The guest-role primary key."),
+    ),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Guest role not found or not deleted.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 204,
+            description = "Guest role deleted.",
+        ),
+    ),
+)]
+#[delete("/{guest_role_id}")]
+pub async fn delete_tenant_guest_role_url(
+    tenant: TenantData,
+    path: web::Path<Uuid>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    match delete_tenant_guest_role(
+        profile.to_profile(),
+        tenant.tenant_id().to_owned(),
+        path.into_inner(),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(res) => delete_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
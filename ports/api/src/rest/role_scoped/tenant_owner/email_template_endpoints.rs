@@ -0,0 +1,355 @@
+use std::str::FromStr;
+
+use crate::dtos::{MyceliumProfileData, TenantData};
+
+use crate::models::active_backend_modules::SqlAppModule;
+use actix_web::{delete, get, post, put, web, HttpResponse, Responder};
+use myc_core::{
+    domain::{
+        dtos::tenant::{
+            EmailTemplateKind, RenderedEmailTemplate, TenantEmailTemplate,
+        },
+        entities::{TenantDeletion, TenantFetching, TenantRegistration},
+    },
+    models::AccountLifeCycle,
+    use_cases::role_scoped::tenant_owner::{
+        delete_tenant_email_template, list_tenant_email_templates,
+        preview_tenant_email_template, set_tenant_email_template,
+    },
+};
+use myc_http_tools::{
+    utils::HttpJsonResponse,
+    wrappers::default_response_to_http_response::{
+        delete_response_kind, fetch_many_response_kind, handle_mapped_error,
+    },
+};
+use serde::Deserialize;
+use shaku::HasComponent;
+use utoipa::ToSchema;
+
+// ? ---------------------------------------------------------------------------
+// ? Configure application
+// ? ---------------------------------------------------------------------------
+
+pub fn configure(config: &mut web::ServiceConfig) {
+    config
+        .service(list_tenant_email_templates_url)
+        .service(preview_tenant_email_template_url)
+        .service(set_tenant_email_template_url)
+        .service(delete_tenant_email_template_url);
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Define API structs
+// ? ---------------------------------------------------------------------------
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SetTenantEmailTemplateBody {
+    subject: String,
+    body: String,
+}
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewTenantEmailTemplateBody {
+    kind: EmailTemplateKind,
+    locale: String,
+    subject: Option<String>,
+    body: Option<String>,
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Define API paths
+// ? ---------------------------------------------------------------------------
+
+/// List the email template overrides of a tenant
+#[utoipa::path(
+    get,
+    operation_id = "list_tenant_email_templates",
+    params(
+        (
+            "x-mycelium-tenant-id" = Uuid,
+            Header,
+            description = "The tenant unique id."
+        ),
+    ),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 204,
+            description = "Not found.",
+        ),
+        (
+            status = 200,
+            description = "Fetching success.",
+            body = [TenantEmailTemplate],
+        ),
+    ),
+)]
+#[get("")]
+pub async fn list_tenant_email_templates_url(
+    tenant: TenantData,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    match list_tenant_email_templates(
+        profile.to_profile(),
+        tenant.tenant_id().to_owned(),
+        Box::new(&*app_module.resolve_ref() as &dyn TenantFetching),
+    )
+    .await
+    {
+        Ok(res) => fetch_many_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
+/// Create or replace an email template override
+///
+/// The template is validated and test-rendered against sample parameters
+/// before being stored. Templates are rendered in a sandbox: environment
+/// access is disabled and no other template may be included.
+///
+#[utoipa::path(
+    put,
+    operation_id = "set_tenant_email_template",
+    params(
+        (
+            "x-mycelium-tenant-id" = Uuid,
+            Header,
+            description = "The tenant unique id."
+        ),
+        ("kind" = EmailTemplateKind, Path, description = "The email kind."),
+        ("locale" = String, Path, description = "The template locale."),
+    ),
+    request_body = SetTenantEmailTemplateBody,
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Invalid template.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 200,
+            description = "Template stored.",
+            body = TenantEmailTemplate,
+        ),
+    ),
+)]
+#[put("/{kind}/{locale}")]
+pub async fn set_tenant_email_template_url(
+    tenant: TenantData,
+    path: web::Path<(String, String)>,
+    body: web::Json<SetTenantEmailTemplateBody>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    let (kind, locale) = path.into_inner();
+
+    let kind = match EmailTemplateKind::from_str(&kind) {
+        Ok(kind) => kind,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(
+                HttpJsonResponse::new_message(
+                    "The email kind is invalid".to_string(),
+                ),
+            );
+        }
+    };
+
+    match set_tenant_email_template(
+        profile.to_profile(),
+        tenant.tenant_id().to_owned(),
+        kind,
+        locale,
+        body.subject.to_owned(),
+        body.body.to_owned(),
+        Box::new(&*app_module.resolve_ref() as &dyn TenantRegistration),
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(template) => HttpResponse::Ok().json(template),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
+/// Delete an email template override
+///
+/// The notification falls back to the default file templates.
+///
+#[utoipa::path(
+    delete,
+    operation_id = "delete_tenant_email_template",
+    params(
+        (
+            "x-mycelium-tenant-id" = Uuid,
+            Header,
+            description = "The tenant unique id."
+        ),
+        ("kind" = EmailTemplateKind, Path, description = "The email kind."),
+        ("locale" = String, Path, description = "The template locale."),
+    ),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Template not deleted.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 204,
+            description = "Template deleted.",
+        ),
+    ),
+)]
+#[delete("/{kind}/{locale}")]
+pub async fn delete_tenant_email_template_url(
+    tenant: TenantData,
+    path: web::Path<(String, String)>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    let (kind, locale) = path.into_inner();
+
+    let kind = match EmailTemplateKind::from_str(&kind) {
+        Ok(kind) => kind,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(
+                HttpJsonResponse::new_message(
+                    "The email kind is invalid".to_string(),
+                ),
+            );
+        }
+    };
+
+    match delete_tenant_email_template(
+        profile.to_profile(),
+        tenant.tenant_id().to_owned(),
+        kind,
+        locale,
+        Box::new(&*app_module.resolve_ref() as &dyn TenantDeletion),
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(res) => delete_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
+/// Preview an email template
+///
+/// Renders the draft informed in the body or, when subject and body are
+/// omitted, the stored override (or the default templates) of the kind and
+/// locale. Use-case parameters are filled with sample values.
+///
+#[utoipa::path(
+    post,
+    operation_id = "preview_tenant_email_template",
+    params(
+        (
+            "x-mycelium-tenant-id" = Uuid,
+            Header,
+            description = "The tenant unique id."
+        ),
+    ),
+    request_body = PreviewTenantEmailTemplateBody,
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Invalid template.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 200,
+            description = "Rendered template.",
+            body = RenderedEmailTemplate,
+        ),
+    ),
+)]
+#[post("/preview")]
+pub async fn preview_tenant_email_template_url(
+    tenant: TenantData,
+    body: web::Json<PreviewTenantEmailTemplateBody>,
+    profile: MyceliumProfileData,
+    life_cycle_settings: web::Data<AccountLifeCycle>,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    let body = body.into_inner();
+
+    match preview_tenant_email_template(
+        profile.to_profile(),
+        tenant.tenant_id().to_owned(),
+        body.kind,
+        body.locale,
+        body.subject,
+        body.body,
+        life_cycle_settings.get_ref().clone(),
+        Box::new(&*app_module.resolve_ref() as &dyn TenantFetching),
+    )
+    .await
+    {
+        Ok(rendered) => HttpResponse::Ok().json(rendered),
+        Err(err) => handle_mapped_error(err),
+    }
+}
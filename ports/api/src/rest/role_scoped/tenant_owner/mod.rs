@@ -1,5 +1,7 @@
 pub(crate) mod account_endpoints;
+pub(crate) mod email_template_endpoints;
 pub(crate) mod meta_endpoints;
 pub(crate) mod owner_endpoints;
+pub(crate) mod scim_credential_endpoints;
 pub(crate) mod telegram_config_endpoints;
 pub(crate) mod tenant_endpoints;
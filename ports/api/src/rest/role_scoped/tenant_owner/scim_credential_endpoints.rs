@@ -0,0 +1,240 @@
+use crate::dtos::{MyceliumProfileData, TenantData};
+
+use crate::models::active_backend_modules::SqlAppModule;
+use actix_web::{delete, get, post, web, HttpResponse, Responder};
+use myc_core::{
+    domain::{
+        dtos::scim::{IssuedScimCredential, ScimCredential},
+        entities::{
+            ScimCredentialDeletion, ScimCredentialFetching,
+            ScimCredentialRegistration,
+        },
+    },
+    use_cases::role_scoped::tenant_owner::{
+        create_scim_credential, delete_scim_credential, list_scim_credentials,
+    },
+};
+use myc_http_tools::{
+    utils::HttpJsonResponse,
+    wrappers::default_response_to_http_response::{
+        delete_response_kind, fetch_many_response_kind, handle_mapped_error,
+    },
+};
+use serde::Deserialize;
+use shaku::HasComponent;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+// ? ---------------------------------------------------------------------------
+// ? Configure application
+// ? ---------------------------------------------------------------------------
+
+pub fn configure(config: &mut web::ServiceConfig) {
+    config
+        .service(create_scim_credential_url)
+        .service(list_scim_credentials_url)
+        .service(delete_scim_credential_url);
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Define API structs
+// ? ---------------------------------------------------------------------------
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateScimCredentialBody {
+    /// A name to identify the SCIM client, as the identity provider
+    name: String,
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Define API paths
+// ? ---------------------------------------------------------------------------
+
+/// Issue a SCIM credential
+///
+/// The credential authenticates the SCIM client of the tenant, as a bearer
+/// token of the `/scim/v2` endpoints. The secret is returned once.
+///
+#[utoipa::path(
+    post,
+    operation_id = "create_scim_credential",
+    params(
+        (
+            "x-mycelium-tenant-id" = Uuid,
+            Header,
+            description = "The tenant unique id."
+        ),
+    ),
+    request_body = CreateScimCredentialBody,
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Credential not created.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 201,
+            description = "Credential created.",
+            body = IssuedScimCredential,
+        ),
+    ),
+)]
+#[post("")]
+pub async fn create_scim_credential_url(
+    tenant: TenantData,
+    body: web::Json<CreateScimCredentialBody>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    match create_scim_credential(
+        profile.to_profile(),
+        tenant.tenant_id().to_owned(),
+        body.name.to_owned(),
+        Box::new(&*app_module.resolve_ref() as &dyn ScimCredentialRegistration),
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(credential) => HttpResponse::Created().json(credential),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
+/// List the SCIM credentials of a tenant
+///
+/// Secrets are not listed.
+///
+#[utoipa::path(
+    get,
+    operation_id = "list_scim_credentials",
+    params(
+        (
+            "x-mycelium-tenant-id" = Uuid,
+            Header,
+            description = "The tenant unique id."
+        ),
+    ),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 204,
+            description = "Not found.",
+        ),
+        (
+            status = 200,
+            description = "Fetching success.",
+            body = [ScimCredential],
+        ),
+    ),
+)]
+#[get("")]
+pub async fn list_scim_credentials_url(
+    tenant: TenantData,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    match list_scim_credentials(
+        profile.to_profile(),
+        tenant.tenant_id().to_owned(),
+        Box::new(&*app_module.resolve_ref() as &dyn ScimCredentialFetching),
+    )
+    .await
+    {
+        Ok(res) => fetch_many_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
+/// Revoke a SCIM credential
+///
+/// The SCIM client loses access at once. Provisioned users and groups are
+/// kept.
+///
+#[utoipa::path(
+    delete,
+    operation_id = "delete_scim_credential",
+    params(
+        (
+            "x-mycelium-tenant-id" = Uuid,
+            Header,
+            description = "The tenant unique id."
+        ),
+        ("id" = Uuid, Path, description = "The credential id."),
+    ),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Credential not deleted.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 204,
+            description = "Credential deleted.",
+        ),
+    ),
+)]
+#[delete("/{id}")]
+pub async fn delete_scim_credential_url(
+    tenant: TenantData,
+    path: web::Path<Uuid>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    match delete_scim_credential(
+        profile.to_profile(),
+        tenant.tenant_id().to_owned(),
+        path.into_inner(),
+        Box::new(&*app_module.resolve_ref() as &dyn ScimCredentialDeletion),
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(res) => delete_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
@@ -1,15 +1,20 @@
 use crate::dtos::MyceliumProfileData;
 
 use crate::models::active_backend_modules::SqlAppModule;
-use actix_web::{patch, web, Responder};
-use myc_core::use_cases::role_scoped::tenant_owner::{
-    update_tenant_archiving_status, update_tenant_name_and_description,
-    update_tenant_trashing_status, update_tenant_verifying_status,
+use actix_web::{get, patch, web, Responder};
+use myc_core::{
+    domain::dtos::tenant::Tenant,
+    use_cases::role_scoped::tenant_owner::{
+        list_tenant_descendants, restore_trashed_tenant,
+        update_tenant_archiving_status, update_tenant_name_and_description,
+        update_tenant_parent, update_tenant_trashing_status,
+        update_tenant_verifying_status,
+    },
 };
 use myc_http_tools::{
     utils::HttpJsonResponse,
     wrappers::default_response_to_http_response::{
-        handle_mapped_error, updating_response_kind,
+        fetch_many_response_kind, handle_mapped_error, updating_response_kind,
     },
 };
 use serde::Deserialize;
@@ -26,7 +31,10 @@ pub fn configure(config: &mut web::ServiceConfig) {
         .service(update_tenant_name_and_description_url)
         .service(update_tenant_archiving_status_url)
         .service(update_tenant_trashing_status_url)
-        .service(update_tenant_verifying_status_url);
+        .service(restore_trashed_tenant_url)
+        .service(update_tenant_verifying_status_url)
+        .service(update_tenant_parent_url)
+        .service(list_tenant_descendants_url);
 }
 
 // ? ---------------------------------------------------------------------------
@@ -40,6 +48,13 @@ pub struct UpdateTenantNameAndDescriptionBody {
     description: Option<String>,
 }
 
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateTenantParentBody {
+    /// The new parent tenant. Omit it to detach the tenant from its parent.
+    parent_id: Option<Uuid>,
+}
+
 // ? ---------------------------------------------------------------------------
 // ? Define API paths
 // ? ---------------------------------------------------------------------------
@@ -210,6 +225,63 @@ pub async fn update_tenant_trashing_status_url(
     }
 }
 
+/// Restore a trashed tenant
+///
+/// Trashed tenants could be restored until purged, once the trash retention
+/// period ends.
+#[utoipa::path(
+    patch,
+    operation_id = "restore_trashed_tenant",
+    params(
+        ("tenant_id" = Uuid, Path, description = "The tenant unique id."),
+    ),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Tenant not in the trash.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 202,
+            description = "Tenant restored.",
+        ),
+    ),
+)]
+#[patch("/{tenant_id}/restore")]
+pub async fn restore_trashed_tenant_url(
+    path: web::Path<Uuid>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    match restore_trashed_tenant(
+        profile.to_profile(),
+        path.into_inner(),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(res) => updating_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
 /// Include a verified status to a tenant
 #[utoipa::path(
     patch,
@@ -263,3 +335,119 @@ pub async fn update_tenant_verifying_status_url(
         Err(err) => handle_mapped_error(err),
     }
 }
+
+/// Attach a tenant to a parent tenant, or detach it from its parent
+///
+/// Owners of the parent tenant own the tenant too, and the tenant inherits
+/// the parent meta it does not override. The profile should own the tenant
+/// and the new parent, or the current parent when detaching.
+#[utoipa::path(
+    patch,
+    operation_id = "update_tenant_parent",
+    params(
+        ("tenant_id" = Uuid, Path, description = "The tenant unique id."),
+    ),
+    request_body = UpdateTenantParentBody,
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Tenant not updated.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 202,
+            description = "Tenant updated.",
+            body = Tenant,
+        ),
+    ),
+)]
+#[patch("/{tenant_id}/parent")]
+pub async fn update_tenant_parent_url(
+    path: web::Path<Uuid>,
+    body: web::Json<UpdateTenantParentBody>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    match update_tenant_parent(
+        profile.to_profile(),
+        path.into_inner(),
+        body.parent_id.to_owned(),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(res) => updating_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
+/// List the descendants of a tenant
+///
+/// Children are listed before their own children.
+#[utoipa::path(
+    get,
+    operation_id = "list_tenant_descendants",
+    params(
+        ("tenant_id" = Uuid, Path, description = "The tenant unique id."),
+    ),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 204,
+            description = "Not found.",
+        ),
+        (
+            status = 200,
+            description = "Fetching success.",
+            body = [Tenant],
+        ),
+    ),
+)]
+#[get("/{tenant_id}/descendants")]
+pub async fn list_tenant_descendants_url(
+    path: web::Path<Uuid>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    match list_tenant_descendants(
+        profile.to_profile(),
+        path.into_inner(),
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(res) => fetch_many_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
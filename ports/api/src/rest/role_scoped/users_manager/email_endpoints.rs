@@ -0,0 +1,229 @@
+use crate::{dtos::MyceliumProfileData, rest::shared::PaginationParams};
+
+use crate::models::active_backend_modules::SqlAppModule;
+use actix_web::{delete, get, web, HttpResponse, Responder};
+use myc_core::{
+    domain::dtos::{
+        email::Email,
+        email_delivery::{
+            EmailSuppression, MessageDelivery, MessageDeliveryStatus,
+        },
+    },
+    use_cases::role_scoped::users_manager::email::{
+        delete_email_suppression, list_email_suppressions,
+        list_message_deliveries,
+    },
+};
+use myc_http_tools::{
+    utils::HttpJsonResponse,
+    wrappers::default_response_to_http_response::{
+        delete_response_kind, fetch_many_response_kind, handle_mapped_error,
+    },
+};
+use serde::Deserialize;
+use shaku::HasComponent;
+use utoipa::{IntoParams, ToSchema};
+
+// ? ---------------------------------------------------------------------------
+// ? Configure application
+// ? ---------------------------------------------------------------------------
+
+pub fn configure(config: &mut web::ServiceConfig) {
+    config
+        .service(list_message_deliveries_url)
+        .service(list_email_suppressions_url)
+        .service(delete_email_suppression_url);
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Define API structs
+// ? ---------------------------------------------------------------------------
+
+#[derive(Deserialize, ToSchema, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct ListMessageDeliveriesParams {
+    recipient: Option<String>,
+    status: Option<MessageDeliveryStatus>,
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Define API paths
+//
+// Email
+//
+// ? ---------------------------------------------------------------------------
+
+/// List message deliveries
+///
+/// List the delivery status of the messages sent by the platform, newest
+/// first. Results can be filtered by recipient and status.
+#[utoipa::path(
+    get,
+    operation_id = "list_message_deliveries",
+    params(
+        ListMessageDeliveriesParams,
+        PaginationParams,
+    ),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 204,
+            description = "Not found.",
+        ),
+        (
+            status = 200,
+            description = "Fetching success.",
+            body = [MessageDelivery],
+        ),
+    ),
+)]
+#[get("/deliveries")]
+pub async fn list_message_deliveries_url(
+    info: web::Query<ListMessageDeliveriesParams>,
+    page: web::Query<PaginationParams>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    match list_message_deliveries(
+        profile.to_profile(),
+        info.recipient.to_owned(),
+        info.status.to_owned(),
+        page.page_size.to_owned(),
+        page.skip.to_owned(),
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(res) => fetch_many_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
+/// List suppressed email addresses
+///
+/// Addresses which hard-bounced or complained receive no further email.
+#[utoipa::path(
+    get,
+    operation_id = "list_email_suppressions",
+    params(PaginationParams),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 204,
+            description = "Not found.",
+        ),
+        (
+            status = 200,
+            description = "Fetching success.",
+            body = [EmailSuppression],
+        ),
+    ),
+)]
+#[get("/suppressions")]
+pub async fn list_email_suppressions_url(
+    page: web::Query<PaginationParams>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    match list_email_suppressions(
+        profile.to_profile(),
+        page.page_size.to_owned(),
+        page.skip.to_owned(),
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(res) => fetch_many_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
+/// Lift an email suppression
+///
+/// Use it once the owner of a bounced address confirms it is reachable again.
+#[utoipa::path(
+    delete,
+    operation_id = "delete_email_suppression",
+    params(
+        ("email" = String, Path, description = "The suppressed address."),
+    ),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Suppression not deleted.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 204,
+            description = "Suppression deleted.",
+        ),
+    ),
+)]
+#[delete("/suppressions/{email}")]
+pub async fn delete_email_suppression_url(
+    path: web::Path<String>,
+    profile: MyceliumProfileData,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    let email = match Email::from_string(path.to_owned()) {
+        Ok(email) => email,
+        Err(err) => {
+            return HttpResponse::BadRequest()
+                .json(HttpJsonResponse::new_message(err.to_string()))
+        }
+    };
+
+    match delete_email_suppression(
+        profile.to_profile(),
+        email,
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(res) => delete_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
@@ -1 +1,2 @@
 pub(crate) mod account_endpoints;
+pub(crate) mod email_endpoints;
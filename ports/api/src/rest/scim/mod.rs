@@ -0,0 +1,1025 @@
+use crate::models::active_backend_modules::SqlAppModule;
+
+use actix_web::{
+    delete, get, http::StatusCode, patch, post, put, web, HttpRequest,
+    HttpResponse, Responder,
+};
+use myc_core::{
+    domain::{
+        dtos::{
+            native_error_codes::NativeErrorCodes,
+            scim::{
+                ScimCredential, ScimErrorType, ScimGroup, ScimPatchRequest,
+                ScimUpdate, ScimUser, SCIM_ERROR_SCHEMA,
+                SCIM_GROUP_EXTENSION_SCHEMA, SCIM_GROUP_SCHEMA,
+                SCIM_LIST_RESPONSE_SCHEMA, SCIM_MAX_PAGE_SIZE,
+                SCIM_USER_SCHEMA,
+            },
+        },
+        entities::{
+            AccountFetching, AccountRegistration, AccountUpdating,
+            ScimCredentialFetching, ScimResourceDeletion, ScimResourceFetching,
+            ScimResourceRegistration, ScimResourceUpdating, UserFetching,
+            UserRegistration,
+        },
+    },
+    models::AccountLifeCycle,
+    use_cases::service::scim::{
+        authenticate_scim_credential, create_scim_group, create_scim_user,
+        delete_scim_group, delete_scim_user, get_scim_group, get_scim_user,
+        list_scim_groups, list_scim_users, update_scim_group, update_scim_user,
+    },
+};
+use mycelium_base::{
+    entities::{DeletionResponseKind, FetchResponseKind},
+    utils::errors::MappedErrors,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use shaku::HasComponent;
+use tracing::error;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+/// The media type of the SCIM messages
+const SCIM_CONTENT_TYPE: &str = "application/scim+json";
+
+// ? ---------------------------------------------------------------------------
+// ? Configure application
+// ? ---------------------------------------------------------------------------
+
+pub fn configure(config: &mut web::ServiceConfig) {
+    config
+        .service(service_provider_config_url)
+        .service(resource_types_url)
+        .service(schemas_url)
+        .service(list_scim_users_url)
+        .service(create_scim_user_url)
+        .service(get_scim_user_url)
+        .service(replace_scim_user_url)
+        .service(patch_scim_user_url)
+        .service(delete_scim_user_url)
+        .service(list_scim_groups_url)
+        .service(create_scim_group_url)
+        .service(get_scim_group_url)
+        .service(replace_scim_group_url)
+        .service(patch_scim_group_url)
+        .service(delete_scim_group_url);
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Define API structs
+// ? ---------------------------------------------------------------------------
+
+#[derive(Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct ListScimResourcesParams {
+    /// A SCIM filter expression, as `userName eq "ana@example.com"`
+    filter: Option<String>,
+
+    /// The one-based index of the first resource
+    start_index: Option<usize>,
+
+    /// The page size, up to 200
+    count: Option<usize>,
+}
+
+/// A SCIM error, as defined by RFC 7644, section 3.12
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimErrorResponse {
+    schemas: Vec<String>,
+
+    status: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scim_type: Option<String>,
+
+    detail: String,
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Define API helpers
+// ? ---------------------------------------------------------------------------
+
+fn scim_response<T: Serialize>(status: StatusCode, body: T) -> HttpResponse {
+    HttpResponse::build(status)
+        .content_type(SCIM_CONTENT_TYPE)
+        .json(body)
+}
+
+fn scim_error_response(
+    status: StatusCode,
+    scim_type: Option<ScimErrorType>,
+    detail: String,
+) -> HttpResponse {
+    scim_response(
+        status,
+        ScimErrorResponse {
+            schemas: vec![SCIM_ERROR_SCHEMA.to_string()],
+            status: status.as_u16().to_string(),
+            scim_type: scim_type.map(|scim_type| scim_type.to_string()),
+            detail,
+        },
+    )
+}
+
+/// Answer a failed SCIM request with a SCIM error
+///
+/// Errors tagged with a SCIM error type are answered as bad requests, except
+/// for uniqueness conflicts. Unexpected errors are logged and answered
+/// without details.
+fn handle_scim_error(err: MappedErrors) -> HttpResponse {
+    if err.has_str_code(NativeErrorCodes::MYC00008.as_str()) {
+        return scim_error_response(StatusCode::UNAUTHORIZED, None, err.msg());
+    }
+
+    if err.is_in(vec![
+        NativeErrorCodes::MYC00013,
+        NativeErrorCodes::MYC00019,
+        NativeErrorCodes::MYC00020,
+    ]) {
+        return scim_error_response(StatusCode::FORBIDDEN, None, err.msg());
+    }
+
+    if let Some(scim_type) = ScimErrorType::all()
+        .into_iter()
+        .find(|scim_type| err.has_str_code(scim_type.as_str()))
+    {
+        let status = match scim_type {
+            ScimErrorType::Uniqueness => StatusCode::CONFLICT,
+            _ => StatusCode::BAD_REQUEST,
+        };
+
+        return scim_error_response(status, Some(scim_type), err.msg());
+    }
+
+    if err.is_in(vec![
+        NativeErrorCodes::MYC00002,
+        NativeErrorCodes::MYC00003,
+        NativeErrorCodes::MYC00014,
+        NativeErrorCodes::MYC00017,
+    ]) {
+        return scim_error_response(
+            StatusCode::CONFLICT,
+            Some(ScimErrorType::Uniqueness),
+            err.msg(),
+        );
+    }
+
+    if err.expected() {
+        return scim_error_response(StatusCode::BAD_REQUEST, None, err.msg());
+    }
+
+    error!("Unexpected error on SCIM request: {err}");
+
+    scim_error_response(
+        StatusCode::INTERNAL_SERVER_ERROR,
+        None,
+        "Unexpected error".to_string(),
+    )
+}
+
+/// Authenticate the SCIM client by the bearer credential of the request
+async fn authenticate(
+    req: &HttpRequest,
+    app_module: &SqlAppModule,
+) -> Result<ScimCredential, HttpResponse> {
+    let secret = match req
+        .headers()
+        .get(actix_web::http::header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| {
+            value
+                .strip_prefix("Bearer ")
+                .or(value.strip_prefix("bearer "))
+        }) {
+        Some(secret) => secret.to_string(),
+        None => {
+            return Err(scim_error_response(
+                StatusCode::UNAUTHORIZED,
+                None,
+                "A bearer SCIM credential is required".to_string(),
+            ))
+        }
+    };
+
+    authenticate_scim_credential(
+        secret,
+        Box::new(&*app_module.resolve_ref() as &dyn ScimCredentialFetching),
+    )
+    .await
+    .map_err(handle_scim_error)
+}
+
+/// Parse the JSON body of a SCIM request
+///
+/// SCIM clients send the `application/scim+json` media type, which the JSON
+/// extractor rejects, so the body is parsed here.
+fn parse_body<T: for<'de> Deserialize<'de>>(
+    body: &web::Bytes,
+) -> Result<T, HttpResponse> {
+    serde_json::from_slice(body).map_err(|err| {
+        scim_error_response(
+            StatusCode::BAD_REQUEST,
+            Some(ScimErrorType::InvalidSyntax),
+            format!("Invalid request body: {err}"),
+        )
+    })
+}
+
+fn not_found(kind: &str, id: Uuid) -> HttpResponse {
+    scim_error_response(
+        StatusCode::NOT_FOUND,
+        None,
+        format!("{kind} {id} not found"),
+    )
+}
+
+fn attribute(
+    name: &str,
+    kind: &str,
+    multi_valued: bool,
+    required: bool,
+) -> Value {
+    json!({
+        "name": name,
+        "type": kind,
+        "multiValued": multi_valued,
+        "required": required,
+        "caseExact": false,
+        "mutability": "readWrite",
+        "returned": "default",
+        "uniqueness": if name == "userName" || name == "displayName" {
+            "server"
+        } else {
+            "none"
+        },
+    })
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Define API paths
+// ? ---------------------------------------------------------------------------
+
+/// Describe the SCIM features supported by the service
+#[utoipa::path(
+    get,
+    operation_id = "scim_service_provider_config",
+    responses(
+        (status = 200, description = "Service provider configuration."),
+    ),
+)]
+#[get("/ServiceProviderConfig")]
+pub async fn service_provider_config_url() -> impl Responder {
+    scim_response(
+        StatusCode::OK,
+        json!({
+            "schemas": [
+                "urn:ietf:params:scim:schemas:core:2.0:ServiceProviderConfig"
+            ],
+            "patch": { "supported": true },
+            "bulk": {
+                "supported": false,
+                "maxOperations": 0,
+                "maxPayloadSize": 0,
+            },
+            "filter": {
+                "supported": true,
+                "maxResults": SCIM_MAX_PAGE_SIZE,
+            },
+            "changePassword": { "supported": false },
+            "sort": { "supported": false },
+            "etag": { "supported": false },
+            "authenticationSchemes": [{
+                "type": "oauthbearertoken",
+                "name": "Bearer credential",
+                "description": "A SCIM credential issued by the tenant owner",
+                "primary": true,
+            }],
+        }),
+    )
+}
+
+/// List the SCIM resource types served
+#[utoipa::path(
+    get,
+    operation_id = "scim_resource_types",
+    responses((status = 200, description = "Resource types.")),
+)]
+#[get("/ResourceTypes")]
+pub async fn resource_types_url() -> impl Responder {
+    let resources = vec![
+        json!({
+            "schemas": ["urn:ietf:params:scim:schemas:core:2.0:ResourceType"],
+            "id": "User",
+            "name": "User",
+            "endpoint": "/Users",
+            "schema": SCIM_USER_SCHEMA,
+        }),
+        json!({
+            "schemas": ["urn:ietf:params:scim:schemas:core:2.0:ResourceType"],
+            "id": "Group",
+            "name": "Group",
+            "endpoint": "/Groups",
+            "schema": SCIM_GROUP_SCHEMA,
+            "schemaExtensions": [{
+                "schema": SCIM_GROUP_EXTENSION_SCHEMA,
+                "required": false,
+            }],
+        }),
+    ];
+
+    scim_response(
+        StatusCode::OK,
+        json!({
+            "schemas": [SCIM_LIST_RESPONSE_SCHEMA],
+            "totalResults": resources.len(),
+            "startIndex": 1,
+            "itemsPerPage": resources.len(),
+            "Resources": resources,
+        }),
+    )
+}
+
+/// List the SCIM schemas served
+#[utoipa::path(
+    get,
+    operation_id = "scim_schemas",
+    responses((status = 200, description = "Schemas.")),
+)]
+#[get("/Schemas")]
+pub async fn schemas_url() -> impl Responder {
+    let resources = vec![
+        json!({
+            "id": SCIM_USER_SCHEMA,
+            "name": "User",
+            "description": "A user, mapped onto a Mycelium user and its \
+                personal account",
+            "attributes": [
+                attribute("userName", "string", false, true),
+                attribute("externalId", "string", false, false),
+                attribute("displayName", "string", false, false),
+                attribute("name", "complex", false, false),
+                attribute("emails", "complex", true, false),
+                attribute("active", "boolean", false, false),
+            ],
+        }),
+        json!({
+            "id": SCIM_GROUP_SCHEMA,
+            "name": "Group",
+            "description": "A group, mapped onto a guest role of a \
+                subscription account",
+            "attributes": [
+                attribute("displayName", "string", false, true),
+                attribute("externalId", "string", false, false),
+                attribute("members", "complex", true, false),
+            ],
+        }),
+        json!({
+            "id": SCIM_GROUP_EXTENSION_SCHEMA,
+            "name": "MyceliumGroup",
+            "description": "The subscription account and the guest role a \
+                group maps onto",
+            "attributes": [
+                attribute("accountName", "string", false, true),
+                attribute("role", "string", false, true),
+            ],
+        }),
+    ];
+
+    scim_response(
+        StatusCode::OK,
+        json!({
+            "schemas": [SCIM_LIST_RESPONSE_SCHEMA],
+            "totalResults": resources.len(),
+            "startIndex": 1,
+            "itemsPerPage": resources.len(),
+            "Resources": resources,
+        }),
+    )
+}
+
+/// List the provisioned users
+#[utoipa::path(
+    get,
+    operation_id = "list_scim_users",
+    params(ListScimResourcesParams),
+    responses(
+        (status = 500, description = "Unknown internal server error.", body = ScimErrorResponse),
+        (status = 401, description = "Unauthorized.", body = ScimErrorResponse),
+        (status = 400, description = "Invalid filter.", body = ScimErrorResponse),
+        (status = 200, description = "Fetching success."),
+    ),
+    security(("Bearer" = [])),
+)]
+#[get("/Users")]
+pub async fn list_scim_users_url(
+    req: HttpRequest,
+    query: web::Query<ListScimResourcesParams>,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    let credential = match authenticate(&req, &app_module).await {
+        Ok(credential) => credential,
+        Err(res) => return res,
+    };
+
+    match list_scim_users(
+        credential,
+        query.filter.to_owned(),
+        query.start_index,
+        query.count,
+        Box::new(&*app_module.resolve_ref() as &dyn ScimResourceFetching),
+    )
+    .await
+    {
+        Ok(users) => scim_response(StatusCode::OK, users),
+        Err(err) => handle_scim_error(err),
+    }
+}
+
+/// Provision a user
+///
+/// The user maps onto the Mycelium user of its email, which is registered
+/// with a personal account when missing.
+///
+#[utoipa::path(
+    post,
+    operation_id = "create_scim_user",
+    request_body = ScimUser,
+    responses(
+        (status = 500, description = "Unknown internal server error.", body = ScimErrorResponse),
+        (status = 409, description = "User already provisioned.", body = ScimErrorResponse),
+        (status = 401, description = "Unauthorized.", body = ScimErrorResponse),
+        (status = 400, description = "Invalid user.", body = ScimErrorResponse),
+        (status = 201, description = "User provisioned.", body = ScimUser),
+    ),
+    security(("Bearer" = [])),
+)]
+#[post("/Users")]
+pub async fn create_scim_user_url(
+    req: HttpRequest,
+    body: web::Bytes,
+    life_cycle_settings: web::Data<AccountLifeCycle>,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    let credential = match authenticate(&req, &app_module).await {
+        Ok(credential) => credential,
+        Err(res) => return res,
+    };
+
+    let document = match parse_body::<Value>(&body) {
+        Ok(document) => document,
+        Err(res) => return res,
+    };
+
+    match create_scim_user(
+        credential,
+        document,
+        life_cycle_settings.get_ref().to_owned(),
+        Box::new(&*app_module.resolve_ref() as &dyn ScimResourceFetching),
+        Box::new(&*app_module.resolve_ref() as &dyn ScimResourceRegistration),
+        Box::new(&*app_module.resolve_ref() as &dyn UserFetching),
+        Box::new(&*app_module.resolve_ref() as &dyn UserRegistration),
+        Box::new(&*app_module.resolve_ref() as &dyn AccountRegistration),
+        Box::new(&*app_module.resolve_ref() as &dyn AccountFetching),
+        Box::new(&*app_module.resolve_ref() as &dyn AccountUpdating),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(user) => scim_response(StatusCode::CREATED, user),
+        Err(err) => handle_scim_error(err),
+    }
+}
+
+/// Get a provisioned user
+#[utoipa::path(
+    get,
+    operation_id = "get_scim_user",
+    params(("id" = Uuid, Path, description = "The SCIM user id.")),
+    responses(
+        (status = 500, description = "Unknown internal server error.", body = ScimErrorResponse),
+        (status = 404, description = "Not found.", body = ScimErrorResponse),
+        (status = 401, description = "Unauthorized.", body = ScimErrorResponse),
+        (status = 200, description = "Fetching success.", body = ScimUser),
+    ),
+    security(("Bearer" = [])),
+)]
+#[get("/Users/{id}")]
+pub async fn get_scim_user_url(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    let credential = match authenticate(&req, &app_module).await {
+        Ok(credential) => credential,
+        Err(res) => return res,
+    };
+
+    let user_id = path.into_inner();
+
+    match get_scim_user(
+        credential,
+        user_id,
+        Box::new(&*app_module.resolve_ref() as &dyn ScimResourceFetching),
+    )
+    .await
+    {
+        Ok(FetchResponseKind::Found(user)) => {
+            scim_response(StatusCode::OK, user)
+        }
+        Ok(FetchResponseKind::NotFound(_)) => not_found("User", user_id),
+        Err(err) => handle_scim_error(err),
+    }
+}
+
+async fn update_user(
+    req: HttpRequest,
+    user_id: Uuid,
+    update: Result<ScimUpdate, HttpResponse>,
+    life_cycle_settings: web::Data<AccountLifeCycle>,
+    app_module: web::Data<SqlAppModule>,
+) -> HttpResponse {
+    let credential = match authenticate(&req, &app_module).await {
+        Ok(credential) => credential,
+        Err(res) => return res,
+    };
+
+    let update = match update {
+        Ok(update) => update,
+        Err(res) => return res,
+    };
+
+    match update_scim_user(
+        credential,
+        user_id,
+        update,
+        life_cycle_settings.get_ref().to_owned(),
+        Box::new(&*app_module.resolve_ref() as &dyn ScimResourceFetching),
+        Box::new(&*app_module.resolve_ref() as &dyn ScimResourceUpdating),
+        Box::new(&*app_module.resolve_ref() as &dyn AccountFetching),
+        Box::new(&*app_module.resolve_ref() as &dyn AccountUpdating),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(FetchResponseKind::Found(user)) => {
+            scim_response(StatusCode::OK, user)
+        }
+        Ok(FetchResponseKind::NotFound(_)) => not_found("User", user_id),
+        Err(err) => handle_scim_error(err),
+    }
+}
+
+/// Replace a provisioned user
+///
+/// Deactivating the user revokes the guest roles its groups grant.
+///
+#[utoipa::path(
+    put,
+    operation_id = "replace_scim_user",
+    params(("id" = Uuid, Path, description = "The SCIM user id.")),
+    request_body = ScimUser,
+    responses(
+        (status = 500, description = "Unknown internal server error.", body = ScimErrorResponse),
+        (status = 409, description = "User name already provisioned.", body = ScimErrorResponse),
+        (status = 404, description = "Not found.", body = ScimErrorResponse),
+        (status = 401, description = "Unauthorized.", body = ScimErrorResponse),
+        (status = 400, description = "Invalid user.", body = ScimErrorResponse),
+        (status = 200, description = "User updated.", body = ScimUser),
+    ),
+    security(("Bearer" = [])),
+)]
+#[put("/Users/{id}")]
+pub async fn replace_scim_user_url(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    body: web::Bytes,
+    life_cycle_settings: web::Data<AccountLifeCycle>,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    update_user(
+        req,
+        path.into_inner(),
+        parse_body::<Value>(&body).map(ScimUpdate::Replace),
+        life_cycle_settings,
+        app_module,
+    )
+    .await
+}
+
+/// Patch a provisioned user
+///
+/// Deactivating the user revokes the guest roles its groups grant.
+///
+#[utoipa::path(
+    patch,
+    operation_id = "patch_scim_user",
+    params(("id" = Uuid, Path, description = "The SCIM user id.")),
+    request_body = ScimPatchRequest,
+    responses(
+        (status = 500, description = "Unknown internal server error.", body = ScimErrorResponse),
+        (status = 409, description = "User name already provisioned.", body = ScimErrorResponse),
+        (status = 404, description = "Not found.", body = ScimErrorResponse),
+        (status = 401, description = "Unauthorized.", body = ScimErrorResponse),
+        (status = 400, description = "Invalid patch.", body = ScimErrorResponse),
+        (status = 200, description = "User updated.", body = ScimUser),
+    ),
+    security(("Bearer" = [])),
+)]
+#[patch("/Users/{id}")]
+pub async fn patch_scim_user_url(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    body: web::Bytes,
+    life_cycle_settings: web::Data<AccountLifeCycle>,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    update_user(
+        req,
+        path.into_inner(),
+        parse_body::<ScimPatchRequest>(&body).map(ScimUpdate::Patch),
+        life_cycle_settings,
+        app_module,
+    )
+    .await
+}
+
+/// Deprovision a user
+///
+/// The user loses the guest roles its groups grant, and its personal account
+/// is deactivated when created by the provisioning.
+///
+#[utoipa::path(
+    delete,
+    operation_id = "delete_scim_user",
+    params(("id" = Uuid, Path, description = "The SCIM user id.")),
+    responses(
+        (status = 500, description = "Unknown internal server error.", body = ScimErrorResponse),
+        (status = 404, description = "Not found.", body = ScimErrorResponse),
+        (status = 401, description = "Unauthorized.", body = ScimErrorResponse),
+        (status = 204, description = "User deprovisioned."),
+    ),
+    security(("Bearer" = [])),
+)]
+#[delete("/Users/{id}")]
+pub async fn delete_scim_user_url(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    let credential = match authenticate(&req, &app_module).await {
+        Ok(credential) => credential,
+        Err(res) => return res,
+    };
+
+    let user_id = path.into_inner();
+
+    match delete_scim_user(
+        credential,
+        user_id,
+        Box::new(&*app_module.resolve_ref() as &dyn ScimResourceFetching),
+        Box::new(&*app_module.resolve_ref() as &dyn ScimResourceUpdating),
+        Box::new(&*app_module.resolve_ref() as &dyn ScimResourceDeletion),
+        Box::new(&*app_module.resolve_ref() as &dyn AccountFetching),
+        Box::new(&*app_module.resolve_ref() as &dyn AccountUpdating),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(DeletionResponseKind::Deleted) => HttpResponse::NoContent().finish(),
+        Ok(DeletionResponseKind::NotDeleted(..)) => not_found("User", user_id),
+        Err(err) => handle_scim_error(err),
+    }
+}
+
+/// List the provisioned groups
+#[utoipa::path(
+    get,
+    operation_id = "list_scim_groups",
+    params(ListScimResourcesParams),
+    responses(
+        (status = 500, description = "Unknown internal server error.", body = ScimErrorResponse),
+        (status = 401, description = "Unauthorized.", body = ScimErrorResponse),
+        (status = 400, description = "Invalid filter.", body = ScimErrorResponse),
+        (status = 200, description = "Fetching success."),
+    ),
+    security(("Bearer" = [])),
+)]
+#[get("/Groups")]
+pub async fn list_scim_groups_url(
+    req: HttpRequest,
+    query: web::Query<ListScimResourcesParams>,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    let credential = match authenticate(&req, &app_module).await {
+        Ok(credential) => credential,
+        Err(res) => return res,
+    };
+
+    match list_scim_groups(
+        credential,
+        query.filter.to_owned(),
+        query.start_index,
+        query.count,
+        Box::new(&*app_module.resolve_ref() as &dyn ScimResourceFetching),
+    )
+    .await
+    {
+        Ok(groups) => scim_response(StatusCode::OK, groups),
+        Err(err) => handle_scim_error(err),
+    }
+}
+
+/// Provision a group
+///
+/// The group maps onto a guest role of a subscription account, named by the
+/// Mycelium group extension or by a display name formatted as
+/// `<account name>:<role>`. The account is created when missing, and the
+/// active members are invited to the role.
+///
+#[utoipa::path(
+    post,
+    operation_id = "create_scim_group",
+    request_body = ScimGroup,
+    responses(
+        (status = 500, description = "Unknown internal server error.", body = ScimErrorResponse),
+        (status = 409, description = "Group already provisioned.", body = ScimErrorResponse),
+        (status = 401, description = "Unauthorized.", body = ScimErrorResponse),
+        (status = 400, description = "Invalid group.", body = ScimErrorResponse),
+        (status = 201, description = "Group provisioned.", body = ScimGroup),
+    ),
+    security(("Bearer" = [])),
+)]
+#[post("/Groups")]
+pub async fn create_scim_group_url(
+    req: HttpRequest,
+    body: web::Bytes,
+    life_cycle_settings: web::Data<AccountLifeCycle>,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    let credential = match authenticate(&req, &app_module).await {
+        Ok(credential) => credential,
+        Err(res) => return res,
+    };
+
+    let document = match parse_body::<Value>(&body) {
+        Ok(document) => document,
+        Err(res) => return res,
+    };
+
+    match create_scim_group(
+        credential,
+        document,
+        life_cycle_settings.get_ref().to_owned(),
+        Box::new(&*app_module.resolve_ref() as &dyn ScimResourceFetching),
+        Box::new(&*app_module.resolve_ref() as &dyn ScimResourceRegistration),
+        Box::new(&*app_module.resolve_ref() as &dyn AccountFetching),
+        Box::new(&*app_module.resolve_ref() as &dyn AccountRegistration),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(group) => scim_response(StatusCode::CREATED, group),
+        Err(err) => handle_scim_error(err),
+    }
+}
+
+/// Get a provisioned group
+#[utoipa::path(
+    get,
+    operation_id = "get_scim_group",
+    params(("id" = Uuid, Path, description = "The SCIM group id.")),
+    responses(
+        (status = 500, description = "Unknown internal server error.", body = ScimErrorResponse),
+        (status = 404, description = "Not found.", body = ScimErrorResponse),
+        (status = 401, description = "Unauthorized.", body = ScimErrorResponse),
+        (status = 200, description = "Fetching success.", body = ScimGroup),
+    ),
+    security(("Bearer" = [])),
+)]
+#[get("/Groups/{id}")]
+pub async fn get_scim_group_url(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    let credential = match authenticate(&req, &app_module).await {
+        Ok(credential) => credential,
+        Err(res) => return res,
+    };
+
+    let group_id = path.into_inner();
+
+    match get_scim_group(
+        credential,
+        group_id,
+        Box::new(&*app_module.resolve_ref() as &dyn ScimResourceFetching),
+    )
+    .await
+    {
+        Ok(FetchResponseKind::Found(group)) => {
+            scim_response(StatusCode::OK, group)
+        }
+        Ok(FetchResponseKind::NotFound(_)) => not_found("Group", group_id),
+        Err(err) => handle_scim_error(err),
+    }
+}
+
+async fn update_group(
+    req: HttpRequest,
+    group_id: Uuid,
+    update: Result<ScimUpdate, HttpResponse>,
+    life_cycle_settings: web::Data<AccountLifeCycle>,
+    app_module: web::Data<SqlAppModule>,
+) -> HttpResponse {
+    let credential = match authenticate(&req, &app_module).await {
+        Ok(credential) => credential,
+        Err(res) => return res,
+    };
+
+    let update = match update {
+        Ok(update) => update,
+        Err(res) => return res,
+    };
+
+    match update_scim_group(
+        credential,
+        group_id,
+        update,
+        life_cycle_settings.get_ref().to_owned(),
+        Box::new(&*app_module.resolve_ref() as &dyn ScimResourceFetching),
+        Box::new(&*app_module.resolve_ref() as &dyn ScimResourceUpdating),
+        Box::new(&*app_module.resolve_ref() as &dyn AccountFetching),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(FetchResponseKind::Found(group)) => {
+            scim_response(StatusCode::OK, group)
+        }
+        Ok(FetchResponseKind::NotFound(_)) => not_found("Group", group_id),
+        Err(err) => handle_scim_error(err),
+    }
+}
+
+/// Replace a provisioned group
+///
+/// Members added are invited to the guest role of the group, and members
+/// removed lose it.
+///
+#[utoipa::path(
+    put,
+    operation_id = "replace_scim_group",
+    params(("id" = Uuid, Path, description = "The SCIM group id.")),
+    request_body = ScimGroup,
+    responses(
+        (status = 500, description = "Unknown internal server error.", body = ScimErrorResponse),
+        (status = 409, description = "Group name already provisioned.", body = ScimErrorResponse),
+        (status = 404, description = "Not found.", body = ScimErrorResponse),
+        (status = 401, description = "Unauthorized.", body = ScimErrorResponse),
+        (status = 400, description = "Invalid group.", body = ScimErrorResponse),
+        (status = 200, description = "Group updated.", body = ScimGroup),
+    ),
+    security(("Bearer" = [])),
+)]
+#[put("/Groups/{id}")]
+pub async fn replace_scim_group_url(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    body: web::Bytes,
+    life_cycle_settings: web::Data<AccountLifeCycle>,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    update_group(
+        req,
+        path.into_inner(),
+        parse_body::<Value>(&body).map(ScimUpdate::Replace),
+        life_cycle_settings,
+        app_module,
+    )
+    .await
+}
+
+/// Patch a provisioned group
+///
+/// Members added are invited to the guest role of the group, and members
+/// removed lose it.
+///
+#[utoipa::path(
+    patch,
+    operation_id = "patch_scim_group",
+    params(("id" = Uuid, Path, description = "The SCIM group id.")),
+    request_body = ScimPatchRequest,
+    responses(
+        (status = 500, description = "Unknown internal server error.", body = ScimErrorResponse),
+        (status = 409, description = "Group name already provisioned.", body = ScimErrorResponse),
+        (status = 404, description = "Not found.", body = ScimErrorResponse),
+        (status = 401, description = "Unauthorized.", body = ScimErrorResponse),
+        (status = 400, description = "Invalid patch.", body = ScimErrorResponse),
+        (status = 200, description = "Group updated.", body = ScimGroup),
+    ),
+    security(("Bearer" = [])),
+)]
+#[patch("/Groups/{id}")]
+pub async fn patch_scim_group_url(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    body: web::Bytes,
+    life_cycle_settings: web::Data<AccountLifeCycle>,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    update_group(
+        req,
+        path.into_inner(),
+        parse_body::<ScimPatchRequest>(&body).map(ScimUpdate::Patch),
+        life_cycle_settings,
+        app_module,
+    )
+    .await
+}
+
+/// Deprovision a group
+///
+/// The members lose the guest role of the group. The subscription account
+/// is kept.
+///
+#[utoipa::path(
+    delete,
+    operation_id = "delete_scim_group",
+    params(("id" = Uuid, Path, description = "The SCIM group id.")),
+    responses(
+        (status = 500, description = "Unknown internal server error.", body = ScimErrorResponse),
+        (status = 404, description = "Not found.", body = ScimErrorResponse),
+        (status = 401, description = "Unauthorized.", body = ScimErrorResponse),
+        (status = 204, description = "Group deprovisioned."),
+    ),
+    security(("Bearer" = [])),
+)]
+#[delete("/Groups/{id}")]
+pub async fn delete_scim_group_url(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    app_module: web::Data<SqlAppModule>,
+) -> impl Responder {
+    let credential = match authenticate(&req, &app_module).await {
+        Ok(credential) => credential,
+        Err(res) => return res,
+    };
+
+    let group_id = path.into_inner();
+
+    match delete_scim_group(
+        credential,
+        group_id,
+        Box::new(&*app_module.resolve_ref() as &dyn ScimResourceFetching),
+        Box::new(&*app_module.resolve_ref() as &dyn ScimResourceDeletion),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(DeletionResponseKind::Deleted) => HttpResponse::NoContent().finish(),
+        Ok(DeletionResponseKind::NotDeleted(..)) => {
+            not_found("Group", group_id)
+        }
+        Err(err) => handle_scim_error(err),
+    }
+}
@@ -99,6 +99,9 @@ impl UrlScope {
 
 pub enum UrlGroup {
     Accounts,
+    BulkImports,
+    EmailTemplates,
+    Emails,
     ErrorCodes,
     GuestRoles,
     Guests,
@@ -106,6 +109,7 @@ pub enum UrlGroup {
     Owners,
     Profile,
     Routes,
+    ScimCredentials,
     Services,
     Tags,
     Tenants,
@@ -119,6 +123,9 @@ impl Display for UrlGroup {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
         match self {
             UrlGroup::Accounts => write!(f, "accounts"),
+            UrlGroup::BulkImports => write!(f, "bulk-imports"),
+            UrlGroup::EmailTemplates => write!(f, "email-templates"),
+            UrlGroup::Emails => write!(f, "emails"),
             UrlGroup::ErrorCodes => write!(f, "error-codes"),
             UrlGroup::GuestRoles => write!(f, "guest-roles"),
             UrlGroup::Guests => write!(f, "guests"),
@@ -126,6 +133,7 @@ impl Display for UrlGroup {
             UrlGroup::Owners => write!(f, "owners"),
             UrlGroup::Profile => write!(f, "profile"),
             UrlGroup::Routes => write!(f, "routes"),
+            UrlGroup::ScimCredentials => write!(f, "scim-credentials"),
             UrlGroup::Services => write!(f, "services"),
             UrlGroup::Tags => write!(f, "tags"),
             UrlGroup::Tenants => write!(f, "tenants"),
@@ -145,6 +153,9 @@ impl UrlGroup {
     pub fn str(&self) -> &str {
         match self {
             UrlGroup::Accounts => "accounts",
+            UrlGroup::BulkImports => "bulk-imports",
+            UrlGroup::EmailTemplates => "email-templates",
+            UrlGroup::Emails => "emails",
             UrlGroup::ErrorCodes => "error-codes",
             UrlGroup::GuestRoles => "guest-roles",
             UrlGroup::Guests => "guests",
@@ -152,6 +163,7 @@ impl UrlGroup {
             UrlGroup::Owners => "owners",
             UrlGroup::Profile => "profile",
             UrlGroup::Routes => "routes",
+            UrlGroup::ScimCredentials => "scim-credentials",
             UrlGroup::Services => "services",
             UrlGroup::Tags => "tags",
             UrlGroup::Tenants => "tenants",
@@ -364,6 +364,7 @@ pub async fn login_via_telegram_url(
         telegram_user,
         Box::new(&*sql_app_module.resolve_ref() as &dyn AccountFetching),
         life_cycle_settings.get_ref().to_owned(),
+        Box::new(sql_app_module.resolve_ref()),
     )
     .await
     {
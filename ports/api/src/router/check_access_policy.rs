@@ -0,0 +1,119 @@
+use super::resolve_client_ip;
+use crate::{
+    access_policy::{access_policy_evaluator, build_request_attributes},
+    models::active_backend_modules::SqlAppModule,
+};
+
+use actix_web::{web, HttpRequest};
+use chrono::Local;
+use myc_core::{
+    domain::dtos::{
+        access_policy::{
+            AccessPolicy, AccessPolicyAttributes, AccessPolicyProfileAttributes,
+        },
+        tenant::TenantMetaKey,
+    },
+    use_cases::role_scoped::beginner::tenant::fetch_tenant_public_info,
+};
+use myc_http_tools::{
+    responses::GatewayError, settings::DEFAULT_TENANT_ID_KEY, Profile,
+};
+use mycelium_base::entities::FetchResponseKind;
+use shaku::HasComponent;
+use std::{collections::HashMap, str::FromStr};
+use uuid::Uuid;
+
+/// Check the access policy of the route
+///
+/// The policy is evaluated against the request attributes and the requester
+/// profile. The request is rejected with the reason of the first rule that
+/// did not pass.
+///
+#[tracing::instrument(name = "check_access_policy", skip_all)]
+pub(super) async fn check_access_policy(
+    req: &HttpRequest,
+    policy: &AccessPolicy,
+    profile: &Profile,
+) -> Result<(), GatewayError> {
+    let tenant_id = req
+        .headers()
+        .get(DEFAULT_TENANT_ID_KEY)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| Uuid::from_str(value).ok());
+
+    let mut profile_attributes =
+        AccessPolicyProfileAttributes::from_profile(profile, tenant_id);
+
+    if let Some(tenant_id) = tenant_id {
+        if let Some(tenant_meta) =
+            fetch_tenant_meta(req, profile, tenant_id).await
+        {
+            profile_attributes =
+                profile_attributes.with_tenant_meta(tenant_meta);
+        }
+    }
+
+    let attributes = AccessPolicyAttributes {
+        request: build_request_attributes(
+            req.method().as_str(),
+            req.path(),
+            req.query_string(),
+            req.headers().iter().filter_map(|(name, value)| {
+                value.to_str().ok().map(|value| (name.as_str(), value))
+            }),
+            resolve_client_ip(req),
+            Local::now(),
+        ),
+        profile: profile_attributes,
+    };
+
+    let decision = policy.evaluate(&attributes, access_policy_evaluator());
+
+    tracing::info!(
+        stage = "router.check_access_policy",
+        allowed = decision.allowed,
+        reason = %decision.reason,
+        "Access policy evaluated"
+    );
+
+    if !decision.allowed {
+        return Err(GatewayError::Forbidden(format!(
+            "Access denied by policy. {}",
+            decision.reason
+        )));
+    }
+
+    Ok(())
+}
+
+async fn fetch_tenant_meta(
+    req: &HttpRequest,
+    profile: &Profile,
+    tenant_id: Uuid,
+) -> Option<HashMap<TenantMetaKey, String>> {
+    let app_module = match req.app_data::<web::Data<SqlAppModule>>() {
+        Some(app_module) => app_module,
+        None => {
+            tracing::error!(
+                "Unable to extract tenant fetching module from request"
+            );
+
+            return None;
+        }
+    };
+
+    match fetch_tenant_public_info(
+        profile.to_owned(),
+        tenant_id,
+        Box::new(&*app_module.resolve_ref()),
+    )
+    .await
+    {
+        Ok(FetchResponseKind::Found(tenant)) => tenant.meta,
+        Ok(FetchResponseKind::NotFound(_)) => None,
+        Err(err) => {
+            tracing::warn!("Tenant metadata not available to policy: {err}");
+            None
+        }
+    }
+}
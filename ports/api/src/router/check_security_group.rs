@@ -1,3 +1,4 @@
+use super::check_access_policy;
 use crate::middleware::{
     fetch_and_inject_email_to_forward, fetch_and_inject_profile_from_body_idp,
     fetch_and_inject_profile_from_token_to_forward, BodyIdpContext,
@@ -148,6 +149,7 @@ pub(super) async fn check_security_group(
                     downstream_request,
                     None,
                     None,
+                    None,
                     service_name,
                 )
                 .instrument(span.to_owned())
@@ -174,6 +176,7 @@ pub(super) async fn check_security_group(
                     downstream_request,
                     None,
                     Some(roles),
+                    None,
                     service_name,
                 )
                 .instrument(span.to_owned())
@@ -185,6 +188,65 @@ pub(super) async fn check_security_group(
             );
             (mod_downstream_request, Some(UserInfo::new_profile(profile)))
         }
+        //
+        // Protected routes should include the user profile filtered by named
+        // permissions into the header
+        //
+        SecurityGroup::ProtectedByPermissions(permissions) => {
+            tracing::info!(
+                stage = "router.profile_resolution",
+                "Profile resolution by permissions started"
+            );
+            let (mod_downstream_request, profile) =
+                fetch_and_inject_profile_from_token_to_forward(
+                    req,
+                    downstream_request,
+                    None,
+                    None,
+                    Some(permissions),
+                    service_name,
+                )
+                .instrument(span.to_owned())
+                .await?;
+            tracing::info!(
+                stage = "router.profile_resolution",
+                outcome = "ok",
+                "Profile resolution by permissions completed"
+            );
+            (mod_downstream_request, Some(UserInfo::new_profile(profile)))
+        }
+        //
+        // Protected routes should include the full qualified user profile into
+        // the header if the access policy allows the request
+        //
+        SecurityGroup::ProtectedByPolicy(policy) => {
+            tracing::info!(
+                stage = "router.profile_resolution",
+                "Profile resolution by policy started"
+            );
+            let (mod_downstream_request, profile) =
+                fetch_and_inject_profile_from_token_to_forward(
+                    req.clone(),
+                    downstream_request,
+                    None,
+                    None,
+                    None,
+                    service_name,
+                )
+                .instrument(span.to_owned())
+                .await?;
+
+            check_access_policy(&req, &policy, &profile)
+                .instrument(span.to_owned())
+                .await?;
+
+            tracing::info!(
+                stage = "router.profile_resolution",
+                outcome = "ok",
+                "Profile resolution by policy completed"
+            );
+            (mod_downstream_request, Some(UserInfo::new_profile(profile)))
+        }
     };
 
     tracing::info!(
@@ -227,7 +289,7 @@ async fn authenticate_from_body_idp(
 
     let (new_downstream_request, profile) =
         fetch_and_inject_profile_from_body_idp(
-            req,
+            req.clone(),
             downstream_request,
             ctx,
             service_record,
@@ -235,6 +297,10 @@ async fn authenticate_from_body_idp(
         )
         .await?;
 
+    if let SecurityGroup::ProtectedByPolicy(ref policy) = security_group {
+        check_access_policy(&req, policy, &profile).await?;
+    }
+
     tracing::info!(
         stage = "router.body_idp_auth",
         outcome = "ok",
@@ -58,7 +58,7 @@ pub(crate) fn parse_forwarded_for(value: &str) -> Option<String> {
 /// 2. `X-Forwarded-For` (first value)
 /// 3. TCP `peer_addr`
 ///
-fn resolve_client_ip(req: &HttpRequest) -> Option<String> {
+pub(super) fn resolve_client_ip(req: &HttpRequest) -> Option<String> {
     if let Some(forwarded) = req.headers().get(RFC7239_FORWARDED_KEY) {
         let value = forwarded.to_str().ok()?;
         let parsed = parse_forwarded_for(value);
@@ -18,6 +18,7 @@
 /// - Inject spans to the request to be used by the tracing system.
 ///
 mod build_the_gateway_response;
+mod check_access_policy;
 mod check_method_permission;
 mod check_security_group;
 mod check_source_reliability;
@@ -29,6 +30,7 @@ mod stream_request_to_downstream;
 mod strip_inbound_mycelium_headers;
 
 use build_the_gateway_response::*;
+use check_access_policy::*;
 use check_method_permission::*;
 use check_security_group::*;
 use check_source_reliability::*;
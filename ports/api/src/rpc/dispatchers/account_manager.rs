@@ -54,6 +54,11 @@ pub async fn dispatch_account_manager(
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(app_module.resolve_ref()),
             )
             .await
             .map_err(mapped_errors_to_jsonrpc_error)?;
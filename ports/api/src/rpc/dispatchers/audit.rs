@@ -1,7 +1,10 @@
 use super::super::{
     errors::{invalid_params, mapped_errors_to_jsonrpc_error, params_required},
     method_names,
-    params::FetchResourceAuditTrailParams,
+    params::{
+        ExportResourceAuditTrailParams, FetchResourceAuditTrailParams,
+        SearchResourceAuditTrailParams,
+    },
     response_kind::fetch_many_response_kind_to_result,
     types::{self, JsonRpcError},
 };
@@ -9,15 +12,24 @@ use crate::dtos::MyceliumProfileData;
 
 use crate::models::active_backend_modules::SqlAppModule;
 use actix_web::web;
+use chrono::{DateTime, Utc};
 use myc_core::{
     domain::{
         dtos::{
-            account_type::AccountType, related_accounts::RelatedAccounts,
-            resource_audit_log::ResourceAuditResourceType,
+            account_type::AccountType,
+            related_accounts::RelatedAccounts,
+            resource_audit_log::{
+                ResourceAuditEventKind, ResourceAuditExportFormat,
+                ResourceAuditLogCursor, ResourceAuditLogFilter,
+                ResourceAuditResourceType,
+            },
         },
         entities::AccountFetching,
     },
-    use_cases::shared::audit::fetch_resource_audit_trail,
+    use_cases::shared::audit::{
+        fetch_resource_audit_trail, search_resource_audit_trail,
+        RESOURCE_AUDIT_MAX_PAGE_SIZE,
+    },
 };
 use mycelium_base::entities::FetchResponseKind;
 use shaku::HasComponent;
@@ -37,6 +49,27 @@ fn parse_resource_type(s: &str) -> Option<ResourceAuditResourceType> {
         "tenantMeta" => Some(ResourceAuditResourceType::TenantMeta),
         "guestRole" => Some(ResourceAuditResourceType::GuestRole),
         "webhook" => Some(ResourceAuditResourceType::Webhook),
+        "guestUser" => Some(ResourceAuditResourceType::GuestUser),
+        "connectionString" => Some(ResourceAuditResourceType::ConnectionString),
+        "errorCode" => Some(ResourceAuditResourceType::ErrorCode),
+        _ => None,
+    }
+}
+
+fn parse_event_kind(s: &str) -> Option<ResourceAuditEventKind> {
+    match s {
+        "created" => Some(ResourceAuditEventKind::Created),
+        "updated" => Some(ResourceAuditEventKind::Updated),
+        "deleted" => Some(ResourceAuditEventKind::Deleted),
+        "accessed" => Some(ResourceAuditEventKind::Accessed),
+        _ => None,
+    }
+}
+
+fn parse_export_format(s: &str) -> Option<ResourceAuditExportFormat> {
+    match s {
+        "csv" => Some(ResourceAuditExportFormat::Csv),
+        "jsonl" => Some(ResourceAuditExportFormat::Jsonl),
         _ => None,
     }
 }
@@ -57,7 +90,8 @@ pub async fn dispatch_audit(
                 .ok_or_else(|| {
                     invalid_params(
                         "resourceType must be one of: account, accountMeta, \
-                         user, tenant, tenantMeta, guestRole, webhook",
+                         user, tenant, tenantMeta, guestRole, webhook, \
+                         guestUser, connectionString, errorCode",
                     )
                 })?;
 
@@ -81,6 +115,90 @@ pub async fn dispatch_audit(
 
             fetch_many_response_kind_to_result(result)
         }
+        method_names::AUDIT_RESOURCE_TRAIL_SEARCH => {
+            let p: SearchResourceAuditTrailParams =
+                serde_json::from_value(params.ok_or_else(params_required)?)
+                    .map_err(|e| invalid_params(e.to_string()))?;
+
+            let (filter, cursor) = parse_search_params(&p)?;
+
+            let (tenant_id, resource_owner_account_id) =
+                resolve_search_context(&filter, app_module).await?;
+
+            let page = search_resource_audit_trail(
+                profile.to_profile(),
+                filter,
+                tenant_id,
+                resource_owner_account_id,
+                cursor,
+                p.page_size,
+                Box::new(app_module.resolve_ref()),
+            )
+            .await
+            .map_err(mapped_errors_to_jsonrpc_error)?;
+
+            serde_json::to_value(page).map_err(|e| JsonRpcError {
+                code: types::codes::INTERNAL_ERROR,
+                message: e.to_string(),
+                data: None,
+            })
+        }
+        method_names::AUDIT_RESOURCE_TRAIL_EXPORT => {
+            let p: ExportResourceAuditTrailParams =
+                serde_json::from_value(params.ok_or_else(params_required)?)
+                    .map_err(|e| invalid_params(e.to_string()))?;
+
+            let format = parse_export_format(&p.format).ok_or_else(|| {
+                invalid_params("format must be one of: csv, jsonl")
+            })?;
+
+            let (filter, mut cursor) = parse_search_params(&p.search)?;
+
+            let (tenant_id, resource_owner_account_id) =
+                resolve_search_context(&filter, app_module).await?;
+
+            //
+            // JSON-RPC answers with a single document, so the whole export
+            // is collected here; the REST export streams it instead.
+            //
+            let mut content = format.header().unwrap_or_default();
+
+            loop {
+                let page = search_resource_audit_trail(
+                    profile.to_profile(),
+                    filter.to_owned(),
+                    tenant_id,
+                    resource_owner_account_id,
+                    cursor,
+                    Some(RESOURCE_AUDIT_MAX_PAGE_SIZE),
+                    Box::new(app_module.resolve_ref()),
+                )
+                .await
+                .map_err(mapped_errors_to_jsonrpc_error)?;
+
+                for log in page.records.iter() {
+                    content.push_str(
+                        &format
+                            .encode(log)
+                            .map_err(mapped_errors_to_jsonrpc_error)?,
+                    );
+                }
+
+                cursor = match page.next_cursor {
+                    Some(next) => Some(
+                        ResourceAuditLogCursor::decode(&next)
+                            .map_err(mapped_errors_to_jsonrpc_error)?,
+                    ),
+                    None => break,
+                };
+            }
+
+            Ok(serde_json::json!({
+                "format": p.format,
+                "contentType": format.content_type(),
+                "content": content,
+            }))
+        }
         _ => Err(JsonRpcError {
             code: types::codes::METHOD_NOT_FOUND,
             message: format!("Method not found: {}", method),
@@ -99,6 +217,92 @@ pub async fn dispatch_audit(
 // mapping; see `STATE.md`'s "RPC ↔ REST Audit" section).
 // ? ---------------------------------------------------------------------------
 
+fn parse_search_params(
+    p: &SearchResourceAuditTrailParams,
+) -> Result<
+    (ResourceAuditLogFilter, Option<ResourceAuditLogCursor>),
+    JsonRpcError,
+> {
+    let resource_type = p
+        .resource_type
+        .as_deref()
+        .map(|value| {
+            parse_resource_type(value).ok_or_else(|| {
+                invalid_params(
+                    "resourceType must be one of: account, accountMeta, \
+                     user, tenant, tenantMeta, guestRole, webhook, \
+                     guestUser, connectionString, errorCode",
+                )
+            })
+        })
+        .transpose()?;
+
+    let event = p
+        .event
+        .as_deref()
+        .map(|value| {
+            parse_event_kind(value).ok_or_else(|| {
+                invalid_params(
+                    "event must be one of: created, updated, deleted, \
+                     accessed",
+                )
+            })
+        })
+        .transpose()?;
+
+    let parse_moment = |value: &Option<String>, name: &str| {
+        value
+            .as_deref()
+            .map(|value| {
+                DateTime::parse_from_rfc3339(value)
+                    .map(|moment| moment.with_timezone(&Utc))
+                    .map_err(|_| {
+                        invalid_params(format!(
+                            "{name} must be an RFC 3339 timestamp"
+                        ))
+                    })
+            })
+            .transpose()
+    };
+
+    let cursor = p
+        .cursor
+        .as_deref()
+        .map(ResourceAuditLogCursor::decode)
+        .transpose()
+        .map_err(|_| invalid_params("Invalid cursor"))?;
+
+    Ok((
+        ResourceAuditLogFilter {
+            resource_type,
+            resource_id: p.resource_id,
+            tenant_id: p.tenant_id,
+            performed_by: p.performed_by,
+            event,
+            created_after: parse_moment(&p.created_after, "createdAfter")?,
+            created_before: parse_moment(&p.created_before, "createdBefore")?,
+            metadata_contains: p.metadata_contains.to_owned(),
+        },
+        cursor,
+    ))
+}
+
+/// Mirrors the REST handler's `resolve_search_context`.
+async fn resolve_search_context(
+    filter: &ResourceAuditLogFilter,
+    app_module: &web::Data<SqlAppModule>,
+) -> Result<(Option<Uuid>, Option<Uuid>), JsonRpcError> {
+    match (&filter.resource_type, filter.resource_id) {
+        (Some(resource_type), Some(resource_id)) => {
+            resolve_audit_context(resource_type, resource_id, app_module).await
+        }
+        (None, Some(_)) => Err(invalid_params(
+            "resourceType is required when resourceId is set",
+        )),
+        _ => Ok((filter.tenant_id, None)),
+    }
+}
+
 async fn resolve_audit_context(
     resource_type: &ResourceAuditResourceType,
     resource_id: Uuid,
@@ -10,10 +10,10 @@ use super::super::{
         CreateAccountMetaParams, CreateConnectionStringParams,
         CreateDefaultAccountParams, CreateDefaultUserParams,
         DeleteAccountMetaParams, DeleteConnectionStringParams,
-        DeleteMyAccountParams, FetchMyProfileParams,
-        FetchTenantPublicInfoParams, RevokeConnectionStringParams,
-        StartPasswordRedefinitionParams, TotpCheckTokenParams,
-        TotpDisableParams, TotpFinishActivationParams,
+        DeleteMyAccountParams, EraseMyDataParams, ExportMyDataParams,
+        FetchMyProfileParams, FetchTenantPublicInfoParams,
+        RevokeConnectionStringParams, StartPasswordRedefinitionParams,
+        TotpCheckTokenParams, TotpDisableParams, TotpFinishActivationParams,
         TotpStartActivationParams, UpdateAccountMetaParams,
         UpdateOwnAccountNameParams,
     },
@@ -40,13 +40,14 @@ use myc_core::{
         email::Email,
         guest_role::Permission,
         profile::{LicensedResources, TenantsOwnership},
+        resource_audit_log::ResourceAuditLogCursor,
         security_group::PermissionedRole,
     },
     models::AccountLifeCycle,
     use_cases::role_scoped::beginner::{
         account::{
-            create_user_account, delete_my_account, get_my_account_details,
-            update_own_account_name,
+            create_user_account, delete_my_account, erase_my_data,
+            export_my_data, get_my_account_details, update_own_account_name,
         },
         guest_user::accept_invitation,
         meta::{create_account_meta, delete_account_meta, update_account_meta},
@@ -125,6 +126,7 @@ pub async fn dispatch_beginners(
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
             )
             .await
             .map_err(mapped_errors_to_jsonrpc_error)?;
@@ -186,6 +188,72 @@ pub async fn dispatch_beginners(
             .map_err(mapped_errors_to_jsonrpc_error)?;
             delete_response_kind_to_result(result)
         }
+        method_names::BEGINNERS_ACCOUNTS_EXPORT_DATA => {
+            let p: ExportMyDataParams =
+                serde_json::from_value(params.ok_or_else(params_required)?)
+                    .map_err(|e| invalid_params(e.to_string()))?;
+            if p.account_id != profile.acc_id {
+                warn!(
+                    "Account {} trying to export {}",
+                    profile.acc_id, p.account_id
+                );
+                return Err(forbidden_owner_only());
+            }
+            let cursor = p
+                .cursor
+                .as_deref()
+                .map(ResourceAuditLogCursor::decode)
+                .transpose()
+                .map_err(|_| invalid_params("Invalid cursor"))?;
+            let result = export_my_data(
+                profile.to_profile(),
+                cursor,
+                p.page_size,
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+            )
+            .await
+            .map_err(mapped_errors_to_jsonrpc_error)?;
+            serde_json::to_value(result).map_err(|e| JsonRpcError {
+                code: types::codes::INTERNAL_ERROR,
+                message: e.to_string(),
+                data: None,
+            })
+        }
+        method_names::BEGINNERS_ACCOUNTS_ERASE_DATA => {
+            let life_cycle = life_cycle_settings
+                .ok_or_else(|| invalid_params("Life cycle config required"))?
+                .get_ref();
+            let p: EraseMyDataParams =
+                serde_json::from_value(params.ok_or_else(params_required)?)
+                    .map_err(|e| invalid_params(e.to_string()))?;
+            if p.account_id != profile.acc_id {
+                warn!(
+                    "Account {} trying to erase {}",
+                    profile.acc_id, p.account_id
+                );
+                return Err(forbidden_owner_only());
+            }
+            let result = erase_my_data(
+                profile.to_profile(),
+                life_cycle.to_owned(),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+            )
+            .await
+            .map_err(mapped_errors_to_jsonrpc_error)?;
+            serde_json::to_value(result).map_err(|e| JsonRpcError {
+                code: types::codes::INTERNAL_ERROR,
+                message: e.to_string(),
+                data: None,
+            })
+        }
         method_names::BEGINNERS_GUESTS_ACCEPT_INVITATION => {
             let p: AcceptInvitationParams =
                 serde_json::from_value(params.ok_or_else(params_required)?)
@@ -197,6 +265,8 @@ pub async fn dispatch_beginners(
                 p.guest_role_name,
                 permission,
                 Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(app_module.resolve_ref()),
             )
             .await
             .map_err(mapped_errors_to_jsonrpc_error)?;
@@ -344,6 +414,8 @@ pub async fn dispatch_beginners(
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(app_module.resolve_ref()),
             )
             .await
             .map_err(mapped_errors_to_jsonrpc_error)?;
@@ -373,6 +445,7 @@ pub async fn dispatch_beginners(
                 profile.to_profile(),
                 p.token_id,
                 Box::new(&*app_module.resolve_ref()),
+                Box::new(app_module.resolve_ref()),
             )
             .await
             .map_err(mapped_errors_to_jsonrpc_error)?;
@@ -386,6 +459,7 @@ pub async fn dispatch_beginners(
                 profile.to_profile(),
                 p.token_id,
                 Box::new(&*app_module.resolve_ref()),
+                Box::new(app_module.resolve_ref()),
             )
             .await
             .map_err(mapped_errors_to_jsonrpc_error)?;
@@ -426,6 +500,7 @@ pub async fn dispatch_beginners(
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
             )
             .await
             .map_err(mapped_errors_to_jsonrpc_error)?;
@@ -470,11 +545,13 @@ pub async fn dispatch_beginners(
                 .map_err(|e| invalid_params(e.to_string()))?;
             let _ = start_password_redefinition(
                 email,
+                p.tenant_id,
                 life_cycle.to_owned(),
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
             )
             .await
             .map_err(mapped_errors_to_jsonrpc_error)?;
@@ -497,12 +574,14 @@ pub async fn dispatch_beginners(
                 p.token,
                 email,
                 p.new_password,
+                p.tenant_id,
                 life_cycle.to_owned(),
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
             )
             .await
             .map_err(mapped_errors_to_jsonrpc_error)?;
@@ -522,6 +601,7 @@ pub async fn dispatch_beginners(
                 email,
                 p.password,
                 Box::new(&*app_module.resolve_ref()),
+                Box::new(app_module.resolve_ref()),
             )
             .await
             .map_err(mapped_errors_to_jsonrpc_error)?;
@@ -554,6 +634,8 @@ pub async fn dispatch_beginners(
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
             )
             .await
             .map_err(mapped_errors_to_jsonrpc_error)?;
@@ -586,6 +668,8 @@ pub async fn dispatch_beginners(
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
             )
             .await
             .map_err(mapped_errors_to_jsonrpc_error)?;
@@ -612,6 +696,7 @@ pub async fn dispatch_beginners(
                 life_cycle.to_owned(),
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
+                Box::new(app_module.resolve_ref()),
             )
             .await
             .map_err(mapped_errors_to_jsonrpc_error)?;
@@ -640,6 +725,8 @@ pub async fn dispatch_beginners(
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
             )
             .await
             .map_err(mapped_errors_to_jsonrpc_error)?;
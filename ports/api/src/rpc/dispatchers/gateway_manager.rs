@@ -1,18 +1,34 @@
 use super::super::{
-    errors::{invalid_params, mapped_errors_to_jsonrpc_error},
+    errors::{invalid_params, mapped_errors_to_jsonrpc_error, params_required},
     method_names,
-    params::{ListOperationsParams, ListRoutesParams, ListServicesParams},
+    params::{
+        ExplainAccessPolicyParams, ListOperationsParams, ListRoutesParams,
+        ListServicesParams,
+    },
     response_kind::fetch_many_response_kind_to_result,
     types::{self, JsonRpcError},
 };
-use crate::{dtos::MyceliumProfileData, openapi_processor::list_operations};
+use crate::{
+    access_policy::{access_policy_evaluator, build_dry_run_attributes},
+    dtos::MyceliumProfileData,
+    openapi_processor::list_operations,
+};
 
 use actix_web::web;
-use myc_core::use_cases::role_scoped::gateway_manager::{
-    route::list_routes, service::list_services,
+use chrono::{DateTime, Local};
+use http::uri::PathAndQuery;
+use myc_core::{
+    domain::dtos::access_policy::{
+        AccessPolicy, AccessPolicyProfileAttributes,
+    },
+    use_cases::role_scoped::gateway_manager::{
+        route::{explain_access_policy, list_routes},
+        service::list_services,
+    },
 };
 use myc_mem_db::repositories::MemDbAppModule;
 use shaku::HasComponent;
+use std::str::FromStr;
 
 pub async fn dispatch_gateway_manager(
     profile: &MyceliumProfileData,
@@ -40,6 +56,55 @@ pub async fn dispatch_gateway_manager(
             .map_err(mapped_errors_to_jsonrpc_error)?;
             fetch_many_response_kind_to_result(result)
         }
+        method_names::GATEWAY_MANAGER_ROUTES_EXPLAIN_POLICY => {
+            let p: ExplainAccessPolicyParams =
+                serde_json::from_value(params.ok_or_else(params_required)?)
+                    .map_err(|e| invalid_params(e.to_string()))?;
+            let path = PathAndQuery::from_str(&p.path)
+                .map_err(|e| invalid_params(e.to_string()))?;
+            let at = match p.at {
+                Some(at) => DateTime::parse_from_rfc3339(&at)
+                    .map_err(|e| invalid_params(e.to_string()))?
+                    .with_timezone(&Local),
+                None => Local::now(),
+            };
+            let policy = p
+                .policy
+                .map(serde_json::from_value::<AccessPolicy>)
+                .transpose()
+                .map_err(|e| invalid_params(e.to_string()))?;
+            let profile = profile.to_profile();
+            let profile_attributes = match p.profile {
+                Some(v) => serde_json::from_value(v)
+                    .map_err(|e| invalid_params(e.to_string()))?,
+                None => {
+                    AccessPolicyProfileAttributes::from_profile(&profile, None)
+                }
+            };
+            let attributes = build_dry_run_attributes(
+                &p.method,
+                &path,
+                p.headers.unwrap_or_default(),
+                p.client_ip,
+                at,
+                profile_attributes,
+            );
+            let result = explain_access_policy(
+                profile,
+                path,
+                policy,
+                attributes,
+                Box::new(&*mem_module.resolve_ref()),
+                Box::new(access_policy_evaluator()),
+            )
+            .await
+            .map_err(mapped_errors_to_jsonrpc_error)?;
+            serde_json::to_value(result).map_err(|e| JsonRpcError {
+                code: types::codes::INTERNAL_ERROR,
+                message: e.to_string(),
+                data: None,
+            })
+        }
         method_names::GATEWAY_MANAGER_SERVICES_LIST => {
             let p: ListServicesParams = params
                 .map(serde_json::from_value)
@@ -5,7 +5,7 @@ use super::super::{
         CreateGuestRoleParams, DeleteGuestRoleParams,
         GuestManagerListGuestRolesParams, InsertRoleChildParams,
         RemoveRoleChildParams, UpdateGuestRoleNameAndDescriptionParams,
-        UpdateGuestRolePermissionParams,
+        UpdateGuestRoleNamedPermissionsParams, UpdateGuestRolePermissionParams,
     },
     response_kind::{
         delete_response_kind_to_result, fetch_many_response_kind_to_result,
@@ -23,7 +23,8 @@ use myc_core::{
     use_cases::role_scoped::guest_manager::guest_role::{
         create_guest_role, delete_guest_role, insert_role_child,
         list_guest_roles, remove_role_child,
-        update_guest_role_name_and_description, update_guest_role_permission,
+        update_guest_role_name_and_description,
+        update_guest_role_named_permissions, update_guest_role_permission,
     },
 };
 use shaku::HasComponent;
@@ -120,6 +121,22 @@ pub async fn dispatch_guest_manager(
             .map_err(mapped_errors_to_jsonrpc_error)?;
             updating_response_kind_to_result(result)
         }
+        method_names::GUEST_MANAGER_GUEST_ROLES_UPDATE_NAMED_PERMISSIONS => {
+            let p: UpdateGuestRoleNamedPermissionsParams =
+                serde_json::from_value(params.ok_or_else(params_required)?)
+                    .map_err(|e| invalid_params(e.to_string()))?;
+            let result = update_guest_role_named_permissions(
+                profile.to_profile(),
+                p.guest_role_id,
+                p.permissions,
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+            )
+            .await
+            .map_err(mapped_errors_to_jsonrpc_error)?;
+            updating_response_kind_to_result(result)
+        }
         method_names::GUEST_MANAGER_GUEST_ROLES_INSERT_ROLE_CHILD => {
             let p: InsertRoleChildParams =
                 serde_json::from_value(params.ok_or_else(params_required)?)
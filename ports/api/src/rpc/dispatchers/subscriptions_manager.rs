@@ -1,15 +1,18 @@
-//! Dispatcher JSON-RPC para o escopo subscriptionsManager (accounts, guests, guestRoles, tags).
+//! Dispatcher JSON-RPC para o escopo subscriptionsManager (accounts, guests, guestRoles, tags, bulkImports).
 
 use super::super::{
     errors::{invalid_params, mapped_errors_to_jsonrpc_error, params_required},
     method_names,
     params::{
         CreateRoleAssociatedAccountParams, CreateSubscriptionAccountParams,
-        DeleteTagParams, GetAccountDetailsParams,
+        DeleteTagParams, ExtendGuestGrantParams, GetAccountDetailsParams,
+        GetBulkImportParams, GuestInvitationParams,
         GuestUserToSubscriptionAccountParams, ListAccountsByTypeParams,
+        ListBulkImportsParams, ListGuestInvitationsParams,
         ListGuestOnSubscriptionAccountParams,
         ListLicensedAccountsOfEmailParams, PropagateSubscriptionAccountParams,
         RegisterTagParams, RevokeUserGuestToSubscriptionAccountParams,
+        SubmitBulkImportParams,
         SubscriptionsManagerFetchGuestRoleDetailsParams,
         SubscriptionsManagerListGuestRolesParams,
         UpdateAccountNameAndFlagsParams,
@@ -26,11 +29,13 @@ use crate::dtos::MyceliumProfileData;
 
 use crate::models::active_backend_modules::SqlAppModule;
 use actix_web::web;
+use chrono::{DateTime, Local};
 use myc_core::{
     domain::{
         actors::SystemActor,
         dtos::{
-            account::VerboseStatus, account_type::AccountType, email::Email,
+            account::VerboseStatus, account_type::AccountType,
+            bulk_import::BulkImportFormat, email::Email,
             guest_role::Permission, security_group::PermissionedRole, tag::Tag,
         },
     },
@@ -42,10 +47,12 @@ use myc_core::{
             propagate_existing_subscription_account,
             update_account_name_and_flags,
         },
+        bulk_import::{get_bulk_import, list_bulk_imports, submit_bulk_import},
         guest::{
-            guest_user_to_subscription_account,
+            cancel_guest_invitation, extend_guest_grant,
+            guest_user_to_subscription_account, list_guest_invitations,
             list_guest_on_subscription_account,
-            list_licensed_accounts_of_email,
+            list_licensed_accounts_of_email, resend_guest_invitation,
             revoke_user_guest_to_subscription_account,
             update_flags_from_subscription_account,
         },
@@ -57,6 +64,18 @@ use shaku::HasComponent;
 use std::str::FromStr;
 use uuid::Uuid;
 
+fn parse_optional_datetime(
+    value: Option<String>,
+) -> Result<Option<DateTime<Local>>, JsonRpcError> {
+    value
+        .map(|value| {
+            DateTime::parse_from_rfc3339(&value)
+                .map(|value| value.with_timezone(&Local))
+                .map_err(|e| invalid_params(e.to_string()))
+        })
+        .transpose()
+}
+
 fn parse_actor(s: &str) -> Result<SystemActor, JsonRpcError> {
     let kebab = match s {
         "gatewayManager" => "gateway-manager",
@@ -238,6 +257,7 @@ pub async fn dispatch_subscriptions_manager(
                 p.tenant_id,
                 p.account_id,
                 Box::new(&*app_module.resolve_ref()),
+                Box::new(app_module.resolve_ref()),
             )
             .await
             .map_err(mapped_errors_to_jsonrpc_error)?;
@@ -318,12 +338,20 @@ pub async fn dispatch_subscriptions_manager(
                 email,
                 p.role_id,
                 p.account_id,
+                parse_optional_datetime(p.valid_from)?,
+                parse_optional_datetime(p.valid_until)?,
                 life_cycle.to_owned(),
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(app_module.resolve_ref()),
             )
             .await
             .map_err(mapped_errors_to_jsonrpc_error)?;
@@ -350,6 +378,24 @@ pub async fn dispatch_subscriptions_manager(
             .map_err(mapped_errors_to_jsonrpc_error)?;
             updating_response_kind_to_result(result)
         }
+        method_names::SUBSCRIPTIONS_MANAGER_GUESTS_EXTEND_GUEST_GRANT => {
+            let p: ExtendGuestGrantParams =
+                serde_json::from_value(params.ok_or_else(params_required)?)
+                    .map_err(|e| invalid_params(e.to_string()))?;
+            let result = extend_guest_grant(
+                profile.to_profile(),
+                p.tenant_id,
+                p.account_id,
+                p.role_id,
+                parse_optional_datetime(p.valid_until)?,
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+            )
+            .await
+            .map_err(mapped_errors_to_jsonrpc_error)?;
+            updating_response_kind_to_result(result)
+        }
         method_names::SUBSCRIPTIONS_MANAGER_GUESTS_REVOKE_USER_GUEST_TO_SUBSCRIPTION_ACCOUNT => {
             let p: RevokeUserGuestToSubscriptionAccountParams =
                 serde_json::from_value(params.ok_or_else(params_required)?)
@@ -361,6 +407,67 @@ pub async fn dispatch_subscriptions_manager(
                 p.role_id,
                 p.email,
                 Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(app_module.resolve_ref()),
+            )
+            .await
+            .map_err(mapped_errors_to_jsonrpc_error)?;
+            delete_response_kind_to_result(result)
+        }
+        method_names::SUBSCRIPTIONS_MANAGER_GUESTS_LIST_INVITATIONS => {
+            let p: ListGuestInvitationsParams =
+                serde_json::from_value(params.ok_or_else(params_required)?)
+                    .map_err(|e| invalid_params(e.to_string()))?;
+            let result = list_guest_invitations(
+                profile.to_profile(),
+                p.tenant_id,
+                p.account_id,
+                Box::new(&*app_module.resolve_ref()),
+            )
+            .await
+            .map_err(mapped_errors_to_jsonrpc_error)?;
+            fetch_many_response_kind_to_result(result)
+        }
+        method_names::SUBSCRIPTIONS_MANAGER_GUESTS_RESEND_INVITATION => {
+            let life_cycle = life_cycle_settings
+                .ok_or_else(|| invalid_params("Life cycle config required"))?
+                .get_ref();
+            let p: GuestInvitationParams =
+                serde_json::from_value(params.ok_or_else(params_required)?)
+                    .map_err(|e| invalid_params(e.to_string()))?;
+            let result = resend_guest_invitation(
+                profile.to_profile(),
+                p.tenant_id,
+                p.account_id,
+                p.guest_user_id,
+                life_cycle.to_owned(),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(app_module.resolve_ref()),
+            )
+            .await
+            .map_err(mapped_errors_to_jsonrpc_error)?;
+            updating_response_kind_to_result(result)
+        }
+        method_names::SUBSCRIPTIONS_MANAGER_GUESTS_CANCEL_INVITATION => {
+            let p: GuestInvitationParams =
+                serde_json::from_value(params.ok_or_else(params_required)?)
+                    .map_err(|e| invalid_params(e.to_string()))?;
+            let result = cancel_guest_invitation(
+                profile.to_profile(),
+                p.tenant_id,
+                p.account_id,
+                p.guest_user_id,
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(app_module.resolve_ref()),
             )
             .await
             .map_err(mapped_errors_to_jsonrpc_error)?;
@@ -469,6 +576,54 @@ pub async fn dispatch_subscriptions_manager(
             .map_err(mapped_errors_to_jsonrpc_error)?;
             delete_response_kind_to_result(result)
         }
+        method_names::SUBSCRIPTIONS_MANAGER_BULK_IMPORTS_SUBMIT => {
+            let p: SubmitBulkImportParams =
+                serde_json::from_value(params.ok_or_else(params_required)?)
+                    .map_err(|e| invalid_params(e.to_string()))?;
+            let format =
+                BulkImportFormat::from_str(&p.format).map_err(invalid_params)?;
+            let result = submit_bulk_import(
+                profile.to_profile(),
+                p.tenant_id,
+                format,
+                p.content,
+                p.dry_run.unwrap_or(false),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+            )
+            .await
+            .map_err(mapped_errors_to_jsonrpc_error)?;
+            get_or_create_response_kind_to_result(result)
+        }
+        method_names::SUBSCRIPTIONS_MANAGER_BULK_IMPORTS_LIST => {
+            let p: ListBulkImportsParams =
+                serde_json::from_value(params.ok_or_else(params_required)?)
+                    .map_err(|e| invalid_params(e.to_string()))?;
+            let result = list_bulk_imports(
+                profile.to_profile(),
+                p.tenant_id,
+                p.page_size,
+                p.skip,
+                Box::new(&*app_module.resolve_ref()),
+            )
+            .await
+            .map_err(mapped_errors_to_jsonrpc_error)?;
+            fetch_many_response_kind_to_result(result)
+        }
+        method_names::SUBSCRIPTIONS_MANAGER_BULK_IMPORTS_GET => {
+            let p: GetBulkImportParams =
+                serde_json::from_value(params.ok_or_else(params_required)?)
+                    .map_err(|e| invalid_params(e.to_string()))?;
+            let result = get_bulk_import(
+                profile.to_profile(),
+                p.tenant_id,
+                p.bulk_import_id,
+                Box::new(&*app_module.resolve_ref()),
+            )
+            .await
+            .map_err(mapped_errors_to_jsonrpc_error)?;
+            fetch_response_kind_to_result(result)
+        }
         _ => Err(JsonRpcError {
             code: types::codes::METHOD_NOT_FOUND,
             message: format!("Method not found: {}", method),
@@ -57,6 +57,7 @@ pub async fn dispatch_system_manager(
                 p.details,
                 p.is_internal,
                 Box::new(&*app_module.resolve_ref()),
+                Box::new(app_module.resolve_ref()),
             )
             .await
             .map_err(mapped_errors_to_jsonrpc_error)?;
@@ -111,6 +112,7 @@ pub async fn dispatch_system_manager(
                 p.details,
                 Box::new(&*app_module.resolve_ref()),
                 Box::new(&*app_module.resolve_ref()),
+                Box::new(app_module.resolve_ref()),
             )
             .await
             .map_err(mapped_errors_to_jsonrpc_error)?;
@@ -129,6 +131,7 @@ pub async fn dispatch_system_manager(
                 p.prefix,
                 p.code,
                 Box::new(&*app_module.resolve_ref()),
+                Box::new(app_module.resolve_ref()),
             )
             .await
             .map_err(mapped_errors_to_jsonrpc_error)?;
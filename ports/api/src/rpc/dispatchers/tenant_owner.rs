@@ -2,15 +2,18 @@ use super::super::{
     errors::{invalid_params, mapped_errors_to_jsonrpc_error, params_required},
     method_names,
     params::{
-        CreateManagementAccountParams, CreateTenantMetaParams,
+        CreateManagementAccountParams, CreateScimCredentialParams,
+        CreateTenantMetaParams, DeleteScimCredentialParams,
         DeleteTenantManagerAccountParams, DeleteTenantMetaParams,
-        GuestTenantOwnerParams, RevokeTenantOwnerParams,
-        UpdateTenantArchivingStatusParams,
-        UpdateTenantNameAndDescriptionParams, UpdateTenantTrashingStatusParams,
-        UpdateTenantVerifyingStatusParams,
+        GuestTenantOwnerParams, ListScimCredentialsParams,
+        ListTenantDescendantsParams, RestoreTrashedTenantParams,
+        RevokeTenantOwnerParams, UpdateTenantArchivingStatusParams,
+        UpdateTenantNameAndDescriptionParams, UpdateTenantParentParams,
+        UpdateTenantTrashingStatusParams, UpdateTenantVerifyingStatusParams,
     },
     response_kind::{
         create_response_kind_to_result, delete_response_kind_to_result,
+        fetch_many_response_kind_to_result,
         get_or_create_response_kind_to_result,
         updating_response_kind_to_result,
     },
@@ -23,10 +26,12 @@ use actix_web::web;
 use myc_core::{
     domain::dtos::{email::Email, tenant::TenantMetaKey},
     use_cases::role_scoped::tenant_owner::{
-        create_management_account, create_tenant_meta,
-        delete_tenant_manager_account, delete_tenant_meta, guest_tenant_owner,
-        revoke_tenant_owner, update_tenant_archiving_status,
-        update_tenant_name_and_description, update_tenant_trashing_status,
+        create_management_account, create_scim_credential, create_tenant_meta,
+        delete_scim_credential, delete_tenant_manager_account,
+        delete_tenant_meta, guest_tenant_owner, list_scim_credentials,
+        list_tenant_descendants, restore_trashed_tenant, revoke_tenant_owner,
+        update_tenant_archiving_status, update_tenant_name_and_description,
+        update_tenant_parent, update_tenant_trashing_status,
         update_tenant_verifying_status,
     },
 };
@@ -138,6 +143,53 @@ pub async fn dispatch_tenant_owner(
             .map_err(mapped_errors_to_jsonrpc_error)?;
             delete_response_kind_to_result(result)
         }
+        method_names::TENANT_OWNER_SCIM_CREDENTIALS_CREATE => {
+            let p: CreateScimCredentialParams =
+                serde_json::from_value(params.ok_or_else(params_required)?)
+                    .map_err(|e| invalid_params(e.to_string()))?;
+            let result = create_scim_credential(
+                profile.to_profile(),
+                p.tenant_id,
+                p.name,
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+            )
+            .await
+            .map_err(mapped_errors_to_jsonrpc_error)?;
+            serde_json::to_value(result).map_err(|e| JsonRpcError {
+                code: types::codes::INTERNAL_ERROR,
+                message: e.to_string(),
+                data: None,
+            })
+        }
+        method_names::TENANT_OWNER_SCIM_CREDENTIALS_LIST => {
+            let p: ListScimCredentialsParams =
+                serde_json::from_value(params.ok_or_else(params_required)?)
+                    .map_err(|e| invalid_params(e.to_string()))?;
+            let result = list_scim_credentials(
+                profile.to_profile(),
+                p.tenant_id,
+                Box::new(&*app_module.resolve_ref()),
+            )
+            .await
+            .map_err(mapped_errors_to_jsonrpc_error)?;
+            fetch_many_response_kind_to_result(result)
+        }
+        method_names::TENANT_OWNER_SCIM_CREDENTIALS_DELETE => {
+            let p: DeleteScimCredentialParams =
+                serde_json::from_value(params.ok_or_else(params_required)?)
+                    .map_err(|e| invalid_params(e.to_string()))?;
+            let result = delete_scim_credential(
+                profile.to_profile(),
+                p.tenant_id,
+                p.credential_id,
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+            )
+            .await
+            .map_err(mapped_errors_to_jsonrpc_error)?;
+            delete_response_kind_to_result(result)
+        }
         method_names::TENANT_OWNER_TENANT_UPDATE_AND_DESCRIPTION => {
             let p: UpdateTenantNameAndDescriptionParams =
                 serde_json::from_value(params.ok_or_else(params_required)?)
@@ -185,6 +237,21 @@ pub async fn dispatch_tenant_owner(
             .map_err(mapped_errors_to_jsonrpc_error)?;
             updating_response_kind_to_result(result)
         }
+        method_names::TENANT_OWNER_TENANT_RESTORE => {
+            let p: RestoreTrashedTenantParams =
+                serde_json::from_value(params.ok_or_else(params_required)?)
+                    .map_err(|e| invalid_params(e.to_string()))?;
+            let result = restore_trashed_tenant(
+                profile.to_profile(),
+                p.tenant_id,
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+            )
+            .await
+            .map_err(mapped_errors_to_jsonrpc_error)?;
+            updating_response_kind_to_result(result)
+        }
         method_names::TENANT_OWNER_TENANT_UPDATE_VERIFYING_STATUS => {
             let p: UpdateTenantVerifyingStatusParams =
                 serde_json::from_value(params.ok_or_else(params_required)?)
@@ -200,6 +267,35 @@ pub async fn dispatch_tenant_owner(
             .map_err(mapped_errors_to_jsonrpc_error)?;
             updating_response_kind_to_result(result)
         }
+        method_names::TENANT_OWNER_TENANT_UPDATE_PARENT => {
+            let p: UpdateTenantParentParams =
+                serde_json::from_value(params.ok_or_else(params_required)?)
+                    .map_err(|e| invalid_params(e.to_string()))?;
+            let result = update_tenant_parent(
+                profile.to_profile(),
+                p.tenant_id,
+                p.parent_id,
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+                Box::new(&*app_module.resolve_ref()),
+            )
+            .await
+            .map_err(mapped_errors_to_jsonrpc_error)?;
+            updating_response_kind_to_result(result)
+        }
+        method_names::TENANT_OWNER_TENANT_LIST_DESCENDANTS => {
+            let p: ListTenantDescendantsParams =
+                serde_json::from_value(params.ok_or_else(params_required)?)
+                    .map_err(|e| invalid_params(e.to_string()))?;
+            let result = list_tenant_descendants(
+                profile.to_profile(),
+                p.tenant_id,
+                Box::new(&*app_module.resolve_ref()),
+            )
+            .await
+            .map_err(mapped_errors_to_jsonrpc_error)?;
+            fetch_many_response_kind_to_result(result)
+        }
         _ => Err(JsonRpcError {
             code: types::codes::METHOD_NOT_FOUND,
             message: format!("Method not found: {}", method),
@@ -4,14 +4,38 @@ use crate::rpc::{method_names, params};
 pub fn methods() -> Vec<serde_json::Value> {
     let fetch_resource_audit_trail_schema =
         schema::param_schema_value::<params::FetchResourceAuditTrailParams>();
+    let search_resource_audit_trail_schema =
+        schema::param_schema_value::<params::SearchResourceAuditTrailParams>();
+    let export_resource_audit_trail_schema =
+        schema::param_schema_value::<params::ExportResourceAuditTrailParams>();
 
-    vec![serde_json::json!({
-        "name": method_names::AUDIT_RESOURCE_TRAIL_FETCH,
-        "summary": "Fetch the audit trail of a resource",
-        "description": "Returns the immutable audit trail for a single resource, newest first. Visible to staff (any resource), tenant owners/managers (resources of their own tenant), and personal-account owners (their own account). Not role scoped -- permission branching happens inside the use case.",
-        "tags": [{ "name": "audit" }, { "name": "resourceTrail" }],
-        "params": [{ "name": "params", "required": true, "schema": fetch_resource_audit_trail_schema }],
-        "result": { "name": "result", "description": "Audit trail (FetchManyResponseKind), newest first", "schema": { "type": "array", "items": { "type": "object" } } },
-        "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
-    })]
+    vec![
+        serde_json::json!({
+            "name": method_names::AUDIT_RESOURCE_TRAIL_FETCH,
+            "summary": "Fetch the audit trail of a resource",
+            "description": "Returns the immutable audit trail for a single resource, newest first. Visible to staff (any resource), tenant owners/managers (resources of their own tenant), and personal-account owners (their own account). Not role scoped -- permission branching happens inside the use case.",
+            "tags": [{ "name": "audit" }, { "name": "resourceTrail" }],
+            "params": [{ "name": "params", "required": true, "schema": fetch_resource_audit_trail_schema }],
+            "result": { "name": "result", "description": "Audit trail (FetchManyResponseKind), newest first", "schema": { "type": "array", "items": { "type": "object" } } },
+            "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
+        }),
+        serde_json::json!({
+            "name": method_names::AUDIT_RESOURCE_TRAIL_SEARCH,
+            "summary": "Search the audit trail",
+            "description": "Filters the audit trail by resource, tenant, actor, event kind, time range and metadata text, newest first, one cursor page at a time. Standing is checked against the resource when resourceId is set, otherwise against tenantId; only staff can search without either.",
+            "tags": [{ "name": "audit" }, { "name": "resourceTrail" }],
+            "params": [{ "name": "params", "required": true, "schema": search_resource_audit_trail_schema }],
+            "result": { "name": "result", "description": "One page: records (newest first) and nextCursor (null on the last page)", "schema": { "type": "object" } },
+            "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
+        }),
+        serde_json::json!({
+            "name": method_names::AUDIT_RESOURCE_TRAIL_EXPORT,
+            "summary": "Export the audit trail",
+            "description": "Exports every row matching the search filters as CSV or JSON Lines, newest first, starting after cursor when set. Standing is checked as in audit.resourceTrail.search.",
+            "tags": [{ "name": "audit" }, { "name": "resourceTrail" }],
+            "params": [{ "name": "params", "required": true, "schema": export_resource_audit_trail_schema }],
+            "result": { "name": "result", "description": "format, contentType and the exported content", "schema": { "type": "object" } },
+            "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
+        }),
+    ]
 }
@@ -8,6 +8,10 @@ pub fn methods() -> Vec<serde_json::Value> {
         schema::param_schema_value::<params::UpdateOwnAccountNameParams>();
     let delete_my_account_schema =
         schema::param_schema_value::<params::DeleteMyAccountParams>();
+    let export_my_data_schema =
+        schema::param_schema_value::<params::ExportMyDataParams>();
+    let erase_my_data_schema =
+        schema::param_schema_value::<params::EraseMyDataParams>();
     let accept_invitation_schema =
         schema::param_schema_value::<params::AcceptInvitationParams>();
     let create_account_meta_schema =
@@ -105,6 +109,24 @@ pub fn methods() -> Vec<serde_json::Value> {
             "result": { "name": "result", "description": "Deletion result (DeletionResponseKind)", "schema": { "type": "object" } },
             "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
         }),
+        serde_json::json!({
+            "name": method_names::BEGINNERS_ACCOUNTS_EXPORT_DATA,
+            "summary": "Export my data",
+            "description": "Exports everything Mycelium holds about the owners of the current account: users and TOTP state, the account and its meta, guest memberships, connection string metadata and audit entries. Secrets are redacted. Restricted to the account owner.",
+            "tags": [{ "name": "beginners" }, { "name": "accounts" }],
+            "params": [{ "name": "params", "required": true, "schema": export_my_data_schema }],
+            "result": { "name": "result", "description": "The data export (DataSubjectExport)", "schema": { "type": "object" } },
+            "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
+        }),
+        serde_json::json!({
+            "name": method_names::BEGINNERS_ACCOUNTS_ERASE_DATA,
+            "summary": "Erase my data",
+            "description": "Pseudonymises every mention of the account owners in the audit trail and in the stored webhook payloads. Call it before deleting the account. Restricted to the account owner.",
+            "tags": [{ "name": "beginners" }, { "name": "accounts" }],
+            "params": [{ "name": "params", "required": true, "schema": erase_my_data_schema }],
+            "result": { "name": "result", "description": "What was pseudonymised (DataSubjectErasure)", "schema": { "type": "object" } },
+            "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
+        }),
         serde_json::json!({
             "name": method_names::BEGINNERS_GUESTS_ACCEPT_INVITATION,
             "summary": "Accept invitation",
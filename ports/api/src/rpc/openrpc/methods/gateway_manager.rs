@@ -4,6 +4,8 @@ use crate::rpc::{method_names, params};
 pub fn methods() -> Vec<serde_json::Value> {
     let list_routes_schema =
         schema::param_schema_value::<params::ListRoutesParams>();
+    let explain_access_policy_schema =
+        schema::param_schema_value::<params::ExplainAccessPolicyParams>();
     let list_services_schema =
         schema::param_schema_value::<params::ListServicesParams>();
     let list_operations_schema =
@@ -19,6 +21,15 @@ pub fn methods() -> Vec<serde_json::Value> {
             "result": { "name": "result", "description": "List of routes (FetchManyResponseKind)", "schema": { "type": "array", "items": { "type": "object" } } },
             "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
         }),
+        serde_json::json!({
+            "name": method_names::GATEWAY_MANAGER_ROUTES_EXPLAIN_POLICY,
+            "summary": "Explain access policy",
+            "description": "Evaluates an access policy as a dry-run and explains why the described request would be allowed or denied. Uses the policy of the route matching the path unless a draft policy is given. Restricted to GatewayManager users.",
+            "tags": [{ "name": "gatewayManager" }, { "name": "routes" }],
+            "params": [{ "name": "params", "required": true, "schema": explain_access_policy_schema }],
+            "result": { "name": "result", "description": "AccessPolicyExplanation (policy, attributes, decision)", "schema": { "type": "object" } },
+            "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
+        }),
         serde_json::json!({
             "name": method_names::GATEWAY_MANAGER_SERVICES_LIST,
             "summary": "List services",
@@ -16,6 +16,9 @@ pub fn methods() -> Vec<serde_json::Value> {
     >();
     let update_permission_schema =
         schema::param_schema_value::<params::UpdateGuestRolePermissionParams>();
+    let update_named_permissions_schema = schema::param_schema_value::<
+        params::UpdateGuestRoleNamedPermissionsParams,
+    >();
     let insert_role_child_schema =
         schema::param_schema_value::<params::InsertRoleChildParams>();
     let remove_role_child_schema =
@@ -67,6 +70,15 @@ pub fn methods() -> Vec<serde_json::Value> {
             "result": { "name": "result", "description": "Updated guest role (UpdatingResponseKind)", "schema": { "type": "object" } },
             "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
         }),
+        serde_json::json!({
+            "name": method_names::GUEST_MANAGER_GUEST_ROLES_UPDATE_NAMED_PERMISSIONS,
+            "summary": "Update guest role named permissions",
+            "description": "Replaces the named permissions granted by a guest role, as invoices:approve. Requires GuestsManager privileges.",
+            "tags": [{ "name": "guestManager" }, { "name": "guestRoles" }],
+            "params": [{ "name": "params", "required": true, "schema": update_named_permissions_schema }],
+            "result": { "name": "result", "description": "Updated guest role (UpdatingResponseKind)", "schema": { "type": "object" } },
+            "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
+        }),
         serde_json::json!({
             "name": method_names::GUEST_MANAGER_GUEST_ROLES_INSERT_ROLE_CHILD,
             "summary": "Insert child role",
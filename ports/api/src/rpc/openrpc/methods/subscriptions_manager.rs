@@ -30,12 +30,21 @@ pub fn methods() -> Vec<serde_json::Value> {
         schema::param_schema_value::<
             subscriptions_manager::UpdateFlagsFromSubscriptionAccountParams,
         >();
+    let extend_guest_grant_schema = schema::param_schema_value::<
+        subscriptions_manager::ExtendGuestGrantParams,
+    >();
     let revoke_user_guest_schema = schema::param_schema_value::<
         subscriptions_manager::RevokeUserGuestToSubscriptionAccountParams,
     >();
     let list_guest_on_subscription_account_schema = schema::param_schema_value::<
         subscriptions_manager::ListGuestOnSubscriptionAccountParams,
     >();
+    let list_guest_invitations_schema = schema::param_schema_value::<
+        subscriptions_manager::ListGuestInvitationsParams,
+    >();
+    let guest_invitation_schema = schema::param_schema_value::<
+        subscriptions_manager::GuestInvitationParams,
+    >();
     let list_guest_roles_schema = schema::param_schema_value::<
         subscriptions_manager::SubscriptionsManagerListGuestRolesParams,
     >();
@@ -49,6 +58,15 @@ pub fn methods() -> Vec<serde_json::Value> {
         schema::param_schema_value::<subscriptions_manager::UpdateTagParams>();
     let delete_tag_schema =
         schema::param_schema_value::<subscriptions_manager::DeleteTagParams>();
+    let submit_bulk_import_schema = schema::param_schema_value::<
+        subscriptions_manager::SubmitBulkImportParams,
+    >();
+    let list_bulk_imports_schema = schema::param_schema_value::<
+        subscriptions_manager::ListBulkImportsParams,
+    >();
+    let get_bulk_import_schema = schema::param_schema_value::<
+        subscriptions_manager::GetBulkImportParams,
+    >();
 
     vec![
         serde_json::json!({
@@ -117,7 +135,7 @@ pub fn methods() -> Vec<serde_json::Value> {
         serde_json::json!({
             "name": method_names::SUBSCRIPTIONS_MANAGER_GUESTS_GUEST_USER_TO_SUBSCRIPTION_ACCOUNT,
             "summary": "Guest user to subscription account",
-            "description": "Adds a guest user (by email) to a subscription account under the given role.",
+            "description": "Adds a guest user (by email) to a subscription account under the given role. Optional validFrom and validUntil bound the grant in time.",
             "tags": [{ "name": "subscriptionsManager" }, { "name": "guests" }],
             "params": [{ "name": "params", "required": true, "schema": guest_user_to_subscription_account_schema }],
             "result": { "name": "result", "description": "Created or existing guest user (GetOrCreateResponseKind)", "schema": { "type": "object" } },
@@ -132,6 +150,15 @@ pub fn methods() -> Vec<serde_json::Value> {
             "result": { "name": "result", "description": "Updated guest user (UpdatingResponseKind)", "schema": { "type": "object" } },
             "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
         }),
+        serde_json::json!({
+            "name": method_names::SUBSCRIPTIONS_MANAGER_GUESTS_EXTEND_GUEST_GRANT,
+            "summary": "Extend guest grant",
+            "description": "Moves the end of a time-bound guest grant. Omitting validUntil makes the grant permanent.",
+            "tags": [{ "name": "subscriptionsManager" }, { "name": "guests" }],
+            "params": [{ "name": "params", "required": true, "schema": extend_guest_grant_schema }],
+            "result": { "name": "result", "description": "Updated guest grant (UpdatingResponseKind)", "schema": { "type": "object" } },
+            "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
+        }),
         serde_json::json!({
             "name": method_names::SUBSCRIPTIONS_MANAGER_GUESTS_REVOKE_USER_GUEST_TO_SUBSCRIPTION_ACCOUNT,
             "summary": "Revoke user guest to subscription account",
@@ -150,6 +177,33 @@ pub fn methods() -> Vec<serde_json::Value> {
             "result": { "name": "result", "description": "Paginated list of guest users (FetchManyResponseKind)", "schema": { "type": "object" } },
             "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
         }),
+        serde_json::json!({
+            "name": method_names::SUBSCRIPTIONS_MANAGER_GUESTS_LIST_INVITATIONS,
+            "summary": "List guest invitations",
+            "description": "Lists the invitations to an account not accepted yet, with who sent them, when they were last sent and when they expire.",
+            "tags": [{ "name": "subscriptionsManager" }, { "name": "guests" }],
+            "params": [{ "name": "params", "required": true, "schema": list_guest_invitations_schema }],
+            "result": { "name": "result", "description": "List of pending invitations (FetchManyResponseKind)", "schema": { "type": "array" } },
+            "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
+        }),
+        serde_json::json!({
+            "name": method_names::SUBSCRIPTIONS_MANAGER_GUESTS_RESEND_INVITATION,
+            "summary": "Resend guest invitation",
+            "description": "Sends a pending invitation again and renews its expiration date.",
+            "tags": [{ "name": "subscriptionsManager" }, { "name": "guests" }],
+            "params": [{ "name": "params", "required": true, "schema": guest_invitation_schema }],
+            "result": { "name": "result", "description": "Renewed invitation (UpdatingResponseKind)", "schema": { "type": "object" } },
+            "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
+        }),
+        serde_json::json!({
+            "name": method_names::SUBSCRIPTIONS_MANAGER_GUESTS_CANCEL_INVITATION,
+            "summary": "Cancel guest invitation",
+            "description": "Removes an invitation not accepted yet.",
+            "tags": [{ "name": "subscriptionsManager" }, { "name": "guests" }],
+            "params": [{ "name": "params", "required": true, "schema": guest_invitation_schema }],
+            "result": { "name": "result", "description": "null on success (DeletionResponseKind)", "schema": { "type": "null" } },
+            "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
+        }),
         serde_json::json!({
             "name": method_names::SUBSCRIPTIONS_MANAGER_GUEST_ROLES_LIST,
             "summary": "List guest roles",
@@ -195,5 +249,32 @@ pub fn methods() -> Vec<serde_json::Value> {
             "result": { "name": "result", "description": "null on success (DeletionResponseKind)", "schema": { "type": "null" } },
             "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
         }),
+        serde_json::json!({
+            "name": method_names::SUBSCRIPTIONS_MANAGER_BULK_IMPORTS_SUBMIT,
+            "summary": "Submit bulk import",
+            "description": "Submits CSV or JSON rows inviting emails to guest roles on subscription accounts, created when missing. Dry runs only validate the rows. Re-submitting the same rows returns the job submitted first.",
+            "tags": [{ "name": "subscriptionsManager" }, { "name": "bulkImports" }],
+            "params": [{ "name": "params", "required": true, "schema": submit_bulk_import_schema }],
+            "result": { "name": "result", "description": "Submitted, existing or dry run job (GetOrCreateResponseKind)", "schema": { "type": "object" } },
+            "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
+        }),
+        serde_json::json!({
+            "name": method_names::SUBSCRIPTIONS_MANAGER_BULK_IMPORTS_LIST,
+            "summary": "List bulk imports",
+            "description": "Lists the bulk imports of the tenant, the most recent first.",
+            "tags": [{ "name": "subscriptionsManager" }, { "name": "bulkImports" }],
+            "params": [{ "name": "params", "required": true, "schema": list_bulk_imports_schema }],
+            "result": { "name": "result", "description": "Paginated bulk imports", "schema": { "type": "object" } },
+            "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
+        }),
+        serde_json::json!({
+            "name": method_names::SUBSCRIPTIONS_MANAGER_BULK_IMPORTS_GET,
+            "summary": "Get bulk import",
+            "description": "Returns the status and the row results of a bulk import.",
+            "tags": [{ "name": "subscriptionsManager" }, { "name": "bulkImports" }],
+            "params": [{ "name": "params", "required": true, "schema": get_bulk_import_schema }],
+            "result": { "name": "result", "description": "Bulk import or null", "schema": { "type": ["object", "null"] } },
+            "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
+        }),
     ]
 }
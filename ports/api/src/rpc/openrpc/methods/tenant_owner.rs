@@ -15,6 +15,12 @@ pub fn methods() -> Vec<serde_json::Value> {
         schema::param_schema_value::<params::GuestTenantOwnerParams>();
     let revoke_tenant_owner_schema =
         schema::param_schema_value::<params::RevokeTenantOwnerParams>();
+    let create_scim_credential_schema =
+        schema::param_schema_value::<params::CreateScimCredentialParams>();
+    let list_scim_credentials_schema =
+        schema::param_schema_value::<params::ListScimCredentialsParams>();
+    let delete_scim_credential_schema =
+        schema::param_schema_value::<params::DeleteScimCredentialParams>();
     let update_tenant_name_and_description_schema = schema::param_schema_value::<
         params::UpdateTenantNameAndDescriptionParams,
     >();
@@ -24,9 +30,15 @@ pub fn methods() -> Vec<serde_json::Value> {
     let update_tenant_trashing_status_schema = schema::param_schema_value::<
         params::UpdateTenantTrashingStatusParams,
     >();
+    let restore_trashed_tenant_schema =
+        schema::param_schema_value::<params::RestoreTrashedTenantParams>();
     let update_tenant_verifying_status_schema = schema::param_schema_value::<
         params::UpdateTenantVerifyingStatusParams,
     >();
+    let update_tenant_parent_schema =
+        schema::param_schema_value::<params::UpdateTenantParentParams>();
+    let list_tenant_descendants_schema =
+        schema::param_schema_value::<params::ListTenantDescendantsParams>();
 
     vec![
         serde_json::json!({
@@ -83,6 +95,33 @@ pub fn methods() -> Vec<serde_json::Value> {
             "result": { "name": "result", "description": "null on success (DeletionResponseKind)", "schema": { "type": "null" } },
             "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
         }),
+        serde_json::json!({
+            "name": method_names::TENANT_OWNER_SCIM_CREDENTIALS_CREATE,
+            "summary": "Create SCIM credential",
+            "description": "Issues a bearer credential for a SCIM client of the tenant. The secret is returned only once.",
+            "tags": [{ "name": "tenantOwner" }, { "name": "scimCredentials" }],
+            "params": [{ "name": "params", "required": true, "schema": create_scim_credential_schema }],
+            "result": { "name": "result", "description": "Issued credential with its secret (IssuedScimCredential)", "schema": { "type": "object" } },
+            "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
+        }),
+        serde_json::json!({
+            "name": method_names::TENANT_OWNER_SCIM_CREDENTIALS_LIST,
+            "summary": "List SCIM credentials",
+            "description": "Lists the SCIM credentials of the tenant, without their secrets.",
+            "tags": [{ "name": "tenantOwner" }, { "name": "scimCredentials" }],
+            "params": [{ "name": "params", "required": true, "schema": list_scim_credentials_schema }],
+            "result": { "name": "result", "description": "List of ScimCredential (FetchManyResponseKind)", "schema": { "type": "array" } },
+            "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
+        }),
+        serde_json::json!({
+            "name": method_names::TENANT_OWNER_SCIM_CREDENTIALS_DELETE,
+            "summary": "Delete SCIM credential",
+            "description": "Revokes a SCIM credential of the tenant.",
+            "tags": [{ "name": "tenantOwner" }, { "name": "scimCredentials" }],
+            "params": [{ "name": "params", "required": true, "schema": delete_scim_credential_schema }],
+            "result": { "name": "result", "description": "null on success (DeletionResponseKind)", "schema": { "type": "null" } },
+            "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
+        }),
         serde_json::json!({
             "name": method_names::TENANT_OWNER_TENANT_UPDATE_AND_DESCRIPTION,
             "summary": "Update tenant name and description",
@@ -110,6 +149,15 @@ pub fn methods() -> Vec<serde_json::Value> {
             "result": { "name": "result", "description": "Updated tenant (UpdatingResponseKind)", "schema": { "type": "object" } },
             "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
         }),
+        serde_json::json!({
+            "name": method_names::TENANT_OWNER_TENANT_RESTORE,
+            "summary": "Restore trashed tenant",
+            "description": "Takes the tenant out of the trash, before it is purged at the end of the trash retention period.",
+            "tags": [{ "name": "tenantOwner" }, { "name": "tenant" }],
+            "params": [{ "name": "params", "required": true, "schema": restore_trashed_tenant_schema }],
+            "result": { "name": "result", "description": "Updated tenant (UpdatingResponseKind)", "schema": { "type": "object" } },
+            "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
+        }),
         serde_json::json!({
             "name": method_names::TENANT_OWNER_TENANT_UPDATE_VERIFYING_STATUS,
             "summary": "Update tenant verifying status",
@@ -119,5 +167,23 @@ pub fn methods() -> Vec<serde_json::Value> {
             "result": { "name": "result", "description": "Updated tenant (UpdatingResponseKind)", "schema": { "type": "object" } },
             "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
         }),
+        serde_json::json!({
+            "name": method_names::TENANT_OWNER_TENANT_UPDATE_PARENT,
+            "summary": "Update tenant parent",
+            "description": "Attaches the tenant to a parent tenant, or detaches it when parentId is omitted. Requires the ownership of the tenant and of the new parent, or of the current parent when detaching. Descendants of the tenant could not be its parent.",
+            "tags": [{ "name": "tenantOwner" }, { "name": "tenant" }],
+            "params": [{ "name": "params", "required": true, "schema": update_tenant_parent_schema }],
+            "result": { "name": "result", "description": "Updated tenant (UpdatingResponseKind)", "schema": { "type": "object" } },
+            "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
+        }),
+        serde_json::json!({
+            "name": method_names::TENANT_OWNER_TENANT_LIST_DESCENDANTS,
+            "summary": "List tenant descendants",
+            "description": "Lists the descendants of the tenant, children before their own children.",
+            "tags": [{ "name": "tenantOwner" }, { "name": "tenant" }],
+            "params": [{ "name": "params", "required": true, "schema": list_tenant_descendants_schema }],
+            "result": { "name": "result", "description": "List of tenants (FetchManyResponseKind)", "schema": { "type": "array", "items": { "type": "object" } } },
+            "errors": [{ "code": -32602, "message": "Invalid params" }, { "code": -32401, "message": "Forbidden" }]
+        }),
     ]
 }
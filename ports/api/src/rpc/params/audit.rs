@@ -6,8 +6,50 @@ use uuid::Uuid;
 #[serde(rename_all = "camelCase")]
 pub struct FetchResourceAuditTrailParams {
     #[schemars(
-        description = "Resource type: account, accountMeta, user, tenant, tenantMeta, guestRole, webhook"
+        description = "Resource type: account, accountMeta, user, tenant, tenantMeta, guestRole, webhook, guestUser, connectionString, errorCode"
     )]
     pub resource_type: String,
     pub resource_id: Uuid,
 }
+
+/// Filters shared by `audit.resourceTrail.search` and
+/// `audit.resourceTrail.export`. Mirrors the REST query string of
+/// `/_adm/audit/search`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResourceAuditTrailParams {
+    #[schemars(
+        description = "Resource type: account, accountMeta, user, tenant, tenantMeta, guestRole, webhook, guestUser, connectionString, errorCode. Required with resourceId"
+    )]
+    pub resource_type: Option<String>,
+    pub resource_id: Option<Uuid>,
+    #[schemars(
+        description = "Without resourceId, tenant owners and managers must set their own tenant"
+    )]
+    pub tenant_id: Option<Uuid>,
+    #[schemars(description = "User or account that performed the action")]
+    pub performed_by: Option<Uuid>,
+    #[schemars(
+        description = "Event kind: created, updated, deleted, accessed"
+    )]
+    pub event: Option<String>,
+    #[schemars(description = "RFC 3339 timestamp, inclusive")]
+    pub created_after: Option<String>,
+    #[schemars(description = "RFC 3339 timestamp, exclusive")]
+    pub created_before: Option<String>,
+    #[schemars(description = "Case-insensitive text searched in metadata")]
+    pub metadata_contains: Option<String>,
+    #[schemars(description = "nextCursor of the previous page")]
+    pub cursor: Option<String>,
+    #[schemars(description = "Rows per page (default 50, at most 1000)")]
+    pub page_size: Option<i64>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportResourceAuditTrailParams {
+    #[serde(flatten)]
+    pub search: SearchResourceAuditTrailParams,
+    #[schemars(description = "Export format: csv, jsonl")]
+    pub format: String,
+}
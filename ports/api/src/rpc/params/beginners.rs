@@ -33,6 +33,30 @@ pub struct DeleteMyAccountParams {
     pub account_id: Uuid,
 }
 
+#[derive(Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportMyDataParams {
+    #[schemars(
+        description = "Account ID (must match the authenticated user's account)"
+    )]
+    pub account_id: Uuid,
+    #[schemars(description = "auditNextCursor of the previous page")]
+    pub cursor: Option<String>,
+    #[schemars(
+        description = "Audit entries per page (default 50, at most 1000)"
+    )]
+    pub page_size: Option<i64>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EraseMyDataParams {
+    #[schemars(
+        description = "Account ID (must match the authenticated user's account)"
+    )]
+    pub account_id: Uuid,
+}
+
 // ---------------------------------------------------------------------------
 // Guests (accept invitation)
 // ---------------------------------------------------------------------------
@@ -168,6 +192,7 @@ pub struct CheckTokenAndActivateUserParams {
 #[serde(rename_all = "camelCase")]
 pub struct StartPasswordRedefinitionParams {
     pub email: String,
+    pub tenant_id: Option<Uuid>,
 }
 
 #[derive(Deserialize, JsonSchema)]
@@ -176,6 +201,7 @@ pub struct CheckTokenAndResetPasswordParams {
     pub token: String,
     pub email: String,
     pub new_password: String,
+    pub tenant_id: Option<Uuid>,
 }
 
 #[derive(Deserialize, JsonSchema)]
@@ -2,6 +2,7 @@
 
 use schemars::JsonSchema;
 use serde::Deserialize;
+use std::collections::HashMap;
 use uuid::Uuid;
 
 // ---------------------------------------------------------------------------
@@ -21,6 +22,37 @@ pub struct ListRoutesParams {
     pub skip: Option<i32>,
 }
 
+// ---------------------------------------------------------------------------
+// Routes (explain access policy)
+// ---------------------------------------------------------------------------
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ExplainAccessPolicyParams {
+    #[schemars(description = "Request method")]
+    pub method: String,
+    #[schemars(
+        description = "Request path, including the service name and the query string"
+    )]
+    pub path: String,
+    #[schemars(description = "Request headers")]
+    pub headers: Option<HashMap<String, String>>,
+    #[schemars(description = "Client IP address")]
+    pub client_ip: Option<String>,
+    #[schemars(
+        description = "Request date and time (RFC 3339). Defaults to now"
+    )]
+    pub at: Option<String>,
+    #[schemars(
+        description = "Profile attributes of the requester. Defaults to the attributes of the current profile"
+    )]
+    pub profile: Option<serde_json::Value>,
+    #[schemars(
+        description = "Draft policy to evaluate. Defaults to the policy of the route matching the path"
+    )]
+    pub policy: Option<serde_json::Value>,
+}
+
 // ---------------------------------------------------------------------------
 // Services (list services)
 // ---------------------------------------------------------------------------
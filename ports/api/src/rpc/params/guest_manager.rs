@@ -61,6 +61,17 @@ pub struct UpdateGuestRolePermissionParams {
     pub permission: i32,
 }
 
+#[derive(Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateGuestRoleNamedPermissionsParams {
+    #[schemars(description = "Guest role ID")]
+    pub guest_role_id: Uuid,
+    #[schemars(
+        description = "Named permissions granted by the role, as invoices:approve. Replaces the current ones."
+    )]
+    pub permissions: Vec<String>,
+}
+
 #[derive(Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct InsertRoleChildParams {
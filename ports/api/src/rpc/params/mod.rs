@@ -16,27 +16,32 @@ pub(crate) use account_manager::{
     FetchGuestRoleDetailsParams, GuestToChildrenAccountParams,
     ListGuestRolesParams,
 };
-pub(crate) use audit::FetchResourceAuditTrailParams;
+pub(crate) use audit::{
+    ExportResourceAuditTrailParams, FetchResourceAuditTrailParams,
+    SearchResourceAuditTrailParams,
+};
 pub(crate) use beginners::{
     AcceptInvitationParams, CheckEmailPasswordValidityParams,
     CheckTokenAndActivateUserParams, CheckTokenAndResetPasswordParams,
     CreateAccountMetaParams, CreateConnectionStringParams,
     CreateDefaultAccountParams, CreateDefaultUserParams,
     DeleteAccountMetaParams, DeleteConnectionStringParams,
-    DeleteMyAccountParams, FetchMyProfileParams, FetchTenantPublicInfoParams,
+    DeleteMyAccountParams, EraseMyDataParams, ExportMyDataParams,
+    FetchMyProfileParams, FetchTenantPublicInfoParams,
     RevokeConnectionStringParams, StartPasswordRedefinitionParams,
     TotpCheckTokenParams, TotpDisableParams, TotpFinishActivationParams,
     TotpStartActivationParams, UpdateAccountMetaParams,
     UpdateOwnAccountNameParams,
 };
 pub(crate) use gateway_manager::{
-    ListOperationsParams, ListRoutesParams, ListServicesParams,
+    ExplainAccessPolicyParams, ListOperationsParams, ListRoutesParams,
+    ListServicesParams,
 };
 pub(crate) use guest_manager::{
     CreateGuestRoleParams, DeleteGuestRoleParams, InsertRoleChildParams,
     ListGuestRolesParams as GuestManagerListGuestRolesParams,
     RemoveRoleChildParams, UpdateGuestRoleNameAndDescriptionParams,
-    UpdateGuestRolePermissionParams,
+    UpdateGuestRoleNamedPermissionsParams, UpdateGuestRolePermissionParams,
 };
 pub(crate) use managers::{
     CreateSystemAccountParams, CreateTenantParams, DeleteTenantParams,
@@ -48,11 +53,13 @@ pub(crate) use staff::{
 };
 pub(crate) use subscriptions_manager::{
     CreateRoleAssociatedAccountParams, CreateSubscriptionAccountParams,
-    DeleteTagParams, GetAccountDetailsParams,
+    DeleteTagParams, ExtendGuestGrantParams, GetAccountDetailsParams,
+    GetBulkImportParams, GuestInvitationParams,
     GuestUserToSubscriptionAccountParams, ListAccountsByTypeParams,
+    ListBulkImportsParams, ListGuestInvitationsParams,
     ListGuestOnSubscriptionAccountParams, ListLicensedAccountsOfEmailParams,
     PropagateSubscriptionAccountParams, RegisterTagParams,
-    RevokeUserGuestToSubscriptionAccountParams,
+    RevokeUserGuestToSubscriptionAccountParams, SubmitBulkImportParams,
     SubscriptionsManagerFetchGuestRoleDetailsParams,
     SubscriptionsManagerListGuestRolesParams, UpdateAccountNameAndFlagsParams,
     UpdateFlagsFromSubscriptionAccountParams, UpdateTagParams,
@@ -71,10 +78,13 @@ pub(crate) use tenant_manager::{
     TenantManagerUpdateTagParams,
 };
 pub(crate) use tenant_owner::{
-    CreateManagementAccountParams, CreateTenantMetaParams,
+    CreateManagementAccountParams, CreateScimCredentialParams,
+    CreateTenantMetaParams, DeleteScimCredentialParams,
     DeleteTenantManagerAccountParams, DeleteTenantMetaParams,
-    GuestTenantOwnerParams, RevokeTenantOwnerParams,
-    UpdateTenantArchivingStatusParams, UpdateTenantNameAndDescriptionParams,
+    GuestTenantOwnerParams, ListScimCredentialsParams,
+    ListTenantDescendantsParams, RestoreTrashedTenantParams,
+    RevokeTenantOwnerParams, UpdateTenantArchivingStatusParams,
+    UpdateTenantNameAndDescriptionParams, UpdateTenantParentParams,
     UpdateTenantTrashingStatusParams, UpdateTenantVerifyingStatusParams,
 };
 pub(crate) use users_manager::UserManagerAccountIdParams;
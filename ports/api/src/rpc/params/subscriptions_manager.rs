@@ -113,6 +113,22 @@ pub struct GuestUserToSubscriptionAccountParams {
     pub account_id: Uuid,
     pub role_id: Uuid,
     pub email: String,
+    #[schemars(description = "Start of the grant validity (RFC3339)")]
+    pub valid_from: Option<String>,
+    #[schemars(description = "End of the grant validity (RFC3339)")]
+    pub valid_until: Option<String>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtendGuestGrantParams {
+    pub tenant_id: Uuid,
+    pub account_id: Uuid,
+    pub role_id: Uuid,
+    #[schemars(
+        description = "New end of the grant validity (RFC3339). Omit to make the grant permanent"
+    )]
+    pub valid_until: Option<String>,
 }
 
 #[derive(Deserialize, JsonSchema)]
@@ -143,6 +159,22 @@ pub struct ListGuestOnSubscriptionAccountParams {
     pub skip: Option<i32>,
 }
 
+#[derive(Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ListGuestInvitationsParams {
+    pub tenant_id: Uuid,
+    pub account_id: Uuid,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GuestInvitationParams {
+    pub tenant_id: Uuid,
+    pub account_id: Uuid,
+    #[schemars(description = "The guest user the invitation was sent to")]
+    pub guest_user_id: Uuid,
+}
+
 // ---------------------------------------------------------------------------
 // Guest roles
 // ---------------------------------------------------------------------------
@@ -195,3 +227,34 @@ pub struct DeleteTagParams {
     pub account_id: Uuid,
     pub tag_id: Uuid,
 }
+
+// ---------------------------------------------------------------------------
+// Bulk imports
+// ---------------------------------------------------------------------------
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitBulkImportParams {
+    pub tenant_id: Uuid,
+    #[schemars(description = "The format of the content: csv or json")]
+    pub format: String,
+    #[schemars(description = "The rows to import, as CSV or as a JSON array")]
+    pub content: String,
+    #[schemars(description = "Only validate the rows, without importing them")]
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ListBulkImportsParams {
+    pub tenant_id: Uuid,
+    pub page_size: Option<i32>,
+    pub skip: Option<i32>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBulkImportParams {
+    pub tenant_id: Uuid,
+    pub bulk_import_id: Uuid,
+}
@@ -59,6 +59,31 @@ pub struct RevokeTenantOwnerParams {
     pub email: String,
 }
 
+// ---------------------------------------------------------------------------
+// SCIM credentials
+// ---------------------------------------------------------------------------
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateScimCredentialParams {
+    pub tenant_id: Uuid,
+    #[schemars(description = "A label of the SCIM client, e.g. okta")]
+    pub name: String,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ListScimCredentialsParams {
+    pub tenant_id: Uuid,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteScimCredentialParams {
+    pub tenant_id: Uuid,
+    pub credential_id: Uuid,
+}
+
 // ---------------------------------------------------------------------------
 // Tenant
 // ---------------------------------------------------------------------------
@@ -83,8 +108,28 @@ pub struct UpdateTenantTrashingStatusParams {
     pub tenant_id: Uuid,
 }
 
+#[derive(Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreTrashedTenantParams {
+    pub tenant_id: Uuid,
+}
+
 #[derive(Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateTenantVerifyingStatusParams {
     pub tenant_id: Uuid,
 }
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateTenantParentParams {
+    pub tenant_id: Uuid,
+    /// The new parent tenant. Omit it to detach the tenant from its parent.
+    pub parent_id: Option<Uuid>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ListTenantDescendantsParams {
+    pub tenant_id: Uuid,
+}
@@ -0,0 +1,804 @@
+use crate::functions::load_core_module;
+
+use clap::{
+    Arg, ArgAction, ArgMatches, Command, FromArgMatches, Parser, Subcommand,
+    ValueEnum,
+};
+use myc_core::{
+    domain::{
+        dtos::{email::Email, profile::Profile},
+        entities::{
+            EmailSuppressionFetching, LicensedResourcesFetching,
+            LocalMessageWrite, ProfileFetching, ResourceAuditLogRegistration,
+            TenantFetching, TokenDeletion, TokenFetching, TokenRegistration,
+        },
+    },
+    use_cases::{
+        role_scoped::beginner::token::{
+            create_connection_string, delete_connection_string,
+            list_my_connection_strings,
+        },
+        service::profile::{fetch_profile_from_email, ProfileResponse},
+    },
+};
+use myc_diesel::repositories::SqlAppModule;
+use mycelium_base::entities::FetchManyResponseKind;
+use mycelium_rpc_catalog::method_names::{METHODS, RPC_DISCOVER};
+use serde_json::{json, Map, Value};
+use shaku::HasComponent;
+use std::{env::var, fs, io::Read, process::exit, sync::Arc};
+use uuid::Uuid;
+
+/// A JSON-RPC method of the gateway, with its `admin` subcommand path
+pub(crate) struct RpcMethod {
+    pub name: &'static str,
+    pub path: Vec<String>,
+}
+
+/// Methods served by the endpoint itself rather than by a use case.
+const SKIPPED_METHODS: &[&str] = &[RPC_DISCOVER];
+
+/// `accountManager` → `account-manager`
+fn to_kebab_case(segment: &str) -> String {
+    let mut kebab = String::with_capacity(segment.len() + 4);
+
+    for (index, char) in segment.chars().enumerate() {
+        if char.is_ascii_uppercase() {
+            if index > 0 {
+                kebab.push('-');
+            }
+
+            kebab.push(char.to_ascii_lowercase());
+        } else {
+            kebab.push(char);
+        }
+    }
+
+    kebab
+}
+
+/// The methods of the catalogue, the single source of the `admin`
+/// subcommands, in declaration order.
+fn rpc_methods() -> Vec<RpcMethod> {
+    METHODS
+        .iter()
+        .filter(|method| !SKIPPED_METHODS.contains(method))
+        .map(|method| RpcMethod {
+            name: method,
+            path: method.split('.').map(to_kebab_case).collect(),
+        })
+        .collect()
+}
+
+/// Path of the JSON-RPC endpoint on the gateway.
+const RPC_PATH: &str = "_adm/rpc";
+
+const GATEWAY_URL_ENV: &str = "MYC_GATEWAY_URL";
+const DEFAULT_GATEWAY_URL: &str = "http://localhost:8080";
+const CONNECTION_STRING_ENV: &str = "MYC_CONNECTION_STRING";
+
+/// Header the gateway reads connection strings from.
+const CONNECTION_STRING_HEADER: &str = "x-mycelium-connection-string";
+
+/// Header the gateway reads the requested tenant from.
+const TENANT_ID_HEADER: &str = "x-mycelium-tenant-id";
+
+/// Lifetime of the connection strings minted for `--email`, in seconds.
+const MINTED_CONNECTION_STRING_TTL: i64 = 300;
+
+/// Width above which table cells are truncated.
+const MAX_CELL_WIDTH: usize = 60;
+
+#[derive(Parser, Debug)]
+pub(crate) struct Arguments {
+    /// Base URL of the gateway (default: MYC_GATEWAY_URL, then
+    /// http://localhost:8080).
+    #[clap(long, global = true, value_name = "URL")]
+    pub gateway_url: Option<String>,
+
+    /// Connection string to authenticate with (default:
+    /// MYC_CONNECTION_STRING).
+    #[clap(long, global = true, value_name = "TOKEN")]
+    pub connection_string: Option<String>,
+
+    /// Authenticate via direct database access, as the account of this
+    /// email.
+    ///
+    /// A connection string of the account is created in the database
+    /// (DATABASE_URL, SETTINGS_PATH) for the call and deleted afterwards.
+    /// The account owner is notified of it, as for any connection string.
+    #[clap(long, global = true, value_name = "EMAIL")]
+    pub email: Option<String>,
+
+    /// Tenant the call is made on behalf of.
+    #[clap(long, global = true, value_name = "UUID")]
+    pub tenant_id: Option<Uuid>,
+
+    /// Output format.
+    #[clap(long, global = true, value_enum, default_value_t = OutputFormat::Json)]
+    pub output: OutputFormat,
+
+    #[clap(subcommand)]
+    pub cmd: Commands,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub(crate) enum OutputFormat {
+    Json,
+    Table,
+}
+
+#[derive(Subcommand, Debug)]
+pub(crate) enum Commands {
+    /// List the JSON-RPC methods and their subcommands
+    Methods,
+
+    /// Show the summary and parameters of a method, as served by the gateway
+    Describe {
+        /// The method name (e.g. managers.tenants.list)
+        method: String,
+    },
+
+    #[clap(flatten)]
+    Call(RpcCall),
+}
+
+/// A call to a JSON-RPC method, one subcommand per method of the catalogue
+#[derive(Debug)]
+pub(crate) struct RpcCall {
+    pub method: &'static str,
+    pub params: Option<String>,
+    pub param: Vec<String>,
+}
+
+/// The method name prefix of a subcommand path (e.g. `managers.tenants`).
+fn method_prefix(methods: &[RpcMethod], path: &[String]) -> String {
+    methods
+        .iter()
+        .find(|method| method.path.starts_with(path))
+        .map(|method| {
+            method
+                .name
+                .split('.')
+                .take(path.len())
+                .collect::<Vec<_>>()
+                .join(".")
+        })
+        .unwrap_or_default()
+}
+
+/// Build the subcommands of the methods whose path starts with `prefix`.
+fn method_subcommands(
+    methods: &[RpcMethod],
+    prefix: &[String],
+) -> Vec<Command> {
+    let depth = prefix.len();
+    let mut segments: Vec<&String> = vec![];
+
+    for method in methods
+        .iter()
+        .filter(|method| method.path.starts_with(prefix))
+    {
+        if method.path.len() > depth && !segments.contains(&&method.path[depth])
+        {
+            segments.push(&method.path[depth]);
+        }
+    }
+
+    segments
+        .into_iter()
+        .map(|segment| {
+            let path = [prefix, &[segment.to_owned()]].concat();
+
+            match methods.iter().find(|method| method.path == path) {
+                Some(method) => Command::new(segment.to_owned())
+                    .about(format!("Call {}", method.name))
+                    .arg(
+                        Arg::new("params")
+                            .long("params")
+                            .value_name("JSON")
+                            .help(
+                                "Parameters as a JSON object, @FILE to read \
+                                 them from a file or - from stdin",
+                            ),
+                    )
+                    .arg(
+                        Arg::new("param")
+                            .long("param")
+                            .value_name("KEY=VALUE")
+                            .action(ArgAction::Append)
+                            .help(
+                                "A single parameter, parsed as JSON when \
+                                 valid and as a string otherwise",
+                            ),
+                    ),
+                None => Command::new(segment.to_owned())
+                    .about(format!(
+                        "Methods under {}",
+                        method_prefix(methods, &path)
+                    ))
+                    .subcommand_required(true)
+                    .subcommands(method_subcommands(methods, &path)),
+            }
+        })
+        .collect()
+}
+
+/// Build the parameters of a call from its `--params` and `--param`
+/// arguments.
+fn build_params(call: &RpcCall) -> Result<Option<Value>, String> {
+    let mut params = match call.params.as_deref() {
+        None => None,
+        Some(raw) => {
+            let raw = match raw {
+                "-" => {
+                    let mut raw = String::new();
+                    std::io::stdin().read_to_string(&mut raw).map_err(
+                        |err| format!("Unable to read stdin: {err}"),
+                    )?;
+                    raw
+                }
+                raw => match raw.strip_prefix('@') {
+                    Some(path) => fs::read_to_string(path).map_err(|err| {
+                        format!("Unable to read {path}: {err}")
+                    })?,
+                    None => raw.to_string(),
+                },
+            };
+
+            Some(
+                serde_json::from_str::<Value>(&raw).map_err(|err| {
+                    format!("--params is not valid JSON: {err}")
+                })?,
+            )
+        }
+    };
+
+    for param in call.param.iter() {
+        let Some((key, value)) = param.split_once('=') else {
+            return Err(format!("--param expects KEY=VALUE, got {param}"));
+        };
+
+        let value = serde_json::from_str::<Value>(value)
+            .unwrap_or_else(|_| Value::String(value.to_string()));
+
+        match params.get_or_insert_with(|| Value::Object(Map::new())) {
+            Value::Object(object) => {
+                object.insert(key.to_string(), value);
+            }
+            _ => {
+                return Err(
+                    "--param requires --params to be a JSON object".to_string()
+                )
+            }
+        }
+    }
+
+    Ok(params)
+}
+
+impl FromArgMatches for RpcCall {
+    fn from_arg_matches(matches: &ArgMatches) -> Result<Self, clap::Error> {
+        let mut path = vec![];
+        let mut current = matches;
+
+        while let Some((name, sub_matches)) = current.subcommand() {
+            path.push(name);
+            current = sub_matches;
+        }
+
+        let method = rpc_methods()
+            .into_iter()
+            .find(|method| method.path == path)
+            .ok_or_else(|| {
+                clap::Error::raw(
+                    clap::error::ErrorKind::InvalidSubcommand,
+                    format!("Unknown method {}\n", path.join(" ")),
+                )
+            })?;
+
+        Ok(Self {
+            method: method.name,
+            params: current.get_one::<String>("params").cloned(),
+            param: current
+                .get_many::<String>("param")
+                .into_iter()
+                .flatten()
+                .cloned()
+                .collect(),
+        })
+    }
+
+    fn update_from_arg_matches(
+        &mut self,
+        matches: &ArgMatches,
+    ) -> Result<(), clap::Error> {
+        *self = Self::from_arg_matches(matches)?;
+        Ok(())
+    }
+}
+
+impl Subcommand for RpcCall {
+    fn augment_subcommands(cmd: Command) -> Command {
+        cmd.subcommands(method_subcommands(&rpc_methods(), &[]))
+    }
+
+    fn augment_subcommands_for_update(cmd: Command) -> Command {
+        Self::augment_subcommands(cmd)
+    }
+
+    fn has_subcommand(name: &str) -> bool {
+        rpc_methods().iter().any(|method| method.path[0] == name)
+    }
+}
+
+/// A connection string created for `--email`, deleted once the call is done
+struct MintedConnectionString {
+    name: String,
+    profile: Profile,
+    module: Arc<SqlAppModule>,
+}
+
+/// Resolve the connection string the call authenticates with
+async fn resolve_credentials(
+    args: &Arguments,
+) -> (String, Option<MintedConnectionString>) {
+    let Some(email) = args.email.as_ref() else {
+        return match args
+            .connection_string
+            .clone()
+            .or_else(|| var(CONNECTION_STRING_ENV).ok())
+            .filter(|connection_string| !connection_string.is_empty())
+        {
+            Some(connection_string) => (connection_string, None),
+            None => {
+                tracing::error!(
+                    "Set --connection-string (or {CONNECTION_STRING_ENV}), or \
+                     --email to authenticate via the database"
+                );
+                exit(1);
+            }
+        };
+    };
+
+    if args.connection_string.is_some() {
+        tracing::error!("--email and --connection-string are exclusive");
+        exit(1);
+    }
+
+    let email = match Email::from_string(email.to_owned()) {
+        Ok(email) => email,
+        Err(err) => {
+            tracing::error!("Invalid email: {err}");
+            exit(1);
+        }
+    };
+
+    let (core_config, module) = load_core_module("admin --email").await;
+
+    let profile_fetching_repo: &dyn ProfileFetching = module.resolve_ref();
+    let licensed_resources_fetching_repo: &dyn LicensedResourcesFetching =
+        module.resolve_ref();
+
+    let profile = match fetch_profile_from_email(
+        email,
+        None,
+        None,
+        None,
+        Box::new(profile_fetching_repo),
+        Box::new(licensed_resources_fetching_repo),
+    )
+    .await
+    {
+        Ok(ProfileResponse::RegisteredUser(profile)) => profile,
+        Ok(ProfileResponse::UnregisteredUser(_)) => {
+            tracing::error!("No account is registered with this email");
+            exit(1);
+        }
+        Err(err) => {
+            tracing::error!("Failed to fetch the profile: {err}");
+            exit(1);
+        }
+    };
+
+    let name = format!("myc-cli admin {}", Uuid::new_v4());
+
+    let token_registration_repo: &dyn TokenRegistration = module.resolve_ref();
+    let message_sending_repo: &dyn LocalMessageWrite = module.resolve_ref();
+    let email_suppression_fetching_repo: &dyn EmailSuppressionFetching =
+        module.resolve_ref();
+    let tenant_fetching_repo: &dyn TenantFetching = module.resolve_ref();
+    let audit_repo: &dyn ResourceAuditLogRegistration = module.resolve_ref();
+
+    let connection_string = create_connection_string(
+        profile.to_owned(),
+        name.to_owned(),
+        MINTED_CONNECTION_STRING_TTL,
+        None,
+        None,
+        None,
+        core_config.account_life_cycle,
+        Box::new(token_registration_repo),
+        Box::new(message_sending_repo),
+        Box::new(email_suppression_fetching_repo),
+        Box::new(tenant_fetching_repo),
+        Box::new(audit_repo),
+    )
+    .await;
+
+    let minted = MintedConnectionString {
+        name,
+        profile,
+        module,
+    };
+
+    match connection_string {
+        Ok(connection_string) => (connection_string, Some(minted)),
+        Err(err) => {
+            //
+            // The connection string may have been registered before the
+            // failing step (e.g. the owner notification).
+            //
+            minted.delete().await;
+
+            tracing::error!("Failed to create a connection string: {err}");
+            exit(1);
+        }
+    }
+}
+
+impl MintedConnectionString {
+    async fn delete(self) {
+        let token_fetching_repo: &dyn TokenFetching = self.module.resolve_ref();
+
+        let tokens = match list_my_connection_strings(
+            self.profile.to_owned(),
+            Box::new(token_fetching_repo),
+        )
+        .await
+        {
+            Ok(FetchManyResponseKind::Found(tokens)) => tokens,
+            Ok(FetchManyResponseKind::FoundPaginated { records, .. }) => {
+                records
+            }
+            Ok(FetchManyResponseKind::NotFound) => vec![],
+            Err(err) => {
+                tracing::warn!(
+                    "Failed to list the connection strings, {} expires on \
+                     its own: {err}",
+                    self.name
+                );
+                return;
+            }
+        };
+
+        let token_deletion_repo: &dyn TokenDeletion = self.module.resolve_ref();
+        let audit_repo: &dyn ResourceAuditLogRegistration =
+            self.module.resolve_ref();
+
+        for token in tokens.iter().filter(|token| token.name == self.name) {
+            if let Err(err) = delete_connection_string(
+                self.profile.to_owned(),
+                token.id,
+                Box::new(token_deletion_repo),
+                Box::new(audit_repo),
+            )
+            .await
+            {
+                tracing::warn!(
+                    "Failed to delete {}, it expires on its own: {err}",
+                    self.name
+                );
+            }
+        }
+    }
+}
+
+/// Call `method` on the gateway, returning its result or its JSON-RPC error.
+async fn call_method(
+    args: &Arguments,
+    connection_string: &str,
+    method: &str,
+    params: Option<Value>,
+) -> Result<Value, String> {
+    let gateway_url = args
+        .gateway_url
+        .clone()
+        .or_else(|| var(GATEWAY_URL_ENV).ok())
+        .unwrap_or_else(|| DEFAULT_GATEWAY_URL.to_string());
+
+    let mut body = json!({ "jsonrpc": "2.0", "method": method, "id": 1 });
+
+    if let Some(params) = params {
+        body["params"] = params;
+    }
+
+    let mut request = reqwest::Client::new()
+        .post(format!("{}/{RPC_PATH}", gateway_url.trim_end_matches('/')))
+        .header(CONNECTION_STRING_HEADER, connection_string)
+        .json(&body);
+
+    if let Some(tenant_id) = args.tenant_id {
+        request = request.header(TENANT_ID_HEADER, tenant_id.to_string());
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|err| format!("Unable to reach the gateway: {err}"))?;
+
+    let status = response.status();
+
+    let response = response.json::<Value>().await.map_err(|err| {
+        format!("Unexpected gateway response ({status}): {err}")
+    })?;
+
+    if let Some(error) = response.get("error") {
+        return Err(format!(
+            "{method} failed ({}): {}{}",
+            error["code"],
+            error["message"].as_str().unwrap_or_default(),
+            match error.get("data") {
+                Some(data) => format!(" {data}"),
+                None => String::new(),
+            }
+        ));
+    }
+
+    match response.get("result") {
+        Some(result) => Ok(result.to_owned()),
+        None => Err(format!(
+            "Unexpected gateway response ({status}): {response}"
+        )),
+    }
+}
+
+/// Render a cell of a table
+fn cell(value: &Value) -> String {
+    let text = match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.to_owned(),
+        value => value.to_string(),
+    };
+
+    match text.chars().count() > MAX_CELL_WIDTH {
+        true => {
+            let truncated = text.chars().take(MAX_CELL_WIDTH - 1).collect();
+            [truncated, "…".to_string()].concat()
+        }
+        false => text,
+    }
+}
+
+/// Render `rows` under `columns` as an aligned text table
+fn format_rows(columns: &[String], rows: &[Vec<String>]) -> String {
+    let widths = columns
+        .iter()
+        .enumerate()
+        .map(|(index, column)| {
+            rows.iter()
+                .map(|row| row[index].chars().count())
+                .chain([column.chars().count()])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    let line = |cells: &[String]| {
+        cells
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    [line(columns)]
+        .into_iter()
+        .chain([line(
+            &widths
+                .iter()
+                .map(|width| "-".repeat(*width))
+                .collect::<Vec<_>>(),
+        )])
+        .chain(rows.iter().map(|row| line(row)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Render a method result as a table
+///
+/// A list of objects gets a column per field, a paginated result the table
+/// of its records and an object a row per field.
+fn format_table(value: &Value) -> String {
+    match value {
+        Value::Array(items) => {
+            let mut columns: Vec<String> = vec![];
+
+            for item in items {
+                if let Value::Object(object) = item {
+                    for key in object.keys() {
+                        if !columns.contains(key) {
+                            columns.push(key.to_owned());
+                        }
+                    }
+                }
+            }
+
+            if columns.is_empty() {
+                return format_rows(
+                    &["value".to_string()],
+                    &items
+                        .iter()
+                        .map(|item| vec![cell(item)])
+                        .collect::<Vec<_>>(),
+                );
+            }
+
+            let rows = items
+                .iter()
+                .map(|item| {
+                    columns
+                        .iter()
+                        .map(|column| {
+                            item.get(column).map(cell).unwrap_or_default()
+                        })
+                        .collect()
+                })
+                .collect::<Vec<_>>();
+
+            format_rows(&columns, &rows)
+        }
+        Value::Object(object) => match object.get("records") {
+            Some(records @ Value::Array(_)) => format!(
+                "{}\n\n{} of {} records",
+                format_table(records),
+                records.as_array().map(Vec::len).unwrap_or_default(),
+                cell(object.get("count").unwrap_or(&Value::Null))
+            ),
+            _ => format_rows(
+                &["field".to_string(), "value".to_string()],
+                &object
+                    .iter()
+                    .map(|(key, value)| vec![key.to_owned(), cell(value)])
+                    .collect::<Vec<_>>(),
+            ),
+        },
+        Value::Null => "(none)".to_string(),
+        value => cell(value),
+    }
+}
+
+/// Render the OpenRPC description of a method, with a row per parameter
+fn format_description(described: &Value) -> String {
+    let schema = &described["params"][0]["schema"];
+
+    let required = schema["required"]
+        .as_array()
+        .map(|required| {
+            required
+                .iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    let rows = schema["properties"]
+        .as_object()
+        .map(|properties| {
+            properties
+                .iter()
+                .map(|(name, property)| {
+                    vec![
+                        name.to_owned(),
+                        cell(&property["type"]),
+                        required.contains(&name.as_str()).to_string(),
+                        cell(&property["description"]),
+                    ]
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    format!(
+        "{}\n\n{}\n\n{}",
+        cell(&described["summary"]),
+        described["description"].as_str().unwrap_or_default(),
+        match rows.is_empty() {
+            true => "No parameters".to_string(),
+            false => format_rows(
+                &["param", "type", "required", "description"]
+                    .map(str::to_string),
+                &rows,
+            ),
+        }
+    )
+}
+
+fn print_value(value: &Value, output: OutputFormat) {
+    match output {
+        OutputFormat::Table => println!("{}", format_table(value)),
+        OutputFormat::Json => match serde_json::to_string_pretty(value) {
+            Ok(json) => println!("{json}"),
+            Err(err) => {
+                tracing::error!("Failed to serialize the result: {err}");
+                exit(1);
+            }
+        },
+    }
+}
+
+#[tracing::instrument(name = "admin_cmd", skip_all)]
+pub(crate) async fn admin_cmd(args: Arguments) {
+    let (method, params) = match &args.cmd {
+        Commands::Methods => {
+            let methods = rpc_methods()
+                .iter()
+                .map(|method| {
+                    json!({
+                        "method": method.name,
+                        "command": format!("myc-cli admin {}", method.path.join(" ")),
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            print_value(&Value::Array(methods), args.output);
+            return;
+        }
+        Commands::Describe { method } => {
+            if !rpc_methods().iter().any(|known| known.name == method) {
+                tracing::error!("Unknown method {method}");
+                exit(1);
+            }
+
+            (RPC_DISCOVER, None)
+        }
+        Commands::Call(call) => match build_params(call) {
+            Ok(params) => (call.method, params),
+            Err(err) => {
+                tracing::error!("{err}");
+                exit(1);
+            }
+        },
+    };
+
+    let (connection_string, minted) = resolve_credentials(&args).await;
+
+    let result = call_method(&args, &connection_string, method, params).await;
+
+    if let Some(minted) = minted {
+        minted.delete().await;
+    }
+
+    let result = match (result, &args.cmd) {
+        (Err(err), _) => {
+            tracing::error!("{err}");
+            exit(1);
+        }
+        (Ok(spec), Commands::Describe { method }) => {
+            match spec["methods"].as_array().and_then(|methods| {
+                methods.iter().find(|known| known["name"] == *method)
+            }) {
+                Some(described) => match args.output {
+                    OutputFormat::Table => {
+                        println!("{}", format_description(described));
+                        return;
+                    }
+                    OutputFormat::Json => described.to_owned(),
+                },
+                None => {
+                    tracing::error!(
+                        "The gateway does not describe {method}: it may run \
+                         another release than this CLI"
+                    );
+                    exit(1);
+                }
+            }
+        }
+        (Ok(result), _) => result,
+    };
+
+    print_value(&result, args.output);
+}
@@ -0,0 +1,378 @@
+use crate::functions::load_core_module;
+
+use clap::{Args, Parser};
+use myc_core::{
+    domain::{
+        entities::{
+            EncryptionKeyFetching, ResourceAuditLogArchival,
+            ResourceAuditLogFetching,
+        },
+        utils::ResourceAuditArchiveStore,
+    },
+    use_cases::shared::audit::{
+        archive_expired_resource_audit_rows, restore_resource_audit_archive,
+        set_resource_audit_legal_hold, set_resource_audit_retention_days,
+        verify_resource_audit_chain,
+    },
+};
+use mycelium_base::entities::FetchManyResponseKind;
+use shaku::HasComponent;
+use std::{path::PathBuf, process::exit};
+use uuid::Uuid;
+
+#[derive(Parser, Debug)]
+pub(crate) struct Arguments {
+    #[clap(subcommand)]
+    pub cmd: Commands,
+}
+
+#[derive(Parser, Debug)]
+pub(crate) enum Commands {
+    /// Verify the resource audit log hash chains and their signed
+    /// checkpoints.
+    ///
+    /// Reports every gap, broken link, modified row, and invalid checkpoint.
+    /// Exits with status 1 when any verified chain is not intact.
+    Verify(VerifyArguments),
+
+    /// Archive the rows older than their chain's retention.
+    ///
+    /// Expired rows are written to signed, compressed files under
+    /// `core.audit.archivePath` and then deleted. Chains on legal hold are
+    /// skipped. Exits with status 1 when any chain could not be archived.
+    Archive,
+
+    /// Set the retention of a chain.
+    Retention(RetentionArguments),
+
+    /// Place a chain on legal hold, which blocks the deletion of its rows,
+    /// or release it.
+    Hold(HoldArguments),
+
+    /// Restore the rows of an archive into the resource audit log, so they
+    /// can be queried again.
+    ///
+    /// The archive is verified first: manifest signature, file digest, and
+    /// hash chain. Restored rows are deleted again by the next retention run
+    /// unless the chain is on legal hold.
+    Restore(RestoreArguments),
+}
+
+#[derive(Parser, Debug)]
+pub(crate) struct VerifyArguments {
+    /// Verify a single tenant chain by UUID.
+    #[clap(long, value_name = "UUID", conflicts_with = "system")]
+    pub tenant_id: Option<Uuid>,
+
+    /// Verify only the system chain (events without a tenant).
+    #[clap(long)]
+    pub system: bool,
+}
+
+#[derive(Args, Debug)]
+#[group(required = true, multiple = false)]
+pub(crate) struct ChainArguments {
+    /// A tenant chain by UUID.
+    #[clap(long, value_name = "UUID")]
+    pub tenant_id: Option<Uuid>,
+
+    /// The system chain (events without a tenant).
+    #[clap(long)]
+    pub system: bool,
+}
+
+impl ChainArguments {
+    fn chain_tenant_id(&self) -> Option<Uuid> {
+        if self.system {
+            None
+        } else {
+            self.tenant_id
+        }
+    }
+}
+
+#[derive(Parser, Debug)]
+pub(crate) struct RetentionArguments {
+    #[clap(flatten)]
+    pub chain: ChainArguments,
+
+    /// Keep rows for this many days.
+    #[clap(long, value_name = "DAYS", required_unless_present = "default")]
+    pub days: Option<i32>,
+
+    /// Fall back to the configured default retention
+    /// (`core.audit.retentionDays`).
+    #[clap(long, conflicts_with = "days")]
+    pub default: bool,
+}
+
+#[derive(Parser, Debug)]
+pub(crate) struct HoldArguments {
+    #[clap(flatten)]
+    pub chain: ChainArguments,
+
+    /// Release the legal hold instead of placing it.
+    #[clap(long)]
+    pub release: bool,
+}
+
+#[derive(Parser, Debug)]
+pub(crate) struct RestoreArguments {
+    /// Path of the archive manifest (`<archive>.manifest.json`).
+    #[clap(value_name = "MANIFEST")]
+    pub manifest: PathBuf,
+}
+
+#[tracing::instrument(name = "verify_audit_chain_cmd", skip_all)]
+pub(crate) async fn verify_audit_chain_cmd(args: VerifyArguments) {
+    let (core_config, module) = load_core_module("audit verify").await;
+
+    let fetching_repo: &dyn ResourceAuditLogFetching = module.resolve_ref();
+    let enc_key_repo: &dyn EncryptionKeyFetching = module.resolve_ref();
+
+    let chains = if args.system {
+        vec![None]
+    } else if let Some(tenant_id) = args.tenant_id {
+        vec![Some(tenant_id)]
+    } else {
+        match fetching_repo.list_chain_tenants().await {
+            Ok(FetchManyResponseKind::Found(chains)) => chains,
+            Ok(FetchManyResponseKind::FoundPaginated { records, .. }) => {
+                records
+            }
+            Ok(FetchManyResponseKind::NotFound) => {
+                tracing::info!("No chained audit rows found");
+                return;
+            }
+            Err(err) => {
+                tracing::error!("Failed to list audit chains: {err}");
+                exit(1);
+            }
+        }
+    };
+
+    let mut broken_chains = 0;
+
+    for chain_tenant_id in chains {
+        let chain_name = chain_tenant_id
+            .map(|id| id.to_string())
+            .unwrap_or("system".to_string());
+
+        let report = match verify_resource_audit_chain(
+            chain_tenant_id,
+            core_config.account_life_cycle.to_owned(),
+            Box::new(fetching_repo),
+            Box::new(enc_key_repo),
+        )
+        .await
+        {
+            Ok(report) => report,
+            Err(err) => {
+                tracing::error!("Failed to verify chain {chain_name}: {err}");
+                broken_chains += 1;
+                continue;
+            }
+        };
+
+        if report.is_intact() {
+            tracing::info!(
+                "Chain {chain_name}: intact ({} rows, {} checkpoints, {} \
+                 archives, {} redacted)",
+                report.rows,
+                report.checkpoints,
+                report.archives,
+                report.redacted
+            );
+
+            continue;
+        }
+
+        broken_chains += 1;
+
+        tracing::error!(
+            "Chain {chain_name}: {} issue(s) ({} rows, {} checkpoints, {} \
+             archives, {} redacted)",
+            report.issues.len(),
+            report.rows,
+            report.checkpoints,
+            report.archives,
+            report.redacted
+        );
+
+        for issue in report.issues {
+            tracing::error!(
+                "  {}",
+                serde_json::to_string(&issue).unwrap_or(format!("{issue:?}"))
+            );
+        }
+    }
+
+    if broken_chains > 0 {
+        exit(1);
+    }
+}
+
+#[tracing::instrument(name = "archive_audit_rows_cmd", skip_all)]
+pub(crate) async fn archive_audit_rows_cmd() {
+    let (core_config, module) = load_core_module("audit archive").await;
+
+    let default_retention_days = match &core_config.audit.retention_days {
+        None => None,
+        Some(days) => match days.async_get_or_error().await {
+            Ok(days) => Some(days),
+            Err(err) => {
+                tracing::error!("Failed to resolve audit retention: {err}");
+                exit(1);
+            }
+        },
+    };
+
+    let store = match core_config.audit.archive_path.async_get_or_error().await
+    {
+        Ok(path) => match ResourceAuditArchiveStore::open(path) {
+            Ok(store) => store,
+            Err(err) => {
+                tracing::error!("Failed to open audit archive store: {err}");
+                exit(1);
+            }
+        },
+        Err(err) => {
+            tracing::error!("Failed to resolve audit archive path: {err}");
+            exit(1);
+        }
+    };
+
+    let fetching_repo: &dyn ResourceAuditLogFetching = module.resolve_ref();
+    let archival_repo: &dyn ResourceAuditLogArchival = module.resolve_ref();
+    let enc_key_repo: &dyn EncryptionKeyFetching = module.resolve_ref();
+
+    let reports = match archive_expired_resource_audit_rows(
+        default_retention_days,
+        &store,
+        core_config.account_life_cycle.to_owned(),
+        Box::new(fetching_repo),
+        Box::new(archival_repo),
+        Box::new(enc_key_repo),
+    )
+    .await
+    {
+        Ok(reports) => reports,
+        Err(err) => {
+            tracing::error!("Failed to archive audit rows: {err}");
+            exit(1);
+        }
+    };
+
+    let mut failed_chains = 0;
+
+    for report in reports {
+        let chain_name = report
+            .chain_tenant_id
+            .map(|id| id.to_string())
+            .unwrap_or("system".to_string());
+
+        if let Some(err) = &report.error {
+            failed_chains += 1;
+            tracing::error!("Chain {chain_name}: {err}");
+            continue;
+        }
+
+        match &report.archive {
+            Some(archive) => tracing::info!(
+                "Chain {chain_name}: archived {} rows to {}, purged {}",
+                archive.row_count,
+                store.manifest_path_of(&archive.location).display(),
+                report.purged_rows
+            ),
+            None if report.legal_hold => {
+                tracing::info!("Chain {chain_name}: on legal hold, skipped")
+            }
+            None => tracing::info!(
+                "Chain {chain_name}: nothing to archive, purged {}",
+                report.purged_rows
+            ),
+        }
+    }
+
+    if failed_chains > 0 {
+        exit(1);
+    }
+}
+
+#[tracing::instrument(name = "set_audit_retention_cmd", skip_all)]
+pub(crate) async fn set_audit_retention_cmd(args: RetentionArguments) {
+    let (_, module) = load_core_module("audit retention").await;
+
+    let fetching_repo: &dyn ResourceAuditLogFetching = module.resolve_ref();
+    let archival_repo: &dyn ResourceAuditLogArchival = module.resolve_ref();
+
+    match set_resource_audit_retention_days(
+        args.chain.chain_tenant_id(),
+        if args.default { None } else { args.days },
+        Box::new(fetching_repo),
+        Box::new(archival_repo),
+    )
+    .await
+    {
+        Ok(policy) => tracing::info!(
+            "Retention policy stored: {}",
+            serde_json::to_string(&policy).unwrap_or(format!("{policy:?}"))
+        ),
+        Err(err) => {
+            tracing::error!("Failed to set audit retention: {err}");
+            exit(1);
+        }
+    }
+}
+
+#[tracing::instrument(name = "set_audit_legal_hold_cmd", skip_all)]
+pub(crate) async fn set_audit_legal_hold_cmd(args: HoldArguments) {
+    let (_, module) = load_core_module("audit hold").await;
+
+    let fetching_repo: &dyn ResourceAuditLogFetching = module.resolve_ref();
+    let archival_repo: &dyn ResourceAuditLogArchival = module.resolve_ref();
+
+    match set_resource_audit_legal_hold(
+        args.chain.chain_tenant_id(),
+        !args.release,
+        Box::new(fetching_repo),
+        Box::new(archival_repo),
+    )
+    .await
+    {
+        Ok(policy) => tracing::info!(
+            "Retention policy stored: {}",
+            serde_json::to_string(&policy).unwrap_or(format!("{policy:?}"))
+        ),
+        Err(err) => {
+            tracing::error!("Failed to set audit legal hold: {err}");
+            exit(1);
+        }
+    }
+}
+
+#[tracing::instrument(name = "restore_audit_archive_cmd", skip_all)]
+pub(crate) async fn restore_audit_archive_cmd(args: RestoreArguments) {
+    let (core_config, module) = load_core_module("audit restore").await;
+
+    let archival_repo: &dyn ResourceAuditLogArchival = module.resolve_ref();
+    let enc_key_repo: &dyn EncryptionKeyFetching = module.resolve_ref();
+
+    match restore_resource_audit_archive(
+        &args.manifest,
+        core_config.account_life_cycle.to_owned(),
+        Box::new(archival_repo),
+        Box::new(enc_key_repo),
+    )
+    .await
+    {
+        Ok(restored) => tracing::info!(
+            "Restored {restored} rows from {}",
+            args.manifest.display()
+        ),
+        Err(err) => {
+            tracing::error!("Failed to restore audit archive: {err}");
+            exit(1);
+        }
+    }
+}
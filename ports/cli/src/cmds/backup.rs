@@ -0,0 +1,75 @@
+use crate::functions::{open_backup_store, resolve_backup_passphrase};
+
+use clap::Parser;
+use myc_core::domain::{dtos::backup::BackupScope, utils::backup};
+use std::{path::PathBuf, process::exit};
+use uuid::Uuid;
+
+#[derive(Parser, Debug)]
+pub(crate) struct Arguments {
+    /// Path of the archive to write.
+    #[clap(long, value_name = "PATH")]
+    pub output: PathBuf,
+
+    /// Archive only the rows of this tenant, instead of the whole instance.
+    #[clap(long, value_name = "UUID")]
+    pub tenant_id: Option<Uuid>,
+
+    /// Read the SQLite database of a standalone instance, instead of the
+    /// Postgres database at DATABASE_URL.
+    #[clap(long, value_name = "PATH")]
+    pub sqlite_path: Option<String>,
+
+    /// Encrypt the archive with a passphrase, read from
+    /// MYC_BACKUP_PASSPHRASE or prompted for.
+    #[clap(long)]
+    pub encrypt: bool,
+
+    /// Rows read per query.
+    #[clap(long, value_name = "ROWS", default_value_t = 500)]
+    pub batch_size: u64,
+}
+
+#[tracing::instrument(name = "backup_cmd", skip_all)]
+pub(crate) async fn backup_cmd(args: Arguments) {
+    let passphrase = args.encrypt.then(|| resolve_backup_passphrase(true));
+
+    let scope = match args.tenant_id {
+        Some(tenant_id) => BackupScope::Tenant(tenant_id),
+        None => BackupScope::Instance,
+    };
+
+    let mut store = open_backup_store(args.sqlite_path.as_deref(), false);
+
+    match backup(
+        store.as_mut(),
+        scope,
+        &args.output,
+        passphrase.as_deref(),
+        args.batch_size,
+    ) {
+        Err(err) => {
+            tracing::error!("backup failed: {err}");
+            exit(1);
+        }
+        Ok(tables) => {
+            for table in tables.iter() {
+                tracing::info!(
+                    "{}: {} rows (sha256 {})",
+                    table.table,
+                    table.rows,
+                    table.sha256
+                );
+            }
+
+            tracing::info!("backup complete");
+            tracing::info!("  Scope:    {scope}");
+            tracing::info!("  Tables:   {}", tables.len());
+            tracing::info!(
+                "  Rows:     {}",
+                tables.iter().map(|table| table.rows).sum::<u64>()
+            );
+            tracing::info!("  Archive:  {}", args.output.display());
+        }
+    }
+}
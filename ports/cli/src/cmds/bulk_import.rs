@@ -0,0 +1,241 @@
+use crate::functions::load_core_module;
+
+use clap::Parser;
+use myc_core::{
+    domain::{
+        dtos::{
+            bulk_import::{BulkImport, BulkImportFormat, BulkImportRow},
+            email::Email,
+            written_by::WrittenBy,
+        },
+        entities::{
+            AccountFetching, AccountRegistration, AccountTagRegistration,
+            BulkImportRegistration, BulkImportUpdating,
+            EmailSuppressionFetching, EncryptionKeyFetching, GuestRoleFetching,
+            GuestUserFetching, GuestUserOnAccountFetching,
+            GuestUserOnAccountUpdating, GuestUserRegistration,
+            LocalMessageWrite, NotificationRecipientFetching,
+            ResourceAuditLogRegistration, TenantFetching, WebHookRegistration,
+        },
+    },
+    use_cases::{run_bulk_import, validate_bulk_import_rows},
+};
+use mycelium_base::entities::GetOrCreateResponseKind;
+use shaku::HasComponent;
+use std::{path::PathBuf, process::exit, str::FromStr};
+use uuid::Uuid;
+
+#[derive(Parser, Debug)]
+pub(crate) struct Arguments {
+    #[clap(subcommand)]
+    pub cmd: Commands,
+}
+
+#[derive(Parser, Debug)]
+pub(crate) enum Commands {
+    /// Import guests and subscription accounts from a CSV or JSON file.
+    ///
+    /// Each row invites an email to a guest role on a subscription account,
+    /// created when missing. The import runs in this process and prints the
+    /// result of each row as JSON. Importing the same rows again prints the
+    /// job registered first instead of running it again.
+    Run(RunArguments),
+}
+
+#[derive(Parser, Debug)]
+pub(crate) struct RunArguments {
+    /// The tenant the rows are imported into.
+    #[clap(long, value_name = "UUID")]
+    pub tenant_id: Uuid,
+
+    /// The file holding the rows.
+    #[clap(long, value_name = "PATH")]
+    pub file: PathBuf,
+
+    /// The file format (csv or json). Defaults to the file extension.
+    #[clap(long, value_name = "FORMAT")]
+    pub format: Option<String>,
+
+    /// Only validate the rows, without importing them.
+    #[clap(long)]
+    pub dry_run: bool,
+
+    /// The email recorded as the author of the import and the invitations.
+    #[clap(long, value_name = "EMAIL")]
+    pub requested_by: String,
+}
+
+#[tracing::instrument(name = "run_bulk_import_cmd", skip_all)]
+pub(crate) async fn run_bulk_import_cmd(args: RunArguments) {
+    let requested_by = match Email::from_string(args.requested_by.to_owned()) {
+        Ok(email) => email,
+        Err(err) => {
+            tracing::error!("Invalid requester email: {err}");
+            exit(1);
+        }
+    };
+
+    let format = match args.format.to_owned().or_else(|| {
+        args.file
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_string())
+    }) {
+        Some(format) => match BulkImportFormat::from_str(&format) {
+            Ok(format) => format,
+            Err(err) => {
+                tracing::error!("{err}");
+                exit(1);
+            }
+        },
+        None => {
+            tracing::error!("The file has no extension, set --format");
+            exit(1);
+        }
+    };
+
+    let content = match std::fs::read_to_string(&args.file) {
+        Ok(content) => content,
+        Err(err) => {
+            tracing::error!("Failed to read {}: {err}", args.file.display());
+            exit(1);
+        }
+    };
+
+    let rows = match BulkImportRow::parse_many(format, &content) {
+        Ok(rows) => rows,
+        Err(err) => {
+            tracing::error!("Invalid import file: {}", err.msg());
+            exit(1);
+        }
+    };
+
+    let (core_config, module) = load_core_module("bulk-import run").await;
+
+    let guest_role_fetching_repo: &dyn GuestRoleFetching = module.resolve_ref();
+
+    if args.dry_run {
+        match validate_bulk_import_rows(
+            args.tenant_id,
+            &rows,
+            Box::new(guest_role_fetching_repo),
+        )
+        .await
+        {
+            Ok(results) => print_json(serde_json::to_string_pretty(&results)),
+            Err(err) => {
+                tracing::error!("Failed to validate the rows: {err}");
+                exit(1);
+            }
+        }
+
+        return;
+    }
+
+    //
+    // The job is registered as claimed by this process, so the API
+    // dispatchers leave it alone while it runs.
+    //
+    let bulk_import = BulkImport::new(
+        args.tenant_id,
+        rows,
+        WrittenBy::new_from_email(&requested_by.email()),
+    )
+    .claimed(format!("myc-cli-{}", Uuid::new_v4()));
+
+    let registration_repo: &dyn BulkImportRegistration = module.resolve_ref();
+
+    let bulk_import = match registration_repo.get_or_create(bulk_import).await {
+        Ok(GetOrCreateResponseKind::Created(bulk_import)) => bulk_import,
+        Ok(GetOrCreateResponseKind::NotCreated(bulk_import, msg)) => {
+            tracing::info!(
+                "{msg}: bulk import {} is {}",
+                bulk_import.id,
+                bulk_import.status
+            );
+
+            print_json(serde_json::to_string_pretty(&bulk_import));
+            return;
+        }
+        Err(err) => {
+            tracing::error!("Failed to register the bulk import: {err}");
+            exit(1);
+        }
+    };
+
+    let bulk_import_updating_repo: &dyn BulkImportUpdating =
+        module.resolve_ref();
+    let account_fetching_repo: &dyn AccountFetching = module.resolve_ref();
+    let account_registration_repo: &dyn AccountRegistration =
+        module.resolve_ref();
+    let account_tag_registration_repo: &dyn AccountTagRegistration =
+        module.resolve_ref();
+    let guest_user_registration_repo: &dyn GuestUserRegistration =
+        module.resolve_ref();
+    let guest_user_fetching_repo: &dyn GuestUserFetching = module.resolve_ref();
+    let guest_user_on_account_fetching_repo: &dyn GuestUserOnAccountFetching =
+        module.resolve_ref();
+    let guest_user_on_account_updating_repo: &dyn GuestUserOnAccountUpdating =
+        module.resolve_ref();
+    let message_write_repo: &dyn LocalMessageWrite = module.resolve_ref();
+    let suppression_repo: &dyn EmailSuppressionFetching = module.resolve_ref();
+    let tenant_fetching_repo: &dyn TenantFetching = module.resolve_ref();
+    let recipient_repo: &dyn NotificationRecipientFetching =
+        module.resolve_ref();
+    let enc_key_repo: &dyn EncryptionKeyFetching = module.resolve_ref();
+    let webhook_registration_repo: &dyn WebHookRegistration =
+        module.resolve_ref();
+    let audit_repo: &dyn ResourceAuditLogRegistration = module.resolve_ref();
+
+    match run_bulk_import(
+        bulk_import,
+        core_config.account_life_cycle,
+        Box::new(bulk_import_updating_repo),
+        Box::new(guest_role_fetching_repo),
+        Box::new(account_fetching_repo),
+        Box::new(account_registration_repo),
+        Box::new(account_tag_registration_repo),
+        Box::new(guest_user_registration_repo),
+        Box::new(guest_user_fetching_repo),
+        Box::new(guest_user_on_account_fetching_repo),
+        Box::new(guest_user_on_account_updating_repo),
+        Box::new(message_write_repo),
+        Box::new(suppression_repo),
+        Box::new(tenant_fetching_repo),
+        Box::new(recipient_repo),
+        Box::new(enc_key_repo),
+        Box::new(webhook_registration_repo),
+        Box::new(audit_repo),
+    )
+    .await
+    {
+        Ok(bulk_import) => {
+            let summary = bulk_import.summary();
+
+            tracing::info!(
+                "Bulk import {} {}: {} rows, {} done, {} failed, {} invalid",
+                bulk_import.id,
+                bulk_import.status,
+                summary.total,
+                summary.done,
+                summary.failed,
+                summary.invalid
+            );
+
+            print_json(serde_json::to_string_pretty(&bulk_import.results));
+        }
+        Err(err) => {
+            tracing::error!("Failed to run the bulk import: {err}");
+            exit(1);
+        }
+    }
+}
+
+fn print_json(json: serde_json::Result<String>) {
+    match json {
+        Ok(json) => println!("{json}"),
+        Err(err) => {
+            tracing::error!("Failed to serialize the result: {err}");
+            exit(1);
+        }
+    }
+}
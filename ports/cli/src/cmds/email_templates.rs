@@ -0,0 +1,214 @@
+use crate::functions::load_core_module;
+
+use clap::Parser;
+use myc_core::{
+    domain::{
+        dtos::email::Email,
+        entities::{
+            EmailSuppressionFetching, LocalMessageWrite, TenantFetching,
+        },
+    },
+    use_cases::role_scoped::system_manager::email_templates::{
+        list_available_email_templates, preview_email_template,
+        queue_test_email,
+    },
+};
+use mycelium_base::entities::{CreateResponseKind, FetchManyResponseKind};
+use shaku::HasComponent;
+use std::{collections::HashMap, process::exit};
+use uuid::Uuid;
+
+#[derive(Parser, Debug)]
+pub(crate) struct Arguments {
+    #[clap(subcommand)]
+    pub cmd: Commands,
+}
+
+#[derive(Parser, Debug)]
+pub(crate) enum Commands {
+    /// List the templates found in `TEMPLATES_DIR`, with their locales.
+    List(ListArguments),
+
+    /// Render a template to HTML or text, without sending it.
+    ///
+    /// The template is rendered with its sample parameters, replaced by the
+    /// `--param` values.
+    Render(RenderArguments),
+
+    /// Send a test message of a template.
+    ///
+    /// The message is queued and delivered by the email dispatcher of a
+    /// running API, through the configured transport. The subject is
+    /// prefixed with `[TEST]`.
+    SendTest(SendTestArguments),
+}
+
+#[derive(Parser, Debug)]
+pub(crate) struct ListArguments {
+    /// Only list the templates available in this locale.
+    #[clap(long, value_name = "LOCALE")]
+    pub locale: Option<String>,
+}
+
+#[derive(Parser, Debug)]
+pub(crate) struct TemplateArguments {
+    /// The template name, as `magic-link-request`.
+    #[clap(value_name = "NAME")]
+    pub name: String,
+
+    /// The locale to render. Defaults to the configured locale.
+    #[clap(long, value_name = "LOCALE")]
+    pub locale: Option<String>,
+
+    /// A template parameter, replacing its sample value. Repeatable.
+    #[clap(long = "param", value_name = "KEY=VALUE", value_parser = parse_parameter)]
+    pub parameters: Vec<(String, String)>,
+
+    /// Render with the name, links and locale of a tenant.
+    #[clap(long, value_name = "UUID")]
+    pub tenant_id: Option<Uuid>,
+}
+
+#[derive(Parser, Debug)]
+pub(crate) struct RenderArguments {
+    #[clap(flatten)]
+    pub template: TemplateArguments,
+
+    /// Print the plain-text alternative instead of the HTML body.
+    #[clap(long)]
+    pub text: bool,
+}
+
+#[derive(Parser, Debug)]
+pub(crate) struct SendTestArguments {
+    #[clap(flatten)]
+    pub template: TemplateArguments,
+
+    /// The recipient address.
+    #[clap(long, value_name = "EMAIL")]
+    pub to: String,
+}
+
+fn parse_parameter(value: &str) -> Result<(String, String), String> {
+    match value.split_once('=') {
+        Some((key, value)) if !key.trim().is_empty() => {
+            Ok((key.trim().to_string(), value.to_string()))
+        }
+        _ => Err(format!("Expected KEY=VALUE, got {value}")),
+    }
+}
+
+impl TemplateArguments {
+    fn parameters(&self) -> Option<HashMap<String, String>> {
+        if self.parameters.is_empty() {
+            return None;
+        }
+
+        Some(self.parameters.iter().cloned().collect())
+    }
+}
+
+#[tracing::instrument(name = "list_email_templates_cmd", skip_all)]
+pub(crate) async fn list_email_templates_cmd(args: ListArguments) {
+    let templates = match list_available_email_templates(args.locale) {
+        FetchManyResponseKind::Found(templates) => templates,
+        FetchManyResponseKind::FoundPaginated { records, .. } => records,
+        FetchManyResponseKind::NotFound => {
+            tracing::info!("No email templates found");
+            return;
+        }
+    };
+
+    for template in templates {
+        tracing::info!(
+            "{}: {}{}",
+            template.name,
+            template.locales.join(", "),
+            if template.tenant_overridable {
+                " (tenant overridable)"
+            } else {
+                ""
+            }
+        );
+    }
+}
+
+#[tracing::instrument(name = "render_email_template_cmd", skip_all)]
+pub(crate) async fn render_email_template_cmd(args: RenderArguments) {
+    let (core_config, module) =
+        load_core_module("email-templates render").await;
+
+    let tenant_fetching_repo: &dyn TenantFetching = module.resolve_ref();
+
+    let rendered = match preview_email_template(
+        args.template.name.to_owned(),
+        args.template.locale.to_owned(),
+        args.template.parameters(),
+        args.template.tenant_id,
+        core_config.account_life_cycle,
+        Box::new(tenant_fetching_repo),
+    )
+    .await
+    {
+        Ok(rendered) => rendered,
+        Err(err) => {
+            tracing::error!("Failed to render email template: {err}");
+            exit(1);
+        }
+    };
+
+    println!("Subject: {}\n", rendered.subject);
+
+    if args.text {
+        println!("{}", rendered.text_body.unwrap_or_default());
+    } else {
+        println!("{}", rendered.body);
+    }
+}
+
+#[tracing::instrument(name = "send_test_email_cmd", skip_all)]
+pub(crate) async fn send_test_email_cmd(args: SendTestArguments) {
+    let to = match Email::from_string(args.to.to_owned()) {
+        Ok(email) => email,
+        Err(err) => {
+            tracing::error!("Invalid recipient: {err}");
+            exit(1);
+        }
+    };
+
+    let (core_config, module) =
+        load_core_module("email-templates send-test").await;
+
+    let message_write_repo: &dyn LocalMessageWrite = module.resolve_ref();
+    let suppression_repo: &dyn EmailSuppressionFetching = module.resolve_ref();
+    let tenant_fetching_repo: &dyn TenantFetching = module.resolve_ref();
+
+    match queue_test_email(
+        args.template.name.to_owned(),
+        args.template.locale.to_owned(),
+        args.template.parameters(),
+        args.template.tenant_id,
+        to,
+        core_config.account_life_cycle,
+        Box::new(message_write_repo),
+        Box::new(suppression_repo),
+        Box::new(tenant_fetching_repo),
+    )
+    .await
+    {
+        Ok(CreateResponseKind::Created(id)) => {
+            tracing::info!(
+                "Test message queued{}",
+                id.map(|id| format!(": {id}")).unwrap_or_default()
+            )
+        }
+        Ok(CreateResponseKind::NotCreated(_, msg)) => {
+            tracing::error!("Test message not queued: {msg}");
+            exit(1);
+        }
+        Err(err) => {
+            tracing::error!("Failed to send test message: {err}");
+            exit(1);
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use clap::Parser;
 use myc_core::{
-    domain::entities::ErrorCodeRegistration,
+    domain::entities::{
+        ErrorCodeRegistration, ErrorCodeTranslationRegistration,
+    },
     use_cases::role_scoped::system_manager::error_codes::batch_register_native_error_codes,
 };
 use myc_diesel::repositories::{
@@ -42,11 +44,18 @@ pub(crate) async fn batch_register_native_error_codes_cmd() {
     );
 
     let repo: &dyn ErrorCodeRegistration = module.resolve_ref();
+    let translation_repo: &dyn ErrorCodeTranslationRegistration =
+        module.resolve_ref();
 
     //
     // Batch register the native error codes
     //
-    match batch_register_native_error_codes(Box::new(repo)).await {
+    match batch_register_native_error_codes(
+        Box::new(repo),
+        Box::new(translation_repo),
+    )
+    .await
+    {
         Err(err) => tracing::error!("{err}"),
         Ok(res) => {
             if res.unpersisted_errors.len() > 0 {
@@ -65,6 +74,11 @@ pub(crate) async fn batch_register_native_error_codes_cmd() {
                 "{} native error codes registered",
                 res.persisted_errors
             );
+
+            tracing::info!(
+                "{} native error code translations registered",
+                res.persisted_translations
+            );
         }
     };
 }
@@ -0,0 +1,214 @@
+use crate::functions::try_to_resolve_database_url;
+
+use clap::Parser;
+use myc_config::{
+    read_standalone_secret, standalone_secrets_dir, STANDALONE_KEYRING_SERVICE,
+};
+use myc_core::{domain::utils::TableChecksum, models::CoreConfig};
+use myc_diesel::{
+    migration::{migrate_backend, BackendMigrationSource},
+    repositories::DieselDbPoolProvider,
+};
+use myc_diesel_sqlite::table_reader::SqliteTableReader;
+use mycelium_base::utils::errors::MappedErrors;
+use serde_json::{Map, Value};
+use std::{env::var, path::PathBuf, process::exit};
+
+/// Environment variable the operator sets to the standalone `token_secret`
+/// when it was supplied explicitly (instead of generated) on the standalone
+/// instance.
+const SOURCE_TOKEN_SECRET_ENV: &str = "MYC_SOURCE_TOKEN_SECRET";
+
+/// HMAC key version of the connection strings a standalone instance signs.
+const STANDALONE_HMAC_VERSION: u32 = 1;
+
+/// Rows read per query while checksumming a source table.
+const CHECKSUM_PAGE_SIZE: u64 = 1000;
+
+#[derive(Parser, Debug)]
+pub(crate) struct Arguments {
+    /// Path of the SQLite database of the standalone instance.
+    ///
+    /// The standalone instance must be stopped while the migration runs.
+    #[clap(long, value_name = "PATH")]
+    pub sqlite_path: String,
+
+    /// Rows copied per transaction.
+    #[clap(long, value_name = "ROWS", default_value_t = 500)]
+    pub batch_size: u64,
+}
+
+struct SqliteSource(SqliteTableReader);
+
+impl BackendMigrationSource for SqliteSource {
+    fn tables(&mut self) -> Result<Vec<String>, MappedErrors> {
+        self.0.tables()
+    }
+
+    fn count_rows(&mut self, table: &str) -> Result<u64, MappedErrors> {
+        self.0.count_rows(table)
+    }
+
+    fn read_rows(
+        &mut self,
+        table: &str,
+        offset: u64,
+        limit: u64,
+    ) -> Result<Vec<Map<String, Value>>, MappedErrors> {
+        self.0.read_rows(table, offset, limit)
+    }
+
+    fn checksum_rows(
+        &mut self,
+        table: &str,
+        excluded_columns: &[&str],
+    ) -> Result<TableChecksum, MappedErrors> {
+        self.0
+            .checksum_rows(table, excluded_columns, CHECKSUM_PAGE_SIZE)
+    }
+}
+
+/// Resolve a secret of the standalone instance
+///
+/// An explicit value in `env` wins; otherwise the secret generated by the
+/// standalone instance is read from the OS keyring or from the encrypted file
+/// next to its database.
+fn resolve_source_secret(
+    env: Option<&str>,
+    sqlite_path: &str,
+    name: &str,
+) -> Result<Option<String>, MappedErrors> {
+    if let Some(value) = env.and_then(|env| var(env).ok()) {
+        return Ok(Some(value));
+    }
+
+    read_standalone_secret(
+        STANDALONE_KEYRING_SERVICE,
+        &standalone_secrets_dir(sqlite_path),
+        name,
+    )
+}
+
+#[tracing::instrument(name = "migrate_backend_cmd", skip_all)]
+pub(crate) async fn migrate_backend_cmd(args: Arguments) {
+    let settings_path = match var("SETTINGS_PATH") {
+        Ok(p) => p,
+        Err(_) => {
+            tracing::error!(
+                "SETTINGS_PATH env var is required for migrate-backend"
+            );
+            exit(1);
+        }
+    };
+
+    let core_config = match CoreConfig::from_default_config_file(PathBuf::from(
+        &settings_path,
+    )) {
+        Ok(c) => c,
+        Err(err) => {
+            tracing::error!("Failed to load core config: {err}");
+            exit(1);
+        }
+    };
+
+    let source_token_secret = match resolve_source_secret(
+        Some(SOURCE_TOKEN_SECRET_ENV),
+        &args.sqlite_path,
+        "token_secret",
+    ) {
+        Ok(Some(secret)) => secret,
+        Ok(None) => {
+            tracing::error!(
+                "The standalone token secret was not found in the OS keyring \
+                 nor next to the database: set {SOURCE_TOKEN_SECRET_ENV}"
+            );
+            exit(1);
+        }
+        Err(err) => {
+            tracing::error!(
+                "Failed to read the standalone token secret: {err}"
+            );
+            exit(1);
+        }
+    };
+
+    let target_config = core_config.account_life_cycle.clone();
+    let source_config =
+        target_config.with_token_secret_override(source_token_secret);
+
+    //
+    // Connection strings carry the signature of the standalone HMAC key,
+    // which cannot be re-signed: the target has to verify them with the very
+    // same key.
+    //
+    let source_hmac_secret =
+        resolve_source_secret(None, &args.sqlite_path, "hmac_secret")
+            .ok()
+            .flatten();
+
+    let target_hmac_secret =
+        match target_config.hmac_secret_resolver(STANDALONE_HMAC_VERSION) {
+            Some(resolver) => resolver.async_get_or_error().await.ok(),
+            None => None,
+        };
+
+    if source_hmac_secret.is_some() && source_hmac_secret != target_hmac_secret
+    {
+        tracing::warn!(
+            "The target hmacSecrets version {STANDALONE_HMAC_VERSION} differs \
+             from the standalone HMAC secret: connection strings issued by the \
+             standalone instance will be rejected until it is configured"
+        );
+    }
+
+    let reader = match SqliteTableReader::open(&args.sqlite_path) {
+        Ok(reader) => reader,
+        Err(err) => {
+            tracing::error!("Failed to open the standalone database: {err}");
+            exit(1);
+        }
+    };
+
+    let database_url = try_to_resolve_database_url();
+    let pool = DieselDbPoolProvider::new(&database_url);
+
+    match migrate_backend(
+        &pool,
+        &mut SqliteSource(reader),
+        &source_config,
+        &target_config,
+        args.batch_size,
+    )
+    .await
+    {
+        Err(err) => {
+            tracing::error!("migrate-backend failed: {err}");
+            tracing::error!(
+                "Copied batches are kept: once fixed, running the command \
+                 again resumes the copy"
+            );
+            exit(1);
+        }
+        Ok(report) => {
+            for table in report.tables.iter() {
+                tracing::info!(
+                    "{}: {} rows verified (checksum {}{})",
+                    table.table,
+                    table.rows,
+                    table.checksum,
+                    match table.resumed_from {
+                        0 => String::new(),
+                        rows if rows == table.rows => {
+                            ", copied by a previous run".to_string()
+                        }
+                        rows => format!(", resumed after {rows} rows"),
+                    }
+                );
+            }
+
+            tracing::info!("migrate-backend complete");
+            tracing::info!("  Tables copied:  {}", report.tables.len());
+            tracing::info!("  DEKs rewrapped: {}", report.rewrapped_deks);
+        }
+    }
+}
@@ -1,4 +1,11 @@
 pub(crate) mod accounts;
+pub(crate) mod admin;
+pub(crate) mod audit;
+pub(crate) mod backup;
+pub(crate) mod bulk_import;
+pub(crate) mod email_templates;
 pub(crate) mod error_codes;
+pub(crate) mod migrate_backend;
 pub(crate) mod migrate_dek;
+pub(crate) mod restore;
 pub(crate) mod rotate_kek;
@@ -0,0 +1,178 @@
+use crate::functions::{open_backup_store, resolve_backup_passphrase};
+
+use clap::Parser;
+use myc_config::{
+    resolve_or_generate_standalone_secret, secret_resolver::SecretResolver,
+    standalone_secrets_dir, STANDALONE_KEYRING_SERVICE,
+};
+use myc_core::{
+    domain::utils::{restore, BackupArchiveReader},
+    models::{AccountLifeCycle, CoreConfig},
+};
+use std::{env::var, path::PathBuf, process::exit};
+
+/// Environment variable the operator sets to the `token_secret` of the
+/// instance the backup was written on, when it is not the one of the target.
+const SOURCE_TOKEN_SECRET_ENV: &str = "MYC_SOURCE_TOKEN_SECRET";
+
+#[derive(Parser, Debug)]
+pub(crate) struct Arguments {
+    /// Path of the archive to restore.
+    #[clap(long, value_name = "PATH")]
+    pub input: PathBuf,
+
+    /// Restore to the SQLite database of a standalone instance, created if
+    /// missing, instead of the Postgres database at DATABASE_URL.
+    ///
+    /// The standalone instance must be stopped while the restore runs.
+    #[clap(long, value_name = "PATH")]
+    pub sqlite_path: Option<String>,
+
+    /// Rows written per statement.
+    #[clap(long, value_name = "ROWS", default_value_t = 500)]
+    pub batch_size: u64,
+}
+
+/// Resolve the lifecycle config the target KEK is derived from
+///
+/// A standalone target keeps its token secret in the OS keyring or next to
+/// its database, unless the config points to an environment variable.
+async fn resolve_target_config(
+    config: AccountLifeCycle,
+    sqlite_path: Option<&str>,
+) -> AccountLifeCycle {
+    let Some(sqlite_path) = sqlite_path else {
+        return config;
+    };
+
+    let token_secret = match config.token_secret_resolver() {
+        resolver @ SecretResolver::Env(_) => {
+            resolver.async_get_or_error().await
+        }
+        _ => resolve_or_generate_standalone_secret(
+            STANDALONE_KEYRING_SERVICE,
+            &standalone_secrets_dir(sqlite_path),
+            "token_secret",
+        ),
+    };
+
+    match token_secret {
+        Ok(secret) => config.with_token_secret_override(secret),
+        Err(err) => {
+            tracing::error!(
+                "Failed to resolve the standalone token secret: {err}"
+            );
+            exit(1);
+        }
+    }
+}
+
+#[tracing::instrument(name = "restore_cmd", skip_all)]
+pub(crate) async fn restore_cmd(args: Arguments) {
+    let settings_path = match var("SETTINGS_PATH") {
+        Ok(p) => p,
+        Err(_) => {
+            tracing::error!("SETTINGS_PATH env var is required for restore");
+            exit(1);
+        }
+    };
+
+    let core_config = match CoreConfig::from_default_config_file(PathBuf::from(
+        &settings_path,
+    )) {
+        Ok(c) => c,
+        Err(err) => {
+            tracing::error!("Failed to load core config: {err}");
+            exit(1);
+        }
+    };
+
+    let passphrase = match BackupArchiveReader::is_encrypted(&args.input) {
+        Ok(true) => Some(resolve_backup_passphrase(false)),
+        Ok(false) => None,
+        Err(err) => {
+            tracing::error!("Failed to open the archive: {err}");
+            exit(1);
+        }
+    };
+
+    let target_config = resolve_target_config(
+        core_config.account_life_cycle,
+        args.sqlite_path.as_deref(),
+    )
+    .await;
+
+    let kek = match target_config.derive_kek_bytes().await {
+        Ok(kek) => kek,
+        Err(err) => {
+            tracing::error!("Failed to derive the target KEK: {err}");
+            exit(1);
+        }
+    };
+
+    let source_kek = match var(SOURCE_TOKEN_SECRET_ENV) {
+        Ok(secret) => match target_config
+            .with_token_secret_override(secret)
+            .derive_kek_bytes()
+            .await
+        {
+            Ok(kek) => Some(kek),
+            Err(err) => {
+                tracing::error!("Failed to derive the source KEK: {err}");
+                exit(1);
+            }
+        },
+        Err(_) => None,
+    };
+
+    let mut store = open_backup_store(args.sqlite_path.as_deref(), true);
+
+    match restore(
+        store.as_mut(),
+        &args.input,
+        passphrase.as_deref(),
+        &kek,
+        source_kek.as_ref(),
+        args.batch_size,
+    ) {
+        Err(err) => {
+            tracing::error!("restore failed: {err}");
+            tracing::error!("Nothing was written to the target database");
+            exit(1);
+        }
+        Ok(report) => {
+            for table in report.tables.iter() {
+                tracing::info!(
+                    "{}: {} rows restored{}",
+                    table.table,
+                    table.rows,
+                    match table.skipped {
+                        0 => String::new(),
+                        skipped => format!(
+                            ", {skipped} skipped (referencing rows missing \
+                             from the target)"
+                        ),
+                    }
+                );
+            }
+
+            if report.detached_parent {
+                tracing::warn!(
+                    "The parent of the restored tenant does not exist in the \
+                     target: the tenant was restored as a root tenant"
+                );
+            }
+
+            tracing::info!("restore complete");
+            tracing::info!("  Scope:          {}", report.header.scope);
+            tracing::info!(
+                "  Written by:     Mycelium {} ({})",
+                report.header.mycelium_version,
+                report.header.backend
+            );
+            tracing::info!("  Created at:     {}", report.header.created_at);
+            tracing::info!("  Tables:         {}", report.tables.len());
+            tracing::info!("  DEKs rewrapped: {}", report.rewrapped_deks);
+        }
+    }
+}
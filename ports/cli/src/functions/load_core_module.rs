@@ -0,0 +1,74 @@
+use super::try_to_resolve_database_url;
+
+use myc_core::{domain::utils::ResourceAuditSpill, models::CoreConfig};
+use myc_diesel::repositories::{
+    DieselDbPoolProvider, DieselDbPoolProviderParameters,
+    ResourceAuditLogRegistrationSqlDbRepository,
+    ResourceAuditLogRegistrationSqlDbRepositoryParameters, SqlAppModule,
+};
+use std::{env::var, path::PathBuf, process::exit, sync::Arc};
+
+/// Load core config and module
+///
+/// Load the core config from `SETTINGS_PATH` and build the module the
+/// commands resolve their repositories from.
+///
+pub(crate) async fn load_core_module(
+    command: &str,
+) -> (CoreConfig, Arc<SqlAppModule>) {
+    let settings_path = match var("SETTINGS_PATH") {
+        Ok(p) => p,
+        Err(_) => {
+            tracing::error!("SETTINGS_PATH env var is required for {command}");
+            exit(1);
+        }
+    };
+
+    let core_config = match CoreConfig::from_default_config_file(PathBuf::from(
+        &settings_path,
+    )) {
+        Ok(c) => c,
+        Err(err) => {
+            tracing::error!("Failed to load core config: {err}");
+            exit(1);
+        }
+    };
+
+    let database_url = try_to_resolve_database_url();
+
+    let spill = match core_config.audit.spill_path.async_get_or_error().await {
+        Ok(path) => match ResourceAuditSpill::open(path) {
+            Ok(spill) => Arc::new(spill),
+            Err(err) => {
+                tracing::error!("Failed to open audit spill file: {err}");
+                exit(1);
+            }
+        },
+        Err(err) => {
+            tracing::error!("Failed to resolve audit spill path: {err}");
+            exit(1);
+        }
+    };
+
+    let module = Arc::new(
+        SqlAppModule::builder()
+            .with_component_parameters::<DieselDbPoolProvider>(
+                DieselDbPoolProviderParameters {
+                    pool: DieselDbPoolProvider::new(database_url.as_str()),
+                },
+            )
+            //
+            // No dispatcher drains the spill file here, so the events the
+            // commands emit (as the bulk import's) are persisted by the next
+            // API dispatcher.
+            //
+            .with_component_parameters::<ResourceAuditLogRegistrationSqlDbRepository>(
+                ResourceAuditLogRegistrationSqlDbRepositoryParameters {
+                    spill,
+                },
+            )
+            .build(),
+    );
+
+    (core_config, module)
+}
@@ -1,3 +1,7 @@
+mod load_core_module;
+mod open_backup_store;
 mod try_to_resolve_database_url;
 
+pub(crate) use load_core_module::*;
+pub(crate) use open_backup_store::*;
 pub(crate) use try_to_resolve_database_url::*;
@@ -0,0 +1,76 @@
+use super::try_to_resolve_database_url;
+
+use myc_core::domain::utils::BackupStore;
+use myc_diesel::{
+    migration::PostgresBackupStore, repositories::DieselDbPoolProvider,
+};
+use myc_diesel_sqlite::backup_store::SqliteBackupStore;
+use std::{env::var, process::exit};
+
+/// Environment variable holding the passphrase of encrypted backup archives
+const BACKUP_PASSPHRASE_ENV: &str = "MYC_BACKUP_PASSPHRASE";
+
+/// Open the database a backup is read from or restored to
+///
+/// The SQLite database at `sqlite_path` if given, otherwise the Postgres
+/// database at DATABASE_URL. With `provision`, a missing SQLite database is
+/// created with the current schema.
+///
+pub(crate) fn open_backup_store(
+    sqlite_path: Option<&str>,
+    provision: bool,
+) -> Box<dyn BackupStore> {
+    let store: Result<Box<dyn BackupStore>, _> = match sqlite_path {
+        Some(path) if provision => SqliteBackupStore::provision(path)
+            .map(|store| Box::new(store) as Box<dyn BackupStore>),
+        Some(path) => SqliteBackupStore::open(path)
+            .map(|store| Box::new(store) as Box<dyn BackupStore>),
+        None => {
+            let database_url = try_to_resolve_database_url();
+            let pool = DieselDbPoolProvider::new(&database_url);
+
+            PostgresBackupStore::new(&pool)
+                .map(|store| Box::new(store) as Box<dyn BackupStore>)
+        }
+    };
+
+    match store {
+        Ok(store) => store,
+        Err(err) => {
+            tracing::error!("Failed to open the database: {err}");
+            exit(1);
+        }
+    }
+}
+
+/// Resolve the passphrase of an encrypted backup archive
+///
+/// Try to fetch it from the MYC_BACKUP_PASSPHRASE environment variable. If
+/// not found, ask for it, twice when `confirm` is set.
+///
+pub(crate) fn resolve_backup_passphrase(confirm: bool) -> String {
+    if let Ok(passphrase) = var(BACKUP_PASSPHRASE_ENV) {
+        if !passphrase.is_empty() {
+            return passphrase;
+        }
+    }
+
+    let passphrase = rpassword::prompt_password("Backup passphrase: ")
+        .expect("Failed to read the backup passphrase");
+
+    if passphrase.is_empty() {
+        tracing::error!("The backup passphrase must not be empty");
+        exit(1);
+    }
+
+    if confirm
+        && rpassword::prompt_password("Repeat the backup passphrase: ")
+            .expect("Failed to read the backup passphrase")
+            != passphrase
+    {
+        tracing::error!("The backup passphrases do not match");
+        exit(1);
+    }
+
+    passphrase
+}
@@ -2,7 +2,10 @@ mod cmds;
 mod functions;
 
 use clap::Parser;
-use cmds::{accounts, error_codes, migrate_dek, rotate_kek};
+use cmds::{
+    accounts, admin, audit, backup, bulk_import, email_templates, error_codes,
+    migrate_backend, migrate_dek, restore, rotate_kek,
+};
 use std::env::set_var;
 
 #[derive(Parser, Debug)]
@@ -19,6 +22,37 @@ enum Cli {
     /// Rewrap every tenant's DEK under a new KEK (without touching
     /// user-data ciphertexts or invalidating connection strings).
     Kek(rotate_kek::Arguments),
+
+    /// Verify, archive, and restore the tamper-evident resource audit log
+    Audit(audit::Arguments),
+
+    /// List, render, and send test messages of the notification email
+    /// templates
+    EmailTemplates(email_templates::Arguments),
+
+    /// Import guests and subscription accounts in bulk
+    BulkImport(bulk_import::Arguments),
+
+    /// Copy a standalone (SQLite) installation to the Postgres database of
+    /// the configured gateway, rewrapping the tenant DEKs to its KEK.
+    ///
+    /// Row counts and checksums are verified table by table. An interrupted
+    /// run resumes from the last copied batch when started again.
+    MigrateBackend(migrate_backend::Arguments),
+
+    /// Write the rows of the instance, or of a single tenant, to a
+    /// versioned and compressed archive, optionally encrypted.
+    Backup(backup::Arguments),
+
+    /// Restore an archive written by `backup`: an instance archive into an
+    /// empty database, a tenant archive over the current rows of the tenant.
+    ///
+    /// Everything is written in a single transaction.
+    Restore(restore::Arguments),
+
+    /// Call the JSON-RPC admin methods of the gateway, one subcommand per
+    /// method, authenticated with a connection string or via the database.
+    Admin(admin::Arguments),
 }
 
 #[tokio::main]
@@ -52,5 +86,42 @@ pub async fn main() {
                 rotate_kek::rotate_kek_cmd(args).await
             }
         },
+        Cli::Audit(sub_args) => match sub_args.cmd {
+            audit::Commands::Verify(args) => {
+                audit::verify_audit_chain_cmd(args).await
+            }
+            audit::Commands::Archive => audit::archive_audit_rows_cmd().await,
+            audit::Commands::Retention(args) => {
+                audit::set_audit_retention_cmd(args).await
+            }
+            audit::Commands::Hold(args) => {
+                audit::set_audit_legal_hold_cmd(args).await
+            }
+            audit::Commands::Restore(args) => {
+                audit::restore_audit_archive_cmd(args).await
+            }
+        },
+        Cli::EmailTemplates(sub_args) => match sub_args.cmd {
+            email_templates::Commands::List(args) => {
+                email_templates::list_email_templates_cmd(args).await
+            }
+            email_templates::Commands::Render(args) => {
+                email_templates::render_email_template_cmd(args).await
+            }
+            email_templates::Commands::SendTest(args) => {
+                email_templates::send_test_email_cmd(args).await
+            }
+        },
+        Cli::BulkImport(sub_args) => match sub_args.cmd {
+            bulk_import::Commands::Run(args) => {
+                bulk_import::run_bulk_import_cmd(args).await
+            }
+        },
+        Cli::MigrateBackend(args) => {
+            migrate_backend::migrate_backend_cmd(args).await
+        }
+        Cli::Backup(args) => backup::backup_cmd(args).await,
+        Cli::Restore(args) => restore::restore_cmd(args).await,
+        Cli::Admin(args) => admin::admin_cmd(args).await,
     }
 }